@@ -0,0 +1,220 @@
+//! Async client over `reqwest`.
+
+use reqwest::{Method, Url};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::error::{error_message, ApiError};
+use crate::routes::{self, Route};
+use crate::types::*;
+
+/// Client for one ClickGraph server.
+///
+/// Cheap to clone; clones share the connection pool.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: Url,
+    http: reqwest::Client,
+}
+
+impl Client {
+    /// Client for the server at `base_url` (e.g. `http://localhost:7475`).
+    pub fn new(base_url: &str) -> Result<Self, url::ParseError> {
+        Self::with_http_client(base_url, reqwest::Client::new())
+    }
+
+    /// Client sending through `http`, for custom timeouts, TLS or headers.
+    pub fn with_http_client(
+        base_url: &str,
+        http: reqwest::Client,
+    ) -> Result<Self, url::ParseError> {
+        Ok(Self {
+            base_url: Url::parse(base_url)?,
+            http,
+        })
+    }
+
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// `GET /health`.
+    pub async fn health(&self) -> Result<HealthResponse, ApiError> {
+        self.send(routes::HEALTH, "", None::<&()>).await
+    }
+
+    /// `POST /query` with a JSON result.
+    ///
+    /// `request.format` must be unset or `JSONEachRow`; use [`Client::query_text`]
+    /// for the text formats.
+    pub async fn query(&self, request: &QueryRequest) -> Result<QueryResponse, ApiError> {
+        self.send(routes::QUERY, "", Some(request)).await
+    }
+
+    /// `POST /query` with several `;`-separated statements.
+    pub async fn query_statements(
+        &self,
+        request: &QueryRequest,
+    ) -> Result<StatementsResponse, ApiError> {
+        self.send(routes::QUERY, "", Some(request)).await
+    }
+
+    /// `POST /query`, returning the body as text (`Pretty`, `CSV`, ...).
+    pub async fn query_text(&self, request: &QueryRequest) -> Result<String, ApiError> {
+        self.send_text(routes::QUERY, "", Some(request)).await
+    }
+
+    /// `POST /query/sql`: translate Cypher to SQL without running it.
+    pub async fn query_sql(
+        &self,
+        request: &SqlGenerationRequest,
+    ) -> Result<SqlGenerationResponse, ApiError> {
+        self.send(routes::QUERY_SQL, "", Some(request)).await
+    }
+
+    /// `POST /query/natural`: answer a question with LLM-written Cypher.
+    pub async fn query_natural(
+        &self,
+        request: &NaturalQueryRequest,
+    ) -> Result<NaturalQueryResponse, ApiError> {
+        self.send(routes::QUERY_NATURAL, "", Some(request)).await
+    }
+
+    /// `POST /export`: the subgraph as a GraphML or GEXF document.
+    pub async fn export(&self, request: &ExportRequest) -> Result<String, ApiError> {
+        self.send_text(routes::EXPORT, "", Some(request)).await
+    }
+
+    /// `POST /import`: NDJSON progress lines, one per mapping, then a
+    /// summary (or the planned statements with `sql_only`).
+    pub async fn import(&self, request: &ImportRequest) -> Result<String, ApiError> {
+        self.send_text(routes::IMPORT, "", Some(request)).await
+    }
+
+    /// `GET /schemas`.
+    pub async fn list_schemas(&self) -> Result<ListSchemasResponse, ApiError> {
+        self.send(routes::LIST_SCHEMAS, "", None::<&()>).await
+    }
+
+    /// `GET /schemas/{name}`.
+    pub async fn get_schema(&self, name: &str) -> Result<Value, ApiError> {
+        self.send(routes::GET_SCHEMA, name, None::<&()>).await
+    }
+
+    /// `GET /schemas/{name}/llm-context`.
+    pub async fn llm_context(&self, name: &str) -> Result<Value, ApiError> {
+        self.send(routes::LLM_CONTEXT, name, None::<&()>).await
+    }
+
+    /// `GET /schemas/{name}/denormalization-advice` with the default
+    /// thresholds.
+    pub async fn denormalization_advice(&self, name: &str) -> Result<Value, ApiError> {
+        self.send(routes::DENORMALIZATION_ADVICE, name, None::<&()>)
+            .await
+    }
+
+    /// `POST /schemas/load`.
+    pub async fn load_schema(
+        &self,
+        request: &SchemaLoadRequest,
+    ) -> Result<SchemaLoadResponse, ApiError> {
+        self.send(routes::LOAD_SCHEMA, "", Some(request)).await
+    }
+
+    /// `GET /schemas/{name}/lint`: categorized warnings for a loaded schema.
+    pub async fn lint_schema(&self, name: &str) -> Result<Value, ApiError> {
+        self.send(routes::LINT_SCHEMA, name, None::<&()>).await
+    }
+
+    /// `POST /schemas/lint`: lint schema YAML without loading it.
+    pub async fn lint_schema_yaml(&self, request: &SchemaLintRequest) -> Result<Value, ApiError> {
+        self.send(routes::LINT_SCHEMA_YAML, "", Some(request)).await
+    }
+
+    /// `POST /schemas/introspect`.
+    pub async fn introspect(
+        &self,
+        request: &IntrospectRequest,
+    ) -> Result<IntrospectResponse, ApiError> {
+        self.send(routes::INTROSPECT, "", Some(request)).await
+    }
+
+    /// `POST /schemas/draft`.
+    pub async fn draft_schema(&self, request: &DraftRequest) -> Result<DraftResponse, ApiError> {
+        self.send(routes::DRAFT_SCHEMA, "", Some(request)).await
+    }
+
+    /// `GET /schemas/templates`.
+    pub async fn list_schema_templates(&self) -> Result<Value, ApiError> {
+        self.send(routes::LIST_SCHEMA_TEMPLATES, "", None::<&()>)
+            .await
+    }
+
+    /// `GET /schemas/templates/{name}` for the template's default database.
+    pub async fn schema_template(&self, name: &str) -> Result<Value, ApiError> {
+        self.send(routes::SCHEMA_TEMPLATE, name, None::<&()>).await
+    }
+
+    /// Call any endpoint with a JSON body, for routes without a typed method.
+    pub async fn call(
+        &self,
+        route: Route,
+        name: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, ApiError> {
+        self.send(route, name, body).await
+    }
+
+    /// The URL of `route`, with `name` (percent-encoded) in its `{name}`
+    /// segment.
+    pub fn url(&self, route: Route, name: &str) -> Url {
+        let mut url = self.base_url.clone();
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .expect("http(s) base URLs have a path");
+            segments.pop_if_empty();
+            for segment in route.path.trim_start_matches('/').split('/') {
+                segments.push(if segment == "{name}" { name } else { segment });
+            }
+        }
+        url
+    }
+
+    async fn send<B: Serialize, T: DeserializeOwned>(
+        &self,
+        route: Route,
+        name: &str,
+        body: Option<&B>,
+    ) -> Result<T, ApiError> {
+        let text = self.send_text(route, name, body).await?;
+        serde_json::from_str(&text).map_err(|source| ApiError::Decode {
+            endpoint: format!("{} {}", route.method, route.path),
+            source,
+        })
+    }
+
+    async fn send_text<B: Serialize>(
+        &self,
+        route: Route,
+        name: &str,
+        body: Option<&B>,
+    ) -> Result<String, ApiError> {
+        let method = Method::from_bytes(route.method.as_bytes()).expect("route methods are valid");
+        let mut request = self.http.request(method, self.url(route, name));
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if status.is_success() {
+            Ok(text)
+        } else {
+            Err(ApiError::Status {
+                status: status.as_u16(),
+                message: error_message(&text),
+            })
+        }
+    }
+}
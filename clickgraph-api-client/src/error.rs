@@ -0,0 +1,53 @@
+//! Error type for API calls.
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ApiError {
+    /// The request could not be sent or its response could not be read.
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The server answered with a non-success status.
+    #[error("Server returned {status}: {message}")]
+    Status { status: u16, message: String },
+
+    /// The response body did not match the expected type.
+    #[error("Unexpected response from {endpoint}: {source}")]
+    Decode {
+        endpoint: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+impl ApiError {
+    /// HTTP status of a [`ApiError::Status`] error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ApiError::Status { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// The message of an error response body.
+///
+/// Handlers answer with plain text or with JSON carrying an `error` (or
+/// `message`) field, plus a `suggestion` for known ClickHouse failures;
+/// anything else is returned as-is.
+pub(crate) fn error_message(body: &str) -> String {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(body) else {
+        return body.trim().to_string();
+    };
+    let Some(message) = ["error", "message"]
+        .iter()
+        .find_map(|key| value.get(*key).and_then(|v| v.as_str()))
+    else {
+        return body.trim().to_string();
+    };
+    match value.get("suggestion").and_then(|v| v.as_str()) {
+        Some(suggestion) => format!("{message} (suggestion: {suggestion})"),
+        None => message.to_string(),
+    }
+}
@@ -0,0 +1,35 @@
+//! Typed async client for the ClickGraph HTTP API.
+//!
+//! [`Client`] wraps `reqwest` with one method per endpoint, taking and
+//! returning the structs in [`types`] instead of hand-built JSON.
+//!
+//! ```no_run
+//! use clickgraph_api_client::{Client, QueryRequest};
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new("http://localhost:7475")?;
+//! let response = client
+//!     .query(
+//!         &QueryRequest::new("MATCH (u:User) WHERE u.user_id = $id RETURN u.name AS name")
+//!             .schema("social_benchmark")
+//!             .parameter("id", 1),
+//!     )
+//!     .await?;
+//! for row in &response.results {
+//!     println!("{}", row["name"]);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Non-2xx answers surface as [`ApiError::Status`] carrying the server's
+//! error message.
+
+mod client;
+mod error;
+pub mod routes;
+pub mod types;
+
+pub use client::Client;
+pub use error::ApiError;
+pub use types::*;
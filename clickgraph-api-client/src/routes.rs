@@ -0,0 +1,112 @@
+//! Endpoints of the ClickGraph HTTP API.
+//!
+//! Mirrors the server's `build_router`. Paths with a `{name}` segment are
+//! templates; [`path`] fills them in. The server's contract tests request
+//! every entry of [`ALL`] against the real router, so a route renamed on one
+//! side fails the build of the other.
+
+/// An HTTP method and path template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Route {
+    pub method: &'static str,
+    pub path: &'static str,
+}
+
+const fn get(path: &'static str) -> Route {
+    Route {
+        method: "GET",
+        path,
+    }
+}
+
+const fn post(path: &'static str) -> Route {
+    Route {
+        method: "POST",
+        path,
+    }
+}
+
+const fn delete(path: &'static str) -> Route {
+    Route {
+        method: "DELETE",
+        path,
+    }
+}
+
+pub const HEALTH: Route = get("/health");
+pub const QUERY: Route = post("/query");
+pub const QUERY_SQL: Route = post("/query/sql");
+pub const QUERY_SCRIPT: Route = post("/query/script");
+pub const QUERY_ESTIMATE: Route = post("/query/estimate");
+pub const QUERY_NATURAL: Route = post("/query/natural");
+pub const EXPORT: Route = post("/export");
+pub const EXPORT_GET: Route = get("/export");
+pub const IMPORT: Route = post("/import");
+pub const LIST_STORED_QUERIES: Route = get("/queries");
+pub const REGISTER_STORED_QUERY: Route = post("/queries");
+pub const GET_STORED_QUERY: Route = get("/queries/{name}");
+pub const DELETE_STORED_QUERY: Route = delete("/queries/{name}");
+pub const LIST_SCHEMAS: Route = get("/schemas");
+pub const LOAD_SCHEMA: Route = post("/schemas/load");
+pub const RELOAD_SCHEMAS: Route = post("/schemas/reload");
+pub const GET_SCHEMA: Route = get("/schemas/{name}");
+pub const COMPLETE: Route = get("/schemas/{name}/complete");
+pub const SCHEMA_STATUS: Route = get("/schemas/{name}/status");
+pub const LLM_CONTEXT: Route = get("/schemas/{name}/llm-context");
+pub const DENORMALIZATION_ADVICE: Route = get("/schemas/{name}/denormalization-advice");
+pub const VALIDATE_CONSTRAINTS: Route = get("/schemas/{name}/constraints/validate");
+pub const LINT_SCHEMA: Route = get("/schemas/{name}/lint");
+pub const LINT_SCHEMA_YAML: Route = post("/schemas/lint");
+pub const INTROSPECT: Route = post("/schemas/introspect");
+pub const DISCOVER_PROMPT: Route = post("/schemas/discover-prompt");
+pub const DRAFT_SCHEMA: Route = post("/schemas/draft");
+pub const LIST_SCHEMA_TEMPLATES: Route = get("/schemas/templates");
+pub const SCHEMA_TEMPLATE: Route = get("/schemas/templates/{name}");
+pub const NEO4J_TX_COMMIT: Route = post("/db/{name}/tx/commit");
+pub const METRICS: Route = get("/metrics");
+pub const STATS: Route = get("/stats");
+pub const STATS_QUERIES: Route = get("/stats/queries");
+pub const STATS_RESOURCES: Route = get("/stats/resources");
+
+/// Every route the server exposes.
+pub const ALL: &[Route] = &[
+    HEALTH,
+    QUERY,
+    QUERY_SQL,
+    QUERY_SCRIPT,
+    QUERY_ESTIMATE,
+    QUERY_NATURAL,
+    EXPORT,
+    EXPORT_GET,
+    IMPORT,
+    LIST_STORED_QUERIES,
+    REGISTER_STORED_QUERY,
+    GET_STORED_QUERY,
+    DELETE_STORED_QUERY,
+    LIST_SCHEMAS,
+    LOAD_SCHEMA,
+    RELOAD_SCHEMAS,
+    GET_SCHEMA,
+    COMPLETE,
+    SCHEMA_STATUS,
+    LLM_CONTEXT,
+    DENORMALIZATION_ADVICE,
+    VALIDATE_CONSTRAINTS,
+    LINT_SCHEMA,
+    LINT_SCHEMA_YAML,
+    INTROSPECT,
+    DISCOVER_PROMPT,
+    DRAFT_SCHEMA,
+    LIST_SCHEMA_TEMPLATES,
+    SCHEMA_TEMPLATE,
+    NEO4J_TX_COMMIT,
+    METRICS,
+    STATS,
+    STATS_QUERIES,
+    STATS_RESOURCES,
+];
+
+/// `route`'s path with its `{name}` segment replaced by `name`.
+pub fn path(route: Route, name: &str) -> String {
+    route.path.replace("{name}", name)
+}
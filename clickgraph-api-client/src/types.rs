@@ -0,0 +1,522 @@
+//! Request and response bodies of the ClickGraph HTTP API.
+//!
+//! Field names and optionality follow the server's `server::models` and
+//! handler payloads. Optional request fields are left out of the JSON when
+//! unset, so the server applies its own defaults.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Result format of `POST /query`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputFormat {
+    JSONEachRow,
+    Pretty,
+    PrettyCompact,
+    Csv,
+    CSVWithNames,
+    Graph,
+}
+
+/// Handling of tables staler than `QueryRequest::max_staleness_secs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StaleAction {
+    /// Run the query and flag it in `X-Query-Notification`.
+    Annotate,
+    /// Wait up to the table's typical lag for it to catch up.
+    Wait,
+    /// Fail the query.
+    Error,
+}
+
+/// Body of `POST /query` and `POST /query/script`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueryRequest {
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<OutputFormat>,
+    /// Return the generated SQL without executing it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql_only: Option<bool>,
+    /// Schema to query; the server's default graph when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    /// Values for `$name` parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<HashMap<String, Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+    /// Arguments for parameterized views.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view_parameters: Option<HashMap<String, Value>>,
+    /// ClickHouse role to `SET ROLE` to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// Cap on edge types inferred for untyped patterns (server default 4).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_inferred_types: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_query_cache: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_cache_ttl: Option<u64>,
+    /// Stream `JSONEachRow` rows as NDJSON.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+    /// Byte budget for the response body; rows past it are returned on
+    /// later pages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_response_bytes: Option<usize>,
+    /// `continuation_token` of the previous page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+    /// Maximum lag in seconds of watermarked edge tables the query reads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_staleness_secs: Option<u64>,
+    /// What to do when a table is staler than `max_staleness_secs`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_stale: Option<StaleAction>,
+    /// Statement timeout in milliseconds (capped by the server's).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+    /// YAML `nodes:` / `edges:` merged into the schema for this request only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_overrides: Option<String>,
+    /// ClickHouse settings for this request's queries; the server only
+    /// accepts the ones it allows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<BTreeMap<String, Value>>,
+}
+
+impl QueryRequest {
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn schema(mut self, schema_name: impl Into<String>) -> Self {
+        self.schema_name = Some(schema_name.into());
+        self
+    }
+
+    pub fn parameter(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.parameters
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
+    pub fn setting(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.settings
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+}
+
+/// JSON result of `POST /query` (the default `JSONEachRow` format).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryResponse {
+    /// One object per row, keyed by RETURN alias.
+    pub results: Vec<Value>,
+    /// Set when `max_response_bytes` cut the rows short; send it back to
+    /// fetch the next page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+}
+
+/// Body of `POST /query/natural`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NaturalQueryRequest {
+    pub question: String,
+    /// Schema to ground the question in; the server's default graph when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// Return the validated Cypher without running it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cypher_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+impl NaturalQueryRequest {
+    pub fn new(question: impl Into<String>) -> Self {
+        Self {
+            question: question.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn schema(mut self, schema_name: impl Into<String>) -> Self {
+        self.schema_name = Some(schema_name.into());
+        self
+    }
+}
+
+/// Result of `POST /query/natural`: the generated Cypher and its rows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NaturalQueryResponse {
+    pub question: String,
+    pub schema: String,
+    pub cypher: String,
+    /// `high`, `medium`, `low` or `unstated`, as the model judged it.
+    pub confidence: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Unset with `cypher_only`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<Value>>,
+}
+
+/// One statement of a multi-statement `POST /query`, or one progress line
+/// of `POST /query/script`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatementResult {
+    pub statement: String,
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of a multi-statement `POST /query`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatementsResponse {
+    pub statements: Vec<StatementResult>,
+}
+
+/// Document format of `/export`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    GraphMl,
+    Gexf,
+}
+
+/// Body of `POST /export`: a query, or labels and types to export whole.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExportRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rel_types: Vec<String>,
+    /// Row limit per label or type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub format: ExportFormat,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<HashMap<String, Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+/// Body of `POST /import`: files to load into the tables of a graph.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    pub mappings: Vec<ImportMapping>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// Return the statements without running them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql_only: Option<bool>,
+}
+
+/// One file (or glob) loaded into the table of a label or type.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportMapping {
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rel_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    /// ClickHouse input format, from the file extension when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Source column per property, when it is not named like it.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub columns: HashMap<String, String>,
+}
+
+/// Body of `POST /query/sql`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SqlGenerationRequest {
+    pub query: String,
+    /// Target dialect, `"clickhouse"` when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_database: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<HashMap<String, Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view_parameters: Option<HashMap<String, Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// Include the logical plan in the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_plan: Option<bool>,
+}
+
+impl SqlGenerationRequest {
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Result of `POST /query/sql`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SqlGenerationResponse {
+    pub cypher_query: String,
+    pub target_database: String,
+    /// Statements to run in order (e.g. `SET ROLE` before the query).
+    pub sql: Vec<String>,
+    #[serde(default)]
+    pub parameters: Option<HashMap<String, Value>>,
+    #[serde(default)]
+    pub view_parameters: Option<HashMap<String, Value>>,
+    #[serde(default)]
+    pub role: Option<String>,
+    pub metadata: SqlGenerationMetadata,
+    #[serde(default)]
+    pub logical_plan: Option<String>,
+    #[serde(default)]
+    pub dialect_notes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SqlGenerationMetadata {
+    pub query_type: String,
+    pub cache_status: String,
+    pub parse_time_ms: f64,
+    pub planning_time_ms: f64,
+    pub sql_generation_time_ms: f64,
+    pub total_time_ms: f64,
+}
+
+/// Result of `GET /health`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthResponse {
+    pub service: String,
+    pub status: String,
+    pub version: String,
+}
+
+/// Result of `GET /schemas`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListSchemasResponse {
+    pub schemas: Vec<SchemaInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaInfo {
+    pub name: String,
+    pub node_count: usize,
+    pub relationship_count: usize,
+}
+
+/// Body of `POST /schemas/load`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaLoadRequest {
+    pub schema_name: String,
+    /// Schema YAML.
+    pub config_content: String,
+    /// Check the mapped tables and columns against ClickHouse before loading.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validate_schema: Option<bool>,
+}
+
+/// Body of `POST /schemas/lint`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaLintRequest {
+    /// Schema YAML.
+    pub config_content: String,
+    /// Check the tables in ClickHouse as well; `true` when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live: Option<bool>,
+    /// `info`, `warning` or `error`: answer `422` on a finding this severe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_on: Option<String>,
+}
+
+/// Result of `POST /schemas/load`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaLoadResponse {
+    pub message: String,
+    pub schema_name: String,
+}
+
+/// Body of `POST /schemas/introspect`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IntrospectRequest {
+    pub database: String,
+}
+
+/// Result of `POST /schemas/introspect`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntrospectResponse {
+    pub database: String,
+    pub tables: Vec<TableMetadata>,
+    pub next_step: String,
+    #[serde(default)]
+    pub suggestions: Vec<Suggestion>,
+    /// Foreign keys confirmed by sampling column values.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inferred_edges: Vec<InferredEdge>,
+}
+
+/// A foreign key inferred from the data: sampled values of
+/// `from_table.from_column` found in the key column `to_table.to_column`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InferredEdge {
+    pub from_table: String,
+    pub from_column: String,
+    pub to_table: String,
+    pub to_column: String,
+    /// Share of the sampled values present in the key column, 0–100.
+    pub match_percent: f64,
+    pub sampled_values: u64,
+    pub distinct_values: u64,
+    /// `many_to_one` or `one_to_one`.
+    pub cardinality: String,
+    /// `high` or `medium`.
+    pub confidence: String,
+}
+
+/// What kind of ClickHouse object an introspected source is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceKind {
+    #[default]
+    Table,
+    View,
+    MaterializedView,
+    Dictionary,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableMetadata {
+    pub name: String,
+    #[serde(default)]
+    pub kind: SourceKind,
+    /// Whether the source can back a node or edge definition.
+    #[serde(default = "default_graph_source")]
+    pub graph_source: bool,
+    /// Why the source is unusable, or what to know before using it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Parameters of a parameterized view.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub view_parameters: Vec<String>,
+    pub columns: Vec<ColumnMetadata>,
+    pub row_count: Option<u64>,
+    pub sample: Vec<Value>,
+}
+
+fn default_graph_source() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnMetadata {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub data_type: String,
+    pub is_primary_key: bool,
+    pub is_in_order_by: bool,
+}
+
+/// A node / edge / FK-edge candidate found by introspection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub table: String,
+    #[serde(rename = "type")]
+    pub suggestion_type: String,
+    pub reason: String,
+}
+
+/// Body of `POST /schemas/draft`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DraftRequest {
+    pub database: String,
+    pub schema_name: String,
+    pub nodes: Vec<NodeHint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<EdgeHint>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fk_edges: Option<Vec<FkEdgeHint>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<DraftOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeHint {
+    pub table: String,
+    pub label: String,
+    pub node_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EdgeHint {
+    pub table: String,
+    #[serde(rename = "type")]
+    pub edge_type: String,
+    pub from_node: String,
+    pub to_node: String,
+    pub from_id: String,
+    pub to_id: String,
+}
+
+/// An edge stored as a foreign key on a node table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FkEdgeHint {
+    pub table: String,
+    #[serde(rename = "type")]
+    pub edge_type: String,
+    pub from_node: String,
+    pub to_node: String,
+    pub from_id: String,
+    pub to_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DraftOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_discover_columns: Option<bool>,
+}
+
+/// Result of `POST /schemas/draft`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DraftResponse {
+    /// Schema YAML to review, then pass to [`SchemaLoadRequest`].
+    pub yaml: String,
+    pub message: String,
+}
@@ -0,0 +1,148 @@
+use clickgraph_api_client::{routes, ApiError, Client, IntrospectRequest, QueryRequest};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn query_sends_typed_request_and_decodes_rows() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/query"))
+        .and(body_json(json!({
+            "query": "MATCH (u:User) WHERE u.user_id = $id RETURN u.name AS name",
+            "schema_name": "social",
+            "parameters": {"id": 1}
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({"results": [{"name": "Alice"}]})),
+        )
+        .mount(&server)
+        .await;
+
+    let client = Client::new(&server.uri()).unwrap();
+    let response = client
+        .query(
+            &QueryRequest::new("MATCH (u:User) WHERE u.user_id = $id RETURN u.name AS name")
+                .schema("social")
+                .parameter("id", 1),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.results, vec![json!({"name": "Alice"})]);
+}
+
+#[tokio::test]
+async fn error_status_carries_server_message() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/query"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Parse error: unexpected ')'"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/schemas/missing"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(json!({"error": "Schema 'missing' not found"})),
+        )
+        .mount(&server)
+        .await;
+
+    let client = Client::new(&server.uri()).unwrap();
+    let err = client
+        .query(&QueryRequest::new("MATCH ("))
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(400));
+    assert!(err.to_string().contains("unexpected ')'"), "{err}");
+
+    match client.get_schema("missing").await.unwrap_err() {
+        ApiError::Status { status, message } => {
+            assert_eq!(status, 404);
+            assert_eq!(message, "Schema 'missing' not found");
+        }
+        other => panic!("expected status error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn error_status_appends_server_suggestion() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/query"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+            "error": "Executor error: Code: 241. DB::Exception: Memory limit exceeded. (MEMORY_LIMIT_EXCEEDED)",
+            "error_code": "MEMORY_LIMIT_EXCEEDED",
+            "clickhouse_code": 241,
+            "suggestion": "Narrow the pattern with a WHERE filter or LIMIT"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = Client::new(&server.uri()).unwrap();
+    let err = client
+        .query(&QueryRequest::new("MATCH (a)-[*]->(b) RETURN b"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(500));
+    assert!(
+        err.to_string().ends_with(
+            "(MEMORY_LIMIT_EXCEEDED) (suggestion: Narrow the pattern with a WHERE filter or LIMIT)"
+        ),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn introspect_decodes_tables_and_suggestions() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/schemas/introspect"))
+        .and(body_json(json!({"database": "social"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "database": "social",
+            "tables": [{
+                "name": "users",
+                "columns": [
+                    {"name": "user_id", "type": "UInt64", "is_primary_key": true, "is_in_order_by": true},
+                    {"name": "name", "type": "String", "is_primary_key": false, "is_in_order_by": false}
+                ],
+                "row_count": 3,
+                "sample": [{"user_id": 1, "name": "Alice"}]
+            }],
+            "next_step": "POST /schemas/draft",
+            "suggestions": [{"table": "users", "type": "node", "reason": "has a primary key"}]
+        })))
+        .mount(&server)
+        .await;
+
+    let client = Client::new(&server.uri()).unwrap();
+    let response = client
+        .introspect(&IntrospectRequest {
+            database: "social".into(),
+        })
+        .await
+        .unwrap();
+    let users = &response.tables[0];
+    assert_eq!(users.columns[0].data_type, "UInt64");
+    assert!(users.columns[0].is_primary_key);
+    assert_eq!(users.row_count, Some(3));
+    assert_eq!(response.suggestions[0].suggestion_type, "node");
+}
+
+#[test]
+fn name_segments_are_percent_encoded() {
+    let client = Client::new("http://localhost:7475/api/").unwrap();
+    assert_eq!(
+        client.url(routes::GET_SCHEMA, "my schema").as_str(),
+        "http://localhost:7475/api/schemas/my%20schema"
+    );
+    assert_eq!(
+        client.url(routes::NEO4J_TX_COMMIT, "social").as_str(),
+        "http://localhost:7475/api/db/social/tx/commit"
+    );
+    assert_eq!(
+        routes::path(routes::COMPLETE, "social"),
+        "/schemas/social/complete"
+    );
+}
@@ -0,0 +1,248 @@
+//! `:explain` — draw a query's plan as a tree
+//!
+//! Combines the server's `EXPLAIN` response, whose `plan` lists each pattern
+//! with its strategy and CTE, with the per-stage row estimates of
+//! `/query/estimate`:
+//!
+//! ```text
+//! MATCH (a:User)-[r:FOLLOWS*1..3]->(b:User) RETURN b.name
+//! └── (a:User)-[r:FOLLOWS*1..3]->(b:User)
+//!     ├── strategy: variable_length
+//!     ├── cte: vlp_a_b (recursive)
+//!     └── estimated rows: ~12.4K
+//! ```
+//!
+//! Estimates need the statistics queries to succeed; without them the tree is
+//! drawn without row counts.
+
+use reqwest::Client;
+use serde_json::{json, Value};
+
+/// The `EXPLAIN` response for `query`, and its estimate when one is available
+/// (the error otherwise).
+pub async fn fetch_plan(
+    client: &Client,
+    url: &str,
+    query: &str,
+) -> Result<(Value, Result<Value, String>), String> {
+    let query = strip_explain(query);
+    let explain = post(
+        client,
+        &format!("{}/query", url),
+        json!({ "query": format!("EXPLAIN {}", query) }),
+    )
+    .await?;
+    let estimate = post(
+        client,
+        &format!("{}/query/estimate", url),
+        json!({ "query": query, "schema_name": explain.get("schema_name") }),
+    )
+    .await;
+    Ok((explain, estimate))
+}
+
+async fn post(client: &Client, endpoint: &str, payload: Value) -> Result<Value, String> {
+    let response = client
+        .post(endpoint)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        response.json().await.map_err(|e| e.to_string())
+    } else {
+        let text = response.text().await.unwrap_or_default();
+        Err(text)
+    }
+}
+
+/// `:explain EXPLAIN MATCH ...` means the same as `:explain MATCH ...`.
+fn strip_explain(query: &str) -> &str {
+    let query = query.trim();
+    match query.get(..8) {
+        Some(head) if head.eq_ignore_ascii_case("EXPLAIN ") => query[8..].trim_start(),
+        _ => query,
+    }
+}
+
+/// Render the plan tree. `estimate` is the `/query/estimate` response, if any.
+pub fn render_plan(explain: &Value, estimate: Option<&Value>) -> String {
+    let str_of = |v: &Value, key: &str| v.get(key).and_then(|s| s.as_str()).map(str::to_string);
+    let empty = Vec::new();
+    let patterns = explain
+        .pointer("/plan/patterns")
+        .and_then(|p| p.as_array())
+        .unwrap_or(&empty);
+    let ctes = explain
+        .pointer("/plan/ctes")
+        .and_then(|c| c.as_array())
+        .unwrap_or(&empty);
+    let stages = estimate
+        .and_then(|e| e.get("stages"))
+        .and_then(|s| s.as_array())
+        .unwrap_or(&empty);
+
+    let mut out = String::new();
+    out.push_str(&str_of(explain, "cypher_query").unwrap_or_default());
+    out.push('\n');
+    if patterns.is_empty() {
+        out.push_str("└── (no patterns)\n");
+    }
+
+    // Stages are matched by alias; anonymous aliases differ between the two
+    // responses, so those take the next unused stage of the same kind.
+    let mut used = vec![false; stages.len()];
+    for (i, pattern) in patterns.iter().enumerate() {
+        let last = i + 1 == patterns.len();
+        let (branch, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        out.push_str(branch);
+        out.push_str(&str_of(pattern, "pattern").unwrap_or_default());
+        out.push('\n');
+
+        let strategy = str_of(pattern, "strategy").unwrap_or_default();
+        let mut details = vec![format!("strategy: {}", strategy)];
+        if let Some(cte) = str_of(pattern, "cte") {
+            let recursive = ctes
+                .iter()
+                .any(|c| str_of(c, "name").as_deref() == Some(&cte) && c["recursive"] == true);
+            details.push(if recursive {
+                format!("cte: {} (recursive)", cte)
+            } else {
+                format!("cte: {}", cte)
+            });
+        }
+        let kind = if strategy == "scan" {
+            "anchor"
+        } else {
+            "expand"
+        };
+        let alias = str_of(pattern, "alias");
+        let stage = (0..stages.len())
+            .find(|&s| !used[s] && str_of(&stages[s], "alias") == alias)
+            .or_else(|| {
+                (0..stages.len()).find(|&s| {
+                    !used[s] && stages[s].get("stage").and_then(|k| k.as_str()) == Some(kind)
+                })
+            });
+        if let Some(s) = stage {
+            used[s] = true;
+            if let Some(rows) = stages[s].get("estimated_rows").and_then(|r| r.as_f64()) {
+                details.push(format!("estimated rows: {}", format_rows(rows)));
+            }
+        }
+
+        for (j, detail) in details.iter().enumerate() {
+            out.push_str(indent);
+            out.push_str(if j + 1 == details.len() {
+                "└── "
+            } else {
+                "├── "
+            });
+            out.push_str(detail);
+            out.push('\n');
+        }
+    }
+
+    // CTEs no pattern reads from, such as those of WITH clauses
+    let other: Vec<String> = ctes
+        .iter()
+        .filter_map(|c| str_of(c, "name"))
+        .filter(|name| {
+            !patterns
+                .iter()
+                .any(|p| str_of(p, "cte").as_deref() == Some(name))
+        })
+        .collect();
+    if !other.is_empty() {
+        out.push_str(&format!("\nOther CTEs: {}\n", other.join(", ")));
+    }
+    if let Some(rows) = estimate
+        .and_then(|e| e.get("estimated_rows"))
+        .and_then(|r| r.as_f64())
+    {
+        out.push_str(&format!("\nEstimated result rows: {}\n", format_rows(rows)));
+    }
+    out
+}
+
+/// `850`, `~12.4K`, `~3.1M`: estimates are orders of magnitude.
+fn format_rows(rows: f64) -> String {
+    if rows < 1000.0 {
+        format!("{:.0}", rows)
+    } else if rows < 1e6 {
+        format!("~{:.1}K", rows / 1e3)
+    } else if rows < 1e9 {
+        format!("~{:.1}M", rows / 1e6)
+    } else {
+        format!("~{:.1}B", rows / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explain_response() -> Value {
+        json!({
+            "cypher_query": "MATCH (a:User)-[:FOLLOWS]->(b:User)-[r:FOLLOWS*1..3]->(c:User) RETURN c.name",
+            "plan": {
+                "patterns": [
+                    {"pattern": "(a:User)-[t1:FOLLOWS]->(b:User)", "alias": "t1", "strategy": "join"},
+                    {"pattern": "(b:User)-[r:FOLLOWS*1..3]->(c:User)", "alias": "r",
+                     "strategy": "variable_length", "cte": "vlp_b_c"}
+                ],
+                "ctes": [{"name": "vlp_b_c", "recursive": true}]
+            }
+        })
+    }
+
+    #[test]
+    fn test_render_plan_with_estimates() {
+        // The anonymous hop is `_anon1` in the estimate and `t1` in the plan.
+        let estimate = json!({
+            "estimated_rows": 12400,
+            "stages": [
+                {"stage": "anchor", "alias": "a", "estimated_rows": 1000.0},
+                {"stage": "expand", "alias": "_anon1", "estimated_rows": 4000.0},
+                {"stage": "expand", "alias": "r", "estimated_rows": 12400.0}
+            ]
+        });
+        let tree = render_plan(&explain_response(), Some(&estimate));
+        assert_eq!(
+            tree,
+            "MATCH (a:User)-[:FOLLOWS]->(b:User)-[r:FOLLOWS*1..3]->(c:User) RETURN c.name\n\
+             ├── (a:User)-[t1:FOLLOWS]->(b:User)\n\
+             │   ├── strategy: join\n\
+             │   └── estimated rows: ~4.0K\n\
+             └── (b:User)-[r:FOLLOWS*1..3]->(c:User)\n    \
+             ├── strategy: variable_length\n    \
+             ├── cte: vlp_b_c (recursive)\n    \
+             └── estimated rows: ~12.4K\n\
+             \n\
+             Estimated result rows: ~12.4K\n"
+        );
+    }
+
+    #[test]
+    fn test_render_plan_without_estimates() {
+        let tree = render_plan(&explain_response(), None);
+        assert!(tree.contains("│   └── strategy: join\n"), "{}", tree);
+        assert!(!tree.contains("estimated"), "{}", tree);
+    }
+
+    #[test]
+    fn test_strip_explain_and_format_rows() {
+        assert_eq!(
+            strip_explain(" explain  MATCH (n) RETURN n"),
+            "MATCH (n) RETURN n"
+        );
+        assert_eq!(strip_explain("MATCH (n) RETURN n"), "MATCH (n) RETURN n");
+        assert_eq!(format_rows(850.4), "850");
+        assert_eq!(format_rows(3_100_000.0), "~3.1M");
+    }
+}
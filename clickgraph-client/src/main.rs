@@ -1,4 +1,6 @@
+mod explain;
 mod llm;
+mod params;
 
 use clap::Parser;
 use reqwest::Client;
@@ -16,24 +18,33 @@ struct Args {
 fn print_usage() {
     println!("ClickGraph Client Commands:");
     println!("  <query>           - Execute Cypher query (default)");
+    println!("  :ask <question>  - Answer a question with server-side LLM Cypher");
+    println!("  :explain <query> - Show the query's plan with row estimates");
     println!("  :discover <db>   - LLM-powered schema discovery (needs ANTHROPIC_API_KEY)");
     println!("  :introspect <db> - Show tables/columns in database");
     println!("  :design <db>     - Interactive schema design wizard");
     println!("  :schemas         - List loaded schemas");
+    println!("  :context <name>  - Schema description for LLM prompts");
     println!("  :load <file>     - Load schema from YAML file");
+    println!("  :param n => v    - Set session parameter $n (:params lists, :params clear)");
     println!("  :help            - Show this help");
     println!();
     println!("Examples:");
+    println!("  :ask who follows the most users?");
+    println!("  :explain MATCH (a:User)-[:FOLLOWS*1..3]->(b) RETURN b.name");
+    println!("  :param country => 'US'");
     println!("  :discover mydb");
     println!("  :introspect lineage");
     println!("  :schemas");
+    println!("  :context social");
     println!("  MATCH (n:User) RETURN n.name LIMIT 5");
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
-    let client = Client::new();
+    let mut client = Client::new();
+    let mut session: Option<String> = None;
 
     println!("\nConnected to ClickGraph server at {}.", args.url);
     println!("Type :help for commands.\n");
@@ -125,6 +136,63 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 eprintln!("Error: {}", e);
                             }
                         },
+                        ":ask" | ":a" => {
+                            if let Some(question) = arg {
+                                match ask(&client, &args.url, &question).await {
+                                    Ok(response) => print_natural_result(&response),
+                                    Err(e) => eprintln!("Error: {}", e),
+                                }
+                            } else {
+                                println!("Usage: :ask <question>");
+                            }
+                        }
+                        ":explain" | ":e" => {
+                            if let Some(query) = arg {
+                                match explain::fetch_plan(&client, &args.url, &query).await {
+                                    Ok((plan, estimate)) => {
+                                        println!(
+                                            "\n{}",
+                                            explain::render_plan(&plan, estimate.as_ref().ok())
+                                        );
+                                        if let Err(e) = estimate {
+                                            println!("(no row estimates: {})\n", e);
+                                        }
+                                    }
+                                    Err(e) => eprintln!("Error: {}", e),
+                                }
+                            } else {
+                                println!("Usage: :explain <query>");
+                            }
+                        }
+                        ":param" | ":params" => {
+                            let previous = session.clone();
+                            match params::run_command(&client, &args.url, &mut session, input).await
+                            {
+                                Ok(parameters) => println!("{}", params::render(&parameters)),
+                                Err(e) => eprintln!("Error: {}", e),
+                            }
+                            if session != previous {
+                                if let Some(id) = &session {
+                                    match params::session_client(id) {
+                                        Ok(session_client) => client = session_client,
+                                        Err(e) => eprintln!("Error: {}", e),
+                                    }
+                                }
+                            }
+                        }
+                        ":context" | ":c" => {
+                            if let Some(name) = arg {
+                                match get_llm_context(&client, &args.url, &name).await {
+                                    Ok(context) => println!(
+                                        "{}",
+                                        serde_json::to_string_pretty(&context).unwrap_or_default()
+                                    ),
+                                    Err(e) => eprintln!("Error: {}", e),
+                                }
+                            } else {
+                                println!("Usage: :context <schema>");
+                            }
+                        }
                         ":load" => {
                             if let Some(file_path) = arg {
                                 match load_schema_from_file(&client, &args.url, &file_path).await {
@@ -568,6 +636,32 @@ async fn run_design_wizard(
         })
         .unwrap_or_default();
 
+    let inferred: Vec<InferredEdgeInfo> = response
+        .get("inferred_edges")
+        .and_then(|e| e.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|e| {
+                    let text = |key: &str| Some(e.get(key)?.as_str()?.to_string());
+                    Some(InferredEdgeInfo {
+                        from_table: text("from_table")?,
+                        from_column: text("from_column")?,
+                        to_table: text("to_table")?,
+                        match_percent: e.get("match_percent")?.as_f64()?,
+                        confidence: text("confidence")?,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let table_pk = |table: &str| {
+        tables
+            .iter()
+            .find(|t| t.name == table)
+            .and_then(|t| t.columns.iter().find(|c| c.is_pk))
+            .map(|c| c.name.clone())
+    };
+
     // Step 2: Define nodes
     println!("Step 2: Define nodes");
     println!("  Enter table names to create nodes (comma-separated), or press Enter to skip:");
@@ -624,6 +718,32 @@ async fn run_design_wizard(
     for s in &edge_suggestions {
         println!("    {} ({})", s.table, s.reason);
     }
+    // A key-less table with two data-confirmed references is an edge table
+    let mut edge_tables: Vec<&str> = Vec::new();
+    for e in &inferred {
+        if table_pk(&e.from_table).is_none() && !edge_tables.contains(&e.from_table.as_str()) {
+            edge_tables.push(&e.from_table);
+        }
+    }
+    for table in edge_tables {
+        let refs: Vec<_> = inferred.iter().filter(|e| e.from_table == table).collect();
+        if let [from, to, ..] = refs.as_slice() {
+            println!(
+                "    {}:{}:{}:{}:{}:{}  (inferred from data: {})",
+                table,
+                table.to_uppercase(),
+                to_label(&from.to_table),
+                to_label(&to.to_table),
+                from.from_column,
+                to.from_column,
+                [from, to]
+                    .iter()
+                    .map(|e| e.summary())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
     println!("\n");
 
     let readline = rl.readline("edges> ");
@@ -663,6 +783,25 @@ async fn run_design_wizard(
     for s in &fk_suggestions {
         println!("    {} ({})", s.table, s.reason);
     }
+    // References confirmed by sampling, ready to paste
+    for e in &inferred {
+        if let Some(pk) = table_pk(&e.from_table) {
+            let base = e
+                .from_column
+                .trim_end_matches("_id")
+                .trim_end_matches("_key");
+            println!(
+                "    {}:HAS_{}:{}:{}:{}:{}  (inferred from data: {})",
+                e.from_table,
+                base.to_uppercase(),
+                to_label(&e.from_table),
+                to_label(&e.to_table),
+                pk,
+                e.from_column,
+                e.summary()
+            );
+        }
+    }
     println!("\n");
 
     let readline = rl.readline("fk_edges> ");
@@ -820,6 +959,63 @@ async fn list_schemas(client: &Client, url: &str) -> Result<Value, String> {
     }
 }
 
+async fn ask(client: &Client, url: &str, question: &str) -> Result<Value, String> {
+    let endpoint = format!("{}/query/natural", url);
+
+    let response = client
+        .post(&endpoint)
+        .json(&json!({ "question": question }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        response.json().await.map_err(|e| e.to_string())
+    } else {
+        let text = response.text().await.unwrap_or_default();
+        Err(text)
+    }
+}
+
+fn print_natural_result(response: &Value) {
+    let cypher = response
+        .get("cypher")
+        .and_then(|c| c.as_str())
+        .unwrap_or("");
+    let confidence = response
+        .get("confidence")
+        .and_then(|c| c.as_str())
+        .unwrap_or("unstated");
+    println!(
+        "
+{}
+",
+        cypher
+    );
+    match response.get("note").and_then(|n| n.as_str()) {
+        Some(note) => println!("Confidence: {} ({})", confidence, note),
+        None => println!("Confidence: {}", confidence),
+    }
+    print_query_result(response.get("results").unwrap_or(&Value::Null));
+}
+
+async fn get_llm_context(client: &Client, url: &str, schema: &str) -> Result<Value, String> {
+    let endpoint = format!("{}/schemas/{}/llm-context", url, schema);
+
+    let response = client
+        .get(&endpoint)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        response.json().await.map_err(|e| e.to_string())
+    } else {
+        let text = response.text().await.unwrap_or_default();
+        Err(text)
+    }
+}
+
 async fn load_schema_from_file(
     client: &Client,
     url: &str,
@@ -874,6 +1070,24 @@ struct SuggestionInfo {
     reason: String,
 }
 
+#[derive(Debug, Clone)]
+struct InferredEdgeInfo {
+    from_table: String,
+    from_column: String,
+    to_table: String,
+    match_percent: f64,
+    confidence: String,
+}
+
+impl InferredEdgeInfo {
+    fn summary(&self) -> String {
+        format!(
+            "{} -> {} {:.1}% match, {}",
+            self.from_column, self.to_table, self.match_percent, self.confidence
+        )
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 struct NodeHint {
     table: String,
@@ -0,0 +1,105 @@
+//! `:param` / `:params` — session parameters kept by the server.
+//!
+//! The command is sent to `/query` as is. The server answers with the
+//! session's id and parameters; every later request carries that id in the
+//! `X-Session-Id` header, so queries can use `$name` without resending the
+//! values.
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Client,
+};
+use serde_json::{json, Value};
+
+const SESSION_HEADER: &str = "X-Session-Id";
+
+/// Run a parameter command in `session`, adopting the session id the
+/// server answers with. Returns the session's parameters.
+pub async fn run_command(
+    client: &Client,
+    url: &str,
+    session: &mut Option<String>,
+    command: &str,
+) -> Result<Value, String> {
+    let mut request = client
+        .post(format!("{}/query", url))
+        .json(&json!({ "query": command }));
+    if let Some(id) = session.as_deref() {
+        request = request.header(SESSION_HEADER, id);
+    }
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(response.text().await.unwrap_or_default());
+    }
+    let body: Value = response.json().await.map_err(|e| e.to_string())?;
+    if let Some(id) = body.get("session_id").and_then(Value::as_str) {
+        *session = Some(id.to_string());
+    }
+    Ok(body.get("parameters").cloned().unwrap_or(json!({})))
+}
+
+/// HTTP client sending `session`'s id with every request.
+pub fn session_client(session: &str) -> Result<Client, String> {
+    let mut headers = HeaderMap::new();
+    let value = HeaderValue::from_str(session).map_err(|e| e.to_string())?;
+    headers.insert(SESSION_HEADER, value);
+    Client::builder()
+        .default_headers(headers)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// One `:param name => value` line per parameter.
+pub fn render(parameters: &Value) -> String {
+    match parameters.as_object() {
+        Some(map) if !map.is_empty() => map
+            .iter()
+            .map(|(name, value)| format!(":param {} => {}", name, cypher_literal(value)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => "(no parameters)".to_string(),
+    }
+}
+
+fn cypher_literal(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'")),
+        Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(cypher_literal)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Object(map) => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(k, v)| format!("{}: {}", k, cypher_literal(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_parameters_as_param_commands() {
+        let parameters = json!({
+            "country": "O'Hare",
+            "k": 10,
+            "filter": {"tags": ["a", "b"], "min": -1.5, "none": null}
+        });
+        assert_eq!(
+            render(&parameters),
+            ":param country => 'O\\'Hare'\n\
+             :param filter => {min: -1.5, none: null, tags: ['a', 'b']}\n\
+             :param k => 10"
+        );
+        assert_eq!(render(&json!({})), "(no parameters)");
+    }
+}
@@ -24,6 +24,25 @@ const DEFAULT_MAX_CTE_DEPTH: u32 = 100;
 /// One pending edge insertion: `(from_raw_id, to_raw_id, properties)`.
 type PendingEdge = (String, String, HashMap<String, Value>);
 
+/// Visibility guarantee for reads that follow a Cypher write on the same
+/// [`Connection`].
+///
+/// With server-side async inserts (`async_insert = 1`) an INSERT can return
+/// before its rows are readable, so a `MATCH` issued right after `CREATE` may
+/// miss them. Set via [`Connection::set_write_consistency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WriteConsistency {
+    /// Server defaults; no query-time settings are added to writes.
+    #[default]
+    Eventual,
+    /// INSERTs wait for the async-insert buffer to flush
+    /// (`SETTINGS wait_for_async_insert = 1`).
+    ReadYourWrites,
+    /// `ReadYourWrites`, plus `SYSTEM FLUSH ASYNC INSERT QUEUE` on the tables
+    /// written since the last read, issued before that read.
+    ReadYourWritesWithFlush,
+}
+
 /// A connection to an embedded ClickGraph database.
 ///
 /// # Example
@@ -46,6 +65,9 @@ pub struct Connection<'db> {
     db: &'db Database,
     /// Query timeout in milliseconds. 0 = no timeout (default).
     query_timeout_ms: u64,
+    write_consistency: WriteConsistency,
+    /// Tables written under `ReadYourWritesWithFlush` and not yet flushed.
+    pending_flush: std::sync::Mutex<Vec<(String, String)>>,
 }
 
 impl<'db> Connection<'db> {
@@ -57,6 +79,8 @@ impl<'db> Connection<'db> {
             schema: Arc::clone(&db.schema),
             db,
             query_timeout_ms: 0,
+            write_consistency: WriteConsistency::default(),
+            pending_flush: std::sync::Mutex::new(Vec::new()),
         })
     }
 
@@ -73,6 +97,17 @@ impl<'db> Connection<'db> {
         self.query_timeout_ms
     }
 
+    /// Set the read-your-writes guarantee for Cypher writes issued through
+    /// `query()`. Defaults to [`WriteConsistency::Eventual`].
+    pub fn set_write_consistency(&mut self, consistency: WriteConsistency) {
+        self.write_consistency = consistency;
+    }
+
+    /// Get the current write consistency.
+    pub fn get_write_consistency(&self) -> WriteConsistency {
+        self.write_consistency
+    }
+
     /// Execute a Cypher query and return an iterator over the result rows.
     ///
     /// This is synchronous — it blocks until the query completes.
@@ -94,6 +129,48 @@ impl<'db> Connection<'db> {
             .block_on(self.with_timeout(self.query_async(cypher)))
     }
 
+    /// Execute a Cypher write statement with values for its `$parameters`.
+    ///
+    /// CREATE writes the supplied values in place of the placeholders, and
+    /// `UNWIND $rows AS row CREATE ...` (or `FOREACH (row IN $rows | CREATE
+    /// ...)`) writes every element of `rows` in one multi-row INSERT per
+    /// table. Read statements are rejected; use [`Connection::query`].
+    ///
+    /// ```no_run
+    /// # use clickgraph_embedded::{Database, Connection, SystemConfig};
+    /// # let db = Database::new("schema.yaml", SystemConfig::default()).unwrap();
+    /// # let conn = Connection::new(&db).unwrap();
+    /// let rows = serde_json::json!([{"id": 1, "name": "Alice"}, {"id": 2, "name": "Bob"}]);
+    /// conn.query_with_params(
+    ///     "UNWIND $rows AS row CREATE (:User {user_id: row.id, name: row.name})",
+    ///     [("rows".to_string(), rows)].into_iter().collect(),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn query_with_params(
+        &self,
+        cypher: &str,
+        params: HashMap<String, serde_json::Value>,
+    ) -> Result<QueryResult, EmbeddedError> {
+        let cleaned = clickgraph::open_cypher_parser::strip_comments(cypher);
+        let (_, stmt) = clickgraph::open_cypher_parser::parse_cypher_statement(&cleaned)
+            .map_err(|e| EmbeddedError::Query(format!("Parse error: {:?}", e)))?;
+        if !matches!(
+            &stmt,
+            clickgraph::open_cypher_parser::ast::CypherStatement::Query { query, .. }
+                if is_write_query(query)
+        ) {
+            return Err(EmbeddedError::Query(
+                "query_with_params supports only write statements (CREATE / MERGE / SET / \
+                 DELETE / REMOVE / FOREACH)"
+                    .to_string(),
+            ));
+        }
+        self.db
+            .runtime
+            .block_on(self.with_timeout(self.handle_write_async(cypher, &params)))
+    }
+
     /// Execute a Cypher query and return the generated SQL without executing it.
     ///
     /// Useful for debugging and understanding what SQL ClickGraph generates.
@@ -184,9 +261,10 @@ impl<'db> Connection<'db> {
     /// Uses `cypher_to_sql_with_metadata()` to get plan metadata, then
     /// transforms the result rows into `GraphNode`s and `GraphEdge`s.
     pub fn query_graph(&self, cypher: &str) -> Result<GraphResult, EmbeddedError> {
-        self.db
-            .runtime
-            .block_on(self.with_timeout(self.query_graph_async(cypher, &self.executor)))
+        self.db.runtime.block_on(self.with_timeout(async {
+            self.flush_pending_writes().await?;
+            self.query_graph_async(cypher, &self.executor).await
+        }))
     }
 
     /// Execute a Cypher query on the remote cluster and return a structured graph result.
@@ -1253,42 +1331,55 @@ impl<'db> Connection<'db> {
             // emits lightweight INSERT / UPDATE / DELETE per Phase 2's
             // WriteRenderPlan. Read queries fall through to the regular
             // SELECT path below.
-            //
-            // Any query carrying a write clause — CREATE / SET / DELETE /
-            // REMOVE — must enter the write pipeline so we either execute it
-            // or reject it with a clear error. Falling through to the read
-            // path produces confusing render-time errors. Note that
-            // `get_query_type` only inspects SET / DELETE / REMOVE, so we
-            // also need an explicit `create_clause.is_some()` check to cover
-            // `CREATE`, `MATCH ... CREATE`, and `CREATE ... RETURN` variants.
             if let clickgraph::open_cypher_parser::ast::CypherStatement::Query { query, .. } = &stmt
             {
-                use clickgraph::query_planner::types::QueryType;
-                let is_write = matches!(
-                    clickgraph::query_planner::get_query_type(query),
-                    QueryType::Update | QueryType::Delete
-                ) || query.create_clause.is_some();
-                if is_write {
-                    return self.handle_write_async(cypher).await;
+                if is_write_query(query) {
+                    return self.handle_write_async(cypher, &HashMap::new()).await;
                 }
             }
         }
+        self.flush_pending_writes().await?;
         self.query_with_executor_async(cypher, &self.executor).await
     }
 
+    /// Flush the async-insert queue for tables written since the last local
+    /// read. No-op unless `ReadYourWritesWithFlush` recorded writes.
+    async fn flush_pending_writes(&self) -> Result<(), EmbeddedError> {
+        use clickgraph::clickhouse_query_generator::write_to_sql::flush_async_insert_queue_sql;
+
+        let tables = std::mem::take(&mut *self.pending_flush.lock().unwrap());
+        if tables.is_empty() {
+            return Ok(());
+        }
+        self.executor
+            .execute_json(&flush_async_insert_queue_sql(&tables), None)
+            .await
+            .map_err(EmbeddedError::from)?;
+        Ok(())
+    }
+
     /// Plan, render, and execute a Cypher write query (CREATE / SET /
     /// DELETE / REMOVE). Returns Neo4j-compatible counters as a single-row
     /// `QueryResult` per Decision 0.8 of the embedded-writes design — or,
     /// when the statement also carries a RETURN clause (Phase 5d), the
     /// row payload from re-running the read pipeline against the modified
     /// state with the write counters attached via `QueryResult::get_write_counters()`.
-    async fn handle_write_async(&self, cypher: &str) -> Result<QueryResult, EmbeddedError> {
+    ///
+    /// `params` supplies values for the statement's `$parameters`; see
+    /// [`Connection::query_with_params`].
+    async fn handle_write_async(
+        &self,
+        cypher: &str,
+        params: &HashMap<String, serde_json::Value>,
+    ) -> Result<QueryResult, EmbeddedError> {
         use clickgraph::clickhouse_query_generator::cypher_to_sql_read_only;
-        use clickgraph::clickhouse_query_generator::write_to_sql::write_render_to_sql;
+        use clickgraph::clickhouse_query_generator::write_to_sql::{
+            write_render_to_sql, write_render_to_sql_sync_inserts,
+        };
         use clickgraph::open_cypher_parser::ast::CypherStatement;
         use clickgraph::query_planner::logical_plan::LogicalPlan;
         use clickgraph::query_planner::write_guard::ensure_write_target_writable;
-        use clickgraph::render_plan::write_plan_builder::build_write_plan;
+        use clickgraph::render_plan::write_plan_builder::build_write_plan_with_parameters;
         use clickgraph::server::query_context::{
             set_current_schema, with_query_context, QueryContext,
         };
@@ -1296,7 +1387,9 @@ impl<'db> Connection<'db> {
         let schema = Arc::clone(&self.schema);
         let executor = Arc::clone(&self.executor);
         let executor_kind = self.db.executor_kind;
+        let write_options = self.db.write_options;
         let cypher = cypher.to_string();
+        let params = params.clone();
 
         with_query_context(QueryContext::new(None), async move {
             set_current_schema(Arc::clone(&schema));
@@ -1342,15 +1435,16 @@ impl<'db> Connection<'db> {
                 )
             })?;
 
-            let write_plan = build_write_plan(write_subplan, &schema)
-                .map_err(|e| EmbeddedError::Query(format!("Write render error: {}", e)))?
-                .ok_or_else(|| {
-                    EmbeddedError::Query(
-                        "Internal error: write subplan resolved but `build_write_plan` \
+            let write_plan =
+                build_write_plan_with_parameters(write_subplan, &schema, write_options, &params)
+                    .map_err(|e| EmbeddedError::Query(format!("Write render error: {}", e)))?
+                    .ok_or_else(|| {
+                        EmbeddedError::Query(
+                            "Internal error: write subplan resolved but `build_write_plan` \
                          returned None. This is a planner/rendering bug; please report."
-                            .to_string(),
-                    )
-                })?;
+                                .to_string(),
+                        )
+                    })?;
 
             // Phase 5d v1 supports DELETE / SET / REMOVE + RETURN by
             // executing the write, then re-running the read pipeline with
@@ -1374,7 +1468,12 @@ impl<'db> Connection<'db> {
                 ));
             }
 
-            let stmts = write_render_to_sql(&write_plan);
+            let stmts = match self.write_consistency {
+                WriteConsistency::Eventual => write_render_to_sql(&write_plan),
+                WriteConsistency::ReadYourWrites | WriteConsistency::ReadYourWritesWithFlush => {
+                    write_render_to_sql_sync_inserts(&write_plan)
+                }
+            };
             let compile_time_ms = compile_start.elapsed().as_secs_f64() * 1000.0;
 
             // Resolve counters via `count()` probes against the same
@@ -1394,19 +1493,30 @@ impl<'db> Connection<'db> {
             }
             let execution_time_ms = exec_start.elapsed().as_secs_f64() * 1000.0;
 
+            if self.write_consistency == WriteConsistency::ReadYourWritesWithFlush {
+                let mut pending = self.pending_flush.lock().unwrap();
+                for table in write_plan.target_tables() {
+                    if !pending.contains(&table) {
+                        pending.push(table);
+                    }
+                }
+            }
+
             if !has_return {
-                // Pure-write path: surface counters as a synthetic 4-column
+                // Pure-write path: surface counters as a synthetic 5-column
                 // single-row result for back-compat with Phase 5a/5b.
                 let column_names = vec![
                     "nodes_created".to_string(),
                     "properties_set".to_string(),
                     "nodes_deleted".to_string(),
+                    "relationships_created".to_string(),
                     "relationships_deleted".to_string(),
                 ];
                 let row = vec![
                     Value::Int64(counters.nodes_created as i64),
                     Value::Int64(counters.properties_set as i64),
                     Value::Int64(counters.nodes_deleted as i64),
+                    Value::Int64(counters.relationships_created as i64),
                     Value::Int64(counters.relationships_deleted as i64),
                 ];
                 return Ok(QueryResult::with_timing(
@@ -1428,6 +1538,7 @@ impl<'db> Connection<'db> {
             let read_compile_ms = read_compile_start.elapsed().as_secs_f64() * 1000.0;
 
             let read_exec_start = Instant::now();
+            self.flush_pending_writes().await?;
             let json_rows = executor
                 .execute_json(&read_sql, None)
                 .await
@@ -1458,6 +1569,10 @@ impl<'db> Connection<'db> {
             counter_map.insert("nodes_created".to_string(), counters.nodes_created as i64);
             counter_map.insert("properties_set".to_string(), counters.properties_set as i64);
             counter_map.insert("nodes_deleted".to_string(), counters.nodes_deleted as i64);
+            counter_map.insert(
+                "relationships_created".to_string(),
+                counters.relationships_created as i64,
+            );
             counter_map.insert(
                 "relationships_deleted".to_string(),
                 counters.relationships_deleted as i64,
@@ -1475,6 +1590,22 @@ impl<'db> Connection<'db> {
     }
 }
 
+/// Whether `query` carries a write clause and must take the write pipeline.
+///
+/// Any query carrying a write clause must enter that pipeline so it is either
+/// executed or rejected with a clear error; falling through to the read path
+/// produces confusing render-time errors. `get_query_type` only inspects
+/// SET / DELETE / REMOVE, so CREATE / FOREACH / MERGE are checked explicitly.
+fn is_write_query(query: &clickgraph::open_cypher_parser::ast::OpenCypherQueryAst<'_>) -> bool {
+    use clickgraph::query_planner::types::QueryType;
+    matches!(
+        clickgraph::query_planner::get_query_type(query),
+        QueryType::Update | QueryType::Delete
+    ) || query.create_clause.is_some()
+        || query.foreach_clause.is_some()
+        || query.merge_clause.is_some()
+}
+
 /// Walk past read-only wrappers (Projection / OrderBy / Skip / Limit) to
 /// find the topmost write subplan inside `plan`. Returns `None` if the
 /// plan tree contains no write node, or if a write is buried under a
@@ -1509,8 +1640,9 @@ fn find_write_subplan(
 }
 
 /// Counters surfaced as `nodes_created` / `properties_set` /
-/// `nodes_deleted` / `relationships_deleted` on the write `QueryResult`.
-/// `INSERT` counts come straight from the rendered op (rows are exact);
+/// `nodes_deleted` / `relationships_created` / `relationships_deleted` on
+/// the write `QueryResult`. `VALUES` INSERT counts come straight from the
+/// rendered op (rows are exact); `INSERT ... SELECT` counts are probed;
 /// `DELETE` and `UPDATE` counts come from `probe_*_count_sql` probes
 /// run against chdb just before the mutation, since the lightweight
 /// write path doesn't return affected-row counts and a static "+= 1
@@ -1523,6 +1655,7 @@ struct WriteCounters {
     nodes_created: u64,
     properties_set: u64,
     nodes_deleted: u64,
+    relationships_created: u64,
     relationships_deleted: u64,
 }
 
@@ -1532,6 +1665,14 @@ struct WriteCounters {
 /// straight in without a probe.
 enum ProbeAction {
     NodesCreatedStatic(u64),
+    RelationshipsCreatedStatic(u64),
+    /// Node `INSERT ... SELECT` (UNWIND over the read pipeline): one node
+    /// per row the probe counts.
+    NodesCreatedProbe(String),
+    /// Relationship `INSERT ... SELECT`: one edge per row the probe counts.
+    RelationshipsCreatedProbe(String),
+    /// MERGE insert: one node is created only if the probe counts no match.
+    NodesMergedProbe(String),
     NodesDeletedProbe(String),
     RelationshipsDeletedProbe(String),
     /// `properties_set` is per-property-per-row: `assignments * affected_rows`.
@@ -1549,17 +1690,28 @@ fn collect_counter_probes(plan: &clickgraph::render_plan::WriteRenderPlan) -> Ve
 
 fn push_probes(plan: &clickgraph::render_plan::WriteRenderPlan, out: &mut Vec<ProbeAction>) {
     use clickgraph::clickhouse_query_generator::write_to_sql::{
-        probe_delete_count_sql, probe_update_count_sql,
+        probe_delete_count_sql, probe_insert_select_count_sql, probe_merge_match_count_sql,
+        probe_update_count_sql,
     };
     use clickgraph::render_plan::WriteRenderPlan;
     match plan {
-        WriteRenderPlan::Insert(op) => {
-            // INSERT counts are exact: one row per VALUES tuple.
-            // Whether it's a node or relationship is encoded in the table,
-            // but rel CREATE is currently rejected by build_write_plan, so
-            // every Insert here is a node create.
-            out.push(ProbeAction::NodesCreatedStatic(op.rows.len() as u64));
+        WriteRenderPlan::Insert(op) if op.merge_key.is_some() => {
+            if let Some(sql) = probe_merge_match_count_sql(op) {
+                out.push(ProbeAction::NodesMergedProbe(sql));
+            }
         }
+        WriteRenderPlan::Insert(op) if op.relationship => {
+            // Edges between MATCH-bound nodes are written per matched row.
+            match probe_insert_select_count_sql(op) {
+                Some(sql) => out.push(ProbeAction::RelationshipsCreatedProbe(sql)),
+                None => out.push(ProbeAction::RelationshipsCreatedStatic(op.rows.len() as u64)),
+            }
+        }
+        WriteRenderPlan::Insert(op) => match probe_insert_select_count_sql(op) {
+            Some(sql) => out.push(ProbeAction::NodesCreatedProbe(sql)),
+            // INSERT counts are exact: one row per VALUES tuple.
+            None => out.push(ProbeAction::NodesCreatedStatic(op.rows.len() as u64)),
+        },
         WriteRenderPlan::Update(op) => {
             out.push(ProbeAction::PropertiesSetProbe {
                 assignments: op.assignments.len() as u64,
@@ -1642,6 +1794,18 @@ async fn resolve_write_counters(
     for probe in collect_counter_probes(plan) {
         match probe {
             ProbeAction::NodesCreatedStatic(n) => c.nodes_created += n,
+            ProbeAction::NodesCreatedProbe(sql) => {
+                c.nodes_created += run_count_probe(executor, &sql).await?;
+            }
+            ProbeAction::RelationshipsCreatedStatic(n) => c.relationships_created += n,
+            ProbeAction::RelationshipsCreatedProbe(sql) => {
+                c.relationships_created += run_count_probe(executor, &sql).await?;
+            }
+            ProbeAction::NodesMergedProbe(sql) => {
+                if run_count_probe(executor, &sql).await? == 0 {
+                    c.nodes_created += 1;
+                }
+            }
             ProbeAction::NodesDeletedProbe(sql) => {
                 c.nodes_deleted += run_count_probe(executor, &sql).await?;
             }
@@ -1823,6 +1987,7 @@ graph_schema:
                 .unwrap(),
             executor_kind: clickgraph::query_planner::write_guard::ExecutorKind::EmbeddedChdb,
             dialect: clickgraph::sql_generator::SqlDialect::ClickHouse,
+            write_options: Default::default(),
         }
     }
 
@@ -1879,6 +2044,7 @@ graph_schema:
                 .unwrap(),
             executor_kind: clickgraph::query_planner::write_guard::ExecutorKind::EmbeddedChdb,
             dialect: clickgraph::sql_generator::SqlDialect::ClickHouse,
+            write_options: Default::default(),
         };
         (db, captured)
     }
@@ -1915,6 +2081,7 @@ graph_schema:
                 .unwrap(),
             executor_kind: clickgraph::query_planner::write_guard::ExecutorKind::EmbeddedChdb,
             dialect: clickgraph::sql_generator::SqlDialect::ClickHouse,
+            write_options: Default::default(),
         }
     }
 
@@ -2425,7 +2592,7 @@ graph_schema:
             .expect("CREATE should succeed via write dispatch");
 
         // Counters surface as a single-row QueryResult per Decision 0.8.
-        assert_eq!(result.get_column_names().len(), 4);
+        assert_eq!(result.get_column_names().len(), 5);
         assert_eq!(result.num_rows(), 1);
         let row = result.next().unwrap();
         assert_eq!(row.get("nodes_created").unwrap().as_i64(), Some(1));
@@ -2445,6 +2612,155 @@ graph_schema:
         );
     }
 
+    #[test]
+    fn cypher_create_relationship_counts_created_edges() {
+        let (db, captured) = make_capturing_db(build_writable_test_schema());
+        let conn = Connection::new(&db).unwrap();
+        let mut result = conn
+            .query(
+                "CREATE (a:Person {person_id: 'p1'})-[:KNOWS {since: 2020}]->\
+                 (b:Person {person_id: 'p2'})",
+            )
+            .expect("relationship CREATE should succeed");
+        let row = result.next().unwrap();
+        assert_eq!(row.get("nodes_created").unwrap().as_i64(), Some(2));
+        assert_eq!(row.get("relationships_created").unwrap().as_i64(), Some(1));
+        {
+            let sqls = captured.lock().unwrap();
+            assert_eq!(
+                sqls.as_slice(),
+                [
+                    "INSERT INTO `test_db`.`persons` (`person_id`) VALUES ('p1'), ('p2')",
+                    "INSERT INTO `test_db`.`knows` (`from_person_id`, `to_person_id`, \
+                     `since_year`) VALUES ('p1', 'p2', 2020)",
+                ]
+            );
+        }
+
+        // Between MATCH-bound nodes the edge count comes from a probe of the
+        // INSERT ... SELECT source.
+        captured.lock().unwrap().clear();
+        let mut result = conn
+            .query(
+                "MATCH (a:Person), (b:Person) WHERE a.person_id = 'p1' AND b.person_id = 'p2' \
+                 CREATE (a)-[:KNOWS]->(b)",
+            )
+            .expect("MATCH … CREATE relationship should succeed");
+        let row = result.next().unwrap();
+        assert_eq!(row.get("nodes_created").unwrap().as_i64(), Some(0));
+        assert_eq!(row.get("relationships_created").unwrap().as_i64(), Some(1));
+        let sqls = captured.lock().unwrap();
+        assert!(
+            sqls[0].starts_with("SELECT count() AS n FROM ("),
+            "got: {:?}",
+            sqls
+        );
+        assert!(
+            sqls[1].starts_with(
+                "INSERT INTO `test_db`.`knows` (`from_person_id`, `to_person_id`) SELECT"
+            ),
+            "got: {:?}",
+            sqls
+        );
+    }
+
+    #[test]
+    fn query_with_params_batches_unwind_rows_into_one_insert() {
+        let (db, captured) = make_capturing_db(build_writable_test_schema());
+        let conn = Connection::new(&db).unwrap();
+        let rows = serde_json::json!([
+            {"id": "p1", "name": "Alice"},
+            {"id": "p2", "name": "Bob"},
+            {"id": "p3"},
+        ]);
+        let mut result = conn
+            .query_with_params(
+                "UNWIND $rows AS row CREATE (:Person {person_id: row.id, name: row.name})",
+                [("rows".to_string(), rows)].into_iter().collect(),
+            )
+            .expect("UNWIND $rows CREATE should succeed");
+        let row = result.next().unwrap();
+        assert_eq!(row.get("nodes_created").unwrap().as_i64(), Some(3));
+        assert_eq!(
+            captured.lock().unwrap().as_slice(),
+            [
+                "INSERT INTO `test_db`.`persons` (`person_id`, `full_name`) \
+              VALUES ('p1', 'Alice'), ('p2', 'Bob'), ('p3', NULL)"
+            ]
+        );
+
+        let err = conn
+            .query_with_params("MATCH (p:Person) RETURN p.name", HashMap::new())
+            .expect_err("reads take no parameters here");
+        assert!(err.to_string().contains("only write statements"), "{err}");
+    }
+
+    #[test]
+    fn cypher_delete_honours_write_options() {
+        let (mut db, captured) = make_capturing_db(build_writable_test_schema());
+        db.write_options.require_delete_predicate = true;
+        let conn = Connection::new(&db).unwrap();
+        let err = conn
+            .query("MATCH (p:Person) DETACH DELETE p")
+            .expect_err("unfiltered DELETE must be rejected");
+        assert!(err.to_string().contains("without a WHERE clause"), "{err}");
+        assert!(captured.lock().unwrap().is_empty());
+        conn.query("MATCH (p:Person {person_id: 'p1'}) DETACH DELETE p")
+            .expect("DELETE with an inline predicate should succeed");
+
+        db.write_options.allow_deletes = false;
+        let conn = Connection::new(&db).unwrap();
+        let err = conn
+            .query("MATCH (p:Person) WHERE p.person_id = 'p1' DELETE p")
+            .expect_err("DELETE must be rejected when disabled");
+        assert!(err.to_string().contains("allow_deletes"), "{err}");
+    }
+
+    #[test]
+    fn read_your_writes_waits_for_async_insert() {
+        let (db, captured) = make_capturing_db(build_writable_test_schema());
+        let mut conn = Connection::new(&db).unwrap();
+        conn.set_write_consistency(WriteConsistency::ReadYourWrites);
+        conn.query("CREATE (a:Person {person_id: 'p1', name: 'Alice'})")
+            .expect("CREATE should succeed");
+        conn.query("MATCH (a:Person) RETURN a.name").unwrap();
+
+        let sqls = captured.lock().unwrap();
+        assert_eq!(sqls.len(), 2, "no flush expected, got {:?}", sqls);
+        assert!(
+            sqls[0].contains("SETTINGS wait_for_async_insert = 1 VALUES"),
+            "got: {}",
+            sqls[0]
+        );
+    }
+
+    #[test]
+    fn read_your_writes_with_flush_flushes_written_tables_once() {
+        let (db, captured) = make_capturing_db(build_writable_test_schema());
+        let mut conn = Connection::new(&db).unwrap();
+        conn.set_write_consistency(WriteConsistency::ReadYourWritesWithFlush);
+        conn.query("CREATE (a:Person {person_id: 'p1'})").unwrap();
+        conn.query("CREATE (a:Person {person_id: 'p2'})").unwrap();
+        conn.query("MATCH (a:Person) RETURN a.name").unwrap();
+        conn.query("MATCH (a:Person) RETURN a.name").unwrap();
+
+        let sqls = captured.lock().unwrap();
+        let flushes: Vec<_> = sqls
+            .iter()
+            .filter(|sql| sql.starts_with("SYSTEM FLUSH ASYNC INSERT QUEUE"))
+            .collect();
+        assert_eq!(flushes.len(), 1, "got {:?}", sqls);
+        assert_eq!(
+            flushes[0].as_str(),
+            "SYSTEM FLUSH ASYNC INSERT QUEUE `test_db`.`persons`"
+        );
+        assert!(
+            sqls[3].starts_with("SELECT"),
+            "flush must precede the first read, got {:?}",
+            sqls
+        );
+    }
+
     /// `find_first_with_prefix` lets dispatch tests probe the captured
     /// SQL stream without depending on the exact ordering between
     /// count-probes (Phase 5d) and the lightweight DELETE/UPDATE itself.
@@ -2623,11 +2939,11 @@ graph_schema:
             .expect("MATCH … CREATE must reach write pipeline");
         // Counter-shape ensures we routed to handle_write_async; if it
         // had fallen through to the read path, the QueryResult columns
-        // would not be the four counter columns.
+        // would not be the five counter columns.
         let cols = result.get_column_names();
         assert_eq!(
             cols.len(),
-            4,
+            5,
             "expected counter result from write path, got cols={:?}",
             cols
         );
@@ -2743,7 +3059,7 @@ graph_schema:
     /// pipeline, then re-run the read pipeline and surface the user-visible
     /// row payload plus the side-effect counters via the new
     /// `QueryResult::get_write_counters()` side-channel. The pure-write
-    /// 4-column synthetic counter row is *not* used in this path — the
+    /// 5-column synthetic counter row is *not* used in this path — the
     /// row payload comes from the read pipeline.
     ///
     /// We pin dispatch-shape (side-channel populated, read columns
@@ -2772,13 +3088,14 @@ graph_schema:
              not the synthetic counter row; got {:?}",
             cols
         );
-        // The four canonical counters must all be present in the side-
+        // The five canonical counters must all be present in the side-
         // channel map even when zero, so downstream consumers (e.g. the
         // TCK harness) can read every key without `Option` juggling.
         for key in [
             "nodes_created",
             "properties_set",
             "nodes_deleted",
+            "relationships_created",
             "relationships_deleted",
         ] {
             assert!(
@@ -19,6 +19,7 @@ use clickgraph::executor::remote::RemoteClickHouseExecutor;
 use clickgraph::executor::{ExecutorError, QueryExecutor};
 use clickgraph::graph_catalog::config::GraphSchemaConfig;
 use clickgraph::graph_catalog::graph_schema::GraphSchema;
+use clickgraph::render_plan::write_plan_builder::WriteOptions;
 use clickgraph::server::connection_pool::RoleConnectionPool;
 use clickgraph::sql_generator::SqlDialect;
 
@@ -101,6 +102,15 @@ pub struct SystemConfig {
     /// Cypher queries on the remote cluster. Results can then be stored locally
     /// via `store_subgraph()` for fast re-querying.
     pub remote: Option<RemoteConfig>,
+
+    /// Whether Cypher `DELETE` / `DETACH DELETE` may run at all.
+    /// `None` allows deletes (the default).
+    pub allow_deletes: Option<bool>,
+
+    /// Reject `DELETE` / `DETACH DELETE` whose `MATCH` carries no `WHERE`
+    /// clause or inline property predicate, so a bare `MATCH (n:Label)
+    /// DELETE n` cannot wipe a table by accident. `None` means `false`.
+    pub require_delete_predicate: Option<bool>,
 }
 
 /// An embedded ClickGraph database.
@@ -129,6 +139,8 @@ pub struct Database {
     /// renderer routes through the right `FunctionMapper`. Default
     /// `ClickHouse`; `new_databricks()` sets it to `Databricks`.
     pub(crate) dialect: SqlDialect,
+    /// Safety switches applied when lowering Cypher writes to SQL.
+    pub(crate) write_options: WriteOptions,
 }
 
 impl Database {
@@ -167,6 +179,7 @@ impl Database {
         config: SystemConfig,
     ) -> Result<Self, EmbeddedError> {
         let runtime = build_runtime()?;
+        let write_options = write_options_from(&config);
 
         let (session_dir, auto_cleanup) = match config.session_dir {
             Some(dir) => (dir, false),
@@ -220,6 +233,7 @@ impl Database {
             runtime,
             executor_kind: clickgraph::query_planner::write_guard::ExecutorKind::EmbeddedChdb,
             dialect: SqlDialect::ClickHouse,
+            write_options,
         })
     }
 
@@ -244,6 +258,7 @@ impl Database {
             runtime,
             executor_kind: clickgraph::query_planner::write_guard::ExecutorKind::Remote,
             dialect: SqlDialect::ClickHouse,
+            write_options: WriteOptions::default(),
         })
     }
 
@@ -280,6 +295,7 @@ impl Database {
             runtime,
             executor_kind: clickgraph::query_planner::write_guard::ExecutorKind::Remote,
             dialect: SqlDialect::Databricks,
+            write_options: WriteOptions::default(),
         })
     }
 
@@ -331,6 +347,7 @@ impl Database {
             // construct a chdb-backed Database via `new` instead.
             executor_kind: clickgraph::query_planner::write_guard::ExecutorKind::SqlOnly,
             dialect: SqlDialect::ClickHouse,
+            write_options: WriteOptions::default(),
         })
     }
 
@@ -440,6 +457,19 @@ fn build_runtime() -> Result<tokio::runtime::Runtime, EmbeddedError> {
         .map_err(|e| EmbeddedError::Query(format!("Failed to create runtime: {}", e)))
 }
 
+/// Resolve the write safety switches from a `SystemConfig`, falling back to
+/// `WriteOptions::default()` for unset fields.
+#[cfg(feature = "embedded")]
+fn write_options_from(config: &SystemConfig) -> WriteOptions {
+    let defaults = WriteOptions::default();
+    WriteOptions {
+        allow_deletes: config.allow_deletes.unwrap_or(defaults.allow_deletes),
+        require_delete_predicate: config
+            .require_delete_predicate
+            .unwrap_or(defaults.require_delete_predicate),
+    }
+}
+
 #[cfg(feature = "embedded")]
 fn pseudo_random_suffix() -> String {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -47,7 +47,7 @@ pub mod value;
 pub(crate) mod write_helpers;
 
 pub use clickgraph::sql_generator::SqlDialect;
-pub use connection::Connection;
+pub use connection::{Connection, WriteConsistency};
 pub use cypher_loader::LoadStats;
 #[cfg(feature = "embedded")]
 pub use database::StorageCredentials;
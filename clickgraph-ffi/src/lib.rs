@@ -372,7 +372,7 @@ impl QueryResult {
     /// clause — the row payload then carries the read-pipeline output
     /// and these counters reflect the write portion. Pure read queries
     /// and pure-write queries (which surface counters as a synthetic
-    /// 4-column row) return `None`.
+    /// 5-column row) return `None`.
     pub fn get_write_counters(&self) -> Option<std::collections::HashMap<String, i64>> {
         self.write_counters.clone()
     }
@@ -892,6 +892,7 @@ fn to_rust_system_config(config: SystemConfig) -> RustSystemConfig {
             database: r.database,
             cluster_name: r.cluster_name,
         }),
+        ..RustSystemConfig::default()
     }
 }
 
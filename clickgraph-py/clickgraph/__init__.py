@@ -178,7 +178,7 @@ class QueryResult:
         # Phase 5d write+RETURN side-channel: populated only when the
         # originating Cypher combined a write clause with a RETURN
         # clause; `None` for pure reads and pure writes (whose counters
-        # are surfaced as a synthetic 4-column row payload). Wrapped in
+        # are surfaced as a synthetic 5-column row payload). Wrapped in
         # try/except so the wrapper keeps working when the auto-
         # generated `_ffi.py` predates the side-channel addition; the
         # warning above already nags about that case.
@@ -217,12 +217,13 @@ class QueryResult:
         """Side-effect counters from a write+RETURN query (Phase 5d).
 
         Returns a dict with keys ``nodes_created`` / ``properties_set`` /
-        ``nodes_deleted`` / ``relationships_deleted`` only when the
+        ``nodes_deleted`` / ``relationships_created`` /
+        ``relationships_deleted`` only when the
         originating Cypher combined a write clause (CREATE / SET /
         DELETE / REMOVE) with a RETURN clause — the row payload then
         carries the read-pipeline output and these counters reflect
         the write portion. Pure read queries and pure-write queries
-        (which surface counters as a synthetic 4-column row) return
+        (which surface counters as a synthetic 5-column row) return
         ``None``.
         """
         return None if self._write_counters is None else dict(self._write_counters)
@@ -3820,7 +3820,7 @@ class QueryResultProtocol(typing.Protocol):
         clause — the row payload then carries the read-pipeline output
         and these counters reflect the write portion. Pure read queries
         and pure-write queries (which surface counters as a synthetic
-        4-column row) return `None`.
+        5-column row) return `None`.
         """
 
         raise NotImplementedError
@@ -3955,7 +3955,7 @@ class QueryResult():
         clause — the row payload then carries the read-pipeline output
         and these counters reflect the write portion. Pure read queries
         and pure-write queries (which surface counters as a synthetic
-        4-column row) return `None`.
+        5-column row) return `None`.
         """
 
         return _UniffiConverterOptionalMapStringInt64.lift(
@@ -235,7 +235,7 @@ async fn when_executing_query(world: &mut TckWorld, step: &Step) {
             // Phase 5d: write+RETURN attaches counters via the side-channel
             // (`get_write_counters`), and the row payload carries the
             // user-visible result of the read pipeline. Pure-write
-            // statements still surface the synthetic 4-column counter row
+            // statements still surface the synthetic 5-column counter row
             // for back-compat (Phase 5a/5b).
             if let Some(side_counters) = result.get_write_counters() {
                 let counters: HashMap<String, i64> =
@@ -249,7 +249,7 @@ async fn when_executing_query(world: &mut TckWorld, step: &Step) {
                     .collect();
                 world.error = None;
             } else if is_write_counter_shape(&col_names) {
-                // Pure-write synthetic counter-row. Stash the four counters
+                // Pure-write synthetic counter-row. Stash the five counters
                 // and leave result_rows empty (the TCK write shapes are
                 // `the result should be empty`). Non-Int64 cells are a
                 // regression signal; surface as a captured error.
@@ -310,14 +310,15 @@ async fn when_executing_query(world: &mut TckWorld, step: &Step) {
     }
 }
 
-/// Recognise the four-column counter shape that `handle_write_async`
+/// Recognise the five-column counter shape that `handle_write_async`
 /// returns. Used to distinguish read vs. write QueryResult and route the
 /// side-effect step to counter assertions.
 fn is_write_counter_shape(cols: &[String]) -> bool {
-    const EXPECTED: [&str; 4] = [
+    const EXPECTED: [&str; 5] = [
         "nodes_created",
         "properties_set",
         "nodes_deleted",
+        "relationships_created",
         "relationships_deleted",
     ];
     cols.len() == EXPECTED.len() && EXPECTED.iter().all(|n| cols.iter().any(|c| c == n))
@@ -485,9 +486,8 @@ async fn then_side_effects(world: &mut TckWorld, step: &Step) {
 
     // Each Gherkin row is `| <key> | <value> |`. The keys we know how to
     // map are listed in `effect_to_counter`. Unknown keys (today: any
-    // `+labels` / `-labels` / `+properties` from a label mutation, plus
-    // any future `+nodes` for relationship CREATE that's still unsupported)
-    // mark the scenario as skipped so it's surfaced as @wip rather than
+    // `+labels` / `-labels` / `+properties` from a label mutation) mark
+    // the scenario as skipped so it's surfaced as @wip rather than
     // failing the run.
     for row in &table.rows {
         if row.len() < 2 {
@@ -543,7 +543,7 @@ async fn then_side_effects(world: &mut TckWorld, step: &Step) {
 
 /// Map a TCK side-effect key to the QueryResult counter column it
 /// corresponds to. `None` means the side effect isn't representable in
-/// the current four-counter shape — typically label mutations, which
+/// the current five-counter shape — typically label mutations, which
 /// ClickGraph doesn't support at all (labels are baked into the table
 /// identity, see Cypher Language Reference).
 fn effect_to_counter(key: &str) -> Option<&'static str> {
@@ -551,6 +551,7 @@ fn effect_to_counter(key: &str) -> Option<&'static str> {
         "+nodes" => Some("nodes_created"),
         "+properties" => Some("properties_set"),
         "-nodes" => Some("nodes_deleted"),
+        "+relationships" => Some("relationships_created"),
         "-relationships" => Some("relationships_deleted"),
         // `-properties` (REMOVE / SET = NULL) is also `properties_set`
         // in our model since the work is a SET … = NULL update — but
@@ -558,8 +559,6 @@ fn effect_to_counter(key: &str) -> Option<&'static str> {
         // mirror that here.
         "-properties" => Some("properties_set"),
         // Label mutations: not supported at all (out of scope).
-        // Relationship CREATE side effect (`+relationships`): not yet
-        // supported, so leave unmapped to surface as @wip skip.
         _ => None,
     }
 }
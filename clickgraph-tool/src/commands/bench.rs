@@ -0,0 +1,577 @@
+//! `cg bench` — a load generator for a running ClickGraph server.
+//!
+//! Queries are generated from the schema rather than written by hand, so the
+//! same command works for any graph: 1-hop patterns over every edge type,
+//! 3-hop chains where the edge endpoints line up, variable-length paths over
+//! self-referencing edge types and aggregates (node counts, top degrees).
+//! A `--mix` preset weights the four kinds; the request schedule is drawn
+//! from a seeded generator, so two runs with the same flags send the same
+//! queries in the same order.
+//!
+//! Requests go to the server's `POST /query` with the schema's `name` as
+//! `schema_name`, from `--concurrency` workers. The report gives latency
+//! percentiles per query kind and overall, plus throughput — enough to
+//! compare deployment sizes with a repeatable workload.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clickgraph::graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::config::CgConfig;
+
+/// Cap on generated queries per kind, so wide schemas don't dilute the mix
+/// into thousands of one-off patterns.
+const MAX_QUERIES_PER_KIND: usize = 16;
+
+/// Row limit on generated pattern queries.
+const ROW_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Kind {
+    OneHop,
+    ThreeHop,
+    Vlp,
+    Aggregate,
+}
+
+impl Kind {
+    const ALL: [Kind; 4] = [Kind::OneHop, Kind::ThreeHop, Kind::Vlp, Kind::Aggregate];
+
+    fn label(self) -> &'static str {
+        match self {
+            Kind::OneHop => "1-hop",
+            Kind::ThreeHop => "3-hop",
+            Kind::Vlp => "vlp",
+            Kind::Aggregate => "aggregate",
+        }
+    }
+}
+
+/// Preset weighting of the query kinds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mix {
+    /// Mostly short patterns and aggregates, some deep traversals
+    ReadHeavy,
+    /// Mostly 3-hop chains and variable-length paths
+    Traversal,
+    /// Mostly aggregates
+    Analytics,
+    /// Every kind equally
+    Uniform,
+}
+
+impl Mix {
+    fn name(self) -> &'static str {
+        match self {
+            Mix::ReadHeavy => "read-heavy",
+            Mix::Traversal => "traversal",
+            Mix::Analytics => "analytics",
+            Mix::Uniform => "uniform",
+        }
+    }
+
+    /// Relative weight of `kind`, out of 100.
+    fn weight(self, kind: Kind) -> u64 {
+        match (self, kind) {
+            (Mix::ReadHeavy, Kind::OneHop) => 50,
+            (Mix::ReadHeavy, Kind::ThreeHop) => 10,
+            (Mix::ReadHeavy, Kind::Vlp) => 10,
+            (Mix::ReadHeavy, Kind::Aggregate) => 30,
+            (Mix::Traversal, Kind::OneHop) => 20,
+            (Mix::Traversal, Kind::ThreeHop) => 35,
+            (Mix::Traversal, Kind::Vlp) => 35,
+            (Mix::Traversal, Kind::Aggregate) => 10,
+            (Mix::Analytics, Kind::OneHop) => 15,
+            (Mix::Analytics, Kind::ThreeHop) => 10,
+            (Mix::Analytics, Kind::Vlp) => 5,
+            (Mix::Analytics, Kind::Aggregate) => 70,
+            (Mix::Uniform, _) => 25,
+        }
+    }
+}
+
+impl FromStr for Mix {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "read-heavy" | "read_heavy" => Ok(Mix::ReadHeavy),
+            "traversal" => Ok(Mix::Traversal),
+            "analytics" => Ok(Mix::Analytics),
+            "uniform" => Ok(Mix::Uniform),
+            _ => Err(anyhow!(
+                "Unknown mix '{}'; expected read-heavy, traversal, analytics or uniform",
+                s
+            )),
+        }
+    }
+}
+
+/// Settings for one `cg bench` run.
+pub struct BenchOptions {
+    /// ClickGraph server base URL
+    pub server: String,
+    /// Schema name sent with each request (defaults to the YAML's `name`)
+    pub schema_name: Option<String>,
+    pub mix: Mix,
+    pub concurrency: usize,
+    /// Measured requests; ignored when `duration` is set
+    pub requests: usize,
+    /// Run for this long instead of a fixed request count
+    pub duration: Option<Duration>,
+    /// Unmeasured requests sent first to warm caches and connections
+    pub warmup: usize,
+    pub seed: u64,
+    /// Print the generated queries and schedule without sending anything
+    pub dry_run: bool,
+    pub format: String,
+}
+
+struct BenchQuery {
+    kind: Kind,
+    cypher: String,
+}
+
+/// One request's outcome.
+struct Sample {
+    query: usize,
+    latency: Duration,
+    error: Option<String>,
+}
+
+/// Request count, errors and latency percentiles for a set of samples.
+#[derive(Debug, Serialize)]
+struct Stats {
+    requests: usize,
+    errors: usize,
+    p50_ms: f64,
+    p90_ms: f64,
+    p95_ms: f64,
+    p99_ms: f64,
+    max_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    server: String,
+    schema_name: String,
+    mix: &'static str,
+    concurrency: usize,
+    elapsed_s: f64,
+    throughput_rps: f64,
+    overall: Stats,
+    by_kind: BTreeMap<Kind, Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_error: Option<String>,
+}
+
+/// `cg bench` — generate a query mix from the schema, run it against a
+/// ClickGraph server and print latency percentiles. Fails only when every
+/// request failed.
+pub async fn run_bench(opts: &BenchOptions, cfg: &CgConfig) -> Result<()> {
+    let path = cfg.require_schema()?;
+    let config = GraphSchemaConfig::from_yaml_file(path)
+        .map_err(|e| anyhow!("Failed to load schema '{}': {}", path, e))?;
+    let schema_name = opts
+        .schema_name
+        .clone()
+        .or_else(|| config.name.clone())
+        .unwrap_or_else(|| "default".to_string());
+    let schema = config
+        .to_graph_schema()
+        .map_err(|e| anyhow!("Failed to build schema: {}", e))?;
+
+    let queries = generate_queries(&schema);
+    if queries.is_empty() {
+        bail!("The schema has no node labels or edge types to generate queries from.");
+    }
+    let schedule_len = opts.requests.max(1);
+    let schedule = Arc::new(schedule(&queries, opts.mix, schedule_len, opts.seed));
+
+    if opts.dry_run {
+        print_plan(&queries, &schedule, opts.mix);
+        return Ok(());
+    }
+    if opts.concurrency == 0 {
+        bail!("--concurrency must be at least 1");
+    }
+
+    let queries = Arc::new(queries);
+    let workload = Workload {
+        client: reqwest::Client::new(),
+        endpoint: format!("{}/query", opts.server.trim_end_matches('/')),
+        schema_name: schema_name.clone(),
+        queries: queries.clone(),
+        schedule,
+    };
+    if opts.warmup > 0 {
+        workload
+            .run(opts.concurrency, Stop::After(opts.warmup))
+            .await?;
+    }
+    let stop = match opts.duration {
+        Some(duration) => Stop::At(Instant::now() + duration),
+        None => Stop::After(opts.requests),
+    };
+    let started = Instant::now();
+    let samples = workload.run(opts.concurrency, stop).await?;
+    let elapsed = started.elapsed();
+
+    let report = build_report(&samples, &queries, elapsed, opts, schema_name);
+    match opts.format.as_str() {
+        "json" => println!("{}", serde_json::to_string_pretty(&report)?),
+        _ => print_text(&report),
+    }
+
+    if report.overall.requests > 0 && report.overall.errors == report.overall.requests {
+        bail!(
+            "All {} requests failed: {}",
+            report.overall.requests,
+            report.first_error.unwrap_or_default()
+        );
+    }
+    Ok(())
+}
+
+/// When workers stop taking requests.
+#[derive(Clone, Copy)]
+enum Stop {
+    After(usize),
+    At(Instant),
+}
+
+struct Workload {
+    client: reqwest::Client,
+    endpoint: String,
+    schema_name: String,
+    queries: Arc<Vec<BenchQuery>>,
+    /// Query indices in send order; cycled when a timed run outlasts it
+    schedule: Arc<Vec<usize>>,
+}
+
+impl Workload {
+    async fn run(&self, concurrency: usize, stop: Stop) -> Result<Vec<Sample>> {
+        let next = Arc::new(AtomicUsize::new(0));
+        let mut workers = Vec::with_capacity(concurrency);
+        for _ in 0..concurrency {
+            let client = self.client.clone();
+            let endpoint = self.endpoint.clone();
+            let schema_name = self.schema_name.clone();
+            let queries = self.queries.clone();
+            let schedule = self.schedule.clone();
+            let next = next.clone();
+            workers.push(tokio::spawn(async move {
+                let mut samples = Vec::new();
+                loop {
+                    let n = next.fetch_add(1, Ordering::Relaxed);
+                    match stop {
+                        Stop::After(total) if n >= total => break,
+                        Stop::At(deadline) if Instant::now() >= deadline => break,
+                        _ => {}
+                    }
+                    let query = schedule[n % schedule.len()];
+                    let started = Instant::now();
+                    let error =
+                        send(&client, &endpoint, &schema_name, &queries[query].cypher).await;
+                    samples.push(Sample {
+                        query,
+                        latency: started.elapsed(),
+                        error,
+                    });
+                }
+                samples
+            }));
+        }
+
+        let mut samples = Vec::new();
+        for worker in workers {
+            samples.extend(worker.await.context("Benchmark worker panicked")?);
+        }
+        Ok(samples)
+    }
+}
+
+/// Send one query; returns the error message when it did not succeed.
+async fn send(
+    client: &reqwest::Client,
+    endpoint: &str,
+    schema_name: &str,
+    cypher: &str,
+) -> Option<String> {
+    let body = json!({ "query": cypher, "schema_name": schema_name });
+    let response = match client.post(endpoint).json(&body).send().await {
+        Ok(response) => response,
+        Err(e) => return Some(format!("request failed: {}", e)),
+    };
+    let status = response.status();
+    // Read the whole body so the timing covers the full result transfer
+    match response.bytes().await {
+        Ok(_) if status.is_success() => None,
+        Ok(bytes) => Some(format!(
+            "HTTP {}: {}",
+            status.as_u16(),
+            String::from_utf8_lossy(&bytes).trim()
+        )),
+        Err(e) => Some(format!("reading response failed: {}", e)),
+    }
+}
+
+/// Generate up to `MAX_QUERIES_PER_KIND` queries of each kind from the
+/// schema's node labels and edge types.
+fn generate_queries(schema: &GraphSchema) -> Vec<BenchQuery> {
+    let nodes = schema.all_node_schemas();
+    let ret = |alias: &str, label: &str| -> String {
+        nodes
+            .get(label)
+            .and_then(|node| node.property_mappings.keys().min())
+            .map(|prop| format!("{}.{}", alias, prop))
+            .unwrap_or_else(|| alias.to_string())
+    };
+
+    // (type, from, to) for every edge whose endpoints are known labels
+    let mut edges: Vec<(String, String, String)> = schema
+        .get_relationships_schemas()
+        .iter()
+        .filter(|(_, rel)| nodes.contains_key(&rel.from_node) && nodes.contains_key(&rel.to_node))
+        .map(|(key, rel)| {
+            let rel_type = key.split("::").next().unwrap_or(key).to_string();
+            (rel_type, rel.from_node.clone(), rel.to_node.clone())
+        })
+        .collect();
+    edges.sort();
+    edges.dedup();
+
+    let mut queries = Vec::new();
+    let mut push = |kind: Kind, cypher: String| {
+        if queries
+            .iter()
+            .filter(|q: &&BenchQuery| q.kind == kind)
+            .count()
+            < MAX_QUERIES_PER_KIND
+        {
+            queries.push(BenchQuery { kind, cypher });
+        }
+    };
+
+    for (rel_type, from, to) in &edges {
+        push(
+            Kind::OneHop,
+            format!(
+                "MATCH (a:{from})-[:{rel_type}]->(b:{to}) RETURN {}, {} LIMIT {ROW_LIMIT}",
+                ret("a", from),
+                ret("b", to)
+            ),
+        );
+    }
+
+    for (t1, a, b) in &edges {
+        for (t2, _, c) in edges.iter().filter(|(_, from, _)| from == b) {
+            for (t3, _, d) in edges.iter().filter(|(_, from, _)| from == c) {
+                push(
+                    Kind::ThreeHop,
+                    format!(
+                        "MATCH (a:{a})-[:{t1}]->(b:{b})-[:{t2}]->(c:{c})-[:{t3}]->(d:{d}) \
+                         RETURN {}, {} LIMIT {ROW_LIMIT}",
+                        ret("a", a),
+                        ret("d", d)
+                    ),
+                );
+            }
+        }
+    }
+
+    for (rel_type, from, _) in edges.iter().filter(|(_, from, to)| from == to) {
+        push(
+            Kind::Vlp,
+            format!(
+                "MATCH (a:{from})-[:{rel_type}*1..3]->(b:{from}) RETURN {}, {} LIMIT {ROW_LIMIT}",
+                ret("a", from),
+                ret("b", from)
+            ),
+        );
+    }
+
+    for label in nodes.keys() {
+        push(
+            Kind::Aggregate,
+            format!("MATCH (n:{label}) RETURN count(n) AS total"),
+        );
+    }
+    for (rel_type, from, to) in &edges {
+        push(
+            Kind::Aggregate,
+            format!(
+                "MATCH (a:{from})-[:{rel_type}]->(b:{to}) \
+                 RETURN {}, count(b) AS degree ORDER BY degree DESC LIMIT 10",
+                ret("a", from)
+            ),
+        );
+    }
+
+    queries
+}
+
+/// Draw `len` query indices: a kind by the mix's weights (kinds the schema
+/// produced no queries for are left out), then a query of that kind.
+fn schedule(queries: &[BenchQuery], mix: Mix, len: usize, seed: u64) -> Vec<usize> {
+    let by_kind: Vec<(Kind, Vec<usize>)> = Kind::ALL
+        .iter()
+        .map(|&kind| {
+            let indices = (0..queries.len())
+                .filter(|&i| queries[i].kind == kind)
+                .collect();
+            (kind, indices)
+        })
+        .filter(|(kind, indices): &(Kind, Vec<usize>)| !indices.is_empty() && mix.weight(*kind) > 0)
+        .collect();
+    let total_weight: u64 = by_kind.iter().map(|(kind, _)| mix.weight(*kind)).sum();
+    if total_weight == 0 {
+        return (0..len).map(|i| i % queries.len()).collect();
+    }
+
+    let mut rng = SplitMix64(seed);
+    (0..len)
+        .map(|_| {
+            let mut pick = rng.next() % total_weight;
+            let (_, indices) = by_kind
+                .iter()
+                .find(|(kind, _)| {
+                    let weight = mix.weight(*kind);
+                    if pick < weight {
+                        true
+                    } else {
+                        pick -= weight;
+                        false
+                    }
+                })
+                .expect("pick is below the total weight");
+            indices[(rng.next() % indices.len() as u64) as usize]
+        })
+        .collect()
+}
+
+/// Small deterministic generator; the schedule only needs to be repeatable.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+fn build_report(
+    samples: &[Sample],
+    queries: &[BenchQuery],
+    elapsed: Duration,
+    opts: &BenchOptions,
+    schema_name: String,
+) -> BenchReport {
+    let mut by_kind: BTreeMap<Kind, Vec<&Sample>> = BTreeMap::new();
+    for sample in samples {
+        by_kind
+            .entry(queries[sample.query].kind)
+            .or_default()
+            .push(sample);
+    }
+    let elapsed_s = elapsed.as_secs_f64();
+
+    BenchReport {
+        server: opts.server.clone(),
+        schema_name,
+        mix: opts.mix.name(),
+        concurrency: opts.concurrency,
+        elapsed_s,
+        throughput_rps: if elapsed_s > 0.0 {
+            samples.len() as f64 / elapsed_s
+        } else {
+            0.0
+        },
+        overall: stats(samples.iter()),
+        by_kind: by_kind
+            .into_iter()
+            .map(|(kind, samples)| (kind, stats(samples.into_iter())))
+            .collect(),
+        first_error: samples.iter().find_map(|s| s.error.clone()),
+    }
+}
+
+fn stats<'a>(samples: impl Iterator<Item = &'a Sample>) -> Stats {
+    let mut errors = 0;
+    let mut latencies: Vec<f64> = samples
+        .map(|s| {
+            if s.error.is_some() {
+                errors += 1;
+            }
+            s.latency.as_secs_f64() * 1000.0
+        })
+        .collect();
+    latencies.sort_by(f64::total_cmp);
+    Stats {
+        requests: latencies.len(),
+        errors,
+        p50_ms: percentile(&latencies, 50.0),
+        p90_ms: percentile(&latencies, 90.0),
+        p95_ms: percentile(&latencies, 95.0),
+        p99_ms: percentile(&latencies, 99.0),
+        max_ms: latencies.last().copied().unwrap_or(0.0),
+    }
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn print_plan(queries: &[BenchQuery], schedule: &[usize], mix: Mix) {
+    println!("Mix: {} ({} requests)", mix.name(), schedule.len());
+    for (i, query) in queries.iter().enumerate() {
+        let count = schedule.iter().filter(|&&q| q == i).count();
+        println!("  {:9} x{:<5} {}", query.kind.label(), count, query.cypher);
+    }
+}
+
+fn print_text(report: &BenchReport) {
+    println!(
+        "Benchmark: {} requests, concurrency {}, mix {} against {} (schema {})",
+        report.overall.requests, report.concurrency, report.mix, report.server, report.schema_name
+    );
+    println!();
+    println!(
+        "{:10} {:>8} {:>7} {:>9} {:>9} {:>9} {:>9} {:>9}",
+        "kind", "requests", "errors", "p50 ms", "p90 ms", "p95 ms", "p99 ms", "max ms"
+    );
+    let row = |label: &str, r: &Stats| {
+        println!(
+            "{:10} {:>8} {:>7} {:>9.1} {:>9.1} {:>9.1} {:>9.1} {:>9.1}",
+            label, r.requests, r.errors, r.p50_ms, r.p90_ms, r.p95_ms, r.p99_ms, r.max_ms
+        );
+    };
+    for (kind, stats) in &report.by_kind {
+        row(kind.label(), stats);
+    }
+    row("all", &report.overall);
+    println!();
+    println!(
+        "Throughput: {:.1} req/s over {:.1}s",
+        report.throughput_rps, report.elapsed_s
+    );
+    if let Some(error) = &report.first_error {
+        println!("First error: {}", error);
+    }
+}
@@ -1,3 +1,5 @@
+pub mod bench;
 pub mod nl;
 pub mod query;
 pub mod schema;
+pub mod verify;
@@ -43,18 +43,7 @@ pub async fn run_query(cypher: &str, sql_only: bool, format: &str, cfg: &CgConfi
         return run_query_databricks(cypher, format, cfg).await;
     }
 
-    let ch_url = cfg
-        .clickhouse_url
-        .as_deref()
-        .ok_or_else(|| anyhow!("No ClickHouse URL. Use --clickhouse or CG_CLICKHOUSE_URL."))?;
-
-    let remote = RemoteConfig {
-        url: ch_url.to_string(),
-        user: cfg.ch_user.clone(),
-        password: cfg.ch_password.clone(),
-        database: cfg.ch_database.clone(),
-        cluster_name: None,
-    };
+    let remote = remote_config(cfg)?;
     let schema_path = path.to_string();
     let cypher = cypher.to_string();
 
@@ -78,6 +67,22 @@ pub async fn run_query(cypher: &str, sql_only: bool, format: &str, cfg: &CgConfi
     Ok(())
 }
 
+/// Remote ClickHouse connection settings from the resolved config.
+pub(crate) fn remote_config(cfg: &CgConfig) -> Result<RemoteConfig> {
+    let ch_url = cfg
+        .clickhouse_url
+        .as_deref()
+        .ok_or_else(|| anyhow!("No ClickHouse URL. Use --clickhouse or CG_CLICKHOUSE_URL."))?;
+
+    Ok(RemoteConfig {
+        url: ch_url.to_string(),
+        user: cfg.ch_user.clone(),
+        password: cfg.ch_password.clone(),
+        database: cfg.ch_database.clone(),
+        cluster_name: None,
+    })
+}
+
 // ── Databricks execution ────────────────────────────────────────────────────
 //
 // `databricks` is a non-default `cg` build feature. When it is off, calling
@@ -269,7 +274,7 @@ fn value_str(v: &Value) -> String {
     }
 }
 
-fn value_to_json(v: &Value) -> serde_json::Value {
+pub(crate) fn value_to_json(v: &Value) -> serde_json::Value {
     match v {
         Value::Null => serde_json::Value::Null,
         Value::Bool(b) => serde_json::Value::Bool(*b),
@@ -0,0 +1,471 @@
+//! `cg verify` — run the same Cypher on Neo4j and on ClickGraph and diff the
+//! results.
+//!
+//! Meant for checking a ClickGraph schema against a Neo4j instance loaded
+//! with the same dataset: every query runs on both sides and the report
+//! lists, per query, whether the results match and which rows one side has
+//! that the other does not.
+//!
+//! Neo4j is reached over its HTTP transactional endpoint
+//! (`POST /db/{database}/tx/commit`), ClickGraph through the same remote
+//! ClickHouse path as `cg query`. Rows are compared as a multiset unless the
+//! query has an `ORDER BY`, in which case order must match too. Values are
+//! compared after normalisation:
+//!
+//! - numbers compare by value (`3` = `3.0`), floats to 10 significant digits;
+//! - numeric strings compare as numbers, since ClickHouse quotes 64-bit
+//!   integers in JSON output (`count()` arrives as `"3"`);
+//! - map keys are order-insensitive, lists are order-sensitive.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Context, Result};
+use clickgraph_embedded::{
+    connection::Connection,
+    database::{Database, RemoteConfig},
+};
+use reqwest::Url;
+use serde::Serialize;
+use serde_json::{json, Value as Json};
+
+use super::query::{remote_config, value_to_json};
+use crate::config::CgConfig;
+use crate::DialectArg;
+
+/// Rows of each kind shown per query in the report.
+const SAMPLE_ROWS: usize = 5;
+
+/// Neo4j connection settings for `cg verify`.
+pub struct Neo4jTarget {
+    /// HTTP(S) base URL, optionally carrying `user:password@`.
+    pub url: String,
+    pub user: Option<String>,
+    pub password: Option<String>,
+    pub database: String,
+}
+
+/// One side's result: column names and rows as JSON values.
+struct ResultSet {
+    columns: Vec<String>,
+    rows: Vec<Vec<Json>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Match,
+    Mismatch,
+    Neo4jError,
+    ClickgraphError,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Match => "MATCH",
+            Status::Mismatch => "MISMATCH",
+            Status::Neo4jError => "NEO4J ERROR",
+            Status::ClickgraphError => "CLICKGRAPH ERROR",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct QueryReport {
+    query: String,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    neo4j_rows: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    clickgraph_rows: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    columns: Option<ColumnDiff>,
+    /// Rows Neo4j returned that ClickGraph did not (first `SAMPLE_ROWS`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    missing: Vec<Json>,
+    /// Rows ClickGraph returned that Neo4j did not (first `SAMPLE_ROWS`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    extra: Vec<Json>,
+    /// First differing row position, for `ORDER BY` queries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_difference: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct ColumnDiff {
+    neo4j: Vec<String>,
+    clickgraph: Vec<String>,
+}
+
+/// `cg verify` — dual-run `queries` (plus any read from `file`) and print a
+/// compatibility report. Fails when any query does not match.
+pub async fn run_verify(
+    queries: &[String],
+    file: Option<&str>,
+    neo4j: &Neo4jTarget,
+    format: &str,
+    cfg: &CgConfig,
+) -> Result<()> {
+    if matches!(cfg.dialect, DialectArg::Databricks) {
+        bail!("`cg verify` runs ClickGraph against ClickHouse; --dialect databricks is not supported.");
+    }
+    let mut queries = queries.to_vec();
+    if let Some(file) = file {
+        let text = std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read query file {}", file))?;
+        queries.extend(split_queries(&text));
+    }
+    if queries.is_empty() {
+        bail!("No queries to verify. Pass them as arguments or with --file.");
+    }
+
+    let schema_path = cfg.require_schema()?.to_string();
+    let remote = remote_config(cfg)?;
+    let (endpoint, auth) = neo4j_endpoint(neo4j)?;
+
+    let clickgraph_results = run_clickgraph(schema_path, remote, queries.clone()).await?;
+
+    let client = reqwest::Client::new();
+    let mut reports = Vec::with_capacity(queries.len());
+    for (query, clickgraph) in queries.iter().zip(clickgraph_results) {
+        let neo4j = run_neo4j(&client, &endpoint, auth.as_ref(), query).await;
+        reports.push(compare(query, neo4j, clickgraph));
+    }
+
+    match format {
+        "json" => print_json(&reports)?,
+        _ => print_text(&reports),
+    }
+
+    let failed = reports.iter().filter(|r| r.status != Status::Match).count();
+    if failed > 0 {
+        bail!("{} of {} queries did not match", failed, reports.len());
+    }
+    Ok(())
+}
+
+/// Split a query file on `;`, dropping `//` comment lines and blank entries.
+fn split_queries(text: &str) -> Vec<String> {
+    let code: String = text
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    code.split(';')
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The transactional commit endpoint and the basic-auth pair to send, taking
+/// credentials from the URL when no explicit user is given.
+fn neo4j_endpoint(target: &Neo4jTarget) -> Result<(Url, Option<(String, String)>)> {
+    let mut url =
+        Url::parse(&target.url).with_context(|| format!("Invalid Neo4j URL {}", target.url))?;
+    match url.scheme() {
+        "http" | "https" => {}
+        scheme if scheme.starts_with("bolt") || scheme.starts_with("neo4j") => bail!(
+            "`cg verify` talks to Neo4j over HTTP; use the HTTP URL (e.g. http://localhost:7474) \
+             instead of {}://",
+            scheme
+        ),
+        scheme => bail!("Unsupported Neo4j URL scheme {}://", scheme),
+    }
+
+    let url_user = (!url.username().is_empty()).then(|| url.username().to_string());
+    let url_password = url.password().map(str::to_string);
+    let auth = match (target.user.clone().or(url_user), target.password.clone()) {
+        (Some(user), Some(password)) => Some((user, password)),
+        (Some(user), None) => Some((user, url_password.unwrap_or_default())),
+        (None, _) => None,
+    };
+    url.set_username("")
+        .and_then(|_| url.set_password(None))
+        .map_err(|_| anyhow!("Invalid Neo4j URL {}", target.url))?;
+
+    if !url.path().ends_with('/') {
+        url.set_path(&format!("{}/", url.path()));
+    }
+    let endpoint = url
+        .join(&format!("db/{}/tx/commit", target.database))
+        .with_context(|| format!("Invalid Neo4j database name {}", target.database))?;
+    Ok((endpoint, auth))
+}
+
+/// Run one query through Neo4j's HTTP API.
+async fn run_neo4j(
+    client: &reqwest::Client,
+    endpoint: &Url,
+    auth: Option<&(String, String)>,
+    query: &str,
+) -> Result<ResultSet, String> {
+    let mut request = client.post(endpoint.clone()).json(&json!({
+        "statements": [{ "statement": query, "resultDataContents": ["row"] }]
+    }));
+    if let Some((user, password)) = auth {
+        request = request.basic_auth(user, Some(password));
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Neo4j request failed: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Neo4j returned HTTP {}: {}", status, body.trim()));
+    }
+    let body: Json = response
+        .json()
+        .await
+        .map_err(|e| format!("Unreadable Neo4j response: {}", e))?;
+
+    if let Some(error) = body["errors"].as_array().and_then(|errors| errors.first()) {
+        return Err(format!(
+            "{}: {}",
+            error["code"].as_str().unwrap_or("Neo4j error"),
+            error["message"].as_str().unwrap_or_default()
+        ));
+    }
+    let result = &body["results"][0];
+    let columns = result["columns"]
+        .as_array()
+        .ok_or("Neo4j response has no result columns")?
+        .iter()
+        .map(|c| c.as_str().unwrap_or_default().to_string())
+        .collect();
+    let rows = result["data"]
+        .as_array()
+        .map(|data| {
+            data.iter()
+                .map(|record| record["row"].as_array().cloned().unwrap_or_default())
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(ResultSet { columns, rows })
+}
+
+/// Run every query on ClickGraph over one remote connection.
+async fn run_clickgraph(
+    schema_path: String,
+    remote: RemoteConfig,
+    queries: Vec<String>,
+) -> Result<Vec<Result<ResultSet, String>>> {
+    tokio::task::spawn_blocking(move || {
+        let db = Database::new_remote(&schema_path, remote)
+            .map_err(|e| anyhow!("Failed to connect to ClickHouse: {}", e))?;
+        let conn = Connection::new(&db).map_err(|e| anyhow!("{}", e))?;
+        Ok(queries
+            .iter()
+            .map(|query| {
+                let result = conn.query_remote(query).map_err(|e| e.to_string())?;
+                let columns = result.get_column_names().to_vec();
+                let rows = result
+                    .map(|row| row.values().iter().map(value_to_json).collect())
+                    .collect();
+                Ok(ResultSet { columns, rows })
+            })
+            .collect())
+    })
+    .await?
+}
+
+fn compare(
+    query: &str,
+    neo4j: Result<ResultSet, String>,
+    clickgraph: Result<ResultSet, String>,
+) -> QueryReport {
+    let mut report = QueryReport {
+        query: query.to_string(),
+        status: Status::Match,
+        error: None,
+        neo4j_rows: None,
+        clickgraph_rows: None,
+        columns: None,
+        missing: Vec::new(),
+        extra: Vec::new(),
+        first_difference: None,
+    };
+    let (neo4j, clickgraph) = match (neo4j, clickgraph) {
+        (Err(e), _) => {
+            report.status = Status::Neo4jError;
+            report.error = Some(e);
+            return report;
+        }
+        (_, Err(e)) => {
+            report.status = Status::ClickgraphError;
+            report.error = Some(e);
+            return report;
+        }
+        (Ok(neo4j), Ok(clickgraph)) => (neo4j, clickgraph),
+    };
+    report.neo4j_rows = Some(neo4j.rows.len());
+    report.clickgraph_rows = Some(clickgraph.rows.len());
+
+    if neo4j.columns != clickgraph.columns {
+        report.status = Status::Mismatch;
+        report.columns = Some(ColumnDiff {
+            neo4j: neo4j.columns.clone(),
+            clickgraph: clickgraph.columns.clone(),
+        });
+    }
+
+    let neo4j_keys: Vec<String> = neo4j.rows.iter().map(|r| row_key(r)).collect();
+    let clickgraph_keys: Vec<String> = clickgraph.rows.iter().map(|r| row_key(r)).collect();
+
+    if is_ordered(query) {
+        let first = (0..neo4j_keys.len().max(clickgraph_keys.len()))
+            .find(|&i| neo4j_keys.get(i) != clickgraph_keys.get(i));
+        if let Some(i) = first {
+            report.status = Status::Mismatch;
+            report.first_difference = Some(i);
+            report.missing = neo4j
+                .rows
+                .get(i)
+                .map(|r| row_json(&neo4j.columns, r))
+                .into_iter()
+                .collect();
+            report.extra = clickgraph
+                .rows
+                .get(i)
+                .map(|r| row_json(&clickgraph.columns, r))
+                .into_iter()
+                .collect();
+        }
+        return report;
+    }
+
+    // Multiset difference: each ClickGraph row cancels one equal Neo4j row.
+    let mut remaining: HashMap<&str, usize> = HashMap::new();
+    for key in &neo4j_keys {
+        *remaining.entry(key).or_default() += 1;
+    }
+    for (key, row) in clickgraph_keys.iter().zip(&clickgraph.rows) {
+        match remaining.get_mut(key.as_str()) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => report.extra.push(row_json(&clickgraph.columns, row)),
+        }
+    }
+    for (key, row) in neo4j_keys.iter().zip(&neo4j.rows) {
+        if let Some(count) = remaining.get_mut(key.as_str()).filter(|c| **c > 0) {
+            *count -= 1;
+            report.missing.push(row_json(&neo4j.columns, row));
+        }
+    }
+    if !report.missing.is_empty() || !report.extra.is_empty() {
+        report.status = Status::Mismatch;
+    }
+    report.missing.truncate(SAMPLE_ROWS);
+    report.extra.truncate(SAMPLE_ROWS);
+    report
+}
+
+/// Whether result order is part of the query's contract.
+fn is_ordered(query: &str) -> bool {
+    query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .windows(2)
+        .any(|w| w[0].eq_ignore_ascii_case("ORDER") && w[1].eq_ignore_ascii_case("BY"))
+}
+
+fn row_json(columns: &[String], row: &[Json]) -> Json {
+    Json::Object(columns.iter().cloned().zip(row.iter().cloned()).collect())
+}
+
+fn row_key(row: &[Json]) -> String {
+    row.iter().map(canonical).collect::<Vec<_>>().join("\u{1f}")
+}
+
+/// A comparison key for one value, per the normalisation rules in the module
+/// docs.
+fn canonical(value: &Json) -> String {
+    match value {
+        Json::Null => "null".to_string(),
+        Json::Bool(b) => b.to_string(),
+        Json::Number(n) => n
+            .as_f64()
+            .map(canonical_number)
+            .unwrap_or_else(|| n.to_string()),
+        Json::String(s) => match s.parse::<f64>() {
+            Ok(f) if f.is_finite() => canonical_number(f),
+            _ => Json::String(s.clone()).to_string(),
+        },
+        Json::Array(items) => format!(
+            "[{}]",
+            items.iter().map(canonical).collect::<Vec<_>>().join(",")
+        ),
+        Json::Object(map) => {
+            let mut entries: Vec<String> = map
+                .iter()
+                .map(|(k, v)| format!("{}:{}", Json::String(k.clone()), canonical(v)))
+                .collect();
+            entries.sort();
+            format!("{{{}}}", entries.join(","))
+        }
+    }
+}
+
+fn canonical_number(f: f64) -> String {
+    if f.fract() == 0.0 && f.abs() < 1e15 {
+        format!("{}", f as i64)
+    } else {
+        format!("{:.9e}", f)
+    }
+}
+
+fn print_text(reports: &[QueryReport]) {
+    for report in reports {
+        let rows = match (report.neo4j_rows, report.clickgraph_rows) {
+            (Some(n), Some(c)) if n == c => format!(" ({} rows)", n),
+            (Some(n), Some(c)) => format!(" (neo4j {} rows, clickgraph {} rows)", n, c),
+            _ => String::new(),
+        };
+        println!("[{}] {}{}", report.status.label(), report.query, rows);
+        if let Some(error) = &report.error {
+            println!("    {}", error);
+        }
+        if let Some(columns) = &report.columns {
+            println!(
+                "    columns differ: neo4j {:?}, clickgraph {:?}",
+                columns.neo4j, columns.clickgraph
+            );
+        }
+        if let Some(i) = report.first_difference {
+            println!("    first difference at row {}", i);
+        }
+        for row in &report.missing {
+            println!("    - neo4j only:      {}", row);
+        }
+        for row in &report.extra {
+            println!("    + clickgraph only: {}", row);
+        }
+    }
+    let count = |status| reports.iter().filter(|r| r.status == status).count();
+    println!(
+        "\n{} queries: {} match, {} mismatch, {} neo4j error, {} clickgraph error",
+        reports.len(),
+        count(Status::Match),
+        count(Status::Mismatch),
+        count(Status::Neo4jError),
+        count(Status::ClickgraphError)
+    );
+}
+
+fn print_json(reports: &[QueryReport]) -> Result<()> {
+    let count = |status| reports.iter().filter(|r| r.status == status).count();
+    let report = json!({
+        "summary": {
+            "total": reports.len(),
+            "match": count(Status::Match),
+            "mismatch": count(Status::Mismatch),
+            "neo4j_error": count(Status::Neo4jError),
+            "clickgraph_error": count(Status::ClickgraphError),
+        },
+        "queries": reports,
+    });
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
@@ -0,0 +1,175 @@
+//! Integration tests for `cg bench` — generating a query mix from the schema
+//! and running it against a ClickGraph server.
+//!
+//! The server is a `wiremock` stand-in for `POST /query`, so the real `cg`
+//! binary runs its full schedule/send/report path.
+
+use std::io::Write;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::{json, Value};
+use tempfile::NamedTempFile;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SOCIAL_YAML: &str = r#"
+name: cg_bench_test
+graph_schema:
+  nodes:
+    - label: User
+      database: test_db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+    - label: Post
+      database: test_db
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: test_db
+      table: follows
+      from_node: User
+      to_node: User
+      from_id: follower_id
+      to_id: followed_id
+      property_mappings: {}
+    - type: AUTHORED
+      database: test_db
+      table: posts
+      from_node: User
+      to_node: Post
+      from_id: author_id
+      to_id: post_id
+      property_mappings: {}
+"#;
+
+fn write_schema() -> NamedTempFile {
+    let mut f = NamedTempFile::new().expect("tempfile");
+    f.write_all(SOCIAL_YAML.as_bytes()).expect("write");
+    f.flush().expect("flush");
+    f
+}
+
+fn cg(schema: &NamedTempFile) -> Command {
+    let mut cmd = Command::cargo_bin("cg").expect("cg binary");
+    cmd.env_remove("CG_SERVER_URL")
+        .arg("--schema")
+        .arg(schema.path());
+    cmd
+}
+
+#[test]
+fn dry_run_lists_every_query_kind() {
+    let schema = write_schema();
+    cg(&schema)
+        .args(["bench", "--dry-run", "--mix", "uniform"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "MATCH (a:User)-[:FOLLOWS]->(b:User) RETURN a.name, b.name LIMIT 100",
+        ))
+        .stdout(predicate::str::contains(
+            "MATCH (a:User)-[:FOLLOWS]->(b:User)-[:FOLLOWS]->(c:User)-[:AUTHORED]->(d:Post)",
+        ))
+        .stdout(predicate::str::contains("-[:FOLLOWS*1..3]->"))
+        .stdout(predicate::str::contains(
+            "MATCH (n:User) RETURN count(n) AS total",
+        ));
+}
+
+#[test]
+fn same_seed_gives_the_same_schedule() {
+    let schema = write_schema();
+    let plan = |seed: &str| {
+        cg(&schema)
+            .args(["bench", "--dry-run", "--seed", seed])
+            .output()
+            .expect("run cg")
+            .stdout
+    };
+    assert_eq!(plan("7"), plan("7"));
+    assert_ne!(plan("7"), plan("8"));
+}
+
+#[test]
+fn unknown_mix_is_rejected() {
+    let schema = write_schema();
+    cg(&schema)
+        .args(["bench", "--dry-run", "--mix", "write-heavy"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown mix 'write-heavy'"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reports_latency_percentiles_for_every_request() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/query"))
+        .and(body_partial_json(json!({ "schema_name": "cg_bench_test" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "results": [] })))
+        .expect(25)
+        .mount(&server)
+        .await;
+
+    let schema = write_schema();
+    let output = cg(&schema)
+        .args(["bench", "--server", &server.uri()])
+        .args(["-c", "3", "-n", "20", "--warmup", "5", "--format", "json"])
+        .output()
+        .expect("run cg");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let report: Value = serde_json::from_slice(&output.stdout).expect("json report");
+    assert_eq!(report["mix"], "read-heavy");
+    assert_eq!(report["overall"]["requests"], 20);
+    assert_eq!(report["overall"]["errors"], 0);
+    assert!(
+        report["overall"]["p99_ms"].as_f64().unwrap()
+            >= report["overall"]["p50_ms"].as_f64().unwrap()
+    );
+    let per_kind: u64 = report["by_kind"]
+        .as_object()
+        .expect("by_kind")
+        .values()
+        .map(|k| k["requests"].as_u64().unwrap())
+        .sum();
+    assert_eq!(per_kind, 20);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fails_when_every_request_fails() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/query"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("Clickhouse Error: boom"))
+        .mount(&server)
+        .await;
+
+    let schema = write_schema();
+    cg(&schema)
+        .args([
+            "bench",
+            "--server",
+            &server.uri(),
+            "-n",
+            "4",
+            "--warmup",
+            "0",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("First error: HTTP 500"))
+        .stderr(predicate::str::contains("All 4 requests failed"));
+}
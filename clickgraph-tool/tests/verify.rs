@@ -0,0 +1,224 @@
+//! Integration tests for `cg verify` — dual-running Cypher on Neo4j and on
+//! ClickGraph and diffing the results.
+//!
+//! Both backends are `wiremock` servers: one stands in for Neo4j's HTTP
+//! transactional endpoint, the other for ClickHouse's HTTP interface, so the
+//! real `cg` binary runs its full request/compare/report path.
+
+use std::io::Write;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::json;
+use tempfile::NamedTempFile;
+use wiremock::matchers::{basic_auth, body_string_contains, method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+const SOCIAL_YAML: &str = r#"
+name: cg_verify_test
+graph_schema:
+  nodes:
+    - label: User
+      database: test_db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+  edges:
+    - type: FOLLOWS
+      database: test_db
+      table: follows
+      from_node: User
+      to_node: User
+      from_id: follower_id
+      to_id: followed_id
+      property_mappings: {}
+"#;
+
+const NAMES: &str = "MATCH (u:User) RETURN u.name AS name";
+const COUNT: &str = "MATCH (u:User) RETURN count(u) AS n";
+
+fn write_schema() -> NamedTempFile {
+    let mut f = NamedTempFile::new().expect("tempfile");
+    f.write_all(SOCIAL_YAML.as_bytes()).expect("write");
+    f.flush().expect("flush");
+    f
+}
+
+/// A ClickHouse stand-in answering `count(` SQL with `count_rows` and any
+/// other SQL with `name_rows`, as JSONEachRow.
+async fn clickhouse(name_rows: &'static str, count_rows: &'static str) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(path("/"))
+        .respond_with(move |request: &Request| {
+            let mut sql: String = request
+                .url
+                .query_pairs()
+                .filter(|(k, _)| k == "query")
+                .map(|(_, v)| v.into_owned())
+                .collect();
+            sql.push_str(&String::from_utf8_lossy(&request.body));
+            let body = if sql.contains("count(") {
+                count_rows
+            } else {
+                name_rows
+            };
+            // The client asks for LZ4-framed responses unless built with the
+            // crate's test utilities; answer in whichever form it requested.
+            let compressed = request
+                .url
+                .query_pairs()
+                .any(|(k, v)| k == "compress" && v == "1");
+            if compressed {
+                let bytes = clickhouse::_priv::lz4_compress(body.as_bytes()).expect("lz4");
+                ResponseTemplate::new(200).set_body_bytes(bytes.to_vec())
+            } else {
+                ResponseTemplate::new(200).set_body_string(body)
+            }
+        })
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn neo4j() -> MockServer {
+    let server = MockServer::start().await;
+    for (statement, columns, rows) in [
+        (NAMES, json!(["name"]), json!([["alice"], ["bob"]])),
+        (COUNT, json!(["n"]), json!([[2]])),
+    ] {
+        let data: Vec<_> = rows
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| json!({ "row": row, "meta": [null] }))
+            .collect();
+        Mock::given(method("POST"))
+            .and(path("/db/neo4j/tx/commit"))
+            .and(basic_auth("neo4j", "secret"))
+            .and(body_string_contains(statement))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [{ "columns": columns, "data": data }],
+                "errors": []
+            })))
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/db/neo4j/tx/commit"))
+        .and(body_string_contains("RETURN nope"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [],
+            "errors": [{
+                "code": "Neo.ClientError.Statement.SyntaxError",
+                "message": "Variable `nope` not defined"
+            }]
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+fn verify(schema: &NamedTempFile, clickhouse: &MockServer, neo4j: &MockServer) -> Command {
+    let neo4j_url = neo4j.uri().replace("http://", "http://neo4j:secret@");
+    let mut cmd = Command::cargo_bin("cg").expect("bin");
+    cmd.env_remove("CG_NEO4J_USER")
+        .env_remove("CG_NEO4J_PASSWORD")
+        .arg("--schema")
+        .arg(schema.path())
+        .arg("--clickhouse")
+        .arg(clickhouse.uri())
+        .arg("verify")
+        .arg("--neo4j")
+        .arg(neo4j_url);
+    cmd
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn verify_reports_match_ignoring_row_order_and_quoted_integers() {
+    // ClickHouse returns the names in the other order and `count()` as a
+    // quoted UInt64; neither is a difference.
+    let clickhouse = clickhouse(
+        "{\"name\":\"bob\"}\n{\"name\":\"alice\"}\n",
+        "{\"n\":\"2\"}\n",
+    )
+    .await;
+    let neo4j = neo4j().await;
+    let schema = write_schema();
+
+    verify(&schema, &clickhouse, &neo4j)
+        .arg(NAMES)
+        .arg(COUNT)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "[MATCH] {} (2 rows)",
+            NAMES
+        )))
+        .stdout(predicate::str::contains(
+            "2 queries: 2 match, 0 mismatch, 0 neo4j error, 0 clickgraph error",
+        ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn verify_reports_row_differences_and_errors_as_json() {
+    let clickhouse = clickhouse(
+        "{\"name\":\"alice\"}\n{\"name\":\"carol\"}\n",
+        "{\"n\":\"2\"}\n",
+    )
+    .await;
+    let neo4j = neo4j().await;
+    let schema = write_schema();
+    let mut file = NamedTempFile::new().expect("tempfile");
+    writeln!(
+        file,
+        "// smoke set\n{};\nMATCH (u:User) RETURN nope;",
+        NAMES
+    )
+    .expect("write");
+
+    let output = verify(&schema, &clickhouse, &neo4j)
+        .arg("--file")
+        .arg(file.path())
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("2 of 2 queries did not match"))
+        .get_output()
+        .stdout
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).expect("json report");
+
+    assert_eq!(report["summary"]["mismatch"], 1, "{report}");
+    assert_eq!(report["summary"]["neo4j_error"], 1, "{report}");
+    let names = &report["queries"][0];
+    assert_eq!(names["status"], "mismatch");
+    assert_eq!(names["missing"], json!([{ "name": "bob" }]));
+    assert_eq!(names["extra"], json!([{ "name": "carol" }]));
+    let error = &report["queries"][1];
+    assert_eq!(error["status"], "neo4j_error");
+    assert!(
+        error["error"].as_str().unwrap().contains("SyntaxError"),
+        "{report}"
+    );
+}
+
+#[test]
+fn verify_rejects_bolt_urls() {
+    let schema = write_schema();
+    Command::cargo_bin("cg")
+        .expect("bin")
+        .arg("--schema")
+        .arg(schema.path())
+        .arg("--clickhouse")
+        .arg("http://127.0.0.1:1")
+        .arg("verify")
+        .arg("--neo4j")
+        .arg("bolt://localhost:7687")
+        .arg(NAMES)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("use the HTTP URL"));
+}
@@ -1,3 +1,5 @@
+use crate::server::recursion_depth::RecursionDepthPolicy;
+use crate::utils::redaction::LiteralRedaction;
 use serde::{Deserialize, Serialize};
 use std::env;
 use thiserror::Error;
@@ -120,6 +122,11 @@ pub struct ServerConfig {
     /// where query text may carry sensitive values.
     pub metrics_query_preview: bool,
 
+    /// Capacity of the in-memory workload log: the traversals of recent
+    /// successful read queries, analyzed by
+    /// `/schemas/{name}/denormalization-advice`. 0 = off. Default: 0.
+    pub workload_log_capacity: usize,
+
     /// S1 stats-informed planning (`docs/design/STATS_PLANNING.md`): fetch
     /// per-table row counts from `system.tables` and let the planner rank
     /// semantically-equivalent anchor/FROM choices by table size. Ordering
@@ -133,6 +140,193 @@ pub struct ServerConfig {
     /// (`CLICKGRAPH_STATS_TTL_SECS`). Default: 300.
     #[serde(default = "default_stats_ttl_secs")]
     pub stats_ttl_secs: u64,
+
+    /// Roles trusted to bypass per-label `max_unfiltered_rows` guards
+    /// (`CLICKGRAPH_TRUSTED_ROLES`, comma-separated). Matched against the
+    /// request / Bolt RUN `role`. Default: none.
+    #[serde(default)]
+    pub trusted_roles: Vec<String>,
+
+    /// ClickHouse settings a request may set for its own queries
+    /// (`CLICKGRAPH_ALLOWED_SETTINGS`, comma-separated), e.g.
+    /// `max_memory_usage,max_execution_time,join_algorithm`. Default: none.
+    #[serde(default)]
+    pub allowed_settings: Vec<String>,
+
+    /// Inject `ORDER BY <node id>` into unordered Bolt queries so PULL batch
+    /// boundaries are stable across PULL messages and retries
+    /// (`CLICKGRAPH_BOLT_STABLE_ORDER`). Default: false.
+    #[serde(default)]
+    pub bolt_stable_order: bool,
+
+    /// `host:port` advertised in Bolt ROUTE responses for `neo4j://` drivers
+    /// (`CLICKGRAPH_BOLT_ADVERTISED_ADDRESS`). Needed behind NAT, proxies or
+    /// containers; when unset, the address the driver connected to is used.
+    #[serde(default)]
+    pub bolt_advertised_address: Option<String>,
+
+    /// Poll the YAML at `GRAPH_CONFIG_PATH` every N seconds and hot-reload it
+    /// when its modification time changes (`CLICKGRAPH_SCHEMA_WATCH_SECS`).
+    /// Default: 0 (off; reload with `POST /schemas/reload` instead).
+    #[serde(default)]
+    pub schema_watch_secs: u64,
+
+    /// Allow queries whose pattern parts are disconnected, i.e. a cartesian
+    /// product (`CLICKGRAPH_ALLOW_CARTESIAN_PRODUCT`). When false, such queries
+    /// fail unless prefixed with `CYPHER cartesian=allow`. Default: false.
+    #[serde(default)]
+    pub allow_cartesian_product: bool,
+
+    /// Accept `schema_overrides` on `/query`, which merges an inline mapping
+    /// into the graph's schema for one request
+    /// (`CLICKGRAPH_ALLOW_SCHEMA_OVERRIDES`). When false, such requests fail
+    /// with 403. Default: false.
+    #[serde(default)]
+    pub allow_schema_overrides: bool,
+
+    /// Let `CALL graph.construct` run its INSERTs
+    /// (`CLICKGRAPH_ALLOW_GRAPH_CONSTRUCT`). When false, only `sql_only`
+    /// calls are served and the rest fail with 403. Default: false.
+    #[serde(default)]
+    pub allow_graph_construct: bool,
+
+    /// Directory for spilling Bolt results that clients PULL in batches
+    /// (`CLICKGRAPH_BOLT_SPILL_DIR`). Rows past `bolt_spill_threshold_rows`
+    /// are written to a temporary file there instead of staying in memory
+    /// until fetched. Default: unset (no spilling).
+    #[serde(default)]
+    pub bolt_spill_dir: Option<String>,
+
+    /// Rows of a Bolt result kept in memory before the rest spill to disk
+    /// (`CLICKGRAPH_BOLT_SPILL_THRESHOLD_ROWS`). Default: 10000.
+    #[serde(default = "default_bolt_spill_threshold_rows")]
+    pub bolt_spill_threshold_rows: usize,
+
+    /// Maximum size of one result's spill file in bytes; larger results fail
+    /// (`CLICKGRAPH_BOLT_SPILL_MAX_BYTES`). Default: 1 GiB.
+    #[serde(default = "default_bolt_spill_max_bytes")]
+    pub bolt_spill_max_bytes: u64,
+
+    /// Replace string and number literals in Cypher text and generated SQL
+    /// before they are logged, kept in the slow-query ring or returned in
+    /// error messages: `off`, `elide` (`?`) or `hash` (short SHA-256)
+    /// (`CLICKGRAPH_REDACT_LITERALS`). Default: off.
+    #[serde(default)]
+    pub redact_literals: LiteralRedaction,
+
+    /// What a query that ClickHouse aborts at `max_cte_depth` returns:
+    /// `error` (an actionable message instead of the raw exception) or
+    /// `unroll` (re-plan with paths cut at `vlp_fallback_hops` and return
+    /// those rows with a notification) (`CLICKGRAPH_RECURSION_DEPTH_POLICY`).
+    /// Default: error.
+    #[serde(default)]
+    pub recursion_depth_policy: RecursionDepthPolicy,
+
+    /// Hop bound of the `unroll` fallback plan
+    /// (`CLICKGRAPH_VLP_FALLBACK_HOPS`). Default: 5.
+    #[serde(default = "default_vlp_fallback_hops")]
+    #[validate(range(
+        min = 1,
+        max = 32,
+        message = "VLP fallback hops must be between 1 and 32"
+    ))]
+    pub vlp_fallback_hops: u32,
+
+    /// PEM certificate chain served by the HTTP and Bolt listeners
+    /// (`CLICKGRAPH_TLS_CERT`). With `tls_key_path`, both listeners accept
+    /// TLS connections only. Default: unset (plaintext).
+    #[serde(default)]
+    pub tls_cert_path: Option<String>,
+
+    /// PEM private key of `tls_cert_path` (`CLICKGRAPH_TLS_KEY`).
+    #[serde(default)]
+    pub tls_key_path: Option<String>,
+
+    /// PEM CA certificates that client certificates must be issued by
+    /// (`CLICKGRAPH_TLS_CLIENT_CA`). Setting it turns on mutual TLS.
+    /// Default: unset (no client certificates).
+    #[serde(default)]
+    pub tls_client_ca_path: Option<String>,
+
+    /// Serve `POST /query/natural`, which turns a question into Cypher with
+    /// the configured LLM (`CLICKGRAPH_NL_QUERY`). The API key is read from
+    /// `ANTHROPIC_API_KEY` / `OPENAI_API_KEY`, never from this config.
+    /// Default: false (the endpoint answers 404).
+    #[serde(default)]
+    pub nl_query_enabled: bool,
+
+    /// LLM API format: `anthropic` or `openai` for any OpenAI-compatible
+    /// server (`CLICKGRAPH_LLM_PROVIDER`). Default: anthropic.
+    #[serde(default)]
+    pub llm_provider: Option<String>,
+
+    /// Model asked to write Cypher (`CLICKGRAPH_LLM_MODEL`). Default: the
+    /// provider's default model.
+    #[serde(default)]
+    pub llm_model: Option<String>,
+
+    /// LLM endpoint URL (`CLICKGRAPH_LLM_API_URL`), e.g. a local Ollama or
+    /// vLLM server, which then needs no API key. Default: the provider's API.
+    #[serde(default)]
+    pub llm_api_url: Option<String>,
+
+    /// ClickHouse cluster whose replicas' `system.query_log` resource
+    /// reports read (`CLICKGRAPH_QUERY_LOG_CLUSTER`). Default: unset (the
+    /// connected node's log only).
+    #[serde(default)]
+    pub query_log_cluster: Option<String>,
+
+    /// Table (`table` or `database.table`) that per-tenant resource usage
+    /// is exported to on every `attribution_export_secs` boundary
+    /// (`CLICKGRAPH_ATTRIBUTION_TABLE`). Created if missing. Default: unset
+    /// (no export).
+    #[serde(default)]
+    pub attribution_table: Option<String>,
+
+    /// Bucket size of the attribution export, in seconds
+    /// (`CLICKGRAPH_ATTRIBUTION_EXPORT_SECS`). Default: 3600.
+    #[serde(default = "default_attribution_export_secs")]
+    #[validate(range(
+        min = 60,
+        message = "Attribution export interval must be at least 60 seconds"
+    ))]
+    pub attribution_export_secs: u64,
+
+    /// How often, in seconds, schema elements marked `lazy` whose table is
+    /// still missing or invalid are checked again, both by the background
+    /// completion task and by queries (`CLICKGRAPH_LAZY_SCHEMA_RETRY_SECS`).
+    /// Default: 30; 0 leaves completion to queries only.
+    #[serde(default = "default_lazy_schema_retry_secs")]
+    pub lazy_schema_retry_secs: u64,
+
+    /// Retries of a read query that failed with a transient ClickHouse error
+    /// (network errors, `TOO_MANY_SIMULTANEOUS_QUERIES`, ...), with
+    /// exponential backoff from `read_retry_base_ms`
+    /// (`CLICKGRAPH_READ_RETRIES`). Remote mode only. Default: 2; 0 = off.
+    #[serde(default = "default_read_retries")]
+    pub read_retries: u32,
+
+    /// First backoff of a read retry in milliseconds, doubled per attempt
+    /// (`CLICKGRAPH_READ_RETRY_BASE_MS`). Default: 100.
+    #[serde(default = "default_read_retry_base_ms")]
+    pub read_retry_base_ms: u64,
+
+    /// Deterministic test mode (`CLICKGRAPH_TEST_MODE`): `rand()` is seeded
+    /// with `test_seed`, current-time functions return `test_frozen_time`
+    /// and generated aliases and CTE names are numbered per query, so a
+    /// query renders byte-identical SQL on every run. Default: false.
+    #[serde(default)]
+    pub test_mode: bool,
+
+    /// Seed of `rand()` in test mode (`CLICKGRAPH_TEST_SEED`). Default: 0.
+    #[serde(default)]
+    pub test_seed: u64,
+
+    /// The instant current-time functions return in test mode
+    /// (`CLICKGRAPH_TEST_FROZEN_TIME`, RFC 3339). Default:
+    /// 2000-01-01T00:00:00Z.
+    #[serde(default = "default_test_frozen_time")]
+    pub test_frozen_time: chrono::DateTime<chrono::Utc>,
 }
 
 impl Default for ServerConfig {
@@ -157,8 +351,39 @@ impl Default for ServerConfig {
             slow_query_threshold_ms: 0,
             metrics_ch_summary: false,
             metrics_query_preview: false,
+            workload_log_capacity: 0,
             stats_enabled: false,
             stats_ttl_secs: 300,
+            trusted_roles: Vec::new(),
+            allowed_settings: Vec::new(),
+            bolt_stable_order: false,
+            bolt_advertised_address: None,
+            schema_watch_secs: 0,
+            allow_cartesian_product: false,
+            allow_schema_overrides: false,
+            allow_graph_construct: false,
+            bolt_spill_dir: None,
+            bolt_spill_threshold_rows: 10_000,
+            bolt_spill_max_bytes: 1 << 30,
+            redact_literals: LiteralRedaction::Off,
+            recursion_depth_policy: RecursionDepthPolicy::Error,
+            vlp_fallback_hops: 5,
+            tls_cert_path: None,
+            tls_key_path: None,
+            tls_client_ca_path: None,
+            nl_query_enabled: false,
+            llm_provider: None,
+            llm_model: None,
+            llm_api_url: None,
+            query_log_cluster: None,
+            attribution_table: None,
+            attribution_export_secs: 3600,
+            lazy_schema_retry_secs: 30,
+            read_retries: 2,
+            read_retry_base_ms: 100,
+            test_mode: false,
+            test_seed: 0,
+            test_frozen_time: default_test_frozen_time(),
         }
     }
 }
@@ -186,8 +411,45 @@ impl ServerConfig {
             slow_query_threshold_ms: parse_env_var("CLICKGRAPH_SLOW_QUERY_THRESHOLD_MS", "0")?,
             metrics_ch_summary: parse_env_var("CLICKGRAPH_METRICS_CH_SUMMARY", "false")?,
             metrics_query_preview: parse_env_var("CLICKGRAPH_METRICS_QUERY_PREVIEW", "false")?,
+            workload_log_capacity: parse_env_var("CLICKGRAPH_WORKLOAD_LOG_CAPACITY", "0")?,
             stats_enabled: parse_env_var("CLICKGRAPH_STATS_ENABLED", "false")?,
             stats_ttl_secs: parse_env_var("CLICKGRAPH_STATS_TTL_SECS", "300")?,
+            trusted_roles: parse_env_list("CLICKGRAPH_TRUSTED_ROLES"),
+            allowed_settings: parse_env_list("CLICKGRAPH_ALLOWED_SETTINGS"),
+            bolt_stable_order: parse_env_var("CLICKGRAPH_BOLT_STABLE_ORDER", "false")?,
+            bolt_advertised_address: env::var("CLICKGRAPH_BOLT_ADVERTISED_ADDRESS").ok(),
+            schema_watch_secs: parse_env_var("CLICKGRAPH_SCHEMA_WATCH_SECS", "0")?,
+            allow_cartesian_product: parse_env_var("CLICKGRAPH_ALLOW_CARTESIAN_PRODUCT", "false")?,
+            allow_schema_overrides: parse_env_var("CLICKGRAPH_ALLOW_SCHEMA_OVERRIDES", "false")?,
+            allow_graph_construct: parse_env_var("CLICKGRAPH_ALLOW_GRAPH_CONSTRUCT", "false")?,
+            bolt_spill_dir: env::var("CLICKGRAPH_BOLT_SPILL_DIR").ok(),
+            bolt_spill_threshold_rows: parse_env_var(
+                "CLICKGRAPH_BOLT_SPILL_THRESHOLD_ROWS",
+                "10000",
+            )?,
+            bolt_spill_max_bytes: parse_env_var("CLICKGRAPH_BOLT_SPILL_MAX_BYTES", "1073741824")?,
+            redact_literals: parse_env_var("CLICKGRAPH_REDACT_LITERALS", "off")?,
+            recursion_depth_policy: parse_env_var("CLICKGRAPH_RECURSION_DEPTH_POLICY", "error")?,
+            vlp_fallback_hops: parse_env_var("CLICKGRAPH_VLP_FALLBACK_HOPS", "5")?,
+            tls_cert_path: env::var("CLICKGRAPH_TLS_CERT").ok(),
+            tls_key_path: env::var("CLICKGRAPH_TLS_KEY").ok(),
+            tls_client_ca_path: env::var("CLICKGRAPH_TLS_CLIENT_CA").ok(),
+            nl_query_enabled: parse_env_var("CLICKGRAPH_NL_QUERY", "false")?,
+            llm_provider: env::var("CLICKGRAPH_LLM_PROVIDER").ok(),
+            llm_model: env::var("CLICKGRAPH_LLM_MODEL").ok(),
+            llm_api_url: env::var("CLICKGRAPH_LLM_API_URL").ok(),
+            query_log_cluster: env::var("CLICKGRAPH_QUERY_LOG_CLUSTER").ok(),
+            attribution_table: env::var("CLICKGRAPH_ATTRIBUTION_TABLE").ok(),
+            attribution_export_secs: parse_env_var("CLICKGRAPH_ATTRIBUTION_EXPORT_SECS", "3600")?,
+            lazy_schema_retry_secs: parse_env_var("CLICKGRAPH_LAZY_SCHEMA_RETRY_SECS", "30")?,
+            read_retries: parse_env_var("CLICKGRAPH_READ_RETRIES", "2")?,
+            read_retry_base_ms: parse_env_var("CLICKGRAPH_READ_RETRY_BASE_MS", "100")?,
+            test_mode: parse_env_var("CLICKGRAPH_TEST_MODE", "false")?,
+            test_seed: parse_env_var("CLICKGRAPH_TEST_SEED", "0")?,
+            test_frozen_time: parse_env_var(
+                "CLICKGRAPH_TEST_FROZEN_TIME",
+                DEFAULT_TEST_FROZEN_TIME,
+            )?,
         };
 
         config.validate()?;
@@ -219,9 +481,47 @@ impl ServerConfig {
             slow_query_threshold_ms: parse_env_var("CLICKGRAPH_SLOW_QUERY_THRESHOLD_MS", "0")?,
             metrics_ch_summary: parse_env_var("CLICKGRAPH_METRICS_CH_SUMMARY", "false")?,
             metrics_query_preview: parse_env_var("CLICKGRAPH_METRICS_QUERY_PREVIEW", "false")?,
+            workload_log_capacity: parse_env_var("CLICKGRAPH_WORKLOAD_LOG_CAPACITY", "0")?,
             // Stats knobs are operational and env-only, like the metrics knobs.
             stats_enabled: parse_env_var("CLICKGRAPH_STATS_ENABLED", "false")?,
             stats_ttl_secs: parse_env_var("CLICKGRAPH_STATS_TTL_SECS", "300")?,
+            // Access knobs are env-only, like the metrics knobs.
+            trusted_roles: parse_env_list("CLICKGRAPH_TRUSTED_ROLES"),
+            allowed_settings: parse_env_list("CLICKGRAPH_ALLOWED_SETTINGS"),
+            bolt_stable_order: parse_env_var("CLICKGRAPH_BOLT_STABLE_ORDER", "false")?,
+            bolt_advertised_address: env::var("CLICKGRAPH_BOLT_ADVERTISED_ADDRESS").ok(),
+            schema_watch_secs: parse_env_var("CLICKGRAPH_SCHEMA_WATCH_SECS", "0")?,
+            allow_cartesian_product: parse_env_var("CLICKGRAPH_ALLOW_CARTESIAN_PRODUCT", "false")?,
+            allow_schema_overrides: parse_env_var("CLICKGRAPH_ALLOW_SCHEMA_OVERRIDES", "false")?,
+            allow_graph_construct: parse_env_var("CLICKGRAPH_ALLOW_GRAPH_CONSTRUCT", "false")?,
+            bolt_spill_dir: env::var("CLICKGRAPH_BOLT_SPILL_DIR").ok(),
+            bolt_spill_threshold_rows: parse_env_var(
+                "CLICKGRAPH_BOLT_SPILL_THRESHOLD_ROWS",
+                "10000",
+            )?,
+            bolt_spill_max_bytes: parse_env_var("CLICKGRAPH_BOLT_SPILL_MAX_BYTES", "1073741824")?,
+            redact_literals: parse_env_var("CLICKGRAPH_REDACT_LITERALS", "off")?,
+            recursion_depth_policy: parse_env_var("CLICKGRAPH_RECURSION_DEPTH_POLICY", "error")?,
+            vlp_fallback_hops: parse_env_var("CLICKGRAPH_VLP_FALLBACK_HOPS", "5")?,
+            tls_cert_path: env::var("CLICKGRAPH_TLS_CERT").ok(),
+            tls_key_path: env::var("CLICKGRAPH_TLS_KEY").ok(),
+            tls_client_ca_path: env::var("CLICKGRAPH_TLS_CLIENT_CA").ok(),
+            nl_query_enabled: parse_env_var("CLICKGRAPH_NL_QUERY", "false")?,
+            llm_provider: env::var("CLICKGRAPH_LLM_PROVIDER").ok(),
+            llm_model: env::var("CLICKGRAPH_LLM_MODEL").ok(),
+            llm_api_url: env::var("CLICKGRAPH_LLM_API_URL").ok(),
+            query_log_cluster: env::var("CLICKGRAPH_QUERY_LOG_CLUSTER").ok(),
+            attribution_table: env::var("CLICKGRAPH_ATTRIBUTION_TABLE").ok(),
+            attribution_export_secs: parse_env_var("CLICKGRAPH_ATTRIBUTION_EXPORT_SECS", "3600")?,
+            lazy_schema_retry_secs: parse_env_var("CLICKGRAPH_LAZY_SCHEMA_RETRY_SECS", "30")?,
+            read_retries: parse_env_var("CLICKGRAPH_READ_RETRIES", "2")?,
+            read_retry_base_ms: parse_env_var("CLICKGRAPH_READ_RETRY_BASE_MS", "100")?,
+            test_mode: parse_env_var("CLICKGRAPH_TEST_MODE", "false")?,
+            test_seed: parse_env_var("CLICKGRAPH_TEST_SEED", "0")?,
+            test_frozen_time: parse_env_var(
+                "CLICKGRAPH_TEST_FROZEN_TIME",
+                DEFAULT_TEST_FROZEN_TIME,
+            )?,
         };
 
         config.validate()?;
@@ -267,8 +567,44 @@ impl ServerConfig {
         self.slow_query_threshold_ms = other.slow_query_threshold_ms;
         self.metrics_ch_summary = other.metrics_ch_summary;
         self.metrics_query_preview = other.metrics_query_preview;
+        self.workload_log_capacity = other.workload_log_capacity;
         self.stats_enabled = other.stats_enabled;
         self.stats_ttl_secs = other.stats_ttl_secs;
+        self.trusted_roles = other.trusted_roles;
+        self.allowed_settings = other.allowed_settings;
+        self.bolt_stable_order = other.bolt_stable_order;
+        self.bolt_advertised_address = other.bolt_advertised_address;
+        self.schema_watch_secs = other.schema_watch_secs;
+        self.allow_cartesian_product = other.allow_cartesian_product;
+        self.allow_schema_overrides = other.allow_schema_overrides;
+        self.allow_graph_construct = other.allow_graph_construct;
+        self.bolt_spill_dir = other.bolt_spill_dir;
+        self.bolt_spill_threshold_rows = other.bolt_spill_threshold_rows;
+        self.bolt_spill_max_bytes = other.bolt_spill_max_bytes;
+        self.redact_literals = other.redact_literals;
+        self.recursion_depth_policy = other.recursion_depth_policy;
+        self.vlp_fallback_hops = other.vlp_fallback_hops;
+        self.tls_cert_path = other.tls_cert_path;
+        self.tls_key_path = other.tls_key_path;
+        self.tls_client_ca_path = other.tls_client_ca_path;
+        self.nl_query_enabled = other.nl_query_enabled;
+        self.llm_provider = other.llm_provider;
+        self.llm_model = other.llm_model;
+        self.llm_api_url = other.llm_api_url;
+        self.query_log_cluster = other.query_log_cluster;
+        self.attribution_table = other.attribution_table;
+        self.attribution_export_secs = other.attribution_export_secs;
+        self.lazy_schema_retry_secs = other.lazy_schema_retry_secs;
+        self.read_retries = other.read_retries;
+        self.read_retry_base_ms = other.read_retry_base_ms;
+        self.test_mode = other.test_mode;
+        self.test_seed = other.test_seed;
+        self.test_frozen_time = other.test_frozen_time;
+    }
+
+    /// Whether `role` may bypass per-label unfiltered scan guards
+    pub fn is_trusted_role(&self, role: Option<&str>) -> bool {
+        role.is_some_and(|r| self.trusted_roles.iter().any(|t| t == r))
     }
 }
 
@@ -296,6 +632,59 @@ fn default_stats_ttl_secs() -> u64 {
     300
 }
 
+/// serde default for `ServerConfig::bolt_spill_threshold_rows` (YAML-file config path).
+fn default_bolt_spill_threshold_rows() -> usize {
+    10_000
+}
+
+/// serde default for `ServerConfig::bolt_spill_max_bytes` (YAML-file config path).
+fn default_bolt_spill_max_bytes() -> u64 {
+    1 << 30
+}
+
+/// serde default for `ServerConfig::vlp_fallback_hops` (YAML-file config path).
+fn default_vlp_fallback_hops() -> u32 {
+    5
+}
+
+fn default_attribution_export_secs() -> u64 {
+    3600
+}
+
+fn default_lazy_schema_retry_secs() -> u64 {
+    30
+}
+
+fn default_read_retries() -> u32 {
+    2
+}
+
+fn default_read_retry_base_ms() -> u64 {
+    100
+}
+
+const DEFAULT_TEST_FROZEN_TIME: &str = "2000-01-01T00:00:00Z";
+
+fn default_test_frozen_time() -> chrono::DateTime<chrono::Utc> {
+    DEFAULT_TEST_FROZEN_TIME
+        .parse()
+        .expect("DEFAULT_TEST_FROZEN_TIME is RFC 3339")
+}
+
+/// Parse a comma-separated environment variable into a list (empty when unset)
+fn parse_env_list(key: &str) -> Vec<String> {
+    env::var(key)
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Parse an environment variable with a default value
 fn parse_env_var<T: std::str::FromStr>(key: &str, default: &str) -> Result<T, ConfigError>
 where
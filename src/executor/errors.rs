@@ -33,3 +33,81 @@ pub enum ExecutorError {
     #[error("Output format `{0}` is not supported by this executor")]
     UnsupportedFormat(String),
 }
+
+/// ClickHouse error codes of failures a later attempt of the same read can
+/// get past: the connection or the server was briefly unavailable, or busy.
+/// Query limits (`TIMEOUT_EXCEEDED`, `MEMORY_LIMIT_EXCEEDED`) and
+/// cancellation (`QUERY_WAS_CANCELLED`) are permanent.
+const TRANSIENT_CLICKHOUSE_CODES: &[u32] = &[
+    32,   // ATTEMPT_TO_READ_AFTER_EOF
+    202,  // TOO_MANY_SIMULTANEOUS_QUERIES
+    203,  // NO_FREE_CONNECTION
+    209,  // SOCKET_TIMEOUT
+    210,  // NETWORK_ERROR
+    236,  // ABORTED
+    279,  // ALL_CONNECTION_TRIES_FAILED
+    373,  // SESSION_IS_LOCKED
+    425,  // SYSTEM_ERROR
+    999,  // KEEPER_EXCEPTION
+    1000, // POCO_EXCEPTION (connection reset)
+];
+
+impl ExecutorError {
+    /// Whether the failure is transient, so that running the same read again
+    /// may succeed: transport errors and the ClickHouse codes of an
+    /// unavailable or overloaded server. Everything else is permanent.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ExecutorError::Io(_) => true,
+            ExecutorError::QueryFailed(message) => {
+                // `clickhouse` crate transport failures
+                message.starts_with("network error")
+                    || message.starts_with("timeout expired")
+                    || clickhouse_code(message)
+                        .is_some_and(|code| TRANSIENT_CLICKHOUSE_CODES.contains(&code))
+            }
+            ExecutorError::Remote { status, .. } => matches!(status, 429 | 502 | 503 | 504),
+            ExecutorError::Parse(_)
+            | ExecutorError::Auth(_)
+            | ExecutorError::UnsupportedFormat(_) => false,
+        }
+    }
+}
+
+/// The code of a ClickHouse exception message (`Code: 202. DB::Exception: ...`).
+fn clickhouse_code(message: &str) -> Option<u32> {
+    let rest = &message[message.find("Code: ")? + "Code: ".len()..];
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..digits].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_transient_failures() {
+        let failed = |message: &str| ExecutorError::QueryFailed(message.to_string());
+        assert!(failed(
+            "bad response: Code: 202. DB::Exception: Too many simultaneous queries. Maximum: 100"
+        )
+        .is_transient());
+        assert!(failed("network error: connection closed before message completed").is_transient());
+        assert!(ExecutorError::Io("error reading a body from connection".into()).is_transient());
+        assert!(ExecutorError::Remote {
+            status: 503,
+            body: String::new()
+        }
+        .is_transient());
+
+        assert!(!failed("bad response: Code: 62. DB::Exception: Syntax error").is_transient());
+        assert!(!failed("bad response: Code: 159. DB::Exception: Timeout exceeded").is_transient());
+        assert!(
+            !failed("bad response: Code: 394. DB::Exception: Query was cancelled").is_transient()
+        );
+        assert!(!ExecutorError::Parse("EOF while parsing".into()).is_transient());
+        assert!(!ExecutorError::Auth("expired".into()).is_transient());
+    }
+}
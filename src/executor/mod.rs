@@ -9,10 +9,13 @@
 //!   Statement Execution API client (requires the `databricks` feature).
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::stream::{BoxStream, StreamExt};
 use serde_json::Value;
 
 pub mod errors;
 pub mod remote;
+pub mod retry;
 pub use errors::ExecutorError;
 
 #[cfg(feature = "embedded")]
@@ -26,6 +29,9 @@ pub mod databricks_sql;
 #[cfg(feature = "databricks")]
 mod text_format;
 
+/// Result bytes delivered in chunks as the backend produces them.
+pub type ByteStream = BoxStream<'static, Result<Bytes, ExecutorError>>;
+
 /// Backend-agnostic SQL execution interface.
 ///
 /// Implemented by different backends (remote ClickHouse, embedded chdb, etc.).
@@ -54,6 +60,132 @@ pub trait QueryExecutor: Send + Sync {
         role: Option<&str>,
     ) -> Result<String, ExecutorError>;
 
+    /// Whether this backend binds ClickHouse query parameters
+    /// (`{name:Type}` placeholders) server-side. Backends that return `false`
+    /// are only ever given SQL with parameters inlined as literals.
+    fn supports_query_params(&self) -> bool {
+        false
+    }
+
+    /// [`execute_json`](Self::execute_json) with query parameter values,
+    /// given as `(name, value)` pairs in ClickHouse's escaped text format.
+    async fn execute_json_with_params(
+        &self,
+        sql: &str,
+        params: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        if !params.is_empty() {
+            return Err(ExecutorError::QueryFailed(
+                "query parameters are not supported by this executor".to_string(),
+            ));
+        }
+        self.execute_json(sql, role).await
+    }
+
+    /// [`execute_text`](Self::execute_text) with query parameter values.
+    async fn execute_text_with_params(
+        &self,
+        sql: &str,
+        format: &str,
+        params: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        if !params.is_empty() {
+            return Err(ExecutorError::QueryFailed(
+                "query parameters are not supported by this executor".to_string(),
+            ));
+        }
+        self.execute_text(sql, format, role).await
+    }
+
+    /// [`execute_json_with_params`](Self::execute_json_with_params) with
+    /// per-query ClickHouse settings (e.g. `use_query_cache`) as
+    /// `(name, value)` pairs. Backends without such settings ignore them.
+    async fn execute_json_with_settings(
+        &self,
+        sql: &str,
+        params: &[(String, String)],
+        _settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.execute_json_with_params(sql, params, role).await
+    }
+
+    /// [`execute_text_with_params`](Self::execute_text_with_params) with
+    /// per-query ClickHouse settings.
+    async fn execute_text_with_settings(
+        &self,
+        sql: &str,
+        format: &str,
+        params: &[(String, String)],
+        _settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        self.execute_text_with_params(sql, format, params, role)
+            .await
+    }
+
+    /// Execute SQL and stream its `JSONEachRow` output, one JSON object per
+    /// line, without holding the whole result in memory.
+    ///
+    /// Chunk boundaries need not fall on line boundaries. The default
+    /// buffers the rows from
+    /// [`execute_json_with_settings`](Self::execute_json_with_settings) and
+    /// yields them as a single chunk; backends that can read their response
+    /// incrementally override it.
+    async fn execute_json_stream(
+        &self,
+        sql: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<ByteStream, ExecutorError> {
+        let rows = self
+            .execute_json_with_settings(sql, params, settings, role)
+            .await?;
+        let mut body = Vec::new();
+        for row in &rows {
+            serde_json::to_writer(&mut body, row)
+                .map_err(|e| ExecutorError::Parse(e.to_string()))?;
+            body.push(b'\n');
+        }
+        Ok(futures_util::stream::once(async move { Ok(Bytes::from(body)) }).boxed())
+    }
+
+    /// Whether [`execute_json_in_session`](Self::execute_json_in_session)
+    /// keeps server-side state (temporary tables, `SET` settings) between
+    /// calls.
+    fn supports_sessions(&self) -> bool {
+        false
+    }
+
+    /// [`execute_json_with_settings`](Self::execute_json_with_settings) in the
+    /// backend session of the ClickGraph session `session` (e.g. one Bolt
+    /// connection): calls with the same `session` and `role` reach the same
+    /// server-side session, one at a time. Backends without sessions run the
+    /// query on its own.
+    async fn execute_json_in_session(
+        &self,
+        _session: &str,
+        sql: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.execute_json_with_settings(sql, params, settings, role)
+            .await
+    }
+
+    /// Release the backend sessions opened for `session`. Called when the
+    /// ClickGraph session ends; a no-op for backends without sessions.
+    async fn close_session(&self, _session: &str) {}
+
+    /// Stop the backend queries started with these `query_id`s (see
+    /// `server::cancellation`), if still running. Best effort: failures are
+    /// only logged. A no-op for backends that can't cancel.
+    async fn cancel_queries(&self, _query_ids: &[String]) {}
+
     /// Downcast hook for callers that need a backend's concrete capabilities
     /// beyond this trait — currently only the Databricks executor, whose
     /// concrete type schema introspection (`DatabricksProbe`) drives directly.
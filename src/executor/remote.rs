@@ -13,15 +13,32 @@
 //!   crate drops — can be captured. The request uses the SAME settings as the
 //!   crate client (`RoleConnectionPool::http_endpoint` →
 //!   `ConnectionConfig::standard_options`) so results are identical.
+//!
+//! Queries issued inside `server::endpoint_routing::batch` go to the batch
+//! pool when one is configured ([`RemoteClickHouseExecutor::with_batch_pool`]).
+//!
+//! Reads that fail with a transient error are retried under the executor's
+//! [`RetryPolicy`] ([`RemoteClickHouseExecutor::with_retry_policy`]). A
+//! streamed read is only retried before its first chunk is returned; a
+//! failure after that ends the stream with an error.
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures_util::stream::StreamExt;
 use serde_json::Value;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
-use super::{ExecutorError, QueryExecutor};
-use crate::server::connection_pool::RoleConnectionPool;
-use crate::server::metrics::{record_ch_network_bytes, record_ch_summary};
+use super::retry::RetryPolicy;
+use super::{ByteStream, ExecutorError, QueryExecutor};
+use crate::server::connection_pool::{ClickHouseSession, RoleConnectionPool};
+use crate::server::endpoint_routing::{self, Endpoint};
+use crate::server::metrics::{
+    record_ch_network_bytes, record_ch_query_cache_hit, record_ch_round_trip, record_ch_summary,
+};
+use crate::server::{attribution, cancellation};
+use crate::utils::redaction::{redact, redact_sql};
 
 /// SQL executor that delegates to a remote ClickHouse server via HTTP.
 ///
@@ -29,12 +46,27 @@ use crate::server::metrics::{record_ch_network_bytes, record_ch_summary};
 /// backend-agnostic [`QueryExecutor`] trait.
 pub struct RemoteClickHouseExecutor {
     pool: Arc<RoleConnectionPool>,
+    /// Pool of the batch endpoint heavy procedures run on; `None` sends
+    /// them to `pool` too.
+    batch_pool: Option<Arc<RoleConnectionPool>>,
+    /// Retries of reads failing with a transient error
+    retry: RetryPolicy,
     /// When true, the JSON read path runs via direct HTTP to capture the
     /// ClickHouse summary header (Phase B). From `CLICKGRAPH_METRICS_CH_SUMMARY`.
     ch_summary: bool,
     /// Reusable HTTP client for the Phase B summary path (shares a connection
     /// pool). Only used when `ch_summary` is set.
     http: reqwest::Client,
+    /// Open ClickHouse sessions by ClickGraph session and role
+    sessions: Mutex<HashMap<SessionKey, OpenSession>>,
+}
+
+/// ClickGraph session and role a [`ClickHouseSession`] was opened for.
+type SessionKey = (String, Option<String>);
+
+struct OpenSession {
+    session: ClickHouseSession,
+    last_used: Instant,
 }
 
 impl RemoteClickHouseExecutor {
@@ -45,37 +77,90 @@ impl RemoteClickHouseExecutor {
     pub fn with_ch_summary(pool: Arc<RoleConnectionPool>, ch_summary: bool) -> Self {
         Self {
             pool,
+            batch_pool: None,
+            retry: RetryPolicy::NONE,
             ch_summary,
             http: reqwest::Client::new(),
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Send queries issued inside `endpoint_routing::batch` to `pool`.
+    pub fn with_batch_pool(mut self, pool: Arc<RoleConnectionPool>) -> Self {
+        self.batch_pool = Some(pool);
+        self
+    }
+
+    /// Retry reads failing with a transient error under `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Pool of the endpoint the current statement's queries go to
+    fn pool(&self) -> &RoleConnectionPool {
+        match (endpoint_routing::current(), &self.batch_pool) {
+            (Endpoint::Batch, Some(batch_pool)) => batch_pool,
+            _ => &self.pool,
         }
     }
 
+    /// The ClickHouse session of `session` and `role`, opened on first use.
+    /// Sessions ClickHouse has expired by now are forgotten first; a later
+    /// query of theirs starts a fresh one.
+    fn session_for(&self, session: &str, role: Option<&str>) -> ClickHouseSession {
+        let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        sessions.retain(|_, open| now.duration_since(open.last_used) < open.session.timeout());
+        let open = sessions
+            .entry((session.to_string(), role.map(str::to_string)))
+            .or_insert_with(|| OpenSession {
+                session: self.pool.open_session(role),
+                last_used: now,
+            });
+        open.last_used = now;
+        open.session.clone()
+    }
+
     /// Phase B: execute a SELECT via direct HTTP and capture
     /// `X-ClickHouse-Summary` (read_rows / read_bytes / elapsed). Returns the
     /// same `Vec<Value>` (JSONEachRow) shape as the crate path.
     async fn execute_json_via_http(
         &self,
         sql: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
         role: Option<&str>,
     ) -> Result<Vec<Value>, ExecutorError> {
-        let ep = self.pool.http_endpoint(role);
+        let ep = self.pool().http_endpoint(role);
 
         // Compose the URL exactly as the crate would: database + standard
         // options as query params, plus JSONEachRow output and
         // wait_end_of_query=1 (the latter makes ClickHouse buffer server-side so
         // the summary is a complete response header rather than a trailer).
         let mut url = reqwest::Url::parse(&ep.url)
-            .map_err(|e| ExecutorError::Io(format!("invalid ClickHouse URL: {e}")))?;
+            .map_err(|e| ExecutorError::QueryFailed(format!("invalid ClickHouse URL: {e}")))?;
         {
             let mut q = url.query_pairs_mut();
             q.append_pair("database", &ep.database);
             for (name, value) in &ep.options {
                 q.append_pair(name, value);
             }
+            for (name, value) in settings
+                .iter()
+                .chain(&cancellation::query_options())
+                .chain(&attribution::query_settings())
+            {
+                q.append_pair(name, value);
+            }
+            for (name, value) in params {
+                q.append_pair(&format!("param_{name}"), value);
+            }
             q.append_pair("default_format", "JSONEachRow");
             q.append_pair("wait_end_of_query", "1");
         }
 
+        record_ch_round_trip();
         let resp = self
             .http
             .post(url)
@@ -94,6 +179,11 @@ impl RemoteClickHouseExecutor {
             .and_then(|v| v.to_str().ok())
         {
             record_summary_header(summary);
+            if uses_query_cache(settings) {
+                if let Some(hit) = query_cache_hit(summary) {
+                    record_ch_query_cache_hit(hit);
+                }
+            }
         }
         let body = resp
             .bytes()
@@ -101,7 +191,11 @@ impl RemoteClickHouseExecutor {
             .map_err(|e| ExecutorError::Io(format!("reading response body: {e}")))?;
         if !status.is_success() {
             let text = String::from_utf8_lossy(&body);
-            log::error!("ClickHouse query failed. SQL was:\n{sql}\nError: {text}");
+            log::error!(
+                "ClickHouse query failed. SQL was:\n{}\nError: {}",
+                redact_sql(sql),
+                redact(&text)
+            );
             return Err(ExecutorError::QueryFailed(text.to_string()));
         }
         record_ch_network_bytes(body.len() as u64);
@@ -136,6 +230,22 @@ fn record_summary_header(header: &str) {
     record_ch_summary(num("read_rows"), num("read_bytes"), num("elapsed_ns"));
 }
 
+/// Whether `settings` turn on ClickHouse's query cache.
+fn uses_query_cache(settings: &[(String, String)]) -> bool {
+    settings
+        .iter()
+        .any(|(name, value)| name == "use_query_cache" && value == "1")
+}
+
+/// Infer from the `X-ClickHouse-Summary` of a query run with the query cache
+/// whether the result was served from the cache: a cache hit reads nothing
+/// from storage, so both `read_rows` and `read_bytes` are zero.
+fn query_cache_hit(header: &str) -> Option<bool> {
+    let v = serde_json::from_str::<serde_json::Value>(header).ok()?;
+    let num = |k: &str| -> Option<u64> { v.get(k)?.as_str()?.parse().ok() };
+    Some(num("read_rows")? == 0 && num("read_bytes")? == 0)
+}
+
 /// Drain a `fetch_bytes` cursor into one buffer and record the bytes received.
 ///
 /// Reading the raw chunks (rather than `.lines()`, which consumes the cursor)
@@ -147,10 +257,7 @@ async fn drain_cursor(
     sql: &str,
 ) -> Result<Vec<u8>, ExecutorError> {
     let mut buf: Vec<u8> = Vec::new();
-    while let Some(chunk) = cursor.next().await.map_err(|e| {
-        log::error!("ClickHouse read failed. SQL was:\n{}\nError: {}", sql, e);
-        ExecutorError::Io(e.to_string())
-    })? {
+    while let Some(chunk) = cursor.next().await.map_err(|e| read_failed(sql, e))? {
         let chunk: Bytes = chunk;
         buf.extend_from_slice(&chunk);
     }
@@ -158,49 +265,141 @@ async fn drain_cursor(
     Ok(buf)
 }
 
-#[async_trait]
-impl QueryExecutor for RemoteClickHouseExecutor {
-    async fn execute_json(
+/// Log a failure reading the response of `sql`.
+fn read_failed(sql: &str, e: clickhouse::error::Error) -> ExecutorError {
+    log::error!(
+        "ClickHouse read failed. SQL was:\n{}\nError: {}",
+        redact_sql(sql),
+        redact(&e.to_string())
+    );
+    ExecutorError::Io(e.to_string())
+}
+
+/// Log a failure starting `sql`.
+fn query_failed(sql: &str, e: clickhouse::error::Error) -> ExecutorError {
+    log::error!(
+        "ClickHouse query failed. SQL was:\n{}\nError: {}",
+        redact_sql(sql),
+        redact(&e.to_string())
+    );
+    ExecutorError::QueryFailed(e.to_string())
+}
+
+/// `sql` on `client` with its settings and `param_<name>` options applied.
+fn query_on(
+    client: &clickhouse::Client,
+    sql: &str,
+    params: &[(String, String)],
+    settings: &[(String, String)],
+) -> clickhouse::query::Query {
+    let query = settings
+        .iter()
+        .chain(&cancellation::query_options())
+        .chain(&attribution::query_settings())
+        .fold(client.query(sql), |query, (name, value)| {
+            query.with_option(name.as_str(), value.as_str())
+        });
+    params.iter().fold(query, |query, (name, value)| {
+        query.with_option(format!("param_{name}"), value.as_str())
+    })
+}
+
+/// Run `query` and parse its `JSONEachRow` output.
+async fn read_json_rows(
+    query: clickhouse::query::Query,
+    sql: &str,
+) -> Result<Vec<Value>, ExecutorError> {
+    record_ch_round_trip();
+    let cursor = query
+        .fetch_bytes("JSONEachRow")
+        .map_err(|e| query_failed(sql, e))?;
+    let buf = drain_cursor(cursor, sql).await?;
+
+    let mut rows = Vec::new();
+    for line in buf.split(|&b| b == b'\n') {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let value: Value = serde_json::from_slice(line).map_err(|e| {
+            log::error!("Failed to parse JSON from ClickHouse response: {}", e);
+            ExecutorError::Parse(e.to_string())
+        })?;
+        rows.push(value);
+    }
+    Ok(rows)
+}
+
+impl RemoteClickHouseExecutor {
+    /// Start a query with its settings and `param_<name>` options applied.
+    async fn query(
+        &self,
+        sql: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> clickhouse::query::Query {
+        let client = self.pool().get_client(role).await;
+        query_on(&client, sql, params, settings)
+    }
+
+    async fn fetch_json(
+        &self,
+        sql: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.retry
+            .run(sql, || self.fetch_json_once(sql, params, settings, role))
+            .await
+    }
+
+    async fn fetch_json_once(
         &self,
         sql: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
         role: Option<&str>,
     ) -> Result<Vec<Value>, ExecutorError> {
         // Phase B: capture the ClickHouse summary via a direct HTTP request.
-        if self.ch_summary {
-            return self.execute_json_via_http(sql, role).await;
+        // The query cache status is read from the same summary.
+        if self.ch_summary || uses_query_cache(settings) {
+            return self
+                .execute_json_via_http(sql, params, settings, role)
+                .await;
         }
-        let client = self.pool.get_client(role).await;
-        let cursor = client.query(sql).fetch_bytes("JSONEachRow").map_err(|e| {
-            log::error!("ClickHouse query failed. SQL was:\n{}\nError: {}", sql, e);
-            ExecutorError::QueryFailed(e.to_string())
-        })?;
-        let buf = drain_cursor(cursor, sql).await?;
+        let query = self.query(sql, params, settings, role).await;
+        read_json_rows(query, sql).await
+    }
 
-        let mut rows = Vec::new();
-        for line in buf.split(|&b| b == b'\n') {
-            if line.iter().all(u8::is_ascii_whitespace) {
-                continue;
-            }
-            let value: Value = serde_json::from_slice(line).map_err(|e| {
-                log::error!("Failed to parse JSON from ClickHouse response: {}", e);
-                ExecutorError::Parse(e.to_string())
-            })?;
-            rows.push(value);
-        }
-        Ok(rows)
+    async fn fetch_text(
+        &self,
+        sql: &str,
+        format: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        self.retry
+            .run(sql, || {
+                self.fetch_text_once(sql, format, params, settings, role)
+            })
+            .await
     }
 
-    async fn execute_text(
+    async fn fetch_text_once(
         &self,
         sql: &str,
         format: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
         role: Option<&str>,
     ) -> Result<String, ExecutorError> {
-        let client = self.pool.get_client(role).await;
-        let cursor = client.query(sql).fetch_bytes(format).map_err(|e| {
-            log::error!("ClickHouse query failed. SQL was:\n{}\nError: {}", sql, e);
-            ExecutorError::QueryFailed(e.to_string())
-        })?;
+        let query = self.query(sql, params, settings, role).await;
+        record_ch_round_trip();
+        let cursor = query
+            .fetch_bytes(format)
+            .map_err(|e| query_failed(sql, e))?;
         let buf = drain_cursor(cursor, sql).await?;
 
         let mut text = String::from_utf8(buf).map_err(|e| ExecutorError::Parse(e.to_string()))?;
@@ -213,6 +412,167 @@ impl QueryExecutor for RemoteClickHouseExecutor {
     }
 }
 
+#[async_trait]
+impl QueryExecutor for RemoteClickHouseExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.fetch_json(sql, &[], &[], role).await
+    }
+
+    async fn execute_text(
+        &self,
+        sql: &str,
+        format: &str,
+        role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        self.fetch_text(sql, format, &[], &[], role).await
+    }
+
+    fn supports_query_params(&self) -> bool {
+        true
+    }
+
+    async fn execute_json_with_params(
+        &self,
+        sql: &str,
+        params: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.fetch_json(sql, params, &[], role).await
+    }
+
+    async fn execute_text_with_params(
+        &self,
+        sql: &str,
+        format: &str,
+        params: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        self.fetch_text(sql, format, params, &[], role).await
+    }
+
+    async fn execute_json_with_settings(
+        &self,
+        sql: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.fetch_json(sql, params, settings, role).await
+    }
+
+    async fn execute_text_with_settings(
+        &self,
+        sql: &str,
+        format: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        self.fetch_text(sql, format, params, settings, role).await
+    }
+
+    fn supports_sessions(&self) -> bool {
+        true
+    }
+
+    async fn execute_json_in_session(
+        &self,
+        session: &str,
+        sql: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.retry
+            .run(sql, || async {
+                let ch_session = self.session_for(session, role);
+                read_json_rows(query_on(ch_session.client(), sql, params, settings), sql).await
+            })
+            .await
+    }
+
+    async fn close_session(&self, session: &str) {
+        let closing: Vec<ClickHouseSession> = {
+            let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+            let keys: Vec<SessionKey> = sessions
+                .keys()
+                .filter(|(owner, _)| owner == session)
+                .cloned()
+                .collect();
+            keys.iter()
+                .filter_map(|key| sessions.remove(key))
+                .map(|open| open.session)
+                .collect()
+        };
+        for ch_session in closing {
+            ch_session.close().await;
+        }
+    }
+
+    async fn cancel_queries(&self, query_ids: &[String]) {
+        self.pool.kill_queries(query_ids).await;
+        if let Some(batch_pool) = &self.batch_pool {
+            batch_pool.kill_queries(query_ids).await;
+        }
+    }
+
+    /// Forwards the ClickHouse response chunk by chunk. The Phase B summary
+    /// path is skipped: `wait_end_of_query` would make ClickHouse buffer the
+    /// whole result server-side.
+    ///
+    /// The query is retried until its first chunk arrives, so the stream
+    /// returned holds the rows of a single attempt. A later failure is not
+    /// retried: the caller may have forwarded rows already.
+    async fn execute_json_stream(
+        &self,
+        sql: &str,
+        params: &[(String, String)],
+        settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<ByteStream, ExecutorError> {
+        let (first, cursor) = self
+            .retry
+            .run(sql, || async {
+                let query = self.query(sql, params, settings, role).await;
+                record_ch_round_trip();
+                let mut cursor = query
+                    .fetch_bytes("JSONEachRow")
+                    .map_err(|e| query_failed(sql, e))?;
+                let first = cursor.next().await.map_err(|e| read_failed(sql, e))?;
+                Ok((first, cursor))
+            })
+            .await?;
+        let sql = sql.to_string();
+        let rest = futures_util::stream::try_unfold(
+            (cursor, first.is_none()),
+            move |(mut cursor, done)| {
+                let sql = sql.clone();
+                async move {
+                    if done {
+                        record_ch_network_bytes(cursor.received_bytes());
+                        return Ok(None);
+                    }
+                    match cursor.next().await {
+                        Ok(Some(chunk)) => Ok(Some((chunk, (cursor, false)))),
+                        Ok(None) => {
+                            record_ch_network_bytes(cursor.received_bytes());
+                            Ok(None)
+                        }
+                        Err(e) => Err(read_failed(&sql, e)),
+                    }
+                }
+            },
+        );
+        Ok(futures_util::stream::iter(first.map(Ok))
+            .chain(rest)
+            .boxed())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,4 +617,84 @@ mod tests {
         assert_eq!(stats.read_bytes, Some(0));
         assert_eq!(stats.elapsed_ns, Some(0));
     }
+
+    async fn executor() -> RemoteClickHouseExecutor {
+        let pool = RoleConnectionPool::new_with_params(
+            "http://localhost:8123",
+            "test_user",
+            "test_pass",
+            None,
+            None,
+            100,
+        )
+        .await
+        .unwrap();
+        RemoteClickHouseExecutor::new(Arc::new(pool))
+    }
+
+    #[tokio::test]
+    async fn batch_statements_use_the_batch_pool() {
+        let batch_pool = Arc::new(
+            RoleConnectionPool::new_with_params(
+                "http://replica:8123",
+                "test_user",
+                "test_pass",
+                None,
+                None,
+                100,
+            )
+            .await
+            .unwrap(),
+        );
+        let executor = executor().await;
+        let batch = endpoint_routing::batch(async { executor.pool().http_endpoint(None).url });
+        assert_eq!(batch.await, "http://localhost:8123");
+
+        let executor = executor.with_batch_pool(batch_pool);
+        assert_eq!(
+            executor.pool().http_endpoint(None).url,
+            "http://localhost:8123"
+        );
+        let batch = endpoint_routing::batch(async { executor.pool().http_endpoint(None).url });
+        assert_eq!(batch.await, "http://replica:8123");
+    }
+
+    #[tokio::test]
+    async fn sessions_are_sticky_per_owner_and_role() {
+        let executor = executor().await;
+        let a = executor.session_for("bolt-1", None);
+        assert_eq!(executor.session_for("bolt-1", None).id(), a.id());
+        assert_ne!(executor.session_for("bolt-1", Some("analyst")).id(), a.id());
+        assert_ne!(executor.session_for("bolt-2", None).id(), a.id());
+        assert_eq!(a.timeout(), std::time::Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn expired_sessions_are_forgotten() {
+        let executor = executor().await;
+        let first = executor.session_for("bolt-1", None);
+        {
+            let mut sessions = executor.sessions.lock().unwrap();
+            let open = sessions.get_mut(&("bolt-1".to_string(), None)).unwrap();
+            open.last_used -= first.timeout();
+        }
+        assert_ne!(executor.session_for("bolt-1", None).id(), first.id());
+        assert_eq!(executor.sessions.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn query_cache_hit_reads_nothing() {
+        let settings = [("use_query_cache".to_string(), "1".to_string())];
+        assert!(uses_query_cache(&settings));
+        assert!(!uses_query_cache(&[]));
+        assert_eq!(
+            query_cache_hit(r#"{"read_rows":"0","read_bytes":"0","elapsed_ns":"90"}"#),
+            Some(true)
+        );
+        assert_eq!(
+            query_cache_hit(r#"{"read_rows":"12","read_bytes":"480"}"#),
+            Some(false)
+        );
+        assert_eq!(query_cache_hit("not json"), None);
+    }
 }
@@ -0,0 +1,190 @@
+//! Retry of idempotent reads on transient ClickHouse failures.
+//!
+//! A read query that fails with a transient error
+//! ([`ExecutorError::is_transient`]) is run again from the start, after an
+//! exponential backoff. Only statements that read (`SELECT`, `WITH`, `SHOW`,
+//! ...) are retried; an `INSERT` or `CREATE` may have taken effect before the
+//! failure. An attempt never reuses what an earlier one received: buffered
+//! reads collect each attempt's response into a fresh buffer, and streamed
+//! reads are only retried until their first chunk has been handed on.
+
+use std::future::Future;
+use std::time::Duration;
+
+use super::ExecutorError;
+use crate::utils::redaction::redact;
+
+/// Ceiling on one retry's backoff, so a retried read fails within seconds
+/// rather than outlasting the client's patience.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// How often and how patiently reads are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts after the first
+    pub retries: u32,
+    /// Backoff before the first retry, doubled for each further one
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Run every query once.
+    pub const NONE: RetryPolicy = RetryPolicy {
+        retries: 0,
+        base_delay: Duration::ZERO,
+    };
+
+    pub fn new(retries: u32, base_delay: Duration) -> Self {
+        Self {
+            retries,
+            base_delay,
+        }
+    }
+
+    /// Backoff before retry number `attempt` (0-based), capped at
+    /// [`MAX_RETRY_DELAY`].
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32 << attempt.min(16);
+        self.base_delay.saturating_mul(factor).min(MAX_RETRY_DELAY)
+    }
+
+    /// Run `attempt` for `sql`, again while it fails with a transient error
+    /// and retries are left. Statements that are not reads run once.
+    pub async fn run<T, F, Fut>(&self, sql: &str, mut attempt: F) -> Result<T, ExecutorError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ExecutorError>>,
+    {
+        if self.retries == 0 || !is_read_only(sql) {
+            return attempt().await;
+        }
+        let mut retry = 0;
+        loop {
+            match attempt().await {
+                Err(e) if e.is_transient() && retry < self.retries => {
+                    let delay = self.delay(retry);
+                    retry += 1;
+                    log::warn!(
+                        "Transient ClickHouse failure, retry {}/{} in {:?}: {}",
+                        retry,
+                        self.retries,
+                        delay,
+                        redact(&e.to_string())
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Whether `sql` only reads, so running it twice is harmless.
+pub fn is_read_only(sql: &str) -> bool {
+    let keyword: String = skip_comments(sql)
+        .trim_start_matches(|c: char| c == '(' || c.is_whitespace())
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect::<String>()
+        .to_ascii_uppercase();
+    matches!(
+        keyword.as_str(),
+        "SELECT" | "WITH" | "SHOW" | "DESCRIBE" | "DESC" | "EXPLAIN" | "EXISTS"
+    )
+}
+
+/// `sql` from its first token that is not in a leading comment.
+fn skip_comments(mut sql: &str) -> &str {
+    loop {
+        sql = sql.trim_start();
+        if let Some(rest) = sql.strip_prefix("--") {
+            sql = rest.find('\n').map_or("", |end| &rest[end..]);
+        } else if let Some(rest) = sql.strip_prefix("/*") {
+            sql = rest.find("*/").map_or("", |end| &rest[end + 2..]);
+        } else {
+            return sql;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn reads_are_recognized() {
+        assert!(is_read_only("SELECT 1"));
+        assert!(is_read_only("  with t AS (SELECT 1) SELECT * FROM t"));
+        assert!(is_read_only(
+            "-- stats\n/* planner */ (SELECT 1) UNION ALL (SELECT 2)"
+        ));
+        assert!(is_read_only("SHOW TABLES"));
+        assert!(!is_read_only("INSERT INTO FUNCTION s3('...') SELECT 1"));
+        assert!(!is_read_only("CREATE TEMPORARY TABLE t AS SELECT 1"));
+        assert!(!is_read_only("KILL QUERY WHERE query_id = 'x'"));
+        assert!(!is_read_only("-- SELECT\nDROP TABLE t"));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(100));
+        assert_eq!(policy.delay(0), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(400));
+        assert_eq!(policy.delay(10), MAX_RETRY_DELAY);
+    }
+
+    fn transient() -> ExecutorError {
+        ExecutorError::QueryFailed("network error: connection reset".to_string())
+    }
+
+    #[tokio::test]
+    async fn transient_read_failures_are_retried() {
+        let policy = RetryPolicy::new(2, Duration::ZERO);
+        let attempts = AtomicU32::new(0);
+        let result = policy
+            .run("SELECT 1", || async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(transient()),
+                    n => Ok(n),
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 1);
+
+        // Out of retries: the last error is returned
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), _> = policy
+            .run("SELECT 1", || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(transient())
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn writes_and_permanent_failures_run_once() {
+        let policy = RetryPolicy::new(2, Duration::ZERO);
+        for (sql, error) in [
+            ("INSERT INTO t SELECT 1", transient()),
+            (
+                "SELECT 1",
+                ExecutorError::QueryFailed("Code: 62. DB::Exception: Syntax error".to_string()),
+            ),
+        ] {
+            let attempts = AtomicU32::new(0);
+            let mut error = Some(error);
+            let result: Result<(), _> = policy
+                .run(sql, || {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    let error = error.take().unwrap_or_else(transient);
+                    async move { Err(error) }
+                })
+                .await;
+            assert!(result.is_err());
+            assert_eq!(attempts.load(Ordering::SeqCst), 1, "{sql}");
+        }
+    }
+}
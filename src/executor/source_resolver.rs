@@ -89,7 +89,7 @@ pub(crate) fn escape_sql_string(s: &str) -> String {
 }
 
 /// Infer a ClickHouse format string from a file extension.
-fn detect_format_from_path(path: &str) -> &'static str {
+pub(crate) fn detect_format_from_path(path: &str) -> &'static str {
     // Strip query string / fragment if present (e.g. s3:// URLs with ?params)
     let base = path.split('?').next().unwrap_or(path);
     let lower = base.to_lowercase();
@@ -1,15 +1,17 @@
 use super::errors::GraphSchemaError;
-use super::expression_parser::{parse_property_value, PropertyValue};
+use super::expression_parser::{parse_property_value, parse_scalar_expression, PropertyValue};
 use super::filter_parser::SchemaFilter;
+use super::graph_constraints::GraphConstraints;
 use super::graph_schema::{
-    FulltextIndexConfig, GraphSchema, NodeIdSchema, NodeSchema, RelationshipSchema,
-    VectorIndexConfig,
+    ClusterLayout, Collation, EdgeTemporal, Freshness, FulltextIndexConfig, GraphSchema,
+    NodeIdSchema, NodeSchema, QueryCacheConfig, RelationshipSchema, ReturnProjection, SoftDelete,
+    UnfilteredScanAction, UnfilteredScanLimit, UnknownLabelMode, VectorIndexConfig,
 };
 use super::schema_types::SchemaType;
 use super::schema_validator::SchemaValidator;
 use serde::{Deserialize, Serialize};
 use serde_yaml;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::str::FromStr;
 use thiserror::Error;
 
@@ -24,11 +26,14 @@ pub enum IdentifierError {
 
 /// Multi-schema configuration wrapper
 /// Supports loading multiple schemas from a single YAML file
+///
+/// `Single` is boxed: a `GraphSchemaConfig` is several times larger than the
+/// `Multi` variant (clippy::large_enum_variant).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum SchemaConfigFile {
     /// Single schema (backward compatible)
-    Single(GraphSchemaConfig),
+    Single(Box<GraphSchemaConfig>),
     /// Multiple schemas in one file
     Multi {
         /// Optional default schema name
@@ -36,9 +41,35 @@ pub enum SchemaConfigFile {
         default_schema: Option<String>,
         /// List of schemas
         schemas: Vec<GraphSchemaConfig>,
+        /// Isolated per-tenant copies of the schemas above
+        #[serde(default)]
+        tenants: Vec<TenantDefinition>,
     },
 }
 
+/// A tenant graph: a copy of a named schema, optionally reading its tables
+/// from another ClickHouse database. Queries whose graph is a tenant graph
+/// cannot reach another graph with `USE`.
+///
+/// Example YAML:
+/// ```yaml
+/// tenants:
+///   - name: team_a
+///     schema: social
+///     database: team_a
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenantDefinition {
+    /// Graph name the tenant selects (`X-Graph-Name`, Bolt `db`)
+    pub name: String,
+    /// Schema of the `schemas` list the tenant graph copies
+    pub schema: String,
+    /// ClickHouse database of every node and edge table (default: the
+    /// schema's own)
+    #[serde(default)]
+    pub database: Option<String>,
+}
+
 /// Identifier type supporting both single and composite IDs
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(untagged)]
@@ -403,6 +434,153 @@ pub struct GraphSchemaDefinition {
     /// Maps Neo4j-style fulltext indexes to ClickHouse text search functions
     #[serde(default)]
     pub fulltext_indexes: Vec<FulltextIndexDefinition>,
+
+    /// Declared graph facts the planner may rely on (acyclic edge types,
+    /// disjoint labels). Not enforced on data — see `graph_constraints`.
+    #[serde(default)]
+    pub constraints: GraphConstraintsDefinition,
+
+    /// What a MATCH on an undefined label or relationship type does:
+    /// `strict` (planning error, default) or `lenient` (empty result plus a
+    /// notification) — see `query_planner::label_resolution`.
+    #[serde(default)]
+    pub unknown_labels: UnknownLabelMode,
+
+    /// Default ClickHouse query cache use for read queries
+    /// (`enabled`, `ttl_seconds`); per-request flags override it.
+    #[serde(default)]
+    pub query_cache: QueryCacheConfig,
+
+    /// Date column every node and edge table is partitioned by when the graph
+    /// is backed by daily snapshot tables; enables `USE graph FOR TIMESTAMP`.
+    #[serde(default)]
+    pub snapshot_column: Option<String>,
+
+    /// ClickHouse cluster the node and edge tables are sharded over; reads
+    /// go through its Distributed tables with `GLOBAL` joins.
+    #[serde(default)]
+    pub cluster: Option<ClusterDefinition>,
+
+    /// Named views of the graph, each selectable as `USE <graph>.<view>`
+    #[serde(default)]
+    pub views: BTreeMap<String, GraphViewDefinition>,
+}
+
+/// Per-request schema fragment: extra or replacement node and edge
+/// definitions (see [`GraphSchemaConfig::with_overrides`])
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SchemaOverrides {
+    #[serde(default)]
+    nodes: Vec<NodeDefinition>,
+    #[serde(default)]
+    edges: Vec<EdgeDefinition>,
+}
+
+/// Cluster section in schema config
+///
+/// Tables mapped by their local (per-shard) name are read through the
+/// Distributed table over them: the one named in `distributed_tables`, else
+/// the local name plus `distributed_suffix`. Without either, the mapped
+/// tables are taken to be Distributed tables already.
+///
+/// Example YAML:
+/// ```yaml
+/// cluster:
+///   name: main
+///   distributed_suffix: _all     # users -> users_all
+///   distributed_tables:
+///     events_local: events       # or analytics.events_local: analytics.events
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClusterDefinition {
+    /// Cluster name, as in `system.clusters`
+    pub name: String,
+
+    /// Suffix of the Distributed table over each mapped local table
+    #[serde(default)]
+    pub distributed_suffix: Option<String>,
+
+    /// Distributed table over a local table, keyed by `table` or
+    /// `database.table`; a value without a database stays in the local one
+    #[serde(default)]
+    pub distributed_tables: BTreeMap<String, String>,
+}
+
+impl ClusterDefinition {
+    /// The Distributed table reading `database.table`, as (database, table);
+    /// `None` when the table is read as mapped.
+    fn distributed_table(&self, database: &str, table: &str) -> Option<(String, String)> {
+        let explicit = self
+            .distributed_tables
+            .get(&format!("{}.{}", database, table))
+            .or_else(|| self.distributed_tables.get(table));
+        match explicit {
+            Some(target) => Some(match target.split_once('.') {
+                Some((db, name)) => (db.to_string(), name.to_string()),
+                None => (database.to_string(), target.clone()),
+            }),
+            None => self
+                .distributed_suffix
+                .as_deref()
+                .filter(|suffix| !suffix.is_empty())
+                .map(|suffix| (database.to_string(), format!("{}{}", table, suffix))),
+        }
+    }
+}
+
+/// A named view of the graph: a subset of its labels and relationship types
+/// whose every scan also carries the view's filters. The view is registered
+/// as its own graph, `<graph>.<view>`, so `USE social.prod_only` (or that
+/// graph name over HTTP and Bolt) queries it with the same plans as any
+/// other graph.
+///
+/// Example YAML:
+/// ```yaml
+/// views:
+///   prod_only:
+///     labels: [User, Post]         # default: every label
+///     types: [FOLLOWS, AUTHORED]   # default: every type between kept labels
+///     filters:
+///       User: "env = 'prod'"
+///       FOLLOWS: "env = 'prod'"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GraphViewDefinition {
+    /// Node labels the view keeps
+    #[serde(default)]
+    pub labels: Option<Vec<String>>,
+
+    /// Relationship types the view keeps; a type is dropped anyway when one
+    /// of its endpoint labels is
+    #[serde(default)]
+    pub types: Option<Vec<String>>,
+
+    /// Predicate per label or type, ANDed into its table's `filter`
+    #[serde(default)]
+    pub filters: BTreeMap<String, String>,
+}
+
+/// Graph constraints section in schema config
+///
+/// Example YAML:
+/// ```yaml
+/// constraints:
+///   acyclic: [MANAGES]
+///   disjoint_labels:
+///     - [Person, Company]
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphConstraintsDefinition {
+    /// Relationship types with no directed cycles
+    #[serde(default)]
+    pub acyclic: Vec<String>,
+
+    /// Groups of node labels that never label the same node
+    #[serde(default)]
+    pub disjoint_labels: Vec<Vec<String>>,
 }
 
 /// Vector index definition in schema config
@@ -541,6 +719,11 @@ pub struct NodeDefinition {
     /// - "camelCase": Convert to camelCase (user_id → userId)
     #[serde(default = "default_naming_convention")]
     pub naming_convention: String,
+    /// Optional: Defer engine detection, column discovery and validation of
+    /// this table to its first use (or the background completion task), so
+    /// startup doesn't wait on it and the table may not exist yet at load
+    #[serde(default)]
+    pub lazy: bool,
 
     // ===== Denormalized node support =====
     /// Optional: Property mappings when this node appears as from_node in relationships
@@ -561,6 +744,19 @@ pub struct NodeDefinition {
     #[serde(default)]
     pub filter: Option<String>,
 
+    /// Optional: Soft-delete column of this table. Reads skip deleted rows
+    /// (unless the query is prefixed `CYPHER include_deleted=true`) and
+    /// DELETE marks rows instead of removing them.
+    /// Example: `{column: deleted_at}` or `{column: is_deleted, marker: flag}`
+    #[serde(default)]
+    pub soft_delete: Option<SoftDelete>,
+
+    /// Optional: Collations of string properties, applied to comparisons and
+    /// ORDER BY. Values are `+`-joined tokens: a locale, `ci`, `ai`.
+    /// Example: `{name: "de+ci", city: "ai"}`
+    #[serde(default)]
+    pub collations: HashMap<String, String>,
+
     // ===== Neo4j elementId support =====
     /// Optional: Type for single node_id column
     /// Required for Neo4j compatibility (elementId function support)
@@ -604,6 +800,41 @@ pub struct NodeDefinition {
     /// upstream by the executor admission check.
     #[serde(default)]
     pub id_generation: Option<String>,
+
+    // ===== Exploration guard =====
+    /// Optional: Maximum rows an unfiltered whole-label scan may return
+    /// (`MATCH (n:Event) RETURN n` with no WHERE / property filter).
+    /// Unset = no guard.
+    #[serde(default)]
+    pub max_unfiltered_rows: Option<u64>,
+
+    /// Optional: What to do when an unfiltered scan exceeds `max_unfiltered_rows`
+    /// Accepted values (case-insensitive): "limit" (default) injects
+    /// `LIMIT max_unfiltered_rows` plus a notification, "reject" fails the query.
+    #[serde(default)]
+    pub on_unfiltered_scan: Option<String>,
+
+    // ===== Whole-node projection =====
+    /// Optional: Properties `RETURN n` projects, in priority order (after the
+    /// id). Unset = every mapped property. `RETURN n { .* }` projects all.
+    #[serde(default)]
+    pub return_properties: Option<Vec<String>>,
+
+    /// Optional: Maximum columns `RETURN n` projects: the id, then
+    /// `return_properties`, then the remaining properties by name.
+    #[serde(default)]
+    pub max_return_columns: Option<usize>,
+
+    // ===== Data catalog metadata =====
+    /// Optional: Human-readable description, published to external data
+    /// catalogs (see `server::catalog_export`). Not used by query planning.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Optional: Owners of this label, as catalog user/group names or URNs
+    /// Example: `["alice", "urn:li:corpGroup:fraud-team"]`
+    #[serde(default)]
+    pub owners: Vec<String>,
 }
 
 fn default_naming_convention() -> String {
@@ -634,6 +865,468 @@ fn parse_id_generation(
     }
 }
 
+/// Resolve `max_unfiltered_rows` / `on_unfiltered_scan` on node definitions
+/// into per-label unfiltered scan guards.
+fn resolve_unfiltered_scan_limits(
+    definitions: &[NodeDefinition],
+) -> Result<BTreeMap<String, UnfilteredScanLimit>, GraphSchemaError> {
+    let mut limits = BTreeMap::new();
+
+    for def in definitions {
+        let Some(max_rows) = def.max_unfiltered_rows else {
+            if def.on_unfiltered_scan.is_some() {
+                return Err(GraphSchemaError::InvalidConfig {
+                    message: format!(
+                        "Node '{}': on_unfiltered_scan requires max_unfiltered_rows",
+                        def.label
+                    ),
+                });
+            }
+            continue;
+        };
+        if max_rows == 0 {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!(
+                    "Node '{}': max_unfiltered_rows must be greater than 0",
+                    def.label
+                ),
+            });
+        }
+        let action = match def
+            .on_unfiltered_scan
+            .as_deref()
+            .map(|v| v.trim().to_ascii_lowercase())
+            .as_deref()
+        {
+            None | Some("limit") => UnfilteredScanAction::Limit,
+            Some("reject") => UnfilteredScanAction::Reject,
+            Some(other) => {
+                return Err(GraphSchemaError::InvalidConfig {
+                    message: format!(
+                    "Node '{}': invalid on_unfiltered_scan '{}' (expected one of: limit, reject)",
+                    def.label, other
+                ),
+                })
+            }
+        };
+        limits.insert(def.label.clone(), UnfilteredScanLimit { max_rows, action });
+    }
+
+    Ok(limits)
+}
+
+/// Resolve `return_properties` / `max_return_columns` on node definitions
+/// into per-label whole-node projections.
+fn resolve_return_projections(
+    definitions: &[NodeDefinition],
+) -> Result<BTreeMap<String, ReturnProjection>, GraphSchemaError> {
+    let mut projections = BTreeMap::new();
+
+    for def in definitions {
+        if def.return_properties.is_none() && def.max_return_columns.is_none() {
+            continue;
+        }
+        if def.max_return_columns == Some(0) {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!(
+                    "Node '{}': max_return_columns must be greater than 0",
+                    def.label
+                ),
+            });
+        }
+        let properties = def.return_properties.clone().unwrap_or_default();
+        let id_properties = def.node_id.columns();
+        for property in &properties {
+            if !def.properties.contains_key(property) && !id_properties.contains(&property.as_str())
+            {
+                return Err(GraphSchemaError::InvalidConfig {
+                    message: format!(
+                        "Node '{}': return_properties names unknown property '{}'",
+                        def.label, property
+                    ),
+                });
+            }
+        }
+        projections.insert(
+            def.label.clone(),
+            ReturnProjection {
+                properties,
+                max_columns: def.max_return_columns,
+            },
+        );
+    }
+
+    Ok(projections)
+}
+
+/// Resolve `soft_delete` on node and edge definitions into per-table
+/// markers, keyed by `database.table`. Definitions sharing a table must agree.
+fn resolve_soft_deletes(
+    nodes: &[NodeDefinition],
+    edges: &[EdgeDefinition],
+) -> Result<BTreeMap<String, SoftDelete>, GraphSchemaError> {
+    let declared = nodes
+        .iter()
+        .map(|def| (&def.database, &def.table, &def.soft_delete))
+        .chain(edges.iter().map(|edge| match edge {
+            EdgeDefinition::Standard(def) => (&def.database, &def.table, &def.soft_delete),
+            EdgeDefinition::Polymorphic(def) => (&def.database, &def.table, &def.soft_delete),
+        }));
+
+    let mut soft_deletes: BTreeMap<String, SoftDelete> = BTreeMap::new();
+    for (database, table, soft_delete) in declared {
+        let Some(soft_delete) = soft_delete else {
+            continue;
+        };
+        if soft_delete.column.trim().is_empty() {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!(
+                    "Table '{}.{}': soft_delete.column is empty",
+                    database, table
+                ),
+            });
+        }
+        let key = format!("{}.{}", database, table);
+        match soft_deletes.get(&key) {
+            Some(existing) if existing != soft_delete => {
+                return Err(GraphSchemaError::InvalidConfig {
+                    message: format!(
+                        "Table '{}' declares conflicting soft_delete settings ('{}' and '{}')",
+                        key, existing.column, soft_delete.column
+                    ),
+                });
+            }
+            Some(_) => {}
+            None => {
+                soft_deletes.insert(key, soft_delete.clone());
+            }
+        }
+    }
+
+    Ok(soft_deletes)
+}
+
+/// Resolve `freshness` on edge definitions into per-table watermarks, keyed
+/// by `database.table`. Definitions sharing a table must agree.
+fn resolve_freshness(
+    edges: &[EdgeDefinition],
+) -> Result<BTreeMap<String, Freshness>, GraphSchemaError> {
+    let declared = edges.iter().map(|edge| match edge {
+        EdgeDefinition::Standard(def) => (&def.database, &def.table, &def.freshness),
+        EdgeDefinition::Polymorphic(def) => (&def.database, &def.table, &def.freshness),
+    });
+
+    let mut freshness: BTreeMap<String, Freshness> = BTreeMap::new();
+    for (database, table, declared) in declared {
+        let Some(declared) = declared else {
+            continue;
+        };
+        let key = format!("{}.{}", database, table);
+        if declared.watermark.trim().is_empty() {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!("Table '{}': freshness.watermark is empty", key),
+            });
+        }
+        match freshness.get(&key) {
+            Some(existing) if existing != declared => {
+                return Err(GraphSchemaError::InvalidConfig {
+                    message: format!(
+                        "Table '{}' declares conflicting freshness settings ('{}' and '{}')",
+                        key, existing.watermark, declared.watermark
+                    ),
+                });
+            }
+            Some(_) => {}
+            None => {
+                freshness.insert(key, declared.clone());
+            }
+        }
+    }
+
+    Ok(freshness)
+}
+
+/// Resolve `temporal` on edge definitions into per-table time dimensions,
+/// keyed by `database.table`. Definitions sharing a table must agree.
+fn resolve_edge_temporal(
+    edges: &[EdgeDefinition],
+) -> Result<BTreeMap<String, EdgeTemporal>, GraphSchemaError> {
+    let declared = edges.iter().map(|edge| match edge {
+        EdgeDefinition::Standard(def) => (&def.database, &def.table, &def.temporal),
+        EdgeDefinition::Polymorphic(def) => (&def.database, &def.table, &def.temporal),
+    });
+
+    let mut temporal: BTreeMap<String, EdgeTemporal> = BTreeMap::new();
+    for (database, table, declared) in declared {
+        let Some(declared) = declared else {
+            continue;
+        };
+        let key = format!("{}.{}", database, table);
+        let periods = [
+            ("valid_time", &declared.valid_time),
+            ("system_time", &declared.system_time),
+        ];
+        if periods.iter().all(|(_, period)| period.is_none()) {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!(
+                    "Table '{}': temporal declares neither valid_time nor system_time",
+                    key
+                ),
+            });
+        }
+        for (name, period) in periods {
+            let Some(period) = period else {
+                continue;
+            };
+            if period.from.trim().is_empty()
+                || period.to.as_deref().is_some_and(|to| to.trim().is_empty())
+            {
+                return Err(GraphSchemaError::InvalidConfig {
+                    message: format!("Table '{}': temporal.{} has an empty column", key, name),
+                });
+            }
+        }
+        match temporal.get(&key) {
+            Some(existing) if existing != declared => {
+                return Err(GraphSchemaError::InvalidConfig {
+                    message: format!("Table '{}' declares conflicting temporal settings", key),
+                });
+            }
+            Some(_) => {}
+            None => {
+                temporal.insert(key, declared.clone());
+            }
+        }
+    }
+    Ok(temporal)
+}
+
+/// Resolve `cost` on standard edge definitions, keyed by edge type.
+/// Definitions of the same type must agree.
+fn resolve_edge_costs(
+    edges: &[EdgeDefinition],
+) -> Result<BTreeMap<String, String>, GraphSchemaError> {
+    let mut costs: BTreeMap<String, String> = BTreeMap::new();
+    for edge in edges {
+        let EdgeDefinition::Standard(def) = edge else {
+            continue;
+        };
+        let Some(cost) = &def.cost else {
+            continue;
+        };
+        let cost = cost.trim();
+        parse_scalar_expression(cost).map_err(|e| GraphSchemaError::InvalidConfig {
+            message: format!("Edge '{}': invalid cost '{}': {}", def.type_name, cost, e),
+        })?;
+        match costs.get(&def.type_name) {
+            Some(existing) if existing != cost => {
+                return Err(GraphSchemaError::InvalidConfig {
+                    message: format!(
+                        "Edge '{}' declares conflicting costs ('{}' and '{}')",
+                        def.type_name, existing, cost
+                    ),
+                });
+            }
+            Some(_) => {}
+            None => {
+                costs.insert(def.type_name.clone(), cost.to_string());
+            }
+        }
+    }
+    Ok(costs)
+}
+
+/// Resolve `collations` on node and edge definitions into per-column
+/// collations, keyed by `database.table` and then column. Each property must be
+/// mapped to a plain column; definitions sharing a table must agree.
+fn resolve_collations(
+    nodes: &[NodeDefinition],
+    edges: &[EdgeDefinition],
+) -> Result<BTreeMap<String, BTreeMap<String, Collation>>, GraphSchemaError> {
+    let declared = nodes
+        .iter()
+        .map(|def| (&def.database, &def.table, &def.properties, &def.collations))
+        .chain(edges.iter().map(|edge| match edge {
+            EdgeDefinition::Standard(def) => {
+                (&def.database, &def.table, &def.properties, &def.collations)
+            }
+            EdgeDefinition::Polymorphic(def) => {
+                (&def.database, &def.table, &def.properties, &def.collations)
+            }
+        }));
+
+    let mut collations: BTreeMap<String, BTreeMap<String, Collation>> = BTreeMap::new();
+    for (database, table, properties, declared_collations) in declared {
+        let key = format!("{}.{}", database, table);
+        for (property, spec) in declared_collations {
+            let invalid = |reason: String| GraphSchemaError::InvalidConfig {
+                message: format!("Table '{}': collation of '{}' {}", key, property, reason),
+            };
+            let column = match properties
+                .get(property)
+                .map(|value| parse_property_value(value))
+            {
+                Some(Ok(PropertyValue::Column(column))) => column,
+                Some(_) => return Err(invalid("requires a plain column mapping".to_string())),
+                None => {
+                    return Err(invalid(
+                        "refers to a property not in property_mappings".to_string(),
+                    ))
+                }
+            };
+            let collation = Collation::from_str(spec).map_err(invalid)?;
+            let columns = collations.entry(key.clone()).or_default();
+            match columns.get(&column) {
+                Some(existing) if *existing != collation => {
+                    return Err(GraphSchemaError::InvalidConfig {
+                        message: format!(
+                            "Table '{}' declares conflicting collations for column '{}' ('{}' and '{}')",
+                            key, column, existing, collation
+                        ),
+                    });
+                }
+                _ => {
+                    columns.insert(column, collation);
+                }
+            }
+        }
+    }
+
+    Ok(collations)
+}
+
+/// Resolve `aggregate` on standard edge definitions into the subqueries
+/// behind their logical tables, keyed by `database.table`. Each groups the
+/// raw rows by the edge's id columns, `group_by`, and the graph's
+/// `snapshot_column` (so `FOR TIMESTAMP` still filters the aggregated rows).
+fn resolve_edge_aggregates(
+    edges: &[EdgeDefinition],
+    snapshot_column: Option<&str>,
+) -> Result<BTreeMap<String, String>, GraphSchemaError> {
+    let mut aggregates = BTreeMap::new();
+
+    for edge in edges {
+        let EdgeDefinition::Standard(def) = edge else {
+            continue;
+        };
+        let Some(aggregate) = &def.aggregate else {
+            continue;
+        };
+        let invalid = |reason: String| GraphSchemaError::InvalidConfig {
+            message: format!("Aggregated edge '{}': {}", def.type_name, reason),
+        };
+        if def.view_parameters.is_some()
+            || def.auto_discover_columns
+            || def.source.is_some()
+            || def.lazy
+        {
+            return Err(invalid(
+                "view_parameters, auto_discover_columns, source and lazy do not apply to an aggregate"
+                    .to_string(),
+            ));
+        }
+        if def.use_final == Some(true) {
+            return Err(invalid(
+                "use_final does not apply to an aggregate".to_string(),
+            ));
+        }
+        if aggregate.source_table.trim().is_empty() {
+            return Err(invalid("aggregate.source_table is empty".to_string()));
+        }
+
+        let mut group_by: Vec<&str> = Vec::new();
+        let keys = def.from_id.columns().into_iter().chain(def.to_id.columns());
+        let extra = aggregate.group_by.iter().map(String::as_str);
+        for column in keys.chain(extra).chain(snapshot_column) {
+            if !group_by.contains(&column) {
+                group_by.push(column);
+            }
+        }
+
+        let mut select: Vec<String> = group_by.iter().map(|c| c.to_string()).collect();
+        for (name, expr) in &aggregate.measures {
+            let is_identifier = name
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !is_identifier || expr.trim().is_empty() {
+                return Err(invalid(format!("invalid measure '{}: {}'", name, expr)));
+            }
+            if group_by.contains(&name.as_str()) {
+                return Err(invalid(format!(
+                    "measure '{}' shadows a grouped column",
+                    name
+                )));
+            }
+            select.push(format!("{} AS {}", expr.trim(), name));
+        }
+
+        let source = if aggregate.source_table.contains('.') {
+            aggregate.source_table.clone()
+        } else {
+            format!("{}.{}", def.database, aggregate.source_table)
+        };
+        let mut sql = format!("SELECT {} FROM {}", select.join(", "), source);
+        if let Some(filter) = &aggregate.filter {
+            SchemaFilter::new(filter)
+                .map_err(|e| invalid(format!("invalid aggregate.filter '{}': {}", filter, e)))?;
+            sql.push_str(&format!(" WHERE {}", filter));
+        }
+        sql.push_str(&format!(" GROUP BY {}", group_by.join(", ")));
+
+        aggregates.insert(format!("{}.{}", def.database, def.table), sql);
+    }
+
+    Ok(aggregates)
+}
+
+/// Resolve the `constraints` section, checking that every referenced
+/// relationship type and label exists.
+fn resolve_graph_constraints(
+    definition: &GraphConstraintsDefinition,
+    nodes: &HashMap<String, NodeSchema>,
+    relationships: &HashMap<String, RelationshipSchema>,
+) -> Result<GraphConstraints, GraphSchemaError> {
+    let mut constraints = GraphConstraints::default();
+
+    for rel_type in &definition.acyclic {
+        let known = relationships
+            .keys()
+            .any(|key| key.split("::").next() == Some(rel_type.as_str()));
+        if !known {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!(
+                    "constraints.acyclic: unknown relationship type '{}'",
+                    rel_type
+                ),
+            });
+        }
+        constraints.acyclic_types.insert(rel_type.clone());
+    }
+
+    for group in &definition.disjoint_labels {
+        if group.len() < 2 {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!(
+                    "constraints.disjoint_labels: group {:?} must name at least two labels",
+                    group
+                ),
+            });
+        }
+        if let Some(label) = group.iter().find(|l| !nodes.contains_key(l.as_str())) {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!("constraints.disjoint_labels: unknown label '{}'", label),
+            });
+        }
+        constraints
+            .disjoint_label_groups
+            .push(group.iter().cloned().collect());
+    }
+
+    Ok(constraints)
+}
+
 /// Relationship definition in schema config
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RelationshipDefinition {
@@ -794,12 +1487,48 @@ pub struct StandardEdgeDefinition {
     #[serde(default = "default_naming_convention")]
     pub naming_convention: String,
 
+    /// Optional: Deferred discovery and validation (see `NodeDefinition::lazy`)
+    #[serde(default)]
+    pub lazy: bool,
+
     /// Optional: SQL predicate filter applied to all queries on this edge
     /// Column references are prefixed with table alias at query time
     /// Example: "is_active = 1 AND created_at >= now() - INTERVAL 30 DAY"
     #[serde(default)]
     pub filter: Option<String>,
 
+    /// Optional: Soft-delete column of this table (see `NodeDefinition::soft_delete`)
+    #[serde(default)]
+    pub soft_delete: Option<SoftDelete>,
+
+    /// Optional: Watermark column and typical lag of an eventually-updated
+    /// table (e.g. a materialized view's target), checked by queries that
+    /// set `max_staleness_secs`
+    #[serde(default)]
+    pub freshness: Option<Freshness>,
+
+    /// Optional: Valid-time and system-time columns of a bitemporal table,
+    /// read by `USE graph VALID AT` / `AS OF SYSTEM TIME`
+    /// Example: `{valid_time: {from: valid_from, to: valid_to}, system_time: {from: recorded_at, to: superseded_at}}`
+    #[serde(default)]
+    pub temporal: Option<EdgeTemporal>,
+
+    /// Optional: Cost of traversing one edge in `graph.shortestPath.dijkstra`,
+    /// an expression over this edge's properties
+    /// Example: "duration_min + 45"
+    #[serde(default)]
+    pub cost: Option<String>,
+
+    /// Optional: Collations of string properties (see `NodeDefinition::collations`)
+    #[serde(default)]
+    pub collations: HashMap<String, String>,
+
+    /// Optional: Aggregated edge. `table` is then a logical name; every read
+    /// of this edge type scans a GROUP BY over the raw `aggregate.source_table`
+    /// rows (one edge per from_id / to_id pair) instead.
+    #[serde(default)]
+    pub aggregate: Option<EdgeAggregateDefinition>,
+
     /// Optional: Constraint expression across from/to nodes for edge validation
     /// References use "from.property" and "to.property" syntax (resolved to columns at compile time)
     /// Example: "from.timestamp <= to.timestamp" or "from.context = to.context AND from.timestamp < to.timestamp"
@@ -828,6 +1557,43 @@ pub struct StandardEdgeDefinition {
     /// Values are SchemaType strings: "integer", "float", "string", "boolean", "datetime", "date", "uuid"
     #[serde(default)]
     pub property_types: HashMap<String, String>,
+
+    /// Optional: Catalog description (see `NodeDefinition::description`)
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Optional: Catalog owners (see `NodeDefinition::owners`)
+    #[serde(default)]
+    pub owners: Vec<String>,
+}
+
+/// Rollup behind an aggregated edge (`aggregate` on a standard edge)
+///
+/// Example YAML:
+/// ```yaml
+/// aggregate:
+///   source_table: conn_log
+///   filter: "proto = 'tcp'"
+///   measures:
+///     weight: "count()"
+///     bytes: "sum(bytes)"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgeAggregateDefinition {
+    /// Raw rows aggregated into edges (`table` or `database.table`; the
+    /// edge's database when unqualified)
+    pub source_table: String,
+    /// Columns grouped on besides from_id / to_id (one edge per combination)
+    #[serde(default)]
+    pub group_by: Vec<String>,
+    /// Aggregated columns: name -> aggregate expression over the raw rows.
+    /// Each is exposed as an edge property of the same name unless
+    /// property_mappings maps it.
+    #[serde(default)]
+    pub measures: BTreeMap<String, String>,
+    /// Optional: predicate on the raw rows, applied before grouping
+    #[serde(default)]
+    pub filter: Option<String>,
 }
 
 /// Polymorphic edge definition
@@ -923,11 +1689,39 @@ pub struct PolymorphicEdgeDefinition {
     #[serde(default)]
     pub filter: Option<String>,
 
+    /// Optional: Soft-delete column of this table (see `NodeDefinition::soft_delete`)
+    #[serde(default)]
+    pub soft_delete: Option<SoftDelete>,
+
+    /// Optional: Watermark column and typical lag of an eventually-updated
+    /// table (e.g. a materialized view's target), checked by queries that
+    /// set `max_staleness_secs`
+    #[serde(default)]
+    pub freshness: Option<Freshness>,
+
+    /// Optional: Valid-time and system-time columns of a bitemporal table,
+    /// read by `USE graph VALID AT` / `AS OF SYSTEM TIME`
+    /// Example: `{valid_time: {from: valid_from, to: valid_to}, system_time: {from: recorded_at, to: superseded_at}}`
+    #[serde(default)]
+    pub temporal: Option<EdgeTemporal>,
+
+    /// Optional: Collations of string properties (see `NodeDefinition::collations`)
+    #[serde(default)]
+    pub collations: HashMap<String, String>,
+
     /// Optional: Constraint expression across from/to nodes for edge validation
     /// References use "from.property" and "to.property" syntax (resolved to columns at compile time)
     /// Example: "from.timestamp <= to.timestamp" or "from.context = to.context AND from.timestamp < to.timestamp"
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub constraints: Option<String>,
+
+    /// Optional: Catalog description, shared by every discovered type
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Optional: Catalog owners (see `NodeDefinition::owners`)
+    #[serde(default)]
+    pub owners: Vec<String>,
 }
 
 /// Convert snake_case to camelCase
@@ -1035,6 +1829,65 @@ struct TableDiscovery {
     engine: Option<TableEngine>,
 }
 
+/// ClickHouse metadata of a schema's tables, keyed by `database.table`:
+/// column names and types (for auto-discovery) and the detected engine.
+///
+/// Filled by `GraphSchemaConfig::discover_tables()` at load; lazy elements
+/// are added once their table has been checked.
+#[derive(Debug, Clone, Default)]
+pub struct DiscoveredTables {
+    tables: HashMap<String, DiscoveredTable>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct DiscoveredTable {
+    /// (name, type) in table order; `None` when not queried
+    columns: Option<Vec<(String, String)>>,
+    engine: Option<TableEngine>,
+}
+
+impl DiscoveredTables {
+    fn key(database: &str, table: &str) -> String {
+        format!("{}.{}", database, table)
+    }
+
+    /// Record the metadata of one table, replacing what was known about it.
+    pub fn insert(
+        &mut self,
+        database: &str,
+        table: &str,
+        columns: Option<Vec<(String, String)>>,
+        engine: Option<TableEngine>,
+    ) {
+        self.tables.insert(
+            Self::key(database, table),
+            DiscoveredTable { columns, engine },
+        );
+    }
+
+    /// Discovery data for one element: columns only when it auto-discovers
+    /// them, types only when asked for (nodes).
+    fn discovery(
+        &self,
+        database: &str,
+        table: &str,
+        auto_discover: bool,
+        with_types: bool,
+    ) -> TableDiscovery {
+        let Some(found) = self.tables.get(&Self::key(database, table)) else {
+            return TableDiscovery::default();
+        };
+        let columns = found.columns.as_ref().filter(|_| auto_discover);
+        TableDiscovery {
+            columns: columns.map(|cols| cols.iter().map(|(name, _)| name.clone()).collect()),
+            column_info: columns
+                .filter(|_| with_types)
+                .map(|cols| cols.iter().cloned().collect()),
+            engine: found.engine.clone(),
+        }
+    }
+}
+
 /// Build property mappings with optional auto-discovery
 fn build_property_mappings(
     manual_mappings: HashMap<String, String>,
@@ -1648,9 +2501,18 @@ fn build_standard_edge_schema(
     nodes: &HashMap<String, NodeSchema>,
     discovery: &TableDiscovery,
 ) -> Result<RelationshipSchema, GraphSchemaError> {
-    // Build property mappings (with optional auto-discovery)
+    // Build property mappings (with optional auto-discovery); an aggregate's
+    // measures are properties of the same name unless mapped explicitly
+    let mut properties = std_edge.properties.clone();
+    if let Some(aggregate) = &std_edge.aggregate {
+        for measure in aggregate.measures.keys() {
+            properties
+                .entry(measure.clone())
+                .or_insert_with(|| measure.clone());
+        }
+    }
     let raw_mappings = build_property_mappings(
-        std_edge.properties.clone(),
+        properties,
         discovery,
         std_edge.auto_discover_columns,
         &std_edge.exclude_columns,
@@ -1659,8 +2521,12 @@ fn build_standard_edge_schema(
 
     let property_mappings = parse_property_mappings(raw_mappings)?;
 
-    // Determine use_final
-    let use_final = determine_use_final(std_edge.use_final, &discovery.engine);
+    // Determine use_final (an aggregate subquery takes no FINAL)
+    let use_final = if std_edge.aggregate.is_some() {
+        Some(false)
+    } else {
+        determine_use_final(std_edge.use_final, &discovery.engine)
+    };
 
     // Parse filter if provided
     let filter = if let Some(filter_str) = &std_edge.filter {
@@ -1876,6 +2742,290 @@ fn build_polymorphic_edge_schemas(
 }
 
 impl GraphSchemaConfig {
+    /// Copy of this schema under `name`, with every node and edge table in
+    /// `database` when given.
+    pub fn for_tenant(&self, name: &str, database: Option<&str>) -> Self {
+        let mut config = self.clone();
+        config.name = Some(name.to_string());
+        if let Some(database) = database {
+            let schema = &mut config.graph_schema;
+            for node in &mut schema.nodes {
+                node.database = database.to_string();
+            }
+            for rel in &mut schema.relationships {
+                rel.database = database.to_string();
+            }
+            for edge in &mut schema.edges {
+                match edge {
+                    EdgeDefinition::Standard(def) => def.database = database.to_string(),
+                    EdgeDefinition::Polymorphic(def) => def.database = database.to_string(),
+                }
+            }
+        }
+        config
+    }
+
+    /// Copy of this schema under `name`, restricted to the labels and types
+    /// of its view `view`, with the view's filters ANDed into each table's
+    /// own. The copy declares no views.
+    pub fn for_view(&self, name: &str, view: &str) -> Result<Self, GraphSchemaError> {
+        let invalid = |message: String| GraphSchemaError::InvalidConfig {
+            message: format!("view '{}': {}", view, message),
+        };
+        let definition = self
+            .graph_schema
+            .views
+            .get(view)
+            .ok_or_else(|| invalid("not declared".to_string()))?;
+
+        let labels: BTreeSet<&str> = self
+            .graph_schema
+            .nodes
+            .iter()
+            .map(|n| n.label.as_str())
+            .collect();
+        let types: BTreeSet<&str> = self
+            .graph_schema
+            .relationships
+            .iter()
+            .map(|r| r.type_name.as_str())
+            .chain(self.graph_schema.edges.iter().flat_map(|edge| match edge {
+                EdgeDefinition::Standard(def) => vec![def.type_name.as_str()],
+                EdgeDefinition::Polymorphic(def) => {
+                    def.type_values.iter().map(String::as_str).collect()
+                }
+            }))
+            .collect();
+        for label in definition.labels.iter().flatten() {
+            if !labels.contains(label.as_str()) {
+                return Err(invalid(format!("unknown label '{}'", label)));
+            }
+        }
+        for rel_type in definition.types.iter().flatten() {
+            if !types.contains(rel_type.as_str()) {
+                return Err(invalid(format!("unknown relationship type '{}'", rel_type)));
+            }
+        }
+        if let Some(name) = definition
+            .filters
+            .keys()
+            .find(|k| !labels.contains(k.as_str()) && !types.contains(k.as_str()))
+        {
+            return Err(invalid(format!(
+                "filter on unknown label or relationship type '{}'",
+                name
+            )));
+        }
+
+        let keeps_label = |label: &str| {
+            definition
+                .labels
+                .as_ref()
+                .is_none_or(|kept| kept.iter().any(|l| l == label))
+        };
+        let keeps_type = |rel_type: &str| {
+            definition
+                .types
+                .as_ref()
+                .is_none_or(|kept| kept.iter().any(|t| t == rel_type))
+        };
+        let keeps_endpoint = |label: Option<&String>| label.is_none_or(|l| keeps_label(l));
+        let and = |filter: &mut Option<String>, predicate: String| {
+            *filter = Some(match filter.take() {
+                Some(existing) => format!("({}) AND ({})", existing, predicate),
+                None => predicate,
+            });
+        };
+
+        let mut config = self.clone();
+        config.name = Some(name.to_string());
+        let schema = &mut config.graph_schema;
+        schema.views.clear();
+        schema.nodes.retain(|node| keeps_label(&node.label));
+        for node in &mut schema.nodes {
+            if let Some(predicate) = definition.filters.get(&node.label) {
+                and(&mut node.filter, predicate.clone());
+            }
+        }
+        schema.relationships.retain(|rel| {
+            keeps_type(&rel.type_name)
+                && keeps_endpoint(rel.from_node.as_ref())
+                && keeps_endpoint(rel.to_node.as_ref())
+        });
+        for rel in &mut schema.relationships {
+            if let Some(predicate) = definition.filters.get(&rel.type_name) {
+                and(&mut rel.filter, predicate.clone());
+            }
+        }
+        schema.edges.retain_mut(|edge| match edge {
+            EdgeDefinition::Standard(def) => {
+                keeps_type(&def.type_name)
+                    && keeps_label(&def.from_node)
+                    && keeps_label(&def.to_node)
+            }
+            EdgeDefinition::Polymorphic(def) => {
+                def.type_values.retain(|t| keeps_type(t));
+                for values in [&mut def.from_label_values, &mut def.to_label_values]
+                    .into_iter()
+                    .flatten()
+                {
+                    values.retain(|l| keeps_label(l));
+                }
+                !def.type_values.is_empty()
+                    && keeps_endpoint(def.from_node.as_ref())
+                    && keeps_endpoint(def.to_node.as_ref())
+            }
+        });
+        for edge in &mut schema.edges {
+            match edge {
+                EdgeDefinition::Standard(def) => {
+                    if let Some(predicate) = definition.filters.get(&def.type_name) {
+                        and(&mut def.filter, predicate.clone());
+                    }
+                }
+                EdgeDefinition::Polymorphic(def) => {
+                    // Rows of the table's other types pass through
+                    let predicates: Vec<String> = def
+                        .type_values
+                        .iter()
+                        .filter_map(|t| {
+                            let predicate = definition.filters.get(t)?;
+                            Some(match &def.type_column {
+                                Some(column) => format!(
+                                    "{} <> '{}' OR ({})",
+                                    column,
+                                    t.replace('\'', "\\'"),
+                                    predicate
+                                ),
+                                None => predicate.clone(),
+                            })
+                        })
+                        .collect();
+                    for predicate in predicates {
+                        and(&mut def.filter, predicate);
+                    }
+                }
+            }
+        }
+
+        let kept_labels: BTreeSet<String> = schema.nodes.iter().map(|n| n.label.clone()).collect();
+        schema
+            .vector_indexes
+            .retain(|index| kept_labels.contains(&index.label));
+        schema
+            .fulltext_indexes
+            .retain(|index| kept_labels.contains(&index.label));
+        for group in &mut schema.constraints.disjoint_labels {
+            group.retain(|label| kept_labels.contains(label));
+        }
+        schema
+            .constraints
+            .disjoint_labels
+            .retain(|group| group.len() >= 2);
+        let kept_types: BTreeSet<String> = schema
+            .relationships
+            .iter()
+            .map(|r| r.type_name.clone())
+            .chain(schema.edges.iter().flat_map(|edge| match edge {
+                EdgeDefinition::Standard(def) => vec![def.type_name.clone()],
+                EdgeDefinition::Polymorphic(def) => def.type_values.clone(),
+            }))
+            .collect();
+        schema
+            .constraints
+            .acyclic
+            .retain(|rel_type| kept_types.contains(rel_type));
+        Ok(config)
+    }
+
+    /// Copy of this schema with the node and edge definitions of the YAML
+    /// fragment `overrides` (`nodes:` / `edges:` lists) merged in. A node
+    /// replaces the one with the same label, a standard edge the one with the
+    /// same type and endpoints; anything else is added. Every definition must
+    /// read a database this schema already reads, so a tenant's overrides stay
+    /// in the tenant's database.
+    pub fn with_overrides(&self, overrides: &str) -> Result<Self, GraphSchemaError> {
+        let overrides: SchemaOverrides =
+            serde_yaml::from_str(overrides).map_err(|e| GraphSchemaError::ConfigParseError {
+                error: format!("schema overrides: {}", e),
+            })?;
+        let databases: BTreeSet<&str> = self
+            .graph_schema
+            .nodes
+            .iter()
+            .map(|n| n.database.as_str())
+            .chain(
+                self.graph_schema
+                    .relationships
+                    .iter()
+                    .map(|r| r.database.as_str()),
+            )
+            .chain(self.graph_schema.edges.iter().map(|edge| match edge {
+                EdgeDefinition::Standard(def) => def.database.as_str(),
+                EdgeDefinition::Polymorphic(def) => def.database.as_str(),
+            }))
+            .collect();
+        let foreign = |kind: &str, name: &str, database: &str| {
+            GraphSchemaError::InvalidConfig {
+            message: format!(
+                "schema overrides: {} '{}' reads database '{}', outside the schema's databases {:?}",
+                kind, name, database, databases
+            ),
+        }
+        };
+        for node in &overrides.nodes {
+            if !databases.contains(node.database.as_str()) {
+                return Err(foreign("node", &node.label, &node.database));
+            }
+        }
+        for edge in &overrides.edges {
+            let (name, database) = match edge {
+                EdgeDefinition::Standard(def) => (def.type_name.as_str(), def.database.as_str()),
+                EdgeDefinition::Polymorphic(def) => (def.table.as_str(), def.database.as_str()),
+            };
+            if !databases.contains(database) {
+                return Err(foreign("edge", name, database));
+            }
+        }
+
+        let mut config = self.clone();
+        let schema = &mut config.graph_schema;
+        for node in overrides.nodes {
+            match schema.nodes.iter_mut().find(|n| n.label == node.label) {
+                Some(existing) => *existing = node,
+                None => schema.nodes.push(node),
+            }
+        }
+        for edge in overrides.edges {
+            if let EdgeDefinition::Standard(def) = &edge {
+                for label in [&def.from_node, &def.to_node] {
+                    if !schema.nodes.iter().any(|n| &n.label == label) {
+                        return Err(GraphSchemaError::InvalidConfig {
+                            message: format!(
+                                "schema overrides: edge '{}' references undefined node label '{}'",
+                                def.type_name, label
+                            ),
+                        });
+                    }
+                }
+            }
+            let existing = match &edge {
+                EdgeDefinition::Standard(def) => schema.edges.iter_mut().find(|e| {
+                    matches!(e, EdgeDefinition::Standard(old)
+                        if old.type_name == def.type_name
+                            && old.from_node == def.from_node
+                            && old.to_node == def.to_node)
+                }),
+                EdgeDefinition::Polymorphic(_) => None,
+            };
+            match existing {
+                Some(existing) => *existing = edge,
+                None => schema.edges.push(edge),
+            }
+        }
+        Ok(config)
+    }
+
     /// Load graph schema configuration from a YAML file
     pub fn from_yaml_file<P: AsRef<Path>>(path: P) -> Result<Self, GraphSchemaError> {
         let contents = fs::read_to_string(path).map_err(|e| GraphSchemaError::ConfigReadError {
@@ -1901,6 +3051,24 @@ impl GraphSchemaConfig {
             });
         }
 
+        if let Some(cluster) = &self.graph_schema.cluster {
+            if cluster.name.trim().is_empty() {
+                return Err(GraphSchemaError::InvalidConfig {
+                    message: "cluster.name must name a ClickHouse cluster".to_string(),
+                });
+            }
+        }
+        if let Some(view) = self
+            .graph_schema
+            .views
+            .keys()
+            .find(|view| view.is_empty() || view.contains('.'))
+        {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!("view name '{}' must be non-empty and contain no '.'", view),
+            });
+        }
+
         // Validate polymorphic node configurations (label_column + label_value consistency)
         self.validate_polymorphic_nodes()?;
 
@@ -2223,10 +3391,69 @@ impl GraphSchemaConfig {
     /// This is the sync version that doesn't require a ClickHouse connection.
     /// For auto-discovery and engine detection, use `to_graph_schema_with_client()`.
     pub fn to_graph_schema(&self) -> Result<GraphSchema, GraphSchemaError> {
-        self.validate()?;
+        self.to_graph_schema_discovered(&DiscoveredTables::default())
+    }
+
+    /// Convert to GraphSchema using table metadata gathered beforehand
+    /// (see `discover_tables()`); tables missing from `discovered` are built
+    /// without auto-discovered properties or engine.
+    pub fn to_graph_schema_discovered(
+        &self,
+        discovered: &DiscoveredTables,
+    ) -> Result<GraphSchema, GraphSchemaError> {
+        match self.distributed() {
+            Some((config, layout)) => {
+                let mut schema = config.build_graph_schema(discovered)?;
+                schema.set_cluster(Some(layout));
+                Ok(schema)
+            }
+            None => self.build_graph_schema(discovered),
+        }
+    }
+
+    /// With a `cluster`, this config reading every mapped local table through
+    /// its Distributed table, and the layout recording those local tables;
+    /// `None` without one.
+    fn distributed(&self) -> Option<(GraphSchemaConfig, ClusterLayout)> {
+        let cluster = self.graph_schema.cluster.as_ref()?;
+        let mut config = self.clone();
+        let mut local_tables = BTreeMap::new();
+        let mut resolve = |database: &mut String, table: &mut String| {
+            if let Some((db, distributed)) = cluster.distributed_table(database, table) {
+                local_tables.insert(
+                    format!("{}.{}", db, distributed),
+                    format!("{}.{}", database, table),
+                );
+                *database = db;
+                *table = distributed;
+            }
+        };
+        for node in &mut config.graph_schema.nodes {
+            resolve(&mut node.database, &mut node.table);
+        }
+        for rel in &mut config.graph_schema.relationships {
+            resolve(&mut rel.database, &mut rel.table);
+        }
+        for edge in &mut config.graph_schema.edges {
+            match edge {
+                EdgeDefinition::Standard(def) => resolve(&mut def.database, &mut def.table),
+                EdgeDefinition::Polymorphic(def) => resolve(&mut def.database, &mut def.table),
+            }
+        }
+        Some((
+            config,
+            ClusterLayout {
+                name: cluster.name.clone(),
+                local_tables,
+            },
+        ))
+    }
 
-        // No discovery data in sync mode
-        let no_discovery = TableDiscovery::default();
+    fn build_graph_schema(
+        &self,
+        discovered: &DiscoveredTables,
+    ) -> Result<GraphSchema, GraphSchemaError> {
+        self.validate()?;
 
         let mut nodes = HashMap::new();
         let mut relationships = HashMap::new();
@@ -2234,7 +3461,15 @@ impl GraphSchemaConfig {
         // Convert node definitions using shared builder
         // Store with BOTH composite key (table::label) AND label-only for backward compat
         for node_def in &self.graph_schema.nodes {
-            let node_schema = build_node_schema(node_def, &no_discovery)?;
+            let node_schema = build_node_schema(
+                node_def,
+                &discovered.discovery(
+                    &node_def.database,
+                    &node_def.table,
+                    node_def.auto_discover_columns,
+                    true,
+                ),
+            )?;
             // Composite key for table-specific lookup
             let composite_key = format!(
                 "{}::{}::{}",
@@ -2255,8 +3490,17 @@ impl GraphSchemaConfig {
 
         // Convert legacy relationship definitions using shared builder
         for rel_def in &self.graph_schema.relationships {
-            let rel_schema =
-                build_relationship_schema(rel_def, &default_node_type, &nodes, &no_discovery)?;
+            let rel_schema = build_relationship_schema(
+                rel_def,
+                &default_node_type,
+                &nodes,
+                &discovered.discovery(
+                    &rel_def.database,
+                    &rel_def.table,
+                    rel_def.auto_discover_columns,
+                    false,
+                ),
+            )?;
             // Register with composite key: TYPE::FROM::TO
             let composite_key = GraphSchema::make_rel_composite_key(
                 &rel_def.type_name,
@@ -2270,7 +3514,16 @@ impl GraphSchemaConfig {
         for edge_def in &self.graph_schema.edges {
             match edge_def {
                 EdgeDefinition::Standard(std_edge) => {
-                    let rel_schema = build_standard_edge_schema(std_edge, &nodes, &no_discovery)?;
+                    let rel_schema = build_standard_edge_schema(
+                        std_edge,
+                        &nodes,
+                        &discovered.discovery(
+                            &std_edge.database,
+                            &std_edge.table,
+                            std_edge.auto_discover_columns,
+                            false,
+                        ),
+                    )?;
                     // Register with composite key: TYPE::FROM::TO
                     let composite_key = GraphSchema::make_rel_composite_key(
                         &std_edge.type_name,
@@ -2284,7 +3537,10 @@ impl GraphSchemaConfig {
                     relationships.insert(composite_key, rel_schema);
                 }
                 EdgeDefinition::Polymorphic(poly_edge) => {
-                    let poly_schemas = build_polymorphic_edge_schemas(poly_edge, &no_discovery)?;
+                    let poly_schemas = build_polymorphic_edge_schemas(
+                        poly_edge,
+                        &discovered.discovery(&poly_edge.database, &poly_edge.table, false, false),
+                    )?;
                     for (type_name, rel_schema) in poly_schemas {
                         if relationships.contains_key(&type_name) {
                             log::warn!(
@@ -2314,14 +3570,42 @@ impl GraphSchemaConfig {
         let fulltext_indexes =
             resolve_fulltext_indexes(&self.graph_schema.fulltext_indexes, &nodes)?;
 
-        Ok(GraphSchema::build_with_indexes(
+        let unfiltered_scan_limits = resolve_unfiltered_scan_limits(&self.graph_schema.nodes)?;
+        let return_projections = resolve_return_projections(&self.graph_schema.nodes)?;
+        let soft_deletes =
+            resolve_soft_deletes(&self.graph_schema.nodes, &self.graph_schema.edges)?;
+        let edge_aggregates = resolve_edge_aggregates(
+            &self.graph_schema.edges,
+            self.graph_schema.snapshot_column.as_deref(),
+        )?;
+        let collations = resolve_collations(&self.graph_schema.nodes, &self.graph_schema.edges)?;
+        let freshness = resolve_freshness(&self.graph_schema.edges)?;
+        let edge_costs = resolve_edge_costs(&self.graph_schema.edges)?;
+        let edge_temporal = resolve_edge_temporal(&self.graph_schema.edges)?;
+        let constraints =
+            resolve_graph_constraints(&self.graph_schema.constraints, &nodes, &relationships)?;
+
+        let mut schema = GraphSchema::build_with_indexes(
             1,
             "default".to_string(),
             nodes,
             relationships,
             vector_indexes,
             fulltext_indexes,
-        ))
+        );
+        schema.set_unfiltered_scan_limits(unfiltered_scan_limits);
+        schema.set_return_projections(return_projections);
+        schema.set_constraints(constraints);
+        schema.set_unknown_labels(self.graph_schema.unknown_labels);
+        schema.set_query_cache(self.graph_schema.query_cache);
+        schema.set_snapshot_column(self.graph_schema.snapshot_column.clone());
+        schema.set_soft_deletes(soft_deletes)?;
+        schema.set_edge_aggregates(edge_aggregates);
+        schema.set_collations(collations);
+        schema.set_freshness(freshness);
+        schema.set_edge_costs(edge_costs);
+        schema.set_edge_temporal(edge_temporal);
+        Ok(schema)
     }
 
     /// Convert to GraphSchema with auto-discovery and engine detection
@@ -2330,8 +3614,7 @@ impl GraphSchemaConfig {
     /// - Auto-discovery of table columns when `auto_discover_columns = true`
     /// - Automatic engine detection for FINAL keyword support
     ///
-    /// Uses the same helper functions as `to_graph_schema()` but with populated
-    /// `TableDiscovery` data from ClickHouse metadata queries.
+    /// Elements marked `lazy` are built without either; see `discover_tables()`.
     ///
     /// # Arguments
     /// * `client` - ClickHouse client for querying metadata
@@ -2342,194 +3625,103 @@ impl GraphSchemaConfig {
         &self,
         client: &clickhouse::Client,
     ) -> Result<GraphSchema, GraphSchemaError> {
-        use super::column_info::query_table_columns;
-        use super::engine_detection::detect_table_engine;
-
         self.validate()?;
+        let discovered = self.discover_tables(client).await?;
+        self.to_graph_schema_discovered(&discovered)
+    }
 
-        let mut nodes = HashMap::new();
-        let mut relationships = HashMap::new();
+    /// Query ClickHouse for the metadata `to_graph_schema_discovered()` needs:
+    /// the engine of every table, and the columns of tables whose element
+    /// auto-discovers them. Lazy elements are skipped.
+    pub async fn discover_tables(
+        &self,
+        client: &clickhouse::Client,
+    ) -> Result<DiscoveredTables, GraphSchemaError> {
+        // A cluster graph reads, and so discovers, its Distributed tables
+        match self.distributed() {
+            Some((config, _)) => config.discover_mapped_tables(client).await,
+            None => self.discover_mapped_tables(client).await,
+        }
+    }
 
-        // Convert node definitions with auto-discovery
-        // Store with BOTH composite key (table::label) AND label-only for backward compat
+    async fn discover_mapped_tables(
+        &self,
+        client: &clickhouse::Client,
+    ) -> Result<DiscoveredTables, GraphSchemaError> {
+        use super::column_info::query_table_column_info;
+        use super::engine_detection::detect_table_engine;
+
+        // (database, table, auto-discover columns, element described in errors)
+        let mut tables: Vec<(&str, &str, bool, String)> = Vec::new();
         for node_def in &self.graph_schema.nodes {
-            // Gather discovery data
-            let (columns, column_info) = if node_def.auto_discover_columns {
-                use super::column_info::query_table_column_info;
-                let col_info = query_table_column_info(client, &node_def.database, &node_def.table)
+            if !node_def.lazy {
+                tables.push((
+                    &node_def.database,
+                    &node_def.table,
+                    node_def.auto_discover_columns,
+                    format!("node '{}'", node_def.label),
+                ));
+            }
+        }
+        for rel_def in &self.graph_schema.relationships {
+            tables.push((
+                &rel_def.database,
+                &rel_def.table,
+                rel_def.auto_discover_columns,
+                format!("relationship '{}'", rel_def.type_name),
+            ));
+        }
+        for edge_def in &self.graph_schema.edges {
+            match edge_def {
+                EdgeDefinition::Standard(std_edge) if !std_edge.lazy => tables.push((
+                    &std_edge.database,
+                    &std_edge.table,
+                    std_edge.auto_discover_columns,
+                    format!("edge '{}'", std_edge.type_name),
+                )),
+                EdgeDefinition::Standard(_) => {}
+                // Polymorphic edges don't support auto_discover_columns,
+                // but we still detect the engine
+                EdgeDefinition::Polymorphic(poly_edge) => tables.push((
+                    &poly_edge.database,
+                    &poly_edge.table,
+                    false,
+                    format!("edge table '{}'", poly_edge.table),
+                )),
+            }
+        }
+
+        let mut discovered = DiscoveredTables::default();
+        for (database, table, auto_discover, element) in tables {
+            // Tables shared by several elements are queried once (with
+            // columns if any of them auto-discovers)
+            if let Some(known) = discovered
+                .tables
+                .get(&DiscoveredTables::key(database, table))
+            {
+                if known.columns.is_some() || !auto_discover {
+                    continue;
+                }
+            }
+            let columns = if auto_discover {
+                let col_info = query_table_column_info(client, database, table)
                     .await
                     .map_err(|e| GraphSchemaError::ConfigReadError {
-                        error: format!(
-                            "Failed to query columns for node '{}': {}",
-                            node_def.label, e
-                        ),
+                        error: format!("Failed to query columns for {}: {}", element, e),
                     })?;
-                let names: Vec<String> = col_info.iter().map(|c| c.name.clone()).collect();
-                let types: HashMap<String, String> = col_info
-                    .into_iter()
-                    .map(|c| (c.name, c.data_type))
-                    .collect();
-                (Some(names), Some(types))
-            } else {
-                (None, None)
-            };
-
-            let engine = detect_table_engine(client, &node_def.database, &node_def.table)
-                .await
-                .ok();
-
-            let discovery = TableDiscovery {
-                columns,
-                column_info,
-                engine,
-            };
-
-            let node_schema = build_node_schema(node_def, &discovery)?;
-            // Composite key for table-specific lookup
-            let composite_key = format!(
-                "{}::{}::{}",
-                node_def.database, node_def.table, node_def.label
-            );
-            nodes.insert(composite_key, node_schema.clone());
-            // Label-only key for backward compat (last one wins if duplicates)
-            nodes.insert(node_def.label.clone(), node_schema);
-        }
-
-        // Get default node type for legacy relationship definitions
-        let default_node_type = self
-            .graph_schema
-            .nodes
-            .first()
-            .map(|n| n.label.clone())
-            .unwrap_or_else(|| "Unknown".to_string());
-
-        // Convert relationship definitions with auto-discovery
-        for rel_def in &self.graph_schema.relationships {
-            let columns = if rel_def.auto_discover_columns {
                 Some(
-                    query_table_columns(client, &rel_def.database, &rel_def.table)
-                        .await
-                        .map_err(|e| GraphSchemaError::ConfigReadError {
-                            error: format!(
-                                "Failed to query columns for relationship '{}': {}",
-                                rel_def.type_name, e
-                            ),
-                        })?,
+                    col_info
+                        .into_iter()
+                        .map(|c| (c.name, c.data_type))
+                        .collect(),
                 )
             } else {
                 None
             };
-
-            let engine = detect_table_engine(client, &rel_def.database, &rel_def.table)
-                .await
-                .ok();
-
-            let discovery = TableDiscovery {
-                columns,
-                column_info: None,
-                engine,
-            };
-
-            let rel_schema =
-                build_relationship_schema(rel_def, &default_node_type, &nodes, &discovery)?;
-            // Use composite key: TYPE::FROM::TO
-            let composite_key = GraphSchema::make_rel_composite_key(
-                &rel_def.type_name,
-                &rel_schema.from_node,
-                &rel_schema.to_node,
-            );
-            relationships.insert(composite_key, rel_schema.clone());
-        }
-
-        // Convert edge definitions (new format) with auto-discovery
-        for edge_def in &self.graph_schema.edges {
-            match edge_def {
-                EdgeDefinition::Standard(std_edge) => {
-                    let columns = if std_edge.auto_discover_columns {
-                        Some(
-                            query_table_columns(client, &std_edge.database, &std_edge.table)
-                                .await
-                                .map_err(|e| GraphSchemaError::ConfigReadError {
-                                    error: format!(
-                                        "Failed to query columns for edge '{}': {}",
-                                        std_edge.type_name, e
-                                    ),
-                                })?,
-                        )
-                    } else {
-                        None
-                    };
-
-                    let engine = detect_table_engine(client, &std_edge.database, &std_edge.table)
-                        .await
-                        .ok();
-
-                    let discovery = TableDiscovery {
-                        columns,
-                        column_info: None,
-                        engine,
-                    };
-
-                    let rel_schema = build_standard_edge_schema(std_edge, &nodes, &discovery)?;
-                    // Use composite key: TYPE::FROM::TO
-                    let composite_key = GraphSchema::make_rel_composite_key(
-                        &std_edge.type_name,
-                        &rel_schema.from_node,
-                        &rel_schema.to_node,
-                    );
-                    relationships.insert(composite_key, rel_schema);
-                }
-                EdgeDefinition::Polymorphic(poly_edge) => {
-                    // Polymorphic edges don't support auto_discover_columns,
-                    // but we still detect the engine
-                    let engine = detect_table_engine(client, &poly_edge.database, &poly_edge.table)
-                        .await
-                        .ok();
-
-                    let discovery = TableDiscovery {
-                        columns: None,
-                        column_info: None,
-                        engine,
-                    };
-
-                    for (type_name, rel_schema) in
-                        build_polymorphic_edge_schemas(poly_edge, &discovery)?
-                    {
-                        if relationships.contains_key(&type_name) {
-                            log::warn!(
-                                "⚠️ Schema collision: polymorphic edge '{}' overwrites existing \
-                                 standard edge with the same type name.",
-                                type_name
-                            );
-                        }
-                        relationships.insert(type_name, rel_schema);
-                    }
-                }
-            }
+            let engine = detect_table_engine(client, database, table).await.ok();
+            discovered.insert(database, table, columns, engine);
         }
-
-        // Strip composite node keys (db::table::label) — only needed during build-time
-        // for denormalized edge property resolution. Runtime uses simple label keys only.
-        let nodes: HashMap<String, NodeSchema> = nodes
-            .into_iter()
-            .filter(|(k, _)| !k.contains("::"))
-            .collect();
-
-        // Resolve vector index definitions against node schemas
-        let vector_indexes = resolve_vector_indexes(&self.graph_schema.vector_indexes, &nodes)?;
-
-        // Resolve fulltext index definitions against node schemas
-        let fulltext_indexes =
-            resolve_fulltext_indexes(&self.graph_schema.fulltext_indexes, &nodes)?;
-
-        Ok(GraphSchema::build_with_indexes(
-            1,
-            "default".to_string(),
-            nodes,
-            relationships,
-            vector_indexes,
-            fulltext_indexes,
-        ))
+        Ok(discovered)
     }
 }
 
@@ -2864,9 +4056,12 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    collations: HashMap::new(),
                     auto_discover_columns: false,
                     exclude_columns: vec![],
                     naming_convention: "snake_case".to_string(),
+                    lazy: false,
                     // Denormalized node properties defined HERE (on node, not edge)
                     from_node_properties: Some({
                         let mut props = HashMap::new();
@@ -2885,6 +4080,12 @@ graph_schema:
                     source: None,
                     property_types: HashMap::new(),
                     id_generation: None,
+                    max_unfiltered_rows: None,
+                    on_unfiltered_scan: None,
+                    return_properties: None,
+                    max_return_columns: None,
+                    description: None,
+                    owners: Vec::new(),
                 }],
                 relationships: vec![],
                 edges: vec![EdgeDefinition::Standard(StandardEdgeDefinition {
@@ -2906,17 +4107,32 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    freshness: None,
+                    temporal: None,
+                    cost: None,
+                    collations: HashMap::new(),
+                    aggregate: None,
                     auto_discover_columns: false,
                     exclude_columns: vec![],
                     naming_convention: "snake_case".to_string(),
+                    lazy: false,
                     constraints: None,
                     id_type: None,
                     id_types: None,
                     source: None,
                     property_types: HashMap::new(),
+                    description: None,
+                    owners: Vec::new(),
                 })],
                 vector_indexes: Vec::new(),
                 fulltext_indexes: Vec::new(),
+                constraints: Default::default(),
+                unknown_labels: Default::default(),
+                query_cache: Default::default(),
+                snapshot_column: None,
+                cluster: None,
+                views: BTreeMap::new(),
             },
         };
 
@@ -2942,9 +4158,12 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    collations: HashMap::new(),
                     auto_discover_columns: false,
                     exclude_columns: vec![],
                     naming_convention: "snake_case".to_string(),
+                    lazy: false,
                     from_node_properties: None, // Missing! Node is used as from_node in edge
                     to_node_properties: Some({
                         let mut props = HashMap::new();
@@ -2956,6 +4175,12 @@ graph_schema:
                     source: None,
                     property_types: HashMap::new(),
                     id_generation: None,
+                    max_unfiltered_rows: None,
+                    on_unfiltered_scan: None,
+                    return_properties: None,
+                    max_return_columns: None,
+                    description: None,
+                    owners: Vec::new(),
                 }],
                 relationships: vec![],
                 edges: vec![EdgeDefinition::Standard(StandardEdgeDefinition {
@@ -2971,17 +4196,32 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    freshness: None,
+                    temporal: None,
+                    cost: None,
+                    collations: HashMap::new(),
+                    aggregate: None,
                     auto_discover_columns: false,
                     exclude_columns: vec![],
                     naming_convention: "snake_case".to_string(),
+                    lazy: false,
                     constraints: None,
                     id_type: None,
                     id_types: None,
                     source: None,
                     property_types: HashMap::new(),
+                    description: None,
+                    owners: Vec::new(),
                 })],
                 vector_indexes: Vec::new(),
                 fulltext_indexes: Vec::new(),
+                constraints: Default::default(),
+                unknown_labels: Default::default(),
+                query_cache: Default::default(),
+                snapshot_column: None,
+                cluster: None,
+                views: BTreeMap::new(),
             },
         };
 
@@ -3011,9 +4251,12 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    collations: HashMap::new(),
                     auto_discover_columns: false,
                     exclude_columns: vec![],
                     naming_convention: "snake_case".to_string(),
+                    lazy: false,
                     from_node_properties: None,
                     to_node_properties: None,
                     r#type: None,
@@ -3021,6 +4264,12 @@ graph_schema:
                     source: None,
                     property_types: HashMap::new(),
                     id_generation: None,
+                    max_unfiltered_rows: None,
+                    on_unfiltered_scan: None,
+                    return_properties: None,
+                    max_return_columns: None,
+                    description: None,
+                    owners: Vec::new(),
                 }],
                 relationships: vec![],
                 edges: vec![EdgeDefinition::Polymorphic(PolymorphicEdgeDefinition {
@@ -3047,10 +4296,22 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    freshness: None,
+                    temporal: None,
+                    collations: HashMap::new(),
                     constraints: None,
+                    description: None,
+                    owners: Vec::new(),
                 })],
                 vector_indexes: Vec::new(),
                 fulltext_indexes: Vec::new(),
+                constraints: Default::default(),
+                unknown_labels: Default::default(),
+                query_cache: Default::default(),
+                snapshot_column: None,
+                cluster: None,
+                views: BTreeMap::new(),
             },
         };
 
@@ -3075,9 +4336,12 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    collations: HashMap::new(),
                     auto_discover_columns: false,
                     exclude_columns: vec![],
                     naming_convention: "snake_case".to_string(),
+                    lazy: false,
                     from_node_properties: None,
                     to_node_properties: None,
                     r#type: None,
@@ -3085,6 +4349,12 @@ graph_schema:
                     source: None,
                     property_types: HashMap::new(),
                     id_generation: None,
+                    max_unfiltered_rows: None,
+                    on_unfiltered_scan: None,
+                    return_properties: None,
+                    max_return_columns: None,
+                    description: None,
+                    owners: Vec::new(),
                 }],
                 relationships: vec![],
                 edges: vec![EdgeDefinition::Polymorphic(PolymorphicEdgeDefinition {
@@ -3106,10 +4376,22 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    freshness: None,
+                    temporal: None,
+                    collations: HashMap::new(),
                     constraints: None,
+                    description: None,
+                    owners: Vec::new(),
                 })],
                 vector_indexes: Vec::new(),
                 fulltext_indexes: Vec::new(),
+                constraints: Default::default(),
+                unknown_labels: Default::default(),
+                query_cache: Default::default(),
+                snapshot_column: None,
+                cluster: None,
+                views: BTreeMap::new(),
             },
         };
 
@@ -3141,9 +4423,12 @@ graph_schema:
                         view_parameters: None,
                         use_final: None,
                         filter: None,
+                        soft_delete: None,
+                        collations: HashMap::new(),
                         auto_discover_columns: false,
                         exclude_columns: vec![],
                         naming_convention: "snake_case".to_string(),
+                        lazy: false,
                         from_node_properties: None,
                         to_node_properties: None,
                         r#type: None,
@@ -3151,6 +4436,12 @@ graph_schema:
                         source: None,
                         property_types: HashMap::new(),
                         id_generation: None,
+                        max_unfiltered_rows: None,
+                        on_unfiltered_scan: None,
+                        return_properties: None,
+                        max_return_columns: None,
+                        description: None,
+                        owners: Vec::new(),
                     },
                     NodeDefinition {
                         label: "User".to_string(),
@@ -3163,9 +4454,12 @@ graph_schema:
                         view_parameters: None,
                         use_final: None,
                         filter: None,
+                        soft_delete: None,
+                        collations: HashMap::new(),
                         auto_discover_columns: false,
                         exclude_columns: vec![],
                         naming_convention: "snake_case".to_string(),
+                        lazy: false,
                         from_node_properties: None,
                         to_node_properties: None,
                         r#type: None,
@@ -3173,6 +4467,12 @@ graph_schema:
                         source: None,
                         property_types: HashMap::new(),
                         id_generation: None,
+                        max_unfiltered_rows: None,
+                        on_unfiltered_scan: None,
+                        return_properties: None,
+                        max_return_columns: None,
+                        description: None,
+                        owners: Vec::new(),
                     },
                 ],
                 relationships: vec![],
@@ -3198,10 +4498,22 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    freshness: None,
+                    temporal: None,
+                    collations: HashMap::new(),
                     constraints: None,
+                    description: None,
+                    owners: Vec::new(),
                 })],
                 vector_indexes: Vec::new(),
                 fulltext_indexes: Vec::new(),
+                constraints: Default::default(),
+                unknown_labels: Default::default(),
+                query_cache: Default::default(),
+                snapshot_column: None,
+                cluster: None,
+                views: BTreeMap::new(),
             },
         };
 
@@ -3232,9 +4544,12 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    collations: HashMap::new(),
                     auto_discover_columns: false,
                     exclude_columns: vec![],
                     naming_convention: "snake_case".to_string(),
+                    lazy: false,
                     from_node_properties: None,
                     to_node_properties: None,
                     r#type: None,
@@ -3242,6 +4557,12 @@ graph_schema:
                     source: None,
                     property_types: HashMap::new(),
                     id_generation: None,
+                    max_unfiltered_rows: None,
+                    on_unfiltered_scan: None,
+                    return_properties: None,
+                    max_return_columns: None,
+                    description: None,
+                    owners: Vec::new(),
                 }],
                 relationships: vec![],
                 edges: vec![EdgeDefinition::Polymorphic(PolymorphicEdgeDefinition {
@@ -3263,10 +4584,22 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    freshness: None,
+                    temporal: None,
+                    collations: HashMap::new(),
                     constraints: None,
+                    description: None,
+                    owners: Vec::new(),
                 })],
                 vector_indexes: Vec::new(),
                 fulltext_indexes: Vec::new(),
+                constraints: Default::default(),
+                unknown_labels: Default::default(),
+                query_cache: Default::default(),
+                snapshot_column: None,
+                cluster: None,
+                views: BTreeMap::new(),
             },
         };
 
@@ -3302,9 +4635,12 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    collations: HashMap::new(),
                     auto_discover_columns: false,
                     exclude_columns: vec![],
                     naming_convention: "snake_case".to_string(),
+                    lazy: false,
                     from_node_properties: None,
                     to_node_properties: None,
                     r#type: None,
@@ -3312,6 +4648,12 @@ graph_schema:
                     source: None,
                     property_types: HashMap::new(),
                     id_generation: None,
+                    max_unfiltered_rows: None,
+                    on_unfiltered_scan: None,
+                    return_properties: None,
+                    max_return_columns: None,
+                    description: None,
+                    owners: Vec::new(),
                 }],
                 relationships: vec![],
                 edges: vec![EdgeDefinition::Polymorphic(PolymorphicEdgeDefinition {
@@ -3333,10 +4675,22 @@ graph_schema:
                     view_parameters: None,
                     use_final: None,
                     filter: None,
+                    soft_delete: None,
+                    freshness: None,
+                    temporal: None,
+                    collations: HashMap::new(),
                     constraints: None,
+                    description: None,
+                    owners: Vec::new(),
                 })],
                 vector_indexes: Vec::new(),
                 fulltext_indexes: Vec::new(),
+                constraints: Default::default(),
+                unknown_labels: Default::default(),
+                query_cache: Default::default(),
+                snapshot_column: None,
+                cluster: None,
+                views: BTreeMap::new(),
             },
         };
 
@@ -3735,9 +5089,12 @@ mod node_id_identity_mapping_tests {
             view_parameters: None,
             use_final: None,
             filter: None,
+            soft_delete: None,
+            collations: HashMap::new(),
             auto_discover_columns: false,
             exclude_columns: vec![],
             naming_convention: "snake_case".to_string(),
+            lazy: false,
             from_node_properties: None,
             to_node_properties: None,
             r#type: None,
@@ -3745,6 +5102,12 @@ mod node_id_identity_mapping_tests {
             source: None,
             property_types: HashMap::new(),
             id_generation: None,
+            max_unfiltered_rows: None,
+            on_unfiltered_scan: None,
+            return_properties: None,
+            max_return_columns: None,
+            description: None,
+            owners: Vec::new(),
         };
 
         let discovery = TableDiscovery {
@@ -3791,9 +5154,12 @@ mod node_id_identity_mapping_tests {
             view_parameters: None,
             use_final: None,
             filter: None,
+            soft_delete: None,
+            collations: HashMap::new(),
             auto_discover_columns: false,
             exclude_columns: vec![],
             naming_convention: "snake_case".to_string(),
+            lazy: false,
             from_node_properties: None,
             to_node_properties: None,
             r#type: None,
@@ -3801,6 +5167,12 @@ mod node_id_identity_mapping_tests {
             source: None,
             property_types: HashMap::new(),
             id_generation: None,
+            max_unfiltered_rows: None,
+            on_unfiltered_scan: None,
+            return_properties: None,
+            max_return_columns: None,
+            description: None,
+            owners: Vec::new(),
         };
 
         let discovery = TableDiscovery {
@@ -3848,9 +5220,12 @@ mod node_id_identity_mapping_tests {
             view_parameters: None,
             use_final: None,
             filter: None,
+            soft_delete: None,
+            collations: HashMap::new(),
             auto_discover_columns: false,
             exclude_columns: vec![],
             naming_convention: "snake_case".to_string(),
+            lazy: false,
             from_node_properties: None,
             to_node_properties: None,
             r#type: None,
@@ -3858,6 +5233,12 @@ mod node_id_identity_mapping_tests {
             source: None,
             property_types: HashMap::new(),
             id_generation: None,
+            max_unfiltered_rows: None,
+            on_unfiltered_scan: None,
+            return_properties: None,
+            max_return_columns: None,
+            description: None,
+            owners: Vec::new(),
         };
 
         let discovery = TableDiscovery {
@@ -3972,6 +5353,163 @@ graph_schema:
             "error should name the bad value: {msg}"
         );
     }
+
+    #[test]
+    fn test_unfiltered_scan_limit_parses_and_validates() {
+        let yaml = r#"
+name: scan_guard_test
+graph_schema:
+  nodes:
+    - label: Event
+      database: test
+      table: events
+      node_id: id
+      max_unfiltered_rows: 500
+      on_unfiltered_scan: Reject
+      property_mappings:
+        id: id
+    - label: User
+      database: test
+      table: users
+      node_id: id
+      property_mappings:
+        id: id
+"#;
+        let schema = GraphSchemaConfig::from_yaml_str(yaml)
+            .unwrap()
+            .to_graph_schema()
+            .unwrap();
+        assert_eq!(
+            schema.unfiltered_scan_limit("Event"),
+            Some(&UnfilteredScanLimit {
+                max_rows: 500,
+                action: UnfilteredScanAction::Reject,
+            })
+        );
+        assert!(schema.unfiltered_scan_limit("User").is_none());
+
+        // An action without a maximum is a config error
+        let yaml_bad = r#"
+name: scan_guard_test
+graph_schema:
+  nodes:
+    - label: Event
+      database: test
+      table: events
+      node_id: id
+      on_unfiltered_scan: limit
+      property_mappings:
+        id: id
+"#;
+        let err = GraphSchemaConfig::from_yaml_str(yaml_bad)
+            .unwrap()
+            .to_graph_schema()
+            .expect_err("on_unfiltered_scan without max_unfiltered_rows must be rejected");
+        assert!(err.to_string().contains("requires max_unfiltered_rows"));
+    }
+
+    #[test]
+    fn test_return_projection_parses_and_validates() {
+        let schema_with = |extra: &str| {
+            format!(
+                r#"
+name: return_projection_test
+graph_schema:
+  nodes:
+    - label: Event
+      database: test
+      table: events
+      node_id: id
+{extra}
+      property_mappings:
+        id: id
+        title: title
+"#
+            )
+        };
+        let schema = GraphSchemaConfig::from_yaml_str(&schema_with(
+            "      return_properties: [title]\n      max_return_columns: 4",
+        ))
+        .unwrap()
+        .to_graph_schema()
+        .unwrap();
+        assert_eq!(
+            schema.return_projection("Event"),
+            Some(&ReturnProjection {
+                properties: vec!["title".to_string()],
+                max_columns: Some(4),
+            })
+        );
+
+        let err =
+            GraphSchemaConfig::from_yaml_str(&schema_with("      return_properties: [missing]"))
+                .unwrap()
+                .to_graph_schema()
+                .expect_err("unknown return property must be rejected");
+        assert!(err.to_string().contains("missing"));
+
+        assert!(
+            GraphSchemaConfig::from_yaml_str(&schema_with("      max_return_columns: 0"))
+                .unwrap()
+                .to_graph_schema()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_graph_constraints_reject_unknown_names() {
+        let schema_with = |constraints: &str| {
+            format!(
+                r#"
+name: constraints_test
+graph_schema:
+  constraints:
+{}
+  nodes:
+    - label: Person
+      database: test
+      table: people
+      node_id: id
+      property_mappings:
+        id: id
+    - label: Company
+      database: test
+      table: companies
+      node_id: id
+      property_mappings:
+        id: id
+  edges:
+    - type: MANAGES
+      database: test
+      table: reporting
+      from_id: manager_id
+      to_id: report_id
+      from_node: Person
+      to_node: Person
+"#,
+                constraints
+            )
+        };
+        let load = |constraints: &str| {
+            GraphSchemaConfig::from_yaml_str(&schema_with(constraints))
+                .unwrap()
+                .to_graph_schema()
+        };
+
+        let schema = load("    acyclic: [MANAGES]\n    disjoint_labels: [[Person, Company]]")
+            .expect("known names are accepted");
+        assert!(schema.constraints().is_acyclic("MANAGES"));
+        assert!(schema.constraints().are_disjoint("Person", "Company"));
+
+        let err = load("    acyclic: [OWNS]").expect_err("unknown type");
+        assert!(err.to_string().contains("unknown relationship type 'OWNS'"));
+
+        let err = load("    disjoint_labels: [[Person, Robot]]").expect_err("unknown label");
+        assert!(err.to_string().contains("unknown label 'Robot'"));
+
+        let err = load("    disjoint_labels: [[Person]]").expect_err("single label");
+        assert!(err.to_string().contains("at least two labels"));
+    }
 }
 
 #[cfg(test)]
@@ -4351,4 +5889,283 @@ graph_schema:
             err
         );
     }
+
+    #[test]
+    fn test_schema_overrides_merge_nodes_and_edges() {
+        let config = GraphSchemaConfig::from_yaml_str(
+            r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: shared
+      table: users
+      node_id: user_id
+      property_mappings:
+        name: full_name
+  edges:
+    - type: FOLLOWS
+      database: shared
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+"#,
+        )
+        .expect("valid yaml");
+
+        let merged = config
+            .with_overrides(
+                r#"
+nodes:
+  - label: User
+    database: shared
+    table: users_v2
+    node_id: user_id
+    property_mappings: {}
+  - label: Post
+    database: shared
+    table: posts
+    node_id: post_id
+    property_mappings: {}
+edges:
+  - type: AUTHORED
+    database: shared
+    table: posts
+    from_id: author_id
+    to_id: post_id
+    from_node: User
+    to_node: Post
+"#,
+            )
+            .expect("valid overrides");
+        assert_eq!(merged.graph_schema.nodes.len(), 2);
+        assert_eq!(merged.graph_schema.nodes[0].table, "users_v2");
+        assert_eq!(merged.graph_schema.edges.len(), 2);
+        let schema = merged.to_graph_schema().expect("valid merged schema");
+        assert_eq!(schema.node_schema("Post").unwrap().table_name, "posts");
+        // The shared config is untouched
+        assert_eq!(config.graph_schema.nodes[0].table, "users");
+
+        let err = config.with_overrides("vertices: []").unwrap_err();
+        assert!(err.to_string().contains("schema overrides"), "{}", err);
+        let err = config
+            .with_overrides(
+                r#"
+edges:
+  - type: LIKES
+    database: shared
+    table: likes
+    from_id: user_id
+    to_id: post_id
+    from_node: User
+    to_node: Comment
+"#,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("'Comment'"), "{}", err);
+    }
+
+    #[test]
+    fn test_schema_overrides_stay_in_the_tenant_database() {
+        let config = GraphSchemaConfig::from_yaml_str(
+            r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: shared
+      table: users
+      node_id: user_id
+      property_mappings: {}
+"#,
+        )
+        .expect("valid yaml")
+        .for_tenant("acme", Some("acme_db"));
+        let overrides = |database: &str| {
+            format!(
+                "nodes:\n  - label: Post\n    database: {}\n    table: posts\n    node_id: post_id\n    property_mappings: {{}}\n",
+                database
+            )
+        };
+
+        let merged = config
+            .with_overrides(&overrides("acme_db"))
+            .expect("tenant database");
+        assert_eq!(merged.graph_schema.nodes[1].database, "acme_db");
+
+        // Neither another tenant's database nor the base schema's is reachable
+        for database in ["other_db", "shared"] {
+            let err = config.with_overrides(&overrides(database)).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains(&format!("database '{}'", database)),
+                "{}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_tenant_copies_schema_into_its_database() {
+        let file: SchemaConfigFile = serde_yaml::from_str(
+            r#"
+schemas:
+  - name: social
+    graph_schema:
+      nodes:
+        - label: User
+          database: shared
+          table: users
+          node_id: user_id
+          property_mappings:
+            name: full_name
+      edges:
+        - type: FOLLOWS
+          database: shared
+          table: follows
+          from_id: follower_id
+          to_id: followed_id
+          from_node: User
+          to_node: User
+tenants:
+  - name: team_a
+    schema: social
+    database: team_a
+"#,
+        )
+        .expect("valid yaml");
+        let SchemaConfigFile::Multi {
+            schemas, tenants, ..
+        } = file
+        else {
+            panic!("multi-schema file");
+        };
+        assert_eq!(tenants[0].schema, "social");
+
+        let tenant = schemas[0].for_tenant(&tenants[0].name, tenants[0].database.as_deref());
+        assert_eq!(tenant.name.as_deref(), Some("team_a"));
+        assert_eq!(tenant.graph_schema.nodes[0].database, "team_a");
+        let EdgeDefinition::Standard(edge) = &tenant.graph_schema.edges[0] else {
+            panic!("standard edge");
+        };
+        assert_eq!(edge.database, "team_a");
+        let schema = tenant.to_graph_schema().expect("valid tenant schema");
+        assert_eq!(schema.node_schema("User").unwrap().database, "team_a");
+
+        // Without a database the copy reads the schema's own tables
+        let same = schemas[0].for_tenant("team_b", None);
+        assert_eq!(same.graph_schema.nodes[0].database, "shared");
+    }
+    #[test]
+    fn test_view_keeps_a_filtered_subset() {
+        let config = GraphSchemaConfig::from_yaml_str(
+            r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: app
+      table: users
+      node_id: user_id
+      filter: "is_deleted = 0"
+      property_mappings:
+        name: full_name
+    - label: Post
+      database: app
+      table: posts
+      node_id: post_id
+      property_mappings: {}
+    - label: Bot
+      database: app
+      table: bots
+      node_id: bot_id
+      property_mappings: {}
+  edges:
+    - type: FOLLOWS
+      database: app
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+    - type: AUTHORED
+      database: app
+      table: authored
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+    - type: RUNS
+      database: app
+      table: runs
+      from_id: user_id
+      to_id: bot_id
+      from_node: User
+      to_node: Bot
+    - polymorphic: true
+      database: app
+      table: interactions
+      from_id: from_id
+      to_id: to_id
+      type_column: kind
+      from_node: User
+      to_node: Post
+      type_values: [LIKED, SHARED]
+  constraints:
+    disjoint_labels:
+      - [User, Bot]
+  views:
+    prod_only:
+      labels: [User, Post]
+      types: [FOLLOWS, AUTHORED, LIKED]
+      filters:
+        User: "env = 'prod'"
+        FOLLOWS: "created_at >= now() - toIntervalDay(30)"
+        LIKED: "weight > 0"
+"#,
+        )
+        .expect("valid yaml");
+
+        let view = config.for_view("social.prod_only", "prod_only").unwrap();
+        assert_eq!(view.name.as_deref(), Some("social.prod_only"));
+        assert!(view.graph_schema.views.is_empty());
+        let labels: Vec<&str> = view
+            .graph_schema
+            .nodes
+            .iter()
+            .map(|n| n.label.as_str())
+            .collect();
+        assert_eq!(labels, ["User", "Post"]);
+        assert_eq!(
+            view.graph_schema.nodes[0].filter.as_deref(),
+            Some("(is_deleted = 0) AND (env = 'prod')")
+        );
+        assert_eq!(view.graph_schema.edges.len(), 3);
+        let EdgeDefinition::Polymorphic(interactions) = &view.graph_schema.edges[2] else {
+            panic!("polymorphic edge");
+        };
+        assert_eq!(interactions.type_values, ["LIKED"]);
+        assert_eq!(
+            interactions.filter.as_deref(),
+            Some("kind <> 'LIKED' OR (weight > 0)")
+        );
+        assert!(view.graph_schema.constraints.disjoint_labels.is_empty());
+
+        let schema = view.to_graph_schema().expect("valid view schema");
+        assert!(schema.node_schema("Bot").is_err());
+        assert!(schema
+            .get_relationships_schemas()
+            .keys()
+            .all(|key| !key.starts_with("RUNS")));
+        // The base schema is untouched
+        assert_eq!(config.graph_schema.nodes.len(), 3);
+
+        let err = config.for_view("social.x", "x").unwrap_err();
+        assert!(err.to_string().contains("not declared"), "{}", err);
+        let mut bad = config.clone();
+        bad.graph_schema.views.get_mut("prod_only").unwrap().filters =
+            BTreeMap::from([("Comment".to_string(), "1 = 1".to_string())]);
+        let err = bad.for_view("social.prod_only", "prod_only").unwrap_err();
+        assert!(err.to_string().contains("'Comment'"), "{}", err);
+    }
 }
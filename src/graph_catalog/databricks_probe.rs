@@ -25,7 +25,7 @@
 use crate::executor::databricks_sql::DatabricksSqlExecutor;
 use crate::executor::QueryExecutor;
 use crate::graph_catalog::schema_discovery::{
-    ColumnMetadata, IntrospectResponse, SchemaDiscovery, TableMetadata,
+    ColumnMetadata, IntrospectResponse, SchemaDiscovery, SourceKind, TableMetadata,
 };
 use serde_json::Value;
 
@@ -82,6 +82,10 @@ impl DatabricksProbe {
 
             table_metadata.push(TableMetadata {
                 name: table_name,
+                kind: SourceKind::Table,
+                graph_source: true,
+                note: None,
+                view_parameters: Vec::new(),
                 columns,
                 row_count: None,
                 sample,
@@ -100,6 +104,7 @@ impl DatabricksProbe {
             tables: table_metadata,
             next_step,
             suggestions,
+            inferred_edges: Vec::new(),
         })
     }
 }
@@ -0,0 +1,512 @@
+//! Workload-aware denormalization advice.
+//!
+//! An edge whose node properties live in the edge table
+//! (`from_node_properties` / `to_node_properties`) is traversed without
+//! joining the node tables. This module finds the edges where that pays off
+//! for the actual workload: given the traversals of executed queries and the
+//! node properties each one read, it proposes a denormalized copy of the
+//! edge table that carries the frequently read properties, the YAML for the
+//! edge definition and, optionally, the DDL to build the copy.
+//!
+//! Each suggestion quantifies the join elimination: of the node-side joins
+//! the traversals needed (one per side whose properties were read), how
+//! many read only properties the copy would carry.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+
+use super::expression_parser::PropertyValue;
+use super::graph_schema::{GraphSchema, NodeSchema, RelationshipSchema};
+use super::node_classification::rel_has_any_denormalized;
+
+/// One fixed-length hop of an executed query, with the node properties the
+/// query read on each side.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraversalAccess {
+    pub edge_type: String,
+    pub from_node: String,
+    pub to_node: String,
+    pub from_properties: BTreeSet<String>,
+    pub to_properties: BTreeSet<String>,
+}
+
+/// The traversals of one executed query and its wall time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadQuery {
+    pub traversals: Vec<TraversalAccess>,
+    pub total_ms: f64,
+}
+
+/// Thresholds for [`advise`].
+#[derive(Debug, Clone, Copy)]
+pub struct AdvisorOptions {
+    /// Queries that must traverse an edge before it is considered
+    pub min_queries: usize,
+    /// Node properties copied per side, most read first
+    pub max_properties_per_side: usize,
+    /// Include `CREATE TABLE ... AS SELECT` DDL for the copy
+    pub include_ddl: bool,
+}
+
+impl Default for AdvisorOptions {
+    fn default() -> Self {
+        Self {
+            min_queries: 5,
+            max_properties_per_side: 8,
+            include_ddl: false,
+        }
+    }
+}
+
+/// A node property proposed for the denormalized edge table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DenormalizedProperty {
+    pub property: String,
+    /// Column of the node table it is copied from
+    pub source_column: String,
+    /// Column of the denormalized edge table
+    pub column: String,
+    /// Traversals that read it
+    pub reads: usize,
+}
+
+/// A proposed denormalized edge table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DenormalizationAdvice {
+    pub edge_type: String,
+    pub from_node: String,
+    pub to_node: String,
+    /// `database.table` of the current edge table
+    pub edge_table: String,
+    /// `database.table` proposed for the denormalized copy
+    pub proposed_table: String,
+    /// Queries that traverse the edge
+    pub queries: usize,
+    /// Share of the analyzed workload's wall time spent in those queries
+    pub workload_time_percent: f64,
+    pub from_properties: Vec<DenormalizedProperty>,
+    pub to_properties: Vec<DenormalizedProperty>,
+    /// Node-side joins the traversals needed
+    pub joins_total: usize,
+    /// Those the denormalized table makes unnecessary
+    pub joins_eliminated: usize,
+    pub join_elimination_percent: f64,
+    /// Edge definition for the schema YAML
+    pub yaml: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ddl: Option<String>,
+}
+
+/// Per-edge aggregation of the workload.
+#[derive(Default)]
+struct EdgeUsage<'a> {
+    queries: usize,
+    time_ms: f64,
+    traversals: Vec<&'a TraversalAccess>,
+}
+
+/// Denormalization suggestions for `workload` against `schema`, most joins
+/// eliminated first. Edges that already carry node properties, use
+/// composite ids or view parameters are left out.
+pub fn advise(
+    schema: &GraphSchema,
+    workload: &[WorkloadQuery],
+    options: AdvisorOptions,
+) -> Vec<DenormalizationAdvice> {
+    let total_ms: f64 = workload.iter().map(|q| q.total_ms).sum();
+    let mut usage: HashMap<(&str, &str, &str), EdgeUsage> = HashMap::new();
+    for query in workload {
+        let mut seen = BTreeSet::new();
+        for t in &query.traversals {
+            let key = (
+                t.edge_type.as_str(),
+                t.from_node.as_str(),
+                t.to_node.as_str(),
+            );
+            let entry = usage.entry(key).or_default();
+            entry.traversals.push(t);
+            if seen.insert(key) {
+                entry.queries += 1;
+                entry.time_ms += query.total_ms;
+            }
+        }
+    }
+
+    let mut advice: Vec<DenormalizationAdvice> = usage
+        .into_iter()
+        .filter(|(_, u)| u.queries >= options.min_queries.max(1))
+        .filter_map(|((edge_type, from, to), u)| {
+            let rel = schema
+                .get_rel_schema_with_nodes(edge_type, Some(from), Some(to))
+                .ok()?;
+            edge_advice(schema, rel, edge_type, &u, total_ms, options)
+        })
+        .collect();
+    advice.sort_by(|a, b| {
+        b.joins_eliminated
+            .cmp(&a.joins_eliminated)
+            .then_with(|| a.edge_type.cmp(&b.edge_type))
+            .then_with(|| a.from_node.cmp(&b.from_node))
+    });
+    advice
+}
+
+fn edge_advice(
+    schema: &GraphSchema,
+    rel: &RelationshipSchema,
+    edge_type: &str,
+    usage: &EdgeUsage,
+    total_ms: f64,
+    options: AdvisorOptions,
+) -> Option<DenormalizationAdvice> {
+    if rel_has_any_denormalized(rel)
+        || rel.from_id.is_composite()
+        || rel.to_id.is_composite()
+        || rel.view_parameters.as_ref().is_some_and(|p| !p.is_empty())
+    {
+        return None;
+    }
+    let from_id = rel.from_id.as_single().ok()?;
+    let to_id = rel.to_id.as_single().ok()?;
+
+    let side = |label: &str,
+                prefix: &str,
+                edge_id: &str,
+                read: &dyn Fn(&TraversalAccess) -> &BTreeSet<String>| {
+        let node = schema.node_schema_opt(label)?;
+        // A node stored in the edge table is already read without a join
+        if node.database == rel.database && node.table_name == rel.table_name {
+            return None;
+        }
+        Some(side_properties(
+            node,
+            prefix,
+            edge_id,
+            usage.traversals.iter().map(|t| read(t)),
+            options.max_properties_per_side,
+        ))
+    };
+    // `None` marks a side read without a join today
+    let from_side = side(&rel.from_node, "from", from_id, &|t| &t.from_properties);
+    let to_side = side(&rel.to_node, "to", to_id, &|t| &t.to_properties);
+
+    let covers = |read: &BTreeSet<String>, copied: &[DenormalizedProperty]| {
+        read.iter().all(|p| copied.iter().any(|c| &c.property == p))
+    };
+    let mut joins_total = 0;
+    let mut joins_eliminated = 0;
+    for t in &usage.traversals {
+        for (read, copied) in [
+            (&t.from_properties, &from_side),
+            (&t.to_properties, &to_side),
+        ] {
+            let Some(copied) = copied else {
+                continue;
+            };
+            if read.is_empty() {
+                continue;
+            }
+            joins_total += 1;
+            if !copied.is_empty() && covers(read, copied) {
+                joins_eliminated += 1;
+            }
+        }
+    }
+    if joins_eliminated == 0 {
+        return None;
+    }
+    let from_properties = from_side.unwrap_or_default();
+    let to_properties = to_side.unwrap_or_default();
+
+    let proposed_table = format!("{}_denorm", rel.table_name);
+    let yaml = edge_yaml(
+        rel,
+        edge_type,
+        &proposed_table,
+        &from_properties,
+        &to_properties,
+    );
+    let ddl = options.include_ddl.then(|| {
+        edge_ddl(
+            schema,
+            rel,
+            &proposed_table,
+            from_id,
+            to_id,
+            &from_properties,
+            &to_properties,
+        )
+    });
+    Some(DenormalizationAdvice {
+        edge_type: edge_type.to_string(),
+        from_node: rel.from_node.clone(),
+        to_node: rel.to_node.clone(),
+        edge_table: format!("{}.{}", rel.database, rel.table_name),
+        proposed_table: format!("{}.{}", rel.database, proposed_table),
+        queries: usage.queries,
+        workload_time_percent: percent(usage.time_ms, total_ms),
+        from_properties,
+        to_properties,
+        joins_total,
+        joins_eliminated,
+        join_elimination_percent: percent(joins_eliminated as f64, joins_total as f64),
+        yaml,
+        ddl,
+    })
+}
+
+/// The column-mapped properties of `node` read by the traversals, most read
+/// first. The node's id property maps to the edge's own id column instead of
+/// a copy.
+fn side_properties<'a>(
+    node: &NodeSchema,
+    prefix: &str,
+    edge_id: &str,
+    reads: impl Iterator<Item = &'a BTreeSet<String>>,
+    limit: usize,
+) -> Vec<DenormalizedProperty> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for read in reads {
+        for property in read {
+            *counts.entry(property.as_str()).or_default() += 1;
+        }
+    }
+    let node_id = node.id_physical_columns();
+    let mut properties: Vec<DenormalizedProperty> = counts
+        .into_iter()
+        .filter_map(|(property, reads)| {
+            let PropertyValue::Column(source_column) = node.property_mappings.get(property)? else {
+                return None;
+            };
+            let column = if node_id == [source_column.as_str()] {
+                edge_id.to_string()
+            } else {
+                format!("{prefix}_{property}")
+            };
+            Some(DenormalizedProperty {
+                property: property.to_string(),
+                source_column: source_column.clone(),
+                column,
+                reads,
+            })
+        })
+        .collect();
+    properties.sort_by(|a, b| b.reads.cmp(&a.reads).then(a.property.cmp(&b.property)));
+    properties.truncate(limit);
+    properties
+}
+
+fn percent(part: f64, whole: f64) -> f64 {
+    if whole <= 0.0 {
+        0.0
+    } else {
+        (part * 1000.0 / whole).round() / 10.0
+    }
+}
+
+/// The edge definition pointing at the denormalized table.
+fn edge_yaml(
+    rel: &RelationshipSchema,
+    edge_type: &str,
+    table: &str,
+    from_properties: &[DenormalizedProperty],
+    to_properties: &[DenormalizedProperty],
+) -> String {
+    let mut yaml = String::new();
+    let _ = writeln!(yaml, "- type: {edge_type}");
+    let _ = writeln!(yaml, "  database: {}", rel.database);
+    let _ = writeln!(yaml, "  table: {table}");
+    let _ = writeln!(yaml, "  from_node: {}", rel.from_node);
+    let _ = writeln!(yaml, "  to_node: {}", rel.to_node);
+    let _ = writeln!(yaml, "  from_id: {}", rel.from_id.columns()[0]);
+    let _ = writeln!(yaml, "  to_id: {}", rel.to_id.columns()[0]);
+    for (key, properties) in [
+        ("from_node_properties", from_properties),
+        ("to_node_properties", to_properties),
+    ] {
+        if properties.is_empty() {
+            continue;
+        }
+        let _ = writeln!(yaml, "  {key}:");
+        for p in properties {
+            let _ = writeln!(yaml, "    {}: {}", p.property, p.column);
+        }
+    }
+    let mut mappings: Vec<(&String, &PropertyValue)> = rel.property_mappings.iter().collect();
+    mappings.sort_by_key(|(name, _)| name.as_str());
+    let _ = writeln!(
+        yaml,
+        "  property_mappings:{}",
+        if mappings.is_empty() { " {}" } else { "" }
+    );
+    for (name, value) in mappings {
+        match value {
+            PropertyValue::Column(column) => {
+                let _ = writeln!(yaml, "    {name}: {column}");
+            }
+            PropertyValue::Expression(expr) => {
+                let _ = writeln!(yaml, "    {name}: \"{}\"", expr.replace('"', "\\\""));
+            }
+        }
+    }
+    yaml
+}
+
+/// `CREATE TABLE ... AS SELECT` building the denormalized copy from the
+/// current edge and node tables. The copy is a snapshot; keep it current
+/// with a materialized view or a scheduled rebuild.
+fn edge_ddl(
+    schema: &GraphSchema,
+    rel: &RelationshipSchema,
+    table: &str,
+    from_id: &str,
+    to_id: &str,
+    from_properties: &[DenormalizedProperty],
+    to_properties: &[DenormalizedProperty],
+) -> String {
+    let mut select = vec!["e.*".to_string()];
+    let mut joins = String::new();
+    for (alias, label, edge_id, properties) in [
+        ("f", &rel.from_node, from_id, from_properties),
+        ("t", &rel.to_node, to_id, to_properties),
+    ] {
+        let copied: Vec<_> = properties.iter().filter(|p| p.column != edge_id).collect();
+        let Some(node) = schema.node_schema_opt(label) else {
+            continue;
+        };
+        let Ok::<[String; 1], _>([node_id]) = node.id_physical_columns().try_into() else {
+            continue;
+        };
+        if copied.is_empty() {
+            continue;
+        }
+        select.extend(
+            copied
+                .iter()
+                .map(|p| format!("{alias}.`{}` AS `{}`", p.source_column, p.column)),
+        );
+        let _ = write!(
+            joins,
+            "\nLEFT JOIN `{}`.`{}` AS {alias} ON {alias}.`{node_id}` = e.`{edge_id}`",
+            node.database, node.table_name
+        );
+    }
+    format!(
+        "CREATE TABLE `{db}`.`{table}`\nENGINE = MergeTree\nORDER BY (`{from_id}`, `{to_id}`)\nAS SELECT {}\nFROM `{db}`.`{}` AS e{joins}",
+        select.join(", "),
+        rel.table_name,
+        db = rel.database,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    fn schema() -> GraphSchema {
+        let yaml = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: id
+      property_mappings:
+        id: user_id
+        name: full_name
+        country: country
+        score: "toFloat64(points) / 10"
+    - label: Post
+      database: db
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: AUTHORED
+      database: db
+      table: authored
+      from_node: User
+      to_node: Post
+      from_id: author_id
+      to_id: post_id
+      property_mappings:
+        at: created_at
+"#;
+        GraphSchemaConfig::from_yaml_str(yaml)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .expect("valid schema")
+    }
+
+    fn query(from: &[&str], to: &[&str], total_ms: f64) -> WorkloadQuery {
+        WorkloadQuery {
+            traversals: vec![TraversalAccess {
+                edge_type: "AUTHORED".into(),
+                from_node: "User".into(),
+                to_node: "Post".into(),
+                from_properties: from.iter().map(|p| p.to_string()).collect(),
+                to_properties: to.iter().map(|p| p.to_string()).collect(),
+            }],
+            total_ms,
+        }
+    }
+
+    #[test]
+    fn proposes_frequently_read_properties_with_join_elimination() {
+        let workload = vec![
+            query(&["name"], &["title"], 30.0),
+            query(&["name", "country"], &[], 30.0),
+            query(&["name", "score"], &["post_id"], 30.0),
+            WorkloadQuery {
+                traversals: Vec::new(),
+                total_ms: 10.0,
+            },
+        ];
+        let options = AdvisorOptions {
+            min_queries: 3,
+            max_properties_per_side: 1,
+            include_ddl: true,
+        };
+        let advice = advise(&schema(), &workload, options);
+        assert_eq!(advice.len(), 1);
+        let a = &advice[0];
+        assert_eq!(a.proposed_table, "db.authored_denorm");
+        assert_eq!(a.queries, 3);
+        assert_eq!(a.workload_time_percent, 90.0);
+        // One property per side: `name` (read 3 times); `score` is
+        // expression-mapped and never copied
+        assert_eq!(a.from_properties.len(), 1);
+        assert_eq!(a.from_properties[0].column, "from_name");
+        assert_eq!(a.from_properties[0].reads, 3);
+        // The node id maps onto the edge's own id column
+        assert_eq!(a.to_properties[0].property, "post_id");
+        assert_eq!(a.to_properties[0].column, "post_id");
+        // Sides read: 3 from + 2 to; covered: from of query 1, to of query 3
+        assert_eq!((a.joins_total, a.joins_eliminated), (5, 2));
+        assert_eq!(a.join_elimination_percent, 40.0);
+        assert!(a
+            .yaml
+            .contains("  from_node_properties:\n    name: from_name\n"));
+        assert!(a
+            .yaml
+            .contains("  property_mappings:\n    at: created_at\n"));
+        let ddl = a.ddl.as_deref().unwrap();
+        assert!(ddl.starts_with("CREATE TABLE `db`.`authored_denorm`"));
+        assert!(ddl.contains("AS SELECT e.*, f.`full_name` AS `from_name`"));
+        assert!(ddl.contains("LEFT JOIN `db`.`users` AS f ON f.`user_id` = e.`author_id`"));
+        // Only the id is read on the Post side: no join needed to copy it
+        assert!(!ddl.contains("`db`.`posts`"));
+    }
+
+    #[test]
+    fn skips_rarely_traversed_edges() {
+        let workload = vec![query(&["name"], &[], 5.0)];
+        assert!(advise(&schema(), &workload, AdvisorOptions::default()).is_empty());
+    }
+}
@@ -27,9 +27,13 @@
 //!
 //! All elementId strings are 100% reversible using simple string operations:
 //! - Split on `:` to extract label/type and ID portion
-//! - Split ID portion on `|` for composite IDs
+//! - Split ID portion on unescaped `|` for composite IDs
 //! - Split relationship IDs on `->` for from/to IDs
 //!
+//! ID values containing `|` or `\` are backslash-escaped (`a|b` → `a\|b`) so a
+//! composite key always splits back into the same columns, and the same node
+//! gets the same elementId whether it is returned directly or inside a path.
+//!
 //! This enables Neo4j Browser's "Expand" feature which requires parsing
 //! `WHERE id(n) = X` or `WHERE elementId(n) = 'X'` back into SQL predicates.
 
@@ -66,6 +70,64 @@ impl fmt::Display for ElementIdError {
 
 impl std::error::Error for ElementIdError {}
 
+/// Join ID values into the canonical ID portion of an elementId.
+///
+/// Values are separated by `|`; any `|` or `\` inside a value is escaped with
+/// a backslash so [`split_id_values`] recovers the exact values.
+///
+/// # Examples
+///
+/// ```
+/// use clickgraph::graph_catalog::element_id::join_id_values;
+///
+/// assert_eq!(join_id_values(&["tenant_1", "456"]), "tenant_1|456");
+/// assert_eq!(join_id_values(&["a|b"]), "a\\|b");
+/// ```
+pub fn join_id_values<S: AsRef<str>>(id_values: &[S]) -> String {
+    id_values
+        .iter()
+        .map(|v| v.as_ref().replace('\\', "\\\\").replace('|', "\\|"))
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// Split the ID portion of an elementId back into its values (inverse of
+/// [`join_id_values`]).
+///
+/// # Examples
+///
+/// ```
+/// use clickgraph::graph_catalog::element_id::split_id_values;
+///
+/// assert_eq!(split_id_values("tenant_1|456"), vec!["tenant_1", "456"]);
+/// assert_eq!(split_id_values("a\\|b"), vec!["a|b"]);
+/// ```
+pub fn split_id_values(id_portion: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut chars = id_portion.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            '|' => values.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    values.push(current);
+    values
+}
+
+/// Strip the Neo4j-Browser-compat trailing `-` sentinel (see
+/// [`generate_node_element_id`]). Only one `-` is removed so ID values that
+/// themselves end in `-` survive the round trip.
+fn strip_sentinel(element_id: &str) -> &str {
+    element_id.strip_suffix('-').unwrap_or(element_id)
+}
+
 /// Generate a Neo4j-compatible elementId for a node.
 ///
 /// # Arguments
@@ -96,19 +158,14 @@ impl std::error::Error for ElementIdError {}
 /// let element_id = generate_node_element_id("Account", &["tenant_1", "456"]);
 /// assert_eq!(element_id, "Account:tenant_1|456-");
 /// ```
-pub fn generate_node_element_id(label: &str, id_values: &[&str]) -> String {
+pub fn generate_node_element_id<S: AsRef<str>>(label: &str, id_values: &[S]) -> String {
     // Trailing `-` is a Neo4j-Browser-compat sentinel: Browser's expand-query
     // generator picks elementId-mode iff the first nodeId string contains `-`,
     // otherwise it falls back to legacy id() mode and tries `parseInt(elementId)`,
     // which yields NaN → 0 for our `Label:id` format and breaks click-to-expand.
     // Parsers strip the trailing `-` so internal callers see the same value.
-    if id_values.len() == 1 {
-        // Single ID: "Label:id-"
-        format!("{}:{}-", label, id_values[0])
-    } else {
-        // Composite ID: "Label:id1|id2|id3-"
-        format!("{}:{}-", label, id_values.join("|"))
-    }
+    // Single ID: "Label:id-", composite ID: "Label:id1|id2|id3-"
+    format!("{}:{}-", label, join_id_values(id_values))
 }
 
 /// Parse a Neo4j node elementId back into its components.
@@ -147,7 +204,7 @@ pub fn generate_node_element_id(label: &str, id_values: &[&str]) -> String {
 /// ```
 pub fn parse_node_element_id(element_id: &str) -> Result<(String, Vec<String>), ElementIdError> {
     // Strip the Neo4j-Browser-compat trailing `-` sentinel (see generator docs).
-    let element_id = element_id.trim_end_matches('-');
+    let element_id = strip_sentinel(element_id);
 
     // Split on first colon: "Label:id_portion"
     let parts: Vec<&str> = element_id.splitn(2, ':').collect();
@@ -169,8 +226,8 @@ pub fn parse_node_element_id(element_id: &str) -> Result<(String, Vec<String>),
         return Err(ElementIdError::MissingId);
     }
 
-    // Split ID portion on pipe for composite IDs
-    let id_values: Vec<String> = id_portion.split('|').map(|s| s.to_string()).collect();
+    // Split ID portion on unescaped pipes for composite IDs
+    let id_values = split_id_values(id_portion);
 
     Ok((label.to_string(), id_values))
 }
@@ -180,8 +237,8 @@ pub fn parse_node_element_id(element_id: &str) -> Result<(String, Vec<String>),
 /// # Arguments
 ///
 /// * `rel_type` - The relationship type (e.g., "FOLLOWS", "AUTHORED")
-/// * `from_id` - The from node ID (single or composite, already joined with `|`)
-/// * `to_id` - The to node ID (single or composite, already joined with `|`)
+/// * `from_id` - The from node ID (single or composite, already joined with [`join_id_values`])
+/// * `to_id` - The to node ID (single or composite, already joined with [`join_id_values`])
 ///
 /// # Returns
 ///
@@ -253,7 +310,7 @@ pub fn parse_relationship_element_id(
     element_id: &str,
 ) -> Result<(String, String, String), ElementIdError> {
     // Strip the Neo4j-Browser-compat trailing `-` sentinel (see generator docs).
-    let element_id = element_id.trim_end_matches('-');
+    let element_id = strip_sentinel(element_id);
 
     // Split on first colon: "RelType:from_id->to_id"
     let parts: Vec<&str> = element_id.splitn(2, ':').collect();
@@ -582,4 +639,31 @@ mod tests {
         // splitn(2, ':') will split only on first colon
         assert_eq!(ids, vec!["post:123:456"]);
     }
+
+    #[test]
+    fn test_composite_element_id_escapes_pipes_and_backslashes() {
+        let element_id = generate_node_element_id("Account", &["acme|eu", r"c:\tmp", "7"]);
+        assert_eq!(element_id, r"Account:acme\|eu|c:\\tmp|7-");
+
+        let (label, ids) = parse_node_element_id(&element_id).unwrap();
+        assert_eq!(label, "Account");
+        assert_eq!(ids, vec!["acme|eu", r"c:\tmp", "7"]);
+    }
+
+    #[test]
+    fn test_node_element_id_value_ending_in_dash_round_trips() {
+        // Only the single sentinel `-` is stripped, not the value's own dashes
+        let element_id = generate_node_element_id("Order", &["tenant-", "12-"]);
+        assert_eq!(element_id, "Order:tenant-|12--");
+
+        let (_, ids) = parse_node_element_id(&element_id).unwrap();
+        assert_eq!(ids, vec!["tenant-", "12-"]);
+    }
+
+    #[test]
+    fn test_join_split_id_values_inverse() {
+        let values = ["a|b", r"\", "", "plain"];
+        let joined = join_id_values(&values);
+        assert_eq!(split_id_values(&joined), values);
+    }
 }
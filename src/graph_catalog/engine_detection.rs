@@ -90,6 +90,15 @@ impl TableEngine {
         )
     }
 
+    /// Returns true for MergeTree-family engines, which accept lightweight
+    /// `DELETE FROM`. Other engines need an `ALTER TABLE ... DELETE` mutation.
+    pub fn supports_lightweight_delete(&self) -> bool {
+        match self {
+            TableEngine::Other(name) => name.ends_with("MergeTree"),
+            _ => true,
+        }
+    }
+
     /// Get engine name for logging/debugging
     pub fn name(&self) -> &str {
         match self {
@@ -171,7 +180,7 @@ pub async fn detect_table_engine(
 }
 
 /// Parses engine name and full specification into TableEngine enum
-fn parse_engine(engine: &str, engine_full: &str) -> Result<TableEngine> {
+pub fn parse_engine(engine: &str, engine_full: &str) -> Result<TableEngine> {
     match engine {
         "ReplacingMergeTree" => {
             let version_column = extract_version_column(engine_full);
@@ -400,6 +409,14 @@ mod tests {
         assert!(engine.requires_final_for_correctness());
     }
 
+    #[test]
+    fn test_lightweight_delete_support() {
+        assert!(TableEngine::MergeTree.supports_lightweight_delete());
+        assert!(TableEngine::Other("ReplicatedMergeTree".to_string()).supports_lightweight_delete());
+        assert!(!TableEngine::Other("Memory".to_string()).supports_lightweight_delete());
+        assert!(!TableEngine::Other("Log".to_string()).supports_lightweight_delete());
+    }
+
     #[test]
     fn test_parse_aggregating_merge_tree() {
         let engine = parse_engine("AggregatingMergeTree", "AggregatingMergeTree").unwrap();
@@ -122,6 +122,19 @@ impl PropertyValue {
         }
     }
 
+    /// Rebuild a value from its [`raw`](Self::raw) text, as carried by the
+    /// `(property, column)` lists used for whole-entity expansion. Text that
+    /// `parse_property_value` reads as an expression (a derived property such
+    /// as `resp_bytes / nullIf(duration, 0)`) comes back as `Expression`, so
+    /// the renderer qualifies its columns instead of quoting the whole text
+    /// as one identifier; everything else stays a `Column`.
+    pub fn from_raw(raw: String) -> Self {
+        match parse_property_value(&raw) {
+            Ok(PropertyValue::Expression(_)) => PropertyValue::Expression(raw),
+            _ => PropertyValue::Column(raw),
+        }
+    }
+
     /// Get raw value (for debugging)
     pub fn raw(&self) -> &str {
         match self {
@@ -256,6 +269,36 @@ impl ClickHouseExpr {
             ClickHouseExpr::Literal(lit) => lit.to_sql(),
         }
     }
+
+    /// Generate SQL with every column reference replaced by `column(name)`.
+    /// Used for expressions written over property names rather than columns.
+    pub fn to_sql_mapped(
+        &self,
+        column: &dyn Fn(&str) -> Result<String, String>,
+    ) -> Result<String, String> {
+        Ok(match self {
+            ClickHouseExpr::Column(col) | ClickHouseExpr::QuotedColumn(col) => column(col)?,
+            ClickHouseExpr::FunctionCall { name, args } => {
+                let args_sql = args
+                    .iter()
+                    .map(|a| a.to_sql_mapped(column))
+                    .collect::<Result<Vec<_>, _>>()?;
+                format!("{}({})", name, args_sql.join(", "))
+            }
+            ClickHouseExpr::BinaryOp { op, left, right } => format!(
+                "({} {} {})",
+                left.to_sql_mapped(column)?,
+                op.to_str(),
+                right.to_sql_mapped(column)?
+            ),
+            ClickHouseExpr::ArrayIndex { array, index } => format!(
+                "{}[{}]",
+                array.to_sql_mapped(column)?,
+                index.to_sql_mapped(column)?
+            ),
+            ClickHouseExpr::Literal(lit) => lit.to_sql(),
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -323,16 +366,19 @@ pub fn parse_property_value(value: &str) -> Result<PropertyValue, String> {
         return Ok(PropertyValue::Column(value.to_string()));
     }
 
-    // Parse as expression
-    match parse_clickhouse_scalar_expr(value) {
-        Ok((remaining, _ast)) => {
-            let remaining = remaining.trim();
-            if !remaining.is_empty() {
-                return Err(format!("Unexpected trailing content: '{}'", remaining));
-            }
-            // Store as expression (raw string)
-            Ok(PropertyValue::Expression(value.to_string()))
-        }
+    // Parse as expression, stored as the raw string
+    parse_scalar_expression(value)?;
+    Ok(PropertyValue::Expression(value.to_string()))
+}
+
+/// Parse a whole scalar expression (no trailing content)
+pub fn parse_scalar_expression(value: &str) -> Result<ClickHouseExpr, String> {
+    match parse_clickhouse_scalar_expr(value.trim()) {
+        Ok((remaining, ast)) if remaining.trim().is_empty() => Ok(ast),
+        Ok((remaining, _)) => Err(format!(
+            "Unexpected trailing content: '{}'",
+            remaining.trim()
+        )),
         Err(e) => Err(format!("Parse error: {:?}", e)),
     }
 }
@@ -667,6 +713,18 @@ mod tests {
         assert_eq!(pv.to_sql("u"), "u.user_id");
     }
 
+    #[test]
+    fn test_from_raw_round_trips_mapping_kind() {
+        let expr = PropertyValue::from_raw("resp_bytes / nullIf(duration, 0)".to_string());
+        assert!(matches!(expr, PropertyValue::Expression(_)));
+        assert_eq!(expr.to_sql("r"), "(r.resp_bytes / nullIf(r.duration, 0))");
+
+        let dotted = PropertyValue::from_raw("id.orig_h".to_string());
+        assert!(matches!(dotted, PropertyValue::Column(_)));
+        let spaced = PropertyValue::from_raw("first name".to_string());
+        assert!(matches!(spaced, PropertyValue::Column(_)));
+    }
+
     #[test]
     fn test_column_with_underscore() {
         let pv = parse_property_value("first_name").unwrap();
@@ -833,6 +891,23 @@ mod tests {
         assert_eq!(cols, vec!["first_name", "last_name"]);
     }
 
+    #[test]
+    fn test_to_sql_mapped_resolves_columns() {
+        let expr = parse_scalar_expression("duration_min + greatest(layover, 30)").unwrap();
+        let sql = expr
+            .to_sql_mapped(&|name| match name {
+                "duration_min" => Ok("t.minutes".to_string()),
+                "layover" => Ok("t.layover_min".to_string()),
+                other => Err(format!("unknown {other}")),
+            })
+            .unwrap();
+        assert_eq!(sql, "(t.minutes + greatest(t.layover_min, 30))");
+
+        let err = expr.to_sql_mapped(&|name| Err(format!("unknown {name}")));
+        assert_eq!(err.unwrap_err(), "unknown duration_min");
+        assert!(parse_scalar_expression("price extra").is_err());
+    }
+
     #[test]
     fn test_modulo_operator() {
         let pv = parse_property_value("id % 10").unwrap();
@@ -0,0 +1,416 @@
+//! Foreign-key inference from data samples.
+//!
+//! Name heuristics (`generate_suggestions`) only guess that `customer_id`
+//! may reference a `customer` table. This module checks the guess against
+//! the data: for every column whose type can hold another table's
+//! single-column primary key, it samples up to [`FK_SAMPLE_ROWS`] non-empty
+//! values and counts how many exist in that key column:
+//!
+//! ```sql
+//! SELECT count() AS sampled, countIf(v IN (SELECT `id` FROM db.customers)) AS matched,
+//!        uniqExact(v) AS distinct_values
+//! FROM (SELECT `customer_id` AS v FROM db.orders WHERE notEmpty(`customer_id`) LIMIT 1000)
+//! ```
+//!
+//! Pairs where at least [`MIN_MATCH_PERCENT`] of the sample is contained are
+//! proposed as [`InferredEdge`]s with their match percentage. Candidates are
+//! checked in order of how key-like their names are, at most
+//! [`MAX_FK_PROBES`] per introspection.
+
+use clickhouse::Client;
+use serde::{Deserialize, Serialize};
+
+use super::schema_discovery::{ColumnMetadata, TableMetadata};
+
+/// Values sampled from each candidate column.
+pub const FK_SAMPLE_ROWS: u64 = 1000;
+/// Candidate pairs probed per introspection.
+pub const MAX_FK_PROBES: usize = 200;
+/// Smallest share of sampled values that must exist in the key column.
+pub const MIN_MATCH_PERCENT: f64 = 80.0;
+/// Share of sampled values above which an edge is `high` confidence.
+const HIGH_MATCH_PERCENT: f64 = 95.0;
+/// Distinct sampled values below which an edge stays `medium` confidence:
+/// a handful of small integers fits inside almost any integer key.
+const MIN_DISTINCT_FOR_HIGH: u64 = 10;
+
+/// A foreign key inferred from the data: the sampled values of
+/// `from_table.from_column` were found in the key column
+/// `to_table.to_column`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InferredEdge {
+    pub from_table: String,
+    pub from_column: String,
+    pub to_table: String,
+    pub to_column: String,
+    /// Share of the sampled values present in the key column, 0–100
+    pub match_percent: f64,
+    /// Non-empty values sampled from `from_column`
+    pub sampled_values: u64,
+    /// Distinct values among them
+    pub distinct_values: u64,
+    /// `many_to_one` when sampled values repeat, `one_to_one` otherwise
+    pub cardinality: String,
+    /// `high` or `medium`
+    pub confidence: String,
+}
+
+impl InferredEdge {
+    /// One-line description for an introspection suggestion.
+    pub fn reason(&self) -> String {
+        format!(
+            "{}.{} references {}.{}: {:.1}% of {} sampled values match ({}, {} confidence)",
+            self.from_table,
+            self.from_column,
+            self.to_table,
+            self.to_column,
+            self.match_percent,
+            self.sampled_values,
+            self.cardinality,
+            self.confidence
+        )
+    }
+}
+
+/// A column that may reference another table's key column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FkCandidate {
+    pub from_table: String,
+    pub from_column: String,
+    pub to_table: String,
+    pub to_column: String,
+    /// Whether values of the column's type can be empty strings
+    pub string_key: bool,
+}
+
+/// Type families that can hold a key; values are only compared within one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyFamily {
+    Integer,
+    Uuid,
+    String,
+}
+
+/// The key family of a ClickHouse type, looking through `Nullable` and
+/// `LowCardinality`; `None` for types that don't hold keys (dates, floats,
+/// arrays, ...).
+fn key_family(data_type: &str) -> Option<KeyFamily> {
+    let mut inner = data_type.trim();
+    while let Some(rest) = inner
+        .strip_prefix("Nullable(")
+        .or_else(|| inner.strip_prefix("LowCardinality("))
+    {
+        inner = rest.strip_suffix(')')?;
+    }
+    if inner.starts_with("Int") || inner.starts_with("UInt") {
+        Some(KeyFamily::Integer)
+    } else if inner == "UUID" {
+        Some(KeyFamily::Uuid)
+    } else if inner == "String" || inner.starts_with("FixedString(") {
+        Some(KeyFamily::String)
+    } else {
+        None
+    }
+}
+
+/// Whether a source can be read for values: usable, not parameterized and
+/// not empty.
+fn readable(table: &TableMetadata) -> bool {
+    table.graph_source && table.view_parameters.is_empty() && table.row_count.unwrap_or(0) > 0
+}
+
+/// The single primary-key column of a table, if it has exactly one.
+fn single_key(table: &TableMetadata) -> Option<&ColumnMetadata> {
+    let mut keys = table.columns.iter().filter(|c| c.is_primary_key);
+    let key = keys.next()?;
+    keys.next().is_none().then_some(key)
+}
+
+/// Lowercase table name without a plural `s`, for name matching.
+fn singular(table: &str) -> String {
+    let lower = table.to_lowercase();
+    if let Some(stem) = lower.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if lower.ends_with("ss") {
+        lower
+    } else {
+        lower.strip_suffix('s').map(str::to_string).unwrap_or(lower)
+    }
+}
+
+/// How key-like `column` looks as a reference to `to_table.to_column`;
+/// higher is checked first.
+fn name_score(column: &str, to_table: &str, to_column: &str) -> u8 {
+    let column = column.to_lowercase();
+    let to_column = to_column.to_lowercase();
+    let stem = singular(to_table);
+    if column == to_column && column != "id" {
+        3
+    } else if column.contains(&stem) {
+        2
+    } else if column.ends_with("_id") || column.ends_with("_key") || column.ends_with("id") {
+        1
+    } else {
+        0
+    }
+}
+
+/// Column pairs worth probing, most key-like names first, capped at
+/// [`MAX_FK_PROBES`].
+///
+/// A target is a readable table with a single-column primary key of a key
+/// type. A candidate is a column of a readable table with the same key
+/// family. A table's own primary key only counts as a candidate when it is
+/// named like the target key (`profiles.user_id` → `users.user_id`), since
+/// two surrogate `id` columns always overlap; a table only references itself
+/// through key-like names (`manager_id`).
+pub(crate) fn fk_candidates(tables: &[TableMetadata]) -> Vec<FkCandidate> {
+    let mut scored = Vec::new();
+    for target in tables.iter().filter(|t| readable(t)) {
+        let Some(key) = single_key(target) else {
+            continue;
+        };
+        let Some(family) = key_family(&key.data_type) else {
+            continue;
+        };
+        for source in tables.iter().filter(|t| readable(t)) {
+            let source_key = single_key(source).map(|c| c.name.as_str());
+            for column in &source.columns {
+                if source.name == target.name && column.name == key.name {
+                    continue;
+                }
+                if key_family(&column.data_type) != Some(family) {
+                    continue;
+                }
+                if source_key == Some(column.name.as_str()) && column.name != key.name {
+                    continue;
+                }
+                let score = name_score(&column.name, &target.name, &key.name);
+                if source.name == target.name && score == 0 {
+                    continue;
+                }
+                scored.push((
+                    score,
+                    FkCandidate {
+                        from_table: source.name.clone(),
+                        from_column: column.name.clone(),
+                        to_table: target.name.clone(),
+                        to_column: key.name.clone(),
+                        string_key: family == KeyFamily::String,
+                    },
+                ));
+            }
+        }
+    }
+    // Stable: equal scores keep table and column order
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .take(MAX_FK_PROBES)
+        .collect()
+}
+
+/// The inferred edge for a probed candidate, or `None` when too few sampled
+/// values exist in the key column.
+pub(crate) fn score_candidate(
+    candidate: &FkCandidate,
+    sampled: u64,
+    matched: u64,
+    distinct: u64,
+) -> Option<InferredEdge> {
+    if sampled == 0 {
+        return None;
+    }
+    let match_percent = (matched as f64 * 1000.0 / sampled as f64).round() / 10.0;
+    if match_percent < MIN_MATCH_PERCENT {
+        return None;
+    }
+    let confidence = if match_percent >= HIGH_MATCH_PERCENT && distinct >= MIN_DISTINCT_FOR_HIGH {
+        "high"
+    } else {
+        "medium"
+    };
+    Some(InferredEdge {
+        from_table: candidate.from_table.clone(),
+        from_column: candidate.from_column.clone(),
+        to_table: candidate.to_table.clone(),
+        to_column: candidate.to_column.clone(),
+        match_percent,
+        sampled_values: sampled,
+        distinct_values: distinct,
+        cardinality: if distinct < sampled {
+            "many_to_one"
+        } else {
+            "one_to_one"
+        }
+        .to_string(),
+        confidence: confidence.to_string(),
+    })
+}
+
+/// The containment probe for one candidate.
+pub(crate) fn probe_sql(database: &str, candidate: &FkCandidate) -> String {
+    let non_empty = if candidate.string_key {
+        format!("notEmpty(`{}`)", candidate.from_column)
+    } else {
+        format!("isNotNull(`{}`)", candidate.from_column)
+    };
+    format!(
+        "SELECT count() AS sampled, countIf(v IN (SELECT `{to_column}` FROM `{db}`.`{to_table}`)) AS matched, \
+         uniqExact(v) AS distinct_values \
+         FROM (SELECT `{from_column}` AS v FROM `{db}`.`{from_table}` WHERE {non_empty} LIMIT {limit})",
+        to_column = candidate.to_column,
+        to_table = candidate.to_table,
+        from_column = candidate.from_column,
+        from_table = candidate.from_table,
+        db = database,
+        limit = FK_SAMPLE_ROWS,
+    )
+}
+
+#[derive(Debug, clickhouse::Row, Deserialize)]
+struct ProbeRow {
+    sampled: u64,
+    matched: u64,
+    distinct_values: u64,
+}
+
+/// Probe the candidates of `tables` and return the inferred edges. A probe
+/// that fails (permissions, incompatible values) is logged and skipped.
+pub async fn infer_fk_edges(
+    client: &Client,
+    database: &str,
+    tables: &[TableMetadata],
+) -> Vec<InferredEdge> {
+    let mut edges = Vec::new();
+    for candidate in fk_candidates(tables) {
+        let sql = probe_sql(database, &candidate);
+        match client.query(&sql).fetch_one::<ProbeRow>().await {
+            Ok(row) => edges.extend(score_candidate(
+                &candidate,
+                row.sampled,
+                row.matched,
+                row.distinct_values,
+            )),
+            Err(e) => log::warn!(
+                "FK probe {}.{} -> {}.{} failed: {}",
+                candidate.from_table,
+                candidate.from_column,
+                candidate.to_table,
+                candidate.to_column,
+                e
+            ),
+        }
+    }
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::schema_discovery::SourceKind;
+
+    fn table(name: &str, rows: u64, cols: &[(&str, &str, bool)]) -> TableMetadata {
+        TableMetadata {
+            name: name.to_string(),
+            kind: SourceKind::Table,
+            graph_source: true,
+            note: None,
+            view_parameters: Vec::new(),
+            columns: cols
+                .iter()
+                .map(|(name, data_type, pk)| ColumnMetadata {
+                    name: name.to_string(),
+                    data_type: data_type.to_string(),
+                    is_primary_key: *pk,
+                    is_in_order_by: *pk,
+                })
+                .collect(),
+            row_count: Some(rows),
+            sample: Vec::new(),
+        }
+    }
+
+    fn pairs(candidates: &[FkCandidate]) -> Vec<String> {
+        candidates
+            .iter()
+            .map(|c| {
+                format!(
+                    "{}.{}->{}.{}",
+                    c.from_table, c.from_column, c.to_table, c.to_column
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn candidates_match_key_families_and_rank_by_name() {
+        let tables = vec![
+            table(
+                "customers",
+                10,
+                &[("id", "UInt64", true), ("name", "String", false)],
+            ),
+            table(
+                "orders",
+                100,
+                &[
+                    ("order_id", "UInt64", true),
+                    ("amount", "Float64", false),
+                    ("qty", "UInt32", false),
+                    ("cust", "Nullable(UInt64)", false),
+                    ("customer_ref", "LowCardinality(Nullable(UInt64))", false),
+                    ("created_at", "DateTime", false),
+                ],
+            ),
+            // Parameterized views and empty tables are never read
+            table("empty", 0, &[("id", "UInt64", true)]),
+        ];
+        let candidates = fk_candidates(&tables);
+        assert_eq!(
+            pairs(&candidates),
+            vec![
+                "orders.customer_ref->customers.id",
+                "orders.qty->customers.id",
+                "orders.cust->customers.id",
+            ]
+        );
+        // Keys never reference each other, and orders' plain columns are
+        // not named like references to its own key
+        assert!(candidates.iter().all(|c| c.to_table == "customers"));
+        assert!(!candidates[0].string_key);
+    }
+
+    #[test]
+    fn scores_containment_and_cardinality() {
+        let candidate = FkCandidate {
+            from_table: "orders".into(),
+            from_column: "customer_id".into(),
+            to_table: "customers".into(),
+            to_column: "id".into(),
+            string_key: false,
+        };
+        let edge = score_candidate(&candidate, 1000, 987, 120).unwrap();
+        assert_eq!(edge.match_percent, 98.7);
+        assert_eq!(edge.cardinality, "many_to_one");
+        assert_eq!(edge.confidence, "high");
+        assert!(edge.reason().contains("98.7% of 1000 sampled values"));
+
+        // Few distinct values stay medium; low containment is dropped
+        assert_eq!(
+            score_candidate(&candidate, 500, 500, 4).unwrap().confidence,
+            "medium"
+        );
+        assert_eq!(
+            score_candidate(&candidate, 20, 20, 20).unwrap().cardinality,
+            "one_to_one"
+        );
+        assert!(score_candidate(&candidate, 1000, 500, 300).is_none());
+        assert!(score_candidate(&candidate, 0, 0, 0).is_none());
+
+        let sql = probe_sql("shop", &candidate);
+        assert!(sql.contains("countIf(v IN (SELECT `id` FROM `shop`.`customers`))"));
+        assert!(sql.contains("WHERE isNotNull(`customer_id`) LIMIT 1000"));
+    }
+}
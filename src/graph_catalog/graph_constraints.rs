@@ -0,0 +1,292 @@
+//! Declarative graph constraints.
+//!
+//! A schema may declare facts about its data that ClickGraph cannot infer from
+//! table definitions alone:
+//!
+//! ```yaml
+//! graph_schema:
+//!   constraints:
+//!     acyclic: [MANAGES]          # no directed cycle of MANAGES edges
+//!     disjoint_labels:
+//!       - [Person, Company]       # no node is both a Person and a Company
+//! ```
+//!
+//! The planner trusts these facts:
+//! - a variable-length walk over a single acyclic relationship type can never
+//!   revisit an edge, so the recursive CTE drops its `path_edges` array and the
+//!   per-hop `NOT has(...)` cycle check;
+//! - `n:B` on a variable already bound to a label disjoint with `B` folds to
+//!   `false` at plan time, even where the label would otherwise need a runtime
+//!   `label_column` comparison.
+//!
+//! Nothing enforces the facts on write. [`validation_checks`] produces one
+//! ClickHouse query per declared fact, each returning a `violations` count, so
+//! operators can verify them against the data (`GET
+//! /schemas/{name}/constraints/validate`). A wrong declaration silently changes
+//! query results — validate after loading data.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::graph_catalog::{
+    expression_parser::PropertyValue,
+    graph_schema::{GraphSchema, NodeSchema, RelationshipSchema},
+};
+
+/// Default hop bound for the acyclicity check's recursive walk.
+pub const DEFAULT_CYCLE_CHECK_DEPTH: u32 = 10;
+
+/// Runtime graph constraints (resolved from the `constraints` schema section)
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct GraphConstraints {
+    /// Relationship types declared free of directed cycles
+    pub acyclic_types: BTreeSet<String>,
+    /// Groups of node labels declared pairwise disjoint
+    pub disjoint_label_groups: Vec<BTreeSet<String>>,
+}
+
+impl GraphConstraints {
+    pub fn is_empty(&self) -> bool {
+        self.acyclic_types.is_empty() && self.disjoint_label_groups.is_empty()
+    }
+
+    /// Whether `rel_type` was declared acyclic
+    pub fn is_acyclic(&self, rel_type: &str) -> bool {
+        self.acyclic_types.contains(rel_type)
+    }
+
+    /// Whether two distinct labels were declared disjoint
+    pub fn are_disjoint(&self, a: &str, b: &str) -> bool {
+        a != b
+            && self
+                .disjoint_label_groups
+                .iter()
+                .any(|group| group.contains(a) && group.contains(b))
+    }
+}
+
+/// One validation query for a declared constraint.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ConstraintCheck {
+    /// Human-readable constraint, e.g. `acyclic MANAGES`
+    pub constraint: String,
+    /// ClickHouse query returning a single `violations` count
+    pub sql: String,
+}
+
+/// Build the validation queries for every declared constraint.
+///
+/// Acyclicity is checked per edge table with a recursive walk bounded by
+/// `max_depth` hops; a cycle longer than that is not detected. Disjointness is
+/// checked by joining the two labels' id columns, which assumes the ids share a
+/// domain; labels stored in one table and told apart by `label_column` are
+/// disjoint by construction and are skipped.
+pub fn validation_checks(schema: &GraphSchema, max_depth: u32) -> Vec<ConstraintCheck> {
+    let constraints = schema.constraints();
+    let mut checks = Vec::new();
+
+    for rel_type in &constraints.acyclic_types {
+        for rel in schema.rel_schemas_for_type(rel_type) {
+            checks.push(ConstraintCheck {
+                constraint: format!("acyclic {}", rel_type),
+                sql: cycle_check_sql(rel_type, rel, max_depth.max(1)),
+            });
+        }
+    }
+
+    for group in &constraints.disjoint_label_groups {
+        let labels: Vec<&String> = group.iter().collect();
+        for (i, a) in labels.iter().enumerate() {
+            for b in &labels[i + 1..] {
+                let (Some(node_a), Some(node_b)) =
+                    (schema.node_schema_opt(a), schema.node_schema_opt(b))
+                else {
+                    continue;
+                };
+                let shared_label_table = node_a.label_column.is_some()
+                    && qualified_table(&node_a.database, &node_a.table_name)
+                        == qualified_table(&node_b.database, &node_b.table_name);
+                if shared_label_table {
+                    continue;
+                }
+                checks.push(ConstraintCheck {
+                    constraint: format!("disjoint {}, {}", a, b),
+                    sql: disjoint_check_sql(node_a, node_b),
+                });
+            }
+        }
+    }
+
+    checks
+}
+
+fn cycle_check_sql(rel_type: &str, rel: &RelationshipSchema, max_depth: u32) -> String {
+    let table = qualified_table(&rel.database, &rel.table_name);
+    let from = id_expr("e", &rel.from_id.columns());
+    let to = id_expr("e", &rel.to_id.columns());
+
+    let mut edge_filters = Vec::new();
+    if let Some(type_column) = &rel.type_column {
+        edge_filters.push(format!(
+            "e.{} = '{}'",
+            type_column,
+            rel_type.replace('\'', "\\'")
+        ));
+    }
+    if let Some(sql) = rel.filter.as_ref().and_then(|f| f.to_sql("e").ok()) {
+        edge_filters.push(sql);
+    }
+    let base_where = if edge_filters.is_empty() {
+        String::new()
+    } else {
+        format!("\n    WHERE {}", edge_filters.join(" AND "))
+    };
+    let recursive_where = std::iter::once(format!("w.hops < {}", max_depth))
+        .chain(std::iter::once("w.end_id != w.start_id".to_string()))
+        .chain(edge_filters)
+        .collect::<Vec<_>>()
+        .join(" AND ");
+
+    format!(
+        "WITH RECURSIVE walk AS (\n    \
+         SELECT {from} AS start_id, {to} AS end_id, 1 AS hops\n    \
+         FROM {table} AS e{base_where}\n    \
+         UNION ALL\n    \
+         SELECT w.start_id, {to} AS end_id, w.hops + 1 AS hops\n    \
+         FROM walk AS w\n    \
+         JOIN {table} AS e ON {from} = w.end_id\n    \
+         WHERE {recursive_where}\n\
+         )\n\
+         SELECT count() AS violations FROM walk WHERE start_id = end_id"
+    )
+}
+
+fn disjoint_check_sql(a: &NodeSchema, b: &NodeSchema) -> String {
+    format!(
+        "SELECT count() AS violations\n\
+         FROM ({}) AS a\n\
+         INNER JOIN ({}) AS b ON a.id = b.id",
+        node_id_select(a),
+        node_id_select(b)
+    )
+}
+
+fn node_id_select(node: &NodeSchema) -> String {
+    // node_id names Cypher properties; select the mapped columns.
+    let columns: Vec<String> = node
+        .node_id
+        .columns()
+        .into_iter()
+        .map(|property| match node.property_mappings.get(property) {
+            Some(PropertyValue::Column(col)) => col.clone(),
+            _ => property.to_string(),
+        })
+        .collect();
+    let column_refs: Vec<&str> = columns.iter().map(String::as_str).collect();
+    let mut sql = format!(
+        "SELECT {} AS id FROM {} AS n",
+        id_expr("n", &column_refs),
+        qualified_table(&node.database, &node.table_name)
+    );
+    if let Some(filter) = node.filter.as_ref().and_then(|f| f.to_sql("n").ok()) {
+        sql.push_str(&format!(" WHERE {}", filter));
+    }
+    sql
+}
+
+fn id_expr(alias: &str, columns: &[&str]) -> String {
+    match columns {
+        [single] => format!("{}.{}", alias, single),
+        many => format!(
+            "tuple({})",
+            many.iter()
+                .map(|c| format!("{}.{}", alias, c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn qualified_table(database: &str, table: &str) -> String {
+    if database.is_empty() {
+        table.to_string()
+    } else {
+        format!("{}.{}", database, table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    const SCHEMA_YAML: &str = r#"
+graph_schema:
+  constraints:
+    acyclic: [MANAGES]
+    disjoint_labels:
+      - [Person, Company]
+  nodes:
+    - label: Person
+      database: hr
+      table: people
+      node_id: id
+      property_mappings:
+        id: person_id
+    - label: Company
+      database: hr
+      table: companies
+      node_id: id
+      property_mappings:
+        id: company_id
+  edges:
+    - type: MANAGES
+      database: hr
+      table: reporting
+      from_id: manager_id
+      to_id: report_id
+      from_node: Person
+      to_node: Person
+"#;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .expect("valid schema")
+    }
+
+    #[test]
+    fn lookups() {
+        let schema = schema();
+        let constraints = schema.constraints();
+        assert!(constraints.is_acyclic("MANAGES"));
+        assert!(!constraints.is_acyclic("KNOWS"));
+        assert!(constraints.are_disjoint("Person", "Company"));
+        assert!(constraints.are_disjoint("Company", "Person"));
+        assert!(!constraints.are_disjoint("Person", "Person"));
+    }
+
+    #[test]
+    fn validation_queries_cover_each_constraint() {
+        let checks = validation_checks(&schema(), 5);
+        assert_eq!(checks.len(), 2);
+
+        assert_eq!(checks[0].constraint, "acyclic MANAGES");
+        assert!(checks[0].sql.contains("FROM hr.reporting AS e"));
+        assert!(checks[0]
+            .sql
+            .contains("JOIN hr.reporting AS e ON e.manager_id = w.end_id"));
+        assert!(checks[0].sql.contains("w.hops < 5"));
+        assert!(checks[0].sql.ends_with("WHERE start_id = end_id"));
+
+        assert_eq!(checks[1].constraint, "disjoint Company, Person");
+        assert!(checks[1]
+            .sql
+            .contains("(SELECT n.company_id AS id FROM hr.companies AS n) AS a"));
+        assert!(checks[1]
+            .sql
+            .contains("(SELECT n.person_id AS id FROM hr.people AS n) AS b ON a.id = b.id"));
+    }
+}
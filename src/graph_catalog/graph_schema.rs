@@ -16,6 +16,7 @@ use super::engine_detection::TableEngine;
 use super::errors::GraphSchemaError;
 use super::expression_parser::PropertyValue;
 use super::filter_parser::SchemaFilter;
+use super::graph_constraints::GraphConstraints;
 use super::schema_types::SchemaType;
 
 /// Original-orientation edge-identity column names projected by the #617
@@ -698,6 +699,27 @@ impl RelationshipSchema {
         }
         cols
     }
+
+    /// `(column, value)` pairs selecting this relationship's rows out of a
+    /// table shared with other types or endpoint labels: `type_column` =
+    /// `rel_type`, `from_label_column` = `from_node`, `to_label_column` =
+    /// `to_node`.
+    pub fn row_discriminators<'a>(&'a self, rel_type: &'a str) -> Vec<(&'a str, &'a str)> {
+        [
+            (&self.type_column, rel_type),
+            (&self.from_label_column, self.from_node.as_str()),
+            (&self.to_label_column, self.to_node.as_str()),
+        ]
+        .into_iter()
+        .filter_map(|(column, value)| column.as_deref().map(|c| (c, value)))
+        .collect()
+    }
+
+    /// The column holding each row's relationship type, when the table is
+    /// shared by several types.
+    pub fn type_discriminator_column(&self) -> Option<&str> {
+        self.type_column.as_deref()
+    }
 }
 
 impl NodeSchema {
@@ -706,6 +728,24 @@ impl NodeSchema {
         format!("{}.{}", self.database, self.table_name)
     }
 
+    /// Whether this label's rows can be read from its table on their own:
+    /// not embedded in an edge table, not behind a parameterized view.
+    pub fn has_standalone_table(&self) -> bool {
+        !self.is_denormalized && self.view_parameters.is_none()
+    }
+
+    /// `(label_column, label_value)` selecting this label's rows out of a
+    /// table shared with other labels.
+    pub fn label_discriminator(&self) -> Option<(&str, &str)> {
+        Some((self.label_column.as_deref()?, self.label_value.as_deref()?))
+    }
+
+    /// The column holding each row's label, when the table is shared by
+    /// several labels.
+    pub fn label_discriminator_column(&self) -> Option<&str> {
+        self.label_column.as_deref()
+    }
+
     /// #492 review RN5: The node's identity columns as PHYSICAL table columns
     /// (node_id property names resolved through property_mappings; identity
     /// fallback when unmapped). Used to compare two node-table rows for
@@ -1020,6 +1060,75 @@ pub struct GraphSchema {
     /// Maps index name -> config (label, properties, analyzer)
     #[serde(skip)]
     fulltext_indexes: BTreeMap<String, FulltextIndexConfig>,
+
+    /// Guards against unfiltered whole-label scans (`max_unfiltered_rows`)
+    /// Maps node label -> limit
+    #[serde(skip)]
+    unfiltered_scan_limits: BTreeMap<String, UnfilteredScanLimit>,
+
+    /// Whole-node projections of wide labels (`return_properties` /
+    /// `max_return_columns`)
+    /// Maps node label -> projection
+    #[serde(skip)]
+    return_projections: BTreeMap<String, ReturnProjection>,
+
+    /// Declared graph facts (acyclic edge types, disjoint labels)
+    #[serde(skip)]
+    constraints: GraphConstraints,
+
+    /// Handling of labels / relationship types the schema does not define
+    #[serde(skip)]
+    unknown_labels: UnknownLabelMode,
+
+    /// Default ClickHouse query cache use for read queries
+    #[serde(skip)]
+    query_cache: QueryCacheConfig,
+
+    /// Date column every table is partitioned by when the graph is backed by
+    /// snapshot tables (`USE graph FOR TIMESTAMP ...`)
+    #[serde(skip)]
+    snapshot_column: Option<String>,
+
+    /// Soft-delete markers, folded into the table scans' filters
+    /// Maps `database.table` -> marker column
+    #[serde(skip)]
+    soft_deletes: BTreeMap<String, SoftDelete>,
+
+    /// Aggregated edge tables: logical tables read as a GROUP BY over raw rows
+    /// Maps `database.table` -> aggregate subquery
+    #[serde(skip)]
+    edge_aggregates: BTreeMap<String, String>,
+
+    /// Watermarks of eventually-updated edge tables, checked by queries that
+    /// ask for fresh data
+    /// Maps `database.table` -> watermark column and typical lag
+    #[serde(skip)]
+    freshness: BTreeMap<String, Freshness>,
+
+    /// Traversal costs of edge types for weighted shortest paths
+    /// Maps edge type -> expression over the edge's properties
+    #[serde(skip)]
+    edge_costs: BTreeMap<String, String>,
+
+    /// Time dimensions of bitemporal edge tables
+    /// Maps `database.table` -> valid / system time columns
+    #[serde(skip)]
+    edge_temporal: BTreeMap<String, EdgeTemporal>,
+
+    /// ClickHouse cluster the tables are sharded over (`cluster` in the
+    /// schema); its joins and `IN` subqueries are rendered `GLOBAL`
+    #[serde(skip)]
+    cluster: Option<ClusterLayout>,
+
+    /// Collations of string columns, applied to comparisons and ORDER BY
+    /// Maps `database.table` -> column -> collation
+    #[serde(skip)]
+    collations: BTreeMap<String, BTreeMap<String, Collation>>,
+
+    /// Query-wide collation (`CYPHER collation=...`), also applied when any
+    /// column is compared with a string literal
+    #[serde(skip)]
+    query_collation: Option<Collation>,
 }
 
 /// Runtime vector index configuration (resolved from schema definition)
@@ -1058,6 +1167,370 @@ pub struct FulltextIndexConfig {
     pub analyzer: String,
 }
 
+/// What the planner does with an unfiltered scan of a guarded label
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UnfilteredScanAction {
+    /// Cap the result with `LIMIT max_rows` and attach a notification
+    #[default]
+    Limit,
+    /// Fail the query at plan time
+    Reject,
+}
+
+/// What a MATCH on a label or relationship type the schema does not define does
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownLabelMode {
+    /// Fail the query at plan time
+    #[default]
+    Strict,
+    /// Match nothing and attach a notification
+    Lenient,
+}
+
+/// Default ClickHouse query cache use for read queries on this graph
+/// (`query_cache` in the schema; a request's `use_query_cache` /
+/// `query_cache_ttl` override it)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QueryCacheConfig {
+    /// Send `use_query_cache = 1` with read queries
+    #[serde(default)]
+    pub enabled: bool,
+    /// `query_cache_ttl` in seconds (ClickHouse's default of 60 when unset)
+    #[serde(default)]
+    pub ttl_seconds: Option<u64>,
+}
+
+/// Runtime guard for a node label whose table is too large to scan unfiltered
+/// (resolved from `max_unfiltered_rows` / `on_unfiltered_scan`)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct UnfilteredScanLimit {
+    /// Maximum rows an unfiltered `MATCH (n:Label) RETURN ...` may return
+    pub max_rows: u64,
+    /// Action taken when the query would exceed `max_rows`
+    pub action: UnfilteredScanAction,
+}
+
+/// Day of a `FOR TIMESTAMP` literal (`YYYY-MM-DD[( |T)HH:MM:SS[.fff]]`)
+fn snapshot_date(timestamp: &str) -> Option<chrono::NaiveDate> {
+    let timestamp = timestamp.trim();
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(timestamp, "%Y-%m-%d") {
+        return Some(date);
+    }
+    ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| chrono::NaiveDateTime::parse_from_str(timestamp, format).ok())
+        .map(|datetime| datetime.date())
+}
+
+/// An `AS OF SYSTEM TIME` / `VALID AT` literal (`YYYY-MM-DD[( |T)HH:MM:SS[.fff]]`,
+/// a bare date meaning its midnight) as a millisecond `toDateTime64` expression
+fn instant_expression(timestamp: &str) -> Option<String> {
+    let timestamp = timestamp.trim();
+    let datetime = chrono::NaiveDate::parse_from_str(timestamp, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .or_else(|| {
+            ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+                .iter()
+                .find_map(|format| chrono::NaiveDateTime::parse_from_str(timestamp, format).ok())
+        })?;
+    Some(format!(
+        "toDateTime64('{}', 3)",
+        datetime.format("%Y-%m-%d %H:%M:%S%.3f")
+    ))
+}
+
+/// Soft-delete convention of a node or edge table (`soft_delete` in the
+/// schema): rows are marked deleted instead of removed, reads skip them
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SoftDelete {
+    /// Column marking a row deleted (`deleted_at`, `is_deleted`)
+    pub column: String,
+    /// How the column marks it
+    #[serde(default)]
+    pub marker: SoftDeleteMarker,
+}
+
+/// Watermark of an eventually-updated edge table (`freshness` in the schema),
+/// such as the target table of a materialized view: how far its rows have
+/// caught up, and how far behind they usually run
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Freshness {
+    /// DateTime column set when a row is written; its `max()` is the watermark
+    pub watermark: String,
+    /// Usual lag behind the source in seconds; bounds how long `on_stale: wait`
+    /// waits for the table to catch up
+    #[serde(default)]
+    pub typical_lag_seconds: u64,
+}
+
+/// Time dimensions of a bitemporal edge table (`temporal` in the schema):
+/// when each row's fact held in the modelled world (valid time), and when the
+/// table held the row (system, or transaction, time), as CDC pipelines record
+/// it. Either dimension may be declared alone.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct EdgeTemporal {
+    /// Read by `USE graph VALID AT '<ts>'`
+    #[serde(default)]
+    pub valid_time: Option<TimePeriod>,
+    /// Read by `USE graph AS OF SYSTEM TIME '<ts>'`
+    #[serde(default)]
+    pub system_time: Option<TimePeriod>,
+}
+
+/// The ClickHouse cluster a graph's tables are sharded over. Node and edge
+/// tables are read through Distributed tables, each spreading a local table
+/// over the cluster's shards.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct ClusterLayout {
+    pub name: String,
+    /// Maps `database.distributed_table` -> `database.local_table`, for the
+    /// tables the schema maps by their local name
+    #[serde(default)]
+    pub local_tables: BTreeMap<String, String>,
+}
+
+/// A half-open `[from, to)` period held in two columns of a row. A NULL `to`,
+/// or no `to` column, leaves the period open-ended.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TimePeriod {
+    pub from: String,
+    #[serde(default)]
+    pub to: Option<String>,
+}
+
+impl TimePeriod {
+    /// Predicate keeping the rows whose period contains `instant`, a SQL
+    /// DateTime64 expression
+    pub fn contains_predicate(&self, instant: &str) -> String {
+        match &self.to {
+            Some(to) => format!(
+                "{from} <= {instant} AND ({to} IS NULL OR {to} > {instant})",
+                from = self.from,
+            ),
+            None => format!("{} <= {}", self.from, instant),
+        }
+    }
+}
+
+/// Dimension of a bitemporal query predicate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeDimension {
+    /// `AS OF SYSTEM TIME`
+    System,
+    /// `VALID AT`
+    Valid,
+}
+
+impl TimeDimension {
+    fn clause(self) -> &'static str {
+        match self {
+            TimeDimension::System => "AS OF SYSTEM TIME",
+            TimeDimension::Valid => "VALID AT",
+        }
+    }
+
+    /// Key of the dimension under `temporal` in the schema
+    fn key(self) -> &'static str {
+        match self {
+            TimeDimension::System => "system_time",
+            TimeDimension::Valid => "valid_time",
+        }
+    }
+
+    fn period(self, temporal: &EdgeTemporal) -> Option<&TimePeriod> {
+        match self {
+            TimeDimension::System => temporal.system_time.as_ref(),
+            TimeDimension::Valid => temporal.valid_time.as_ref(),
+        }
+    }
+}
+
+/// How a soft-delete column marks a deleted row
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SoftDeleteMarker {
+    /// Nullable deletion time: NULL while the row is live
+    #[default]
+    Timestamp,
+    /// 0 / 1 flag: 1 once the row is deleted
+    Flag,
+}
+
+impl SoftDelete {
+    /// Predicate a live row satisfies
+    pub fn live_predicate(&self) -> String {
+        match self.marker {
+            SoftDeleteMarker::Timestamp => format!("{} IS NULL", self.column),
+            SoftDeleteMarker::Flag => format!("{} = 0", self.column),
+        }
+    }
+
+    /// SQL value DELETE assigns to the column
+    pub fn deleted_value(&self) -> &'static str {
+        match self.marker {
+            SoftDeleteMarker::Timestamp => "now()",
+            SoftDeleteMarker::Flag => "1",
+        }
+    }
+}
+
+/// Collation of string values (`collations` in the schema, or the `CYPHER
+/// collation=...` query option), written as `+`-separated tokens: `ci` (case
+/// insensitive), `ai` (accent insensitive), anything else the ICU locale ORDER
+/// BY sorts by, e.g. `de`, `ci`, `tr+ci`, `en_US+ci+ai`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Collation {
+    /// Locale of `ORDER BY ... COLLATE '<locale>'`
+    pub locale: Option<String>,
+    /// Compare and sort lowercased
+    pub case_insensitive: bool,
+    /// Compare and sort with diacritics stripped
+    pub accent_insensitive: bool,
+}
+
+impl std::str::FromStr for Collation {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let mut collation = Collation::default();
+        for token in spec.split('+').map(str::trim) {
+            match token.to_ascii_lowercase().as_str() {
+                "ci" => collation.case_insensitive = true,
+                "ai" => collation.accent_insensitive = true,
+                _ if token.is_empty()
+                    || !token
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') =>
+                {
+                    return Err(format!(
+                        "invalid collation '{}': expected a locale (e.g. 'de', 'en_US') \
+                         and/or 'ci', 'ai' joined by '+'",
+                        spec
+                    ));
+                }
+                _ if collation.locale.is_some() => {
+                    return Err(format!(
+                        "invalid collation '{}': more than one locale",
+                        spec
+                    ));
+                }
+                _ => collation.locale = Some(token.to_string()),
+            }
+        }
+        Ok(collation)
+    }
+}
+
+impl fmt::Display for Collation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tokens: Vec<&str> = self
+            .locale
+            .as_deref()
+            .into_iter()
+            .chain(self.case_insensitive.then_some("ci"))
+            .chain(self.accent_insensitive.then_some("ai"))
+            .collect();
+        write!(f, "{}", tokens.join("+"))
+    }
+}
+
+/// `filter` ANDed with `predicate` (`({filter}) AND {predicate}`)
+fn and_filter(
+    filter: &Option<SchemaFilter>,
+    predicate: &str,
+) -> Result<SchemaFilter, GraphSchemaError> {
+    let combined = match filter {
+        Some(existing) => format!("({}) AND {}", existing.raw, predicate),
+        None => predicate.to_string(),
+    };
+    SchemaFilter::new(&combined).map_err(|e| GraphSchemaError::InvalidConfig {
+        message: format!("Invalid filter '{}': {}", combined, e),
+    })
+}
+
+/// Undo [`and_filter`]: `filter` without a trailing `predicate`
+fn strip_and_filter(filter: &Option<SchemaFilter>, predicate: &str) -> Option<SchemaFilter> {
+    let existing = filter.as_ref()?;
+    if existing.raw == predicate {
+        return None;
+    }
+    let inner = existing
+        .raw
+        .strip_suffix(predicate)
+        .and_then(|rest| rest.strip_suffix(") AND "))
+        .and_then(|rest| rest.strip_prefix('('));
+    match inner {
+        Some(inner) => SchemaFilter::new(inner).ok(),
+        None => Some(existing.clone()),
+    }
+}
+
+/// Replace the qualified table name `table` in `sql` where it stands alone
+/// (not part of a longer identifier or a backquoted name).
+fn replace_table_reference(sql: &str, table: &str, replacement: &str) -> String {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '`');
+    let mut out = String::with_capacity(sql.len());
+    let mut rest = sql;
+    while let Some(pos) = rest.find(table) {
+        let end = pos + table.len();
+        let standalone = !rest[..pos].chars().next_back().is_some_and(is_ident)
+            && !rest[end..].chars().next().is_some_and(is_ident);
+        out.push_str(&rest[..pos]);
+        out.push_str(if standalone { replacement } else { table });
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Properties `RETURN n` projects for a wide node label
+/// (resolved from `return_properties` / `max_return_columns`)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct ReturnProjection {
+    /// Properties projected right after the id, in priority order
+    pub properties: Vec<String>,
+    /// Column budget filled with the remaining properties; `None` projects
+    /// only the id and `properties`
+    pub max_columns: Option<usize>,
+}
+
+impl ReturnProjection {
+    /// Pick the `(property, column)` pairs to project out of `available`:
+    /// the id properties (always, even past the budget), then `properties`,
+    /// then the rest of `available` in its order while the budget lasts.
+    pub fn select(
+        &self,
+        id_properties: &[&str],
+        available: Vec<(String, String)>,
+    ) -> Vec<(String, String)> {
+        let budget = self.max_columns.unwrap_or(usize::MAX);
+        let mut remaining = available;
+        let mut selected = Vec::new();
+        let mut take = |name: &str, selected: &mut Vec<(String, String)>| {
+            if let Some(pos) = remaining.iter().position(|(p, _)| p == name) {
+                selected.push(remaining.remove(pos));
+            }
+        };
+        for id in id_properties {
+            take(id, &mut selected);
+        }
+        for property in &self.properties {
+            if selected.len() >= budget {
+                break;
+            }
+            take(property, &mut selected);
+        }
+        if self.max_columns.is_some() {
+            let room = budget.saturating_sub(selected.len());
+            selected.extend(remaining.into_iter().take(room));
+        }
+        selected
+    }
+}
+
 impl GraphSchema {
     /// Create a composite key for a relationship: "type::from_node::to_node"
     /// This allows multiple relationships with the same type but different node combinations
@@ -1091,6 +1564,20 @@ impl GraphSchema {
             rel_type_index,
             vector_indexes: BTreeMap::new(),
             fulltext_indexes: BTreeMap::new(),
+            unfiltered_scan_limits: BTreeMap::new(),
+            return_projections: BTreeMap::new(),
+            constraints: GraphConstraints::default(),
+            unknown_labels: UnknownLabelMode::default(),
+            query_cache: QueryCacheConfig::default(),
+            snapshot_column: None,
+            soft_deletes: BTreeMap::new(),
+            edge_aggregates: BTreeMap::new(),
+            freshness: BTreeMap::new(),
+            edge_costs: BTreeMap::new(),
+            edge_temporal: BTreeMap::new(),
+            cluster: None,
+            collations: BTreeMap::new(),
+            query_collation: None,
         }
     }
 
@@ -1757,6 +2244,359 @@ impl GraphSchema {
         self.fulltext_indexes.get(name)
     }
 
+    /// Install the per-label unfiltered scan guards (resolved from node definitions)
+    pub fn set_unfiltered_scan_limits(&mut self, limits: BTreeMap<String, UnfilteredScanLimit>) {
+        self.unfiltered_scan_limits = limits;
+    }
+
+    /// Look up the unfiltered scan guard for a node label
+    pub fn unfiltered_scan_limit(&self, label: &str) -> Option<&UnfilteredScanLimit> {
+        self.unfiltered_scan_limits.get(label)
+    }
+
+    /// Install the per-label whole-node projections (resolved from node definitions)
+    pub fn set_return_projections(&mut self, projections: BTreeMap<String, ReturnProjection>) {
+        self.return_projections = projections;
+    }
+
+    /// Look up the whole-node projection for a node label
+    pub fn return_projection(&self, label: &str) -> Option<&ReturnProjection> {
+        self.return_projections.get(label)
+    }
+
+    /// Install the declared graph constraints (resolved from `constraints`)
+    pub fn set_constraints(&mut self, constraints: GraphConstraints) {
+        self.constraints = constraints;
+    }
+
+    /// Declared graph constraints the planner may rely on
+    pub fn constraints(&self) -> &GraphConstraints {
+        &self.constraints
+    }
+
+    /// Install the unknown label handling (from `unknown_labels`)
+    pub fn set_unknown_labels(&mut self, mode: UnknownLabelMode) {
+        self.unknown_labels = mode;
+    }
+
+    /// How the planner treats labels / relationship types the schema lacks
+    pub fn unknown_labels(&self) -> UnknownLabelMode {
+        self.unknown_labels
+    }
+
+    /// Install the query cache defaults (from `query_cache`)
+    pub fn set_query_cache(&mut self, query_cache: QueryCacheConfig) {
+        self.query_cache = query_cache;
+    }
+
+    /// Default ClickHouse query cache use for read queries
+    pub fn query_cache(&self) -> QueryCacheConfig {
+        self.query_cache
+    }
+
+    /// Install the snapshot partition column (from `snapshot_column`)
+    pub fn set_snapshot_column(&mut self, column: Option<String>) {
+        self.snapshot_column = column;
+    }
+
+    /// Snapshot partition column, when the graph is backed by snapshot tables
+    pub fn snapshot_column(&self) -> Option<&str> {
+        self.snapshot_column.as_deref()
+    }
+
+    /// This schema as of one snapshot: every node and relationship table scan
+    /// additionally filters `snapshot_column = toDate('<date>')`, ANDed with
+    /// the table's own `filter`. `timestamp` is `YYYY-MM-DD`, optionally
+    /// followed by a time (`T` or space separated) that is truncated to its day.
+    pub fn at_snapshot(&self, timestamp: &str) -> Result<GraphSchema, GraphSchemaError> {
+        let column = self
+            .snapshot_column
+            .as_deref()
+            .ok_or_else(|| GraphSchemaError::InvalidConfig {
+                message: format!(
+                    "FOR TIMESTAMP requires snapshot tables, but graph '{}' declares no snapshot_column",
+                    self.database
+                ),
+            })?;
+        let date = snapshot_date(timestamp).ok_or_else(|| GraphSchemaError::InvalidConfig {
+            message: format!(
+                "Invalid snapshot timestamp '{}': expected 'YYYY-MM-DD' or 'YYYY-MM-DD HH:MM:SS'",
+                timestamp
+            ),
+        })?;
+        let predicate = format!("{} = toDate('{}')", column, date.format("%Y-%m-%d"));
+
+        let mut schema = self.clone();
+        for node in schema.nodes.values_mut() {
+            // Denormalized nodes read their edge table, which is filtered below
+            if !node.is_denormalized {
+                node.filter = Some(and_filter(&node.filter, &predicate)?);
+            }
+        }
+        for rel in schema.relationships.values_mut() {
+            rel.filter = Some(and_filter(&rel.filter, &predicate)?);
+        }
+        Ok(schema)
+    }
+
+    /// Install the soft-delete markers (resolved from `soft_delete` on node
+    /// and edge definitions, keyed by `database.table`) and fold each
+    /// table's live-row predicate into its scans' `filter`
+    pub fn set_soft_deletes(
+        &mut self,
+        soft_deletes: BTreeMap<String, SoftDelete>,
+    ) -> Result<(), GraphSchemaError> {
+        for node in self.nodes.values_mut() {
+            let key = format!("{}.{}", node.database, node.table_name);
+            // Denormalized nodes read their edge table, which is filtered below
+            if let Some(soft_delete) = soft_deletes.get(&key).filter(|_| !node.is_denormalized) {
+                node.filter = Some(and_filter(&node.filter, &soft_delete.live_predicate())?);
+            }
+        }
+        for rel in self.relationships.values_mut() {
+            let key = format!("{}.{}", rel.database, rel.table_name);
+            if let Some(soft_delete) = soft_deletes.get(&key) {
+                rel.filter = Some(and_filter(&rel.filter, &soft_delete.live_predicate())?);
+            }
+        }
+        self.soft_deletes = soft_deletes;
+        Ok(())
+    }
+
+    /// Look up the soft-delete marker of a table
+    pub fn soft_delete(&self, database: &str, table: &str) -> Option<&SoftDelete> {
+        self.soft_deletes.get(&format!("{}.{}", database, table))
+    }
+
+    /// The detected engine of `database.table`, from whichever node or edge
+    /// maps onto it
+    pub fn table_engine(&self, database: &str, table: &str) -> Option<&TableEngine> {
+        self.nodes
+            .values()
+            .filter(|n| n.database == database && n.table_name == table)
+            .find_map(|n| n.engine.as_ref())
+            .or_else(|| {
+                self.relationships
+                    .values()
+                    .filter(|r| r.database == database && r.table_name == table)
+                    .find_map(|r| r.engine.as_ref())
+            })
+    }
+
+    /// Install the aggregated edge tables (resolved from `aggregate` on edge
+    /// definitions): `database.table` -> `SELECT ... GROUP BY ...` subquery
+    pub fn set_edge_aggregates(&mut self, edge_aggregates: BTreeMap<String, String>) {
+        self.edge_aggregates = edge_aggregates;
+    }
+
+    /// The aggregate subquery behind a logical edge table, if it is one
+    pub fn edge_aggregate(&self, database: &str, table: &str) -> Option<&str> {
+        self.edge_aggregates
+            .get(&format!("{}.{}", database, table))
+            .map(String::as_str)
+    }
+
+    /// Install the edge table watermarks (resolved from `freshness` on edge
+    /// definitions, keyed by `database.table`)
+    pub fn set_freshness(&mut self, freshness: BTreeMap<String, Freshness>) {
+        self.freshness = freshness;
+    }
+
+    /// Tables with a declared watermark, as `database.table`
+    pub fn freshness_tables(&self) -> impl Iterator<Item = (&str, &Freshness)> {
+        self.freshness.iter().map(|(table, f)| (table.as_str(), f))
+    }
+
+    /// Install the edge type costs (resolved from `cost` on edge definitions)
+    pub fn set_edge_costs(&mut self, edge_costs: BTreeMap<String, String>) {
+        self.edge_costs = edge_costs;
+    }
+
+    /// The declared traversal cost of an edge type, if any
+    pub fn edge_cost(&self, rel_type: &str) -> Option<&str> {
+        self.edge_costs.get(rel_type).map(String::as_str)
+    }
+
+    /// Install the time dimensions of bitemporal edge tables (resolved from
+    /// `temporal` on edge definitions, keyed by `database.table`)
+    pub fn set_edge_temporal(&mut self, edge_temporal: BTreeMap<String, EdgeTemporal>) {
+        self.edge_temporal = edge_temporal;
+    }
+
+    /// The time dimensions of a bitemporal table, if it is one
+    pub fn edge_temporal(&self, database: &str, table: &str) -> Option<&EdgeTemporal> {
+        self.edge_temporal.get(&format!("{}.{}", database, table))
+    }
+
+    /// Install the cluster layout (resolved from `cluster` in the schema)
+    pub fn set_cluster(&mut self, cluster: Option<ClusterLayout>) {
+        self.cluster = cluster;
+    }
+
+    /// The cluster the graph's tables are sharded over, if any
+    pub fn cluster(&self) -> Option<&ClusterLayout> {
+        self.cluster.as_ref()
+    }
+
+    /// This schema at one instant of a time dimension: every scan of a table
+    /// declaring that dimension (edges, and nodes sharing their table) also
+    /// requires its period to contain `timestamp`. Tables without the
+    /// dimension are read whole. Fails when no table declares it.
+    pub fn at_time(
+        &self,
+        dimension: TimeDimension,
+        timestamp: &str,
+    ) -> Result<GraphSchema, GraphSchemaError> {
+        if !self
+            .edge_temporal
+            .values()
+            .any(|temporal| dimension.period(temporal).is_some())
+        {
+            return Err(GraphSchemaError::InvalidConfig {
+                message: format!(
+                    "{} requires bitemporal edges, but graph '{}' declares no edge with temporal.{}",
+                    dimension.clause(),
+                    self.database,
+                    dimension.key()
+                ),
+            });
+        }
+        let instant =
+            instant_expression(timestamp).ok_or_else(|| GraphSchemaError::InvalidConfig {
+                message: format!(
+                    "Invalid {} timestamp '{}': expected 'YYYY-MM-DD' or 'YYYY-MM-DD HH:MM:SS'",
+                    dimension.clause(),
+                    timestamp
+                ),
+            })?;
+        let predicate = |database: &str, table: &str| {
+            self.edge_temporal
+                .get(&format!("{}.{}", database, table))
+                .and_then(|temporal| dimension.period(temporal))
+                .map(|period| period.contains_predicate(&instant))
+        };
+
+        let mut schema = self.clone();
+        for node in schema.nodes.values_mut() {
+            // Denormalized nodes read their edge table, which is filtered below
+            if node.is_denormalized {
+                continue;
+            }
+            if let Some(predicate) = predicate(&node.database, &node.table_name) {
+                node.filter = Some(and_filter(&node.filter, &predicate)?);
+            }
+        }
+        for rel in schema.relationships.values_mut() {
+            if let Some(predicate) = predicate(&rel.database, &rel.table_name) {
+                rel.filter = Some(and_filter(&rel.filter, &predicate)?);
+            }
+        }
+        Ok(schema)
+    }
+
+    /// Replace every reference to an aggregated edge table in generated SQL
+    /// with its parenthesized aggregate subquery. Those tables do not exist
+    /// in ClickHouse; table references are the only place their qualified
+    /// name appears (columns are read through table aliases).
+    pub fn expand_edge_aggregates(&self, sql: String) -> String {
+        let mut sql = sql;
+        for (table, subquery) in &self.edge_aggregates {
+            sql = replace_table_reference(&sql, table, &format!("({})", subquery));
+        }
+        sql
+    }
+
+    /// Install the column collations (resolved from `collations` on node and
+    /// edge definitions): `database.table` -> column -> collation
+    pub fn set_collations(&mut self, collations: BTreeMap<String, BTreeMap<String, Collation>>) {
+        self.collations = collations;
+    }
+
+    /// The collation of a column of a `database.table`
+    pub fn collation(&self, table: &str, column: &str) -> Option<&Collation> {
+        self.collations.get(table)?.get(column)
+    }
+
+    /// Whether any column or the query has a collation
+    pub fn has_collations(&self) -> bool {
+        !self.collations.is_empty() || self.query_collation.is_some()
+    }
+
+    /// The query-wide collation, if the query set one
+    pub fn query_collation(&self) -> Option<&Collation> {
+        self.query_collation.as_ref()
+    }
+
+    /// This schema under a query-wide collation (the `CYPHER collation=...`
+    /// query option): it replaces every column collation and applies to every
+    /// property declared `string` in `property_types`.
+    pub fn with_query_collation(&self, collation: Collation) -> GraphSchema {
+        let mut schema = self.clone();
+        for columns in schema.collations.values_mut() {
+            for column_collation in columns.values_mut() {
+                *column_collation = collation.clone();
+            }
+        }
+        let nodes = self
+            .nodes
+            .values()
+            .filter(|node| !node.is_denormalized)
+            .map(|node| {
+                (
+                    &node.database,
+                    &node.table_name,
+                    &node.property_types,
+                    &node.property_mappings,
+                )
+            });
+        let rels = self.relationships.values().map(|rel| {
+            (
+                &rel.database,
+                &rel.table_name,
+                &rel.property_types,
+                &rel.property_mappings,
+            )
+        });
+        for (database, table, types, mappings) in nodes.chain(rels) {
+            for (property, _) in types.iter().filter(|(_, ty)| **ty == SchemaType::String) {
+                if let Some(PropertyValue::Column(column)) = mappings.get(property) {
+                    schema
+                        .collations
+                        .entry(format!("{}.{}", database, table))
+                        .or_default()
+                        .insert(column.clone(), collation.clone());
+                }
+            }
+        }
+        schema.query_collation = Some(collation);
+        schema
+    }
+
+    /// This schema with soft-deleted rows visible (the `CYPHER
+    /// include_deleted=true` query option): scans drop the live-row
+    /// predicates [`Self::set_soft_deletes`] folded into their filters.
+    /// Apply before [`Self::at_snapshot`].
+    pub fn including_deleted(&self) -> GraphSchema {
+        let mut schema = self.clone();
+        for node in schema.nodes.values_mut() {
+            let key = format!("{}.{}", node.database, node.table_name);
+            if let Some(soft_delete) = self
+                .soft_deletes
+                .get(&key)
+                .filter(|_| !node.is_denormalized)
+            {
+                node.filter = strip_and_filter(&node.filter, &soft_delete.live_predicate());
+            }
+        }
+        for rel in schema.relationships.values_mut() {
+            let key = format!("{}.{}", rel.database, rel.table_name);
+            if let Some(soft_delete) = self.soft_deletes.get(&key) {
+                rel.filter = strip_and_filter(&rel.filter, &soft_delete.live_predicate());
+            }
+        }
+        schema
+    }
+
     /// Expand a polymorphic `$any` node type to all concrete node labels.
     /// Returns a single-element vec for concrete types, all node labels for `$any`.
     pub fn expand_node_type(&self, node_type: &str) -> Vec<String> {
@@ -3453,4 +4293,15 @@ mod tests {
         result.sort();
         assert_eq!(result.len(), 2);
     }
+
+    #[test]
+    fn test_collation_spec_round_trips() {
+        let collation: Collation = "de+ci+ai".parse().unwrap();
+        assert_eq!(collation.locale.as_deref(), Some("de"));
+        assert!(collation.case_insensitive && collation.accent_insensitive);
+        assert_eq!(collation.to_string(), "de+ci+ai");
+
+        assert!("de+fr".parse::<Collation>().is_err());
+        assert!("de'; DROP".parse::<Collation>().is_err());
+    }
 }
@@ -0,0 +1,399 @@
+//! Compact graph description for LLM agents writing Cypher
+//!
+//! Where `llm_prompt` describes raw tables so an LLM can draft a schema, this
+//! describes a loaded schema the way a Cypher author sees it: labels with
+//! their id and properties, relationship patterns, and a few sampled values
+//! per property so the model can guess literal formats. The output is meant
+//! to be pasted into a system prompt, so it is kept short: property types are
+//! inlined (`"age: integer"`) and examples are capped and truncated.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::config::{EdgeDefinition, GraphSchemaConfig};
+use super::expression_parser::PropertyValue;
+use super::graph_schema::GraphSchema;
+
+/// Example values per property when the request doesn't say
+pub const DEFAULT_SAMPLES: usize = 3;
+
+/// Upper bound for the requested number of examples per property
+pub const MAX_SAMPLES: usize = 10;
+
+/// Rows read from each table to pick examples from
+const SAMPLE_ROWS: usize = 1000;
+
+/// Examples longer than this are cut
+const MAX_EXAMPLE_CHARS: usize = 40;
+
+const RULES: [&str; 3] = [
+    "Use only the labels, relationship types and properties listed here.",
+    "Relationships only exist in the directions their patterns show; use an undirected pattern to match either way.",
+    "Node ids are the `id` properties; match nodes by them when the user names a specific entity.",
+];
+
+/// LLM context of one schema (`GET /schemas/{name}/llm-context`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LlmContext {
+    pub schema: String,
+    pub nodes: Vec<NodeContext>,
+    pub edges: Vec<EdgeContext>,
+    pub rules: Vec<String>,
+    /// Rough size of the serialized context (chars / 4)
+    pub estimated_tokens: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NodeContext {
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub id: Vec<String>,
+    /// `name` or `name: type`, sorted by name
+    pub properties: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub examples: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EdgeContext {
+    /// `(:From)-[:TYPE]->(:To)`
+    pub pattern: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub examples: BTreeMap<String, Vec<String>>,
+}
+
+/// Which entry of an [`LlmContext`] a sample query fills in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleOwner {
+    Node(usize),
+    Edge(usize),
+}
+
+/// One query reading example values of an entry's properties. Its single
+/// result row has the values of `properties[i]` under `p{i}`.
+#[derive(Debug, Clone)]
+pub struct SampleQuery {
+    pub owner: SampleOwner,
+    pub sql: String,
+    properties: Vec<String>,
+}
+
+/// Descriptions of node labels and edge types in a schema's YAML, by name
+pub fn descriptions(config: &GraphSchemaConfig) -> HashMap<String, String> {
+    let schema = &config.graph_schema;
+    let nodes = schema
+        .nodes
+        .iter()
+        .filter_map(|node| Some((node.label.clone(), node.description.clone()?)));
+    let edges = schema.edges.iter().flat_map(|edge| match edge {
+        EdgeDefinition::Standard(def) => def
+            .description
+            .clone()
+            .map(|d| vec![(def.type_name.clone(), d)])
+            .unwrap_or_default(),
+        EdgeDefinition::Polymorphic(def) => def
+            .description
+            .clone()
+            .map(|d| {
+                def.type_values
+                    .iter()
+                    .map(|t| (t.clone(), d.clone()))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    });
+    nodes.chain(edges).collect()
+}
+
+/// The context of `schema`, without examples, and the queries that read
+/// `samples` examples per property (none when `samples` is 0).
+pub fn build(
+    name: &str,
+    schema: &GraphSchema,
+    descriptions: &HashMap<String, String>,
+    samples: usize,
+) -> (LlmContext, Vec<SampleQuery>) {
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut queries = Vec::new();
+    let samples = samples.min(MAX_SAMPLES);
+
+    // Nodes are registered under their label and under `db::table::label`
+    for (label, node) in schema
+        .all_node_schemas()
+        .iter()
+        .filter(|(key, _)| !key.contains("::"))
+    {
+        let properties = describe_properties(&node.property_mappings, &node.property_types);
+        if samples > 0 && !node.is_denormalized && node.view_parameters.is_none() {
+            let filter = match (&node.label_column, &node.label_value) {
+                (Some(column), Some(value)) => Some(format!(
+                    "{} = '{}'",
+                    quote(column),
+                    value.replace('\\', "\\\\").replace('\'', "\\'")
+                )),
+                _ => None,
+            };
+            queries.extend(sample_query(
+                SampleOwner::Node(nodes.len()),
+                &node.database,
+                &node.table_name,
+                &node.property_mappings,
+                filter,
+                samples,
+            ));
+        }
+        nodes.push(NodeContext {
+            label: label.clone(),
+            description: descriptions.get(label).cloned(),
+            id: node
+                .node_id
+                .columns()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            properties,
+            examples: BTreeMap::new(),
+        });
+    }
+
+    for (rel_type, keys) in schema.get_rel_type_index() {
+        for key in keys {
+            let Some(rel) = schema.get_relationships_schemas().get(key) else {
+                continue;
+            };
+            // Polymorphic tables hold several types; unfiltered samples
+            // would mix them
+            if samples > 0 && rel.type_column.is_none() && rel.view_parameters.is_none() {
+                queries.extend(sample_query(
+                    SampleOwner::Edge(edges.len()),
+                    &rel.database,
+                    &rel.table_name,
+                    &rel.property_mappings,
+                    None,
+                    samples,
+                ));
+            }
+            edges.push(EdgeContext {
+                pattern: format!("(:{})-[:{}]->(:{})", rel.from_node, rel_type, rel.to_node),
+                description: descriptions.get(rel_type).cloned(),
+                properties: describe_properties(&rel.property_mappings, &rel.property_types),
+                examples: BTreeMap::new(),
+            });
+        }
+    }
+
+    let mut context = LlmContext {
+        schema: name.to_string(),
+        nodes,
+        edges,
+        rules: RULES.iter().map(|r| r.to_string()).collect(),
+        estimated_tokens: 0,
+    };
+    context.estimate_tokens();
+    (context, queries)
+}
+
+impl LlmContext {
+    /// Fill in the examples `row` (the result of `query`) holds.
+    pub fn add_examples(&mut self, query: &SampleQuery, row: &Value) {
+        let examples = match query.owner {
+            SampleOwner::Node(i) => self.nodes.get_mut(i).map(|n| &mut n.examples),
+            SampleOwner::Edge(i) => self.edges.get_mut(i).map(|e| &mut e.examples),
+        };
+        let Some(examples) = examples else {
+            return;
+        };
+        for (i, property) in query.properties.iter().enumerate() {
+            let values: Vec<String> = row
+                .get(format!("p{}", i))
+                .and_then(Value::as_array)
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default();
+            if !values.is_empty() {
+                examples.insert(property.clone(), values);
+            }
+        }
+        self.estimate_tokens();
+    }
+
+    fn estimate_tokens(&mut self) {
+        self.estimated_tokens = 0;
+        self.estimated_tokens = serde_json::to_string(self).map_or(0, |json| json.len() / 4);
+    }
+}
+
+fn describe_properties(
+    mappings: &HashMap<String, PropertyValue>,
+    types: &HashMap<String, super::schema_types::SchemaType>,
+) -> Vec<String> {
+    let mut names: Vec<&String> = mappings.keys().collect();
+    names.sort();
+    names
+        .into_iter()
+        .map(|name| match types.get(name) {
+            Some(ty) => format!("{}: {}", name, ty),
+            None => name.clone(),
+        })
+        .collect()
+}
+
+/// Backquoted ClickHouse identifier
+fn quote(identifier: &str) -> String {
+    format!("`{}`", identifier.replace('`', "``"))
+}
+
+/// Query reading up to `samples` distinct non-empty values of each
+/// column-mapped property, from the first [`SAMPLE_ROWS`] rows of the table.
+fn sample_query(
+    owner: SampleOwner,
+    database: &str,
+    table: &str,
+    mappings: &HashMap<String, PropertyValue>,
+    filter: Option<String>,
+    samples: usize,
+) -> Option<SampleQuery> {
+    let mut columns: Vec<(&String, &String)> = mappings
+        .iter()
+        .filter_map(|(property, value)| match value {
+            PropertyValue::Column(column) => Some((property, column)),
+            PropertyValue::Expression(_) => None,
+        })
+        .collect();
+    if columns.is_empty() {
+        return None;
+    }
+    columns.sort();
+
+    let aggregates: Vec<String> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, (_, column))| {
+            let value = format!(
+                "substring(toString({}), 1, {})",
+                quote(column),
+                MAX_EXAMPLE_CHARS
+            );
+            format!(
+                "groupUniqArrayIf({})({}, {} != '') AS p{}",
+                samples, value, value, i
+            )
+        })
+        .collect();
+    let mut selected: Vec<String> = columns.iter().map(|(_, column)| quote(column)).collect();
+    selected.dedup();
+    let sql = format!(
+        "SELECT {} FROM (SELECT {} FROM {}.{}{} LIMIT {})",
+        aggregates.join(", "),
+        selected.join(", "),
+        quote(database),
+        quote(table),
+        filter.map(|f| format!(" WHERE {}", f)).unwrap_or_default(),
+        SAMPLE_ROWS
+    );
+    Some(SampleQuery {
+        owner,
+        sql,
+        properties: columns.into_iter().map(|(p, _)| p.clone()).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: social
+      table: users
+      node_id: user_id
+      description: Registered users
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        name_upper: "upper(full_name)"
+      property_types:
+        user_id: integer
+  edges:
+    - type: FOLLOWS
+      database: social
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings:
+        since: follow_date
+"#;
+
+    fn context(samples: usize) -> (LlmContext, Vec<SampleQuery>) {
+        let config = GraphSchemaConfig::from_yaml_str(SCHEMA).unwrap();
+        let schema = config.to_graph_schema().unwrap();
+        build("social", &schema, &descriptions(&config), samples)
+    }
+
+    #[test]
+    fn describes_labels_patterns_and_properties() {
+        let (ctx, _) = context(0);
+        assert_eq!(ctx.nodes.len(), 1);
+        let user = &ctx.nodes[0];
+        assert_eq!(user.label, "User");
+        assert_eq!(user.description.as_deref(), Some("Registered users"));
+        assert_eq!(user.id, vec!["user_id"]);
+        assert_eq!(
+            user.properties,
+            vec!["name", "name_upper", "user_id: integer"]
+        );
+        assert_eq!(ctx.edges[0].pattern, "(:User)-[:FOLLOWS]->(:User)");
+        assert_eq!(ctx.edges[0].properties, vec!["since"]);
+        assert!(ctx.estimated_tokens > 0);
+    }
+
+    #[test]
+    fn samples_column_properties_only() {
+        let (_, queries) = context(2);
+        assert_eq!(queries.len(), 2);
+        let users = &queries[0];
+        assert_eq!(users.owner, SampleOwner::Node(0));
+        assert_eq!(users.properties, vec!["name", "user_id"]);
+        assert!(!users.sql.contains("upper("), "{}", users.sql);
+        assert!(users.sql.contains("groupUniqArrayIf(2)("), "{}", users.sql);
+        assert!(
+            users
+                .sql
+                .ends_with("FROM (SELECT `full_name`, `user_id` FROM `social`.`users` LIMIT 1000)"),
+            "{}",
+            users.sql
+        );
+        assert!(context(0).1.is_empty());
+    }
+
+    #[test]
+    fn examples_land_on_their_entry() {
+        let (mut ctx, queries) = context(2);
+        ctx.add_examples(&queries[0], &json!({"p0": ["Alice", "Bob"], "p1": []}));
+        let user = &ctx.nodes[0];
+        assert_eq!(user.examples["name"], vec!["Alice", "Bob"]);
+        assert!(!user.examples.contains_key("user_id"));
+
+        let json = serde_json::to_value(&ctx).unwrap();
+        assert!(json["edges"][0].get("examples").is_none());
+    }
+}
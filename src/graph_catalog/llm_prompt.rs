@@ -5,7 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::schema_discovery::TableMetadata;
+use super::schema_discovery::{SourceKind, TableMetadata};
 
 /// Maximum tables per prompt batch to stay within context limits
 const MAX_TABLES_PER_BATCH: usize = 40;
@@ -78,6 +78,8 @@ graph_schema:
 7. Polymorphic references (object_type + object_id) should be noted but may need multiple edge entries with filters
 8. Omit internal/audit columns from property_mappings unless they carry domain meaning
 9. Tables with no PK and only event data (audit logs, event streams) are typically not modeled as graph entities — skip them or add a comment
+10. Views, materialized views and dictionaries are valid node/edge tables; sources marked "NOT USABLE" must not appear in the schema
+11. For a parameterized view, list its parameters under the definition's view_parameters
 
 Return ONLY the YAML, no explanation."#;
 
@@ -154,7 +156,27 @@ fn build_single_prompt(
     ));
 
     for table in tables {
-        user_prompt.push_str(&format!("### {}\n", table.name));
+        let kind = match table.kind {
+            SourceKind::Table => "",
+            SourceKind::View => " (view)",
+            SourceKind::MaterializedView => " (materialized view)",
+            SourceKind::Dictionary => " (dictionary)",
+        };
+        user_prompt.push_str(&format!("### {}{}\n", table.name, kind));
+        if !table.graph_source {
+            user_prompt.push_str("NOT USABLE as a graph source");
+            if let Some(note) = &table.note {
+                user_prompt.push_str(&format!(" — {}", note));
+            }
+            user_prompt.push_str("\n\n");
+            continue;
+        }
+        if !table.view_parameters.is_empty() {
+            user_prompt.push_str(&format!(
+                "View parameters: {}\n",
+                table.view_parameters.join(", ")
+            ));
+        }
 
         // Columns
         user_prompt.push_str("Columns:\n");
@@ -213,7 +235,7 @@ fn build_single_prompt(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph_catalog::schema_discovery::ColumnMetadata;
+    use crate::graph_catalog::schema_discovery::{ColumnMetadata, SourceKind};
 
     fn make_table(name: &str, cols: &[(&str, &str, bool)]) -> TableMetadata {
         TableMetadata {
@@ -227,6 +249,10 @@ mod tests {
                     is_in_order_by: false,
                 })
                 .collect(),
+            kind: SourceKind::Table,
+            graph_source: true,
+            note: None,
+            view_parameters: vec![],
             row_count: Some(100),
             sample: vec![],
         }
@@ -281,6 +307,10 @@ mod tests {
     fn test_column_flags() {
         let tables = vec![TableMetadata {
             name: "t".to_string(),
+            kind: SourceKind::Table,
+            graph_source: true,
+            note: None,
+            view_parameters: vec![],
             columns: vec![ColumnMetadata {
                 name: "ts".to_string(),
                 data_type: "DateTime".to_string(),
@@ -299,6 +329,10 @@ mod tests {
     fn test_sample_data_included() {
         let tables = vec![TableMetadata {
             name: "users".to_string(),
+            kind: SourceKind::Table,
+            graph_source: true,
+            note: None,
+            view_parameters: vec![],
             columns: vec![],
             row_count: Some(42),
             sample: vec![serde_json::json!({"id": 1, "name": "Alice"})],
@@ -311,6 +345,22 @@ mod tests {
         assert!(prompt.contains("Alice"));
     }
 
+    #[test]
+    fn test_source_kinds_annotated() {
+        let mut view = make_table("active_users", &[("id", "UInt64", false)]);
+        view.kind = SourceKind::View;
+        view.view_parameters = vec!["tenant_id".to_string()];
+        let mut queue = make_table("events_queue", &[("id", "UInt64", false)]);
+        queue.graph_source = false;
+        queue.note = Some("Kafka engine: reading consumes messages".to_string());
+
+        let result = format_discovery_prompt("db", &[view, queue]);
+        let prompt = &result.prompts[0].user_prompt;
+        assert!(prompt.contains("### active_users (view)"));
+        assert!(prompt.contains("View parameters: tenant_id"));
+        assert!(prompt.contains("### events_queue\nNOT USABLE as a graph source — Kafka engine"));
+    }
+
     #[test]
     fn test_no_batching_at_boundary() {
         let tables: Vec<TableMetadata> = (0..40)
@@ -2,16 +2,23 @@ pub mod column_info;
 pub mod composite_key_utils;
 pub mod config;
 pub mod constraint_compiler;
+pub mod denormalization_advisor;
 pub mod element_id;
 pub mod engine_detection;
 pub mod errors;
 pub mod expression_parser;
 pub mod filter_parser;
+pub mod fk_inference;
+pub mod graph_constraints;
 pub mod graph_schema;
+pub mod llm_context;
 pub mod llm_prompt;
 pub mod node_classification;
 pub mod pattern_schema;
+pub mod schema_diff;
 pub mod schema_discovery;
+pub mod schema_lint;
+pub mod schema_templates;
 
 // DeltaGraph Phase 3: schema discovery against a Databricks SQL
 // Warehouse. Mirrors `schema_discovery` but drives the executor over
@@ -42,6 +49,8 @@ pub use composite_key_utils::{
 pub use config::{GraphSchemaConfig, GraphSchemaDefinition};
 pub use engine_detection::{detect_table_engine, TableEngine};
 pub use filter_parser::SchemaFilter;
+#[allow(unused_imports)]
+pub use fk_inference::InferredEdge;
 pub use graph_schema::{
     classify_edge_table_pattern, edge_has_node_properties, is_fully_denormalized_edge_table,
     is_node_denormalized_on_edge, Direction, EdgeTablePattern, GraphSchema, GraphSchemaElement,
@@ -58,7 +67,8 @@ pub use pattern_schema::{
 #[allow(unused_imports)]
 pub use schema_discovery::{
     extract_yaml_list_items, merge_batch_yaml, ColumnMetadata, DraftOptions, DraftRequest,
-    EdgeHint, FkEdgeHint, IntrospectResponse, NodeHint, SchemaDiscovery, Suggestion, TableMetadata,
+    EdgeHint, FkEdgeHint, IntrospectResponse, NodeHint, SchemaDiscovery, SourceKind, Suggestion,
+    TableMetadata,
 };
 #[allow(unused_imports)]
 pub use schema_validator::SchemaValidator;
@@ -212,7 +212,8 @@ pub enum EdgeAccessStrategy {
     FkEdge {
         /// The node table that contains the FK column
         node_table: String,
-        /// The FK column name
+        /// The FK column name (comma-separated for composite foreign keys,
+        /// in the same order as the referenced node's ID columns)
         fk_column: String,
     },
 }
@@ -1285,7 +1286,12 @@ impl PatternSchemaContext {
                 cols.extend(properties.values().cloned());
             }
             EdgeAccessStrategy::FkEdge { fk_column, .. } => {
-                cols.insert(fk_column.clone());
+                cols.extend(
+                    Identifier::from_comma_separated(fk_column)
+                        .columns()
+                        .into_iter()
+                        .map(str::to_string),
+                );
             }
         }
         if let NodeAccessStrategy::EmbeddedInEdge {
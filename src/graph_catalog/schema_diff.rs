@@ -0,0 +1,183 @@
+//! Differences between two versions of a graph schema.
+//!
+//! Used by schema hot-reload (`POST /schemas/reload`) to report what a
+//! reload changed. Nodes are compared by label and edges by relationship
+//! type; an element counts as changed when any of its resolved mapping
+//! (table, ids, properties, filters, ...) differs.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::graph_schema::GraphSchema;
+
+/// What changed between two versions of one schema, each list sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SchemaDiff {
+    pub added_nodes: Vec<String>,
+    pub removed_nodes: Vec<String>,
+    pub changed_nodes: Vec<String>,
+    pub added_edges: Vec<String>,
+    pub removed_edges: Vec<String>,
+    pub changed_edges: Vec<String>,
+}
+
+impl SchemaDiff {
+    /// Compare `old` (absent for a newly added schema) with `new`.
+    pub fn between(old: Option<&GraphSchema>, new: &GraphSchema) -> Self {
+        let (old_nodes, old_edges) = old.map(snapshot).unwrap_or_default();
+        let (new_nodes, new_edges) = snapshot(new);
+        let (added_nodes, removed_nodes, changed_nodes) = compare(&old_nodes, &new_nodes);
+        let (added_edges, removed_edges, changed_edges) = compare(&old_edges, &new_edges);
+        SchemaDiff {
+            added_nodes,
+            removed_nodes,
+            changed_nodes,
+            added_edges,
+            removed_edges,
+            changed_edges,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == SchemaDiff::default()
+    }
+}
+
+type Snapshot = BTreeMap<String, Value>;
+
+/// Nodes by label and edges by type, serialized for comparison. A type
+/// mapped for several endpoint pairs compares all of its mappings at once.
+/// Array order is ignored: lists like `column_names` are filled from hash
+/// maps, so two builds of the same YAML can order them differently.
+fn snapshot(schema: &GraphSchema) -> (Snapshot, Snapshot) {
+    let nodes = schema
+        .all_node_schemas()
+        .iter()
+        .map(|(label, node)| (label.clone(), normalized(node)))
+        .collect();
+    let relationships = schema.get_relationships_schemas();
+    let edges = schema
+        .get_rel_type_index()
+        .iter()
+        .map(|(rel_type, keys)| {
+            let mappings: Vec<Value> = keys
+                .iter()
+                .filter_map(|key| relationships.get(key))
+                .map(normalized)
+                .collect();
+            (rel_type.clone(), sort_array(mappings))
+        })
+        .collect();
+    (nodes, edges)
+}
+
+fn normalized<T: Serialize>(element: &T) -> Value {
+    let mut value = serde_json::to_value(element).unwrap_or_default();
+    sort_arrays(&mut value);
+    value
+}
+
+fn sort_arrays(value: &mut Value) {
+    match value {
+        Value::Array(items) => {
+            items.iter_mut().for_each(sort_arrays);
+            items.sort_by_cached_key(Value::to_string);
+        }
+        Value::Object(fields) => fields.values_mut().for_each(sort_arrays),
+        _ => {}
+    }
+}
+
+fn sort_array(mut items: Vec<Value>) -> Value {
+    items.sort_by_cached_key(Value::to_string);
+    Value::Array(items)
+}
+
+/// `(added, removed, changed)` names.
+fn compare(old: &Snapshot, new: &Snapshot) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let added = new
+        .keys()
+        .filter(|name| !old.contains_key(*name))
+        .cloned()
+        .collect();
+    let removed = old
+        .keys()
+        .filter(|name| !new.contains_key(*name))
+        .cloned()
+        .collect();
+    let changed = new
+        .iter()
+        .filter(|(name, value)| old.get(*name).is_some_and(|old| old != *value))
+        .map(|(name, _)| name.clone())
+        .collect();
+    (added, removed, changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    fn schema(yaml: &str) -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(yaml)
+            .unwrap()
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    const BASE: &str = r#"
+name: diff_test
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: user_id
+      property_mappings:
+        name: full_name
+    - label: Post
+      database: test
+      table: posts
+      node_id: post_id
+      property_mappings: {}
+  edges:
+    - type: FOLLOWS
+      database: test
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings: {}
+"#;
+
+    #[test]
+    fn test_identical_schemas_have_no_diff() {
+        let diff = SchemaDiff::between(Some(&schema(BASE)), &schema(BASE));
+        assert!(diff.is_empty(), "{diff:?}");
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_changed() {
+        let changed = BASE
+            .replace("name: full_name", "name: display_name")
+            .replace(
+                "label: Post\n      database: test\n      table: posts\n      node_id: post_id",
+                "label: Tag\n      database: test\n      table: tags\n      node_id: tag_id",
+            )
+            .replace("type: FOLLOWS", "type: KNOWS");
+        let diff = SchemaDiff::between(Some(&schema(BASE)), &schema(&changed));
+        assert_eq!(diff.added_nodes, vec!["Tag"]);
+        assert_eq!(diff.removed_nodes, vec!["Post"]);
+        assert_eq!(diff.changed_nodes, vec!["User"]);
+        assert_eq!(diff.added_edges, vec!["KNOWS"]);
+        assert_eq!(diff.removed_edges, vec!["FOLLOWS"]);
+        assert!(diff.changed_edges.is_empty());
+
+        let new = SchemaDiff::between(None, &schema(BASE));
+        assert_eq!(new.added_nodes, vec!["Post", "User"]);
+        assert_eq!(new.added_edges, vec!["FOLLOWS"]);
+    }
+}
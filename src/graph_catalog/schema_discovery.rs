@@ -2,6 +2,16 @@
 //!
 //! This module provides functionality to discover table structures in ClickHouse databases
 //! and generate graph schema suggestions.
+//!
+//! Views, materialized views and dictionaries are introspected alongside plain
+//! tables, since graphs are often modelled over curated views. Each source is
+//! classified by [`classify_source`]: streaming engines (reading them consumes
+//! messages) and dictionary layouts that cannot be scanned are reported but
+//! flagged as unusable, and are never read for row counts or samples.
+//!
+//! Reference columns are also checked against the data by
+//! [`super::fk_inference`], which reports the confirmed ones as
+//! `inferred_edges` with their match percentages.
 
 use clickhouse::Client;
 use regex::Regex;
@@ -9,6 +19,8 @@ use serde::{Deserialize, Serialize};
 use std::sync::OnceLock;
 use tokio::io::AsyncBufReadExt;
 
+use super::fk_inference::{self, InferredEdge};
+
 fn validate_sql_identifier(identifier: &str) -> Result<String, String> {
     static RE: OnceLock<Regex> = OnceLock::new();
     let re = RE.get_or_init(|| Regex::new(r"^[a-zA-Z_][a-zA-Z0-9_.]*$").unwrap());
@@ -28,14 +40,159 @@ pub struct ColumnMetadata {
     pub is_in_order_by: bool,
 }
 
+/// What kind of ClickHouse object an introspected source is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceKind {
+    #[default]
+    Table,
+    View,
+    MaterializedView,
+    Dictionary,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TableMetadata {
     pub name: String,
+    #[serde(default)]
+    pub kind: SourceKind,
+    /// Whether the source can back a node or edge definition
+    #[serde(default = "default_graph_source")]
+    pub graph_source: bool,
+    /// Why the source is unusable, or what to know before using it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Parameters of a parameterized view, for the schema's `view_parameters`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub view_parameters: Vec<String>,
     pub columns: Vec<ColumnMetadata>,
     pub row_count: Option<u64>,
     pub sample: Vec<serde_json::Value>,
 }
 
+fn default_graph_source() -> bool {
+    true
+}
+
+/// How a `system.tables` entry can be used as a graph source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceClass {
+    pub kind: SourceKind,
+    pub graph_source: bool,
+    pub note: Option<String>,
+    pub view_parameters: Vec<String>,
+    /// Key columns a dictionary declares with `PRIMARY KEY`
+    pub key_columns: Vec<String>,
+}
+
+/// Classify a source from its `system.tables` `engine` and `create_table_query`.
+pub fn classify_source(engine: &str, create_table_query: &str) -> SourceClass {
+    let mut class = SourceClass {
+        kind: SourceKind::Table,
+        graph_source: true,
+        note: None,
+        view_parameters: Vec::new(),
+        key_columns: Vec::new(),
+    };
+    match engine {
+        "View" => {
+            class.kind = SourceKind::View;
+            class.view_parameters = view_parameters(create_table_query);
+            class.note = Some(if class.view_parameters.is_empty() {
+                "view: its SELECT runs on every graph query that reads it".to_string()
+            } else {
+                format!(
+                    "parameterized view: list {} under view_parameters and pass values per query",
+                    class.view_parameters.join(", ")
+                )
+            });
+        }
+        "MaterializedView" => {
+            class.kind = SourceKind::MaterializedView;
+            class.note =
+                Some("materialized view: reads the rows stored in its target table".to_string());
+        }
+        "Dictionary" => {
+            class.kind = SourceKind::Dictionary;
+            class.key_columns = dictionary_key(create_table_query);
+            if let Some(layout) = unscannable_layout(create_table_query) {
+                class.graph_source = false;
+                class.note = Some(format!(
+                    "dictionary with {layout} layout: a scan returns only keys already looked up"
+                ));
+            }
+        }
+        "Kafka" | "RabbitMQ" | "NATS" | "FileLog" | "RedisStreams" | "S3Queue" | "AzureQueue" => {
+            class.graph_source = false;
+            class.note = Some(format!(
+                "{engine} engine: reading consumes messages; model the table its materialized view writes to"
+            ));
+        }
+        "LiveView" | "WindowView" => {
+            class.kind = SourceKind::View;
+            class.graph_source = false;
+            class.note = Some(format!("{engine}: streaming view, not a stable row source"));
+        }
+        "Null" => {
+            class.graph_source = false;
+            class.note = Some("Null engine: always empty".to_string());
+        }
+        _ => {}
+    }
+    class
+}
+
+/// `{name:Type}` placeholders of a parameterized view, in order of appearance.
+fn view_parameters(create_table_query: &str) -> Vec<String> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| Regex::new(r"\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*:\s*[^}]+\}").unwrap());
+    let mut params: Vec<String> = Vec::new();
+    for cap in re.captures_iter(create_table_query) {
+        if !params.iter().any(|p| p == &cap[1]) {
+            params.push(cap[1].to_string());
+        }
+    }
+    params
+}
+
+/// Columns of a dictionary's `PRIMARY KEY` clause.
+fn dictionary_key(create_table_query: &str) -> Vec<String> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)\bPRIMARY KEY\s+(.+?)\s+(?:SOURCE|LAYOUT|LIFETIME|RANGE|SETTINGS|COMMENT)\b",
+        )
+        .unwrap()
+    });
+    re.captures(create_table_query)
+        .map(|cap| {
+            cap[1]
+                .split(',')
+                .map(|c| c.trim().trim_matches('`').to_string())
+                .filter(|c| !c.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The layout of a dictionary that only holds keys already looked up (or
+/// none at all), so `SELECT` over it does not return its full contents.
+fn unscannable_layout(create_table_query: &str) -> Option<String> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| Regex::new(r"(?i)\bLAYOUT\s*\(\s*([A-Za-z_]+)").unwrap());
+    let layout = re.captures(create_table_query)?[1].to_ascii_uppercase();
+    matches!(
+        layout.as_str(),
+        "CACHE"
+            | "SSD_CACHE"
+            | "DIRECT"
+            | "COMPLEX_KEY_CACHE"
+            | "COMPLEX_KEY_SSD_CACHE"
+            | "COMPLEX_KEY_DIRECT"
+    )
+    .then_some(layout)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Suggestion {
     pub table: String,
@@ -51,6 +208,10 @@ pub struct IntrospectResponse {
     pub next_step: String,
     #[serde(default)]
     pub suggestions: Vec<Suggestion>,
+    /// Foreign keys confirmed by sampling column values; see
+    /// [`super::fk_inference`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inferred_edges: Vec<InferredEdge>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,35 +261,79 @@ pub struct DraftOptions {
 
 pub struct SchemaDiscovery;
 
+#[derive(Debug, clickhouse::Row, Deserialize)]
+struct SourceRow {
+    name: String,
+    engine: String,
+    create_table_query: String,
+}
+
 impl SchemaDiscovery {
-    /// Introspect a database and return table metadata
+    /// Introspect a database and return metadata for its tables, views,
+    /// materialized views and dictionaries
     pub async fn introspect(client: &Client, database: &str) -> Result<IntrospectResponse, String> {
-        let tables = Self::list_tables(client, database).await?;
+        let sources = Self::list_tables(client, database).await?;
 
         let mut table_metadata = Vec::new();
         let mut suggestions = Vec::new();
 
-        for table_name in tables {
-            let columns = Self::get_columns(client, database, &table_name).await?;
-            let row_count = Self::get_row_count(client, database, &table_name)
-                .await
-                .ok();
-            let sample = Self::get_sample_data(client, database, &table_name)
-                .await
-                .unwrap_or_default();
+        for source in sources {
+            let table_name = source.name;
+            let class = classify_source(&source.engine, &source.create_table_query);
+            let mut columns = Self::get_columns(client, database, &table_name).await?;
+            for column in &mut columns {
+                if class.key_columns.contains(&column.name) {
+                    column.is_primary_key = true;
+                }
+            }
+
+            // Only read sources that are safe to read without arguments: a
+            // streaming engine would lose the messages a sample consumed
+            let readable = class.graph_source && class.view_parameters.is_empty();
+            let (row_count, sample) = if readable {
+                (
+                    Self::get_row_count(client, database, &table_name)
+                        .await
+                        .ok(),
+                    Self::get_sample_data(client, database, &table_name)
+                        .await
+                        .unwrap_or_default(),
+                )
+            } else {
+                (None, Vec::new())
+            };
 
             // Generate structural suggestions
-            let table_suggestions = Self::generate_suggestions(&table_name, &columns);
-            suggestions.extend(table_suggestions);
+            if class.graph_source {
+                suggestions.extend(Self::generate_suggestions(&table_name, &columns));
+            } else {
+                suggestions.push(Suggestion {
+                    table: table_name.clone(),
+                    suggestion_type: "unsupported_source".to_string(),
+                    reason: class.note.clone().unwrap_or_default(),
+                });
+            }
 
             table_metadata.push(TableMetadata {
                 name: table_name,
+                kind: class.kind,
+                graph_source: class.graph_source,
+                note: class.note,
+                view_parameters: class.view_parameters,
                 columns,
                 row_count,
                 sample,
             });
         }
 
+        // Confirm reference columns against the data, beyond their names
+        let inferred_edges = fk_inference::infer_fk_edges(client, database, &table_metadata).await;
+        suggestions.extend(inferred_edges.iter().map(|edge| Suggestion {
+            table: edge.from_table.clone(),
+            suggestion_type: "inferred_fk_edge".to_string(),
+            reason: edge.reason(),
+        }));
+
         let help = format!(
             "Review tables and columns above, then create your schema.\n\
 To generate YAML draft:\n\
@@ -145,30 +350,26 @@ curl -X POST http://localhost:7475/schemas/draft -H 'Content-Type: application/j
             tables: table_metadata,
             next_step: help,
             suggestions,
+            inferred_edges,
         })
     }
 
-    /// List all tables in a database
-    async fn list_tables(client: &Client, database: &str) -> Result<Vec<String>, String> {
+    /// List the tables, views, materialized views and dictionaries of a
+    /// database. Temporary tables and the `.inner` tables backing
+    /// materialized views are left out.
+    async fn list_tables(client: &Client, database: &str) -> Result<Vec<SourceRow>, String> {
         let db = validate_sql_identifier(database)?;
 
-        #[derive(Debug, clickhouse::Row, Deserialize)]
-        struct TableName {
-            name: String,
-        }
-
         let query = format!(
-            "SELECT name FROM system.tables WHERE database = '{}' AND engine NOT IN ('SystemTable', 'MaterializedView') ORDER BY name",
+            "SELECT name, engine, create_table_query FROM system.tables WHERE database = '{}' AND engine != 'SystemTable' AND NOT is_temporary AND NOT startsWith(name, '.inner') ORDER BY name",
             db
         );
 
-        let rows: Vec<TableName> = client
+        client
             .query(&query)
             .fetch_all()
             .await
-            .map_err(|e| format!("Failed to list tables: {}", e))?;
-
-        Ok(rows.into_iter().map(|t| t.name).collect())
+            .map_err(|e| format!("Failed to list tables: {}", e))
     }
 
     /// Get columns for a table
@@ -514,6 +715,74 @@ pub fn extract_yaml_list_items(yaml: &str, section: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_classify_views() {
+        let plain = classify_source("View", "CREATE VIEW db.active AS SELECT * FROM db.users");
+        assert_eq!(plain.kind, SourceKind::View);
+        assert!(plain.graph_source);
+        assert!(plain.view_parameters.is_empty());
+
+        let param = classify_source(
+            "View",
+            "CREATE VIEW db.tenant_users AS SELECT * FROM db.users \
+             WHERE tenant_id = {tenant_id:String} AND region IN {region: Array(String)} \
+             AND tenant_id != {tenant_id:String}",
+        );
+        assert_eq!(param.view_parameters, vec!["tenant_id", "region"]);
+        assert!(param.note.unwrap().contains("view_parameters"));
+
+        let mv = classify_source(
+            "MaterializedView",
+            "CREATE MATERIALIZED VIEW db.mv TO db.t AS SELECT 1",
+        );
+        assert_eq!(mv.kind, SourceKind::MaterializedView);
+        assert!(mv.graph_source);
+    }
+
+    #[test]
+    fn test_classify_dictionaries() {
+        let hashed = classify_source(
+            "Dictionary",
+            "CREATE DICTIONARY db.countries (`code` String, `name` String) PRIMARY KEY code \
+             SOURCE(CLICKHOUSE(TABLE 'countries')) LIFETIME(MIN 0 MAX 300) LAYOUT(COMPLEX_KEY_HASHED())",
+        );
+        assert_eq!(hashed.kind, SourceKind::Dictionary);
+        assert!(hashed.graph_source);
+        assert_eq!(hashed.key_columns, vec!["code"]);
+
+        let cached = classify_source(
+            "Dictionary",
+            "CREATE DICTIONARY db.users_dict (`id` UInt64, `name` String) PRIMARY KEY id \
+             SOURCE(CLICKHOUSE(TABLE 'users')) LIFETIME(300) LAYOUT(CACHE(SIZE_IN_CELLS 1000))",
+        );
+        assert!(!cached.graph_source);
+        assert!(cached.note.unwrap().contains("CACHE"));
+    }
+
+    #[test]
+    fn test_classify_streaming_engines() {
+        let kafka = classify_source("Kafka", "CREATE TABLE db.q (`id` UInt64) ENGINE = Kafka");
+        assert_eq!(kafka.kind, SourceKind::Table);
+        assert!(!kafka.graph_source);
+
+        let table = classify_source(
+            "MergeTree",
+            "CREATE TABLE db.t (`id` UInt64) ENGINE = MergeTree ORDER BY id",
+        );
+        assert!(table.graph_source);
+        assert!(table.note.is_none());
+    }
+
+    #[test]
+    fn test_table_metadata_defaults_when_deserialized() {
+        let table: TableMetadata = serde_json::from_value(serde_json::json!({
+            "name": "users", "columns": [], "row_count": null, "sample": []
+        }))
+        .unwrap();
+        assert_eq!(table.kind, SourceKind::Table);
+        assert!(table.graph_source);
+    }
+
     #[test]
     fn test_generate_draft_simple() {
         let request = DraftRequest {
@@ -0,0 +1,800 @@
+//! Schema linting.
+//!
+//! Checks a graph schema for problems that load fine but make it confusing
+//! or slow to query:
+//!
+//! - **naming**: labels that are not `PascalCase`, relationship types that
+//!   are not `UPPER_SNAKE_CASE`, and labels or types that differ only in case
+//! - **mappings**: labels without property mappings
+//! - **tables**: mapped tables or columns that ClickHouse doesn't have
+//! - **join_keys**: node ids and relationship endpoint ids missing from their
+//!   table's sort key, so every join on them scans the table
+//! - **unused_columns**: table columns no label or type maps
+//!
+//! A graph sharded over a `cluster` is also checked for tables that are not
+//! Distributed tables over that cluster, or that distribute another local
+//! table than the schema maps (reported under **tables**).
+//!
+//! The last three need the tables' live metadata ([`TableMetadata`], read
+//! with [`metadata_queries`]); without it only the first two run. Findings
+//! carry a [`Severity`] so CI can fail on, say, warnings and above.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use super::expression_parser::PropertyValue;
+use super::graph_schema::{GraphSchema, NodeSchema, RelationshipSchema};
+use super::table_stats::{schema_databases, validate_database_identifier};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" => Ok(Severity::Info),
+            "warning" => Ok(Severity::Warning),
+            "error" => Ok(Severity::Error),
+            _ => Err(format!(
+                "unknown severity '{}' (expected info, warning or error)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintCategory {
+    Naming,
+    Mappings,
+    Tables,
+    JoinKeys,
+    UnusedColumns,
+}
+
+/// One problem found in the schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintWarning {
+    pub category: LintCategory,
+    pub severity: Severity,
+    /// `node:Label`, `relationship:TYPE` or `table:database.table`
+    pub element: String,
+    pub message: String,
+}
+
+/// What ClickHouse reports about one table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableMetadata {
+    pub columns: BTreeSet<String>,
+    /// Sort key expressions, in order; empty for engines without one. A
+    /// Distributed table has its local table's.
+    pub sorting_key: Vec<String>,
+    /// Table engine (`MergeTree`, `Distributed`, ...)
+    pub engine: String,
+    /// Engine with its arguments, as in `system.tables.engine_full`
+    pub engine_full: String,
+}
+
+impl TableMetadata {
+    /// Cluster, database and table of a Distributed table's local table.
+    fn distributed_over(&self) -> Option<(String, String, String)> {
+        if self.engine != "Distributed" {
+            return None;
+        }
+        let args = self.engine_full.split_once('(')?.1;
+        let mut args = args
+            .split([',', ')'])
+            .map(|a| a.trim().trim_matches(|c| c == '\'' || c == '`').to_string());
+        Some((args.next()?, args.next()?, args.next()?))
+    }
+}
+
+/// Lint `schema`, with the live checks when `tables` (keyed by
+/// `database.table`) is given. Findings are ordered by severity, most
+/// severe first.
+pub fn lint(
+    schema: &GraphSchema,
+    tables: Option<&HashMap<String, TableMetadata>>,
+) -> Vec<LintWarning> {
+    let mut warnings = Vec::new();
+    lint_naming(schema, &mut warnings);
+    lint_mappings(schema, &mut warnings);
+    if let Some(tables) = tables {
+        lint_tables(schema, tables, &mut warnings);
+        lint_cluster(schema, tables, &mut warnings);
+    }
+    warnings.sort_by_key(|w| std::cmp::Reverse(w.severity));
+    warnings
+}
+
+/// The queries reading the metadata of the schema's databases: the tables
+/// with their sort keys (`database`, `name`, `sorting_key`), then the
+/// columns (`database`, `table`, `name`).
+pub fn metadata_queries(schema: &GraphSchema) -> Result<(String, String), String> {
+    let mut quoted = Vec::new();
+    for db in schema_databases(schema) {
+        quoted.push(format!("'{}'", validate_database_identifier(&db)?));
+    }
+    if quoted.is_empty() {
+        quoted.push("''".to_string());
+    }
+    let databases = quoted.join(", ");
+    Ok((
+        format!(
+            "SELECT database, name, sorting_key, engine, engine_full FROM system.tables WHERE database IN ({})",
+            databases
+        ),
+        format!(
+            "SELECT database, table, name FROM system.columns WHERE database IN ({})",
+            databases
+        ),
+    ))
+}
+
+/// Table metadata from the rows of the two [`metadata_queries`].
+pub fn parse_metadata(
+    table_rows: &[serde_json::Value],
+    column_rows: &[serde_json::Value],
+) -> HashMap<String, TableMetadata> {
+    let field = |row: &serde_json::Value, name: &str| {
+        row.get(name)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    let mut tables: HashMap<String, TableMetadata> = HashMap::new();
+    for row in table_rows {
+        let key = format!("{}.{}", field(row, "database"), field(row, "name"));
+        let table = tables.entry(key).or_default();
+        table.sorting_key = split_sorting_key(&field(row, "sorting_key"));
+        table.engine = field(row, "engine");
+        table.engine_full = field(row, "engine_full");
+    }
+    // Joins through a Distributed table use its local table's sort key
+    let local_keys: Vec<(String, Vec<String>)> = tables
+        .iter()
+        .filter_map(|(key, table)| {
+            let (_, database, local) = table.distributed_over()?;
+            let local = tables.get(&format!("{}.{}", database, local))?;
+            Some((key.clone(), local.sorting_key.clone()))
+        })
+        .collect();
+    for (key, sorting_key) in local_keys {
+        if let Some(table) = tables.get_mut(&key) {
+            table.sorting_key = sorting_key;
+        }
+    }
+    for row in column_rows {
+        let key = format!("{}.{}", field(row, "database"), field(row, "table"));
+        if let Some(table) = tables.get_mut(&key) {
+            table.columns.insert(field(row, "name"));
+        }
+    }
+    tables
+}
+
+/// `a, toDate(b), c` → `["a", "toDate(b)", "c"]`, backticks stripped.
+fn split_sorting_key(key: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in key.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    parts.push(current);
+    parts
+        .into_iter()
+        .map(|p| p.trim().trim_matches('`').to_string())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+/// Base labels with their node schemas.
+fn labels(schema: &GraphSchema) -> BTreeMap<&str, &NodeSchema> {
+    schema
+        .all_node_schemas()
+        .iter()
+        .map(|(key, node)| (key.rsplit("::").next().unwrap_or(key), node))
+        .collect()
+}
+
+/// Relationship types with their schemas, one entry per endpoint pair.
+fn relationships(schema: &GraphSchema) -> Vec<(&str, &RelationshipSchema)> {
+    schema
+        .get_relationships_schemas()
+        .iter()
+        .filter_map(|(key, rel)| {
+            let rel_type = key.split("::").next().unwrap_or(key);
+            (!rel_type.is_empty()).then_some((rel_type, rel))
+        })
+        .collect()
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_upper_snake_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn lint_naming(schema: &GraphSchema, warnings: &mut Vec<LintWarning>) {
+    let labels: Vec<&str> = labels(schema).into_keys().collect();
+    let mut types: Vec<&str> = relationships(schema).into_iter().map(|(t, _)| t).collect();
+    types.dedup();
+
+    for label in &labels {
+        if !is_pascal_case(label) {
+            warnings.push(LintWarning {
+                category: LintCategory::Naming,
+                severity: Severity::Info,
+                element: format!("node:{}", label),
+                message: format!("label '{}' is not PascalCase", label),
+            });
+        }
+    }
+    for rel_type in &types {
+        if !is_upper_snake_case(rel_type) {
+            warnings.push(LintWarning {
+                category: LintCategory::Naming,
+                severity: Severity::Info,
+                element: format!("relationship:{}", rel_type),
+                message: format!("relationship type '{}' is not UPPER_SNAKE_CASE", rel_type),
+            });
+        }
+    }
+    for (kind, names) in [("node", &labels), ("relationship", &types)] {
+        let mut by_folded: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+        for name in names.iter() {
+            by_folded.entry(name.to_lowercase()).or_default().push(name);
+        }
+        for spellings in by_folded.values().filter(|s| s.len() > 1) {
+            warnings.push(LintWarning {
+                category: LintCategory::Naming,
+                severity: Severity::Warning,
+                element: format!("{}:{}", kind, spellings[0]),
+                message: format!(
+                    "{} names differ only in case: {}; Cypher matches them separately",
+                    kind,
+                    spellings.join(", ")
+                ),
+            });
+        }
+    }
+}
+
+fn lint_mappings(schema: &GraphSchema, warnings: &mut Vec<LintWarning>) {
+    for (label, node) in labels(schema) {
+        let id_properties = node.node_id.columns();
+        let mapped = node
+            .property_mappings
+            .keys()
+            .chain(node.from_properties.iter().flat_map(|p| p.keys()))
+            .chain(node.to_properties.iter().flat_map(|p| p.keys()))
+            .any(|property| !id_properties.contains(&property.as_str()));
+        if !mapped {
+            warnings.push(LintWarning {
+                category: LintCategory::Mappings,
+                severity: Severity::Warning,
+                element: format!("node:{}", label),
+                message: format!(
+                    "label '{}' maps no properties besides its id; add property_mappings",
+                    label
+                ),
+            });
+        }
+    }
+}
+
+/// Columns read by a mapped value: the value itself when it names a column,
+/// else the columns of the expression.
+fn value_columns(value: &str) -> Vec<String> {
+    if value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+    {
+        vec![value.to_string()]
+    } else {
+        PropertyValue::Expression(value.to_string()).get_columns()
+    }
+}
+
+fn node_columns(node: &NodeSchema) -> Vec<String> {
+    let mut columns = node.id_physical_columns();
+    for value in node.property_mappings.values() {
+        columns.extend(value.get_columns());
+    }
+    for props in [&node.from_properties, &node.to_properties]
+        .into_iter()
+        .flatten()
+    {
+        columns.extend(props.values().flat_map(|v| value_columns(v)));
+    }
+    columns.extend(node.label_discriminator_column().map(str::to_string));
+    columns.extend(node.filter.iter().flat_map(|f| f.get_columns()));
+    columns
+}
+
+fn relationship_columns(rel: &RelationshipSchema) -> Vec<String> {
+    let mut columns: Vec<String> = rel
+        .from_id
+        .columns()
+        .into_iter()
+        .chain(rel.to_id.columns())
+        .chain(rel.edge_id.iter().flat_map(|id| id.columns()))
+        .map(str::to_string)
+        .collect();
+    for value in rel.property_mappings.values() {
+        columns.extend(value.get_columns());
+    }
+    for props in [&rel.from_node_properties, &rel.to_node_properties]
+        .into_iter()
+        .flatten()
+    {
+        columns.extend(props.values().flat_map(|v| value_columns(v)));
+    }
+    for column in [
+        &rel.type_column,
+        &rel.from_label_column,
+        &rel.to_label_column,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        columns.push(column.clone());
+    }
+    columns.extend(rel.filter.iter().flat_map(|f| f.get_columns()));
+    columns
+}
+
+/// Whether every one of `key_columns` is in `table`'s sort key.
+fn in_sorting_key(table: &TableMetadata, key_columns: &[&str]) -> bool {
+    key_columns
+        .iter()
+        .all(|c| table.sorting_key.iter().any(|k| k == c))
+}
+
+/// Check that `element`'s table and columns exist, and record the columns
+/// as used. Returns the table when it exists.
+fn check_element<'a>(
+    tables: &'a HashMap<String, TableMetadata>,
+    element: &str,
+    table_name: String,
+    columns: Vec<String>,
+    used: &mut BTreeMap<String, BTreeSet<String>>,
+    warnings: &mut Vec<LintWarning>,
+) -> Option<&'a TableMetadata> {
+    let Some(table) = tables.get(&table_name) else {
+        warnings.push(LintWarning {
+            category: LintCategory::Tables,
+            severity: Severity::Error,
+            element: element.to_string(),
+            message: format!("table {} does not exist", table_name),
+        });
+        return None;
+    };
+    let missing: BTreeSet<&String> = columns
+        .iter()
+        .filter(|c| !table.columns.contains(*c))
+        .collect();
+    if !missing.is_empty() {
+        warnings.push(LintWarning {
+            category: LintCategory::Tables,
+            severity: Severity::Error,
+            element: element.to_string(),
+            message: format!(
+                "columns missing from {}: {}",
+                table_name,
+                missing.into_iter().cloned().collect::<Vec<_>>().join(", ")
+            ),
+        });
+    }
+    used.entry(table_name).or_default().extend(columns);
+    Some(table)
+}
+
+fn lint_tables(
+    schema: &GraphSchema,
+    tables: &HashMap<String, TableMetadata>,
+    warnings: &mut Vec<LintWarning>,
+) {
+    // Columns read per table, over every element stored in it
+    let mut used: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+
+    for (label, node) in labels(schema) {
+        let element = format!("node:{}", label);
+        let Some(table) = check_element(
+            tables,
+            &element,
+            node.full_table_name(),
+            node_columns(node),
+            &mut used,
+            warnings,
+        ) else {
+            continue;
+        };
+        let id_columns = node.id_physical_columns();
+        let id_columns: Vec<&str> = id_columns.iter().map(String::as_str).collect();
+        if node.has_standalone_table()
+            && !table.sorting_key.is_empty()
+            && !in_sorting_key(table, &id_columns)
+        {
+            warnings.push(LintWarning {
+                category: LintCategory::JoinKeys,
+                severity: Severity::Warning,
+                element,
+                message: format!(
+                    "id ({}) is not in the sort key ({}) of {}; joins on it scan the table",
+                    id_columns.join(", "),
+                    table.sorting_key.join(", "),
+                    node.full_table_name()
+                ),
+            });
+        }
+    }
+
+    for (rel_type, rel) in relationships(schema) {
+        let element = format!("relationship:{}", rel_type);
+        let Some(table) = check_element(
+            tables,
+            &element,
+            rel.full_table_name(),
+            relationship_columns(rel),
+            &mut used,
+            warnings,
+        ) else {
+            continue;
+        };
+        // An FK edge is joined through its node table's id
+        if rel.is_fk_edge || table.sorting_key.is_empty() {
+            continue;
+        }
+        let unsorted: Vec<String> = [("from", &rel.from_id), ("to", &rel.to_id)]
+            .into_iter()
+            .filter(|(_, id)| !in_sorting_key(table, &id.columns()))
+            .map(|(side, id)| format!("{}_id ({})", side, id.columns().join(", ")))
+            .collect();
+        if unsorted.is_empty() {
+            continue;
+        }
+        warnings.push(LintWarning {
+            category: LintCategory::JoinKeys,
+            // One sorted side still serves traversals from that end
+            severity: if unsorted.len() == 2 {
+                Severity::Warning
+            } else {
+                Severity::Info
+            },
+            element,
+            message: format!(
+                "{} not in the sort key ({}) of {} ({} -> {}); joins on it scan the table",
+                unsorted.join(" and "),
+                table.sorting_key.join(", "),
+                rel.full_table_name(),
+                rel.from_node,
+                rel.to_node
+            ),
+        });
+    }
+
+    for (table_name, used_columns) in used {
+        let Some(table) = tables.get(&table_name) else {
+            continue;
+        };
+        let unused: Vec<&str> = table
+            .columns
+            .iter()
+            .filter(|c| !used_columns.contains(*c))
+            .map(String::as_str)
+            .collect();
+        if !unused.is_empty() {
+            warnings.push(LintWarning {
+                category: LintCategory::UnusedColumns,
+                severity: Severity::Info,
+                element: format!("table:{}", table_name),
+                message: format!(
+                    "columns not mapped by any label or type: {}",
+                    unused.join(", ")
+                ),
+            });
+        }
+    }
+}
+
+/// On a cluster graph, every mapped table must be a Distributed table over
+/// that cluster, over the local table the schema maps: any other table
+/// answers from the shard the query happens to run on.
+fn lint_cluster(
+    schema: &GraphSchema,
+    tables: &HashMap<String, TableMetadata>,
+    warnings: &mut Vec<LintWarning>,
+) {
+    let Some(cluster) = schema.cluster() else {
+        return;
+    };
+    let mapped: BTreeSet<String> = labels(schema)
+        .values()
+        .map(|node| node.full_table_name())
+        .chain(
+            relationships(schema)
+                .iter()
+                .map(|(_, rel)| rel.full_table_name()),
+        )
+        .collect();
+    for table_name in mapped {
+        let Some(table) = tables.get(&table_name) else {
+            continue;
+        };
+        let problem = match table.distributed_over() {
+            None => Some(format!(
+                "{} is a {} table, not a Distributed table over cluster '{}'; \
+                 queries would read only the shard they run on",
+                table_name, table.engine, cluster.name
+            )),
+            Some((over, _, _)) if over != cluster.name => Some(format!(
+                "{} is distributed over cluster '{}', not '{}'",
+                table_name, over, cluster.name
+            )),
+            Some((_, database, local)) => {
+                let local = format!("{}.{}", database, local);
+                cluster
+                    .local_tables
+                    .get(&table_name)
+                    .filter(|expected| **expected != local)
+                    .map(|expected| {
+                        format!(
+                            "{} distributes {}, but the schema maps local table {}",
+                            table_name, local, expected
+                        )
+                    })
+            }
+        };
+        if let Some(message) = problem {
+            warnings.push(LintWarning {
+                category: LintCategory::Tables,
+                severity: Severity::Error,
+                element: format!("table:{}", table_name),
+                message,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    const SCHEMA_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: app
+      table: users
+      node_id: user_id
+      property_mappings:
+        name: full_name
+    - label: post
+      database: app
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+  edges:
+    - type: FOLLOWS
+      database: app
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+    - type: Follows
+      database: app
+      table: follows_v2
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+"#;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    fn table(columns: &[&str], sorting_key: &str) -> TableMetadata {
+        TableMetadata {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            sorting_key: split_sorting_key(sorting_key),
+            ..Default::default()
+        }
+    }
+
+    fn find<'a>(
+        warnings: &'a [LintWarning],
+        element: &str,
+        category: LintCategory,
+    ) -> Vec<&'a LintWarning> {
+        warnings
+            .iter()
+            .filter(|w| w.element == element && w.category == category)
+            .collect()
+    }
+
+    #[test]
+    fn test_static_checks() {
+        let warnings = lint(&schema(), None);
+        assert_eq!(find(&warnings, "node:post", LintCategory::Naming).len(), 1);
+        assert_eq!(
+            find(&warnings, "relationship:Follows", LintCategory::Naming)[0].severity,
+            Severity::Info
+        );
+        let collision = find(&warnings, "relationship:FOLLOWS", LintCategory::Naming);
+        assert_eq!(collision[0].severity, Severity::Warning);
+        assert!(collision[0].message.contains("FOLLOWS, Follows"));
+        assert_eq!(
+            find(&warnings, "node:post", LintCategory::Mappings).len(),
+            1
+        );
+        assert!(find(&warnings, "node:User", LintCategory::Mappings).is_empty());
+        assert!(warnings
+            .iter()
+            .all(|w| w.category != LintCategory::JoinKeys));
+        // Most severe first
+        assert_eq!(warnings[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_live_checks() {
+        let tables = HashMap::from([
+            (
+                "app.users".to_string(),
+                table(&["user_id", "full_name", "email"], "user_id"),
+            ),
+            (
+                "app.posts".to_string(),
+                table(&["post_id"], "toDate(created), post_id"),
+            ),
+            (
+                "app.follows".to_string(),
+                table(&["follower_id", "followed_id"], "follower_id, followed_id"),
+            ),
+            (
+                "app.follows_v2".to_string(),
+                table(&["follower_id", "created"], "created"),
+            ),
+        ]);
+        let warnings = lint(&schema(), Some(&tables));
+
+        assert!(find(&warnings, "node:User", LintCategory::JoinKeys).is_empty());
+        assert!(find(&warnings, "node:post", LintCategory::JoinKeys).is_empty());
+        assert!(find(&warnings, "relationship:FOLLOWS", LintCategory::JoinKeys).is_empty());
+
+        let missing = find(&warnings, "relationship:Follows", LintCategory::Tables);
+        assert_eq!(missing[0].severity, Severity::Error);
+        assert!(missing[0].message.contains("followed_id"));
+        let unsorted = find(&warnings, "relationship:Follows", LintCategory::JoinKeys);
+        assert_eq!(unsorted[0].severity, Severity::Warning);
+        assert!(unsorted[0]
+            .message
+            .contains("from_id (follower_id) and to_id (followed_id)"));
+
+        let unused = find(&warnings, "table:app.users", LintCategory::UnusedColumns);
+        assert!(
+            unused[0].message.ends_with(": email"),
+            "{}",
+            unused[0].message
+        );
+        assert_eq!(warnings[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_missing_table_and_metadata_rows() {
+        let warnings = lint(&schema(), Some(&HashMap::new()));
+        let missing = find(&warnings, "node:User", LintCategory::Tables);
+        assert_eq!(missing[0].message, "table app.users does not exist");
+
+        let tables = parse_metadata(
+            &[
+                serde_json::json!({"database": "app", "name": "users", "sorting_key": "`user_id`, intHash32(user_id)"}),
+            ],
+            &[
+                serde_json::json!({"database": "app", "table": "users", "name": "user_id"}),
+                serde_json::json!({"database": "app", "table": "other", "name": "x"}),
+            ],
+        );
+        assert_eq!(
+            tables["app.users"],
+            table(&["user_id"], "user_id, intHash32(user_id)")
+        );
+        assert_eq!(tables.len(), 1);
+
+        let (tables_sql, columns_sql) = metadata_queries(&schema()).unwrap();
+        assert!(tables_sql.ends_with("WHERE database IN ('app')"));
+        assert!(columns_sql.starts_with("SELECT database, table, name FROM system.columns"));
+    }
+    #[test]
+    fn test_cluster_checks() {
+        let yaml = SCHEMA_YAML.replace(
+            "graph_schema:\n",
+            "graph_schema:\n  cluster:\n    name: main\n    distributed_suffix: _all\n",
+        );
+        let schema = GraphSchemaConfig::from_yaml_str(&yaml)
+            .unwrap()
+            .to_graph_schema()
+            .unwrap();
+        let distributed = |engine_full: &str| TableMetadata {
+            engine: "Distributed".to_string(),
+            engine_full: engine_full.to_string(),
+            ..table(&[], "")
+        };
+        let tables = HashMap::from([
+            (
+                "app.users_all".to_string(),
+                distributed("Distributed('main', 'app', 'users', rand())"),
+            ),
+            (
+                "app.posts_all".to_string(),
+                distributed("Distributed('other', 'app', 'posts')"),
+            ),
+            (
+                "app.follows_all".to_string(),
+                distributed("Distributed(main, app, follows_v2, rand())"),
+            ),
+            (
+                "app.follows_v2_all".to_string(),
+                TableMetadata {
+                    engine: "MergeTree".to_string(),
+                    ..table(&[], "")
+                },
+            ),
+        ]);
+        let warnings = lint(&schema, Some(&tables));
+
+        assert!(find(&warnings, "table:app.users_all", LintCategory::Tables).is_empty());
+        let other = find(&warnings, "table:app.posts_all", LintCategory::Tables);
+        assert!(other[0].message.contains("cluster 'other', not 'main'"));
+        let wrong = find(&warnings, "table:app.follows_all", LintCategory::Tables);
+        assert!(wrong[0]
+            .message
+            .contains("distributes app.follows_v2, but the schema maps local table app.follows"));
+        let local = find(&warnings, "table:app.follows_v2_all", LintCategory::Tables);
+        assert_eq!(local[0].severity, Severity::Error);
+        assert!(local[0].message.contains("MergeTree table"));
+
+        // A Distributed table joins on its local table's sort key
+        let tables = parse_metadata(
+            &[
+                serde_json::json!({"database": "app", "name": "users", "sorting_key": "user_id", "engine": "MergeTree", "engine_full": "MergeTree ORDER BY user_id"}),
+                serde_json::json!({"database": "app", "name": "users_all", "sorting_key": "", "engine": "Distributed", "engine_full": "Distributed('main', 'app', 'users', rand())"}),
+            ],
+            &[],
+        );
+        assert_eq!(tables["app.users_all"].sorting_key, vec!["user_id"]);
+    }
+}
@@ -0,0 +1,420 @@
+//! Built-in schema templates for common raw-log layouts.
+//!
+//! A template is a ready-to-load graph mapping for tables whose shape is
+//! fixed by the tool that writes them — Zeek logs, NetFlow/IPFIX collectors,
+//! the OpenTelemetry ClickHouse exporter — so exploring such data needs
+//! neither introspection nor an LLM-drafted schema. Rendering substitutes
+//! the database and schema name into the YAML; templates that need a
+//! flattening or aggregating view (e.g. one row per DNS answer) also return
+//! the `CREATE VIEW IF NOT EXISTS` statements to run before loading.
+//!
+//! Served by `GET /schemas/templates` and `GET /schemas/templates/{name}`.
+
+use serde::Serialize;
+
+/// One built-in template.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct SchemaTemplate {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Database used when the caller does not name one.
+    pub default_database: &'static str,
+    /// Source tables the mapping expects, without the database.
+    pub tables: &'static [&'static str],
+    #[serde(skip)]
+    yaml: &'static str,
+    #[serde(skip)]
+    setup_sql: &'static [&'static str],
+}
+
+/// A template rendered for one database.
+#[derive(Debug, Clone, Serialize)]
+pub struct RenderedTemplate {
+    pub template: &'static str,
+    pub schema_name: String,
+    pub database: String,
+    /// Views the mapping reads, to create before loading the YAML.
+    pub setup_sql: Vec<String>,
+    pub yaml: String,
+}
+
+/// All built-in templates, in listing order.
+pub fn templates() -> &'static [SchemaTemplate] {
+    TEMPLATES
+}
+
+/// The template called `name`.
+pub fn find(name: &str) -> Option<&'static SchemaTemplate> {
+    TEMPLATES.iter().find(|t| t.name == name)
+}
+
+impl SchemaTemplate {
+    /// Render for `database` (default: [`Self::default_database`]) under
+    /// `schema_name` (default: the template name with `-` as `_`). Both must
+    /// be plain identifiers since they are pasted into YAML and SQL.
+    pub fn render(
+        &self,
+        database: Option<&str>,
+        schema_name: Option<&str>,
+    ) -> Result<RenderedTemplate, String> {
+        let database = database.unwrap_or(self.default_database);
+        let default_name = self.name.replace('-', "_");
+        let schema_name = schema_name.unwrap_or(&default_name);
+        for (what, value) in [("database", database), ("schema name", schema_name)] {
+            if !is_identifier(value) {
+                return Err(format!(
+                    "{what} {value:?} must contain only ASCII letters, digits and underscores"
+                ));
+            }
+        }
+        let fill = |text: &str| {
+            text.replace("{database}", database)
+                .replace("{schema_name}", schema_name)
+        };
+        Ok(RenderedTemplate {
+            template: self.name,
+            schema_name: schema_name.to_string(),
+            database: database.to_string(),
+            setup_sql: self.setup_sql.iter().map(|sql| fill(sql)).collect(),
+            yaml: fill(self.yaml),
+        })
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+const TEMPLATES: &[SchemaTemplate] = &[
+    SchemaTemplate {
+        name: "zeek",
+        description: "Zeek conn/dns/http logs as IP, Domain and Host nodes with ACCESSED, QUERIED and RESOLVED_TO edges",
+        default_database: "zeek",
+        tables: &["conn_log", "dns_log", "http_log"],
+        yaml: ZEEK_YAML,
+        setup_sql: &[ZEEK_DNS_ANSWERS_VIEW],
+    },
+    SchemaTemplate {
+        name: "netflow",
+        description: "NetFlow/IPFIX flow records as IP nodes with ACCESSED edges",
+        default_database: "netflow",
+        tables: &["flows"],
+        yaml: NETFLOW_YAML,
+        setup_sql: &[],
+    },
+    SchemaTemplate {
+        name: "otel-traces",
+        description: "OpenTelemetry spans (ClickHouse exporter) as Span and Service nodes with CHILD_OF, IN_SERVICE and CALLS edges",
+        default_database: "otel",
+        tables: &["otel_traces"],
+        yaml: OTEL_TRACES_YAML,
+        setup_sql: &[OTEL_SERVICES_VIEW, OTEL_SERVICE_CALLS_VIEW],
+    },
+];
+
+// Zeek: IP and Domain live on the log rows themselves (denormalized nodes),
+// so every log table carries the node definitions it can serve. DNS answers
+// are an array; the view turns them into one row per resolved address.
+const ZEEK_DNS_ANSWERS_VIEW: &str = "CREATE VIEW IF NOT EXISTS {database}.dns_answers AS \
+SELECT uid, ts, query, answer, qtype_name, rcode_name \
+FROM {database}.dns_log ARRAY JOIN answers AS answer \
+WHERE isIPv4String(answer) OR isIPv6String(answer)";
+
+const ZEEK_YAML: &str = r#"# Zeek conn/dns/http logs (template "zeek")
+#   (:IP)-[:ACCESSED]->(:IP)         conn_log
+#   (:IP)-[:ACCESSED]->(:Host)       http_log
+#   (:IP)-[:QUERIED]->(:Domain)      dns_log
+#   (:Domain)-[:RESOLVED_TO]->(:IP)  dns_answers view (one row per answer)
+name: {schema_name}
+version: "1.0"
+
+graph_schema:
+  nodes:
+    - label: IP
+      database: {database}
+      table: conn_log
+      node_id: ip
+      property_mappings: {}
+      from_node_properties:
+        ip: "id.orig_h"
+        port: "id.orig_p"
+      to_node_properties:
+        ip: "id.resp_h"
+        port: "id.resp_p"
+
+    - label: IP
+      database: {database}
+      table: http_log
+      node_id: ip
+      property_mappings: {}
+      from_node_properties:
+        ip: "id.orig_h"
+
+    - label: IP
+      database: {database}
+      table: dns_log
+      node_id: ip
+      property_mappings: {}
+      from_node_properties:
+        ip: "id.orig_h"
+
+    - label: IP
+      database: {database}
+      table: dns_answers
+      node_id: ip
+      property_mappings: {}
+      to_node_properties:
+        ip: answer
+
+    - label: Domain
+      database: {database}
+      table: dns_log
+      node_id: name
+      property_mappings: {}
+      to_node_properties:
+        name: query
+
+    - label: Domain
+      database: {database}
+      table: dns_answers
+      node_id: name
+      property_mappings: {}
+      from_node_properties:
+        name: query
+
+    - label: Host
+      database: {database}
+      table: http_log
+      node_id: name
+      property_mappings: {}
+      to_node_properties:
+        name: host
+
+  edges:
+    - type: ACCESSED
+      database: {database}
+      table: conn_log
+      from_id: "id.orig_h"
+      to_id: "id.resp_h"
+      from_node: IP
+      to_node: IP
+      edge_id: uid
+      property_mappings:
+        uid: uid
+        timestamp: ts
+        protocol: proto
+        service: service
+        duration: duration
+        orig_bytes: orig_bytes
+        resp_bytes: resp_bytes
+        conn_state: conn_state
+
+    - type: ACCESSED
+      database: {database}
+      table: http_log
+      from_id: "id.orig_h"
+      to_id: host
+      from_node: IP
+      to_node: Host
+      edge_id: uid
+      property_mappings:
+        uid: uid
+        timestamp: ts
+        method: method
+        uri: uri
+        status_code: status_code
+        user_agent: user_agent
+
+    - type: QUERIED
+      database: {database}
+      table: dns_log
+      from_id: "id.orig_h"
+      to_id: query
+      from_node: IP
+      to_node: Domain
+      edge_id: uid
+      property_mappings:
+        uid: uid
+        timestamp: ts
+        dns_server: "id.resp_h"
+        qtype: qtype_name
+        rcode: rcode_name
+
+    - type: RESOLVED_TO
+      database: {database}
+      table: dns_answers
+      from_id: query
+      to_id: answer
+      from_node: Domain
+      to_node: IP
+      property_mappings:
+        uid: uid
+        timestamp: ts
+        qtype: qtype_name
+"#;
+
+// NetFlow: column names of the common collector layout (goflow2 and
+// similar ClickHouse sinks); rename in the YAML for other collectors.
+const NETFLOW_YAML: &str = r#"# NetFlow/IPFIX flow records (template "netflow")
+#   (:IP)-[:ACCESSED]->(:IP)  flows
+name: {schema_name}
+version: "1.0"
+
+graph_schema:
+  nodes:
+    - label: IP
+      database: {database}
+      table: flows
+      node_id: ip
+      property_mappings: {}
+      from_node_properties:
+        ip: src_addr
+        port: src_port
+      to_node_properties:
+        ip: dst_addr
+        port: dst_port
+
+  edges:
+    - type: ACCESSED
+      database: {database}
+      table: flows
+      from_id: src_addr
+      to_id: dst_addr
+      from_node: IP
+      to_node: IP
+      property_mappings:
+        timestamp: time_received
+        protocol: proto
+        bytes: bytes
+        packets: packets
+        exporter: sampler_address
+"#;
+
+// OpenTelemetry: the ClickHouse exporter's `otel_traces` table. Services
+// and the service call graph are aggregates, hence views.
+const OTEL_SERVICES_VIEW: &str = "CREATE VIEW IF NOT EXISTS {database}.otel_services AS \
+SELECT ServiceName, count() AS span_count, min(Timestamp) AS first_seen, max(Timestamp) AS last_seen \
+FROM {database}.otel_traces GROUP BY ServiceName";
+
+const OTEL_SERVICE_CALLS_VIEW: &str = "CREATE VIEW IF NOT EXISTS {database}.otel_service_calls AS \
+SELECT parent.ServiceName AS caller, child.ServiceName AS callee, count() AS calls, \
+avg(child.Duration) AS avg_duration_ns, countIf(child.StatusCode = 'Error') AS errors \
+FROM {database}.otel_traces AS child \
+INNER JOIN {database}.otel_traces AS parent \
+ON child.TraceId = parent.TraceId AND child.ParentSpanId = parent.SpanId \
+WHERE parent.ServiceName != child.ServiceName \
+GROUP BY caller, callee";
+
+const OTEL_TRACES_YAML: &str = r#"# OpenTelemetry traces (template "otel-traces")
+#   (:Span)-[:CHILD_OF]->(:Span)          otel_traces
+#   (:Span)-[:IN_SERVICE]->(:Service)     otel_traces
+#   (:Service)-[:CALLS]->(:Service)       otel_service_calls view
+name: {schema_name}
+version: "1.0"
+
+graph_schema:
+  nodes:
+    - label: Span
+      database: {database}
+      table: otel_traces
+      node_id: SpanId
+      property_mappings:
+        span_id: SpanId
+        trace_id: TraceId
+        name: SpanName
+        kind: SpanKind
+        service: ServiceName
+        timestamp: Timestamp
+        duration_ns: Duration
+        status: StatusCode
+
+    - label: Service
+      database: {database}
+      table: otel_services
+      node_id: ServiceName
+      property_mappings:
+        name: ServiceName
+        span_count: span_count
+        first_seen: first_seen
+        last_seen: last_seen
+
+  edges:
+    - type: CHILD_OF
+      database: {database}
+      table: otel_traces
+      from_id: SpanId
+      to_id: ParentSpanId
+      from_node: Span
+      to_node: Span
+      property_mappings: {}
+
+    - type: IN_SERVICE
+      database: {database}
+      table: otel_traces
+      from_id: SpanId
+      to_id: ServiceName
+      from_node: Span
+      to_node: Service
+      property_mappings: {}
+
+    - type: CALLS
+      database: {database}
+      table: otel_service_calls
+      from_id: caller
+      to_id: callee
+      from_node: Service
+      to_node: Service
+      property_mappings:
+        calls: calls
+        avg_duration_ns: avg_duration_ns
+        errors: errors
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    #[test]
+    fn every_template_renders_to_a_loadable_schema() {
+        for template in templates() {
+            let rendered = template
+                .render(Some("logs"), None)
+                .unwrap_or_else(|e| panic!("{}: {e}", template.name));
+            assert!(!rendered.yaml.contains("{database}"));
+            let config = GraphSchemaConfig::from_yaml_str(&rendered.yaml)
+                .unwrap_or_else(|e| panic!("{}: {e:?}", template.name));
+            assert_eq!(config.name.as_deref(), Some(rendered.schema_name.as_str()));
+            let schema = config
+                .to_graph_schema()
+                .unwrap_or_else(|e| panic!("{}: {e:?}", template.name));
+            for table in template.tables {
+                assert!(
+                    rendered.yaml.contains(&format!("table: {table}")),
+                    "{} does not map {table}",
+                    template.name
+                );
+            }
+            assert!(!schema.get_relationships_schemas().is_empty());
+            for sql in &rendered.setup_sql {
+                assert!(sql.contains("logs."), "{sql}");
+            }
+        }
+        assert_eq!(
+            find("otel-traces")
+                .unwrap()
+                .render(None, None)
+                .unwrap()
+                .schema_name,
+            "otel_traces"
+        );
+    }
+
+    #[test]
+    fn rejects_non_identifier_names() {
+        let zeek = find("zeek").unwrap();
+        assert!(zeek.render(Some("zeek; DROP TABLE x"), None).is_err());
+        assert!(zeek.render(None, Some("a b")).is_err());
+        assert!(find("syslog").is_none());
+    }
+}
@@ -130,7 +130,7 @@ impl ClickHouseTableStatsSource {
 /// Same identifier discipline as `schema_discovery::validate_sql_identifier`:
 /// database names are interpolated into the query text, so restrict them to a
 /// safe identifier charset instead of trusting schema-file contents.
-fn validate_database_identifier(identifier: &str) -> Result<&str, String> {
+pub(crate) fn validate_database_identifier(identifier: &str) -> Result<&str, String> {
     let ok = !identifier.is_empty()
         && identifier
             .chars()
@@ -81,10 +81,14 @@ pub struct OpenCypherQueryAst<'a> {
     /// When populated, this takes precedence over match_clauses and optional_match_clauses
     pub reading_clauses: Vec<ReadingClause<'a>>,
     pub call_clause: Option<CallClause<'a>>,
+    /// `CALL { ... }` subquery run per incoming row
+    pub call_subquery: Option<CallSubquery<'a>>,
     pub unwind_clauses: Vec<UnwindClause<'a>>, // Support multiple UNWIND clauses for cartesian product
     pub with_clause: Option<WithClause<'a>>,
     pub where_clause: Option<WhereClause<'a>>,
     pub create_clause: Option<CreateClause<'a>>,
+    pub foreach_clause: Option<ForeachClause<'a>>,
+    pub merge_clause: Option<MergeClause<'a>>,
     pub set_clause: Option<SetClause<'a>>,
     pub remove_clause: Option<RemoveClause<'a>>,
     pub delete_clause: Option<DeleteClause<'a>>,
@@ -94,9 +98,24 @@ pub struct OpenCypherQueryAst<'a> {
     pub limit_clause: Option<LimitClause>,
 }
 
+/// `EXPLAIN` / `PROFILE` prefix of a query
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ExplainMode {
+    /// Plan only: return the logical plan and generated SQL
+    Explain,
+    /// Plan and run, reporting ClickHouse execution stats
+    Profile,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct UseClause<'a> {
     pub database_name: &'a str,
+    /// `FOR TIMESTAMP '<ts>'`: read the snapshot tables as of `ts`
+    pub snapshot: Option<&'a str>,
+    /// `AS OF SYSTEM TIME '<ts>'`: read bitemporal edges as recorded at `ts`
+    pub system_time: Option<&'a str>,
+    /// `VALID AT '<ts>'`: read bitemporal edges whose facts held at `ts`
+    pub valid_time: Option<&'a str>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -127,6 +146,28 @@ pub struct CreateClause<'a> {
     pub path_patterns: Vec<PathPattern<'a>>,
 }
 
+/// FOREACH clause: run the nested CREATE once per list element
+/// Example: FOREACH (row IN $rows | CREATE (:Person {id: row.id}))
+#[derive(Debug, PartialEq, Clone)]
+pub struct ForeachClause<'a> {
+    /// The variable bound to each element
+    pub variable: &'a str,
+    /// The list to iterate over
+    pub list: Expression<'a>,
+    pub create_clause: CreateClause<'a>,
+}
+
+/// MERGE clause: match the pattern, or create it when no match exists.
+/// Example: MERGE (n:Person {id: 1}) ON CREATE SET n.name = 'Ada'
+#[derive(Debug, PartialEq, Clone)]
+pub struct MergeClause<'a> {
+    pub path_pattern: PathPattern<'a>,
+    /// `ON CREATE SET` items, applied only when the pattern is created
+    pub on_create: Vec<OperatorApplication<'a>>,
+    /// `ON MATCH SET` items, applied only when the pattern already exists
+    pub on_match: Vec<OperatorApplication<'a>>,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct SetClause<'a> {
     pub set_items: Vec<OperatorApplication<'a>>,
@@ -161,6 +202,9 @@ pub struct ReturnItem<'a> {
     /// Original text of the expression from the query, used as default alias when no explicit AS is provided
     /// This preserves the exact user input including spacing, matching Neo4j's behavior
     pub original_text: Option<&'a str>,
+    /// `RETURN n { .* }`: project every property of `n`, ignoring the
+    /// label's `return_properties` / `max_return_columns`
+    pub all_properties: bool,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -197,6 +241,17 @@ pub struct CallClause<'a> {
     pub yield_items: Option<Vec<&'a str>>,
 }
 
+/// `CALL { [WITH imports] ... RETURN ... [UNION ...] }` subquery clause.
+///
+/// The body is a full query (UNIONs included). A leading `WITH a, b` (the
+/// importing WITH) names the outer variables the body may use; without one
+/// the body is uncorrelated.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CallSubquery<'a> {
+    pub query: Box<OpenCypherQueryAst<'a>>,
+    pub union_clauses: Vec<UnionClause<'a>>,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct CallArgument<'a> {
     pub name: &'a str,
@@ -575,11 +630,16 @@ pub enum Expression<'a> {
     /// EXISTS subquery expression: EXISTS { (pattern) } or EXISTS { MATCH (pattern) WHERE ... }
     /// Evaluates to true if the pattern matches at least one result
     ExistsExpression(Box<ExistsSubquery<'a>>),
+    /// COUNT subquery expression: COUNT { (pattern) } or COUNT { MATCH (pattern) WHERE ... }
+    /// Evaluates to the number of matches; the body has the same shape as EXISTS
+    CountExpression(Box<ExistsSubquery<'a>>),
     /// Reduce expression: reduce(acc = init, x IN list | expr)
     /// Folds a list into a single value using an accumulator
     ReduceExp(ReduceExpression<'a>),
     /// Map literal: {key1: value1, key2: value2}
     /// Used in duration({days: 5}), point({x: 1, y: 2}), etc.
+    /// Map projections `n {.name, .*}` parse to this too; `.*` is the entry
+    /// `("*", n.*)`.
     MapLiteral(Vec<(&'a str, Expression<'a>)>),
     /// Label expression: variable:Label
     /// Returns true if the variable has the specified label
@@ -632,12 +692,14 @@ pub struct LambdaExpression<'a> {
     pub body: Box<Expression<'a>>,
 }
 
-/// EXISTS subquery: checks if a pattern exists
+/// EXISTS subquery: checks if a pattern exists. Also the body of a COUNT
+/// subquery, which counts the matches instead.
 ///
 /// Examples:
 /// ```text
 /// EXISTS { (u)-[:FOLLOWS]->(:User) }
 /// EXISTS { MATCH (u)-[:FOLLOWS]->(f) WHERE f.active = true }
+/// COUNT { (u)-[:FOLLOWS]->() }
 /// ```
 #[derive(Debug, PartialEq, Clone)]
 pub struct ExistsSubquery<'a> {
@@ -741,6 +803,12 @@ impl fmt::Display for OpenCypherQueryAst<'_> {
         if let Some(ref c) = self.create_clause {
             writeln!(f, "├── CreateClause: {:#?}", c)?;
         }
+        if let Some(ref fe) = self.foreach_clause {
+            writeln!(f, "├── ForeachClause: {:#?}", fe)?;
+        }
+        if let Some(ref m) = self.merge_clause {
+            writeln!(f, "├── MergeClause: {:#?}", m)?;
+        }
         if let Some(ref s) = self.set_clause {
             writeln!(f, "├── SetClause: {:#?}", s)?;
         }
@@ -1,12 +1,12 @@
-use crate::open_cypher_parser::ast::{CallArgument, CallClause, Expression};
+use crate::open_cypher_parser::ast::{CallArgument, CallClause, CallSubquery, Expression};
 use crate::open_cypher_parser::common::ws;
 use crate::open_cypher_parser::errors::OpenCypherParsingError;
 use crate::open_cypher_parser::expression;
 use nom::branch::alt;
-use nom::bytes::complete::tag;
+use nom::bytes::complete::{tag, tag_no_case};
 use nom::character::complete::{alphanumeric1, char};
 use nom::combinator::opt;
-use nom::multi::separated_list0;
+use nom::multi::{many0, separated_list0};
 use nom::sequence::delimited;
 use nom::{IResult, Parser};
 
@@ -39,6 +39,25 @@ pub fn parse_call_clause<'a>(
     ))
 }
 
+/// Parse a `CALL { ... }` subquery: a full query, UNIONs included, in braces.
+pub fn parse_call_subquery<'a>(
+    input: &'a str,
+) -> IResult<&'a str, CallSubquery<'a>, OpenCypherParsingError<'a>> {
+    let (input, _) = ws(tag_no_case("CALL")).parse(input)?;
+    let (input, _) = ws(char('{')).parse(input)?;
+    let (input, query) = super::parse_query_with_nom(input)?;
+    let (input, union_clauses) = many0(super::parse_union_clause).parse(input)?;
+    let (input, _) = ws(char('}')).parse(input)?;
+
+    Ok((
+        input,
+        CallSubquery {
+            query: Box::new(query),
+            union_clauses,
+        },
+    ))
+}
+
 fn parse_procedure_name<'a>(
     input: &'a str,
 ) -> IResult<&'a str, &'a str, OpenCypherParsingError<'a>> {
@@ -111,6 +130,25 @@ mod tests {
         assert_eq!(call.arguments[1].name, "damping");
     }
 
+    #[test]
+    fn test_parse_call_subquery() {
+        let input = "CALL { WITH u MATCH (u)-[:FOLLOWS]->(f) RETURN count(f) AS c } RETURN c";
+        let (rest, call) = parse_call_subquery(input).unwrap();
+        assert_eq!(rest, "RETURN c");
+        let import = call.query.with_clause.as_ref().unwrap();
+        assert_eq!(import.with_items.len(), 1);
+        assert!(import.subsequent_match.is_some());
+        assert!(call.query.return_clause.is_some());
+        assert!(call.union_clauses.is_empty());
+
+        let input = "call { MATCH (a:A) RETURN a.name AS name UNION ALL MATCH (b:B) RETURN b.name AS name }";
+        let (rest, call) = parse_call_subquery(input).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(call.union_clauses.len(), 1);
+
+        assert!(parse_call_subquery("CALL pagerank").is_err());
+    }
+
     #[test]
     fn test_parse_call_clause_with_arrow_syntax() {
         let input = "CALL pagerank.graph(nodeLabels => 'User', maxIterations => 5)";
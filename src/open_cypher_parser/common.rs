@@ -1,11 +1,11 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_until, take_while1},
-    character::complete::{alpha1, alphanumeric1, digit1, multispace0},
-    combinator::{opt, recognize},
+    bytes::complete::{tag, tag_no_case, take_until, take_while1},
+    character::complete::{alpha1, alphanumeric1, digit1, multispace0, satisfy},
+    combinator::{not, opt, peek, recognize},
     error::ParseError,
     multi::many0,
-    sequence::{delimited, pair},
+    sequence::{delimited, pair, terminated},
     IResult, Parser,
 };
 
@@ -157,6 +157,17 @@ where
     delimited(multispace0, inner, multispace0)
 }
 
+/// Case-insensitive keyword that is not the start of a longer identifier,
+/// so `DISTINCT` matches in `count(DISTINCT x)` but not in `distinct_users`.
+pub fn keyword<'a, E: ParseError<&'a str>>(
+    kw: &'static str,
+) -> impl Parser<&'a str, Output = &'a str, Error = E> {
+    ws(terminated(
+        tag_no_case(kw),
+        not(peek(satisfy(|c: char| c.is_alphanumeric() || c == '_'))),
+    ))
+}
+
 // This parsed multuple dots as well. Keep it for now here
 // pub fn parse_alphanumeric_with_underscore_dot_star(input: &str) -> IResult<&str, &str> {
 
@@ -0,0 +1,53 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag_no_case,
+    character::complete::{multispace0, multispace1},
+    combinator::{opt, value},
+    sequence::terminated,
+    IResult, Parser,
+};
+
+use super::{ast::ExplainMode, errors::OpenCypherParsingError};
+
+/// Parse an optional `EXPLAIN` / `PROFILE` prefix
+/// Examples:
+///   EXPLAIN MATCH (n) RETURN n
+///   PROFILE MATCH (a)-[:KNOWS]->(b) RETURN count(*)
+pub fn parse_explain_prefix<'a>(
+    input: &'a str,
+) -> IResult<&'a str, Option<ExplainMode>, OpenCypherParsingError<'a>> {
+    let (input, _) = multispace0.parse(input)?;
+    // The keyword must be followed by whitespace, so `EXPLAINED` is not a prefix
+    opt(terminated(
+        alt((
+            value(ExplainMode::Explain, tag_no_case("EXPLAIN")),
+            value(ExplainMode::Profile, tag_no_case("PROFILE")),
+        )),
+        multispace1,
+    ))
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_explain_and_profile() {
+        let (rest, mode) = parse_explain_prefix("  explain MATCH (n) RETURN n").unwrap();
+        assert_eq!(mode, Some(ExplainMode::Explain));
+        assert_eq!(rest, "MATCH (n) RETURN n");
+
+        let (rest, mode) = parse_explain_prefix("PROFILE\n MATCH (n) RETURN n").unwrap();
+        assert_eq!(mode, Some(ExplainMode::Profile));
+        assert_eq!(rest, "MATCH (n) RETURN n");
+    }
+
+    #[test]
+    fn test_no_explain_prefix() {
+        for input in ["MATCH (n) RETURN n", "EXPLAINED", "EXPLAIN"] {
+            let (_, mode) = parse_explain_prefix(input).unwrap();
+            assert_eq!(mode, None, "{input}");
+        }
+    }
+}
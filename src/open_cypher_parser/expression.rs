@@ -11,7 +11,7 @@ use nom::{
 
 use nom::character::complete::char;
 
-use crate::open_cypher_parser::common::{self, ws};
+use crate::open_cypher_parser::common::{self, keyword, ws};
 
 use super::{
     ast::{
@@ -37,11 +37,13 @@ fn parse_postfix_expression(input: &'_ str) -> IResult<&'_ str, Expression<'_>>
     // First, parse any primary expression (function call, literal, variable, etc.)
     let (mut input, mut expr) = alt((
         parse_exists_expression, // Must be before parse_function_call to catch EXISTS { }
+        parse_count_subquery_expression, // Must be before parse_function_call to catch COUNT { }
         parse_case_expression,
         parse_reduce_expression, // Must be before parse_function_call to catch reduce(...)
         parse_pattern_comprehension, // Must be before parse_list_literal to catch [(pattern) | ...]
         parse_list_comprehension, // Must be before parse_list_literal to catch [x IN list WHERE ...]
         parse_path_pattern_expression,
+        parse_map_projection, // Must be before parse_function_call: `n {...}` vs `n(...)`
         parse_function_call,
         parse_property_access,
         parse_map_literal, // Must be before list_literal (different brackets anyway)
@@ -203,7 +205,25 @@ fn parse_postfix_expression(input: &'_ str) -> IResult<&'_ str, Expression<'_>>
 fn parse_exists_expression(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
     // Parse EXISTS keyword
     let (input, _) = ws(tag_no_case("EXISTS")).parse(input)?;
+    let (input, body) = parse_subquery_body(input)?;
+    Ok((input, Expression::ExistsExpression(Box::new(body))))
+}
+
+/// Parse COUNT subquery expression
+/// Syntax: COUNT { pattern } or COUNT { MATCH pattern WHERE condition }
+/// Examples:
+///   COUNT { (n)--() }
+///   COUNT { MATCH (u)-[:FOLLOWS]->(f) WHERE f.active = true }
+/// `count(...)` with parentheses is the aggregate and is left to parse_function_call.
+fn parse_count_subquery_expression(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
+    let (input, _) = ws(tag_no_case("COUNT")).parse(input)?;
+    let (input, body) = parse_subquery_body(input)?;
+    Ok((input, Expression::CountExpression(Box::new(body))))
+}
 
+/// Parse the `{ [MATCH] pattern [WHERE condition] }` body shared by EXISTS and
+/// COUNT subqueries.
+fn parse_subquery_body(input: &'_ str) -> IResult<&'_ str, ExistsSubquery<'_>> {
     // Parse opening brace
     let (input, _) = ws(char('{')).parse(input)?;
 
@@ -225,20 +245,20 @@ fn parse_exists_expression(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
 
     Ok((
         input,
-        Expression::ExistsExpression(Box::new(ExistsSubquery {
+        ExistsSubquery {
             pattern,
             where_clause: where_clause.map(Box::new),
-        })),
+        },
     ))
 }
 
 fn parse_case_expression(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
-    let (input, _) = ws(tag_no_case("CASE")).parse(input)?;
+    let (input, _) = keyword("CASE").parse(input)?;
 
-    // Check if this is a searched CASE (starts with WHEN) or simple CASE (has an expression)
-    // Try to peek ahead to see if the next non-whitespace token is "WHEN"
-    let (input_after_ws, _) = multispace0.parse(input)?;
-    let is_searched = input_after_ws.starts_with("WHEN") || input_after_ws.starts_with("when");
+    // A searched CASE starts with WHEN; a simple CASE has an operand first
+    let is_searched = peek(keyword::<nom::error::Error<&str>>("WHEN"))
+        .parse(input)
+        .is_ok();
 
     let (input, case_expr) = if is_searched {
         // Searched CASE - no case_expr
@@ -257,8 +277,8 @@ fn parse_case_expression(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
 
     loop {
         let res = preceded(
-            ws(tag_no_case("WHEN")),
-            separated_pair(parse_expression, ws(tag_no_case("THEN")), parse_expression),
+            keyword("WHEN"),
+            separated_pair(parse_expression, keyword("THEN"), parse_expression),
         )
         .parse(remaining_input);
 
@@ -274,10 +294,10 @@ fn parse_case_expression(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
 
     // Optional ELSE clause
     let (input, else_expr) =
-        opt(preceded(ws(tag_no_case("ELSE")), parse_expression)).parse(remaining_input)?;
+        opt(preceded(keyword("ELSE"), parse_expression)).parse(remaining_input)?;
 
     // END keyword
-    let (input, _) = ws(tag_no_case("END")).parse(input)?;
+    let (input, _) = keyword("END").parse(input)?;
 
     Ok((
         input,
@@ -702,16 +722,6 @@ fn parse_unary_expression(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
                 operands: vec![Expression::Literal(Literal::Integer(0)), expr],
             })
         }),
-        // DISTINCT is a unary operator
-        map(
-            preceded(ws(tag_no_case("DISTINCT")), parse_unary_expression),
-            |expr| {
-                Expression::OperatorApplicationExp(OperatorApplication {
-                    operator: Operator::Distinct,
-                    operands: vec![expr],
-                })
-            },
-        ),
         parse_primary, // fallback to a primary expression
     ))
     .parse(input)
@@ -989,8 +999,11 @@ pub fn parse_function_call(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
 
     // Then parse the comma-separated arguments within parentheses.
     // Need to try lambda first before regular expression
-    let (input, args) = delimited(
-        ws(char('(')),
+    let (input, _) = ws(char('(')).parse(input)?;
+    // Aggregate modifier: `count(DISTINCT n.a + 1)` applies to the whole
+    // first argument, kept as a `Distinct` operator around it.
+    let (input, distinct) = opt(keyword("DISTINCT")).parse(input)?;
+    let (input, mut args) = terminated(
         separated_list0(
             ws(char(',')),
             alt((parse_lambda_expression, parse_expression)),
@@ -998,6 +1011,19 @@ pub fn parse_function_call(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
         ws(char(')')),
     )
     .parse(input)?;
+    if distinct.is_some() {
+        if args.is_empty() {
+            return Err(nom::Err::Error(Error::new(input, ErrorKind::Tag)));
+        }
+        let first = args.remove(0);
+        args.insert(
+            0,
+            Expression::OperatorApplicationExp(OperatorApplication {
+                operator: Operator::Distinct,
+                operands: vec![first],
+            }),
+        );
+    }
 
     Ok((
         input,
@@ -1069,6 +1095,40 @@ pub fn parse_map_literal(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
     Ok((input, Expression::MapLiteral(pairs)))
 }
 
+/// Map projection: `n {.name, .*, total: count(x), m}`.
+///
+/// Desugared to a map literal: `.name` becomes `name: n.name`, `key: expr` is
+/// kept, and a bare variable `m` becomes `m: m`. `.*` becomes the entry
+/// `*: n.*`, which the analyzer expands to every property of `n`'s labels.
+pub fn parse_map_projection(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
+    let (input, variable) = ws(parse_identifier).parse(input)?;
+    let (input, _) = ws(char('{')).parse(input)?;
+    let (input, entries) = terminated(
+        separated_list0(
+            ws(char(',')),
+            alt((
+                map(preceded(ws(char('.')), parse_property_name), |key| {
+                    (
+                        key,
+                        Expression::PropertyAccessExp(PropertyAccess {
+                            base: variable,
+                            key,
+                        }),
+                    )
+                }),
+                separated_pair(ws(parse_identifier), ws(char(':')), parse_expression),
+                map(ws(parse_identifier), |name| {
+                    (name, Expression::Variable(name))
+                }),
+            )),
+        ),
+        ws(char('}')),
+    )
+    .parse(input)?;
+
+    Ok((input, Expression::MapLiteral(entries)))
+}
+
 pub fn parse_list_literal(input: &'_ str) -> IResult<&'_ str, Expression<'_>> {
     // Parse content within [ ... ] as a comma-separated list of expressions.
     // Note: Pattern comprehensions [(pattern) | projection] are handled by
@@ -1147,9 +1207,9 @@ pub fn parse_property_access(input: &'_ str) -> IResult<&'_ str, Expression<'_>>
     //   - If x is a temporal value: month(x) (temporal accessor)
     //   - If x is a map/node: x['month'] (property access)
     // The spec resolves this at the semantic level (type checking), but we resolve at parse
-    // time because post-WITH temporal values lose their type information. This means a node
-    // property literally named "month", "day", "year", etc. would be misinterpreted. If that
-    // occurs, the workaround is to use map syntax: `node['month']`.
+    // time because post-WITH temporal values lose their type information. A node or
+    // relationship property literally named "month", "day", "year", etc. is turned back into
+    // a property access by the analyzer's temporal accessor resolution, once labels are known.
     //
     // Guard: only apply when first_key is the TERMINAL segment (no further `.segment` follows).
     // If chained (e.g., `x.month.something`), it's clearly a property access, not temporal.
@@ -1565,6 +1625,33 @@ mod tests {
         assert_eq!(&expr, &expected);
     }
 
+    #[test]
+    fn test_parse_function_call_distinct_modifier() {
+        // DISTINCT covers the whole first argument, not just its first operand
+        let (rem, expr) = parse_function_call("count(DISTINCT a + b)").unwrap();
+        assert_eq!(rem, "");
+        let expected = Expression::FunctionCallExp(FunctionCall {
+            name: "count".to_string(),
+            args: vec![Expression::OperatorApplicationExp(OperatorApplication {
+                operator: Operator::Distinct,
+                operands: vec![Expression::OperatorApplicationExp(OperatorApplication {
+                    operator: Operator::Addition,
+                    operands: vec![Expression::Variable("a"), Expression::Variable("b")],
+                })],
+            })],
+        });
+        assert_eq!(&expr, &expected);
+
+        // An identifier that merely starts with "distinct" is a plain argument
+        let (rem, expr) = parse_function_call("count(distinct_users)").unwrap();
+        assert_eq!(rem, "");
+        let expected = Expression::FunctionCallExp(FunctionCall {
+            name: "count".to_string(),
+            args: vec![Expression::Variable("distinct_users")],
+        });
+        assert_eq!(&expr, &expected);
+    }
+
     // list
     #[test]
     fn test_parse_list_literal() {
@@ -1753,6 +1840,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_map_projection() {
+        let (rem, expr) = parse_expression("n {.name, .*, total: count(x), m}").unwrap();
+        assert_eq!(rem, "");
+        let expected = Expression::MapLiteral(vec![
+            (
+                "name",
+                Expression::PropertyAccessExp(PropertyAccess {
+                    base: "n",
+                    key: "name",
+                }),
+            ),
+            (
+                "*",
+                Expression::PropertyAccessExp(PropertyAccess {
+                    base: "n",
+                    key: "*",
+                }),
+            ),
+            (
+                "total",
+                Expression::FunctionCallExp(FunctionCall {
+                    name: "count".to_string(),
+                    args: vec![Expression::Variable("x")],
+                }),
+            ),
+            ("m", Expression::Variable("m")),
+        ]);
+        assert_eq!(&expr, &expected);
+
+        // Nested inside a function call, without a space before the brace
+        let (rem, expr) = parse_expression("collect(f{.title})").unwrap();
+        assert_eq!(rem, "");
+        let Expression::FunctionCallExp(fc) = expr else {
+            panic!("Expected FunctionCallExp");
+        };
+        assert!(matches!(&fc.args[0], Expression::MapLiteral(entries) if entries.len() == 1));
+    }
+
+    #[test]
+    fn test_parse_case_expression() {
+        // Searched CASE, keywords in any case
+        let (rem, expr) =
+            parse_expression("Case When n.age > 18 Then 'adult' else 'minor' End").unwrap();
+        assert_eq!(rem, "");
+        let Expression::Case(case) = expr else {
+            panic!("Expected Case, got {expr:?}");
+        };
+        assert!(case.expr.is_none());
+        assert_eq!(case.when_then.len(), 1);
+        assert_eq!(
+            case.else_expr.as_deref(),
+            Some(&Expression::Literal(Literal::String("minor")))
+        );
+
+        // Simple CASE
+        let (rem, expr) =
+            parse_expression("CASE n.city WHEN 'a' THEN 1 WHEN 'b' THEN 2 END").unwrap();
+        assert_eq!(rem, "");
+        let Expression::Case(case) = expr else {
+            panic!("Expected Case, got {expr:?}");
+        };
+        assert!(case.expr.is_some());
+        assert_eq!(case.when_then.len(), 2);
+        assert!(case.else_expr.is_none());
+
+        // An identifier starting with `case` is not the keyword
+        let (rem, expr) = parse_expression("caseId").unwrap();
+        assert_eq!(rem, "");
+        assert_eq!(expr, Expression::Variable("caseId"));
+    }
+
     #[test]
     fn test_parse_duration_with_map_arg() {
         let (rem, expr) = parse_expression("duration({days: 5})").unwrap();
@@ -2249,6 +2408,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_count_subquery_vs_count_aggregate() {
+        let (remaining, expr) =
+            parse_expression("COUNT { (n)-[:FOLLOWS]->(m) WHERE m.age > 30 } > 2").unwrap();
+        assert_eq!(remaining, "");
+        if let Expression::OperatorApplicationExp(op) = &expr {
+            match &op.operands[0] {
+                Expression::CountExpression(body) => assert!(body.where_clause.is_some()),
+                other => panic!("Expected CountExpression, got {:?}", other),
+            }
+        } else {
+            panic!("Expected comparison, got {:?}", expr);
+        }
+
+        let (_, expr) = parse_expression("count(n)").unwrap();
+        assert!(matches!(expr, Expression::FunctionCallExp(_)));
+    }
+
     #[test]
     fn test_list_comprehension_size_wrapper() {
         // size([p IN posts WHERE (p)-[:HAS_TAG]->()<-[:HAS_INTEREST]-(person)])
@@ -0,0 +1,97 @@
+use nom::character::complete::char;
+use nom::{bytes::complete::tag_no_case, combinator::cut, error::context, IResult, Parser};
+
+use super::{
+    ast::{Expression, ForeachClause},
+    common::ws,
+    create_clause,
+    errors::OpenCypherParsingError,
+    expression::{parse_expression, parse_identifier},
+};
+
+/// Wrapper to convert parse_expression errors to OpenCypherParsingError
+fn expression_parser(input: &str) -> IResult<&str, Expression<'_>, OpenCypherParsingError<'_>> {
+    parse_expression(input).map_err(|e| match e {
+        nom::Err::Incomplete(needed) => nom::Err::Incomplete(needed),
+        nom::Err::Error(err) => nom::Err::Failure(OpenCypherParsingError::from(err)),
+        nom::Err::Failure(err) => nom::Err::Failure(OpenCypherParsingError::from(err)),
+    })
+}
+
+/// Wrapper to convert parse_identifier errors to OpenCypherParsingError
+fn identifier_parser(input: &str) -> IResult<&str, &str, OpenCypherParsingError<'_>> {
+    parse_identifier(input).map_err(|e| match e {
+        nom::Err::Incomplete(needed) => nom::Err::Incomplete(needed),
+        nom::Err::Error(err) => nom::Err::Failure(OpenCypherParsingError::from(err)),
+        nom::Err::Failure(err) => nom::Err::Failure(OpenCypherParsingError::from(err)),
+    })
+}
+
+/// Parse a FOREACH clause: FOREACH (<variable> IN <list> | CREATE <patterns>)
+///
+/// Only CREATE is accepted as the nested update clause.
+///
+/// Examples:
+/// - FOREACH (row IN $rows | CREATE (:Person {id: row.id}))
+/// - FOREACH (x IN [1, 2] | CREATE (:N {v: x}), (:M {v: x}))
+pub fn parse_foreach_clause(
+    input: &'_ str,
+) -> IResult<&'_ str, ForeachClause<'_>, OpenCypherParsingError<'_>> {
+    let (input, _) = ws(tag_no_case("FOREACH")).parse(input)?;
+
+    let (input, _) = context("Expected ( after FOREACH", cut(ws(char('(')))).parse(input)?;
+    let (input, variable) =
+        context("Expected variable in FOREACH", cut(ws(identifier_parser))).parse(input)?;
+    let (input, _) = context(
+        "Expected IN after FOREACH variable",
+        cut(ws(tag_no_case("IN"))),
+    )
+    .parse(input)?;
+    let (input, list) = context(
+        "Error parsing FOREACH list expression",
+        cut(ws(expression_parser)),
+    )
+    .parse(input)?;
+    let (input, _) = context(
+        "Expected | after FOREACH list expression",
+        cut(ws(char('|'))),
+    )
+    .parse(input)?;
+    let (input, create_clause) = context(
+        "FOREACH supports only a nested CREATE clause",
+        cut(ws(create_clause::parse_create_clause)),
+    )
+    .parse(input)?;
+    let (input, _) = context("Expected ) to close FOREACH", cut(ws(char(')')))).parse(input)?;
+
+    Ok((
+        input,
+        ForeachClause {
+            variable,
+            list,
+            create_clause,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_foreach_create() {
+        let input =
+            "FOREACH (row IN $rows | CREATE (:Person {id: row.id}), (:Tag {name: row.tag}))";
+        let (remaining, clause) = parse_foreach_clause(input).unwrap();
+        assert!(remaining.trim().is_empty());
+        assert_eq!(clause.variable, "row");
+        assert_eq!(clause.list, Expression::Parameter("rows"));
+        assert_eq!(clause.create_clause.path_patterns.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_foreach_rejects_other_updates() {
+        assert!(parse_foreach_clause("FOREACH (x IN [1] | SET x.a = 1)").is_err());
+        assert!(parse_foreach_clause("FOREACH (x IN [1] CREATE (:N))").is_err());
+    }
+}
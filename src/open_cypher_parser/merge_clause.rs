@@ -0,0 +1,115 @@
+use nom::branch::alt;
+use nom::character::complete::multispace1;
+use nom::combinator::cut;
+use nom::error::context;
+use nom::multi::many0;
+use nom::{bytes::complete::tag_no_case, IResult, Parser};
+
+use super::ast::{MergeClause, OperatorApplication, PathPattern};
+use super::common::ws;
+use super::errors::OpenCypherParsingError;
+use super::path_pattern;
+use super::set_clause::parse_set_clause;
+
+/// Which `ON ... SET` action a MERGE sub-clause belongs to.
+enum MergeAction {
+    OnCreate,
+    OnMatch,
+}
+
+pub fn parse_merge_clause(
+    input: &'_ str,
+) -> IResult<&'_ str, MergeClause<'_>, OpenCypherParsingError<'_>> {
+    let (input, _) = tag_no_case("MERGE")(input)?;
+
+    let (input, path_pattern) = context("Error in merge clause", cut(path_parser)).parse(input)?;
+
+    let (input, actions) = many0(merge_action).parse(input)?;
+
+    let mut on_create = Vec::new();
+    let mut on_match = Vec::new();
+    for (action, items) in actions {
+        match action {
+            MergeAction::OnCreate => on_create.extend(items),
+            MergeAction::OnMatch => on_match.extend(items),
+        }
+    }
+
+    Ok((
+        input,
+        MergeClause {
+            path_pattern,
+            on_create,
+            on_match,
+        },
+    ))
+}
+
+/// `ON CREATE SET ...` / `ON MATCH SET ...`
+fn merge_action(
+    input: &'_ str,
+) -> IResult<&'_ str, (MergeAction, Vec<OperatorApplication<'_>>), OpenCypherParsingError<'_>> {
+    let (input, _) = ws(tag_no_case("ON")).parse(input)?;
+    let (input, action) = alt((
+        tag_no_case("CREATE").map(|_| MergeAction::OnCreate),
+        tag_no_case("MATCH").map(|_| MergeAction::OnMatch),
+    ))
+    .parse(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, set_clause) =
+        context("Error in merge action", cut(parse_set_clause)).parse(input)?;
+    Ok((input, (action, set_clause.set_items)))
+}
+
+fn path_parser(input: &str) -> IResult<&str, PathPattern<'_>, OpenCypherParsingError<'_>> {
+    path_pattern::parse_path_pattern(input).map_err(|e| match e {
+        nom::Err::Incomplete(needed) => nom::Err::Incomplete(needed),
+        nom::Err::Error(err) => nom::Err::Failure(OpenCypherParsingError::from(err)),
+        nom::Err::Failure(err) => nom::Err::Failure(OpenCypherParsingError::from(err)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::open_cypher_parser::ast::{Expression, Literal, Operator};
+
+    #[test]
+    fn test_parse_merge_clause_node_pattern() {
+        let (remaining, merge) = parse_merge_clause("MERGE (n:Person {id: 1})").unwrap();
+        assert_eq!(remaining, "");
+        match &merge.path_pattern {
+            PathPattern::Node(node) => {
+                assert_eq!(node.name, Some("n"));
+                assert_eq!(node.labels, Some(vec!["Person"]));
+                assert_eq!(node.properties.as_ref().map(|p| p.len()), Some(1));
+            }
+            other => panic!("Expected Node variant, got: {:?}", other),
+        }
+        assert!(merge.on_create.is_empty());
+        assert!(merge.on_match.is_empty());
+    }
+
+    #[test]
+    fn test_parse_merge_clause_with_actions() {
+        let (remaining, merge) = parse_merge_clause(
+            "MERGE (n:Person {id: 1}) ON CREATE SET n.name = 'Ada', n.age = 36 \
+             ON MATCH SET n.seen = true RETURN n",
+        )
+        .unwrap();
+        assert_eq!(remaining.trim_start(), "RETURN n");
+        assert_eq!(merge.on_create.len(), 2);
+        assert_eq!(merge.on_match.len(), 1);
+        assert_eq!(merge.on_match[0].operator, Operator::Equal);
+        assert_eq!(
+            merge.on_match[0].operands[1],
+            Expression::Literal(Literal::Boolean(true))
+        );
+    }
+
+    #[test]
+    fn test_parse_merge_clause_missing_pattern() {
+        assert!(parse_merge_clause("MERGE").is_err());
+        assert!(parse_merge_clause("CREATE (n)").is_err());
+    }
+}
@@ -1,7 +1,8 @@
 use ast::{
-    CallClause, CreateClause, CypherStatement, DeleteClause, MatchClause, OpenCypherQueryAst,
-    OptionalMatchClause, ReadingClause, RemoveClause, ReturnClause, SetClause, UnionClause,
-    UnionType, UnwindClause, UseClause, WhereClause, WithClause,
+    CallClause, CallSubquery, CreateClause, CypherStatement, DeleteClause, ForeachClause,
+    MatchClause, MergeClause, OpenCypherQueryAst, OptionalMatchClause, ReadingClause, RemoveClause,
+    ReturnClause, SetClause, UnionClause, UnionType, UnwindClause, UseClause, WhereClause,
+    WithClause,
 };
 pub use common::strip_comments;
 use common::ws;
@@ -20,9 +21,12 @@ pub(crate) mod copy_to;
 mod create_clause;
 mod delete_clause;
 pub(crate) mod errors;
+mod explain_clause;
 mod expression;
+mod foreach_clause;
 mod limit_clause;
 mod match_clause;
+mod merge_clause;
 mod optional_match_clause;
 mod order_by_and_page_clause;
 mod order_by_clause;
@@ -37,6 +41,18 @@ mod use_clause;
 mod where_clause;
 mod with_clause;
 
+/// Split a leading `EXPLAIN` / `PROFILE` keyword off a query.
+///
+/// Returns the mode (if any) and the query that follows it. The prefix is not
+/// part of [`parse_cypher_statement`]'s grammar: callers strip it first and
+/// plan the remaining statement as usual.
+pub fn split_explain_prefix(input: &str) -> (Option<ast::ExplainMode>, &str) {
+    match explain_clause::parse_explain_prefix(input) {
+        Ok((rest, Some(mode))) => (Some(mode), rest),
+        _ => (None, input),
+    }
+}
+
 /// Parse a complete Cypher statement, potentially with UNION clauses or standalone procedure call.
 ///
 /// This is the all-consuming top-level entry point: on success, `input` (the
@@ -76,6 +92,49 @@ fn ensure_fully_consumed(remaining: &'_ str) -> Result<(), nom::Err<OpenCypherPa
     }
 }
 
+/// Parse a script of `;`-separated statements.
+///
+/// Returns each statement's source text (without its `;`, keeping any
+/// `EXPLAIN` / `PROFILE` prefix) with its AST. Every statement except the last
+/// must be terminated by `;`, and empty statements (`;;`) are skipped. The
+/// split follows the grammar, so a `;` inside a string literal or quoted
+/// identifier does not end a statement. As with [`parse_cypher_statement`],
+/// comments must already be stripped.
+pub fn parse_cypher_statements(
+    input: &'_ str,
+) -> Result<Vec<(&'_ str, CypherStatement<'_>)>, OpenCypherParsingError<'_>> {
+    let mut statements = Vec::new();
+    let mut rest = input;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ';');
+        if rest.is_empty() {
+            return Ok(statements);
+        }
+        let (_, body) = split_explain_prefix(rest);
+        let (remaining, statement) = match parse_cypher_statement_body(body) {
+            Ok(parsed) => parsed,
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => return Err(e),
+            Err(nom::Err::Incomplete(_)) => {
+                return Err(OpenCypherParsingError {
+                    errors: vec![(rest, "Incomplete statement")],
+                })
+            }
+        };
+        let consumed = rest[..rest.len() - remaining.len()].trim_end();
+        if !consumed.ends_with(';') && !remaining.trim().is_empty() {
+            return Err(OpenCypherParsingError {
+                errors: vec![
+                    (remaining, "Expected ';' between statements"),
+                    (remaining.trim(), "Unparsed input"),
+                ],
+            });
+        }
+        let text = consumed.strip_suffix(';').unwrap_or(consumed).trim_end();
+        statements.push((text, statement));
+        rest = remaining;
+    }
+}
+
 /// Parses a complete Cypher statement without enforcing that all input is
 /// consumed. Internal helper for `parse_cypher_statement` — do not call
 /// directly outside this module; callers must go through
@@ -104,8 +163,9 @@ fn parse_cypher_statement_body(
     if let Ok((remaining, procedure_call)) =
         standalone_procedure_call::parse_standalone_procedure_call(input)
     {
+        // A `;` may end the call and start the next statement of a script.
         let check = remaining.trim();
-        if check.is_empty() || check == ";" {
+        if check.is_empty() || check.starts_with(';') {
             let (input, _) = opt(ws(tag(";"))).parse(remaining)?;
             return Ok((input, CypherStatement::ProcedureCall(procedure_call)));
         }
@@ -223,6 +283,11 @@ pub fn parse_query_with_nom(
 
     let (input, call_clause): (&str, Option<CallClause>) =
         opt(call_clause::parse_call_clause).parse(input)?;
+    let (input, call_subquery): (&str, Option<CallSubquery>) = if call_clause.is_none() {
+        opt(call_clause::parse_call_subquery).parse(input)?
+    } else {
+        (input, None)
+    };
 
     // Parse UNWIND clauses (can appear after MATCH/OPTIONAL MATCH, before WITH/RETURN)
     // Supports multiple consecutive UNWIND for cartesian product
@@ -246,6 +311,13 @@ pub fn parse_query_with_nom(
 
     let (input, create_clause): (&str, Option<CreateClause>) =
         opt(create_clause::parse_create_clause).parse(input)?;
+    let (input, foreach_clause): (&str, Option<ForeachClause>) = if create_clause.is_none() {
+        opt(foreach_clause::parse_foreach_clause).parse(input)?
+    } else {
+        (input, None)
+    };
+    let (input, merge_clause): (&str, Option<MergeClause>) =
+        opt(merge_clause::parse_merge_clause).parse(input)?;
     let (input, set_clause): (&str, Option<SetClause>) =
         opt(set_clause::parse_set_clause).parse(input)?;
     let (input, remove_clause): (&str, Option<RemoveClause>) =
@@ -270,10 +342,13 @@ pub fn parse_query_with_nom(
         optional_match_clauses,
         reading_clauses,
         call_clause,
+        call_subquery,
         unwind_clauses,
         with_clause,
         where_clause,
         create_clause,
+        foreach_clause,
+        merge_clause,
         set_clause,
         remove_clause,
         delete_clause,
@@ -677,6 +752,7 @@ mod tests {
                 }),
                 alias: Some("otherName"),
                 original_text: None,
+                all_properties: false,
             }],
         };
         assert_eq!(return_clause, expected_return_clause);
@@ -825,6 +901,7 @@ mod tests {
                     expression: Expression::Variable("p"),
                     alias: Some("tom_hardy"),
                     original_text: None,
+                    all_properties: false,
                 },
                 // movie.name AS movieName
                 ReturnItem {
@@ -834,6 +911,7 @@ mod tests {
                     }),
                     alias: Some("movieName"),
                     original_text: None,
+                    all_properties: false,
                 },
                 // (a)-[]->(c)
                 ReturnItem {
@@ -860,6 +938,7 @@ mod tests {
                     ])),
                     alias: None,
                     original_text: Some("(a)-[]->(c)"),
+                    all_properties: false,
                 },
             ],
         };
@@ -1003,6 +1082,7 @@ mod tests {
                 expression: Expression::Variable("r"),
                 alias: None,
                 original_text: Some("r"),
+                all_properties: false,
             }],
         };
         assert_eq!(return_clause, expected_return_clause);
@@ -1095,6 +1175,7 @@ mod tests {
                 expression: Expression::Variable("n"),
                 alias: None,
                 original_text: Some("n"),
+                all_properties: false,
             }],
         };
         assert_eq!(return_clause, expected_return_clause);
@@ -1258,6 +1339,7 @@ mod tests {
                 expression: Expression::Variable("andres"),
                 alias: None,
                 original_text: Some("andres"),
+                all_properties: false,
             }],
         };
         assert_eq!(return_clause, expected_return_clause);
@@ -1379,6 +1461,7 @@ mod tests {
                 expression: Expression::Variable("p"),
                 alias: None,
                 original_text: Some("p"),
+                all_properties: false,
             }],
         };
         assert_eq!(return_clause, expected_return_clause);
@@ -1519,6 +1602,7 @@ mod tests {
                 expression: Expression::Variable("p"),
                 alias: None,
                 original_text: Some("p"),
+                all_properties: false,
             }],
         };
         assert_eq!(return_clause, expected_return_clause);
@@ -1854,6 +1938,46 @@ mod tests {
         assert!(remaining.trim().is_empty());
     }
 
+    #[test]
+    fn test_parse_cypher_statements_splits_on_semicolons() {
+        let script = strip_comments(
+            "// setup\n\
+             CALL db.labels();\n\
+             MATCH (n) WHERE n.name = 'a;b' RETURN n;;\n\
+             EXPLAIN MATCH (a) RETURN a UNION MATCH (b) RETURN b\n",
+        );
+        let statements = parse_cypher_statements(&script).unwrap();
+        let texts: Vec<&str> = statements.iter().map(|(text, _)| *text).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "CALL db.labels()",
+                "MATCH (n) WHERE n.name = 'a;b' RETURN n",
+                "EXPLAIN MATCH (a) RETURN a UNION MATCH (b) RETURN b",
+            ]
+        );
+        assert!(matches!(statements[0].1, CypherStatement::ProcedureCall(_)));
+        assert!(matches!(
+            &statements[2].1,
+            CypherStatement::Query { union_clauses, .. } if union_clauses.len() == 1
+        ));
+
+        assert_eq!(parse_cypher_statements(" ; \n").unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_parse_cypher_statements_requires_separators() {
+        let err = parse_cypher_statements("MATCH (n) RETURN n MATCH (m) RETURN m").unwrap_err();
+        assert_eq!(err.errors[0].1, "Expected ';' between statements");
+
+        let err =
+            parse_cypher_statements("MATCH (n) RETURN n; RETURN GARBAGE GARBAGE").unwrap_err();
+        assert_eq!(err.errors[1].0, "GARBAGE");
+
+        // A lone CALL still rejects trailing tokens after its `;`.
+        assert!(parse_cypher_statement("CALL db.labels(); RETURN 1").is_err());
+    }
+
     #[test]
     fn test_parse_query_with_nom_leftover_still_available_for_legacy_callers() {
         // parse_query_with_nom itself (used internally, and by parse_query's
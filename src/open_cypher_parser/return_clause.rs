@@ -9,18 +9,42 @@ use nom::{
 };
 
 use super::{
-    ast::{ReturnClause, ReturnItem},
-    common::ws,
+    ast::{Expression, ReturnClause, ReturnItem},
+    common::{keyword, ws},
     errors::OpenCypherParsingError,
     expression::{parse_expression, parse_identifier},
 };
 
-fn parse_return_item(input: &'_ str) -> IResult<&'_ str, ReturnItem<'_>> {
-    // Capture the original text of the expression using recognize
-    let (input, expr_text) = recognize(parse_expression).parse(input)?;
+/// `{ .* }` after a variable.
+fn all_properties_projection(input: &str) -> IResult<&str, &str> {
+    recognize((
+        char('{'),
+        multispace0,
+        char('.'),
+        multispace0,
+        char('*'),
+        multispace0,
+        char('}'),
+    ))
+    .parse(input)
+}
 
-    // Parse the expression again to get the AST (recognize consumes but doesn't parse)
-    let (_, expression) = parse_expression.parse(expr_text)?;
+fn parse_return_item(input: &'_ str) -> IResult<&'_ str, ReturnItem<'_>> {
+    // `n { .* }` as a whole item: every property of `n`, past the label's
+    // return projection. Other map projections are expressions.
+    let whole_node: IResult<&str, (&str, &str)> =
+        (ws(parse_identifier), ws(all_properties_projection)).parse(input);
+    let (input, expr_text, expression, all_properties) = match whole_node {
+        Ok((rest, (name, _))) => (rest, name, Expression::Variable(name), true),
+        Err(_) => {
+            // Capture the original text of the expression using recognize
+            let (input, expr_text) = recognize(parse_expression).parse(input)?;
+
+            // Parse the expression again to get the AST (recognize consumes but doesn't parse)
+            let (_, expression) = parse_expression.parse(expr_text)?;
+            (input, expr_text, expression, false)
+        }
+    };
 
     let (input, alias) = opt(preceded(ws(tag_no_case("AS")), ws(parse_identifier))).parse(input)?;
 
@@ -35,6 +59,7 @@ fn parse_return_item(input: &'_ str) -> IResult<&'_ str, ReturnItem<'_>> {
         expression,
         alias,
         original_text,
+        all_properties,
     };
     Ok((input, return_item))
 }
@@ -47,7 +72,7 @@ pub fn parse_return_clause(
     let (input, _) = ws(tag_no_case("RETURN")).parse(input)?;
 
     // Check for optional DISTINCT keyword
-    let (input, distinct) = opt(ws(tag_no_case("DISTINCT"))).parse(input)?;
+    let (input, distinct) = opt(keyword("DISTINCT")).parse(input)?;
     let distinct = distinct.is_some();
 
     let (input, return_items) = context(
@@ -77,8 +102,6 @@ fn return_item_parser(input: &str) -> IResult<&str, ReturnItem<'_>, OpenCypherPa
 
 #[cfg(test)]
 mod tests {
-    use crate::open_cypher_parser::ast::Expression;
-
     use super::*;
     use nom::Err;
 
@@ -93,6 +116,7 @@ mod tests {
                     expression: Expression::Variable("a"),
                     alias: None,
                     original_text: Some("a"),
+                    all_properties: false,
                 };
                 assert_eq!(&return_item, &expected);
             }
@@ -111,6 +135,7 @@ mod tests {
                     expression: Expression::Variable("a"),
                     alias: Some("alias"),
                     original_text: None, // No original_text when explicit alias is provided
+                    all_properties: false,
                 };
                 assert_eq!(&return_item, &expected);
             }
@@ -158,6 +183,7 @@ mod tests {
                     expression: Expression::Variable("a"),
                     alias: None,
                     original_text: Some("a"),
+                    all_properties: false,
                 };
                 assert_eq!(&return_clause.return_items[0], &expected_item);
             }
@@ -177,16 +203,19 @@ mod tests {
                     expression: Expression::Variable("a"),
                     alias: None,
                     original_text: Some("a"),
+                    all_properties: false,
                 };
                 let expected_item2 = ReturnItem {
                     expression: Expression::Variable("b"),
                     alias: Some("aliasB"),
                     original_text: None,
+                    all_properties: false,
                 };
                 let expected_item3 = ReturnItem {
                     expression: Expression::Variable("c"),
                     alias: None,
                     original_text: Some("c"),
+                    all_properties: false,
                 };
                 assert_eq!(&return_clause.return_items[0], &expected_item1);
                 assert_eq!(&return_clause.return_items[1], &expected_item2);
@@ -208,16 +237,19 @@ mod tests {
                     expression: Expression::Variable("a"),
                     alias: Some("a_alias"),
                     original_text: None,
+                    all_properties: false,
                 };
                 let expected_item2 = ReturnItem {
                     expression: Expression::Variable("b"),
                     alias: None,
                     original_text: Some("b"),
+                    all_properties: false,
                 };
                 let expected_item3 = ReturnItem {
                     expression: Expression::Variable("c"),
                     alias: Some("c_alias"),
                     original_text: None,
+                    all_properties: false,
                 };
                 assert_eq!(&return_clause.return_items[0], &expected_item1);
                 assert_eq!(&return_clause.return_items[1], &expected_item2);
@@ -400,4 +432,17 @@ mod tests {
             Err(e) => panic!("Parsing failed unexpectedly: {:?}", e),
         }
     }
+
+    #[test]
+    fn test_parse_all_properties_projection() {
+        let (remaining, clause) = parse_return_clause("RETURN n { .* }, m{.*} AS x, k").unwrap();
+        assert_eq!(remaining, "");
+        let items = &clause.return_items;
+        assert_eq!(items[0].expression, Expression::Variable("n"));
+        assert_eq!(items[0].original_text, Some("n"));
+        assert!(items[0].all_properties);
+        assert_eq!(items[1].alias, Some("x"));
+        assert!(items[1].all_properties);
+        assert!(!items[2].all_properties);
+    }
 }
@@ -1,24 +1,28 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag, tag_no_case, take_while1},
+    bytes::complete::{tag, tag_no_case, take_while, take_while1},
     error::context,
-    sequence::delimited,
+    sequence::{delimited, preceded},
     IResult, Parser,
 };
 
 use super::{ast::UseClause, common::ws, errors::OpenCypherParsingError};
 
-/// Parse a USE clause: USE database_name
+/// Parse a USE clause:
+/// USE database_name [FOR TIMESTAMP 'ts'] [AS OF SYSTEM TIME 'ts'] [VALID AT 'ts']
+/// The time modifiers may come in any order, each at most once.
 /// Examples:
 ///   USE social_network
 ///   USE ecommerce
 ///   USE `my-database`
+///   USE social FOR TIMESTAMP '2024-06-01'
+///   USE ledger AS OF SYSTEM TIME '2024-06-01 12:00:00' VALID AT '2024-01-01'
 pub fn parse_use_clause<'a>(
     input: &'a str,
 ) -> IResult<&'a str, UseClause<'a>, OpenCypherParsingError<'a>> {
     let (input, _) = ws(tag_no_case("USE")).parse(input)?;
 
-    let (input, database_name) = context(
+    let (mut input, database_name) = context(
         "Error parsing database name in USE clause",
         ws(alt((
             // Backtick-quoted identifier: USE `my-database`
@@ -29,11 +33,80 @@ pub fn parse_use_clause<'a>(
     )
     .parse(input)?;
 
-    let use_clause = UseClause { database_name };
+    let mut use_clause = UseClause {
+        database_name,
+        snapshot: None,
+        system_time: None,
+        valid_time: None,
+    };
+
+    // A repeated modifier is left unparsed, and fails the statement
+    loop {
+        if use_clause.snapshot.is_none() {
+            if let Ok((rest, ts)) = context(
+                "Error parsing FOR TIMESTAMP in USE clause",
+                preceded(
+                    (ws(tag_no_case("FOR")), ws(tag_no_case("TIMESTAMP"))),
+                    quoted_timestamp,
+                ),
+            )
+            .parse(input)
+            {
+                use_clause.snapshot = Some(ts);
+                input = rest;
+                continue;
+            }
+        }
+        if use_clause.system_time.is_none() {
+            if let Ok((rest, ts)) = context(
+                "Error parsing AS OF SYSTEM TIME in USE clause",
+                preceded(
+                    (
+                        ws(tag_no_case("AS")),
+                        ws(tag_no_case("OF")),
+                        ws(tag_no_case("SYSTEM")),
+                        ws(tag_no_case("TIME")),
+                    ),
+                    quoted_timestamp,
+                ),
+            )
+            .parse(input)
+            {
+                use_clause.system_time = Some(ts);
+                input = rest;
+                continue;
+            }
+        }
+        if use_clause.valid_time.is_none() {
+            if let Ok((rest, ts)) = context(
+                "Error parsing VALID AT in USE clause",
+                preceded(
+                    (ws(tag_no_case("VALID")), ws(tag_no_case("AT"))),
+                    quoted_timestamp,
+                ),
+            )
+            .parse(input)
+            {
+                use_clause.valid_time = Some(ts);
+                input = rest;
+                continue;
+            }
+        }
+        break;
+    }
 
     Ok((input, use_clause))
 }
 
+/// A single- or double-quoted timestamp literal
+fn quoted_timestamp<'a>(input: &'a str) -> IResult<&'a str, &'a str, OpenCypherParsingError<'a>> {
+    ws(alt((
+        delimited(tag("'"), take_while(|c: char| c != '\''), tag("'")),
+        delimited(tag("\""), take_while(|c: char| c != '"'), tag("\"")),
+    )))
+    .parse(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,6 +196,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_use_clause_for_timestamp() {
+        let input = "USE social FOR TIMESTAMP '2024-06-01' MATCH (n) RETURN n";
+        let (remaining, use_clause) = parse_use_clause(input).unwrap();
+        assert_eq!(use_clause.database_name, "social");
+        assert_eq!(use_clause.snapshot, Some("2024-06-01"));
+        assert_eq!(remaining, "MATCH (n) RETURN n");
+
+        let (_, use_clause) =
+            parse_use_clause("use social for timestamp \"2024-06-01 12:00:00\"").unwrap();
+        assert_eq!(use_clause.snapshot, Some("2024-06-01 12:00:00"));
+
+        let (_, use_clause) = parse_use_clause("USE social").unwrap();
+        assert_eq!(use_clause.snapshot, None);
+    }
+
+    #[test]
+    fn test_parse_use_clause_bitemporal() {
+        let input = "USE ledger VALID AT '2024-01-01' as of system time '2024-06-01 12:00:00' MATCH (n) RETURN n";
+        let (remaining, use_clause) = parse_use_clause(input).unwrap();
+        assert_eq!(use_clause.database_name, "ledger");
+        assert_eq!(use_clause.system_time, Some("2024-06-01 12:00:00"));
+        assert_eq!(use_clause.valid_time, Some("2024-01-01"));
+        assert_eq!(use_clause.snapshot, None);
+        assert_eq!(remaining, "MATCH (n) RETURN n");
+
+        // A repeated modifier is not consumed
+        let (remaining, use_clause) =
+            parse_use_clause("USE ledger VALID AT '2024-01-01' VALID AT '2024-02-01'").unwrap();
+        assert_eq!(use_clause.valid_time, Some("2024-01-01"));
+        assert_eq!(remaining, "VALID AT '2024-02-01'");
+    }
+
     #[test]
     fn test_parse_use_clause_numeric_start() {
         let input = "USE 123db";
@@ -10,7 +10,7 @@ use nom::{
 
 use super::{
     ast::{WithClause, WithItem},
-    common::ws,
+    common::{keyword, ws},
     errors::OpenCypherParsingError,
     expression::{parse_expression, parse_identifier},
     limit_clause::parse_limit_clause,
@@ -54,7 +54,7 @@ pub fn parse_with_clause(
     let (input, _) = ws(tag_no_case("WITH")).parse(input)?;
 
     // Parse optional DISTINCT modifier
-    let (input, distinct) = opt(ws(tag_no_case("DISTINCT"))).parse(input)?;
+    let (input, distinct) = opt(keyword("DISTINCT")).parse(input)?;
     let distinct = distinct.is_some();
 
     // Try parsing `WITH *` (star projection) before attempting regular items.
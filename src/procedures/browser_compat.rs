@@ -61,20 +61,6 @@ fn property_keys(schema: &GraphSchema) -> HashSet<String> {
     keys
 }
 
-/// `db.schema.visualization()` — Neo4j Browser renders this as the schema
-/// diagram. The result is a single record with `nodes` and `relationships`
-/// columns, each a list of graph objects. The procedure layer can only emit
-/// plain JSON (not Bolt Node/Relationship structures), so we return empty lists
-/// here: a valid shape that stops the FAILURE cascade. (A richer virtual-graph
-/// implementation belongs in the Bolt handler, where graph objects can be
-/// packstream-encoded.)
-pub fn db_schema_visualization(_schema: &GraphSchema) -> ProcedureResult {
-    Ok(vec![HashMap::from([
-        ("nodes".to_string(), serde_json::json!([])),
-        ("relationships".to_string(), serde_json::json!([])),
-    ])])
-}
-
 /// `db.indexes()` — ClickGraph manages no indexes; empty (valid) result.
 pub fn db_indexes(_schema: &GraphSchema) -> ProcedureResult {
     Ok(vec![])
@@ -131,14 +117,6 @@ mod tests {
         GraphSchema::build(1, "test".to_string(), HashMap::new(), HashMap::new())
     }
 
-    #[test]
-    fn schema_visualization_returns_valid_empty_shape() {
-        let r = db_schema_visualization(&empty_schema()).expect("ok");
-        assert_eq!(r.len(), 1);
-        assert!(r[0].get("nodes").unwrap().is_array());
-        assert!(r[0].get("relationships").unwrap().is_array());
-    }
-
     #[test]
     fn indexes_and_license_are_empty() {
         assert!(db_indexes(&empty_schema()).expect("ok").is_empty());
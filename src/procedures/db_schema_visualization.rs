@@ -0,0 +1,280 @@
+//! db.schema.visualization() procedure - Returns the schema as a virtual graph
+//!
+//! Neo4j compatible procedure that Neo4j Browser calls on connect. It renders
+//! the result as the schema diagram and derives its default per-label styling
+//! (colours and captions) from the labels and types it returns.
+//!
+//! The virtual graph has one node per label and one relationship per distinct
+//! (type, from-label, to-label) triple. [`schema_graph`] builds it from the
+//! graph catalog; [`execute`] returns it as JSON for HTTP, and the Bolt handler
+//! packstream-encodes the same graph as Node and Relationship structures.
+
+use crate::graph_catalog::graph_schema::GraphSchema;
+use crate::procedures::ProcedureResult;
+use std::collections::{HashMap, HashSet};
+
+/// A label of the schema graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaNode {
+    pub id: i64,
+    pub label: String,
+}
+
+/// A (type, from-label, to-label) triple of the schema graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaRelationship {
+    pub id: i64,
+    pub rel_type: String,
+    pub start: i64,
+    pub end: i64,
+}
+
+/// The virtual graph returned by `db.schema.visualization()`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaGraph {
+    pub nodes: Vec<SchemaNode>,
+    pub relationships: Vec<SchemaRelationship>,
+}
+
+fn node_element_id(id: i64) -> String {
+    format!("schema-node-{}", id)
+}
+
+impl SchemaNode {
+    pub fn element_id(&self) -> String {
+        node_element_id(self.id)
+    }
+
+    /// Properties as Neo4j reports them; `name` drives the Browser's caption.
+    pub fn properties(&self) -> HashMap<String, serde_json::Value> {
+        HashMap::from([
+            ("name".to_string(), serde_json::json!(self.label)),
+            ("indexes".to_string(), serde_json::json!([])),
+            ("constraints".to_string(), serde_json::json!([])),
+        ])
+    }
+}
+
+impl SchemaRelationship {
+    pub fn element_id(&self) -> String {
+        format!("schema-rel-{}", self.id)
+    }
+
+    pub fn start_element_id(&self) -> String {
+        node_element_id(self.start)
+    }
+
+    pub fn end_element_id(&self) -> String {
+        node_element_id(self.end)
+    }
+}
+
+/// Build the schema graph: labels in name order, then relationships in
+/// (type, from-label, to-label) order. Relationships whose endpoints are not
+/// labels of the schema (polymorphic `$any` endpoints) are left out.
+pub fn schema_graph(schema: &GraphSchema) -> SchemaGraph {
+    let mut labels: Vec<&str> = schema
+        .all_node_schemas()
+        .keys()
+        .map(|k| k.rsplit("::").next().unwrap_or(k))
+        .collect();
+    labels.sort();
+    labels.dedup();
+    let nodes: Vec<SchemaNode> = labels
+        .iter()
+        .enumerate()
+        .map(|(i, label)| SchemaNode {
+            id: i as i64,
+            label: label.to_string(),
+        })
+        .collect();
+    let id_of: HashMap<&str, i64> = nodes.iter().map(|n| (n.label.as_str(), n.id)).collect();
+
+    let mut triples: Vec<(&str, i64, i64)> = Vec::new();
+    let mut seen = HashSet::new();
+    for (key, rel) in schema.get_relationships_schemas() {
+        let rel_type = key.split("::").next().unwrap_or(key);
+        if rel_type.is_empty() {
+            continue;
+        }
+        let (Some(&start), Some(&end)) = (
+            id_of.get(rel.from_node.as_str()),
+            id_of.get(rel.to_node.as_str()),
+        ) else {
+            continue;
+        };
+        if seen.insert((rel_type, start, end)) {
+            triples.push((rel_type, start, end));
+        }
+    }
+    triples.sort();
+    let relationships = triples
+        .into_iter()
+        .enumerate()
+        .map(|(i, (rel_type, start, end))| SchemaRelationship {
+            id: i as i64,
+            rel_type: rel_type.to_string(),
+            start,
+            end,
+        })
+        .collect();
+
+    SchemaGraph {
+        nodes,
+        relationships,
+    }
+}
+
+/// Execute db.schema.visualization() procedure
+///
+/// Returns one record with `nodes` and `relationships` columns.
+///
+/// # Example Response
+/// ```json
+/// [{
+///   "nodes": [
+///     {"id": 0, "elementId": "schema-node-0", "labels": ["User"],
+///      "properties": {"name": "User", "indexes": [], "constraints": []}}
+///   ],
+///   "relationships": [
+///     {"id": 0, "elementId": "schema-rel-0", "type": "FOLLOWS",
+///      "startNodeElementId": "schema-node-0", "endNodeElementId": "schema-node-0",
+///      "properties": {}}
+///   ]
+/// }]
+/// ```
+pub fn execute(schema: &GraphSchema) -> ProcedureResult {
+    let graph = schema_graph(schema);
+    let nodes: Vec<serde_json::Value> = graph
+        .nodes
+        .iter()
+        .map(|node| {
+            serde_json::json!({
+                "id": node.id,
+                "elementId": node.element_id(),
+                "labels": [node.label],
+                "properties": node.properties(),
+            })
+        })
+        .collect();
+    let relationships: Vec<serde_json::Value> = graph
+        .relationships
+        .iter()
+        .map(|rel| {
+            serde_json::json!({
+                "id": rel.id,
+                "elementId": rel.element_id(),
+                "type": rel.rel_type,
+                "startNodeElementId": rel.start_element_id(),
+                "endNodeElementId": rel.end_element_id(),
+                "properties": {},
+            })
+        })
+        .collect();
+
+    Ok(vec![HashMap::from([
+        ("nodes".to_string(), serde_json::json!(nodes)),
+        (
+            "relationships".to_string(),
+            serde_json::json!(relationships),
+        ),
+    ])])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    const SCHEMA_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: prod
+      table: users
+      node_id: user_id
+      property_mappings:
+        name: full_name
+    - label: Post
+      database: prod
+      table: posts
+      node_id: post_id
+      property_mappings:
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: prod
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+    - type: LIKED
+      database: prod
+      table: user_likes
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+    - type: LIKED
+      database: prod
+      table: post_likes
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+"#;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_one_node_per_label_and_one_relationship_per_triple() {
+        let graph = schema_graph(&schema());
+        let labels: Vec<&str> = graph.nodes.iter().map(|n| n.label.as_str()).collect();
+        assert_eq!(labels, vec!["Post", "User"]);
+
+        let triples: Vec<(&str, &str, &str)> = graph
+            .relationships
+            .iter()
+            .map(|r| {
+                (
+                    r.rel_type.as_str(),
+                    labels[r.start as usize],
+                    labels[r.end as usize],
+                )
+            })
+            .collect();
+        assert_eq!(
+            triples,
+            vec![("FOLLOWS", "User", "User"), ("LIKED", "User", "Post")]
+        );
+    }
+
+    #[test]
+    fn test_execute_response_format() {
+        let results = execute(&schema()).expect("Should succeed");
+        assert_eq!(results.len(), 1);
+        let nodes = results[0]["nodes"].as_array().unwrap();
+        assert_eq!(nodes[1]["labels"], serde_json::json!(["User"]));
+        assert_eq!(nodes[1]["properties"]["name"], "User");
+
+        let rels = results[0]["relationships"].as_array().unwrap();
+        assert_eq!(rels[0]["type"], "FOLLOWS");
+        assert_eq!(rels[0]["startNodeElementId"], nodes[1]["elementId"]);
+        assert_eq!(rels[1]["endNodeElementId"], nodes[0]["elementId"]);
+    }
+
+    #[test]
+    fn test_empty_schema() {
+        let schema = GraphSchema::build(1, "test".to_string(), HashMap::new(), HashMap::new());
+        let results = execute(&schema).expect("Should succeed");
+        assert_eq!(results[0]["nodes"], serde_json::json!([]));
+        assert_eq!(results[0]["relationships"], serde_json::json!([]));
+    }
+}
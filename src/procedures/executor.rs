@@ -303,7 +303,7 @@ pub async fn execute_procedure_union_with_return(
 /// Returns true if the query:
 /// - Has a call_clause
 /// - Has NO match_clauses, optional_match_clauses, or reading_clauses
-/// - Has NO create/set/delete/remove clauses
+/// - Has NO create/merge/set/delete/remove clauses
 /// - May have RETURN, WITH, WHERE, UNWIND (these project/filter procedure results)
 ///
 /// This is used to determine if a Query AST should be executed as a procedure
@@ -316,6 +316,8 @@ pub fn is_procedure_only_query(
         && query.optional_match_clauses.is_empty()
         && query.reading_clauses.is_empty()
         && query.create_clause.is_none()
+        && query.foreach_clause.is_none()
+        && query.merge_clause.is_none()
         && query.set_clause.is_none()
         && query.delete_clause.is_none()
         && query.remove_clause.is_none()
@@ -0,0 +1,331 @@
+//! Connected components over one node label.
+//!
+//! Implements `graph.wcc(label, relTypes, config)` (weakly connected) and
+//! `graph.scc(label, relTypes, config)` (strongly connected): a single query
+//! whose CTE chain unrolls label propagation, one CTE per round, and returns
+//! each node id with the id of its component.
+//!
+//! ```cypher
+//! CALL graph.wcc('Account', 'TRANSFERRED', {iterations: 20})
+//! CALL graph.scc('Account', ['TRANSFERRED', 'PAID'])
+//! ```
+//!
+//! | Config key | Default | |
+//! |------------|---------|---|
+//! | `iterations` (`maxIterations`) | 20 | 1 to [`MAX_ITERATIONS`](super::graph_page_rank::MAX_ITERATIONS) rounds |
+//!
+//! A component's id is the smallest node id in it. Labels travel one hop per
+//! round, so a component whose nodes are more than `iterations` hops apart is
+//! reported as several smaller components — never merged with another one.
+//!
+//! - **WCC**: every node starts labelled with its own id and takes the
+//!   smallest label among itself and its neighbours, ignoring direction.
+//! - **SCC**: every node collects the set of its ancestors (nodes with a path
+//!   to it). Two nodes share a component when each is the other's ancestor,
+//!   so a node's component id is its smallest ancestor that it also reaches.
+//!
+//! Same subgraph rules as `graph.pageRank`: relationships between two `label`
+//! nodes only, and a single id column.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use super::graph_page_rank::{
+    extract_iterations, extract_rel_types, extract_string, label_subgraph_ctes, DEFAULT_ITERATIONS,
+};
+use crate::graph_catalog::graph_schema::GraphSchema;
+use crate::open_cypher_parser::ast::Expression;
+
+/// Weakly connected components procedure name (matched case-insensitively).
+pub const WCC_PROCEDURE_NAME: &str = "graph.wcc";
+
+/// Strongly connected components procedure name (matched case-insensitively).
+pub const SCC_PROCEDURE_NAME: &str = "graph.scc";
+
+/// Which connectivity a components call computes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentKind {
+    /// Connected when ignoring edge direction
+    Weak,
+    /// Connected by directed paths both ways
+    Strong,
+}
+
+impl ComponentKind {
+    pub fn procedure_name(self) -> &'static str {
+        match self {
+            ComponentKind::Weak => WCC_PROCEDURE_NAME,
+            ComponentKind::Strong => SCC_PROCEDURE_NAME,
+        }
+    }
+}
+
+/// The component kind a procedure name asks for, if it is `graph.wcc` or
+/// `graph.scc`.
+pub fn component_kind(name: &str) -> Option<ComponentKind> {
+    [ComponentKind::Weak, ComponentKind::Strong]
+        .into_iter()
+        .find(|kind| name.eq_ignore_ascii_case(kind.procedure_name()))
+}
+
+/// Parsed `graph.wcc` / `graph.scc` arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentsCall {
+    pub kind: ComponentKind,
+    pub label: String,
+    pub rel_types: Vec<String>,
+    pub iterations: u32,
+}
+
+/// Parse `graph.wcc(label, relTypes [, config])` / `graph.scc(...)` arguments.
+pub fn parse_components_call(
+    kind: ComponentKind,
+    args: &[&Expression<'_>],
+) -> Result<ComponentsCall, String> {
+    let procedure = kind.procedure_name();
+    let (label, rel_types, config) = match args {
+        [label, rel_types] => (label, rel_types, None),
+        [label, rel_types, config] => (label, rel_types, Some(config)),
+        _ => {
+            return Err(format!(
+                "{} requires 2 or 3 arguments (label, relTypes, config), got {}",
+                procedure,
+                args.len()
+            ))
+        }
+    };
+
+    let mut call = ComponentsCall {
+        kind,
+        label: extract_string(label, procedure, "the label")?,
+        rel_types: extract_rel_types(rel_types, procedure)?,
+        iterations: DEFAULT_ITERATIONS,
+    };
+
+    let entries = match config {
+        None => &[][..],
+        Some(Expression::MapLiteral(entries)) => entries.as_slice(),
+        Some(_) => return Err(format!("{}: the config must be a map", procedure)),
+    };
+    for (key, value) in entries {
+        match key.to_lowercase().as_str() {
+            "iterations" | "maxiterations" => {
+                call.iterations = extract_iterations(value, procedure)?
+            }
+            other => {
+                return Err(format!(
+                    "{}: unknown config key `{}` (expected iterations)",
+                    procedure, other
+                ))
+            }
+        }
+    }
+    Ok(call)
+}
+
+/// Build the components query.
+///
+/// ```text
+/// WCC                                    SCC
+/// wcc_adj  node -> [node, neighbours]    scc_adj  node -> [node, successors]
+/// wcc_0    own id                        scc_0    [own id]
+/// wcc_k    min label pushed to a node    scc_k    union of ancestor sets pushed to a node
+/// ```
+///
+/// Each round reads the previous one once (ClickHouse inlines every CTE
+/// reference), by pushing each node's label to the targets in its
+/// adjacency list and aggregating per target.
+pub fn build_components_sql(schema: &GraphSchema, call: &ComponentsCall) -> Result<String, String> {
+    let (prefix, reverse_edges) = match call.kind {
+        ComponentKind::Weak => ("wcc", " UNION ALL SELECT dst, src FROM wcc_edges"),
+        ComponentKind::Strong => ("scc", ""),
+    };
+    let [nodes, edges] = label_subgraph_ctes(
+        schema,
+        call.kind.procedure_name(),
+        &call.label,
+        &call.rel_types,
+        prefix,
+        None,
+    )?;
+    let mut ctes = vec![
+        nodes,
+        edges,
+        format!(
+            "{p}_adj AS (SELECT node_id, groupArray(target) AS targets FROM \
+             (SELECT node_id, node_id AS target FROM {p}_nodes \
+             UNION ALL SELECT src, dst FROM {p}_edges{reverse_edges}) GROUP BY node_id)",
+            p = prefix,
+            reverse_edges = reverse_edges,
+        ),
+    ];
+
+    let n = call.iterations;
+    match call.kind {
+        ComponentKind::Weak => {
+            ctes.push("wcc_0 AS (SELECT node_id, node_id AS label FROM wcc_nodes)".to_string());
+            for k in 1..=n {
+                ctes.push(format!(
+                    "wcc_{k} AS (SELECT node_id, min(label) AS label FROM \
+                     (SELECT arrayJoin(a.targets) AS node_id, c.label AS label \
+                     FROM wcc_{prev} AS c INNER JOIN wcc_adj AS a ON a.node_id = c.node_id) \
+                     GROUP BY node_id)",
+                    k = k,
+                    prev = k - 1,
+                ));
+            }
+            Ok(format!(
+                "WITH {}\nSELECT node_id AS nodeId, label AS componentId FROM wcc_{} \
+                 ORDER BY componentId, nodeId",
+                ctes.join(",\n"),
+                n
+            ))
+        }
+        ComponentKind::Strong => {
+            ctes.push(
+                "scc_0 AS (SELECT node_id, [node_id] AS ancestors FROM scc_nodes)".to_string(),
+            );
+            for k in 1..=n {
+                ctes.push(format!(
+                    "scc_{k} AS (SELECT node_id, groupUniqArrayArray(ancestors) AS ancestors FROM \
+                     (SELECT arrayJoin(a.targets) AS node_id, c.ancestors AS ancestors \
+                     FROM scc_{prev} AS c INNER JOIN scc_adj AS a ON a.node_id = c.node_id) \
+                     GROUP BY node_id)",
+                    k = k,
+                    prev = k - 1,
+                ));
+            }
+            Ok(format!(
+                "WITH {}\nSELECT a.node_id AS nodeId, min(a.ancestor) AS componentId \
+                 FROM (SELECT node_id, arrayJoin(ancestors) AS ancestor FROM scc_{n}) AS a \
+                 INNER JOIN scc_{n} AS b ON b.node_id = a.ancestor \
+                 WHERE has(b.ancestors, a.node_id) \
+                 GROUP BY a.node_id ORDER BY componentId, nodeId",
+                ctes.join(",\n"),
+                n = n
+            ))
+        }
+    }
+}
+
+/// One result record: `nodeId` and `componentId` as returned.
+pub fn component_record(row: &Value) -> HashMap<String, Value> {
+    ["nodeId", "componentId"]
+        .into_iter()
+        .map(|column| {
+            (
+                column.to_string(),
+                row.get(column).cloned().unwrap_or(Value::Null),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+    use crate::open_cypher_parser::ast::CypherStatement;
+    use serde_json::json;
+
+    fn parse(call: &str) -> Result<ComponentsCall, String> {
+        let (_, stmt) = crate::open_cypher_parser::parse_cypher_statement(call).unwrap();
+        let CypherStatement::ProcedureCall(pc) = stmt else {
+            panic!("expected a procedure call");
+        };
+        let kind = component_kind(pc.procedure_name).expect("components procedure");
+        let args: Vec<&Expression> = pc.arguments.iter().collect();
+        parse_components_call(kind, &args)
+    }
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_file("benchmarks/social_network/schemas/social_benchmark.yaml")
+            .unwrap()
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_kinds_and_config() {
+        let call = parse("CALL graph.WCC('User', 'FOLLOWS')").unwrap();
+        assert_eq!(call.kind, ComponentKind::Weak);
+        assert_eq!(call.iterations, DEFAULT_ITERATIONS);
+
+        let call = parse("CALL graph.scc('User', ['FOLLOWS'], {maxIterations: 4})").unwrap();
+        assert_eq!(call.kind, ComponentKind::Strong);
+        assert_eq!(call.rel_types, vec!["FOLLOWS"]);
+        assert_eq!(call.iterations, 4);
+
+        assert_eq!(component_kind("graph.pageRank"), None);
+        for (call, expected) in [
+            (
+                "CALL graph.wcc('User')",
+                "graph.wcc requires 2 or 3 arguments",
+            ),
+            (
+                "CALL graph.scc('User', 'FOLLOWS', {iterations: 0})",
+                "graph.scc: iterations",
+            ),
+            (
+                "CALL graph.wcc('User', 'FOLLOWS', {damping: 0.5})",
+                "unknown config key",
+            ),
+        ] {
+            let err = parse(call).unwrap_err();
+            assert!(err.contains(expected), "{call}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_wcc_sql_propagates_over_both_directions() {
+        let call = parse("CALL graph.wcc('User', 'FOLLOWS', {iterations: 3})").unwrap();
+        let sql = build_components_sql(&schema(), &call).unwrap();
+        assert!(
+            sql.contains(
+                "UNION ALL SELECT src, dst FROM wcc_edges UNION ALL SELECT dst, src FROM wcc_edges"
+            ),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("FROM wcc_2 AS c INNER JOIN wcc_adj AS a ON a.node_id = c.node_id"),
+            "{sql}"
+        );
+        assert!(!sql.contains("wcc_4"), "{sql}");
+        assert!(
+            sql.ends_with("FROM wcc_3 ORDER BY componentId, nodeId"),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn test_scc_sql_matches_mutual_ancestors() {
+        let call = parse("CALL graph.scc('User', 'FOLLOWS', {iterations: 2})").unwrap();
+        let sql = build_components_sql(&schema(), &call).unwrap();
+        assert!(!sql.contains("SELECT dst, src"), "{sql}");
+        assert!(sql.contains("groupUniqArrayArray(ancestors)"), "{sql}");
+        assert!(
+            sql.contains(
+                "INNER JOIN scc_2 AS b ON b.node_id = a.ancestor WHERE has(b.ancestors, a.node_id)"
+            ),
+            "{sql}"
+        );
+
+        let err = build_components_sql(
+            &schema(),
+            &parse("CALL graph.scc('User', 'AUTHORED')").unwrap(),
+        )
+        .unwrap_err();
+        assert!(
+            err.contains("graph.scc: relationship type `AUTHORED` does not connect"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_record_keeps_ids() {
+        let record = component_record(&json!({"nodeId": 7, "componentId": 2}));
+        assert_eq!(record["nodeId"], json!(7));
+        assert_eq!(record["componentId"], json!(2));
+    }
+}
@@ -0,0 +1,679 @@
+//! Graph construction from query results.
+//!
+//! Implements `graph.construct(cypher, mapping)`: runs a read query and
+//! writes each result row into node and relationship tables, so a derived
+//! graph (e.g. a co-occurrence graph built from shared-session patterns) can
+//! be materialised and then queried like any other.
+//!
+//! ```cypher
+//! CALL graph.construct(
+//!   'MATCH (a:User)-[:FOLLOWS]->(x:User)<-[:FOLLOWS]-(b:User)
+//!    WHERE a.user_id < b.user_id
+//!    RETURN a.user_id AS src, b.user_id AS dst, count(*) AS shared',
+//!   {
+//!     relationships: [{type: 'CO_FOLLOWS', from: 'src', to: 'dst',
+//!                      properties: {weight: 'shared'}}],
+//!     batch_size: 100000
+//!   })
+//! ```
+//!
+//! # Mapping templates
+//!
+//! Each entry of `nodes` / `relationships` maps result fields (RETURN
+//! aliases) onto one target table:
+//!
+//! | Template | Target table and columns |
+//! |----------|--------------------------|
+//! | `{label, properties: {prop: field}}` | the label's table; properties resolved through the schema, the node id must be mapped |
+//! | `{type, from, to, properties}` | the relationship type's table; `from` / `to` fill its `from_id` / `to_id` columns |
+//! | `{table: 'db.t', columns: {col: field}}` | an explicit table and columns, in a database the schema reads |
+//!
+//! Node targets insert `DISTINCT` rows so a node shared by many result rows
+//! is written once.
+//!
+//! # Execution Flow
+//!
+//! Like `apoc.export.*`, this bypasses `ProcedureRegistry`:
+//! 1. Handler detects `graph.construct` → [`parse_construct_call`]
+//! 2. [`ConstructSpec::resolve`] turns templates into [`ConstructTarget`]s
+//! 3. Caller translates the inner Cypher → SQL via the normal pipeline
+//! 4. [`build_construct_sql`] emits one `INSERT INTO ... SELECT` per target,
+//!    node tables first; the caller runs them in order and stops at the
+//!    first failure
+
+use crate::graph_catalog::expression_parser::PropertyValue;
+use crate::graph_catalog::graph_schema::GraphSchema;
+use crate::open_cypher_parser::ast::{CypherStatement, Expression, Literal};
+
+/// Procedure name (matched case-insensitively).
+pub const PROCEDURE_NAME: &str = "graph.construct";
+
+/// Default rows per inserted block.
+pub const DEFAULT_BATCH_SIZE: u64 = 100_000;
+
+/// Alias of the inner query inside each generated INSERT.
+const SOURCE_ALIAS: &str = "__construct";
+
+/// Check whether a procedure name is `graph.construct`.
+pub fn is_construct_procedure(name: &str) -> bool {
+    name.eq_ignore_ascii_case(PROCEDURE_NAME)
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// Mapping templates
+// ───────────────────────────────────────────────────────────────────────
+
+/// How one template names its target table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateTarget {
+    /// A node label of the schema.
+    Label(String),
+    /// A relationship type of the schema, with the fields for its endpoints.
+    RelType {
+        rel_type: String,
+        from: String,
+        to: String,
+    },
+    /// An explicit `database.table`.
+    Table(String),
+}
+
+/// One entry of the `nodes` / `relationships` lists.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingTemplate {
+    pub target: TemplateTarget,
+    /// `(property or column, result field)` pairs, in declaration order.
+    pub fields: Vec<(String, String)>,
+    /// Insert distinct rows (node templates).
+    pub distinct: bool,
+}
+
+/// Parsed `graph.construct(cypher, mapping)` arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstructSpec {
+    pub cypher_query: String,
+    pub templates: Vec<MappingTemplate>,
+    pub batch_size: u64,
+}
+
+/// A template resolved against the schema: what to insert where.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstructTarget {
+    /// `node:Label`, `relationship:TYPE` or `table:db.t`, for reporting.
+    pub name: String,
+    pub database: String,
+    pub table: String,
+    /// `(column, result field)` pairs.
+    pub columns: Vec<(String, String)>,
+    pub distinct: bool,
+}
+
+impl ConstructTarget {
+    /// `database.table`
+    pub fn table_name(&self) -> String {
+        format!("{}.{}", self.database, self.table)
+    }
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// Argument extraction
+// ───────────────────────────────────────────────────────────────────────
+
+fn extract_string(expr: &Expression<'_>, what: &str) -> Result<String, String> {
+    match expr {
+        Expression::Literal(Literal::String(s)) => Ok(s.to_string()),
+        _ => Err(format!(
+            "graph.construct: {} must be a string literal",
+            what
+        )),
+    }
+}
+
+fn extract_map<'e, 'a>(
+    expr: &'e Expression<'a>,
+    what: &str,
+) -> Result<&'e [(&'a str, Expression<'a>)], String> {
+    match expr {
+        Expression::MapLiteral(entries) => Ok(entries),
+        _ => Err(format!("graph.construct: {} must be a map", what)),
+    }
+}
+
+/// `{key: 'field', ...}` → `[(key, field)]`
+fn extract_field_map(expr: &Expression<'_>, what: &str) -> Result<Vec<(String, String)>, String> {
+    extract_map(expr, what)?
+        .iter()
+        .map(|(key, value)| {
+            Ok((
+                key.to_string(),
+                extract_string(value, &format!("{}.{}", what, key))?,
+            ))
+        })
+        .collect()
+}
+
+fn parse_template(expr: &Expression<'_>, is_node: bool) -> Result<MappingTemplate, String> {
+    let kind = if is_node { "nodes" } else { "relationships" };
+    let entries = extract_map(expr, &format!("each entry of `{}`", kind))?;
+
+    let mut label = None;
+    let mut rel_type = None;
+    let mut table = None;
+    let mut from = None;
+    let mut to = None;
+    let mut fields = Vec::new();
+    for (key, value) in entries {
+        match key.to_lowercase().as_str() {
+            "label" if is_node => label = Some(extract_string(value, "label")?),
+            "type" if !is_node => rel_type = Some(extract_string(value, "type")?),
+            "table" => table = Some(extract_string(value, "table")?),
+            "from" if !is_node => from = Some(extract_string(value, "from")?),
+            "to" if !is_node => to = Some(extract_string(value, "to")?),
+            "properties" | "columns" => fields.extend(extract_field_map(value, key)?),
+            other => {
+                return Err(format!(
+                    "graph.construct: unknown key `{}` in a `{}` template",
+                    other, kind
+                ))
+            }
+        }
+    }
+
+    let target = match (label, rel_type, table) {
+        (Some(label), None, None) => TemplateTarget::Label(label),
+        (None, Some(rel_type), None) => TemplateTarget::RelType {
+            rel_type,
+            from: from.ok_or("graph.construct: relationship template needs `from`")?,
+            to: to.ok_or("graph.construct: relationship template needs `to`")?,
+        },
+        (None, None, Some(table)) => {
+            if from.is_some() || to.is_some() {
+                return Err(
+                    "graph.construct: `from` / `to` apply to `type` templates; map the \
+                     endpoint columns under `columns` for a `table` template"
+                        .to_string(),
+                );
+            }
+            TemplateTarget::Table(table)
+        }
+        _ => {
+            return Err(format!(
+                "graph.construct: each `{}` template needs exactly one of {}",
+                kind,
+                if is_node {
+                    "`label` or `table`"
+                } else {
+                    "`type` or `table`"
+                }
+            ))
+        }
+    };
+
+    if fields.is_empty() && !matches!(target, TemplateTarget::RelType { .. }) {
+        return Err(format!(
+            "graph.construct: a `{}` template must map at least one field",
+            kind
+        ));
+    }
+
+    Ok(MappingTemplate {
+        target,
+        fields,
+        distinct: is_node,
+    })
+}
+
+fn parse_template_list(
+    expr: &Expression<'_>,
+    is_node: bool,
+) -> Result<Vec<MappingTemplate>, String> {
+    match expr {
+        Expression::List(items) => items.iter().map(|t| parse_template(t, is_node)).collect(),
+        single @ Expression::MapLiteral(_) => Ok(vec![parse_template(single, is_node)?]),
+        _ => Err(format!(
+            "graph.construct: `{}` must be a list of templates",
+            if is_node { "nodes" } else { "relationships" }
+        )),
+    }
+}
+
+/// Parse `graph.construct(cypher, mapping)` arguments.
+pub fn parse_construct_call(args: &[&Expression<'_>]) -> Result<ConstructSpec, String> {
+    let [query, mapping] = args else {
+        return Err(format!(
+            "graph.construct requires 2 arguments (cypher, mapping), got {}",
+            args.len()
+        ));
+    };
+    let cypher_query = extract_string(query, "the query")?;
+
+    let mut templates = Vec::new();
+    let mut relationship_templates = Vec::new();
+    let mut batch_size = DEFAULT_BATCH_SIZE;
+    for (key, value) in extract_map(mapping, "the mapping")? {
+        match key.to_lowercase().as_str() {
+            "nodes" => templates.extend(parse_template_list(value, true)?),
+            "relationships" => relationship_templates.extend(parse_template_list(value, false)?),
+            "batch_size" => {
+                batch_size = match value {
+                    Expression::Literal(Literal::Integer(n)) if *n > 0 => *n as u64,
+                    _ => {
+                        return Err(
+                            "graph.construct: batch_size must be a positive integer".to_string()
+                        )
+                    }
+                }
+            }
+            other => {
+                return Err(format!(
+                    "graph.construct: unknown mapping key `{}` (expected nodes, relationships, batch_size)",
+                    other
+                ))
+            }
+        }
+    }
+    // Nodes are written before the relationships that reference them.
+    templates.extend(relationship_templates);
+    if templates.is_empty() {
+        return Err(
+            "graph.construct: the mapping needs at least one `nodes` or `relationships` template"
+                .to_string(),
+        );
+    }
+
+    Ok(ConstructSpec {
+        cypher_query,
+        templates,
+        batch_size,
+    })
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// Resolution
+// ───────────────────────────────────────────────────────────────────────
+
+/// Result field names of the inner query: RETURN aliases, or the expression
+/// text for un-aliased items.
+pub fn result_fields(cypher: &str) -> Result<Vec<String>, String> {
+    let (_, stmt) = crate::open_cypher_parser::parse_cypher_statement(cypher)
+        .map_err(|e| format!("Inner Cypher parse error: {}", e))?;
+    let CypherStatement::Query { query, .. } = stmt else {
+        return Err("graph.construct: the inner statement must be a read query".to_string());
+    };
+    let return_clause = query
+        .return_clause
+        .as_ref()
+        .ok_or("graph.construct: the inner query must end with RETURN")?;
+    Ok(return_clause
+        .return_items
+        .iter()
+        .filter_map(|item| item.alias.or(item.original_text))
+        .map(str::to_string)
+        .collect())
+}
+
+fn is_identifier(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn writable_column(
+    mapping: Option<&PropertyValue>,
+    column_names: &[String],
+    key: &str,
+) -> Option<String> {
+    match mapping {
+        Some(PropertyValue::Column(c)) => Some(c.clone()),
+        Some(PropertyValue::Expression(_)) => None,
+        None => column_names.iter().find(|c| *c == key).cloned(),
+    }
+}
+
+impl ConstructSpec {
+    /// Resolve every template against `schema`, checking that each mapped
+    /// field is one of `fields` (the inner query's result columns).
+    pub fn resolve(
+        &self,
+        schema: &GraphSchema,
+        fields: &[String],
+    ) -> Result<Vec<ConstructTarget>, String> {
+        self.templates
+            .iter()
+            .map(|t| resolve_template(t, schema, fields))
+            .collect()
+    }
+}
+
+fn resolve_template(
+    template: &MappingTemplate,
+    schema: &GraphSchema,
+    fields: &[String],
+) -> Result<ConstructTarget, String> {
+    let (name, database, table, mut columns) = match &template.target {
+        TemplateTarget::Label(label) => {
+            let node = schema
+                .node_schema_opt(label)
+                .ok_or_else(|| format!("graph.construct: unknown node label `{}`", label))?;
+            let id_column = node.node_id.column_or_error().map_err(|e| {
+                format!(
+                    "graph.construct: node `{}` has a composite id ({})",
+                    label, e
+                )
+            })?;
+            let mut columns = Vec::new();
+            for (property, field) in &template.fields {
+                let column = writable_column(
+                    node.property_mappings.get(property),
+                    &node.column_names,
+                    property,
+                )
+                .ok_or_else(|| {
+                    format!(
+                        "graph.construct: property `{}` of `{}` cannot be mapped to a writable column",
+                        property, label
+                    )
+                })?;
+                columns.push((column, field.clone()));
+            }
+            if !columns.iter().any(|(c, _)| c == id_column) {
+                return Err(format!(
+                    "graph.construct: the `{}` template must map the node id (column `{}`)",
+                    label, id_column
+                ));
+            }
+            (
+                format!("node:{}", label),
+                node.database.clone(),
+                node.table_name.clone(),
+                columns,
+            )
+        }
+        TemplateTarget::RelType { rel_type, from, to } => {
+            let rel = match schema.rel_schemas_for_type(rel_type).as_slice() {
+                [rel] => *rel,
+                [] => {
+                    return Err(format!(
+                        "graph.construct: unknown relationship type `{}`",
+                        rel_type
+                    ))
+                }
+                _ => {
+                    return Err(format!(
+                        "graph.construct: relationship type `{}` maps to several tables; \
+                         use a `table` template",
+                        rel_type
+                    ))
+                }
+            };
+            let endpoint = |id: &crate::graph_catalog::config::Identifier| {
+                id.as_single().map(str::to_string).map_err(|e| {
+                    format!(
+                        "graph.construct: relationship `{}` has a composite endpoint ({})",
+                        rel_type, e
+                    )
+                })
+            };
+            let mut columns = vec![
+                (endpoint(&rel.from_id)?, from.clone()),
+                (endpoint(&rel.to_id)?, to.clone()),
+            ];
+            for (property, field) in &template.fields {
+                let column = writable_column(
+                    rel.property_mappings.get(property),
+                    &rel.column_names,
+                    property,
+                )
+                .ok_or_else(|| {
+                    format!(
+                        "graph.construct: property `{}` of `{}` cannot be mapped to a writable column",
+                        property, rel_type
+                    )
+                })?;
+                columns.push((column, field.clone()));
+            }
+            (
+                format!("relationship:{}", rel_type),
+                rel.database.clone(),
+                rel.table_name.clone(),
+                columns,
+            )
+        }
+        TemplateTarget::Table(qualified) => {
+            let (database, table) = qualified
+                .split_once('.')
+                .filter(|(d, t)| is_identifier(d) && is_identifier(t))
+                .ok_or_else(|| {
+                    format!(
+                        "graph.construct: table `{}` must be written as database.table",
+                        qualified
+                    )
+                })?;
+            // Only the graph's own databases, so a pinned or tenant graph
+            // cannot write into another's tables
+            let databases: std::collections::BTreeSet<&str> = schema
+                .all_node_schemas()
+                .values()
+                .map(|n| n.database.as_str())
+                .chain(
+                    schema
+                        .get_relationships_schemas()
+                        .values()
+                        .map(|r| r.database.as_str()),
+                )
+                .collect();
+            if !databases.contains(database) {
+                return Err(format!(
+                    "graph.construct: table `{}` is outside the graph's databases ({})",
+                    qualified,
+                    databases.into_iter().collect::<Vec<_>>().join(", ")
+                ));
+            }
+            if let Some((column, _)) = template.fields.iter().find(|(c, _)| !is_identifier(c)) {
+                return Err(format!("graph.construct: invalid column name `{}`", column));
+            }
+            (
+                format!("table:{}", qualified),
+                database.to_string(),
+                table.to_string(),
+                template.fields.clone(),
+            )
+        }
+    };
+
+    if let Some((_, field)) = columns.iter().find(|(_, f)| !fields.contains(f)) {
+        return Err(format!(
+            "graph.construct: `{}` maps field `{}`, which the query does not return (returns: {})",
+            name,
+            field,
+            fields.join(", ")
+        ));
+    }
+    // The same column mapped twice would make an invalid INSERT.
+    columns.dedup();
+    let mut seen = std::collections::HashSet::new();
+    if let Some((column, _)) = columns.iter().find(|(c, _)| !seen.insert(c.clone())) {
+        return Err(format!(
+            "graph.construct: column `{}` of `{}` is mapped more than once",
+            column, name
+        ));
+    }
+
+    Ok(ConstructTarget {
+        name,
+        database,
+        table,
+        columns,
+        distinct: template.distinct,
+    })
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// SQL
+// ───────────────────────────────────────────────────────────────────────
+
+fn quote_identifier(s: &str) -> String {
+    format!("`{}`", s.replace('\\', "\\\\").replace('`', "\\`"))
+}
+
+/// `INSERT INTO db.table (cols) SETTINGS ... SELECT [DISTINCT] fields FROM (inner)`
+///
+/// `batch_size` bounds the rows read per block and squashes inserted blocks
+/// up to that many rows, so each target is written in parts of roughly
+/// `batch_size` rows instead of one part per source block.
+pub fn build_construct_sql(inner_sql: &str, target: &ConstructTarget, batch_size: u64) -> String {
+    let columns: Vec<String> = target
+        .columns
+        .iter()
+        .map(|(c, _)| quote_identifier(c))
+        .collect();
+    let fields: Vec<String> = target
+        .columns
+        .iter()
+        .map(|(_, f)| format!("{}.{}", SOURCE_ALIAS, quote_identifier(f)))
+        .collect();
+    format!(
+        "INSERT INTO {}.{} ({}) SETTINGS max_block_size = {}, min_insert_block_size_rows = {} \
+         SELECT {}{} FROM ({}) AS {}",
+        quote_identifier(&target.database),
+        quote_identifier(&target.table),
+        columns.join(", "),
+        batch_size,
+        batch_size,
+        if target.distinct { "DISTINCT " } else { "" },
+        fields.join(", "),
+        inner_sql.trim().trim_end_matches(';'),
+        SOURCE_ALIAS,
+    )
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// Tests
+// ───────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    fn parse(call: &str) -> Result<ConstructSpec, String> {
+        let (_, stmt) = crate::open_cypher_parser::parse_cypher_statement(call).unwrap();
+        let CypherStatement::ProcedureCall(pc) = stmt else {
+            panic!("expected a procedure call");
+        };
+        assert!(is_construct_procedure(pc.procedure_name));
+        let args: Vec<&Expression> = pc.arguments.iter().collect();
+        parse_construct_call(&args)
+    }
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_file("benchmarks/social_network/schemas/social_benchmark.yaml")
+            .unwrap()
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_templates_orders_nodes_first() {
+        let spec = parse(
+            "CALL graph.construct('MATCH (u:User) RETURN u.user_id AS id', {
+               relationships: [{type: 'FOLLOWS', from: 'id', to: 'id'}],
+               nodes: [{label: 'User', properties: {user_id: 'id'}}],
+               batch_size: 500})",
+        )
+        .unwrap();
+        assert_eq!(spec.batch_size, 500);
+        assert_eq!(
+            spec.templates[0].target,
+            TemplateTarget::Label("User".into())
+        );
+        assert!(spec.templates[0].distinct);
+        assert!(matches!(
+            spec.templates[1].target,
+            TemplateTarget::RelType { .. }
+        ));
+        assert!(!spec.templates[1].distinct);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_mappings() {
+        for call in [
+            "CALL graph.construct('MATCH (u:User) RETURN u')",
+            "CALL graph.construct('MATCH (u:User) RETURN u', {})",
+            "CALL graph.construct('MATCH (u:User) RETURN u', {nodes: [{properties: {a: 'b'}}]})",
+            "CALL graph.construct('MATCH (u:User) RETURN u', {relationships: [{type: 'T', from: 'a'}]})",
+            "CALL graph.construct('MATCH (u:User) RETURN u', {nodes: [{label: 'User'}], batch_size: 0})",
+            "CALL graph.construct('MATCH (u:User) RETURN u', {edges: []})",
+        ] {
+            assert!(parse(call).is_err(), "{call}");
+        }
+    }
+
+    #[test]
+    fn test_resolve_and_build_sql() {
+        let query = "MATCH (a:User)-[:FOLLOWS]->(b:User) RETURN a.user_id AS src, b.user_id AS dst, a.name AS name";
+        let spec = parse(&format!(
+            "CALL graph.construct('{query}', {{
+               nodes: [{{label: 'User', properties: {{user_id: 'src', name: 'name'}}}}],
+               relationships: [{{type: 'FOLLOWS', from: 'src', to: 'dst'}},
+                               {{table: 'social.pairs', columns: {{a: 'src', b: 'dst'}}}}]}})"
+        ))
+        .unwrap();
+        let fields = result_fields(&spec.cypher_query).unwrap();
+        assert_eq!(fields, ["src", "dst", "name"]);
+
+        let targets = spec.resolve(&schema(), &fields).unwrap();
+        let names: Vec<_> = targets.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["node:User", "relationship:FOLLOWS", "table:social.pairs"]
+        );
+        // `name` is mapped to `full_name` by the schema.
+        assert!(targets[0]
+            .columns
+            .contains(&("full_name".to_string(), "name".to_string())));
+
+        let sql = build_construct_sql("SELECT 1 AS src", &targets[2], 1000);
+        assert_eq!(
+            sql,
+            "INSERT INTO `social`.`pairs` (`a`, `b`) SETTINGS max_block_size = 1000, \
+             min_insert_block_size_rows = 1000 SELECT __construct.`src`, __construct.`dst` \
+             FROM (SELECT 1 AS src) AS __construct"
+        );
+        assert!(build_construct_sql("SELECT 1", &targets[0], 10).contains("SELECT DISTINCT "));
+    }
+
+    #[test]
+    fn test_resolve_rejects_unreturned_fields_and_missing_ids() {
+        let schema = schema();
+        let fields = vec!["src".to_string()];
+        let resolve = |call: &str| parse(call).unwrap().resolve(&schema, &fields);
+
+        let err = resolve(
+            "CALL graph.construct('q', {relationships: [{type: 'FOLLOWS', from: 'src', to: 'dst'}]})",
+        )
+        .unwrap_err();
+        assert!(err.contains("`dst`"), "{err}");
+
+        let err = resolve(
+            "CALL graph.construct('q', {nodes: [{label: 'User', properties: {name: 'src'}}]})",
+        )
+        .unwrap_err();
+        assert!(err.contains("node id"), "{err}");
+
+        assert!(resolve(
+            "CALL graph.construct('q', {nodes: [{table: 'no_database', columns: {a: 'src'}}]})"
+        )
+        .is_err());
+        assert!(resolve(
+            "CALL graph.construct('q', {nodes: [{label: 'Nope', properties: {id: 'src'}}]})"
+        )
+        .is_err());
+        let err = resolve(
+            "CALL graph.construct('q', {nodes: [{table: 'derived.pairs', columns: {a: 'src'}}]})",
+        )
+        .unwrap_err();
+        assert!(
+            err.contains("outside the graph's databases (social)"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,700 @@
+//! Partitioned export of the whole logical graph.
+//!
+//! Implements `graph.exportGraph(config)`: writes every node label and
+//! relationship type of the schema to object storage as Parquet, one
+//! directory per label or type, each split into hash partitions of the id,
+//! plus a `manifest.json` describing the files, for offline analytics and
+//! backups of the graph.
+//!
+//! ```cypher
+//! CALL graph.exportGraph({destination: 's3://bucket/graphs/social', partitions: 16})
+//! ```
+//!
+//! | Config key | Default | Meaning |
+//! |------------|---------|---------|
+//! | `destination` | required | `s3://`, `gs://` or local directory |
+//! | `partitions` | 8 | Files per label or type |
+//! | `parallelism` | 4 | `INSERT` statements run at once |
+//! | `compression` | ClickHouse default | Parquet codec |
+//! | `overwrite` | false | Replace files that already exist |
+//!
+//! Files are laid out as `<destination>/nodes/<Label>/part-00000.parquet` and
+//! `<destination>/relationships/<TYPE>/part-00000.parquet`. Node files hold
+//! `_id` and every mapped property; relationship files hold `_from`, `_to`
+//! and every mapped property, with the logical property names as columns.
+//! Partition `k` of `n` holds the rows with `cityHash64(id) % n = k` (the
+//! source id for relationships), so every id range of the hash lands in
+//! exactly one file. Label filters and discriminators apply as in queries.
+//!
+//! Nodes without a table of their own (denormalized or polymorphic endpoints)
+//! are skipped: their ids are the `_from` / `_to` of the relationship files.
+//! Parameterized views are skipped too.
+//!
+//! # Execution Flow
+//!
+//! Like `graph.sampleSubgraph`, this bypasses `ProcedureRegistry`:
+//! 1. Handler detects `graph.exportGraph` → [`parse_export_graph_call`]
+//! 2. [`build_export_plan`] emits one `INSERT INTO FUNCTION` per file; the
+//!    caller runs [`ExportPlan::files`] `parallelism` at a time
+//! 3. [`count_sql`] reads the row count of every partition, and
+//!    [`manifest_sql`] writes the [`manifest`] next to the files
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde_json::Value;
+
+use crate::clickhouse_query_generator::quote_identifier;
+use crate::graph_catalog::config::Identifier;
+use crate::graph_catalog::expression_parser::PropertyValue;
+use crate::graph_catalog::graph_schema::GraphSchema;
+use crate::open_cypher_parser::ast::{Expression, Literal};
+use crate::procedures::apoc_export::{build_export_sql, resolve_destination, ExportConfig};
+use crate::procedures::graph_quality::{
+    node_conditions, relationship_conditions, sql_string, where_clause,
+};
+
+/// Procedure name (matched case-insensitively).
+pub const PROCEDURE_NAME: &str = "graph.exportGraph";
+
+/// Default files per label or type.
+pub const DEFAULT_PARTITIONS: u32 = 8;
+
+/// Default statements run at once.
+pub const DEFAULT_PARALLELISM: usize = 4;
+
+/// Upper bounds, to keep a typo from issuing thousands of statements.
+const MAX_PARTITIONS: u32 = 1024;
+const MAX_PARALLELISM: usize = 64;
+
+/// Name of the manifest written next to the files.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Alias of the source table in each statement.
+const TABLE_ALIAS: &str = "t";
+
+/// Check whether a procedure name is `graph.exportGraph`.
+pub fn is_export_graph_procedure(name: &str) -> bool {
+    name.eq_ignore_ascii_case(PROCEDURE_NAME)
+}
+
+/// Parsed `graph.exportGraph(config)` arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportGraphCall {
+    /// Base URI, without a trailing `/`
+    pub destination: String,
+    pub partitions: u32,
+    pub parallelism: usize,
+    pub compression: Option<String>,
+    pub overwrite: bool,
+}
+
+/// Parse `graph.exportGraph(config)` arguments.
+pub fn parse_export_graph_call(args: &[&Expression<'_>]) -> Result<ExportGraphCall, String> {
+    let entries = match args {
+        [Expression::MapLiteral(entries)] => entries,
+        [] | [_] => {
+            return Err(
+                "graph.exportGraph takes a config map with at least a `destination`".to_string(),
+            )
+        }
+        _ => {
+            return Err(format!(
+                "graph.exportGraph takes 1 argument (config), got {}",
+                args.len()
+            ))
+        }
+    };
+    let mut destination = None;
+    let mut call = ExportGraphCall {
+        destination: String::new(),
+        partitions: DEFAULT_PARTITIONS,
+        parallelism: DEFAULT_PARALLELISM,
+        compression: None,
+        overwrite: false,
+    };
+    for (key, value) in entries {
+        match (key.to_lowercase().as_str(), value) {
+            ("destination", Expression::Literal(Literal::String(uri))) => {
+                destination = Some(uri.trim_end_matches('/').to_string())
+            }
+            ("partitions", Expression::Literal(Literal::Integer(n)))
+                if (1..=MAX_PARTITIONS as i64).contains(n) =>
+            {
+                call.partitions = *n as u32
+            }
+            ("parallelism", Expression::Literal(Literal::Integer(n)))
+                if (1..=MAX_PARALLELISM as i64).contains(n) =>
+            {
+                call.parallelism = *n as usize
+            }
+            ("compression", Expression::Literal(Literal::String(codec))) => {
+                call.compression = Some(codec.to_string())
+            }
+            ("overwrite", Expression::Literal(Literal::Boolean(b))) => call.overwrite = *b,
+            ("destination" | "compression", _) => {
+                return Err(format!("graph.exportGraph: {} must be a string", key))
+            }
+            ("partitions", _) => {
+                return Err(format!(
+                    "graph.exportGraph: partitions must be an integer from 1 to {}",
+                    MAX_PARTITIONS
+                ))
+            }
+            ("parallelism", _) => {
+                return Err(format!(
+                    "graph.exportGraph: parallelism must be an integer from 1 to {}",
+                    MAX_PARALLELISM
+                ))
+            }
+            ("overwrite", _) => {
+                return Err("graph.exportGraph: overwrite must be a boolean".to_string())
+            }
+            _ => {
+                return Err(format!(
+                    "graph.exportGraph: unknown config key `{}` (expected destination, \
+                     partitions, parallelism, compression, overwrite)",
+                    key
+                ))
+            }
+        }
+    }
+    call.destination = destination
+        .filter(|d| !d.is_empty())
+        .ok_or("graph.exportGraph: `destination` is required")?;
+    if !(call.destination.starts_with("s3://")
+        || call.destination.starts_with("gs://")
+        || !call.destination.contains("://")
+        || call.destination.starts_with("file://"))
+    {
+        return Err(format!(
+            "graph.exportGraph: unsupported destination '{}' (expected s3://, gs:// or a local directory)",
+            call.destination
+        ));
+    }
+    Ok(call)
+}
+
+/// One exported label or relationship mapping.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportElement {
+    /// `node` or `relationship`
+    pub kind: &'static str,
+    /// Label, type, or `TYPE::From::To` for a type mapped more than once
+    pub name: String,
+    /// `database.table` of the source
+    pub source: String,
+    /// Output columns, in file order
+    pub columns: Vec<String>,
+    /// Directory of its files, relative to the destination
+    pub directory: String,
+    /// SQL expression partitions are hashed on
+    key: String,
+    /// ` WHERE ...` selecting the element's rows, or nothing
+    conditions: String,
+}
+
+/// One written file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportFile {
+    /// Index into [`ExportPlan::elements`]
+    pub element: usize,
+    pub partition: u32,
+    /// Path relative to the destination
+    pub path: String,
+    /// `INSERT INTO FUNCTION ... SELECT`
+    pub sql: String,
+}
+
+/// Every statement of one export run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportPlan {
+    pub destination: String,
+    pub partitions: u32,
+    pub elements: Vec<ExportElement>,
+    pub files: Vec<ExportFile>,
+    /// Elements left out, with the reason
+    pub skipped: Vec<String>,
+}
+
+/// `alias.a` for one column, `(alias.a, alias.b)` for several.
+fn key_expr(columns: &[String]) -> String {
+    let cols: Vec<String> = columns
+        .iter()
+        .map(|c| format!("{}.{}", TABLE_ALIAS, quote_identifier(c)))
+        .collect();
+    if cols.len() == 1 {
+        cols[0].clone()
+    } else {
+        format!("({})", cols.join(", "))
+    }
+}
+
+/// Property columns, sorted by property name.
+fn property_columns(mappings: &HashMap<String, PropertyValue>) -> Vec<(String, String)> {
+    let mut properties: Vec<(String, String)> = mappings
+        .iter()
+        .map(|(name, value)| (name.clone(), value.to_sql(TABLE_ALIAS)))
+        .collect();
+    properties.sort();
+    properties
+}
+
+/// A directory name that is safe in a URI path.
+fn path_segment(name: &str) -> String {
+    name.replace("::", "__")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// `SELECT ... FROM ... WHERE ...` of one element, before partitioning.
+fn select_sql(columns: &[(String, String)], source: &str) -> String {
+    format!(
+        "SELECT {} FROM {} AS {TABLE_ALIAS}",
+        columns
+            .iter()
+            .map(|(name, expr)| format!("{} AS `{}`", expr, name.replace('`', "\\`")))
+            .collect::<Vec<_>>()
+            .join(", "),
+        source
+    )
+}
+
+/// ` SETTINGS` replacing existing files, for `overwrite`.
+const OVERWRITE_SETTINGS: &str =
+    " SETTINGS s3_truncate_on_insert = 1, engine_file_truncate_on_insert = 1";
+
+/// Plan the export of every node label and relationship type of `schema`.
+pub fn build_export_plan(
+    schema: &GraphSchema,
+    call: &ExportGraphCall,
+) -> Result<ExportPlan, String> {
+    let mut skipped = Vec::new();
+    // (element, output columns with their expressions)
+    let mut elements: Vec<(ExportElement, Vec<(String, String)>)> = Vec::new();
+
+    // Node keys may be qualified ("db::table::Label"); export each label once.
+    let mut seen = BTreeSet::new();
+    for (key, node) in schema.all_node_schemas() {
+        let label = key.rsplit("::").next().unwrap_or(key);
+        if !seen.insert((label.to_string(), node.full_table_name())) {
+            continue;
+        }
+        if node.view_parameters.is_some() {
+            skipped.push(format!(
+                "node:{} (parameterized view {})",
+                label,
+                node.full_table_name()
+            ));
+            continue;
+        }
+        if !node.has_standalone_table() {
+            skipped.push(format!(
+                "node:{} (no table of its own; its ids are in the relationship files)",
+                label
+            ));
+            continue;
+        }
+        let id = key_expr(&node.id_physical_columns());
+        let mut columns = vec![("_id".to_string(), id.clone())];
+        columns.extend(property_columns(&node.property_mappings));
+        elements.push((
+            ExportElement {
+                kind: "node",
+                name: label.to_string(),
+                source: node.full_table_name(),
+                columns: columns.iter().map(|(c, _)| c.clone()).collect(),
+                directory: format!("nodes/{}", path_segment(label)),
+                key: id,
+                conditions: where_clause(&node_conditions(node, TABLE_ALIAS)?),
+            },
+            columns,
+        ));
+    }
+
+    // A type mapped for several endpoint pairs gets one directory per pair,
+    // named by its composite key ("TYPE::From::To").
+    for (rel_type, keys) in schema.get_rel_type_index() {
+        for key in keys {
+            let Some(rel) = schema.get_relationships_schemas().get(key) else {
+                continue;
+            };
+            let name = if keys.len() == 1 { rel_type } else { key };
+            if rel.view_parameters.is_some() {
+                skipped.push(format!(
+                    "relationship:{} (parameterized view {})",
+                    name,
+                    rel.full_table_name()
+                ));
+                continue;
+            }
+            let ids = |id: &Identifier| {
+                key_expr(
+                    &id.columns()
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>(),
+                )
+            };
+            let from = ids(&rel.from_id);
+            let mut columns = vec![
+                ("_from".to_string(), from.clone()),
+                ("_to".to_string(), ids(&rel.to_id)),
+            ];
+            columns.extend(property_columns(&rel.property_mappings));
+            elements.push((
+                ExportElement {
+                    kind: "relationship",
+                    name: name.to_string(),
+                    source: rel.full_table_name(),
+                    columns: columns.iter().map(|(c, _)| c.clone()).collect(),
+                    directory: format!("relationships/{}", path_segment(name)),
+                    key: from,
+                    conditions: where_clause(&relationship_conditions(rel, rel_type, TABLE_ALIAS)?),
+                },
+                columns,
+            ));
+        }
+    }
+
+    if elements.is_empty() {
+        return Err("graph.exportGraph: the schema maps no table that can be exported".into());
+    }
+    let mut directories = HashMap::new();
+    for (element, _) in &elements {
+        if let Some(other) = directories.insert(element.directory.clone(), &element.name) {
+            return Err(format!(
+                "graph.exportGraph: {} and {} would both be written to {}/",
+                other, element.name, element.directory
+            ));
+        }
+    }
+
+    let config = ExportConfig {
+        compression: call.compression.clone(),
+    };
+    let mut files = Vec::new();
+    for (index, (element, columns)) in elements.iter().enumerate() {
+        let select = select_sql(columns, &element.source);
+        for partition in 0..call.partitions {
+            let mut filter = element.conditions.clone();
+            if call.partitions > 1 {
+                filter.push_str(if filter.is_empty() {
+                    " WHERE "
+                } else {
+                    " AND "
+                });
+                filter.push_str(&format!(
+                    "cityHash64({}) % {} = {}",
+                    element.key, call.partitions, partition
+                ));
+            }
+            let path = format!("{}/part-{:05}.parquet", element.directory, partition);
+            let mut sql = build_export_sql(
+                &format!("{select}{filter}"),
+                &format!("{}/{}", call.destination, path),
+                "Parquet",
+                &config,
+            )?;
+            if call.overwrite {
+                sql.push_str(OVERWRITE_SETTINGS);
+            }
+            files.push(ExportFile {
+                element: index,
+                partition,
+                path,
+                sql,
+            });
+        }
+    }
+
+    Ok(ExportPlan {
+        destination: call.destination.clone(),
+        partitions: call.partitions,
+        elements: elements.into_iter().map(|(e, _)| e).collect(),
+        files,
+        skipped,
+    })
+}
+
+/// One query reading the row count of every partition of every element.
+pub fn count_sql(plan: &ExportPlan) -> String {
+    plan.elements
+        .iter()
+        .enumerate()
+        .map(|(index, e)| {
+            format!(
+                "SELECT {index} AS element, toUInt32(cityHash64({}) % {}) AS partition, \
+                 count() AS rows FROM {} AS {TABLE_ALIAS}{} GROUP BY partition",
+                e.key, plan.partitions, e.source, e.conditions
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" UNION ALL ")
+}
+
+/// The manifest: format, partitioning, and every element with its columns
+/// and files, each with its row count from the [`count_sql`] rows.
+pub fn manifest(plan: &ExportPlan, schema_name: &str, rows: &[Value]) -> Value {
+    let number = |v: Option<&Value>| match v {
+        Some(Value::Number(n)) => n.as_u64(),
+        Some(Value::String(s)) => s.parse().ok(),
+        _ => None,
+    };
+    let mut counts: HashMap<(u64, u64), u64> = HashMap::new();
+    for row in rows {
+        if let (Some(element), Some(partition), Some(n)) = (
+            number(row.get("element")),
+            number(row.get("partition")),
+            number(row.get("rows")),
+        ) {
+            *counts.entry((element, partition)).or_default() += n;
+        }
+    }
+    let elements: Vec<Value> = plan
+        .elements
+        .iter()
+        .enumerate()
+        .map(|(index, e)| {
+            let files: Vec<Value> = plan
+                .files
+                .iter()
+                .filter(|f| f.element == index)
+                .map(|f| {
+                    serde_json::json!({
+                        "path": f.path,
+                        "partition": f.partition,
+                        "rows": counts
+                            .get(&(index as u64, f.partition as u64))
+                            .copied()
+                            .unwrap_or(0),
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "kind": e.kind,
+                "name": e.name,
+                "source": e.source,
+                "columns": e.columns,
+                "rows": files.iter().filter_map(|f| f["rows"].as_u64()).sum::<u64>(),
+                "files": files,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "schema": schema_name,
+        "format": "Parquet",
+        "partitions": plan.partitions,
+        "partition_key": "cityHash64(id) % partitions",
+        "elements": elements,
+        "skipped": plan.skipped,
+    })
+}
+
+/// `INSERT INTO FUNCTION` writing `manifest` to `<destination>/manifest.json`.
+pub fn manifest_sql(
+    plan: &ExportPlan,
+    manifest: &Value,
+    overwrite: bool,
+) -> Result<String, String> {
+    let mut sql = format!(
+        "{} SELECT {}",
+        resolve_destination(
+            &format!("{}/{}", plan.destination, MANIFEST_FILE),
+            "RawBLOB"
+        )?,
+        sql_string(&serde_json::to_string_pretty(manifest).map_err(|e| e.to_string())?)
+    );
+    if overwrite {
+        sql.push_str(OVERWRITE_SETTINGS);
+    }
+    Ok(sql)
+}
+
+/// The procedure's record: where the export went, its totals and manifest.
+pub fn export_record(plan: &ExportPlan, manifest: Value) -> HashMap<String, Value> {
+    let rows: u64 = manifest["elements"]
+        .as_array()
+        .map(|elements| elements.iter().filter_map(|e| e["rows"].as_u64()).sum())
+        .unwrap_or(0);
+    HashMap::from([
+        (
+            "destination".to_string(),
+            Value::from(plan.destination.clone()),
+        ),
+        (
+            "manifest_path".to_string(),
+            Value::from(format!("{}/{}", plan.destination, MANIFEST_FILE)),
+        ),
+        ("files".to_string(), Value::from(plan.files.len())),
+        ("rows".to_string(), Value::from(rows)),
+        ("manifest".to_string(), manifest),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+    use crate::open_cypher_parser::ast::CypherStatement;
+
+    const SCHEMA_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: prod
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+    - label: Post
+      database: prod
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+  edges:
+    - type: FOLLOWS
+      database: prod
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings:
+        since: follow_date
+"#;
+
+    fn parse(call: &str) -> Result<ExportGraphCall, String> {
+        let (_, stmt) = crate::open_cypher_parser::parse_cypher_statement(call).unwrap();
+        let CypherStatement::ProcedureCall(pc) = stmt else {
+            panic!("expected a procedure call");
+        };
+        assert!(is_export_graph_procedure(pc.procedure_name));
+        let args: Vec<&Expression> = pc.arguments.iter().collect();
+        parse_export_graph_call(&args)
+    }
+
+    fn export_plan(call: &str) -> ExportPlan {
+        let schema = GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .unwrap();
+        build_export_plan(&schema, &parse(call).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let call = parse("CALL graph.exportGraph({destination: 's3://bucket/graph/'})").unwrap();
+        assert_eq!(call.destination, "s3://bucket/graph");
+        assert_eq!(
+            (call.partitions, call.parallelism, call.overwrite),
+            (DEFAULT_PARTITIONS, DEFAULT_PARALLELISM, false)
+        );
+        let call = parse(
+            "CALL graph.exportGraph({destination: 'gs://b/g', partitions: 2, parallelism: 8, \
+             compression: 'zstd', overwrite: true})",
+        )
+        .unwrap();
+        assert_eq!((call.partitions, call.parallelism), (2, 8));
+        assert_eq!(call.compression.as_deref(), Some("zstd"));
+        assert!(call.overwrite);
+
+        for bad in [
+            "CALL graph.exportGraph()",
+            "CALL graph.exportGraph({partitions: 4})",
+            "CALL graph.exportGraph({destination: 'https://host/x'})",
+            "CALL graph.exportGraph({destination: 's3://b', partitions: 0})",
+            "CALL graph.exportGraph({destination: 's3://b', parallelism: 1000})",
+            "CALL graph.exportGraph({destination: 's3://b', format: 'csv'})",
+        ] {
+            assert!(parse(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_plan_writes_one_file_per_partition() {
+        let plan = export_plan(
+            "CALL graph.exportGraph({destination: 's3://bucket/g', partitions: 2, \
+             compression: 'zstd'})",
+        );
+        let paths: Vec<&str> = plan.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "nodes/Post/part-00000.parquet",
+                "nodes/Post/part-00001.parquet",
+                "nodes/User/part-00000.parquet",
+                "nodes/User/part-00001.parquet",
+                "relationships/FOLLOWS/part-00000.parquet",
+                "relationships/FOLLOWS/part-00001.parquet",
+            ]
+        );
+        assert_eq!(plan.elements[1].columns, ["_id", "name", "user_id"]);
+        assert_eq!(
+            plan.files[3].sql,
+            "INSERT INTO FUNCTION s3('s3://bucket/g/nodes/User/part-00001.parquet', 'Parquet') \
+             SETTINGS output_format_parquet_compression_method = 'zstd' \
+             SELECT t.user_id AS `_id`, t.full_name AS `name`, t.user_id AS `user_id` \
+             FROM prod.users AS t WHERE cityHash64(t.user_id) % 2 = 1"
+        );
+        assert_eq!(
+            plan.files[4].sql,
+            "INSERT INTO FUNCTION s3('s3://bucket/g/relationships/FOLLOWS/part-00000.parquet', 'Parquet') \
+             SETTINGS output_format_parquet_compression_method = 'zstd' \
+             SELECT t.follower_id AS `_from`, t.followed_id AS `_to`, t.follow_date AS `since` \
+             FROM prod.follows AS t WHERE cityHash64(t.follower_id) % 2 = 0"
+        );
+
+        let single = export_plan(
+            "CALL graph.exportGraph({destination: '/tmp/g', partitions: 1, overwrite: true})",
+        );
+        assert_eq!(single.files.len(), 3);
+        assert!(
+            single.files[0].sql.ends_with(
+                "FROM prod.posts AS t SETTINGS s3_truncate_on_insert = 1, \
+                 engine_file_truncate_on_insert = 1"
+            ),
+            "{}",
+            single.files[0].sql
+        );
+    }
+
+    #[test]
+    fn test_manifest_counts_rows_per_file() {
+        let plan =
+            export_plan("CALL graph.exportGraph({destination: 's3://bucket/g', partitions: 2})");
+        assert!(count_sql(&plan).starts_with(
+            "SELECT 0 AS element, toUInt32(cityHash64(t.post_id) % 2) AS partition, \
+             count() AS rows FROM prod.posts AS t GROUP BY partition UNION ALL"
+        ));
+        let rows = [
+            serde_json::json!({"element": 1, "partition": 0, "rows": "3"}),
+            serde_json::json!({"element": 1, "partition": 1, "rows": "4"}),
+            serde_json::json!({"element": 2, "partition": 1, "rows": 10}),
+        ];
+        let manifest = manifest(&plan, "social", &rows);
+        assert_eq!(manifest["elements"][1]["name"], "User");
+        assert_eq!(manifest["elements"][1]["rows"], 7);
+        assert_eq!(manifest["elements"][1]["files"][1]["rows"], 4);
+        assert_eq!(manifest["elements"][0]["rows"], 0);
+        assert_eq!(manifest["elements"][2]["kind"], "relationship");
+
+        let sql = manifest_sql(&plan, &manifest, false).unwrap();
+        assert!(sql.starts_with(
+            "INSERT INTO FUNCTION s3('s3://bucket/g/manifest.json', 'RawBLOB') SELECT '{"
+        ));
+        let record = export_record(&plan, manifest);
+        assert_eq!(record["rows"], 17);
+        assert_eq!(record["files"], 6);
+        assert_eq!(record["manifest_path"], "s3://bucket/g/manifest.json");
+    }
+}
@@ -0,0 +1,513 @@
+//! PageRank over one node label.
+//!
+//! Implements `graph.pageRank(label, relTypes, config)`: a single query whose
+//! CTE chain unrolls the power iteration, one CTE per round, and returns each
+//! node id with its score.
+//!
+//! ```cypher
+//! CALL graph.pageRank('User', 'FOLLOWS', {iterations: 20, dampingFactor: 0.85})
+//! CALL graph.pageRank('User', ['FOLLOWS', 'FRIENDS_WITH'])
+//! ```
+//!
+//! | Config key | Default | |
+//! |------------|---------|---|
+//! | `iterations` (`maxIterations`) | 20 | 1 to [`MAX_ITERATIONS`] rounds |
+//! | `dampingFactor` (`damping`) | 0.85 | in `[0, 1)` |
+//!
+//! Only relationships whose both endpoints are `label` take part, and the
+//! label needs a table of its own with a single id column. Rank held by nodes
+//! without outgoing edges is not redistributed, so scores may sum to less
+//! than 1.
+//!
+//! # Execution Flow
+//!
+//! Like `graph.quality`, this bypasses `ProcedureRegistry`:
+//! 1. Handler detects `graph.pageRank` → [`parse_page_rank_call`]
+//! 2. [`build_page_rank_sql`] renders the iteration chain
+//! 3. Caller runs the SQL; [`page_rank_record`] shapes each row
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use super::graph_quality::{node_conditions, relationship_conditions, where_clause};
+use crate::clickhouse_query_generator::quote_identifier;
+use crate::graph_catalog::graph_schema::{GraphSchema, RelationshipSchema};
+use crate::open_cypher_parser::ast::{Expression, Literal};
+
+/// Procedure name (matched case-insensitively).
+pub const PROCEDURE_NAME: &str = "graph.pageRank";
+
+/// Rounds run when the config does not say.
+pub const DEFAULT_ITERATIONS: u32 = 20;
+
+/// Upper bound on rounds: every round nests one more subquery, and
+/// ClickHouse caps nesting (`max_subquery_depth`).
+pub const MAX_ITERATIONS: u32 = 40;
+
+/// Damping factor used when the config does not say.
+pub const DEFAULT_DAMPING_FACTOR: f64 = 0.85;
+
+/// Alias of the scanned table in the node and edge CTEs.
+const TABLE_ALIAS: &str = "t";
+
+/// The weight of the edges of one relationship table, as SQL over the table
+/// alias: called with the relationship type, its schema and the alias.
+pub(crate) type EdgeWeight<'a> =
+    &'a dyn Fn(&str, &RelationshipSchema, &str) -> Result<String, String>;
+
+/// Check whether a procedure name is `graph.pageRank`.
+pub fn is_page_rank_procedure(name: &str) -> bool {
+    name.eq_ignore_ascii_case(PROCEDURE_NAME)
+}
+
+/// Parsed `graph.pageRank` arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRankCall {
+    pub label: String,
+    pub rel_types: Vec<String>,
+    pub iterations: u32,
+    pub damping_factor: f64,
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// Argument extraction
+// ───────────────────────────────────────────────────────────────────────
+
+pub(crate) fn extract_string(
+    expr: &Expression<'_>,
+    procedure: &str,
+    what: &str,
+) -> Result<String, String> {
+    match expr {
+        Expression::Literal(Literal::String(s)) => Ok(s.to_string()),
+        _ => Err(format!("{}: {} must be a string literal", procedure, what)),
+    }
+}
+
+/// `'TYPE'` or `['A', 'B']`.
+pub(crate) fn extract_rel_types(
+    expr: &Expression<'_>,
+    procedure: &str,
+) -> Result<Vec<String>, String> {
+    let types = match expr {
+        Expression::List(items) => items
+            .iter()
+            .map(|item| extract_string(item, procedure, "each relationship type"))
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => vec![extract_string(
+            expr,
+            procedure,
+            "the relationship type (or a list of them)",
+        )?],
+    };
+    if types.is_empty() {
+        return Err(format!(
+            "{}: the relationship type list is empty",
+            procedure
+        ));
+    }
+    Ok(types)
+}
+
+/// A round count from 1 to [`MAX_ITERATIONS`].
+pub(crate) fn extract_iterations(expr: &Expression<'_>, procedure: &str) -> Result<u32, String> {
+    match expr {
+        Expression::Literal(Literal::Integer(n)) if (1..=MAX_ITERATIONS as i64).contains(n) => {
+            Ok(*n as u32)
+        }
+        _ => Err(format!(
+            "{}: iterations must be an integer from 1 to {}",
+            procedure, MAX_ITERATIONS
+        )),
+    }
+}
+
+fn extract_number(expr: &Expression<'_>) -> Option<f64> {
+    match expr {
+        Expression::Literal(Literal::Integer(n)) => Some(*n as f64),
+        Expression::Literal(Literal::Float(f)) => Some(*f),
+        _ => None,
+    }
+}
+
+/// Parse `graph.pageRank(label, relTypes [, config])` arguments.
+pub fn parse_page_rank_call(args: &[&Expression<'_>]) -> Result<PageRankCall, String> {
+    let (label, rel_types, config) = match args {
+        [label, rel_types] => (label, rel_types, None),
+        [label, rel_types, config] => (label, rel_types, Some(config)),
+        _ => {
+            return Err(format!(
+                "graph.pageRank requires 2 or 3 arguments (label, relTypes, config), got {}",
+                args.len()
+            ))
+        }
+    };
+
+    let mut call = PageRankCall {
+        label: extract_string(label, PROCEDURE_NAME, "the label")?,
+        rel_types: extract_rel_types(rel_types, PROCEDURE_NAME)?,
+        iterations: DEFAULT_ITERATIONS,
+        damping_factor: DEFAULT_DAMPING_FACTOR,
+    };
+
+    let entries = match config {
+        None => &[][..],
+        Some(Expression::MapLiteral(entries)) => entries.as_slice(),
+        Some(_) => return Err("graph.pageRank: the config must be a map".to_string()),
+    };
+    for (key, value) in entries {
+        match key.to_lowercase().as_str() {
+            "iterations" | "maxiterations" => {
+                call.iterations = extract_iterations(value, PROCEDURE_NAME)?
+            }
+            "dampingfactor" | "damping" => {
+                call.damping_factor = match extract_number(value) {
+                    Some(d) if (0.0..1.0).contains(&d) => d,
+                    _ => {
+                        return Err(
+                            "graph.pageRank: dampingFactor must be a number in [0, 1)".to_string()
+                        )
+                    }
+                }
+            }
+            other => {
+                return Err(format!(
+                    "graph.pageRank: unknown config key `{}` (expected iterations, dampingFactor)",
+                    other
+                ))
+            }
+        }
+    }
+    Ok(call)
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// SQL generation
+// ───────────────────────────────────────────────────────────────────────
+
+/// The single id column of an id list, or an error naming `what`.
+fn single_column<'a>(
+    columns: &'a [String],
+    procedure: &str,
+    what: &str,
+) -> Result<&'a str, String> {
+    match columns {
+        [column] => Ok(column),
+        _ => Err(format!(
+            "{}: {} has a composite id, which is not supported",
+            procedure, what
+        )),
+    }
+}
+
+/// `SELECT src, dst` for every edge table of `rel_types` between two `label`
+/// nodes, plus `weight` when a weight property is given.
+fn edge_branches(
+    schema: &GraphSchema,
+    procedure: &str,
+    label: &str,
+    rel_types: &[String],
+    weight: Option<EdgeWeight<'_>>,
+) -> Result<Vec<String>, String> {
+    let mut branches = Vec::new();
+    for rel_type in rel_types {
+        let rels = schema.rel_schemas_for_type(rel_type);
+        if rels.is_empty() {
+            return Err(format!(
+                "{}: relationship type `{}` is not in the schema",
+                procedure, rel_type
+            ));
+        }
+        let before = branches.len();
+        for rel in rels {
+            if rel.from_node != label || rel.to_node != label {
+                continue;
+            }
+            if rel.view_parameters.is_some() {
+                return Err(format!(
+                    "{}: relationship type `{}` is a parameterized view",
+                    procedure, rel_type
+                ));
+            }
+            let from: Vec<String> = rel
+                .from_id
+                .columns()
+                .iter()
+                .map(|c| c.to_string())
+                .collect();
+            let to: Vec<String> = rel.to_id.columns().iter().map(|c| c.to_string()).collect();
+            let what = format!("relationship type `{}`", rel_type);
+            let weight = match weight {
+                None => String::new(),
+                Some(weight) => {
+                    format!(
+                        ", toFloat64({}) AS weight",
+                        weight(rel_type, rel, TABLE_ALIAS)?
+                    )
+                }
+            };
+            branches.push(format!(
+                "SELECT {t}.{} AS src, {t}.{} AS dst{} FROM {} AS {t}{}",
+                quote_identifier(single_column(&from, procedure, &what)?),
+                quote_identifier(single_column(&to, procedure, &what)?),
+                weight,
+                rel.full_table_name(),
+                where_clause(&relationship_conditions(rel, rel_type, TABLE_ALIAS)?),
+                t = TABLE_ALIAS,
+            ));
+        }
+        if branches.len() == before {
+            return Err(format!(
+                "{}: relationship type `{}` does not connect `{}` to `{}`",
+                procedure, rel_type, label, label
+            ));
+        }
+    }
+    Ok(branches)
+}
+
+/// The `{prefix}_nodes (node_id)` and `{prefix}_edges (src, dst)` CTEs of the
+/// subgraph a graph algorithm runs on: the nodes of `label` and the
+/// `rel_types` relationships between them. With `weight`, the edges also
+/// carry it as a `Float64` `weight`, and edges without one are left out.
+pub(crate) fn label_subgraph_ctes(
+    schema: &GraphSchema,
+    procedure: &str,
+    label: &str,
+    rel_types: &[String],
+    prefix: &str,
+    weight: Option<EdgeWeight<'_>>,
+) -> Result<[String; 2], String> {
+    let node = schema
+        .node_schema_opt(label)
+        .ok_or_else(|| format!("{}: label `{}` is not in the schema", procedure, label))?;
+    if !node.has_standalone_table() {
+        return Err(format!(
+            "{}: label `{}` has no table of its own (denormalized or parameterized)",
+            procedure, label
+        ));
+    }
+    let id_columns = node.id_physical_columns();
+    let id = single_column(&id_columns, procedure, &format!("label `{}`", label))?;
+
+    Ok([
+        format!(
+            "{p}_nodes AS (SELECT DISTINCT {t}.{} AS node_id FROM {} AS {t}{})",
+            quote_identifier(id),
+            node.full_table_name(),
+            where_clause(&node_conditions(node, TABLE_ALIAS)?),
+            p = prefix,
+            t = TABLE_ALIAS,
+        ),
+        format!(
+            "{p}_edges AS (SELECT {columns} FROM ({}) \
+             WHERE src IN (SELECT node_id FROM {p}_nodes) AND dst IN (SELECT node_id FROM {p}_nodes){weighted})",
+            edge_branches(schema, procedure, label, rel_types, weight)?
+                .join(" UNION ALL "),
+            p = prefix,
+            columns = if weight.is_some() { "src, dst, weight" } else { "src, dst" },
+            weighted = if weight.is_some() { " AND weight IS NOT NULL" } else { "" },
+        ),
+    ])
+}
+
+/// Build the PageRank query.
+///
+/// ```text
+/// pr_nodes  node ids of the label
+/// pr_edges  (src, dst) between those nodes
+/// pr_out    out-degree per source
+/// pr_0      1 / N for every node
+/// pr_k      (1 - d) / N + d * Σ pr_{k-1}(src) / out(src) over incoming edges
+/// ```
+pub fn build_page_rank_sql(schema: &GraphSchema, call: &PageRankCall) -> Result<String, String> {
+    let [nodes, edges] = label_subgraph_ctes(
+        schema,
+        PROCEDURE_NAME,
+        &call.label,
+        &call.rel_types,
+        "pr",
+        None,
+    )?;
+    let mut ctes = vec![
+        nodes,
+        edges,
+        "pr_out AS (SELECT src AS node_id, toFloat64(count()) AS out_degree \
+         FROM pr_edges GROUP BY src)"
+            .to_string(),
+        "pr_0 AS (SELECT node_id, 1 / (SELECT toFloat64(count()) FROM pr_nodes) AS score \
+         FROM pr_nodes)"
+            .to_string(),
+    ];
+    let d = call.damping_factor;
+    for k in 1..=call.iterations {
+        ctes.push(format!(
+            "pr_{k} AS (SELECT n.node_id AS node_id, \
+             (1 - {d}) / (SELECT toFloat64(count()) FROM pr_nodes) + {d} * coalesce(c.contribution, 0) AS score \
+             FROM pr_nodes AS n LEFT JOIN \
+             (SELECT e.dst AS node_id, sum(p.score / o.out_degree) AS contribution \
+             FROM pr_edges AS e \
+             INNER JOIN pr_{prev} AS p ON p.node_id = e.src \
+             INNER JOIN pr_out AS o ON o.node_id = e.src \
+             GROUP BY e.dst) AS c ON c.node_id = n.node_id)",
+            k = k,
+            prev = k - 1,
+            d = d,
+        ));
+    }
+
+    Ok(format!(
+        "WITH {}\nSELECT node_id AS nodeId, score FROM pr_{} ORDER BY score DESC, nodeId",
+        ctes.join(",\n"),
+        call.iterations
+    ))
+}
+
+/// One result record: `nodeId` as returned, `score` as a float.
+pub fn page_rank_record(row: &Value) -> HashMap<String, Value> {
+    let score = match row.get("score") {
+        Some(Value::String(s)) => s.parse::<f64>().map(Value::from).unwrap_or(Value::Null),
+        Some(v) => v.clone(),
+        None => Value::Null,
+    };
+    HashMap::from([
+        (
+            "nodeId".to_string(),
+            row.get("nodeId").cloned().unwrap_or(Value::Null),
+        ),
+        ("score".to_string(), score),
+    ])
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// Tests
+// ───────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+    use crate::open_cypher_parser::ast::CypherStatement;
+    use serde_json::json;
+
+    fn parse(call: &str) -> Result<PageRankCall, String> {
+        let (_, stmt) = crate::open_cypher_parser::parse_cypher_statement(call).unwrap();
+        let CypherStatement::ProcedureCall(pc) = stmt else {
+            panic!("expected a procedure call");
+        };
+        assert!(is_page_rank_procedure(pc.procedure_name));
+        let args: Vec<&Expression> = pc.arguments.iter().collect();
+        parse_page_rank_call(&args)
+    }
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_file("benchmarks/social_network/schemas/social_benchmark.yaml")
+            .unwrap()
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_defaults_and_config() {
+        let call = parse("CALL graph.pageRank('User', 'FOLLOWS')").unwrap();
+        assert_eq!(call.rel_types, vec!["FOLLOWS"]);
+        assert_eq!(call.iterations, DEFAULT_ITERATIONS);
+        assert_eq!(call.damping_factor, DEFAULT_DAMPING_FACTOR);
+
+        let call = parse(
+            "CALL graph.pagerank('User', ['FOLLOWS', 'FRIENDS_WITH'], {iterations: 5, dampingFactor: 0.5})",
+        )
+        .unwrap();
+        assert_eq!(call.label, "User");
+        assert_eq!(call.rel_types, vec!["FOLLOWS", "FRIENDS_WITH"]);
+        assert_eq!(call.iterations, 5);
+        assert_eq!(call.damping_factor, 0.5);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_arguments() {
+        for (call, expected) in [
+            ("CALL graph.pageRank('User')", "requires 2 or 3 arguments"),
+            (
+                "CALL graph.pageRank('User', 'FOLLOWS', {iterations: 0})",
+                "iterations",
+            ),
+            (
+                "CALL graph.pageRank('User', 'FOLLOWS', {iterations: 500})",
+                "iterations",
+            ),
+            (
+                "CALL graph.pageRank('User', 'FOLLOWS', {damping: 1})",
+                "dampingFactor",
+            ),
+            (
+                "CALL graph.pageRank('User', 'FOLLOWS', {tolerance: 0.1})",
+                "unknown config key",
+            ),
+            ("CALL graph.pageRank('User', [])", "list is empty"),
+            (
+                "CALL graph.pageRank(1, 'FOLLOWS')",
+                "the label must be a string",
+            ),
+        ] {
+            let err = parse(call).unwrap_err();
+            assert!(err.contains(expected), "{call}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_sql_unrolls_iterations() {
+        let call = parse("CALL graph.pageRank('User', 'FOLLOWS', {iterations: 3})").unwrap();
+        let sql = build_page_rank_sql(&schema(), &call).unwrap();
+        assert!(
+            sql.contains(
+                "pr_nodes AS (SELECT DISTINCT t.user_id AS node_id FROM social.users_bench AS t)"
+            ),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("SELECT t.follower_id AS src, t.followed_id AS dst FROM social.user_follows_bench AS t"),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("INNER JOIN pr_2 AS p ON p.node_id = e.src"),
+            "{sql}"
+        );
+        assert!(!sql.contains("pr_4"), "{sql}");
+        assert!(sql.contains("(1 - 0.85) / (SELECT toFloat64(count()) FROM pr_nodes) + 0.85 *"));
+        assert!(
+            sql.ends_with("FROM pr_3 ORDER BY score DESC, nodeId"),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn test_sql_rejects_unusable_patterns() {
+        let schema = schema();
+        for (call, expected) in [
+            (
+                "CALL graph.pageRank('Nope', 'FOLLOWS')",
+                "label `Nope` is not in the schema",
+            ),
+            (
+                "CALL graph.pageRank('User', 'NOPE')",
+                "`NOPE` is not in the schema",
+            ),
+            (
+                "CALL graph.pageRank('User', 'AUTHORED')",
+                "does not connect `User` to `User`",
+            ),
+        ] {
+            let err = build_page_rank_sql(&schema, &parse(call).unwrap()).unwrap_err();
+            assert!(err.contains(expected), "{call}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_record_parses_quoted_scores() {
+        let record = page_rank_record(&json!({"nodeId": "7", "score": "0.25"}));
+        assert_eq!(record["nodeId"], json!("7"));
+        assert_eq!(record["score"], json!(0.25));
+    }
+}
@@ -0,0 +1,414 @@
+//! Graph data quality checks.
+//!
+//! Implements `graph.quality()`: one aggregate query over every mapped table
+//! that reports, per node label and relationship type, how well the data
+//! fits the graph the schema describes.
+//!
+//! ```cypher
+//! CALL graph.quality()
+//! ```
+//!
+//! | Column | Nodes | Relationships |
+//! |--------|-------|---------------|
+//! | `row_count` | rows of the label (after its filter / label value) | rows of the type |
+//! | `table_row_count` | all rows of the table | all rows of the table |
+//! | `label_coverage` | `row_count / table_row_count` | same |
+//! | `null_ids` / `null_id_ratio` | rows with a NULL id column | rows with a NULL `from_id` / `to_id` |
+//! | `duplicate_ids` | ids shared by more than one row | — |
+//! | `orphan_from` / `orphan_to` | — | rows whose endpoint id has no node row |
+//!
+//! Orphan counts are `null` when an endpoint has no table of its own
+//! (denormalized or polymorphic endpoints). Parameterized views are skipped:
+//! they cannot be read without their view parameters.
+//!
+//! # Execution Flow
+//!
+//! Like `graph.construct`, this bypasses `ProcedureRegistry`:
+//! 1. Handler detects `graph.quality` → [`build_quality_sql`]
+//! 2. Caller runs the SQL (one `UNION ALL` branch per label / type)
+//! 3. [`quality_record`] turns each row into the procedure's record, adding
+//!    the ratios
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde_json::Value;
+
+use crate::clickhouse_query_generator::quote_identifier;
+use crate::graph_catalog::filter_parser::SchemaFilter;
+use crate::graph_catalog::graph_schema::{GraphSchema, NodeSchema, RelationshipSchema};
+
+/// Procedure name (matched case-insensitively).
+pub const PROCEDURE_NAME: &str = "graph.quality";
+
+/// Alias of the checked table in each branch.
+const TABLE_ALIAS: &str = "t";
+
+/// Alias of the endpoint node table in orphan lookups.
+const NODE_ALIAS: &str = "n";
+
+/// Counter columns of every branch, in order.
+const COUNTERS: [&str; 6] = [
+    "row_count",
+    "table_row_count",
+    "null_ids",
+    "duplicate_ids",
+    "orphan_from",
+    "orphan_to",
+];
+
+/// Check whether a procedure name is `graph.quality`.
+pub fn is_quality_procedure(name: &str) -> bool {
+    name.eq_ignore_ascii_case(PROCEDURE_NAME)
+}
+
+/// Escape a string for a single-quoted SQL literal.
+pub(crate) fn sql_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// A counter column; every branch casts to the same type so `UNION ALL`
+/// lines up.
+fn counter(expr: &str, name: &str) -> String {
+    format!("CAST({} AS Nullable(UInt64)) AS {}", expr, name)
+}
+
+/// `isNull(a) OR isNull(b) ...` over `columns` of `alias`.
+fn any_null(alias: &str, columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|c| format!("isNull({}.{})", alias, quote_identifier(c)))
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
+
+/// `alias.a` for one column, `(alias.a, alias.b)` for several.
+fn column_tuple(alias: &str, columns: &[String]) -> String {
+    let cols: Vec<String> = columns
+        .iter()
+        .map(|c| format!("{}.{}", alias, quote_identifier(c)))
+        .collect();
+    if cols.len() == 1 {
+        cols[0].clone()
+    } else {
+        format!("({})", cols.join(", "))
+    }
+}
+
+/// ` WHERE ...` for `conditions`, or nothing.
+pub(crate) fn where_clause(conditions: &[String]) -> String {
+    if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
+    }
+}
+
+fn filter_sql(filter: &Option<SchemaFilter>, alias: &str) -> Result<Option<String>, String> {
+    filter
+        .as_ref()
+        .map(|f| f.to_sql(alias).map(|sql| format!("({})", sql)))
+        .transpose()
+}
+
+/// The rows of node table `node` that belong to its label.
+pub(crate) fn node_conditions(node: &NodeSchema, alias: &str) -> Result<Vec<String>, String> {
+    let mut conditions: Vec<String> = filter_sql(&node.filter, alias)?.into_iter().collect();
+    if let Some((column, value)) = node.label_discriminator() {
+        conditions.push(format!(
+            "{}.{} = {}",
+            alias,
+            quote_identifier(column),
+            sql_string(value)
+        ));
+    }
+    Ok(conditions)
+}
+
+/// The node table an endpoint's ids can be looked up in, if it has one.
+fn endpoint_table<'a>(schema: &'a GraphSchema, label: &str) -> Option<&'a NodeSchema> {
+    schema
+        .node_schema_opt(label)
+        .filter(|node| node.has_standalone_table())
+}
+
+fn node_branch(label: &str, node: &NodeSchema) -> Result<String, String> {
+    let table = node.full_table_name();
+    let columns = node.id_physical_columns();
+    let ids: Vec<String> = (0..columns.len()).map(|i| format!("id{}", i)).collect();
+    let id_list = columns
+        .iter()
+        .zip(&ids)
+        .map(|(c, id)| format!("{}.{} AS {}", TABLE_ALIAS, quote_identifier(c), id))
+        .collect::<Vec<_>>()
+        .join(", ");
+    // Group by id once; the outer query counts rows, NULL ids and ids seen
+    // more than once.
+    let null_id = ids
+        .iter()
+        .map(|id| format!("isNull({})", id))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    Ok(format!(
+        "SELECT 'node' AS kind, {} AS name, {} AS table_name, {}, {}, {}, {}, {}, {} \
+         FROM (SELECT {}, count() AS n FROM {} AS {}{} GROUP BY {})",
+        sql_string(label),
+        sql_string(&table),
+        counter("sum(n)", "row_count"),
+        counter(
+            &format!("(SELECT count() FROM {})", table),
+            "table_row_count"
+        ),
+        counter(&format!("sumIf(n, {})", null_id), "null_ids"),
+        counter(
+            &format!("countIf(n > 1 AND NOT ({}))", null_id),
+            "duplicate_ids"
+        ),
+        counter("NULL", "orphan_from"),
+        counter("NULL", "orphan_to"),
+        id_list,
+        table,
+        TABLE_ALIAS,
+        where_clause(&node_conditions(node, TABLE_ALIAS)?),
+        ids.join(", "),
+    ))
+}
+
+/// `countIf(<fk> NOT IN (SELECT <ids> FROM <node table>))`, or NULL when the
+/// endpoint has no table of its own.
+fn orphan_count(
+    schema: &GraphSchema,
+    label: &str,
+    label_values: &Option<Vec<String>>,
+    fk: &[String],
+) -> Result<String, String> {
+    if label_values.is_some() {
+        return Ok("NULL".to_string());
+    }
+    let Some(node) = endpoint_table(schema, label) else {
+        return Ok("NULL".to_string());
+    };
+    let ids = node.id_physical_columns();
+    if ids.len() != fk.len() {
+        return Ok("NULL".to_string());
+    }
+    Ok(format!(
+        "countIf(NOT ({}) AND {} NOT IN (SELECT {} FROM {} AS {}{}))",
+        any_null(TABLE_ALIAS, fk),
+        column_tuple(TABLE_ALIAS, fk),
+        ids.iter()
+            .map(|c| format!("{}.{}", NODE_ALIAS, quote_identifier(c)))
+            .collect::<Vec<_>>()
+            .join(", "),
+        node.full_table_name(),
+        NODE_ALIAS,
+        where_clause(&node_conditions(node, NODE_ALIAS)?),
+    ))
+}
+
+/// The rows of edge table `rel` that belong to `rel_type`.
+pub(crate) fn relationship_conditions(
+    rel: &RelationshipSchema,
+    rel_type: &str,
+    alias: &str,
+) -> Result<Vec<String>, String> {
+    let mut conditions: Vec<String> = filter_sql(&rel.filter, alias)?.into_iter().collect();
+    for (column, value) in rel.row_discriminators(rel_type) {
+        conditions.push(format!(
+            "{}.{} = {}",
+            alias,
+            quote_identifier(column),
+            sql_string(value)
+        ));
+    }
+    Ok(conditions)
+}
+
+fn relationship_branch(
+    schema: &GraphSchema,
+    rel_type: &str,
+    name: &str,
+    rel: &RelationshipSchema,
+) -> Result<String, String> {
+    let table = rel.full_table_name();
+    let from: Vec<String> = rel
+        .from_id
+        .columns()
+        .iter()
+        .map(|c| c.to_string())
+        .collect();
+    let to: Vec<String> = rel.to_id.columns().iter().map(|c| c.to_string()).collect();
+
+    let conditions = relationship_conditions(rel, rel_type, TABLE_ALIAS)?;
+
+    let endpoints: Vec<String> = from.iter().chain(&to).cloned().collect();
+    Ok(format!(
+        "SELECT 'relationship' AS kind, {} AS name, {} AS table_name, {}, {}, {}, {}, {}, {} \
+         FROM {} AS {}{}",
+        sql_string(name),
+        sql_string(&table),
+        counter("count()", "row_count"),
+        counter(
+            &format!("(SELECT count() FROM {})", table),
+            "table_row_count"
+        ),
+        counter(
+            &format!("countIf({})", any_null(TABLE_ALIAS, &endpoints)),
+            "null_ids"
+        ),
+        counter("NULL", "duplicate_ids"),
+        counter(
+            &orphan_count(schema, &rel.from_node, &rel.from_label_values, &from)?,
+            "orphan_from"
+        ),
+        counter(
+            &orphan_count(schema, &rel.to_node, &rel.to_label_values, &to)?,
+            "orphan_to"
+        ),
+        table,
+        TABLE_ALIAS,
+        where_clause(&conditions),
+    ))
+}
+
+/// Build the quality query for every node label and relationship type of
+/// `schema`: one `UNION ALL` branch each, nodes first. `None` when the schema
+/// maps nothing that can be checked.
+pub fn build_quality_sql(schema: &GraphSchema) -> Result<Option<String>, String> {
+    let mut branches = Vec::new();
+
+    // Node keys may be qualified ("db::table::Label"); report each label once.
+    let mut seen = BTreeSet::new();
+    for (key, node) in schema.all_node_schemas() {
+        let label = key.rsplit("::").next().unwrap_or(key);
+        if !node.has_standalone_table() || !seen.insert((label.to_string(), node.full_table_name()))
+        {
+            continue;
+        }
+        branches.push(node_branch(label, node)?);
+    }
+
+    // A type mapped for several endpoint pairs gets one row per pair, named
+    // by its composite key ("TYPE::From::To").
+    for (rel_type, keys) in schema.get_rel_type_index() {
+        for key in keys {
+            let Some(rel) = schema.get_relationships_schemas().get(key) else {
+                continue;
+            };
+            if rel.view_parameters.is_some() {
+                continue;
+            }
+            let name = if keys.len() == 1 { rel_type } else { key };
+            branches.push(relationship_branch(schema, rel_type, name, rel)?);
+        }
+    }
+
+    if branches.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(branches.join("\nUNION ALL\n")))
+}
+
+/// One procedure record from a row of the quality query. Counters arrive as
+/// JSON strings under ClickHouse's default
+/// `output_format_json_quote_64bit_integers`.
+pub fn quality_record(row: &Value) -> HashMap<String, Value> {
+    let num = |name: &str| match row.get(name) {
+        Some(Value::Number(n)) => n.as_u64(),
+        Some(Value::String(s)) => s.parse::<u64>().ok(),
+        _ => None,
+    };
+    let ratio = |part: Option<u64>, whole: Option<u64>| match (part, whole) {
+        (Some(part), Some(whole)) if whole > 0 => Value::from(part as f64 / whole as f64),
+        _ => Value::Null,
+    };
+
+    let mut record = HashMap::new();
+    for column in ["kind", "name", "table_name"] {
+        record.insert(
+            column.to_string(),
+            row.get(column).cloned().unwrap_or(Value::Null),
+        );
+    }
+    for column in COUNTERS {
+        record.insert(
+            column.to_string(),
+            num(column).map_or(Value::Null, Value::from),
+        );
+    }
+    record.insert(
+        "label_coverage".to_string(),
+        ratio(num("row_count"), num("table_row_count")),
+    );
+    record.insert(
+        "null_id_ratio".to_string(),
+        ratio(num("null_ids"), num("row_count")),
+    );
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+    use serde_json::json;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_file("benchmarks/social_network/schemas/social_benchmark.yaml")
+            .unwrap()
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_quality_sql_covers_labels_and_types() {
+        assert!(is_quality_procedure("GRAPH.quality"));
+        assert!(!is_quality_procedure("graph.construct"));
+
+        let sql = build_quality_sql(&schema()).unwrap().unwrap();
+        let branches: Vec<&str> = sql.split("\nUNION ALL\n").collect();
+        let user = branches
+            .iter()
+            .find(|b| b.contains("'node' AS kind, 'User'"))
+            .expect("User branch");
+        assert!(
+            user.contains(
+                "(SELECT t.user_id AS id0, count() AS n FROM social.users_bench AS t GROUP BY id0)"
+            ),
+            "{user}"
+        );
+        assert!(user.contains("countIf(n > 1 AND NOT (isNull(id0)))"));
+
+        let follows = branches
+            .iter()
+            .find(|b| b.contains("'relationship' AS kind, 'FOLLOWS'"))
+            .expect("FOLLOWS branch");
+        assert!(
+            follows.contains("NOT IN (SELECT n.user_id FROM social.users_bench AS n)"),
+            "{follows}"
+        );
+        assert!(follows.contains("FROM social.user_follows_bench AS t"));
+        // Every branch has the same columns.
+        for branch in &branches {
+            for column in COUNTERS {
+                assert!(branch.contains(&format!("AS {}", column)), "{branch}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_quality_record_adds_ratios() {
+        let record = quality_record(&json!({
+            "kind": "node", "name": "User", "table_name": "social.users_bench",
+            "row_count": "80", "table_row_count": "100", "null_ids": "4",
+            "duplicate_ids": "1", "orphan_from": null, "orphan_to": null
+        }));
+        assert_eq!(record["row_count"], json!(80));
+        assert_eq!(record["orphan_from"], Value::Null);
+        assert_eq!(record["label_coverage"], json!(0.8));
+        assert_eq!(record["null_id_ratio"], json!(0.05));
+
+        let empty = quality_record(&json!({"row_count": "0", "table_row_count": "0"}));
+        assert_eq!(empty["label_coverage"], Value::Null);
+        assert_eq!(empty["null_id_ratio"], Value::Null);
+    }
+}
@@ -0,0 +1,777 @@
+//! Representative subgraph sampling.
+//!
+//! Implements `graph.sampleSubgraph(config)`: copies a laptop-sized sample
+//! of every mapped table into a new database and derives a schema YAML that
+//! points at the copies, so development can run against the same shape of
+//! graph as production.
+//!
+//! ```cypher
+//! CALL graph.sampleSubgraph({nodesPerLabel: 10000, preserveDegreeDistribution: true})
+//! ```
+//!
+//! | Config key | Default | Meaning |
+//! |------------|---------|---------|
+//! | `nodesPerLabel` | 10000 | Nodes sampled per label (about; see below) |
+//! | `preserveDegreeDistribution` | true | Sample each degree stratum in proportion |
+//! | `database` | `<schema>_sample` | Target database, created if missing |
+//! | `seed` | 0 | Hash seed; the same seed picks the same sample |
+//! | `replace` | false | `CREATE OR REPLACE` existing sample tables |
+//!
+//! Nodes are picked per label by `cityHash64(id, seed)`, so the sample is
+//! stable across runs. With `preserveDegreeDistribution`, every node is put
+//! in a stratum by `log2` of its degree over all relationship tables it is an
+//! endpoint of, and each stratum keeps its share of `nodesPerLabel` rows (at
+//! least one), so hubs stay in the sample. Relationship tables keep the rows
+//! whose endpoints were both sampled (the induced subgraph); an endpoint
+//! without a table of its own (denormalized or polymorphic) does not filter,
+//! and a relationship with no such endpoint keeps the rows of
+//! `nodesPerLabel` sampled source ids.
+//!
+//! A table shared by several labels or types is copied once. Parameterized
+//! views are skipped and left out of the derived schema.
+//!
+//! # Execution Flow
+//!
+//! Like `graph.construct`, this bypasses `ProcedureRegistry`:
+//! 1. Handler detects `graph.sampleSubgraph` → [`parse_sample_call`]
+//! 2. [`build_sample_plan`] emits one `CREATE TABLE ... AS SELECT` per table,
+//!    node tables first; the caller runs [`SamplePlan::statements`] in order
+//! 3. [`count_sql`] reads the copied row counts and [`derive_schema_yaml`]
+//!    rewrites the schema's YAML to the sample database
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde_json::Value;
+
+use crate::clickhouse_query_generator::quote_identifier;
+use crate::graph_catalog::config::GraphSchemaConfig;
+use crate::graph_catalog::graph_schema::{GraphSchema, NodeSchema, RelationshipSchema};
+use crate::open_cypher_parser::ast::{Expression, Literal};
+use crate::procedures::graph_quality::{
+    node_conditions, relationship_conditions, sql_string, where_clause,
+};
+
+/// Procedure name (matched case-insensitively).
+pub const PROCEDURE_NAME: &str = "graph.sampleSubgraph";
+
+/// Default nodes sampled per label.
+pub const DEFAULT_NODES_PER_LABEL: u64 = 10_000;
+
+/// Alias of the source table in each statement.
+const TABLE_ALIAS: &str = "t";
+
+/// Alias of the relationship table in degree subqueries.
+const EDGE_ALIAS: &str = "e";
+
+/// Check whether a procedure name is `graph.sampleSubgraph`.
+pub fn is_sample_procedure(name: &str) -> bool {
+    name.eq_ignore_ascii_case(PROCEDURE_NAME)
+}
+
+/// Parsed `graph.sampleSubgraph(config)` arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleCall {
+    pub nodes_per_label: u64,
+    pub preserve_degree_distribution: bool,
+    /// Target database; `<schema>_sample` when not given
+    pub database: Option<String>,
+    pub seed: u64,
+    pub replace: bool,
+}
+
+impl Default for SampleCall {
+    fn default() -> Self {
+        Self {
+            nodes_per_label: DEFAULT_NODES_PER_LABEL,
+            preserve_degree_distribution: true,
+            database: None,
+            seed: 0,
+            replace: false,
+        }
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Parse `graph.sampleSubgraph([config])` arguments.
+pub fn parse_sample_call(args: &[&Expression<'_>]) -> Result<SampleCall, String> {
+    let mut call = SampleCall::default();
+    let entries = match args {
+        [] => return Ok(call),
+        [Expression::MapLiteral(entries)] => entries,
+        [_] => return Err("graph.sampleSubgraph: the config must be a map".to_string()),
+        _ => {
+            return Err(format!(
+                "graph.sampleSubgraph takes at most 1 argument (config), got {}",
+                args.len()
+            ))
+        }
+    };
+    for (key, value) in entries {
+        match (key.to_lowercase().as_str(), value) {
+            ("nodesperlabel", Expression::Literal(Literal::Integer(n))) if *n > 0 => {
+                call.nodes_per_label = *n as u64
+            }
+            ("preservedegreedistribution", Expression::Literal(Literal::Boolean(b))) => {
+                call.preserve_degree_distribution = *b
+            }
+            ("database", Expression::Literal(Literal::String(db))) if is_identifier(db) => {
+                call.database = Some(db.to_string())
+            }
+            ("seed", Expression::Literal(Literal::Integer(n))) if *n >= 0 => call.seed = *n as u64,
+            ("replace", Expression::Literal(Literal::Boolean(b))) => call.replace = *b,
+            ("nodesperlabel", _) => {
+                return Err(
+                    "graph.sampleSubgraph: nodesPerLabel must be a positive integer".to_string(),
+                )
+            }
+            ("database", _) => {
+                return Err(
+                    "graph.sampleSubgraph: database must be a plain identifier string".to_string(),
+                )
+            }
+            ("seed", _) => {
+                return Err("graph.sampleSubgraph: seed must be a non-negative integer".to_string())
+            }
+            ("preservedegreedistribution" | "replace", _) => {
+                return Err(format!("graph.sampleSubgraph: {} must be a boolean", key))
+            }
+            _ => {
+                return Err(format!(
+                    "graph.sampleSubgraph: unknown config key `{}` (expected nodesPerLabel, \
+                     preserveDegreeDistribution, database, seed, replace)",
+                    key
+                ))
+            }
+        }
+    }
+    Ok(call)
+}
+
+/// One copied table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleTable {
+    /// `node:User`, `relationship:FOLLOWS`, ... — every element stored in it
+    pub elements: Vec<String>,
+    /// `database.table` of the source
+    pub source: String,
+    /// Table name in the sample database
+    pub table: String,
+    /// `CREATE TABLE ... AS SELECT`
+    pub sql: String,
+}
+
+/// Every statement of one sampling run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SamplePlan {
+    pub database: String,
+    pub tables: Vec<SampleTable>,
+    /// Elements left out, with the reason
+    pub skipped: Vec<String>,
+}
+
+impl SamplePlan {
+    /// `CREATE DATABASE`, then the node tables, then the relationship tables.
+    pub fn statements(&self) -> Vec<String> {
+        std::iter::once(format!(
+            "CREATE DATABASE IF NOT EXISTS {}",
+            backtick(&self.database)
+        ))
+        .chain(self.tables.iter().map(|t| t.sql.clone()))
+        .collect()
+    }
+}
+
+fn backtick(s: &str) -> String {
+    format!("`{}`", s.replace('`', "\\`"))
+}
+
+/// `alias.a` for one column, `(alias.a, alias.b)` for several.
+fn key_expr(alias: &str, columns: &[String]) -> String {
+    let cols: Vec<String> = columns
+        .iter()
+        .map(|c| format!("{}.{}", alias, quote_identifier(c)))
+        .collect();
+    if cols.len() == 1 {
+        cols[0].clone()
+    } else {
+        format!("({})", cols.join(", "))
+    }
+}
+
+/// An endpoint side of a relationship mapping that touches a node table.
+struct Side<'a> {
+    rel_type: &'a str,
+    rel: &'a RelationshipSchema,
+    columns: Vec<String>,
+}
+
+/// Label of a node schema key, which may be qualified (`db::table::Label`).
+fn label_of(key: &str) -> &str {
+    key.rsplit("::").next().unwrap_or(key)
+}
+
+/// The node table an endpoint's ids can be looked up in, if it has one.
+fn endpoint_table<'a>(schema: &'a GraphSchema, label: &str) -> Option<&'a NodeSchema> {
+    schema
+        .node_schema_opt(label)
+        .filter(|node| node.has_standalone_table())
+}
+
+/// Ids of the sampled rows of `label`, as a subquery returning one `key`
+/// column.
+fn sampled_keys(
+    label: &str,
+    node: &NodeSchema,
+    sides: &[Side],
+    call: &SampleCall,
+) -> Result<String, String> {
+    let ids = node.id_physical_columns();
+    let key = key_expr(TABLE_ALIAS, &ids);
+    let conditions = where_clause(&node_conditions(node, TABLE_ALIAS)?);
+    let n = call.nodes_per_label;
+    let seed = call.seed;
+    let sides: Vec<&Side> = sides
+        .iter()
+        .filter(|s| s.columns.len() == ids.len())
+        .collect();
+
+    if !call.preserve_degree_distribution || sides.is_empty() {
+        return Ok(format!(
+            "SELECT {key} AS key FROM {} AS {TABLE_ALIAS}{conditions} \
+             ORDER BY cityHash64(key, {seed}) LIMIT {n}",
+            node.full_table_name()
+        ));
+    }
+
+    // Degree of every id over the relationship tables `label` is an
+    // endpoint of; strata are 0 (isolated) and floor(log2(degree)) + 1.
+    let mut endpoints = Vec::new();
+    for side in sides {
+        let rel_conditions = relationship_conditions(side.rel, side.rel_type, EDGE_ALIAS)?;
+        endpoints.push(format!(
+            "SELECT {} AS key FROM {} AS {EDGE_ALIAS}{}",
+            key_expr(EDGE_ALIAS, &side.columns),
+            side.rel.full_table_name(),
+            where_clause(&rel_conditions)
+        ));
+    }
+    log::debug!(
+        "graph.sampleSubgraph: {} stratified over {} endpoint mapping(s)",
+        label,
+        endpoints.len()
+    );
+    Ok(format!(
+        "SELECT key FROM (\
+         SELECT key, row_number() OVER (PARTITION BY stratum ORDER BY cityHash64(key, {seed})) AS rn, \
+         count() OVER (PARTITION BY stratum) AS stratum_rows, count() OVER () AS total_rows \
+         FROM (SELECT {key} AS key, if(d.degree = 0, 0, toUInt32(log2(d.degree)) + 1) AS stratum \
+         FROM {} AS {TABLE_ALIAS} LEFT JOIN (SELECT key, count() AS degree FROM ({}) GROUP BY key) AS d \
+         ON {key} = d.key{conditions})) \
+         WHERE rn <= greatest(1, round(stratum_rows * {n} / total_rows))",
+        node.full_table_name(),
+        endpoints.join(" UNION ALL "),
+    ))
+}
+
+fn create_table(call: &SampleCall, database: &str, table: &str, select: &str) -> String {
+    format!(
+        "CREATE {}TABLE {}.{} ENGINE = MergeTree ORDER BY tuple() AS {}",
+        if call.replace { "OR REPLACE " } else { "" },
+        backtick(database),
+        backtick(table),
+        select
+    )
+}
+
+/// Plan the copy of every mapped table of `schema` into the sample database.
+pub fn build_sample_plan(
+    schema: &GraphSchema,
+    schema_name: &str,
+    call: &SampleCall,
+) -> Result<SamplePlan, String> {
+    let database = match &call.database {
+        Some(db) => db.clone(),
+        None => format!(
+            "{}_sample",
+            schema_name
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect::<String>()
+        ),
+    };
+    let mut skipped = Vec::new();
+
+    // Relationship mappings, by node label of each standalone endpoint.
+    let mut rels: Vec<(&str, &RelationshipSchema)> = Vec::new();
+    for (rel_type, keys) in schema.get_rel_type_index() {
+        for key in keys {
+            let Some(rel) = schema.get_relationships_schemas().get(key) else {
+                continue;
+            };
+            if rel.view_parameters.is_some() {
+                skipped.push(format!(
+                    "relationship:{} (parameterized view {})",
+                    rel_type,
+                    rel.full_table_name()
+                ));
+                continue;
+            }
+            rels.push((rel_type, rel));
+        }
+    }
+    let mut sides: HashMap<&str, Vec<Side>> = HashMap::new();
+    for (rel_type, rel) in &rels {
+        for (label, id, label_values) in [
+            (&rel.from_node, &rel.from_id, &rel.from_label_values),
+            (&rel.to_node, &rel.to_id, &rel.to_label_values),
+        ] {
+            if label_values.is_none() {
+                sides.entry(label.as_str()).or_default().push(Side {
+                    rel_type,
+                    rel,
+                    columns: id.columns().iter().map(|c| c.to_string()).collect(),
+                });
+            }
+        }
+    }
+
+    // Node tables, each copied once with the sampled rows of all its labels.
+    let mut node_tables: BTreeMap<String, Vec<(&str, &NodeSchema)>> = BTreeMap::new();
+    let mut seen = BTreeSet::new();
+    for (key, node) in schema.all_node_schemas() {
+        let label = label_of(key);
+        if !seen.insert((label, node.full_table_name())) {
+            continue;
+        }
+        if node.view_parameters.is_some() {
+            skipped.push(format!(
+                "node:{} (parameterized view {})",
+                label,
+                node.full_table_name()
+            ));
+        } else if node.has_standalone_table() {
+            node_tables
+                .entry(node.full_table_name())
+                .or_default()
+                .push((label, node));
+        }
+    }
+
+    let mut tables: Vec<SampleTable> = Vec::new();
+    // Sample table name → its source, to catch two sources with one name
+    let mut copied: HashMap<String, String> = HashMap::new();
+    let mut claim = |source: &str, table: &str| -> Result<(), String> {
+        if let Some(other) = copied.insert(table.to_string(), source.to_string()) {
+            return Err(format!(
+                "graph.sampleSubgraph: {} and {} would both be copied to {}.{}; \
+                 sample one source database at a time",
+                other, source, database, table
+            ));
+        }
+        Ok(())
+    };
+
+    for (source, labels) in &node_tables {
+        let node = labels[0].1;
+        claim(source, &node.table_name)?;
+        let ids = node.id_physical_columns();
+        let mut filters = Vec::new();
+        for (label, node) in labels {
+            let label_sides = sides.get(label).map(Vec::as_slice).unwrap_or_default();
+            filters.push(format!(
+                "{} IN ({})",
+                key_expr(TABLE_ALIAS, &ids),
+                sampled_keys(label, node, label_sides, call)?
+            ));
+        }
+        let select = format!(
+            "SELECT {TABLE_ALIAS}.* FROM {source} AS {TABLE_ALIAS} WHERE {}",
+            filters.join(" OR ")
+        );
+        tables.push(SampleTable {
+            elements: labels.iter().map(|(l, _)| format!("node:{}", l)).collect(),
+            source: source.clone(),
+            table: node.table_name.clone(),
+            sql: create_table(call, &database, &node.table_name, &select),
+        });
+    }
+
+    // Relationship tables not already copied as a node table keep the rows
+    // between sampled nodes.
+    let mut edge_tables: BTreeMap<String, Vec<(&str, &RelationshipSchema)>> = BTreeMap::new();
+    for (rel_type, rel) in &rels {
+        if !node_tables.contains_key(&rel.full_table_name()) {
+            edge_tables
+                .entry(rel.full_table_name())
+                .or_default()
+                .push((rel_type, rel));
+        }
+    }
+    for (source, mappings) in &edge_tables {
+        let rel = mappings[0].1;
+        claim(source, &rel.table_name)?;
+        let mut filters = Vec::new();
+        for (rel_type, rel) in mappings {
+            let mut conditions = relationship_conditions(rel, rel_type, TABLE_ALIAS)?;
+            let mut filtered = false;
+            for (label, id) in [(&rel.from_node, &rel.from_id), (&rel.to_node, &rel.to_id)] {
+                let columns: Vec<String> = id.columns().iter().map(|c| c.to_string()).collect();
+                let Some(node) = endpoint_table(schema, label) else {
+                    continue;
+                };
+                let ids = node.id_physical_columns();
+                if ids.len() != columns.len() {
+                    continue;
+                }
+                conditions.push(format!(
+                    "{} IN (SELECT {} FROM {}.{})",
+                    key_expr(TABLE_ALIAS, &columns),
+                    ids.iter()
+                        .map(|c| quote_identifier(c))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    backtick(&database),
+                    backtick(&node.table_name)
+                ));
+                filtered = true;
+            }
+            if !filtered {
+                // No endpoint of its own: keep the edges of sampled source ids
+                let from: Vec<String> = rel
+                    .from_id
+                    .columns()
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect();
+                let key = key_expr(TABLE_ALIAS, &from);
+                conditions.push(format!(
+                    "{key} IN (SELECT DISTINCT {key} FROM {source} AS {TABLE_ALIAS} \
+                     ORDER BY cityHash64({key}, {}) LIMIT {})",
+                    call.seed, call.nodes_per_label
+                ));
+            }
+            filters.push(format!("({})", conditions.join(" AND ")));
+        }
+        let select = format!(
+            "SELECT {TABLE_ALIAS}.* FROM {source} AS {TABLE_ALIAS} WHERE {}",
+            filters.join(" OR ")
+        );
+        tables.push(SampleTable {
+            elements: mappings
+                .iter()
+                .map(|(t, _)| format!("relationship:{}", t))
+                .collect(),
+            source: source.clone(),
+            table: rel.table_name.clone(),
+            sql: create_table(call, &database, &rel.table_name, &select),
+        });
+    }
+
+    if tables.is_empty() {
+        return Err("graph.sampleSubgraph: the schema maps no table that can be sampled".into());
+    }
+    if let Some(t) = tables
+        .iter()
+        .find(|t| t.source.split('.').next() == Some(database.as_str()))
+    {
+        return Err(format!(
+            "graph.sampleSubgraph: the sample database `{}` holds source table {}; choose another `database`",
+            database, t.source
+        ));
+    }
+    Ok(SamplePlan {
+        database,
+        tables,
+        skipped,
+    })
+}
+
+/// One query reading the row count of every copied table.
+pub fn count_sql(plan: &SamplePlan) -> String {
+    plan.tables
+        .iter()
+        .map(|t| {
+            format!(
+                "SELECT {} AS table_name, count() AS rows FROM {}.{}",
+                sql_string(&t.table),
+                backtick(&plan.database),
+                backtick(&t.table)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" UNION ALL ")
+}
+
+/// Drop `null` entries so the derived YAML only carries what was set.
+fn strip_nulls(value: &mut serde_yaml::Value) {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            map.retain(|_, v| !v.is_null());
+            for (_, v) in map.iter_mut() {
+                strip_nulls(v);
+            }
+        }
+        serde_yaml::Value::Sequence(items) => items.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
+
+/// The schema's YAML pointing at the sample database: every copied node and
+/// edge definition reads its copy (without `use_final`, as the copies are
+/// plain MergeTree tables); definitions of skipped tables are dropped.
+pub fn derive_schema_yaml(
+    config: &GraphSchemaConfig,
+    schema_name: &str,
+    plan: &SamplePlan,
+) -> Result<String, String> {
+    let mut yaml = serde_yaml::to_value(config).map_err(|e| e.to_string())?;
+    strip_nulls(&mut yaml);
+    let copied: HashMap<&str, &str> = plan
+        .tables
+        .iter()
+        .map(|t| (t.source.as_str(), t.table.as_str()))
+        .collect();
+    if let Some(root) = yaml.as_mapping_mut() {
+        root.insert("name".into(), format!("{}_sample", schema_name).into());
+    }
+    let definitions = yaml
+        .get_mut("graph_schema")
+        .and_then(|g| g.as_mapping_mut())
+        .ok_or("graph.sampleSubgraph: the schema config has no graph_schema")?;
+    for list in ["nodes", "relationships", "edges"] {
+        let Some(serde_yaml::Value::Sequence(items)) = definitions.get_mut(list) else {
+            continue;
+        };
+        items.retain_mut(|item| {
+            let Some(def) = item.as_mapping_mut() else {
+                return false;
+            };
+            let source = format!(
+                "{}.{}",
+                def.get("database")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default(),
+                def.get("table")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+            );
+            let Some(table) = copied.get(source.as_str()) else {
+                return false;
+            };
+            def.insert("database".into(), plan.database.clone().into());
+            def.insert("table".into(), table.to_string().into());
+            def.remove("use_final");
+            true
+        });
+    }
+    serde_yaml::to_string(&yaml).map_err(|e| e.to_string())
+}
+
+/// The procedure's record: the sample database, each copied table with its
+/// row count, the skipped elements and the derived schema YAML.
+pub fn sample_record(
+    plan: &SamplePlan,
+    rows: &[Value],
+    schema_yaml: String,
+) -> HashMap<String, Value> {
+    let count = |table: &str| {
+        rows.iter()
+            .find(|r| r.get("table_name").and_then(Value::as_str) == Some(table))
+            .and_then(|r| match r.get("rows") {
+                Some(Value::Number(n)) => n.as_u64(),
+                Some(Value::String(s)) => s.parse().ok(),
+                _ => None,
+            })
+    };
+    let tables: Vec<Value> = plan
+        .tables
+        .iter()
+        .map(|t| {
+            serde_json::json!({
+                "elements": t.elements,
+                "source": t.source,
+                "table": format!("{}.{}", plan.database, t.table),
+                "rows": count(&t.table),
+            })
+        })
+        .collect();
+    HashMap::from([
+        ("database".to_string(), Value::from(plan.database.clone())),
+        ("tables".to_string(), Value::from(tables)),
+        ("skipped".to_string(), Value::from(plan.skipped.clone())),
+        ("schema_yaml".to_string(), Value::from(schema_yaml)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::open_cypher_parser::ast::CypherStatement;
+
+    const SCHEMA_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: prod
+      table: users
+      node_id: id
+      use_final: true
+      property_mappings:
+        id: user_id
+        name: full_name
+    - label: Post
+      database: prod
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+  edges:
+    - type: FOLLOWS
+      database: prod
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings: {}
+    - type: AUTHORED
+      database: prod
+      table: authored
+      from_id: author_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings: {}
+"#;
+
+    fn parse(call: &str) -> Result<SampleCall, String> {
+        let (_, stmt) = crate::open_cypher_parser::parse_cypher_statement(call).unwrap();
+        let CypherStatement::ProcedureCall(pc) = stmt else {
+            panic!("expected a procedure call");
+        };
+        assert!(is_sample_procedure(pc.procedure_name));
+        let args: Vec<&Expression> = pc.arguments.iter().collect();
+        parse_sample_call(&args)
+    }
+
+    fn config() -> GraphSchemaConfig {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML).expect("valid yaml")
+    }
+
+    #[test]
+    fn test_parse_config() {
+        assert_eq!(
+            parse("CALL graph.sampleSubgraph()").unwrap(),
+            SampleCall::default()
+        );
+        let call = parse(
+            "CALL graph.sampleSubgraph({nodesPerLabel: 500, preserveDegreeDistribution: false, \
+             database: 'dev', seed: 7, replace: true})",
+        )
+        .unwrap();
+        assert_eq!(call.nodes_per_label, 500);
+        assert!(!call.preserve_degree_distribution);
+        assert_eq!(call.database.as_deref(), Some("dev"));
+        assert_eq!((call.seed, call.replace), (7, true));
+
+        for bad in [
+            "CALL graph.sampleSubgraph({nodesPerLabel: 0})",
+            "CALL graph.sampleSubgraph({database: 'a.b'})",
+            "CALL graph.sampleSubgraph({edgesPerType: 5})",
+            "CALL graph.sampleSubgraph('x')",
+        ] {
+            assert!(parse(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_plan_samples_nodes_then_induced_edges() {
+        let schema = config().to_graph_schema().unwrap();
+        let call = SampleCall {
+            nodes_per_label: 100,
+            ..SampleCall::default()
+        };
+        let plan = build_sample_plan(&schema, "social", &call).unwrap();
+        assert_eq!(plan.database, "social_sample");
+        let order: Vec<&str> = plan.tables.iter().map(|t| t.source.as_str()).collect();
+        assert_eq!(
+            order,
+            ["prod.posts", "prod.users", "prod.authored", "prod.follows"]
+        );
+
+        let users = &plan.tables[1].sql;
+        assert!(users.starts_with(
+            "CREATE TABLE `social_sample`.`users` ENGINE = MergeTree ORDER BY tuple() AS SELECT t.* FROM prod.users AS t WHERE t.user_id IN ("
+        ), "{users}");
+        // User degree counts both FOLLOWS sides and the AUTHORED source side
+        assert!(users.contains("SELECT e.follower_id AS key FROM prod.follows AS e"));
+        assert!(users.contains("SELECT e.followed_id AS key FROM prod.follows AS e"));
+        assert!(users.contains("SELECT e.author_id AS key FROM prod.authored AS e"));
+        assert!(users.contains("round(stratum_rows * 100 / total_rows)"));
+
+        let follows = &plan.tables[3].sql;
+        assert!(
+            follows.ends_with(
+                "WHERE (t.follower_id IN (SELECT user_id FROM `social_sample`.`users`) \
+             AND t.followed_id IN (SELECT user_id FROM `social_sample`.`users`))"
+            ),
+            "{follows}"
+        );
+
+        let uniform = build_sample_plan(
+            &schema,
+            "social",
+            &SampleCall {
+                preserve_degree_distribution: false,
+                ..call
+            },
+        )
+        .unwrap();
+        assert!(uniform.tables[1]
+            .sql
+            .ends_with("ORDER BY cityHash64(key, 0) LIMIT 100)"));
+        assert_eq!(
+            uniform.statements()[0],
+            "CREATE DATABASE IF NOT EXISTS `social_sample`"
+        );
+    }
+
+    #[test]
+    fn test_plan_rejects_sampling_into_a_source_database() {
+        let schema = config().to_graph_schema().unwrap();
+        let call = SampleCall {
+            database: Some("prod".into()),
+            ..SampleCall::default()
+        };
+        let err = build_sample_plan(&schema, "social", &call).unwrap_err();
+        assert!(err.contains("`prod`"), "{err}");
+    }
+
+    #[test]
+    fn test_derived_schema_reads_the_copies() {
+        let config = config();
+        let schema = config.to_graph_schema().unwrap();
+        let plan = build_sample_plan(&schema, "social", &SampleCall::default()).unwrap();
+        let yaml = derive_schema_yaml(&config, "social", &plan).unwrap();
+
+        let derived = GraphSchemaConfig::from_yaml_str(&yaml).expect("derived yaml loads");
+        assert_eq!(derived.name.as_deref(), Some("social_sample"));
+        assert!(!yaml.contains("prod"), "{yaml}");
+        assert!(!yaml.contains("use_final"), "{yaml}");
+        let derived = derived.to_graph_schema().unwrap();
+        let user = derived.node_schema_opt("User").unwrap();
+        assert_eq!(user.full_table_name(), "social_sample.users");
+
+        let record = sample_record(
+            &plan,
+            &[serde_json::json!({"table_name": "users", "rows": "42"})],
+            yaml,
+        );
+        assert_eq!(record["tables"][1]["rows"], 42);
+        assert_eq!(record["tables"][0]["rows"], Value::Null);
+    }
+}
@@ -0,0 +1,699 @@
+//! Weighted shortest path between two nodes (Dijkstra).
+//!
+//! Implements `graph.shortestPath.dijkstra(source, target, config)`: a single
+//! recursive-CTE query that walks outgoing edges from `source`, carrying each
+//! path's accumulated weight, and returns the cheapest path to `target`.
+//!
+//! ```cypher
+//! CALL graph.shortestPath.dijkstra(17, 42, {weightProperty: 'cost'})
+//! CALL graph.shortestPath.dijkstra('A1', 'B7',
+//!     {weightProperty: 'length_m', relTypes: ['ROAD', 'FERRY'], label: 'Junction', maxHops: 60})
+//! CALL graph.shortestPath.dijkstra('LHR', 'CDG',
+//!     {costs: {FLIGHT: 'duration_min + 90', TRAIN: 'duration_min'}})
+//! ```
+//!
+//! | Config key | Default | |
+//! |------------|---------|---|
+//! | `weightProperty` (`relationshipWeightProperty`) | | numeric relationship property |
+//! | `costs` | | map of type name to cost expression |
+//! | `relTypes` (`relationshipTypes`) | the types in `costs`, else every type with `weightProperty`, else every type with a declared `cost` | type name or list |
+//! | `label` | the one label those types connect | |
+//! | `maxHops` | [`DEFAULT_MAX_HOPS`] | 1 to [`MAX_HOPS`] edges per path |
+//!
+//! The cost of an edge is its type's expression in `costs`, else its
+//! `weightProperty`, else the `cost` declared on the edge in the schema. Cost
+//! expressions are written over the edge's property names
+//! (`duration_min + 90`), so each mode of a multi-modal graph can be priced
+//! on its own terms; the edges of every type are searched together.
+//!
+//! `source` and `target` are node id literals. Paths follow edge direction
+//! between nodes of `label` only (same subgraph rules as `graph.pageRank`),
+//! never revisit a node, and edges without a weight are skipped. Weights are
+//! expected to be non-negative; the result is then the cheapest path of at
+//! most `maxHops` edges.
+//!
+//! # Execution Flow
+//!
+//! Like `graph.pageRank`, this bypasses `ProcedureRegistry`:
+//! 1. Handler detects `graph.shortestPath.dijkstra` → [`parse_dijkstra_call`]
+//! 2. [`build_dijkstra_sql`] renders the recursive CTE
+//! 3. Caller runs the SQL; [`dijkstra_record`] shapes the row
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::Value;
+
+use super::graph_page_rank::{extract_rel_types, extract_string, label_subgraph_ctes};
+use super::graph_quality::sql_string;
+use crate::graph_catalog::expression_parser::parse_scalar_expression;
+use crate::graph_catalog::graph_schema::{GraphSchema, RelationshipSchema};
+use crate::open_cypher_parser::ast::{Expression, Literal};
+
+/// Procedure name (matched case-insensitively).
+pub const PROCEDURE_NAME: &str = "graph.shortestPath.dijkstra";
+
+/// Path length bound when the config does not say.
+pub const DEFAULT_MAX_HOPS: u32 = 20;
+
+/// Upper bound on `maxHops`: one recursion step per hop.
+pub const MAX_HOPS: u32 = 100;
+
+/// Check whether a procedure name is `graph.shortestPath.dijkstra`.
+pub fn is_dijkstra_procedure(name: &str) -> bool {
+    name.eq_ignore_ascii_case(PROCEDURE_NAME)
+}
+
+/// Parsed `graph.shortestPath.dijkstra` arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DijkstraCall {
+    /// Source node id, as a SQL literal
+    pub source: String,
+    /// Target node id, as a SQL literal
+    pub target: String,
+    pub weight_property: Option<String>,
+    /// Cost expression per relationship type, over its property names
+    pub costs: BTreeMap<String, String>,
+    /// Empty: the types in `costs`, else every type that has
+    /// `weight_property`, else every type with a declared cost
+    pub rel_types: Vec<String>,
+    pub label: Option<String>,
+    pub max_hops: u32,
+}
+
+/// A node id literal rendered as SQL.
+fn extract_node_id(expr: &Expression<'_>, what: &str) -> Result<String, String> {
+    match expr {
+        Expression::Literal(Literal::Integer(n)) => Ok(n.to_string()),
+        Expression::Literal(Literal::String(s)) => Ok(sql_string(s)),
+        _ => Err(format!(
+            "{}: {} must be an integer or string node id",
+            PROCEDURE_NAME, what
+        )),
+    }
+}
+
+/// Parse `graph.shortestPath.dijkstra(source, target, config)` arguments.
+pub fn parse_dijkstra_call(args: &[&Expression<'_>]) -> Result<DijkstraCall, String> {
+    let [source, target, config] = args else {
+        return Err(format!(
+            "{} requires 3 arguments (source, target, config), got {}",
+            PROCEDURE_NAME,
+            args.len()
+        ));
+    };
+    let Expression::MapLiteral(entries) = config else {
+        return Err(format!("{}: the config must be a map", PROCEDURE_NAME));
+    };
+
+    let mut call = DijkstraCall {
+        source: extract_node_id(source, "the source")?,
+        target: extract_node_id(target, "the target")?,
+        weight_property: None,
+        costs: BTreeMap::new(),
+        rel_types: Vec::new(),
+        label: None,
+        max_hops: DEFAULT_MAX_HOPS,
+    };
+    for (key, value) in entries {
+        match key.to_lowercase().as_str() {
+            "weightproperty" | "relationshipweightproperty" => {
+                call.weight_property =
+                    Some(extract_string(value, PROCEDURE_NAME, "weightProperty")?)
+            }
+            "costs" => call.costs = extract_costs(value)?,
+            "reltypes" | "relationshiptypes" => {
+                call.rel_types = extract_rel_types(value, PROCEDURE_NAME)?
+            }
+            "label" => call.label = Some(extract_string(value, PROCEDURE_NAME, "label")?),
+            "maxhops" => {
+                call.max_hops = match value {
+                    Expression::Literal(Literal::Integer(n))
+                        if (1..=MAX_HOPS as i64).contains(n) =>
+                    {
+                        *n as u32
+                    }
+                    _ => {
+                        return Err(format!(
+                            "{}: maxHops must be an integer from 1 to {}",
+                            PROCEDURE_NAME, MAX_HOPS
+                        ))
+                    }
+                }
+            }
+            other => {
+                return Err(format!(
+                "{}: unknown config key `{}` (expected weightProperty, costs, relTypes, label, maxHops)",
+                PROCEDURE_NAME, other
+            ))
+            }
+        }
+    }
+    Ok(call)
+}
+
+/// `{TYPE: 'expression', ...}`: a cost expression per relationship type.
+fn extract_costs(expr: &Expression<'_>) -> Result<BTreeMap<String, String>, String> {
+    let Expression::MapLiteral(entries) = expr else {
+        return Err(format!(
+            "{}: costs must be a map of relationship type to cost expression",
+            PROCEDURE_NAME
+        ));
+    };
+    if entries.is_empty() {
+        return Err(format!("{}: costs is empty", PROCEDURE_NAME));
+    }
+    let mut costs = BTreeMap::new();
+    for (rel_type, value) in entries {
+        let cost = extract_string(value, PROCEDURE_NAME, "each cost")?;
+        parse_scalar_expression(&cost).map_err(|e| {
+            format!(
+                "{}: invalid cost `{}` for `{}`: {}",
+                PROCEDURE_NAME, cost, rel_type, e
+            )
+        })?;
+        costs.insert(rel_type.to_string(), cost);
+    }
+    Ok(costs)
+}
+
+/// Which types the search takes by default, for error messages.
+fn default_types(call: &DijkstraCall) -> String {
+    match &call.weight_property {
+        _ if !call.costs.is_empty() => "in `costs`".to_string(),
+        Some(property) => format!("with property `{}`", property),
+        None => "with a cost (set weightProperty or costs, or declare `cost` on edges)".to_string(),
+    }
+}
+
+/// Whether `rel_type` is searched when the call names no types.
+fn is_default_type(
+    schema: &GraphSchema,
+    call: &DijkstraCall,
+    rel_type: &str,
+    rel: &RelationshipSchema,
+) -> bool {
+    if !call.costs.is_empty() {
+        call.costs.contains_key(rel_type)
+    } else if let Some(property) = &call.weight_property {
+        rel.property_mappings.contains_key(property)
+    } else {
+        schema.edge_cost(rel_type).is_some()
+    }
+}
+
+/// The weight of one `rel_type` table as SQL over `alias`: its expression in
+/// `costs`, else `weightProperty`, else its declared cost.
+fn edge_weight(
+    schema: &GraphSchema,
+    call: &DijkstraCall,
+    rel_type: &str,
+    rel: &RelationshipSchema,
+    alias: &str,
+) -> Result<String, String> {
+    let mut cost = call.costs.get(rel_type).map(String::as_str);
+    if cost.is_none() {
+        if let Some(property) = &call.weight_property {
+            if let Some(value) = rel.property_mappings.get(property) {
+                return Ok(value.to_sql(alias));
+            }
+        }
+        cost = schema.edge_cost(rel_type);
+    }
+    let cost = cost.ok_or_else(|| match &call.weight_property {
+        Some(property) => format!(
+            "{}: relationship type `{}` has no property `{}`",
+            PROCEDURE_NAME, rel_type, property
+        ),
+        None => format!(
+            "{}: relationship type `{}` has no cost (set weightProperty or costs, or declare `cost` on the edge)",
+            PROCEDURE_NAME, rel_type
+        ),
+    })?;
+    parse_scalar_expression(cost)
+        .and_then(|expr| {
+            expr.to_sql_mapped(&|property| {
+                rel.property_mappings
+                    .get(property)
+                    .map(|value| value.to_sql(alias))
+                    .ok_or_else(|| format!("`{}` is not a property of `{}`", property, rel_type))
+            })
+        })
+        .map_err(|e| {
+            format!(
+                "{}: cost `{}` of `{}`: {}",
+                PROCEDURE_NAME, cost, rel_type, e
+            )
+        })
+}
+
+/// The label and relationship types the search runs on: the configured ones,
+/// or every self-connecting default type (see [`is_default_type`]).
+fn resolve_subgraph(
+    schema: &GraphSchema,
+    call: &DijkstraCall,
+) -> Result<(String, Vec<String>), String> {
+    // label -> types connecting it to itself and carrying a weight
+    let mut candidates: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (key, rel) in schema.get_relationships_schemas() {
+        let rel_type = key.split("::").next().unwrap_or(key);
+        let wanted = if call.rel_types.is_empty() {
+            is_default_type(schema, call, rel_type, rel)
+        } else {
+            call.rel_types.iter().any(|t| t == rel_type)
+        };
+        if wanted && rel.from_node == rel.to_node {
+            let types = candidates.entry(rel.from_node.as_str()).or_default();
+            if !types.iter().any(|t| t == rel_type) {
+                types.push(rel_type.to_string());
+            }
+        }
+    }
+
+    let label = match &call.label {
+        Some(label) => label.clone(),
+        None => match candidates.keys().collect::<Vec<_>>().as_slice() {
+            [label] => label.to_string(),
+            [] => {
+                return Err(format!(
+                    "{}: no relationship type {} connects a label to itself",
+                    PROCEDURE_NAME,
+                    default_types(call)
+                ))
+            }
+            labels => {
+                return Err(format!(
+                    "{}: relationship types {} connect several labels ({}); set `label`",
+                    PROCEDURE_NAME,
+                    default_types(call),
+                    labels
+                        .iter()
+                        .map(|l| format!("`{}`", l))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            }
+        },
+    };
+
+    let rel_types = if call.rel_types.is_empty() {
+        candidates.remove(label.as_str()).ok_or_else(|| {
+            format!(
+                "{}: no relationship type {} connects `{}` to `{}`",
+                PROCEDURE_NAME,
+                default_types(call),
+                label,
+                label
+            )
+        })?
+    } else {
+        call.rel_types.clone()
+    };
+    Ok((label, rel_types))
+}
+
+/// Build the shortest-path query.
+///
+/// ```text
+/// sp_nodes  node ids of the label
+/// sp_edges  (src, dst, weight) between those nodes, one UNION ALL branch
+///           per edge table with its type's cost as the weight
+/// sp_paths  source alone, then each step extends every path by one edge
+///           and keeps only the cheapest new path per reached node
+/// ```
+///
+/// Per-node pruning keeps every recursion step at most one row per node, so
+/// a step costs one join of the frontier with the edges. Paths stop at the
+/// target and after `maxHops` edges; the cheapest path that reached the
+/// target at any step wins.
+pub fn build_dijkstra_sql(schema: &GraphSchema, call: &DijkstraCall) -> Result<String, String> {
+    let (label, rel_types) = resolve_subgraph(schema, call)?;
+    let [nodes, edges] = label_subgraph_ctes(
+        schema,
+        PROCEDURE_NAME,
+        &label,
+        &rel_types,
+        "sp",
+        Some(&|rel_type, rel, alias| edge_weight(schema, call, rel_type, rel, alias)),
+    )?;
+    let paths = format!(
+        "sp_paths AS (\
+         SELECT node_id, [node_id] AS path_nodes, [toFloat64(0)] AS costs, toFloat64(0) AS total_weight \
+         FROM sp_nodes WHERE node_id = {source} \
+         UNION ALL \
+         SELECT node_id, best.1 AS path_nodes, best.2 AS costs, total_weight FROM \
+         (SELECT node_id, argMin((path, path_costs), w) AS best, min(w) AS total_weight FROM \
+         (SELECT e.dst AS node_id, p.total_weight + e.weight AS w, \
+         arrayPushBack(p.path_nodes, e.dst) AS path, arrayPushBack(p.costs, w) AS path_costs \
+         FROM sp_paths AS p INNER JOIN sp_edges AS e ON e.src = p.node_id \
+         WHERE length(p.path_nodes) <= {max_hops} AND p.node_id != {target} \
+         AND NOT has(p.path_nodes, e.dst)) \
+         GROUP BY node_id))",
+        source = call.source,
+        target = call.target,
+        max_hops = call.max_hops,
+    );
+
+    Ok(format!(
+        "WITH RECURSIVE {},\n{},\n{}\n\
+         SELECT {source} AS sourceNodeId, {target} AS targetNodeId, total_weight AS totalCost, \
+         path_nodes AS nodeIds, costs FROM sp_paths WHERE node_id = {target} \
+         ORDER BY totalCost, length(nodeIds) LIMIT 1",
+        nodes,
+        edges,
+        paths,
+        source = call.source,
+        target = call.target,
+    ))
+}
+
+/// The result record: ids as returned, costs as floats.
+pub fn dijkstra_record(row: &Value) -> HashMap<String, Value> {
+    fn float(value: &Value) -> Value {
+        match value {
+            Value::String(s) => s.parse::<f64>().map(Value::from).unwrap_or(Value::Null),
+            other => other.clone(),
+        }
+    }
+
+    let mut record: HashMap<String, Value> = ["sourceNodeId", "targetNodeId", "nodeIds"]
+        .into_iter()
+        .map(|column| {
+            (
+                column.to_string(),
+                row.get(column).cloned().unwrap_or(Value::Null),
+            )
+        })
+        .collect();
+    record.insert(
+        "totalCost".to_string(),
+        row.get("totalCost").map(float).unwrap_or(Value::Null),
+    );
+    record.insert(
+        "costs".to_string(),
+        match row.get("costs") {
+            Some(Value::Array(costs)) => Value::Array(costs.iter().map(float).collect()),
+            _ => Value::Null,
+        },
+    );
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+    use crate::open_cypher_parser::ast::CypherStatement;
+    use serde_json::json;
+
+    const SCHEMA_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: Junction
+      database: roads
+      table: junctions
+      node_id: junction_id
+      property_mappings:
+        junction_id: junction_id
+    - label: Depot
+      database: roads
+      table: depots
+      node_id: depot_id
+      property_mappings:
+        depot_id: depot_id
+  edges:
+    - type: ROAD
+      database: roads
+      table: road_segments
+      from_id: from_junction
+      to_id: to_junction
+      from_node: Junction
+      to_node: Junction
+      property_mappings:
+        cost: travel_seconds
+    - type: FERRY
+      database: roads
+      table: ferries
+      from_id: from_junction
+      to_id: to_junction
+      from_node: Junction
+      to_node: Junction
+      property_mappings:
+        cost: crossing_seconds
+    - type: TRUCK_ROUTE
+      database: roads
+      table: truck_routes
+      from_id: from_depot
+      to_id: to_depot
+      from_node: Depot
+      to_node: Depot
+      property_mappings:
+        distance: km
+"#;
+
+    fn parse(call: &str) -> Result<DijkstraCall, String> {
+        let (_, stmt) = crate::open_cypher_parser::parse_cypher_statement(call).unwrap();
+        let CypherStatement::ProcedureCall(pc) = stmt else {
+            panic!("expected a procedure call");
+        };
+        assert!(is_dijkstra_procedure(pc.procedure_name));
+        let args: Vec<&Expression> = pc.arguments.iter().collect();
+        parse_dijkstra_call(&args)
+    }
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .unwrap()
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_ids_and_config() {
+        let call =
+            parse("CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'cost'})").unwrap();
+        assert_eq!((call.source.as_str(), call.target.as_str()), ("1", "9"));
+        assert_eq!(call.weight_property.as_deref(), Some("cost"));
+        assert!(call.rel_types.is_empty());
+        assert_eq!(call.max_hops, DEFAULT_MAX_HOPS);
+
+        let call = parse(
+            "CALL graph.shortestPath.Dijkstra('A1', 'B7', \
+             {relationshipWeightProperty: 'cost', relTypes: 'ROAD', label: 'Junction', maxHops: 5})",
+        )
+        .unwrap();
+        assert_eq!(call.source, "'A1'");
+        assert_eq!(call.rel_types, vec!["ROAD"]);
+        assert_eq!(call.label.as_deref(), Some("Junction"));
+        assert_eq!(call.max_hops, 5);
+
+        for (call, expected) in [
+            (
+                "CALL graph.shortestPath.dijkstra(1, 9)",
+                "requires 3 arguments",
+            ),
+            (
+                "CALL graph.shortestPath.dijkstra(1, 9, {costs: ['ROAD']})",
+                "costs must be a map",
+            ),
+            (
+                "CALL graph.shortestPath.dijkstra(1, 9, {costs: {ROAD: 'cost +'}})",
+                "invalid cost `cost +` for `ROAD`",
+            ),
+            (
+                "CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'cost', maxHops: 0})",
+                "maxHops",
+            ),
+            (
+                "CALL graph.shortestPath.dijkstra(1.5, 9, {weightProperty: 'cost'})",
+                "the source must be",
+            ),
+            (
+                "CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'cost', damping: 1})",
+                "unknown config key",
+            ),
+        ] {
+            let err = parse(call).unwrap_err();
+            assert!(err.contains(expected), "{call}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_sql_carries_weight_and_prunes_per_node() {
+        let call =
+            parse("CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'cost', maxHops: 4})")
+                .unwrap();
+        let sql = build_dijkstra_sql(&schema(), &call).unwrap();
+        assert!(sql.starts_with("WITH RECURSIVE sp_nodes AS (SELECT DISTINCT t.junction_id AS node_id FROM roads.junctions AS t)"), "{sql}");
+        // Both Junction types with a `cost`, and not TRUCK_ROUTE
+        assert!(
+            sql.contains("toFloat64(t.travel_seconds) AS weight FROM roads.road_segments AS t"),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("toFloat64(t.crossing_seconds) AS weight FROM roads.ferries AS t"),
+            "{sql}"
+        );
+        assert!(!sql.contains("truck_routes"), "{sql}");
+        assert!(sql.contains(" AND weight IS NOT NULL)"), "{sql}");
+        assert!(
+            sql.contains("FROM sp_nodes WHERE node_id = 1 UNION ALL"),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("argMin((path, path_costs), w) AS best, min(w) AS total_weight"),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("WHERE length(p.path_nodes) <= 4 AND p.node_id != 9 AND NOT has(p.path_nodes, e.dst)"),
+            "{sql}"
+        );
+        assert!(
+            sql.ends_with(
+                "FROM sp_paths WHERE node_id = 9 ORDER BY totalCost, length(nodeIds) LIMIT 1"
+            ),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn test_subgraph_resolution() {
+        let schema = schema();
+
+        let call =
+            parse("CALL graph.shortestPath.dijkstra('x', 'y', {weightProperty: 'distance'})")
+                .unwrap();
+        assert_eq!(
+            resolve_subgraph(&schema, &call).unwrap(),
+            ("Depot".to_string(), vec!["TRUCK_ROUTE".to_string()])
+        );
+
+        let call = parse(
+            "CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'cost', relTypes: ['FERRY']})",
+        )
+        .unwrap();
+        let sql = build_dijkstra_sql(&schema, &call).unwrap();
+        assert!(!sql.contains("road_segments"), "{sql}");
+
+        for (call, expected) in [
+            (
+                "CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'toll'})",
+                "no relationship type with property `toll`",
+            ),
+            (
+                "CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'cost', relTypes: 'TRUCK_ROUTE'})",
+                "`TRUCK_ROUTE` has no property `cost`",
+            ),
+            (
+                "CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'cost', label: 'Depot'})",
+                "connects `Depot` to `Depot`",
+            ),
+        ] {
+            let err = build_dijkstra_sql(&schema, &parse(call).unwrap()).unwrap_err();
+            assert!(err.contains(expected), "{call}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_per_type_costs() {
+        let schema = schema();
+
+        // Each type priced by its own expression over its properties
+        let call = parse(
+            "CALL graph.shortestPath.dijkstra(1, 9, \
+             {costs: {ROAD: 'cost * 2', FERRY: 'greatest(cost, 600) + 900'}})",
+        )
+        .unwrap();
+        let sql = build_dijkstra_sql(&schema, &call).unwrap();
+        assert!(
+            sql.contains(
+                "toFloat64((t.travel_seconds * 2)) AS weight FROM roads.road_segments AS t"
+            ),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(
+                "toFloat64((greatest(t.crossing_seconds, 600) + 900)) AS weight FROM roads.ferries AS t"
+            ),
+            "{sql}"
+        );
+
+        // `costs` overrides weightProperty for its types only
+        let call = parse(
+            "CALL graph.shortestPath.dijkstra(1, 9, \
+             {weightProperty: 'cost', relTypes: ['ROAD', 'FERRY'], costs: {FERRY: '1800'}})",
+        )
+        .unwrap();
+        let sql = build_dijkstra_sql(&schema, &call).unwrap();
+        assert!(
+            sql.contains("toFloat64(t.travel_seconds) AS weight"),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("toFloat64(1800) AS weight FROM roads.ferries"),
+            "{sql}"
+        );
+
+        let call =
+            parse("CALL graph.shortestPath.dijkstra(1, 9, {costs: {ROAD: 'toll_eur'}})").unwrap();
+        let err = build_dijkstra_sql(&schema, &call).unwrap_err();
+        assert!(
+            err.contains("`toll_eur` is not a property of `ROAD`"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_declared_costs() {
+        let yaml = SCHEMA_YAML
+            .replace(
+                "      property_mappings:\n        cost: travel_seconds",
+                "      cost: \"cost + 30\"\n      property_mappings:\n        cost: travel_seconds",
+            )
+            .replace(
+                "      property_mappings:\n        cost: crossing_seconds",
+                "      cost: cost\n      property_mappings:\n        cost: crossing_seconds",
+            );
+        let declared = GraphSchemaConfig::from_yaml_str(&yaml)
+            .unwrap()
+            .to_graph_schema()
+            .unwrap();
+        assert_eq!(declared.edge_cost("ROAD"), Some("cost + 30"));
+
+        // Without weightProperty or costs, the declared costs apply
+        let call = parse("CALL graph.shortestPath.dijkstra(1, 9, {})").unwrap();
+        assert_eq!(
+            resolve_subgraph(&declared, &call).unwrap(),
+            (
+                "Junction".to_string(),
+                vec!["FERRY".to_string(), "ROAD".to_string()]
+            )
+        );
+        let sql = build_dijkstra_sql(&declared, &call).unwrap();
+        assert!(
+            sql.contains("toFloat64((t.travel_seconds + 30)) AS weight"),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("toFloat64(t.crossing_seconds) AS weight"),
+            "{sql}"
+        );
+
+        // Without declared costs there is nothing to search
+        let err = build_dijkstra_sql(&schema(), &call).unwrap_err();
+        assert!(err.contains("no relationship type with a cost"), "{err}");
+    }
+
+    #[test]
+    fn test_record_parses_quoted_costs() {
+        let record = dijkstra_record(&json!({
+            "sourceNodeId": 1,
+            "targetNodeId": 9,
+            "totalCost": "7.5",
+            "nodeIds": [1, 4, 9],
+            "costs": [0, "2.5", "7.5"],
+        }));
+        assert_eq!(record["totalCost"], json!(7.5));
+        assert_eq!(record["costs"], json!([0, 2.5, 7.5]));
+        assert_eq!(record["nodeIds"], json!([1, 4, 9]));
+    }
+}
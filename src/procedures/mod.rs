@@ -10,6 +10,7 @@
 //! - `db.propertyKeys()` - Returns all unique property keys across nodes and relationships
 //! - `db.schema.nodeTypeProperties()` - Returns property metadata for each node type
 //! - `db.schema.relTypeProperties()` - Returns property metadata for each relationship type
+//! - `db.schema.visualization()` - Returns the schema as a virtual graph of labels and types
 //! - `apoc.meta.schema()` - Returns APOC-format schema metadata for MCP server compatibility
 //!
 //! # Architecture
@@ -39,10 +40,18 @@ pub mod db_property_keys;
 pub mod db_relationship_types;
 pub mod db_schema_node_type_properties;
 pub mod db_schema_rel_type_properties;
+pub mod db_schema_visualization;
 pub mod dbms_components;
 pub mod dbms_stubs;
 pub mod executor;
 pub mod fulltext_search;
+pub mod graph_components;
+pub mod graph_construct;
+pub mod graph_export;
+pub mod graph_page_rank;
+pub mod graph_quality;
+pub mod graph_sample;
+pub mod graph_shortest_path;
 pub mod return_evaluator;
 pub mod show_databases;
 pub mod vector_search;
@@ -93,6 +102,10 @@ impl ProcedureRegistry {
             "db.schema.relTypeProperties",
             Arc::new(db_schema_rel_type_properties::execute),
         );
+        registry.register(
+            "db.schema.visualization",
+            Arc::new(db_schema_visualization::execute),
+        );
 
         // Register APOC procedures for MCP server compatibility
         registry.register("apoc.meta.schema", Arc::new(apoc_meta_schema::execute));
@@ -101,10 +114,6 @@ impl ProcedureRegistry {
         // Neo4j Browser sidebar/connect probes — return valid (possibly empty)
         // results instead of an Unknown-procedure FAILURE, which otherwise
         // cascades into a blank sidebar and uncaptioned graph rendering.
-        registry.register(
-            "db.schema.visualization",
-            Arc::new(browser_compat::db_schema_visualization),
-        );
         registry.register("db.indexes", Arc::new(browser_compat::db_indexes));
         registry.register(
             "dbms.licenseAgreementDetails",
@@ -164,7 +173,7 @@ mod tests {
     #[test]
     fn test_registry_creation() {
         let registry = ProcedureRegistry::new();
-        // 17 procedures (6 core + 2 apoc + 6 dbms stubs + 3 Browser-compat probes)
+        // 17 procedures (7 core + 2 apoc + 6 dbms stubs + 2 Browser-compat probes)
         assert_eq!(registry.names().len(), 17);
 
         // Verify all expected procedures are registered
@@ -102,6 +102,12 @@ pub(crate) fn undirected_vlp_single_walk_core(
     if spec.exact_hop_count() == Some(1) {
         return false;
     }
+    doubled_edge_rel_schema_ok(graph_rel, graph_schema)
+}
+
+/// Relationship-schema half of the doubled-edge scope, shared by the VLP and
+/// OPTIONAL single-hop predicates.
+fn doubled_edge_rel_schema_ok(graph_rel: &GraphRel, graph_schema: &GraphSchema) -> bool {
     // Exactly one known relationship type (multi-type has its own skip above).
     // Labels at this stage may be composite schema keys ("FOLLOWS::User::User");
     // resolve those directly, falling back to the plain-type index.
@@ -134,6 +140,27 @@ pub(crate) fn undirected_vlp_single_walk_core(
         && rel_schema.doubled_edge_walk_compatible()
 }
 
+/// OPTIONAL undirected single hops over the same doubled-edge set. The
+/// two-arm split is complete for a required hop, but each OPTIONAL arm
+/// null-extends on its own: a node with edges in only one direction gets its
+/// real match from one arm plus an `(a, NULL)` row from the other. One LEFT
+/// JOIN against the doubled edges null-extends only when there is no edge in
+/// either direction.
+///
+/// Same schema scope as [`undirected_vlp_single_walk_core`]; named paths keep
+/// the split (path functions are resolved per arm).
+pub(crate) fn undirected_optional_hop_single_walk_core(
+    graph_rel: &GraphRel,
+    graph_schema: &GraphSchema,
+) -> bool {
+    graph_rel.variable_length.is_none()
+        && graph_rel.is_optional == Some(true)
+        && graph_rel.shortest_path_mode.is_none()
+        && graph_rel.pattern_combinations.is_none()
+        && graph_rel.path_variable.is_none()
+        && doubled_edge_rel_schema_ok(graph_rel, graph_schema)
+}
+
 /// #617: rewrite every in-scope undirected VLP GraphRel (see
 /// [`undirected_vlp_single_walk_scope`]) to `direction: Outgoing` +
 /// `was_undirected: Some(true)`. Returns `None` when nothing changed.
@@ -145,12 +172,30 @@ fn normalize_undirected_vlp_rels(
     plan: &Arc<LogicalPlan>,
     graph_schema: &GraphSchema,
 ) -> Option<Arc<LogicalPlan>> {
-    fn walk(plan: &LogicalPlan, graph_schema: &GraphSchema, changed: &mut bool) -> LogicalPlan {
-        let mapped = plan.map_children(|child| walk(child, graph_schema, changed));
+    // `in_optional_hop`: the node is a child of an OPTIONAL GraphRel. A chain
+    // of optional hops stays on the split path, where the #589 gate rejects it.
+    fn walk(
+        plan: &LogicalPlan,
+        graph_schema: &GraphSchema,
+        changed: &mut bool,
+        in_optional_hop: bool,
+    ) -> LogicalPlan {
+        let is_optional_hop =
+            matches!(plan, LogicalPlan::GraphRel(gr) if gr.is_optional == Some(true));
+        let mapped = plan.map_children(|child| walk(child, graph_schema, changed, is_optional_hop));
         if let LogicalPlan::GraphRel(graph_rel) = &mapped {
-            if undirected_vlp_single_walk_scope(graph_rel, graph_schema) {
+            let standalone_optional_hop = !in_optional_hop
+                && !matches!(
+                    graph_rel.left.as_ref(),
+                    LogicalPlan::GraphRel(inner) if inner.is_optional == Some(true)
+                );
+            if undirected_vlp_single_walk_scope(graph_rel, graph_schema)
+                || (graph_rel.direction == Direction::Either
+                    && standalone_optional_hop
+                    && undirected_optional_hop_single_walk_core(graph_rel, graph_schema))
+            {
                 crate::debug_print!(
-                    "🔄 BidirectionalUnion(#617): undirected VLP '{}' → single doubled-edge walk (no Union split)",
+                    "🔄 BidirectionalUnion(#617): undirected '{}' → single doubled-edge walk (no Union split)",
                     graph_rel.alias
                 );
                 *changed = true;
@@ -164,7 +209,7 @@ fn normalize_undirected_vlp_rels(
         mapped
     }
     let mut changed = false;
-    let new_plan = walk(plan, graph_schema, &mut changed);
+    let new_plan = walk(plan, graph_schema, &mut changed, false);
     changed.then(|| Arc::new(new_plan))
 }
 
@@ -1679,16 +1724,19 @@ fn apply_direction_combination_inner(
                 where_predicate: graph_rel.where_predicate.clone(),
                 labels: graph_rel.labels.clone(),
                 is_optional: graph_rel.is_optional,
-                anchor_connection: if is_incoming_swap {
-                    graph_rel.anchor_connection.as_ref().map(|ac| {
-                        if ac == &graph_rel.left_connection {
-                            graph_rel.right_connection.clone()
-                        } else if ac == &graph_rel.right_connection {
-                            graph_rel.left_connection.clone()
-                        } else {
-                            ac.clone()
-                        }
-                    })
+                // The anchor names a node alias, not a side: the swapped
+                // branch still drives from the same (required) node, which
+                // now sits on the right. `None` on an OPTIONAL hop means "the
+                // left node", so spell it out once the sides are swapped —
+                // otherwise the optional node becomes the FROM table of the
+                // reverse branch.
+                anchor_connection: if is_incoming_swap && graph_rel.is_optional == Some(true) {
+                    Some(
+                        graph_rel
+                            .anchor_connection
+                            .clone()
+                            .unwrap_or_else(|| graph_rel.left_connection.clone()),
+                    )
                 } else {
                     graph_rel.anchor_connection.clone()
                 },
@@ -182,6 +182,12 @@ impl AnalyzerPass for CteSchemaResolver {
                     self.analyze_with_graph_schema(skip.input.clone(), plan_ctx, _graph_schema)?;
                 skip.rebuild_or_clone(child_tf, logical_plan.clone())
             }
+            // `WITH ... UNWIND xs AS x MATCH ...` hangs the WithClause under the Unwind
+            LogicalPlan::Unwind(unwind) => {
+                let child_tf =
+                    self.analyze_with_graph_schema(unwind.input.clone(), plan_ctx, _graph_schema)?;
+                unwind.rebuild_or_clone(child_tf, logical_plan.clone())
+            }
             LogicalPlan::Union(union) => {
                 let mut transformed = false;
                 let resolved_inputs: Vec<Arc<LogicalPlan>> = union
@@ -211,13 +217,32 @@ impl AnalyzerPass for CteSchemaResolver {
                 }
             }
 
+            // `WITH ... MATCH ...` puts the earlier stages on the left; a
+            // multi-stage pipeline can hang further WITHs below either side
+            LogicalPlan::CartesianProduct(cp) => {
+                let left_tf =
+                    self.analyze_with_graph_schema(cp.left.clone(), plan_ctx, _graph_schema)?;
+                let right_tf =
+                    self.analyze_with_graph_schema(cp.right.clone(), plan_ctx, _graph_schema)?;
+
+                if left_tf.is_yes() || right_tf.is_yes() {
+                    Transformed::Yes(Arc::new(LogicalPlan::CartesianProduct(
+                        crate::query_planner::logical_plan::CartesianProduct {
+                            left: left_tf.get_plan(),
+                            right: right_tf.get_plan(),
+                            ..cp.clone()
+                        },
+                    )))
+                } else {
+                    Transformed::No(logical_plan.clone())
+                }
+            }
+
             // Leaf nodes - no recursion needed
             LogicalPlan::Empty
             | LogicalPlan::ViewScan(_)
             | LogicalPlan::Cte(_)
-            | LogicalPlan::PageRank(_)
-            | LogicalPlan::Unwind(_)
-            | LogicalPlan::CartesianProduct(_) => Transformed::No(logical_plan.clone()),
+            | LogicalPlan::PageRank(_) => Transformed::No(logical_plan.clone()),
 
             // Write variants — read-side analysis pass-through.
             LogicalPlan::Create(_)
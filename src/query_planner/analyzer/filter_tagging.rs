@@ -1157,7 +1157,7 @@ impl FilterTagging {
                                     "FilterTagging: Found property '{}' in owning edge '{}' ViewScan -> '{}'",
                                     property_access.column.raw(), edge_alias, column
                                 );
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(
+                                crate::graph_catalog::expression_parser::PropertyValue::from_raw(
                                     column,
                                 )
                             } else {
@@ -1171,7 +1171,7 @@ impl FilterTagging {
                                         "FilterTagging: Found property '{}' via fallback in ViewScan -> '{}'",
                                         property_access.column.raw(), column
                                     );
-                                    crate::graph_catalog::expression_parser::PropertyValue::Column(
+                                    crate::graph_catalog::expression_parser::PropertyValue::from_raw(
                                         column,
                                     )
                                 } else {
@@ -1207,7 +1207,7 @@ impl FilterTagging {
                                     "FilterTagging: Found property '{}' in standalone ViewScan -> '{}'",
                                     property_access.column.raw(), column
                                 );
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(
+                                crate::graph_catalog::expression_parser::PropertyValue::from_raw(
                                     column,
                                 )
                             } else {
@@ -1510,8 +1510,19 @@ impl FilterTagging {
                 }
 
                 // Other graph introspection functions (type, labels, label) are handled by ProjectionTagging
-                // for RETURN clauses. In WHERE clauses, pass them through with mapped args.
+                // for RETURN clauses. In WHERE clauses, fold them from the catalog when the alias
+                // has a single label/type; otherwise pass them through with mapped args.
                 if matches!(fn_name_lower.as_str(), "type" | "labels" | "label") {
+                    if let [LogicalExpr::TableAlias(alias)] = fn_call.args.as_slice() {
+                        if let Some(folded) = Self::fold_introspection_fn(
+                            &fn_name_lower,
+                            &alias.0,
+                            plan_ctx,
+                            graph_schema,
+                        ) {
+                            return Ok(folded);
+                        }
+                    }
                     let mut mapped_args = Vec::new();
                     for arg in fn_call.args {
                         mapped_args.push(self.apply_property_mapping_internal(
@@ -1640,6 +1651,43 @@ impl FilterTagging {
                     to: mapped_to,
                 })
             }
+            LogicalExpr::ArraySubscript { array, index } => {
+                // e.g. collect(n.name)[0]
+                let mapped_array = self.apply_property_mapping_internal(
+                    *array,
+                    plan_ctx,
+                    graph_schema,
+                    plan,
+                    preserve_id_function,
+                )?;
+                let mapped_index = self.apply_property_mapping_internal(
+                    *index,
+                    plan_ctx,
+                    graph_schema,
+                    plan,
+                    preserve_id_function,
+                )?;
+                Ok(LogicalExpr::ArraySubscript {
+                    array: Box::new(mapped_array),
+                    index: Box::new(mapped_index),
+                })
+            }
+            LogicalExpr::MapLiteral(entries) => {
+                let mut mapped_entries = Vec::with_capacity(entries.len());
+                for (key, value) in entries {
+                    mapped_entries.push((
+                        key,
+                        self.apply_property_mapping_internal(
+                            value,
+                            plan_ctx,
+                            graph_schema,
+                            plan,
+                            preserve_id_function,
+                        )?,
+                    ));
+                }
+                Ok(LogicalExpr::MapLiteral(mapped_entries))
+            }
             LogicalExpr::LabelExpression {
                 variable,
                 label: check_label,
@@ -1653,6 +1701,25 @@ impl FilterTagging {
 
                 if let Ok(table_ctx) = plan_ctx.get_table_ctx(&variable) {
                     if let Some(known_labels) = table_ctx.get_labels() {
+                        // Labels declared disjoint (`constraints.disjoint_labels`) can
+                        // never co-occur: fold to false without a runtime check.
+                        let constraints = graph_schema.constraints();
+                        if !known_labels.is_empty()
+                            && known_labels
+                                .iter()
+                                .all(|l| constraints.are_disjoint(l, &check_label))
+                        {
+                            log::trace!(
+                                "FilterTagging: LabelExpression {}:{} - disjoint with {:?}",
+                                variable,
+                                check_label,
+                                known_labels
+                            );
+                            return Ok(LogicalExpr::Literal(
+                                crate::query_planner::logical_expr::Literal::Boolean(false),
+                            ));
+                        }
+
                         // Check if this is a polymorphic table with label_column
                         // We need to look up the node schema to see if it has label_column
                         if let Some(first_label) = known_labels.first() {
@@ -2366,6 +2433,63 @@ impl FilterTagging {
     /// Find the label for an alias by looking at GraphNode in the plan tree
     /// This is used for UNION branches where the branch has a typed GraphNode
     /// but the global plan_ctx still has the untyped entry
+    /// Resolve `type(r)`, `labels(n)` or `label(n)` for a WHERE clause from the
+    /// schema, when `alias` is bound to exactly one relationship type or label.
+    ///
+    /// The value is a literal, or the per-row discriminator column when the
+    /// table holds several types or labels. Multi-type aliases (read from VLP
+    /// / UNION CTE columns) return `None`.
+    fn fold_introspection_fn(
+        fn_name_lower: &str,
+        alias: &str,
+        plan_ctx: &PlanCtx,
+        graph_schema: &GraphSchema,
+    ) -> Option<LogicalExpr> {
+        use crate::graph_catalog::expression_parser::PropertyValue;
+        use crate::query_planner::logical_expr::Literal;
+
+        let table_ctx = plan_ctx.get_table_ctx(alias).ok()?;
+        if table_ctx.is_cte_reference() {
+            return None;
+        }
+        let label = match table_ctx.get_labels()?.as_slice() {
+            [label] if !label.is_empty() => label.clone(),
+            _ => return None,
+        };
+        let column = |column: String| {
+            LogicalExpr::PropertyAccessExp(PropertyAccess {
+                table_alias: TableAlias(alias.to_string()),
+                column: PropertyValue::Column(column),
+            })
+        };
+
+        match (fn_name_lower, table_ctx.is_relation()) {
+            ("type", true) => {
+                let rel_schema = graph_schema.get_rel_schema(&label).ok()?;
+                Some(match rel_schema.type_discriminator_column() {
+                    Some(discriminator) => column(discriminator.to_string()),
+                    None => LogicalExpr::Literal(Literal::String(
+                        crate::graph_catalog::composite_key_utils::extract_type_name(&label)
+                            .to_string(),
+                    )),
+                })
+            }
+            ("labels" | "label", false) => {
+                let node_schema = graph_schema.node_schema(&label).ok()?;
+                let value = match node_schema.label_discriminator_column() {
+                    Some(discriminator) => column(discriminator.to_string()),
+                    None => LogicalExpr::Literal(Literal::String(label)),
+                };
+                Some(if fn_name_lower == "labels" {
+                    LogicalExpr::List(vec![value])
+                } else {
+                    value
+                })
+            }
+            _ => None,
+        }
+    }
+
     fn find_label_in_plan(plan: &LogicalPlan, alias: &str) -> Option<String> {
         match plan {
             LogicalPlan::GraphNode(node) => {
@@ -2597,20 +2721,14 @@ impl FilterTagging {
                     if let LogicalPlan::ViewScan(scan) = node.input.as_ref() {
                         // Check from_node_properties first
                         if let Some(from_props) = &scan.from_node_properties {
-                            if let Some(
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(col),
-                            ) = from_props.get(property)
-                            {
-                                return Some(col.clone());
+                            if let Some(col) = from_props.get(property).map(|v| v.raw()) {
+                                return Some(col.to_string());
                             }
                         }
                         // Then check to_node_properties
                         if let Some(to_props) = &scan.to_node_properties {
-                            if let Some(
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(col),
-                            ) = to_props.get(property)
-                            {
-                                return Some(col.clone());
+                            if let Some(col) = to_props.get(property).map(|v| v.raw()) {
+                                return Some(col.to_string());
                             }
                         }
                         // Finally check regular property_mapping
@@ -2639,24 +2757,18 @@ impl FilterTagging {
                     // Check if alias is the left (from) node of THIS relationship
                     if rel.left_connection == alias {
                         if let Some(from_props) = &scan.from_node_properties {
-                            if let Some(
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(col),
-                            ) = from_props.get(property)
-                            {
+                            if let Some(col) = from_props.get(property).map(|v| v.raw()) {
                                 log::trace!("FilterTagging: find_property_in_viewscan - found '{}' in from_node_properties -> '{}'", property, col);
-                                return Some(col.clone());
+                                return Some(col.to_string());
                             }
                         }
                     }
                     // Check if alias is the right (to) node of THIS relationship
                     if rel.right_connection == alias {
                         if let Some(to_props) = &scan.to_node_properties {
-                            if let Some(
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(col),
-                            ) = to_props.get(property)
-                            {
+                            if let Some(col) = to_props.get(property).map(|v| v.raw()) {
                                 log::trace!("FilterTagging: find_property_in_viewscan - found '{}' in to_node_properties -> '{}'", property, col);
-                                return Some(col.clone());
+                                return Some(col.to_string());
                             }
                         }
                     }
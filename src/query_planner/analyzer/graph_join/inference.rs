@@ -2747,8 +2747,24 @@ impl GraphJoinInference {
         // Resolve table names (CTE vs base table)
         let left_table =
             Self::get_table_name_with_prefix(left_cte_name, left_alias, left_node_schema, plan_ctx);
-        let rel_table =
+        let mut rel_table =
             Self::get_rel_table_name_with_prefix(rel_cte_name, rel_alias, rel_schema, plan_ctx);
+        // An OPTIONAL undirected hop kept whole by BidirectionalUnion joins the
+        // doubled-edge CTE (both orientations of every edge) emitted by CTE
+        // extraction, so one LEFT JOIN covers both directions.
+        if _graph_rel.was_undirected == Some(true)
+            && rel_table == format!("{}.{}", rel_schema.database, rel_schema.table_name)
+            && crate::query_planner::analyzer::bidirectional_union::undirected_optional_hop_single_walk_core(
+                _graph_rel,
+                plan_ctx.schema(),
+            )
+        {
+            rel_table = crate::sql_generator::emitters::clickhouse::variable_length_cte::undirected_doubled_edges_cte_name(
+                &_graph_rel.left_connection,
+                &_graph_rel.right_connection,
+                &rel_table,
+            );
+        }
         let right_table = Self::get_table_name_with_prefix(
             right_cte_name,
             right_alias,
@@ -346,11 +346,9 @@ pub fn generate_pattern_joins(
                     let left_id = own_table_id(&ctx.left_node, "FkEdgeJoin left")?;
                     let r_left_id =
                         helpers::resolve_identifier(&left_id, t.left_cte_name, plan_ctx);
-                    let r_from_id = Identifier::Single(helpers::resolve_column(
-                        from_id,
-                        t.right_cte_name,
-                        plan_ctx,
-                    ));
+                    let from_ident = Identifier::from_comma_separated(from_id);
+                    let r_from_id =
+                        helpers::resolve_identifier(&from_ident, t.right_cte_name, plan_ctx);
                     // #632: a SELF-REFERENCING FK-edge (from_node == to_node, one
                     // physical table aliased twice) needs DIFFERENT columns on the
                     // two aliases: the FROM/child row carries the FK column
@@ -368,22 +366,18 @@ pub fn generate_pattern_joins(
                     //     to_id=replyOfCommentId (FK) → FK = to_id.
                     // Correct join is always `child(from-node).FK = parent(to-node)
                     // .node_id`, verified live against both schemas.
-                    let node_id_col = first_col(&left_id); // self-ref: left/right same table
-                    let fk_col = if from_id == &node_id_col {
-                        to_id.clone()
+                    // Composite keys compare the whole column list, so a
+                    // composite FK pairs column-by-column with the composite PK.
+                    let fk_ident = if from_ident == left_id {
+                        Identifier::from_comma_separated(to_id)
                     } else {
-                        from_id.clone()
+                        from_ident.clone()
                     };
-                    let r_selfref_left = Identifier::Single(helpers::resolve_column(
-                        &fk_col,
-                        t.left_cte_name,
-                        plan_ctx,
-                    ));
-                    let r_selfref_right = Identifier::Single(helpers::resolve_column(
-                        &node_id_col,
-                        t.right_cte_name,
-                        plan_ctx,
-                    ));
+                    // self-ref: left/right same table, so the PK is left_id on both
+                    let r_selfref_left =
+                        helpers::resolve_identifier(&fk_ident, t.left_cte_name, plan_ctx);
+                    let r_selfref_right =
+                        helpers::resolve_identifier(&left_id, t.right_cte_name, plan_ctx);
                     let (cond_left_id, cond_right_id) = if *is_self_referencing {
                         (&r_selfref_left, &r_selfref_right)
                     } else {
@@ -433,11 +427,11 @@ pub fn generate_pattern_joins(
                     let right_id = own_table_id(&ctx.right_node, "FkEdgeJoin right")?;
                     let r_right_id =
                         helpers::resolve_identifier(&right_id, t.right_cte_name, plan_ctx);
-                    let r_to_id = Identifier::Single(helpers::resolve_column(
-                        to_id,
+                    let r_to_id = helpers::resolve_identifier(
+                        &Identifier::from_comma_separated(to_id),
                         t.left_cte_name,
                         plan_ctx,
-                    ));
+                    );
                     let left_avail = already_available.contains(t.left_alias);
                     let right_avail = already_available.contains(t.right_alias);
 
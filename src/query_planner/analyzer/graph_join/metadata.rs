@@ -346,6 +346,21 @@ pub fn expr_references_alias(expr: &LogicalExpr, alias: &str) -> bool {
             .iter()
             .any(|operand| expr_references_alias(operand, alias)),
         LogicalExpr::List(list) => list.iter().any(|item| expr_references_alias(item, alias)),
+        LogicalExpr::MapLiteral(entries) => entries
+            .iter()
+            .any(|(_, value)| expr_references_alias(value, alias)),
+        LogicalExpr::ArraySubscript { array, index } => {
+            expr_references_alias(array, alias) || expr_references_alias(index, alias)
+        }
+        LogicalExpr::ArraySlicing { array, from, to } => {
+            expr_references_alias(array, alias)
+                || from
+                    .as_deref()
+                    .is_some_and(|f| expr_references_alias(f, alias))
+                || to
+                    .as_deref()
+                    .is_some_and(|t| expr_references_alias(t, alias))
+        }
         LogicalExpr::Case(case) => {
             if let Some(expr) = &case.expr {
                 if expr_references_alias(expr, alias) {
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use crate::query_planner::{
     analyzer::analyzer_pass::{AnalyzerPass, AnalyzerResult},
-    logical_expr::LogicalExpr,
+    logical_expr::{visitors::HasAggregateCheck, LogicalExpr},
     logical_plan::{GroupBy, LogicalPlan, Projection, ProjectionItem},
     plan_ctx::PlanCtx,
     transformed::Transformed,
@@ -47,39 +47,21 @@ impl GroupByBuilding {
         }
     }
 
-    /// Check if an expression contains any aggregate function calls (recursively).
-    /// This is needed to detect computed aggregates like COUNT(b) * 10.
-    fn contains_aggregate(expr: &LogicalExpr) -> bool {
-        match expr {
-            LogicalExpr::AggregateFnCall(_) => true,
-            LogicalExpr::OperatorApplicationExp(op) => {
-                op.operands.iter().any(Self::contains_aggregate)
-            }
-            LogicalExpr::ScalarFnCall(func) => func.args.iter().any(Self::contains_aggregate),
-            LogicalExpr::List(list) => list.iter().any(Self::contains_aggregate),
-            LogicalExpr::Case(case_expr) => {
-                // Check if CASE expression contains aggregates in:
-                // 1. The optional simple CASE expression
-                // 2. Any WHEN condition or THEN value
-                // 3. The optional ELSE expression
-                if let Some(expr) = &case_expr.expr {
-                    if Self::contains_aggregate(expr) {
-                        return true;
-                    }
-                }
-                for (when_cond, then_val) in &case_expr.when_then {
-                    if Self::contains_aggregate(when_cond) || Self::contains_aggregate(then_val) {
-                        return true;
-                    }
-                }
-                if let Some(else_expr) = &case_expr.else_expr {
-                    if Self::contains_aggregate(else_expr) {
-                        return true;
-                    }
+    /// Grouping keys contributed by a projection item: the item itself when
+    /// it has no aggregate, otherwise the non-literal, non-aggregate entries
+    /// of a map literal or map projection (recursively). Other aggregating
+    /// items contribute nothing.
+    fn collect_grouping_keys(expr: &LogicalExpr, keys: &mut Vec<LogicalExpr>) {
+        if !HasAggregateCheck::check(expr) {
+            keys.push(expr.clone());
+            return;
+        }
+        if let LogicalExpr::MapLiteral(entries) = expr {
+            for (_, value) in entries {
+                if !matches!(value, LogicalExpr::Literal(_)) {
+                    Self::collect_grouping_keys(value, keys);
                 }
-                false
             }
-            _ => false,
         }
     }
 }
@@ -124,11 +106,12 @@ impl GroupByBuilding {
     ) -> Transformed<Arc<LogicalPlan>> {
         match node.as_ref() {
             LogicalPlan::Projection(projection) => {
-                // Use contains_aggregate to properly detect aggregates including computed expressions
+                // Every item without an aggregate anywhere inside it (including
+                // `collect(x)[0]`, `{k: count(*)}`) is an implicit grouping key
                 let non_agg_projections: Vec<ProjectionItem> = projection
                     .items
                     .iter()
-                    .filter(|item| !Self::contains_aggregate(&item.expression))
+                    .filter(|item| !HasAggregateCheck::check(&item.expression))
                     .cloned()
                     .collect();
 
@@ -139,8 +122,14 @@ impl GroupByBuilding {
                     non_agg_projections.len()
                 );
 
-                if non_agg_projections.len() < projection.items.len()
-                    && !non_agg_projections.is_empty()
+                // Map entries next to an aggregate (`n {.name, total: count(f)}`)
+                // are grouping keys too, as in Neo4j
+                let mut grouping_exprs: Vec<LogicalExpr> = Vec::new();
+                for item in &projection.items {
+                    Self::collect_grouping_keys(&item.expression, &mut grouping_exprs);
+                }
+
+                if non_agg_projections.len() < projection.items.len() && !grouping_exprs.is_empty()
                 {
                     // Projection mixes aggregates and plain expressions — wrap
                     // it in a GroupBy keyed on the non-aggregate items. (The
@@ -183,10 +172,7 @@ impl GroupByBuilding {
                         };
                     Transformed::Yes(Arc::new(LogicalPlan::GroupBy(GroupBy {
                         input: wrapped,
-                        expressions: non_agg_projections
-                            .into_iter()
-                            .map(|item| item.expression)
-                            .collect(),
+                        expressions: grouping_exprs,
                         having_clause: None,
                         is_materialization_boundary: false,
                         exposed_alias: None,
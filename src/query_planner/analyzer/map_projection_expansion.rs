@@ -0,0 +1,239 @@
+//! Analyzer pass that expands the `.*` selector of a map projection into one
+//! entry per property of the projected variable.
+//!
+//! The parser reads a map projection `n {.name, .*}` as a map literal, with
+//! `.*` kept as the entry `*: n.*` because the properties depend on `n`'s
+//! labels:
+//!
+//! ```cypher
+//! MATCH (u:User) RETURN u {.*, city: toUpper(u.city)}
+//! ```
+//!
+//! Before: `MapLiteral [("*", u.*), ("city", toUpper(u.city))]`
+//! After: `MapLiteral [("country", u.country), ("email", u.email), ...,
+//! ("city", toUpper(u.city))]`, which later passes map to columns like any
+//! other property access.
+//!
+//! Properties come out sorted by name; explicit entries replace a property of
+//! the same name. A variable with several possible labels gets the properties
+//! every label maps. `.*` on anything but a node or single-hop relationship is
+//! rejected. A RETURN item that is exactly `n {.*}` never gets here: it keeps
+//! returning the whole node (see `ReturnItem::all_properties`).
+
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+
+use crate::graph_catalog::{expression_parser::PropertyValue, graph_schema::GraphSchema};
+use crate::query_planner::{
+    analyzer::{
+        analyzer_pass::AnalyzerResult, errors::AnalyzerError,
+        temporal_accessor_resolution::collect_entity_aliases,
+    },
+    logical_expr::{
+        visitors::{map_expression, ExprRewrite},
+        LogicalExpr, PropertyAccess, TableAlias,
+    },
+    logical_plan::{LogicalPlan, OrderByItem, ProjectionItem},
+    plan_ctx::PlanCtx,
+    transformed::Transformed,
+};
+
+/// Map key the parser gives the `.*` selector
+const ALL_PROPERTIES_KEY: &str = "*";
+
+/// Main entry point: expands `.*` selectors throughout the plan tree
+pub fn expand_map_projections(
+    plan: Arc<LogicalPlan>,
+    plan_ctx: &PlanCtx,
+    graph_schema: &GraphSchema,
+) -> AnalyzerResult<Arc<LogicalPlan>> {
+    let mut entity_aliases = HashSet::new();
+    collect_entity_aliases(&plan, &mut entity_aliases);
+    Expander {
+        plan_ctx,
+        graph_schema,
+        entity_aliases,
+    }
+    .rewrite_plan(plan)
+}
+
+struct Expander<'a> {
+    plan_ctx: &'a PlanCtx,
+    graph_schema: &'a GraphSchema,
+    /// Aliases bound to a whole node or relationship
+    entity_aliases: HashSet<String>,
+}
+
+impl Expander<'_> {
+    fn rewrite_plan(&self, plan: Arc<LogicalPlan>) -> AnalyzerResult<Arc<LogicalPlan>> {
+        LogicalPlan::transform_up(&plan, &mut |node| self.rewrite_node(node))
+            .map(|transformed| transformed.get_plan())
+    }
+
+    fn rewrite_node(
+        &self,
+        node: &Arc<LogicalPlan>,
+    ) -> AnalyzerResult<Transformed<Arc<LogicalPlan>>> {
+        let rewritten = match node.as_ref() {
+            LogicalPlan::Projection(p) => {
+                let mut p = p.clone();
+                p.items = self.rewrite_items(&p.items)?;
+                LogicalPlan::Projection(p)
+            }
+            LogicalPlan::OrderBy(o) => {
+                let mut o = o.clone();
+                o.items = self.rewrite_order_by(&o.items)?;
+                LogicalPlan::OrderBy(o)
+            }
+            LogicalPlan::WithClause(wc) => {
+                let mut wc = wc.clone();
+                wc.items = self.rewrite_items(&wc.items)?;
+                wc.order_by = wc
+                    .order_by
+                    .as_ref()
+                    .map(|items| self.rewrite_order_by(items))
+                    .transpose()?;
+                LogicalPlan::WithClause(wc)
+            }
+            LogicalPlan::Unwind(u) => {
+                let mut u = u.clone();
+                u.expression = self.rewrite_expr(&u.expression)?;
+                LogicalPlan::Unwind(u)
+            }
+            _ => return Ok(Transformed::No(Arc::clone(node))),
+        };
+        if &rewritten == node.as_ref() {
+            Ok(Transformed::No(Arc::clone(node)))
+        } else {
+            Ok(Transformed::Yes(Arc::new(rewritten)))
+        }
+    }
+
+    fn rewrite_items(&self, items: &[ProjectionItem]) -> AnalyzerResult<Vec<ProjectionItem>> {
+        items
+            .iter()
+            .map(|item| {
+                Ok(ProjectionItem {
+                    expression: self.rewrite_expr(&item.expression)?,
+                    col_alias: item.col_alias.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn rewrite_order_by(&self, items: &[OrderByItem]) -> AnalyzerResult<Vec<OrderByItem>> {
+        items
+            .iter()
+            .map(|item| {
+                Ok(OrderByItem {
+                    expression: self.rewrite_expr(&item.expression)?,
+                    order: item.order.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn rewrite_expr(&self, expr: &LogicalExpr) -> AnalyzerResult<LogicalExpr> {
+        let mut error = None;
+        let rewritten = map_expression(expr, &mut |node| {
+            let LogicalExpr::MapLiteral(entries) = node else {
+                return ExprRewrite::Recurse;
+            };
+            let Some(alias) = entries.iter().find_map(|(key, value)| match value {
+                LogicalExpr::PropertyAccessExp(pa)
+                    if key == ALL_PROPERTIES_KEY && pa.column.raw() == ALL_PROPERTIES_KEY =>
+                {
+                    Some(pa.table_alias.0.clone())
+                }
+                _ => None,
+            }) else {
+                return ExprRewrite::Recurse;
+            };
+            match self.expand_entries(&alias, entries) {
+                Ok(expanded) => ExprRewrite::Replace(LogicalExpr::MapLiteral(expanded)),
+                Err(e) => {
+                    error.get_or_insert(e);
+                    ExprRewrite::Recurse
+                }
+            }
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(rewritten),
+        }
+    }
+
+    /// Entries of `alias {..., .*}` with `.*` replaced by the alias's
+    /// properties. The other entries are rewritten too, since a replaced node
+    /// is not recursed into.
+    fn expand_entries(
+        &self,
+        alias: &str,
+        entries: &[(String, LogicalExpr)],
+    ) -> AnalyzerResult<Vec<(String, LogicalExpr)>> {
+        let explicit: Vec<(String, LogicalExpr)> = entries
+            .iter()
+            .filter(|(key, _)| key != ALL_PROPERTIES_KEY)
+            .map(|(key, value)| Ok((key.clone(), self.rewrite_expr(value)?)))
+            .collect::<AnalyzerResult<_>>()?;
+        let properties = self.properties_of(alias)?;
+        log::debug!(
+            "MapProjectionExpansion: {}{{.*}} → {} properties",
+            alias,
+            properties.len()
+        );
+        let mut expanded: Vec<(String, LogicalExpr)> = properties
+            .into_iter()
+            .filter(|property| !explicit.iter().any(|(key, _)| key == property))
+            .map(|property| {
+                let value = LogicalExpr::PropertyAccessExp(PropertyAccess {
+                    table_alias: TableAlias(alias.to_string()),
+                    column: PropertyValue::Column(property.clone()),
+                });
+                (property, value)
+            })
+            .collect();
+        expanded.extend(explicit);
+        Ok(expanded)
+    }
+
+    /// Property names every label of the node or relationship `alias` maps
+    fn properties_of(&self, alias: &str) -> AnalyzerResult<BTreeSet<String>> {
+        let unsupported = || AnalyzerError::UnsupportedPattern {
+            message: format!(
+                "map projection {alias}{{.*}}: `{alias}` is not a node or relationship"
+            ),
+        };
+        if !self.entity_aliases.contains(alias) {
+            return Err(unsupported());
+        }
+        let table_ctx = self
+            .plan_ctx
+            .get_table_ctx(alias)
+            .map_err(|_| unsupported())?;
+        let labels = table_ctx
+            .get_labels()
+            .filter(|labels| !labels.is_empty())
+            .ok_or_else(unsupported)?;
+        let mut common: Option<BTreeSet<String>> = None;
+        for label in labels {
+            let mappings = if table_ctx.is_relation() {
+                self.graph_schema
+                    .get_rel_schema(label)
+                    .map(|rel| &rel.property_mappings)
+                    .map_err(|_| AnalyzerError::RelationshipTypeNotFound(label.clone()))?
+            } else {
+                self.graph_schema
+                    .node_schema(label)
+                    .map(|node| &node.property_mappings)
+                    .map_err(|_| AnalyzerError::NodeLabelNotFound(label.clone()))?
+            };
+            let names: BTreeSet<String> = mappings.keys().cloned().collect();
+            common = Some(match common {
+                Some(previous) => previous.intersection(&names).cloned().collect(),
+                None => names,
+            });
+        }
+        Ok(common.unwrap_or_default())
+    }
+}
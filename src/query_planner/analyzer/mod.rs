@@ -126,6 +126,7 @@ pub mod graph_join;
 pub use graph_join as graph_join_inference;
 mod graph_traversal_planning;
 mod group_by_building;
+mod map_projection_expansion;
 pub mod match_type_inference;
 mod optional_cartesian_distribution;
 mod plan_sanitization;
@@ -133,6 +134,7 @@ mod projected_columns_resolver;
 mod projection_tagging;
 mod query_validation;
 // mod schema_inference;  // REMOVED (Feb 16, 2026) - Fully merged into TypeInference
+mod temporal_accessor_resolution;
 mod type_inference;
 mod union_distribution;
 mod unwind_property_rewriter;
@@ -193,6 +195,20 @@ pub fn initial_analyzing(
 
     check_plan_size(&plan, "TypeInference")?;
 
+    // Step 2.1: Temporal Accessor Resolution - `r.day` on a relationship that
+    // maps a `day` property is that property, not `day(r)`. Needs the labels
+    // TypeInference just resolved.
+    let plan = temporal_accessor_resolution::resolve_temporal_accessors(
+        plan,
+        plan_ctx,
+        current_graph_schema,
+    );
+
+    // Step 2.2: Map Projection Expansion - `n {.*}` lists every property of
+    // n's labels, also resolved by TypeInference.
+    let plan =
+        map_projection_expansion::expand_map_projections(plan, plan_ctx, current_graph_schema)?;
+
     // Step 2.5: VLP Transitivity Check - validate variable-length path patterns
     // This runs after TypeInference to ensure we have relationship types resolved
     // Checks if VLP patterns are semantically valid (relationship must be transitive)
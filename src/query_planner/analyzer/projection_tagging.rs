@@ -1331,13 +1331,11 @@ impl ProjectionTagging {
                                             underlying_alias.clone()
                                         }
                                         _ => {
-                                            // If it's not a simple alias (e.g., it's an aggregate),
-                                            // just use count(*) since we can't resolve to a table
-                                            item.expression =
-                                                LogicalExpr::AggregateFnCall(AggregateFnCall {
-                                                    name: aggregate_fn_call.name.clone(),
-                                                    args: vec![LogicalExpr::Star],
-                                                });
+                                            // A scalar (`WITH n.city AS city ...
+                                            // count(city)`): keep the argument so
+                                            // VariableResolver points it at the
+                                            // CTE column; count(*) would also
+                                            // count the rows where it is NULL
                                             return Ok(());
                                         }
                                     }
@@ -1784,6 +1782,26 @@ impl ProjectionTagging {
                 });
                 Ok(())
             }
+            LogicalExpr::MapLiteral(entries) => {
+                // Map literals and map projections (`n {.name, total: count(f)}`):
+                // tag each value so nested aggregates such as count(node) are
+                // resolved. A bare variable value is kept as-is.
+                let mut transformed_entries = Vec::with_capacity(entries.len());
+                for (key, value) in entries {
+                    if matches!(value, LogicalExpr::TableAlias(_)) {
+                        transformed_entries.push((key, value));
+                        continue;
+                    }
+                    let mut value_item = ProjectionItem {
+                        expression: value,
+                        col_alias: None,
+                    };
+                    Self::tag_projection(&mut value_item, plan_ctx, graph_schema, input_plan)?;
+                    transformed_entries.push((key, value_item.expression));
+                }
+                item.expression = LogicalExpr::MapLiteral(transformed_entries);
+                Ok(())
+            }
             LogicalExpr::Lambda(lambda_expr) => {
                 // Lambda expressions need special handling:
                 // - Lambda parameters are local variables (don't resolve them)
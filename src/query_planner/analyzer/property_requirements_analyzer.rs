@@ -398,8 +398,11 @@ impl PropertyRequirementsAnalyzer {
             LogicalExpr::AggregateFnCall(agg) => {
                 log::info!("🔍 Analyzing aggregate function: {}", agg.name);
 
-                // Special handling for collect()
-                if agg.name.to_lowercase() == "collect" && agg.args.len() == 1 {
+                // Special handling for collect(node); `collect(u.name)` only needs
+                // the property and is analyzed like any other aggregate
+                if agg.name.to_lowercase() == "collect"
+                    && matches!(agg.args.as_slice(), [LogicalExpr::TableAlias(_)])
+                {
                     // collect(node) - DO NOT analyze the argument here!
                     // The UNWIND analysis will map requirements from UNWIND alias to source alias.
                     // Example: UNWIND collect(f) AS friend, friend.name → f.name (handled in UNWIND case)
@@ -482,6 +485,13 @@ impl PropertyRequirementsAnalyzer {
                 }
             }
 
+            // Map literals and map projections: {name: n.name}, n {.name}
+            LogicalExpr::MapLiteral(entries) => {
+                for (_, value) in entries {
+                    Self::analyze_expression(value, requirements);
+                }
+            }
+
             // Literals - no requirements
             LogicalExpr::Literal(_) => {}
 
@@ -1045,8 +1055,7 @@ mod tests {
 
     #[test]
     fn test_collect_with_property_access() {
-        // Test: collect(u.name) should NOT analyze argument - handled by UNWIND mapping
-        // This changed in commit 610f0ce: collect() args are analyzed via UNWIND, not directly
+        // Test: collect(u.name) needs only u.name; only collect(u) is left to UNWIND
         let mut reqs = PropertyRequirements::new();
 
         let expr = LogicalExpr::AggregateFnCall(AggregateFnCall {
@@ -1059,8 +1068,9 @@ mod tests {
 
         PropertyRequirementsAnalyzer::analyze_expression(&expr, &mut reqs);
 
-        // collect() args are NOT analyzed directly - handled by UNWIND
-        assert!(reqs.get_requirements("u").is_none());
+        let u_props = reqs.get_requirements("u").unwrap();
+        assert_eq!(u_props.len(), 1);
+        assert!(u_props.contains("name"));
     }
 
     #[test]
@@ -628,6 +628,7 @@ mod tests {
         let plan = Arc::new(LogicalPlan::Create(Create {
             input: Arc::new(LogicalPlan::WithClause(scoping_with)),
             patterns: vec![],
+            merge: None,
         }));
 
         let result = collapse_scoping_only_withs(plan);
@@ -0,0 +1,225 @@
+//! Analyzer pass that turns a temporal accessor on a graph variable back into
+//! a property access when the variable's label or type maps that property.
+//!
+//! The parser reads `x.day`, `x.month`, ... as `day(x)`, `month(x)` (the
+//! temporal accessors) because post-WITH temporal values carry no type. A node
+//! or relationship can however map a property of that name, typically a
+//! computed one:
+//!
+//! ```yaml
+//! property_mappings:
+//!   day: "toDate(ts)"
+//! ```
+//!
+//! ```cypher
+//! MATCH (a:User)-[r:FOLLOWS]->(b) RETURN r.day
+//! ```
+//!
+//! Before: `ScalarFnCall { name: "day", args: [TableAlias("r")] }`
+//! After: `PropertyAccess { table_alias: "r", column: "day" }`, which later
+//! passes map to `toDate(r.ts)` like any other property.
+//!
+//! Runs right after TypeInference, so inferred labels count too. A variable
+//! qualifies only when it is bound to a node or a single-hop relationship (in
+//! a MATCH, or passed on whole by a WITH) and every one of its labels maps the
+//! property. `WITH r.since AS s RETURN s.day` stays a temporal accessor.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::graph_catalog::{expression_parser::PropertyValue, graph_schema::GraphSchema};
+use crate::query_planner::{
+    logical_expr::{
+        visitors::{map_expression, ExprRewrite},
+        LogicalExpr, PropertyAccess, TableAlias,
+    },
+    logical_plan::{LogicalPlan, OrderByItem, ProjectionItem},
+    plan_ctx::PlanCtx,
+};
+
+/// Accessors the parser turns into function calls (see
+/// `open_cypher_parser::expression`)
+const TEMPORAL_ACCESSORS: [&str; 9] = [
+    "year",
+    "month",
+    "day",
+    "hour",
+    "minute",
+    "second",
+    "millisecond",
+    "microsecond",
+    "nanosecond",
+];
+
+/// Main entry point: rewrites accessor calls throughout the plan tree
+pub fn resolve_temporal_accessors(
+    plan: Arc<LogicalPlan>,
+    plan_ctx: &PlanCtx,
+    graph_schema: &GraphSchema,
+) -> Arc<LogicalPlan> {
+    let mut entity_aliases = HashSet::new();
+    collect_entity_aliases(&plan, &mut entity_aliases);
+    Resolver {
+        plan_ctx,
+        graph_schema,
+        entity_aliases,
+    }
+    .rewrite_plan(plan)
+}
+
+struct Resolver<'a> {
+    plan_ctx: &'a PlanCtx,
+    graph_schema: &'a GraphSchema,
+    /// Aliases bound to a whole node or relationship
+    entity_aliases: HashSet<String>,
+}
+
+pub(super) fn collect_entity_aliases(plan: &LogicalPlan, aliases: &mut HashSet<String>) {
+    match plan {
+        LogicalPlan::GraphNode(node) => {
+            aliases.insert(node.alias.clone());
+        }
+        LogicalPlan::GraphRel(rel) if rel.variable_length.is_none() => {
+            aliases.insert(rel.alias.clone());
+        }
+        LogicalPlan::WithClause(wc) => {
+            for item in &wc.items {
+                if let LogicalExpr::TableAlias(alias) = &item.expression {
+                    aliases.insert(item.col_alias.as_ref().map_or(&alias.0, |a| &a.0).clone());
+                }
+            }
+        }
+        _ => {}
+    }
+    plan.for_each_child(|child| collect_entity_aliases(child, aliases));
+}
+
+impl Resolver<'_> {
+    fn rewrite_plan(&self, plan: Arc<LogicalPlan>) -> Arc<LogicalPlan> {
+        let plan = Arc::new(
+            plan.as_ref()
+                .map_children(|c| self.rewrite_plan(Arc::new(c.clone())).as_ref().clone()),
+        );
+        let rewritten = match plan.as_ref() {
+            LogicalPlan::Projection(p) => {
+                let mut p = p.clone();
+                p.items = self.rewrite_items(&p.items);
+                LogicalPlan::Projection(p)
+            }
+            LogicalPlan::Filter(f) => {
+                let mut f = f.clone();
+                f.predicate = self.rewrite_expr(&f.predicate);
+                LogicalPlan::Filter(f)
+            }
+            LogicalPlan::GroupBy(g) => {
+                let mut g = g.clone();
+                g.expressions = g.expressions.iter().map(|e| self.rewrite_expr(e)).collect();
+                g.having_clause = g.having_clause.as_ref().map(|h| self.rewrite_expr(h));
+                LogicalPlan::GroupBy(g)
+            }
+            LogicalPlan::OrderBy(o) => {
+                let mut o = o.clone();
+                o.items = self.rewrite_order_by(&o.items);
+                LogicalPlan::OrderBy(o)
+            }
+            LogicalPlan::WithClause(wc) => {
+                let mut wc = wc.clone();
+                wc.items = self.rewrite_items(&wc.items);
+                wc.order_by = wc
+                    .order_by
+                    .as_ref()
+                    .map(|items| self.rewrite_order_by(items));
+                wc.where_clause = wc.where_clause.as_ref().map(|w| self.rewrite_expr(w));
+                LogicalPlan::WithClause(wc)
+            }
+            LogicalPlan::GraphRel(gr) => {
+                let mut gr = gr.clone();
+                gr.where_predicate = gr.where_predicate.as_ref().map(|w| self.rewrite_expr(w));
+                LogicalPlan::GraphRel(gr)
+            }
+            LogicalPlan::Unwind(u) => {
+                let mut u = u.clone();
+                u.expression = self.rewrite_expr(&u.expression);
+                LogicalPlan::Unwind(u)
+            }
+            _ => return plan,
+        };
+        Arc::new(rewritten)
+    }
+
+    fn rewrite_items(&self, items: &[ProjectionItem]) -> Vec<ProjectionItem> {
+        items
+            .iter()
+            .map(|item| ProjectionItem {
+                expression: self.rewrite_expr(&item.expression),
+                col_alias: item.col_alias.clone(),
+            })
+            .collect()
+    }
+
+    fn rewrite_order_by(&self, items: &[OrderByItem]) -> Vec<OrderByItem> {
+        items
+            .iter()
+            .map(|item| OrderByItem {
+                expression: self.rewrite_expr(&item.expression),
+                order: item.order.clone(),
+            })
+            .collect()
+    }
+
+    fn rewrite_expr(&self, expr: &LogicalExpr) -> LogicalExpr {
+        map_expression(expr, &mut |node| {
+            let LogicalExpr::ScalarFnCall(fn_call) = node else {
+                return ExprRewrite::Recurse;
+            };
+            let [LogicalExpr::TableAlias(alias)] = fn_call.args.as_slice() else {
+                return ExprRewrite::Recurse;
+            };
+            let Some(property) = TEMPORAL_ACCESSORS
+                .iter()
+                .find(|accessor| fn_call.name.eq_ignore_ascii_case(accessor))
+            else {
+                return ExprRewrite::Recurse;
+            };
+            if !self.maps_property(&alias.0, property) {
+                return ExprRewrite::Recurse;
+            }
+            log::debug!(
+                "TemporalAccessorResolution: {}({}) → {}.{} (mapped property)",
+                fn_call.name,
+                alias.0,
+                alias.0,
+                property
+            );
+            ExprRewrite::Replace(LogicalExpr::PropertyAccessExp(PropertyAccess {
+                table_alias: TableAlias(alias.0.clone()),
+                column: PropertyValue::Column(property.to_string()),
+            }))
+        })
+    }
+
+    /// Whether `alias` is a node or relationship whose every label maps
+    /// `property`
+    fn maps_property(&self, alias: &str, property: &str) -> bool {
+        if !self.entity_aliases.contains(alias) {
+            return false;
+        }
+        let Ok(table_ctx) = self.plan_ctx.get_table_ctx(alias) else {
+            return false;
+        };
+        let Some(labels) = table_ctx.get_labels().filter(|labels| !labels.is_empty()) else {
+            return false;
+        };
+        labels.iter().all(|label| {
+            if table_ctx.is_relation() {
+                self.graph_schema
+                    .get_rel_schema(label)
+                    .is_ok_and(|rel| rel.property_mappings.contains_key(property))
+            } else {
+                self.graph_schema
+                    .node_schema(label)
+                    .is_ok_and(|node| node.property_mappings.contains_key(property))
+            }
+        })
+    }
+}
@@ -552,6 +552,7 @@ mod tests {
         let create = LogicalPlan::Create(Create {
             input: Arc::new(rel_with_buried_union.as_ref().clone()),
             patterns: vec![],
+            merge: None,
         });
 
         let result = distribute_union_impl(&create, 0);
@@ -63,7 +63,9 @@ use crate::{
     graph_catalog::expression_parser::PropertyValue,
     query_planner::{
         analyzer::{analyzer_pass::AnalyzerPass, errors::AnalyzerError},
-        logical_expr::{CteEntityRef, EntityType, LogicalExpr, PropertyAccess, TableAlias},
+        logical_expr::{
+            ColumnAlias, CteEntityRef, EntityType, LogicalExpr, PropertyAccess, TableAlias,
+        },
         logical_plan::{LogicalPlan, ProjectionItem, WithClause},
         plan_ctx::PlanCtx,
         transformed::Transformed,
@@ -202,6 +204,14 @@ impl VariableResolver {
         })
     }
 
+    /// Step past a chain of Unwind nodes to the plan they unwind over.
+    fn skip_unwinds(mut plan: &LogicalPlan) -> &LogicalPlan {
+        while let LogicalPlan::Unwind(u) = plan {
+            plan = &u.input;
+        }
+        plan
+    }
+
     /// Collect all GraphNode and GraphRel aliases from a plan tree
     /// This is used to populate scope with schema entities from MATCH patterns
     ///
@@ -334,12 +344,47 @@ impl VariableResolver {
                 }
 
                 // Step 4: Resolve WITH items expressions (use parent scope, not new scope!)
-                // The expressions in WITH items reference variables from BEFORE the WITH
+                // The expressions in WITH items reference variables from BEFORE the WITH.
+                // For chained WITHs (`WITH n.city AS city WITH count(city) AS c`) that
+                // includes the scalar columns exported by the previous WITH.
+                let mut items_scope = scope.clone();
+                if let LogicalPlan::WithClause(prev) = Self::skip_unwinds(new_input.as_ref()) {
+                    for alias in &prev.exported_aliases {
+                        let Some(prev_cte) = prev.cte_references.get(alias) else {
+                            continue;
+                        };
+                        if plan_ctx
+                            .and_then(|ctx| Self::lookup_entity_from_plan_ctx(ctx, alias))
+                            .is_none()
+                        {
+                            items_scope.add_variable(
+                                alias.clone(),
+                                VarSource::CteColumn {
+                                    cte_name: prev_cte.clone(),
+                                    column_name: alias.clone(),
+                                },
+                            );
+                        }
+                    }
+                }
                 let resolved_items = wc
                     .items
                     .iter()
-                    .map(|item| self.resolve_projection_item(item, scope))
-                    .collect::<Result<Vec<_>, _>>()?;
+                    .map(|item| {
+                        let mut resolved = self.resolve_projection_item(item, &items_scope)?;
+                        // A scalar passed straight through (`WITH u, fc`) keeps its name
+                        // as this stage's column, so later stages find it in the CTE.
+                        if let (
+                            None,
+                            LogicalExpr::TableAlias(name),
+                            LogicalExpr::PropertyAccessExp(_),
+                        ) = (&resolved.col_alias, &item.expression, &resolved.expression)
+                        {
+                            resolved.col_alias = Some(ColumnAlias(name.0.clone()));
+                        }
+                        Ok(resolved)
+                    })
+                    .collect::<Result<Vec<_>, AnalyzerError>>()?;
 
                 // Step 5: Register alias sources for renamed variables
                 // When we have `WITH u AS person`, we need to track that person → (u, cte_name)
@@ -426,7 +471,9 @@ impl VariableResolver {
 
                 // Build projection scope
                 // Start with current scope (or WITH exported scope if input is WithClause or CartesianProduct)
-                let mut projection_scope = match new_input.as_ref() {
+                // `WITH ... UNWIND xs AS x RETURN ...` keeps the WITH's exports visible
+                // through the UNWIND, so look past any Unwind chain first.
+                let mut projection_scope = match Self::skip_unwinds(new_input.as_ref()) {
                     LogicalPlan::WithClause(wc) => {
                         log::info!("🔍 VariableResolver: Input is WithClause, adding {} exported aliases to scope",
                                    wc.exported_aliases.len());
@@ -492,6 +539,9 @@ impl VariableResolver {
                                 LogicalPlan::GroupBy(gb) => {
                                     current = &*gb.input;
                                 }
+                                LogicalPlan::Unwind(u) => {
+                                    current = &*u.input;
+                                }
                                 _ => break,
                             }
                         }
@@ -1176,6 +1226,15 @@ impl VariableResolver {
                 }
             }
 
+            LogicalExpr::MapLiteral(entries) => {
+                // Map literal / map projection: resolve each value
+                let resolved_entries = entries
+                    .iter()
+                    .map(|(key, value)| Ok((key.clone(), self.resolve_expression(value, scope)?)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(LogicalExpr::MapLiteral(resolved_entries))
+            }
+
             LogicalExpr::Case(case_expr) => {
                 // CASE expression: resolve condition, when/then branches, else branch
                 let resolved_expr = if let Some(ref e) = case_expr.expr {
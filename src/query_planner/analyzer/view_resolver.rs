@@ -93,7 +93,7 @@ impl<'a> ViewResolver<'a> {
                     if let Some(ref from_props) = node_schema.from_properties {
                         if let Some(mapped) = from_props.get(property) {
                             return Ok(
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(
+                                crate::graph_catalog::expression_parser::PropertyValue::from_raw(
                                     mapped.clone(),
                                 ),
                             );
@@ -105,7 +105,7 @@ impl<'a> ViewResolver<'a> {
                     if let Some(ref to_props) = node_schema.to_properties {
                         if let Some(mapped) = to_props.get(property) {
                             return Ok(
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(
+                                crate::graph_catalog::expression_parser::PropertyValue::from_raw(
                                     mapped.clone(),
                                 ),
                             );
@@ -118,7 +118,7 @@ impl<'a> ViewResolver<'a> {
                     if let Some(ref from_props) = node_schema.from_properties {
                         if let Some(mapped) = from_props.get(property) {
                             return Ok(
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(
+                                crate::graph_catalog::expression_parser::PropertyValue::from_raw(
                                     mapped.clone(),
                                 ),
                             );
@@ -128,7 +128,7 @@ impl<'a> ViewResolver<'a> {
                     if let Some(ref to_props) = node_schema.to_properties {
                         if let Some(mapped) = to_props.get(property) {
                             return Ok(
-                                crate::graph_catalog::expression_parser::PropertyValue::Column(
+                                crate::graph_catalog::expression_parser::PropertyValue::from_raw(
                                     mapped.clone(),
                                 ),
                             );
@@ -41,6 +41,15 @@ impl WherePropertyExtractor {
         properties
     }
 
+    /// Add the property references of any expression (a RETURN item, a
+    /// WITH item, ...) to `properties`, keyed by alias
+    pub fn collect_property_references(
+        expr: &Expression,
+        properties: &mut HashMap<String, HashSet<String>>,
+    ) {
+        Self::walk_expression(expr, properties);
+    }
+
     /// Recursively walk expression tree to find all property accesses
     fn walk_expression(expr: &Expression, properties: &mut HashMap<String, HashSet<String>>) {
         match expr {
@@ -96,8 +105,8 @@ impl WherePropertyExtractor {
                 }
             }
 
-            Expression::ExistsExpression(exists) => {
-                // Recurse into EXISTS subquery
+            Expression::ExistsExpression(exists) | Expression::CountExpression(exists) => {
+                // Recurse into EXISTS / COUNT subquery
                 if let Some(where_clause) = &exists.where_clause {
                     Self::walk_expression(&where_clause.conditions, properties);
                 }
@@ -0,0 +1,727 @@
+//! `CALL { ... }` subqueries — inlining a subquery into its enclosing query.
+//!
+//! A `CALL { }` body without UNION is rewritten, before planning, into the
+//! clauses the planner already handles: the body's MATCH clauses join the
+//! outer ones, and its RETURN becomes a WITH that carries the outer variables
+//! plus the returned columns.
+//!
+//! ```text
+//! MATCH (u:User)
+//! CALL { WITH u MATCH (u)-[:FOLLOWS]->(f) RETURN count(f) AS following }
+//! RETURN u.name, following
+//! ```
+//! becomes
+//! ```text
+//! MATCH (u:User) OPTIONAL MATCH (u)-[:FOLLOWS]->(f)
+//! WITH u, count(f) AS following
+//! RETURN u.name, following
+//! ```
+//!
+//! - An aggregate-only RETURN yields one row per outer row, so its MATCH
+//!   becomes OPTIONAL MATCH (`count` → 0 for outer rows without a match) and
+//!   `count(*)` counts the body's first new variable.
+//! - Otherwise the body keeps inner-join semantics: outer rows the body does
+//!   not match are dropped.
+//!
+//! Scoping follows openCypher: the body only sees the outer variables its
+//! importing `WITH` names, its returned columns must not redeclare an outer
+//! variable, and its other variables are not visible after the CALL.
+//!
+//! A body with UNION is left in place for the planner (see
+//! `plan_builder::build_call_union_input`); it must be uncorrelated and open
+//! the query.
+//!
+//! Call site: [`crate::query_planner::evaluate_read_statement`].
+
+use crate::open_cypher_parser::ast::{
+    CallSubquery, CypherStatement, Expression, MatchClause, OpenCypherQueryAst, Operator,
+    OperatorApplication, OptionalMatchClause, PathPattern, ReadingClause, ReturnClause,
+    UnionClause, WhereClause, WithClause, WithItem,
+};
+use crate::query_planner::errors::QueryPlannerError;
+
+/// Inline the `CALL { }` subqueries of every branch of `statement`.
+pub fn inline_call_subqueries(
+    statement: CypherStatement<'_>,
+) -> Result<CypherStatement<'_>, QueryPlannerError> {
+    let CypherStatement::Query {
+        query,
+        union_clauses,
+    } = statement
+    else {
+        return Ok(statement);
+    };
+    Ok(CypherStatement::Query {
+        query: Box::new(inline_query(*query)?),
+        union_clauses: union_clauses
+            .into_iter()
+            .map(|u| {
+                Ok(UnionClause {
+                    union_type: u.union_type,
+                    query: inline_query(u.query)?,
+                })
+            })
+            .collect::<Result<_, QueryPlannerError>>()?,
+    })
+}
+
+fn invalid(message: String) -> QueryPlannerError {
+    QueryPlannerError::InvalidQuery(message)
+}
+
+fn inline_query(
+    mut query: OpenCypherQueryAst<'_>,
+) -> Result<OpenCypherQueryAst<'_>, QueryPlannerError> {
+    let Some(call) = query.call_subquery.take() else {
+        return Ok(query);
+    };
+    let outer_vars = bound_in_reading_clauses(&query.reading_clauses);
+
+    if !call.union_clauses.is_empty() {
+        return keep_union_call(query, call, &outer_vars);
+    }
+
+    let body = inline_query(*call.query)?;
+    let body = Body::split(body)?;
+    body.check_scope(&outer_vars)?;
+    Ok(body.inline_into(query, &outer_vars))
+}
+
+/// A UNION body is planned as the input of the outer query, so nothing may
+/// come before it and it cannot read outer variables.
+fn keep_union_call<'a>(
+    mut query: OpenCypherQueryAst<'a>,
+    call: CallSubquery<'a>,
+    outer_vars: &[&str],
+) -> Result<OpenCypherQueryAst<'a>, QueryPlannerError> {
+    if !outer_vars.is_empty() || !query.reading_clauses.is_empty() {
+        return Err(invalid(
+            "CALL { ... UNION ... } is only supported as the first clause of a query".to_string(),
+        ));
+    }
+    let branches =
+        std::iter::once(call.query.as_ref()).chain(call.union_clauses.iter().map(|u| &u.query));
+    for branch in branches {
+        if import_with(branch).is_some() {
+            return Err(invalid(
+                "CALL { ... UNION ... } cannot import outer variables".to_string(),
+            ));
+        }
+        if branch.call_subquery.is_some() {
+            return Err(invalid(
+                "CALL { } nested inside a UNION subquery is not supported".to_string(),
+            ));
+        }
+        check_returns(branch.return_clause.as_ref())?;
+    }
+    query.call_subquery = Some(call);
+    Ok(query)
+}
+
+/// The importing `WITH a, b` of a body, when the body opens with one.
+fn import_with<'q, 'a>(body: &'q OpenCypherQueryAst<'a>) -> Option<&'q WithClause<'a>> {
+    if body.reading_clauses.is_empty() && body.unwind_clauses.is_empty() {
+        body.with_clause.as_ref()
+    } else {
+        None
+    }
+}
+
+fn check_returns(ret: Option<&ReturnClause<'_>>) -> Result<(), QueryPlannerError> {
+    let ret = ret.ok_or_else(|| invalid("CALL { } subqueries must end with RETURN".to_string()))?;
+    for item in &ret.return_items {
+        if item.alias.is_none() && !matches!(item.expression, Expression::Variable(_)) {
+            return Err(invalid(format!(
+                "Expression '{}' returned from CALL {{ }} must be aliased (use AS)",
+                item.original_text.unwrap_or("?")
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// A UNION-free `CALL { }` body, split into the parts that get inlined.
+struct Body<'a> {
+    imports: Vec<&'a str>,
+    reading_clauses: Vec<ReadingClause<'a>>,
+    where_clause: Option<WhereClause<'a>>,
+    return_clause: ReturnClause<'a>,
+    order_by: Option<crate::open_cypher_parser::ast::OrderByClause<'a>>,
+    skip: Option<crate::open_cypher_parser::ast::SkipClause>,
+    limit: Option<crate::open_cypher_parser::ast::LimitClause>,
+}
+
+impl<'a> Body<'a> {
+    fn split(body: OpenCypherQueryAst<'a>) -> Result<Self, QueryPlannerError> {
+        if body.create_clause.is_some()
+            || body.foreach_clause.is_some()
+            || body.merge_clause.is_some()
+            || body.set_clause.is_some()
+            || body.remove_clause.is_some()
+            || body.delete_clause.is_some()
+        {
+            return Err(invalid(
+                "Write clauses inside CALL { } are not supported".to_string(),
+            ));
+        }
+        if body.call_clause.is_some() {
+            return Err(invalid(
+                "Procedure calls inside CALL { } are not supported".to_string(),
+            ));
+        }
+        check_returns(body.return_clause.as_ref())?;
+        let unsupported_tail = || {
+            invalid(
+                "CALL { } bodies support MATCH / OPTIONAL MATCH / WHERE followed by RETURN; \
+                 UNWIND and WITH inside the body are not supported yet"
+                    .to_string(),
+            )
+        };
+
+        let (imports, reading_clauses) = match import_with(&body) {
+            Some(with) => {
+                if with.is_star
+                    || with.distinct
+                    || with.order_by.is_some()
+                    || with.skip.is_some()
+                    || with.limit.is_some()
+                    || with.where_clause.is_some()
+                {
+                    return Err(invalid(
+                        "The importing WITH of CALL { } may only list variables, e.g. WITH a, b"
+                            .to_string(),
+                    ));
+                }
+                if with.subsequent_unwind.is_some() || with.subsequent_with.is_some() {
+                    return Err(unsupported_tail());
+                }
+                let mut imports = Vec::with_capacity(with.with_items.len());
+                for item in &with.with_items {
+                    match (&item.expression, item.alias) {
+                        (Expression::Variable(v), None) => imports.push(*v),
+                        (Expression::Variable(v), Some(a)) if *v == a => imports.push(*v),
+                        _ => {
+                            return Err(invalid(
+                                "The importing WITH of CALL { } may only list variables, e.g. WITH a, b"
+                                    .to_string(),
+                            ))
+                        }
+                    }
+                }
+                let mut reading: Vec<ReadingClause> = with
+                    .subsequent_match
+                    .iter()
+                    .map(|m| ReadingClause::Match((**m).clone()))
+                    .collect();
+                reading.extend(
+                    with.subsequent_optional_matches
+                        .iter()
+                        .cloned()
+                        .map(ReadingClause::OptionalMatch),
+                );
+                (imports, reading)
+            }
+            None => {
+                if body.with_clause.is_some() || !body.unwind_clauses.is_empty() {
+                    return Err(unsupported_tail());
+                }
+                (Vec::new(), body.reading_clauses)
+            }
+        };
+
+        Ok(Body {
+            imports,
+            reading_clauses,
+            where_clause: body.where_clause,
+            return_clause: body.return_clause.expect("checked above"),
+            order_by: body.order_by_clause,
+            skip: body.skip_clause,
+            limit: body.limit_clause,
+        })
+    }
+
+    fn returned_names(&self) -> Vec<&'a str> {
+        self.return_clause
+            .return_items
+            .iter()
+            .filter_map(|item| match (&item.expression, item.alias) {
+                (_, Some(alias)) => Some(alias),
+                (Expression::Variable(v), None) => Some(*v),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn aggregate_only(&self) -> bool {
+        let items = &self.return_clause.return_items;
+        !items.is_empty() && items.iter().all(|i| contains_aggregate(&i.expression))
+    }
+
+    /// openCypher scoping: imports must exist outside, the body may not read
+    /// or rebind an outer variable it did not import, and returned columns
+    /// may not redeclare an outer variable.
+    fn check_scope(&self, outer_vars: &[&str]) -> Result<(), QueryPlannerError> {
+        for import in &self.imports {
+            if !outer_vars.contains(import) {
+                return Err(invalid(format!(
+                    "Variable `{import}` imported into CALL {{ }} is not defined before the CALL"
+                )));
+            }
+        }
+        let hidden = |v: &str| outer_vars.contains(&v) && !self.imports.contains(&v);
+
+        let bound = bound_in_reading_clauses(&self.reading_clauses);
+        if let Some(v) = bound.iter().find(|v| hidden(v)) {
+            return Err(invalid(format!(
+                "Variable `{v}` is already defined outside CALL {{ }}; import it with WITH {v} \
+                 or use another name inside the subquery"
+            )));
+        }
+
+        let mut referenced = Vec::new();
+        for clause in &self.reading_clauses {
+            if let Some(w) = reading_clause_where(clause) {
+                referenced_variables(&w.conditions, &mut referenced);
+            }
+        }
+        if let Some(w) = &self.where_clause {
+            referenced_variables(&w.conditions, &mut referenced);
+        }
+        for item in &self.return_clause.return_items {
+            referenced_variables(&item.expression, &mut referenced);
+        }
+        if let Some(v) = referenced.iter().find(|v| hidden(v) && !bound.contains(v)) {
+            return Err(invalid(format!(
+                "Variable `{v}` is not in scope inside CALL {{ }}; import it with WITH {v}"
+            )));
+        }
+
+        for name in self.returned_names() {
+            if outer_vars.contains(&name) {
+                return Err(invalid(format!(
+                    "Variable `{name}` returned from CALL {{ }} is already defined outside it"
+                )));
+            }
+        }
+
+        let correlated = !self.imports.is_empty() || !outer_vars.is_empty();
+        if correlated && (self.order_by.is_some() || self.skip.is_some() || self.limit.is_some()) {
+            return Err(invalid(
+                "ORDER BY / SKIP / LIMIT inside CALL { } are only supported when the CALL \
+                 opens the query"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn inline_into(
+        self,
+        mut query: OpenCypherQueryAst<'a>,
+        outer_vars: &[&'a str],
+    ) -> OpenCypherQueryAst<'a> {
+        let aggregate_only = self.aggregate_only();
+        let returned = self.returned_names();
+        let first_new = bound_in_reading_clauses(&self.reading_clauses)
+            .into_iter()
+            .find(|v| !outer_vars.contains(v));
+        let mut reading = self.reading_clauses;
+        if let Some(extra) = self.where_clause {
+            and_into_last_match(&mut reading, extra);
+        }
+        if aggregate_only {
+            reading = optional_reading(reading);
+        }
+
+        let mut with_items: Vec<WithItem<'a>> = outer_vars
+            .iter()
+            .map(|v| WithItem {
+                expression: Expression::Variable(v),
+                alias: None,
+            })
+            .collect();
+        for (item, name) in self.return_clause.return_items.into_iter().zip(returned) {
+            let expression = if aggregate_only {
+                count_star_as(item.expression, first_new)
+            } else {
+                item.expression
+            };
+            with_items.push(WithItem {
+                alias: (!matches!(expression, Expression::Variable(v) if v == name))
+                    .then_some(name),
+                expression,
+            });
+        }
+
+        // The outer clauses after the CALL move behind the new WITH.
+        let mut unwinds = std::mem::take(&mut query.unwind_clauses).into_iter();
+        let subsequent_unwind = unwinds.next();
+        let mut subsequent_with = query.with_clause.take().map(Box::new);
+        for unwind in unwinds {
+            subsequent_with = Some(Box::new(WithClause {
+                with_items: Vec::new(),
+                is_star: true,
+                distinct: false,
+                order_by: None,
+                skip: None,
+                limit: None,
+                where_clause: None,
+                subsequent_unwind: Some(unwind),
+                subsequent_match: None,
+                subsequent_optional_matches: Vec::new(),
+                subsequent_with,
+            }));
+        }
+
+        query.reading_clauses.extend(reading);
+        rebuild_clause_lists(&mut query);
+        query.with_clause = Some(WithClause {
+            with_items,
+            is_star: false,
+            distinct: self.return_clause.distinct,
+            order_by: self.order_by,
+            skip: self.skip,
+            limit: self.limit,
+            where_clause: None,
+            subsequent_unwind,
+            subsequent_match: None,
+            subsequent_optional_matches: Vec::new(),
+            subsequent_with,
+        });
+        query
+    }
+}
+
+/// Keep `match_clauses` / `optional_match_clauses` in step with
+/// `reading_clauses`, as the parser does.
+fn rebuild_clause_lists(query: &mut OpenCypherQueryAst<'_>) {
+    query.match_clauses.clear();
+    query.optional_match_clauses.clear();
+    for clause in &query.reading_clauses {
+        match clause {
+            ReadingClause::Match(m) => query.match_clauses.push(m.clone()),
+            ReadingClause::OptionalMatch(o) => query.optional_match_clauses.push(o.clone()),
+        }
+    }
+}
+
+fn reading_clause_where<'q, 'a>(clause: &'q ReadingClause<'a>) -> Option<&'q WhereClause<'a>> {
+    match clause {
+        ReadingClause::Match(m) => m.where_clause.as_ref(),
+        ReadingClause::OptionalMatch(o) => o.where_clause.as_ref(),
+    }
+}
+
+fn and_where<'a>(current: Option<WhereClause<'a>>, extra: WhereClause<'a>) -> WhereClause<'a> {
+    match current {
+        None => extra,
+        Some(w) => WhereClause {
+            conditions: Expression::OperatorApplicationExp(OperatorApplication {
+                operator: Operator::And,
+                operands: vec![w.conditions, extra.conditions],
+            }),
+        },
+    }
+}
+
+/// A body-level WHERE filters the body's last MATCH.
+fn and_into_last_match<'a>(reading: &mut [ReadingClause<'a>], extra: WhereClause<'a>) {
+    match reading.last_mut() {
+        Some(ReadingClause::Match(m)) => {
+            m.where_clause = Some(and_where(m.where_clause.take(), extra))
+        }
+        Some(ReadingClause::OptionalMatch(o)) => {
+            o.where_clause = Some(and_where(o.where_clause.take(), extra))
+        }
+        None => {}
+    }
+}
+
+/// The body's MATCH clauses as one OPTIONAL MATCH, so an outer row the body
+/// does not match still gets its (empty) aggregates.
+fn optional_reading(reading: Vec<ReadingClause<'_>>) -> Vec<ReadingClause<'_>> {
+    let mut merged: Option<OptionalMatchClause> = None;
+    let mut rest = Vec::new();
+    for clause in reading {
+        match clause {
+            ReadingClause::Match(MatchClause {
+                path_patterns,
+                where_clause,
+            }) => {
+                let target = merged.get_or_insert_with(|| OptionalMatchClause {
+                    path_patterns: Vec::new(),
+                    where_clause: None,
+                });
+                target
+                    .path_patterns
+                    .extend(path_patterns.into_iter().map(|(_, pattern)| pattern));
+                if let Some(w) = where_clause {
+                    target.where_clause = Some(and_where(target.where_clause.take(), w));
+                }
+            }
+            optional => rest.push(optional),
+        }
+    }
+    merged
+        .map(ReadingClause::OptionalMatch)
+        .into_iter()
+        .chain(rest)
+        .collect()
+}
+
+/// `count(*)` over an OPTIONAL MATCH would count the unmatched outer row;
+/// count the body's first new variable instead.
+fn count_star_as<'a>(expr: Expression<'a>, variable: Option<&'a str>) -> Expression<'a> {
+    match expr {
+        Expression::FunctionCallExp(mut f) => {
+            let is_count_star = f.name.eq_ignore_ascii_case("count")
+                && matches!(f.args.as_slice(), [Expression::Variable("*")]);
+            match variable {
+                Some(v) if is_count_star => f.args = vec![Expression::Variable(v)],
+                _ => {
+                    f.args = f
+                        .args
+                        .into_iter()
+                        .map(|a| count_star_as(a, variable))
+                        .collect()
+                }
+            }
+            Expression::FunctionCallExp(f)
+        }
+        Expression::OperatorApplicationExp(mut op) => {
+            op.operands = op
+                .operands
+                .into_iter()
+                .map(|o| count_star_as(o, variable))
+                .collect();
+            Expression::OperatorApplicationExp(op)
+        }
+        other => other,
+    }
+}
+
+fn contains_aggregate(expr: &Expression<'_>) -> bool {
+    const AGGREGATES: &[&str] = &[
+        "count",
+        "sum",
+        "avg",
+        "min",
+        "max",
+        "collect",
+        "stdev",
+        "stdevp",
+        "percentilecont",
+        "percentiledisc",
+    ];
+    match expr {
+        Expression::FunctionCallExp(f) => {
+            AGGREGATES.contains(&f.name.to_lowercase().as_str())
+                || f.args.iter().any(contains_aggregate)
+        }
+        Expression::OperatorApplicationExp(op) => op.operands.iter().any(contains_aggregate),
+        Expression::List(items) => items.iter().any(contains_aggregate),
+        _ => false,
+    }
+}
+
+/// Variables a list of MATCH / OPTIONAL MATCH clauses binds, in order.
+fn bound_in_reading_clauses<'a>(clauses: &[ReadingClause<'a>]) -> Vec<&'a str> {
+    let mut out = Vec::new();
+    for clause in clauses {
+        match clause {
+            ReadingClause::Match(m) => {
+                for (path_var, pattern) in &m.path_patterns {
+                    if let Some(p) = path_var {
+                        push_unique(&mut out, p);
+                    }
+                    pattern_variables(pattern, &mut out);
+                }
+            }
+            ReadingClause::OptionalMatch(o) => {
+                for pattern in &o.path_patterns {
+                    pattern_variables(pattern, &mut out);
+                }
+            }
+        }
+    }
+    out
+}
+
+fn push_unique<'a>(out: &mut Vec<&'a str>, name: &'a str) {
+    if !out.contains(&name) {
+        out.push(name);
+    }
+}
+
+fn pattern_variables<'a>(pattern: &PathPattern<'a>, out: &mut Vec<&'a str>) {
+    match pattern {
+        PathPattern::Node(node) => {
+            if let Some(name) = node.name {
+                push_unique(out, name);
+            }
+        }
+        PathPattern::ConnectedPattern(hops) => {
+            for hop in hops {
+                if let Some(name) = hop.start_node.borrow().name {
+                    push_unique(out, name);
+                }
+                if let Some(name) = hop.relationship.name {
+                    push_unique(out, name);
+                }
+                if let Some(name) = hop.end_node.borrow().name {
+                    push_unique(out, name);
+                }
+            }
+        }
+        PathPattern::ShortestPath(inner) | PathPattern::AllShortestPaths(inner) => {
+            pattern_variables(inner, out)
+        }
+    }
+}
+
+/// Variables an expression reads. Lambdas and comprehensions are not entered:
+/// their own variables would read as outer references.
+fn referenced_variables<'a>(expr: &Expression<'a>, out: &mut Vec<&'a str>) {
+    match expr {
+        Expression::Variable(v) if *v != "*" => push_unique(out, v),
+        Expression::PropertyAccessExp(pa) => push_unique(out, pa.base),
+        Expression::LabelExpression { variable, .. } => push_unique(out, variable),
+        Expression::FunctionCallExp(f) => f.args.iter().for_each(|a| referenced_variables(a, out)),
+        Expression::OperatorApplicationExp(op) => op
+            .operands
+            .iter()
+            .for_each(|o| referenced_variables(o, out)),
+        Expression::List(items) => items.iter().for_each(|i| referenced_variables(i, out)),
+        Expression::MapLiteral(entries) => entries
+            .iter()
+            .for_each(|(_, v)| referenced_variables(v, out)),
+        Expression::Case(case) => {
+            if let Some(e) = &case.expr {
+                referenced_variables(e, out);
+            }
+            for (when, then) in &case.when_then {
+                referenced_variables(when, out);
+                referenced_variables(then, out);
+            }
+            if let Some(e) = &case.else_expr {
+                referenced_variables(e, out);
+            }
+        }
+        Expression::ArraySubscript { array, index } => {
+            referenced_variables(array, out);
+            referenced_variables(index, out);
+        }
+        Expression::ArraySlicing { array, from, to } => {
+            referenced_variables(array, out);
+            for bound in [from, to].into_iter().flatten() {
+                referenced_variables(bound, out);
+            }
+        }
+        Expression::PathPattern(pattern) => pattern_variables(pattern, out),
+        Expression::ExistsExpression(sub) | Expression::CountExpression(sub) => {
+            pattern_variables(&sub.pattern, out);
+            if let Some(w) = &sub.where_clause {
+                referenced_variables(&w.conditions, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::open_cypher_parser::parse_cypher_statement;
+
+    fn inline(cypher: &str) -> Result<OpenCypherQueryAst<'_>, String> {
+        let (_, stmt) = parse_cypher_statement(cypher).map_err(|e| format!("{e:?}"))?;
+        match inline_call_subqueries(stmt).map_err(|e| e.to_string())? {
+            CypherStatement::Query { query, .. } => Ok(*query),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn correlated_count_becomes_optional_match_and_with() {
+        let q = inline(
+            "MATCH (u:User) CALL { WITH u MATCH (u)-[:FOLLOWS]->(f) RETURN count(*) AS c } \
+             RETURN u.name, c",
+        )
+        .unwrap();
+        assert!(q.call_subquery.is_none());
+        assert!(matches!(
+            q.reading_clauses.as_slice(),
+            [ReadingClause::Match(_), ReadingClause::OptionalMatch(_)]
+        ));
+        let with = q.with_clause.unwrap();
+        assert_eq!(with.with_items[0].expression, Expression::Variable("u"));
+        assert_eq!(with.with_items[1].alias, Some("c"));
+        // count(*) counts the body's new variable, so users without follows get 0
+        let Expression::FunctionCallExp(f) = &with.with_items[1].expression else {
+            panic!("{:?}", with.with_items[1]);
+        };
+        assert_eq!(f.args, vec![Expression::Variable("f")]);
+        assert!(q.return_clause.is_some());
+    }
+
+    #[test]
+    fn non_aggregating_body_keeps_match() {
+        let q = inline(
+            "MATCH (u:User) CALL { WITH u MATCH (u)-[:FOLLOWS]->(f) RETURN f.name AS friend } \
+             RETURN u.name, friend",
+        )
+        .unwrap();
+        assert!(matches!(
+            q.reading_clauses.as_slice(),
+            [ReadingClause::Match(_), ReadingClause::Match(_)]
+        ));
+    }
+
+    #[test]
+    fn scoping_errors() {
+        let err = inline(
+            "MATCH (u:User) CALL { MATCH (u)-[:FOLLOWS]->(f) RETURN count(f) AS c } RETURN c",
+        )
+        .unwrap_err();
+        assert!(err.contains("`u` is already defined outside"), "{err}");
+
+        let err = inline(
+            "MATCH (u:User)-[:FOLLOWS]->(v:User) \
+             CALL { WITH u MATCH (u)-[:AUTHORED]->(p) WHERE p.author = v.name RETURN count(p) AS c } \
+             RETURN c",
+        )
+        .unwrap_err();
+        assert!(err.contains("`v` is not in scope"), "{err}");
+
+        let err =
+            inline("MATCH (u:User) CALL { WITH x MATCH (x)--(y) RETURN y } RETURN y").unwrap_err();
+        assert!(err.contains("`x` imported into CALL"), "{err}");
+
+        let err = inline(
+            "MATCH (u:User) CALL { WITH u MATCH (u)--(f) RETURN f AS u2, f.name } RETURN u2",
+        )
+        .unwrap_err();
+        assert!(err.contains("must be aliased"), "{err}");
+
+        let err = inline("MATCH (u:User) CALL { WITH u MATCH (u)--(f) RETURN f AS u } RETURN u")
+            .unwrap_err();
+        assert!(err.contains("`u` returned from CALL"), "{err}");
+    }
+
+    #[test]
+    fn union_body_must_open_the_query() {
+        let q = inline(
+            "CALL { MATCH (a:User) RETURN a.name AS name UNION MATCH (p:Post) RETURN p.title AS name } \
+             RETURN name ORDER BY name",
+        )
+        .unwrap();
+        assert!(q.call_subquery.is_some());
+
+        let err = inline(
+            "MATCH (u:User) CALL { MATCH (a:User) RETURN a.name AS name UNION MATCH (p:Post) RETURN p.title AS name } \
+             RETURN name",
+        )
+        .unwrap_err();
+        assert!(err.contains("first clause"), "{err}");
+    }
+}
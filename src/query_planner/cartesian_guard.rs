@@ -0,0 +1,212 @@
+//! Cartesian guard — disconnected patterns need an explicit opt-in.
+//!
+//! `MATCH (a:User), (b:Post)` (or `MATCH (a) MATCH (b)`, `WITH a MATCH (b)`)
+//! joins two pattern parts that share no variable and no predicate. The
+//! result is every combination of their rows — a CROSS JOIN whose size is the
+//! product of both sides, which on large tables is almost always a mistake.
+//!
+//! The planner represents such a query as a [`CartesianProduct`] without a
+//! `join_condition` (a WHERE predicate relating both sides is moved into
+//! `join_condition` by `CartesianJoinExtraction`, so correlated parts are not
+//! affected). Unless the server allows cartesian products
+//! (`CLICKGRAPH_ALLOW_CARTESIAN_PRODUCT`) or the query opts in with the
+//! `CYPHER cartesian=allow` prefix, the query fails with guidance.
+//!
+//! Call sites: the HTTP and Bolt read paths call [`enforce_cartesian_policy`]
+//! after the planner produces a `LogicalPlan`, next to the scan guard.
+
+use std::ops::ControlFlow;
+
+use thiserror::Error;
+
+use crate::query_planner::logical_plan::{CartesianProduct, Descend, LogicalPlan};
+
+/// The `CYPHER` prefix option that allows a cartesian product for one query.
+pub const ALLOW_HINT: &str = "cartesian=allow";
+
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum CartesianGuardError {
+    #[error(
+        "Pattern parts {left} and {right} are not connected, so the query would return \
+         every combination of their rows (a cartesian product). Connect them with a \
+         relationship or a WHERE predicate that relates both, or allow it explicitly with \
+         the `CYPHER {hint}` query prefix (server-wide: CLICKGRAPH_ALLOW_CARTESIAN_PRODUCT=true)."
+    )]
+    CartesianProductRejected {
+        left: String,
+        right: String,
+        hint: &'static str,
+    },
+}
+
+/// Whether the query starts with a `CYPHER` prefix carrying [`ALLOW_HINT`],
+/// e.g. `CYPHER cartesian=allow MATCH (a:User), (b:Post) RETURN ...`.
+pub fn has_allow_hint(query: &str) -> bool {
+    crate::query_planner::has_prefix_option(query, ALLOW_HINT)
+}
+
+/// Check `plan` for a disconnected cartesian product.
+///
+/// Returns whether the plan contains one (`Ok(true)` only when `allowed`).
+/// Plans that allow one must not be shared through the query cache unless
+/// the server allows them for every query.
+pub fn enforce_cartesian_policy(
+    plan: &LogicalPlan,
+    allowed: bool,
+) -> Result<bool, CartesianGuardError> {
+    let Some((left, right)) = find_disconnected_product(plan) else {
+        return Ok(false);
+    };
+    if allowed {
+        log::info!("Cartesian product of {} and {} allowed", left, right);
+        return Ok(true);
+    }
+    Err(CartesianGuardError::CartesianProductRejected {
+        left,
+        right,
+        hint: ALLOW_HINT,
+    })
+}
+
+/// Sides of the first product with no join condition whose sides both read
+/// a table. Sides without a scan (`UNWIND` lists, literal-only `WITH`) are
+/// row generators, not patterns, and pass.
+fn find_disconnected_product(plan: &LogicalPlan) -> Option<(String, String)> {
+    plan.find_map_node(|node| match node {
+        LogicalPlan::CartesianProduct(cp) if is_disconnected(cp) => {
+            Some((pattern_aliases(&cp.left), pattern_aliases(&cp.right)))
+        }
+        _ => None,
+    })
+}
+
+fn is_disconnected(cp: &CartesianProduct) -> bool {
+    cp.join_condition.is_none() && reads_table(&cp.left) && reads_table(&cp.right)
+}
+
+fn reads_table(plan: &LogicalPlan) -> bool {
+    plan.any_node(|node| matches!(node, LogicalPlan::ViewScan(_)))
+}
+
+/// `(a), (b)` — the named pattern variables of one side, for the message.
+fn pattern_aliases(plan: &LogicalPlan) -> String {
+    let mut aliases: Vec<String> = Vec::new();
+    plan.walk(&mut |node| {
+        if let LogicalPlan::GraphNode(node) = node {
+            if !aliases.contains(&node.alias) {
+                aliases.push(node.alias.clone());
+            }
+        }
+        ControlFlow::<(), _>::Continue(Descend::Yes)
+    });
+    if aliases.is_empty() {
+        return "(…)".to_string();
+    }
+    aliases
+        .iter()
+        .map(|alias| format!("({alias})"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+    use crate::graph_catalog::graph_schema::GraphSchema;
+    use crate::open_cypher_parser;
+    use crate::query_planner::evaluate_read_statement;
+
+    const SCHEMA_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: id
+      property_mappings:
+        id: id
+        name: name
+    - label: Post
+      database: test
+      table: posts
+      node_id: id
+      property_mappings:
+        id: id
+        title: title
+  edges:
+    - type: AUTHORED
+      database: test
+      table: authored
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings: {}
+"#;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .expect("valid schema")
+    }
+
+    fn guard(cypher: &str, allowed: bool) -> Result<bool, CartesianGuardError> {
+        let schema = schema();
+        let statement = open_cypher_parser::parse_cypher_statement(cypher)
+            .expect("parse")
+            .1;
+        let (plan, _) =
+            evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+        enforce_cartesian_policy(&plan, allowed)
+    }
+
+    #[test]
+    fn disconnected_patterns_are_rejected_with_guidance() {
+        for cypher in [
+            "MATCH (a:User), (b:Post) RETURN a.name, b.title",
+            "MATCH (a:User) MATCH (b:Post) RETURN a.name, b.title",
+            "MATCH (a:User)-[:AUTHORED]->(p:Post), (b:User) RETURN a.name, b.name",
+        ] {
+            let err = guard(cypher, false).unwrap_err();
+            let message = err.to_string();
+            assert!(message.contains("(b)"), "{cypher}: {message}");
+            assert!(message.contains("CYPHER cartesian=allow"), "{message}");
+        }
+    }
+
+    #[test]
+    fn allowed_products_are_reported() {
+        assert_eq!(
+            guard("MATCH (a:User), (b:Post) RETURN a.name, b.title", true),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn connected_and_correlated_patterns_pass() {
+        for cypher in [
+            "MATCH (a:User)-[:AUTHORED]->(p:Post) RETURN a.name, p.title",
+            "MATCH (a:User), (b:User) WHERE a.id = b.id RETURN a.name, b.name",
+            "MATCH (a:User), (b:User) WHERE a.id < b.id RETURN a.name, b.name",
+            "MATCH (a:User) RETURN a.name",
+        ] {
+            assert_eq!(guard(cypher, false), Ok(false), "{cypher}");
+        }
+    }
+
+    #[test]
+    fn allow_hint_is_read_from_the_cypher_prefix() {
+        assert!(has_allow_hint(
+            "CYPHER cartesian=allow MATCH (a), (b) RETURN a"
+        ));
+        assert!(has_allow_hint(
+            "cypher replan=force CARTESIAN=ALLOW MATCH (a), (b) RETURN a"
+        ));
+        assert!(!has_allow_hint("MATCH (a), (b) RETURN 'cartesian=allow'"));
+        assert!(!has_allow_hint(
+            "CYPHER replan=force MATCH (a), (b) RETURN a"
+        ));
+    }
+}
@@ -0,0 +1,419 @@
+//! Label resolution — what a MATCH on a label or relationship type the schema
+//! does not define means.
+//!
+//! Neo4j treats an unknown label as "no such nodes": the MATCH is simply
+//! empty. ClickGraph maps labels to tables, so by default (`unknown_labels:
+//! strict`) an unknown label stays a planning error. A schema can opt into
+//! `unknown_labels: lenient`, in which case [`resolve_unknown_labels`] rewrites
+//! a query whose required MATCH names an unknown label or type into one that
+//! returns no rows (or, for an aggregate-only RETURN, the single row of
+//! aggregates over nothing: `count` → 0, `collect` → `[]`, ...) and records an
+//! [`UnknownLabels`] notice for the caller to surface as a notification.
+//!
+//! Only required MATCH clauses are considered. For a UNION, branches with an
+//! unknown label are dropped and the rest run as usual. The rewrite is not
+//! applied (and the planner reports the usual error) when an unknown label is
+//! only referenced by OPTIONAL MATCH, or when a WITH clause aggregates, since
+//! the empty result is then not a plain "no rows".
+//!
+//! Call site: [`crate::query_planner::evaluate_read_statement`], so HTTP,
+//! Bolt and embedded queries behave the same.
+
+use crate::graph_catalog::graph_schema::{GraphSchema, UnknownLabelMode};
+use crate::open_cypher_parser::ast::{
+    CypherStatement, Expression, LimitClause, Literal, MatchClause, OpenCypherQueryAst,
+    PathPattern, ReadingClause, ReturnClause, ReturnItem, UnionType, WithClause,
+};
+
+/// Unknown labels / relationship types that made a lenient query empty.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UnknownLabels {
+    pub labels: Vec<String>,
+    pub rel_types: Vec<String>,
+}
+
+impl UnknownLabels {
+    fn is_empty(&self) -> bool {
+        self.labels.is_empty() && self.rel_types.is_empty()
+    }
+
+    fn extend(&mut self, other: UnknownLabels) {
+        for label in other.labels {
+            if !self.labels.contains(&label) {
+                self.labels.push(label);
+            }
+        }
+        for rel_type in other.rel_types {
+            if !self.rel_types.contains(&rel_type) {
+                self.rel_types.push(rel_type);
+            }
+        }
+    }
+
+    /// Human-readable notification text.
+    pub fn description(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.labels.is_empty() {
+            parts.push(format!("label(s) {}", quote_all(&self.labels)));
+        }
+        if !self.rel_types.is_empty() {
+            parts.push(format!(
+                "relationship type(s) {}",
+                quote_all(&self.rel_types)
+            ));
+        }
+        format!(
+            "The schema does not define {}; the patterns using them match nothing \
+             (unknown_labels: lenient).",
+            parts.join(" and ")
+        )
+    }
+}
+
+fn quote_all(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|n| format!("`{}`", n))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Apply the schema's `unknown_labels` mode to a statement.
+///
+/// Returns the statement unchanged (and `None`) in strict mode or when every
+/// required MATCH only names known labels and types.
+pub fn resolve_unknown_labels<'a>(
+    statement: CypherStatement<'a>,
+    schema: &GraphSchema,
+) -> (CypherStatement<'a>, Option<UnknownLabels>) {
+    if schema.unknown_labels() != UnknownLabelMode::Lenient {
+        return (statement, None);
+    }
+    let CypherStatement::Query {
+        query,
+        union_clauses,
+    } = statement
+    else {
+        return (statement, None);
+    };
+
+    // Split the statement into its branches and keep the ones that can match.
+    let union_type = union_clauses.first().map(|u| u.union_type.clone());
+    let mut branches = vec![*query];
+    branches.extend(union_clauses.into_iter().map(|u| u.query));
+    let shape = branches[0].clone();
+
+    let mut notice = UnknownLabels::default();
+    let mut kept = Vec::with_capacity(branches.len());
+    for branch in branches {
+        let unknown = unknown_in_required_matches(&branch, schema);
+        if unknown.is_empty() || with_aggregates(branch.with_clause.as_ref()) {
+            kept.push(branch);
+        } else {
+            notice.extend(unknown);
+        }
+    }
+    if notice.is_empty() {
+        return (rebuild(kept, union_type), None);
+    }
+    log::info!("{}", notice.description());
+
+    if kept.is_empty() {
+        let query = empty_result(shape);
+        return (
+            CypherStatement::Query {
+                query: Box::new(query),
+                union_clauses: Vec::new(),
+            },
+            Some(notice),
+        );
+    }
+    // A lone surviving branch of a (distinct) UNION still deduplicates.
+    if kept.len() == 1 && union_type == Some(UnionType::Distinct) {
+        if let Some(ret) = kept[0].return_clause.as_mut() {
+            ret.distinct = true;
+        }
+    }
+    (rebuild(kept, union_type), Some(notice))
+}
+
+fn rebuild<'a>(
+    mut branches: Vec<OpenCypherQueryAst<'a>>,
+    union_type: Option<UnionType>,
+) -> CypherStatement<'a> {
+    let first = branches.remove(0);
+    CypherStatement::Query {
+        query: Box::new(first),
+        union_clauses: branches
+            .into_iter()
+            .map(|query| crate::open_cypher_parser::ast::UnionClause {
+                union_type: union_type.clone().unwrap_or(UnionType::All),
+                query,
+            })
+            .collect(),
+    }
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// Finding unknown names
+// ───────────────────────────────────────────────────────────────────────
+
+fn unknown_in_required_matches(
+    query: &OpenCypherQueryAst<'_>,
+    schema: &GraphSchema,
+) -> UnknownLabels {
+    let mut unknown = UnknownLabels::default();
+    let mut check = |clause: &MatchClause<'_>| {
+        for (_, pattern) in &clause.path_patterns {
+            collect_unknown(pattern, schema, &mut unknown);
+        }
+    };
+    query.match_clauses.iter().for_each(&mut check);
+    for reading in &query.reading_clauses {
+        if let ReadingClause::Match(clause) = reading {
+            check(clause);
+        }
+    }
+    let mut with = query.with_clause.as_ref();
+    while let Some(clause) = with {
+        if let Some(subsequent) = &clause.subsequent_match {
+            check(subsequent);
+        }
+        with = clause.subsequent_with.as_deref();
+    }
+    unknown
+}
+
+fn collect_unknown(pattern: &PathPattern<'_>, schema: &GraphSchema, out: &mut UnknownLabels) {
+    let node = |labels: &Option<Vec<&str>>, out: &mut UnknownLabels| {
+        // `(n:A|B)` matches if any alternative exists.
+        if let Some(labels) = labels {
+            if !labels.iter().any(|l| is_known_label(l, schema)) {
+                out.extend(UnknownLabels {
+                    labels: labels.iter().map(|l| l.to_string()).collect(),
+                    rel_types: Vec::new(),
+                });
+            }
+        }
+    };
+    match pattern {
+        PathPattern::Node(n) => node(&n.labels, out),
+        PathPattern::ConnectedPattern(connected) => {
+            for c in connected {
+                node(&c.start_node.borrow().labels, out);
+                node(&c.end_node.borrow().labels, out);
+                if let Some(types) = &c.relationship.labels {
+                    if !types.iter().any(|t| is_known_rel_type(t, schema)) {
+                        out.extend(UnknownLabels {
+                            labels: Vec::new(),
+                            rel_types: types.iter().map(|t| t.to_string()).collect(),
+                        });
+                    }
+                }
+            }
+        }
+        PathPattern::ShortestPath(inner) | PathPattern::AllShortestPaths(inner) => {
+            collect_unknown(inner, schema, out)
+        }
+    }
+}
+
+fn is_known_label(label: &str, schema: &GraphSchema) -> bool {
+    label.starts_with('$') || schema.node_schema_opt(label).is_some()
+}
+
+fn is_known_rel_type(rel_type: &str, schema: &GraphSchema) -> bool {
+    rel_type.starts_with('$') || !schema.rel_schemas_for_type(rel_type).is_empty()
+}
+
+// ───────────────────────────────────────────────────────────────────────
+// Empty result
+// ───────────────────────────────────────────────────────────────────────
+
+/// Aggregates and their value over zero rows (`None` → null).
+fn empty_aggregate<'a>(name: &str) -> Option<Option<Expression<'a>>> {
+    match name.to_lowercase().as_str() {
+        "count" | "sum" => Some(Some(Expression::Literal(Literal::Integer(0)))),
+        "collect" => Some(Some(Expression::List(Vec::new()))),
+        "min" | "max" | "avg" | "stdev" | "stdevp" | "percentilecont" | "percentiledisc" => {
+            Some(None)
+        }
+        _ => None,
+    }
+}
+
+fn contains_aggregate(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::FunctionCallExp(f) => {
+            empty_aggregate(&f.name).is_some() || f.args.iter().any(contains_aggregate)
+        }
+        Expression::OperatorApplicationExp(op) => op.operands.iter().any(contains_aggregate),
+        Expression::List(items) => items.iter().any(contains_aggregate),
+        _ => false,
+    }
+}
+
+fn with_aggregates(with: Option<&WithClause<'_>>) -> bool {
+    let mut clause = with;
+    while let Some(w) = clause {
+        if w.with_items
+            .iter()
+            .any(|i| contains_aggregate(&i.expression))
+        {
+            return true;
+        }
+        clause = w.subsequent_with.as_deref();
+    }
+    false
+}
+
+/// Replace every aggregate call by its value over zero rows.
+fn fold_aggregates(expr: Expression<'_>) -> Expression<'_> {
+    match expr {
+        Expression::FunctionCallExp(f) => match empty_aggregate(&f.name) {
+            Some(value) => value.unwrap_or(Expression::Literal(Literal::Null)),
+            None => {
+                let mut f = f;
+                f.args = f.args.into_iter().map(fold_aggregates).collect();
+                Expression::FunctionCallExp(f)
+            }
+        },
+        Expression::OperatorApplicationExp(mut op) => {
+            op.operands = op.operands.into_iter().map(fold_aggregates).collect();
+            Expression::OperatorApplicationExp(op)
+        }
+        Expression::List(items) => {
+            Expression::List(items.into_iter().map(fold_aggregates).collect())
+        }
+        other => other,
+    }
+}
+
+/// A MATCH-less query with `shape`'s RETURN columns: no rows, or one row of
+/// empty aggregates when every RETURN item aggregates.
+fn empty_result(shape: OpenCypherQueryAst<'_>) -> OpenCypherQueryAst<'_> {
+    let items = shape
+        .return_clause
+        .map(|r| r.return_items)
+        .unwrap_or_default();
+    let aggregate_only =
+        !items.is_empty() && items.iter().all(|i| contains_aggregate(&i.expression));
+    let return_items = items
+        .into_iter()
+        .map(|item| ReturnItem {
+            alias: item.alias.or(item.original_text),
+            expression: if aggregate_only {
+                fold_aggregates(item.expression)
+            } else {
+                Expression::Literal(Literal::Null)
+            },
+            original_text: item.original_text,
+            all_properties: false,
+        })
+        .collect();
+
+    OpenCypherQueryAst {
+        use_clause: shape.use_clause,
+        match_clauses: Vec::new(),
+        optional_match_clauses: Vec::new(),
+        reading_clauses: Vec::new(),
+        call_clause: None,
+        call_subquery: None,
+        unwind_clauses: Vec::new(),
+        with_clause: None,
+        where_clause: None,
+        create_clause: None,
+        foreach_clause: None,
+        merge_clause: None,
+        set_clause: None,
+        remove_clause: None,
+        delete_clause: None,
+        return_clause: Some(ReturnClause {
+            distinct: false,
+            return_items,
+        }),
+        order_by_clause: None,
+        skip_clause: None,
+        limit_clause: (!aggregate_only).then_some(LimitClause { limit_item: 0 }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    fn schema(mode: &str) -> GraphSchema {
+        let yaml =
+            std::fs::read_to_string("benchmarks/social_network/schemas/social_benchmark.yaml")
+                .unwrap()
+                .replace(
+                    "graph_schema:",
+                    &format!("graph_schema:\n  unknown_labels: {}", mode),
+                );
+        GraphSchemaConfig::from_yaml_str(&yaml)
+            .unwrap()
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    fn sql(query: &str, schema: &GraphSchema) -> Result<String, String> {
+        crate::clickhouse_query_generator::cypher_to_sql(query, schema, 100)
+    }
+
+    #[test]
+    fn strict_mode_keeps_the_planning_error() {
+        let err = sql("MATCH (n:Nope) RETURN n", &schema("strict")).unwrap_err();
+        assert!(err.contains("Nope"), "{err}");
+    }
+
+    #[test]
+    fn lenient_mode_returns_no_rows() {
+        let schema = schema("lenient");
+        let (_, stmt) = crate::open_cypher_parser::parse_cypher_statement(
+            "MATCH (n:Nope)-[:NOPE]->(u:User) RETURN n.name AS name, u",
+        )
+        .unwrap();
+        let (_, notice) = resolve_unknown_labels(stmt, &schema);
+        let notice = notice.unwrap();
+        assert_eq!(notice.labels, ["Nope"]);
+        assert_eq!(notice.rel_types, ["NOPE"]);
+
+        let out = sql("MATCH (n:Nope) RETURN n.name AS name, n", &schema).unwrap();
+        assert!(out.contains("LIMIT 0") && out.contains("\"name\""), "{out}");
+        assert!(!out.contains("FROM"), "{out}");
+    }
+
+    #[test]
+    fn lenient_aggregates_over_nothing() {
+        let out = sql(
+            "MATCH (n:Nope) RETURN count(n) AS c, collect(n.name) AS names, max(n.age) + 1 AS m",
+            &schema("lenient"),
+        )
+        .unwrap();
+        assert!(!out.contains("LIMIT 0"), "one row expected; SQL:\n{out}");
+        assert!(out.contains("0 AS \"c\""), "{out}");
+    }
+
+    #[test]
+    fn lenient_union_drops_unknown_branches() {
+        let schema = schema("lenient");
+        let out = sql(
+            "MATCH (n:Nope) RETURN n.name AS name UNION ALL MATCH (u:User) RETURN u.name AS name",
+            &schema,
+        )
+        .unwrap();
+        assert!(
+            out.contains("users_bench") && !out.contains("UNION"),
+            "{out}"
+        );
+
+        // Known labels and OPTIONAL MATCH are left to the planner.
+        assert!(sql("MATCH (u:User) RETURN u.name", &schema).is_ok());
+        assert!(sql(
+            "MATCH (u:User) OPTIONAL MATCH (u)-[:NOPE]->(m:Nope) RETURN u.name, m",
+            &schema
+        )
+        .is_err());
+    }
+}
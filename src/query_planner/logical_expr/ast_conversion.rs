@@ -208,10 +208,31 @@ impl<'a> TryFrom<open_cypher_parser::ast::FunctionCall<'a>> for LogicalExpr {
                 }
                 return Ok(LogicalExpr::PatternCount(PatternCount {
                     pattern: PathPattern::try_from(pp.clone())?,
+                    where_clause: None,
                 }));
             }
         }
 
+        // Legacy exists(): `exists(n.prop)` is a null check, and
+        // `exists((a)-[:R]->())` is the pre-4.x spelling of `EXISTS { ... }`.
+        // Left as a ScalarFnCall it would render as ClickHouse's subquery-only
+        // `exists(...)`, which rejects a column argument.
+        if name_lower == "exists" && value.args.len() == 1 {
+            let arg = value.args.into_iter().next().expect("one argument");
+            if let open_cypher_parser::ast::Expression::PathPattern(pattern) = arg {
+                return Ok(LogicalExpr::ExistsSubquery(ExistsSubquery::try_from(
+                    open_cypher_parser::ast::ExistsSubquery {
+                        pattern,
+                        where_clause: None,
+                    },
+                )?));
+            }
+            return Ok(LogicalExpr::OperatorApplicationExp(OperatorApplication {
+                operator: Operator::IsNotNull,
+                operands: vec![LogicalExpr::try_from(arg)?],
+            }));
+        }
+
         // Standard Neo4j aggregate functions. Includes the standard-deviation
         // aggregates (stDev/stDevP) — #638/#600.3: without these, `stDev(x)` was
         // classified as a ScalarFnCall, so a post-WITH aggregation stage treated
@@ -665,6 +686,28 @@ impl<'a> std::convert::TryFrom<open_cypher_parser::ast::Expression<'a>> for Logi
             Expression::ExistsExpression(exists) => Ok(LogicalExpr::ExistsSubquery(
                 ExistsSubquery::try_from(*exists)?,
             )),
+            Expression::CountExpression(count) => {
+                // Same guardrail as size(...) (#578): the pattern-count renderer
+                // cannot carry a hop bound or enumerate OR'd types.
+                if let Some(reason) = path_pattern_has_vlp_or_multi_type(&count.pattern) {
+                    return Err(errors::LogicalExprError::UnsupportedExpression(format!(
+                        "this COUNT {{ ... }} pattern {reason}. ClickGraph counts \
+                         COUNT subqueries with a correlated per-hop subquery that \
+                         cannot carry a hop bound or enumerate every OR'd \
+                         relationship type. Workaround: count via a top-level \
+                         MATCH + count(*), or add one COUNT {{ }} per \
+                         relationship type."
+                    )));
+                }
+                let count = *count;
+                Ok(LogicalExpr::PatternCount(PatternCount {
+                    pattern: PathPattern::try_from(count.pattern)?,
+                    where_clause: count
+                        .where_clause
+                        .map(|w| LogicalExpr::try_from(w.conditions).map(Box::new))
+                        .transpose()?,
+                }))
+            }
             Expression::ReduceExp(reduce) => Ok(LogicalExpr::ReduceExpr(ReduceExpr {
                 accumulator: reduce.accumulator.to_string(),
                 initial_value: Box::new(Self::try_from(*reduce.initial_value)?),
@@ -115,6 +115,50 @@ pub(crate) fn find_label_for_alias_in_plan(
     }
 }
 
+/// Give every `PatternCount` whose anchor (the pattern's first node) is
+/// unlabeled the label that alias is bound to in `plan`.
+///
+/// `MATCH (n:User) WHERE COUNT { (n)--() } > 100` writes the anchor without a
+/// label; the renderer needs it to find the edge tables touching `n` when the
+/// relationship is untyped, and to resolve `n`'s id column in general.
+pub(crate) fn label_pattern_count_anchors(expr: &LogicalExpr, plan: &LogicalPlan) -> LogicalExpr {
+    use super::visitors::{map_expression, ExprRewrite};
+    use super::{ConnectedPattern, NodePattern, PathPattern, PatternCount};
+    use std::sync::Arc;
+
+    map_expression(expr, &mut |e| {
+        let LogicalExpr::PatternCount(pc) = e else {
+            return ExprRewrite::Recurse;
+        };
+        let PathPattern::ConnectedPattern(connected) = &pc.pattern else {
+            return ExprRewrite::Recurse;
+        };
+        let Some(first) = connected.first() else {
+            return ExprRewrite::Recurse;
+        };
+        let label = match (&first.start_node.label, &first.start_node.name) {
+            (None, Some(alias)) => find_label_for_alias_in_plan(plan, alias),
+            _ => None,
+        };
+        let Some(label) = label else {
+            return ExprRewrite::Recurse;
+        };
+
+        let mut connected = connected.clone();
+        connected[0] = ConnectedPattern {
+            start_node: Arc::new(NodePattern {
+                label: Some(label),
+                ..first.start_node.as_ref().clone()
+            }),
+            ..first.clone()
+        };
+        ExprRewrite::Replace(LogicalExpr::PatternCount(PatternCount {
+            pattern: PathPattern::ConnectedPattern(connected),
+            where_clause: pc.where_clause.clone(),
+        }))
+    })
+}
+
 /// Map a Cypher property to its corresponding database column using the schema.
 ///
 /// This is the core property mapping function that consults GLOBAL_SCHEMAS.
@@ -219,7 +263,7 @@ pub fn rewrite_expression_with_property_mapping(
                         }
                         return LogicalExpr::PropertyAccessExp(PropertyAccess {
                             table_alias: prop.table_alias.clone(),
-                            column: PropertyValue::Column(db_col),
+                            column: PropertyValue::from_raw(db_col),
                         });
                     }
                     ResolvedProperty::Unresolved => {
@@ -266,7 +310,7 @@ pub fn rewrite_expression_with_property_mapping(
                             );
                             LogicalExpr::PropertyAccessExp(PropertyAccess {
                                 table_alias: prop.table_alias.clone(),
-                                column: PropertyValue::Column(db_column),
+                                column: PropertyValue::from_raw(db_column),
                             })
                         }
                         Err(e) => {
@@ -148,12 +148,16 @@ pub enum LogicalExpr {
     CteEntityRef(CteEntityRef),
 }
 
-/// Pattern count for size() on patterns
-/// Represents `size((n)-[:REL]->())` which counts pattern matches
+/// Pattern count for size() on patterns and COUNT subqueries
+/// Represents `size((n)-[:REL]->())` or `COUNT { (n)-[:REL]->(m) WHERE ... }`,
+/// which count pattern matches
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PatternCount {
     /// The pattern to count
     pub pattern: PathPattern,
+    /// Inner WHERE of a `COUNT { ... WHERE ... }` subquery
+    #[serde(default)]
+    pub where_clause: Option<Box<LogicalExpr>>,
 }
 
 /// Reduce expression for folding a list into a single value
@@ -8,7 +8,7 @@ use std::sync::Arc;
 use crate::graph_catalog::expression_parser::PropertyValue;
 use crate::open_cypher_parser::ast;
 use crate::query_planner::logical_expr::{
-    LogicalExpr, Operator, OperatorApplication, Property, PropertyAccess, TableAlias,
+    Literal, LogicalExpr, Operator, OperatorApplication, Property, PropertyAccess, TableAlias,
 };
 use crate::query_planner::logical_plan::{
     errors::LogicalPlanError, plan_builder::LogicalPlanResult, GraphRel, LogicalPlan,
@@ -170,6 +170,63 @@ pub fn convert_properties(
     Ok(extracted_props)
 }
 
+/// Take a `{sample: f}` traversal hint out of a relationship's properties.
+///
+/// `-[r:FOLLOWS {sample: 0.1}]->` scans about 10% of the FOLLOWS edges for
+/// this occurrence only (see `try_generate_relationship_view_scan`), so the
+/// hint is removed from `props` instead of becoming an `r.sample = 0.1`
+/// filter. A relationship type that maps a `sample` property keeps the
+/// ordinary meaning.
+///
+/// # Errors
+/// * The fraction is not a number in (0, 1]
+/// * The relationship does not resolve to exactly one type
+pub fn take_edge_sample(
+    props: &mut Vec<Property>,
+    rel_alias: &str,
+    rel_labels: &Option<Vec<String>>,
+    plan_ctx: &mut PlanCtx,
+) -> LogicalPlanResult<()> {
+    let Some(index) = props
+        .iter()
+        .position(|p| matches!(p, Property::PropertyKV(kv) if kv.key == "sample"))
+    else {
+        return Ok(());
+    };
+    let single_type = match rel_labels.as_deref() {
+        Some([rel_type]) => rel_type,
+        _ => {
+            return Err(LogicalPlanError::QueryPlanningError(format!(
+                "The sample hint on relationship '{}' needs a single relationship type",
+                rel_alias
+            )))
+        }
+    };
+    if plan_ctx
+        .schema()
+        .get_rel_schema(single_type)
+        .is_ok_and(|rel_schema| rel_schema.property_mappings.contains_key("sample"))
+    {
+        return Ok(());
+    }
+    let Property::PropertyKV(hint) = props.remove(index) else {
+        unreachable!("position matched a key/value property");
+    };
+    let fraction = match hint.value {
+        LogicalExpr::Literal(Literal::Float(f)) => f,
+        LogicalExpr::Literal(Literal::Integer(i)) => i as f64,
+        _ => f64::NAN,
+    };
+    if !(fraction > 0.0 && fraction <= 1.0) {
+        return Err(LogicalPlanError::QueryPlanningError(format!(
+            "The sample hint on relationship '{}' must be a number in (0, 1]",
+            rel_alias
+        )));
+    }
+    plan_ctx.set_edge_sample(rel_alias, fraction);
+    Ok(())
+}
+
 /// Convert all property patterns in plan_ctx to filter expressions.
 ///
 /// Iterates through all table contexts in plan_ctx, extracts property patterns,
@@ -25,6 +25,7 @@ use super::helpers::{
     convert_properties, convert_properties_to_operator_application, determine_optional_anchor,
     generate_denormalization_aware_scan, generate_scan, is_denormalized_scan,
     is_label_denormalized, register_node_in_context, register_relationship_in_context,
+    take_edge_sample,
 };
 use super::view_scan::generate_relationship_center;
 use crate::query_planner::analyzer::match_type_inference::{
@@ -329,7 +330,7 @@ fn traverse_connected_pattern_with_mode<'a>(
         }
 
         // Compute rel_properties early (needed by both multi-type and regular paths)
-        let rel_properties_early = rel
+        let mut rel_properties_early = rel
             .properties
             .clone()
             .map(|props| {
@@ -346,6 +347,7 @@ fn traverse_connected_pattern_with_mode<'a>(
                 ))
             })?
             .unwrap_or_default();
+        take_edge_sample(&mut rel_properties_early, &rel_alias, &rel_labels, plan_ctx)?;
 
         // === FULLY UNTYPED MULTI-TYPE PATTERN - DEFERRED UNION ===
         // **NEW (Feb 2026 - PatternResolver 2.0)**: Instead of creating Union immediately,
@@ -969,7 +971,7 @@ fn traverse_connected_pattern_with_mode<'a>(
         );
 
         log::debug!("Parsed relationship labels: {:?}", rel_labels);
-        let rel_properties = rel
+        let mut rel_properties = rel
             .properties
             .clone()
             .map(|props| {
@@ -986,6 +988,7 @@ fn traverse_connected_pattern_with_mode<'a>(
                 ))
             })?
             .unwrap_or_default();
+        take_edge_sample(&mut rel_properties, &rel_alias, &rel_labels, plan_ctx)?;
 
         crate::debug_print!(
             "│ End node: alias='{}', label={:?}",
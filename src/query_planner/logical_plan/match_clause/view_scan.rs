@@ -14,6 +14,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::graph_catalog::expression_parser::PropertyValue;
+use crate::graph_catalog::filter_parser::SchemaFilter;
 use crate::query_planner::logical_plan::errors::LogicalPlanError;
 use crate::query_planner::logical_plan::plan_builder::LogicalPlanResult;
 use crate::query_planner::logical_plan::{LogicalPlan, Union, UnionType, ViewScan};
@@ -43,7 +44,36 @@ fn ensure_node_id_property(
 ) {
     props
         .entry(id_prop_name.to_string())
-        .or_insert_with(|| PropertyValue::Column(id_column.to_string()));
+        .or_insert_with(|| PropertyValue::from_raw(id_column.to_string()));
+}
+
+/// `(SELECT DISTINCT <column> AS <property>, ... FROM <table> [WHERE <filter>])`
+/// over a denormalized node's property mappings, for scanning a node that
+/// appears in a single position of its source table. The schema filter
+/// references source columns, so it is applied inside.
+fn distinct_node_source(
+    table: &str,
+    properties: &HashMap<String, PropertyValue>,
+    filter: Option<&crate::graph_catalog::filter_parser::SchemaFilter>,
+) -> String {
+    let mut names: Vec<&String> = properties.keys().collect();
+    names.sort();
+    let columns: Vec<String> = names
+        .into_iter()
+        .map(|name| {
+            format!(
+                "{} AS {}",
+                properties[name].to_sql_column_only(),
+                PropertyValue::Column(name.clone()).to_sql_column_only()
+            )
+        })
+        .collect();
+    let mut sql = format!("(SELECT DISTINCT {} FROM {}", columns.join(", "), table);
+    if let Some(filter) = filter {
+        sql.push_str(&format!(" WHERE {}", filter.raw));
+    }
+    sql.push(')');
+    sql
 }
 
 /// Try to generate a ViewScan for a node by looking up the label in the schema from plan_ctx.
@@ -144,7 +174,9 @@ pub fn try_generate_view_scan(
                                 let mut property_mapping: HashMap<String, PropertyValue> =
                                     from_props
                                         .iter()
-                                        .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+                                        .map(|(k, v)| {
+                                            (k.clone(), PropertyValue::from_raw(v.clone()))
+                                        })
                                         .collect();
 
                                 // Get the actual ID column name from node_id property
@@ -225,7 +257,7 @@ pub fn try_generate_view_scan(
                                 // Populate property_mapping from to_props so full node expansion works
                                 let mut property_mapping: HashMap<String, PropertyValue> = to_props
                                     .iter()
-                                    .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+                                    .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
                                     .collect();
 
                                 // Get the actual ID column name from node_id property
@@ -363,7 +395,7 @@ pub fn try_generate_view_scan(
                 .map(|props| {
                     props
                         .iter()
-                        .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+                        .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
                         .collect()
                 })
                 .unwrap_or_default();
@@ -375,7 +407,7 @@ pub fn try_generate_view_scan(
                 .map(|props| {
                     props
                         .iter()
-                        .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+                        .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
                         .collect()
                 })
                 .unwrap_or_default();
@@ -415,7 +447,7 @@ pub fn try_generate_view_scan(
             from_scan.from_node_properties = node_schema.from_properties.as_ref().map(|props| {
                 props
                     .iter()
-                    .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+                    .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
                     .collect()
             });
             // Make the node_id projectable for whole-node `RETURN n` viz (Bolt transformer).
@@ -423,6 +455,7 @@ pub fn try_generate_view_scan(
                 ensure_node_id_property(props, &id_prop_name, &from_id_column);
             }
             from_scan.schema_filter = node_schema.filter.clone();
+            from_scan.use_final = node_schema.should_use_final();
             from_scan.node_label = Some(base_label.to_string());
             // Note: to_node_properties is None - this is the FROM branch
 
@@ -439,7 +472,7 @@ pub fn try_generate_view_scan(
             to_scan.to_node_properties = node_schema.to_properties.as_ref().map(|props| {
                 props
                     .iter()
-                    .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+                    .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
                     .collect()
             });
             // Make the node_id projectable for whole-node `RETURN n` viz (Bolt transformer).
@@ -447,6 +480,7 @@ pub fn try_generate_view_scan(
                 ensure_node_id_property(props, &id_prop_name, &to_id_column);
             }
             to_scan.schema_filter = node_schema.filter.clone();
+            to_scan.use_final = node_schema.should_use_final();
             to_scan.node_label = Some(base_label.to_string());
             // Note: from_node_properties is None - this is the TO branch
 
@@ -487,36 +521,46 @@ pub fn try_generate_view_scan(
             })
             .cloned()
             .unwrap_or_else(|| id_prop_name.clone());
+        // A node in only one position is a column of its source table, so the
+        // table holds one row per OCCURRENCE (e.g. every dns_log row naming a
+        // domain). Scan the distinct property tuples instead, so each node is
+        // one row — the same semantics the both-positions UNION DISTINCT above
+        // has. The subquery's columns are the property names themselves.
+        let mut properties: HashMap<String, PropertyValue> = node_schema
+            .from_properties
+            .as_ref()
+            .or(node_schema.to_properties.as_ref())
+            .map(|props| {
+                props
+                    .iter()
+                    .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Make the node_id projectable for whole-node `RETURN n` viz (Bolt transformer).
+        ensure_node_id_property(&mut properties, &id_prop_name, &single_id_column);
+        let source =
+            distinct_node_source(&full_table_name, &properties, node_schema.filter.as_ref());
+        let identity: HashMap<String, PropertyValue> = properties
+            .keys()
+            .map(|k| (k.clone(), PropertyValue::Column(k.clone())))
+            .collect();
+
         let mut view_scan = ViewScan::new(
-            full_table_name,
+            source,
             None,
             HashMap::new(),
-            single_id_column.clone(),
+            id_prop_name.clone(),
             vec![],
             vec![],
         );
 
         view_scan.is_denormalized = true;
-        view_scan.from_node_properties = node_schema.from_properties.as_ref().map(|props| {
-            props
-                .iter()
-                .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
-                .collect()
-        });
-        view_scan.to_node_properties = node_schema.to_properties.as_ref().map(|props| {
-            props
-                .iter()
-                .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
-                .collect()
-        });
-        // Make the node_id projectable for whole-node `RETURN n` viz (Bolt transformer).
-        if let Some(props) = view_scan.from_node_properties.as_mut() {
-            ensure_node_id_property(props, &id_prop_name, &single_id_column);
-        }
-        if let Some(props) = view_scan.to_node_properties.as_mut() {
-            ensure_node_id_property(props, &id_prop_name, &single_id_column);
+        if node_schema.from_properties.is_some() {
+            view_scan.from_node_properties = Some(identity);
+        } else {
+            view_scan.to_node_properties = Some(identity);
         }
-        view_scan.schema_filter = node_schema.filter.clone();
         // Extract base label from potentially qualified name (e.g., "brahmand::flights_denorm::Airport" -> "Airport")
         let base_label = if label.contains("::") {
             label.split("::").last().unwrap_or(label)
@@ -564,6 +608,7 @@ pub fn try_generate_view_scan(
             );
 
             view_scan.schema_filter = other_schema.filter.clone();
+            view_scan.use_final = other_schema.should_use_final();
             // Extract base label from potentially qualified name
             let base_label = if label.contains("::") {
                 label.split("::").last().unwrap_or(label)
@@ -752,14 +797,14 @@ pub fn try_generate_view_scan(
         view_scan.from_node_properties = node_schema.from_properties.as_ref().map(|props| {
             props
                 .iter()
-                .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+                .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
                 .collect()
         });
 
         view_scan.to_node_properties = node_schema.to_properties.as_ref().map(|props| {
             props
                 .iter()
-                .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+                .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
                 .collect()
         });
 
@@ -780,6 +825,7 @@ pub fn try_generate_view_scan(
 
     // Set schema-level filter if defined in schema
     view_scan.schema_filter = node_schema.filter.clone();
+    view_scan.use_final = node_schema.should_use_final();
     if view_scan.schema_filter.is_some() {
         log::info!(
             "ViewScan: Applied schema filter for label '{}': {:?}",
@@ -812,7 +858,7 @@ pub fn try_generate_view_scan(
 /// - `Some(plan)` - Successfully created relationship ViewScan
 /// - `None` - Relationship type not found in schema
 pub fn try_generate_relationship_view_scan(
-    _alias: &str,
+    alias: &str,
     rel_type: &str,
     left_node_label: Option<&str>,
     right_node_label: Option<&str>,
@@ -942,13 +988,13 @@ pub fn try_generate_relationship_view_scan(
     view_scan.from_node_properties = rel_schema.from_node_properties.as_ref().map(|props| {
         props
             .iter()
-            .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+            .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
             .collect()
     });
     view_scan.to_node_properties = rel_schema.to_node_properties.as_ref().map(|props| {
         props
             .iter()
-            .map(|(k, v)| (k.clone(), PropertyValue::Column(v.clone())))
+            .map(|(k, v)| (k.clone(), PropertyValue::from_raw(v.clone())))
             .collect()
     });
 
@@ -976,6 +1022,7 @@ pub fn try_generate_relationship_view_scan(
 
     // Set schema-level filter if defined in schema
     view_scan.schema_filter = rel_schema.filter.clone();
+    view_scan.use_final = rel_schema.should_use_final();
     if view_scan.schema_filter.is_some() {
         log::info!(
             "ViewScan: Applied schema filter for relationship '{}': {:?}",
@@ -984,9 +1031,66 @@ pub fn try_generate_relationship_view_scan(
         );
     }
 
+    // `-[r:TYPE {sample: f}]->` hint: this occurrence scans a fraction of the edges
+    if let Some(fraction) = plan_ctx.edge_sample(alias) {
+        view_scan.schema_filter =
+            sampled_edge_filter(&view_scan.schema_filter, rel_schema, fraction);
+        log::info!(
+            "ViewScan: Sampling {} of relationship '{}' for '{}'",
+            fraction,
+            rel_type,
+            alias
+        );
+    }
+
     Some(Arc::new(LogicalPlan::ViewScan(Arc::new(view_scan))))
 }
 
+/// Hash buckets a sampled edge falls into; the fraction's resolution.
+const EDGE_SAMPLE_BUCKETS: u64 = 1_000_000;
+
+/// `filter` ANDed with a predicate keeping `fraction` of the edges.
+///
+/// Edges are picked by hashing their identity (edge id when mapped, else the
+/// endpoint ids), not by `rand()`: every scan of the table — both sides of a
+/// multi-hop join, each step of a variable-length CTE — then keeps the same
+/// edges, so the traversal runs over one consistent sampled graph.
+fn sampled_edge_filter(
+    filter: &Option<SchemaFilter>,
+    rel_schema: &crate::graph_catalog::graph_schema::RelationshipSchema,
+    fraction: f64,
+) -> Option<SchemaFilter> {
+    let kept = (fraction * EDGE_SAMPLE_BUCKETS as f64).round() as u64;
+    if kept >= EDGE_SAMPLE_BUCKETS {
+        return filter.clone();
+    }
+    let columns = match &rel_schema.edge_id {
+        Some(edge_id) => edge_id.columns(),
+        None => {
+            let mut columns = rel_schema.from_id.columns();
+            columns.extend(rel_schema.to_id.columns());
+            columns
+        }
+    };
+    let predicate = format!(
+        "{} < {}",
+        crate::sql_generator::function_mapper::current_function_mapper()
+            .hash_bucket(&columns.join(", "), EDGE_SAMPLE_BUCKETS),
+        kept
+    );
+    let combined = match filter {
+        Some(existing) => format!("({}) AND {}", existing.raw, predicate),
+        None => predicate,
+    };
+    match SchemaFilter::new(&combined) {
+        Ok(sampled) => Some(sampled),
+        Err(e) => {
+            log::warn!("Could not apply edge sample '{}': {}", combined, e);
+            filter.clone()
+        }
+    }
+}
+
 /// Generate a relationship center (ViewScan if possible, otherwise Empty plan).
 ///
 /// This function is used internally during pattern processing to create the
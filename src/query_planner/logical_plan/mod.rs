@@ -329,7 +329,8 @@ static ALIAS_COUNTER: AtomicU32 = AtomicU32::new(1);
 /// Generate a simple, human-readable alias for anonymous nodes/edges.
 /// Returns "t1", "t2", "t3", etc. Much easier to read than UUID hex strings!
 pub fn generate_id() -> String {
-    let n = ALIAS_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let n = crate::server::query_context::next_deterministic_counter(|c| &mut c.aliases)
+        .unwrap_or_else(|| ALIAS_COUNTER.fetch_add(1, Ordering::SeqCst));
     format!("t{}", n)
 }
 
@@ -344,6 +345,7 @@ pub fn reset_alias_counter() {
 pub fn reset_all_counters() {
     ALIAS_COUNTER.store(1, Ordering::SeqCst);
     CTE_COUNTER.store(1, Ordering::SeqCst);
+    crate::server::query_context::reset_deterministic_counters();
 }
 
 static CTE_COUNTER: AtomicU32 = AtomicU32::new(1);
@@ -351,7 +353,8 @@ static CTE_COUNTER: AtomicU32 = AtomicU32::new(1);
 /// Generate a simple, human-readable CTE name.
 /// Returns "cte1", "cte2", "cte3", etc. Much shorter than UUID strings!
 pub fn generate_cte_id() -> String {
-    let n = CTE_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let n = crate::server::query_context::next_deterministic_counter(|c| &mut c.ctes)
+        .unwrap_or_else(|| CTE_COUNTER.fetch_add(1, Ordering::SeqCst));
     format!("cte{}", n)
 }
 
@@ -448,6 +451,8 @@ pub struct PatternComprehensionMeta {
     pub target_label: Option<String>,
     /// Property name from the projection (e.g., "name" in `| b.name`)
     pub target_property: Option<String>,
+    /// Pattern variable the projection reads (e.g., "b" in `| b.name`)
+    pub target_alias: Option<String>,
     /// ALL outer variables correlated from pattern (multi-correlation support)
     pub correlation_vars: Vec<CorrelationVarInfo>,
     /// Full multi-hop pattern chain (serializable form of ConnectedPattern)
@@ -582,6 +587,17 @@ pub struct Create {
     #[serde(with = "serde_arc")]
     pub input: Arc<LogicalPlan>,
     pub patterns: Vec<CreatePattern>,
+    /// Set for MERGE: each node is inserted only when no row matches its
+    /// pattern properties. `None` for plain CREATE.
+    #[serde(default)]
+    pub merge: Option<MergeOptions>,
+}
+
+/// MERGE-specific parts of a `Create`.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct MergeOptions {
+    /// `ON CREATE SET` items, written together with the inserted row.
+    pub on_create: Vec<SetItem>,
 }
 
 /// A single `n.prop = expr` SET item.
@@ -2856,6 +2872,7 @@ mod tests {
         assert_map_children_identity(&LogicalPlan::Create(Create {
             input: leaf.clone(),
             patterns: vec![],
+            merge: None,
         }));
 
         // --- SetProperties (one child, write op) ---
@@ -3089,6 +3106,7 @@ mod tests {
         let create = LogicalPlan::Create(Create {
             input: marker("in_create"),
             patterns: vec![],
+            merge: None,
         });
         assert!(has_marker(&create, "in_create"));
     }
@@ -3406,6 +3424,7 @@ mod tests {
             expression: CypherExpression::Variable("customer_name"),
             alias: Some("full_name"),
             original_text: None,
+            all_properties: false,
         };
 
         let projection_item = ProjectionItem::try_from(ast_return_item).unwrap();
@@ -14,6 +14,7 @@ use std::sync::Arc;
 
 use crate::{
     open_cypher_parser::ast::OrderByClause,
+    query_planner::logical_expr::{visitors::TableAliasCollector, LogicalExpr},
     query_planner::logical_plan::{errors::LogicalPlanError, LogicalPlan, OrderBy, OrderByItem},
 };
 
@@ -29,8 +30,50 @@ pub fn evaluate_order_by_clause<'a>(
     let predicates = predicates.map_err(|e| {
         LogicalPlanError::QueryPlanningError(format!("Failed to convert ORDER BY item: {}", e))
     })?;
+    if let LogicalPlan::Projection(projection) = plan.as_ref() {
+        let star = projection
+            .items
+            .iter()
+            .any(|item| matches!(item.expression, LogicalExpr::Star));
+        if projection.distinct && !star {
+            check_distinct_order_scope(&projection.items, &predicates)?;
+        }
+    }
     Ok(Arc::new(LogicalPlan::OrderBy(OrderBy {
         input: plan,
         items: predicates,
     })))
 }
+
+/// After `RETURN DISTINCT`, an ORDER BY item must be a returned expression or
+/// read only returned variables and aliases (`RETURN DISTINCT u ORDER BY
+/// u.age`), as in Neo4j. Sorting by anything else would need values that
+/// differ within one distinct row.
+fn check_distinct_order_scope(
+    returned: &[crate::query_planner::logical_plan::ProjectionItem],
+    order_by: &[OrderByItem],
+) -> Result<(), LogicalPlanError> {
+    let names: Vec<&str> = returned
+        .iter()
+        .filter_map(|item| match &item.expression {
+            LogicalExpr::TableAlias(alias) => Some(alias.0.as_str()),
+            _ => item.col_alias.as_ref().map(|alias| alias.0.as_str()),
+        })
+        .collect();
+    for item in order_by {
+        if returned.iter().any(|r| r.expression == item.expression) {
+            continue;
+        }
+        if let Some(variable) = TableAliasCollector::collect(&item.expression)
+            .into_iter()
+            .find(|variable| !names.contains(&variable.as_str()))
+        {
+            return Err(LogicalPlanError::QueryPlanningError(format!(
+                "In a RETURN with DISTINCT, ORDER BY can only use returned variables and \
+                 expressions; '{}' is not returned",
+                variable
+            )));
+        }
+    }
+    Ok(())
+}
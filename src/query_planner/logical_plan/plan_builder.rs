@@ -56,11 +56,30 @@ pub fn build_logical_plan(
     let mut logical_plan: Arc<LogicalPlan> = Arc::new(LogicalPlan::Empty);
     let mut plan_ctx = PlanCtx::with_all_parameters(
         Arc::new(schema.clone()),
-        tenant_id,
-        view_parameter_values,
+        tenant_id.clone(),
+        view_parameter_values.clone(),
         max_inferred_types.unwrap_or(20), // Default 20 for Neo4j Browser compatibility
     );
 
+    // A `CALL { ... UNION ... }` opening the query is its input; its returned
+    // columns reach the rest of the query through a WITH. UNION-free CALL
+    // bodies were inlined before planning (`query_planner::call_subquery`).
+    let call_with = match &query_ast.call_subquery {
+        Some(call) => {
+            let (input, call_ctx) = build_call_union_input(
+                call,
+                schema,
+                tenant_id,
+                view_parameter_values,
+                max_inferred_types,
+            )?;
+            logical_plan = input;
+            plan_ctx = call_ctx;
+            Some(call_returns_with(call, query_ast.with_clause.as_ref()))
+        }
+        None => None,
+    };
+
     log::debug!(
         "build_logical_plan: Processing query with {} MATCH clauses, {} optional_match_clauses",
         query_ast.match_clauses.len(),
@@ -71,6 +90,8 @@ pub fn build_logical_plan(
     // Standalone write queries (e.g., `CREATE (a:Person {...})` with no RETURN)
     // are valid — they have no read-side clauses but do have CREATE/SET/DELETE/REMOVE.
     let has_write_clause = query_ast.create_clause.is_some()
+        || query_ast.foreach_clause.is_some()
+        || query_ast.merge_clause.is_some()
         || query_ast.set_clause.is_some()
         || query_ast.delete_clause.is_some()
         || query_ast.remove_clause.is_some();
@@ -207,7 +228,7 @@ pub fn build_logical_plan(
     // Process WITH clause before WHERE to create intermediate projections
     // WITH creates a projection that can be referenced by subsequent clauses (including WHERE)
     // This now handles chained WITH...MATCH...WITH patterns via recursion
-    if let Some(with_clause_ast) = &query_ast.with_clause {
+    if let Some(with_clause_ast) = call_with.as_ref().or(query_ast.with_clause.as_ref()) {
         logical_plan = process_with_clause_chain(with_clause_ast, logical_plan, &mut plan_ctx)?;
     }
 
@@ -218,12 +239,31 @@ pub fn build_logical_plan(
     }
 
     // Process write clauses (embedded chdb only; gated by `write_guard` at the
-    // executor entry point). Order matters: CREATE wraps its preceding read
-    // pipeline, then SET / REMOVE / DELETE chain on top. Per OpenCypher,
-    // queries with multiple write clauses apply them in this order.
+    // executor entry point). Order matters: CREATE (or MERGE) wraps its
+    // preceding read pipeline, then SET / REMOVE / DELETE chain on top. Per
+    // OpenCypher, queries with multiple write clauses apply them in this order.
     if let Some(create_clause) = &query_ast.create_clause {
         logical_plan = write_clause_builder::build_create(create_clause, logical_plan, schema)?;
     }
+    // `FOREACH (x IN list | CREATE ...)` creates once per element, which is
+    // `UNWIND list AS x CREATE ...` as long as no rows are returned after it.
+    if let Some(foreach) = &query_ast.foreach_clause {
+        if query_ast.return_clause.is_some() {
+            return Err(LogicalPlanError::QueryPlanningError(
+                "FOREACH followed by RETURN is not supported; use UNWIND ... CREATE".to_string(),
+            ));
+        }
+        let unwind = crate::open_cypher_parser::ast::UnwindClause {
+            expression: foreach.list.clone(),
+            alias: foreach.variable,
+        };
+        logical_plan = unwind_clause::evaluate_unwind_clause(&unwind, logical_plan, &mut plan_ctx)?;
+        logical_plan =
+            write_clause_builder::build_create(&foreach.create_clause, logical_plan, schema)?;
+    }
+    if let Some(merge_clause) = &query_ast.merge_clause {
+        logical_plan = write_clause_builder::build_merge(merge_clause, logical_plan, schema)?;
+    }
     if let Some(set_clause) = &query_ast.set_clause {
         logical_plan = write_clause_builder::build_set(set_clause, logical_plan, schema)?;
     }
@@ -274,6 +314,64 @@ pub fn build_logical_plan(
     Ok((logical_plan, plan_ctx))
 }
 
+/// Plan the UNION body of a `CALL { }` like a top-level UNION statement.
+fn build_call_union_input(
+    call: &crate::open_cypher_parser::ast::CallSubquery<'_>,
+    schema: &GraphSchema,
+    tenant_id: Option<String>,
+    view_parameter_values: Option<HashMap<String, String>>,
+    max_inferred_types: Option<usize>,
+) -> LogicalPlanResult<(Arc<LogicalPlan>, PlanCtx)> {
+    super::evaluate_cypher_statement(
+        crate::open_cypher_parser::ast::CypherStatement::Query {
+            query: call.query.clone(),
+            union_clauses: call.union_clauses.clone(),
+        },
+        schema,
+        tenant_id,
+        view_parameter_values,
+        max_inferred_types,
+    )
+}
+
+/// `WITH <returned columns>` over a `CALL { }` input, followed by the
+/// query's own WITH chain.
+fn call_returns_with<'a>(
+    call: &crate::open_cypher_parser::ast::CallSubquery<'a>,
+    then: Option<&crate::open_cypher_parser::ast::WithClause<'a>>,
+) -> crate::open_cypher_parser::ast::WithClause<'a> {
+    use crate::open_cypher_parser::ast::{Expression, WithClause, WithItem};
+
+    let with_items = call
+        .query
+        .return_clause
+        .iter()
+        .flat_map(|r| &r.return_items)
+        .filter_map(|item| match (&item.expression, item.alias) {
+            (_, Some(alias)) => Some(alias),
+            (Expression::Variable(v), None) => Some(*v),
+            _ => None,
+        })
+        .map(|name| WithItem {
+            expression: Expression::Variable(name),
+            alias: None,
+        })
+        .collect();
+    WithClause {
+        with_items,
+        is_star: false,
+        distinct: false,
+        order_by: None,
+        skip: None,
+        limit: None,
+        where_clause: None,
+        subsequent_unwind: None,
+        subsequent_match: None,
+        subsequent_optional_matches: Vec::new(),
+        subsequent_with: then.cloned().map(Box::new),
+    }
+}
+
 /// Process a chain of WITH clauses recursively
 /// Handles patterns like: WITH a MATCH ... WITH a, b MATCH ... WITH a, b, c ...
 ///
@@ -479,8 +577,9 @@ fn extract_source_alias_from_expr(
         // Simple variable reference: WITH u AS person
         LogicalExpr::TableAlias(ta) => Some(ta.0.clone()),
 
-        // Property access: WITH u.name AS name - extract the table alias
-        LogicalExpr::PropertyAccessExp(pa) => Some(pa.table_alias.0.clone()),
+        // Property access (WITH u.name AS name) is NOT a renaming: the
+        // output is a scalar, and copying `u`'s labels would make `name` a
+        // node that `toUpper(name)` resolves as `name.name`
 
         // Column reference: WITH x AS y
         LogicalExpr::Column(col) => Some(col.0.clone()),
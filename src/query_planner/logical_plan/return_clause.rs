@@ -20,6 +20,7 @@ use crate::{
     graph_catalog::expression_parser::PropertyValue,
     open_cypher_parser::ast::{Expression, ReturnClause, ReturnItem},
     query_planner::logical_expr::{
+        expression_rewriter::label_pattern_count_anchors, visitors::HasAggregateCheck,
         AggregateFnCall, ColumnAlias, LogicalExpr, PropertyAccess, TableAlias,
     },
     query_planner::logical_plan::{LogicalPlan, Projection, ProjectionItem, Union, UnionType},
@@ -35,33 +36,9 @@ type PatternComprehension<'a> = (
 );
 use std::sync::Arc;
 
-/// Check if an expression contains any aggregate function calls (recursively).
+/// Check if an expression contains any aggregate function calls, at any depth.
 fn contains_aggregate(expr: &LogicalExpr) -> bool {
-    match expr {
-        LogicalExpr::AggregateFnCall(_) => true,
-        LogicalExpr::OperatorApplicationExp(op) => op.operands.iter().any(contains_aggregate),
-        LogicalExpr::ScalarFnCall(func) => func.args.iter().any(contains_aggregate),
-        LogicalExpr::List(list) => list.iter().any(contains_aggregate),
-        LogicalExpr::Case(case_expr) => {
-            if let Some(expr) = &case_expr.expr {
-                if contains_aggregate(expr) {
-                    return true;
-                }
-            }
-            for (when_cond, then_val) in &case_expr.when_then {
-                if contains_aggregate(when_cond) || contains_aggregate(then_val) {
-                    return true;
-                }
-            }
-            if let Some(else_expr) = &case_expr.else_expr {
-                if contains_aggregate(else_expr) {
-                    return true;
-                }
-            }
-            false
-        }
-        _ => false,
-    }
+    HasAggregateCheck::check(expr)
 }
 
 /// Extract all PropertyAccess expressions from an expression (for columns needed in subquery).
@@ -435,9 +412,11 @@ fn rewrite_pattern_comprehensions<'a>(
             rewrite_expression_pattern_comprehensions(item.expression);
 
         // Extract metadata for CTE+JOIN generation (same approach as WITH clause)
-        for (pattern, _where_clause, projection) in pattern_comprehensions {
+        for (pattern, where_clause, projection) in pattern_comprehensions {
             use crate::query_planner::logical_plan::with_clause::{
+                extract_all_correlation_variables_from_pattern,
                 extract_correlation_variable_from_pattern, extract_direction_and_rel_types,
+                extract_pattern_hops, extract_projected_property,
             };
             use crate::query_planner::logical_plan::AggregationType;
 
@@ -462,6 +441,14 @@ fn rewrite_pattern_comprehensions<'a>(
             // Extract target node label and projected property from the pattern
             let (target_label, target_property) =
                 extract_target_info(&pattern, &projection, &correlation_var);
+            let (target_alias, _) = extract_projected_property(&projection);
+
+            // Full hop chain and WHERE for multi-hop / filtered comprehensions
+            let correlation_vars =
+                extract_all_correlation_variables_from_pattern(&pattern, plan_ctx);
+            let pattern_hops = extract_pattern_hops(&pattern, plan_ctx);
+            let where_clause = where_clause
+                .and_then(|w| crate::query_planner::logical_expr::LogicalExpr::try_from(*w).ok());
 
             // Determine aggregation type from the rewritten expression
             let agg_type = match &rewritten_expr {
@@ -506,9 +493,10 @@ fn rewrite_pattern_comprehensions<'a>(
                     result_alias: result_alias.clone(),
                     target_label,
                     target_property,
-                    correlation_vars: vec![],
-                    pattern_hops: vec![],
-                    where_clause: None,
+                    target_alias,
+                    correlation_vars,
+                    pattern_hops,
+                    where_clause,
                     position_index: pc_counter,
                     list_constraint: None,
                 },
@@ -521,6 +509,7 @@ fn rewrite_pattern_comprehensions<'a>(
             expression: rewritten_expr,
             alias: item.alias,
             original_text: item.original_text,
+            all_properties: item.all_properties,
         };
         rewritten_items.push(new_item);
     }
@@ -549,6 +538,12 @@ pub fn evaluate_return_clause<'a>(
     }
     crate::debug_print!("========================================");
 
+    for item in &return_clause.return_items {
+        if let (true, Expression::Variable(alias)) = (item.all_properties, &item.expression) {
+            plan_ctx.require_full_projection(alias);
+        }
+    }
+
     // Rewrite pattern comprehensions before converting to ProjectionItems
     let (rewritten_return_items, plan, pattern_comp_metas) =
         rewrite_pattern_comprehensions(return_clause.return_items.clone(), plan, plan_ctx);
@@ -560,6 +555,12 @@ pub fn evaluate_return_clause<'a>(
     let projection_items: Vec<ProjectionItem> = rewritten_return_items
         .iter()
         .map(|item| ProjectionItem::try_from(item.clone()))
+        .map(|item| {
+            item.map(|item| ProjectionItem {
+                expression: label_pattern_count_anchors(&item.expression, &plan),
+                col_alias: item.col_alias,
+            })
+        })
         .collect::<Result<Vec<_>, _>>()?;
 
     // If input is a Union, handle specially
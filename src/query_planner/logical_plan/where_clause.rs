@@ -27,7 +27,7 @@ use std::sync::Arc;
 use crate::{
     open_cypher_parser::ast::WhereClause,
     query_planner::{
-        logical_expr::LogicalExpr,
+        logical_expr::{expression_rewriter::label_pattern_count_anchors, LogicalExpr},
         logical_plan::{errors::LogicalPlanError, Filter, LogicalPlan, Union},
     },
 };
@@ -43,6 +43,7 @@ pub fn evaluate_where_clause<'a>(
                 e
             ))
         })?;
+    let predicates = label_pattern_count_anchors(&predicates, &plan);
     log::debug!(
         "evaluate_where_clause: WHERE predicate after conversion: {:?}",
         predicates
@@ -27,7 +27,7 @@
 use crate::{
     open_cypher_parser::ast::{Expression, WithClause as AstWithClause, WithItem},
     query_planner::{
-        logical_expr::LogicalExpr,
+        logical_expr::{expression_rewriter::label_pattern_count_anchors, LogicalExpr},
         logical_plan::{errors::LogicalPlanError, LogicalPlan, OrderByItem, ProjectionItem},
         plan_ctx::PlanCtx,
     },
@@ -44,6 +44,8 @@ struct PatternComprehensionInfo<'a> {
     where_clause: Option<Box<Expression<'a>>>,
     /// How this comprehension is aggregated (Count, GroupArray, Sum, etc.)
     aggregation_type: crate::query_planner::logical_plan::AggregationType,
+    /// Projection after `|` (e.g., `f.name`); None for list comprehensions
+    projection: Option<Expression<'a>>,
     /// For list comprehensions: (iteration_var, list_alias) — e.g., ("p", "posts")
     list_constraint: Option<(String, String)>,
 }
@@ -194,6 +196,12 @@ pub fn evaluate_with_clause<'a>(
     let projection_items: Vec<ProjectionItem> = rewritten_with_items
         .iter()
         .map(|item| ProjectionItem::try_from(item.clone()))
+        .map(|item| {
+            item.map(|item| ProjectionItem {
+                expression: label_pattern_count_anchors(&item.expression, &plan),
+                col_alias: item.col_alias,
+            })
+        })
         .collect::<Result<Vec<_>, _>>()?;
 
     log::debug!(
@@ -245,6 +253,7 @@ pub fn evaluate_with_clause<'a>(
                     e
                 ))
             })?;
+        let predicate = label_pattern_count_anchors(&predicate, &with_node.input);
         with_node = with_node.with_where(predicate);
     }
 
@@ -365,33 +374,16 @@ fn rewrite_with_pattern_comprehensions<'a>(
             // Extract full pattern info for correlated subquery generation
             let correlation_vars_info =
                 extract_all_correlation_variables_from_pattern(&pc_info.pattern, plan_ctx);
-            let mut pattern_hops_info = extract_connected_pattern_info(&pc_info.pattern);
+            let pattern_hops_info = extract_pattern_hops(&pc_info.pattern, plan_ctx);
             let pc_where_clause = pc_info
                 .where_clause
                 .as_ref()
                 .and_then(|w| LogicalExpr::try_from(w.as_ref().clone()).ok());
-
-            // Enrich hop labels from plan_ctx for variables without explicit labels
-            for hop in &mut pattern_hops_info {
-                if hop.start_label.is_none() {
-                    if let Some(ref alias) = hop.start_alias {
-                        if let Ok(ctx) = plan_ctx.get_table_ctx(alias) {
-                            if let Some(labels) = ctx.get_labels() {
-                                hop.start_label = labels.iter().next().cloned();
-                            }
-                        }
-                    }
-                }
-                if hop.end_label.is_none() {
-                    if let Some(ref alias) = hop.end_alias {
-                        if let Ok(ctx) = plan_ctx.get_table_ctx(alias) {
-                            if let Some(labels) = ctx.get_labels() {
-                                hop.end_label = labels.iter().next().cloned();
-                            }
-                        }
-                    }
-                }
-            }
+            let (target_alias, target_property) = pc_info
+                .projection
+                .as_ref()
+                .map(extract_projected_property)
+                .unwrap_or_default();
 
             // For list comprehension, try to infer the iteration variable's label
             // from the list source. e.g., [p IN posts WHERE (p)-[:HAS_TAG]->()...] where
@@ -427,7 +419,8 @@ fn rewrite_with_pattern_comprehensions<'a>(
                     agg_type: pc_info.aggregation_type,
                     result_alias: result_alias.clone(),
                     target_label: None,
-                    target_property: None,
+                    target_property,
+                    target_alias,
                     correlation_vars: correlation_vars_info,
                     pattern_hops: pattern_hops_info,
                     where_clause: pc_where_clause,
@@ -469,19 +462,24 @@ fn rewrite_expression_pattern_comprehensions<'a>(
 
     match expr {
         Expression::PatternComprehension(pc) => {
-            log::info!("🔄 Found bare PatternComprehension, replacing with collect()");
-            // Found a bare pattern comprehension - collect it and replace with collect(projection)
-            let collect_call = Expression::FunctionCallExp(FunctionCall {
-                name: "collect".to_string(),
-                args: vec![(*pc.projection).clone()],
+            log::info!("🔄 Found bare PatternComprehension, replacing with placeholder");
+            // A bare pattern comprehension is a list: the pre-aggregated PC CTE
+            // collects the projection, so leave a count(*) placeholder that the
+            // render phase swaps for the CTE's result column.
+            let placeholder = Expression::FunctionCallExp(FunctionCall {
+                name: "count".to_string(),
+                args: vec![Expression::Literal(
+                    crate::open_cypher_parser::ast::Literal::String("*"),
+                )],
             });
             (
-                collect_call,
+                placeholder,
                 vec![PatternComprehensionInfo {
                     pattern: (*pc.pattern).clone(),
                     where_clause: pc.where_clause.clone(),
                     aggregation_type:
                         crate::query_planner::logical_plan::AggregationType::GroupArray,
+                    projection: Some((*pc.projection).clone()),
                     list_constraint: None,
                 }],
             )
@@ -551,8 +549,12 @@ fn rewrite_expression_pattern_comprehensions<'a>(
                         }
                     };
 
-                    // For size/length, replace with count(*), for others replace with the function call
-                    let replacement_expr = if matches!(agg_type, AggregationType::Count) {
+                    // For size/length and collect, replace with the count(*) placeholder the
+                    // PC CTE fills in; for others replace with the function call
+                    let replacement_expr = if matches!(
+                        agg_type,
+                        AggregationType::Count | AggregationType::GroupArray
+                    ) {
                         Expression::FunctionCallExp(FunctionCall {
                             name: "count".to_string(),
                             args: vec![Expression::Literal(
@@ -572,6 +574,7 @@ fn rewrite_expression_pattern_comprehensions<'a>(
                             pattern: (*pc.pattern).clone(),
                             where_clause: pc.where_clause.clone(),
                             aggregation_type: agg_type,
+                            projection: Some((*pc.projection).clone()),
                             list_constraint: None,
                         }],
                     );
@@ -640,6 +643,7 @@ fn rewrite_expression_pattern_comprehensions<'a>(
                                         pattern: path_pattern,
                                         where_clause: None, // WHERE is already in the pattern
                                         aggregation_type: crate::query_planner::logical_plan::AggregationType::Count,
+                                        projection: None,
                                         list_constraint: Some((iteration_var, list_alias)),
                                     }],
                                 );
@@ -827,10 +831,22 @@ pub(crate) fn extract_direction_and_rel_types(
     }
 }
 
+/// Extract the variable and property a comprehension projects
+/// (e.g., `f.name` → (Some("f"), Some("name"))).
+pub(crate) fn extract_projected_property(
+    projection: &Expression<'_>,
+) -> (Option<String>, Option<String>) {
+    match projection {
+        Expression::PropertyAccessExp(pa) => (Some(pa.base.to_string()), Some(pa.key.to_string())),
+        Expression::Variable(v) => (Some(v.to_string()), None),
+        _ => (None, None),
+    }
+}
+
 /// Extract ALL correlation variables from a pattern (not just the first one).
 /// A correlation variable is a named node that already exists in the outer scope (plan_ctx).
 /// Returns a Vec with position info for each correlated variable.
-fn extract_all_correlation_variables_from_pattern(
+pub(crate) fn extract_all_correlation_variables_from_pattern(
     pattern: &crate::open_cypher_parser::ast::PathPattern<'_>,
     plan_ctx: &PlanCtx,
 ) -> Vec<crate::query_planner::logical_plan::CorrelationVarInfo> {
@@ -948,6 +964,30 @@ fn extract_connected_pattern_info(
     }
 }
 
+/// Convert a pattern into its hop chain, filling in labels of unlabeled
+/// variables that the outer scope already binds.
+pub(crate) fn extract_pattern_hops(
+    pattern: &crate::open_cypher_parser::ast::PathPattern<'_>,
+    plan_ctx: &PlanCtx,
+) -> Vec<crate::query_planner::logical_plan::ConnectedPatternInfo> {
+    let mut hops = extract_connected_pattern_info(pattern);
+    let label_of = |alias: &Option<String>| {
+        alias
+            .as_ref()
+            .and_then(|a| plan_ctx.get_table_ctx(a).ok())
+            .and_then(|ctx| ctx.get_labels().and_then(|l| l.first().cloned()))
+    };
+    for hop in &mut hops {
+        if hop.start_label.is_none() {
+            hop.start_label = label_of(&hop.start_alias);
+        }
+        if hop.end_label.is_none() {
+            hop.end_label = label_of(&hop.end_alias);
+        }
+    }
+    hops
+}
+
 /// Extract a PathPattern from an expression.
 /// Used for list comprehension WHERE clauses that contain graph patterns.
 /// e.g., `(p)-[:HAS_TAG]->()<-[:HAS_INTEREST]-(person)` → PathPattern
@@ -1,8 +1,9 @@
 //! Write-clause builder: AST → LogicalPlan write variants.
 //!
-//! Converts parsed write-clause AST nodes (`CreateClause`, `SetClause`,
-//! `DeleteClause`, `RemoveClause`) into the corresponding `LogicalPlan`
-//! variants (`Create`, `SetProperties`, `Delete`, `Remove`).
+//! Converts parsed write-clause AST nodes (`CreateClause`, `MergeClause`,
+//! `SetClause`, `DeleteClause`, `RemoveClause`) into the corresponding
+//! `LogicalPlan` variants (`Create`, `SetProperties`, `Delete`, `Remove`).
+//! MERGE is a `Create` carrying [`MergeOptions`].
 //!
 //! Per the embedded-writes design (Phase 1), this builder:
 //! - Resolves node/relationship labels against the `GraphSchema`.
@@ -19,14 +20,15 @@ use crate::{
     graph_catalog::graph_schema::GraphSchema,
     open_cypher_parser::ast::{
         ConnectedPattern, CreateClause, DeleteClause, Direction as AstDirection, Expression,
-        NodePattern, Operator as AstOperator, OperatorApplication, PathPattern, Property,
-        PropertyKVPair, RelationshipPattern, RemoveClause, SetClause,
+        MergeClause, NodePattern, Operator as AstOperator, OperatorApplication, PathPattern,
+        Property, PropertyKVPair, RelationshipPattern, RemoveClause, SetClause,
     },
     query_planner::{
         logical_expr::{Direction, LogicalExpr},
         logical_plan::{
             errors::LogicalPlanError, generate_id, Create, CreateNode, CreatePattern, CreateRel,
-            Delete, LogicalPlan, Remove, RemoveItem, SetItem, SetProperties, WriteProperty,
+            Delete, LogicalPlan, MergeOptions, Remove, RemoveItem, SetItem, SetProperties,
+            WriteProperty,
         },
     },
 };
@@ -59,7 +61,83 @@ pub fn build_create(
             "CREATE clause must specify at least one node or relationship".to_string(),
         ));
     }
-    Ok(Arc::new(LogicalPlan::Create(Create { input, patterns })))
+    Ok(Arc::new(LogicalPlan::Create(Create {
+        input,
+        patterns,
+        merge: None,
+    })))
+}
+
+/// Build a MERGE as a `LogicalPlan::Create` with [`MergeOptions`].
+///
+/// v1 scope: a single labelled node pattern in a standalone MERGE. The
+/// pattern's properties are the match key; `ON CREATE SET` items on the
+/// merged alias become extra columns of the inserted row. Relationship
+/// MERGE, `ON MATCH SET`, and MERGE after a read pipeline are rejected.
+pub fn build_merge(
+    merge: &MergeClause<'_>,
+    input: Arc<LogicalPlan>,
+    schema: &GraphSchema,
+) -> Result<Arc<LogicalPlan>> {
+    if !matches!(*input, LogicalPlan::Empty) {
+        return Err(LogicalPlanError::QueryPlanningError(
+            "MERGE after MATCH / UNWIND / WITH is not supported yet; \
+             MERGE must be the first clause of the query"
+                .to_string(),
+        ));
+    }
+    if !merge.on_match.is_empty() {
+        return Err(LogicalPlanError::QueryPlanningError(
+            "MERGE ... ON MATCH SET is not supported yet".to_string(),
+        ));
+    }
+
+    let PathPattern::Node(node_pat) = &merge.path_pattern else {
+        return Err(LogicalPlanError::QueryPlanningError(
+            "MERGE only supports a single node pattern, e.g. `MERGE (n:Label {key: value})`"
+                .to_string(),
+        ));
+    };
+    if node_pat.labels.is_none() {
+        return Err(LogicalPlanError::QueryPlanningError(
+            "MERGE requires a node label".to_string(),
+        ));
+    }
+    let node = create_node_from_pattern(node_pat, schema)?;
+
+    let mut on_create = Vec::with_capacity(merge.on_create.len());
+    for op in &merge.on_create {
+        let item = set_item_from_op(op)?;
+        if node.alias.as_deref() != Some(item.target_alias.as_str()) {
+            return Err(LogicalPlanError::QueryPlanningError(format!(
+                "ON CREATE SET target `{}` is not the merged node",
+                item.target_alias
+            )));
+        }
+        if node.properties.iter().any(|p| p.key == item.property) {
+            return Err(LogicalPlanError::QueryPlanningError(format!(
+                "ON CREATE SET `{}.{}` is already part of the MERGE pattern",
+                item.target_alias, item.property
+            )));
+        }
+        let known = schema.node_schema_opt(&node.label).is_some_and(|ns| {
+            ns.property_mappings.contains_key(&item.property)
+                || ns.column_names.iter().any(|c| c == &item.property)
+        });
+        if !known {
+            return Err(LogicalPlanError::QueryPlanningError(format!(
+                "property `{}` is not defined for node label `{}` (ON CREATE SET clause)",
+                item.property, node.label
+            )));
+        }
+        on_create.push(item);
+    }
+
+    Ok(Arc::new(LogicalPlan::Create(Create {
+        input,
+        patterns: vec![CreatePattern::Node(node)],
+        merge: Some(MergeOptions { on_create }),
+    })))
 }
 
 /// Collect every alias bound by `plan` (GraphNode / GraphRel aliases at any
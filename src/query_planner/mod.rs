@@ -1,25 +1,38 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use errors::QueryPlannerError;
 use plan_ctx::PlanCtx;
 use types::QueryType;
 
 use crate::{
-    graph_catalog::graph_schema::GraphSchema,
-    open_cypher_parser::ast::{CypherStatement, OpenCypherQueryAst},
+    graph_catalog::{
+        errors::GraphSchemaError,
+        graph_schema::{Collation, GraphSchema, TimeDimension},
+    },
+    open_cypher_parser::ast::{CypherStatement, OpenCypherQueryAst, UseClause},
     query_planner::logical_plan::{LogicalPlan, PageRank},
+    server::metrics::{record_planner_stage, PlannerStage},
 };
 
 pub mod analyzer;
 pub mod ast_transform;
+pub mod call_subquery;
+pub mod cartesian_guard;
 mod errors;
 pub mod join_context;
+pub mod label_resolution;
 pub mod logical_expr;
 pub mod logical_plan;
 pub mod optimizer;
+pub mod parameter_types;
 pub mod plan_ctx;
+pub mod scan_guard;
+pub mod stable_order;
 pub mod transformed;
+pub mod traversal_profile;
 pub mod typed_variable;
 pub mod types;
 pub mod write_guard;
@@ -58,6 +71,237 @@ pub fn get_statement_query_type(statement: &CypherStatement) -> QueryType {
     }
 }
 
+/// The graph a statement targets via `USE <graph>`, if any.
+///
+/// Every UNION branch is planned against one schema, so branches may repeat
+/// the first branch's USE (or omit it) but must not name a different graph.
+pub fn target_graph<'a>(
+    statement: &CypherStatement<'a>,
+) -> Result<Option<&'a str>, QueryPlannerError> {
+    use_clause_setting(
+        statement,
+        |u| Some(u.database_name),
+        |first, name| {
+            format!(
+            "UNION branches target different graphs ('{}' and '{}'); a query runs against a single graph",
+            first, name
+        )
+        },
+    )
+}
+
+/// The snapshot a statement reads via `USE <graph> FOR TIMESTAMP '<ts>'`, if
+/// any. Like the graph itself, one snapshot applies to every UNION branch.
+pub fn target_snapshot<'a>(
+    statement: &CypherStatement<'a>,
+) -> Result<Option<&'a str>, QueryPlannerError> {
+    use_clause_setting(
+        statement,
+        |u| u.snapshot,
+        |first, ts| {
+            format!(
+            "UNION branches read different snapshots ('{}' and '{}'); a query reads a single snapshot",
+            first, ts
+        )
+        },
+    )
+}
+
+/// The `CYPHER` prefix option that shows soft-deleted rows to one query
+/// (audits), e.g. `CYPHER include_deleted=true MATCH (u:User) RETURN u`.
+pub const INCLUDE_DELETED_HINT: &str = "include_deleted=true";
+
+/// Whether the query starts with a `CYPHER` prefix carrying `option`,
+/// e.g. `CYPHER cartesian=allow MATCH (a:User), (b:Post) RETURN ...`.
+pub fn has_prefix_option(query: &str, option: &str) -> bool {
+    let mut tokens = query.split_whitespace();
+    if !tokens
+        .next()
+        .is_some_and(|token| token.eq_ignore_ascii_case("CYPHER"))
+    {
+        return false;
+    }
+    tokens
+        .take_while(|token| token.contains('='))
+        .any(|token| token.eq_ignore_ascii_case(option))
+}
+
+/// The value of a `CYPHER` prefix option, e.g. `de+ci` for
+/// `CYPHER collation=de+ci MATCH ...`. Option names match case-insensitively.
+pub fn prefix_option_value<'q>(query: &'q str, name: &str) -> Option<&'q str> {
+    let mut tokens = query.split_whitespace();
+    if !tokens
+        .next()
+        .is_some_and(|token| token.eq_ignore_ascii_case("CYPHER"))
+    {
+        return None;
+    }
+    tokens
+        .take_while(|token| token.contains('='))
+        .find_map(|token| {
+            let (key, value) = token.split_once('=')?;
+            key.eq_ignore_ascii_case(name).then_some(value)
+        })
+}
+
+/// The `CYPHER` prefix options that change the schema a query reads. The
+/// query cache key ignores the prefix, so queries setting any of them bypass
+/// the cache.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryOptions {
+    /// `include_deleted=true`: scans of soft-delete tables also return
+    /// deleted rows
+    pub include_deleted: bool,
+    /// `collation=<spec>` (e.g. `de+ci`): the collation of every string
+    /// comparison and ORDER BY key, overriding the schema's column collations
+    pub collation: Option<String>,
+}
+
+impl QueryOptions {
+    pub fn from_query(query: &str) -> Self {
+        QueryOptions {
+            include_deleted: has_prefix_option(query, INCLUDE_DELETED_HINT),
+            collation: prefix_option_value(query, "collation").map(str::to_string),
+        }
+    }
+
+    /// Whether the query's SQL must not be shared through the query cache
+    pub fn bypasses_cache(&self) -> bool {
+        self.include_deleted || self.collation.is_some()
+    }
+
+    /// `schema` as these options see it, `None` when they change nothing
+    pub fn apply(&self, schema: &GraphSchema) -> Result<Option<GraphSchema>, QueryPlannerError> {
+        let mut view = None;
+        if self.include_deleted {
+            view = Some(schema.including_deleted());
+        }
+        if let Some(spec) = self.collation.as_deref() {
+            let collation = spec.parse::<Collation>().map_err(|e| {
+                QueryPlannerError::InvalidQuery(format!(
+                    "Invalid query collation '{}': {}",
+                    spec, e
+                ))
+            })?;
+            view = Some(
+                view.as_ref()
+                    .unwrap_or(schema)
+                    .with_query_collation(collation),
+            );
+        }
+        Ok(view)
+    }
+}
+
+/// Where in time a statement reads: its USE clause's `FOR TIMESTAMP`
+/// snapshot and `AS OF SYSTEM TIME` / `VALID AT` instants, each agreed across
+/// UNION branches. Owned, so it can outlive the parsed statement.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimeTravel {
+    pub snapshot: Option<String>,
+    pub system_time: Option<String>,
+    pub valid_time: Option<String>,
+}
+
+impl TimeTravel {
+    pub fn of(statement: &CypherStatement) -> Result<Self, QueryPlannerError> {
+        let system_time = use_clause_setting(
+            statement,
+            |u| u.system_time,
+            |first, ts| {
+                format!(
+                "UNION branches read different system times ('{}' and '{}'); a query reads a single system time",
+                first, ts
+            )
+            },
+        )?;
+        let valid_time = use_clause_setting(
+            statement,
+            |u| u.valid_time,
+            |first, ts| {
+                format!(
+                "UNION branches read different valid times ('{}' and '{}'); a query reads a single valid time",
+                first, ts
+            )
+            },
+        )?;
+        Ok(TimeTravel {
+            snapshot: target_snapshot(statement)?.map(str::to_string),
+            system_time: system_time.map(str::to_string),
+            valid_time: valid_time.map(str::to_string),
+        })
+    }
+
+    /// `schema` at these points in time, `None` when none is set: the
+    /// snapshot's tables, narrowed to the rows of bitemporal edges recorded at
+    /// the system time and valid at the valid time
+    pub fn apply(&self, schema: &GraphSchema) -> Result<Option<GraphSchema>, QueryPlannerError> {
+        let invalid = |e: GraphSchemaError| QueryPlannerError::InvalidQuery(e.to_string());
+        let mut view: Option<GraphSchema> = None;
+        if let Some(timestamp) = self.snapshot.as_deref() {
+            view = Some(schema.at_snapshot(timestamp).map_err(invalid)?);
+        }
+        for (dimension, timestamp) in [
+            (TimeDimension::System, &self.system_time),
+            (TimeDimension::Valid, &self.valid_time),
+        ] {
+            if let Some(timestamp) = timestamp.as_deref() {
+                let current = view.as_ref().unwrap_or(schema);
+                view = Some(current.at_time(dimension, timestamp).map_err(invalid)?);
+            }
+        }
+        Ok(view)
+    }
+}
+
+/// The schema a statement is planned against: `schema` itself, or its view
+/// at the USE clause's points in time ([`TimeTravel`]: every table scan then
+/// filters on the snapshot column and the bitemporal edges' periods), seen
+/// through the query's prefix `options`.
+pub fn snapshot_schema<'s>(
+    statement: &CypherStatement,
+    schema: &'s GraphSchema,
+    options: &QueryOptions,
+) -> Result<Cow<'s, GraphSchema>, QueryPlannerError> {
+    let schema = match options.apply(schema)? {
+        Some(view) => Cow::Owned(view),
+        None => Cow::Borrowed(schema),
+    };
+    match TimeTravel::of(statement)?.apply(&schema)? {
+        Some(view) => Ok(Cow::Owned(view)),
+        None => Ok(schema),
+    }
+}
+
+/// One USE clause setting, checked to agree across UNION branches (branches
+/// that omit it inherit it).
+fn use_clause_setting<'a>(
+    statement: &CypherStatement<'a>,
+    setting: impl Fn(&UseClause<'a>) -> Option<&'a str>,
+    conflict: impl Fn(&str, &str) -> String,
+) -> Result<Option<&'a str>, QueryPlannerError> {
+    let CypherStatement::Query {
+        query,
+        union_clauses,
+    } = statement
+    else {
+        return Ok(None);
+    };
+    let mut target = query.use_clause.as_ref().and_then(&setting);
+    for branch in union_clauses {
+        let Some(value) = branch.query.use_clause.as_ref().and_then(&setting) else {
+            continue;
+        };
+        match target {
+            Some(first) if first != value => {
+                return Err(QueryPlannerError::InvalidQuery(conflict(first, value)));
+            }
+            _ => target = Some(value),
+        }
+    }
+    Ok(target)
+}
+
 /// Evaluate a read query AST and return both the logical plan and the plan context.
 /// The plan context contains analysis-phase metadata (VLP endpoints, property requirements, etc.)
 /// that is needed during the rendering phase.
@@ -107,6 +351,10 @@ pub fn evaluate_read_statement(
     view_parameter_values: Option<HashMap<String, String>>,
     max_inferred_types: Option<usize>,
 ) -> Result<(LogicalPlan, PlanCtx), QueryPlannerError> {
+    let statement = call_subquery::inline_call_subqueries(statement)?;
+    let (statement, unknown_labels) =
+        label_resolution::resolve_unknown_labels(statement, current_graph_schema);
+    let stage_start = Instant::now();
     let (logical_plan, mut plan_ctx) = logical_plan::evaluate_cypher_statement(
         statement,
         current_graph_schema,
@@ -114,22 +362,39 @@ pub fn evaluate_read_statement(
         view_parameter_values,
         max_inferred_types,
     )?;
+    record_planner_stage(PlannerStage::Build, stage_start.elapsed().as_secs_f64());
 
+    // Analyzer and optimizer passes interleave; each stage's time is summed
+    let mut analyze_secs = 0.0;
+    let mut optimize_secs = 0.0;
+    let stage_start = Instant::now();
     let logical_plan =
         analyzer::initial_analyzing(logical_plan, &mut plan_ctx, current_graph_schema)?;
+    analyze_secs += stage_start.elapsed().as_secs_f64();
 
+    let stage_start = Instant::now();
     let logical_plan = optimizer::initial_optimization(logical_plan, &mut plan_ctx)?;
+    optimize_secs += stage_start.elapsed().as_secs_f64();
 
+    let stage_start = Instant::now();
     let logical_plan =
         analyzer::intermediate_analyzing(logical_plan, &mut plan_ctx, current_graph_schema)?;
+    analyze_secs += stage_start.elapsed().as_secs_f64();
 
+    let stage_start = Instant::now();
     let logical_plan = optimizer::final_optimization(logical_plan, &mut plan_ctx)?;
+    optimize_secs += stage_start.elapsed().as_secs_f64();
 
+    let stage_start = Instant::now();
     let logical_plan =
         analyzer::final_analyzing(logical_plan, &mut plan_ctx, current_graph_schema)?;
+    analyze_secs += stage_start.elapsed().as_secs_f64();
+    record_planner_stage(PlannerStage::Analyze, analyze_secs);
+    record_planner_stage(PlannerStage::Optimize, optimize_secs);
 
     let logical_plan =
         Arc::into_inner(logical_plan).ok_or(QueryPlannerError::LogicalPlanExtractor)?;
+    plan_ctx.set_unknown_labels(unknown_labels);
     Ok((logical_plan, plan_ctx))
 }
 
@@ -1332,11 +1332,36 @@ impl OptimizerPass for FilterIntoGraphRel {
                     Transformed::No(_) => Transformed::No(logical_plan.clone()),
                 }
             }
-            // Write variants — read-side filter pushdown does not apply.
-            LogicalPlan::Create(_)
-            | LogicalPlan::SetProperties(_)
-            | LogicalPlan::Delete(_)
-            | LogicalPlan::Remove(_) => Transformed::No(logical_plan.clone()),
+            // Write variants: the write itself takes no filter, but its
+            // MATCH input does — inline property maps (`MATCH (a {id: 1})
+            // DELETE a`) only live in plan_ctx until pushed down here.
+            LogicalPlan::Create(c) => match self.optimize(c.input.clone(), plan_ctx)? {
+                Transformed::Yes(input) => Transformed::Yes(Arc::new(LogicalPlan::Create(
+                    crate::query_planner::logical_plan::Create { input, ..c.clone() },
+                ))),
+                Transformed::No(_) => Transformed::No(logical_plan.clone()),
+            },
+            LogicalPlan::SetProperties(sp) => match self.optimize(sp.input.clone(), plan_ctx)? {
+                Transformed::Yes(input) => Transformed::Yes(Arc::new(LogicalPlan::SetProperties(
+                    crate::query_planner::logical_plan::SetProperties {
+                        input,
+                        ..sp.clone()
+                    },
+                ))),
+                Transformed::No(_) => Transformed::No(logical_plan.clone()),
+            },
+            LogicalPlan::Delete(d) => match self.optimize(d.input.clone(), plan_ctx)? {
+                Transformed::Yes(input) => Transformed::Yes(Arc::new(LogicalPlan::Delete(
+                    crate::query_planner::logical_plan::Delete { input, ..d.clone() },
+                ))),
+                Transformed::No(_) => Transformed::No(logical_plan.clone()),
+            },
+            LogicalPlan::Remove(r) => match self.optimize(r.input.clone(), plan_ctx)? {
+                Transformed::Yes(input) => Transformed::Yes(Arc::new(LogicalPlan::Remove(
+                    crate::query_planner::logical_plan::Remove { input, ..r.clone() },
+                ))),
+                Transformed::No(_) => Transformed::No(logical_plan.clone()),
+            },
         };
         Ok(transformed_plan)
     }
@@ -0,0 +1,256 @@
+//! Expected types of query parameters, inferred from the properties they are
+//! compared with.
+//!
+//! `u.age > $minAge` binds `$minAge` to the declared type of `User.age`
+//! (`property_types`, or the node's `type` for its id property), and
+//! `u.age IN $ages` to a list of it. The server checks supplied values
+//! against these before the query runs (`server::parameter_substitution`),
+//! so a wrong type is a 400 naming the expected type instead of a ClickHouse
+//! cast error mid-query. Properties without a declared type are not checked.
+//!
+//! Like `traversal_profile`, this reads the plan as built, before analysis
+//! rewrites property names to columns.
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+use crate::graph_catalog::expression_parser::PropertyValue;
+use crate::graph_catalog::graph_schema::GraphSchema;
+use crate::graph_catalog::schema_types::SchemaType;
+use crate::open_cypher_parser;
+use crate::query_planner::logical_expr::visitors::{walk_expression, ExpressionVisitor};
+use crate::query_planner::logical_expr::{LogicalExpr, Operator, OperatorApplication};
+use crate::query_planner::logical_plan::{self, Descend, LogicalPlan};
+use crate::query_planner::plan_ctx::PlanCtx;
+
+/// The type a parameter must have, and the property it was inferred from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterType {
+    pub expected: SchemaType,
+    /// `IN $param`: a list of `expected`.
+    pub list: bool,
+    /// The compared property, e.g. `u.age`.
+    pub site: String,
+}
+
+/// Expected types of the parameters of `cypher`, keyed by parameter name.
+/// Queries that do not plan have none.
+pub fn infer_parameter_types(cypher: &str, schema: &GraphSchema) -> HashMap<String, ParameterType> {
+    let Ok((_, statement)) = open_cypher_parser::parse_cypher_statement(cypher) else {
+        return HashMap::new();
+    };
+    match logical_plan::evaluate_cypher_statement(statement, schema, None, None, None) {
+        Ok((plan, plan_ctx)) => infer_from_plan(&plan, &plan_ctx, schema),
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// Expected parameter types of a built (unanalyzed) plan and its context,
+/// which holds the filters of inline `{key: $param}` maps. When a parameter
+/// is compared with several typed properties, the first one wins.
+pub fn infer_from_plan(
+    plan: &LogicalPlan,
+    plan_ctx: &PlanCtx,
+    schema: &GraphSchema,
+) -> HashMap<String, ParameterType> {
+    // alias -> (label, is relationship)
+    let mut elements: HashMap<String, (String, bool)> = HashMap::new();
+    let mut comparisons = ComparisonCollector::default();
+    for (alias, table_ctx) in plan_ctx.iter_table_contexts() {
+        for filter in table_ctx.get_filters() {
+            walk_expression(filter, &mut comparisons);
+        }
+        if let Some([label]) = table_ctx.get_labels().map(Vec::as_slice) {
+            elements.insert(alias.clone(), (label.clone(), table_ctx.is_relation()));
+        }
+    }
+
+    plan.walk(&mut |node| {
+        let mut exprs: Vec<&LogicalExpr> = Vec::new();
+        match node {
+            LogicalPlan::GraphNode(n) => {
+                if let Some(label) = &n.label {
+                    elements.insert(n.alias.clone(), (label.clone(), false));
+                }
+            }
+            LogicalPlan::GraphRel(rel) => {
+                if let Some([rel_type]) = rel.labels.as_deref() {
+                    elements.insert(rel.alias.clone(), (rel_type.clone(), true));
+                }
+                exprs.extend(&rel.where_predicate);
+            }
+            LogicalPlan::Filter(f) => exprs.push(&f.predicate),
+            LogicalPlan::WithClause(w) => exprs.extend(&w.where_clause),
+            LogicalPlan::GroupBy(g) => exprs.extend(&g.having_clause),
+            _ => {}
+        }
+        for expr in exprs {
+            walk_expression(expr, &mut comparisons);
+        }
+        ControlFlow::<(), _>::Continue(Descend::Yes)
+    });
+
+    let mut types = HashMap::new();
+    for (parameter, alias, property, list) in comparisons.found {
+        if types.contains_key(&parameter) {
+            continue;
+        }
+        let Some((label, is_rel)) = elements.get(&alias) else {
+            continue;
+        };
+        if let Some(expected) = property_type(schema, label, *is_rel, &property) {
+            types.insert(
+                parameter,
+                ParameterType {
+                    expected,
+                    list,
+                    site: format!("{}.{}", alias, property),
+                },
+            );
+        }
+    }
+    types
+}
+
+/// Declared type of `label.property`: `property_types`, else the node's
+/// single id `type`.
+fn property_type(
+    schema: &GraphSchema,
+    label: &str,
+    is_rel: bool,
+    property: &str,
+) -> Option<SchemaType> {
+    if is_rel {
+        let rel = schema.get_relationships_schema_opt(label).or_else(|| {
+            let rel_type = label.split("::").next().unwrap_or(label);
+            schema.rel_schemas_for_type(rel_type).into_iter().next()
+        })?;
+        return rel.property_types.get(property).cloned();
+    }
+    let node = schema.node_schema_opt(label)?;
+    if let Some(declared) = node.property_types.get(property) {
+        return Some(declared.clone());
+    }
+    match node.node_id_types.as_deref() {
+        Some([id_type]) if node.node_id.columns() == [property] => Some(id_type.clone()),
+        _ => None,
+    }
+}
+
+/// `(parameter, alias, property, list)` for every `alias.property <op>
+/// $parameter` comparison, in either operand order.
+#[derive(Default)]
+struct ComparisonCollector {
+    found: Vec<(String, String, String, bool)>,
+}
+
+impl ExpressionVisitor for ComparisonCollector {
+    type Output = ();
+
+    fn visit_operator(&mut self, op: &OperatorApplication) {
+        let list = match op.operator {
+            Operator::Equal
+            | Operator::NotEqual
+            | Operator::LessThan
+            | Operator::GreaterThan
+            | Operator::LessThanEqual
+            | Operator::GreaterThanEqual
+            | Operator::StartsWith
+            | Operator::EndsWith
+            | Operator::Contains => false,
+            Operator::In | Operator::NotIn => true,
+            _ => return,
+        };
+        let [left, right] = op.operands.as_slice() else {
+            return;
+        };
+        let pair = match (left, right) {
+            (LogicalExpr::PropertyAccessExp(prop), LogicalExpr::Parameter(name)) => {
+                Some((prop, name))
+            }
+            // `$value IN u.tags` is a membership test on a list property
+            (LogicalExpr::Parameter(name), LogicalExpr::PropertyAccessExp(prop)) if !list => {
+                Some((prop, name))
+            }
+            _ => None,
+        };
+        if let Some((prop, name)) = pair {
+            if let PropertyValue::Column(property) = &prop.column {
+                self.found.push((
+                    name.clone(),
+                    prop.table_alias.0.clone(),
+                    property.clone(),
+                    list,
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(
+            r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: user_id
+      type: integer
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        age: age
+        nickname: nickname
+      property_types:
+        name: string
+        age: integer
+  edges:
+    - type: FOLLOWS
+      database: test
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings:
+        since: follow_date
+      property_types:
+        since: date
+"#,
+        )
+        .expect("valid yaml")
+        .to_graph_schema()
+        .expect("valid schema")
+    }
+
+    #[test]
+    fn infers_types_from_compared_properties() {
+        let types = infer_parameter_types(
+            "MATCH (u:User)-[f:FOLLOWS]->(v:User) \
+             WHERE u.age > $minAge AND $name = v.name AND f.since >= $since \
+             AND v.user_id IN $ids AND u.nickname = $nick \
+             RETURN v.name",
+            &schema(),
+        );
+        assert_eq!(types["minAge"].expected, SchemaType::Integer);
+        assert_eq!(types["minAge"].site, "u.age");
+        assert_eq!(types["name"].expected, SchemaType::String);
+        assert_eq!(types["since"].expected, SchemaType::Date);
+        assert_eq!(types["ids"].expected, SchemaType::Integer);
+        assert!(types["ids"].list);
+        // No declared type, nothing to check
+        assert!(!types.contains_key("nick"));
+    }
+
+    #[test]
+    fn inline_property_maps_are_comparisons_too() {
+        let types = infer_parameter_types("MATCH (u:User {age: $age}) RETURN u.name", &schema());
+        assert_eq!(types["age"].expected, SchemaType::Integer);
+    }
+}
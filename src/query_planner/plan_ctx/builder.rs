@@ -215,6 +215,9 @@ impl PlanCtxBuilder {
             where_property_requirements: self.where_property_requirements,
             where_label_constraints: HashMap::new(),
             status_messages: Vec::new(),
+            unknown_labels: None,
+            full_projection_aliases: HashSet::new(),
+            edge_samples: HashMap::new(),
             node_combinations: HashMap::new(),
             pattern_combinations: HashMap::new(),
             group_combinations: HashMap::new(),
@@ -43,6 +43,7 @@ use crate::{
     query_planner::{
         analyzer::property_requirements::PropertyRequirements,
         join_context::VlpEndpointInfo,
+        label_resolution::UnknownLabels,
         logical_expr::LogicalExpr,
         logical_plan::ProjectionItem,
         plan_ctx::errors::PlanCtxError,
@@ -159,6 +160,18 @@ pub struct PlanCtx {
     /// Example: (Warning, "Type combinations limited to 38")
     status_messages: Vec<(StatusLevel, String)>,
 
+    /// Unknown labels / relationship types that made this query empty under
+    /// `unknown_labels: lenient` (see `query_planner::label_resolution`)
+    unknown_labels: Option<UnknownLabels>,
+
+    /// Aliases returned as `RETURN n { .* }`: expanded to every property,
+    /// past the label's `return_properties` / `max_return_columns`
+    full_projection_aliases: HashSet<String>,
+
+    /// Relationship aliases written with a `{sample: f}` traversal hint
+    /// Map: `rel_alias → fraction` of the edge table the scan keeps
+    edge_samples: HashMap<String, f64>,
+
     /// Node type combinations for simple untyped node queries
     /// Map: `node_alias → Vec<label>`
     /// Example: `{"n": ["User", "Post", "ZeekLog"]}`
@@ -530,6 +543,9 @@ impl PlanCtx {
             where_property_requirements: HashMap::new(),
             where_label_constraints: HashMap::new(),
             status_messages: Vec::new(),
+            unknown_labels: None,
+            full_projection_aliases: HashSet::new(),
+            edge_samples: HashMap::new(),
             node_combinations: HashMap::new(),
             group_combinations: HashMap::new(),
             pattern_combinations: HashMap::new(),
@@ -563,6 +579,9 @@ impl PlanCtx {
             where_property_requirements: HashMap::new(),
             where_label_constraints: HashMap::new(),
             status_messages: Vec::new(),
+            unknown_labels: None,
+            full_projection_aliases: HashSet::new(),
+            edge_samples: HashMap::new(),
             node_combinations: HashMap::new(),
             group_combinations: HashMap::new(),
             pattern_combinations: HashMap::new(),
@@ -626,6 +645,9 @@ impl PlanCtx {
             where_property_requirements: HashMap::new(),
             where_label_constraints: HashMap::new(),
             status_messages: Vec::new(),
+            unknown_labels: None,
+            full_projection_aliases: HashSet::new(),
+            edge_samples: HashMap::new(),
             node_combinations: HashMap::new(),
             group_combinations: HashMap::new(),
             pattern_combinations: HashMap::new(),
@@ -671,6 +693,9 @@ impl PlanCtx {
             where_property_requirements: HashMap::new(),
             where_label_constraints: HashMap::new(),
             status_messages: Vec::new(),
+            unknown_labels: None,
+            full_projection_aliases: HashSet::new(),
+            edge_samples: HashMap::new(),
             node_combinations: HashMap::new(),
             group_combinations: HashMap::new(),
             pattern_combinations: HashMap::new(),
@@ -707,6 +732,9 @@ impl PlanCtx {
             where_property_requirements: HashMap::new(),
             where_label_constraints: HashMap::new(),
             status_messages: Vec::new(),
+            unknown_labels: None,
+            full_projection_aliases: HashSet::new(),
+            edge_samples: HashMap::new(),
             node_combinations: HashMap::new(),
             group_combinations: HashMap::new(),
             pattern_combinations: HashMap::new(),
@@ -762,6 +790,41 @@ impl PlanCtx {
         self.status_messages.clear();
     }
 
+    /// Record the unknown labels a lenient schema resolved to "no match"
+    pub fn set_unknown_labels(&mut self, unknown: Option<UnknownLabels>) {
+        self.unknown_labels = unknown;
+    }
+
+    /// Unknown labels that made this query empty, if any
+    pub fn unknown_labels(&self) -> Option<&UnknownLabels> {
+        self.unknown_labels.as_ref()
+    }
+
+    /// Record that `alias` was returned as `alias { .* }`
+    pub fn require_full_projection(&mut self, alias: &str) {
+        self.full_projection_aliases.insert(alias.to_string());
+    }
+
+    /// Whether `alias` was returned as `alias { .* }`
+    pub fn has_full_projection(&self, alias: &str) -> bool {
+        self.full_projection_aliases.contains(alias)
+    }
+
+    /// Every alias returned as `alias { .* }`
+    pub fn full_projection_aliases(&self) -> &HashSet<String> {
+        &self.full_projection_aliases
+    }
+
+    /// Record that the scan of relationship `alias` keeps only `fraction` of its edges
+    pub fn set_edge_sample(&mut self, alias: &str, fraction: f64) {
+        self.edge_samples.insert(alias.to_string(), fraction);
+    }
+
+    /// Sampling fraction of relationship `alias`, if it carries a `sample` hint
+    pub fn edge_sample(&self, alias: &str) -> Option<f64> {
+        self.edge_samples.get(alias).copied()
+    }
+
     // ========================================================================
     // CTE Management
     // ========================================================================
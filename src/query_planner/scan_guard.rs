@@ -0,0 +1,285 @@
+//! Scan guard — per-label protection against unfiltered whole-label scans.
+//!
+//! `MATCH (n:Event) RETURN n` against a 10B-row table is an easy foot-gun for
+//! exploratory clients. Node definitions can declare `max_unfiltered_rows`
+//! (resolved into [`UnfilteredScanLimit`]); this guard looks for the
+//! anonymous-exploration shape and either caps it with a `LIMIT` plus a
+//! notification, or rejects it, depending on `on_unfiltered_scan`.
+//!
+//! The guarded shape is a single labelled node scan with no WHERE clause and no
+//! inline property filter, projected without aggregation, optionally under
+//! ORDER BY / SKIP / LIMIT. Anything else (relationships, filters, WITH,
+//! UNION, aggregates) passes through untouched. A `LIMIT` at or below the
+//! declared maximum already satisfies the guard.
+//!
+//! Call sites: the HTTP and Bolt read paths call [`enforce_unfiltered_scan_limit`]
+//! after the planner produces a `LogicalPlan` and before rendering. Trusted
+//! callers (server `trusted_roles`) bypass the guard.
+
+use std::sync::Arc;
+
+use crate::{
+    graph_catalog::graph_schema::{GraphSchema, UnfilteredScanAction, UnfilteredScanLimit},
+    query_planner::{
+        logical_expr::{
+            visitors::{walk_expression, ExpressionVisitor},
+            AggregateFnCall,
+        },
+        logical_plan::{Limit, LogicalPlan},
+    },
+};
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum ScanGuardError {
+    #[error(
+        "Unfiltered scan of node label `{label}` is not allowed: it may return more than \
+         {max_rows} rows. Add a WHERE clause or property filter, or a LIMIT of at most {max_rows}."
+    )]
+    UnfilteredScanRejected { label: String, max_rows: u64 },
+}
+
+/// What the guard did to the plan.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanGuardOutcome {
+    /// The plan is not an unfiltered scan of a guarded label (or its LIMIT is
+    /// already within bounds). The plan is returned unchanged.
+    NotApplicable,
+    /// The plan is a guarded scan, but the caller is trusted. Unchanged.
+    Trusted,
+    /// `LIMIT max_rows` was injected; `notification` explains why.
+    Limited { notification: String },
+}
+
+impl ScanGuardOutcome {
+    /// Whether the plan matched a guarded label. Generated SQL for such plans
+    /// depends on who is asking, so it must not be shared through the query cache.
+    pub fn is_guarded(&self) -> bool {
+        !matches!(self, ScanGuardOutcome::NotApplicable)
+    }
+
+    pub fn notification(&self) -> Option<&str> {
+        match self {
+            ScanGuardOutcome::Limited { notification } => Some(notification),
+            _ => None,
+        }
+    }
+}
+
+/// Apply the label's `max_unfiltered_rows` guard to an unfiltered scan.
+pub fn enforce_unfiltered_scan_limit(
+    plan: LogicalPlan,
+    schema: &GraphSchema,
+    trusted: bool,
+) -> Result<(LogicalPlan, ScanGuardOutcome), ScanGuardError> {
+    let Some((label, limit, current_limit)) = find_unfiltered_scan(&plan, schema) else {
+        return Ok((plan, ScanGuardOutcome::NotApplicable));
+    };
+    if current_limit.is_some_and(|count| count >= 0 && count as u64 <= limit.max_rows) {
+        return Ok((plan, ScanGuardOutcome::NotApplicable));
+    }
+    if trusted {
+        return Ok((plan, ScanGuardOutcome::Trusted));
+    }
+
+    match limit.action {
+        UnfilteredScanAction::Reject => Err(ScanGuardError::UnfilteredScanRejected {
+            label,
+            max_rows: limit.max_rows,
+        }),
+        UnfilteredScanAction::Limit => {
+            let count = i64::try_from(limit.max_rows).unwrap_or(i64::MAX);
+            let plan = match plan {
+                LogicalPlan::Limit(l) => LogicalPlan::Limit(Limit {
+                    input: l.input,
+                    count,
+                }),
+                other => LogicalPlan::Limit(Limit {
+                    input: Arc::new(other),
+                    count,
+                }),
+            };
+            let notification = format!(
+                "Unfiltered scan of node label `{}` was limited to {} rows \
+                 (max_unfiltered_rows). Add a filter to see specific rows.",
+                label, limit.max_rows
+            );
+            log::warn!("{}", notification);
+            Ok((plan, ScanGuardOutcome::Limited { notification }))
+        }
+    }
+}
+
+/// Match the guarded shape and return `(label, guard, existing LIMIT)`.
+fn find_unfiltered_scan<'a>(
+    plan: &LogicalPlan,
+    schema: &'a GraphSchema,
+) -> Option<(String, &'a UnfilteredScanLimit, Option<i64>)> {
+    let mut current_limit = None;
+    let mut node = plan;
+    loop {
+        node = match node {
+            LogicalPlan::Limit(l) => {
+                current_limit.get_or_insert(l.count);
+                &l.input
+            }
+            LogicalPlan::Skip(s) => &s.input,
+            LogicalPlan::OrderBy(ob) => &ob.input,
+            LogicalPlan::GraphJoins(gj) => &gj.input,
+            LogicalPlan::Projection(p) => {
+                if p.items
+                    .iter()
+                    .any(|item| contains_aggregate(&item.expression))
+                {
+                    return None;
+                }
+                &p.input
+            }
+            _ => break,
+        };
+    }
+
+    let LogicalPlan::GraphNode(gn) = node else {
+        return None;
+    };
+    let LogicalPlan::ViewScan(scan) = gn.input.as_ref() else {
+        return None;
+    };
+    if scan.view_filter.is_some() {
+        return None;
+    }
+    let label = gn.label.as_ref()?;
+    let limit = schema.unfiltered_scan_limit(label)?;
+    Some((label.clone(), limit, current_limit))
+}
+
+fn contains_aggregate(expr: &crate::query_planner::logical_expr::LogicalExpr) -> bool {
+    struct AggregateFinder(bool);
+    impl ExpressionVisitor for AggregateFinder {
+        type Output = ();
+        fn visit_aggregate_fn(&mut self, _agg_call: &AggregateFnCall) {
+            self.0 = true;
+        }
+    }
+    let mut finder = AggregateFinder(false);
+    walk_expression(expr, &mut finder);
+    finder.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+    use crate::open_cypher_parser;
+    use crate::query_planner::evaluate_read_statement;
+
+    const SCHEMA_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: Event
+      database: test
+      table: events
+      node_id: id
+      property_mappings:
+        id: id
+        kind: kind
+      max_unfiltered_rows: 1000
+    - label: Alert
+      database: test
+      table: alerts
+      node_id: id
+      property_mappings:
+        id: id
+      max_unfiltered_rows: 50
+      on_unfiltered_scan: reject
+    - label: User
+      database: test
+      table: users
+      node_id: id
+      property_mappings:
+        id: id
+  edges: []
+"#;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .expect("valid schema")
+    }
+
+    fn guard(
+        cypher: &str,
+        trusted: bool,
+    ) -> Result<(LogicalPlan, ScanGuardOutcome), ScanGuardError> {
+        let schema = schema();
+        let statement = open_cypher_parser::parse_cypher_statement(cypher)
+            .expect("parse")
+            .1;
+        let (plan, _) =
+            evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+        enforce_unfiltered_scan_limit(plan, &schema, trusted)
+    }
+
+    fn top_limit(plan: &LogicalPlan) -> Option<i64> {
+        match plan {
+            LogicalPlan::Limit(l) => Some(l.count),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn unfiltered_scan_gets_limit_and_notification() {
+        let (plan, outcome) = guard("MATCH (n:Event) RETURN n", false).unwrap();
+        assert_eq!(top_limit(&plan), Some(1000));
+        assert!(outcome.notification().unwrap().contains("`Event`"));
+    }
+
+    #[test]
+    fn oversized_limit_is_lowered() {
+        let (plan, outcome) = guard("MATCH (n:Event) RETURN n.kind LIMIT 5000", false).unwrap();
+        assert_eq!(top_limit(&plan), Some(1000));
+        assert!(matches!(outcome, ScanGuardOutcome::Limited { .. }));
+    }
+
+    #[test]
+    fn limit_within_bounds_is_untouched() {
+        let (plan, outcome) = guard("MATCH (n:Event) RETURN n LIMIT 10", false).unwrap();
+        assert_eq!(top_limit(&plan), Some(10));
+        assert_eq!(outcome, ScanGuardOutcome::NotApplicable);
+    }
+
+    #[test]
+    fn filtered_and_aggregated_scans_are_not_guarded() {
+        for cypher in [
+            "MATCH (n:Event) WHERE n.kind = 'x' RETURN n",
+            "MATCH (n:Event {id: 1}) RETURN n",
+            "MATCH (n:Event) RETURN count(n)",
+            "MATCH (n:User) RETURN n",
+        ] {
+            let (_, outcome) = guard(cypher, false).unwrap();
+            assert_eq!(outcome, ScanGuardOutcome::NotApplicable, "{cypher}");
+        }
+    }
+
+    #[test]
+    fn reject_action_fails_the_query() {
+        let err = guard("MATCH (a:Alert) RETURN a", false).unwrap_err();
+        assert_eq!(
+            err,
+            ScanGuardError::UnfilteredScanRejected {
+                label: "Alert".to_string(),
+                max_rows: 50
+            }
+        );
+    }
+
+    #[test]
+    fn trusted_callers_bypass_the_guard() {
+        let (plan, outcome) = guard("MATCH (a:Alert) RETURN a", true).unwrap();
+        assert_eq!(top_limit(&plan), None);
+        assert_eq!(outcome, ScanGuardOutcome::Trusted);
+        assert!(outcome.is_guarded());
+    }
+}
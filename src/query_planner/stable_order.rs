@@ -0,0 +1,205 @@
+//! Stable result order — inject `ORDER BY <node id>` into unordered queries.
+//!
+//! Bolt clients PULL results in batches (`PULL {n: 1000}`) and drivers retry
+//! transactions on transient failures. Without an ORDER BY, ClickHouse is free
+//! to return rows in a different order on every execution, so a retried query
+//! can shift batch boundaries and duplicate or skip rows. When enabled, the
+//! Bolt path calls [`inject_stable_order`] on read plans that have no ORDER BY:
+//! every node returned as a bare variable (`RETURN n, m`) contributes its
+//! node-id column(s) as sort keys, in RETURN order.
+//!
+//! Queries that already order, return no node variables, or whose returned
+//! nodes cannot be resolved to a single labelled schema are left unchanged.
+
+use std::sync::Arc;
+
+use crate::{
+    graph_catalog::{expression_parser::PropertyValue, graph_schema::GraphSchema},
+    query_planner::{
+        logical_expr::{LogicalExpr, PropertyAccess, TableAlias},
+        logical_plan::{Limit, LogicalPlan, OrderBy, OrderByItem, OrderByOrder, Skip},
+    },
+};
+
+/// Add `ORDER BY` on the ids of the returned nodes when the query has none.
+///
+/// Returns the rewritten plan and the injected sort keys (`alias.column`),
+/// which are empty when the plan was left unchanged.
+pub fn inject_stable_order(plan: LogicalPlan, schema: &GraphSchema) -> (LogicalPlan, Vec<String>) {
+    let items = stable_order_items(&plan, schema);
+    if items.is_empty() {
+        return (plan, Vec::new());
+    }
+    let keys = items
+        .iter()
+        .filter_map(|item| match &item.expression {
+            LogicalExpr::PropertyAccessExp(pa) => {
+                Some(format!("{}.{}", pa.table_alias.0, pa.column.raw()))
+            }
+            _ => None,
+        })
+        .collect();
+    (insert_order_by(plan, items), keys)
+}
+
+/// Sort keys for the returned node variables, or empty when the plan already
+/// orders (or has nothing to order by).
+fn stable_order_items(plan: &LogicalPlan, schema: &GraphSchema) -> Vec<OrderByItem> {
+    let mut node = plan;
+    let projection = loop {
+        node = match node {
+            LogicalPlan::Limit(l) => &l.input,
+            LogicalPlan::Skip(s) => &s.input,
+            LogicalPlan::GraphJoins(gj) => &gj.input,
+            LogicalPlan::Projection(p) => break p,
+            _ => return Vec::new(),
+        };
+    };
+
+    let mut items = Vec::new();
+    for item in &projection.items {
+        let LogicalExpr::TableAlias(TableAlias(alias)) = &item.expression else {
+            continue;
+        };
+        let label = plan.find_map_node(|n| match n {
+            LogicalPlan::GraphNode(gn) if &gn.alias == alias => gn.label.clone(),
+            _ => None,
+        });
+        let Some(node_schema) = label.and_then(|l| schema.node_schema_opt(&l)) else {
+            continue;
+        };
+        // node_id names Cypher properties; sort on the mapped columns, as the
+        // analyzer does for user-written ORDER BY items.
+        for property in node_schema.node_id.columns() {
+            let column = node_schema
+                .property_mappings
+                .get(property)
+                .cloned()
+                .unwrap_or_else(|| PropertyValue::Column(property.to_string()));
+            items.push(OrderByItem {
+                expression: LogicalExpr::PropertyAccessExp(PropertyAccess {
+                    table_alias: TableAlias(alias.clone()),
+                    column,
+                }),
+                order: OrderByOrder::Asc,
+            });
+        }
+    }
+    items
+}
+
+/// Place the ORDER BY beneath any SKIP / LIMIT so paging applies to sorted rows.
+fn insert_order_by(plan: LogicalPlan, items: Vec<OrderByItem>) -> LogicalPlan {
+    match plan {
+        LogicalPlan::Limit(l) => LogicalPlan::Limit(Limit {
+            input: Arc::new(insert_order_by(Arc::unwrap_or_clone(l.input), items)),
+            count: l.count,
+        }),
+        LogicalPlan::Skip(s) => LogicalPlan::Skip(Skip {
+            input: Arc::new(insert_order_by(Arc::unwrap_or_clone(s.input), items)),
+            count: s.count,
+        }),
+        other => LogicalPlan::OrderBy(OrderBy {
+            input: Arc::new(other),
+            items,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+    use crate::open_cypher_parser;
+    use crate::query_planner::evaluate_read_statement;
+
+    const SCHEMA_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: uid
+        name: full_name
+    - label: Post
+      database: test
+      table: posts
+      node_id: [tenant_id, post_id]
+      property_mappings:
+        tenant_id: tenant_id
+        post_id: post_id
+  edges:
+    - type: AUTHORED
+      database: test
+      table: authored
+      from_id: uid
+      to_id: post_id
+      from_node: User
+      to_node: Post
+"#;
+
+    fn inject(cypher: &str) -> (LogicalPlan, Vec<String>) {
+        let schema = GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .expect("valid schema");
+        let statement = open_cypher_parser::parse_cypher_statement(cypher)
+            .expect("parse")
+            .1;
+        let (plan, _) =
+            evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+        inject_stable_order(plan, &schema)
+    }
+
+    #[test]
+    fn returned_node_is_ordered_by_mapped_id_column() {
+        use crate::render_plan::plan_builder::RenderPlanBuilder;
+
+        let (plan, keys) = inject("MATCH (u:User) RETURN u");
+        assert_eq!(keys, vec!["u.uid".to_string()]);
+        assert!(matches!(plan, LogicalPlan::OrderBy(_)));
+
+        let schema = GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .unwrap()
+            .to_graph_schema()
+            .unwrap();
+        let render_plan = plan.to_render_plan(&schema).expect("render");
+        let sql = crate::clickhouse_query_generator::generate_sql(render_plan, 100);
+        assert!(sql.contains("ORDER BY u.uid ASC"), "{sql}");
+    }
+
+    #[test]
+    fn order_by_goes_beneath_skip_and_limit() {
+        let (plan, keys) = inject("MATCH (u:User) RETURN u SKIP 5 LIMIT 10");
+        assert_eq!(keys, vec!["u.uid".to_string()]);
+        let LogicalPlan::Limit(limit) = plan else {
+            panic!("expected LIMIT on top");
+        };
+        let LogicalPlan::Skip(skip) = limit.input.as_ref() else {
+            panic!("expected SKIP under LIMIT");
+        };
+        assert!(matches!(skip.input.as_ref(), LogicalPlan::OrderBy(_)));
+    }
+
+    #[test]
+    fn composite_id_contributes_every_column() {
+        let (_, keys) = inject("MATCH (p:Post) RETURN p");
+        assert_eq!(
+            keys,
+            vec!["p.tenant_id".to_string(), "p.post_id".to_string()]
+        );
+    }
+
+    #[test]
+    fn existing_order_and_scalar_returns_are_untouched() {
+        for cypher in [
+            "MATCH (u:User) RETURN u ORDER BY u.name",
+            "MATCH (u:User) RETURN u.name",
+        ] {
+            let (_, keys) = inject(cypher);
+            assert!(keys.is_empty(), "{cypher}");
+        }
+    }
+}
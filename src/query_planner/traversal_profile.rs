@@ -0,0 +1,187 @@
+//! Traversal profile — which edges a read query hops and which node
+//! properties it reads on each side.
+//!
+//! The workload log (`server::workload_log`) records a profile per executed
+//! query; `graph_catalog::denormalization_advisor` turns the log into
+//! denormalization suggestions. Profiles come from the plan as built, before
+//! analysis resolves property names to columns, so they speak in graph
+//! terms. Variable-length hops and hops with several relationship types are
+//! left out: neither can be served by a single denormalized edge table.
+
+use std::collections::{BTreeSet, HashMap};
+use std::ops::ControlFlow;
+
+use crate::graph_catalog::denormalization_advisor::TraversalAccess;
+use crate::graph_catalog::graph_schema::GraphSchema;
+use crate::open_cypher_parser;
+use crate::query_planner::logical_expr::visitors::PropertyAccessCollector;
+use crate::query_planner::logical_expr::LogicalExpr;
+use crate::query_planner::logical_plan::{self, Descend, LogicalPlan};
+
+/// The fixed-length hops of `cypher` with the properties read per side.
+/// Queries that do not plan (writes, procedures, errors) have none.
+pub fn profile_query(cypher: &str, schema: &GraphSchema) -> Vec<TraversalAccess> {
+    let Ok((_, statement)) = open_cypher_parser::parse_cypher_statement(cypher) else {
+        return Vec::new();
+    };
+    match logical_plan::evaluate_cypher_statement(statement, schema, None, None, None) {
+        Ok((plan, _)) => profile_plan(&plan, schema),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// The hops of a built plan with the properties read per side.
+pub fn profile_plan(plan: &LogicalPlan, schema: &GraphSchema) -> Vec<TraversalAccess> {
+    let mut labels: HashMap<String, String> = HashMap::new();
+    let mut hops: Vec<(String, String, String)> = Vec::new();
+    let mut reads: HashMap<String, BTreeSet<String>> = HashMap::new();
+    let mut whole: BTreeSet<String> = BTreeSet::new();
+
+    plan.walk(&mut |node| {
+        let mut exprs: Vec<&LogicalExpr> = Vec::new();
+        match node {
+            LogicalPlan::GraphNode(n) => {
+                if let Some(label) = &n.label {
+                    labels.insert(n.alias.clone(), label.clone());
+                }
+            }
+            LogicalPlan::GraphRel(rel) => {
+                if let (None, Some([edge_type])) = (&rel.variable_length, rel.labels.as_deref()) {
+                    // Labels may carry the `TYPE::From::To` schema key
+                    hops.push((
+                        edge_type
+                            .split("::")
+                            .next()
+                            .unwrap_or(edge_type)
+                            .to_string(),
+                        rel.left_connection.clone(),
+                        rel.right_connection.clone(),
+                    ));
+                }
+                exprs.extend(&rel.where_predicate);
+            }
+            LogicalPlan::Filter(f) => exprs.push(&f.predicate),
+            LogicalPlan::Projection(p) => {
+                exprs.extend(p.items.iter().map(|i| &i.expression));
+            }
+            LogicalPlan::GroupBy(g) => {
+                exprs.extend(&g.expressions);
+                exprs.extend(&g.having_clause);
+            }
+            LogicalPlan::OrderBy(o) => exprs.extend(o.items.iter().map(|i| &i.expression)),
+            LogicalPlan::WithClause(w) => {
+                exprs.extend(w.items.iter().map(|i| &i.expression));
+                exprs.extend(&w.where_clause);
+                exprs.extend(w.order_by.iter().flatten().map(|i| &i.expression));
+            }
+            LogicalPlan::Unwind(u) => exprs.push(&u.expression),
+            _ => {}
+        }
+        for expr in exprs {
+            // `RETURN u` reads every property of `u`
+            if let LogicalExpr::TableAlias(alias) = expr {
+                whole.insert(alias.0.clone());
+            }
+            for (alias, property) in PropertyAccessCollector::collect(expr) {
+                reads.entry(alias).or_default().insert(property);
+            }
+        }
+        ControlFlow::<(), _>::Continue(Descend::Yes)
+    });
+
+    let side = |alias: &str, label: &str| -> BTreeSet<String> {
+        if whole.contains(alias) {
+            if let Some(node) = schema.node_schema_opt(label) {
+                return node.property_mappings.keys().cloned().collect();
+            }
+        }
+        reads.get(alias).cloned().unwrap_or_default()
+    };
+    hops.into_iter()
+        .filter_map(|(edge_type, from, to)| {
+            let from_node = labels.get(&from)?;
+            let to_node = labels.get(&to)?;
+            Some(TraversalAccess {
+                from_properties: side(&from, from_node),
+                to_properties: side(&to, to_node),
+                edge_type,
+                from_node: from_node.clone(),
+                to_node: to_node.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    const SCHEMA_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: id
+      property_mappings:
+        id: id
+        name: full_name
+        country: country
+    - label: Post
+      database: test
+      table: posts
+      node_id: id
+      property_mappings:
+        id: id
+        title: title
+  edges:
+    - type: AUTHORED
+      database: test
+      table: authored
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings: {}
+"#;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .expect("valid schema")
+    }
+
+    fn set(items: &[&str]) -> BTreeSet<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn records_properties_read_on_each_side() {
+        let profile = profile_query(
+            "MATCH (u:User)-[:AUTHORED]->(p:Post) WHERE u.country = 'NZ' \
+             RETURN u.name, p.title ORDER BY p.title",
+            &schema(),
+        );
+        assert_eq!(profile.len(), 1, "{profile:?}");
+        assert_eq!(profile[0].edge_type, "AUTHORED");
+        assert_eq!(profile[0].from_node, "User");
+        assert_eq!(profile[0].from_properties, set(&["country", "name"]));
+        assert_eq!(profile[0].to_properties, set(&["title"]));
+    }
+
+    #[test]
+    fn returning_a_node_reads_all_its_properties_and_vlp_is_skipped() {
+        let schema = schema();
+        let profile = profile_query("MATCH (u:User)-[:AUTHORED]->(p:Post) RETURN p", &schema);
+        assert_eq!(profile[0].from_properties, set(&[]));
+        assert_eq!(profile[0].to_properties, set(&["id", "title"]));
+
+        let profile = profile_query(
+            "MATCH (u:User)-[:AUTHORED*1..2]->(p:Post) RETURN p.title",
+            &schema,
+        );
+        assert!(profile.is_empty(), "{profile:?}");
+    }
+}
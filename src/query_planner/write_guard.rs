@@ -510,6 +510,7 @@ mod tests {
                 label: "DoesNotExist".to_string(),
                 properties: vec![],
             })],
+            merge: None,
         });
         let schema = empty_schema();
         let err =
@@ -537,6 +538,7 @@ mod tests {
                 end_alias: "b".to_string(),
                 properties: vec![],
             })],
+            merge: None,
         });
         let err =
             ensure_write_target_writable(&plan, &schema, ExecutorKind::EmbeddedChdb).unwrap_err();
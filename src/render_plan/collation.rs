@@ -0,0 +1,198 @@
+//! Column and query collations (`collations` in the schema, the `CYPHER
+//! collation=...` query option) applied to a finished render plan.
+//!
+//! A comparison involving a collated column, or under a query collation a
+//! string literal, compares collation keys: `lowerUTF8(..)` for `ci`,
+//! diacritics stripped after NFD decomposition for `ai`. An ORDER BY item on a
+//! collated column sorts by that key and carries the locale, which renders as
+//! `ASC COLLATE '<locale>'`. ClickHouse has no locale-aware equality, so the
+//! locale only affects sorting.
+
+use std::collections::HashMap;
+
+use super::render_expr::{
+    map_render_expr, Literal, Operator, OperatorApplication, RenderExpr, RenderRewrite,
+    ScalarFnCall,
+};
+use super::{CteContent, RenderPlan};
+use crate::graph_catalog::graph_schema::{Collation, GraphSchema};
+use crate::sql_generator::function_mapper::current_function_mapper;
+
+/// Collations of CTE output columns: CTE name -> column alias -> collation
+type CteCollations = HashMap<String, HashMap<String, Collation>>;
+
+/// Apply the schema's collations to `plan`, its UNION branches and its CTE
+/// bodies. Idempotent: keys already wrapped are no longer bare columns.
+pub fn apply_collations(plan: &mut RenderPlan, schema: &GraphSchema) {
+    if schema.has_collations() {
+        apply(plan, schema, &mut CteCollations::new());
+    }
+}
+
+fn apply(plan: &mut RenderPlan, schema: &GraphSchema, cte_collations: &mut CteCollations) {
+    // CTE bodies first: a column a WITH carries forward keeps its collation
+    for cte in plan.ctes.0.iter_mut() {
+        if let CteContent::Structured(cte_plan) = &mut cte.content {
+            apply(cte_plan, schema, cte_collations);
+            let tables = alias_tables(cte_plan);
+            let columns: HashMap<String, Collation> = cte_plan
+                .select
+                .items
+                .iter()
+                .filter_map(|item| {
+                    let collation =
+                        column_collation(&item.expression, &tables, schema, cte_collations)?;
+                    Some((item.col_alias.as_ref()?.0.clone(), collation.clone()))
+                })
+                .collect();
+            if !columns.is_empty() {
+                cte_collations.insert(cte.cte_name.clone(), columns);
+            }
+        }
+    }
+
+    let tables = alias_tables(plan);
+    let collation_of = |expr: &RenderExpr| -> Option<Collation> {
+        column_collation(expr, &tables, schema, cte_collations).cloned()
+    };
+
+    let mut rewrite = |expr: &RenderExpr| -> RenderRewrite {
+        let RenderExpr::OperatorApplicationExp(op) = expr else {
+            return RenderRewrite::Recurse;
+        };
+        if !is_string_comparison(op.operator) || op.operands.len() != 2 {
+            return RenderRewrite::Recurse;
+        }
+        let collation = op.operands.iter().find_map(&collation_of).or_else(|| {
+            op.operands
+                .iter()
+                .any(|o| matches!(o, RenderExpr::Literal(Literal::String(_))))
+                .then(|| schema.query_collation().cloned())
+                .flatten()
+        });
+        match collation {
+            Some(collation) if has_key(&collation) => {
+                RenderRewrite::Replace(RenderExpr::OperatorApplicationExp(OperatorApplication {
+                    operator: op.operator,
+                    operands: op
+                        .operands
+                        .iter()
+                        .map(|operand| match operand {
+                            RenderExpr::List(items) => RenderExpr::List(
+                                items.iter().map(|i| collation_key(i, &collation)).collect(),
+                            ),
+                            _ => collation_key(operand, &collation),
+                        })
+                        .collect(),
+                }))
+            }
+            _ => RenderRewrite::Recurse,
+        }
+    };
+    if let Some(filter) = plan.filters.0.as_ref() {
+        plan.filters.0 = Some(map_render_expr(filter, &mut rewrite));
+    }
+    if let Some(having) = plan.having_clause.as_ref() {
+        plan.having_clause = Some(map_render_expr(having, &mut rewrite));
+    }
+
+    for item in plan.order_by.0.iter_mut() {
+        let Some(collation) = collation_of(&item.expression) else {
+            continue;
+        };
+        if has_key(&collation) {
+            item.expression = collation_key(&item.expression, &collation);
+        }
+        item.collation = collation.locale;
+    }
+
+    if let Some(union) = plan.union.0.as_mut() {
+        for branch in union.input.iter_mut() {
+            apply(branch, schema, cte_collations);
+        }
+    }
+}
+
+/// The collation of a bare column reference: a table column, or a CTE column
+/// carrying one forward
+fn column_collation<'a>(
+    expr: &RenderExpr,
+    tables: &HashMap<String, String>,
+    schema: &'a GraphSchema,
+    cte_collations: &'a CteCollations,
+) -> Option<&'a Collation> {
+    let RenderExpr::PropertyAccessExp(pa) = expr else {
+        return None;
+    };
+    let table = tables.get(&pa.table_alias.0)?;
+    let column = pa.column.raw();
+    schema
+        .collation(table, column)
+        .or_else(|| cte_collations.get(table)?.get(column))
+}
+
+/// `database.table` read under each alias of the plan's FROM and JOINs
+fn alias_tables(plan: &RenderPlan) -> HashMap<String, String> {
+    let table = |name: &str| name.split('(').next().unwrap_or(name).trim().to_string();
+    let mut tables = HashMap::new();
+    if let Some(from) = plan.from.0.as_ref() {
+        if let Some(alias) = from.alias.as_ref() {
+            tables.insert(alias.clone(), table(&from.name));
+        }
+    }
+    for join in &plan.joins.0 {
+        tables.insert(join.table_alias.clone(), table(&join.table_name));
+    }
+    tables
+}
+
+fn is_string_comparison(operator: Operator) -> bool {
+    matches!(
+        operator,
+        Operator::Equal
+            | Operator::NotEqual
+            | Operator::LessThan
+            | Operator::GreaterThan
+            | Operator::LessThanEqual
+            | Operator::GreaterThanEqual
+            | Operator::In
+            | Operator::NotIn
+            | Operator::StartsWith
+            | Operator::EndsWith
+            | Operator::Contains
+    )
+}
+
+/// Whether the collation compares keys rather than the values themselves
+fn has_key(collation: &Collation) -> bool {
+    collation.case_insensitive || accent_insensitive(collation)
+}
+
+fn accent_insensitive(collation: &Collation) -> bool {
+    collation.accent_insensitive && current_function_mapper().strips_accents()
+}
+
+/// The collation key of `expr`: accents stripped, then lowercased
+fn collation_key(expr: &RenderExpr, collation: &Collation) -> RenderExpr {
+    let call = |name: &str, args: Vec<RenderExpr>| {
+        RenderExpr::ScalarFnCall(ScalarFnCall {
+            name: name.to_string(),
+            args,
+        })
+    };
+    let mut key = expr.clone();
+    if accent_insensitive(collation) {
+        key = call(
+            "replaceRegexpAll",
+            vec![
+                call("normalizeUTF8NFD", vec![key]),
+                RenderExpr::Literal(Literal::String("\\\\p{Mn}".to_string())),
+                RenderExpr::Literal(Literal::String(String::new())),
+            ],
+        );
+    }
+    if collation.case_insensitive {
+        key = call(current_function_mapper().lower_case(), vec![key]);
+    }
+    key
+}
@@ -19,7 +19,7 @@ use crate::query_planner::logical_expr::expression_rewriter::{
 use crate::query_planner::logical_expr::ColumnAlias as LogicalColumnAlias;
 use crate::query_planner::logical_plan::LogicalPlan;
 use crate::render_plan::cte_generation::CteGenerationContext;
-use crate::render_plan::cte_manager::CteManager;
+use crate::render_plan::cte_manager::{CteError, CteManager};
 use crate::render_plan::expression_utils::{flatten_addition_operands, has_string_operand};
 use crate::sql_generator::function_mapper::current_function_mapper;
 use crate::utils::cte_column_naming::cte_column_name;
@@ -30,6 +30,7 @@ use super::filter_pipeline::{categorize_filters, CategorizedFilters};
 use super::plan_builder::RenderPlanBuilder;
 use super::render_expr::{Literal, Operator, OperatorApplication, PropertyAccess, RenderExpr};
 use super::{Cte, CteContent, Join, JoinType};
+use std::collections::{BTreeSet, HashMap};
 
 pub type RenderPlanBuilderResult<T> = Result<T, super::errors::RenderBuildError>;
 
@@ -471,6 +472,7 @@ pub fn generate_vlp_cte_via_manager(
     is_optional: Option<bool>,
     weight_cte: Option<crate::clickhouse_query_generator::WeightCteConfig>,
     needs_path_relationships: bool,
+    path_edge_properties: Vec<String>,
     use_bfs_mode: bool,
     is_undirected: bool,
     undirected_single_walk: bool,
@@ -497,6 +499,7 @@ pub fn generate_vlp_cte_via_manager(
         .with_is_optional(is_optional.unwrap_or(false))
         .with_weight_cte(weight_cte);
     context.needs_path_relationships = needs_path_relationships;
+    context.path_edge_properties = path_edge_properties;
     context.use_bfs_mode = use_bfs_mode;
     context.is_undirected = is_undirected;
     context.undirected_single_walk = undirected_single_walk;
@@ -517,9 +520,12 @@ pub fn generate_vlp_cte_via_manager(
     let schema_arc = Arc::new(schema.clone());
     let manager = CteManager::with_context(schema_arc, context);
     let result = manager.generate_vlp_cte(pattern_ctx, &properties, &filters)
-        .map_err(|e| RenderBuildError::UnsupportedFeature(format!(
-            "CteManager VLP generation failed: {}. Falling back to direct generator may be needed.", e
-        )))?;
+        .map_err(|e| match e {
+            CteError::RecursiveCteUnavailable(msg) => RenderBuildError::UnsupportedFeature(msg),
+            e => RenderBuildError::UnsupportedFeature(format!(
+                "CteManager VLP generation failed: {}. Falling back to direct generator may be needed.", e
+            )),
+        })?;
 
     // Convert CteGenerationResult to Cte, preserving column metadata for deterministic lookups
     let cte = Cte {
@@ -1594,6 +1600,10 @@ pub fn render_expr_to_sql_string(expr: &RenderExpr, alias_mapping: &[(String, St
                 .find(|(cypher, _)| *cypher == prop.table_alias.0)
                 .map(|(_, cte)| cte.clone())
                 .unwrap_or_else(|| prop.table_alias.0.clone());
+            // A computed property inlines its expression, qualified by the alias
+            if let PropertyValue::Expression(_) = &prop.column {
+                return prop.column.to_sql(&table_alias);
+            }
             // Quote column name if it contains dots or special characters
             let quoted_column =
                 crate::clickhouse_query_generator::quote_identifier(prop.column.raw());
@@ -4144,7 +4154,8 @@ pub fn extract_ctes_with_context(
                             view_parameter_values,
                             plan_ctx.map(|ctx| std::sync::Arc::new(ctx.clone())),
                             is_undirected,
-                        );
+                        )
+                        .with_rel_alias(graph_rel.alias.clone());
 
                         // TODO: Add property projections based on what's needed in RETURN clause
                         // For now, we'll generate without specific property projections
@@ -4306,6 +4317,15 @@ pub fn extract_ctes_with_context(
                         extract_schema_filter_from_node(&graph_rel.left, "start_node");
                     let end_schema_filter =
                         extract_schema_filter_from_node(&graph_rel.right, "end_node");
+                    // The edge table's own schema filter (including time-travel
+                    // predicates) must hold at every hop, base and recursive
+                    let rel_filters_sql = match (
+                        rel_filters_sql,
+                        extract_schema_filter_from_node(&graph_rel.center, "rel"),
+                    ) {
+                        (Some(user), Some(schema)) => Some(format!("({}) AND ({})", user, schema)),
+                        (user, schema) => user.or(schema),
+                    };
 
                     // Combine user filters with schema filters using AND
                     let combined_start_filters = match (&start_filters_sql, &start_schema_filter) {
@@ -4478,6 +4498,32 @@ pub fn extract_ctes_with_context(
                         false
                     };
 
+                    // Edge properties folded by `reduce(.. r IN relationships(path) | .. r.prop ..)`
+                    // are accumulated per hop in the CTE. The traditional edge-table walk
+                    // and the denormalized single-table walk thread these arrays through
+                    // both arms.
+                    let path_edge_props = match graph_rel.path_variable {
+                        Some(ref pv) => plan_path_edge_properties(
+                            context.root_plan.as_deref().unwrap_or(plan),
+                            pv,
+                        ),
+                        None => Vec::new(),
+                    };
+                    if !path_edge_props.is_empty()
+                        && (weight_cte_config.is_some()
+                            || !matches!(
+                                pattern_ctx.join_strategy,
+                                JoinStrategy::Traditional { .. }
+                                    | JoinStrategy::SingleTableScan { .. }
+                            ))
+                    {
+                        return Err(RenderBuildError::UnsupportedFeature(format!(
+                            "reduce() over relationships({}) reading edge properties {:?} is only supported for variable-length paths over a separate or denormalized edge table",
+                            graph_rel.path_variable.as_deref().unwrap_or_default(),
+                            path_edge_props
+                        )));
+                    }
+
                     // Detect lightweight BFS mode for shortestPath + length(path)-only queries.
                     // BFS tracks only distinct reachable node_ids per hop level instead of
                     // per-path visited arrays, reducing memory from ~500M rows to ~180K rows.
@@ -4572,6 +4618,7 @@ pub fn extract_ctes_with_context(
                         graph_rel.is_optional,
                         weight_cte_config,
                         needs_path_rels,
+                        path_edge_props,
                         needs_bfs_mode,
                         is_undirected,
                         undirected_single_walk,
@@ -5505,6 +5552,41 @@ pub fn extract_ctes_with_context(
                         graph_rel.alias,
                         cte_name
                     );
+                } else if graph_rel.was_undirected == Some(true)
+                    && crate::query_planner::analyzer::bidirectional_union::undirected_optional_hop_single_walk_core(
+                        graph_rel,
+                        schema,
+                    )
+                {
+                    // OPTIONAL undirected hop kept whole: graph-join inference
+                    // already points its LEFT JOIN at the doubled-edge CTE.
+                    if let Ok(rel_schema) = schema.get_rel_schema(&unique_labels[0]) {
+                        use crate::sql_generator::emitters::clickhouse::variable_length_cte as vlc;
+                        let rel_table =
+                            format!("{}.{}", rel_schema.database, rel_schema.table_name);
+                        let cte_name = vlc::undirected_doubled_edges_cte_name(
+                            &graph_rel.left_connection,
+                            &graph_rel.right_connection,
+                            &rel_table,
+                        );
+                        let body_table_ref = extract_parameterized_table_name(&graph_rel.center)
+                            .unwrap_or_else(|| rel_table.clone());
+                        let body = vlc::build_doubled_edges_cte_body(
+                            &body_table_ref,
+                            &rel_schema.from_id.to_string(),
+                            &rel_schema.to_id.to_string(),
+                            &vlc::doubled_edges_passthrough_columns(
+                                schema,
+                                Some(&unique_labels[0]),
+                                &rel_table,
+                            ),
+                        );
+                        relationship_ctes.push(Cte::new(
+                            cte_name.clone(),
+                            super::CteContent::RawSql(format!("{} AS (\n{}\n)", cte_name, body)),
+                            false,
+                        ));
+                    }
                 } else {
                     crate::debug_println!(
                         "DEBUG cte_extraction: Single relationship type, no UNION needed"
@@ -6426,6 +6508,11 @@ pub fn plan_uses_relationships_fn(plan: &LogicalPlan, path_var: &str) -> bool {
                         .as_ref()
                         .is_some_and(|e| expr_uses_relationships(e, path_var))
             }
+            LogicalExpr::ReduceExpr(r) => {
+                expr_uses_relationships(&r.list, path_var)
+                    || expr_uses_relationships(&r.initial_value, path_var)
+                    || expr_uses_relationships(&r.expression, path_var)
+            }
             _ => false,
         }
     }
@@ -6465,6 +6552,95 @@ pub fn plan_uses_relationships_fn(plan: &LogicalPlan, path_var: &str) -> bool {
     plan_has_relationships(plan, path_var)
 }
 
+/// Collect, per path variable, the edge properties read through the iteration
+/// variable of every `reduce(acc = init, r IN relationships(p) | expr)` in the
+/// plan, e.g. `{"p": {"amount"}}` for `reduce(t = 0, r IN relationships(p) | t + r.amount)`.
+pub fn collect_path_edge_properties(plan: &LogicalPlan) -> HashMap<String, BTreeSet<String>> {
+    use crate::query_planner::logical_expr::visitors::{walk_expression, ExpressionVisitor};
+    use crate::query_planner::logical_expr::{LogicalExpr, PropertyAccess, ReduceExpr};
+    use crate::query_planner::logical_plan::Descend;
+    use std::ops::ControlFlow;
+
+    type EdgeProps = HashMap<String, BTreeSet<String>>;
+
+    /// Properties read through one variable, e.g. `amount` for `r.amount`.
+    struct VarProps<'a> {
+        var: &'a str,
+        out: &'a mut BTreeSet<String>,
+    }
+
+    impl ExpressionVisitor for VarProps<'_> {
+        type Output = ();
+        fn visit_property_access(&mut self, prop: &PropertyAccess) {
+            if prop.table_alias.0 == self.var {
+                self.out.insert(prop.column.raw().to_string());
+            }
+        }
+    }
+
+    /// Every `reduce(.., r IN relationships(p) | ..)`, however deeply nested.
+    struct ReduceFinder<'a> {
+        out: &'a mut EdgeProps,
+    }
+
+    impl ExpressionVisitor for ReduceFinder<'_> {
+        type Output = ();
+        fn visit_reduce(&mut self, reduce: &ReduceExpr) {
+            if let LogicalExpr::ScalarFnCall(fc) = reduce.list.as_ref() {
+                if let (true, [LogicalExpr::TableAlias(path_var)]) = (
+                    fc.name.eq_ignore_ascii_case("relationships"),
+                    fc.args.as_slice(),
+                ) {
+                    let mut props = VarProps {
+                        var: &reduce.variable,
+                        out: self.out.entry(path_var.0.clone()).or_default(),
+                    };
+                    walk_expression(&reduce.expression, &mut props);
+                }
+            }
+        }
+    }
+
+    let mut props = EdgeProps::new();
+    plan.walk(&mut |node| {
+        let mut exprs: Vec<&LogicalExpr> = Vec::new();
+        match node {
+            LogicalPlan::Projection(p) => exprs.extend(p.items.iter().map(|i| &i.expression)),
+            LogicalPlan::Filter(f) => exprs.push(&f.predicate),
+            LogicalPlan::WithClause(wc) => {
+                exprs.extend(wc.items.iter().map(|i| &i.expression));
+                exprs.extend(&wc.where_clause);
+                exprs.extend(wc.order_by.iter().flatten().map(|i| &i.expression));
+            }
+            LogicalPlan::OrderBy(ob) => exprs.extend(ob.items.iter().map(|i| &i.expression)),
+            LogicalPlan::GroupBy(g) => {
+                exprs.extend(&g.expressions);
+                exprs.extend(&g.having_clause);
+            }
+            LogicalPlan::GraphRel(gr) => exprs.extend(&gr.where_predicate),
+            LogicalPlan::Unwind(u) => exprs.push(&u.expression),
+            _ => {}
+        }
+        for expr in exprs {
+            walk_expression(expr, &mut ReduceFinder { out: &mut props });
+        }
+        ControlFlow::<(), _>::Continue(Descend::Yes)
+    });
+    props.retain(|_, names| !names.is_empty());
+    props
+}
+
+/// Edge properties the VLP CTE for `path_var` must accumulate per hop: those
+/// read in `plan` plus those registered for the whole query in the task-local
+/// `QueryContext`. Sorted, so `path_edge_<prop>` columns come out in a stable order.
+pub fn plan_path_edge_properties(plan: &LogicalPlan, path_var: &str) -> Vec<String> {
+    let mut props = crate::server::query_context::get_vlp_path_edge_properties(path_var);
+    if let Some(local) = collect_path_edge_properties(plan).remove(path_var) {
+        props.extend(local);
+    }
+    props.into_iter().collect()
+}
+
 /// Check if the plan uses `nodes(path_var)` — mirrors `plan_uses_relationships_fn`.
 pub fn plan_uses_nodes_fn(plan: &LogicalPlan, path_var: &str) -> bool {
     use crate::query_planner::logical_expr::LogicalExpr;
@@ -7072,6 +7248,17 @@ fn collect_path_aliases_with_ids_recursive(
                 rel_types,
             )?;
         }
+        LogicalPlan::Unwind(u) => {
+            collect_path_aliases_with_ids_recursive(
+                &u.input,
+                schema,
+                plan_ctx,
+                node_aliases,
+                rel_aliases,
+                node_id_columns,
+                rel_types,
+            )?;
+        }
         _ => {}
     }
     Ok(())
@@ -7820,6 +8007,7 @@ pub fn expand_fixed_length_joins_with_context(ctx: &VlpContext) -> (String, Stri
                     to_id_column: None,
                     graph_rel: None,
                     is_cartesian: false,
+                    use_final: false,
                 });
             }
 
@@ -7873,6 +8061,7 @@ pub fn expand_fixed_length_joins_with_context(ctx: &VlpContext) -> (String, Stri
                     to_id_column: None,
                     graph_rel: None,
                     is_cartesian: false,
+                    use_final: false,
                 });
             }
 
@@ -7907,6 +8096,7 @@ pub fn expand_fixed_length_joins_with_context(ctx: &VlpContext) -> (String, Stri
                 // carry `graph_rel` — enough for `fixed_path_hop_count`.
                 graph_rel: None,
                 is_cartesian: false,
+                use_final: false,
             });
 
             log::debug!(
@@ -7969,6 +8159,7 @@ pub fn expand_fixed_length_joins_with_context(ctx: &VlpContext) -> (String, Stri
                     to_id_column: None,
                     graph_rel: None,
                     is_cartesian: false,
+                    use_final: false,
                 });
             }
 
@@ -63,6 +63,9 @@ pub struct CteGenerationContext {
     /// Whether the query uses `relationships(path)`. When false, VLP CTE skips
     /// growing path_relationships arrays, saving significant memory.
     pub needs_path_relationships: bool,
+    /// Edge properties that `reduce()` over `relationships(path)` reads; the VLP
+    /// CTE accumulates each into a per-hop `path_edge_<prop>` array.
+    pub path_edge_properties: Vec<String>,
     /// Lightweight BFS mode for shortestPath queries that only need length(path).
     /// Generates a global-visited-set BFS instead of per-path tracking.
     pub use_bfs_mode: bool,
@@ -105,6 +108,7 @@ impl CteGenerationContext {
             pattern_combinations: None,
             weight_cte: None,
             needs_path_relationships: true,
+            path_edge_properties: Vec::new(),
             use_bfs_mode: false,
             is_undirected: false,
             undirected_single_walk: false,
@@ -310,6 +314,17 @@ pub(crate) fn extract_properties_from_filter(
     properties
 }
 
+/// The property of `node_label` mapped to the expression `expr`
+fn computed_property_name(node_label: &str, expr: &str) -> Option<String> {
+    let schema = crate::server::query_context::get_current_schema_with_fallback()?;
+    let node_schema = schema.node_schema_opt(node_label)?;
+    node_schema
+        .property_mappings
+        .iter()
+        .find(|(_, value)| value.raw() == expr)
+        .map(|(name, _)| name.clone())
+}
+
 fn extract_properties_from_expr_recursive(
     expr: &RenderExpr,
     node_alias: &str,
@@ -320,10 +335,24 @@ fn extract_properties_from_expr_recursive(
         RenderExpr::PropertyAccessExp(prop) => {
             // Check if this property belongs to the target node
             if prop.table_alias.0 == node_alias {
-                let property_name = prop.column.raw();
-                // Map Cypher property to ClickHouse column
-                let column_name = map_property_to_column_with_schema(property_name, node_label)
-                    .unwrap_or_else(|_| property_name.to_string());
+                let (property_name, column_name) = match &prop.column {
+                    // Already mapped to a computed property's expression: the
+                    // CTE column is named after the property, not the expression
+                    PropertyValue::Expression(expr) => {
+                        let Some(name) = computed_property_name(node_label, expr) else {
+                            return;
+                        };
+                        (name, expr.clone())
+                    }
+                    PropertyValue::Column(property_name) => {
+                        // Map Cypher property to ClickHouse column
+                        let column_name =
+                            map_property_to_column_with_schema(property_name, node_label)
+                                .unwrap_or_else(|_| property_name.to_string());
+                        (property_name.clone(), column_name)
+                    }
+                };
+                let property_name = property_name.as_str();
 
                 // Add if not already in the list
                 if !properties.iter().any(|p| p.alias == property_name) {
@@ -6,11 +6,12 @@
 use std::sync::Arc;
 
 use crate::clickhouse_query_generator::variable_length_cte::{
-    NodeProperty, VariableLengthCteGenerator,
+    emit_id_expr, emit_node_key_expr, path_edge_property_column, NodeProperty,
+    VariableLengthCteGenerator,
 };
 use crate::graph_catalog::{
-    config::Identifier, graph_schema::GraphSchema, EdgeAccessStrategy, JoinStrategy,
-    NodeAccessStrategy, NodePosition, PatternSchemaContext,
+    config::Identifier, expression_parser::PropertyValue, graph_schema::GraphSchema,
+    EdgeAccessStrategy, JoinStrategy, NodeAccessStrategy, NodePosition, PatternSchemaContext,
 };
 use crate::query_planner::join_context::{
     VLP_CTE_FROM_ALIAS, VLP_END_ID_COLUMN, VLP_START_ID_COLUMN,
@@ -64,6 +65,10 @@ pub enum CteError {
 
     #[error("Render build error: {0}")]
     RenderBuildError(#[from] RenderBuildError),
+
+    /// The pattern needs `WITH RECURSIVE`, which the target server lacks.
+    #[error("{0}")]
+    RecursiveCteUnavailable(String),
 }
 
 /// Metadata for a column in a generated CTE
@@ -325,7 +330,9 @@ impl CteManager {
     }
 
     /// Get the ID column for FK-edge relationships
-    /// For FK-edge, the FK column points to the ID of the target node
+    /// For FK-edge, the FK column points to the ID of the target node.
+    /// Composite IDs are returned comma-separated (e.g. `"tenant_id, object_id"`),
+    /// matching the FK column list they pair with.
     fn get_fk_edge_node_id_column(
         schema: &GraphSchema,
         pattern_ctx: &PatternSchemaContext,
@@ -369,15 +376,8 @@ impl CteManager {
                     ))
                 })?;
 
-                // Get the ID column from the node schema
-                match &target_node_schema.node_id.id {
-                    Identifier::Single(column) => Ok(column.clone()),
-                    Identifier::Composite(columns) => {
-                        // For composite IDs, use the first column for now
-                        // TODO: Handle composite IDs properly in FK relationships
-                        Ok(columns[0].clone())
-                    }
-                }
+                // Get the ID column(s) from the node schema
+                Ok(target_node_schema.node_id.id.to_string())
             }
             _ => Err(CteError::InvalidStrategy(
                 "get_fk_edge_node_id_column requires EdgeAccessStrategy::FkEdge".into(),
@@ -677,6 +677,16 @@ impl FkEdgeCteStrategy {
         })
     }
 
+    /// Node ID columns (single or composite) shared by both FK-edge endpoints.
+    fn id_identifier(&self) -> Identifier {
+        Identifier::from_comma_separated(&self.id_column)
+    }
+
+    /// FK column(s) on the child row, paired positionally with [`Self::id_identifier`].
+    fn fk_identifier(&self) -> Identifier {
+        Identifier::from_comma_separated(&self.fk_column)
+    }
+
     pub fn validate(&self, pattern_ctx: &PatternSchemaContext) -> Result<(), CteError> {
         // Validate that edge is FK-edge type
         match &pattern_ctx.edge {
@@ -733,32 +743,22 @@ impl FkEdgeCteStrategy {
     ) -> Result<String, CteError> {
         // For FK-edge, we traverse from parent to child (or child to parent depending on direction)
         // The FK column points from child to parent, so we join child.fk_column = parent.id
+        let left = &self.pattern_ctx.left_node_alias;
+        let right = &self.pattern_ctx.right_node_alias;
+        let id = self.id_identifier();
+        let fk = self.fk_identifier();
 
         let mut select_items = vec![
-            format!(
-                "{}.{} as start_id",
-                self.pattern_ctx.left_node_alias, self.id_column
-            ),
-            format!(
-                "{}.{} as end_id",
-                self.pattern_ctx.right_node_alias, self.id_column
-            ),
+            format!("{} as start_id", emit_id_expr(left, &id)),
+            format!("{} as end_id", emit_id_expr(right, &id)),
             "1 as hop_count".to_string(),
-            format!(
-                "{} as path_edges",
-                arr(&format!(
-                    "{}.{}",
-                    self.pattern_ctx.left_node_alias, self.fk_column
-                ))
-            ), // FK column represents the edge
+            format!("{} as path_edges", arr(&emit_node_key_expr(left, &fk))), // FK column represents the edge
             format!(
                 "{} as path_nodes",
                 arr(&format!(
-                    "{}.{}, {}.{}",
-                    self.pattern_ctx.left_node_alias,
-                    self.id_column,
-                    self.pattern_ctx.right_node_alias,
-                    self.id_column
+                    "{}, {}",
+                    emit_node_key_expr(left, &id),
+                    emit_node_key_expr(right, &id)
                 ))
             ),
         ];
@@ -770,15 +770,12 @@ impl FkEdgeCteStrategy {
 
         // FROM clause: join the same table using FK relationship
         let from_clause = format!(
-            "    FROM {} {}\n    JOIN {} {} ON {}.{} = {}.{}",
+            "    FROM {} {}\n    JOIN {} {} ON {}",
             self.node_table,
-            self.pattern_ctx.left_node_alias,
+            left,
             self.node_table,
-            self.pattern_ctx.right_node_alias,
-            self.pattern_ctx.left_node_alias,
-            self.fk_column,
-            self.pattern_ctx.right_node_alias,
-            self.id_column
+            right,
+            fk.to_sql_equality(left, &id, right)
         );
 
         // Build WHERE clause from filters
@@ -804,26 +801,26 @@ impl FkEdgeCteStrategy {
             context.spec.effective_min_hops()
         );
 
+        let right = &self.pattern_ctx.right_node_alias;
+        let id = self.id_identifier();
+
         // Build SELECT clause for recursive case
         let mut select_items = vec![
             format!("{}.start_id", cte_name),
-            format!(
-                "{}.{} as end_id",
-                self.pattern_ctx.right_node_alias, self.id_column
-            ),
+            format!("{} as end_id", emit_id_expr(right, &id)),
             format!("{}.hop_count + 1 as hop_count", cte_name),
             format!(
                 "{} as path_edges",
                 arr_append(
                     &format!("{}.path_edges", cte_name),
-                    &format!("{}.{}", self.pattern_ctx.left_node_alias, self.fk_column),
+                    &emit_node_key_expr(&self.pattern_ctx.left_node_alias, &self.fk_identifier()),
                 )
             ),
             format!(
                 "{} as path_nodes",
                 arr_append(
                     &format!("{}.path_nodes", cte_name),
-                    &format!("{}.{}", self.pattern_ctx.right_node_alias, self.id_column),
+                    &emit_node_key_expr(right, &id),
                 )
             ),
         ];
@@ -846,12 +843,11 @@ impl FkEdgeCteStrategy {
 
         // FROM clause: join CTE with node table using FK relationship
         let from_clause = format!(
-            "    FROM {}\n    JOIN {} {} ON {}.{} = {}.{}",
+            "    FROM {}\n    JOIN {} {} ON {} = {}.{}",
             cte_name,
             self.node_table,
-            self.pattern_ctx.right_node_alias,
-            self.pattern_ctx.right_node_alias,
-            self.id_column,
+            right,
+            emit_id_expr(right, &id),
             cte_name,
             VLP_END_ID_COLUMN // Connect to the end of the current path
         );
@@ -1556,7 +1552,7 @@ impl DenormalizedCteStrategy {
         // 1-hop row.
         let is_zero_hop = min_hops == 0;
         let base_case = if is_zero_hop {
-            self.generate_zero_hop_base_case_sql(properties)?
+            self.generate_zero_hop_base_case_sql(context, properties)?
         } else {
             self.generate_base_case_sql(context, properties, filters)?
         };
@@ -1646,6 +1642,59 @@ impl DenormalizedCteStrategy {
             .unwrap_or_default()
     }
 
+    /// SQL reading edge property `cypher_prop` off `rel_alias`, through the
+    /// relationship schema's property mappings (expression mappings included);
+    /// falls back to a same-named column.
+    fn edge_property_sql(
+        &self,
+        context: &CteGenerationContext,
+        rel_alias: &str,
+        cypher_prop: &str,
+    ) -> String {
+        context
+            .relationship_types
+            .iter()
+            .flatten()
+            .flat_map(
+                |rel_type| match self.schema.get_relationships_schema_opt(rel_type) {
+                    Some(rel_schema) => vec![rel_schema],
+                    None => self
+                        .schema
+                        .rel_schemas_for_type(rel_type.split("::").next().unwrap_or(rel_type)),
+                },
+            )
+            .find_map(|rel_schema| rel_schema.property_mappings.get(cypher_prop).cloned())
+            .unwrap_or_else(|| PropertyValue::Column(cypher_prop.to_string()))
+            .to_sql(rel_alias)
+    }
+
+    /// `path_edge_<prop>` selections for the edge properties folded over
+    /// `relationships(p)`. Each hop is one row of the denormalized table, so
+    /// per-hop values (carrier, flight number, ...) are read off that row via
+    /// the edge's own property mappings rather than the origin/destination
+    /// node sets. The base arm (`prev_alias = None`) seeds each array; the
+    /// recursive arm appends to the array carried on `prev_alias`.
+    fn path_edge_property_selections(
+        &self,
+        context: &CteGenerationContext,
+        rel_alias: &str,
+        prev_alias: Option<&str>,
+    ) -> Vec<String> {
+        let ac = crate::sql_generator::function_mapper::current_function_mapper().array_concat();
+        context
+            .path_edge_properties
+            .iter()
+            .map(|prop| {
+                let column = path_edge_property_column(prop);
+                let value = arr(&self.edge_property_sql(context, rel_alias, prop));
+                match prev_alias {
+                    Some(prev) => format!("{ac}({prev}.{column}, {value}) as {column}"),
+                    None => format!("{value} as {column}"),
+                }
+            })
+            .collect()
+    }
+
     /// Generate the base case SQL (1-hop traversal) for denormalized schema
     fn generate_base_case_sql(
         &self,
@@ -1690,6 +1739,11 @@ impl DenormalizedCteStrategy {
 
         // Add properties from the single table
         self.add_property_selections(&mut select_items, properties)?;
+        select_items.extend(self.path_edge_property_selections(
+            context,
+            &self.pattern_ctx.rel_alias,
+            None,
+        ));
 
         let select_clause = select_items.join(",\n        ");
 
@@ -1735,6 +1789,7 @@ impl DenormalizedCteStrategy {
     /// emitting a NULL-filled or mistyped column.
     fn generate_zero_hop_base_case_sql(
         &self,
+        context: &CteGenerationContext,
         properties: &[NodeProperty],
     ) -> Result<String, CteError> {
         let mut from_role_cols = vec![format!("{} AS __node_id", self.from_col)];
@@ -1767,12 +1822,35 @@ impl DenormalizedCteStrategy {
             canon_props.push((canon_alias, prop));
         }
 
+        // Zero hops traverse no edges, so every `path_edge_<prop>` array starts
+        // empty. A bare `[]` would infer as `Array(Nothing)` and clash with the
+        // recursive arm's typed arrays (see the path_edges cast below), and
+        // the edge property's type isn't known here — so slice a one-element
+        // array of the real column down to nothing, which keeps its type and,
+        // being the same value for every row, leaves the DISTINCT intact.
+        let mapper = crate::sql_generator::function_mapper::current_function_mapper();
+        let rel_alias = &self.pattern_ctx.rel_alias;
+        let mut edge_cols = Vec::new();
+        for (i, prop) in context.path_edge_properties.iter().enumerate() {
+            let empty = mapper.array_slice(
+                &arr(&self.edge_property_sql(context, rel_alias, prop)),
+                "1",
+                Some("0"),
+            );
+            let canon_alias = format!("__edge_{}", i);
+            from_role_cols.push(format!("{} AS {}", empty, canon_alias));
+            to_role_cols.push(format!("{} AS {}", empty, canon_alias));
+            edge_cols.push((canon_alias, path_edge_property_column(prop)));
+        }
+
         let node_universe = format!(
-            "(\n            SELECT DISTINCT {}\n            FROM {}\n            UNION DISTINCT\n            SELECT DISTINCT {}\n            FROM {}\n        ) AS node_universe",
+            "(\n            SELECT DISTINCT {}\n            FROM {} AS {}\n            UNION DISTINCT\n            SELECT DISTINCT {}\n            FROM {} AS {}\n        ) AS node_universe",
             from_role_cols.join(", "),
             self.table,
+            rel_alias,
             to_role_cols.join(", "),
             self.table,
+            rel_alias,
         );
 
         // Empty arrays need an explicit type cast here (unlike the ordinary
@@ -1804,6 +1882,9 @@ impl DenormalizedCteStrategy {
                 canon_alias, prefix, physical_col
             ));
         }
+        for (canon_alias, column) in &edge_cols {
+            select_items.push(format!("node_universe.{} as {}", canon_alias, column));
+        }
 
         let select_clause = select_items.join(",\n        ");
 
@@ -1857,6 +1938,7 @@ impl DenormalizedCteStrategy {
 
         // Add properties from the next table occurrence
         self.add_recursive_property_selections(&mut select_items, properties)?;
+        select_items.extend(self.path_edge_property_selections(context, "next", Some("vp")));
 
         let select_clause = select_items.join(",\n        ");
 
@@ -3254,11 +3336,49 @@ impl VariableLengthCteStrategy {
 
         // Skip path_relationships growth when relationships(path) isn't used
         generator.needs_path_relationships = context.needs_path_relationships;
+        // Per-hop edge property arrays for reduce() over relationships(path)
+        generator.path_edge_properties = context.path_edge_properties.clone();
         // Lightweight BFS mode for shortestPath + length(path)-only queries
         generator.use_bfs_mode = context.use_bfs_mode;
         generator.is_undirected = context.is_undirected;
         generator.undirected_single_walk = context.undirected_single_walk;
 
+        // Re-planning after ClickHouse hit its recursion depth: cap the path
+        // at the fallback bound (never below its lower bound) and unroll it.
+        if let Some(cap) = crate::server::query_context::get_vlp_fallback_hops() {
+            if !generator.requires_recursive_cte() {
+                let bound = generator
+                    .spec
+                    .max_hops
+                    .map_or(cap, |max| max.min(cap))
+                    .max(context.spec.effective_min_hops());
+                generator.spec.max_hops = Some(bound);
+                generator.unroll_recursion = true;
+            }
+        }
+
+        // Without `WITH RECURSIVE`, unroll a bounded path to its upper bound.
+        if let Some(reason) = crate::server::query_context::get_recursive_cte_unavailable() {
+            if generator.spec.max_hops.is_none() {
+                return Err(CteError::RecursiveCteUnavailable(format!(
+                    "Unbounded variable-length pattern ({})-[*{}..]->({}) cannot run: {}. \
+                     Give it an upper bound (e.g. *1..5) or upgrade ClickHouse.",
+                    self.pattern_ctx.left_node_alias,
+                    context.spec.effective_min_hops(),
+                    self.pattern_ctx.right_node_alias,
+                    reason
+                )));
+            }
+            if generator.requires_recursive_cte() {
+                return Err(CteError::RecursiveCteUnavailable(format!(
+                    "Variable-length pattern ({})-[*]->({}) needs a recursive CTE, which \
+                     cannot run: {}",
+                    self.pattern_ctx.left_node_alias, self.pattern_ctx.right_node_alias, reason
+                )));
+            }
+            generator.unroll_recursion = true;
+        }
+
         // Generate the CTE using the comprehensive generator
         let cte = generator.generate_cte();
 
@@ -3299,7 +3419,7 @@ impl VariableLengthCteStrategy {
             sql,
             parameters: vec![],
             cte_name,
-            recursive: true,
+            recursive: cte.is_recursive,
             from_alias: VLP_CTE_FROM_ALIAS.to_string(),
             columns,
             vlp_endpoint: Some(vlp_endpoint),
@@ -539,6 +539,29 @@ impl ExprVisitor for AliasRewriter {
     }
 }
 
+/// Visitor pointing `r.prop` of a multi-type single hop at the
+/// `rel_prop_<prop>` column of its `vlp_multi_type_*` CTE.
+pub struct MultiTypeRelPropertyRewriter {
+    pub rel_alias: String,
+    pub cte_alias: String,
+}
+
+impl ExprVisitor for MultiTypeRelPropertyRewriter {
+    fn transform_property_access(&mut self, prop: &PropertyAccess) -> RenderExpr {
+        if prop.table_alias.0 != self.rel_alias {
+            return RenderExpr::PropertyAccessExp(prop.clone());
+        }
+        RenderExpr::PropertyAccessExp(PropertyAccess {
+            table_alias: TableAlias(self.cte_alias.clone()),
+            column: PropertyValue::Column(
+                crate::clickhouse_query_generator::multi_type_vlp_joins::rel_property_column(
+                    prop.column.raw(),
+                ),
+            ),
+        })
+    }
+}
+
 /// Visitor for adding column name prefixes based on table alias
 /// Mutates expressions in-place to rewrite simple column references to aliased versions
 /// Used for CTE column flattening: "id" → "alias_id"
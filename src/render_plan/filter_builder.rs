@@ -320,6 +320,23 @@ impl FilterBuilder for LogicalPlan {
                         if is_multi_type {
                             if let Some(ref predicate) = graph_rel.where_predicate {
                                 if let Ok(expr) = RenderExpr::try_from(predicate.clone()) {
+                                    // A single hop binds `r` to one edge; its
+                                    // properties are the CTE's rel_prop_* columns.
+                                    let is_single_hop = graph_rel
+                                        .variable_length
+                                        .as_ref()
+                                        .is_none_or(|spec| spec.is_single_hop());
+                                    if is_single_hop {
+                                        use crate::query_planner::join_context::VLP_CTE_FROM_ALIAS;
+                                        use crate::render_plan::expression_utils::{
+                                            ExprVisitor, MultiTypeRelPropertyRewriter,
+                                        };
+                                        let mut rewriter = MultiTypeRelPropertyRewriter {
+                                            rel_alias: graph_rel.alias.clone(),
+                                            cte_alias: VLP_CTE_FROM_ALIAS.to_string(),
+                                        };
+                                        return Ok(Some(rewriter.transform_expr(&expr)));
+                                    }
                                     return Ok(Some(expr));
                                 }
                             }
@@ -696,6 +696,15 @@ impl JoinBuilder for LogicalPlan {
                 )?);
                 Ok(joins)
             }
+            // `WITH collect(x) AS xs UNWIND xs AS x MATCH (n) ...` puts the UNWIND
+            // on the left of a CartesianProduct with the disconnected MATCH.
+            LogicalPlan::CartesianProduct(cp) => {
+                let mut joins = <LogicalPlan as JoinBuilder>::extract_array_join(&cp.left)?;
+                joins.append(&mut <LogicalPlan as JoinBuilder>::extract_array_join(
+                    &cp.right,
+                )?);
+                Ok(joins)
+            }
             _ => Ok(Vec::new()),
         }
     }
@@ -776,6 +785,7 @@ impl JoinBuilder for LogicalPlan {
                                     to_id_column: Some("to_node_id".to_string()),
                                     graph_rel: None,
                                     is_cartesian: false,
+                                    use_final: false,
                                 };
 
                                 return Ok(vec![join]);
@@ -959,6 +969,7 @@ impl JoinBuilder for LogicalPlan {
                             to_id_column: None,
                             graph_rel: None,
                             is_cartesian: false,
+                            use_final: false,
                         });
                     }
                 }
@@ -1072,6 +1083,7 @@ impl JoinBuilder for LogicalPlan {
                                     to_id_column: None,
                                     graph_rel: None,
                                     is_cartesian: false,
+                                    use_final: false,
                                 };
                                 joins.push(join);
 
@@ -1323,6 +1335,7 @@ impl JoinBuilder for LogicalPlan {
                             // #601: carry the cartesian marker onto the rendered
                             // `ON 1=1` cross join so remove_unreferenced_joins keeps it.
                             is_cartesian: logical_join.is_cartesian,
+                            use_final: false,
                         };
                         joins.push(cross_join);
                         continue;
@@ -1470,8 +1483,13 @@ impl JoinBuilder for LogicalPlan {
                     }
 
                     // Fix table_name if we have a resolved table for this alias
+                    // (an OPTIONAL undirected hop already joins its doubled-edge CTE)
                     if let Some(resolved_table) = rel_tables.get(&render_join.table_alias) {
-                        render_join.table_name = resolved_table.clone();
+                        if !crate::sql_generator::emitters::clickhouse::variable_length_cte::is_undirected_doubled_edges_cte(
+                            &render_join.table_name,
+                        ) {
+                            render_join.table_name = resolved_table.clone();
+                        }
                     }
 
                     joins.push(render_join);
@@ -1708,6 +1726,7 @@ impl JoinBuilder for LogicalPlan {
                                 to_id_column: None,
                                 graph_rel: None,
                                 is_cartesian: false,
+                                use_final: false,
                             });
                         }
                     }
@@ -1900,6 +1919,7 @@ impl JoinBuilder for LogicalPlan {
                                 to_id_column: None,
                                 graph_rel: Some(Arc::new(graph_rel.clone())),
                                 is_cartesian: false,
+                                use_final: false,
                             };
 
                             crate::debug_println!(
@@ -2025,6 +2045,7 @@ impl JoinBuilder for LogicalPlan {
                             to_id_column: Some(rel_cols.to_id.to_string()),
                             graph_rel: None,
                             is_cartesian: false,
+                            use_final: false,
                         });
                     }
                     // For single-hop denormalized, no JOINs needed - relationship table IS the data
@@ -2215,6 +2236,7 @@ impl JoinBuilder for LogicalPlan {
                                 to_id_column: Some(inner_rel_cols.to_id.to_string()),
                                 graph_rel: None,
                                 is_cartesian: false,
+                                use_final: false,
                             });
 
                             // JOIN 2: Non-shared node connecting to relationship
@@ -2279,6 +2301,7 @@ impl JoinBuilder for LogicalPlan {
                                             to_id_column: None,
                                             graph_rel: None,
                                             is_cartesian: false,
+                                            use_final: false,
                                         });
                                     }
                                 } else {
@@ -2330,6 +2353,7 @@ impl JoinBuilder for LogicalPlan {
                                             to_id_column: None,
                                             graph_rel: None,
                                             is_cartesian: false,
+                                            use_final: false,
                                         });
                                     }
                                 }
@@ -2375,6 +2399,7 @@ impl JoinBuilder for LogicalPlan {
                                     to_id_column: None,
                                     graph_rel: None,
                                     is_cartesian: false,
+                                    use_final: false,
                                 });
                             }
 
@@ -2464,6 +2489,7 @@ impl JoinBuilder for LogicalPlan {
                                 to_id_column: Some(inner_rel_cols.to_id.to_string()),
                                 graph_rel: None,
                                 is_cartesian: false,
+                                use_final: false,
                             });
 
                             // JOIN 2: Non-shared node (right) connecting to relationship
@@ -2527,6 +2553,7 @@ impl JoinBuilder for LogicalPlan {
                                             to_id_column: None,
                                             graph_rel: None,
                                             is_cartesian: false,
+                                            use_final: false,
                                         });
                                     }
                                 } else {
@@ -2576,6 +2603,7 @@ impl JoinBuilder for LogicalPlan {
                                             to_id_column: None,
                                             graph_rel: None,
                                             is_cartesian: false,
+                                            use_final: false,
                                         });
                                     }
                                 }
@@ -2622,6 +2650,7 @@ impl JoinBuilder for LogicalPlan {
                                     to_id_column: None,
                                     graph_rel: None,
                                     is_cartesian: false,
+                                    use_final: false,
                                 });
                             }
 
@@ -2750,6 +2779,7 @@ impl JoinBuilder for LogicalPlan {
                         to_id_column: Some(rel_col_end.to_string()),
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     });
 
                     // JOIN 2: CTE (right node) -> Relationship table
@@ -2813,6 +2843,7 @@ impl JoinBuilder for LogicalPlan {
                                 to_id_column: None,
                                 graph_rel: None,
                                 is_cartesian: false,
+                                use_final: false,
                             });
                         }
                     }
@@ -3388,6 +3419,7 @@ impl JoinBuilder for LogicalPlan {
                         to_id_column: Some(rel_cols.to_id.to_string()),
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     });
 
                     // JOIN 2: Left node (optional) → relationship table
@@ -3412,6 +3444,7 @@ impl JoinBuilder for LogicalPlan {
                         to_id_column: None,
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     });
 
                     log::info!(
@@ -3508,6 +3541,7 @@ impl JoinBuilder for LogicalPlan {
                         to_id_column: Some(rel_cols.to_id.to_string()),
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     });
 
                     // JOIN 2: Left node (raw table) → relationship
@@ -3530,6 +3564,7 @@ impl JoinBuilder for LogicalPlan {
                         to_id_column: None,
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     });
 
                     log::info!(
@@ -3751,6 +3786,7 @@ impl JoinBuilder for LogicalPlan {
                         to_id_column: Some(rel_cols.to_id.to_string()),
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     });
                 }
 
@@ -3854,6 +3890,7 @@ impl JoinBuilder for LogicalPlan {
                                     to_id_column: None,
                                     graph_rel: None,
                                     is_cartesian: false,
+                                    use_final: false,
                                 });
 
                                 log::debug!(
@@ -4036,6 +4073,7 @@ impl JoinBuilder for LogicalPlan {
                         to_id_column: None,
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     });
                 } else {
                     // Denormalized case: end_table == rel_table (same physical table)
@@ -4065,6 +4103,7 @@ impl JoinBuilder for LogicalPlan {
                         to_id_column: None,
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     });
                     log::debug!(
                         "✓ Denormalized relationship: added self-join for end node {} on table '{}'",
@@ -4189,6 +4228,7 @@ impl JoinBuilder for LogicalPlan {
                                         to_id_column: None,
                                         graph_rel: None,
                                         is_cartesian: false,
+                                        use_final: false,
                                     });
                                 }
                             }
@@ -4250,6 +4290,7 @@ impl JoinBuilder for LogicalPlan {
                                 to_id_column: None,
                                 graph_rel: None,
                                 is_cartesian: false,
+                                use_final: false,
                             });
                         }
                     }
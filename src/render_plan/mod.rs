@@ -1,3 +1,4 @@
+pub mod collation;
 pub mod cte_extraction;
 pub mod cte_generation;
 pub mod cte_manager;
@@ -85,7 +86,7 @@ pub mod view_plan;
 pub mod write_plan_builder;
 pub mod write_render;
 
-pub use write_render::{DeleteOp, InsertOp, RowSource, UpdateOp, WriteRenderPlan};
+pub use write_render::{DeleteKind, DeleteOp, InsertOp, RowSource, UpdateOp, WriteRenderPlan};
 
 #[cfg(test)]
 mod tests;
@@ -249,6 +250,10 @@ pub struct Join {
     /// count). Spurious internal `ON 1=1` joins leave this false and stay prunable.
     #[serde(default)]
     pub is_cartesian: bool,
+    /// Read the joined table with `FINAL` (ClickHouse only). Set from the
+    /// table's node/edge schema by the scan predicate pushdown pass.
+    #[serde(default)]
+    pub use_final: bool,
 }
 
 impl Join {
@@ -282,6 +287,24 @@ impl Join {
         }
         None
     }
+
+    /// A cross join: an inner `JOIN` whose only conditions are the `1 = 1`
+    /// placeholder (kept so it is not mistaken for a FROM marker). Rendered
+    /// as `CROSS JOIN` without an ON clause.
+    pub fn is_cross_join(&self) -> bool {
+        matches!(self.join_type, JoinType::Join)
+            && self.pre_filter.is_none()
+            && self.joining_on.iter().all(|cond| {
+                cond.operator == render_expr::Operator::Equal
+                    && cond.operands.len() == 2
+                    && cond.operands.iter().all(|operand| {
+                        matches!(
+                            operand,
+                            RenderExpr::Literal(render_expr::Literal::Integer(1))
+                        )
+                    })
+            })
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -348,6 +371,7 @@ impl TryFrom<LogicalJoin> for Join {
             to_id_column: value.to_id_column,
             graph_rel: None,
             is_cartesian: value.is_cartesian,
+            use_final: false,
         };
         Ok(join)
     }
@@ -544,6 +568,10 @@ pub struct OrderByItems(pub Vec<OrderByItem>);
 pub struct OrderByItem {
     pub expression: RenderExpr,
     pub order: OrderByOrder,
+    /// ICU locale the item sorts by (`ASC COLLATE 'de'`), from the column's
+    /// or the query's collation
+    #[serde(default)]
+    pub collation: Option<String>,
 }
 
 impl TryFrom<LogicalOrderByItem> for OrderByItem {
@@ -553,6 +581,7 @@ impl TryFrom<LogicalOrderByItem> for OrderByItem {
         let order_by_item = OrderByItem {
             expression: value.expression.try_into()?,
             order: value.order.try_into()?,
+            collation: None,
         };
         Ok(order_by_item)
     }
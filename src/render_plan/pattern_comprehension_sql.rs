@@ -513,7 +513,15 @@ pub(crate) fn generate_pattern_comprehension_cte(
     }
 
     // Build final CTE SQL
-    select_cols.push("COUNT(*) AS result".to_string());
+    let directed_edges: Vec<_> = edge_tables
+        .iter()
+        .map(|(t, a, h)| (t.clone(), a.clone(), *h, h.direction.clone()))
+        .collect();
+    let projected = pc_projection_column(pc_meta, &directed_edges, schema, &mut join_clauses);
+    select_cols.push(format!(
+        "{} AS result",
+        pc_result_aggregate(pc_meta, projected.as_deref())
+    ));
 
     let where_str = if where_conditions.is_empty() {
         String::new()
@@ -590,6 +598,7 @@ fn generate_pc_cte_with_either(
     }
 
     let mut union_parts: Vec<String> = Vec::new();
+    let mut projects_value = false;
 
     for directions in &direction_variants {
         let mut edge_tables: Vec<(
@@ -698,14 +707,25 @@ fn generate_pc_cte_with_either(
             select_cols.push(format!("{}.{} AS {}", edge_alias, edge_col, corr_alias));
         }
 
-        // WHERE clause
+        // WHERE clause — node joins must follow this variant's directions,
+        // not the pattern's undirected hops
         if let Some(ref where_expr) = pc_meta.where_clause {
+            let variant_hops: Vec<_> = edge_tables
+                .iter()
+                .map(
+                    |(_, _, h, dir)| crate::query_planner::logical_plan::ConnectedPatternInfo {
+                        direction: dir.clone(),
+                        ..(*h).clone()
+                    },
+                )
+                .collect();
             if let Some(where_sql) = render_pc_where_clause(
                 where_expr,
-                &pc_meta.pattern_hops,
+                &variant_hops,
                 &edge_tables
                     .iter()
-                    .map(|(t, a, h, _)| (t.clone(), a.clone(), *h))
+                    .zip(&variant_hops)
+                    .map(|((t, a, _, _), h)| (t.clone(), a.clone(), h))
                     .collect::<Vec<_>>(),
                 schema,
                 &mut join_clauses,
@@ -720,6 +740,12 @@ fn generate_pc_cte_with_either(
             format!(" WHERE {}", where_conditions.join(" AND "))
         };
 
+        if let Some(value) = pc_projection_column(pc_meta, &edge_tables, schema, &mut join_clauses)
+        {
+            select_cols.push(format!("{} AS __v", value));
+            projects_value = true;
+        }
+
         let joins_str = if join_clauses.is_empty() {
             String::new()
         } else {
@@ -745,11 +771,15 @@ fn generate_pc_cte_with_either(
         .map(|(_, _, alias)| alias.clone())
         .collect();
 
+    // Every variant projects the same node, so either all of them carry __v or none do
+    let result_agg = pc_result_aggregate(pc_meta, projects_value.then_some("__v"));
+
     let cte_sql = if union_parts.len() == 1 {
         // Single variant — wrap in subquery with outer aggregation
         format!(
-            "SELECT {}, COUNT(*) AS result FROM ({}) AS __u GROUP BY {}",
+            "SELECT {}, {} AS result FROM ({}) AS __u GROUP BY {}",
             corr_aliases.join(", "),
+            result_agg,
             union_parts[0],
             corr_aliases.join(", ")
         )
@@ -757,8 +787,9 @@ fn generate_pc_cte_with_either(
         // Multiple variants — wrap UNION ALL in subquery with outer GROUP BY
         let inner_union = union_parts.join(" UNION ALL ");
         format!(
-            "SELECT {}, COUNT(*) AS result FROM ({}) AS __u GROUP BY {}",
+            "SELECT {}, {} AS result FROM ({}) AS __u GROUP BY {}",
             corr_aliases.join(", "),
+            result_agg,
             inner_union,
             corr_aliases.join(", ")
         )
@@ -1379,6 +1410,119 @@ fn generate_pc_correlated_subquery_with_either(
     }
 }
 
+/// Aggregate for a pre-aggregated PC CTE's `result` column: `groupArray` of the
+/// projected value for list-valued comprehensions, `COUNT(*)` otherwise.
+/// A projection that is not a single property collects `1` per match, like
+/// `build_pattern_comprehension_sql`, so the column stays an array.
+fn pc_result_aggregate(
+    pc_meta: &crate::query_planner::logical_plan::PatternComprehensionMeta,
+    projected: Option<&str>,
+) -> String {
+    use crate::query_planner::logical_plan::AggregationType;
+
+    match &pc_meta.agg_type {
+        AggregationType::GroupArray => format!(
+            "{}({})",
+            current_function_mapper().collect_list(),
+            projected.unwrap_or("1")
+        ),
+        _ => "COUNT(*)".to_string(),
+    }
+}
+
+/// Resolve the projected property of a pattern comprehension (`| f.name`) to a
+/// column, joining the projected node's table onto the edge chain as `__pc_tgt`.
+/// An unlabeled node takes its label from the edge's schema endpoint.
+fn pc_projection_column(
+    pc_meta: &crate::query_planner::logical_plan::PatternComprehensionMeta,
+    edges: &[(
+        String,
+        String,
+        &crate::query_planner::logical_plan::ConnectedPatternInfo,
+        crate::query_planner::logical_expr::Direction,
+    )],
+    schema: &GraphSchema,
+    join_clauses: &mut Vec<String>,
+) -> Option<String> {
+    let alias = pc_meta.target_alias.as_deref()?;
+    let property = pc_meta.target_property.as_deref()?;
+
+    let (hop_idx, is_start) =
+        edges
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(idx, (_, _, hop, _))| {
+                if hop.end_alias.as_deref() == Some(alias) {
+                    Some((idx, false))
+                } else if hop.start_alias.as_deref() == Some(alias) {
+                    Some((idx, true))
+                } else {
+                    None
+                }
+            })?;
+    let (db_table, edge_alias, hop, dir) = &edges[hop_idx];
+
+    let pattern_label = if is_start {
+        hop.start_label.clone()
+    } else {
+        hop.end_label.clone()
+    };
+    let label =
+        pattern_label.or_else(|| infer_hop_endpoint_label(schema, db_table, hop, dir, is_start))?;
+
+    let ns = schema.node_schema(&label).ok()?;
+    let edge_col = find_edge_id_column_with_direction(schema, db_table, is_start, hop, dir);
+    let db_column = ns
+        .property_mappings
+        .get(property)
+        .map(|pv| pv.raw().to_string())
+        .unwrap_or_else(|| property.to_string());
+
+    join_clauses.push(format!(
+        "INNER JOIN {}.{} AS __pc_tgt ON {}.{} = __pc_tgt.{}",
+        ns.database,
+        ns.table_name,
+        edge_alias,
+        edge_col,
+        ns.node_id.id.first_column()
+    ));
+    Some(format!("__pc_tgt.{}", db_column))
+}
+
+/// Label of an unlabeled hop endpoint, read from the schema of the edge table
+/// the hop resolved to (`$any` endpoints stay unresolved).
+fn infer_hop_endpoint_label(
+    schema: &GraphSchema,
+    db_table: &str,
+    hop: &crate::query_planner::logical_plan::ConnectedPatternInfo,
+    direction: &crate::query_planner::logical_expr::Direction,
+    is_start: bool,
+) -> Option<String> {
+    let from_side =
+        (*direction == crate::query_planner::logical_expr::Direction::Incoming) != is_start;
+    let mut sorted_rels: Vec<_> = schema.get_relationships_schemas().iter().collect();
+    sorted_rels.sort_by_key(|(k, _)| k.as_str());
+    sorted_rels
+        .into_iter()
+        .filter(|(key, rel)| {
+            format!("{}.{}", rel.database, rel.table_name) == db_table
+                && hop.rel_type.as_deref().is_none_or(|rt| {
+                    key.split("::")
+                        .next()
+                        .is_some_and(|k| k.eq_ignore_ascii_case(rt))
+                })
+        })
+        .map(|(_, rel)| {
+            if from_side {
+                rel.from_node.clone()
+            } else {
+                rel.to_node.clone()
+            }
+        })
+        .find(|l| l != "$any")
+}
+
 /// Like `find_edge_id_column` but takes an explicit direction parameter
 /// instead of reading from the hop's direction field. Used by the Either
 /// direction handler which overrides the hop's original direction.
@@ -1581,18 +1725,30 @@ fn render_pc_where_clause(
     let mut node_joins_added: HashSet<String> = HashSet::new();
 
     for (hop_idx, hop) in pattern_hops.iter().enumerate() {
+        // Unlabeled nodes take their label from the edge table the hop resolved to
+        let endpoint_label = |label: &Option<String>, is_start: bool| {
+            label.clone().or_else(|| {
+                edge_tables.get(hop_idx).and_then(|(db_table, _, _)| {
+                    infer_hop_endpoint_label(schema, db_table, hop, &hop.direction, is_start)
+                })
+            })
+        };
         // Check start node
-        if let (Some(ref alias), Some(ref label)) = (&hop.start_alias, &hop.start_label) {
+        if let (Some(ref alias), Some(label)) =
+            (&hop.start_alias, endpoint_label(&hop.start_label, true))
+        {
             if !node_alias_map.contains_key(alias.as_str()) {
                 let sql_alias = format!("__n{}s", hop_idx);
-                node_alias_map.insert(alias.clone(), (label.clone(), sql_alias));
+                node_alias_map.insert(alias.clone(), (label, sql_alias));
             }
         }
         // Check end node
-        if let (Some(ref alias), Some(ref label)) = (&hop.end_alias, &hop.end_label) {
+        if let (Some(ref alias), Some(label)) =
+            (&hop.end_alias, endpoint_label(&hop.end_label, false))
+        {
             if !node_alias_map.contains_key(alias.as_str()) {
                 let sql_alias = format!("__n{}e", hop_idx);
-                node_alias_map.insert(alias.clone(), (label.clone(), sql_alias));
+                node_alias_map.insert(alias.clone(), (label, sql_alias));
             }
         }
     }
@@ -1999,21 +2155,22 @@ pub(crate) fn build_pattern_comprehension_sql(
     use crate::open_cypher_parser::ast::Direction;
     use crate::query_planner::logical_plan::AggregationType;
 
-    // Resolve target node table/column for property-based aggregation (e.g., collect(f.name))
-    let target_join_info = target_label.and_then(|tl| {
+    // Resolve target node table/column for property-based aggregation (e.g., collect(f.name)).
+    // An unlabeled target (`(f)`) takes the label of each edge's far endpoint.
+    let target_join_info = |edge_end: &str| {
+        let tl = target_label.unwrap_or(edge_end);
         target_property.and_then(|tp| {
             schema.node_schema(tl).ok().map(|ns| {
                 let target_table = format!("{}.{}", ns.database, ns.table_name);
-                let target_id = ns.node_id.id.to_pipe_joined_sql("__tgt");
                 let db_column = ns
                     .property_mappings
                     .get(tp)
                     .map(|pv| pv.raw().to_string())
                     .unwrap_or_else(|| tp.to_string());
-                (target_table, target_id, db_column, tl.to_string())
+                (target_table, db_column)
             })
         })
-    });
+    };
 
     let mut branches: Vec<String> = Vec::new();
 
@@ -2060,9 +2217,10 @@ pub(crate) fn build_pattern_comprehension_sql(
                 format!(" WHERE {}", branch_where.join(" AND "))
             };
             // For property aggregation, JOIN the target node table
-            if let Some((ref tgt_table, ref _tgt_id, ref tgt_col, ref _tgt_label)) =
-                target_join_info
-            {
+            let target = target_join_info(&rel_schema.to_node);
+            if target_property.is_some() && target.is_none() {
+                // The far endpoint has no such property to collect
+            } else if let Some((ref tgt_table, ref tgt_col)) = target {
                 // Build JOIN condition: edge.to_id = target_node.node_id
                 let join_cond = {
                     let edge_cols = rel_schema.to_id.columns();
@@ -2111,9 +2269,10 @@ pub(crate) fn build_pattern_comprehension_sql(
                 format!(" WHERE {}", branch_where.join(" AND "))
             };
             // For property aggregation, JOIN the target (from) node table
-            if let Some((ref tgt_table, ref _tgt_id, ref tgt_col, ref _tgt_label)) =
-                target_join_info
-            {
+            let target = target_join_info(&rel_schema.from_node);
+            if target_property.is_some() && target.is_none() {
+                // The far endpoint has no such property to collect
+            } else if let Some((ref tgt_table, ref tgt_col)) = target {
                 let join_cond = {
                     let edge_cols = rel_schema.from_id.columns();
                     let tgt_ns = schema.node_schema(&rel_schema.from_node).ok();
@@ -2158,7 +2317,7 @@ pub(crate) fn build_pattern_comprehension_sql(
             // Dialect-aware list aggregate: CH `groupArray`, Spark `collect_list`.
             let collect =
                 crate::sql_generator::function_mapper::current_function_mapper().collect_list();
-            if target_join_info.is_some() {
+            if target_property.is_some() {
                 format!("{collect}(target_prop)")
             } else {
                 format!("{collect}(1)")
@@ -2176,6 +2335,42 @@ pub(crate) fn build_pattern_comprehension_sql(
     ))
 }
 
+/// Build raw SQL for a RETURN-context pattern comprehension CTE, keyed by `node_id`.
+///
+/// Single-hop comprehensions without a WHERE use the schema-wide
+/// `build_pattern_comprehension_sql`. Multi-hop and filtered ones go through
+/// `generate_pattern_comprehension_cte`, which walks the full hop chain and
+/// applies the WHERE, with its correlation column exposed as `node_id`.
+pub(crate) fn build_return_pattern_comprehension_sql(
+    pc_meta: &crate::query_planner::logical_plan::PatternComprehensionMeta,
+    schema: &GraphSchema,
+) -> Option<String> {
+    if pc_meta.pattern_hops.len() > 1 || pc_meta.where_clause.is_some() {
+        let pc_result = generate_pattern_comprehension_cte(pc_meta, schema);
+        if let Some(pc_result) = pc_result.filter(|r| r.correlation_columns.len() == 1) {
+            let (_, _, corr_alias) = &pc_result.correlation_columns[0];
+            return Some(format!(
+                "SELECT {} AS node_id, result FROM ({}) AS __pc",
+                corr_alias, pc_result.cte_sql
+            ));
+        }
+        log::warn!(
+            "⚠️ Pattern comprehension on '{}' needs a single correlation variable — using single-hop form",
+            pc_meta.correlation_var
+        );
+    }
+
+    build_pattern_comprehension_sql(
+        &pc_meta.correlation_label,
+        &pc_meta.direction,
+        &pc_meta.rel_types,
+        &pc_meta.agg_type,
+        schema,
+        pc_meta.target_label.as_deref(),
+        pc_meta.target_property.as_deref(),
+    )
+}
+
 /// Build a RenderExpr for a node's ID, handling composite keys.
 /// For single IDs: `alias.col` (PropertyAccess)
 /// For composite IDs: `concat(toString(alias.col1), '|', toString(alias.col2))`
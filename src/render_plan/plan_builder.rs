@@ -1365,6 +1365,11 @@ impl RenderPlanBuilder for LogicalPlan {
         crate::server::query_context::merge_exists_outer_aliases(
             super::plan_builder_utils::collect_exists_scope_aliases(self),
         );
+        // reduce() over relationships(p) may sit in a clause above the subtree
+        // that builds the VLP CTE — register its edge properties query-wide.
+        crate::server::query_context::merge_vlp_path_edge_properties(
+            super::cte_extraction::collect_path_edge_properties(self),
+        );
 
         if has_with_clause_in_graph_rel(self) {
             // #594: keep Cypher-UNION arms independent (see the ctx-aware path).
@@ -1489,14 +1494,8 @@ impl RenderPlanBuilder for LogicalPlan {
                             format!("pattern_comp_{}_{}", pc_meta.correlation_var, pc_idx);
 
                         if let Some(pc_sql) =
-                            super::plan_builder_utils::build_pattern_comprehension_sql(
-                                &pc_meta.correlation_label,
-                                &pc_meta.direction,
-                                &pc_meta.rel_types,
-                                &pc_meta.agg_type,
-                                schema,
-                                pc_meta.target_label.as_deref(),
-                                pc_meta.target_property.as_deref(),
+                            super::plan_builder_utils::build_return_pattern_comprehension_sql(
+                                pc_meta, schema,
                             )
                         {
                             let pc_cte = super::Cte::new(
@@ -1540,6 +1539,7 @@ impl RenderPlanBuilder for LogicalPlan {
                                 to_id_column: None,
                                 graph_rel: None,
                                 is_cartesian: false,
+                                use_final: false,
                             });
 
                             // Replace with coalesce(__pc_N.result, default)
@@ -2165,6 +2165,7 @@ impl RenderPlanBuilder for LogicalPlan {
                         to_id_column: None,
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     };
 
                     // 4. Extract SELECT, GROUP BY, etc. from the full plan
@@ -2268,6 +2269,7 @@ impl RenderPlanBuilder for LogicalPlan {
                         to_id_column: None,
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     };
 
                     let joins = JoinItems::new(vec![vlp_join]);
@@ -2501,14 +2503,8 @@ impl RenderPlanBuilder for LogicalPlan {
                             format!("pattern_comp_{}_{}", pc_meta.correlation_var, pc_idx);
 
                         if let Some(pc_sql) =
-                            super::plan_builder_utils::build_pattern_comprehension_sql(
-                                &pc_meta.correlation_label,
-                                &pc_meta.direction,
-                                &pc_meta.rel_types,
-                                &pc_meta.agg_type,
-                                schema,
-                                pc_meta.target_label.as_deref(),
-                                pc_meta.target_property.as_deref(),
+                            super::plan_builder_utils::build_return_pattern_comprehension_sql(
+                                pc_meta, schema,
                             )
                         {
                             // Add the pattern comp CTE
@@ -2555,6 +2551,7 @@ impl RenderPlanBuilder for LogicalPlan {
                                 to_id_column: None,
                                 graph_rel: None,
                                 is_cartesian: false,
+                                use_final: false,
                             });
 
                             // Replace the select item with coalesce(__pc_N.result, default)
@@ -2928,10 +2925,9 @@ impl RenderPlanBuilder for LogicalPlan {
                 // By converting them to PropertyAccessExp here, the correct alias is used.
                 for item in &mut render_plan.select.items {
                     if let RenderExpr::Column(Column(ref prop_val)) = item.expression {
-                        let col_name = prop_val.raw().to_string();
                         item.expression = RenderExpr::PropertyAccessExp(PropertyAccess {
                             table_alias: TableAlias(gn.alias.clone()),
-                            column: PropertyValue::Column(col_name),
+                            column: prop_val.clone(),
                         });
                     }
                 }
@@ -2942,10 +2938,9 @@ impl RenderPlanBuilder for LogicalPlan {
                     for branch in &mut union.input {
                         for item in &mut branch.select.items {
                             if let RenderExpr::Column(Column(ref prop_val)) = item.expression {
-                                let col_name = prop_val.raw().to_string();
                                 item.expression = RenderExpr::PropertyAccessExp(PropertyAccess {
                                     table_alias: TableAlias(gn.alias.clone()),
-                                    column: PropertyValue::Column(col_name),
+                                    column: prop_val.clone(),
                                 });
                             }
                         }
@@ -3523,6 +3518,7 @@ impl RenderPlanBuilder for LogicalPlan {
                         to_id_column: None,
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     };
 
                     // Add to existing joins
@@ -3976,6 +3972,9 @@ impl RenderPlanBuilder for LogicalPlan {
             crate::server::query_context::merge_exists_outer_aliases(
                 super::plan_builder_utils::collect_exists_scope_aliases(self),
             );
+            crate::server::query_context::merge_vlp_path_edge_properties(
+                super::cte_extraction::collect_path_edge_properties(self),
+            );
 
             // CRITICAL: If the plan contains WITH clauses, use the specialized handler
             // build_chained_with_match_cte_plan handles chained/nested WITH correctly
@@ -4238,6 +4237,7 @@ impl RenderPlanBuilder for LogicalPlan {
                             // keep the cross join even if its alias looks
                             // unreferenced, so row cardinality is preserved.
                             is_cartesian: true,
+                            use_final: false,
                         });
                     }
                     base.joins.0.extend(std::mem::take(&mut joined.joins.0));
@@ -5692,14 +5692,8 @@ impl RenderPlanBuilder for LogicalPlan {
                                 format!("pattern_comp_{}_{}", pc_meta.correlation_var, pc_idx);
 
                             if let Some(pc_sql) =
-                                super::plan_builder_utils::build_pattern_comprehension_sql(
-                                    &pc_meta.correlation_label,
-                                    &pc_meta.direction,
-                                    &pc_meta.rel_types,
-                                    &pc_meta.agg_type,
-                                    schema,
-                                    pc_meta.target_label.as_deref(),
-                                    pc_meta.target_property.as_deref(),
+                                super::plan_builder_utils::build_return_pattern_comprehension_sql(
+                                    pc_meta, schema,
                                 )
                             {
                                 let pc_cte = super::Cte::new(
@@ -5744,6 +5738,7 @@ impl RenderPlanBuilder for LogicalPlan {
                                     to_id_column: None,
                                     graph_rel: None,
                                     is_cartesian: false,
+                                    use_final: false,
                                 });
 
                                 // Replace with coalesce(__pc_N.result, default)
@@ -6340,6 +6335,8 @@ impl RenderPlanBuilder for LogicalPlan {
             }
         }
 
+        super::collation::apply_collations(&mut render_plan, schema);
+
         Ok(render_plan)
     }
 }
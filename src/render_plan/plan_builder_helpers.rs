@@ -215,6 +215,21 @@ pub(super) fn rewrite_with_aliases_to_cte(
                 .collect();
             (RenderExpr::List(new_items), all_from_with)
         }
+        RenderExpr::MapLiteral(entries) => {
+            let mut all_from_with = true;
+            let new_entries: Vec<(String, RenderExpr)> = entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let (rewritten, from_with) =
+                        rewrite_with_aliases_to_cte(value, with_aliases, cte_name);
+                    if !from_with {
+                        all_from_with = false;
+                    }
+                    (key, rewritten)
+                })
+                .collect();
+            (RenderExpr::MapLiteral(new_entries), all_from_with)
+        }
         // Literals, Star, Column, Parameter, Raw don't need rewriting and don't come from WITH
         other => (other, false),
     }
@@ -321,6 +336,17 @@ pub(super) fn rewrite_table_aliases_to_cte(
                 .collect();
             RenderExpr::List(new_items)
         }
+        RenderExpr::MapLiteral(entries) => RenderExpr::MapLiteral(
+            entries
+                .into_iter()
+                .map(|(key, value)| {
+                    (
+                        key,
+                        rewrite_table_aliases_to_cte(value, with_table_aliases, cte_name),
+                    )
+                })
+                .collect(),
+        ),
         // Other expressions pass through unchanged
         other => other,
     }
@@ -1650,6 +1676,10 @@ pub(crate) fn render_expr_to_sql_string(
                 .find(|(cypher, _)| *cypher == prop.table_alias.0)
                 .map(|(_, cte)| cte.clone())
                 .unwrap_or_else(|| prop.table_alias.0.clone());
+            // A computed property inlines its expression, qualified by the alias
+            if let PropertyValue::Expression(_) = &prop.column {
+                return prop.column.to_sql(&table_alias);
+            }
             format!("{}.{}", table_alias, prop.column.raw())
         }
         RenderExpr::OperatorApplicationExp(op) => {
@@ -2485,6 +2515,90 @@ pub(super) fn rewrite_logical_path_functions(
                 },
             )
         }
+        LogicalExpr::ReduceExpr(reduce) => {
+            use crate::clickhouse_query_generator::variable_length_cte::path_edge_property_column;
+            use crate::query_planner::logical_expr::visitors::{map_expression, ExprRewrite};
+            use crate::query_planner::logical_expr::{Literal, ReduceExpr};
+
+            let is_edge_list = matches!(
+                reduce.list.as_ref(),
+                LogicalExpr::ScalarFnCall(fc)
+                    if fc.name.eq_ignore_ascii_case("relationships")
+                        && matches!(fc.args.as_slice(), [LogicalExpr::TableAlias(a)] if a.0 == path_var_name)
+            );
+
+            // Edge properties read off the iteration variable, in the same
+            // (sorted) order the VLP CTE materialises their arrays.
+            let mut props = std::collections::BTreeSet::new();
+            if is_edge_list {
+                map_expression(&reduce.expression, &mut |e| {
+                    if let LogicalExpr::PropertyAccessExp(pa) = e {
+                        if pa.table_alias.0 == reduce.variable {
+                            props.insert(pa.column.raw().to_string());
+                        }
+                    }
+                    ExprRewrite::Recurse
+                });
+            }
+            let props: Vec<String> = props.into_iter().collect();
+
+            let (list, expression) = if props.is_empty() {
+                (
+                    rewrite_logical_path_functions(&reduce.list, path_var_name),
+                    rewrite_logical_path_functions(&reduce.expression, path_var_name),
+                )
+            } else {
+                let edge_array = |prop: &str| {
+                    LogicalExpr::PropertyAccessExp(PropertyAccess {
+                        table_alias: TableAlias("__vlp_bare_col".to_string()),
+                        column: PropertyValue::Column(path_edge_property_column(prop)),
+                    })
+                };
+                let list = if props.len() == 1 {
+                    edge_array(&props[0])
+                } else {
+                    LogicalExpr::ScalarFnCall(ScalarFnCall {
+                        name: "arrayZip".to_string(),
+                        args: props.iter().map(|p| edge_array(p)).collect(),
+                    })
+                };
+                let substituted = map_expression(&reduce.expression, &mut |e| match e {
+                    LogicalExpr::PropertyAccessExp(pa) if pa.table_alias.0 == reduce.variable => {
+                        let element = LogicalExpr::TableAlias(TableAlias(reduce.variable.clone()));
+                        if props.len() == 1 {
+                            return ExprRewrite::Replace(element);
+                        }
+                        let position = props
+                            .iter()
+                            .position(|p| p == pa.column.raw())
+                            .unwrap_or_default();
+                        ExprRewrite::Replace(LogicalExpr::ScalarFnCall(ScalarFnCall {
+                            name: "tupleElement".to_string(),
+                            args: vec![
+                                element,
+                                LogicalExpr::Literal(Literal::Integer(position as i64 + 1)),
+                            ],
+                        }))
+                    }
+                    _ => ExprRewrite::Recurse,
+                });
+                (
+                    list,
+                    rewrite_logical_path_functions(&substituted, path_var_name),
+                )
+            };
+
+            LogicalExpr::ReduceExpr(ReduceExpr {
+                accumulator: reduce.accumulator.clone(),
+                initial_value: Box::new(rewrite_logical_path_functions(
+                    &reduce.initial_value,
+                    path_var_name,
+                )),
+                variable: reduce.variable.clone(),
+                list: Box::new(list),
+                expression: Box::new(expression),
+            })
+        }
         _ => expr.clone(), // For other expression types, return as-is
     }
 }
@@ -5797,13 +5911,38 @@ pub fn sort_joins_by_dependency(
 
         // Among ready joins, pick the smallest table_alias (natural numeric
         // order, see natural_alias_ord) for determinism
-        let best_pos = ready_positions.iter().copied().min_by(|&a, &b| {
+        let mut best_pos = ready_positions.iter().copied().min_by(|&a, &b| {
             natural_alias_ord(
                 &joins[remaining[a]].table_alias,
                 &joins[remaining[b]].table_alias,
             )
         });
 
+        // When that is a cross join, take the ready cross join with the
+        // fewest rows instead (ties keep alias order): ClickHouse builds the
+        // right side of each cross join in memory, so smaller sides go first.
+        if best_pos.is_some_and(|pos| joins[remaining[pos]].is_cross_join()) {
+            if let Some(stats) = crate::server::query_context::get_current_table_stats() {
+                let rows = |pos: usize| {
+                    stats
+                        .row_count(&joins[remaining[pos]].table_name)
+                        .unwrap_or(u64::MAX)
+                };
+                best_pos = ready_positions
+                    .iter()
+                    .copied()
+                    .filter(|&pos| joins[remaining[pos]].is_cross_join())
+                    .min_by(|&a, &b| {
+                        rows(a).cmp(&rows(b)).then_with(|| {
+                            natural_alias_ord(
+                                &joins[remaining[a]].table_alias,
+                                &joins[remaining[b]].table_alias,
+                            )
+                        })
+                    });
+            }
+        }
+
         if let Some(pos) = best_pos {
             let idx = remaining.remove(pos);
 
@@ -5980,6 +6119,11 @@ pub(super) fn rewrite_denorm_refs(
                 rewrite_denorm_refs(item, edge_alias, col_map);
             }
         }
+        RenderExpr::MapLiteral(entries) => {
+            for (_, value) in entries {
+                rewrite_denorm_refs(value, edge_alias, col_map);
+            }
+        }
         _ => {}
     }
 }
@@ -6765,6 +6909,7 @@ mod tests {
                 to_id_column: None,
                 graph_rel: None,
                 is_cartesian: false,
+                use_final: false,
             }
         }
         let from = crate::render_plan::FromTable {
@@ -63,7 +63,9 @@ use crate::render_plan::{
     UnionItems,
 };
 use crate::utils::cte_column_naming::{cte_column_name, parse_cte_column};
-use crate::utils::cte_naming::{generate_cte_name, is_generated_cte_name};
+use crate::utils::cte_naming::{
+    extract_cte_base_name, generate_cte_base_name, generate_cte_name, is_generated_cte_name,
+};
 
 // Import ALL helper functions from the dedicated helpers module using glob import
 // This allows existing code to call helpers without changes (e.g., extract_table_name())
@@ -1231,6 +1233,7 @@ graph_schema:
                 crate::query_planner::logical_plan::Create {
                     input,
                     patterns: vec![],
+                    merge: None,
                 },
             ))
         }
@@ -1517,6 +1520,7 @@ pub fn extract_order_by(plan: &LogicalPlan) -> RenderPlanBuilderResult<Vec<Order
                                     crate::render_plan::OrderByOrder::Desc
                                 }
                             },
+                            collation: None,
                         });
                     }
                     let mut order_item: OrderByItem = item.try_into()?;
@@ -2293,6 +2297,7 @@ pub(crate) fn generate_swapped_joins_for_optional_match(
         to_id_column: Some(rel_col_to_new.clone()),
         graph_rel: None,
         is_cartesian: false,
+        use_final: false,
     });
 
     // JOIN 2: New node (left_connection) connecting to relationship
@@ -2320,6 +2325,7 @@ pub(crate) fn generate_swapped_joins_for_optional_match(
         to_id_column: None,
         graph_rel: None,
         is_cartesian: false,
+        use_final: false,
     });
 
     Ok(joins)
@@ -4542,10 +4548,15 @@ pub(crate) fn update_graph_joins_cte_refs(
 
             // Check if this WithClause's cte_name needs updating
             let updated_cte_name = if let Some(ref old_cte_name) = wc.cte_name {
-                // Check if any alias exported by this WITH has a new CTE name
+                // Check if any alias exported by this WITH has a new CTE name.
+                // Only a name with this WITH's own alias set is a rename of it; an
+                // exported alias can also map to the upstream stage that produced it
+                // (`WITH c, n, n * 2 AS dbl WITH c, dbl` — `c` is in both).
+                let own_base = generate_cte_base_name(&wc.exported_aliases);
                 wc.exported_aliases
                     .iter()
-                    .find_map(|alias| cte_references.get(alias))
+                    .filter_map(|alias| cte_references.get(alias))
+                    .find(|name| extract_cte_base_name(name).as_ref() == Some(&own_base))
                     .cloned()
                     .or(Some(old_cte_name.clone()))
             } else {
@@ -5362,6 +5373,100 @@ fn split_render_and_conjuncts(expr: RenderExpr) -> Vec<RenderExpr> {
     }
 }
 
+/// Post-WITH OPTIONAL MATCH correlated to the WITH stage (`WITH u, count(*)
+/// AS c OPTIONAL MATCH (u)-[:FOLLOWS]->(m) WHERE c > 0`): the stage CTE is
+/// the FROM and every join is a LEFT JOIN of the optional pattern, so the
+/// join builder's #597 gate fold (which skips CTE-backed patterns) never
+/// sees the clause's anchor-only conjuncts. Move the conjuncts reading only
+/// the CTE into the ON of the clause's entry join, as #472 does for the
+/// reversed-anchor shape: a failing gate NULL-extends the anchor row instead
+/// of dropping it. The rest stay for `fold_optional_edge_node_join_with_predicate`.
+fn fold_cte_anchored_optional_where(render_plan: &mut RenderPlan, plan: &LogicalPlan) {
+    use super::expression_utils::references_alias;
+
+    let joins = &mut render_plan.joins.0;
+    if joins.iter().any(|j| j.join_type != super::JoinType::Left) {
+        return;
+    }
+    let mut tagged = Vec::new();
+    collect_anchor_gated_graphrels(plan, &mut tagged);
+    let [gr] = tagged.as_slice() else {
+        return;
+    };
+    // The edge's own join when it has one (joins are not in ON order yet)
+    let Some(entry_join) = joins
+        .iter()
+        .position(|j| j.table_alias == gr.alias)
+        .or_else(|| {
+            joins.iter().position(|j| {
+                j.table_alias == gr.left_connection || j.table_alias == gr.right_connection
+            })
+        })
+    else {
+        return;
+    };
+    let Some(filter) = render_plan.filters.0.take() else {
+        return;
+    };
+
+    let mut kept = Vec::new();
+    for conj in split_render_and_conjuncts(filter) {
+        let anchor_only = !joins
+            .iter()
+            .any(|j| references_alias(&conj, &j.table_alias));
+        if !anchor_only {
+            kept.push(conj);
+            continue;
+        }
+        // ON conditions are operator applications; a bare boolean column or
+        // function call (`startsWith(...)`) is gated as `<expr> = true`
+        let op = match conj {
+            RenderExpr::OperatorApplicationExp(op) => op,
+            other => OperatorApplication {
+                operator: Operator::Equal,
+                operands: vec![other, RenderExpr::Literal(Literal::Boolean(true))],
+            },
+        };
+        joins[entry_join].joining_on.push(op);
+    }
+    render_plan.filters.0 = kept.into_iter().reduce(|acc, conj| {
+        RenderExpr::OperatorApplicationExp(OperatorApplication {
+            operator: Operator::And,
+            operands: vec![acc, conj],
+        })
+    });
+}
+
+/// The optional `GraphRel`s carrying an anchor-only WHERE (`optional_anchor_where`).
+fn collect_anchor_gated_graphrels<'a>(
+    plan: &'a LogicalPlan,
+    out: &mut Vec<&'a crate::query_planner::logical_plan::GraphRel>,
+) {
+    match plan {
+        LogicalPlan::GraphRel(gr) => {
+            if gr.optional_anchor_where.is_some() {
+                out.push(gr);
+            }
+            collect_anchor_gated_graphrels(&gr.left, out);
+            collect_anchor_gated_graphrels(&gr.right, out);
+        }
+        LogicalPlan::CartesianProduct(cp) => {
+            collect_anchor_gated_graphrels(&cp.left, out);
+            collect_anchor_gated_graphrels(&cp.right, out);
+        }
+        LogicalPlan::GraphJoins(gj) => collect_anchor_gated_graphrels(&gj.input, out),
+        LogicalPlan::Projection(proj) => collect_anchor_gated_graphrels(&proj.input, out),
+        LogicalPlan::Filter(f) => collect_anchor_gated_graphrels(&f.input, out),
+        LogicalPlan::GroupBy(g) => collect_anchor_gated_graphrels(&g.input, out),
+        LogicalPlan::OrderBy(o) => collect_anchor_gated_graphrels(&o.input, out),
+        LogicalPlan::Limit(l) => collect_anchor_gated_graphrels(&l.input, out),
+        LogicalPlan::Skip(s) => collect_anchor_gated_graphrels(&s.input, out),
+        LogicalPlan::GraphNode(gn) => collect_anchor_gated_graphrels(&gn.input, out),
+        LogicalPlan::Unwind(u) => collect_anchor_gated_graphrels(&u.input, out),
+        _ => {}
+    }
+}
+
 fn show_plan_structure(plan: &LogicalPlan, indent: usize) {
     let prefix = "  ".repeat(indent);
     match plan {
@@ -6337,10 +6442,16 @@ impl CteNameAllocator {
     ) -> String {
         let mut cte_name = proposed;
 
-        // If analyzer provided a duplicate name (or hoisted CTE collided), generate a fresh one
-        if self.used_names.contains(&cte_name) {
+        // A name for another alias set is stale: an optimizer pass rewrote the WITH
+        // after analysis (e.g. `collect(x) AS xs UNWIND xs AS x` exports `x`).
+        let stale = extract_cte_base_name(&cte_name)
+            .is_some_and(|base| base != generate_cte_base_name(sorted_aliases));
+
+        // If analyzer provided a duplicate or stale name (or hoisted CTE collided),
+        // generate a fresh one
+        if stale || self.used_names.contains(&cte_name) {
             log::debug!(
-                "🔧 build_chained_with_match_cte_plan: Duplicate CTE name '{}' detected, generating a unique name",
+                "🔧 build_chained_with_match_cte_plan: Duplicate or stale CTE name '{}' detected, generating a unique name",
                 cte_name
             );
 
@@ -6466,13 +6577,52 @@ fn expr_contains_aggregate(expr: &crate::query_planner::logical_expr::LogicalExp
                     .is_some_and(|e| expr_contains_aggregate(e))
         }
         LogicalExpr::List(items) => items.iter().any(expr_contains_aggregate),
+        LogicalExpr::MapLiteral(entries) => entries.iter().any(|(_, v)| expr_contains_aggregate(v)),
         LogicalExpr::ArraySubscript { array, index } => {
             expr_contains_aggregate(array) || expr_contains_aggregate(index)
         }
+        // `collect(p.title)[0..3]`
+        LogicalExpr::ArraySlicing { array, from, to } => {
+            expr_contains_aggregate(array)
+                || from.as_ref().is_some_and(|e| expr_contains_aggregate(e))
+                || to.as_ref().is_some_and(|e| expr_contains_aggregate(e))
+        }
         _ => false,
     }
 }
 
+/// WITH items to derive grouping keys from: a map literal or map projection
+/// with an aggregate inside (`n {.name, total: count(f)}`) stands for its
+/// non-aggregate entries, as in Neo4j. Other items stand for themselves.
+fn with_grouping_candidates(
+    items: &[crate::query_planner::logical_plan::ProjectionItem],
+) -> Vec<crate::query_planner::logical_plan::ProjectionItem> {
+    use crate::query_planner::logical_expr::LogicalExpr;
+    use crate::query_planner::logical_plan::ProjectionItem;
+    fn push_entries(expr: &LogicalExpr, out: &mut Vec<ProjectionItem>) {
+        match expr {
+            LogicalExpr::MapLiteral(entries) if expr_contains_aggregate(expr) => {
+                for (_, value) in entries {
+                    push_entries(value, out);
+                }
+            }
+            _ => out.push(ProjectionItem {
+                expression: expr.clone(),
+                col_alias: None,
+            }),
+        }
+    }
+    let mut out = Vec::new();
+    for item in items {
+        if matches!(item.expression, LogicalExpr::MapLiteral(_)) {
+            push_entries(&item.expression, &mut out);
+        } else {
+            out.push(item.clone());
+        }
+    }
+    out
+}
+
 fn rewrite_person_to_fk(expr: &mut RenderExpr, person_alias: &str, rel_alias: &str, fk_col: &str) {
     match expr {
         RenderExpr::PropertyAccessExp(pa) if pa.table_alias.0 == person_alias => {
@@ -7713,7 +7863,18 @@ pub(crate) fn build_chained_with_match_cte_plan(
                                                 // This must happen in WITH context too, not just in extract_select_items()
                                                 let expanded_expr = if let crate::query_planner::logical_expr::LogicalExpr::AggregateFnCall(ref agg) = rewritten_expr {
                                                     if agg.name.to_lowercase() == "collect" && agg.args.len() == 1 {
-                                                        if let crate::query_planner::logical_expr::LogicalExpr::TableAlias(ref alias) = agg.args[0] {
+                                                        // collect(DISTINCT n) expands the same way, into the
+                                                        // dialect's de-duplicating list aggregate.
+                                                        let (collect_arg, distinct) = match &agg.args[0] {
+                                                            crate::query_planner::logical_expr::LogicalExpr::OperatorApplicationExp(op)
+                                                                if op.operator == crate::query_planner::logical_expr::Operator::Distinct
+                                                                    && op.operands.len() == 1 =>
+                                                            {
+                                                                (&op.operands[0], true)
+                                                            }
+                                                            arg => (arg, false),
+                                                        };
+                                                        if let crate::query_planner::logical_expr::LogicalExpr::TableAlias(alias) = collect_arg {
                                                             log::debug!("🔧 WITH context: Expanding collect({}) to groupArray(tuple(...))", alias.0);
 
                                                             // Extract property requirements for pruning
@@ -7742,7 +7903,16 @@ pub(crate) fn build_chained_with_match_cte_plan(
 
                                                                     // Use centralized expansion utility with property requirements
                                                                     use crate::render_plan::property_expansion::expand_collect_to_group_array;
-                                                                    expand_collect_to_group_array(&alias.0, collect_props, property_requirements)
+                                                                    let expanded = expand_collect_to_group_array(&alias.0, collect_props, property_requirements);
+                                                                    match expanded {
+                                                                        crate::query_planner::logical_expr::LogicalExpr::AggregateFnCall(mut expanded_agg) if distinct => {
+                                                                            expanded_agg.name = current_function_mapper()
+                                                                                .collect_set()
+                                                                                .to_string();
+                                                                            crate::query_planner::logical_expr::LogicalExpr::AggregateFnCall(expanded_agg)
+                                                                        }
+                                                                        other => other,
+                                                                    }
                                                                 }
                                                                 _ => {
                                                                     log::warn!("⚠️  Could not expand collect({}) in WITH - no properties found, keeping as-is", alias.0);
@@ -7838,7 +8008,13 @@ pub(crate) fn build_chained_with_match_cte_plan(
 
                         log::debug!("🔧 build_chained_with_match_cte_plan: Total select_items after expansion: {}", select_items.len());
 
-                        if !select_items.is_empty() {
+                        // A user-written UNION under a WITH (`CALL { ... UNION ... }`)
+                        // already projects the WITH's columns in every arm, each
+                        // from its own pattern; one shared projection would read
+                        // the first arm's columns in all of them.
+                        let is_cypher_union_input =
+                            matches!(plan_to_render, LogicalPlan::Union(u) if u.is_cypher_union);
+                        if !select_items.is_empty() && !is_cypher_union_input {
                             // Check if the logical plan has a denormalized Union.
                             // Denormalized Unions already have per-branch SELECT items with
                             // correct column resolution (origin_code vs dest_code). We must NOT
@@ -8114,7 +8290,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                             // 2. ANY() picks the single value in each group (safe for PK)
                             // 3. GROUP BY 1 column is much faster than GROUP BY 7 columns
                             if has_aggregation {
-                                let group_by_exprs: Vec<RenderExpr> = items.iter()
+                                let group_by_exprs: Vec<RenderExpr> = with_grouping_candidates(items).iter()
                                             .filter(|item| {
                                                 // Exclude: direct aggregates, literals, and expressions containing aggregates
                                                 // (#591: use expr_contains_aggregate, which recurses into
@@ -8220,6 +8396,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                                             OrderByOrder::Desc
                                         }
                                     },
+                                    collation: None,
                                 }
                             })
                         })
@@ -9143,6 +9320,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                                     to_id_column: None,
                                     graph_rel: None,
                                     is_cartesian: false,
+                                    use_final: false,
                                 };
 
                                 // Add LEFT JOIN to the WITH CTE body.
@@ -9159,7 +9337,8 @@ pub(crate) fn build_chained_with_match_cte_plan(
                         }
                     }
 
-                    // Phase B: Replace count(*) placeholders with COALESCE(pc_cte.result, 0)
+                    // Phase B: Replace count(*) placeholders with COALESCE(pc_cte.result, 0),
+                    // or COALESCE(pc_cte.result, []) for list-valued comprehensions
                     // Build replacement expressions indexed by PC position
                     let mut pc_replacements: Vec<String> = Vec::new();
                     let mut cte_name_iter = pc_cte_names.iter();
@@ -9174,15 +9353,24 @@ pub(crate) fn build_chained_with_match_cte_plan(
                             pc_replacements.push("__arraycount_placeholder__".to_string());
                             continue;
                         }
+                        let empty = if matches!(
+                            pc_meta.agg_type,
+                            crate::query_planner::logical_plan::AggregationType::GroupArray
+                        ) {
+                            "[]"
+                        } else {
+                            "0"
+                        };
                         if let Some((idx, ref name)) = next_cte {
                             if *idx == pc_idx {
-                                pc_replacements.push(format!("COALESCE({}.result, 0)", name));
+                                pc_replacements
+                                    .push(format!("COALESCE({}.result, {})", name, empty));
                                 next_cte = cte_name_iter.next();
                             } else {
-                                pc_replacements.push("0".to_string());
+                                pc_replacements.push(empty.to_string());
                             }
                         } else {
-                            pc_replacements.push("0".to_string());
+                            pc_replacements.push(empty.to_string());
                         }
                     }
 
@@ -9410,6 +9598,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                                 to_id_column: None,
                                 graph_rel: None,
                                 is_cartesian: false,
+                                use_final: false,
                             };
                             with_cte_render.joins.0.push(join);
 
@@ -9830,6 +10019,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                                                 to_id_column: None,
                                                 graph_rel: None,
                                                 is_cartesian: false,
+                                                use_final: false,
                                             };
                                             with_cte_render.joins.0.insert(1, old_from_join);
 
@@ -10112,6 +10302,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                                             to_id_column: None,
                                             graph_rel: None,
                                             is_cartesian: false,
+                                            use_final: false,
                                         };
 
                                         // Find the edge table's original ON condition that referenced old FROM
@@ -10264,18 +10455,27 @@ pub(crate) fn build_chained_with_match_cte_plan(
                 // expensive multi-table join evaluated exactly once.
                 let bidi_cte_name = format!("bidi_{}", cte_name);
                 let cast_u8 = current_function_mapper().cast_uint8();
+                // Without `WITH RECURSIVE`, read the reverse edges straight off
+                // the weight CTE (evaluated twice).
+                let recursive =
+                    crate::server::query_context::get_recursive_cte_unavailable().is_none();
                 let bidi_sql = format!(
                     "SELECT source, target, weight, {cast_u8}(0) AS __depth FROM {cte} \
                      UNION ALL \
-                     SELECT target AS source, source AS target, weight, __depth + 1 \
-                     FROM {bidi} WHERE __depth = 0",
+                     SELECT target AS source, source AS target, weight, {reverse_depth} \
+                     FROM {reverse_from}",
                     cte = cte_name,
-                    bidi = bidi_cte_name,
+                    reverse_depth = if recursive { "__depth + 1" } else { "1" },
+                    reverse_from = if recursive {
+                        format!("{} WHERE __depth = 0", bidi_cte_name)
+                    } else {
+                        cte_name.clone()
+                    },
                 );
                 let bidi_cte = super::Cte {
                     cte_name: bidi_cte_name.clone(),
                     content: super::CteContent::RawSql(bidi_sql),
-                    is_recursive: true,
+                    is_recursive: recursive,
                     vlp_start_alias: None,
                     vlp_end_alias: None,
                     vlp_start_table: None,
@@ -10902,7 +11102,13 @@ pub(crate) fn build_chained_with_match_cte_plan(
 
     // All WITH clauses have been processed, now render the final plan
     // Build scope from all accumulated CTE variables for the final rendering pass.
-    let final_scope = with_scope.build_final_scope(schema, &current_plan);
+    let final_scope = with_scope
+        .build_final_scope(schema, &current_plan)
+        .with_return_projection(
+            plan_ctx
+                .map(|ctx| ctx.full_projection_aliases().clone())
+                .unwrap_or_default(),
+        );
     let final_scope_ref = if with_scope.is_empty() && scope.is_none() {
         None
     } else {
@@ -11041,6 +11247,10 @@ pub(crate) fn build_chained_with_match_cte_plan(
                     cte_name,
                     preserved_alias
                 );
+
+                if current_plan.is_optional_pattern() && render_plan.union.0.is_none() {
+                    fold_cte_anchored_optional_where(&mut render_plan, &current_plan);
+                }
             }
         }
     } else if matches!(render_plan.from, FromTableItem(None))
@@ -11418,6 +11628,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                     to_id_column: None,
                     graph_rel: None,
                     is_cartesian: false,
+                    use_final: false,
                 };
 
                 // #453: Post-WITH OPTIONAL MATCH anchoring. When the fresh
@@ -11621,6 +11832,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                         to_id_column: None,
                         graph_rel: None,
                         is_cartesian: false,
+                        use_final: false,
                     };
                     render_plan.joins.0.insert(0, optional_from_join);
                     log::info!(
@@ -11777,6 +11989,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                                     to_id_column: None,
                                     graph_rel: None,
                                     is_cartesian: false,
+                                    use_final: false,
                                 };
                                 branch.joins.0.insert(0, branch_cte_join);
                                 log::info!(
@@ -11821,6 +12034,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                                     to_id_column: None,
                                     graph_rel: None,
                                     is_cartesian: false,
+                                    use_final: false,
                                 };
                                 branch.joins.0.insert(0, branch_cte_join);
                                 log::info!(
@@ -11885,6 +12099,7 @@ pub(crate) fn build_chained_with_match_cte_plan(
                             to_id_column: None,
                             graph_rel: None,
                             is_cartesian: false,
+                            use_final: false,
                         };
                         branch.joins.0.insert(0, cte_join);
                         log::info!(
@@ -14572,7 +14787,8 @@ pub(crate) fn replace_with_clause_with_cte_reference_v2(
 // transition so the existing callers on this path keep resolving.
 pub(crate) use super::pattern_comprehension_sql::{
     add_join_to_plan_or_union_branches, build_node_id_expr_for_join,
-    build_pattern_comprehension_sql, find_node_id_column_from_schema, find_pc_cte_join_column,
+    build_pattern_comprehension_sql, build_return_pattern_comprehension_sql,
+    find_node_id_column_from_schema, find_pc_cte_join_column,
     generate_and_replace_arraycount_pc_subqueries, generate_pattern_comprehension_cte,
     replace_count_star_placeholders_in_select_or_union, rewrite_logical_expr_aliases,
 };
@@ -1,6 +1,6 @@
 //! Post-hoc Query Plan Optimizer
 //!
-//! Five optimization passes applied after join sorting, before SQL generation:
+//! Optimization passes applied after join sorting, before SQL generation:
 //!
 //! 1. **Dead CTE elimination**: Removes CTEs that are never referenced by any
 //!    later CTE or the outer query.
@@ -26,15 +26,36 @@
 //!    promotes that table to FROM position so ClickHouse filters early instead of
 //!    processing millions of rows through chained LEFT JOINs. Re-roots the join
 //!    dependency tree and redistributes ON conditions along the path.
+//!
+//! 7. **Grouping-key `anyLast` unwrapping**: Entity and CTE expansion wraps
+//!    every non-ID column in `anyLast()` because it cannot see the GROUP BY.
+//!    A column that is itself a grouping key is returned bare.
+//!
+//! 8. **Optional aggregate pre-aggregation**: `OPTIONAL MATCH (u)-[:POSTED]->(p)
+//!    WITH u, count(p) AS posts` joins every edge row to its anchor and then
+//!    groups. When the aggregates only read the optional pattern, it is
+//!    aggregated once per anchor id in its own CTE and LEFT JOINed instead,
+//!    with `count`/`sum` defaulting to 0 for anchors without a match.
+//!
+//! 9. **Scan predicate pushdown**: Tables read with FINAL or under a schema
+//!    `filter` get their WHERE predicates (the filter, property and label
+//!    predicates) moved onto their own scan, so the rows are cut before the
+//!    join instead of after it. FINAL tables are also marked FINAL when
+//!    joined, not only in FROM.
 
 use crate::graph_catalog::expression_parser::PropertyValue;
 use crate::query_planner::logical_plan::LogicalPlan;
 use crate::render_plan::expression_utils::references_alias;
 use crate::render_plan::render_expr::{
-    Operator, OperatorApplication, PropertyAccess, RenderExpr, TableAlias,
+    AggregateFnCall, ColumnAlias, Literal, Operator, OperatorApplication, PropertyAccess,
+    RenderExpr, ScalarFnCall, TableAlias,
 };
 use crate::render_plan::view_table_ref::ViewTableRef;
-use crate::render_plan::{CteContent, FromTableItem, Join, JoinType, RenderPlan};
+use crate::render_plan::{
+    ArrayJoinItem, Cte, CteContent, CteItems, FilterItems, FromTableItem, GroupByExpressions, Join,
+    JoinItems, JoinType, LimitItem, OrderByItems, RenderPlan, SelectItem, SelectItems, SkipItem,
+    UnionItems,
+};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
@@ -190,9 +211,17 @@ pub fn optimize_plan(plan: &mut RenderPlan) {
         }
     }
 
+    unwrap_group_key_any_last(plan);
+    if let Some(ref mut union) = plan.union.0 {
+        for branch in union.input.iter_mut() {
+            unwrap_group_key_any_last(branch);
+        }
+    }
+
     for cte in plan.ctes.0.iter_mut() {
         if let CteContent::Structured(ref mut cte_plan) = cte.content {
             optimize_joins_in_plan(cte_plan, &empty);
+            unwrap_group_key_any_last(cte_plan);
 
             let cte_parent_aliases = if cte_plan.union.0.is_some() {
                 collect_referenced_aliases(cte_plan)
@@ -206,6 +235,416 @@ pub fn optimize_plan(plan: &mut RenderPlan) {
             }
         }
     }
+
+    pre_aggregate_optional_matches(plan);
+    push_down_scan_predicates(plan);
+}
+
+// ─── Grouping-Key anyLast Unwrapping ─────────────────────────────────────────
+
+/// Replace `anyLast(k)` select items by `k` when `k` is one of the plan's
+/// GROUP BY expressions: every row of a group has the same `k`, so the
+/// wrapper only hides that the column is a key.
+///
+/// A key naming the item's alias (`WITH u, u.country AS c RETURN c, count(u)`
+/// groups by `c`) is replaced by `k` too: the alias would otherwise resolve
+/// to the `anyLast` aggregate, which ClickHouse refuses in GROUP BY.
+fn unwrap_group_key_any_last(plan: &mut RenderPlan) {
+    if plan.group_by.0.is_empty() {
+        return;
+    }
+    for item in plan.select.items.iter_mut() {
+        let RenderExpr::AggregateFnCall(agg) = &item.expression else {
+            continue;
+        };
+        if agg.name != "anyLast" || agg.args.len() != 1 {
+            continue;
+        }
+        let key = agg.args[0].clone();
+        if let Some(alias) = &item.col_alias {
+            for group_expr in plan.group_by.0.iter_mut() {
+                let names_alias = match group_expr {
+                    RenderExpr::TableAlias(a) => a.0 == alias.0,
+                    RenderExpr::ColumnAlias(a) => a.0 == alias.0,
+                    _ => false,
+                };
+                if names_alias {
+                    *group_expr = key.clone();
+                }
+            }
+        }
+        if plan.group_by.0.contains(&key) {
+            item.expression = key;
+        }
+    }
+}
+
+// ─── Optional Aggregate Pre-aggregation ──────────────────────────────────────
+
+/// Prefix of the CTEs holding a pre-aggregated optional pattern.
+const OPTIONAL_AGG_PREFIX: &str = "__optional_agg_";
+
+/// Move the optional pattern of every eligible aggregating CTE (and of the
+/// outer query) into a CTE grouped by the anchor's join key, placed just
+/// before its reader.
+fn pre_aggregate_optional_matches(plan: &mut RenderPlan) {
+    let mut next = 0;
+    let mut ctes = Vec::with_capacity(plan.ctes.0.len());
+    for mut cte in std::mem::take(&mut plan.ctes.0) {
+        if let CteContent::Structured(ref mut cte_plan) = cte.content {
+            if let Some(agg) = pre_aggregate_optional_match(cte_plan, next) {
+                next += 1;
+                ctes.push(agg);
+            }
+        }
+        ctes.push(cte);
+    }
+    if let Some(agg) = pre_aggregate_optional_match(plan, next) {
+        ctes.push(agg);
+    }
+    plan.ctes.0 = ctes;
+}
+
+/// Aggregates that are the same over an anchor's rows however often each
+/// row repeats, so they survive the anchor no longer being fanned out.
+fn is_duplicate_insensitive(agg: &AggregateFnCall) -> bool {
+    matches!(
+        agg.name.to_ascii_lowercase().as_str(),
+        "anylast" | "any" | "min" | "max"
+    )
+}
+
+/// Rewrite `FROM a LEFT JOIN <optional pattern> ... GROUP BY <a's key>` so
+/// the optional pattern's aggregates come from one pre-aggregated row per
+/// key, returning the CTE that computes them.
+///
+/// Applies only when the result cannot change: every join is a LEFT JOIN of
+/// one chain hanging off `a`, the anchor side of that chain's ON is grouped
+/// on, the aggregates read nothing but the chain, and nothing else (WHERE,
+/// keys, ORDER BY, other select items) reads the chain. Inside the CTE the
+/// chain is INNER joined: an OPTIONAL MATCH matches whole or not at all.
+fn pre_aggregate_optional_match(plan: &mut RenderPlan, index: usize) -> Option<Cte> {
+    if plan.group_by.0.is_empty()
+        || plan.union.0.is_some()
+        || !plan.array_join.0.is_empty()
+        || plan.joins.0.is_empty()
+    {
+        return None;
+    }
+    let anchor = plan.from.0.as_ref()?.alias.clone()?;
+    let optional: HashSet<String> = plan.joins.0.iter().map(|j| j.table_alias.clone()).collect();
+
+    let mut head = None;
+    for (i, join) in plan.joins.0.iter().enumerate() {
+        if !matches!(join.join_type, JoinType::Left)
+            || join.graph_rel.is_some()
+            || join.is_cartesian
+            || join.joining_on.is_empty()
+        {
+            return None;
+        }
+        let mut aliases = HashSet::new();
+        for cond in &join.joining_on {
+            for operand in &cond.operands {
+                collect_correlation_aliases_from_expr(operand, &mut aliases);
+            }
+        }
+        if let Some(pre_filter) = &join.pre_filter {
+            if references_alias(pre_filter, &anchor) {
+                return None;
+            }
+        }
+        // Two patterns off the anchor multiply each other's rows
+        if aliases.contains(&anchor) && head.replace(i).is_some() {
+            return None;
+        }
+        if !aliases.iter().all(|a| *a == anchor || optional.contains(a)) {
+            return None;
+        }
+    }
+    let head = head?;
+
+    // ON `inner = anchor_key` equalities, each anchor key grouped on
+    let mut keys = Vec::new();
+    for cond in &plan.joins.0[head].joining_on {
+        if cond.operator != Operator::Equal || cond.operands.len() != 2 {
+            return None;
+        }
+        let (outer, inner) = match (&cond.operands[0], &cond.operands[1]) {
+            (l, r) if references_alias(r, &anchor) && !references_alias(l, &anchor) => (r, l),
+            (l, r) if references_alias(l, &anchor) && !references_alias(r, &anchor) => (l, r),
+            _ => return None,
+        };
+        if !plan.group_by.0.contains(outer) {
+            return None;
+        }
+        keys.push((outer.clone(), inner.clone()));
+    }
+
+    let reads_optional = |expr: &RenderExpr| {
+        let mut aliases = HashSet::new();
+        collect_aliases_from_expr(expr, &mut aliases);
+        aliases.iter().any(|a| optional.contains(a))
+    };
+    if plan.filters.0.as_ref().is_some_and(reads_optional)
+        || plan.having_clause.as_ref().is_some_and(reads_optional)
+        || plan.group_by.0.iter().any(reads_optional)
+        || plan
+            .order_by
+            .0
+            .iter()
+            .any(|o| reads_optional(&o.expression))
+    {
+        return None;
+    }
+    let mut aggregated = Vec::new();
+    for (i, item) in plan.select.items.iter().enumerate() {
+        match &item.expression {
+            RenderExpr::AggregateFnCall(agg) if reads_optional(&item.expression) => {
+                if agg.args.iter().any(|a| references_alias(a, &anchor)) {
+                    return None;
+                }
+                item.col_alias.as_ref()?;
+                aggregated.push(i);
+            }
+            RenderExpr::AggregateFnCall(agg) if !is_duplicate_insensitive(agg) => return None,
+            expr if reads_optional(expr) => return None,
+            _ => {}
+        }
+    }
+    if aggregated.is_empty() {
+        return None;
+    }
+
+    let cte_name = format!("{}{}", OPTIONAL_AGG_PREFIX, index);
+    let mut joins = std::mem::take(&mut plan.joins.0);
+    let first = joins.remove(head);
+    for join in joins.iter_mut() {
+        join.join_type = JoinType::Inner;
+    }
+
+    let mut select = Vec::new();
+    let mut outer_on = Vec::new();
+    let mut group_by = Vec::new();
+    for (i, (outer, inner)) in keys.into_iter().enumerate() {
+        let key = format!("__key{}", i);
+        select.push(SelectItem {
+            expression: inner.clone(),
+            col_alias: Some(ColumnAlias(key.clone())),
+        });
+        group_by.push(inner);
+        outer_on.push(OperatorApplication {
+            operator: Operator::Equal,
+            operands: vec![property(&cte_name, &key), outer],
+        });
+    }
+    for &i in &aggregated {
+        let item = &mut plan.select.items[i];
+        let column = item.col_alias.as_ref().expect("checked above").0.clone();
+        let RenderExpr::AggregateFnCall(agg) = std::mem::replace(
+            &mut item.expression,
+            RenderExpr::AggregateFnCall(AggregateFnCall {
+                name: "anyLast".to_string(),
+                args: vec![property(&cte_name, &column)],
+            }),
+        ) else {
+            unreachable!("aggregated items are aggregate calls");
+        };
+        // No match means no rows: counts and sums are 0, not NULL
+        let name = agg.name.to_ascii_lowercase();
+        if name.starts_with("count") || name.starts_with("sum") {
+            item.expression = RenderExpr::ScalarFnCall(ScalarFnCall {
+                name: "coalesce".to_string(),
+                args: vec![
+                    item.expression.clone(),
+                    RenderExpr::Literal(Literal::Integer(0)),
+                ],
+            });
+        }
+        select.push(SelectItem {
+            expression: RenderExpr::AggregateFnCall(agg),
+            col_alias: Some(ColumnAlias(column)),
+        });
+    }
+
+    plan.joins.0 = vec![Join {
+        table_name: cte_name.clone(),
+        table_alias: cte_name.clone(),
+        joining_on: outer_on,
+        join_type: JoinType::Left,
+        pre_filter: None,
+        from_id_column: None,
+        to_id_column: None,
+        graph_rel: None,
+        is_cartesian: false,
+        use_final: false,
+    }];
+
+    let body = RenderPlan {
+        ctes: CteItems(vec![]),
+        select: SelectItems {
+            items: select,
+            distinct: false,
+        },
+        from: FromTableItem(Some(ViewTableRef {
+            source: Arc::new(LogicalPlan::Empty),
+            name: first.table_name,
+            alias: Some(first.table_alias),
+            use_final: false,
+        })),
+        joins: JoinItems(joins),
+        array_join: ArrayJoinItem(vec![]),
+        filters: FilterItems(first.pre_filter),
+        group_by: GroupByExpressions(group_by),
+        having_clause: None,
+        order_by: OrderByItems(vec![]),
+        skip: SkipItem(None),
+        limit: LimitItem(None),
+        union: UnionItems(None),
+        fixed_path_info: None,
+        is_multi_label_scan: false,
+        variable_registry: plan.variable_registry.clone(),
+    };
+    let mut cte = Cte::new(cte_name, CteContent::Structured(Box::new(body)), false);
+    cte.variable_registry = plan.variable_registry.clone();
+    Some(cte)
+}
+
+fn property(alias: &str, column: &str) -> RenderExpr {
+    RenderExpr::PropertyAccessExp(PropertyAccess {
+        table_alias: TableAlias(alias.to_string()),
+        column: PropertyValue::Column(column.to_string()),
+    })
+}
+
+// ─── Scan Predicate Pushdown ─────────────────────────────────────────────────
+
+/// Base tables whose scans take pushed-down predicates: those read with FINAL
+/// and those under a schema `filter`, keyed by qualified and bare name, with
+/// whether the table is read with FINAL.
+fn pushdown_tables() -> HashMap<String, bool> {
+    let mut tables: HashMap<String, bool> = HashMap::new();
+    let Some(schema) = crate::server::query_context::get_current_schema_with_fallback() else {
+        return tables;
+    };
+    let scans = schema
+        .all_node_schemas()
+        .values()
+        .map(|n| {
+            (
+                &n.database,
+                &n.table_name,
+                n.should_use_final(),
+                n.filter.is_some(),
+            )
+        })
+        .chain(schema.get_relationships_schemas().values().map(|r| {
+            (
+                &r.database,
+                &r.table_name,
+                r.should_use_final(),
+                r.filter.is_some(),
+            )
+        }));
+    for (database, table, use_final, filtered) in scans {
+        if !use_final && !filtered {
+            continue;
+        }
+        for name in [format!("{}.{}", database, table), table.clone()] {
+            *tables.entry(name).or_default() |= use_final;
+        }
+    }
+    tables
+}
+
+/// Push WHERE predicates into the scans of FINAL and filtered tables, in the
+/// outer query, its UNION branches and every structured CTE.
+fn push_down_scan_predicates(plan: &mut RenderPlan) {
+    let tables = pushdown_tables();
+    if tables.is_empty() {
+        return;
+    }
+    push_down_scan_predicates_in(plan, &tables);
+    for cte in plan.ctes.0.iter_mut() {
+        if let CteContent::Structured(ref mut cte_plan) = cte.content {
+            push_down_scan_predicates_in(cte_plan, &tables);
+        }
+    }
+}
+
+/// Mark the FROM and joins of FINAL tables, then move each WHERE conjunct that reads
+/// nothing but one INNER-joined FINAL or filtered table into that join's
+/// `pre_filter`. A FINAL table renders it inside its scan subquery (after
+/// the merge, before the join); any other table adds it to its ON clause,
+/// which ClickHouse applies while reading the table. The table's schema
+/// filter is such a conjunct, as are its property and label predicates.
+fn push_down_scan_predicates_in(plan: &mut RenderPlan, tables: &HashMap<String, bool>) {
+    if let Some(ref mut union) = plan.union.0 {
+        for branch in union.input.iter_mut() {
+            push_down_scan_predicates_in(branch, tables);
+        }
+    }
+    let RenderPlan {
+        from,
+        joins,
+        filters,
+        ..
+    } = plan;
+    if let Some(from) = from.0.as_mut() {
+        from.use_final |= tables.get(from.name.as_str()) == Some(&true);
+    }
+    for join in joins.0.iter_mut() {
+        if join.graph_rel.is_some() || join.is_cartesian {
+            continue;
+        }
+        let Some(&use_final) = tables.get(join.table_name.as_str()) else {
+            continue;
+        };
+        join.use_final = use_final;
+        if !matches!(join.join_type, JoinType::Inner | JoinType::Join) || join.joining_on.is_empty()
+        {
+            continue;
+        }
+        let Some(filter) = filters.0.take() else {
+            continue;
+        };
+        let (pushed, kept): (Vec<_>, Vec<_>) = split_top_level_and(&filter)
+            .into_iter()
+            .partition(|conjunct| reads_only_scan(conjunct, &join.table_alias));
+        filters.0 = combine_and_conjuncts(kept);
+        if !pushed.is_empty() {
+            join.pre_filter =
+                combine_and_conjuncts(join.pre_filter.take().into_iter().chain(pushed).collect());
+        }
+    }
+}
+
+/// Whether `expr` is a row predicate over `alias`'s columns alone, so it
+/// means the same evaluated on the table's rows as on the joined rows.
+fn reads_only_scan(expr: &RenderExpr, alias: &str) -> bool {
+    fn is_row_local(expr: &RenderExpr) -> bool {
+        match expr {
+            RenderExpr::Literal(_)
+            | RenderExpr::Parameter(_)
+            | RenderExpr::Raw(_)
+            | RenderExpr::PropertyAccessExp(_) => true,
+            RenderExpr::OperatorApplicationExp(op) => op.operands.iter().all(is_row_local),
+            RenderExpr::ScalarFnCall(f) => f.args.iter().all(is_row_local),
+            RenderExpr::List(items) => items.iter().all(is_row_local),
+            RenderExpr::Case(case) => {
+                case.expr.as_deref().is_none_or(is_row_local)
+                    && case
+                        .when_then
+                        .iter()
+                        .all(|(when, then)| is_row_local(when) && is_row_local(then))
+                    && case.else_expr.as_deref().is_none_or(is_row_local)
+            }
+            _ => false,
+        }
+    }
+    let mut aliases = HashSet::new();
+    collect_aliases_from_expr(expr, &mut aliases);
+    is_row_local(expr) && aliases.len() == 1 && aliases.contains(alias)
 }
 
 // ─── VLP Column Pruning ───────────────────────────────────────────────────────
@@ -454,11 +893,9 @@ fn prune_cte_columns(plan: &mut RenderPlan) {
     loop {
         let mut changed = false;
 
+        // CTEs whose SELECT items are all unaliased pass-throughs (`WITH c, n`)
+        // define no prunable columns but still consume upstream ones.
         for cte_name in cte_names.iter().rev() {
-            if !cte_output_columns.contains_key(cte_name) {
-                continue;
-            }
-
             let cte_plan = plan.ctes.0.iter().find(|c| c.cte_name == *cte_name);
             let inner_plan = match cte_plan {
                 Some(cte) => match &cte.content {
@@ -725,6 +1162,11 @@ fn collect_bare_aliases_from_expr(
                 collect_bare_aliases_from_expr(item, known_columns, refs);
             }
         }
+        RenderExpr::MapLiteral(entries) => {
+            for (_, value) in entries {
+                collect_bare_aliases_from_expr(value, known_columns, refs);
+            }
+        }
         RenderExpr::ArraySubscript { array, index } => {
             collect_bare_aliases_from_expr(array, known_columns, refs);
             collect_bare_aliases_from_expr(index, known_columns, refs);
@@ -775,8 +1217,10 @@ fn collect_cte_internal_column_refs(plan: &RenderPlan, alias: &str, columns: &mu
     // expressions that reference the upstream alias (e.g., `score + CASE WHEN ...`)
     for item in &plan.select.items {
         if let RenderExpr::PropertyAccessExp(pa) = &item.expression {
-            if pa.table_alias.0 == alias {
-                // Simple carry-forward: person1.p7_X AS "p7_X" — don't count this
+            if pa.table_alias.0 == alias && item.col_alias.is_some() {
+                // Simple carry-forward: person1.p7_X AS "p7_X" — don't count this.
+                // Unaliased pass-throughs of scalars (`c_n.c`) are never pruned,
+                // so their upstream column is always needed.
                 continue;
             }
         }
@@ -873,6 +1317,11 @@ fn collect_alias_refs_in_expr(
                 collect_alias_refs_in_expr(item, output_columns, refs);
             }
         }
+        RenderExpr::MapLiteral(entries) => {
+            for (_, value) in entries {
+                collect_alias_refs_in_expr(value, output_columns, refs);
+            }
+        }
         RenderExpr::ArraySubscript { array, index } => {
             collect_alias_refs_in_expr(array, output_columns, refs);
             collect_alias_refs_in_expr(index, output_columns, refs);
@@ -1277,6 +1726,11 @@ fn collect_bare_vlp_refs_in_expr(
                 collect_bare_vlp_refs_in_expr(item, vlp_columns, used);
             }
         }
+        RenderExpr::MapLiteral(entries) => {
+            for (_, value) in entries {
+                collect_bare_vlp_refs_in_expr(value, vlp_columns, used);
+            }
+        }
         RenderExpr::InSubquery(subq) => {
             collect_bare_vlp_refs_in_expr(&subq.expr, vlp_columns, used);
         }
@@ -1797,6 +2251,7 @@ fn fold_optional_edge_node_join_with_predicate(plan: &mut RenderPlan) {
                 to_id_column: None,
                 graph_rel: None,
                 is_cartesian: false,
+                use_final: false,
             };
 
             // Replace the node join in place, drop the edge join.
@@ -2563,6 +3018,9 @@ fn has_unresolved_bare_ref(expr: &RenderExpr, alias: &str) -> bool {
                     .is_some_and(|e| has_unresolved_bare_ref(e, alias))
         }
         RenderExpr::List(items) => items.iter().any(|i| has_unresolved_bare_ref(i, alias)),
+        RenderExpr::MapLiteral(entries) => entries
+            .iter()
+            .any(|(_, v)| has_unresolved_bare_ref(v, alias)),
         RenderExpr::InSubquery(subq) => has_unresolved_bare_ref(&subq.expr, alias),
         _ => false,
     }
@@ -2839,6 +3297,9 @@ fn expr_has_correlated_ref(expr: &RenderExpr, alias: &str) -> bool {
                     .is_some_and(|e| expr_has_correlated_ref(e, alias))
         }
         RenderExpr::List(items) => items.iter().any(|i| expr_has_correlated_ref(i, alias)),
+        RenderExpr::MapLiteral(entries) => entries
+            .iter()
+            .any(|(_, v)| expr_has_correlated_ref(v, alias)),
         RenderExpr::ArraySubscript { array, index } => {
             expr_has_correlated_ref(array, alias) || expr_has_correlated_ref(index, alias)
         }
@@ -2887,6 +3348,23 @@ fn has_non_id_column_ref(expr: &RenderExpr, alias: &str, id_column: &str) -> boo
         RenderExpr::List(items) => items
             .iter()
             .any(|i| has_non_id_column_ref(i, alias, id_column)),
+        RenderExpr::MapLiteral(entries) => entries
+            .iter()
+            .any(|(_, v)| has_non_id_column_ref(v, alias, id_column)),
+        // `collect(m.name)[0]`: the subscripted array still reads the node row
+        RenderExpr::ArraySubscript { array, index } => {
+            has_non_id_column_ref(array, alias, id_column)
+                || has_non_id_column_ref(index, alias, id_column)
+        }
+        RenderExpr::ArraySlicing { array, from, to } => {
+            has_non_id_column_ref(array, alias, id_column)
+                || from
+                    .as_deref()
+                    .is_some_and(|f| has_non_id_column_ref(f, alias, id_column))
+                || to
+                    .as_deref()
+                    .is_some_and(|t| has_non_id_column_ref(t, alias, id_column))
+        }
         RenderExpr::InSubquery(subq) => has_non_id_column_ref(&subq.expr, alias, id_column),
         // Raw SQL strings may contain alias references we can't structurally analyze.
         // Conservatively treat any mention of the alias as a non-ID reference.
@@ -3238,6 +3716,7 @@ mod tests {
             to_id_column: None,
             graph_rel: None,
             is_cartesian: false,
+            use_final: false,
         }
     }
 
@@ -3259,6 +3738,7 @@ mod tests {
             to_id_column: Some(to_id.to_string()),
             graph_rel: None,
             is_cartesian: false,
+            use_final: false,
         }
     }
 
@@ -3537,6 +4017,7 @@ mod tests {
         plan.order_by.0.push(OrderByItem {
             expression: prop("person1", "id"),
             order: OrderByOrder::Asc,
+            collation: None,
         });
 
         eliminate_bridge_nodes_in_plan(&mut plan, &HashSet::new());
@@ -3982,6 +4463,7 @@ mod tests {
             to_id_column: None,
             graph_rel: None,
             is_cartesian: false,
+            use_final: false,
         }
     }
 
@@ -4154,6 +4636,99 @@ mod tests {
             "parameter should be treated as constant"
         );
     }
+
+    // =========================================================================
+    // Pass 8: Optional aggregate pre-aggregation tests
+    // =========================================================================
+
+    /// `FROM users AS u` + LEFT JOINs, `count(<alias>.id) AS n` per join,
+    /// grouped by `u.user_id`
+    fn optional_count_plan(joins: Vec<Join>) -> RenderPlan {
+        let counts = joins
+            .iter()
+            .map(|j| {
+                RenderExpr::AggregateFnCall(AggregateFnCall {
+                    name: "count".to_string(),
+                    args: vec![prop(&j.table_alias, "id")],
+                })
+            })
+            .collect();
+        let mut plan = make_plan(joins, counts);
+        for item in plan.select.items.iter_mut() {
+            item.col_alias = Some(ColumnAlias("n".to_string()));
+        }
+        plan.from = make_from("db.users", "u");
+        plan.group_by = GroupByExpressions(vec![prop("u", "user_id")]);
+        plan
+    }
+
+    #[test]
+    fn test_optional_aggregate_pre_aggregated_per_anchor() {
+        let mut plan = optional_count_plan(vec![node_join(
+            "t1",
+            "db.posted",
+            vec![eq_on(prop("t1", "user_id"), prop("u", "user_id"))],
+        )]);
+
+        let cte = pre_aggregate_optional_match(&mut plan, 0).expect("rewritten");
+
+        let CteContent::Structured(body) = &cte.content else {
+            panic!("expected a structured CTE");
+        };
+        assert_eq!(body.from.0.as_ref().unwrap().name, "db.posted");
+        assert_eq!(body.group_by.0, vec![prop("t1", "user_id")]);
+        assert_eq!(plan.joins.0.len(), 1);
+        assert_eq!(plan.joins.0[0].table_name, "__optional_agg_0");
+        assert_eq!(
+            plan.joins.0[0].joining_on,
+            vec![eq_on(
+                prop("__optional_agg_0", "__key0"),
+                prop("u", "user_id")
+            )]
+        );
+        // Anchors without posts count 0
+        assert_eq!(
+            plan.select.items[0].expression,
+            RenderExpr::ScalarFnCall(ScalarFnCall {
+                name: "coalesce".to_string(),
+                args: vec![
+                    RenderExpr::AggregateFnCall(AggregateFnCall {
+                        name: "anyLast".to_string(),
+                        args: vec![prop("__optional_agg_0", "n")],
+                    }),
+                    RenderExpr::Literal(Literal::Integer(0)),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_optional_aggregate_not_rewritten_when_rows_multiply() {
+        // Two patterns off the anchor: each count is multiplied by the other's
+        let mut plan = optional_count_plan(vec![
+            node_join(
+                "t1",
+                "db.posted",
+                vec![eq_on(prop("t1", "user_id"), prop("u", "user_id"))],
+            ),
+            node_join(
+                "t2",
+                "db.follows",
+                vec![eq_on(prop("t2", "follower_id"), prop("u", "user_id"))],
+            ),
+        ]);
+        assert!(pre_aggregate_optional_match(&mut plan, 0).is_none());
+
+        // Grouped on something other than the join key
+        let mut plan = optional_count_plan(vec![node_join(
+            "t1",
+            "db.posted",
+            vec![eq_on(prop("t1", "user_id"), prop("u", "user_id"))],
+        )]);
+        plan.group_by = GroupByExpressions(vec![prop("u", "name")]);
+        assert!(pre_aggregate_optional_match(&mut plan, 0).is_none());
+        assert_eq!(plan.joins.0[0].table_alias, "t1");
+    }
 }
 
 // =============================================================================
@@ -4270,6 +4845,7 @@ fn reorder_from_for_selective_predicate(plan: &mut RenderPlan) {
                 to_id_column: None,
                 graph_rel: None,
                 is_cartesian: false,
+                use_final: false,
             };
             plan.joins.0.push(new_join);
         } else if let Some(join) = plan.joins.0.iter_mut().find(|j| j.table_alias == *alias) {
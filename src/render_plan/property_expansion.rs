@@ -248,7 +248,7 @@ pub fn expand_alias_to_projection_items_unified(
 
             let base_expr = LogicalExpr::PropertyAccessExp(PropertyAccess {
                 table_alias: table_alias.clone(),
-                column: PropertyValue::Column(prop.column_name),
+                column: PropertyValue::from_raw(prop.column_name),
             });
 
             let expr = if prop.needs_anylast_wrap {
@@ -336,7 +336,7 @@ pub fn expand_alias_to_select_items_unified(
 
             let base_expr = RenderExpr::PropertyAccessExp(RenderPropertyAccess {
                 table_alias: table_alias.clone(),
-                column: PropertyValue::Column(prop.column_name),
+                column: PropertyValue::from_raw(prop.column_name),
             });
 
             let expr = if prop.needs_anylast_wrap {
@@ -419,7 +419,7 @@ pub fn expand_alias_to_properties(
             ProjectionItem {
                 expression: LogicalExpr::PropertyAccessExp(PropertyAccess {
                     table_alias: table_alias_to_use.clone(),
-                    column: PropertyValue::Column(col_name),
+                    column: PropertyValue::from_raw(col_name),
                 }),
                 col_alias: Some(ColumnAlias(col_alias_name)),
             }
@@ -463,7 +463,7 @@ pub fn expand_alias_to_select_items(
         .map(|(prop_name, col_name)| SelectItem {
             expression: RenderExpr::PropertyAccessExp(RenderPropertyAccess {
                 table_alias: RenderTableAlias(table_alias_to_use.clone()),
-                column: PropertyValue::Column(col_name),
+                column: PropertyValue::from_raw(col_name),
             }),
             col_alias: Some(RenderColumnAlias(cte_column_name(alias, &prop_name))),
         })
@@ -540,7 +540,7 @@ pub fn expand_collect_to_group_array(
         .map(|(_prop_name, col_name)| {
             LogicalExpr::PropertyAccessExp(PropertyAccess {
                 table_alias: TableAlias(alias.to_string()),
-                column: PropertyValue::Column(col_name),
+                column: PropertyValue::from_raw(col_name),
             })
         })
         .collect();
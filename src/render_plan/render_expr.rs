@@ -803,15 +803,37 @@ fn pattern_count_correlated_aliases(pattern: &PathPattern) -> Vec<String> {
     }
 }
 
-/// Generate SQL for a pattern count (size() on patterns)
+/// Generate SQL for a pattern count (size() on patterns, `COUNT { ... }`)
 ///
 /// For `size((n)-[:REL]->())` pattern, generates:
 /// ```sql
 /// (SELECT COUNT(*) FROM rel_table WHERE rel_table.from_id = n.id)
 /// ```
-fn generate_pattern_count_sql(pattern: &PathPattern) -> Result<String, RenderBuildError> {
+///
+/// A `COUNT { ... WHERE ... }` predicate goes through
+/// [`generate_filtered_pattern_count_sql`], an untyped single hop
+/// (`COUNT { (n)--() }`) through [`generate_untyped_pattern_count_sql`].
+fn generate_pattern_count_sql(
+    pattern: &PathPattern,
+    where_clause: Option<&LogicalExpr>,
+) -> Result<String, RenderBuildError> {
     use crate::server::query_context::get_current_schema;
 
+    if let PathPattern::ConnectedPattern(connected_patterns) = pattern {
+        if let Some(predicate) = where_clause {
+            return generate_filtered_pattern_count_sql(connected_patterns, predicate);
+        }
+        if let [conn] = connected_patterns.as_slice() {
+            if conn.relationship.labels.is_none() {
+                return generate_untyped_pattern_count_sql(conn);
+            }
+        }
+    } else if where_clause.is_some() {
+        return Err(RenderBuildError::UnsupportedFeature(
+            "COUNT { ... WHERE ... } needs a relationship pattern".to_string(),
+        ));
+    }
+
     match pattern {
         PathPattern::ConnectedPattern(connected_patterns) => {
             if connected_patterns.is_empty() {
@@ -965,6 +987,301 @@ fn generate_pattern_count_sql(pattern: &PathPattern) -> Result<String, RenderBui
     }
 }
 
+/// The anchor (first node) of a counted pattern: its alias, schema and the
+/// correlated id SQL.
+fn pattern_count_anchor<'a>(
+    conn: &ConnectedPattern,
+    schema: &'a crate::graph_catalog::graph_schema::GraphSchema,
+    fallback_label: Option<&str>,
+) -> Result<
+    (
+        String,
+        &'a crate::graph_catalog::graph_schema::NodeSchema,
+        String,
+    ),
+    RenderBuildError,
+> {
+    let alias = conn.start_node.name.clone().ok_or_else(|| {
+        RenderBuildError::InvalidRenderPlan(
+            "COUNT { } pattern requires a named start node".to_string(),
+        )
+    })?;
+    let label = conn
+        .start_node
+        .label
+        .as_deref()
+        .or(fallback_label)
+        .ok_or_else(|| {
+            RenderBuildError::InvalidRenderPlan(format!(
+                "Cannot count the untyped relationships of '{alias}': its label is unknown. \
+                 Give the node a label, e.g. COUNT {{ ({alias}:Label)--() }}"
+            ))
+        })?;
+    let node_schema = schema
+        .node_schema_opt(label)
+        .ok_or_else(|| RenderBuildError::NodeSchemaNotFound(label.to_string()))?;
+    let id_sql = resolve_correlation_id_sql(&alias, node_schema);
+    Ok((alias, node_schema, id_sql))
+}
+
+/// `table.col = 'value'` filters selecting one relationship type's rows out
+/// of a table it shares with other types.
+fn pattern_count_discriminators(
+    rel_schema: &crate::graph_catalog::graph_schema::RelationshipSchema,
+    rel_type: &str,
+    table_alias: &str,
+) -> Vec<String> {
+    use crate::procedures::graph_quality::sql_string;
+
+    rel_schema
+        .row_discriminators(rel_type)
+        .into_iter()
+        .map(|(column, value)| format!("{}.{} = {}", table_alias, column, sql_string(value)))
+        .collect()
+}
+
+/// Count an untyped single hop, `COUNT { (n)--() }` / `size((n)-->())`: one
+/// correlated count per edge table (and side) the anchor's label sits on,
+/// summed. An end-node label narrows the tables to those reaching it.
+///
+/// ```sql
+/// (coalesce((SELECT COUNT(*) FROM db.follows WHERE follows.follower_id = n.user_id), 0)
+///  + coalesce((SELECT COUNT(*) FROM db.follows WHERE follows.followed_id = n.user_id), 0))
+/// ```
+///
+/// Each term is coalesced on its own: decorrelation turns every scalar
+/// subquery into a LEFT JOIN, and one NULL term would null the whole sum.
+fn generate_untyped_pattern_count_sql(conn: &ConnectedPattern) -> Result<String, RenderBuildError> {
+    use crate::server::query_context::get_current_schema;
+
+    let schema = get_current_schema().ok_or_else(|| {
+        RenderBuildError::InvalidRenderPlan("No schema available for COUNT { } pattern".to_string())
+    })?;
+    let (_, _, anchor_id) = pattern_count_anchor(conn, &schema, None)?;
+    let label = conn.start_node.label.as_deref().unwrap_or_default();
+    let end_label = conn.end_node.label.as_deref();
+    let (outgoing, incoming) = match conn.relationship.direction {
+        Direction::Outgoing => (true, false),
+        Direction::Incoming => (false, true),
+        Direction::Either => (true, true),
+    };
+
+    // A type registered under several keys yields the same term; keep one.
+    let mut terms = std::collections::BTreeSet::new();
+    for (key, rel_schema) in schema.get_relationships_schemas() {
+        let rel_type = key.split("::").next().unwrap_or(key);
+        let table = &rel_schema.table_name;
+        let sides = [
+            (
+                outgoing,
+                &rel_schema.from_node,
+                &rel_schema.to_node,
+                &rel_schema.from_id,
+            ),
+            (
+                incoming,
+                &rel_schema.to_node,
+                &rel_schema.from_node,
+                &rel_schema.to_id,
+            ),
+        ];
+        for (wanted, anchor_node, far_node, anchor_col) in sides {
+            if !wanted || anchor_node != label || end_label.is_some_and(|l| l != far_node) {
+                continue;
+            }
+            let mut conds = vec![format!(
+                "{} = {}",
+                anchor_col.to_sql_tuple(table),
+                anchor_id
+            )];
+            conds.extend(pattern_count_discriminators(rel_schema, rel_type, table));
+            terms.insert(format!(
+                "coalesce((SELECT COUNT(*) FROM {}.{} WHERE {}), 0)",
+                rel_schema.database,
+                table,
+                conds.join(" AND ")
+            ));
+        }
+    }
+
+    if terms.is_empty() {
+        return Ok("0".to_string());
+    }
+    Ok(format!(
+        "({})",
+        terms.into_iter().collect::<Vec<_>>().join(" + ")
+    ))
+}
+
+/// Count a single directed hop with an inner predicate,
+/// `COUNT { (n)-[r:R]->(m) WHERE ... }`. The end node's table is joined only
+/// when the predicate reads it.
+///
+/// ```sql
+/// (SELECT COUNT(*) FROM db.follows AS r
+///  INNER JOIN db.users AS m ON m.user_id = r.followed_id
+///  WHERE r.follower_id = n.user_id AND (m.age > 30))
+/// ```
+///
+/// Multi-hop, undirected and untyped patterns are rejected rather than
+/// counted under a narrower pattern than the one written.
+fn generate_filtered_pattern_count_sql(
+    connected_patterns: &[ConnectedPattern],
+    predicate: &LogicalExpr,
+) -> Result<String, RenderBuildError> {
+    use crate::query_planner::logical_expr::visitors::TableAliasCollector;
+    use crate::server::query_context::get_current_schema;
+
+    let unsupported = |what: &str| {
+        RenderBuildError::UnsupportedFeature(format!(
+            "COUNT {{ ... WHERE ... }} over {what} is not supported yet; \
+             count with a top-level MATCH ... WHERE ... and count(*) instead"
+        ))
+    };
+    let [conn] = connected_patterns else {
+        return Err(unsupported("a multi-hop pattern"));
+    };
+    let outgoing = match conn.relationship.direction {
+        Direction::Outgoing => true,
+        Direction::Incoming => false,
+        Direction::Either => return Err(unsupported("an undirected relationship")),
+    };
+    let rel_type = conn
+        .relationship
+        .labels
+        .as_ref()
+        .and_then(|l| l.first())
+        .ok_or_else(|| unsupported("an untyped relationship"))?;
+
+    let schema = get_current_schema().ok_or_else(|| {
+        RenderBuildError::InvalidRenderPlan("No schema available for COUNT { } pattern".to_string())
+    })?;
+    let rel_schema = schema
+        .get_relationships_schema_opt(rel_type)
+        .ok_or_else(|| {
+            RenderBuildError::InvalidRenderPlan(format!(
+                "Cannot generate COUNT {{ }} pattern: relationship schema '{}' not found",
+                rel_type
+            ))
+        })?;
+    let (anchor_node, far_node, anchor_col, far_col) = if outgoing {
+        (
+            &rel_schema.from_node,
+            &rel_schema.to_node,
+            &rel_schema.from_id,
+            &rel_schema.to_id,
+        )
+    } else {
+        (
+            &rel_schema.to_node,
+            &rel_schema.from_node,
+            &rel_schema.to_id,
+            &rel_schema.from_id,
+        )
+    };
+    let (anchor_alias, anchor_schema, anchor_id) =
+        pattern_count_anchor(conn, &schema, Some(anchor_node))?;
+
+    let edge_alias = conn
+        .relationship
+        .name
+        .clone()
+        .unwrap_or_else(|| rel_schema.table_name.clone());
+    let mut from_sql = format!(
+        "{}.{} AS {}",
+        rel_schema.database, rel_schema.table_name, edge_alias
+    );
+    let mut mappings = vec![
+        (anchor_alias.as_str(), &anchor_schema.property_mappings),
+        (edge_alias.as_str(), &rel_schema.property_mappings),
+    ];
+
+    let referenced = TableAliasCollector::collect(predicate);
+    let far_alias = conn
+        .end_node
+        .name
+        .as_deref()
+        .filter(|alias| referenced.iter().any(|r| r == alias));
+    if let Some(far_alias) = far_alias {
+        let far_label = conn.end_node.label.as_deref().unwrap_or(far_node);
+        if !rel_schema.is_plain_edge_table() {
+            return Err(unsupported("a node stored on its edge table"));
+        }
+        let far_schema = schema
+            .node_schema_opt(far_label)
+            .ok_or_else(|| RenderBuildError::NodeSchemaNotFound(far_label.to_string()))?;
+        from_sql.push_str(&format!(
+            " INNER JOIN {}.{} AS {} ON {} = {}",
+            far_schema.database,
+            far_schema.table_name,
+            far_alias,
+            far_schema.node_id.sql_tuple(far_alias),
+            far_col.to_sql_tuple(&edge_alias)
+        ));
+        mappings.push((far_alias, &far_schema.property_mappings));
+    }
+
+    let mut conds = vec![format!(
+        "{} = {}",
+        anchor_col.to_sql_tuple(&edge_alias),
+        anchor_id
+    )];
+    conds.extend(pattern_count_discriminators(
+        rel_schema,
+        rel_type,
+        &edge_alias,
+    ));
+    let mapped = map_pattern_count_predicate(predicate, &anchor_alias, &mappings);
+    conds.push(format!("({})", RenderExpr::try_from(mapped)?.to_sql()));
+
+    Ok(format!(
+        "(SELECT COUNT(*) FROM {} WHERE {})",
+        from_sql,
+        conds.join(" AND ")
+    ))
+}
+
+/// Map the property references of a `COUNT { ... WHERE ... }` predicate to
+/// columns: each pattern alias through its schema's property mappings, and
+/// the outer anchor through its WITH-CTE column when it crossed a WITH.
+fn map_pattern_count_predicate(
+    predicate: &LogicalExpr,
+    anchor_alias: &str,
+    mappings: &[(&str, &std::collections::HashMap<String, PropertyValue>)],
+) -> LogicalExpr {
+    use crate::query_planner::logical_expr::visitors::{map_expression, ExprRewrite};
+    use crate::server::query_context::resolve_correlation_cte_column;
+
+    map_expression(predicate, &mut |expr| {
+        let LogicalExpr::PropertyAccessExp(prop) = expr else {
+            return ExprRewrite::Recurse;
+        };
+        let alias = prop.table_alias.0.as_str();
+        let property = prop.column.raw();
+        if alias == anchor_alias {
+            if let Some((sql_alias, column)) = resolve_correlation_cte_column(alias, property) {
+                return ExprRewrite::Replace(LogicalExpr::PropertyAccessExp(
+                    LogicalPropertyAccess {
+                        table_alias: LogicalTableAlias(sql_alias),
+                        column: PropertyValue::Column(column),
+                    },
+                ));
+            }
+        }
+        let mapped = mappings
+            .iter()
+            .find(|(a, _)| *a == alias)
+            .and_then(|(_, m)| m.get(property));
+        ExprRewrite::Replace(match mapped {
+            Some(column) => LogicalExpr::PropertyAccessExp(LogicalPropertyAccess {
+                table_alias: prop.table_alias.clone(),
+                column: column.clone(),
+            }),
+            None => expr.clone(),
+        })
+    })
+}
+
 /// Generate NOT EXISTS SQL for a PathPattern (negative pattern matching / anti-join)
 ///
 /// For `NOT (a)-[:REL]-(b)` pattern, generates:
@@ -1696,7 +2013,7 @@ impl TryFrom<LogicalExpr> for RenderExpr {
                 // outer row with zero pattern matches yields NULL rather than 0;
                 // size() must return 0 there (#599). coalesce is dialect-neutral.
                 let correlated_aliases = pattern_count_correlated_aliases(&pc.pattern);
-                let sql = generate_pattern_count_sql(&pc.pattern)?;
+                let sql = generate_pattern_count_sql(&pc.pattern, pc.where_clause.as_deref())?;
                 RenderExpr::PatternCount(PatternCount {
                     sql: format!("coalesce({}, 0)", sql),
                     correlated_aliases,
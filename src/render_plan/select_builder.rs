@@ -177,6 +177,41 @@ pub(crate) fn properties_for_registered_edge(
     }
 }
 
+/// The properties a whole returned node projects: its label's
+/// `return_properties` / `max_return_columns` subset of `properties`, or all
+/// of them for single-label nodes without one and for `RETURN n { .* }`.
+fn return_projected_properties(
+    alias: &str,
+    typed_var: &TypedVariable,
+    properties: Vec<(String, String)>,
+    plan_ctx: Option<&crate::query_planner::plan_ctx::PlanCtx>,
+) -> Vec<(String, String)> {
+    let (TypedVariable::Node(node), Some(ctx)) = (typed_var, plan_ctx) else {
+        return properties;
+    };
+    let [label] = node.labels.as_slice() else {
+        return properties;
+    };
+    if ctx.has_full_projection(alias) {
+        return properties;
+    }
+    let schema = ctx.schema();
+    let (Some(projection), Some(node_schema)) = (
+        schema.return_projection(label),
+        schema.node_schema_opt(label),
+    ) else {
+        return properties;
+    };
+    let selected = projection.select(&node_schema.node_id.columns(), properties);
+    log::debug!(
+        "Return projection for '{}' ({}): {} properties",
+        alias,
+        label,
+        selected.len()
+    );
+    selected
+}
+
 /// SelectBuilder trait for extracting SELECT items from logical plans
 pub trait SelectBuilder {
     /// Extract SELECT items from the logical plan
@@ -966,7 +1001,7 @@ impl SelectBuilder for LogicalPlan {
                                             table_alias: RenderTableAlias(
                                                 table_alias_for_render.clone(),
                                             ),
-                                            column: PropertyValue::Column(col_name),
+                                            column: PropertyValue::from_raw(col_name),
                                         }),
                                         col_alias: Some(ColumnAlias(format!(
                                             "{}.{}",
@@ -1089,6 +1124,35 @@ impl SelectBuilder for LogicalPlan {
                                 continue;
                             }
 
+                            // Relationship of a multi-type single hop (`[r:A|B]`): the
+                            // `vlp_multi_type_*` CTE projects each property the query
+                            // reads as a `rel_prop_<name>` column (NULL for types
+                            // without it).
+                            if let Some(gr) = self.find_graph_rel_by_rel_alias(cypher_alias) {
+                                let is_multi_type_single_hop =
+                                    gr.labels.as_ref().is_some_and(|l| l.len() > 1)
+                                        && gr.pattern_combinations.is_none()
+                                        && gr
+                                            .variable_length
+                                            .as_ref()
+                                            .is_none_or(|spec| spec.is_single_hop());
+                                if is_multi_type_single_hop {
+                                    select_items.push(SelectItem {
+                                        expression: RenderExpr::PropertyAccessExp(PropertyAccess {
+                                            table_alias: RenderTableAlias("t".to_string()),
+                                            column: PropertyValue::Column(
+                                                crate::clickhouse_query_generator::multi_type_vlp_joins::rel_property_column(col_name),
+                                            ),
+                                        }),
+                                        col_alias: item
+                                            .col_alias
+                                            .as_ref()
+                                            .map(|ca| ColumnAlias(ca.0.clone())),
+                                    });
+                                    continue;
+                                }
+                            }
+
                             // 🔧 FIX: Check if this is a multi-type VLP endpoint first
                             // Multi-type VLP endpoints need JSON extraction, not direct column access
                             if let Some(gr) = self.find_graph_rel_for_alias(cypher_alias) {
@@ -1256,7 +1320,7 @@ impl SelectBuilder for LogicalPlan {
                                     select_items.push(SelectItem {
                                         expression: RenderExpr::PropertyAccessExp(PropertyAccess {
                                             table_alias: RenderTableAlias(table_alias_to_use),
-                                            column: PropertyValue::Column(actual_column),
+                                            column: PropertyValue::from_raw(actual_column),
                                         }),
                                         col_alias: item
                                             .col_alias
@@ -1626,7 +1690,7 @@ impl SelectBuilder for LogicalPlan {
                                                         table_alias: RenderTableAlias(
                                                             resolved_alias,
                                                         ),
-                                                        column: PropertyValue::Column(resolved_col),
+                                                        column: PropertyValue::from_raw(resolved_col),
                                                     },
                                                 ),
                                                 col_alias: item
@@ -2260,6 +2324,8 @@ impl LogicalPlan {
         // Use plan_ctx-aware method to handle coupled edges
         match self.get_properties_with_plan_ctx(lookup_alias, plan_ctx) {
             Ok((properties, resolved_table_alias)) if !properties.is_empty() => {
+                let properties =
+                    return_projected_properties(alias, typed_var, properties, plan_ctx);
                 // For coupled edges, resolved_table_alias may differ from actual_table_alias
                 let table_alias_to_use =
                     resolved_table_alias.unwrap_or_else(|| actual_table_alias.clone());
@@ -2268,7 +2334,7 @@ impl LogicalPlan {
                     select_items.push(SelectItem {
                         expression: RenderExpr::PropertyAccessExp(PropertyAccess {
                             table_alias: RenderTableAlias(table_alias_to_use.clone()),
-                            column: PropertyValue::Column(col_name),
+                            column: PropertyValue::from_raw(col_name),
                         }),
                         col_alias: Some(ColumnAlias(format!("{}.{}", alias, prop_name))),
                     });
@@ -2433,6 +2499,7 @@ impl LogicalPlan {
             );
             return;
         }
+        let properties = return_projected_properties(alias, typed_var, properties, Some(plan_ctx));
 
         // Generate CTE column names and SelectItems
         // Use CTE property mappings from query context (populated by cte_manager)
@@ -2512,7 +2579,7 @@ impl LogicalPlan {
                 select_items.push(SelectItem {
                     expression: RenderExpr::PropertyAccessExp(PropertyAccess {
                         table_alias: RenderTableAlias(resolved_table_alias.clone()),
-                        column: PropertyValue::Column(col_name),
+                        column: PropertyValue::from_raw(col_name),
                     }),
                     col_alias: Some(ColumnAlias(format!("{}.{}", table_alias.0, prop_name))),
                 });
@@ -3367,7 +3434,7 @@ impl LogicalPlan {
             items.push(SelectItem {
                 expression: RenderExpr::PropertyAccessExp(PropertyAccess {
                     table_alias: RenderTableAlias(table_alias_str.clone()),
-                    column: PropertyValue::Column(col_name),
+                    column: PropertyValue::from_raw(col_name),
                 }),
                 col_alias: Some(ColumnAlias(format!("{}.{}", alias, prop_name))),
             });
@@ -0,0 +1,108 @@
+//! Aggregated edges (`aggregate` on an edge definition) read as a
+//! `GROUP BY` subquery over the raw rows wherever the edge type is scanned:
+//! single hops, undirected patterns and variable-length CTEs.
+
+use crate::{graph_catalog::config::GraphSchemaConfig, graph_catalog::graph_schema::GraphSchema};
+
+const NETLOG_YAML: &str = r#"
+name: netlog
+graph_schema:
+  nodes:
+    - label: Host
+      database: logs
+      table: hosts
+      node_id: ip
+      property_mappings:
+        ip: ip
+        name: hostname
+  edges:
+    - type: COMMUNICATES_WITH
+      database: logs
+      table: communicates_with
+      from_id: src_ip
+      to_id: dst_ip
+      from_node: Host
+      to_node: Host
+      aggregate:
+        source_table: conn_log
+        filter: "proto = 'tcp'"
+        measures:
+          weight: "count()"
+          bytes: "sum(bytes)"
+      property_mappings: {}
+"#;
+
+const ROLLUP: &str = "(SELECT src_ip, dst_ip, sum(bytes) AS bytes, count() AS weight \
+                      FROM logs.conn_log WHERE proto = 'tcp' GROUP BY src_ip, dst_ip)";
+
+fn schema(yaml: &str) -> Result<GraphSchema, String> {
+    GraphSchemaConfig::from_yaml_str(yaml)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .map_err(|e| e.to_string())
+}
+
+fn sql(cypher: &str) -> String {
+    let schema = schema(NETLOG_YAML).expect("build graph schema");
+    crate::clickhouse_query_generator::cypher_to_sql(cypher, &schema, 100)
+        .unwrap_or_else(|e| panic!("{cypher}: {e}"))
+}
+
+#[test]
+fn edge_scan_reads_the_rollup() {
+    let sql = sql(
+        "MATCH (a:Host)-[r:COMMUNICATES_WITH]->(b:Host) WHERE r.weight > 10 \
+         RETURN a.name, b.name, r.bytes",
+    );
+
+    assert!(sql.contains(&format!("{ROLLUP} AS r")), "SQL:\n{sql}");
+    assert!(sql.contains("r.weight > 10"), "SQL:\n{sql}");
+    assert!(sql.contains("r.bytes AS \"r.bytes\""), "SQL:\n{sql}");
+    assert!(!sql.contains("communicates_with"), "SQL:\n{sql}");
+}
+
+#[test]
+fn variable_length_steps_read_the_rollup() {
+    let sql = sql("MATCH (a:Host)-[:COMMUNICATES_WITH*1..2]->(b:Host) \
+         WHERE a.ip = '10.0.0.1' RETURN b.ip");
+
+    assert_eq!(sql.matches(ROLLUP).count(), 2, "SQL:\n{sql}");
+    assert!(!sql.contains("communicates_with"), "SQL:\n{sql}");
+}
+
+#[test]
+fn rollup_groups_by_snapshot_and_extra_columns() {
+    let yaml = NETLOG_YAML
+        .replace("graph_schema:\n", "graph_schema:\n  snapshot_column: day\n")
+        .replace(
+            "        filter: \"proto = 'tcp'\"\n",
+            "        group_by: [dst_port]\n",
+        );
+    let schema = schema(&yaml).unwrap();
+
+    let rollup = schema
+        .edge_aggregate("logs", "communicates_with")
+        .expect("aggregate subquery");
+    assert!(
+        rollup.ends_with("GROUP BY src_ip, dst_ip, dst_port, day"),
+        "{rollup}"
+    );
+    assert!(
+        rollup.starts_with("SELECT src_ip, dst_ip, dst_port, day,"),
+        "{rollup}"
+    );
+}
+
+#[test]
+fn invalid_aggregates_are_rejected() {
+    let final_yaml = NETLOG_YAML.replace(
+        "      aggregate:\n",
+        "      use_final: true\n      aggregate:\n",
+    );
+    let err = schema(&final_yaml).unwrap_err();
+    assert!(err.contains("use_final does not apply"), "{err}");
+
+    let shadowing = NETLOG_YAML.replace("weight: \"count()\"", "src_ip: \"count()\"");
+    let err = schema(&shadowing).unwrap_err();
+    assert!(err.contains("shadows a grouped column"), "{err}");
+}
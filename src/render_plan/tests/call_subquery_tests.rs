@@ -0,0 +1,131 @@
+//! `CALL { ... }` subqueries.
+
+use crate::{graph_catalog::config::GraphSchemaConfig, graph_catalog::graph_schema::GraphSchema};
+
+const SOCIAL_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        age: age
+    - label: Post
+      database: db
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+    - type: AUTHORED
+      database: db
+      table: authored
+      from_id: author_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+"#;
+
+fn schema() -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(SOCIAL_YAML)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .expect("build graph schema")
+}
+
+fn sql(cypher: &str) -> Result<String, String> {
+    crate::clickhouse_query_generator::cypher_to_sql(cypher, &schema(), 100)
+}
+
+#[test]
+fn correlated_count_is_a_left_join_grouped_by_the_outer_row() {
+    let sql = sql(
+        "MATCH (u:User) CALL { WITH u MATCH (u)-[:FOLLOWS]->(f:User) RETURN count(*) AS c } \
+         RETURN u.name, c",
+    )
+    .expect("cypher_to_sql");
+    // Edge aliases (`t1`, ...) come from a process-wide counter. The body is
+    // counted per follower and joined to the outer row, 0 when it has none.
+    assert!(sql.contains("FROM db.follows AS t"), "SQL:\n{sql}");
+    assert!(sql.contains(".followed_id) AS \"c\""), "SQL:\n{sql}");
+    assert!(
+        sql.contains(
+            "LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = u.user_id"
+        ),
+        "SQL:\n{sql}"
+    );
+    assert!(
+        sql.contains("coalesce(anyLast(__optional_agg_0.c), 0) AS \"c\""),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("GROUP BY u.user_id"), "SQL:\n{sql}");
+}
+
+#[test]
+fn non_aggregating_body_extends_the_outer_match() {
+    let sql = sql(
+        "MATCH (u:User) CALL { WITH u MATCH (u)-[:AUTHORED]->(p:Post) RETURN p.title AS title } \
+         RETURN u.name, title ORDER BY title",
+    )
+    .expect("cypher_to_sql");
+    assert!(sql.contains("INNER JOIN db.authored AS t"), "SQL:\n{sql}");
+    assert!(sql.contains(".author_id = u.user_id"), "SQL:\n{sql}");
+    assert!(sql.contains("p.title AS \"title\""), "SQL:\n{sql}");
+    assert!(sql.contains("ORDER BY title_u.title ASC"), "SQL:\n{sql}");
+}
+
+#[test]
+fn uncorrelated_body_keeps_its_order_and_limit() {
+    let sql =
+        sql("CALL { MATCH (p:Post) RETURN p.title AS title ORDER BY title LIMIT 3 } RETURN title")
+            .expect("cypher_to_sql");
+    assert!(sql.contains("FROM db.posts AS p"), "SQL:\n{sql}");
+    assert!(sql.contains("LIMIT 3"), "SQL:\n{sql}");
+}
+
+#[test]
+fn union_body_is_post_processed_as_a_whole() {
+    let sql = sql("CALL { MATCH (a:User) RETURN a.name AS name \
+                UNION ALL MATCH (p:Post) RETURN p.title AS name } \
+         RETURN name ORDER BY name LIMIT 5")
+    .expect("cypher_to_sql");
+    assert!(sql.contains("a.full_name AS \"name\""), "SQL:\n{sql}");
+    assert!(sql.contains("p.title AS \"name\""), "SQL:\n{sql}");
+    assert!(sql.contains("UNION ALL"), "SQL:\n{sql}");
+    // ORDER BY / LIMIT apply to the combined rows, after the union.
+    let union_at = sql.find("UNION ALL").unwrap();
+    let order_at = sql.find("ORDER BY").expect("ORDER BY");
+    assert!(order_at > union_at, "SQL:\n{sql}");
+    assert!(sql.trim_end().ends_with("LIMIT 5"), "SQL:\n{sql}");
+}
+
+#[test]
+fn scoping_errors_are_reported() {
+    let err = sql("CALL { WITH u MATCH (u)-[:FOLLOWS]->(f:User) RETURN count(*) AS c } RETURN c")
+        .expect_err("undefined import");
+    assert!(err.contains("not defined before the CALL"), "{err}");
+
+    let err =
+        sql("MATCH (u:User) CALL { MATCH (u)-[:FOLLOWS]->(f:User) RETURN count(*) AS c } RETURN c")
+            .expect_err("outer variable used without import");
+    assert!(err.contains("already defined outside CALL"), "{err}");
+
+    let err = sql(
+        "MATCH (u:User), (f:User) CALL { WITH u MATCH (u)-[:FOLLOWS]->(x:User) RETURN x.name AS f } \
+         RETURN f",
+    )
+    .expect_err("returned name clashes with an outer variable");
+    assert!(err.contains("returned from CALL"), "{err}");
+}
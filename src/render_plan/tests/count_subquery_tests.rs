@@ -0,0 +1,158 @@
+//! `COUNT { pattern [WHERE ...] }` subquery expressions.
+//!
+//! - A typed pattern is a correlated `(SELECT COUNT(*) ...)`, like
+//!   `size(pattern)`.
+//! - An untyped hop sums one count per edge table the anchor's label sits on.
+//! - An inner `WHERE` joins the end node only when the predicate reads it.
+
+use std::sync::Arc;
+
+use crate::{
+    graph_catalog::config::GraphSchemaConfig,
+    graph_catalog::graph_schema::GraphSchema,
+    server::query_context::{with_query_context, QueryContext},
+};
+
+const SOCIAL_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        age: age
+    - label: Post
+      database: db
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings:
+        since: follow_date
+    - type: AUTHORED
+      database: db
+      table: authored
+      from_id: author_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+"#;
+
+fn schema() -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(SOCIAL_YAML)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .expect("build graph schema")
+}
+
+/// The pattern-count renderer looks relationships up in the query's schema.
+async fn try_sql(cypher: &str) -> Result<String, String> {
+    let ctx = QueryContext {
+        schema: Some(Arc::new(schema())),
+        ..QueryContext::default()
+    };
+    with_query_context(ctx, async {
+        crate::clickhouse_query_generator::cypher_to_sql(cypher, &schema(), 100)
+            .map_err(|e| e.to_string())
+    })
+    .await
+}
+
+async fn sql(cypher: &str) -> String {
+    try_sql(cypher).await.expect("cypher_to_sql")
+}
+
+#[tokio::test]
+async fn typed_count_is_a_correlated_count() {
+    let sql = sql("MATCH (u:User) RETURN u.name, COUNT { (u)-[:FOLLOWS]->() } AS following").await;
+    assert!(
+        sql.contains("SELECT COUNT(*) FROM db.follows"),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("follower_id = u.user_id"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn untyped_count_sums_every_edge_table_of_the_anchor() {
+    let sql = sql("MATCH (n:User) WHERE COUNT { (n)--() } > 100 RETURN n.name").await;
+    for side in [
+        "follows.follower_id = n.user_id",
+        "follows.followed_id = n.user_id",
+        "authored.author_id = n.user_id",
+    ] {
+        assert!(sql.contains(side), "missing {side}:\nSQL:\n{sql}");
+    }
+    assert!(sql.contains("> 100"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn untyped_count_is_narrowed_by_the_end_label() {
+    let sql = sql("MATCH (n:User) RETURN COUNT { (n)-->(:Post) } AS posts").await;
+    assert!(
+        sql.contains("authored.author_id = n.user_id"),
+        "SQL:\n{sql}"
+    );
+    assert!(!sql.contains("db.follows"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn inner_where_joins_the_end_node_it_reads() {
+    let sql = sql(
+        "MATCH (u:User) \
+         RETURN u.name, COUNT { (u)-[r:FOLLOWS]->(f:User) WHERE f.age > 30 AND r.since > '2020-01-01' } AS c",
+    )
+    .await;
+    assert!(
+        sql.contains("INNER JOIN db.users AS f ON f.user_id = r.followed_id"),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("r.follower_id = u.user_id"), "SQL:\n{sql}");
+    assert!(sql.contains("f.age > 30"), "SQL:\n{sql}");
+    assert!(sql.contains("r.follow_date"), "SQL:\n{sql}");
+
+    let edge_only = self::sql(
+        "MATCH (u:User) RETURN COUNT { (u)-[r:FOLLOWS]->() WHERE r.since > '2020' } AS c",
+    )
+    .await;
+    assert!(
+        !edge_only.contains("INNER JOIN db.users"),
+        "SQL:\n{edge_only}"
+    );
+}
+
+#[tokio::test]
+async fn count_aggregate_is_not_a_subquery() {
+    let sql = sql("MATCH (u:User)-[:FOLLOWS]->(f:User) RETURN u.name, count(f) AS c").await;
+    assert!(
+        !sql.contains("SELECT COUNT(*) FROM db.follows"),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("GROUP BY u.full_name"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn unsupported_count_patterns_are_rejected() {
+    let err = try_sql("MATCH (u:User) RETURN COUNT { (u)-[:FOLLOWS|AUTHORED]->() } AS c")
+        .await
+        .expect_err("multi-type COUNT should be rejected");
+    assert!(err.contains("COUNT"), "{err}");
+
+    let err =
+        try_sql("MATCH (u:User) RETURN COUNT { (u)-[r:FOLLOWS]-() WHERE r.since > '2020' } AS c")
+            .await
+            .expect_err("undirected filtered COUNT should be rejected");
+    assert!(err.contains("undirected"), "{err}");
+}
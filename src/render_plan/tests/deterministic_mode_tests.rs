@@ -0,0 +1,131 @@
+//! Test mode (`QueryContext::deterministic`): `rand()` is seeded, current-time
+//! functions render the frozen instant, and anonymous aliases are numbered
+//! per query, so the same query renders byte-identical SQL every time.
+
+use crate::{
+    graph_catalog::config::GraphSchemaConfig,
+    graph_catalog::graph_schema::GraphSchema,
+    server::query_context::{with_query_context, DeterministicMode, QueryContext},
+};
+
+const SOCIAL_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        joined: joined_at
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+"#;
+
+fn schema() -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(SOCIAL_YAML)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .expect("build graph schema")
+}
+
+fn test_mode(seed: u64) -> QueryContext {
+    QueryContext {
+        deterministic: Some(DeterministicMode {
+            seed,
+            frozen_time: "2024-03-01T12:30:45Z".parse().unwrap(),
+        }),
+        ..QueryContext::default()
+    }
+}
+
+async fn sql_in(ctx: QueryContext, cypher: &str) -> String {
+    with_query_context(ctx, async {
+        crate::clickhouse_query_generator::cypher_to_sql(cypher, &schema(), 100)
+    })
+    .await
+    .unwrap_or_else(|e| panic!("{cypher}: {e}"))
+}
+
+#[tokio::test]
+async fn current_time_functions_render_the_frozen_instant() {
+    let sql = sql_in(
+        test_mode(0),
+        "MATCH (u:User) WHERE u.joined > datetime() - duration('P1D') \
+         RETURN date(), timestamp(), localdatetime()",
+    )
+    .await;
+
+    assert!(
+        sql.contains("toDateTime64('2024-03-01 12:30:45.000', 3, 'UTC') - toIntervalDay(1)"),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("toDate(toDate('2024-03-01'))"), "SQL:\n{sql}");
+    assert!(
+        sql.contains("toUnixTimestamp(toDateTime('2024-03-01 12:30:45', 'UTC'))"),
+        "SQL:\n{sql}"
+    );
+    for live in ["now()", "now64(", "today()"] {
+        assert!(!sql.contains(live), "{live} left in SQL:\n{sql}");
+    }
+}
+
+#[tokio::test]
+async fn rand_is_seeded_per_call_site() {
+    let sql = sql_in(
+        test_mode(42),
+        "MATCH (u:User) RETURN u.user_id, rand() AS a, rand() AS b ORDER BY a",
+    )
+    .await;
+
+    assert!(
+        sql.contains("(cityHash64(42, 1, rowNumberInAllBlocks()) % 4294967296) / 4294967296.0"),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("cityHash64(42, 2, "), "SQL:\n{sql}");
+
+    let other_seed = sql_in(
+        test_mode(7),
+        "MATCH (u:User) RETURN u.user_id, rand() AS a, rand() AS b ORDER BY a",
+    )
+    .await;
+    assert!(
+        other_seed.contains("cityHash64(7, 1, "),
+        "SQL:\n{other_seed}"
+    );
+}
+
+#[tokio::test]
+async fn outside_test_mode_rand_and_time_stay_live() {
+    let sql = sql_in(
+        QueryContext::default(),
+        "RETURN rand() AS r, datetime() AS t",
+    )
+    .await;
+
+    assert!(sql.contains("(rand() / 4294967296.0)"), "SQL:\n{sql}");
+    assert!(sql.contains("now64(3)"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn aliases_do_not_depend_on_earlier_queries() {
+    let cypher = "MATCH (a:User)-[:FOLLOWS]->(:User)-[:FOLLOWS]->(c:User) \
+                  WHERE a.user_id = 1 RETURN c.user_id, rand() AS r";
+    let first = sql_in(test_mode(0), cypher).await;
+
+    // Other queries, planned in between, advance the process-wide counters
+    for _ in 0..5 {
+        crate::query_planner::logical_plan::generate_id();
+        crate::query_planner::logical_plan::generate_cte_id();
+    }
+    let second = sql_in(test_mode(0), cypher).await;
+
+    assert_eq!(first, second);
+}
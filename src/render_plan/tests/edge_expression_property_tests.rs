@@ -0,0 +1,232 @@
+//! Tests for computed (expression-mapped) properties on edges and nodes.
+//!
+//! A property mapping may be a SQL expression over the entity's row, e.g.
+//! `throughput: "resp_bytes / nullIf(duration, 0)"`. Wherever the property is
+//! used — WHERE, ORDER BY, aggregates, inline `{prop: value}` filters, whole
+//! entity expansion (`RETURN r`), WITH exports, variable-length path endpoints
+//! and `reduce()` over `relationships(p)` — the expression must be rendered
+//! with its columns qualified by the table alias, never quoted as a single
+//! identifier. A property named like a temporal accessor (`r.day`) is still
+//! the property.
+
+use std::sync::Arc;
+
+use crate::graph_catalog::config::GraphSchemaConfig;
+use crate::graph_catalog::graph_schema::GraphSchema;
+use crate::render_plan::plan_builder::RenderPlanBuilder;
+use crate::server::query_context::{set_current_schema, with_query_context, QueryContext};
+use crate::{clickhouse_query_generator, open_cypher_parser, query_planner};
+
+const SCHEMA_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: Host
+      database: net
+      table: hosts
+      node_id: ip
+      property_mappings:
+        ip: ip
+        name: hostname
+        name_len: "length(hostname)"
+  edges:
+    - type: CONN
+      database: net
+      table: conns
+      from_id: src_ip
+      to_id: dst_ip
+      from_node: Host
+      to_node: Host
+      property_mappings:
+        bytes: resp_bytes
+        duration: duration
+        throughput: "resp_bytes / nullIf(duration, 0)"
+        day: "toDate(ts)"
+"#;
+
+const THROUGHPUT_SQL: &str = "(r.resp_bytes / nullIf(r.duration, 0))";
+
+fn schema() -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+        .expect("valid yaml")
+        .to_graph_schema()
+        .expect("valid schema")
+}
+
+async fn cypher_to_sql(cypher: &str) -> String {
+    let schema = schema();
+    with_query_context(QueryContext::default(), async {
+        set_current_schema(Arc::new(schema.clone()));
+        let statement = open_cypher_parser::parse_cypher_statement(cypher)
+            .expect("parse")
+            .1;
+        let (plan, _) =
+            query_planner::evaluate_read_statement(statement, &schema, None, None, None)
+                .expect("plan");
+        let render_plan = plan.to_render_plan(&schema).expect("render");
+        clickhouse_query_generator::generate_sql(render_plan, 100)
+    })
+    .await
+}
+
+fn assert_no_quoted_expression(sql: &str) {
+    assert!(
+        !sql.contains("\"resp_bytes / nullIf(duration, 0)\"")
+            && !sql.contains("\"length(hostname)\"")
+            && !sql.contains("`length(hostname)`"),
+        "Expression must not be quoted as a column name. SQL: {}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn test_computed_edge_property_in_where_order_by_and_return() {
+    let sql = cypher_to_sql(
+        "MATCH (a:Host)-[r:CONN]->(b:Host) WHERE r.throughput > 100 \
+         RETURN a.ip, r.throughput ORDER BY r.throughput DESC",
+    )
+    .await;
+
+    assert!(
+        sql.contains(&format!("WHERE {} > 100", THROUGHPUT_SQL)),
+        "SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains(&format!("ORDER BY {} DESC", THROUGHPUT_SQL)),
+        "SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains(&format!("{} AS \"r.throughput\"", THROUGHPUT_SQL)),
+        "SQL: {}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn test_computed_edge_property_in_aggregate() {
+    let sql =
+        cypher_to_sql("MATCH (a:Host)-[r:CONN]->(b:Host) RETURN a.ip, avg(r.throughput) AS t")
+            .await;
+
+    assert!(
+        sql.contains(&format!("avg({})", THROUGHPUT_SQL)),
+        "SQL: {}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn test_computed_edge_property_in_inline_filter() {
+    let sql = cypher_to_sql("MATCH (a:Host)-[r:CONN {throughput: 5}]->(b:Host) RETURN a.ip").await;
+
+    assert!(
+        sql.contains(&format!("{} = 5", THROUGHPUT_SQL)),
+        "SQL: {}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn test_whole_edge_expansion_renders_computed_property() {
+    let sql = cypher_to_sql("MATCH (a:Host)-[r:CONN]->(b:Host) RETURN r").await;
+
+    assert!(
+        sql.contains(&format!("{} AS \"r.throughput\"", THROUGHPUT_SQL)),
+        "SQL: {}",
+        sql
+    );
+    assert_no_quoted_expression(&sql);
+}
+
+#[tokio::test]
+async fn test_with_export_renders_computed_property() {
+    let sql = cypher_to_sql("MATCH (a:Host)-[r:CONN]->(b:Host) WITH r RETURN r.throughput").await;
+
+    assert!(
+        sql.contains(&format!("{} AS \"p1_r_throughput\"", THROUGHPUT_SQL)),
+        "SQL: {}",
+        sql
+    );
+    assert_no_quoted_expression(&sql);
+}
+
+#[tokio::test]
+async fn test_reduce_over_computed_edge_property() {
+    let sql = cypher_to_sql(
+        "MATCH p = (a:Host {ip: 'x'})-[:CONN*1..3]->(b:Host) \
+         RETURN reduce(t = 0.0, r IN relationships(p) | t + r.throughput) AS w",
+    )
+    .await;
+
+    assert!(
+        sql.contains("[(rel.resp_bytes / nullIf(rel.duration, 0))] as path_edge_throughput"),
+        "Edge property array should carry the computed value. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("t.path_edge_throughput"),
+        "reduce should fold over the computed edge property array. SQL: {}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn test_computed_node_property_in_inline_filter_and_expansion() {
+    let sql = cypher_to_sql("MATCH (a:Host {name_len: 3}) RETURN a.ip").await;
+    assert!(sql.contains("WHERE length(a.hostname) = 3"), "SQL: {}", sql);
+
+    let sql = cypher_to_sql("MATCH (a:Host) RETURN a").await;
+    assert!(
+        sql.contains("length(a.hostname) AS \"a.name_len\""),
+        "SQL: {}",
+        sql
+    );
+    assert_no_quoted_expression(&sql);
+}
+
+#[tokio::test]
+async fn test_computed_property_named_like_temporal_accessor() {
+    let sql = cypher_to_sql(
+        "MATCH (a:Host)-[r:CONN]->(b:Host) WHERE r.day = '2024-01-01' \
+         RETURN r.day ORDER BY r.day",
+    )
+    .await;
+
+    assert!(
+        sql.contains("toDate(r.ts) AS \"r.day\""),
+        "r.day is the mapped property, not day(r). SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("WHERE toDate(r.ts) = '2024-01-01'"),
+        "SQL: {}",
+        sql
+    );
+    assert!(!sql.contains("toDayOfMonth"), "SQL: {}", sql);
+
+    // Without such a property the accessor still applies.
+    let sql = cypher_to_sql("MATCH (a:Host)-[r:CONN]->(b:Host) RETURN r.duration.day").await;
+    assert!(sql.contains("toDayOfMonth"), "SQL: {}", sql);
+}
+
+#[tokio::test]
+async fn test_computed_node_property_on_variable_length_endpoints() {
+    let sql = cypher_to_sql(
+        "MATCH (a:Host)-[:CONN*1..2]->(b:Host) WHERE a.name_len = 3 AND b.name_len > 1 \
+         RETURN b.name_len",
+    )
+    .await;
+
+    assert!(
+        sql.contains("WHERE length(start_node.hostname) = 3"),
+        "SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("length(end_node.hostname) as end_name_len"),
+        "SQL: {}",
+        sql
+    );
+    assert_no_quoted_expression(&sql);
+}
@@ -0,0 +1,146 @@
+//! Planner pruning driven by declarative schema constraints
+//! (`graph_schema.constraints`).
+//!
+//! - `acyclic: [MANAGES]` lets a directed `*` walk over MANAGES drop the
+//!   `path_edges` array and per-hop cycle check from the recursive CTE.
+//! - `disjoint_labels: [[Person, Company]]` folds `n:Company` on a `Person`
+//!   variable to `false` at plan time.
+
+use crate::{graph_catalog::config::GraphSchemaConfig, graph_catalog::graph_schema::GraphSchema};
+
+const EDGES_YAML: &str = r#"
+  nodes:
+    - label: Person
+      database: hr
+      table: people
+      node_id: id
+      property_mappings:
+        id: person_id
+        name: full_name
+    - label: Company
+      database: hr
+      table: companies
+      node_id: id
+      property_mappings:
+        id: company_id
+        name: company_name
+  edges:
+    - type: MANAGES
+      database: hr
+      table: reporting
+      from_id: manager_id
+      to_id: report_id
+      from_node: Person
+      to_node: Person
+"#;
+
+fn schema(constraints: &str) -> GraphSchema {
+    let yaml = format!(
+        "name: hr\ngraph_schema:\n  constraints:\n{}{}",
+        constraints, EDGES_YAML
+    );
+    GraphSchemaConfig::from_yaml_str(&yaml)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .expect("build graph schema")
+}
+
+fn cypher_to_sql(cypher: &str, schema: &GraphSchema) -> String {
+    crate::clickhouse_query_generator::cypher_to_sql(cypher, schema, 100).expect("cypher_to_sql")
+}
+
+const VLP_QUERY: &str =
+    "MATCH (a:Person)-[:MANAGES*1..4]->(b:Person) WHERE a.name = 'Ada' RETURN b.name";
+
+#[test]
+fn acyclic_type_drops_cycle_tracking() {
+    let sql = cypher_to_sql(VLP_QUERY, &schema("    acyclic: [MANAGES]\n"));
+    assert!(
+        sql.contains("WITH RECURSIVE"),
+        "expected a recursive CTE; SQL:\n{sql}"
+    );
+    assert!(
+        !sql.contains("path_edges"),
+        "acyclic walk must not carry path_edges; SQL:\n{sql}"
+    );
+    assert!(
+        !sql.contains("NOT has("),
+        "acyclic walk must not emit a cycle check; SQL:\n{sql}"
+    );
+}
+
+#[test]
+fn undeclared_type_keeps_cycle_tracking() {
+    let sql = cypher_to_sql(VLP_QUERY, &schema("    acyclic: []\n"));
+    assert!(
+        sql.contains("NOT has("),
+        "without the constraint the walk keeps its cycle check; SQL:\n{sql}"
+    );
+}
+
+#[test]
+fn undirected_walk_keeps_cycle_tracking() {
+    let sql = cypher_to_sql(
+        "MATCH (a:Person)-[:MANAGES*1..3]-(b:Person) WHERE a.name = 'Ada' RETURN b.name",
+        &schema("    acyclic: [MANAGES]\n"),
+    );
+    assert!(
+        sql.contains("NOT has("),
+        "an undirected walk can close a cycle in an acyclic graph; SQL:\n{sql}"
+    );
+}
+
+/// Person and Company rows share one table, told apart by `kind`.
+const SHARED_TABLE_YAML: &str = r#"
+  nodes:
+    - label: Person
+      database: hr
+      table: entities
+      node_id: id
+      label_column: kind
+      label_value: Person
+      property_mappings:
+        id: entity_id
+        name: display_name
+    - label: Company
+      database: hr
+      table: entities
+      node_id: id
+      label_column: kind
+      label_value: Company
+      property_mappings:
+        id: entity_id
+        name: display_name
+"#;
+
+fn shared_table_schema(constraints: &str) -> GraphSchema {
+    let yaml = format!(
+        "name: hr\ngraph_schema:\n  constraints:\n{}{}",
+        constraints, SHARED_TABLE_YAML
+    );
+    GraphSchemaConfig::from_yaml_str(&yaml)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .expect("build graph schema")
+}
+
+#[test]
+fn disjoint_label_predicate_folds_to_false() {
+    let query = "MATCH (n:Person) WHERE n:Company RETURN n.name";
+
+    // Without the constraint the label must be checked at runtime.
+    let sql = cypher_to_sql(query, &shared_table_schema("    disjoint_labels: []\n"));
+    assert!(
+        sql.contains("n.kind = 'Company'"),
+        "expected a runtime label_column check; SQL:\n{sql}"
+    );
+
+    let sql = cypher_to_sql(
+        query,
+        &shared_table_schema("    disjoint_labels: [[Person, Company]]\n"),
+    );
+    assert!(
+        !sql.contains("n.kind = 'Company'") && sql.contains("false"),
+        "n:Company on a Person must fold to false; SQL:\n{sql}"
+    );
+}
@@ -0,0 +1,166 @@
+//! `exists()`, `labels()` / `label()` and `type()` outside RETURN.
+//!
+//! - `exists(n.prop)` is a null check on the mapped column, and
+//!   `exists((a)-[:R]->())` is an `EXISTS { ... }` subquery.
+//! - In WHERE, `labels(n)` / `type(r)` fold to the alias's label or type from
+//!   the catalog, or read the per-row `label_column` / `type_column`.
+
+use std::sync::Arc;
+
+use crate::{
+    graph_catalog::config::GraphSchemaConfig,
+    graph_catalog::graph_schema::GraphSchema,
+    server::query_context::{with_query_context, QueryContext},
+};
+
+const SOCIAL_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        email: email_address
+    - label: Message
+      database: db
+      table: messages
+      node_id: id
+      label_column: kind
+      label_value: "Post|Comment"
+      property_mappings:
+        id: id
+        content: body
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+"#;
+
+const POLYMORPHIC_YAML: &str = r#"
+name: interactions
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+  edges:
+    - polymorphic: true
+      database: db
+      table: interactions
+      from_id: from_id
+      to_id: to_id
+      type_column: interaction_type
+      from_label_column: from_type
+      to_label_column: to_type
+      type_values: [FOLLOWS, BLOCKS]
+"#;
+
+fn schema(yaml: &str) -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(yaml)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .expect("build graph schema")
+}
+
+fn sql(cypher: &str, yaml: &str) -> String {
+    crate::clickhouse_query_generator::cypher_to_sql(cypher, &schema(yaml), 100)
+        .expect("cypher_to_sql")
+}
+
+#[test]
+fn exists_on_a_property_is_a_null_check() {
+    let where_sql = sql(
+        "MATCH (u:User) WHERE exists(u.email) RETURN u.name",
+        SOCIAL_YAML,
+    );
+    assert!(
+        where_sql.contains("u.email_address IS NOT NULL"),
+        "SQL:\n{where_sql}"
+    );
+    assert!(!where_sql.contains("exists("), "SQL:\n{where_sql}");
+
+    let return_sql = sql(
+        "MATCH (u:User) RETURN NOT exists(u.email) AS no_email",
+        SOCIAL_YAML,
+    );
+    assert!(
+        return_sql.contains("u.email_address IS NOT NULL"),
+        "SQL:\n{return_sql}"
+    );
+}
+
+#[tokio::test]
+async fn exists_on_a_pattern_is_an_exists_subquery() {
+    // The EXISTS renderer looks relationships up in the query's schema.
+    let ctx = QueryContext {
+        schema: Some(Arc::new(schema(SOCIAL_YAML))),
+        ..QueryContext::default()
+    };
+    let sql = with_query_context(ctx, async {
+        sql(
+            "MATCH (u:User) WHERE exists((u)-[:FOLLOWS]->()) RETURN u.name",
+            SOCIAL_YAML,
+        )
+    })
+    .await;
+    assert!(
+        sql.contains("EXISTS (SELECT 1 FROM db.follows"),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("follower_id = u.user_id"), "SQL:\n{sql}");
+}
+
+#[test]
+fn labels_and_type_fold_from_the_catalog_in_where() {
+    let sql_text = sql(
+        "MATCH (a:User)-[r:FOLLOWS]->(b:User) \
+         WHERE type(r) = 'FOLLOWS' AND 'User' IN labels(b) AND label(a) = 'User' \
+         RETURN b.name",
+        SOCIAL_YAML,
+    );
+    for raw in ["type(r)", "labels(b)", "label(a)"] {
+        assert!(
+            !sql_text.contains(raw),
+            "{raw} left unresolved:\n{sql_text}"
+        );
+    }
+
+    // A label that cannot match folds the predicate to false.
+    let sql_text = sql(
+        "MATCH (u:User) WHERE label(u) = 'Message' RETURN u.name",
+        SOCIAL_YAML,
+    );
+    assert!(sql_text.contains("WHERE false"), "SQL:\n{sql_text}");
+}
+
+#[test]
+fn labels_and_type_read_discriminator_columns_in_where() {
+    let sql_text = sql(
+        "MATCH (m:Message) WHERE 'Post' IN labels(m) RETURN m.content",
+        SOCIAL_YAML,
+    );
+    assert!(sql_text.contains("m.kind"), "SQL:\n{sql_text}");
+    assert!(!sql_text.contains("labels(m)"), "SQL:\n{sql_text}");
+
+    let sql_text = sql(
+        "MATCH (a:User)-[r:FOLLOWS]->(b:User) WHERE type(r) = 'FOLLOWS' RETURN b.name",
+        POLYMORPHIC_YAML,
+    );
+    assert!(
+        sql_text.contains("r.interaction_type = 'FOLLOWS'"),
+        "SQL:\n{sql_text}"
+    );
+    assert!(!sql_text.contains("type(r)"), "SQL:\n{sql_text}");
+}
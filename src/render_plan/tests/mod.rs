@@ -1,17 +1,29 @@
+mod aggregated_edge_tests;
+mod call_subquery_tests;
+mod count_subquery_tests;
 mod databricks_emit_spike_tests;
 mod denormalized_foreign_edge_id_tests;
 mod denormalized_multitype_expand_tests;
 mod denormalized_property_tests;
 mod denormalized_unlabeled_node_tests;
 mod denormalized_virtual_id_viz_tests;
+mod deterministic_mode_tests;
+mod edge_expression_property_tests;
 mod fixed_path_denormalized_edge_tests;
+mod graph_constraint_tests;
+mod introspection_function_tests;
 mod issue_411_generic_id_tests;
 mod multiple_relationship_tests;
+mod path_function_tests;
+mod pattern_comprehension_tests;
 mod pattern_union_dotted_column_tests;
 mod pattern_union_rel_property_tests;
 mod polymorphic_edge_tests;
 mod polymorphic_unlabeled_path_tests;
+mod recursive_cte_fallback_tests;
+mod temporal_function_tests;
 mod variable_length_tests;
+mod vlp_path_edge_props_tests;
 mod vlp_property_pruning_tests;
 mod where_clause_filter_tests;
 mod with_clause_cte_tests;
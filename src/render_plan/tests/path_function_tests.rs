@@ -0,0 +1,99 @@
+//! `nodes(p)`, `relationships(p)` and `length(p)` read the variable-length
+//! path CTE's `path_nodes` / `path_relationships` / `hop_count` columns
+//! wherever they appear, UNWIND included; fixed-length paths render them
+//! from the pattern itself.
+
+use crate::{
+    graph_catalog::config::GraphSchemaConfig,
+    graph_catalog::graph_schema::GraphSchema,
+    server::query_context::{with_query_context, QueryContext},
+};
+
+const SOCIAL_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+"#;
+
+fn schema() -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(SOCIAL_YAML)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .expect("build graph schema")
+}
+
+async fn sql(cypher: &str) -> String {
+    with_query_context(QueryContext::default(), async {
+        crate::clickhouse_query_generator::cypher_to_sql(cypher, &schema(), 100)
+    })
+    .await
+    .unwrap_or_else(|e| panic!("{cypher}: {e}"))
+}
+
+#[tokio::test]
+async fn vlp_path_functions_read_cte_columns() {
+    let sql = sql(
+        "MATCH p = (a:User)-[:FOLLOWS*1..3]->(b:User) WHERE a.user_id = 1 \
+         RETURN nodes(p), relationships(p), length(p)",
+    )
+    .await;
+
+    assert!(sql.contains("t.path_nodes AS \"nodes(p)\""), "SQL:\n{sql}");
+    assert!(
+        sql.contains("t.path_relationships AS \"relationships(p)\""),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("t.hop_count AS \"length(p)\""), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn unwind_nodes_of_vlp_path_unrolls_path_nodes() {
+    let sql = sql(
+        "MATCH p = (a:User)-[:FOLLOWS*1..3]->(b:User) WHERE a.user_id = 1 \
+         UNWIND nodes(p) AS n RETURN n",
+    )
+    .await;
+
+    assert!(sql.contains("ARRAY JOIN t.path_nodes AS n"), "SQL:\n{sql}");
+    assert!(!sql.contains("nodes(p)"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn unwind_relationships_of_vlp_path_unrolls_path_relationships() {
+    let sql = sql("MATCH p = (a:User)-[:FOLLOWS*1..3]->(b:User) \
+         UNWIND relationships(p) AS r RETURN r, length(p)")
+    .await;
+
+    assert!(
+        sql.contains("ARRAY JOIN t.path_relationships AS r"),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("t.hop_count AS \"length(p)\""), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn unwind_nodes_of_fixed_path_unrolls_endpoint_ids() {
+    let sql = sql("MATCH p = (a:User)-[:FOLLOWS]->(b:User) UNWIND nodes(p) AS n RETURN n").await;
+
+    assert!(
+        sql.contains("ARRAY JOIN array(a.user_id, b.user_id) AS n"),
+        "SQL:\n{sql}"
+    );
+    // `b` is only reachable through nodes(p); its join must survive.
+    assert!(sql.contains("JOIN db.users AS b"), "SQL:\n{sql}");
+}
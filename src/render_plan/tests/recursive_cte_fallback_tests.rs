@@ -0,0 +1,165 @@
+//! Variable-length paths on a ClickHouse without `WITH RECURSIVE`
+//! (`QueryContext::recursive_cte_unavailable`): bounded patterns unroll into
+//! one CTE per hop, unbounded ones are rejected with the reason. The
+//! recursion-depth retry (`QueryContext::vlp_fallback_hops`) unrolls every
+//! path, unbounded ones included, to its hop bound.
+
+use crate::{
+    graph_catalog::config::GraphSchemaConfig,
+    graph_catalog::graph_schema::GraphSchema,
+    server::query_context::{with_query_context, QueryContext},
+};
+
+const SOCIAL_YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+"#;
+
+const REASON: &str =
+    "ClickHouse 23.8.9.54 has no recursive CTEs; they require ClickHouse 24.4 or later";
+
+fn schema() -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(SOCIAL_YAML)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .expect("build graph schema")
+}
+
+async fn sql_without_recursion(cypher: &str) -> Result<String, String> {
+    let ctx = QueryContext {
+        recursive_cte_unavailable: Some(REASON.to_string()),
+        ..QueryContext::default()
+    };
+    with_query_context(ctx, async {
+        crate::clickhouse_query_generator::cypher_to_sql(cypher, &schema(), 100)
+    })
+    .await
+}
+
+#[tokio::test]
+async fn bounded_path_unrolls_into_one_cte_per_hop() {
+    let sql = sql_without_recursion(
+        "MATCH (a:User)-[:FOLLOWS*1..3]->(b:User) WHERE a.user_id = 1 RETURN b.name",
+    )
+    .await
+    .expect("bounded VLP renders");
+
+    assert!(!sql.contains("RECURSIVE"), "SQL:\n{sql}");
+    for hop in 1..=3 {
+        assert!(
+            sql.contains(&format!("_hop{hop} AS (")),
+            "hop {hop}:\n{sql}"
+        );
+    }
+    assert!(!sql.contains("_hop4"), "SQL:\n{sql}");
+    // Each hop extends the previous one, never itself.
+    assert!(sql.contains("_hop1 vp"), "SQL:\n{sql}");
+    assert!(sql.contains("_hop2 vp"), "SQL:\n{sql}");
+
+    // The same query keeps its recursive CTE when recursion is available.
+    let recursive = crate::clickhouse_query_generator::cypher_to_sql(
+        "MATCH (a:User)-[:FOLLOWS*1..3]->(b:User) WHERE a.user_id = 1 RETURN b.name",
+        &schema(),
+        100,
+    )
+    .unwrap();
+    assert!(recursive.contains("WITH RECURSIVE"), "SQL:\n{recursive}");
+}
+
+#[tokio::test]
+async fn zero_hop_and_min_hop_bounds_survive_unrolling() {
+    let sql = sql_without_recursion("MATCH (a:User)-[:FOLLOWS*0..2]->(b:User) RETURN b.name")
+        .await
+        .expect("zero-hop VLP renders");
+    assert!(sql.contains("_hop0 AS ("), "SQL:\n{sql}");
+    assert!(sql.contains("_hop2 AS ("), "SQL:\n{sql}");
+    assert!(!sql.contains("RECURSIVE"), "SQL:\n{sql}");
+
+    let sql = sql_without_recursion("MATCH (a:User)-[:FOLLOWS*2..3]->(b:User) RETURN b.name")
+        .await
+        .expect("min-hop VLP renders");
+    assert!(sql.contains("hop_count >= 2"), "SQL:\n{sql}");
+    assert!(sql.contains("_inner_hop3 AS ("), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn shortest_path_unrolls_to_its_bound() {
+    let sql = sql_without_recursion(
+        "MATCH p = shortestPath((a:User)-[:FOLLOWS*1..4]->(b:User)) \
+         WHERE a.user_id = 1 AND b.user_id = 9 RETURN length(p)",
+    )
+    .await
+    .expect("shortestPath renders");
+    assert!(!sql.contains("RECURSIVE"), "SQL:\n{sql}");
+    assert!(sql.contains("_hop4 AS ("), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn unbounded_path_is_rejected_with_the_reason() {
+    for cypher in [
+        "MATCH (a:User)-[:FOLLOWS*]->(b:User) RETURN b.name",
+        "MATCH (a:User)-[:FOLLOWS*2..]->(b:User) RETURN b.name",
+    ] {
+        let err = sql_without_recursion(cypher).await.unwrap_err();
+        assert!(err.contains("Unbounded variable-length pattern"), "{err}");
+        assert!(err.contains("upper bound"), "{err}");
+        assert!(err.contains(REASON), "{err}");
+    }
+}
+
+async fn sql_with_fallback_hops(cypher: &str, hops: u32) -> Result<String, String> {
+    let ctx = QueryContext {
+        vlp_fallback_hops: Some(hops),
+        ..QueryContext::default()
+    };
+    with_query_context(ctx, async {
+        crate::clickhouse_query_generator::cypher_to_sql(cypher, &schema(), 100)
+    })
+    .await
+}
+
+#[tokio::test]
+async fn fallback_hops_cap_and_unroll_every_path() {
+    for cypher in [
+        "MATCH (a:User)-[:FOLLOWS*]->(b:User) RETURN b.name",
+        "MATCH (a:User)-[:FOLLOWS*1..10]->(b:User) RETURN b.name",
+    ] {
+        let sql = sql_with_fallback_hops(cypher, 3)
+            .await
+            .expect("fallback plan renders");
+        assert!(!sql.contains("RECURSIVE"), "SQL:\n{sql}");
+        assert!(sql.contains("_hop3 AS ("), "SQL:\n{sql}");
+        assert!(!sql.contains("_hop4"), "SQL:\n{sql}");
+    }
+
+    // A shorter pattern keeps its own bound.
+    let sql = sql_with_fallback_hops("MATCH (a:User)-[:FOLLOWS*1..2]->(b:User) RETURN b.name", 3)
+        .await
+        .unwrap();
+    assert!(sql.contains("_hop2 AS ("), "SQL:\n{sql}");
+    assert!(!sql.contains("_hop3"), "SQL:\n{sql}");
+
+    // The bound never drops below the pattern's lower bound.
+    let sql = sql_with_fallback_hops("MATCH (a:User)-[:FOLLOWS*4..6]->(b:User) RETURN b.name", 3)
+        .await
+        .unwrap();
+    assert!(sql.contains("hop_count >= 4"), "SQL:\n{sql}");
+    assert!(sql.contains("_hop4 AS ("), "SQL:\n{sql}");
+    assert!(!sql.contains("_hop5"), "SQL:\n{sql}");
+}
@@ -0,0 +1,100 @@
+//! Temporal functions in expressions: `datetime()`, `date()` and `duration()`
+//! (map and ISO 8601 string forms) render as ClickHouse timestamps and
+//! intervals, and `+`/`-` with a duration keeps the type of the temporal
+//! operand, including chained arithmetic and comparisons in WHERE.
+
+use crate::{graph_catalog::config::GraphSchemaConfig, graph_catalog::graph_schema::GraphSchema};
+
+const LOG_YAML: &str = r#"
+name: logs
+graph_schema:
+  nodes:
+    - label: Host
+      database: db
+      table: hosts
+      node_id: ip
+      property_mappings:
+        ip: ip
+        first_seen: first_seen_ms
+  edges:
+    - type: CONNECTED
+      database: db
+      table: conn_log
+      from_id: src_ip
+      to_id: dst_ip
+      from_node: Host
+      to_node: Host
+      property_mappings:
+        created_at: ts
+"#;
+
+fn sql(cypher: &str) -> String {
+    let schema: GraphSchema = GraphSchemaConfig::from_yaml_str(LOG_YAML)
+        .expect("parse schema yaml")
+        .to_graph_schema()
+        .expect("build graph schema");
+    crate::clickhouse_query_generator::cypher_to_sql(cypher, &schema, 100)
+        .unwrap_or_else(|e| panic!("{cypher}: {e}"))
+}
+
+#[test]
+fn recent_window_compares_against_native_timestamp() {
+    let sql = sql("MATCH (a:Host)-[r:CONNECTED]->(b:Host) \
+         WHERE r.created_at > datetime() - duration('P30D') RETURN a.ip");
+
+    assert!(
+        sql.contains("r.ts > (now64(3) - toIntervalDay(30))"),
+        "SQL:\n{sql}"
+    );
+    assert!(!sql.contains("duration("), "SQL:\n{sql}");
+    assert!(
+        !sql.contains("parseDateTime64BestEffort(now64"),
+        "SQL:\n{sql}"
+    );
+}
+
+#[test]
+fn iso8601_durations_map_to_intervals() {
+    let sql = sql("RETURN duration('PT1H30M') AS a, duration('P2W') AS b, \
+         duration('P1Y2M3DT4H5M6S') AS c");
+
+    assert!(
+        sql.contains("(toIntervalHour(1) + toIntervalMinute(30)) AS \"a\""),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("toIntervalWeek(2) AS \"b\""), "SQL:\n{sql}");
+    assert!(
+        sql.contains(
+            "(toIntervalYear(1) + toIntervalMonth(2) + toIntervalDay(3) + \
+             toIntervalHour(4) + toIntervalMinute(5) + toIntervalSecond(6)) AS \"c\""
+        ),
+        "SQL:\n{sql}"
+    );
+}
+
+#[test]
+fn chained_date_arithmetic_stays_a_date() {
+    let sql = sql("MATCH (h:Host) \
+         WHERE h.first_seen >= date('2024-01-01') + duration({months: 1}) + duration('P2D') \
+         RETURN h.ip");
+
+    assert!(
+        sql.contains("((toDate('2024-01-01') + toIntervalMonth(1)) + toIntervalDay(2))"),
+        "SQL:\n{sql}"
+    );
+}
+
+#[test]
+fn epoch_millis_property_round_trips_through_chained_intervals() {
+    let sql = sql("MATCH (h:Host) \
+         RETURN h.first_seen + duration({days: 7}) - duration('PT1H') AS d");
+
+    let inner =
+        "toUnixTimestamp64Milli(fromUnixTimestamp64Milli(h.first_seen_ms) + toIntervalDay(7))";
+    assert!(
+        sql.contains(&format!(
+            "toUnixTimestamp64Milli(fromUnixTimestamp64Milli({inner}) - toIntervalHour(1))"
+        )),
+        "SQL:\n{sql}"
+    );
+}
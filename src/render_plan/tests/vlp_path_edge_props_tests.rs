@@ -0,0 +1,415 @@
+//! Tests for `reduce()` over `relationships(p)` reading edge properties.
+//!
+//! The VLP CTE carries no per-hop edge rows, so a reduce that reads `r.prop`
+//! is served from `path_edge_<prop>` arrays accumulated hop by hop inside the
+//! recursive CTE, and the reduce folds over those arrays instead. Denormalized
+//! (single-table) paths read each hop's values off the edge row itself.
+
+use crate::server::query_context::{with_query_context, QueryContext};
+use crate::{
+    clickhouse_query_generator,
+    graph_catalog::config::Identifier,
+    graph_catalog::graph_schema::{GraphSchema, NodeIdSchema, NodeSchema, RelationshipSchema},
+    graph_catalog::schema_types::SchemaType,
+    open_cypher_parser,
+    query_planner::logical_plan::plan_builder::build_logical_plan,
+    render_plan::plan_builder::RenderPlanBuilder,
+};
+use std::collections::HashMap;
+
+fn prop_col(name: &str) -> crate::graph_catalog::expression_parser::PropertyValue {
+    crate::graph_catalog::expression_parser::PropertyValue::Column(name.to_string())
+}
+
+/// Person/KNOWS schema where KNOWS carries `weight` and a renamed `since`
+/// (`since_year`) so the tests can check that property mappings are honoured.
+fn setup_weighted_knows_schema() -> GraphSchema {
+    let mut nodes = HashMap::new();
+    let mut relationships = HashMap::new();
+
+    // Person node with 6 properties (id + 5 data properties)
+    let person_node = NodeSchema {
+        database: "test_db".to_string(),
+        table_name: "persons".to_string(),
+        column_names: vec![
+            "id".to_string(),
+            "first_name".to_string(),
+            "last_name".to_string(),
+            "email".to_string(),
+            "age".to_string(),
+            "city".to_string(),
+        ],
+        primary_keys: "id".to_string(),
+        node_id: NodeIdSchema::single("id".to_string(), SchemaType::Integer),
+        property_mappings: [
+            ("id".to_string(), prop_col("id")),
+            ("firstName".to_string(), prop_col("first_name")),
+            ("lastName".to_string(), prop_col("last_name")),
+            ("email".to_string(), prop_col("email")),
+            ("age".to_string(), prop_col("age")),
+            ("city".to_string(), prop_col("city")),
+        ]
+        .into_iter()
+        .collect(),
+        view_parameters: None,
+        engine: None,
+        use_final: None,
+        filter: None,
+        is_denormalized: false,
+        from_properties: None,
+        to_properties: None,
+        denormalized_source_table: None,
+        label_column: None,
+        label_value: None,
+        node_id_types: None,
+        source: None,
+        property_types: HashMap::new(),
+        id_generation: None,
+    };
+    nodes.insert("Person".to_string(), person_node);
+
+    let knows_rel = RelationshipSchema {
+        database: "test_db".to_string(),
+        table_name: "knows".to_string(),
+        column_names: vec![
+            "from_id".to_string(),
+            "to_id".to_string(),
+            "weight".to_string(),
+            "since_year".to_string(),
+        ],
+        from_node: "Person".to_string(),
+        to_node: "Person".to_string(),
+        from_node_table: "persons".to_string(),
+        to_node_table: "persons".to_string(),
+        from_id: Identifier::from("from_id"),
+        to_id: Identifier::from("to_id"),
+        from_node_id_dtype: SchemaType::Integer,
+        to_node_id_dtype: SchemaType::Integer,
+        property_mappings: [
+            ("weight".to_string(), prop_col("weight")),
+            ("since".to_string(), prop_col("since_year")),
+        ]
+        .into_iter()
+        .collect(),
+        view_parameters: None,
+        engine: None,
+        use_final: None,
+        filter: None,
+        edge_id: None,
+        type_column: None,
+        from_label_column: None,
+        to_label_column: None,
+        from_node_properties: None,
+        to_node_properties: None,
+        from_label_values: None,
+        to_label_values: None,
+        is_fk_edge: false,
+        constraints: None,
+        edge_id_types: None,
+        source: None,
+        property_types: HashMap::new(),
+    };
+    relationships.insert("KNOWS::Person::Person".to_string(), knows_rel);
+
+    GraphSchema::build(1, "test_db".to_string(), nodes, relationships)
+}
+
+fn cypher_to_sql(cypher: &str) -> String {
+    let ast = open_cypher_parser::parse_query(cypher).expect("Failed to parse Cypher query");
+    let graph_schema = setup_weighted_knows_schema();
+
+    let (logical_plan, mut plan_ctx) = build_logical_plan(&ast, &graph_schema, None, None, None)
+        .expect("Failed to build logical plan");
+
+    use crate::query_planner::analyzer;
+    use crate::query_planner::optimizer;
+
+    let logical_plan =
+        analyzer::initial_analyzing(logical_plan, &mut plan_ctx, &graph_schema).unwrap();
+    let logical_plan =
+        analyzer::intermediate_analyzing(logical_plan, &mut plan_ctx, &graph_schema).unwrap();
+    let logical_plan = optimizer::initial_optimization(logical_plan, &mut plan_ctx).unwrap();
+    let logical_plan = optimizer::final_optimization(logical_plan, &mut plan_ctx).unwrap();
+
+    let render_plan = logical_plan
+        .to_render_plan(&graph_schema)
+        .expect("Failed to build render plan");
+
+    clickhouse_query_generator::generate_sql(render_plan, 100)
+}
+
+/// Render inside a query-context scope so reduce expressions found outside the
+/// subtree that builds the VLP CTE (ORDER BY, WITH) are registered query-wide.
+async fn cypher_to_sql_scoped(cypher: &str) -> String {
+    with_query_context(QueryContext::default(), async { cypher_to_sql(cypher) }).await
+}
+
+#[test]
+fn test_reduce_single_edge_property_folds_over_edge_array() {
+    let sql = cypher_to_sql(
+        "MATCH p = (a:Person {id: 1})-[:KNOWS*1..3]->(b:Person) \
+         RETURN reduce(total = 0, r IN relationships(p) | total + r.weight) AS w",
+    );
+
+    assert!(
+        sql.contains("[rel.weight] as path_edge_weight"),
+        "Base case should seed the edge property array. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("arrayConcat(vp.path_edge_weight, [rel.weight]) as path_edge_weight"),
+        "Recursive case should append to the edge property array. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("arrayFold(r, total -> total + r, t.path_edge_weight"),
+        "reduce should fold over the edge property array. SQL: {}",
+        sql
+    );
+}
+
+#[test]
+fn test_reduce_multiple_edge_properties_zip_arrays() {
+    let sql = cypher_to_sql(
+        "MATCH p = (a:Person {id: 1})-[:KNOWS*1..3]->(b:Person) \
+         RETURN reduce(total = 0, r IN relationships(p) | total + r.weight * r.since) AS w",
+    );
+
+    assert!(
+        sql.contains("[rel.since_year] as path_edge_since"),
+        "Edge property arrays should use the mapped column. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("arrayZip(t.path_edge_since, t.path_edge_weight)"),
+        "Several properties should be zipped in sorted order. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("tupleElement(r, 2) * tupleElement(r, 1)"),
+        "Property accesses should become tuple elements. SQL: {}",
+        sql
+    );
+}
+
+#[test]
+fn test_reduce_without_edge_properties_keeps_relationships_array() {
+    let sql = cypher_to_sql(
+        "MATCH p = (a:Person)-[:KNOWS*1..2]->(b:Person) \
+         RETURN reduce(s = 0, r IN relationships(p) | s + 1) AS n",
+    );
+
+    assert!(
+        !sql.contains("path_edge_"),
+        "Counting reduce should not materialise edge property arrays. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("t.path_relationships"),
+        "Counting reduce should still fold over path_relationships. SQL: {}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn test_reduce_in_order_by_materialises_edge_array() {
+    let sql = cypher_to_sql_scoped(
+        "MATCH p = (a:Person)-[:KNOWS*1..3]->(b:Person) \
+         RETURN b.id ORDER BY reduce(w = 0.0, r IN relationships(p) | w + r.weight)",
+    )
+    .await;
+
+    assert!(
+        sql.contains("as path_edge_weight"),
+        "ORDER BY reduce should still materialise the edge array. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("t.path_edge_weight"),
+        "ORDER BY reduce should fold over the edge array. SQL: {}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn test_reduce_in_with_clause_folds_over_edge_array() {
+    let sql = cypher_to_sql_scoped(
+        "MATCH p = (a:Person)-[:KNOWS*1..3]->(b:Person) \
+         WITH b, reduce(w = 0.0, r IN relationships(p) | w + r.weight) AS cost \
+         RETURN b.id, cost ORDER BY cost",
+    )
+    .await;
+
+    assert!(
+        sql.contains("as path_edge_weight"),
+        "WITH reduce should materialise the edge array. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("arrayFold(r, w -> w + r, path_edge_weight"),
+        "WITH reduce should fold over the edge array. SQL: {}",
+        sql
+    );
+}
+
+/// Denormalized flights table: airports live only in the origin/destination
+/// column sets, and each row (hop) carries its own carrier and flight number.
+const FLIGHTS_SCHEMA_YAML: &str = r#"
+name: denorm_flights_path_props
+version: "1.0"
+graph_schema:
+  nodes:
+    - label: Airport
+      database: test_db
+      table: flights
+      node_id: code
+      property_mappings: {}
+      from_node_properties:
+        code: Origin
+        city: OriginCityName
+      to_node_properties:
+        code: Dest
+        city: DestCityName
+  edges:
+    - type: FLIGHT
+      database: test_db
+      table: flights
+      from_id: Origin
+      to_id: Dest
+      from_node: Airport
+      to_node: Airport
+      property_mappings:
+        carrier: Reporting_Airline
+        flight_num: Flight_Number_Reporting_Airline
+"#;
+
+async fn denormalized_cypher_to_sql(cypher: &str) -> String {
+    use crate::graph_catalog::config::GraphSchemaConfig;
+    use std::sync::Arc;
+
+    let schema = Arc::new(
+        GraphSchemaConfig::from_yaml_str(FLIGHTS_SCHEMA_YAML)
+            .expect("parse schema yaml")
+            .to_graph_schema()
+            .expect("build graph schema"),
+    );
+    with_query_context(QueryContext::default(), async move {
+        crate::server::query_context::set_current_schema(Arc::clone(&schema));
+        clickhouse_query_generator::cypher_to_sql(cypher, &schema, 100).expect("translate cypher")
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_denormalized_reduce_accumulates_per_hop_edge_properties() {
+    let sql = denormalized_cypher_to_sql(
+        "MATCH p = (a:Airport {code: 'LAX'})-[:FLIGHT*1..3]->(b:Airport) \
+         RETURN b.code, reduce(s = [], r IN relationships(p) | s + [r.carrier]) AS carriers",
+    )
+    .await;
+
+    assert!(
+        sql.contains(".Reporting_Airline] as path_edge_carrier"),
+        "Base case should seed the array from the edge's own column. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains(
+            "arrayConcat(vp.path_edge_carrier, [next.Reporting_Airline]) as path_edge_carrier"
+        ),
+        "Recursive case should append the next hop's value. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("t.path_edge_carrier"),
+        "reduce should fold over the per-hop array. SQL: {}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn test_denormalized_reduce_zips_several_edge_properties() {
+    let sql = denormalized_cypher_to_sql(
+        "MATCH p = (a:Airport {code: 'LAX'})-[:FLIGHT*1..2]->(b:Airport) \
+         RETURN reduce(s = [], r IN relationships(p) | s + [r.carrier + r.flight_num]) AS legs",
+    )
+    .await;
+
+    assert!(
+        sql.contains(".Flight_Number_Reporting_Airline] as path_edge_flight_num"),
+        "Every folded property should get its own array. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("arrayZip(t.path_edge_carrier, t.path_edge_flight_num)"),
+        "Several properties should be zipped in sorted order. SQL: {}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn test_denormalized_zero_hop_edge_property_arrays_are_typed_empty() {
+    let sql = denormalized_cypher_to_sql(
+        "MATCH p = (a:Airport {code: 'LAX'})-[:FLIGHT*0..2]->(b:Airport) \
+         RETURN reduce(s = [], r IN relationships(p) | s + [r.carrier]) AS carriers",
+    )
+    .await;
+
+    assert!(
+        sql.contains(".Reporting_Airline], 1, 0) AS __edge_0"),
+        "Zero-hop rows should carry an empty array typed like the column. SQL: {}",
+        sql
+    );
+    assert!(
+        sql.contains("node_universe.__edge_0 as path_edge_carrier"),
+        "Zero-hop arm should project the empty array. SQL: {}",
+        sql
+    );
+}
+
+/// Edge properties `collect_path_edge_properties` finds in the logical plan
+/// of `cypher`, per path variable.
+fn path_edge_properties(cypher: &str) -> HashMap<String, Vec<String>> {
+    let ast = open_cypher_parser::parse_query(cypher).expect("Failed to parse Cypher query");
+    let (plan, _) = build_logical_plan(&ast, &setup_weighted_knows_schema(), None, None, None)
+        .expect("Failed to build logical plan");
+    crate::render_plan::cte_extraction::collect_path_edge_properties(&plan)
+        .into_iter()
+        .map(|(path, props)| (path, props.into_iter().collect()))
+        .collect()
+}
+
+#[test]
+fn test_path_edge_properties_found_in_where() {
+    let props = path_edge_properties(
+        "MATCH p = (a:Person)-[:KNOWS*1..3]->(b:Person) \
+         WHERE reduce(w = 0.0, r IN relationships(p) | w + r.weight) < 10 \
+         RETURN b.id",
+    );
+    assert_eq!(props["p"], ["weight"]);
+
+    let props = path_edge_properties(
+        "MATCH p = (a:Person)-[:KNOWS*1..3]->(b:Person) \
+         WITH p, b WHERE reduce(w = 0.0, r IN relationships(p) | w + r.since) > 2000 \
+         RETURN b.id",
+    );
+    assert_eq!(props["p"], ["since"]);
+}
+
+#[test]
+fn test_path_edge_properties_found_in_nested_expressions() {
+    // Inside a lambda, the shape list comprehensions take in SQL
+    let props = path_edge_properties(
+        "MATCH p = (a:Person)-[:KNOWS*1..3]->(b:Person) \
+         RETURN ch.arrayMap(x -> x * reduce(w = 0.0, r IN relationships(p) | w + r.weight), [1, 2]) AS ws",
+    );
+    assert_eq!(props["p"], ["weight"]);
+
+    // Inside a list, a subscript and an IN list
+    let props = path_edge_properties(
+        "MATCH p = (a:Person)-[:KNOWS*1..3]->(b:Person) \
+         RETURN [reduce(w = 0.0, r IN relationships(p) | w + r.weight)][1] AS first, \
+                reduce(s = 0, r IN relationships(p) | s + r.since) IN [1, 2] AS known",
+    );
+    assert_eq!(props["p"], ["since", "weight"]);
+}
@@ -19,7 +19,10 @@ use crate::open_cypher_parser;
 use crate::query_planner::analyzer;
 use crate::query_planner::logical_plan::plan_builder::build_logical_plan;
 use crate::query_planner::optimizer;
-use crate::render_plan::write_plan_builder::build_write_plan;
+use crate::render_plan::write_plan_builder::{
+    build_write_plan, build_write_plan_with_parameters, WriteOptions, WriteRenderError,
+};
+use crate::render_plan::WriteRenderPlan;
 use std::collections::HashMap;
 
 fn prop_col(name: &str) -> PropertyValue {
@@ -61,7 +64,11 @@ fn knows_rel() -> RelationshipSchema {
     RelationshipSchema {
         database: "test".to_string(),
         table_name: "knows".to_string(),
-        column_names: vec!["from_id".to_string(), "to_id".to_string()],
+        column_names: vec![
+            "from_id".to_string(),
+            "to_id".to_string(),
+            "since".to_string(),
+        ],
         from_node: "Person".to_string(),
         to_node: "Person".to_string(),
         from_node_table: "person".to_string(),
@@ -70,7 +77,9 @@ fn knows_rel() -> RelationshipSchema {
         to_id: Identifier::from("to_id"),
         from_node_id_dtype: SchemaType::String,
         to_node_id_dtype: SchemaType::String,
-        property_mappings: HashMap::new(),
+        property_mappings: [("since".to_string(), prop_col("since"))]
+            .into_iter()
+            .collect(),
         view_parameters: None,
         engine: None,
         use_final: None,
@@ -102,8 +111,33 @@ fn build_test_schema() -> GraphSchema {
 }
 
 fn cypher_to_write_sql(cypher: &str) -> Vec<String> {
+    cypher_to_write_sql_with(cypher, &build_test_schema())
+}
+
+fn cypher_to_write_sql_with(cypher: &str, schema: &GraphSchema) -> Vec<String> {
+    let write_plan = cypher_to_write_plan(cypher, schema, WriteOptions::default())
+        .expect("write build")
+        .expect("write plan present");
+
+    write_render_to_sql(&write_plan)
+}
+
+fn cypher_to_write_plan(
+    cypher: &str,
+    schema: &GraphSchema,
+    options: WriteOptions,
+) -> Result<Option<WriteRenderPlan>, WriteRenderError> {
+    cypher_to_write_plan_with_parameters(cypher, schema, options, &HashMap::new())
+}
+
+fn cypher_to_write_plan_with_parameters(
+    cypher: &str,
+    schema: &GraphSchema,
+    options: WriteOptions,
+    parameters: &HashMap<String, serde_json::Value>,
+) -> Result<Option<WriteRenderPlan>, WriteRenderError> {
     let ast = open_cypher_parser::parse_query(cypher).expect("parse");
-    let schema = build_test_schema();
+    let schema = schema.clone();
     let (logical_plan, mut plan_ctx) =
         build_logical_plan(&ast, &schema, None, None, None).expect("plan");
 
@@ -118,11 +152,7 @@ fn cypher_to_write_sql(cypher: &str) -> Vec<String> {
 
     let plan = std::sync::Arc::try_unwrap(logical_plan).unwrap_or_else(|arc| (*arc).clone());
 
-    let write_plan = build_write_plan(&plan, &schema)
-        .expect("write build")
-        .expect("write plan present");
-
-    write_render_to_sql(&write_plan)
+    build_write_plan_with_parameters(&plan, &schema, options, parameters)
 }
 
 // ---------- CREATE ----------
@@ -147,34 +177,322 @@ fn create_single_node_emits_insert() {
 }
 
 #[test]
-fn create_multiple_standalone_nodes_emit_sequence() {
-    // Two independent CREATE patterns produce two INSERTs.
+fn create_multiple_standalone_nodes_batch_into_one_insert() {
+    // Rows for the same table and column list share one multi-row INSERT.
     let sql = cypher_to_write_sql(
         "CREATE (a:Person {id: 'u1', name: 'Alice'}), (b:Person {id: 'u2', name: 'Bob'})",
     );
+    assert_eq!(
+        sql,
+        vec!["INSERT INTO `test`.`person` (`id`, `name`) VALUES ('u1', 'Alice'), ('u2', 'Bob')"]
+    );
+}
+
+#[test]
+fn create_nodes_with_different_columns_emit_separate_inserts() {
+    let sql =
+        cypher_to_write_sql("CREATE (a:Person {id: 'u1', name: 'Alice'}), (b:Person {id: 'u2'})");
     assert_eq!(sql.len(), 2, "got: {:?}", sql);
     assert!(sql[0].contains("'u1'"));
     assert!(sql[1].contains("'u2'"));
 }
 
 #[test]
-fn create_relationship_between_aliases_is_rejected_with_clear_message() {
+fn create_relationship_between_created_nodes_inserts_edge_row() {
+    let sql = cypher_to_write_sql(
+        "CREATE (a:Person {id: 'u1'})-[:KNOWS {since: 2020}]->(b:Person {id: 'u2'})",
+    );
+    assert_eq!(
+        sql,
+        vec![
+            "INSERT INTO `test`.`person` (`id`) VALUES ('u1'), ('u2')",
+            "INSERT INTO `test`.`knows` (`from_id`, `to_id`, `since`) VALUES ('u1', 'u2', 2020)",
+        ]
+    );
+}
+
+#[test]
+fn create_incoming_relationship_swaps_endpoint_columns() {
+    let sql = cypher_to_write_sql(
+        "CREATE (a:Person {id: 'u1'})<-[:KNOWS]-(b:Person {id: 'u2'}), \
+         (a)-[:KNOWS]->(c:Person {id: 'u3'})",
+    );
+    assert_eq!(
+        sql,
+        vec![
+            "INSERT INTO `test`.`person` (`id`) VALUES ('u1'), ('u2'), ('u3')",
+            "INSERT INTO `test`.`knows` (`from_id`, `to_id`) VALUES ('u2', 'u1'), ('u1', 'u3')",
+        ]
+    );
+}
+
+#[test]
+fn create_relationship_between_matched_aliases_emits_insert_select() {
+    let sql = cypher_to_write_sql(
+        "MATCH (a:Person), (b:Person) WHERE a.id = 'u1' AND b.id = 'u2' \
+         CREATE (a)-[:KNOWS {since: 2021}]->(b)",
+    );
+    assert_eq!(sql.len(), 1, "got: {:?}", sql);
+    let stmt = &sql[0];
+    assert!(
+        stmt.starts_with("INSERT INTO `test`.`knows` (`from_id`, `to_id`, `since`) SELECT"),
+        "got: {}",
+        stmt
+    );
+    assert!(stmt.contains("a.id AS \"from_id\""), "got: {}", stmt);
+    assert!(stmt.contains("b.id AS \"to_id\""), "got: {}", stmt);
+    assert!(stmt.contains("2021 AS \"since\""), "got: {}", stmt);
+    assert!(
+        stmt.contains("'u1'") && stmt.contains("'u2'"),
+        "got: {}",
+        stmt
+    );
+    assert!(!stmt.contains("VALUES"), "got: {}", stmt);
+}
+
+#[test]
+fn create_relationship_from_matched_to_created_node() {
+    let sql = cypher_to_write_sql(
+        "MATCH (a:Person) WHERE a.id = 'u1' CREATE (a)-[:KNOWS]->(b:Person {id: 'u3'})",
+    );
+    assert_eq!(sql.len(), 2, "got: {:?}", sql);
+    assert_eq!(sql[0], "INSERT INTO `test`.`person` (`id`) VALUES ('u3')");
+    assert!(
+        sql[1].starts_with("INSERT INTO `test`.`knows` (`from_id`, `to_id`) SELECT"),
+        "got: {}",
+        sql[1]
+    );
+    assert!(sql[1].contains("a.id AS \"from_id\""), "got: {}", sql[1]);
+    assert!(sql[1].contains("'u3' AS \"to_id\""), "got: {}", sql[1]);
+}
+
+#[test]
+fn create_relationship_requires_endpoint_ids() {
+    let schema = build_test_schema();
     let ast = open_cypher_parser::parse_query(
-        "MATCH (a:Person {id:'u1'}), (b:Person {id:'u2'}) CREATE (a)-[:KNOWS]->(b)",
+        "CREATE (a:Person {name: 'Alice'})-[:KNOWS]->(b:Person {id: 'u2'})",
     )
     .expect("parse");
-    let schema = build_test_schema();
     let (plan, _ctx) = build_logical_plan(&ast, &schema, None, None, None).expect("plan");
     let plan = std::sync::Arc::try_unwrap(plan).unwrap_or_else(|arc| (*arc).clone());
     let err = build_write_plan(&plan, &schema).expect_err("must error");
     let msg = format!("{}", err);
     assert!(
-        msg.contains("KNOWS") && msg.contains("not supported"),
+        msg.contains("endpoint `a`") && msg.contains("without an ID value"),
         "got `{}`",
         msg
     );
 }
 
+#[test]
+fn create_validates_against_property_mappings() {
+    use crate::clickhouse_query_generator::IdStrategy;
+
+    let mut person = person_node();
+    person.property_mappings.insert(
+        "label".to_string(),
+        PropertyValue::Expression("upper(name)".to_string()),
+    );
+    person.id_generation = Some(IdStrategy::Provided);
+    let mut nodes = HashMap::new();
+    nodes.insert("Person".to_string(), person);
+    let mut rel = knows_rel();
+    rel.property_mappings.insert(
+        "years".to_string(),
+        PropertyValue::Expression("2024 - since".to_string()),
+    );
+    let mut rels = HashMap::new();
+    rels.insert("KNOWS::Person::Person".to_string(), rel);
+    let schema = GraphSchema::build(1, "test".to_string(), nodes, rels);
+
+    for (cypher, expected) in [
+        (
+            "CREATE (a:Person {id: 'u1', label: 'X'})",
+            "property `label` cannot be mapped to a writable column",
+        ),
+        (
+            "CREATE (a:Person {name: 'Alice'})",
+            "ID column `id` must be supplied",
+        ),
+        (
+            "CREATE (a:Person {id: 'u1'})-[:KNOWS {years: 3}]->(b:Person {id: 'u2'})",
+            "property `years` cannot be mapped to a writable column",
+        ),
+    ] {
+        let ast = open_cypher_parser::parse_query(cypher).expect("parse");
+        let (plan, _ctx) = build_logical_plan(&ast, &schema, None, None, None).expect(cypher);
+        let plan = std::sync::Arc::try_unwrap(plan).unwrap_or_else(|arc| (*arc).clone());
+        let err = build_write_plan(&plan, &schema).expect_err(cypher);
+        assert!(err.to_string().contains(expected), "{cypher}: got `{err}`");
+    }
+}
+
+#[test]
+fn create_fills_generated_id_column() {
+    use crate::clickhouse_query_generator::IdStrategy;
+
+    let mut person = person_node();
+    person.id_generation = Some(IdStrategy::Snowflake);
+    let mut nodes = HashMap::new();
+    nodes.insert("Person".to_string(), person);
+    let schema = GraphSchema::build(1, "test".to_string(), nodes, HashMap::new());
+
+    let sql = cypher_to_write_sql_with("CREATE (a:Person {name: 'Alice'})", &schema);
+    assert_eq!(
+        sql,
+        vec!["INSERT INTO `test`.`person` (`name`, `id`) VALUES ('Alice', generateSnowflakeID())"]
+    );
+}
+
+// ---------- UNWIND ... CREATE / FOREACH ----------
+
+#[test]
+fn unwind_literal_rows_create_batches_into_one_insert() {
+    let sql = cypher_to_write_sql(
+        "UNWIND [{id: 'u1', name: 'Alice'}, {id: 'u2', name: 'Bob'}] AS row \
+         CREATE (:Person {id: row.id, name: row.name})",
+    );
+    assert_eq!(
+        sql,
+        vec!["INSERT INTO `test`.`person` (`id`, `name`) VALUES ('u1', 'Alice'), ('u2', 'Bob')"]
+    );
+}
+
+#[test]
+fn unwind_parameter_rows_create_nodes_and_edges() {
+    let parameters: HashMap<String, serde_json::Value> = [(
+        "rows".to_string(),
+        serde_json::json!([
+            {"from": "u1", "to": "u2", "since": 2020},
+            {"from": "u3", "to": "u4", "since": 2021},
+        ]),
+    )]
+    .into_iter()
+    .collect();
+    let write_plan = cypher_to_write_plan_with_parameters(
+        "UNWIND $rows AS row \
+         CREATE (a:Person {id: row.from})-[:KNOWS {since: row.since}]->(b:Person {id: row.to})",
+        &build_test_schema(),
+        WriteOptions::default(),
+        &parameters,
+    )
+    .expect("write build")
+    .expect("write plan present");
+    assert_eq!(
+        write_render_to_sql(&write_plan),
+        vec![
+            "INSERT INTO `test`.`person` (`id`) VALUES ('u1'), ('u2'), ('u3'), ('u4')",
+            "INSERT INTO `test`.`knows` (`from_id`, `to_id`, `since`) \
+             VALUES ('u1', 'u2', 2020), ('u3', 'u4', 2021)",
+        ]
+    );
+}
+
+#[test]
+fn unwind_parameter_without_value_is_rejected() {
+    let err = cypher_to_write_plan(
+        "UNWIND $rows AS row CREATE (:Person {id: row.id})",
+        &build_test_schema(),
+        WriteOptions::default(),
+    )
+    .expect_err("missing $rows");
+    assert!(
+        err.to_string().contains("no value was supplied for `rows`"),
+        "got: {err}"
+    );
+}
+
+#[test]
+fn foreach_create_matches_unwind_create() {
+    let sql = cypher_to_write_sql(
+        "FOREACH (x IN ['u1', 'u2'] | CREATE (:Person {id: x}), (:Person {id: x + '-copy'}))",
+    );
+    assert_eq!(sql.len(), 1, "got: {:?}", sql);
+    assert!(
+        sql[0].starts_with("INSERT INTO `test`.`person` (`id`) VALUES ('u1'), "),
+        "got: {}",
+        sql[0]
+    );
+    assert_eq!(sql[0].matches("), (").count(), 3, "got: {}", sql[0]);
+}
+
+#[test]
+fn foreach_with_return_is_rejected() {
+    let ast = open_cypher_parser::parse_query(
+        "FOREACH (x IN [1, 2] | CREATE (:Person {id: x})) RETURN 1",
+    )
+    .expect("parse");
+    let err = build_logical_plan(&ast, &build_test_schema(), None, None, None)
+        .map(|_| ())
+        .expect_err("FOREACH ... RETURN");
+    assert!(err.to_string().contains("FOREACH"), "got `{err}`");
+}
+
+#[test]
+fn unwind_after_match_creates_through_insert_select() {
+    // The list is expanded per matched row, so it renders as one
+    // INSERT ... SELECT over the read pipeline instead of literal rows.
+    let sql = cypher_to_write_sql(
+        "MATCH (p:Person) WHERE p.id = 'u1' UNWIND ['a', 'b'] AS suffix \
+         CREATE (:Person {id: suffix, name: p.name})",
+    );
+    assert_eq!(sql.len(), 1, "got: {:?}", sql);
+    assert!(
+        sql[0].starts_with("INSERT INTO `test`.`person` (`id`, `name`) SELECT "),
+        "got: {}",
+        sql[0]
+    );
+    assert!(sql[0].contains("ARRAY JOIN"), "got: {}", sql[0]);
+    assert!(!sql[0].contains("VALUES"), "got: {}", sql[0]);
+}
+
+// ---------- MERGE ----------
+
+#[test]
+fn merge_node_emits_insert_select_guarded_by_match_count() {
+    let sql = cypher_to_write_sql("MERGE (a:Person {id: 'u1'}) ON CREATE SET a.name = 'Alice'");
+    assert_eq!(sql.len(), 1, "got: {:?}", sql);
+    assert_eq!(
+        sql[0],
+        "INSERT INTO `test`.`person` (`id`, `name`) SELECT 'u1', 'Alice' \
+         WHERE (SELECT count() FROM `test`.`person` WHERE `id` = 'u1') = 0"
+    );
+}
+
+#[test]
+fn merge_unsupported_forms_are_rejected() {
+    let schema = build_test_schema();
+    for (cypher, expected) in [
+        (
+            "MERGE (a:Person {id: 'u1'}) ON MATCH SET a.age = 31",
+            "ON MATCH SET",
+        ),
+        (
+            "MERGE (a:Person {id: 'u1'})-[:KNOWS]->(b:Person {id: 'u2'})",
+            "single node pattern",
+        ),
+        ("MERGE (a {id: 'u1'})", "requires a node label"),
+        (
+            "MATCH (b:Person) MERGE (a:Person {id: 'u1'})",
+            "MERGE must be the first clause",
+        ),
+        (
+            "MERGE (a:Person {id: 'u1'}) ON CREATE SET a.email = 'x'",
+            "property `email` is not defined",
+        ),
+        (
+            "MERGE (a:Person {id: 'u1'}) ON CREATE SET a.id = 'u2'",
+            "already part of the MERGE pattern",
+        ),
+    ] {
+        let ast = open_cypher_parser::parse_query(cypher).expect("parse");
+        let err = build_logical_plan(&ast, &schema, None, None, None)
+            .map(|_| ())
+            .expect_err(cypher);
+        assert!(err.to_string().contains(expected), "{cypher}: got `{err}`");
+    }
+}
+
 // ---------- DELETE ----------
 
 #[test]
@@ -225,6 +543,155 @@ fn detach_delete_chains_rel_deletes_before_node_delete() {
     }
 }
 
+#[test]
+fn delete_on_soft_delete_tables_marks_rows_instead() {
+    use crate::graph_catalog::graph_schema::{SoftDelete, SoftDeleteMarker};
+    use std::collections::BTreeMap;
+
+    let mut schema = build_test_schema();
+    schema
+        .set_soft_deletes(BTreeMap::from([
+            (
+                "test.person".to_string(),
+                SoftDelete {
+                    column: "deleted_at".to_string(),
+                    marker: SoftDeleteMarker::Timestamp,
+                },
+            ),
+            (
+                "test.knows".to_string(),
+                SoftDelete {
+                    column: "is_deleted".to_string(),
+                    marker: SoftDeleteMarker::Flag,
+                },
+            ),
+        ]))
+        .unwrap();
+
+    let sql = cypher_to_write_sql_with(
+        "MATCH (a:Person) WHERE a.id = 'u1' DETACH DELETE a",
+        &schema,
+    );
+    assert_eq!(sql.len(), 3, "got {:?}", sql);
+    for stmt in &sql[..2] {
+        assert!(
+            stmt.starts_with("UPDATE `test`.`knows` SET `is_deleted` = 1"),
+            "got: {}",
+            stmt
+        );
+    }
+    let node = &sql[2];
+    assert!(
+        node.starts_with("UPDATE `test`.`person` SET `deleted_at` = now()"),
+        "got: {}",
+        node
+    );
+    assert!(node.contains("WHERE `id` IN ("), "got: {}", node);
+    // Rows already marked deleted are not matched again.
+    assert!(node.contains("deleted_at IS NULL"), "got: {}", node);
+    assert!(
+        sql.iter().all(|s| !s.starts_with("DELETE")),
+        "got {:?}",
+        sql
+    );
+}
+
+#[test]
+fn delete_on_engine_without_lightweight_delete_uses_mutation() {
+    use crate::graph_catalog::engine_detection::TableEngine;
+
+    let mut person = person_node();
+    person.engine = Some(TableEngine::Other("Memory".to_string()));
+    let mut nodes = HashMap::new();
+    nodes.insert("Person".to_string(), person);
+    let mut rels = HashMap::new();
+    rels.insert("KNOWS::Person::Person".to_string(), knows_rel());
+    let schema = GraphSchema::build(1, "test".to_string(), nodes, rels);
+
+    let sql = cypher_to_write_sql_with(
+        "MATCH (a:Person) WHERE a.id = 'u1' DETACH DELETE a",
+        &schema,
+    );
+    assert_eq!(sql.len(), 3, "got {:?}", sql);
+    // The edge table has no detected engine and keeps the lightweight path.
+    for stmt in &sql[..2] {
+        assert!(
+            stmt.starts_with("DELETE FROM `test`.`knows`"),
+            "got: {}",
+            stmt
+        );
+    }
+    assert!(
+        sql[2].starts_with("ALTER TABLE `test`.`person` DELETE WHERE `id` IN ("),
+        "got: {}",
+        sql[2]
+    );
+    assert!(
+        sql[2].ends_with("SETTINGS mutations_sync = 1"),
+        "got: {}",
+        sql[2]
+    );
+}
+
+#[test]
+fn delete_safety_options() {
+    let schema = build_test_schema();
+    let guarded = WriteOptions {
+        require_delete_predicate: true,
+        ..WriteOptions::default()
+    };
+    for cypher in [
+        "MATCH (a:Person) WHERE a.id = 'u1' DELETE a",
+        "MATCH (a:Person {id: 'u1'}) DETACH DELETE a",
+    ] {
+        cypher_to_write_plan(cypher, &schema, guarded)
+            .unwrap_or_else(|e| panic!("{cypher}: {e}"))
+            .expect("write plan present");
+    }
+    for cypher in [
+        "MATCH (a:Person) DELETE a",
+        "MATCH (a:Person) DETACH DELETE a",
+    ] {
+        let err = cypher_to_write_plan(cypher, &schema, guarded).expect_err(cypher);
+        assert!(
+            err.to_string().contains("without a WHERE clause"),
+            "{cypher}: got `{err}`"
+        );
+        // Unguarded, the same statement is accepted.
+        assert!(cypher_to_write_plan(cypher, &schema, WriteOptions::default()).is_ok());
+    }
+
+    let no_deletes = WriteOptions {
+        allow_deletes: false,
+        ..WriteOptions::default()
+    };
+    let err = cypher_to_write_plan(
+        "MATCH (a:Person) WHERE a.id = 'u1' DELETE a",
+        &schema,
+        no_deletes,
+    )
+    .expect_err("deletes disabled");
+    assert!(err.to_string().contains("allow_deletes"), "got `{err}`");
+    // Other writes are unaffected.
+    assert!(cypher_to_write_plan(
+        "MATCH (a:Person) WHERE a.id = 'u1' SET a.age = 31",
+        &schema,
+        no_deletes
+    )
+    .is_ok());
+}
+
+#[test]
+fn delete_keeps_inline_match_properties() {
+    // Inline property maps must reach the DELETE subquery; dropping them
+    // would turn a targeted delete into a full-table delete.
+    let sql = cypher_to_write_sql("MATCH (a:Person {id: 'u1'}) DETACH DELETE a");
+    assert_eq!(sql.len(), 3, "got {:?}", sql);
+    for stmt in &sql {
+        assert!(stmt.contains("a.id = 'u1'"), "got: {}", stmt);
+    }
+}
+
 // ---------- SET ----------
 
 #[test]
@@ -81,6 +81,12 @@ pub struct VariableScope<'a> {
     /// same-named WITH/node variable. Empty except while recursing a reduce
     /// body. See [`VariableScope::shielding`].
     shielded_names: HashSet<String>,
+
+    /// Set on the scope of the final RETURN only: bare node CTE variables
+    /// expand to their label's `return_properties` / `max_return_columns`
+    /// subset, except the aliases listed here (`RETURN n { .* }`). `None`
+    /// (CTE bodies) always expands every column so later clauses can read it.
+    return_projection: Option<HashSet<String>>,
 }
 
 impl<'a> VariableScope<'a> {
@@ -91,6 +97,7 @@ impl<'a> VariableScope<'a> {
             cte_variables: HashMap::new(),
             plan,
             shielded_names: HashSet::new(),
+            return_projection: None,
         }
     }
 
@@ -106,6 +113,7 @@ impl<'a> VariableScope<'a> {
             cte_variables,
             plan,
             shielded_names: HashSet::new(),
+            return_projection: None,
         }
     }
 
@@ -135,6 +143,7 @@ impl<'a> VariableScope<'a> {
             cte_variables,
             plan: self.plan,
             shielded_names: self.shielded_names.clone(),
+            return_projection: self.return_projection.clone(),
         }
     }
 
@@ -152,6 +161,43 @@ impl<'a> VariableScope<'a> {
             cte_variables: self.cte_variables.clone(),
             plan: self.plan,
             shielded_names,
+            return_projection: self.return_projection.clone(),
+        }
+    }
+
+    /// Mark this as the final RETURN's scope: whole-node expansion applies
+    /// the label's return projection, except for `full_projection_aliases`.
+    pub fn with_return_projection(mut self, full_projection_aliases: HashSet<String>) -> Self {
+        self.return_projection = Some(full_projection_aliases);
+        self
+    }
+
+    /// The `(property, CTE column)` pairs a bare node CTE variable expands to.
+    fn return_projected_properties(
+        &self,
+        alias: &str,
+        properties: Vec<(String, String)>,
+    ) -> Vec<(String, String)> {
+        let Some(full_projection_aliases) = &self.return_projection else {
+            return properties;
+        };
+        if full_projection_aliases.contains(alias) {
+            return properties;
+        }
+        let Some(labels) = self.get_labels(alias) else {
+            return properties;
+        };
+        let [label] = labels.as_slice() else {
+            return properties;
+        };
+        match (
+            self.schema.return_projection(label),
+            self.schema.node_schema_opt(label),
+        ) {
+            (Some(projection), Some(node)) => {
+                projection.select(&node.node_id.columns(), properties)
+            }
+            _ => properties,
         }
     }
 
@@ -555,8 +601,13 @@ pub fn rewrite_render_plan_with_scope(plan: &mut RenderPlan, scope: &VariableSco
                 let from_alias = cte_info.effective_from_alias();
                 if !cte_info.property_mapping.is_empty() {
                     // Expand node CTE variable into individual property columns
-                    let mut props: Vec<_> = cte_info.property_mapping.iter().collect();
-                    props.sort_by(|a, b| a.0.cmp(b.0));
+                    let mut props: Vec<(String, String)> = cte_info
+                        .property_mapping
+                        .iter()
+                        .map(|(p, c)| (p.clone(), c.clone()))
+                        .collect();
+                    props.sort_by(|a, b| a.0.cmp(&b.0));
+                    let props = scope.return_projected_properties(alias_name, props);
                     for (cypher_prop, cte_col) in props {
                         expanded_items.push(SelectItem {
                             expression: RenderExpr::PropertyAccessExp(PropertyAccess {
@@ -1329,6 +1380,7 @@ fn fix_orphan_table_aliases_impl(
                 to_id_column: None,
                 graph_rel: None,
                 is_cartesian: false,
+                use_final: false,
             });
             valid_aliases.insert(from_alias.clone());
             cte_name_to_from_alias.insert(cte_name.clone(), from_alias.clone());
@@ -1728,6 +1780,12 @@ fn rewrite_expr_table_aliases(
                 .collect();
             RenderExpr::List(new_items)
         }
+        RenderExpr::MapLiteral(entries) => RenderExpr::MapLiteral(
+            entries
+                .iter()
+                .map(|(key, value)| (key.clone(), rewrite_expr_table_aliases(value, replacements)))
+                .collect(),
+        ),
         _ => expr.clone(),
     }
 }
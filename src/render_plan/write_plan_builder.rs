@@ -3,40 +3,52 @@
 //!
 //! ## Scope (v1, Phase 2)
 //!
-//! - `CREATE (a:Label {props...})` — single-node INSERT with literal property
-//!   values.
+//! - `CREATE (a:Label {props...})` — node INSERT with literal property
+//!   values. Rows for the same table and column list are batched into one
+//!   multi-row `VALUES` INSERT; the ID column follows the node's
+//!   `id_generation` strategy when the pattern omits it.
+//! - `CREATE (a)-[:R {props...}]->(b)` — edge-table INSERT writing the
+//!   endpoint IDs into `from_id` / `to_id`. Endpoints created in the same
+//!   clause contribute their literal IDs (`VALUES`); endpoints bound by a
+//!   preceding `MATCH` make it an `INSERT ... SELECT` over the read pipeline.
+//! - `MERGE (a:Label {props...})` — the same INSERT, written as
+//!   `INSERT ... SELECT` guarded by a count of rows matching `props`.
 //! - `SET a.prop = expr` — UPDATE on the target alias's table.
 //! - `REMOVE a.prop` — UPDATE setting `prop = NULL`.
 //! - `DELETE a` / `DETACH DELETE a` — DELETE on the target alias's node table,
-//!   with rel-table cleanups in front for `DETACH DELETE`.
+//!   with rel-table cleanups in front for `DETACH DELETE`. Tables with a
+//!   `soft_delete` column get an UPDATE marking the rows deleted instead.
 //!
 //! ## Deferred for follow-up
 //!
-//! - `CREATE (a)-[:R]->(b)` between aliases bound by a preceding `MATCH`.
-//!   Requires the executor (Phase 3) to first read endpoint IDs and then issue
-//!   the rel INSERT — Phase 2 errors out cleanly with a clear message.
+//! - `CREATE` of an edge whose endpoint is created without an explicit ID
+//!   (the DDL default generates it, so the edge cannot reference it).
 //! - SET / DELETE / REMOVE inside chained WITH clauses. Plan / executor
 //!   coordination needed for cross-CTE alias resolution.
 //!
 //! All rejected forms produce a typed error so the caller can surface a
 //! clear message.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use thiserror::Error;
 
+use crate::clickhouse_query_generator::{auto_id_decision, IdInsertDecision};
+use crate::graph_catalog::config::Identifier;
 use crate::graph_catalog::expression_parser::PropertyValue;
-use crate::graph_catalog::graph_schema::{GraphSchema, NodeSchema};
-use crate::query_planner::logical_expr::LogicalExpr;
+use crate::graph_catalog::graph_schema::{GraphSchema, NodeSchema, RelationshipSchema};
+use crate::query_planner::logical_expr::visitors::{map_expression, ExprRewrite};
+use crate::query_planner::logical_expr::{Direction, Literal, LogicalExpr};
 use crate::query_planner::logical_plan::{
-    Create, CreatePattern, CreateRel, Delete, Filter, GraphJoins, LogicalPlan, Remove, SetItem,
-    SetProperties, WriteProperty,
+    Create, CreateNode, CreatePattern, CreateRel, Delete, Filter, GraphJoins, LogicalPlan,
+    MergeOptions, Remove, SetItem, SetProperties, WriteProperty,
 };
 
 use super::errors::RenderBuildError;
 use super::plan_builder::RenderPlanBuilder;
 use super::render_expr::{ColumnAlias, RenderExpr, TableAlias};
-use super::write_render::{DeleteOp, InsertOp, RowSource, UpdateOp, WriteRenderPlan};
+use super::write_render::{DeleteKind, DeleteOp, InsertOp, RowSource, UpdateOp, WriteRenderPlan};
 use super::{RenderPlan, SelectItem, SelectItems};
 
 #[derive(Debug, Error, Clone, PartialEq)]
@@ -54,6 +66,25 @@ impl From<String> for WriteRenderError {
     }
 }
 
+/// Safety switches applied while building a write plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteOptions {
+    /// `false` rejects every `DELETE` / `DETACH DELETE`.
+    pub allow_deletes: bool,
+    /// `true` rejects a `DELETE` whose read pipeline has no `WHERE` or
+    /// inline property predicate — one that would empty the label's table.
+    pub require_delete_predicate: bool,
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        WriteOptions {
+            allow_deletes: true,
+            require_delete_predicate: false,
+        }
+    }
+}
+
 /// Top-level entry point: take a `LogicalPlan` and produce a
 /// `WriteRenderPlan` if the root is a write variant.
 ///
@@ -62,11 +93,33 @@ impl From<String> for WriteRenderError {
 pub fn build_write_plan(
     plan: &LogicalPlan,
     schema: &GraphSchema,
+) -> Result<Option<WriteRenderPlan>, WriteRenderError> {
+    build_write_plan_with_options(plan, schema, WriteOptions::default())
+}
+
+/// [`build_write_plan`] with explicit [`WriteOptions`].
+pub fn build_write_plan_with_options(
+    plan: &LogicalPlan,
+    schema: &GraphSchema,
+    options: WriteOptions,
+) -> Result<Option<WriteRenderPlan>, WriteRenderError> {
+    build_write_plan_with_parameters(plan, schema, options, &HashMap::new())
+}
+
+/// [`build_write_plan_with_options`] with values for the statement's
+/// `$parameters`. CREATE writes supplied values in place of their
+/// placeholders, so `UNWIND $rows AS row CREATE ...` expands to one row per
+/// element of `rows`; other clauses keep the placeholders.
+pub fn build_write_plan_with_parameters(
+    plan: &LogicalPlan,
+    schema: &GraphSchema,
+    options: WriteOptions,
+    parameters: &HashMap<String, serde_json::Value>,
 ) -> Result<Option<WriteRenderPlan>, WriteRenderError> {
     match plan {
-        LogicalPlan::Create(c) => Ok(Some(build_create(c, schema)?)),
+        LogicalPlan::Create(c) => Ok(Some(build_create(c, schema, parameters)?)),
         LogicalPlan::SetProperties(sp) => Ok(Some(build_set(sp, schema)?)),
-        LogicalPlan::Delete(d) => Ok(Some(build_delete(d, schema)?)),
+        LogicalPlan::Delete(d) => Ok(Some(build_delete(d, schema, options)?)),
         LogicalPlan::Remove(r) => Ok(Some(build_remove(r, schema)?)),
         _ => Ok(None),
     }
@@ -76,38 +129,289 @@ pub fn build_write_plan(
 // CREATE
 // ---------------------------------------------------------------------------
 
+/// A node created earlier in the same CREATE clause, kept so relationship
+/// patterns can reference its ID value without a read.
+struct CreatedNode {
+    label: String,
+    /// The inserted row's ID value, when the pattern supplied one (or the
+    /// schema's `id_generation` strategy produced a deterministic
+    /// expression). `None` means the table's DDL default fills it.
+    id_value: Option<RenderExpr>,
+}
+
 fn build_create(
     create: &Create,
     schema: &GraphSchema,
+    parameters: &HashMap<String, serde_json::Value>,
 ) -> Result<WriteRenderPlan, WriteRenderError> {
+    let ops = match unwind_bindings(&create.input, parameters)? {
+        // `UNWIND <list> AS x CREATE ...` over a literal (or supplied) list:
+        // one set of rows per element, batched below into one INSERT per table.
+        Some((base, bindings)) => {
+            let mut ops = Vec::new();
+            for binding in &bindings {
+                let bound = bind_create(create, base.clone(), binding, parameters)?;
+                ops.extend(create_ops(&bound, schema, false)?);
+            }
+            ops
+        }
+        // Any other UNWIND (over a column, or after a MATCH) creates one row
+        // per row of the read pipeline: `INSERT ... SELECT` over it.
+        None => {
+            let bound = bind_create(create, create.input.clone(), &[], parameters)?;
+            create_ops(&bound, schema, contains_unwind(&create.input))?
+        }
+    };
+    Ok(unwrap_singleton(batch_inserts(ops)))
+}
+
+/// The INSERTs of one CREATE. `per_row` writes every node and edge once per
+/// row of `create.input` instead of once per statement.
+fn create_ops(
+    create: &Create,
+    schema: &GraphSchema,
+    per_row: bool,
+) -> Result<Vec<WriteRenderPlan>, WriteRenderError> {
     let mut ops: Vec<WriteRenderPlan> = Vec::new();
+    let mut created: HashMap<String, CreatedNode> = HashMap::new();
     for pattern in &create.patterns {
         match pattern {
             CreatePattern::Node(node) => {
                 let node_schema = schema.node_schema_opt(&node.label).ok_or_else(|| {
                     WriteRenderError::Build(format!("CREATE: unknown node label `{}`", node.label))
                 })?;
-                ops.push(WriteRenderPlan::Insert(build_node_insert(
-                    node.label.as_str(),
-                    &node.properties,
-                    node_schema,
-                )?));
+                let mut insert = match &create.merge {
+                    None => build_node_insert(node.label.as_str(), &node.properties, node_schema)?,
+                    Some(merge) => {
+                        // Pattern properties are the match key; ON CREATE SET
+                        // items only add columns to the inserted row.
+                        let mut properties = node.properties.clone();
+                        properties.extend(merge.on_create.iter().map(|item| WriteProperty {
+                            key: item.property.clone(),
+                            value: item.value.clone(),
+                        }));
+                        let mut insert =
+                            build_node_insert(node.label.as_str(), &properties, node_schema)?;
+                        let key_len = node.properties.len().min(insert.columns.len());
+                        insert.merge_key = Some(insert.columns[..key_len].to_vec());
+                        insert
+                    }
+                };
+                if let Some(alias) = &node.alias {
+                    let id_value = node_schema
+                        .node_id
+                        .column_or_error()
+                        .ok()
+                        .and_then(|id_column| insert_value(&insert, id_column));
+                    created.insert(
+                        alias.clone(),
+                        CreatedNode {
+                            label: node.label.clone(),
+                            id_value,
+                        },
+                    );
+                }
+                if per_row {
+                    let row = insert.rows.pop().unwrap_or_default();
+                    let mut render_plan = create.input.to_render_plan(schema)?;
+                    override_select_to_row(&mut render_plan, &insert.columns, row);
+                    insert.source = Some(Box::new(render_plan));
+                }
+                ops.push(WriteRenderPlan::Insert(insert));
             }
             CreatePattern::Rel(rel) => {
-                // CREATE rel between aliases requires the executor to resolve
-                // endpoint IDs at runtime — Phase 3 work. Phase 2 emits a
-                // clear, actionable error.
-                return Err(WriteRenderError::Build(format!(
-                    "CREATE relationship `{}`: writing edges between bound \
-                     aliases is not supported in this build of ClickGraph yet. \
-                     Use the embedded `Connection::create_edge(...)` API for now \
-                     (or wait for Phase 3 of the embedded-writes plan).",
-                    rel_descriptor(rel)
-                )));
+                ops.push(WriteRenderPlan::Insert(build_rel_insert(
+                    rel, &created, create, schema, per_row,
+                )?));
             }
         }
     }
-    Ok(unwrap_singleton(ops))
+    Ok(ops)
+}
+
+/// Values bound by the UNWINDs of a CREATE's input, one entry per alias.
+type RowBinding = Vec<(String, LogicalExpr)>;
+
+/// Expand a chain of UNWINDs over literal lists (or supplied `$parameters`)
+/// into one binding per produced row, together with the plan below the
+/// chain. `None` when the input is not such a chain, including when a list
+/// is only known at run time.
+#[allow(clippy::type_complexity)] // (plan below the UNWINDs, bindings)
+fn unwind_bindings(
+    plan: &Arc<LogicalPlan>,
+    parameters: &HashMap<String, serde_json::Value>,
+) -> Result<Option<(Arc<LogicalPlan>, Vec<RowBinding>)>, WriteRenderError> {
+    let LogicalPlan::Unwind(unwind) = plan.as_ref() else {
+        return Ok(None);
+    };
+    let (base, outer) = match unwind_bindings(&unwind.input, parameters)? {
+        Some(found) => found,
+        None if matches!(unwind.input.as_ref(), LogicalPlan::Empty) => {
+            (unwind.input.clone(), vec![Vec::new()])
+        }
+        None => return Ok(None),
+    };
+    if let LogicalExpr::Parameter(name) = &unwind.expression {
+        if !parameters.contains_key(name) {
+            return Err(WriteRenderError::Build(format!(
+                "UNWIND ${} AS {}: no value was supplied for `{}`, which CREATE needs \
+                 to build its rows",
+                name, unwind.alias, name
+            )));
+        }
+    }
+    let mut bindings = Vec::new();
+    for binding in outer {
+        let LogicalExpr::List(elements) = bind_value(&unwind.expression, &binding, parameters)?
+        else {
+            return Ok(None);
+        };
+        for element in elements {
+            let mut row = binding.clone();
+            row.push((unwind.alias.clone(), element));
+            bindings.push(row);
+        }
+    }
+    Ok(Some((base, bindings)))
+}
+
+fn contains_unwind(plan: &LogicalPlan) -> bool {
+    match plan {
+        LogicalPlan::Unwind(_) => true,
+        LogicalPlan::Empty => false,
+        other => other.children().into_iter().any(contains_unwind),
+    }
+}
+
+/// `create` over `input`, with the aliases of `binding` and the supplied
+/// `$parameters` replaced by their values in every written property.
+fn bind_create(
+    create: &Create,
+    input: Arc<LogicalPlan>,
+    binding: &[(String, LogicalExpr)],
+    parameters: &HashMap<String, serde_json::Value>,
+) -> Result<Create, WriteRenderError> {
+    let bind_properties = |properties: &[WriteProperty]| {
+        properties
+            .iter()
+            .map(|prop| {
+                Ok(WriteProperty {
+                    key: prop.key.clone(),
+                    value: bind_value(&prop.value, binding, parameters)?,
+                })
+            })
+            .collect::<Result<Vec<_>, WriteRenderError>>()
+    };
+    let patterns = create
+        .patterns
+        .iter()
+        .map(|pattern| {
+            Ok(match pattern {
+                CreatePattern::Node(node) => CreatePattern::Node(CreateNode {
+                    properties: bind_properties(&node.properties)?,
+                    ..node.clone()
+                }),
+                CreatePattern::Rel(rel) => CreatePattern::Rel(CreateRel {
+                    properties: bind_properties(&rel.properties)?,
+                    ..rel.clone()
+                }),
+            })
+        })
+        .collect::<Result<Vec<_>, WriteRenderError>>()?;
+    let merge = match &create.merge {
+        Some(merge) => Some(MergeOptions {
+            on_create: merge
+                .on_create
+                .iter()
+                .map(|item| {
+                    Ok(SetItem {
+                        value: bind_value(&item.value, binding, parameters)?,
+                        ..item.clone()
+                    })
+                })
+                .collect::<Result<Vec<_>, WriteRenderError>>()?,
+        }),
+        None => None,
+    };
+    Ok(Create {
+        input,
+        patterns,
+        merge,
+    })
+}
+
+/// Replace bound aliases (`x`, `x.key` for map elements) and supplied
+/// `$parameters` in `expr` by their values.
+fn bind_value(
+    expr: &LogicalExpr,
+    binding: &[(String, LogicalExpr)],
+    parameters: &HashMap<String, serde_json::Value>,
+) -> Result<LogicalExpr, WriteRenderError> {
+    let bound = |alias: &str| {
+        binding
+            .iter()
+            .rev()
+            .find(|(name, _)| name == alias)
+            .map(|(_, value)| value)
+    };
+    let mut error = None;
+    let value = map_expression(expr, &mut |e| match e {
+        LogicalExpr::TableAlias(alias) => match bound(&alias.0) {
+            Some(value) => ExprRewrite::Replace(value.clone()),
+            None => ExprRewrite::Recurse,
+        },
+        LogicalExpr::PropertyAccessExp(access) => match bound(&access.table_alias.0) {
+            Some(LogicalExpr::MapLiteral(entries)) => ExprRewrite::Replace(
+                entries
+                    .iter()
+                    .find(|(key, _)| key == access.column.raw())
+                    .map(|(_, value)| value.clone())
+                    .unwrap_or(LogicalExpr::Literal(Literal::Null)),
+            ),
+            Some(other) => {
+                error = Some(format!(
+                    "`{}.{}`: the UNWIND element `{}` is not a map ({:?})",
+                    access.table_alias.0,
+                    access.column.raw(),
+                    access.table_alias.0,
+                    other
+                ));
+                ExprRewrite::Recurse
+            }
+            None => ExprRewrite::Recurse,
+        },
+        LogicalExpr::Parameter(name) => match parameters.get(name) {
+            Some(value) => ExprRewrite::Replace(json_to_expr(value)),
+            None => ExprRewrite::Recurse,
+        },
+        _ => ExprRewrite::Recurse,
+    });
+    match error {
+        Some(error) => Err(WriteRenderError::Build(error)),
+        None => Ok(value),
+    }
+}
+
+/// A supplied parameter value as a literal expression; JSON objects become
+/// map literals so `row.key` can read them.
+fn json_to_expr(value: &serde_json::Value) -> LogicalExpr {
+    use serde_json::Value;
+    match value {
+        Value::Null => LogicalExpr::Literal(Literal::Null),
+        Value::Bool(b) => LogicalExpr::Literal(Literal::Boolean(*b)),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => LogicalExpr::Literal(Literal::Integer(i)),
+            None => LogicalExpr::Literal(Literal::Float(n.as_f64().unwrap_or_default())),
+        },
+        Value::String(s) => LogicalExpr::Literal(Literal::String(s.clone())),
+        Value::Array(items) => LogicalExpr::List(items.iter().map(json_to_expr).collect()),
+        Value::Object(entries) => LogicalExpr::MapLiteral(
+            entries
+                .iter()
+                .map(|(key, value)| (key.clone(), json_to_expr(value)))
+                .collect(),
+        ),
+    }
 }
 
 fn build_node_insert(
@@ -126,8 +430,28 @@ fn build_node_insert(
             ))
         })?;
         let value = render_value(&prop.value)?;
-        columns.push(column);
-        row.push(value);
+        push_insert_value(&mut columns, &mut row, column, value);
+    }
+
+    // The ID column follows the schema's `id_generation` strategy when the
+    // pattern doesn't supply it.
+    if let Ok(id_column) = node_schema.node_id.column_or_error() {
+        if !columns.iter().any(|c| c == id_column) {
+            match auto_id_decision(node_schema.id_generation.unwrap_or_default()) {
+                IdInsertDecision::UseDdlDefault => {}
+                IdInsertDecision::Generate(expr) => {
+                    columns.push(id_column.to_string());
+                    row.push(expr);
+                }
+                IdInsertDecision::RequireUserSupplied => {
+                    return Err(WriteRenderError::Build(format!(
+                        "CREATE node `{}`: the ID column `{}` must be supplied \
+                         (schema declares `id_generation: provided`)",
+                        label, id_column
+                    )));
+                }
+            }
+        }
     }
 
     Ok(InsertOp {
@@ -135,9 +459,231 @@ fn build_node_insert(
         table: node_schema.table_name.clone(),
         columns,
         rows: vec![row],
+        merge_key: None,
+        source: None,
+        relationship: false,
+    })
+}
+
+/// INSERT for a relationship pattern. Endpoints created earlier in the same
+/// clause contribute their literal ID values; endpoints bound by the
+/// preceding read pipeline turn the INSERT into `INSERT ... SELECT` over
+/// that pipeline, so one edge row is written per matched row.
+fn build_rel_insert(
+    rel: &CreateRel,
+    created: &HashMap<String, CreatedNode>,
+    create: &Create,
+    schema: &GraphSchema,
+    per_row: bool,
+) -> Result<InsertOp, WriteRenderError> {
+    // The stored edge always runs from the `from_id` side to the `to_id` side.
+    let (from_alias, to_alias) = match rel.direction {
+        Direction::Incoming => (&rel.end_alias, &rel.start_alias),
+        _ => (&rel.start_alias, &rel.end_alias),
+    };
+    let from = resolve_rel_endpoint(rel, from_alias, created, create, schema)?;
+    let to = resolve_rel_endpoint(rel, to_alias, created, create, schema)?;
+
+    let rel_schema = schema
+        .get_rel_schema_with_nodes(&rel.rel_type, Some(&from.label), Some(&to.label))
+        .ok()
+        .filter(|r| {
+            (r.from_node == from.label || r.from_node == "$any")
+                && (r.to_node == to.label || r.to_node == "$any")
+        })
+        .ok_or_else(|| {
+            WriteRenderError::Build(format!(
+                "CREATE relationship `{}`: no `{}` edge is defined from `{}` to `{}`",
+                rel_descriptor(rel),
+                rel.rel_type,
+                from.label,
+                to.label
+            ))
+        })?;
+
+    let from_column = single_endpoint_column(rel, &rel_schema.from_id, "from_id")?;
+    let to_column = single_endpoint_column(rel, &rel_schema.to_id, "to_id")?;
+
+    let mut columns = vec![from_column.clone(), to_column.clone()];
+    let mut row = vec![from.value, to.value];
+    for prop in &rel.properties {
+        let column = resolve_rel_property_column(rel_schema, &prop.key).ok_or_else(|| {
+            WriteRenderError::Build(format!(
+                "CREATE relationship `{}`: property `{}` cannot be mapped to a writable column",
+                rel_descriptor(rel),
+                prop.key
+            ))
+        })?;
+        if column == from_column || column == to_column {
+            return Err(WriteRenderError::Build(format!(
+                "CREATE relationship `{}`: property `{}` maps to the endpoint column `{}`, \
+                 which is filled from the connected nodes",
+                rel_descriptor(rel),
+                prop.key,
+                column
+            )));
+        }
+        let value = render_value(&prop.value)?;
+        push_insert_value(&mut columns, &mut row, column, value);
+    }
+
+    let (rows, source) = if from.bound || to.bound || per_row {
+        let mut render_plan = create.input.to_render_plan(schema)?;
+        override_select_to_row(&mut render_plan, &columns, row);
+        (Vec::new(), Some(Box::new(render_plan)))
+    } else {
+        (vec![row], None)
+    };
+
+    Ok(InsertOp {
+        database: rel_schema.database.clone(),
+        table: rel_schema.table_name.clone(),
+        columns,
+        rows,
+        merge_key: None,
+        source,
+        relationship: true,
+    })
+}
+
+/// One side of a relationship being created.
+struct RelEndpoint {
+    label: String,
+    /// Value written to the edge's endpoint column.
+    value: RenderExpr,
+    /// `true` when the value reads from an alias bound by the read pipeline.
+    bound: bool,
+}
+
+fn resolve_rel_endpoint(
+    rel: &CreateRel,
+    alias: &str,
+    created: &HashMap<String, CreatedNode>,
+    create: &Create,
+    schema: &GraphSchema,
+) -> Result<RelEndpoint, WriteRenderError> {
+    if let Some(node) = created.get(alias) {
+        let value = node.id_value.clone().ok_or_else(|| {
+            WriteRenderError::Build(format!(
+                "CREATE relationship `{}`: endpoint `{}` is created without an ID value, \
+                 so the edge cannot reference it. Supply the `{}` ID property explicitly.",
+                rel_descriptor(rel),
+                alias,
+                node.label
+            ))
+        })?;
+        return Ok(RelEndpoint {
+            label: node.label.clone(),
+            value,
+            bound: false,
+        });
+    }
+
+    let labels = find_all_alias_labels(alias, &create.input);
+    let label = match labels.as_slice() {
+        [label] => label.clone(),
+        [] => {
+            return Err(WriteRenderError::Build(format!(
+                "CREATE relationship `{}`: endpoint `{}` is not bound by a preceding MATCH \
+                 (or its label cannot be resolved at this stage)",
+                rel_descriptor(rel),
+                alias
+            )))
+        }
+        _ => {
+            return Err(WriteRenderError::Build(format!(
+                "CREATE relationship `{}`: endpoint `{}` resolves to multiple labels {:?}; \
+                 add a label to the MATCH pattern",
+                rel_descriptor(rel),
+                alias,
+                labels
+            )))
+        }
+    };
+    let node_schema = schema.node_schema_opt(&label).ok_or_else(|| {
+        WriteRenderError::Build(format!("CREATE: unknown node label `{}`", label))
+    })?;
+    let id_column = node_id_column_or_error(node_schema, "CREATE", alias)?;
+    Ok(RelEndpoint {
+        label,
+        value: RenderExpr::PropertyAccessExp(super::render_expr::PropertyAccess {
+            table_alias: TableAlias(alias.to_string()),
+            column: PropertyValue::Column(id_column),
+        }),
+        bound: true,
+    })
+}
+
+fn single_endpoint_column(
+    rel: &CreateRel,
+    id: &Identifier,
+    side: &str,
+) -> Result<String, WriteRenderError> {
+    id.as_single().map(|c| c.to_string()).map_err(|_| {
+        WriteRenderError::Build(format!(
+            "CREATE relationship `{}`: composite {} is not supported in v1",
+            rel_descriptor(rel),
+            side
+        ))
     })
 }
 
+/// Append `column = value` to an INSERT row; a repeated column keeps the
+/// last value (Cypher map literals are last-wins too).
+fn push_insert_value(
+    columns: &mut Vec<String>,
+    row: &mut Vec<RenderExpr>,
+    column: String,
+    value: RenderExpr,
+) {
+    match columns.iter().position(|c| *c == column) {
+        Some(i) => row[i] = value,
+        None => {
+            columns.push(column);
+            row.push(value);
+        }
+    }
+}
+
+fn insert_value(insert: &InsertOp, column: &str) -> Option<RenderExpr> {
+    let idx = insert.columns.iter().position(|c| c == column)?;
+    insert.rows.first()?.get(idx).cloned()
+}
+
+/// Collapse plain `VALUES` INSERTs that target the same table with the same
+/// column list into one multi-row INSERT, in first-seen position. MERGE and
+/// `INSERT ... SELECT` ops are kept as-is.
+fn batch_inserts(ops: Vec<WriteRenderPlan>) -> Vec<WriteRenderPlan> {
+    let mut out: Vec<WriteRenderPlan> = Vec::with_capacity(ops.len());
+    for op in ops {
+        if let WriteRenderPlan::Insert(insert) = &op {
+            if is_batchable(insert) {
+                let existing = out.iter_mut().find_map(|prev| match prev {
+                    WriteRenderPlan::Insert(prev)
+                        if is_batchable(prev)
+                            && prev.database == insert.database
+                            && prev.table == insert.table
+                            && prev.columns == insert.columns =>
+                    {
+                        Some(prev)
+                    }
+                    _ => None,
+                });
+                if let Some(prev) = existing {
+                    prev.rows.extend(insert.rows.iter().cloned());
+                    continue;
+                }
+            }
+        }
+        out.push(op);
+    }
+    out
+}
+
+fn is_batchable(insert: &InsertOp) -> bool {
+    insert.merge_key.is_none() && insert.source.is_none()
+}
+
 fn resolve_node_property_column(node_schema: &NodeSchema, key: &str) -> Option<String> {
     if let Some(prop_value) = node_schema.property_mappings.get(key) {
         match prop_value {
@@ -153,10 +699,27 @@ fn resolve_node_property_column(node_schema: &NodeSchema, key: &str) -> Option<S
     None
 }
 
+fn resolve_rel_property_column(rel_schema: &RelationshipSchema, key: &str) -> Option<String> {
+    if let Some(prop_value) = rel_schema.property_mappings.get(key) {
+        match prop_value {
+            PropertyValue::Column(c) => return Some(c.clone()),
+            PropertyValue::Expression(_) => return None,
+        }
+    }
+    if rel_schema.column_names.iter().any(|c| c == key) {
+        return Some(key.to_string());
+    }
+    None
+}
+
 fn rel_descriptor(rel: &CreateRel) -> String {
+    let (left, right) = match rel.direction {
+        Direction::Incoming => ("<-", "-"),
+        _ => ("-", "->"),
+    };
     format!(
-        "({})-[:{}]->({})",
-        rel.start_alias, rel.rel_type, rel.end_alias
+        "({}){}[:{}]{}({})",
+        rel.start_alias, left, rel.rel_type, right, rel.end_alias
     )
 }
 
@@ -268,7 +831,7 @@ fn build_remove(rem: &Remove, schema: &GraphSchema) -> Result<WriteRenderPlan, W
         .map(|item| SetItem {
             target_alias: item.target_alias.clone(),
             property: item.property.clone(),
-            value: LogicalExpr::Literal(crate::query_planner::logical_expr::Literal::Null),
+            value: LogicalExpr::Literal(Literal::Null),
         })
         .collect();
     let synthetic = SetProperties {
@@ -302,7 +865,28 @@ fn group_assignments_by_alias(items: &[SetItem]) -> Vec<(String, Vec<SetItem>)>
 // DELETE / DETACH DELETE
 // ---------------------------------------------------------------------------
 
-fn build_delete(del: &Delete, schema: &GraphSchema) -> Result<WriteRenderPlan, WriteRenderError> {
+fn build_delete(
+    del: &Delete,
+    schema: &GraphSchema,
+    options: WriteOptions,
+) -> Result<WriteRenderPlan, WriteRenderError> {
+    if !options.allow_deletes {
+        return Err(WriteRenderError::Build(
+            "DELETE is disabled for this database (allow_deletes = false)".to_string(),
+        ));
+    }
+    if options.require_delete_predicate && !has_row_predicate(&del.input) {
+        return Err(WriteRenderError::Build(format!(
+            "DELETE of {} without a WHERE clause or property predicate would remove every \
+             row of the matched labels; add a predicate (e.g. `WHERE n.id = ...`)",
+            del.targets
+                .iter()
+                .map(|t| format!("`{}`", t))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+
     let mut ops: Vec<WriteRenderPlan> = Vec::new();
 
     for alias in &del.targets {
@@ -389,15 +973,51 @@ fn build_delete_for_label(
     }
 
     let source = build_id_source(alias, &id_column, input, schema)?;
-    ops.push(WriteRenderPlan::Delete(DeleteOp {
-        database: node_schema.database.clone(),
-        table: node_schema.table_name.clone(),
+    ops.push(delete_rows(
+        &node_schema.database,
+        &node_schema.table_name,
         id_column,
         source,
-    }));
+        schema,
+    ));
     Ok(ops)
 }
 
+/// DELETE the rows of `database.table` whose `id_column` is in `source` — or,
+/// when the table declares a `soft_delete` column, an UPDATE marking them
+/// deleted so reads (which filter on that column) skip them. Tables whose
+/// detected engine has no lightweight delete get a mutation instead.
+fn delete_rows(
+    database: &str,
+    table: &str,
+    id_column: String,
+    source: RowSource,
+    schema: &GraphSchema,
+) -> WriteRenderPlan {
+    match schema.soft_delete(database, table) {
+        Some(soft_delete) => WriteRenderPlan::Update(UpdateOp {
+            database: database.to_string(),
+            table: table.to_string(),
+            assignments: vec![(
+                soft_delete.column.clone(),
+                RenderExpr::Raw(soft_delete.deleted_value().to_string()),
+            )],
+            id_column,
+            source,
+        }),
+        None => WriteRenderPlan::Delete(DeleteOp {
+            database: database.to_string(),
+            table: table.to_string(),
+            id_column,
+            source,
+            kind: match schema.table_engine(database, table) {
+                Some(engine) if !engine.supports_lightweight_delete() => DeleteKind::Mutation,
+                _ => DeleteKind::Lightweight,
+            },
+        }),
+    }
+}
+
 /// For DETACH DELETE: enumerate every relationship table that references
 /// `node_label`, and emit one DELETE per side that touches it.
 ///
@@ -423,6 +1043,13 @@ fn build_detach_rel_deletes(
             // column. Standard edge tables only.
             continue;
         }
+        if schema
+            .edge_aggregate(&rel_schema.database, &rel_schema.table_name)
+            .is_some()
+        {
+            // Aggregated edges are derived from raw rows; nothing to delete
+            continue;
+        }
 
         let touches_from = rel_schema.from_node == node_label;
         let touches_to = rel_schema.to_node == node_label;
@@ -449,12 +1076,13 @@ fn build_detach_rel_deletes(
                     ))
                 })?
                 .to_string();
-            deletes.push(WriteRenderPlan::Delete(DeleteOp {
-                database: rel_schema.database.clone(),
-                table: rel_schema.table_name.clone(),
-                id_column: from_col,
-                source: build_id_source(alias, node_id_column, input, schema)?,
-            }));
+            deletes.push(delete_rows(
+                &rel_schema.database,
+                &rel_schema.table_name,
+                from_col,
+                build_id_source(alias, node_id_column, input, schema)?,
+                schema,
+            ));
         }
         if touches_to {
             let to_col = rel_schema
@@ -467,12 +1095,13 @@ fn build_detach_rel_deletes(
                     ))
                 })?
                 .to_string();
-            deletes.push(WriteRenderPlan::Delete(DeleteOp {
-                database: rel_schema.database.clone(),
-                table: rel_schema.table_name.clone(),
-                id_column: to_col,
-                source: build_id_source(alias, node_id_column, input, schema)?,
-            }));
+            deletes.push(delete_rows(
+                &rel_schema.database,
+                &rel_schema.table_name,
+                to_col,
+                build_id_source(alias, node_id_column, input, schema)?,
+                schema,
+            ));
         }
     }
     Ok(deletes)
@@ -496,6 +1125,25 @@ fn build_id_source(
     Ok(RowSource::Subquery(Box::new(render_plan)))
 }
 
+/// `INSERT ... SELECT` source: project `row` (aliased to the INSERT's
+/// `columns`) once per row of the read pipeline.
+fn override_select_to_row(plan: &mut RenderPlan, columns: &[String], row: Vec<RenderExpr>) {
+    plan.select = SelectItems {
+        items: columns
+            .iter()
+            .zip(row)
+            .map(|(column, expression)| SelectItem {
+                expression,
+                col_alias: Some(ColumnAlias(column.clone())),
+            })
+            .collect(),
+        distinct: false,
+    };
+    plan.order_by.0.clear();
+    plan.skip.0 = None;
+    plan.limit.0 = None;
+}
+
 fn override_select_to_id(plan: &mut RenderPlan, alias: &str, id_column: &str) {
     plan.select = SelectItems {
         items: vec![SelectItem {
@@ -676,6 +1324,49 @@ fn slice_plan_to_label(
     }
 }
 
+/// Whether the read pipeline restricts its rows: a `WHERE` (`Filter`), an
+/// inline property map pushed into a scan, or a predicate folded into a
+/// relationship. Schema-level `filter:` clauses don't count.
+fn has_row_predicate(plan: &LogicalPlan) -> bool {
+    match plan {
+        LogicalPlan::Filter(_) => true,
+        LogicalPlan::ViewScan(vs) => {
+            vs.view_filter.is_some() || vs.input.as_deref().is_some_and(has_row_predicate)
+        }
+        LogicalPlan::GraphNode(n) => has_row_predicate(&n.input),
+        LogicalPlan::GraphRel(r) => {
+            r.where_predicate.as_ref().is_some_and(|p| {
+                !matches!(
+                    p,
+                    LogicalExpr::Literal(crate::query_planner::logical_expr::Literal::Boolean(
+                        true
+                    ))
+                )
+            }) || has_row_predicate(&r.left)
+                || has_row_predicate(&r.center)
+                || has_row_predicate(&r.right)
+        }
+        LogicalPlan::Projection(p) => has_row_predicate(&p.input),
+        LogicalPlan::GroupBy(gb) => gb.having_clause.is_some() || has_row_predicate(&gb.input),
+        LogicalPlan::OrderBy(ob) => has_row_predicate(&ob.input),
+        LogicalPlan::Skip(s) => has_row_predicate(&s.input),
+        LogicalPlan::Limit(l) => has_row_predicate(&l.input),
+        LogicalPlan::Cte(c) => has_row_predicate(&c.input),
+        LogicalPlan::GraphJoins(gj) => has_row_predicate(&gj.input),
+        LogicalPlan::Unwind(u) => has_row_predicate(&u.input),
+        LogicalPlan::Union(u) => u.inputs.iter().all(|b| has_row_predicate(b)),
+        LogicalPlan::CartesianProduct(cp) => {
+            has_row_predicate(&cp.left) || has_row_predicate(&cp.right)
+        }
+        LogicalPlan::WithClause(wc) => wc.where_clause.is_some() || has_row_predicate(&wc.input),
+        LogicalPlan::Create(c) => has_row_predicate(&c.input),
+        LogicalPlan::SetProperties(sp) => has_row_predicate(&sp.input),
+        LogicalPlan::Delete(d) => has_row_predicate(&d.input),
+        LogicalPlan::Remove(r) => has_row_predicate(&r.input),
+        LogicalPlan::Empty | LogicalPlan::PageRank(_) => false,
+    }
+}
+
 fn find_alias_rel_type(alias: &str, plan: &LogicalPlan) -> Option<String> {
     match plan {
         LogicalPlan::GraphRel(r) if r.alias == alias => {
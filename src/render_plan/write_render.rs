@@ -11,12 +11,16 @@
 //! ## Lightweight semantics (per Decision 0.7)
 //!
 //! All three operation kinds map to ClickHouse synchronous primitives:
-//! - `Insert` → `INSERT INTO db.table (cols) VALUES (rows)`
+//! - `Insert` → `INSERT INTO db.table (cols) VALUES (rows)`, or for MERGE
+//!   `INSERT INTO db.table (cols) SELECT row WHERE (<match count>) = 0`, or
+//!   for edges between MATCH-bound nodes `INSERT INTO db.table (cols) SELECT ...`
 //! - `Update` → `UPDATE db.table SET col = expr WHERE id IN (...)`
 //!   (lightweight; relies on the table being created with
 //!   `enable_block_number_column` / `enable_block_offset_column` — Phase 3
 //!   wires this in `data_loader.rs`).
-//! - `Delete` → `DELETE FROM db.table WHERE id IN (...)` (lightweight).
+//! - `Delete` → `DELETE FROM db.table WHERE id IN (...)` (lightweight), or
+//!   `ALTER TABLE db.table DELETE WHERE id IN (...)` for tables whose engine
+//!   has no lightweight delete.
 //!
 //! ## DETACH DELETE
 //!
@@ -51,6 +55,33 @@ pub enum WriteRenderPlan {
     Sequence(Vec<WriteRenderPlan>),
 }
 
+impl WriteRenderPlan {
+    /// `(database, table)` pairs written by this plan, in execution order
+    /// and without duplicates.
+    pub fn target_tables(&self) -> Vec<(String, String)> {
+        let mut out = Vec::new();
+        self.collect_target_tables(&mut out);
+        out
+    }
+
+    fn collect_target_tables(&self, out: &mut Vec<(String, String)>) {
+        let target = match self {
+            WriteRenderPlan::Insert(op) => (&op.database, &op.table),
+            WriteRenderPlan::Update(op) => (&op.database, &op.table),
+            WriteRenderPlan::Delete(op) => (&op.database, &op.table),
+            WriteRenderPlan::Sequence(seq) => {
+                for inner in seq {
+                    inner.collect_target_tables(out);
+                }
+                return;
+            }
+        };
+        if !out.iter().any(|(db, t)| db == target.0 && t == target.1) {
+            out.push((target.0.clone(), target.1.clone()));
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct InsertOp {
     pub database: String,
@@ -62,6 +93,21 @@ pub struct InsertOp {
     /// One inner Vec per row to insert. Each value is rendered via
     /// `RenderExpr::to_sql`. Length must equal `columns.len()`.
     pub rows: Vec<Vec<RenderExpr>>,
+    /// MERGE: insert the (single) row only when no existing row has the same
+    /// values in these columns. `Some(vec![])` inserts only into an empty
+    /// table; `None` is a plain INSERT.
+    #[serde(default)]
+    pub merge_key: Option<Vec<String>>,
+    /// `INSERT ... SELECT`: the read pipeline projecting one value per
+    /// entry of `columns`, in order. Set for relationship CREATE between
+    /// MATCH-bound endpoints; `rows` is empty in that case.
+    #[serde(default)]
+    pub source: Option<Box<RenderPlan>>,
+    /// `true` when the target is an edge table (relationship CREATE), so
+    /// callers can report `relationships_created` rather than
+    /// `nodes_created`.
+    #[serde(default)]
+    pub relationship: bool,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -84,6 +130,19 @@ pub struct DeleteOp {
     /// often `from_id` or `to_id`; for node DELETE it's the node ID column.
     pub id_column: String,
     pub source: RowSource,
+    #[serde(default)]
+    pub kind: DeleteKind,
+}
+
+/// How a `DeleteOp` removes rows, chosen from the target table's engine.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum DeleteKind {
+    /// `DELETE FROM` — lightweight delete, MergeTree-family engines.
+    #[default]
+    Lightweight,
+    /// `ALTER TABLE ... DELETE` mutation, run synchronously — engines
+    /// without lightweight delete support (e.g. `Memory`).
+    Mutation,
 }
 
 /// Where the IDs in the WHERE clause of `Update` / `Delete` come from.
@@ -0,0 +1,533 @@
+//! Resource attribution of the shared ClickHouse cluster to graph tenants.
+//!
+//! A statement runs inside [`scope`], which tags every ClickHouse query it
+//! issues with a `log_comment` naming its schema, tenant and role
+//! ([`query_settings`], applied by the remote executor). ClickHouse records
+//! the comment in `system.query_log`, so the read bytes, CPU time and
+//! memory of those queries can be summed per tenant afterwards:
+//! `GET /stats/resources` reports them over a time window, and with
+//! `attribution_table` set a background task copies them into a table on
+//! every `attribution_export_secs` boundary for chargeback.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::AppState;
+
+/// `app` value of the `log_comment`s ClickGraph writes, so reports skip
+/// queries other clients tagged.
+const APP: &str = "clickgraph";
+
+/// Window of `/stats/resources` when `since` is not given.
+const DEFAULT_WINDOW: chrono::Duration = chrono::Duration::hours(24);
+
+/// Slack after an export boundary before its window is read, for
+/// ClickHouse to flush `system.query_log` (every 7.5 s by default).
+const QUERY_LOG_FLUSH_SLACK: Duration = Duration::from_secs(30);
+
+tokio::task_local! {
+    /// Who the statement running in [`scope`] is billed to.
+    static ATTRIBUTION: Attribution;
+}
+
+/// Who a statement's ClickHouse queries are billed to. Missing parts are
+/// reported as empty strings.
+#[derive(Debug, Clone, Default)]
+pub struct Attribution {
+    pub schema: Option<String>,
+    pub tenant: Option<String>,
+    pub role: Option<String>,
+}
+
+impl Attribution {
+    fn log_comment(&self) -> String {
+        json!({
+            "app": APP,
+            "schema": self.schema.as_deref().unwrap_or_default(),
+            "tenant": self.tenant.as_deref().unwrap_or_default(),
+            "role": self.role.as_deref().unwrap_or_default(),
+        })
+        .to_string()
+    }
+}
+
+/// Run the statement `f` with its ClickHouse queries billed to `attribution`.
+pub async fn scope<F: Future>(attribution: Attribution, f: F) -> F::Output {
+    ATTRIBUTION.scope(attribution, f).await
+}
+
+/// ClickHouse settings tagging the next query of the current statement with
+/// its [`Attribution`]. Empty outside [`scope`].
+pub fn query_settings() -> Vec<(String, String)> {
+    ATTRIBUTION
+        .try_with(|attribution| vec![("log_comment".to_string(), attribution.log_comment())])
+        .unwrap_or_default()
+}
+
+/// A column a report can be grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Schema,
+    Tenant,
+    Role,
+}
+
+impl Dimension {
+    const ALL: [Dimension; 3] = [Dimension::Schema, Dimension::Tenant, Dimension::Role];
+
+    fn name(self) -> &'static str {
+        match self {
+            Dimension::Schema => "schema",
+            Dimension::Tenant => "tenant",
+            Dimension::Role => "role",
+        }
+    }
+
+    fn parse(s: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|d| d.name() == s)
+            .ok_or_else(|| format!("unknown group-by `{s}`: expected schema, tenant or role"))
+    }
+}
+
+/// Time bucket of report rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bucket {
+    Hour,
+    Day,
+    /// One row per group for the whole window.
+    None,
+    /// Fixed-size buckets, for the export task.
+    Seconds(u64),
+}
+
+impl Bucket {
+    fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "hour" => Ok(Bucket::Hour),
+            "day" => Ok(Bucket::Day),
+            "none" => Ok(Bucket::None),
+            _ => Err(format!("unknown bucket `{s}`: expected hour, day or none")),
+        }
+    }
+
+    /// Start of the row's bucket; `None` for a single bucket.
+    fn sql(self) -> Option<String> {
+        match self {
+            Bucket::Hour => Some("toStartOfHour(event_time)".to_string()),
+            Bucket::Day => Some("toStartOfDay(event_time)".to_string()),
+            Bucket::None => None,
+            Bucket::Seconds(secs) => Some(format!(
+                "toStartOfInterval(event_time, INTERVAL {secs} SECOND)"
+            )),
+        }
+    }
+}
+
+/// What a report sums, over `[since, until)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    pub bucket: Bucket,
+    pub group_by: Vec<Dimension>,
+    /// Cluster whose `system.query_log` of every replica is read; the
+    /// connected node's only when unset.
+    pub cluster: Option<String>,
+}
+
+impl Report {
+    /// The `system.query_log` aggregation behind the report.
+    pub fn sql(&self) -> String {
+        let mut keys = Vec::new();
+        let mut columns = Vec::new();
+        if let Some(bucket) = self.bucket.sql() {
+            columns.push(format!("{bucket} AS bucket"));
+            keys.push("bucket".to_string());
+        }
+        for dimension in &self.group_by {
+            let name = dimension.name();
+            columns.push(format!(
+                "JSONExtractString(log_comment, '{name}') AS {name}"
+            ));
+            keys.push(name.to_string());
+        }
+        columns.extend(
+            [
+                "count() AS queries",
+                "countIf(type != 'QueryFinish') AS failed_queries",
+                "sum(query_duration_ms) AS duration_ms",
+                "sum(read_rows) AS read_rows",
+                "sum(read_bytes) AS read_bytes",
+                "sum(result_bytes) AS result_bytes",
+                "sum(ProfileEvents['OSCPUVirtualTimeMicroseconds']) / 1000000 AS cpu_seconds",
+                "max(memory_usage) AS peak_memory_bytes",
+                "sum(memory_usage) AS memory_bytes",
+            ]
+            .map(String::from),
+        );
+        let source = match &self.cluster {
+            Some(cluster) => format!(
+                "clusterAllReplicas('{}', system.query_log)",
+                cluster.replace('\\', "\\\\").replace('\'', "\\'")
+            ),
+            None => "system.query_log".to_string(),
+        };
+        // `event_date` is in the server's time zone, so the partition filter
+        // is widened by a day each way; `event_time` does the exact cut
+        let since = self.since.format("%Y-%m-%d %H:%M:%S");
+        let until = self.until.format("%Y-%m-%d %H:%M:%S");
+        let mut sql = format!(
+            "SELECT\n    {}\nFROM {source}\n\
+             WHERE type IN ('QueryFinish', 'ExceptionWhileProcessing')\n  \
+             AND event_date BETWEEN toDate('{since}') - 1 AND toDate('{until}') + 1\n  \
+             AND event_time >= toDateTime('{since}', 'UTC') AND event_time < toDateTime('{until}', 'UTC')\n  \
+             AND JSONExtractString(log_comment, 'app') = '{APP}'",
+            columns.join(",\n    "),
+        );
+        if !keys.is_empty() {
+            sql.push_str(&format!("\nGROUP BY {}", keys.join(", ")));
+        }
+        let mut order = Vec::new();
+        if self.bucket.sql().is_some() {
+            order.push("bucket");
+        }
+        order.push("read_bytes DESC");
+        sql.push_str(&format!("\nORDER BY {}", order.join(", ")));
+        sql
+    }
+}
+
+/// Query string of `GET /stats/resources`.
+#[derive(Debug, Default, Deserialize)]
+pub struct ReportParams {
+    /// Window start, RFC 3339 or `YYYY-MM-DD`. Default: 24 hours before `until`.
+    pub since: Option<String>,
+    /// Window end (exclusive). Default: now.
+    pub until: Option<String>,
+    /// `hour`, `day` or `none`. Default: day.
+    pub bucket: Option<String>,
+    /// Comma-separated `schema`, `tenant`, `role`. Default: `schema,tenant`.
+    pub by: Option<String>,
+}
+
+fn parse_time(name: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(t) = DateTime::parse_from_rfc3339(value) {
+        return Ok(t.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|d| d.and_time(chrono::NaiveTime::MIN).and_utc())
+        .map_err(|_| format!("invalid `{name}` `{value}`: expected RFC 3339 or YYYY-MM-DD"))
+}
+
+impl ReportParams {
+    /// The report asked for, as of `now`.
+    pub fn report(&self, now: DateTime<Utc>, cluster: Option<String>) -> Result<Report, String> {
+        let until = match &self.until {
+            Some(until) => parse_time("until", until)?,
+            None => now,
+        };
+        let since = match &self.since {
+            Some(since) => parse_time("since", since)?,
+            None => until - DEFAULT_WINDOW,
+        };
+        if since >= until {
+            return Err("`since` must be before `until`".to_string());
+        }
+        let bucket = match &self.bucket {
+            Some(bucket) => Bucket::parse(bucket)?,
+            None => Bucket::Day,
+        };
+        let mut group_by = Vec::new();
+        for name in self.by.as_deref().unwrap_or("schema,tenant").split(',') {
+            let name = name.trim();
+            if name.is_empty() {
+                continue;
+            }
+            let dimension = Dimension::parse(name)?;
+            if !group_by.contains(&dimension) {
+                group_by.push(dimension);
+            }
+        }
+        Ok(Report {
+            since,
+            until,
+            bucket,
+            group_by,
+            cluster,
+        })
+    }
+}
+
+/// Report rows as JSON numbers rather than quoted 64-bit integers.
+fn report_settings() -> Vec<(String, String)> {
+    vec![(
+        "output_format_json_quote_64bit_integers".to_string(),
+        "0".to_string(),
+    )]
+}
+
+/// `GET /stats/resources?since=&until=&bucket=&by=` — ClickHouse resources
+/// used by ClickGraph statements, per schema / tenant / role.
+pub async fn resources_handler(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<ReportParams>,
+) -> Response {
+    if app_state.config.embedded {
+        return (
+            StatusCode::NOT_IMPLEMENTED,
+            "resource reports read system.query_log of a ClickHouse server",
+        )
+            .into_response();
+    }
+    let report = match params.report(Utc::now(), app_state.config.query_log_cluster.clone()) {
+        Ok(report) => report,
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+    let rows = match app_state
+        .executor
+        .execute_json_with_settings(&report.sql(), &[], &report_settings(), None)
+        .await
+    {
+        Ok(rows) => rows,
+        Err(e) => {
+            log::warn!("Resource report failed: {}", e);
+            return (
+                StatusCode::BAD_GATEWAY,
+                format!("reading system.query_log failed: {e}"),
+            )
+                .into_response();
+        }
+    };
+    Json(json!({
+        "since": report.since.to_rfc3339(),
+        "until": report.until.to_rfc3339(),
+        "bucket": params.bucket.as_deref().unwrap_or("day"),
+        "group_by": report.group_by.iter().map(|d| d.name()).collect::<Vec<_>>(),
+        "rows": rows,
+    }))
+    .into_response()
+}
+
+/// `db.table` or `table` with each part a plain identifier, backquoted.
+pub fn quote_table_name(name: &str) -> Result<String, String> {
+    let parts: Vec<&str> = name.split('.').collect();
+    let valid = parts.len() <= 2
+        && parts.iter().all(|part| {
+            !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+    if !valid {
+        return Err(format!(
+            "invalid attribution table `{name}`: expected `table` or `database.table`"
+        ));
+    }
+    Ok(parts
+        .iter()
+        .map(|part| format!("`{part}`"))
+        .collect::<Vec<_>>()
+        .join("."))
+}
+
+/// `CREATE TABLE` of the export table. Rows are keyed by bucket and group,
+/// so exporting a window again replaces its rows.
+pub fn export_table_ddl(table: &str) -> String {
+    format!(
+        "CREATE TABLE IF NOT EXISTS {table} (\n    \
+         bucket DateTime,\n    schema String,\n    tenant String,\n    role String,\n    \
+         queries UInt64,\n    failed_queries UInt64,\n    duration_ms UInt64,\n    \
+         read_rows UInt64,\n    read_bytes UInt64,\n    result_bytes UInt64,\n    \
+         cpu_seconds Float64,\n    peak_memory_bytes UInt64,\n    memory_bytes UInt64,\n    \
+         exported_at DateTime DEFAULT now()\n\
+         ) ENGINE = ReplacingMergeTree(exported_at)\n\
+         ORDER BY (bucket, schema, tenant, role)"
+    )
+}
+
+/// `INSERT` copying the window `[since, until)` into the export table, one
+/// row per bucket of `interval` and schema / tenant / role.
+pub fn export_sql(
+    table: &str,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    interval: Duration,
+    cluster: Option<String>,
+) -> String {
+    let report = Report {
+        since,
+        until,
+        bucket: Bucket::Seconds(interval.as_secs()),
+        group_by: Dimension::ALL.to_vec(),
+        cluster,
+    };
+    format!(
+        "INSERT INTO {table} (bucket, schema, tenant, role, queries, failed_queries, \
+         duration_ms, read_rows, read_bytes, result_bytes, cpu_seconds, peak_memory_bytes, \
+         memory_bytes)\n{}",
+        report.sql()
+    )
+}
+
+/// Export every completed `interval` into `table`, forever. Each window is
+/// read once its boundary has passed and the query log had time to flush.
+pub async fn export_loop(
+    client: clickhouse::Client,
+    table: String,
+    interval: Duration,
+    cluster: Option<String>,
+) {
+    let table = match quote_table_name(&table) {
+        Ok(table) => table,
+        Err(e) => {
+            log::error!("Resource attribution export disabled: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = client.query(&export_table_ddl(&table)).execute().await {
+        log::error!("Resource attribution export disabled: creating {table} failed: {e}");
+        return;
+    }
+    let step = interval.as_secs().max(1) as i64;
+    loop {
+        // Sleep past the next boundary, then export the window it closed
+        let now = Utc::now().timestamp();
+        let boundary = (now / step + 1) * step;
+        let wait = Duration::from_secs((boundary - now) as u64) + QUERY_LOG_FLUSH_SLACK;
+        tokio::time::sleep(wait).await;
+
+        let (Some(since), Some(until)) = (
+            DateTime::from_timestamp(boundary - step, 0),
+            DateTime::from_timestamp(boundary, 0),
+        ) else {
+            continue;
+        };
+        let sql = export_sql(&table, since, until, interval, cluster.clone());
+        match client.query(&sql).execute().await {
+            Ok(()) => log::info!("Exported resource attribution for {since} .. {until}"),
+            Err(e) => log::warn!("Resource attribution export for {since} failed: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[tokio::test]
+    async fn statements_in_scope_are_tagged_with_their_attribution() {
+        assert!(query_settings().is_empty());
+        let attribution = Attribution {
+            schema: Some("social".to_string()),
+            tenant: Some("acme".to_string()),
+            role: None,
+        };
+        let settings = scope(attribution, async { query_settings() }).await;
+        assert_eq!(settings.len(), 1);
+        assert_eq!(settings[0].0, "log_comment");
+        let comment: serde_json::Value = serde_json::from_str(&settings[0].1).unwrap();
+        assert_eq!(
+            comment,
+            json!({"app": "clickgraph", "schema": "social", "tenant": "acme", "role": ""})
+        );
+    }
+
+    #[test]
+    fn report_defaults_to_a_day_by_schema_and_tenant() {
+        let now = at("2026-10-17T12:00:00Z");
+        let report = ReportParams::default().report(now, None).unwrap();
+        assert_eq!(report.since, at("2026-10-16T12:00:00Z"));
+        assert_eq!(report.until, now);
+        assert_eq!(report.bucket, Bucket::Day);
+        assert_eq!(report.group_by, vec![Dimension::Schema, Dimension::Tenant]);
+
+        let sql = report.sql();
+        assert!(sql.contains("toStartOfDay(event_time) AS bucket"), "{sql}");
+        assert!(
+            sql.contains("JSONExtractString(log_comment, 'tenant') AS tenant"),
+            "{sql}"
+        );
+        assert!(sql.contains("FROM system.query_log"), "{sql}");
+        assert!(
+            sql.contains("event_time >= toDateTime('2026-10-16 12:00:00', 'UTC')"),
+            "{sql}"
+        );
+        assert!(sql.contains("GROUP BY bucket, schema, tenant"), "{sql}");
+    }
+
+    #[test]
+    fn report_params_are_validated() {
+        let now = Utc::now();
+        let params = |since: &str, bucket: &str, by: &str| ReportParams {
+            since: Some(since.to_string()),
+            until: Some("2026-10-17".to_string()),
+            bucket: Some(bucket.to_string()),
+            by: Some(by.to_string()),
+        };
+        let report = params("2026-10-01", "none", "role, tenant")
+            .report(now, Some("main".to_string()))
+            .unwrap();
+        let sql = report.sql();
+        assert!(!sql.contains("AS bucket"), "{sql}");
+        assert!(sql.contains("GROUP BY role, tenant"), "{sql}");
+        assert!(
+            sql.contains("clusterAllReplicas('main', system.query_log)"),
+            "{sql}"
+        );
+
+        assert!(params("yesterday", "day", "schema")
+            .report(now, None)
+            .is_err());
+        assert!(params("2026-10-18", "day", "schema")
+            .report(now, None)
+            .is_err());
+        assert!(params("2026-10-01", "week", "schema")
+            .report(now, None)
+            .is_err());
+        assert!(params("2026-10-01", "day", "user")
+            .report(now, None)
+            .is_err());
+    }
+
+    #[test]
+    fn export_inserts_fixed_buckets_of_every_dimension() {
+        assert_eq!(
+            quote_table_name("billing.graph_usage").unwrap(),
+            "`billing`.`graph_usage`"
+        );
+        assert!(quote_table_name("usage; DROP TABLE x").is_err());
+        assert!(quote_table_name("a.b.c").is_err());
+
+        let sql = export_sql(
+            "`graph_usage`",
+            at("2026-10-17T11:00:00Z"),
+            at("2026-10-17T12:00:00Z"),
+            Duration::from_secs(3600),
+            None,
+        );
+        assert!(sql.starts_with("INSERT INTO `graph_usage` (bucket, schema, tenant, role,"));
+        assert!(
+            sql.contains("toStartOfInterval(event_time, INTERVAL 3600 SECOND) AS bucket"),
+            "{sql}"
+        );
+        assert!(
+            sql.contains("GROUP BY bucket, schema, tenant, role"),
+            "{sql}"
+        );
+        assert!(export_table_ddl("`graph_usage`").contains("ReplacingMergeTree(exported_at)"));
+    }
+}
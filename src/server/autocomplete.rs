@@ -0,0 +1,351 @@
+//! Catalog-aware autocomplete for editor integrations.
+//!
+//! Backs `GET /schemas/{name}/complete`: given a prefix and a completion
+//! context (node label, property name, or relationship type), rank the
+//! matching names from the loaded schema. Editors (the VS Code plugin, the
+//! web UI) call this on every keystroke, so it works purely on the in-memory
+//! catalog — no ClickHouse round trip.
+//!
+//! Ranking, best first:
+//! 1. case-sensitive prefix match
+//! 2. case-insensitive prefix match
+//! 3. case-insensitive substring match
+//!
+//! Within a tier, names that appear more often in recent query history come
+//! first, then shorter names, then alphabetical order so results are stable.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use crate::graph_catalog::graph_schema::GraphSchema;
+
+/// Default and maximum number of suggestions returned.
+pub const DEFAULT_LIMIT: usize = 20;
+pub const MAX_LIMIT: usize = 200;
+
+/// What kind of name is being completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionContext {
+    Label,
+    Property,
+    RelType,
+}
+
+impl CompletionContext {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "label" => Some(Self::Label),
+            "property" => Some(Self::Property),
+            "reltype" => Some(Self::RelType),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Label => "label",
+            Self::Property => "property",
+            Self::RelType => "reltype",
+        }
+    }
+}
+
+/// A single ranked completion.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Suggestion {
+    pub text: String,
+    pub kind: &'static str,
+    /// For properties: the labels / relationship types that declare it.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
+    /// Number of recent queries that mention this name.
+    pub history_hits: usize,
+}
+
+/// Rank catalog names matching `prefix` in the given context.
+///
+/// `owner` restricts property completion to one label or relationship type
+/// (e.g. the editor knows the cursor is on `n.` with `n:User`). `history` is a
+/// list of recent Cypher query texts used to boost frequently used names.
+pub fn complete(
+    schema: &GraphSchema,
+    prefix: &str,
+    context: CompletionContext,
+    owner: Option<&str>,
+    history: &[String],
+    limit: usize,
+) -> Vec<Suggestion> {
+    let candidates = candidates(schema, context, owner);
+    let prefix_lower = prefix.to_lowercase();
+
+    let mut ranked: Vec<(u8, Suggestion)> = candidates
+        .into_iter()
+        .filter_map(|(name, owners)| {
+            let tier = match_tier(&name, prefix, &prefix_lower)?;
+            let history_hits = history
+                .iter()
+                .filter(|q| mentions(q, &name, context))
+                .count();
+            Some((
+                tier,
+                Suggestion {
+                    text: name,
+                    kind: context.as_str(),
+                    owners: owners.into_iter().collect(),
+                    history_hits,
+                },
+            ))
+        })
+        .collect();
+
+    ranked.sort_by(|(ta, a), (tb, b)| {
+        ta.cmp(tb)
+            .then(b.history_hits.cmp(&a.history_hits))
+            .then(a.text.len().cmp(&b.text.len()))
+            .then(a.text.cmp(&b.text))
+    });
+    ranked
+        .into_iter()
+        .take(limit.min(MAX_LIMIT))
+        .map(|(_, s)| s)
+        .collect()
+}
+
+/// Candidate names for the context, each with the set of declaring owners.
+fn candidates(
+    schema: &GraphSchema,
+    context: CompletionContext,
+    owner: Option<&str>,
+) -> BTreeMap<String, BTreeSet<String>> {
+    let mut out: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    match context {
+        CompletionContext::Label => {
+            for label in schema.all_node_schemas().keys() {
+                out.entry(label.clone()).or_default();
+            }
+        }
+        CompletionContext::RelType => {
+            for rel_type in schema.get_rel_type_index().keys() {
+                out.entry(rel_type.clone()).or_default();
+            }
+        }
+        CompletionContext::Property => {
+            for (label, node) in schema.all_node_schemas() {
+                if owner.is_some_and(|o| o != label) {
+                    continue;
+                }
+                for prop in node.property_mappings.keys() {
+                    out.entry(prop.clone()).or_default().insert(label.clone());
+                }
+            }
+            // The type index collapses `TYPE::From::To` variants under one name.
+            for rel_type in schema.get_rel_type_index().keys() {
+                if owner.is_some_and(|o| o != rel_type) {
+                    continue;
+                }
+                for rel in schema.rel_schemas_for_type(rel_type) {
+                    for prop in rel.property_mappings.keys() {
+                        out.entry(prop.clone())
+                            .or_default()
+                            .insert(rel_type.clone());
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+fn match_tier(name: &str, prefix: &str, prefix_lower: &str) -> Option<u8> {
+    if name.starts_with(prefix) {
+        return Some(0);
+    }
+    let lower = name.to_lowercase();
+    if lower.starts_with(prefix_lower) {
+        Some(1)
+    } else if lower.contains(prefix_lower) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Whether a query text uses `name` in the position the context implies:
+/// `:Label`, `.property`, or `:TYPE` inside a relationship pattern.
+fn mentions(query: &str, name: &str, context: CompletionContext) -> bool {
+    let sigil = match context {
+        CompletionContext::Label | CompletionContext::RelType => ':',
+        CompletionContext::Property => '.',
+    };
+    query.match_indices(name).any(|(idx, _)| {
+        let before = query[..idx].trim_end();
+        let after = query[idx + name.len()..].chars().next();
+        let whole_word = !after.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        whole_word && (before.ends_with(sigil) || (sigil == ':' && before.ends_with('|')))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    const SCHEMA_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        email: email
+    - label: Post
+      database: test
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: test
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings:
+        follow_date: created_at
+    - type: FAVORITED
+      database: test
+      table: favorites
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+"#;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .expect("valid schema")
+    }
+
+    fn texts(suggestions: &[Suggestion]) -> Vec<&str> {
+        suggestions.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn parses_contexts() {
+        assert_eq!(
+            CompletionContext::parse("RelType"),
+            Some(CompletionContext::RelType)
+        );
+        assert_eq!(CompletionContext::parse("column"), None);
+    }
+
+    #[test]
+    fn labels_rank_prefix_before_substring() {
+        let s = complete(
+            &schema(),
+            "se",
+            CompletionContext::Label,
+            None,
+            &[],
+            DEFAULT_LIMIT,
+        );
+        assert_eq!(texts(&s), vec!["User"]);
+
+        let s = complete(
+            &schema(),
+            "us",
+            CompletionContext::Label,
+            None,
+            &[],
+            DEFAULT_LIMIT,
+        );
+        assert_eq!(texts(&s), vec!["User"]);
+
+        let s = complete(
+            &schema(),
+            "",
+            CompletionContext::Label,
+            None,
+            &[],
+            DEFAULT_LIMIT,
+        );
+        assert_eq!(texts(&s), vec!["Post", "User"]);
+    }
+
+    #[test]
+    fn reltypes_are_deduplicated_and_boosted_by_history() {
+        let history = vec![
+            "MATCH (u:User)-[:FOLLOWS]->(v) RETURN v".to_string(),
+            "MATCH (u)-[r:FOLLOWS|FAVORITED]->(v) RETURN r".to_string(),
+        ];
+        let s = complete(
+            &schema(),
+            "F",
+            CompletionContext::RelType,
+            None,
+            &history,
+            DEFAULT_LIMIT,
+        );
+        assert_eq!(texts(&s), vec!["FOLLOWS", "FAVORITED"]);
+        assert_eq!(s[0].history_hits, 2);
+        assert_eq!(s[1].history_hits, 1);
+    }
+
+    #[test]
+    fn properties_report_owners_and_respect_owner_filter() {
+        let s = complete(
+            &schema(),
+            "",
+            CompletionContext::Property,
+            None,
+            &[],
+            DEFAULT_LIMIT,
+        );
+        let follow_date = s.iter().find(|s| s.text == "follow_date").unwrap();
+        assert_eq!(follow_date.owners, vec!["FOLLOWS".to_string()]);
+
+        let s = complete(
+            &schema(),
+            "",
+            CompletionContext::Property,
+            Some("Post"),
+            &[],
+            DEFAULT_LIMIT,
+        );
+        assert_eq!(texts(&s), vec!["title", "post_id"]);
+    }
+
+    #[test]
+    fn history_match_requires_sigil_and_whole_word() {
+        assert!(mentions(
+            "MATCH (u) RETURN u.name",
+            "name",
+            CompletionContext::Property
+        ));
+        assert!(!mentions(
+            "MATCH (u) RETURN u.names",
+            "name",
+            CompletionContext::Property
+        ));
+        assert!(!mentions(
+            "RETURN 'name'",
+            "name",
+            CompletionContext::Property
+        ));
+    }
+
+    #[test]
+    fn limit_is_applied() {
+        let s = complete(&schema(), "", CompletionContext::Property, None, &[], 2);
+        assert_eq!(s.len(), 2);
+    }
+}
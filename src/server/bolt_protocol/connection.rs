@@ -62,7 +62,7 @@ where
         // Apply connection timeout
         let timeout_duration = Duration::from_secs(self.config.connection_timeout);
 
-        timeout(timeout_duration, async {
+        let result = timeout(timeout_duration, async {
             // Step 1: Perform handshake and version negotiation
             self.perform_handshake().await?;
 
@@ -71,8 +71,12 @@ where
 
             Ok::<(), BoltError>(())
         })
-        .await
-        .map_err(|_| BoltError::ConnectionTimeout {
+        .await;
+
+        // Step 3: Release ClickHouse sessions (temporary tables) of this connection
+        self.handler.close_backend_sessions().await;
+
+        result.map_err(|_| BoltError::ConnectionTimeout {
             timeout_seconds: self.config.connection_timeout,
         })?
     }
@@ -36,6 +36,10 @@ pub enum BoltError {
     #[error("User {user} is not authorized to perform action: {action}")]
     AuthorizationFailed { user: String, action: String },
 
+    /// The query targets a graph its session is not confined to
+    #[error("{message}")]
+    GraphForbidden { message: String },
+
     /// Invalid connection state for operation
     #[error("Invalid connection state: {current_state}, expected: {expected_state}")]
     InvalidState {
@@ -74,6 +78,10 @@ pub enum BoltError {
     /// Feature not implemented
     #[error("Feature not implemented: {feature}")]
     NotImplemented { feature: String },
+
+    /// The query ran past its timeout and was cancelled
+    #[error("The query exceeded its timeout of {timeout_ms} ms and was cancelled")]
+    QueryTimeout { timeout_ms: u64 },
 }
 
 /// Result type for Bolt operations
@@ -124,6 +132,7 @@ impl BoltError {
             BoltError::MessageTooLarge { .. } => "Neo.ClientError.Request.InvalidFormat",
             BoltError::AuthenticationFailed { .. } => "Neo.ClientError.Security.Unauthorized",
             BoltError::AuthorizationFailed { .. } => "Neo.ClientError.Security.Forbidden",
+            BoltError::GraphForbidden { .. } => "Neo.ClientError.Security.Forbidden",
             BoltError::InvalidState { .. } => "Neo.ClientError.Request.Invalid",
             BoltError::QueryError { .. } => "Neo.ClientError.Statement.SyntaxError",
             BoltError::TransactionError { .. } => "Neo.TransientError.Transaction.Terminated",
@@ -133,6 +142,9 @@ impl BoltError {
             BoltError::Internal { .. } => "Neo.DatabaseError.General.UnknownError",
             BoltError::MutexPoisoned { .. } => "Neo.TransientError.General.DatabaseUnavailable",
             BoltError::NotImplemented { .. } => "Neo.ClientError.Statement.FeatureNotSupported",
+            BoltError::QueryTimeout { .. } => {
+                "Neo.ClientError.Transaction.TransactionTimedOutClientConfiguration"
+            }
         }
     }
 
@@ -793,6 +793,43 @@ mod tests {
         assert_eq!(&encoded[6..13], b"FOLLOWS");
     }
 
+    #[test]
+    fn test_multi_hop_path_packstream_encoding() {
+        let node = |id: i64| {
+            Node::new(
+                id,
+                vec!["User".to_string()],
+                HashMap::new(),
+                format!("User:{}", id),
+            )
+        };
+        let rel = |from: i64, to: i64| {
+            Relationship::new(
+                0,
+                from,
+                to,
+                "FOLLOWS".to_string(),
+                HashMap::new(),
+                format!("FOLLOWS:{}->{}", from, to),
+                format!("User:{}", from),
+                format!("User:{}", to),
+            )
+        };
+        let path = Path::new(
+            vec![node(1), node(2), node(3)],
+            vec![rel(1, 2), rel(2, 3)],
+            vec![1, 1, 2, 2],
+        );
+
+        let encoded = path.to_packstream();
+
+        assert_eq!(encoded[0], 0xB3); // 3-field struct
+        assert_eq!(encoded[1], 0x50); // 'P' for Path
+        assert_eq!(encoded[2], 0x93); // 3 nodes
+                                      // Trailing field 3: indices [1, 1, 2, 2]
+        assert_eq!(&encoded[encoded.len() - 5..], &[0x94, 1, 1, 2, 2]);
+    }
+
     #[test]
     fn test_node_with_composite_element_id() {
         let properties = HashMap::new();
@@ -11,6 +11,7 @@ use std::sync::{Arc, Mutex};
 use super::auth::{AuthToken, AuthenticatedUser, Authenticator};
 use super::errors::{BoltError, BoltResult};
 use super::messages::{signatures, BoltMessage, BoltValue};
+use super::result_spill::{ResultBuffer, SpillError};
 use super::result_transformer::extract_return_metadata;
 use super::{BoltConfig, BoltContext, ConnectionState};
 
@@ -21,6 +22,7 @@ use crate::query_planner;
 use crate::server::handlers::QueryPerformanceMetrics;
 use crate::server::metrics::{self, ErrorClass, Outcome, QuerySample};
 use crate::server::GLOBAL_SERVER_METRICS;
+use crate::utils::redaction::{redact, redact_sql};
 
 /// Execution plan for procedure-only queries (extracted before async execution)
 #[derive(Debug)]
@@ -45,7 +47,10 @@ struct ProcedureBranch {
 }
 
 use crate::render_plan::plan_builder::RenderPlanBuilder;
-use crate::server::{graph_catalog, parameter_substitution};
+use crate::server::{
+    attribution, cancellation, endpoint_routing, graph_catalog, parameter_substitution,
+    query_settings, session_params, stored_queries,
+};
 
 /// Helper macro for safe mutex locking with proper error handling
 macro_rules! lock_context {
@@ -65,78 +70,47 @@ fn bolt_value_to_string(value: &BoltValue) -> String {
     }
 }
 
-/// Detect Browser 5.x's bundled count query:
-///   `MATCH (n) RETURN count(n) AS result UNION ALL MATCH ()-[r]->() RETURN count(r) AS result`
-///
-/// Older Browser versions issue these as two separate queries which flow through
-/// the normal pipeline correctly. The bundled UNION ALL form crashes our SQL
-/// generator, so it gets intercepted in `handle_run`. Tolerant of whitespace and
-/// trailing semicolons; case-insensitive.
 /// Build the two RECORD fields (`nodes`, `relationships`) returned by
-/// `CALL db.schema.visualization()`: a virtual graph of the schema with one
-/// node per label and one relationship per (type, from-label, to-label). The
+/// `CALL db.schema.visualization()` from the procedure's schema graph. The
 /// node/relationship structures are packstream-encoded so Neo4j Browser renders
 /// them as a real graph and derives default per-label styling from them.
 fn build_schema_visualization_fields(
     schema: &crate::graph_catalog::graph_schema::GraphSchema,
 ) -> Vec<BoltValue> {
+    use crate::procedures::db_schema_visualization::schema_graph;
     use crate::server::bolt_protocol::graph_objects::{encode_packstream_list, Node, Relationship};
-    use std::collections::HashMap as StdHashMap;
 
-    // One virtual node per (deduped) base label.
-    let mut labels: Vec<String> = schema
-        .all_node_schemas()
-        .keys()
-        .map(|k| k.rsplit("::").next().unwrap_or(k).to_string())
+    let graph = schema_graph(schema);
+    let node_bytes: Vec<Vec<u8>> = graph
+        .nodes
+        .iter()
+        .map(|node| {
+            Node::new(
+                node.id,
+                vec![node.label.clone()],
+                node.properties(),
+                node.element_id(),
+            )
+            .to_packstream()
+        })
         .collect();
-    labels.sort();
-    labels.dedup();
-
-    let mut label_id: StdHashMap<String, i64> = StdHashMap::new();
-    let mut node_bytes: Vec<Vec<u8>> = Vec::with_capacity(labels.len());
-    for (i, label) in labels.iter().enumerate() {
-        let id = i as i64;
-        label_id.insert(label.clone(), id);
-        let mut props: StdHashMap<String, serde_json::Value> = StdHashMap::new();
-        // `name` drives the Browser's default caption for the schema node.
-        props.insert("name".to_string(), serde_json::Value::String(label.clone()));
-        let element_id = format!("schema-node-{i}");
-        node_bytes.push(Node::new(id, vec![label.clone()], props, element_id).to_packstream());
-    }
-
-    // One virtual relationship per distinct (type, from-label, to-label).
-    let mut rel_bytes: Vec<Vec<u8>> = Vec::new();
-    let mut seen: std::collections::HashSet<(String, String, String)> =
-        std::collections::HashSet::new();
-    for (key, rel) in schema.get_relationships_schemas().iter() {
-        let rel_type = key.split("::").next().unwrap_or(key);
-        if rel_type.is_empty() {
-            continue;
-        }
-        let from_label = rel.from_node.clone();
-        let to_label = rel.to_node.clone();
-        if !seen.insert((rel_type.to_string(), from_label.clone(), to_label.clone())) {
-            continue;
-        }
-        let (Some(&from_id), Some(&to_id)) = (label_id.get(&from_label), label_id.get(&to_label))
-        else {
-            continue;
-        };
-        let rid = rel_bytes.len() as i64;
-        rel_bytes.push(
+    let rel_bytes: Vec<Vec<u8>> = graph
+        .relationships
+        .iter()
+        .map(|rel| {
             Relationship::new(
-                rid,
-                from_id,
-                to_id,
-                rel_type.to_string(),
-                StdHashMap::new(),
-                format!("schema-rel-{rid}"),
-                format!("schema-node-{from_id}"),
-                format!("schema-node-{to_id}"),
+                rel.id,
+                rel.start,
+                rel.end,
+                rel.rel_type.clone(),
+                HashMap::new(),
+                rel.element_id(),
+                rel.start_element_id(),
+                rel.end_element_id(),
             )
-            .to_packstream(),
-        );
-    }
+            .to_packstream()
+        })
+        .collect();
 
     vec![
         BoltValue::PackstreamBytes(encode_packstream_list(&node_bytes)),
@@ -144,6 +118,77 @@ fn build_schema_visualization_fields(
     ]
 }
 
+/// Seconds a driver may cache a routing table before asking again.
+const ROUTING_TABLE_TTL_SECS: u64 = 300;
+
+/// Client address and database of a routing procedure call.
+type RoutingCall = (Option<String>, Option<String>);
+
+/// Match `CALL dbms.routing.getRoutingTable($context[, $database])`, the
+/// routing procedure of Bolt 4.1/4.2 drivers, returning the routing
+/// context's `address` and the database. Arguments may be parameters or
+/// literals (a map context, a string database); `null` or a missing database
+/// selects the default graph. Any other argument is an error.
+fn routing_procedure_call(
+    query: &str,
+    parameters: &HashMap<String, Value>,
+) -> Option<Result<RoutingCall, String>> {
+    use crate::open_cypher_parser::ast::{CypherStatement, Expression};
+
+    const PROCEDURE: &str = "dbms.routing.getRoutingTable";
+    let cleaned = open_cypher_parser::strip_comments(query);
+    let statement = cleaned.trim().trim_end_matches(';').trim_end();
+    let head = statement.get(.."CALL ".len() + PROCEDURE.len())?;
+    if !head.eq_ignore_ascii_case(&format!("CALL {}", PROCEDURE)) {
+        return None;
+    }
+    // The parser takes the keyword in upper case only
+    let statement = format!("CALL{}", &statement["CALL".len()..]);
+    let arguments = match open_cypher_parser::parse_cypher_statement(&statement) {
+        Ok((_, CypherStatement::ProcedureCall(call)))
+            if call.procedure_name.eq_ignore_ascii_case(PROCEDURE) =>
+        {
+            call.arguments
+        }
+        Ok(_) => return None,
+        Err(e) => return Some(Err(format!("invalid {} call: {}", PROCEDURE, e))),
+    };
+    let value = |argument: &Expression<'_>| match argument {
+        // Drivers may leave the database parameter out
+        Expression::Parameter(name) => Ok(parameters.get(*name).cloned().unwrap_or(Value::Null)),
+        other => stored_queries::literal_json(other, parameters),
+    };
+    let (context, database) = match arguments.as_slice() {
+        [context] => (value(context), Ok(Value::Null)),
+        [context, database] => (value(context), value(database)),
+        _ => return Some(Err(format!("{} expects (context[, database])", PROCEDURE))),
+    };
+    let client_address = match context {
+        Ok(Value::Null) => None,
+        Ok(Value::Object(context)) => match context.get("address") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(address)) => Some(address.clone()),
+            Some(_) => return Some(Err("routing context address must be a string".to_string())),
+        },
+        Ok(_) => return Some(Err("routing context must be a map".to_string())),
+        Err(e) => return Some(Err(e)),
+    };
+    let database = match database {
+        Ok(Value::Null) => None,
+        Ok(Value::String(database)) => Some(database),
+        Ok(_) => return Some(Err("database must be a string".to_string())),
+        Err(e) => return Some(Err(e)),
+    };
+    Some(Ok((client_address, database)))
+}
+
+/// Detect Browser 5.x's bundled count query:
+///   `MATCH (n) RETURN count(n) AS result UNION ALL MATCH ()-[r]->() RETURN count(r) AS result`
+///
+/// Older Browser versions issue these as two separate queries which flow through
+/// the normal pipeline correctly. The bundled UNION ALL form crashes our SQL
+/// generator, so it gets intercepted in `handle_run`. Tolerant of whitespace and
+/// trailing semicolons; case-insensitive.
 fn is_browser_count_union(query_upper: &str) -> bool {
     let normalized: String = query_upper.split_whitespace().collect::<Vec<_>>().join(" ");
     normalized.contains("MATCH (N) RETURN COUNT(N)")
@@ -731,6 +776,15 @@ pub struct BoltHandler {
     executor: Arc<dyn QueryExecutor>,
     /// Cached query results for streaming
     cached_results: Option<Vec<Vec<BoltValue>>>,
+    /// Rows of the current result not yet PULLed (may be spilled to disk)
+    result_buffer: Option<ResultBuffer>,
+    /// Sort keys injected for stable PULL batching (reported in the PULL summary)
+    stable_order_keys: Vec<String>,
+    /// This connection's key for backend sessions (`execute_json_in_session`)
+    session_key: String,
+    /// ClickHouse settings of the current RUN, from its own or its
+    /// transaction's `tx_metadata`
+    query_settings: Vec<(String, String)>,
 }
 
 impl BoltHandler {
@@ -747,9 +801,19 @@ impl BoltHandler {
             authenticated_user: None,
             executor,
             cached_results: None,
+            result_buffer: None,
+            stable_order_keys: Vec::new(),
+            session_key: uuid::Uuid::new_v4().to_string(),
+            query_settings: Vec::new(),
         }
     }
 
+    /// Release the backend sessions this connection opened. Called once the
+    /// connection ends, however it ended.
+    pub async fn close_backend_sessions(&self) {
+        self.executor.close_session(&self.session_key).await;
+    }
+
     /// Handle a Bolt message and return response messages
     pub async fn handle_message(&mut self, message: BoltMessage) -> BoltResult<Vec<BoltMessage>> {
         log::debug!("Handling Bolt message: {}", message.type_name());
@@ -1140,7 +1204,11 @@ impl BoltHandler {
             let mut context = lock_context!(self.context);
             context.set_state(ConnectionState::Ready);
             context.tx_id = None; // Clear any active transaction
+            context.tx_timeout = None;
+            context.tx_settings = None;
         }
+        self.cached_results = None;
+        self.result_buffer = None;
 
         Ok(vec![BoltMessage::success(HashMap::new())])
     }
@@ -1227,6 +1295,40 @@ impl BoltHandler {
         Ok(Some(vec![BoltMessage::success(meta)]))
     }
 
+    /// Run a `:param` / `:params` command against this connection's
+    /// parameters and answer with them as `name`, `value` rows; `None` when
+    /// `query` is not such a command.
+    fn handle_param_command(&mut self, query: &str) -> BoltResult<Option<Vec<BoltMessage>>> {
+        let mut context = lock_context!(self.context);
+        let command = match session_params::parse_command(query, &context.parameters) {
+            None => return Ok(None),
+            Some(Ok(command)) => command,
+            Some(Err(e)) => {
+                return Ok(Some(vec![BoltMessage::failure(
+                    "Neo.ClientError.Statement.SyntaxError".to_string(),
+                    e,
+                )]))
+            }
+        };
+        command.apply(&mut context.parameters);
+        context.set_state(ConnectionState::Streaming);
+        self.cached_results = Some(
+            context
+                .parameters
+                .iter()
+                .map(|(name, value)| {
+                    vec![
+                        BoltValue::Json(Value::String(name.clone())),
+                        BoltValue::Json(value.clone()),
+                    ]
+                })
+                .collect(),
+        );
+        let mut meta = HashMap::new();
+        meta.insert("fields".to_string(), serde_json::json!(["name", "value"]));
+        Ok(Some(vec![BoltMessage::success(meta)]))
+    }
+
     /// Handle RUN message (execute Cypher query)
     async fn handle_run(&mut self, message: BoltMessage) -> BoltResult<Vec<BoltMessage>> {
         // Verify connection state
@@ -1239,6 +1341,8 @@ impl BoltHandler {
                 )]);
             }
         }
+        self.stable_order_keys.clear();
+        self.result_buffer = None;
 
         // Extract query and parameters
         let query = message
@@ -1246,13 +1350,16 @@ impl BoltHandler {
             .ok_or_else(|| BoltError::invalid_message("RUN message missing query"))?;
 
         // Log incoming Cypher query for debugging
-        log::info!("📨 BROWSER SENT CYPHER QUERY: {}", query.trim());
+        log::info!("📨 BROWSER SENT CYPHER QUERY: {}", redact(query.trim()));
 
         // Handle EXPLAIN queries — browser sends "EXPLAIN <partial_query>" as autocomplete
         // probes while the user types. Return empty SUCCESS so probes don't show errors.
         // For fully-formed EXPLAIN queries, this also prevents unnecessary execution.
         if query.trim().to_lowercase().starts_with("explain ") {
-            log::debug!("EXPLAIN query (returning empty plan): {}", query.trim());
+            log::debug!(
+                "EXPLAIN query (returning empty plan): {}",
+                redact(query.trim())
+            );
             let mut meta = HashMap::new();
             meta.insert("fields".to_string(), Value::Array(vec![]));
             // Set state to Streaming so the subsequent PULL gets a clean completion
@@ -1269,12 +1376,77 @@ impl BoltHandler {
             return Ok(response);
         }
 
-        let parameters = message.extract_parameters().unwrap_or_default();
+        // `:param name => value` sets a parameter of this connection
+        if let Some(response) = self.handle_param_command(query)? {
+            return Ok(response);
+        }
+
+        let parameters = {
+            let context = lock_context!(self.context);
+            session_params::merge(&context.parameters, message.extract_parameters())
+                .unwrap_or_default()
+        };
+
+        // `neo4j://` drivers speaking Bolt 4.1/4.2 have no ROUTE message and
+        // fetch their routing table with this procedure instead.
+        if let Some(call) = routing_procedure_call(query, &parameters) {
+            let (client_address, database) = match call {
+                Ok(call) => call,
+                Err(e) => {
+                    return Ok(vec![BoltMessage::failure(
+                        "Neo.ClientError.Procedure.ProcedureCallFailed".to_string(),
+                        e,
+                    )])
+                }
+            };
+            let (db, servers) = match self.routing_table(database, client_address).await {
+                Ok(table) => table,
+                Err(failure) => return Ok(vec![failure]),
+            };
+            log::info!("Returning routing table for database '{}' (procedure)", db);
+            {
+                let mut context = lock_context!(self.context);
+                context.set_state(ConnectionState::Streaming);
+            }
+            self.cached_results = Some(vec![vec![
+                BoltValue::Json(serde_json::json!(ROUTING_TABLE_TTL_SECS)),
+                BoltValue::Json(servers),
+            ]]);
+            let mut result_metadata = HashMap::new();
+            result_metadata.insert("fields".to_string(), serde_json::json!(["ttl", "servers"]));
+            result_metadata.insert("result_consumed_after".to_string(), serde_json::json!(-1));
+            return Ok(vec![BoltMessage::success(result_metadata)]);
+        }
+
+        // `CALL query.run('name', {...})` runs a stored query in place of the
+        // RUN statement, with its own validated parameters.
+        let stored = match stored_queries::expand_run_call(
+            query,
+            &parameters,
+            message.extract_run_role().as_deref(),
+        ) {
+            Ok(stored) => stored,
+            Err(e) => {
+                return Ok(vec![BoltMessage::failure(
+                    e.neo4j_code().to_string(),
+                    e.to_string(),
+                )]);
+            }
+        };
+        let (query, parameters, stored_schema, stored_name) = match stored {
+            Some(bound) => (
+                bound.query,
+                bound.parameters,
+                bound.schema_name,
+                Some(bound.name),
+            ),
+            None => (query.to_string(), parameters, None, None),
+        };
 
         // Substitute Cypher parameters into query string (keeping encoded IDs)
         // This allows parser to see actual values as literals while preserving encoding
         // The AST transformer will then extract labels from encoded IDs for UNION pruning
-        let query = substitute_cypher_parameters(query, &parameters);
+        let query = substitute_cypher_parameters(&query, &parameters);
 
         // NOTE: Do NOT rewrite the browser's directed relationship-fetch to undirected.
         // The browser's Path objects from the expand query already carry all relationship data.
@@ -1333,6 +1505,35 @@ impl BoltHandler {
 
             (schema_name, tenant_id, role, view_parameters)
         };
+        // A stored query cannot take a tenant session to another graph
+        if let Some(stored_graph) = &stored_schema {
+            let pinned = graph_catalog::pinned_graph(None, schema_name.as_deref());
+            if let Err(e) = graph_catalog::check_graph_isolation(pinned.as_deref(), stored_graph) {
+                return Ok(vec![BoltMessage::failure(
+                    "Neo.ClientError.Security.Forbidden".to_string(),
+                    e,
+                )]);
+            }
+        }
+        let schema_name = stored_schema.or(schema_name);
+        // ClickHouse settings of the RUN's (auto-commit) or its transaction's
+        // `tx_metadata`, limited to the server's allowlist
+        let requested_settings = match message.extract_tx_settings() {
+            Some(settings) => Some(settings),
+            None => lock_context!(self.context).tx_settings.clone(),
+        };
+        self.query_settings = match query_settings::validate(
+            requested_settings.iter().flatten(),
+            &self.config.allowed_settings,
+        ) {
+            Ok(settings) => settings,
+            Err(e) => {
+                return Ok(vec![BoltMessage::failure(
+                    "Neo.ClientError.Request.Invalid".to_string(),
+                    e,
+                )])
+            }
+        };
 
         // Store tenant_id on context (needed for execute_cypher_query fallback)
         if let Some(ref tid) = tenant_id {
@@ -1340,7 +1541,7 @@ impl BoltHandler {
             context.tenant_id = Some(tid.clone());
         }
 
-        log::info!("Executing Cypher query: {}", query);
+        log::info!("Executing Cypher query: {}", redact(&query));
 
         if let Some(ref schema) = schema_name {
             log::debug!("Query execution using schema: {}", schema);
@@ -1505,10 +1706,9 @@ impl BoltHandler {
         // as the "Database Information" schema diagram AND uses the labels /
         // relationship types it returns to build the default graph styling
         // (per-label colours + captions) applied to query results. The
-        // procedure-registry path can only emit flat JSON, so we build the
-        // virtual graph here where Node/Relationship structures can be
-        // packstream-encoded: one virtual node per label, one virtual
-        // relationship per (type, from-label, to-label) triple.
+        // procedure registry can only emit flat JSON, so the procedure's
+        // schema graph is packstream-encoded here as Node/Relationship
+        // structures.
         let is_schema_viz = q_norm == "CALL DB.SCHEMA.VISUALIZATION()"
             || q_norm == "CALL DB.SCHEMA.VISUALIZATION"
             || q_norm.starts_with("CALL DB.SCHEMA.VISUALIZATION() ")
@@ -1621,7 +1821,7 @@ impl BoltHandler {
                 count_branch(&node_tables),
                 count_branch(&rel_tables),
             );
-            log::debug!("Browser count UNION SQL: {}", combined_sql);
+            log::debug!("Browser count UNION SQL: {}", redact_sql(&combined_sql));
 
             match self
                 .executor
@@ -1801,6 +2001,14 @@ impl BoltHandler {
             }
         }
 
+        // The first query against a schema checks its pending lazy elements
+        crate::server::lazy_schema::ensure_ready(
+            self.executor.as_ref(),
+            schema_name.as_deref().unwrap_or("default"),
+            std::time::Duration::from_secs(self.config.lazy_schema_retry_secs),
+        )
+        .await;
+
         // Parse and execute the query with task-local schema context
         // Note: id() predicates with encoded values are decoded in FilterTagging pass
         use crate::server::query_context::{with_query_context, QueryContext};
@@ -1811,18 +2019,47 @@ impl BoltHandler {
         // type. The in-flight guard + CH-stats scope mirror the HTTP path.
         let _inflight = GLOBAL_SERVER_METRICS.get().map(|r| r.in_flight_guard());
         let run_start = std::time::Instant::now();
-        let exec_result = metrics::with_ch_stats_scope(with_query_context(
-            ctx,
-            self.execute_cypher_query(
-                &query,
-                parameters,
-                schema_name,
-                tenant_id,
-                role,
-                view_parameters,
-            ),
-        ))
+        // The RUN's `tx_timeout` (auto-commit) or its transaction's, capped by
+        // the server; ClickHouse queries still running at the deadline, or
+        // when the connection drops, are killed
+        let requested_timeout = message
+            .extract_tx_timeout()
+            .or(lock_context!(self.context).tx_timeout);
+        let timeout =
+            cancellation::effective_timeout(requested_timeout, self.config.query_timeout_secs);
+        let executor = self.executor.clone();
+        let billed_to = attribution::Attribution {
+            schema: schema_name.clone(),
+            tenant: tenant_id.clone(),
+            role: role.clone(),
+        };
+        // Boxed to keep the large query future off the stack
+        let run = cancellation::run(
+            executor,
+            timeout,
+            Box::pin(attribution::scope(
+                billed_to,
+                metrics::with_ch_stats_scope(with_query_context(ctx, async {
+                    let result = self
+                        .execute_cypher_query(
+                            &query,
+                            parameters,
+                            schema_name,
+                            tenant_id,
+                            role,
+                            view_parameters,
+                        )
+                        .await;
+                    // Read inside the scope; the slot is gone once it ends
+                    (result, metrics::current_ch_stats())
+                })),
+            )),
+        )
         .await;
+        let (exec_result, ch_stats) = run.unwrap_or_else(|timed_out| {
+            let timeout_ms = timed_out.0.as_millis() as u64;
+            (Err(BoltError::QueryTimeout { timeout_ms }), None)
+        });
 
         let (messages, outcome) = match exec_result {
             Ok(result_metadata) => {
@@ -1838,11 +2075,11 @@ impl BoltHandler {
             Err(query_error) => {
                 let error_code = query_error.error_code().to_string();
                 let error_message = query_error.to_string();
-                log::error!("Query execution failed: {}", query_error);
+                log::error!("Query execution failed: {}", redact(&error_message));
                 log::error!(
                     "Sending FAILURE: code='{}', message='{}'",
                     error_code,
-                    error_message
+                    redact(&error_message)
                 );
 
                 // Don't update state - let client send RESET to recover
@@ -1855,6 +2092,14 @@ impl BoltHandler {
             }
         };
 
+        if let Some(name) = &stored_name {
+            stored_queries::registry().record(
+                name,
+                run_start.elapsed(),
+                matches!(outcome, Outcome::Ok),
+            );
+        }
+
         if let Some(reg) = GLOBAL_SERVER_METRICS.get() {
             let elapsed = run_start.elapsed().as_secs_f64();
             let m = QueryPerformanceMetrics {
@@ -1868,7 +2113,7 @@ impl BoltHandler {
                 outcome,
                 has_phase_breakdown: false,
                 query_text: Some(&query),
-                ch: metrics::current_ch_stats(),
+                ch: ch_stats,
             });
         }
 
@@ -1876,7 +2121,7 @@ impl BoltHandler {
     }
 
     /// Handle PULL message (fetch query results)
-    async fn handle_pull(&mut self, _message: BoltMessage) -> BoltResult<Vec<BoltMessage>> {
+    async fn handle_pull(&mut self, message: BoltMessage) -> BoltResult<Vec<BoltMessage>> {
         // Verify connection state
         {
             let context = lock_context!(self.context);
@@ -1888,10 +2133,34 @@ impl BoltHandler {
             }
         }
 
-        // Stream the cached results as RECORD messages
+        // Stream the cached results as RECORD messages, at most `n` per PULL
+        // (n = -1 means all remaining records)
         let mut messages = Vec::new();
+        let batch_size = message.extract_pull_n();
 
         if let Some(rows) = self.cached_results.take() {
+            // Only a client paging through the result can leave rows behind,
+            // so a PULL-all never spills
+            let spill = if batch_size > 0 {
+                self.config.result_spill.as_ref()
+            } else {
+                None
+            };
+            match ResultBuffer::new(rows, spill) {
+                Ok(buffer) => self.result_buffer = Some(buffer),
+                Err(e) => return self.spill_failure(e),
+            }
+        }
+
+        if let Some(buffer) = self.result_buffer.as_mut() {
+            let limit = (batch_size > 0).then_some(batch_size as usize);
+            let rows = match buffer.take(limit) {
+                Ok(rows) => rows,
+                Err(e) => return self.spill_failure(e),
+            };
+            if buffer.is_empty() {
+                self.result_buffer = None;
+            }
             log::debug!("Streaming {} rows via Bolt RECORD messages", rows.len());
 
             // Send each row as a RECORD message
@@ -1901,11 +2170,30 @@ impl BoltHandler {
             }
         }
 
+        if self.result_buffer.is_some() {
+            // More records remain: stay in STREAMING for the next PULL
+            let mut metadata = HashMap::new();
+            metadata.insert("has_more".to_string(), Value::Bool(true));
+            messages.push(BoltMessage::success(metadata));
+            return Ok(messages);
+        }
+
         // Send SUCCESS with completion metadata
         let mut metadata = HashMap::new();
         metadata.insert("type".to_string(), Value::String("r".to_string()));
         metadata.insert("has_more".to_string(), Value::Bool(false));
         metadata.insert("t_last".to_string(), Value::Number(0.into()));
+        if !self.stable_order_keys.is_empty() {
+            metadata.insert(
+                "stable_order".to_string(),
+                Value::Array(
+                    self.stable_order_keys
+                        .iter()
+                        .map(|k| Value::String(k.clone()))
+                        .collect(),
+                ),
+            );
+        }
 
         messages.push(BoltMessage::success(metadata));
 
@@ -1918,6 +2206,25 @@ impl BoltHandler {
         Ok(messages)
     }
 
+    /// FAILURE for a result that could not be spilled or read back; the
+    /// rest of the result is dropped.
+    fn spill_failure(&mut self, error: SpillError) -> BoltResult<Vec<BoltMessage>> {
+        log::error!("Bolt result spill failed: {}", error);
+        self.result_buffer = None;
+        {
+            let mut context = lock_context!(self.context);
+            context.set_state(ConnectionState::Ready);
+        }
+        let code = match error {
+            SpillError::LimitExceeded { .. } => "Neo.TransientError.General.OutOfMemoryError",
+            SpillError::Io(_) => "Neo.DatabaseError.General.UnknownError",
+        };
+        Ok(vec![BoltMessage::failure(
+            code.to_string(),
+            error.to_string(),
+        )])
+    }
+
     /// Handle DISCARD message (discard query results)
     async fn handle_discard(&mut self, _message: BoltMessage) -> BoltResult<Vec<BoltMessage>> {
         // Verify connection state
@@ -1932,6 +2239,8 @@ impl BoltHandler {
         }
 
         log::debug!("Discarding query results");
+        self.cached_results = None;
+        self.result_buffer = None;
 
         // Update context back to ready state
         {
@@ -1991,6 +2300,8 @@ impl BoltHandler {
         {
             let mut context = lock_context!(self.context);
             context.tx_id = Some(tx_id.clone());
+            context.tx_timeout = message.extract_tx_timeout();
+            context.tx_settings = message.extract_tx_settings();
         }
 
         log::info!("Started transaction: {}", tx_id);
@@ -2004,6 +2315,8 @@ impl BoltHandler {
         let tx_id = {
             let mut context = lock_context!(self.context);
             if let Some(tx_id) = context.tx_id.take() {
+                context.tx_timeout = None;
+                context.tx_settings = None;
                 tx_id
             } else {
                 return Ok(vec![BoltMessage::failure(
@@ -2024,6 +2337,8 @@ impl BoltHandler {
         let tx_id = {
             let mut context = lock_context!(self.context);
             if let Some(tx_id) = context.tx_id.take() {
+                context.tx_timeout = None;
+                context.tx_settings = None;
                 tx_id
             } else {
                 return Ok(vec![BoltMessage::failure(
@@ -2039,87 +2354,92 @@ impl BoltHandler {
     }
 
     /// Handle ROUTE message (return routing table for database)
-    /// ROUTE message format: ROUTE {routing_context} [bookmarks] {extra}
-    /// where extra can contain {"db": "database_name"}
+    /// ROUTE message format: ROUTE {routing_context} [bookmarks] db
+    /// where db is the database name or null (Bolt 4.3), or an extra map
+    /// with a `db` key (Bolt 4.4+)
     async fn handle_route(&mut self, message: BoltMessage) -> BoltResult<Vec<BoltMessage>> {
         log::info!("ROUTE message received");
 
-        // Extract database from ROUTE message (field 2 - extra metadata)
-        let database = if message.fields.len() >= 3 {
-            if let BoltValue::Json(Value::Object(extra_map)) = &message.fields[2] {
-                extra_map
-                    .get("db")
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string())
-            } else {
-                None
-            }
-        } else {
-            None
+        let database = match message.fields.get(2) {
+            Some(BoltValue::Json(Value::Object(extra_map))) => extra_map
+                .get("db")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            Some(BoltValue::Json(Value::String(db))) => Some(db.clone()),
+            _ => None,
+        };
+        // Field 0 is the routing context; its `address` is the host:port the driver dialled.
+        let client_address = match message.fields.first() {
+            Some(BoltValue::Json(Value::Object(routing_context))) => routing_context
+                .get("address")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            _ => None,
         };
 
-        let db_name = database.unwrap_or_else(|| "default".to_string());
-        log::info!("ROUTE request for database: {}", db_name);
+        let (db, servers) = match self.routing_table(database, client_address).await {
+            Ok(table) => table,
+            Err(failure) => return Ok(vec![failure]),
+        };
+        log::info!("✅ Returning routing table for database: {}", db);
 
-        // Verify schema exists
-        let schema_exists = if let Some(schemas_lock) = crate::server::GLOBAL_SCHEMAS.get() {
-            if let Ok(schemas) = schemas_lock.try_read() {
-                schemas.contains_key(&db_name)
-            } else {
-                false
-            }
-        } else {
-            false
+        let mut routing_table = serde_json::Map::new();
+        routing_table.insert("ttl".to_string(), serde_json::json!(ROUTING_TABLE_TTL_SECS));
+        routing_table.insert("db".to_string(), Value::String(db));
+        routing_table.insert("servers".to_string(), servers);
+
+        let mut metadata = HashMap::new();
+        metadata.insert("rt".to_string(), Value::Object(routing_table));
+        Ok(vec![BoltMessage::success(metadata)])
+    }
+
+    /// The routing table of `database` (the default graph when unset), for
+    /// ROUTE and `dbms.routing.getRoutingTable`: the database's name and its
+    /// servers. ClickGraph is a single server, so this instance is the
+    /// writer, the reader and the router. Fails for an unknown database, and
+    /// for another graph than the one a tenant session is confined to.
+    async fn routing_table(
+        &self,
+        database: Option<String>,
+        client_address: Option<String>,
+    ) -> Result<(String, Value), BoltMessage> {
+        let db_name = graph_catalog::resolve_graph_name(None, database.as_deref());
+        log::info!("Routing table requested for database: {}", db_name);
+
+        let selected = {
+            let context = self.context.lock().unwrap_or_else(|e| e.into_inner());
+            context.schema_name.clone()
         };
+        let pinned = graph_catalog::pinned_graph(None, selected.as_deref());
+        if let Err(e) = graph_catalog::check_graph_isolation(pinned.as_deref(), &db_name) {
+            return Err(BoltMessage::failure(
+                "Neo.ClientError.Security.Forbidden".to_string(),
+                e,
+            ));
+        }
 
+        let schema_exists = match crate::server::GLOBAL_SCHEMAS.get() {
+            Some(schemas) => schemas.read().await.contains_key(&db_name),
+            None => false,
+        };
         if !schema_exists {
-            log::warn!("ROUTE requested for non-existent database: {}", db_name);
-            return Ok(vec![BoltMessage::failure(
+            log::warn!(
+                "Routing table requested for non-existent database: {}",
+                db_name
+            );
+            return Err(BoltMessage::failure(
                 "Neo.ClientError.Database.DatabaseNotFound".to_string(),
                 format!("Database '{}' not found", db_name),
-            )]);
+            ));
         }
 
-        // Build routing table response
-        // For ClickGraph (single server, no cluster), we return ourselves for all roles
-        let server_address = format!("{}:{}", self.config.host, self.config.port);
-
-        let mut routing_table = serde_json::Map::new();
-        routing_table.insert("ttl".to_string(), Value::Number(300.into())); // 5 minutes TTL
-        routing_table.insert("db".to_string(), Value::String(db_name));
-
-        // Servers list: we are WRITE, READ, and ROUTE all in one
+        let address = self.config.routing_address(client_address.as_deref());
         let servers = serde_json::json!([
-            {
-                "role": "WRITE",
-                "addresses": [server_address.clone()]
-            },
-            {
-                "role": "READ",
-                "addresses": [server_address.clone()]
-            },
-            {
-                "role": "ROUTE",
-                "addresses": [server_address]
-            }
+            { "role": "WRITE", "addresses": [address.clone()] },
+            { "role": "READ", "addresses": [address.clone()] },
+            { "role": "ROUTE", "addresses": [address] }
         ]);
-
-        routing_table.insert("servers".to_string(), servers);
-
-        // Return SUCCESS with routing table
-        let mut metadata = HashMap::new();
-        metadata.insert("rt".to_string(), Value::Object(routing_table));
-
-        log::info!(
-            "✅ Returning routing table for database: {}",
-            metadata
-                .get("rt")
-                .and_then(|v| v.get("db"))
-                .and_then(|v| v.as_str())
-                .unwrap_or("unknown")
-        );
-
-        Ok(vec![BoltMessage::success(metadata)])
+        Ok((db_name, servers))
     }
 
     /// Execute a Cypher query and return result metadata
@@ -2146,28 +2466,40 @@ impl BoltHandler {
         // here keeps every downstream regex/position-based helper in this
         // function internally self-consistent.
         let stripped_query = open_cypher_parser::strip_comments(query);
-        let query: &str = &stripped_query;
+        // `CYPHER <option>=<value> ...` prefix options, as on the HTTP path
+        let allow_cartesian = self.config.allow_cartesian_product
+            || query_planner::cartesian_guard::has_allow_hint(&stripped_query);
+        let query_options = query_planner::QueryOptions::from_query(&stripped_query);
+        let query: &str = crate::server::query_cache::ReplanOption::strip_prefix(&stripped_query);
 
         // ============================================================
         // PHASE 1: Determine Schema (for id() transformation)
         // ============================================================
 
-        // Parse once to extract schema name
-        let effective_schema = match open_cypher_parser::parse_cypher_statement(query) {
-            Ok((_, stmt)) => match stmt {
-                CypherStatement::Query { query, .. } => {
-                    if let Some(use_clause) = query.use_clause {
-                        use_clause.database_name.to_string()
-                    } else {
-                        schema_name.as_deref().unwrap_or("default").to_string()
-                    }
-                }
-                CypherStatement::ProcedureCall(_) | CypherStatement::CopyTo(_) => {
-                    schema_name.as_deref().unwrap_or("default").to_string()
+        // Parse once to extract schema name (and the USE clause's points in
+        // time: FOR TIMESTAMP, AS OF SYSTEM TIME, VALID AT)
+        let (effective_schema, time_travel) =
+            match open_cypher_parser::parse_cypher_statement(query) {
+                Ok((_, stmt)) => {
+                    let use_graph = query_planner::target_graph(&stmt)
+                        .map_err(|e| BoltError::query_error(e.to_string()))?;
+                    let time_travel = query_planner::TimeTravel::of(&stmt)
+                        .map_err(|e| BoltError::query_error(e.to_string()))?;
+                    (
+                        graph_catalog::resolve_graph_name(use_graph, schema_name.as_deref()),
+                        time_travel,
+                    )
                 }
-            },
-            Err(_) => schema_name.as_deref().unwrap_or("default").to_string(),
-        };
+                Err(_) => (
+                    graph_catalog::resolve_graph_name(None, schema_name.as_deref()),
+                    query_planner::TimeTravel::default(),
+                ),
+            };
+
+        // A session on a tenant graph cannot `USE` another graph
+        let pinned = graph_catalog::pinned_graph(None, schema_name.as_deref());
+        graph_catalog::check_graph_isolation(pinned.as_deref(), &effective_schema)
+            .map_err(|message| BoltError::GraphForbidden { message })?;
 
         // Load the actual GraphSchema object for id() transformation.
         // Set schema name in task-local context so downstream code can use it.
@@ -2309,7 +2641,7 @@ impl BoltHandler {
             )
             .map_err(BoltError::query_error)?;
 
-            log::debug!("Bolt COPY TO SQL: {}", export_sql);
+            log::debug!("Bolt COPY TO SQL: {}", redact_sql(&export_sql));
 
             self.executor
                 .execute_text(&export_sql, "TabSeparated", role.as_deref())
@@ -2613,15 +2945,18 @@ impl BoltHandler {
                         )
                         .map_err(BoltError::query_error)?;
 
-                        log::info!("Bolt export SQL: {}", export_sql);
+                        log::info!("Bolt export SQL: {}", redact_sql(&export_sql));
 
                         // Execute
-                        self.executor
-                            .execute_text(&export_sql, "TabSeparated", role.as_deref())
-                            .await
-                            .map_err(|e| {
-                                BoltError::query_error(format!("Export execution failed: {}", e))
-                            })?;
+                        endpoint_routing::batch(self.executor.execute_text(
+                            &export_sql,
+                            "TabSeparated",
+                            role.as_deref(),
+                        ))
+                        .await
+                        .map_err(|e| {
+                            BoltError::query_error(format!("Export execution failed: {}", e))
+                        })?;
 
                         // Return status as a single record
                         vec![std::collections::HashMap::from([
@@ -2722,6 +3057,184 @@ impl BoltHandler {
                                 })
                             })
                             .collect::<Result<Vec<_>, _>>()?
+                    } else if crate::procedures::graph_quality::is_quality_procedure(&proc_name) {
+                        // ── Data quality checks: graph.quality() ──
+                        log::info!("Executing graph.quality via Bolt");
+
+                        let graph_schema =
+                            graph_catalog::get_graph_schema_by_name(&effective_schema)
+                                .await
+                                .map_err(BoltError::query_error)?;
+                        match crate::procedures::graph_quality::build_quality_sql(&graph_schema)
+                            .map_err(BoltError::query_error)?
+                        {
+                            Some(quality_sql) => endpoint_routing::batch(
+                                self.executor.execute_json(&quality_sql, role.as_deref()),
+                            )
+                            .await
+                            .map_err(|e| {
+                                BoltError::query_error(format!(
+                                    "graph.quality execution failed: {}",
+                                    e
+                                ))
+                            })?
+                            .iter()
+                            .map(crate::procedures::graph_quality::quality_record)
+                            .collect(),
+                            None => Vec::new(),
+                        }
+                    } else if crate::procedures::graph_page_rank::is_page_rank_procedure(&proc_name)
+                    {
+                        // ── PageRank: graph.pageRank(label, relTypes, config) ──
+                        log::info!("Executing graph.pageRank via Bolt");
+
+                        let call = {
+                            let (_, stmt) = open_cypher_parser::parse_cypher_statement(query)
+                                .map_err(|e| {
+                                    BoltError::query_error(format!(
+                                        "graph.pageRank parse error: {}",
+                                        e
+                                    ))
+                                })?;
+                            let expressions: Vec<_> = match &stmt {
+                                CypherStatement::ProcedureCall(pc) => pc.arguments.iter().collect(),
+                                CypherStatement::Query { query: q, .. } => q
+                                    .call_clause
+                                    .as_ref()
+                                    .map(|cc| cc.arguments.iter().map(|a| &a.value).collect())
+                                    .unwrap_or_default(),
+                                CypherStatement::CopyTo(_) => Vec::new(),
+                            };
+                            crate::procedures::graph_page_rank::parse_page_rank_call(&expressions)
+                                .map_err(BoltError::query_error)?
+                        };
+
+                        let graph_schema =
+                            graph_catalog::get_graph_schema_by_name(&effective_schema)
+                                .await
+                                .map_err(BoltError::query_error)?;
+                        let page_rank_sql =
+                            crate::procedures::graph_page_rank::build_page_rank_sql(
+                                &graph_schema,
+                                &call,
+                            )
+                            .map_err(BoltError::query_error)?;
+                        endpoint_routing::batch(
+                            self.executor.execute_json(&page_rank_sql, role.as_deref()),
+                        )
+                        .await
+                        .map_err(|e| {
+                            BoltError::query_error(format!(
+                                "graph.pageRank execution failed: {}",
+                                e
+                            ))
+                        })?
+                        .iter()
+                        .map(crate::procedures::graph_page_rank::page_rank_record)
+                        .collect()
+                    } else if let Some(kind) =
+                        crate::procedures::graph_components::component_kind(&proc_name)
+                    {
+                        // ── Connected components: graph.wcc / graph.scc ──
+                        let procedure = kind.procedure_name();
+                        log::info!("Executing {} via Bolt", procedure);
+
+                        let call = {
+                            let (_, stmt) = open_cypher_parser::parse_cypher_statement(query)
+                                .map_err(|e| {
+                                    BoltError::query_error(format!(
+                                        "{} parse error: {}",
+                                        procedure, e
+                                    ))
+                                })?;
+                            let expressions: Vec<_> = match &stmt {
+                                CypherStatement::ProcedureCall(pc) => pc.arguments.iter().collect(),
+                                CypherStatement::Query { query: q, .. } => q
+                                    .call_clause
+                                    .as_ref()
+                                    .map(|cc| cc.arguments.iter().map(|a| &a.value).collect())
+                                    .unwrap_or_default(),
+                                CypherStatement::CopyTo(_) => Vec::new(),
+                            };
+                            crate::procedures::graph_components::parse_components_call(
+                                kind,
+                                &expressions,
+                            )
+                            .map_err(BoltError::query_error)?
+                        };
+
+                        let graph_schema =
+                            graph_catalog::get_graph_schema_by_name(&effective_schema)
+                                .await
+                                .map_err(BoltError::query_error)?;
+                        let components_sql =
+                            crate::procedures::graph_components::build_components_sql(
+                                &graph_schema,
+                                &call,
+                            )
+                            .map_err(BoltError::query_error)?;
+                        endpoint_routing::batch(
+                            self.executor.execute_json(&components_sql, role.as_deref()),
+                        )
+                        .await
+                        .map_err(|e| {
+                            BoltError::query_error(format!("{} execution failed: {}", procedure, e))
+                        })?
+                        .iter()
+                        .map(crate::procedures::graph_components::component_record)
+                        .collect()
+                    } else if crate::procedures::graph_shortest_path::is_dijkstra_procedure(
+                        &proc_name,
+                    ) {
+                        // ── Weighted shortest path: graph.shortestPath.dijkstra ──
+                        log::info!("Executing graph.shortestPath.dijkstra via Bolt");
+
+                        let call = {
+                            let (_, stmt) = open_cypher_parser::parse_cypher_statement(query)
+                                .map_err(|e| {
+                                    BoltError::query_error(format!(
+                                        "graph.shortestPath.dijkstra parse error: {}",
+                                        e
+                                    ))
+                                })?;
+                            let expressions: Vec<_> = match &stmt {
+                                CypherStatement::ProcedureCall(pc) => pc.arguments.iter().collect(),
+                                CypherStatement::Query { query: q, .. } => q
+                                    .call_clause
+                                    .as_ref()
+                                    .map(|cc| cc.arguments.iter().map(|a| &a.value).collect())
+                                    .unwrap_or_default(),
+                                CypherStatement::CopyTo(_) => Vec::new(),
+                            };
+                            crate::procedures::graph_shortest_path::parse_dijkstra_call(
+                                &expressions,
+                            )
+                            .map_err(BoltError::query_error)?
+                        };
+
+                        let graph_schema =
+                            graph_catalog::get_graph_schema_by_name(&effective_schema)
+                                .await
+                                .map_err(BoltError::query_error)?;
+                        let dijkstra_sql =
+                            crate::procedures::graph_shortest_path::build_dijkstra_sql(
+                                &graph_schema,
+                                &call,
+                            )
+                            .map_err(BoltError::query_error)?;
+                        endpoint_routing::batch(
+                            self.executor.execute_json(&dijkstra_sql, role.as_deref()),
+                        )
+                        .await
+                        .map_err(|e| {
+                            BoltError::query_error(format!(
+                                "graph.shortestPath.dijkstra execution failed: {}",
+                                e
+                            ))
+                        })?
+                        .iter()
+                        .map(crate::procedures::graph_shortest_path::dijkstra_record)
+                        .collect()
                     } else if crate::procedures::fulltext_search::is_fulltext_search_procedure(
                         &proc_name,
                     ) {
@@ -2992,6 +3505,32 @@ impl BoltHandler {
             }
         };
 
+        // `CYPHER include_deleted=true` (soft-deleted rows stay visible) and
+        // `CYPHER collation=...`
+        let graph_schema = match query_options
+            .apply(&graph_schema)
+            .map_err(|e| BoltError::query_error(e.to_string()))?
+        {
+            Some(view) => {
+                crate::server::query_context::set_current_schema(std::sync::Arc::new(view.clone()));
+                view
+            }
+            None => graph_schema,
+        };
+
+        // USE ... FOR TIMESTAMP / AS OF SYSTEM TIME / VALID AT: plan (and
+        // render) against that snapshot and those periods of every table
+        let graph_schema = match time_travel
+            .apply(&graph_schema)
+            .map_err(|e| BoltError::query_error(e.to_string()))?
+        {
+            Some(view) => {
+                crate::server::query_context::set_current_schema(std::sync::Arc::new(view.clone()));
+                view
+            }
+            None => graph_schema,
+        };
+
         let id_mapper_snapshot = {
             let context = lock_context!(self.context);
             context.id_mapper.clone()
@@ -3038,6 +3577,30 @@ impl BoltHandler {
 
         // transformed_for_planning is now dropped
 
+        // Per-label max_unfiltered_rows guard for whole-label exploration scans
+        let trusted = role
+            .as_deref()
+            .is_some_and(|r| self.config.trusted_roles.iter().any(|t| t == r));
+        let (logical_plan, scan_guard) = query_planner::scan_guard::enforce_unfiltered_scan_limit(
+            logical_plan,
+            &graph_schema,
+            trusted,
+        )
+        .map_err(|e| BoltError::query_error(format!("Query planning failed: {}", e)))?;
+        query_planner::cartesian_guard::enforce_cartesian_policy(&logical_plan, allow_cartesian)
+            .map_err(|e| BoltError::query_error(format!("Query planning failed: {}", e)))?;
+        let unknown_labels = plan_ctx.unknown_labels().cloned();
+
+        // Deterministic row order so PULL batch boundaries survive retries
+        let logical_plan = if self.config.stable_order {
+            let (plan, keys) =
+                query_planner::stable_order::inject_stable_order(logical_plan, &graph_schema);
+            self.stable_order_keys = keys;
+            plan
+        } else {
+            logical_plan
+        };
+
         // Extract return metadata for result transformation
         let return_metadata = match extract_return_metadata(&logical_plan, &plan_ctx) {
             Ok(metadata) => metadata,
@@ -3072,6 +3635,13 @@ impl BoltHandler {
         let max_cte_depth = 1000; // Use default from config
         let ch_sql = clickhouse_query_generator::generate_sql(render_plan, max_cte_depth);
 
+        // Check parameters against the declared types of the properties they
+        // are compared with
+        let mut parameters = parameters;
+        let expected = query_planner::parameter_types::infer_parameter_types(query, &graph_schema);
+        parameter_substitution::check_parameter_types(&mut parameters, &expected)
+            .map_err(|e| BoltError::query_error(e.to_string()))?;
+
         // Substitute parameters in SQL (for non-id() parameters like $name, $age, etc.)
         // Note: id() parameters were already handled in Cypher query substitution (line 741)
         let final_sql = match parameter_substitution::substitute_parameters(&ch_sql, &parameters) {
@@ -3084,12 +3654,19 @@ impl BoltHandler {
             }
         };
 
-        log::info!("📊 Executing SQL: {}", final_sql);
+        log::info!("📊 Executing SQL: {}", redact_sql(&final_sql));
 
-        // Execute the query using the backend-agnostic executor
+        // Execute the query using the backend-agnostic executor, in this
+        // connection's backend session so temporary tables persist across RUNs
         let rows_values = self
             .executor
-            .execute_json(&final_sql, role.as_deref())
+            .execute_json_in_session(
+                &self.session_key,
+                &final_sql,
+                &[],
+                &self.query_settings,
+                role.as_deref(),
+            )
             .await
             .map_err(|e| BoltError::query_error(format!("Query execution failed: {}", e)))?;
 
@@ -3204,6 +3781,38 @@ impl BoltHandler {
         );
         metadata.insert("t_first".to_string(), Value::Number(0.into()));
         metadata.insert("qid".to_string(), Value::Number(1.into()));
+        let mut notifications = Vec::new();
+        if let Some(notification) = scan_guard.notification() {
+            notifications.push(serde_json::json!({
+                "code": "ClickGraph.Statement.UnfilteredScanLimited",
+                "title": "Unfiltered scan was limited",
+                "description": notification,
+                "severity": "WARNING",
+            }));
+        }
+        if let Some(unknown) = &unknown_labels {
+            // Same code Neo4j uses for a MATCH on a label that has no nodes
+            let (code, title) = if unknown.labels.is_empty() {
+                (
+                    "Neo.ClientNotification.Statement.UnknownRelationshipTypeWarning",
+                    "The provided relationship type is not in the database.",
+                )
+            } else {
+                (
+                    "Neo.ClientNotification.Statement.UnknownLabelWarning",
+                    "The provided label is not in the database.",
+                )
+            };
+            notifications.push(serde_json::json!({
+                "code": code,
+                "title": title,
+                "description": unknown.description(),
+                "severity": "WARNING",
+            }));
+        }
+        if !notifications.is_empty() {
+            metadata.insert("notifications".to_string(), Value::Array(notifications));
+        }
 
         Ok(metadata)
     }
@@ -3476,6 +4085,57 @@ mod tests {
         assert_eq!(responses[0].signature, signatures::SUCCESS);
     }
 
+    #[tokio::test]
+    async fn test_batched_pull_spills_and_streams_all_rows() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = BoltConfig {
+            result_spill: Some(super::super::result_spill::SpillConfig {
+                dir: dir.path().to_path_buf(),
+                memory_rows: 2,
+                max_bytes: 1 << 20,
+            }),
+            ..BoltConfig::default()
+        };
+        let context = Arc::new(Mutex::new(BoltContext::new()));
+        let mut handler = BoltHandler::new(context, Arc::new(config), Arc::new(StubExecutor));
+        handler
+            .context
+            .lock()
+            .unwrap()
+            .set_state(ConnectionState::Streaming);
+        handler.cached_results = Some(
+            (0..7)
+                .map(|i| vec![BoltValue::Json(Value::from(i))])
+                .collect(),
+        );
+
+        let mut seen = Vec::new();
+        loop {
+            let responses = handler
+                .handle_message(BoltMessage::pull(3, None))
+                .await
+                .unwrap();
+            let (summary, records) = responses.split_last().unwrap();
+            assert!(records.len() <= 3);
+            for record in records {
+                assert_eq!(record.signature, signatures::RECORD);
+                seen.push(record.fields.clone());
+            }
+            assert_eq!(summary.signature, signatures::SUCCESS);
+            if records.len() == 3 && handler.result_buffer.is_some() {
+                assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+                continue;
+            }
+            break;
+        }
+        assert_eq!(seen.len(), 7);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+        assert_eq!(
+            handler.context.lock().unwrap().state,
+            ConnectionState::Ready
+        );
+    }
+
     #[tokio::test]
     async fn test_goodbye_message_handling() {
         let mut handler = create_test_handler();
@@ -3611,4 +4271,187 @@ mod tests {
             "expected a parse-stage error for genuine trailing garbage, got: {msg}"
         );
     }
+
+    #[test]
+    fn routing_procedure_call_matcher() {
+        let params = HashMap::from([
+            (
+                "context".to_string(),
+                serde_json::json!({"address": "graph.example.com:7687"}),
+            ),
+            ("database".to_string(), Value::String("social".to_string())),
+        ]);
+        let call = |query: &str| routing_procedure_call(query, &params);
+        assert_eq!(
+            call("CALL dbms.routing.getRoutingTable($context, $database)"),
+            Some(Ok((
+                Some("graph.example.com:7687".to_string()),
+                Some("social".to_string())
+            )))
+        );
+        // 4.1 drivers send no database; literals and null are accepted too
+        assert_eq!(
+            call("call DBMS.ROUTING.GETROUTINGTABLE($context);"),
+            Some(Ok((Some("graph.example.com:7687".to_string()), None)))
+        );
+        assert_eq!(
+            call("CALL dbms.routing.getRoutingTable({}, 'social')"),
+            Some(Ok((None, Some("social".to_string()))))
+        );
+        assert_eq!(
+            call("CALL dbms.routing.getRoutingTable({}, null)"),
+            Some(Ok((None, None)))
+        );
+        assert_eq!(call("CALL db.labels()"), None);
+    }
+
+    #[test]
+    fn routing_procedure_call_literal_maps() {
+        let params = HashMap::new();
+        let call = |query: &str| routing_procedure_call(query, &params);
+        assert_eq!(
+            call("CALL dbms.routing.getRoutingTable({address: 'h:7687'}, 'db')"),
+            Some(Ok((Some("h:7687".to_string()), Some("db".to_string()))))
+        );
+        // Commas inside the map don't split the arguments
+        assert_eq!(
+            call("CALL dbms.routing.getRoutingTable({address: 'a:7687', region: 'x'}, 'db')"),
+            Some(Ok((Some("a:7687".to_string()), Some("db".to_string()))))
+        );
+        for query in [
+            "CALL dbms.routing.getRoutingTable('h:7687', 'db')",
+            "CALL dbms.routing.getRoutingTable({address: 7687}, 'db')",
+            "CALL dbms.routing.getRoutingTable({}, ['db'])",
+            "CALL dbms.routing.getRoutingTable({}, 'db', 'extra')",
+        ] {
+            assert!(matches!(call(query), Some(Err(_))), "{query}");
+        }
+    }
+
+    async fn register_routing_graph() {
+        let schemas = crate::server::GLOBAL_SCHEMAS
+            .get_or_init(|| async { tokio::sync::RwLock::new(HashMap::new()) })
+            .await;
+        schemas.write().await.insert(
+            "routing_graph".to_string(),
+            crate::graph_catalog::graph_schema::GraphSchema::build(
+                1,
+                "routing_graph".to_string(),
+                HashMap::new(),
+                HashMap::new(),
+            ),
+        );
+    }
+
+    fn route_message(database: BoltValue) -> BoltMessage {
+        BoltMessage::new(
+            signatures::ROUTE,
+            vec![
+                BoltValue::Json(serde_json::json!({"address": "graph.example.com:7687"})),
+                BoltValue::Json(serde_json::json!([])),
+                database,
+            ],
+        )
+    }
+
+    #[tokio::test]
+    #[serial_test::serial(global_schema)]
+    async fn route_accepts_bolt_4_3_and_4_4_database_forms() {
+        register_routing_graph().await;
+        let mut handler = create_test_handler();
+
+        for database in [
+            BoltValue::Json(Value::String("routing_graph".to_string())),
+            BoltValue::Json(serde_json::json!({"db": "routing_graph"})),
+        ] {
+            let responses = handler
+                .handle_message(route_message(database))
+                .await
+                .unwrap();
+            assert_eq!(responses[0].signature, signatures::SUCCESS);
+            let BoltValue::Json(metadata) = &responses[0].fields[0] else {
+                panic!("SUCCESS metadata must be a map");
+            };
+            let rt = &metadata["rt"];
+            assert_eq!(rt["db"], "routing_graph");
+            assert_eq!(rt["ttl"], ROUTING_TABLE_TTL_SECS);
+            let roles: Vec<&str> = rt["servers"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|s| s["role"].as_str().unwrap())
+                .collect();
+            assert_eq!(roles, vec!["WRITE", "READ", "ROUTE"]);
+        }
+
+        let responses = handler
+            .handle_message(route_message(BoltValue::Json(Value::String(
+                "no_such_graph".to_string(),
+            ))))
+            .await
+            .unwrap();
+        assert_eq!(responses[0].signature, signatures::FAILURE);
+    }
+
+    #[tokio::test]
+    #[serial_test::serial(global_schema)]
+    async fn run_get_routing_table_returns_one_row() {
+        register_routing_graph().await;
+        let mut handler = create_test_handler();
+        {
+            let mut context = handler.context.lock().unwrap();
+            context.set_state(ConnectionState::Ready);
+        }
+
+        let run = BoltMessage::run(
+            "CALL dbms.routing.getRoutingTable($context, $database)".to_string(),
+            HashMap::from([
+                ("context".to_string(), serde_json::json!({})),
+                (
+                    "database".to_string(),
+                    Value::String("routing_graph".to_string()),
+                ),
+            ]),
+            None,
+        );
+        let responses = handler.handle_message(run).await.unwrap();
+        assert_eq!(responses[0].signature, signatures::SUCCESS);
+        let rows = handler.cached_results.as_ref().expect("routing row cached");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].len(), 2);
+    }
+
+    #[tokio::test]
+    async fn param_commands_set_connection_parameters() {
+        let mut handler = create_test_handler();
+        {
+            let mut context = handler.context.lock().unwrap();
+            context.set_state(ConnectionState::Ready);
+        }
+        let run = |query: &str| BoltMessage::run(query.to_string(), HashMap::new(), None);
+
+        let responses = handler
+            .handle_message(run(":param country => 'US'"))
+            .await
+            .unwrap();
+        assert_eq!(responses[0].signature, signatures::SUCCESS);
+        handler.handle_message(run(":param {k: 10}")).await.unwrap();
+        let rows = handler.cached_results.as_ref().expect("parameter rows");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            handler.context.lock().unwrap().parameters["k"],
+            serde_json::json!(10)
+        );
+
+        let responses = handler.handle_message(run(":param k")).await.unwrap();
+        assert_eq!(responses[0].signature, signatures::FAILURE);
+
+        handler
+            .context
+            .lock()
+            .unwrap()
+            .set_state(ConnectionState::Ready);
+        handler.handle_message(run(":params clear")).await.unwrap();
+        assert!(handler.context.lock().unwrap().parameters.is_empty());
+    }
 }
@@ -25,7 +25,6 @@
 
 use lazy_static::lazy_static;
 use std::collections::HashMap;
-use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 
@@ -218,8 +217,9 @@ impl IdMapper {
         // portion stays the unadorned id (e.g., `"User:1-"` → label `User`,
         // id_value `1`, not `"1-"`). Without this strip, a numeric id like `1`
         // would fail integer parse and fall back to a hash, breaking the
-        // round-trip with id-rewriter callers.
-        let element_id = element_id.trim_end_matches('-');
+        // round-trip with id-rewriter callers. Only one `-` is stripped so
+        // id values that themselves end in `-` keep their own dashes.
+        let element_id = element_id.strip_suffix('-').unwrap_or(element_id);
 
         // Parse "Label:id_value" format
         let (label, id_part) = if let Some(colon_pos) = element_id.find(':') {
@@ -268,14 +268,19 @@ impl IdMapper {
         Self::hash_string(id_part) & id_mask
     }
 
-    /// Hash a string to a positive 56-bit value
+    /// Hash a string to a positive 47-bit value
+    ///
+    /// Uses FNV-1a rather than `DefaultHasher`, whose algorithm is not
+    /// guaranteed across Rust releases, so string and composite ids map to the
+    /// same integer id across server builds and restarts.
     fn hash_string(s: &str) -> i64 {
-        use std::collections::hash_map::DefaultHasher;
-        let mut hasher = DefaultHasher::new();
-        s.hash(&mut hasher);
-        let hash = hasher.finish();
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+        let hash = s.bytes().fold(FNV_OFFSET_BASIS, |acc, b| {
+            (acc ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+        });
         // Mask to 47 bits for JS-safe encoding and ensure positive
-        ((hash & 0x7FFFFFFFFFFF) as i64).abs().max(1)
+        ((hash & 0x7FFFFFFFFFFF) as i64).max(1)
     }
 
     /// Lookup element_id by integer ID (reverse lookup)
@@ -607,6 +612,26 @@ mod tests {
         assert_ne!(post2, comment3);
     }
 
+    #[test]
+    fn test_composite_ids_stable_across_mappers() {
+        let mut mapper_a = IdMapper::new();
+        let mut mapper_b = IdMapper::new();
+
+        let acct_1 = mapper_a.get_or_assign("Account:acme|1-");
+        let acct_2 = mapper_a.get_or_assign("Account:acme|2-");
+
+        // Same composite key → same id, independent of the mapper instance
+        assert_eq!(acct_1, mapper_b.get_or_assign("Account:acme|1-"));
+        // Differing trailing component → different id
+        assert_ne!(acct_1, acct_2);
+
+        // FNV-1a is fixed, so the hashed id_value is stable across builds
+        assert_eq!(
+            IdMapper::hash_string("a"),
+            0xaf63dc4c8601ec8c_u64 as i64 & 0x7FFFFFFFFFFF
+        );
+    }
+
     #[test]
     fn test_session_cache_chaining() {
         // Create mapper 1, assign some IDs
@@ -201,7 +201,11 @@ impl BoltMessage {
     pub fn failure(code: String, message: String) -> Self {
         let metadata = HashMap::from([
             ("code".to_string(), Value::String(code)),
-            ("message".to_string(), Value::String(message)),
+            (
+                "message".to_string(),
+                // Messages often quote the failing query, literals included
+                Value::String(crate::utils::redaction::redact(&message).into_owned()),
+            ),
         ]);
 
         BoltMessage::new(
@@ -416,6 +420,56 @@ impl BoltMessage {
         None
     }
 
+    /// Extract `tx_timeout` (milliseconds) from BEGIN extra metadata, or from
+    /// RUN extra metadata for an auto-commit transaction
+    pub fn extract_tx_timeout(&self) -> Option<u64> {
+        let extra = match self.signature {
+            signatures::BEGIN => self.fields.first(),
+            signatures::RUN => self.fields.get(2),
+            _ => None,
+        };
+        if let Some(BoltValue::Json(Value::Object(extra_map))) = extra {
+            return extra_map.get("tx_timeout").and_then(Value::as_u64);
+        }
+        None
+    }
+
+    /// Extract the ClickHouse `settings` map of `tx_metadata` from BEGIN
+    /// extra metadata, or from RUN extra metadata for an auto-commit
+    /// transaction
+    pub fn extract_tx_settings(&self) -> Option<serde_json::Map<String, Value>> {
+        let extra = match self.signature {
+            signatures::BEGIN => self.fields.first(),
+            signatures::RUN => self.fields.get(2),
+            _ => None,
+        };
+        if let Some(BoltValue::Json(Value::Object(extra_map))) = extra {
+            if let Some(Value::Object(settings)) = extra_map
+                .get("tx_metadata")
+                .and_then(|metadata| metadata.get("settings"))
+            {
+                return Some(settings.clone());
+            }
+        }
+        None
+    }
+
+    /// Extract the batch size `n` from PULL / DISCARD extra metadata
+    /// PULL message: PULL {extra::Dictionary(n::Integer, qid::Integer)}
+    /// Returns -1 ("all remaining records") when absent.
+    pub fn extract_pull_n(&self) -> i64 {
+        if matches!(self.signature, signatures::PULL | signatures::DISCARD)
+            && !self.fields.is_empty()
+        {
+            if let BoltValue::Json(Value::Object(extra_map)) = &self.fields[0] {
+                if let Some(n) = extra_map.get("n").and_then(Value::as_i64) {
+                    return n;
+                }
+            }
+        }
+        -1
+    }
+
     /// Extract authentication token from LOGON message (Bolt 5.1+)
     /// LOGON message has a single field: auth::Dictionary(scheme::String, ...)
     pub fn extract_logon_auth(&self) -> Option<HashMap<String, Value>> {
@@ -536,6 +590,50 @@ mod tests {
         assert!(!hello.is_response());
     }
 
+    #[test]
+    fn test_pull_batch_size() {
+        let mut extra = serde_json::Map::new();
+        extra.insert("n".to_string(), Value::from(1000));
+        let pull = BoltMessage::new(
+            signatures::PULL,
+            vec![BoltValue::Json(Value::Object(extra))],
+        );
+        assert_eq!(pull.extract_pull_n(), 1000);
+
+        let pull_all = BoltMessage::new(signatures::PULL, vec![]);
+        assert_eq!(pull_all.extract_pull_n(), -1);
+    }
+
+    #[test]
+    fn test_tx_timeout() {
+        let timeout = HashMap::from([("tx_timeout".to_string(), Value::from(2500))]);
+        let begin = BoltMessage::begin(Some(timeout.clone()));
+        assert_eq!(begin.extract_tx_timeout(), Some(2500));
+
+        let run = BoltMessage::run("RETURN 1".to_string(), HashMap::new(), Some(timeout));
+        assert_eq!(run.extract_tx_timeout(), Some(2500));
+
+        let run = BoltMessage::run("RETURN 1".to_string(), HashMap::new(), None);
+        assert_eq!(run.extract_tx_timeout(), None);
+    }
+
+    #[test]
+    fn test_tx_settings() {
+        let metadata = HashMap::from([(
+            "tx_metadata".to_string(),
+            serde_json::json!({"settings": {"join_algorithm": "hash"}}),
+        )]);
+        let begin = BoltMessage::begin(Some(metadata.clone()));
+        let settings = begin.extract_tx_settings().unwrap();
+        assert_eq!(settings["join_algorithm"], "hash");
+
+        let run = BoltMessage::run("RETURN 1".to_string(), HashMap::new(), Some(metadata));
+        assert!(run.extract_tx_settings().is_some());
+
+        let run = BoltMessage::run("RETURN 1".to_string(), HashMap::new(), None);
+        assert_eq!(run.extract_tx_settings(), None);
+    }
+
     #[test]
     fn test_failure_message() {
         let failure = BoltMessage::failure(
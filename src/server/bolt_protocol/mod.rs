@@ -11,7 +11,7 @@
 use crate::executor::QueryExecutor;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
 
 pub mod auth;
 pub mod connection;
@@ -19,6 +19,8 @@ pub mod errors;
 pub mod graph_objects;
 pub mod handler;
 pub mod messages;
+pub mod prefixed;
+pub mod result_spill;
 pub mod result_transformer;
 pub mod websocket;
 
@@ -93,10 +95,16 @@ pub struct BoltContext {
     pub metadata: HashMap<String, String>,
     /// Current transaction ID (if in transaction)
     pub tx_id: Option<String>,
+    /// `tx_timeout` of the current transaction in milliseconds
+    pub tx_timeout: Option<u64>,
+    /// ClickHouse settings of the current transaction (`tx_metadata`)
+    pub tx_settings: Option<serde_json::Map<String, serde_json::Value>>,
     /// Selected graph schema/database name (defaults to "default")
     pub schema_name: Option<String>,
     /// Session-scoped tenant ID for multi-tenancy within a schema
     pub tenant_id: Option<String>,
+    /// Session parameters set with `:param`, seen by every later RUN
+    pub parameters: crate::server::session_params::SessionParameters,
     /// Session-scoped ID mapper for Neo4j Browser compatibility
     /// Maps element_ids to monotonic integer IDs for id() function
     pub id_mapper: IdMapper,
@@ -110,8 +118,11 @@ impl Default for BoltContext {
             user: None,
             metadata: HashMap::new(),
             tx_id: None,
+            tx_timeout: None,
+            tx_settings: None,
             schema_name: None,
             tenant_id: None,
+            parameters: Default::default(),
             id_mapper: IdMapper::new(),
         }
     }
@@ -171,6 +182,27 @@ pub struct BoltConfig {
     pub host: String,
     /// Bolt server port (for ROUTE response)
     pub port: u16,
+    /// Roles (from RUN metadata) trusted to bypass `max_unfiltered_rows` guards
+    pub trusted_roles: Vec<String>,
+    /// ClickHouse settings a transaction's `tx_metadata` may set
+    pub allowed_settings: Vec<String>,
+    /// Inject `ORDER BY <node id>` into unordered queries so PULL batch
+    /// boundaries are stable across retries
+    pub stable_order: bool,
+    /// `host:port` advertised in ROUTE responses, overriding `host`/`port`
+    pub advertised_address: Option<String>,
+    /// Allow disconnected patterns (cartesian products) without the
+    /// `CYPHER cartesian=allow` prefix
+    pub allow_cartesian_product: bool,
+    /// Spill rows of results PULLed in batches to disk; `None` keeps them
+    /// all in memory
+    pub result_spill: Option<result_spill::SpillConfig>,
+    /// Query timeout in seconds, capping the client's `tx_timeout`
+    /// (`query_timeout_secs`; 0 = none)
+    pub query_timeout_secs: u64,
+    /// Retry interval of lazy schema elements that failed their check
+    /// (`lazy_schema_retry_secs`)
+    pub lazy_schema_retry_secs: u64,
 }
 
 impl Default for BoltConfig {
@@ -183,10 +215,40 @@ impl Default for BoltConfig {
             server_agent: format!("ClickGraph/{}", env!("CARGO_PKG_VERSION")),
             host: "localhost".to_string(),
             port: 7687,
+            trusted_roles: Vec::new(),
+            allowed_settings: Vec::new(),
+            stable_order: false,
+            advertised_address: None,
+            allow_cartesian_product: false,
+            result_spill: None,
+            query_timeout_secs: 0,
+            lazy_schema_retry_secs: 30,
         }
     }
 }
 
+impl BoltConfig {
+    /// Address placed in routing tables for `neo4j://` drivers.
+    ///
+    /// Precedence: configured `advertised_address`, then the address the
+    /// driver dialled (the `address` key of the ROUTE routing context), then
+    /// `host:port`. A wildcard bind host (`0.0.0.0`, `::`) is not dialable, so
+    /// it is advertised as `localhost`.
+    pub fn routing_address(&self, client_address: Option<&str>) -> String {
+        if let Some(addr) = self.advertised_address.as_deref().filter(|a| !a.is_empty()) {
+            return addr.to_string();
+        }
+        if let Some(addr) = client_address.filter(|a| !a.is_empty()) {
+            return addr.to_string();
+        }
+        let host = match self.host.as_str() {
+            "0.0.0.0" | "::" | "[::]" | "" => "localhost",
+            h => h,
+        };
+        format!("{}:{}", host, self.port)
+    }
+}
+
 /// Main Bolt protocol server
 #[derive(Clone)]
 pub struct BoltServer {
@@ -211,6 +273,9 @@ impl BoltServer {
         S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
     {
         let context = Arc::new(std::sync::Mutex::new(BoltContext::new()));
+        let _session = crate::server::GLOBAL_SERVER_METRICS
+            .get()
+            .map(|reg| reg.bolt_session_guard());
 
         let connection = connection::BoltConnection::new(
             stream,
@@ -223,6 +288,69 @@ impl BoltServer {
         Ok(())
     }
 
+    /// Serve one accepted connection on the Bolt port: raw Bolt, or a
+    /// WebSocket upgrade from Neo4j Browser, told apart by the first four
+    /// bytes. Other HTTP requests (browser probes, CORS preflights) are
+    /// dropped. `stream` is the TCP stream, or the stream inside TLS.
+    pub async fn serve<S>(&self, mut stream: S, addr_str: String)
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let mut head = [0u8; 4];
+        if let Err(e) = stream.read_exact(&mut head).await {
+            if e.kind() == std::io::ErrorKind::UnexpectedEof {
+                log::warn!(
+                    "Connection from {} closed before protocol detection",
+                    addr_str
+                );
+            } else {
+                log::error!("Failed to read connection from {}: {}", addr_str, e);
+            }
+            return;
+        }
+        let stream = prefixed::PrefixedStream::new(head.to_vec(), stream);
+
+        // Check if this is an HTTP request (WebSocket upgrade or probe)
+        let is_http = head.starts_with(b"GET ")
+            || head.starts_with(b"POST")
+            || head.starts_with(b"OPTI") // OPTIONS preflight
+            || head.starts_with(b"HEAD")
+            || head.starts_with(b"PUT ")
+            || head.starts_with(b"DELE"); // DELETE
+        if !is_http {
+            log::info!("Detected TCP Bolt connection from {}", addr_str);
+            match self.handle_connection(stream, addr_str.clone()).await {
+                Ok(_) => log::debug!("TCP Bolt connection closed successfully"),
+                Err(e) => log::error!("TCP Bolt connection error from {}: {:?}", addr_str, e),
+            }
+            return;
+        }
+
+        // Only GET may carry a WebSocket upgrade; other HTTP methods are
+        // browser probes — drop them silently.
+        if !(head.starts_with(b"GET ") || head.starts_with(b"POST")) {
+            log::debug!(
+                "HTTP {} probe on Bolt port from {} — ignored",
+                std::str::from_utf8(&head).unwrap_or("?"),
+                addr_str
+            );
+            return;
+        }
+        log::debug!("Detected HTTP/WebSocket probe from {}", addr_str);
+        match websocket::WebSocketBoltAdapter::new(stream).await {
+            Ok(ws_adapter) => match self.handle_connection(ws_adapter, addr_str.clone()).await {
+                Ok(_) => log::debug!("WebSocket Bolt connection closed successfully"),
+                Err(e) => log::debug!(
+                    "WebSocket Bolt connection closed from {}: {:?}",
+                    addr_str,
+                    e
+                ),
+            },
+            // Browser probe without WS upgrade — expected, ignore
+            Err(_) => log::debug!("HTTP probe (no WS upgrade) from {} — ignored", addr_str),
+        }
+    }
+
     /// Get server configuration
     pub fn config(&self) -> &BoltConfig {
         &self.config
@@ -352,6 +480,32 @@ mod tests {
         assert_eq!(negotiated, Some(BOLT_VERSION_4_4));
     }
 
+    #[test]
+    fn test_routing_address_precedence() {
+        let mut config = BoltConfig {
+            host: "0.0.0.0".to_string(),
+            ..BoltConfig::default()
+        };
+        assert_eq!(config.routing_address(None), "localhost:7687");
+        assert_eq!(
+            config.routing_address(Some("graph.internal:7687")),
+            "graph.internal:7687"
+        );
+
+        config.advertised_address = Some("graph.example.com:443".to_string());
+        assert_eq!(
+            config.routing_address(Some("graph.internal:7687")),
+            "graph.example.com:443"
+        );
+
+        let config = BoltConfig {
+            host: "10.0.0.5".to_string(),
+            port: 7688,
+            ..BoltConfig::default()
+        };
+        assert_eq!(config.routing_address(None), "10.0.0.5:7688");
+    }
+
     #[test]
     fn test_version_string_formatting() {
         assert_eq!(utils::version_to_string(BOLT_VERSION_4_4), "4.4");
@@ -0,0 +1,304 @@
+//! Disk spill for Bolt results consumed in batches.
+//!
+//! A query's rows are buffered on the connection until the client PULLs
+//! them. When a driver pages through a large result slowly (`PULL {n: 1000}`
+//! at a time), holding every remaining row in memory for the lifetime of the
+//! stream is wasteful. With spilling configured, only the first
+//! `memory_rows` rows stay in memory; the rest are written to a temporary
+//! file under `dir` and read back as later PULLs ask for them.
+//!
+//! The file is bounded by `max_bytes` — a result that would exceed it fails
+//! instead of filling the disk — and is removed when the result is fully
+//! consumed, discarded, reset or the connection closes.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::messages::BoltValue;
+
+const TAG_JSON: u8 = 0;
+const TAG_PACKSTREAM: u8 = 1;
+
+/// Where and how much to spill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpillConfig {
+    /// Directory for spill files (created if missing)
+    pub dir: PathBuf,
+    /// Rows kept in memory before the rest go to disk
+    pub memory_rows: usize,
+    /// Maximum size of one result's spill file (bytes)
+    pub max_bytes: u64,
+}
+
+#[derive(Debug, Error)]
+pub enum SpillError {
+    #[error(
+        "Result exceeds the Bolt spill limit of {max_bytes} bytes; add LIMIT or narrow the query"
+    )]
+    LimitExceeded { max_bytes: u64 },
+
+    #[error("Bolt result spill I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// Rows of one result waiting to be PULLed: a memory prefix, then an
+/// optional spill file holding the remainder in order.
+#[derive(Debug)]
+pub struct ResultBuffer {
+    memory: VecDeque<Vec<BoltValue>>,
+    spilled: Option<SpillFile>,
+}
+
+impl ResultBuffer {
+    /// Buffer `rows`, spilling everything past `memory_rows` when `spill` is set.
+    pub fn new(
+        mut rows: Vec<Vec<BoltValue>>,
+        spill: Option<&SpillConfig>,
+    ) -> Result<Self, SpillError> {
+        let spilled = match spill {
+            Some(config) if rows.len() > config.memory_rows => {
+                let tail = rows.split_off(config.memory_rows);
+                Some(SpillFile::write(&config.dir, tail, config.max_bytes)?)
+            }
+            _ => None,
+        };
+        Ok(ResultBuffer {
+            memory: rows.into(),
+            spilled,
+        })
+    }
+
+    /// Remove and return up to `n` rows (all remaining rows when `None`).
+    pub fn take(&mut self, n: Option<usize>) -> Result<Vec<Vec<BoltValue>>, SpillError> {
+        let n = n.unwrap_or(usize::MAX);
+        let from_memory = n.min(self.memory.len());
+        let mut rows: Vec<_> = self.memory.drain(..from_memory).collect();
+
+        if let Some(file) = self.spilled.as_mut() {
+            while rows.len() < n && file.remaining > 0 {
+                rows.push(file.read_row()?);
+            }
+            if file.remaining == 0 {
+                self.spilled = None;
+            }
+        }
+        Ok(rows)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.memory.is_empty() && self.spilled.is_none()
+    }
+
+    /// Whether part of the result lives on disk.
+    pub fn is_spilled(&self) -> bool {
+        self.spilled.is_some()
+    }
+}
+
+/// Spill file of encoded rows, deleted on drop.
+#[derive(Debug)]
+struct SpillFile {
+    path: PathBuf,
+    reader: BufReader<File>,
+    remaining: usize,
+}
+
+impl SpillFile {
+    fn write(dir: &Path, rows: Vec<Vec<BoltValue>>, max_bytes: u64) -> Result<Self, SpillError> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("clickgraph-bolt-{}.spill", Uuid::new_v4()));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        // Constructed before writing so the file is removed on any error below
+        let mut spill = SpillFile {
+            path,
+            reader: BufReader::new(file),
+            remaining: 0,
+        };
+
+        {
+            let mut writer = BufWriter::new(spill.reader.get_mut());
+            let mut written = 0u64;
+            let mut buf = Vec::new();
+            for row in &rows {
+                buf.clear();
+                encode_row(row, &mut buf)?;
+                written += buf.len() as u64;
+                if written > max_bytes {
+                    return Err(SpillError::LimitExceeded { max_bytes });
+                }
+                writer.write_all(&buf)?;
+            }
+            writer.flush()?;
+        }
+        spill.reader.get_mut().seek(SeekFrom::Start(0))?;
+        spill.remaining = rows.len();
+        log::debug!(
+            "Spilled {} Bolt result rows to {}",
+            spill.remaining,
+            spill.path.display()
+        );
+        Ok(spill)
+    }
+
+    fn read_row(&mut self) -> Result<Vec<BoltValue>, SpillError> {
+        let row = decode_row(&mut self.reader)?;
+        self.remaining -= 1;
+        Ok(row)
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.path) {
+            log::warn!(
+                "Failed to remove Bolt spill file {}: {}",
+                self.path.display(),
+                e
+            );
+        }
+    }
+}
+
+/// `u32` field count, then per field a tag byte, `u32` length and payload
+/// (little-endian).
+fn encode_row(row: &[BoltValue], out: &mut Vec<u8>) -> io::Result<()> {
+    out.extend_from_slice(&len_u32(row.len())?.to_le_bytes());
+    for field in row {
+        let (tag, bytes) = match field {
+            BoltValue::Json(value) => (TAG_JSON, serde_json::to_vec(value)?),
+            BoltValue::PackstreamBytes(bytes) => (TAG_PACKSTREAM, bytes.clone()),
+        };
+        out.push(tag);
+        out.extend_from_slice(&len_u32(bytes.len())?.to_le_bytes());
+        out.extend_from_slice(&bytes);
+    }
+    Ok(())
+}
+
+fn decode_row(reader: &mut impl Read) -> io::Result<Vec<BoltValue>> {
+    let fields = read_u32(reader)? as usize;
+    let mut row = Vec::with_capacity(fields);
+    for _ in 0..fields {
+        let mut tag = [0u8; 1];
+        reader.read_exact(&mut tag)?;
+        let mut bytes = vec![0u8; read_u32(reader)? as usize];
+        reader.read_exact(&mut bytes)?;
+        row.push(match tag[0] {
+            TAG_JSON => BoltValue::Json(serde_json::from_slice(&bytes)?),
+            TAG_PACKSTREAM => BoltValue::PackstreamBytes(bytes),
+            other => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unknown spill field tag {}", other),
+                ))
+            }
+        });
+    }
+    Ok(row)
+}
+
+fn len_u32(len: usize) -> io::Result<u32> {
+    u32::try_from(len)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "value too large to spill"))
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rows(n: usize) -> Vec<Vec<BoltValue>> {
+        (0..n)
+            .map(|i| {
+                vec![
+                    BoltValue::Json(json!({ "id": i, "name": format!("row-{i}") })),
+                    BoltValue::PackstreamBytes(vec![0xB1, 0x70, i as u8]),
+                ]
+            })
+            .collect()
+    }
+
+    fn ids(rows: &[Vec<BoltValue>]) -> Vec<u64> {
+        rows.iter()
+            .map(|row| match &row[0] {
+                BoltValue::Json(v) => v["id"].as_u64().unwrap(),
+                other => panic!("unexpected field {:?}", other),
+            })
+            .collect()
+    }
+
+    fn spill_files(dir: &Path) -> usize {
+        fs::read_dir(dir).map(|d| d.count()).unwrap_or(0)
+    }
+
+    fn config(dir: &Path, memory_rows: usize, max_bytes: u64) -> SpillConfig {
+        SpillConfig {
+            dir: dir.to_path_buf(),
+            memory_rows,
+            max_bytes,
+        }
+    }
+
+    #[test]
+    fn small_results_stay_in_memory() {
+        let dir = tempfile::tempdir().unwrap();
+        let buffer = ResultBuffer::new(rows(3), Some(&config(dir.path(), 3, 1 << 20))).unwrap();
+        assert!(!buffer.is_spilled());
+        assert_eq!(spill_files(dir.path()), 0);
+    }
+
+    #[test]
+    fn spilled_rows_come_back_in_order_and_file_is_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut buffer =
+            ResultBuffer::new(rows(10), Some(&config(dir.path(), 2, 1 << 20))).unwrap();
+        assert!(buffer.is_spilled());
+        assert_eq!(spill_files(dir.path()), 1);
+
+        let mut seen = Vec::new();
+        while !buffer.is_empty() {
+            let batch = buffer.take(Some(3)).unwrap();
+            assert!(batch.len() <= 3);
+            seen.extend(batch);
+        }
+        assert_eq!(ids(&seen), (0..10).collect::<Vec<_>>());
+        assert!(matches!(
+            &seen[7][1],
+            BoltValue::PackstreamBytes(bytes) if bytes == &vec![0xB1, 0x70, 7]
+        ));
+        assert_eq!(spill_files(dir.path()), 0);
+    }
+
+    #[test]
+    fn dropping_a_partly_read_buffer_removes_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut buffer =
+            ResultBuffer::new(rows(10), Some(&config(dir.path(), 0, 1 << 20))).unwrap();
+        assert_eq!(ids(&buffer.take(Some(4)).unwrap()), vec![0, 1, 2, 3]);
+        drop(buffer);
+        assert_eq!(spill_files(dir.path()), 0);
+    }
+
+    #[test]
+    fn exceeding_the_limit_fails_and_leaves_no_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = ResultBuffer::new(rows(100), Some(&config(dir.path(), 0, 256))).unwrap_err();
+        assert!(matches!(err, SpillError::LimitExceeded { max_bytes: 256 }));
+        assert_eq!(spill_files(dir.path()), 0);
+    }
+}
@@ -35,7 +35,8 @@
 use crate::{
     graph_catalog::{
         element_id::{
-            generate_node_element_id, generate_relationship_element_id, parse_node_element_id,
+            generate_node_element_id, generate_relationship_element_id, join_id_values,
+            parse_node_element_id,
         },
         graph_schema::GraphSchema,
     },
@@ -1390,9 +1391,9 @@ pub(crate) fn transform_to_relationship(
             .collect::<Result<Vec<_>, _>>()?
     };
 
-    // Join composite IDs with pipe separator
-    let from_id_str = from_id_values.join("|");
-    let to_id_str = to_id_values.join("|");
+    // Join composite IDs with pipe separator (escaped, matching node element ids)
+    let from_id_str = join_id_values(&from_id_values);
+    let to_id_str = join_id_values(&to_id_values);
 
     // Remove internal ID keys from properties (they're FK columns, not user properties)
     properties.remove("from_id");
@@ -1880,8 +1881,10 @@ fn transform_path_from_json(
     };
 
     // Create start node - element_id is source of truth, integer id derived from it
-    let start_id_str = extract_id_string_from_props(&start_props, Some(schema), Some(&start_label));
-    let start_element_id = generate_node_element_id(&start_label, &[&start_id_str]);
+    let start_id_values =
+        extract_id_values_from_props(&start_props, Some(schema), Some(&start_label));
+    let start_id_str = join_id_values(&start_id_values);
+    let start_element_id = generate_node_element_id(&start_label, &start_id_values);
     let start_id = generate_id_from_element_id(&start_element_id);
     // Clean property keys (remove table alias prefix like "t1_0.")
     let start_props_clean = clean_property_keys(start_props);
@@ -1893,8 +1896,9 @@ fn transform_path_from_json(
     );
 
     // Create end node - element_id is source of truth, integer id derived from it
-    let end_id_str = extract_id_string_from_props(&end_props, Some(schema), Some(&end_label));
-    let end_element_id = generate_node_element_id(&end_label, &[&end_id_str]);
+    let end_id_values = extract_id_values_from_props(&end_props, Some(schema), Some(&end_label));
+    let end_id_str = join_id_values(&end_id_values);
+    let end_element_id = generate_node_element_id(&end_label, &end_id_values);
     let end_id = generate_id_from_element_id(&end_element_id);
     // Clean property keys
     let end_props_clean = clean_property_keys(end_props);
@@ -2030,7 +2034,14 @@ fn transform_vlp_path(
     };
 
     match fields.len() {
-        3 => transform_vlp_path_standard(fields, path_field, start_labels, end_labels, rel_types),
+        3 => transform_vlp_path_standard(
+            fields,
+            path_field,
+            schema,
+            start_labels,
+            end_labels,
+            rel_types,
+        ),
         n if n >= 9 => transform_vlp_path_multi_type(fields, path_field, schema),
         n => Err(format!(
             "VLP path '{}': expected 3 (standard VLP) or 9 (multi-type VLP) tuple fields, got {}",
@@ -2043,20 +2054,22 @@ fn transform_vlp_path(
 /// `tuple(path_nodes, path_relationships, hop_count)` (#469, #486).
 ///
 /// This tuple shape carries only node id values and relationship type
-/// strings — no property data — so the resulting Path's nodes/relationship
+/// strings — no property data — so the resulting Path's nodes/relationships
 /// are built with correct ids/labels/types but EMPTY properties. Labels come
 /// from static query metadata (`start_labels`/`end_labels`/`rel_types`),
 /// which is safe because a standard (non-multi-type) VLP is homogeneous by
 /// construction: transitivity requires FROM/TO node-type overlap across the
 /// whole chain (see `vlp_transitivity_check.rs`).
 ///
-/// Only the overall start/end nodes are materialized (`path_nodes[0]` and
-/// `path_nodes[last]`) — intermediate nodes for `hop_count > 1` are not yet
-/// serialized to Bolt Path objects, mirroring the pre-existing multi-hop
-/// truncation behavior in `transform_vlp_path_multi_type` below.
+/// Every hop is materialized: one node per `path_nodes` entry (the first
+/// takes the start label, the rest the end label) and one relationship per
+/// consecutive pair, typed from `path_relationships[i]`. A node visited
+/// twice (a cycle) appears once in the node list and is referenced again
+/// through the indices, as in Neo4j's own Path encoding.
 fn transform_vlp_path_standard(
     fields: &[Value],
     path_field: &str,
+    schema: &GraphSchema,
     start_labels: &[String],
     end_labels: &[String],
     rel_types: &[String],
@@ -2079,10 +2092,6 @@ fn transform_vlp_path_standard(
             ));
         }
     };
-    let hop_count = fields[2]
-        .as_i64()
-        .or_else(|| fields[2].as_str().and_then(|s| s.parse().ok()))
-        .unwrap_or(1);
 
     if path_nodes.len() < 2 {
         return Err(format!(
@@ -2092,17 +2101,6 @@ fn transform_vlp_path_standard(
         ));
     }
 
-    if hop_count > 1 {
-        log::warn!(
-            "VLP path '{}' with hop_count={} truncated to single hop (start->end) for Bolt serialization",
-            path_field,
-            hop_count
-        );
-    }
-
-    let start_id_str = value_to_id_string(&path_nodes[0]);
-    let end_id_str = value_to_id_string(path_nodes.last().unwrap());
-
     let start_label = start_labels
         .first()
         .cloned()
@@ -2111,45 +2109,67 @@ fn transform_vlp_path_standard(
         .first()
         .cloned()
         .unwrap_or_else(|| "Unknown".to_string());
-    let rel_type = path_rel_types
+    let default_rel_type = rel_types
         .first()
-        .and_then(|v| v.as_str())
-        .map(|s| s.to_string())
-        .or_else(|| rel_types.first().cloned())
+        .cloned()
         .unwrap_or_else(|| "UNKNOWN".to_string());
 
-    let start_element_id = generate_node_element_id(&start_label, &[&start_id_str]);
-    let start_id = generate_id_from_element_id(&start_element_id);
-    let start_node = Node::new(
-        start_id,
-        vec![start_label],
-        HashMap::new(),
-        start_element_id.clone(),
-    );
+    let mut nodes: Vec<Node> = Vec::new();
+    let mut relationships = Vec::with_capacity(path_nodes.len() - 1);
+    let mut indices = Vec::with_capacity(2 * (path_nodes.len() - 1));
+    let mut previous: Option<(usize, Vec<String>)> = None;
 
-    let end_element_id = generate_node_element_id(&end_label, &[&end_id_str]);
-    let end_id = generate_id_from_element_id(&end_element_id);
-    let end_node = Node::new(
-        end_id,
-        vec![end_label],
-        HashMap::new(),
-        end_element_id.clone(),
-    );
+    for (position, raw_id) in path_nodes.iter().enumerate() {
+        let label = if position == 0 {
+            &start_label
+        } else {
+            &end_label
+        };
+        let id_values = value_to_id_values(raw_id, Some(schema), label);
+        let element_id = generate_node_element_id(label, &id_values);
+        let node_index = match nodes.iter().position(|n| n.element_id == element_id) {
+            Some(existing) => existing,
+            None => {
+                nodes.push(Node::new(
+                    generate_id_from_element_id(&element_id),
+                    vec![label.clone()],
+                    HashMap::new(),
+                    element_id,
+                ));
+                nodes.len() - 1
+            }
+        };
 
-    let rel_element_id = generate_relationship_element_id(&rel_type, &start_id_str, &end_id_str);
-    let rel_id = generate_id_from_element_id(&rel_element_id);
-    let relationship = Relationship::new(
-        rel_id,
-        start_node.id,
-        end_node.id,
-        rel_type,
-        HashMap::new(),
-        rel_element_id,
-        start_node.element_id.clone(),
-        end_node.element_id.clone(),
-    );
+        if let Some((from_index, from_id_values)) = previous.take() {
+            let hop = relationships.len();
+            let rel_type = path_rel_types
+                .get(hop)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| default_rel_type.clone());
+            let rel_element_id = generate_relationship_element_id(
+                &rel_type,
+                &join_id_values(&from_id_values),
+                &join_id_values(&id_values),
+            );
+            let (from, to) = (&nodes[from_index], &nodes[node_index]);
+            relationships.push(Relationship::new(
+                generate_id_from_element_id(&rel_element_id),
+                from.id,
+                to.id,
+                rel_type,
+                HashMap::new(),
+                rel_element_id,
+                from.element_id.clone(),
+                to.element_id.clone(),
+            ));
+            indices.push(relationships.len() as i64);
+            indices.push(node_index as i64);
+        }
+        previous = Some((node_index, id_values));
+    }
 
-    Ok(Path::single_hop(start_node, relationship, end_node))
+    Ok(Path::new(nodes, relationships, indices))
 }
 
 /// Transform a VLP multi-type path from its tuple representation.
@@ -2167,7 +2187,7 @@ fn transform_vlp_path_standard(
 fn transform_vlp_path_multi_type(
     fields: &[Value],
     _path_field: &str,
-    _schema: &GraphSchema,
+    schema: &GraphSchema,
 ) -> Result<Path, String> {
     // Extract fields from the tuple
     let start_props_json = fields[0].as_str().unwrap_or("{}");
@@ -2191,14 +2211,16 @@ fn transform_vlp_path_multi_type(
         _ => "UNKNOWN".to_string(),
     };
 
-    let start_id_str = value_to_id_string(&fields[4]);
-    let end_id_str = value_to_id_string(&fields[5]);
     let hop_count = fields[6]
         .as_i64()
         .or_else(|| fields[6].as_str().and_then(|s| s.parse().ok()))
         .unwrap_or(1);
     let start_type = fields[7].as_str().unwrap_or("Unknown").to_string();
     let end_type = fields[8].as_str().unwrap_or("Unknown").to_string();
+    let start_id_values = value_to_id_values(&fields[4], Some(schema), &start_type);
+    let end_id_values = value_to_id_values(&fields[5], Some(schema), &end_type);
+    let start_id_str = join_id_values(&start_id_values);
+    let end_id_str = join_id_values(&end_id_values);
 
     log::debug!(
         "VLP path: {}:{} -[{}]-> {}:{}",
@@ -2218,7 +2240,7 @@ fn transform_vlp_path_multi_type(
         serde_json::from_str(rel_props_json).unwrap_or_default();
 
     // Build start node
-    let start_element_id = generate_node_element_id(&start_type, &[&start_id_str]);
+    let start_element_id = generate_node_element_id(&start_type, &start_id_values);
     let start_id = generate_id_from_element_id(&start_element_id);
     let start_node = Node::new(
         start_id,
@@ -2228,7 +2250,7 @@ fn transform_vlp_path_multi_type(
     );
 
     // Build end node
-    let end_element_id = generate_node_element_id(&end_type, &[&end_id_str]);
+    let end_element_id = generate_node_element_id(&end_type, &end_id_values);
     let end_id = generate_id_from_element_id(&end_element_id);
     let end_node = Node::new(
         end_id,
@@ -2243,7 +2265,7 @@ fn transform_vlp_path_multi_type(
         {
             // Check if there's a schema relationship from start_type -> end_type
             let forward_match =
-                if let Some(rel_schema) = _schema.get_relationships_schema_opt(&rel_type) {
+                if let Some(rel_schema) = schema.get_relationships_schema_opt(&rel_type) {
                     (rel_schema.from_node == "$any" || rel_schema.from_node == start_type)
                         && (rel_schema.to_node == "$any" || rel_schema.to_node == end_type)
                 } else {
@@ -2261,7 +2283,7 @@ fn transform_vlp_path_multi_type(
             } else {
                 // Try reverse direction
                 let reverse_match =
-                    if let Some(rel_schema) = _schema.get_relationships_schema_opt(&rel_type) {
+                    if let Some(rel_schema) = schema.get_relationships_schema_opt(&rel_type) {
                         (rel_schema.from_node == "$any" || rel_schema.from_node == end_type)
                             && (rel_schema.to_node == "$any" || rel_schema.to_node == start_type)
                     } else {
@@ -2357,13 +2379,14 @@ fn clean_property_keys(props: HashMap<String, Value>) -> HashMap<String, Value>
         .collect()
 }
 
-/// Extract ID string from properties HashMap for element_id generation
-/// Tries common ID field names and returns the string representation
-fn extract_id_string_from_props(
+/// Extract ID values from properties HashMap for element_id generation
+/// Tries schema-defined ID columns (all of them, for composite IDs) first,
+/// then common ID field names, and returns the string representations
+fn extract_id_values_from_props(
     props: &HashMap<String, Value>,
     schema: Option<&GraphSchema>,
     label: Option<&str>,
-) -> String {
+) -> Vec<String> {
     // Try schema-defined ID columns first (most reliable)
     if let (Some(schema), Some(label)) = (schema, label) {
         if let Some(node_schema) = schema.node_schema_opt(label) {
@@ -2383,7 +2406,7 @@ fn extract_id_string_from_props(
                 })
                 .collect();
             if id_values.len() == id_columns.len() {
-                return id_values.join("|");
+                return id_values;
             }
         }
     }
@@ -2404,7 +2427,7 @@ fn extract_id_string_from_props(
     for id_field in &id_fields {
         if let Some(val) = props.get(*id_field) {
             if let Some(str_val) = value_to_string(val) {
-                return str_val;
+                return vec![str_val];
             }
         }
     }
@@ -2415,7 +2438,7 @@ fn extract_id_string_from_props(
             let prefixed_key = format!("{}{}", prefix, id_field);
             if let Some(val) = props.get(&prefixed_key) {
                 if let Some(str_val) = value_to_string(val) {
-                    return str_val;
+                    return vec![str_val];
                 }
             }
         }
@@ -2426,12 +2449,12 @@ fn extract_id_string_from_props(
         let key_lower = key.to_lowercase();
         if key_lower.ends_with("_id") || key_lower.ends_with("id") || key_lower == "code" {
             if let Some(str_val) = value_to_string(val) {
-                return str_val;
+                return vec![str_val];
             }
         }
     }
 
-    "0".to_string()
+    vec!["0".to_string()]
 }
 
 /// Generate a unique integer node ID from element_id
@@ -2659,10 +2682,10 @@ fn find_relationship_in_row_with_type(
     // node element_ids. This matches the canonical format used everywhere else
     // (e.g., `LIKED:2->43-`) so Browser dedupes correctly across expansions.
     let from_bare_id = parse_node_element_id(start_element_id)
-        .map(|(_, ids)| ids.join("|"))
+        .map(|(_, ids)| join_id_values(&ids))
         .unwrap_or_else(|_| start_element_id.to_string());
     let to_bare_id = parse_node_element_id(end_element_id)
-        .map(|(_, ids)| ids.join("|"))
+        .map(|(_, ids)| join_id_values(&ids))
         .unwrap_or_else(|_| end_element_id.to_string());
     let rel_element_id = generate_relationship_element_id(rel_type, &from_bare_id, &to_bare_id);
     let rel_id = generate_id_from_element_id(&rel_element_id);
@@ -2719,10 +2742,10 @@ fn create_relationship_with_type(
     // Generate element_id using bare scalar IDs extracted from the node
     // element_ids — same canonical format used elsewhere (`LIKED:2->43-`).
     let from_bare_id = parse_node_element_id(start_element_id)
-        .map(|(_, ids)| ids.join("|"))
+        .map(|(_, ids)| join_id_values(&ids))
         .unwrap_or_else(|_| start_element_id.to_string());
     let to_bare_id = parse_node_element_id(end_element_id)
-        .map(|(_, ids)| ids.join("|"))
+        .map(|(_, ids)| join_id_values(&ids))
         .unwrap_or_else(|_| end_element_id.to_string());
     let element_id = generate_relationship_element_id(rel_type, &from_bare_id, &to_bare_id);
     let id = generate_id_from_element_id(&element_id);
@@ -2755,6 +2778,30 @@ fn value_to_id_string(value: &Value) -> String {
     }
 }
 
+/// Extract node ID values from a JSON Value for element_id generation.
+///
+/// Composite IDs arrive either as a tuple (JSON array, e.g. VLP `path_nodes`
+/// entries) or as a pipe-joined string (`start_id`/`end_id` columns). The
+/// string form is only split when the label's schema declares a composite ID,
+/// so a single string ID that happens to contain `|` stays one value.
+fn value_to_id_values(value: &Value, schema: Option<&GraphSchema>, label: &str) -> Vec<String> {
+    match value {
+        Value::Array(parts) => parts.iter().map(value_to_id_string).collect(),
+        _ => {
+            let id = value_to_id_string(value);
+            let column_count = schema
+                .and_then(|s| s.node_schema_opt(label))
+                .map(|node_schema| node_schema.node_id.id.columns().len())
+                .unwrap_or(1);
+            if column_count > 1 {
+                id.splitn(column_count, '|').map(str::to_string).collect()
+            } else {
+                vec![id]
+            }
+        }
+    }
+}
+
 fn value_to_string(value: &Value) -> Option<String> {
     match value {
         Value::String(s) => Some(s.clone()),
@@ -3433,11 +3480,46 @@ graph_schema:
         assert_eq!(path.relationships[0].rel_type, "FLIGHT");
     }
 
-    /// #486: multi-hop standard VLP (`hop_count > 1`) is truncated to a
-    /// single start->end hop for Bolt serialization (mirrors the pre-existing
-    /// multi-type VLP behavior) rather than erroring.
+    /// Composite-ID VLPs store each `path_nodes` entry as a tuple; the path's
+    /// nodes must get the same element ids as the node would on its own
+    /// (`generate_node_element_id` over the component values), and a
+    /// component containing `|` must not bleed into the next one.
+    #[test]
+    fn test_transform_vlp_path_standard_composite_tuple_ids() {
+        let schema = GraphSchema::build(1, "test".to_string(), HashMap::new(), HashMap::new());
+
+        let mut row: HashMap<String, Value> = HashMap::new();
+        row.insert(
+            "p".to_string(),
+            serde_json::json!([[["acme", 1], ["acme|eu", 2]], ["OWNS"], 1]),
+        );
+
+        let path = transform_vlp_path(
+            &row,
+            "p",
+            &schema,
+            &["Account".to_string()],
+            &["Account".to_string()],
+            &["OWNS".to_string()],
+        )
+        .expect("composite tuple path_nodes should decode to a Path");
+
+        assert_eq!(
+            path.nodes[0].element_id,
+            generate_node_element_id("Account", &["acme", "1"])
+        );
+        assert_eq!(path.nodes[1].element_id, r"Account:acme\|eu|2-");
+        assert_eq!(
+            path.relationships[0].element_id,
+            r"OWNS:acme|1->acme\|eu|2-"
+        );
+    }
+
+    /// Multi-hop standard VLP paths keep every hop so the Browser can draw
+    /// the whole path: one node per `path_nodes` entry, one relationship per
+    /// hop, and indices alternating (1-based rel index, node index).
     #[test]
-    fn test_transform_vlp_path_standard_multi_hop_truncates_to_start_end() {
+    fn test_transform_vlp_path_standard_multi_hop_keeps_every_hop() {
         let schema = GraphSchema::build(1, "test".to_string(), HashMap::new(), HashMap::new());
 
         let mut row: HashMap<String, Value> = HashMap::new();
@@ -3454,10 +3536,48 @@ graph_schema:
             &["Airport".to_string()],
             &["FLIGHT".to_string()],
         )
-        .expect("multi-hop 3-field standard VLP tuple should decode to a truncated Path");
+        .expect("multi-hop 3-field standard VLP tuple should decode to a Path");
+
+        assert_eq!(path.nodes.len(), 3);
+        assert_eq!(path.relationships.len(), 2);
+        assert_eq!(path.indices, vec![1, 1, 2, 2]);
+        assert_eq!(path.nodes[1].element_id, "Airport:20-");
+        assert_eq!(path.relationships[1].element_id, "FLIGHT:20->30-");
+        assert_eq!(
+            path.relationships[1].start_node_element_id,
+            path.nodes[1].element_id
+        );
+        assert_eq!(
+            path.relationships[1].end_node_element_id,
+            path.nodes[2].element_id
+        );
+    }
+
+    /// A path that revisits a node lists it once; the second visit is
+    /// expressed through the indices.
+    #[test]
+    fn test_transform_vlp_path_standard_cycle_reuses_node() {
+        let schema = GraphSchema::build(1, "test".to_string(), HashMap::new(), HashMap::new());
+
+        let mut row: HashMap<String, Value> = HashMap::new();
+        row.insert(
+            "p".to_string(),
+            serde_json::json!([[1, 2, 1], ["FOLLOWS", "FOLLOWS"], 2]),
+        );
+
+        let path = transform_vlp_path(
+            &row,
+            "p",
+            &schema,
+            &["User".to_string()],
+            &["User".to_string()],
+            &["FOLLOWS".to_string()],
+        )
+        .expect("cyclic path should decode");
 
         assert_eq!(path.nodes.len(), 2);
-        assert_eq!(path.relationships.len(), 1);
+        assert_eq!(path.relationships.len(), 2);
+        assert_eq!(path.indices, vec![1, 1, 2, 0]);
     }
 
     /// #486: the 9-field multi-type VLP tuple shape must still decode
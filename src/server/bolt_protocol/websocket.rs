@@ -9,15 +9,16 @@ use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
 use tokio::net::TcpStream;
 use tokio_tungstenite::{accept_async, tungstenite::Message, WebSocketStream};
 
-/// Adapter that wraps a WebSocket connection to implement AsyncRead/AsyncWrite
-pub struct WebSocketBoltAdapter {
-    ws_stream: WebSocketStream<TcpStream>,
+/// Adapter that wraps a WebSocket connection to implement AsyncRead/AsyncWrite.
+/// `S` is the transport underneath: a `TcpStream`, or a TLS stream for `wss`.
+pub struct WebSocketBoltAdapter<S = TcpStream> {
+    ws_stream: WebSocketStream<S>,
     read_buffer: Vec<u8>,
     read_pos: usize,
 }
 
-impl WebSocketBoltAdapter {
-    pub async fn new(stream: TcpStream) -> io::Result<Self> {
+impl<S: AsyncRead + AsyncWrite + Unpin> WebSocketBoltAdapter<S> {
+    pub async fn new(stream: S) -> io::Result<Self> {
         let ws_stream = accept_async(stream).await.map_err(|e| {
             io::Error::new(
                 io::ErrorKind::InvalidData,
@@ -35,7 +36,7 @@ impl WebSocketBoltAdapter {
     }
 }
 
-impl AsyncRead for WebSocketBoltAdapter {
+impl<S: AsyncRead + AsyncWrite + Unpin> AsyncRead for WebSocketBoltAdapter<S> {
     fn poll_read(
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
@@ -110,7 +111,7 @@ impl AsyncRead for WebSocketBoltAdapter {
     }
 }
 
-impl AsyncWrite for WebSocketBoltAdapter {
+impl<S: AsyncRead + AsyncWrite + Unpin> AsyncWrite for WebSocketBoltAdapter<S> {
     fn poll_write(
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
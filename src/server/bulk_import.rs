@@ -0,0 +1,608 @@
+//! Bulk graph import (`POST /import`).
+//!
+//! Loads CSV, TSV, JSON or Parquet files into the tables a schema maps to
+//! node labels and relationship types, so ClickGraph can manage ingestion
+//! as well as querying:
+//!
+//! ```json
+//! {"schema_name": "social", "mappings": [
+//!   {"source": "s3://bucket/users/*.parquet", "label": "User"},
+//!   {"source": "https://example.com/follows.csv", "rel_type": "FOLLOWS",
+//!    "columns": {"since": "followed_at"}}
+//! ]}
+//! ```
+//!
+//! Each mapping becomes one `INSERT INTO <table> (...) SELECT ... FROM
+//! s3()/url()/file()` run by ClickHouse itself, so the data never passes
+//! through ClickGraph. Every target column is read from the source column
+//! named like its property (for relationship endpoints, like the id column);
+//! `columns` renames them. Properties mapped to expressions are left out.
+//! On a table shared by several labels or types, the discriminator columns
+//! are set to the mapping's label or type.
+//!
+//! Every mapping is checked against the schema before anything runs. The
+//! statements then run in order on the batch endpoint, streaming one NDJSON
+//! progress line per mapping and a summary; the first failure stops the
+//! import. Mappings that finished stay imported. With `sql_only`, the
+//! statements are returned instead.
+
+use std::{collections::BTreeMap, convert::Infallible, sync::Arc, time::Instant};
+
+use axum::{
+    body::{Body, Bytes},
+    extract::State,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+
+use super::{endpoint_routing, graph_catalog, handlers::GRAPH_NAME_HEADER, AppState};
+use crate::clickhouse_query_generator::quote_identifier;
+use crate::executor::source_resolver::{detect_format_from_path, escape_sql_string};
+use crate::graph_catalog::config::Identifier;
+use crate::graph_catalog::expression_parser::PropertyValue;
+use crate::graph_catalog::graph_schema::GraphSchema;
+use crate::utils::redaction::{redact, redact_sql};
+
+/// Progress lines buffered ahead of a slow client.
+const IMPORT_CHANNEL_CAPACITY: usize = 16;
+
+/// Body of `POST /import`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportRequest {
+    /// Graph whose mappings name the target tables; the default graph when unset
+    pub schema_name: Option<String>,
+    pub mappings: Vec<ImportMapping>,
+    /// ClickHouse role to run the inserts as
+    pub role: Option<String>,
+    /// Return the statements without running them
+    pub sql_only: Option<bool>,
+}
+
+/// One file (or glob) loaded into the table of a label or type.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportMapping {
+    /// `s3://`, `gs://`, `http(s)://` or a file path on the ClickHouse server
+    pub source: String,
+    /// Node label to load (or `rel_type`)
+    pub label: Option<String>,
+    /// Relationship type to load (or `label`)
+    pub rel_type: Option<String>,
+    /// Endpoint labels, for a type mapped for several endpoint pairs
+    pub from: Option<String>,
+    pub to: Option<String>,
+    /// ClickHouse input format; from the file extension when unset, with
+    /// a header row for CSV and TSV
+    pub format: Option<String>,
+    /// Source column per property (or relationship id column), when it is
+    /// not named like it
+    #[serde(default)]
+    pub columns: BTreeMap<String, String>,
+}
+
+/// The statement loading one mapping.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportStatement {
+    /// `node:Label` or `relationship:TYPE`
+    pub target: String,
+    /// `database.table` written to
+    pub table: String,
+    pub sql: String,
+}
+
+/// Plan the statement loading `mapping` into its table in `schema`.
+pub fn plan_import(
+    schema: &GraphSchema,
+    mapping: &ImportMapping,
+) -> Result<ImportStatement, String> {
+    let source = source_function(&mapping.source, mapping.format.as_deref())?;
+    let (target, table, columns) = match (&mapping.label, &mapping.rel_type) {
+        (Some(label), None) => node_columns(schema, label, &mapping.columns)?,
+        (None, Some(rel_type)) => relationship_columns(schema, rel_type, mapping)?,
+        _ => return Err("each mapping needs exactly one of label or rel_type".to_string()),
+    };
+    let sql = format!(
+        "INSERT INTO {} ({}) SELECT {} FROM {}",
+        table,
+        columns
+            .iter()
+            .map(|(column, _)| quote_identifier(column))
+            .collect::<Vec<_>>()
+            .join(", "),
+        columns
+            .iter()
+            .map(|(_, expr)| expr.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+        source
+    );
+    Ok(ImportStatement { target, table, sql })
+}
+
+/// Target columns with the expressions filling them.
+type Columns = Vec<(String, String)>;
+
+/// Adds target columns, reading each from the source column named by
+/// `columns` or like its key, and checks that every key of `columns` is used.
+struct ColumnsBuilder<'a> {
+    renames: &'a BTreeMap<String, String>,
+    used: Vec<&'a str>,
+    columns: Columns,
+}
+
+impl<'a> ColumnsBuilder<'a> {
+    fn new(renames: &'a BTreeMap<String, String>) -> Self {
+        Self {
+            renames,
+            used: Vec::new(),
+            columns: Vec::new(),
+        }
+    }
+
+    fn has(&self, column: &str) -> bool {
+        self.columns.iter().any(|(c, _)| c == column)
+    }
+
+    /// `column` read from the source column for `key`
+    fn read(&mut self, column: &str, key: &str) {
+        if self.has(column) {
+            return;
+        }
+        let source = match self.renames.get_key_value(key) {
+            Some((key, source)) => {
+                self.used.push(key);
+                source.as_str()
+            }
+            None => key,
+        };
+        self.columns
+            .push((column.to_string(), source_column(source)));
+    }
+
+    /// `column` set to the string `value`
+    fn literal(&mut self, column: &str, value: &str) {
+        if !self.has(column) {
+            self.columns.push((
+                column.to_string(),
+                format!("'{}'", escape_sql_string(value)),
+            ));
+        }
+    }
+
+    /// Every mapped property that is a plain column, in name order.
+    fn properties(&mut self, mappings: &'a std::collections::HashMap<String, PropertyValue>) {
+        let mut properties: Vec<(&String, &PropertyValue)> = mappings.iter().collect();
+        properties.sort_by_key(|(name, _)| name.as_str());
+        for (name, value) in properties {
+            if let PropertyValue::Column(column) = value {
+                self.read(column, name);
+            }
+        }
+    }
+
+    fn finish(self, target: &str) -> Result<Columns, String> {
+        match self
+            .renames
+            .keys()
+            .find(|key| !self.used.contains(&key.as_str()))
+        {
+            Some(key) => Err(format!(
+                "{}: `columns` names '{}', which is not an imported property",
+                target, key
+            )),
+            None => Ok(self.columns),
+        }
+    }
+}
+
+fn node_columns(
+    schema: &GraphSchema,
+    label: &str,
+    renames: &BTreeMap<String, String>,
+) -> Result<(String, String, Columns), String> {
+    let target = format!("node:{}", label);
+    let node = schema
+        .node_schema_opt(label)
+        .ok_or_else(|| format!("{}: no such label in the schema", target))?;
+    if !node.has_standalone_table() {
+        return Err(format!(
+            "{}: stored in a relationship table or a parameterized view; import the \
+             relationship instead",
+            target
+        ));
+    }
+    if node.filter.is_some() {
+        return Err(format!(
+            "{}: the mapping has a filter, so imported rows may not match it",
+            target
+        ));
+    }
+    let mut builder = ColumnsBuilder::new(renames);
+    for (property, column) in node
+        .node_id
+        .columns()
+        .iter()
+        .zip(node.id_physical_columns())
+    {
+        builder.read(&column, property);
+    }
+    builder.properties(&node.property_mappings);
+    if let Some((column, value)) = node.label_discriminator() {
+        builder.literal(column, value);
+    }
+    let columns = builder.finish(&target)?;
+    Ok((target, node.full_table_name(), columns))
+}
+
+fn relationship_columns(
+    schema: &GraphSchema,
+    rel_type: &str,
+    mapping: &ImportMapping,
+) -> Result<(String, String, Columns), String> {
+    let target = format!("relationship:{}", rel_type);
+    let keys = schema
+        .get_rel_type_index()
+        .get(rel_type)
+        .ok_or_else(|| format!("{}: no such relationship type in the schema", target))?;
+    let candidates: Vec<_> = keys
+        .iter()
+        .filter_map(|key| schema.get_relationships_schemas().get(key))
+        .filter(|rel| {
+            mapping
+                .from
+                .as_ref()
+                .is_none_or(|from| &rel.from_node == from)
+                && mapping.to.as_ref().is_none_or(|to| &rel.to_node == to)
+        })
+        .collect();
+    let rel = match candidates.as_slice() {
+        [rel] => *rel,
+        [] => {
+            return Err(format!(
+                "{}: no mapping from {} to {}",
+                target,
+                mapping.from.as_deref().unwrap_or("any label"),
+                mapping.to.as_deref().unwrap_or("any label")
+            ))
+        }
+        _ => {
+            return Err(format!(
+                "{}: mapped for several endpoint pairs; give `from` and `to`",
+                target
+            ))
+        }
+    };
+    if rel.view_parameters.is_some() {
+        return Err(format!("{}: stored in a parameterized view", target));
+    }
+    if rel.filter.is_some() {
+        return Err(format!(
+            "{}: the mapping has a filter, so imported rows may not match it",
+            target
+        ));
+    }
+    // Edges kept as columns of a node table are written with the node
+    let table = rel.full_table_name();
+    let in_node_table = [&rel.from_node, &rel.to_node].into_iter().any(|label| {
+        schema
+            .node_schema_opt(label)
+            .is_some_and(|node| node.full_table_name() == table)
+    });
+    if in_node_table {
+        return Err(format!(
+            "{}: stored in the table of its endpoint nodes; import the nodes instead",
+            target
+        ));
+    }
+
+    let mut builder = ColumnsBuilder::new(&mapping.columns);
+    let ids =
+        |id: &Identifier| -> Vec<String> { id.columns().iter().map(|c| c.to_string()).collect() };
+    for column in ids(&rel.from_id).into_iter().chain(ids(&rel.to_id)) {
+        builder.read(&column, &column);
+    }
+    builder.properties(&rel.property_mappings);
+    for (column, value) in rel.row_discriminators(rel_type) {
+        builder.literal(column, value);
+    }
+    let columns = builder.finish(&target)?;
+    Ok((target, table, columns))
+}
+
+/// A source column reference. Names come from the request, so they are
+/// always quoted.
+fn source_column(name: &str) -> String {
+    format!("`{}`", name.replace('\\', "\\\\").replace('`', "\\`"))
+}
+
+/// The table function reading `source`.
+fn source_function(source: &str, format: Option<&str>) -> Result<String, String> {
+    let format = match format {
+        Some(format) if format.chars().all(|c| c.is_ascii_alphanumeric()) && !format.is_empty() => {
+            format.to_string()
+        }
+        Some(format) => return Err(format!("invalid format '{}'", format)),
+        // Named columns need the header row
+        None => match detect_format_from_path(source) {
+            "CSV" => "CSVWithNames".to_string(),
+            "TSV" => "TSVWithNames".to_string(),
+            other => other.to_string(),
+        },
+    };
+    let (function, location) = if source.starts_with("s3://") {
+        ("s3", source.to_string())
+    } else if let Some(rest) = source.strip_prefix("gs://") {
+        ("s3", format!("https://storage.googleapis.com/{}", rest))
+    } else if source.starts_with("https://") || source.starts_with("http://") {
+        ("url", source.to_string())
+    } else if let Some(path) = source.strip_prefix("file://") {
+        ("file", path.to_string())
+    } else if !source.is_empty() && !source.contains("://") {
+        ("file", source.to_string())
+    } else {
+        return Err(format!(
+            "unsupported source '{}' (expected s3://, gs://, http(s):// or a file path)",
+            source
+        ));
+    };
+    Ok(format!(
+        "{}('{}', '{}')",
+        function,
+        escape_sql_string(&location),
+        format
+    ))
+}
+
+/// `POST /import` — load files into the tables of a graph.
+pub async fn import_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(request): Json<ImportRequest>,
+) -> Result<Response, (StatusCode, String)> {
+    let pinned = match headers.get(GRAPH_NAME_HEADER) {
+        Some(value) => Some(value.to_str().map_err(|_| {
+            (
+                StatusCode::BAD_REQUEST,
+                format!("{} must be a graph name", GRAPH_NAME_HEADER),
+            )
+        })?),
+        None => None,
+    };
+    let schema_name =
+        graph_catalog::resolve_graph_name(None, request.schema_name.as_deref().or(pinned));
+    graph_catalog::check_graph_isolation(pinned, &schema_name)
+        .map_err(|e| (StatusCode::FORBIDDEN, e))?;
+    let schema = graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| (StatusCode::NOT_FOUND, e))?;
+    if request.mappings.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Import needs at least one mapping".to_string(),
+        ));
+    }
+    let statements = request
+        .mappings
+        .iter()
+        .map(|mapping| plan_import(&schema, mapping))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| (StatusCode::BAD_REQUEST, format!("Invalid import: {}", e)))?;
+
+    if request.sql_only.unwrap_or(false) {
+        return Ok(Json(json!({ "statements": statements })).into_response());
+    }
+    log::info!(
+        "Importing {} mappings into graph '{}'",
+        statements.len(),
+        schema_name
+    );
+
+    let (tx, rx) = mpsc::channel::<Bytes>(IMPORT_CHANNEL_CAPACITY);
+    tokio::spawn(async move {
+        let start = Instant::now();
+        let total = statements.len();
+        let mut imported = 0;
+        let mut failed = false;
+        for (index, (statement, mapping)) in statements.iter().zip(&request.mappings).enumerate() {
+            let started = Instant::now();
+            log::debug!("Import SQL: {}", redact_sql(&statement.sql));
+            let result = endpoint_routing::batch(app_state.executor.execute_text(
+                &statement.sql,
+                "TabSeparated",
+                request.role.as_deref(),
+            ))
+            .await;
+            let mut line = json!({
+                "index": index,
+                "target": statement.target,
+                "table": statement.table,
+                "source": mapping.source,
+                "elapsed_ms": started.elapsed().as_millis() as u64,
+            });
+            match result {
+                Ok(_) => {
+                    imported += 1;
+                    line["status"] = json!(StatusCode::OK.as_u16());
+                }
+                Err(e) => {
+                    failed = true;
+                    line["status"] = json!(StatusCode::INTERNAL_SERVER_ERROR.as_u16());
+                    line["error"] = Value::String(redact(&e.to_string()).into_owned());
+                }
+            }
+            if tx.send(ndjson_line(&line)).await.is_err() {
+                log::info!("Import client disconnected after {} mappings", index + 1);
+                return;
+            }
+            if failed {
+                break;
+            }
+        }
+        let summary = json!({
+            "summary": {
+                "total": total,
+                "imported": imported,
+                "failed": failed,
+                "elapsed_ms": start.elapsed().as_millis() as u64,
+            }
+        });
+        let _ = tx.send(ndjson_line(&summary)).await;
+    });
+
+    let lines = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|line| (Ok::<_, Infallible>(line), rx))
+    });
+    let mut response = Response::new(Body::from_stream(lines));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/x-ndjson"),
+    );
+    Ok(response)
+}
+
+fn ndjson_line(value: &Value) -> Bytes {
+    let mut line = value.to_string().into_bytes();
+    line.push(b'\n');
+    Bytes::from(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    const SCHEMA: &str = r#"
+name: import_graph
+graph_schema:
+  nodes:
+    - label: User
+      database: app
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        name_upper: "upper(full_name)"
+    - label: Post
+      database: app
+      table: content
+      node_id: id
+      label_column: kind
+      label_value: post
+      property_mappings:
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: app
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings:
+        since: created_at
+"#;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA)
+            .unwrap()
+            .to_graph_schema()
+            .unwrap()
+    }
+
+    fn mapping(source: &str) -> ImportMapping {
+        ImportMapping {
+            source: source.to_string(),
+            ..ImportMapping::default()
+        }
+    }
+
+    #[test]
+    fn nodes_load_mapped_columns() {
+        let statement = plan_import(
+            &schema(),
+            &ImportMapping {
+                label: Some("User".into()),
+                columns: BTreeMap::from([("name".into(), "display name".into())]),
+                ..mapping("s3://bucket/users/*.parquet")
+            },
+        )
+        .unwrap();
+        assert_eq!(statement.target, "node:User");
+        assert_eq!(statement.table, "app.users");
+        // The computed `name_upper` is left out
+        assert_eq!(
+            statement.sql,
+            "INSERT INTO app.users (user_id, full_name) SELECT `user_id`, `display name` \
+             FROM s3('s3://bucket/users/*.parquet', 'Parquet')"
+        );
+
+        // A shared table gets its label column
+        let statement = plan_import(
+            &schema(),
+            &ImportMapping {
+                label: Some("Post".into()),
+                ..mapping("/data/posts.csv")
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            statement.sql,
+            "INSERT INTO app.content (id, title, kind) SELECT `id`, `title`, 'post' \
+             FROM file('/data/posts.csv', 'CSVWithNames')"
+        );
+    }
+
+    #[test]
+    fn relationships_load_endpoint_ids() {
+        let statement = plan_import(
+            &schema(),
+            &ImportMapping {
+                rel_type: Some("FOLLOWS".into()),
+                format: Some("JSONEachRow".into()),
+                ..mapping("https://example.com/follows")
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            statement.sql,
+            "INSERT INTO app.follows (follower_id, followed_id, created_at) \
+             SELECT `follower_id`, `followed_id`, `since` \
+             FROM url('https://example.com/follows', 'JSONEachRow')"
+        );
+    }
+
+    #[test]
+    fn invalid_mappings_are_rejected() {
+        let schema = schema();
+        let rejected = |m: ImportMapping| plan_import(&schema, &m).unwrap_err();
+        assert!(rejected(ImportMapping {
+            label: Some("Nope".into()),
+            ..mapping("/data/x.csv")
+        })
+        .contains("no such label"));
+        assert!(rejected(mapping("/data/x.csv")).contains("exactly one"));
+        assert!(rejected(ImportMapping {
+            label: Some("User".into()),
+            ..mapping("ftp://host/users.csv")
+        })
+        .contains("unsupported source"));
+        assert!(rejected(ImportMapping {
+            label: Some("User".into()),
+            format: Some("CSV') --".into()),
+            ..mapping("/data/users.csv")
+        })
+        .contains("invalid format"));
+        assert!(rejected(ImportMapping {
+            label: Some("User".into()),
+            columns: BTreeMap::from([("email".into(), "mail".into())]),
+            ..mapping("/data/users.csv")
+        })
+        .contains("'email'"));
+
+        assert_eq!(source_column("a`b"), "`a\\`b`");
+    }
+}
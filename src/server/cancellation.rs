@@ -0,0 +1,163 @@
+//! Statement timeouts, propagated to the ClickHouse queries behind them.
+//!
+//! A statement runs inside [`run`], which tags every ClickHouse query it
+//! issues with a `query_id` ([`query_options`], applied by the remote
+//! executor). When the statement outlives its timeout, or its future is
+//! dropped because the client went away, the queries it started are killed
+//! with `KILL QUERY ... ASYNC` rather than left running on the cluster: a
+//! runaway variable-length traversal stops with the request that asked
+//! for it.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::executor::QueryExecutor;
+
+tokio::task_local! {
+    /// The `query_id`s of the statement running in [`run`].
+    static CANCEL_SCOPE: Arc<CancelScope>;
+}
+
+struct CancelScope {
+    prefix: String,
+    query_ids: Mutex<Vec<String>>,
+}
+
+impl CancelScope {
+    fn next_query_id(&self) -> String {
+        let mut ids = self.query_ids.lock().unwrap_or_else(|e| e.into_inner());
+        let id = format!("{}-{}", self.prefix, ids.len());
+        ids.push(id.clone());
+        id
+    }
+}
+
+/// The statement ran longer than its timeout; its queries were killed.
+#[derive(Debug, Error)]
+#[error("Query exceeded its timeout of {} ms and was cancelled", .0.as_millis())]
+pub struct TimedOut(pub Duration);
+
+/// The timeout of a statement: the one the client asked for
+/// (`timeout_ms`, Bolt `tx_timeout`; `0` means none) capped by the
+/// server's `query_timeout_secs` (`0` means none).
+pub fn effective_timeout(requested_ms: Option<u64>, server_secs: u64) -> Option<Duration> {
+    let requested = requested_ms.filter(|ms| *ms > 0).map(Duration::from_millis);
+    let server = (server_secs > 0).then(|| Duration::from_secs(server_secs));
+    match (requested, server) {
+        (Some(r), Some(s)) => Some(r.min(s)),
+        (r, s) => r.or(s),
+    }
+}
+
+/// ClickHouse options for the next query of the current statement: a fresh
+/// `query_id` to kill it by. Empty outside [`run`].
+pub fn query_options() -> Vec<(String, String)> {
+    CANCEL_SCOPE
+        .try_with(|scope| vec![("query_id".to_string(), scope.next_query_id())])
+        .unwrap_or_default()
+}
+
+/// Kills the statement's queries unless disarmed; dropped with the
+/// statement's future, so a disconnect cancels them too.
+struct KillOnDrop {
+    executor: Arc<dyn QueryExecutor>,
+    scope: Arc<CancelScope>,
+    armed: bool,
+}
+
+impl Drop for KillOnDrop {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        let ids = std::mem::take(
+            &mut *self
+                .scope
+                .query_ids
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()),
+        );
+        if ids.is_empty() {
+            return;
+        }
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        log::info!(
+            "Cancelling {} ClickHouse queries of {}",
+            ids.len(),
+            self.scope.prefix
+        );
+        let executor = self.executor.clone();
+        runtime.spawn(async move { executor.cancel_queries(&ids).await });
+    }
+}
+
+/// Run the statement `f` within `timeout`. Its ClickHouse queries are
+/// killed when it times out or is dropped before finishing.
+pub async fn run<F, T>(
+    executor: Arc<dyn QueryExecutor>,
+    timeout: Option<Duration>,
+    f: F,
+) -> Result<T, TimedOut>
+where
+    F: Future<Output = T>,
+{
+    let scope = Arc::new(CancelScope {
+        prefix: format!("clickgraph-{}", uuid::Uuid::new_v4()),
+        query_ids: Mutex::new(Vec::new()),
+    });
+    let mut guard = KillOnDrop {
+        executor,
+        scope: scope.clone(),
+        armed: true,
+    };
+    let statement = CANCEL_SCOPE.scope(scope, f);
+    let result = match timeout {
+        Some(limit) => tokio::time::timeout(limit, statement)
+            .await
+            .map_err(|_| TimedOut(limit)),
+        None => Ok(statement.await),
+    };
+    guard.armed = result.is_err();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requested_timeout_is_capped_by_the_server() {
+        let secs = Duration::from_secs;
+        assert_eq!(effective_timeout(Some(2_000), 300), Some(secs(2)));
+        assert_eq!(effective_timeout(Some(600_000), 300), Some(secs(300)));
+        assert_eq!(effective_timeout(Some(0), 300), Some(secs(300)));
+        assert_eq!(effective_timeout(None, 0), None);
+        assert_eq!(
+            effective_timeout(Some(1_500), 0),
+            Some(Duration::from_millis(1_500))
+        );
+    }
+
+    #[tokio::test]
+    async fn query_ids_are_unique_within_a_statement() {
+        assert!(query_options().is_empty());
+        let scope = Arc::new(CancelScope {
+            prefix: "clickgraph-test".to_string(),
+            query_ids: Mutex::new(Vec::new()),
+        });
+        let (first, second) = CANCEL_SCOPE
+            .scope(scope.clone(), async { (query_options(), query_options()) })
+            .await;
+        assert_eq!(
+            first[0],
+            ("query_id".to_string(), "clickgraph-test-0".to_string())
+        );
+        assert_eq!(second[0].1, "clickgraph-test-1");
+        assert_eq!(scope.query_ids.lock().unwrap().len(), 2);
+    }
+}
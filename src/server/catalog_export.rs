@@ -0,0 +1,809 @@
+//! Publishing graph schemas to external data catalogs.
+//!
+//! When `CLICKGRAPH_CATALOG_EXPORT` is set, every schema loaded from YAML (at
+//! startup, on reload, or via `/schemas/load`) is pushed to a DataHub or
+//! OpenMetadata instance in the background. Each node label and edge type
+//! becomes one catalog entity carrying its description, owners and property
+//! columns, with lineage to the ClickHouse table that backs it:
+//!
+//! | Variable | Meaning |
+//! |---|---|
+//! | `CLICKGRAPH_CATALOG_EXPORT` | `datahub` or `openmetadata` (unset = off) |
+//! | `CLICKGRAPH_CATALOG_EXPORT_URL` | DataHub GMS / OpenMetadata server URL |
+//! | `CLICKGRAPH_CATALOG_EXPORT_TOKEN` | Bearer token (optional) |
+//! | `CLICKGRAPH_CATALOG_EXPORT_SOURCE` | Name the ClickHouse tables are catalogued under: DataHub platform instance / OpenMetadata service (optional) |
+//! | `CLICKGRAPH_CATALOG_EXPORT_ENV` | DataHub fabric, default `PROD` |
+//!
+//! Export failures are logged and never fail the schema load.
+
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use reqwest::Method;
+use serde_json::{json, Value};
+
+use super::graph_catalog::DEFAULT_GRAPH;
+use crate::graph_catalog::config::{EdgeDefinition, GraphSchemaConfig, Identifier};
+use crate::graph_catalog::schema_types::SchemaType;
+
+/// Data platform / service the graph layer is registered under.
+const GRAPH_PLATFORM: &str = "clickgraph";
+
+/// Per-request timeout for catalog API calls.
+const CATALOG_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Which catalog API to speak.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogKind {
+    DataHub,
+    OpenMetadata,
+}
+
+impl FromStr for CatalogKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "datahub" => Ok(CatalogKind::DataHub),
+            "openmetadata" => Ok(CatalogKind::OpenMetadata),
+            other => Err(format!(
+                "Unknown catalog '{}'. Supported: datahub, openmetadata",
+                other
+            )),
+        }
+    }
+}
+
+/// Where and how to publish schemas.
+#[derive(Debug, Clone)]
+pub struct CatalogExportConfig {
+    pub kind: CatalogKind,
+    /// Server root, without a trailing slash
+    pub url: String,
+    pub token: Option<String>,
+    /// Name the underlying ClickHouse tables are catalogued under
+    pub source: Option<String>,
+    /// DataHub fabric type (`PROD`, `DEV`, ...)
+    pub env: String,
+}
+
+impl CatalogExportConfig {
+    /// Read the `CLICKGRAPH_CATALOG_EXPORT*` variables; `Ok(None)` when export is off.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        let Some(kind) = non_empty_env("CLICKGRAPH_CATALOG_EXPORT") else {
+            return Ok(None);
+        };
+        let kind = kind.parse()?;
+        let url = non_empty_env("CLICKGRAPH_CATALOG_EXPORT_URL")
+            .ok_or("CLICKGRAPH_CATALOG_EXPORT is set but CLICKGRAPH_CATALOG_EXPORT_URL is not")?;
+        Ok(Some(Self {
+            kind,
+            url: url.trim_end_matches('/').to_string(),
+            token: non_empty_env("CLICKGRAPH_CATALOG_EXPORT_TOKEN"),
+            source: non_empty_env("CLICKGRAPH_CATALOG_EXPORT_SOURCE"),
+            env: non_empty_env("CLICKGRAPH_CATALOG_EXPORT_ENV")
+                .unwrap_or_else(|| "PROD".to_string()),
+        }))
+    }
+}
+
+fn non_empty_env(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|v| !v.trim().is_empty())
+}
+
+/// Export settings, read from the environment on first use.
+fn export_config() -> Option<&'static CatalogExportConfig> {
+    static CONFIG: OnceLock<Option<CatalogExportConfig>> = OnceLock::new();
+    CONFIG
+        .get_or_init(|| {
+            CatalogExportConfig::from_env().unwrap_or_else(|e| {
+                log::warn!("Catalog export disabled: {}", e);
+                None
+            })
+        })
+        .as_ref()
+}
+
+/// One node label or edge type as a catalog entity.
+#[derive(Debug, Clone, PartialEq)]
+struct CatalogEntity {
+    name: String,
+    is_edge: bool,
+    database: String,
+    table: String,
+    description: Option<String>,
+    owners: Vec<String>,
+    /// Property columns, sorted by name
+    columns: Vec<(String, SchemaType)>,
+    /// Extra facts shown as custom properties (id columns, endpoints)
+    facts: BTreeMap<String, String>,
+}
+
+/// One HTTP call against the catalog API.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogRequest {
+    pub method: Method,
+    /// Path below the configured server URL
+    pub path: String,
+    pub body: Option<Value>,
+}
+
+impl CatalogRequest {
+    fn post(path: impl Into<String>, body: Value) -> Self {
+        Self {
+            method: Method::POST,
+            path: path.into(),
+            body: Some(body),
+        }
+    }
+
+    fn put(path: impl Into<String>, body: Option<Value>) -> Self {
+        Self {
+            method: Method::PUT,
+            path: path.into(),
+            body,
+        }
+    }
+}
+
+/// Property columns of a mapping: every mapped property plus the id
+/// properties, typed from `property_types` (String when undeclared).
+fn entity_columns(
+    properties: &HashMap<String, String>,
+    ids: &[&Identifier],
+    property_types: &HashMap<String, String>,
+) -> Vec<(String, SchemaType)> {
+    let mut names: Vec<&str> = properties.keys().map(String::as_str).collect();
+    names.extend(ids.iter().flat_map(|id| id.columns()));
+    names.sort_unstable();
+    names.dedup();
+    names
+        .into_iter()
+        .map(|name| {
+            let ty = property_types
+                .get(name)
+                .and_then(|t| t.parse().ok())
+                .unwrap_or(SchemaType::String);
+            (name.to_string(), ty)
+        })
+        .collect()
+}
+
+fn entities(config: &GraphSchemaConfig) -> Vec<CatalogEntity> {
+    let schema = &config.graph_schema;
+    let mut entities = Vec::new();
+
+    for node in &schema.nodes {
+        entities.push(CatalogEntity {
+            name: node.label.clone(),
+            is_edge: false,
+            database: node.database.clone(),
+            table: node.table.clone(),
+            description: node.description.clone(),
+            owners: node.owners.clone(),
+            columns: entity_columns(&node.properties, &[&node.node_id], &node.property_types),
+            facts: BTreeMap::from([("node_id".to_string(), node.node_id.columns().join(","))]),
+        });
+    }
+
+    for edge in &schema.edges {
+        match edge {
+            EdgeDefinition::Standard(edge) => entities.push(CatalogEntity {
+                name: edge.type_name.clone(),
+                is_edge: true,
+                database: edge.database.clone(),
+                table: edge.table.clone(),
+                description: edge.description.clone(),
+                owners: edge.owners.clone(),
+                columns: entity_columns(&edge.properties, &[], &edge.property_types),
+                facts: BTreeMap::from([
+                    ("from_node".to_string(), edge.from_node.clone()),
+                    ("to_node".to_string(), edge.to_node.clone()),
+                    ("from_id".to_string(), edge.from_id.columns().join(",")),
+                    ("to_id".to_string(), edge.to_id.columns().join(",")),
+                ]),
+            }),
+            EdgeDefinition::Polymorphic(edge) => {
+                for type_name in &edge.type_values {
+                    entities.push(CatalogEntity {
+                        name: type_name.clone(),
+                        is_edge: true,
+                        database: edge.database.clone(),
+                        table: edge.table.clone(),
+                        description: edge.description.clone(),
+                        owners: edge.owners.clone(),
+                        columns: entity_columns(&edge.properties, &[], &HashMap::new()),
+                        facts: BTreeMap::from([
+                            ("from_id".to_string(), edge.from_id.columns().join(",")),
+                            ("to_id".to_string(), edge.to_id.columns().join(",")),
+                        ]),
+                    });
+                }
+            }
+        }
+    }
+
+    entities
+}
+
+// ---------------------------------------------------------------------------
+// DataHub
+// ---------------------------------------------------------------------------
+
+fn datahub_dataset_urn(platform: &str, name: &str, env: &str) -> String {
+    format!(
+        "urn:li:dataset:(urn:li:dataPlatform:{},{},{})",
+        platform, name, env
+    )
+}
+
+fn datahub_owner_urn(owner: &str) -> String {
+    if owner.starts_with("urn:") {
+        owner.to_string()
+    } else {
+        format!("urn:li:corpuser:{}", owner)
+    }
+}
+
+fn datahub_field_type(ty: &SchemaType) -> &'static str {
+    match ty {
+        SchemaType::Integer | SchemaType::Float => "com.linkedin.schema.NumberType",
+        SchemaType::Boolean => "com.linkedin.schema.BooleanType",
+        SchemaType::DateTime => "com.linkedin.schema.TimeType",
+        SchemaType::Date => "com.linkedin.schema.DateType",
+        SchemaType::String | SchemaType::Uuid => "com.linkedin.schema.StringType",
+    }
+}
+
+/// `ingestProposal` calls upserting every entity of `schema_name`.
+fn datahub_requests(
+    schema_name: &str,
+    config: &GraphSchemaConfig,
+    target: &CatalogExportConfig,
+) -> Vec<CatalogRequest> {
+    let mut requests = Vec::new();
+    let mut propose = |urn: &str, aspect_name: &str, aspect: Value| {
+        requests.push(CatalogRequest::post(
+            "/aspects?action=ingestProposal",
+            json!({
+                "proposal": {
+                    "entityType": "dataset",
+                    "entityUrn": urn,
+                    "changeType": "UPSERT",
+                    "aspectName": aspect_name,
+                    "aspect": {
+                        "contentType": "application/json",
+                        "value": aspect.to_string(),
+                    },
+                }
+            }),
+        ));
+    };
+
+    for entity in entities(config) {
+        let qualified = format!("{}.{}", schema_name, entity.name);
+        let urn = datahub_dataset_urn(GRAPH_PLATFORM, &qualified, &target.env);
+        let source_name = match &target.source {
+            Some(instance) => format!("{}.{}.{}", instance, entity.database, entity.table),
+            None => format!("{}.{}", entity.database, entity.table),
+        };
+        let upstream = datahub_dataset_urn("clickhouse", &source_name, &target.env);
+
+        let mut custom = entity.facts.clone();
+        custom.insert("graph".to_string(), schema_name.to_string());
+        custom.insert(
+            "table".to_string(),
+            format!("{}.{}", entity.database, entity.table),
+        );
+        let mut properties = json!({ "name": entity.name, "customProperties": custom });
+        if let Some(description) = &entity.description {
+            properties["description"] = json!(description);
+        }
+        propose(&urn, "datasetProperties", properties);
+
+        let sub_type = if entity.is_edge {
+            "Edge Type"
+        } else {
+            "Node Label"
+        };
+        propose(&urn, "subTypes", json!({ "typeNames": [sub_type] }));
+
+        let fields: Vec<Value> = entity
+            .columns
+            .iter()
+            .map(|(name, ty)| {
+                json!({
+                    "fieldPath": name,
+                    "nativeDataType": ty.as_str(),
+                    "type": { "type": { datahub_field_type(ty): {} } },
+                    "nullable": true,
+                })
+            })
+            .collect();
+        propose(
+            &urn,
+            "schemaMetadata",
+            json!({
+                "schemaName": qualified,
+                "platform": format!("urn:li:dataPlatform:{}", GRAPH_PLATFORM),
+                "version": 0,
+                "hash": "",
+                "platformSchema": { "com.linkedin.schema.OtherSchema": { "rawSchema": "" } },
+                "fields": fields,
+            }),
+        );
+
+        propose(
+            &urn,
+            "upstreamLineage",
+            json!({
+                "upstreams": [{
+                    "dataset": upstream,
+                    "type": "VIEW",
+                    "auditStamp": { "time": 0, "actor": "urn:li:corpuser:datahub" },
+                }]
+            }),
+        );
+
+        if !entity.owners.is_empty() {
+            let owners: Vec<Value> = entity
+                .owners
+                .iter()
+                .map(|o| json!({ "owner": datahub_owner_urn(o), "type": "DATAOWNER" }))
+                .collect();
+            propose(&urn, "ownership", json!({ "owners": owners }));
+        }
+    }
+
+    requests
+}
+
+// ---------------------------------------------------------------------------
+// OpenMetadata
+// ---------------------------------------------------------------------------
+
+fn openmetadata_data_type(ty: &SchemaType) -> &'static str {
+    match ty {
+        SchemaType::Integer => "BIGINT",
+        SchemaType::Float => "DOUBLE",
+        SchemaType::String => "STRING",
+        SchemaType::Boolean => "BOOLEAN",
+        SchemaType::DateTime => "TIMESTAMP",
+        SchemaType::Date => "DATE",
+        SchemaType::Uuid => "UUID",
+    }
+}
+
+/// Calls creating the service / database / schemas, one table per entity
+/// and table-to-table lineage. The graph is the database `schema_name` of
+/// the `clickgraph` service, with `nodes` and `edges` database schemas.
+///
+/// `owner_refs` maps owner names to resolved entity references; owners
+/// missing from it are dropped.
+fn openmetadata_requests(
+    schema_name: &str,
+    config: &GraphSchemaConfig,
+    target: &CatalogExportConfig,
+    owner_refs: &HashMap<String, Value>,
+) -> Vec<CatalogRequest> {
+    let database_fqn = format!("{}.{}", GRAPH_PLATFORM, schema_name);
+    let mut requests = vec![
+        CatalogRequest::put(
+            "/api/v1/services/databaseServices",
+            Some(json!({ "name": GRAPH_PLATFORM, "serviceType": "CustomDatabase" })),
+        ),
+        CatalogRequest::put(
+            "/api/v1/databases",
+            Some(json!({ "name": schema_name, "service": GRAPH_PLATFORM })),
+        ),
+    ];
+    for group in ["nodes", "edges"] {
+        requests.push(CatalogRequest::put(
+            "/api/v1/databaseSchemas",
+            Some(json!({ "name": group, "database": database_fqn })),
+        ));
+    }
+
+    for entity in entities(config) {
+        let group = if entity.is_edge { "edges" } else { "nodes" };
+        let columns: Vec<Value> = entity
+            .columns
+            .iter()
+            .map(|(name, ty)| json!({ "name": name, "dataType": openmetadata_data_type(ty) }))
+            .collect();
+        let owners: Vec<&Value> = entity
+            .owners
+            .iter()
+            .filter_map(|o| owner_refs.get(o))
+            .collect();
+        let mut table = json!({
+            "name": entity.name,
+            "databaseSchema": format!("{}.{}", database_fqn, group),
+            "tableType": "View",
+            "columns": columns,
+            "extension": entity.facts,
+        });
+        if let Some(description) = &entity.description {
+            table["description"] = json!(description);
+        }
+        if !owners.is_empty() {
+            table["owners"] = json!(owners);
+        }
+        requests.push(CatalogRequest::put("/api/v1/tables", Some(table)));
+
+        // ClickHouse connectors catalog tables as service.default.<db>.<table>.
+        if let Some(service) = &target.source {
+            requests.push(CatalogRequest::put(
+                format!(
+                    "/api/v1/lineage/table/name/{}.default.{}.{}/table/name/{}.{}.{}",
+                    service, entity.database, entity.table, database_fqn, group, entity.name
+                ),
+                None,
+            ));
+        }
+    }
+
+    requests
+}
+
+// ---------------------------------------------------------------------------
+// Publishing
+// ---------------------------------------------------------------------------
+
+struct CatalogClient<'a> {
+    http: reqwest::Client,
+    target: &'a CatalogExportConfig,
+}
+
+impl<'a> CatalogClient<'a> {
+    fn new(target: &'a CatalogExportConfig) -> Result<Self, String> {
+        let http = reqwest::Client::builder()
+            .timeout(CATALOG_REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| format!("Failed to build catalog HTTP client: {}", e))?;
+        Ok(Self { http, target })
+    }
+
+    fn request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
+        let mut builder = self
+            .http
+            .request(method, format!("{}{}", self.target.url, path));
+        if self.target.kind == CatalogKind::DataHub {
+            builder = builder.header("X-RestLi-Protocol-Version", "2.0.0");
+        }
+        if let Some(token) = &self.target.token {
+            builder = builder.bearer_auth(token);
+        }
+        builder
+    }
+
+    async fn send(&self, request: &CatalogRequest) -> Result<(), String> {
+        let mut builder = self.request(request.method.clone(), &request.path);
+        if let Some(body) = &request.body {
+            builder = builder.json(body);
+        }
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| format!("{} {}: {}", request.method, request.path, e))?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            Err(format!(
+                "{} {}: HTTP {} {}",
+                request.method, request.path, status, text
+            ))
+        }
+    }
+
+    /// OpenMetadata entity reference of a user or team named `owner`.
+    async fn resolve_owner(&self, owner: &str) -> Option<Value> {
+        for (kind, collection) in [("user", "users"), ("team", "teams")] {
+            let path = format!("/api/v1/{}/name/{}", collection, owner);
+            let Ok(response) = self.request(Method::GET, &path).send().await else {
+                return None;
+            };
+            if !response.status().is_success() {
+                continue;
+            }
+            let entity: Value = response.json().await.ok()?;
+            let id = entity.get("id")?.clone();
+            return Some(json!({ "id": id, "type": kind }));
+        }
+        None
+    }
+}
+
+/// Publish one schema to the configured catalog.
+///
+/// Every request is attempted; returns the number that succeeded, or the
+/// first error when none did.
+pub async fn publish(
+    schema_name: &str,
+    config: &GraphSchemaConfig,
+    target: &CatalogExportConfig,
+) -> Result<usize, String> {
+    let client = CatalogClient::new(target)?;
+    let requests = match target.kind {
+        CatalogKind::DataHub => datahub_requests(schema_name, config, target),
+        CatalogKind::OpenMetadata => {
+            let mut owner_refs = HashMap::new();
+            for owner in entities(config).into_iter().flat_map(|e| e.owners) {
+                if owner_refs.contains_key(&owner) {
+                    continue;
+                }
+                match client.resolve_owner(&owner).await {
+                    Some(reference) => {
+                        owner_refs.insert(owner, reference);
+                    }
+                    None => log::warn!(
+                        "Catalog export: no OpenMetadata user or team named '{}'",
+                        owner
+                    ),
+                }
+            }
+            openmetadata_requests(schema_name, config, target, &owner_refs)
+        }
+    };
+
+    let mut sent = 0;
+    let mut first_error = None;
+    for request in &requests {
+        match client.send(request).await {
+            Ok(()) => sent += 1,
+            Err(e) => {
+                log::debug!("Catalog export request failed: {}", e);
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    match first_error {
+        Some(e) if sent == 0 => Err(e),
+        Some(e) => {
+            log::warn!(
+                "Catalog export of '{}': {}/{} requests failed, first: {}",
+                schema_name,
+                requests.len() - sent,
+                requests.len(),
+                e
+            );
+            Ok(sent)
+        }
+        None => Ok(sent),
+    }
+}
+
+/// Publish freshly loaded schemas in the background when export is on.
+///
+/// The `default` alias of another listed schema is not published twice.
+pub fn publish_in_background(schemas: Vec<(String, GraphSchemaConfig)>) {
+    let Some(target) = export_config() else {
+        return;
+    };
+    let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+        return;
+    };
+    let aliased = schemas.len() > 1;
+    let schemas: Vec<_> = schemas
+        .into_iter()
+        .filter(|(name, _)| !(aliased && name == DEFAULT_GRAPH))
+        .collect();
+
+    runtime.spawn(async move {
+        for (schema_name, config) in schemas {
+            match publish(&schema_name, &config, target).await {
+                Ok(sent) => log::info!(
+                    "✓ Published schema '{}' to {:?} ({} requests)",
+                    schema_name,
+                    target.kind,
+                    sent
+                ),
+                Err(e) => log::warn!(
+                    "✗ Failed to publish schema '{}' to {:?}: {}",
+                    schema_name,
+                    target.kind,
+                    e
+                ),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: brahmand
+      table: users
+      node_id: user_id
+      description: Registered users
+      owners: [alice, "urn:li:corpGroup:growth"]
+      property_mappings:
+        name: full_name
+      property_types:
+        user_id: integer
+  edges:
+    - type: FOLLOWS
+      database: brahmand
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings:
+        since: created_at
+"#;
+
+    fn config() -> GraphSchemaConfig {
+        serde_yaml::from_str(YAML).unwrap()
+    }
+
+    fn target(kind: CatalogKind, source: Option<&str>) -> CatalogExportConfig {
+        CatalogExportConfig {
+            kind,
+            url: "http://catalog:8080".to_string(),
+            token: None,
+            source: source.map(str::to_string),
+            env: "PROD".to_string(),
+        }
+    }
+
+    /// (entityUrn, aspectName, aspect) of each DataHub proposal
+    fn proposals(requests: &[CatalogRequest]) -> Vec<(String, String, Value)> {
+        requests
+            .iter()
+            .map(|r| {
+                let p = &r.body.as_ref().unwrap()["proposal"];
+                let aspect = serde_json::from_str(p["aspect"]["value"].as_str().unwrap()).unwrap();
+                (
+                    p["entityUrn"].as_str().unwrap().to_string(),
+                    p["aspectName"].as_str().unwrap().to_string(),
+                    aspect,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_entities_from_config() {
+        let entities = entities(&config());
+        assert_eq!(entities.len(), 2);
+        let user = &entities[0];
+        assert_eq!(user.description.as_deref(), Some("Registered users"));
+        assert_eq!(
+            user.columns,
+            vec![
+                ("name".to_string(), SchemaType::String),
+                ("user_id".to_string(), SchemaType::Integer),
+            ]
+        );
+        let follows = &entities[1];
+        assert!(follows.is_edge);
+        assert_eq!(follows.facts["from_node"], "User");
+        assert!(follows.owners.is_empty());
+    }
+
+    #[test]
+    fn test_datahub_proposals() {
+        let requests = datahub_requests("social", &config(), &target(CatalogKind::DataHub, None));
+        assert!(requests
+            .iter()
+            .all(|r| r.method == Method::POST && r.path == "/aspects?action=ingestProposal"));
+
+        let proposals = proposals(&requests);
+        let user_urn = "urn:li:dataset:(urn:li:dataPlatform:clickgraph,social.User,PROD)";
+        let aspect = |urn: &str, name: &str| {
+            proposals
+                .iter()
+                .find(|(u, n, _)| u == urn && n == name)
+                .map(|(_, _, a)| a.clone())
+        };
+
+        let props = aspect(user_urn, "datasetProperties").unwrap();
+        assert_eq!(props["description"], "Registered users");
+        assert_eq!(props["customProperties"]["table"], "brahmand.users");
+
+        let lineage = aspect(user_urn, "upstreamLineage").unwrap();
+        assert_eq!(
+            lineage["upstreams"][0]["dataset"],
+            "urn:li:dataset:(urn:li:dataPlatform:clickhouse,brahmand.users,PROD)"
+        );
+
+        let ownership = aspect(user_urn, "ownership").unwrap();
+        assert_eq!(ownership["owners"][0]["owner"], "urn:li:corpuser:alice");
+        assert_eq!(ownership["owners"][1]["owner"], "urn:li:corpGroup:growth");
+
+        let schema = aspect(user_urn, "schemaMetadata").unwrap();
+        assert_eq!(schema["fields"][1]["fieldPath"], "user_id");
+        assert!(schema["fields"][1]["type"]["type"]
+            .get("com.linkedin.schema.NumberType")
+            .is_some());
+
+        // No owners → no ownership aspect
+        let follows_urn = "urn:li:dataset:(urn:li:dataPlatform:clickgraph,social.FOLLOWS,PROD)";
+        assert!(aspect(follows_urn, "ownership").is_none());
+        assert_eq!(
+            aspect(follows_urn, "subTypes").unwrap()["typeNames"][0],
+            "Edge Type"
+        );
+    }
+
+    #[test]
+    fn test_datahub_platform_instance() {
+        let requests = datahub_requests(
+            "social",
+            &config(),
+            &target(CatalogKind::DataHub, Some("ch-prod")),
+        );
+        let lineage = proposals(&requests)
+            .into_iter()
+            .find(|(_, n, _)| n == "upstreamLineage")
+            .unwrap()
+            .2;
+        assert_eq!(
+            lineage["upstreams"][0]["dataset"],
+            "urn:li:dataset:(urn:li:dataPlatform:clickhouse,ch-prod.brahmand.users,PROD)"
+        );
+    }
+
+    #[test]
+    fn test_openmetadata_requests() {
+        let owner_refs =
+            HashMap::from([("alice".to_string(), json!({ "id": "0b6f", "type": "user" }))]);
+        let requests = openmetadata_requests(
+            "social",
+            &config(),
+            &target(CatalogKind::OpenMetadata, Some("clickhouse_prod")),
+            &owner_refs,
+        );
+        let paths: Vec<&str> = requests.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/api/v1/services/databaseServices",
+                "/api/v1/databases",
+                "/api/v1/databaseSchemas",
+                "/api/v1/databaseSchemas",
+                "/api/v1/tables",
+                "/api/v1/lineage/table/name/clickhouse_prod.default.brahmand.users/table/name/clickgraph.social.nodes.User",
+                "/api/v1/tables",
+                "/api/v1/lineage/table/name/clickhouse_prod.default.brahmand.follows/table/name/clickgraph.social.edges.FOLLOWS",
+            ]
+        );
+
+        let user = requests[4].body.as_ref().unwrap();
+        assert_eq!(user["databaseSchema"], "clickgraph.social.nodes");
+        assert_eq!(user["description"], "Registered users");
+        assert_eq!(user["columns"][1]["dataType"], "BIGINT");
+        // The unresolved group owner is dropped
+        assert_eq!(user["owners"], json!([{ "id": "0b6f", "type": "user" }]));
+        assert!(requests[6].body.as_ref().unwrap().get("owners").is_none());
+    }
+
+    #[test]
+    fn test_openmetadata_without_source_skips_lineage() {
+        let requests = openmetadata_requests(
+            "social",
+            &config(),
+            &target(CatalogKind::OpenMetadata, None),
+            &HashMap::new(),
+        );
+        assert!(!requests.iter().any(|r| r.path.contains("/lineage/")));
+    }
+
+    #[test]
+    fn test_catalog_kind_parse() {
+        assert_eq!("DataHub".parse(), Ok(CatalogKind::DataHub));
+        assert_eq!("openmetadata".parse(), Ok(CatalogKind::OpenMetadata));
+        assert!("atlas".parse::<CatalogKind>().is_err());
+    }
+}
@@ -79,3 +79,78 @@ pub async fn set_role(client: &Client, role: &str) -> Result<(), clickhouse::err
     log::debug!("Setting ClickHouse role: {}", role);
     client.query(&format!("SET ROLE {}", role)).execute().await
 }
+
+/// First ClickHouse release with `WITH RECURSIVE`.
+pub const MIN_RECURSIVE_CTE_VERSION: (u32, u32) = (24, 4);
+
+/// Why a ClickHouse server with `version()` = `version` and analyzer setting
+/// `analyzer` (`enable_analyzer`, formerly `allow_experimental_analyzer`)
+/// cannot run recursive CTEs, or `None` when it can. Recursive CTEs exist
+/// from 24.4 and only run under the new analyzer. An unparseable version or
+/// empty setting is given the benefit of the doubt.
+pub fn recursive_cte_unavailable_reason(version: &str, analyzer: &str) -> Option<String> {
+    let mut parts = version.trim().split('.').map(|p| p.parse::<u32>());
+    if let (Some(Ok(major)), Some(Ok(minor))) = (parts.next(), parts.next()) {
+        if (major, minor) < MIN_RECURSIVE_CTE_VERSION {
+            return Some(format!(
+                "ClickHouse {} has no recursive CTEs; they require ClickHouse {}.{} or later",
+                version.trim(),
+                MIN_RECURSIVE_CTE_VERSION.0,
+                MIN_RECURSIVE_CTE_VERSION.1
+            ));
+        }
+    }
+    match analyzer.trim() {
+        "0" | "false" => Some(
+            "recursive CTEs require the new analyzer, which is disabled on this ClickHouse \
+             server; set `enable_analyzer = 1` (`allow_experimental_analyzer = 1` before 24.8)"
+                .to_string(),
+        ),
+        _ => None,
+    }
+}
+
+/// Probe `client`'s server for recursive CTE support; see
+/// [`recursive_cte_unavailable_reason`]. A failed probe assumes support, so a
+/// transient startup error never degrades planning.
+pub async fn detect_recursive_cte_support(client: &Client) -> Option<String> {
+    let probe = client
+        .query(
+            "SELECT version(), (SELECT any(value) FROM system.settings \
+             WHERE name IN ('enable_analyzer', 'allow_experimental_analyzer'))",
+        )
+        .fetch_one::<(String, String)>()
+        .await;
+    match probe {
+        Ok((version, analyzer)) => {
+            log::info!("ClickHouse version {version} (analyzer setting: {analyzer:?})");
+            recursive_cte_unavailable_reason(&version, &analyzer)
+        }
+        Err(e) => {
+            log::warn!("Could not detect ClickHouse version, assuming recursive CTE support: {e}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recursive_ctes_need_24_4_and_the_analyzer() {
+        assert_eq!(recursive_cte_unavailable_reason("25.8.1.1", "1"), None);
+        assert_eq!(recursive_cte_unavailable_reason("24.4.1.2088", ""), None);
+        assert_eq!(recursive_cte_unavailable_reason("not-a-version", "1"), None);
+
+        let old = recursive_cte_unavailable_reason("24.3.2.23", "1").unwrap();
+        assert!(
+            old.contains("ClickHouse 24.3.2.23") && old.contains("24.4"),
+            "{old}"
+        );
+        assert!(recursive_cte_unavailable_reason("23.8.9.54", "1").is_some());
+
+        let no_analyzer = recursive_cte_unavailable_reason("24.8.4.13", "0").unwrap();
+        assert!(no_analyzer.contains("enable_analyzer = 1"), "{no_analyzer}");
+    }
+}
@@ -5,6 +5,13 @@
 //!
 //! When `CLICKHOUSE_CLUSTER` is set, discovers cluster nodes from
 //! `system.clusters` and round-robins queries across them.
+//!
+//! [`RoleConnectionPool::batch_from_env`] builds a second pool for the
+//! endpoint heavy procedures run on (`CLICKHOUSE_BATCH_URL`).
+//!
+//! [`RoleConnectionPool::open_session`] hands out a [`ClickHouseSession`]
+//! instead: one `session_id` pinned to one node, for work that needs
+//! ClickHouse session state (temporary tables, `SET` settings).
 
 use clickhouse::Client;
 use serde::Deserialize;
@@ -32,11 +39,16 @@ struct ConnectionConfig {
     password: String,
     database: String,
     max_cte_depth: u32,
+    /// Idle lifetime of server-side sessions (`CLICKHOUSE_SESSION_TIMEOUT_SECS`)
+    session_timeout: Duration,
 }
 
 /// Timeout for cluster discovery query during startup.
 const CLUSTER_DISCOVERY_TIMEOUT: Duration = Duration::from_secs(10);
 
+/// ClickHouse's own default `session_timeout`, in seconds.
+const DEFAULT_SESSION_TIMEOUT_SECS: u64 = 60;
+
 impl RoleConnectionPool {
     /// Create a new role-based connection pool.
     /// If `CLICKHOUSE_CLUSTER` is set, queries the seed node to discover cluster members.
@@ -51,6 +63,22 @@ impl RoleConnectionPool {
         Self::from_config(config)
     }
 
+    /// Pool for the batch endpoint (`CLICKHOUSE_BATCH_URL`, see
+    /// `server::endpoint_routing`), or `None` when it is not configured.
+    /// User, password and database default to the interactive endpoint's;
+    /// `CLICKHOUSE_BATCH_CLUSTER` discovers its nodes like
+    /// `CLICKHOUSE_CLUSTER`.
+    pub async fn batch_from_env(max_cte_depth: u32) -> Result<Option<Self>, String> {
+        let Some(mut config) = ConnectionConfig::batch_from_env(max_cte_depth)? else {
+            return Ok(None);
+        };
+        log::info!("Batch endpoint for heavy procedures: {}", config.urls[0]);
+        if config.cluster_name.is_some() {
+            config.discover_cluster_nodes().await;
+        }
+        Self::from_config(config).map(Some)
+    }
+
     /// Create a new role-based connection pool with explicit connection parameters.
     ///
     /// Unlike `new()`, this does not read from environment variables — all
@@ -70,6 +98,7 @@ impl RoleConnectionPool {
             password: password.to_string(),
             database: database.unwrap_or("default").to_string(),
             max_cte_depth,
+            session_timeout: Duration::from_secs(DEFAULT_SESSION_TIMEOUT_SECS),
         };
 
         if config.cluster_name.is_some() {
@@ -175,6 +204,122 @@ impl RoleConnectionPool {
             options: ConnectionConfig::standard_options(self.base_config.max_cte_depth, role),
         }
     }
+
+    /// Kill the queries with these `query_id`s on every node: a query may
+    /// run on any of them. `ASYNC` returns without waiting for the queries
+    /// to stop. Failures are only logged.
+    pub async fn kill_queries(&self, query_ids: &[String]) {
+        if query_ids.is_empty() {
+            return;
+        }
+        let ids: Vec<String> = query_ids
+            .iter()
+            .map(|id| format!("'{}'", id.replace('\\', "\\\\").replace('\'', "\\'")))
+            .collect();
+        let sql = format!("KILL QUERY WHERE query_id IN ({}) ASYNC", ids.join(", "));
+        for (client, url) in self.default_clients.iter().zip(&self.base_config.urls) {
+            if let Err(e) = client.query(&sql).execute().await {
+                log::warn!("Killing queries on {url} failed: {e}");
+            }
+        }
+    }
+
+    /// Open a server-side session for `role`. Every query sent through the
+    /// returned session carries its `session_id` and goes to one node (picked
+    /// by the same round-robin as `get_client`), so it sees the temporary
+    /// tables and settings of the queries before it.
+    pub fn open_session(&self, role: Option<&str>) -> ClickHouseSession {
+        let idx = self.round_robin.fetch_add(1, Ordering::Relaxed) % self.base_config.urls.len();
+        let id = uuid::Uuid::new_v4().to_string();
+        let timeout = self.base_config.session_timeout;
+        let client = self
+            .base_config
+            .create_client_for_url(&self.base_config.urls[idx], role)
+            .with_option("session_id", id.clone())
+            .with_option("session_timeout", timeout.as_secs().to_string());
+        log::debug!(
+            "Opened ClickHouse session {id} on {}",
+            self.base_config.urls[idx]
+        );
+        ClickHouseSession {
+            id,
+            client,
+            timeout,
+        }
+    }
+}
+
+/// A ClickHouse HTTP session: one `session_id` on one node.
+///
+/// ClickHouse runs one query per session at a time and expires a session
+/// that has been idle for `timeout`; [`close`](Self::close) releases it
+/// sooner.
+#[derive(Clone)]
+pub struct ClickHouseSession {
+    id: String,
+    client: Client,
+    timeout: Duration,
+}
+
+impl ClickHouseSession {
+    /// The `session_id` sent with every query
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Client bound to the session's node and `session_id`
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// How long ClickHouse keeps the session after its last query
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Drop the session's temporary tables and let ClickHouse expire it a
+    /// second later. Failures are only logged: the session expires anyway.
+    pub async fn close(&self) {
+        #[derive(Debug, clickhouse::Row, Deserialize)]
+        struct TempTable {
+            name: String,
+        }
+
+        match self
+            .client
+            .query("SELECT name FROM system.tables WHERE is_temporary")
+            .fetch_all::<TempTable>()
+            .await
+        {
+            Ok(tables) => {
+                for table in tables {
+                    let drop = format!(
+                        "DROP TEMPORARY TABLE IF EXISTS {}",
+                        quote_identifier(&table.name)
+                    );
+                    if let Err(e) = self.client.query(&drop).execute().await {
+                        log::warn!("Session {}: {drop} failed: {e}", self.id);
+                    }
+                }
+            }
+            Err(e) => log::warn!("Session {}: listing temporary tables failed: {e}", self.id),
+        }
+        if let Err(e) = self
+            .client
+            .query("SELECT 1")
+            .with_option("session_timeout", "1")
+            .execute()
+            .await
+        {
+            log::warn!("Session {}: shortening its timeout failed: {e}", self.id);
+        }
+        log::debug!("Closed ClickHouse session {}", self.id);
+    }
+}
+
+/// Backquote a ClickHouse identifier.
+fn quote_identifier(name: &str) -> String {
+    format!("`{}`", name.replace('\\', "\\\\").replace('`', "\\`"))
 }
 
 #[derive(Debug)]
@@ -212,9 +357,32 @@ impl ConnectionConfig {
             // Database is optional - defaults to "default". All queries use fully-qualified table names anyway.
             database: env::var("CLICKHOUSE_DATABASE").unwrap_or_else(|_| "default".to_string()),
             max_cte_depth,
+            session_timeout: Duration::from_secs(
+                env::var("CLICKHOUSE_SESSION_TIMEOUT_SECS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .filter(|&secs| secs > 0)
+                    .unwrap_or(DEFAULT_SESSION_TIMEOUT_SECS),
+            ),
         })
     }
 
+    /// The interactive configuration with the `CLICKHOUSE_BATCH_*` overrides,
+    /// or `None` without `CLICKHOUSE_BATCH_URL`.
+    fn batch_from_env(max_cte_depth: u32) -> Result<Option<Self>, String> {
+        let Ok(url) = env::var("CLICKHOUSE_BATCH_URL") else {
+            return Ok(None);
+        };
+        let interactive = Self::from_env(max_cte_depth)?;
+        Ok(Some(Self {
+            urls: vec![url],
+            cluster_name: env::var("CLICKHOUSE_BATCH_CLUSTER").ok(),
+            user: env::var("CLICKHOUSE_BATCH_USER").unwrap_or(interactive.user),
+            password: env::var("CLICKHOUSE_BATCH_PASSWORD").unwrap_or(interactive.password),
+            ..interactive
+        }))
+    }
+
     /// Discover cluster nodes from `system.clusters` using the seed URL.
     /// Falls back to the seed URL with a warning if discovery fails or returns empty.
     async fn discover_cluster_nodes(&mut self) {
@@ -443,6 +611,39 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_batch_config_env() {
+        unsafe {
+            env::set_var("CLICKHOUSE_URL", "http://localhost:8123");
+            env::set_var("CLICKHOUSE_USER", "test_user");
+            env::set_var("CLICKHOUSE_PASSWORD", "test_pass");
+            env::remove_var("CLICKHOUSE_CLUSTER");
+            env::remove_var("CLICKHOUSE_BATCH_URL");
+        }
+        assert!(ConnectionConfig::batch_from_env(100).unwrap().is_none());
+
+        unsafe {
+            env::set_var("CLICKHOUSE_BATCH_URL", "http://replica:8123");
+            env::set_var("CLICKHOUSE_BATCH_USER", "batch_user");
+        }
+        let config = ConnectionConfig::batch_from_env(100).unwrap().unwrap();
+        assert_eq!(config.urls, vec!["http://replica:8123"]);
+        assert_eq!(config.user, "batch_user");
+        // Unset overrides fall back to the interactive endpoint's
+        assert_eq!(config.password, "test_pass");
+        assert_eq!(
+            config.database,
+            ConnectionConfig::from_env(100).unwrap().database
+        );
+        assert!(config.cluster_name.is_none());
+
+        unsafe {
+            env::remove_var("CLICKHOUSE_BATCH_URL");
+            env::remove_var("CLICKHOUSE_BATCH_USER");
+        }
+    }
+
     #[tokio::test]
     async fn test_new_with_params_single_node() {
         let pool = RoleConnectionPool::new_with_params(
@@ -521,6 +722,56 @@ mod tests {
         assert_eq!(pool.round_robin.load(Ordering::Relaxed), 9);
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_session_timeout_env() {
+        unsafe {
+            env::set_var("CLICKHOUSE_URL", "http://localhost:8123");
+            env::set_var("CLICKHOUSE_USER", "test_user");
+            env::remove_var("CLICKHOUSE_CLUSTER");
+            env::set_var("CLICKHOUSE_SESSION_TIMEOUT_SECS", "15");
+        }
+        let config = ConnectionConfig::from_env(100).unwrap();
+        assert_eq!(config.session_timeout, Duration::from_secs(15));
+
+        // Zero or garbage keeps ClickHouse's default
+        unsafe {
+            env::set_var("CLICKHOUSE_SESSION_TIMEOUT_SECS", "0");
+        }
+        let config = ConnectionConfig::from_env(100).unwrap();
+        assert_eq!(config.session_timeout, Duration::from_secs(60));
+        unsafe {
+            env::remove_var("CLICKHOUSE_SESSION_TIMEOUT_SECS");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_open_session_ids_are_unique() {
+        let pool = RoleConnectionPool::new_with_params(
+            "http://localhost:8123",
+            "test_user",
+            "test_pass",
+            None,
+            None,
+            100,
+        )
+        .await
+        .unwrap();
+        let a = pool.open_session(None);
+        let b = pool.open_session(Some("analyst"));
+        assert_ne!(a.id(), b.id());
+        assert_eq!(
+            a.timeout(),
+            Duration::from_secs(DEFAULT_SESSION_TIMEOUT_SECS)
+        );
+    }
+
+    #[test]
+    fn test_quote_identifier() {
+        assert_eq!(quote_identifier("tmp_paths"), "`tmp_paths`");
+        assert_eq!(quote_identifier("a`b"), "`a\\`b`");
+    }
+
     // --- build_cluster_urls tests ---
 
     #[test]
@@ -0,0 +1,301 @@
+//! Byte-budgeted `/query` responses (`max_response_bytes`).
+//!
+//! A read query with `max_response_bytes` set returns as many whole rows as
+//! fit in the budget — measured on the serialized `{"results": [...]}` body —
+//! and, when rows were left out, a `continuation_token`. Sending the same
+//! request again with that token returns the next page.
+//!
+//! The token is opaque to clients: base64url of a format version, a
+//! fingerprint of the request (query text, schema, tenant, role and
+//! parameters) and the row offset to resume from. The query is re-run for
+//! each page, so pages are consistent only as far as the underlying data and
+//! the query's row order are stable — add `ORDER BY` for deterministic
+//! paging. A token presented with a different request is rejected.
+
+use std::collections::{BTreeMap, HashMap};
+
+use axum::http::StatusCode;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::models::QueryRequest;
+
+const TOKEN_VERSION: &str = "v1";
+
+/// `{"results":[]}` — the body around the rows.
+const ENVELOPE_BYTES: usize = r#"{"results":[]}"#.len();
+
+/// `,"continuation_token":""` plus the longest token we can emit.
+const TOKEN_FIELD_BYTES: usize = r#","continuation_token":"""#.len() + MAX_TOKEN_BYTES;
+
+/// Version, 16 hex fingerprint digits and a `usize` offset, base64url-encoded.
+const MAX_TOKEN_BYTES: usize = (TOKEN_VERSION.len() + 2 + 16 + 20).div_ceil(3) * 4;
+
+/// Paging requested for one `/query` statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Paging {
+    /// Rows already returned by earlier pages.
+    pub offset: usize,
+    pub max_bytes: Option<usize>,
+    fingerprint: String,
+}
+
+/// One page of rows and the token for the next, if any.
+#[derive(Debug)]
+pub(crate) struct Page {
+    pub rows: Vec<Value>,
+    pub continuation_token: Option<String>,
+}
+
+impl Paging {
+    /// Read `max_response_bytes` / `continuation_token` from a request;
+    /// `None` when neither is set.
+    pub fn from_request(payload: &QueryRequest) -> Result<Option<Self>, (StatusCode, String)> {
+        if payload.max_response_bytes.is_none() && payload.continuation_token.is_none() {
+            return Ok(None);
+        }
+        let fingerprint = request_fingerprint(payload);
+        let offset = match &payload.continuation_token {
+            Some(token) => decode_token(token, &fingerprint)?,
+            None => 0,
+        };
+        Ok(Some(Paging {
+            offset,
+            max_bytes: payload.max_response_bytes,
+            fingerprint,
+        }))
+    }
+
+    /// Skip the rows of earlier pages and keep as many of the rest as fit.
+    pub fn page(&self, rows: Vec<Value>) -> Result<Page, (StatusCode, String)> {
+        let remaining: Vec<Value> = rows.into_iter().skip(self.offset).collect();
+        let Some(max_bytes) = self.max_bytes else {
+            return Ok(Page {
+                rows: remaining,
+                continuation_token: None,
+            });
+        };
+
+        let sizes = remaining
+            .iter()
+            .map(|row| serde_json::to_vec(row).map(|bytes| bytes.len()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Failed to serialize result row: {}", e),
+                )
+            })?;
+
+        // Everything left fits: last page, no token needed
+        if rows_fitting(&sizes, max_bytes.saturating_sub(ENVELOPE_BYTES)) == sizes.len() {
+            return Ok(Page {
+                rows: remaining,
+                continuation_token: None,
+            });
+        }
+
+        let budget = max_bytes.saturating_sub(ENVELOPE_BYTES + TOKEN_FIELD_BYTES);
+        let count = rows_fitting(&sizes, budget);
+        if count == 0 {
+            return Err((
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!(
+                    "max_response_bytes {} is too small for the next row ({} bytes) and a continuation token",
+                    max_bytes,
+                    sizes[0] + ENVELOPE_BYTES + TOKEN_FIELD_BYTES
+                ),
+            ));
+        }
+
+        let mut remaining = remaining;
+        remaining.truncate(count);
+        Ok(Page {
+            rows: remaining,
+            continuation_token: Some(encode_token(&self.fingerprint, self.offset + count)),
+        })
+    }
+}
+
+/// Number of leading rows whose comma-separated JSON fits in `budget` bytes.
+fn rows_fitting(sizes: &[usize], budget: usize) -> usize {
+    let mut used = 0;
+    for (i, size) in sizes.iter().enumerate() {
+        let needed = size + usize::from(i > 0);
+        if used + needed > budget {
+            return i;
+        }
+        used += needed;
+    }
+    sizes.len()
+}
+
+/// Hash of everything that decides a request's rows, so a token cannot be
+/// replayed against a different query.
+fn request_fingerprint(payload: &QueryRequest) -> String {
+    fn sorted(map: &Option<HashMap<String, Value>>) -> Option<BTreeMap<&String, &Value>> {
+        map.as_ref().map(|m| m.iter().collect())
+    }
+
+    let identity = serde_json::json!({
+        "query": payload.query,
+        "schema_name": payload.schema_name,
+        "tenant_id": payload.tenant_id,
+        "role": payload.role,
+        "parameters": sorted(&payload.parameters),
+        "view_parameters": sorted(&payload.view_parameters),
+    });
+    let digest = Sha256::digest(identity.to_string().as_bytes());
+    hex::encode(&digest[..8])
+}
+
+fn encode_token(fingerprint: &str, offset: usize) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{}:{}:{}", TOKEN_VERSION, fingerprint, offset))
+}
+
+fn decode_token(token: &str, fingerprint: &str) -> Result<usize, (StatusCode, String)> {
+    let invalid = || {
+        (
+            StatusCode::BAD_REQUEST,
+            "Invalid continuation_token".to_string(),
+        )
+    };
+    let decoded = URL_SAFE_NO_PAD
+        .decode(token)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .ok_or_else(invalid)?;
+    let mut parts = decoded.splitn(3, ':');
+    let (Some(TOKEN_VERSION), Some(token_fingerprint), Some(offset)) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+    let offset = offset.parse::<usize>().map_err(|_| invalid())?;
+    if token_fingerprint != fingerprint {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "continuation_token was issued for a different query".to_string(),
+        ));
+    }
+    Ok(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(body: Value) -> QueryRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    fn rows(n: usize) -> Vec<Value> {
+        (0..n).map(|i| json!({ "n": i })).collect()
+    }
+
+    fn body_len(page: &Page) -> usize {
+        let mut body = json!({ "results": page.rows });
+        if let Some(token) = &page.continuation_token {
+            body["continuation_token"] = json!(token);
+        }
+        body.to_string().len()
+    }
+
+    #[test]
+    fn no_paging_fields_means_no_paging() {
+        let payload = request(json!({ "query": "MATCH (n) RETURN n" }));
+        assert_eq!(Paging::from_request(&payload).unwrap(), None);
+    }
+
+    #[test]
+    fn pages_stay_within_budget_and_cover_all_rows() {
+        let mut payload = request(json!({
+            "query": "MATCH (n) RETURN n.x AS n ORDER BY n",
+            "max_response_bytes": 150,
+        }));
+        let all = rows(30);
+        let mut seen = Vec::new();
+        loop {
+            let paging = Paging::from_request(&payload).unwrap().unwrap();
+            let page = paging.page(all.clone()).unwrap();
+            assert!(body_len(&page) <= 150, "page of {} bytes", body_len(&page));
+            seen.extend(page.rows.clone());
+            match page.continuation_token {
+                Some(token) => payload.continuation_token = Some(token),
+                None => break,
+            }
+        }
+        assert_eq!(seen, all);
+    }
+
+    #[test]
+    fn last_page_fits_without_token_reservation() {
+        let payload = request(json!({ "query": "RETURN 1", "max_response_bytes": 30 }));
+        let page = Paging::from_request(&payload)
+            .unwrap()
+            .unwrap()
+            .page(rows(1))
+            .unwrap();
+        assert_eq!(page.rows.len(), 1);
+        assert!(page.continuation_token.is_none());
+    }
+
+    #[test]
+    fn row_larger_than_budget_is_an_error() {
+        let payload = request(json!({ "query": "RETURN 1", "max_response_bytes": 40 }));
+        let err = Paging::from_request(&payload)
+            .unwrap()
+            .unwrap()
+            .page(rows(5))
+            .unwrap_err();
+        assert_eq!(err.0, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[test]
+    fn token_is_bound_to_the_request() {
+        let token = encode_token(
+            &request_fingerprint(&request(json!({
+                "query": "MATCH (n) RETURN n",
+                "parameters": { "a": 1, "b": 2 },
+            }))),
+            10,
+        );
+
+        // Parameter order does not matter
+        let same = request(json!({
+            "query": "MATCH (n) RETURN n",
+            "parameters": { "b": 2, "a": 1 },
+            "continuation_token": token,
+        }));
+        assert_eq!(Paging::from_request(&same).unwrap().unwrap().offset, 10);
+
+        let other = request(json!({
+            "query": "MATCH (n) RETURN n",
+            "parameters": { "a": 1, "b": 3 },
+            "continuation_token": token,
+        }));
+        let err = Paging::from_request(&other).unwrap_err();
+        assert_eq!(err.0, StatusCode::BAD_REQUEST);
+        assert!(err.1.contains("different query"));
+    }
+
+    #[test]
+    fn malformed_token_is_rejected() {
+        let payload = request(json!({ "query": "RETURN 1", "continuation_token": "not a token" }));
+        let err = Paging::from_request(&payload).unwrap_err();
+        assert_eq!(
+            err,
+            (
+                StatusCode::BAD_REQUEST,
+                "Invalid continuation_token".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn max_token_length_covers_largest_offset() {
+        assert!(encode_token(&"f".repeat(16), usize::MAX).len() <= MAX_TOKEN_BYTES);
+    }
+}
@@ -0,0 +1,50 @@
+//! Routing of heavy procedures to a separate ClickHouse endpoint.
+//!
+//! Graph algorithms, exports and sampling scan whole tables and can keep a
+//! node busy for minutes. With `CLICKHOUSE_BATCH_URL` set, the queries they
+//! issue go to that endpoint (a read replica, or a cluster sized for batch
+//! work) and interactive `MATCH` traffic keeps the low-latency endpoint to
+//! itself. A procedure runs its queries inside [`batch`]; the remote
+//! executor reads [`current`] to pick its connection pool.
+
+use std::future::Future;
+
+tokio::task_local! {
+    /// The endpoint of the statement running in [`batch`].
+    static ENDPOINT: Endpoint;
+}
+
+/// Which ClickHouse endpoint a query goes to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endpoint {
+    /// `CLICKHOUSE_URL`: `MATCH` queries and everything else
+    Interactive,
+    /// `CLICKHOUSE_BATCH_URL`, or the interactive endpoint when unset
+    Batch,
+}
+
+/// Run the procedure `f` with its ClickHouse queries sent to the batch
+/// endpoint.
+pub async fn batch<F: Future>(f: F) -> F::Output {
+    ENDPOINT.scope(Endpoint::Batch, f).await
+}
+
+/// Endpoint for the next query of the current statement. Interactive
+/// outside [`batch`].
+pub fn current() -> Endpoint {
+    ENDPOINT
+        .try_with(|endpoint| *endpoint)
+        .unwrap_or(Endpoint::Interactive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn batch_scope_selects_the_batch_endpoint() {
+        assert_eq!(current(), Endpoint::Interactive);
+        assert_eq!(batch(async { current() }).await, Endpoint::Batch);
+        assert_eq!(current(), Endpoint::Interactive);
+    }
+}
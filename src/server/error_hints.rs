@@ -0,0 +1,192 @@
+//! Actionable suggestions for common ClickHouse failures.
+//!
+//! ClickHouse exceptions end in the error's name (`... (MEMORY_LIMIT_EXCEEDED)`)
+//! and start with its number (`Code: 241.`). For the handful that users can
+//! fix themselves, `/query` answers with a JSON body that names the error and
+//! says what to change instead of only echoing the exception:
+//!
+//! ```json
+//! {"error": "Executor error: Code: 241. ...", "error_code": "MEMORY_LIMIT_EXCEEDED",
+//!  "clickhouse_code": 241, "suggestion": "Narrow the pattern ..."}
+//! ```
+
+use serde_json::{json, Value};
+
+use super::recursion_depth;
+
+/// A ClickHouse error with a known remedy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnownError {
+    MemoryLimitExceeded,
+    TooDeepRecursion,
+    UnknownIdentifier,
+    TypeMismatch,
+}
+
+impl KnownError {
+    const ALL: [KnownError; 4] = [
+        KnownError::MemoryLimitExceeded,
+        KnownError::TooDeepRecursion,
+        KnownError::UnknownIdentifier,
+        KnownError::TypeMismatch,
+    ];
+
+    /// ClickHouse's name for the error.
+    pub fn name(self) -> &'static str {
+        match self {
+            KnownError::MemoryLimitExceeded => "MEMORY_LIMIT_EXCEEDED",
+            KnownError::TooDeepRecursion => "TOO_DEEP_RECURSION",
+            KnownError::UnknownIdentifier => "UNKNOWN_IDENTIFIER",
+            KnownError::TypeMismatch => "TYPE_MISMATCH",
+        }
+    }
+
+    /// ClickHouse's numeric error code.
+    pub fn code(self) -> u32 {
+        match self {
+            KnownError::MemoryLimitExceeded => 241,
+            KnownError::TooDeepRecursion => 306,
+            KnownError::UnknownIdentifier => 47,
+            KnownError::TypeMismatch => 53,
+        }
+    }
+
+    /// What the user can change so the query goes through.
+    pub fn suggestion(self, message: &str, max_cte_depth: u32) -> String {
+        match self {
+            KnownError::MemoryLimitExceeded => {
+                "Narrow the pattern with a WHERE filter or LIMIT, give variable-length \
+                 patterns a smaller upper bound, or raise ClickHouse's max_memory_usage \
+                 for the query's user"
+                    .to_string()
+            }
+            KnownError::TooDeepRecursion => format!(
+                "Add an upper bound to the variable-length pattern (e.g. *1..5) or raise \
+                 --max-cte-depth (CLICKGRAPH_MAX_CTE_DEPTH, currently {max_cte_depth})"
+            ),
+            KnownError::UnknownIdentifier => {
+                let subject = match unknown_identifier(message) {
+                    Some(name) => format!("`{name}` does"),
+                    None => "A column of the generated SQL does".to_string(),
+                };
+                format!(
+                    "{subject} not exist in the table it is read from. Check the property \
+                     name in the query and the schema's property_mappings against the \
+                     ClickHouse table's columns"
+                )
+            }
+            KnownError::TypeMismatch => {
+                "A value's type does not match the column it is compared with or stored \
+                 in. Check the types of literals and parameters (e.g. 42 rather than '42') \
+                 against the property's column type"
+                    .to_string()
+            }
+        }
+    }
+}
+
+/// The known ClickHouse error an executor message reports, if any.
+pub fn classify(message: &str) -> Option<KnownError> {
+    let name = error_name(message);
+    let code = error_code(message);
+    KnownError::ALL
+        .into_iter()
+        .find(|known| name == Some(known.name()) || code == Some(known.code()))
+        // The recursion valve replaces the exception with its own message
+        .or_else(|| {
+            recursion_depth::is_depth_exceeded(message).then_some(KnownError::TooDeepRecursion)
+        })
+}
+
+/// The structured error body for `message`, or `None` when it is not a
+/// known ClickHouse error.
+pub fn error_body(message: &str, max_cte_depth: u32) -> Option<Value> {
+    let known = classify(message)?;
+    Some(json!({
+        "error": message,
+        "error_code": known.name(),
+        "clickhouse_code": known.code(),
+        "suggestion": known.suggestion(message, max_cte_depth),
+    }))
+}
+
+/// The `(ERROR_NAME)` of a ClickHouse exception; newer servers follow it
+/// with `(version ...)`.
+fn error_name(message: &str) -> Option<&str> {
+    message.rmatch_indices('(').find_map(|(at, _)| {
+        let rest = &message[at + 1..];
+        let name = &rest[..rest.find(')')?];
+        (!name.is_empty() && name.bytes().all(|b| b.is_ascii_uppercase() || b == b'_'))
+            .then_some(name)
+    })
+}
+
+/// The number after `Code: ` in a ClickHouse exception.
+fn error_code(message: &str) -> Option<u32> {
+    let rest = &message[message.find("Code: ")? + "Code: ".len()..];
+    let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+/// The identifier ClickHouse could not resolve, from `Unknown expression
+/// identifier `x`` or `Missing columns: 'x'`.
+fn unknown_identifier(message: &str) -> Option<&str> {
+    let (start, quote) = if let Some(at) = message.find("identifier `") {
+        (at + "identifier `".len(), '`')
+    } else {
+        (
+            message.find("Missing columns: '")? + "Missing columns: '".len(),
+            '\'',
+        )
+    };
+    let rest = &message[start..];
+    Some(&rest[..rest.find(quote)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_by_name_or_code() {
+        let memory = "Executor error: Code: 241. DB::Exception: Memory limit (total) exceeded: \
+                      would use 9.31 GiB. (MEMORY_LIMIT_EXCEEDED) (version 24.8.1.1)";
+        assert_eq!(classify(memory), Some(KnownError::MemoryLimitExceeded));
+        assert_eq!(error_name(memory), Some("MEMORY_LIMIT_EXCEEDED"));
+        assert_eq!(
+            classify("Executor error: Code: 53. DB::Exception: Cannot convert string"),
+            Some(KnownError::TypeMismatch)
+        );
+        assert_eq!(
+            classify(&recursion_depth::depth_exceeded_message(100)),
+            Some(KnownError::TooDeepRecursion)
+        );
+        assert_eq!(
+            classify("Executor error: Code: 62. DB::Exception: Syntax error (SYNTAX_ERROR)"),
+            None
+        );
+        assert_eq!(classify("Planning error: unknown label Foo"), None);
+    }
+
+    #[test]
+    fn body_names_the_error_and_suggests_a_fix() {
+        let message = "Executor error: Code: 47. DB::Exception: Unknown expression identifier \
+                       `u.nmae` in scope SELECT u.nmae FROM users AS u. (UNKNOWN_IDENTIFIER)";
+        let body = error_body(message, 100).unwrap();
+        assert_eq!(body["error"], message);
+        assert_eq!(body["error_code"], "UNKNOWN_IDENTIFIER");
+        assert_eq!(body["clickhouse_code"], 47);
+        assert!(body["suggestion"]
+            .as_str()
+            .unwrap()
+            .starts_with("`u.nmae` does not exist"));
+
+        let body = error_body(&recursion_depth::depth_exceeded_message(50), 50).unwrap();
+        assert!(body["suggestion"]
+            .as_str()
+            .unwrap()
+            .contains("--max-cte-depth (CLICKGRAPH_MAX_CTE_DEPTH, currently 50)"));
+
+        assert!(error_body("Executor error: connection refused", 100).is_none());
+    }
+}
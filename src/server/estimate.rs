@@ -0,0 +1,888 @@
+//! Fast result-size estimates (`POST /query/estimate`).
+//!
+//! Instead of running the traversal, the estimator walks the query's MATCH
+//! patterns stage by stage and prices each one with cheap single-table
+//! statistics queries (`count()` and `uniq()`):
+//!
+//! - **anchor** — the first node of a pattern:
+//!   `rows(label) × Π 1/uniq(col)` over its equality predicates;
+//! - **expand** — each relationship hop: the running estimate times the
+//!   fanout `count(edges) / rows(source label)`, scaled by the edge's and the
+//!   target node's equality selectivities. A variable-length hop sums
+//!   `fanout^k` over its hop range.
+//!
+//! Equality (`=`, inline `{key: value}`) and `IN [...]` predicates are used;
+//! their selectivity does not depend on the compared value, so parameters
+//! work as well as literals. Other predicates (ranges, `STARTS WITH`, ...)
+//! are not priced; the response lists them under `unpriced_predicates` and
+//! marks the estimate `approximate`. Columns are assumed uniform and
+//! independent — the figures are orders of magnitude for a UI warning, not a
+//! cost model.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    graph_catalog::graph_schema::{GraphSchema, NodeSchema, RelationshipSchema},
+    open_cypher_parser::{
+        self,
+        ast::{
+            Direction, Expression, NodePattern, OpenCypherQueryAst, Operator, PathPattern,
+            Property, ReadingClause, RelationshipPattern,
+        },
+    },
+    procedures::graph_quality::relationship_conditions,
+};
+
+use super::{graph_catalog, handlers::graph_name_header, AppState};
+
+#[derive(Debug, Deserialize)]
+pub struct EstimateRequest {
+    pub query: String,
+    /// Schema to estimate against (defaults to "default")
+    pub schema_name: Option<String>,
+}
+
+/// One statistics query: total rows of a table plus the distinct count of
+/// each filtered column (`ndv_0`, `ndv_1`, ...).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableProbe {
+    pub sql: String,
+    /// How many values each filtered column is compared against (1 for `=`,
+    /// the list length for `IN`), in `ndv_<i>` order.
+    pub value_counts: Vec<f64>,
+}
+
+impl TableProbe {
+    fn new(
+        database: &str,
+        table: &str,
+        filter: Option<String>,
+        columns: Vec<(String, f64)>,
+    ) -> Self {
+        let mut select = vec!["count() AS rows".to_string()];
+        select.extend(
+            columns
+                .iter()
+                .enumerate()
+                .map(|(i, (col, _))| format!("uniq({}) AS ndv_{}", col, i)),
+        );
+        let table_ref = if database.is_empty() {
+            table.to_string()
+        } else {
+            format!("{}.{}", database, table)
+        };
+        let mut sql = format!("SELECT {} FROM {} AS t", select.join(", "), table_ref);
+        if let Some(filter) = filter {
+            sql.push_str(&format!(" WHERE {}", filter));
+        }
+        TableProbe {
+            sql,
+            value_counts: columns.into_iter().map(|(_, n)| n).collect(),
+        }
+    }
+}
+
+/// Result of a [`TableProbe`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProbeResult {
+    pub rows: f64,
+    /// Product of `values / ndv` over the filtered columns, capped at 1.
+    pub selectivity: f64,
+}
+
+impl ProbeResult {
+    fn from_row(probe: &TableProbe, row: &Value) -> Self {
+        let number = |key: &str| -> f64 {
+            row.get(key)
+                .and_then(|v| v.as_f64().or_else(|| v.as_str()?.parse().ok()))
+                .unwrap_or(0.0)
+        };
+        let selectivity = probe
+            .value_counts
+            .iter()
+            .enumerate()
+            .map(|(i, values)| {
+                let ndv = number(&format!("ndv_{}", i));
+                if ndv > 0.0 {
+                    (values / ndv).min(1.0)
+                } else {
+                    0.0
+                }
+            })
+            .product();
+        ProbeResult {
+            rows: number("rows"),
+            selectivity,
+        }
+    }
+}
+
+/// One priced step of a pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StagePlan {
+    Anchor {
+        alias: String,
+        label: String,
+        node: TableProbe,
+    },
+    Expand {
+        alias: String,
+        target_alias: String,
+        types: Vec<String>,
+        direction: &'static str,
+        min_hops: u32,
+        max_hops: u32,
+        optional: bool,
+        /// Row count of the source label (fanout denominator)
+        source: TableProbe,
+        /// One probe per edge table the hop can use
+        edges: Vec<TableProbe>,
+        /// Target node predicates, if any
+        target: Option<TableProbe>,
+    },
+}
+
+impl StagePlan {
+    fn probes(&self) -> Vec<&TableProbe> {
+        match self {
+            StagePlan::Anchor { node, .. } => vec![node],
+            StagePlan::Expand {
+                source,
+                edges,
+                target,
+                ..
+            } => std::iter::once(source)
+                .chain(edges.iter())
+                .chain(target.iter())
+                .collect(),
+        }
+    }
+}
+
+/// Estimate of one stage, with the running row count after it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StageEstimate {
+    pub stage: &'static str,
+    pub alias: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<String>,
+    /// Rows of the anchor table, or average neighbours per source node
+    pub factor: f64,
+    pub selectivity: f64,
+    pub estimated_rows: f64,
+    pub sql: Vec<String>,
+}
+
+/// Split the query's MATCH patterns into anchor / expand stages.
+///
+/// `max_hops` bounds unbounded variable-length hops (`*`, `*2..`).
+pub fn plan_stages(
+    query: &OpenCypherQueryAst<'_>,
+    schema: &GraphSchema,
+    max_hops: u32,
+) -> Result<Vec<StagePlan>, String> {
+    let mut clauses: Vec<(bool, Vec<&PathPattern<'_>>)> = Vec::new();
+    for clause in &query.reading_clauses {
+        match clause {
+            ReadingClause::Match(m) => {
+                clauses.push((false, m.path_patterns.iter().map(|(_, p)| p).collect()));
+            }
+            ReadingClause::OptionalMatch(m) => {
+                clauses.push((true, m.path_patterns.iter().collect()));
+            }
+        }
+    }
+
+    let mut planner = StagePlanner {
+        schema,
+        predicates: Predicates::of_query(query),
+        max_hops,
+        bound: HashMap::new(),
+        stages: Vec::new(),
+        anonymous: 0,
+    };
+    for (optional, patterns) in clauses {
+        for pattern in patterns {
+            planner.pattern(pattern, optional)?;
+        }
+    }
+    Ok(planner.stages)
+}
+
+/// The query's WHERE conjuncts the estimate leaves out of its selectivities.
+pub fn unpriced_predicates(query: &OpenCypherQueryAst<'_>) -> Vec<String> {
+    Predicates::of_query(query).unpriced
+}
+
+/// Combine stage plans with their probe results into running estimates.
+pub fn evaluate_stages(
+    stages: &[StagePlan],
+    results: &HashMap<String, ProbeResult>,
+) -> Vec<StageEstimate> {
+    let result = |probe: &TableProbe| {
+        results.get(&probe.sql).copied().unwrap_or(ProbeResult {
+            rows: 0.0,
+            selectivity: 1.0,
+        })
+    };
+
+    let mut running = 1.0;
+    let mut out = Vec::with_capacity(stages.len());
+    for stage in stages {
+        let sql = stage.probes().iter().map(|p| p.sql.clone()).collect();
+        match stage {
+            StagePlan::Anchor { alias, label, node } => {
+                let node = result(node);
+                running *= node.rows * node.selectivity;
+                out.push(StageEstimate {
+                    stage: "anchor",
+                    alias: alias.clone(),
+                    label: Some(label.clone()),
+                    types: Vec::new(),
+                    factor: node.rows,
+                    selectivity: node.selectivity,
+                    estimated_rows: running,
+                    sql,
+                });
+            }
+            StagePlan::Expand {
+                alias,
+                types,
+                min_hops,
+                max_hops,
+                optional,
+                source,
+                edges,
+                target,
+                ..
+            } => {
+                let source_rows = result(source).rows;
+                let (edge_rows, edge_selectivity) = edges
+                    .iter()
+                    .map(result)
+                    .fold((0.0, 0.0), |(rows, matching), e| {
+                        (rows + e.rows, matching + e.rows * e.selectivity)
+                    });
+                let edge_selectivity = if edge_rows > 0.0 {
+                    edge_selectivity / edge_rows
+                } else {
+                    1.0
+                };
+                let fanout = if source_rows > 0.0 {
+                    edge_rows * edge_selectivity / source_rows
+                } else {
+                    0.0
+                };
+                let paths: f64 = (*min_hops..=*max_hops).map(|k| fanout.powi(k as i32)).sum();
+                let target_selectivity = target.as_ref().map_or(1.0, |t| result(t).selectivity);
+
+                let expanded = running * paths * target_selectivity;
+                // OPTIONAL MATCH keeps every input row.
+                running = if *optional {
+                    expanded.max(running)
+                } else {
+                    expanded
+                };
+                out.push(StageEstimate {
+                    stage: "expand",
+                    alias: alias.clone(),
+                    label: None,
+                    types: types.clone(),
+                    factor: fanout,
+                    selectivity: edge_selectivity * target_selectivity,
+                    estimated_rows: running,
+                    sql,
+                });
+            }
+        }
+    }
+    out
+}
+
+/// Equality / IN predicates per alias: property → number of compared values.
+#[derive(Debug, Default)]
+struct Predicates {
+    by_alias: HashMap<String, BTreeMap<String, f64>>,
+    /// Conjuncts that are neither, as written
+    unpriced: Vec<String>,
+}
+
+impl Predicates {
+    /// The predicates of the query's WHERE and of each (OPTIONAL) MATCH's.
+    fn of_query(query: &OpenCypherQueryAst<'_>) -> Self {
+        let mut predicates = Predicates::default();
+        if let Some(where_clause) = &query.where_clause {
+            predicates.collect(&where_clause.conditions);
+        }
+        for clause in &query.reading_clauses {
+            let where_clause = match clause {
+                ReadingClause::Match(m) => &m.where_clause,
+                ReadingClause::OptionalMatch(m) => &m.where_clause,
+            };
+            if let Some(w) = where_clause {
+                predicates.collect(&w.conditions);
+            }
+        }
+        predicates
+    }
+
+    fn collect(&mut self, expr: &Expression<'_>) {
+        let Expression::OperatorApplicationExp(op) = expr else {
+            self.unpriced.push(expr.to_string());
+            return;
+        };
+        match (&op.operator, op.operands.as_slice()) {
+            (Operator::And, operands) => operands.iter().for_each(|e| self.collect(e)),
+            (Operator::Equal, [Expression::PropertyAccessExp(pa), _])
+            | (Operator::Equal, [_, Expression::PropertyAccessExp(pa)]) => {
+                self.add(pa.base, pa.key, 1.0)
+            }
+            (Operator::In, [Expression::PropertyAccessExp(pa), Expression::List(values)]) => {
+                self.add(pa.base, pa.key, values.len().max(1) as f64)
+            }
+            _ => self.unpriced.push(expr.to_string()),
+        }
+    }
+
+    fn add(&mut self, alias: &str, key: &str, values: f64) {
+        let entry = self
+            .by_alias
+            .entry(alias.to_string())
+            .or_default()
+            .entry(key.to_string())
+            .or_insert(values);
+        *entry = entry.min(values);
+    }
+
+    /// Predicates on `alias`, merged with inline pattern properties.
+    fn for_alias(&self, alias: &str, inline: Option<&Vec<Property<'_>>>) -> BTreeMap<String, f64> {
+        let mut out = self.by_alias.get(alias).cloned().unwrap_or_default();
+        for prop in inline.into_iter().flatten() {
+            if let Property::PropertyKV(kv) = prop {
+                out.insert(kv.key.to_string(), 1.0);
+            }
+        }
+        out
+    }
+}
+
+struct StagePlanner<'s> {
+    schema: &'s GraphSchema,
+    predicates: Predicates,
+    max_hops: u32,
+    /// Alias → resolved label of nodes already priced
+    bound: HashMap<String, String>,
+    stages: Vec<StagePlan>,
+    anonymous: usize,
+}
+
+impl StagePlanner<'_> {
+    fn pattern(&mut self, pattern: &PathPattern<'_>, optional: bool) -> Result<(), String> {
+        match pattern {
+            PathPattern::Node(node) => {
+                let alias = self.alias(node.name);
+                if !self.bound.contains_key(&alias) {
+                    let label = node
+                        .first_label()
+                        .ok_or_else(|| format!("node `{}` needs a label to be estimated", alias))?;
+                    self.anchor(&alias, label, node)?;
+                }
+                Ok(())
+            }
+            PathPattern::ConnectedPattern(connections) => {
+                for conn in connections {
+                    let start = conn.start_node.borrow();
+                    let end = conn.end_node.borrow();
+                    self.expand(&start, &conn.relationship, &end, optional)?;
+                }
+                Ok(())
+            }
+            PathPattern::ShortestPath(_) | PathPattern::AllShortestPaths(_) => {
+                Err("shortestPath patterns cannot be estimated".to_string())
+            }
+        }
+    }
+
+    fn alias(&mut self, name: Option<&str>) -> String {
+        name.map(str::to_string).unwrap_or_else(|| {
+            self.anonymous += 1;
+            format!("_anon{}", self.anonymous)
+        })
+    }
+
+    fn anchor(&mut self, alias: &str, label: &str, node: &NodePattern<'_>) -> Result<(), String> {
+        let node_schema = self.node_schema(label)?;
+        let predicates = self.predicates.for_alias(alias, node.properties.as_ref());
+        self.stages.push(StagePlan::Anchor {
+            alias: alias.to_string(),
+            label: label.to_string(),
+            node: node_probe(node_schema, &predicates),
+        });
+        self.bound.insert(alias.to_string(), label.to_string());
+        Ok(())
+    }
+
+    fn expand(
+        &mut self,
+        start: &NodePattern<'_>,
+        rel: &RelationshipPattern<'_>,
+        end: &NodePattern<'_>,
+        optional: bool,
+    ) -> Result<(), String> {
+        let start_alias = match start.name {
+            Some(name) => name.to_string(),
+            None => self.alias(None),
+        };
+        let end_alias = self.alias(end.name);
+        let rel_alias = self.alias(rel.name);
+
+        let start_label = start
+            .first_label()
+            .map(str::to_string)
+            .or_else(|| self.bound.get(&start_alias).cloned());
+        let end_label = end
+            .first_label()
+            .map(str::to_string)
+            .or_else(|| self.bound.get(&end_alias).cloned());
+
+        // Edge tables usable by this hop, oriented as (source side, edge).
+        let requested: Option<HashSet<&str>> =
+            rel.labels.as_ref().map(|l| l.iter().copied().collect());
+        let mut types = Vec::new();
+        let mut variants: Vec<(&str, &RelationshipSchema, bool)> = Vec::new();
+        for rel_type in self.schema.get_rel_type_index().keys() {
+            if requested
+                .as_ref()
+                .is_some_and(|r| !r.contains(rel_type.as_str()))
+            {
+                continue;
+            }
+            let mut used = false;
+            for rs in self.schema.rel_schemas_for_type(rel_type) {
+                for outgoing in [true, false] {
+                    let allowed = match rel.direction {
+                        Direction::Outgoing => outgoing,
+                        Direction::Incoming => !outgoing,
+                        Direction::Either => true,
+                    };
+                    let (from, to) = if outgoing {
+                        (&rs.from_node, &rs.to_node)
+                    } else {
+                        (&rs.to_node, &rs.from_node)
+                    };
+                    if allowed
+                        && start_label.as_ref().is_none_or(|l| l == from)
+                        && end_label.as_ref().is_none_or(|l| l == to)
+                    {
+                        variants.push((rel_type.as_str(), rs, outgoing));
+                        used = true;
+                    }
+                }
+            }
+            if used {
+                types.push(rel_type.clone());
+            }
+        }
+        if variants.is_empty() {
+            return Err(format!(
+                "no relationship in the schema matches `{}`",
+                rel.labels
+                    .as_ref()
+                    .map(|l| l.join("|"))
+                    .unwrap_or_else(|| rel_alias.clone())
+            ));
+        }
+
+        let source_label = match start_label {
+            Some(label) => label,
+            None => {
+                let (_, rs, outgoing) = variants[0];
+                if outgoing {
+                    rs.from_node.clone()
+                } else {
+                    rs.to_node.clone()
+                }
+            }
+        };
+        if !self.bound.contains_key(&start_alias) {
+            self.anchor(&start_alias, &source_label, start)?;
+        }
+
+        let edge_predicates = self
+            .predicates
+            .for_alias(&rel_alias, rel.properties.as_ref());
+        let edges = variants
+            .iter()
+            .map(|(rel_type, rs, _)| edge_probe(rs, rel_type, &edge_predicates))
+            .collect::<Result<_, _>>()?;
+
+        let target_predicates = self
+            .predicates
+            .for_alias(&end_alias, end.properties.as_ref());
+        let target_label = end_label.unwrap_or_else(|| {
+            let (_, rs, outgoing) = variants[0];
+            if outgoing {
+                rs.to_node.clone()
+            } else {
+                rs.from_node.clone()
+            }
+        });
+        let target = if target_predicates.is_empty() {
+            None
+        } else {
+            Some(node_probe(
+                self.node_schema(&target_label)?,
+                &target_predicates,
+            ))
+        };
+
+        let (min_hops, max_hops) = match &rel.variable_length {
+            Some(spec) => {
+                let min = spec.min_hops.unwrap_or(1);
+                (min, spec.max_hops.unwrap_or(self.max_hops).max(min))
+            }
+            None => (1, 1),
+        };
+
+        self.stages.push(StagePlan::Expand {
+            alias: rel_alias,
+            target_alias: end_alias.clone(),
+            types,
+            direction: match rel.direction {
+                Direction::Outgoing => "outgoing",
+                Direction::Incoming => "incoming",
+                Direction::Either => "either",
+            },
+            min_hops,
+            max_hops,
+            optional,
+            source: node_probe(self.node_schema(&source_label)?, &BTreeMap::new()),
+            edges,
+            target,
+        });
+        self.bound.insert(end_alias, target_label);
+        Ok(())
+    }
+
+    fn node_schema(&self, label: &str) -> Result<&NodeSchema, String> {
+        self.schema
+            .node_schema_opt(label)
+            .ok_or_else(|| format!("unknown node label `{}`", label))
+    }
+}
+
+fn node_probe(node: &NodeSchema, predicates: &BTreeMap<String, f64>) -> TableProbe {
+    let columns = predicates
+        .iter()
+        .filter_map(|(key, values)| {
+            let mapped = node.property_mappings.get(key)?;
+            Some((mapped.to_sql("t"), *values))
+        })
+        .collect();
+    TableProbe::new(
+        &node.database,
+        &node.table_name,
+        node.filter.as_ref().and_then(|f| f.to_sql("t").ok()),
+        columns,
+    )
+}
+
+/// Probe of `rel_type`'s rows in edge table `rel`: a table shared by several
+/// types or endpoint labels is narrowed by its discriminator columns.
+fn edge_probe(
+    rel: &RelationshipSchema,
+    rel_type: &str,
+    predicates: &BTreeMap<String, f64>,
+) -> Result<TableProbe, String> {
+    let columns = predicates
+        .iter()
+        .filter_map(|(key, values)| {
+            let mapped = rel.property_mappings.get(key)?;
+            Some((mapped.to_sql("t"), *values))
+        })
+        .collect();
+    let filters = relationship_conditions(rel, rel_type, "t")?;
+    Ok(TableProbe::new(
+        &rel.database,
+        &rel.table_name,
+        (!filters.is_empty()).then(|| filters.join(" AND ")),
+        columns,
+    ))
+}
+
+/// `POST /query/estimate`
+pub async fn estimate_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(request): Json<EstimateRequest>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    let error = |status: StatusCode, message: String| {
+        (status, Json(serde_json::json!({ "error": message })))
+    };
+
+    // USE clause > request `schema_name` > `X-Graph-Name` > default graph,
+    // confined like `/query` to a pinned graph
+    let header = graph_name_header(&headers).map_err(|(status, e)| error(status, e))?;
+    let requested = request.schema_name.clone().or_else(|| header.clone());
+    let use_graph = {
+        let cleaned = open_cypher_parser::strip_comments(&request.query);
+        match open_cypher_parser::parse_cypher_statement(cleaned.trim()) {
+            Ok((_, statement)) => crate::query_planner::target_graph(&statement)
+                .map_err(|e| error(StatusCode::BAD_REQUEST, e.to_string()))?
+                .map(str::to_string),
+            Err(_) => None,
+        }
+    };
+    let schema_name = graph_catalog::resolve_graph_name(use_graph.as_deref(), requested.as_deref());
+    let pinned = graph_catalog::pinned_graph(header.as_deref(), requested.as_deref());
+    graph_catalog::check_graph_isolation(pinned.as_deref(), &schema_name)
+        .map_err(|e| error(StatusCode::FORBIDDEN, e))?;
+    let schema = graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| error(StatusCode::NOT_FOUND, e))?;
+
+    // Plan the query first so only queries that would run get estimated.
+    crate::clickhouse_query_generator::cypher_to_sql(
+        &request.query,
+        &schema,
+        app_state.config.max_cte_depth,
+    )
+    .map_err(|e| error(StatusCode::BAD_REQUEST, e))?;
+
+    // The AST holds `Rc`s, so keep it out of the awaits below.
+    let (stages, unpriced, limit) = {
+        let cleaned = open_cypher_parser::strip_comments(&request.query);
+        let query = open_cypher_parser::parse_query(&cleaned).map_err(|e| {
+            error(
+                StatusCode::BAD_REQUEST,
+                format!("Query parsing failed: {}", e),
+            )
+        })?;
+        let stages = plan_stages(&query, &schema, app_state.config.max_cte_depth)
+            .map_err(|e| error(StatusCode::UNPROCESSABLE_ENTITY, e))?;
+        (
+            stages,
+            unpriced_predicates(&query),
+            query.limit_clause.map(|l| l.limit_item),
+        )
+    };
+
+    let mut results: HashMap<String, ProbeResult> = HashMap::new();
+    for stage in &stages {
+        for probe in stage.probes() {
+            if results.contains_key(&probe.sql) {
+                continue;
+            }
+            let rows = app_state
+                .executor
+                .execute_json(&probe.sql, None)
+                .await
+                .map_err(|e| error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            let row = rows.first().cloned().unwrap_or(Value::Null);
+            results.insert(probe.sql.clone(), ProbeResult::from_row(probe, &row));
+        }
+    }
+
+    let estimates = evaluate_stages(&stages, &results);
+    let mut estimated_rows = estimates.last().map_or(0.0, |s| s.estimated_rows);
+    if let Some(limit) = limit {
+        estimated_rows = estimated_rows.min(limit as f64);
+    }
+
+    Ok(Json(serde_json::json!({
+        "schema_name": schema_name,
+        "estimated_rows": estimated_rows.round() as u64,
+        "approximate": !unpriced.is_empty(),
+        "unpriced_predicates": unpriced,
+        "stages": estimates,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    const SCHEMA_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: social
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        country: country
+    - label: Post
+      database: social
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+  edges:
+    - type: FOLLOWS
+      database: social
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+    - type: AUTHORED
+      database: social
+      table: posts
+      from_id: author_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+"#;
+
+    fn schema() -> GraphSchema {
+        GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("valid yaml")
+            .to_graph_schema()
+            .expect("valid schema")
+    }
+
+    fn stages(cypher: &str) -> Vec<StagePlan> {
+        let query = open_cypher_parser::parse_query(cypher).expect("parse");
+        plan_stages(&query, &schema(), 3).expect("stages")
+    }
+
+    #[test]
+    fn anchor_and_hop_probes() {
+        let stages = stages(
+            "MATCH (u:User {name: 'Ada'})-[:FOLLOWS]->(f)-[:AUTHORED]->(p:Post) \
+             WHERE f.country IN ['NZ', 'AU'] RETURN p",
+        );
+        assert_eq!(stages.len(), 3);
+
+        let StagePlan::Anchor { alias, node, .. } = &stages[0] else {
+            panic!("expected anchor, got {:?}", stages[0]);
+        };
+        assert_eq!(alias, "u");
+        assert_eq!(
+            node.sql,
+            "SELECT count() AS rows, uniq(t.full_name) AS ndv_0 FROM social.users AS t"
+        );
+
+        let StagePlan::Expand {
+            types,
+            edges,
+            target,
+            ..
+        } = &stages[1]
+        else {
+            panic!("expected expand, got {:?}", stages[1]);
+        };
+        assert_eq!(types, &vec!["FOLLOWS".to_string()]);
+        assert_eq!(
+            edges[0].sql,
+            "SELECT count() AS rows FROM social.follows AS t"
+        );
+        let target = target.as_ref().expect("target predicates");
+        assert_eq!(target.value_counts, vec![2.0]);
+
+        let StagePlan::Expand { types, .. } = &stages[2] else {
+            panic!("expected expand");
+        };
+        assert_eq!(types, &vec!["AUTHORED".to_string()]);
+    }
+
+    #[test]
+    fn estimates_multiply_selectivity_and_fanout() {
+        let stages = stages("MATCH (u:User {name: $name})-[:FOLLOWS*1..2]->(f) RETURN f");
+        let mut results = HashMap::new();
+        for stage in &stages {
+            for probe in stage.probes() {
+                let row = if probe.sql.contains("follows") {
+                    serde_json::json!({"rows": "3000"})
+                } else if probe.value_counts.is_empty() {
+                    serde_json::json!({"rows": 1000})
+                } else {
+                    serde_json::json!({"rows": 1000, "ndv_0": 500})
+                };
+                results.insert(probe.sql.clone(), ProbeResult::from_row(probe, &row));
+            }
+        }
+
+        let estimates = evaluate_stages(&stages, &results);
+        // 1000 users / 500 distinct names = 2 anchors
+        assert_eq!(estimates[0].estimated_rows, 2.0);
+        // fanout 3 over 1..2 hops: 2 × (3 + 9)
+        assert_eq!(estimates[1].factor, 3.0);
+        assert_eq!(estimates[1].estimated_rows, 24.0);
+    }
+
+    #[test]
+    fn optional_match_never_drops_rows() {
+        let stages = stages("MATCH (u:User) OPTIONAL MATCH (u)-[:AUTHORED]->(p:Post) RETURN u, p");
+        let mut results = HashMap::new();
+        for stage in &stages {
+            for probe in stage.probes() {
+                let rows = if probe.sql.contains("users") { 10 } else { 0 };
+                let row = serde_json::json!({ "rows": rows });
+                results.insert(probe.sql.clone(), ProbeResult::from_row(probe, &row));
+            }
+        }
+        let estimates = evaluate_stages(&stages, &results);
+        assert_eq!(estimates.last().unwrap().estimated_rows, 10.0);
+    }
+
+    #[test]
+    fn shared_edge_table_is_narrowed_to_the_type() {
+        // MENTORS, REVIEWS and HELPS share `community.interactions`
+        let schema = GraphSchemaConfig::from_yaml_file(
+            "schemas/test/community_polymorphic_constraints.yaml",
+        )
+        .expect("valid yaml")
+        .to_graph_schema()
+        .expect("valid schema");
+        let query =
+            open_cypher_parser::parse_query("MATCH (a:Member)-[:MENTORS]->(b:Member) RETURN b")
+                .expect("parse");
+        let stages = plan_stages(&query, &schema, 3).expect("stages");
+        let StagePlan::Expand { edges, .. } = &stages[1] else {
+            panic!("expected expand, got {:?}", stages[1]);
+        };
+        assert_eq!(
+            edges[0].sql,
+            "SELECT count() AS rows FROM community.interactions AS t \
+             WHERE t.interaction_type = 'MENTORS'"
+        );
+    }
+
+    #[test]
+    fn unpriced_predicates_are_reported() {
+        let query = open_cypher_parser::parse_query(
+            "MATCH (u:User) WHERE u.name = 'Ada' AND u.age > 30 \
+             AND u.country STARTS WITH 'N' RETURN u",
+        )
+        .expect("parse");
+        let unpriced = unpriced_predicates(&query);
+        assert_eq!(unpriced.len(), 2, "{unpriced:?}");
+        assert!(unpriced[0].contains("age"), "{unpriced:?}");
+        assert!(unpriced[1].contains("country"), "{unpriced:?}");
+    }
+
+    #[test]
+    fn unknown_relationship_is_an_error() {
+        let query = open_cypher_parser::parse_query("MATCH (u:User)-[:LIKES]->(p) RETURN p")
+            .expect("parse");
+        let err = plan_stages(&query, &schema(), 3).expect_err("unknown type");
+        assert!(err.contains("LIKES"), "got {err}");
+    }
+}
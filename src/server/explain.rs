@@ -0,0 +1,318 @@
+//! `EXPLAIN` / `PROFILE` over HTTP `/query`.
+//!
+//! `EXPLAIN <query>` plans the query and returns the logical plan together
+//! with the generated ClickHouse SQL, without running anything.
+//! `PROFILE <query>` additionally runs the SQL and reads ClickHouse's own
+//! execution stats back from `system.query_log`.
+//!
+//! To find its log entry, the profiled SQL is prefixed with a comment carrying
+//! a unique tag (ClickHouse keeps comments in `query_log.query`). Logs are
+//! flushed with `SYSTEM FLUSH LOGS` before the lookup; without the privilege
+//! for that (or with `log_queries = 0`) the entry may not be visible yet, and
+//! the response carries `stats_error` instead of `stats`.
+//!
+//! Both responses also carry a [`PlanSummary`]: the query's patterns with the
+//! strategy each is planned with and the CTE it reads from, for clients that
+//! draw the plan rather than print the logical plan's text.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::open_cypher_parser::ast::ExplainMode;
+use crate::query_planner::logical_expr::Direction;
+use crate::query_planner::logical_plan::{GraphRel, LogicalPlan};
+use crate::render_plan::Cte;
+
+/// Columns read from `system.query_log` for a profiled query.
+const QUERY_LOG_COLUMNS: [&str; 6] = [
+    "query_duration_ms",
+    "read_rows",
+    "read_bytes",
+    "result_rows",
+    "result_bytes",
+    "memory_usage",
+];
+
+/// Response body for `EXPLAIN` / `PROFILE`.
+#[derive(Debug, Serialize)]
+pub struct ExplainResponse {
+    pub mode: &'static str,
+    pub cypher_query: String,
+    pub schema_name: String,
+    /// Logical plan tree after analysis and optimization
+    pub logical_plan: String,
+    /// Patterns, their strategies and CTEs
+    pub plan: PlanSummary,
+    pub generated_sql: String,
+    /// Rows returned by the profiled query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<Value>>,
+    /// ClickHouse execution stats (`system.query_log`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ProfileStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats_error: Option<String>,
+}
+
+/// ClickHouse-side execution stats of a profiled query.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProfileStats {
+    pub query_duration_ms: u64,
+    pub read_rows: u64,
+    pub read_bytes: u64,
+    pub result_rows: u64,
+    pub result_bytes: u64,
+    pub memory_usage: u64,
+}
+
+impl ProfileStats {
+    /// Parse a `system.query_log` row. 64-bit counters arrive as JSON strings
+    /// under ClickHouse's default `output_format_json_quote_64bit_integers`.
+    pub fn from_row(row: &Value) -> Option<Self> {
+        let num = |name: &str| match row.get(name)? {
+            Value::Number(n) => n.as_u64(),
+            Value::String(s) => s.parse().ok(),
+            _ => None,
+        };
+        Some(ProfileStats {
+            query_duration_ms: num("query_duration_ms")?,
+            read_rows: num("read_rows")?,
+            read_bytes: num("read_bytes")?,
+            result_rows: num("result_rows")?,
+            result_bytes: num("result_bytes")?,
+            memory_usage: num("memory_usage")?,
+        })
+    }
+}
+
+/// One pattern of the plan: a relationship hop, or a node matched alone.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlanPattern {
+    /// `(a:User)-[r:FOLLOWS*1..3]->(b:User)`, or `(a:User)` for a node scan
+    pub pattern: String,
+    /// Alias of the relationship, or of the node for a scan
+    pub alias: String,
+    /// `scan`, `join`, `optional_join`, `type_union`, `fixed_length`,
+    /// `variable_length` or `shortest_path`
+    pub strategy: &'static str,
+    /// The CTE the pattern is computed in, for variable-length paths
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cte: Option<String>,
+}
+
+/// A CTE of the generated SQL.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlanCte {
+    pub name: String,
+    pub recursive: bool,
+}
+
+/// Structured view of a plan, in pattern order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PlanSummary {
+    pub patterns: Vec<PlanPattern>,
+    pub ctes: Vec<PlanCte>,
+}
+
+impl PlanSummary {
+    /// Summarize `plan` and the CTEs of its render plan.
+    pub fn new(plan: &LogicalPlan, ctes: &[Cte]) -> Self {
+        let mut patterns = Vec::new();
+        collect_patterns(plan, false, ctes, &mut patterns);
+        // A split undirected pattern or a multi-type union plans a hop once
+        // per branch; report it once.
+        let mut seen = HashSet::new();
+        patterns.retain(|p| seen.insert(p.alias.clone()));
+        PlanSummary {
+            patterns,
+            ctes: ctes
+                .iter()
+                .map(|cte| PlanCte {
+                    name: cte.cte_name.clone(),
+                    recursive: cte.is_recursive,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Walk collecting relationship hops in query order, and nodes that are not
+/// the endpoint of a hop (`in_hop`) as scans.
+fn collect_patterns(plan: &LogicalPlan, in_hop: bool, ctes: &[Cte], out: &mut Vec<PlanPattern>) {
+    match plan {
+        LogicalPlan::GraphRel(rel) => {
+            // A chain nests earlier hops on the left.
+            collect_patterns(&rel.left, true, ctes, out);
+            out.push(hop_pattern(rel, ctes));
+            collect_patterns(&rel.right, true, ctes, out);
+        }
+        LogicalPlan::GraphNode(node) => {
+            if !in_hop {
+                out.push(PlanPattern {
+                    pattern: node_text(&node.alias, node.label.as_deref()),
+                    alias: node.alias.clone(),
+                    strategy: "scan",
+                    cte: None,
+                });
+            }
+            collect_patterns(&node.input, false, ctes, out);
+        }
+        other => other.for_each_child(|child| collect_patterns(child, false, ctes, out)),
+    }
+}
+
+fn hop_pattern(rel: &GraphRel, ctes: &[Cte]) -> PlanPattern {
+    // Labels may be composite `TYPE::From::To` keys.
+    let mut types: Vec<&str> = rel
+        .labels
+        .iter()
+        .flatten()
+        .map(|key| key.split("::").next().unwrap_or(key))
+        .collect();
+    types.dedup();
+    let mut rel_text = rel.alias.clone();
+    if !types.is_empty() {
+        rel_text.push(':');
+        rel_text.push_str(&types.join("|"));
+    }
+    if let Some(spec) = &rel.variable_length {
+        rel_text.push_str(&match (spec.min_hops, spec.max_hops) {
+            (Some(min), Some(max)) if min == max => format!("*{}", min),
+            (min, max) => format!(
+                "*{}..{}",
+                min.map_or(String::new(), |n| n.to_string()),
+                max.map_or(String::new(), |n| n.to_string())
+            ),
+        });
+    }
+    let arrow = if rel.direction == Direction::Either || rel.was_undirected == Some(true) {
+        "-"
+    } else {
+        "->"
+    };
+    let left = node_label(&rel.left, &rel.left_connection);
+    let right = node_label(&rel.right, &rel.right_connection);
+    let pattern = format!(
+        "{}-[{}]{}{}",
+        node_text(&rel.left_connection, left.as_deref()),
+        rel_text,
+        arrow,
+        node_text(&rel.right_connection, right.as_deref()),
+    );
+
+    let fixed_hops = rel
+        .variable_length
+        .as_ref()
+        .and_then(|spec| spec.exact_hop_count());
+    let strategy = if rel.shortest_path_mode.is_some() {
+        "shortest_path"
+    } else if rel.variable_length.is_some() && fixed_hops.is_none() {
+        "variable_length"
+    } else if fixed_hops.is_some_and(|n| n != 1) {
+        "fixed_length"
+    } else if types.len() > 1 || rel.pattern_combinations.is_some() {
+        "type_union"
+    } else if rel.is_optional == Some(true) {
+        "optional_join"
+    } else {
+        "join"
+    };
+
+    // VLP CTEs record the Cypher aliases of the path's endpoints.
+    let endpoints = [rel.left_connection.as_str(), rel.right_connection.as_str()];
+    let cte = ctes
+        .iter()
+        .find(|cte| {
+            matches!(
+                (cte.vlp_cypher_start_alias.as_deref(), cte.vlp_cypher_end_alias.as_deref()),
+                (Some(start), Some(end)) if endpoints.contains(&start) && endpoints.contains(&end)
+            )
+        })
+        .map(|cte| cte.cte_name.clone());
+
+    PlanPattern {
+        pattern,
+        alias: rel.alias.clone(),
+        strategy,
+        cte,
+    }
+}
+
+fn node_text(alias: &str, label: Option<&str>) -> String {
+    match label {
+        Some(label) => format!("({}:{})", alias, label),
+        None => format!("({})", alias),
+    }
+}
+
+/// Label of the node `alias` within a hop's endpoint subtree.
+fn node_label(plan: &LogicalPlan, alias: &str) -> Option<String> {
+    match plan {
+        LogicalPlan::GraphNode(node) if node.alias == alias => node.label.clone(),
+        other => other
+            .children()
+            .into_iter()
+            .find_map(|child| node_label(child, alias)),
+    }
+}
+
+pub fn mode_name(mode: ExplainMode) -> &'static str {
+    match mode {
+        ExplainMode::Explain => "EXPLAIN",
+        ExplainMode::Profile => "PROFILE",
+    }
+}
+
+/// A fresh tag identifying one profiled run in `system.query_log`.
+pub fn profile_tag() -> String {
+    format!("clickgraph-profile:{}", uuid::Uuid::new_v4().simple())
+}
+
+/// The SQL to run for a profiled query: `sql` behind a comment carrying `tag`.
+pub fn tag_sql(sql: &str, tag: &str) -> String {
+    format!("/* {} */\n{}", tag, sql)
+}
+
+/// Lookup of the finished `query_log` entry of the query tagged with `tag`.
+pub fn query_log_sql(tag: &str) -> String {
+    format!(
+        "SELECT {} FROM system.query_log \
+         WHERE type = 'QueryFinish' AND is_initial_query AND event_date >= yesterday() \
+         AND position(query, '/* {} */') = 1 \
+         ORDER BY event_time DESC LIMIT 1",
+        QUERY_LOG_COLUMNS.join(", "),
+        tag
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn tagged_sql_is_found_by_its_lookup() {
+        let tag = profile_tag();
+        assert_ne!(tag, profile_tag());
+        let sql = tag_sql("SELECT 1", &tag);
+        assert!(sql.starts_with(&format!("/* {} */", tag)));
+        let lookup = query_log_sql(&tag);
+        assert!(lookup.contains(&format!("position(query, '/* {} */') = 1", tag)));
+        // The lookup's own log entry does not start with the tag.
+        assert!(!lookup.starts_with("/*"));
+    }
+
+    #[test]
+    fn stats_parse_quoted_and_plain_counters() {
+        let row = json!({
+            "query_duration_ms": "12", "read_rows": "1000", "read_bytes": 8000,
+            "result_rows": "3", "result_bytes": "96", "memory_usage": "4194304"
+        });
+        let stats = ProfileStats::from_row(&row).unwrap();
+        assert_eq!(stats.read_rows, 1000);
+        assert_eq!(stats.read_bytes, 8000);
+        assert!(ProfileStats::from_row(&json!({"read_rows": "1"})).is_none());
+    }
+}
@@ -0,0 +1,257 @@
+//! Consistency window for reads over eventually-updated edge tables.
+//!
+//! An edge backed by a materialized view's target table lags its source by
+//! however long the inserts take to propagate. Schemas declare that with
+//! `freshness` (a watermark column and the typical lag); a query that sets
+//! `max_staleness_secs` gets each referenced table's `max(watermark)`
+//! checked first, and a table further behind is handled per `on_stale`:
+//! annotated in `X-Query-Notification` ([`StaleAction::Annotate`]), waited
+//! for up to its typical lag ([`StaleAction::Wait`]), or refused
+//! ([`StaleAction::Error`]).
+
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::executor::QueryExecutor;
+use crate::graph_catalog::graph_schema::{Freshness, GraphSchema};
+
+/// How often `on_stale: wait` re-reads the watermarks.
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// What to do when a table is further behind than the query allows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StaleAction {
+    /// Run the query and flag the result as possibly stale
+    #[default]
+    Annotate,
+    /// Wait up to the table's `typical_lag_seconds` for it to catch up,
+    /// then fail
+    Wait,
+    /// Fail without running the query
+    Error,
+}
+
+#[derive(Debug, Error)]
+#[error("expected annotate, wait or error, got '{0}'")]
+pub struct ParseStaleActionError(String);
+
+impl FromStr for StaleAction {
+    type Err = ParseStaleActionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "" | "annotate" | "warn" => Ok(StaleAction::Annotate),
+            "wait" => Ok(StaleAction::Wait),
+            "error" | "fail" => Ok(StaleAction::Error),
+            _ => Err(ParseStaleActionError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FreshnessError {
+    /// Some table is still too far behind (after waiting, with `wait`)
+    #[error("Data is staler than max_staleness_secs = {max_staleness}: {}", describe(.stale))]
+    Stale {
+        max_staleness: u64,
+        stale: Vec<(String, i64)>,
+    },
+
+    #[error("Failed to read the watermark of {table}: {message}")]
+    Watermark { table: String, message: String },
+}
+
+/// The watermarked tables among `sqls`, as (`database.table`, freshness).
+pub fn referenced_tables<'a>(
+    schema: &'a GraphSchema,
+    sqls: &[String],
+) -> Vec<(&'a str, &'a Freshness)> {
+    schema
+        .freshness_tables()
+        .filter(|(table, _)| sqls.iter().any(|sql| references(sql, table)))
+        .collect()
+}
+
+/// Whether `sql` reads `table` (`database.table`), plain or backquoted.
+fn references(sql: &str, table: &str) -> bool {
+    let Some((database, name)) = table.split_once('.') else {
+        return false;
+    };
+    let quoted = format!("`{}`.`{}`", database, name);
+    if sql.contains(&quoted) {
+        return true;
+    }
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    sql.match_indices(table).any(|(start, _)| {
+        let before = sql[..start].chars().next_back();
+        let after = sql[start + table.len()..].chars().next();
+        !before.is_some_and(|c| is_ident(c) || c == '.')
+            && !after.is_some_and(|c| is_ident(c) || c == '.')
+    })
+}
+
+/// Seconds since the newest row of `table` was written.
+fn lag_sql(table: &str, freshness: &Freshness) -> String {
+    format!(
+        "SELECT toInt64(dateDiff('second', max(`{}`), now())) AS lag FROM {}",
+        freshness.watermark.replace('`', "``"),
+        table
+    )
+}
+
+async fn lag_seconds(
+    executor: &dyn QueryExecutor,
+    table: &str,
+    freshness: &Freshness,
+    role: Option<&str>,
+) -> Result<i64, FreshnessError> {
+    let watermark_error = |message: String| FreshnessError::Watermark {
+        table: table.to_string(),
+        message,
+    };
+    let rows = executor
+        .execute_json(&lag_sql(table, freshness), role)
+        .await
+        .map_err(|e| watermark_error(e.to_string()))?;
+    rows.first()
+        .and_then(|row| row.get("lag"))
+        .and_then(lag_value)
+        .ok_or_else(|| watermark_error("no lag returned".to_string()))
+}
+
+/// ClickHouse quotes 64-bit integers in JSON output by default.
+fn lag_value(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+async fn stale_tables(
+    executor: &dyn QueryExecutor,
+    tables: &[(&str, &Freshness)],
+    max_staleness: u64,
+    role: Option<&str>,
+) -> Result<Vec<(String, i64)>, FreshnessError> {
+    let mut stale = Vec::new();
+    for (table, freshness) in tables {
+        let lag = lag_seconds(executor, table, freshness, role).await?;
+        if lag > max_staleness as i64 {
+            stale.push((table.to_string(), lag));
+        }
+    }
+    Ok(stale)
+}
+
+/// Check the watermarks of the tables `sqls` read against `max_staleness`
+/// seconds. Returns the `X-Query-Notification` for a possibly stale result,
+/// if any.
+pub async fn check(
+    executor: &dyn QueryExecutor,
+    schema: &GraphSchema,
+    sqls: &[String],
+    max_staleness: u64,
+    action: StaleAction,
+    role: Option<&str>,
+) -> Result<Option<String>, FreshnessError> {
+    let tables = referenced_tables(schema, sqls);
+    if tables.is_empty() {
+        return Ok(None);
+    }
+
+    let mut stale = stale_tables(executor, &tables, max_staleness, role).await?;
+    if stale.is_empty() {
+        return Ok(None);
+    }
+    match action {
+        StaleAction::Annotate => Ok(Some(stale_notification(&stale))),
+        StaleAction::Error => Err(FreshnessError::Stale {
+            max_staleness,
+            stale,
+        }),
+        StaleAction::Wait => {
+            let longest_lag = tables
+                .iter()
+                .map(|(_, f)| f.typical_lag_seconds)
+                .max()
+                .unwrap_or(0);
+            let deadline = Instant::now() + Duration::from_secs(longest_lag);
+            while !stale.is_empty() {
+                if Instant::now() + WAIT_POLL_INTERVAL > deadline {
+                    return Err(FreshnessError::Stale {
+                        max_staleness,
+                        stale,
+                    });
+                }
+                tokio::time::sleep(WAIT_POLL_INTERVAL).await;
+                stale = stale_tables(executor, &tables, max_staleness, role).await?;
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// The `X-Query-Notification` of a result read from lagging tables.
+pub fn stale_notification(stale: &[(String, i64)]) -> String {
+    format!("Possibly stale results: {}", describe(stale))
+}
+
+fn describe(stale: &[(String, i64)]) -> String {
+    stale
+        .iter()
+        .map(|(table, lag)| format!("{} is {}s behind", table, lag))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stale_action_parses() {
+        assert_eq!("".parse::<StaleAction>().unwrap(), StaleAction::Annotate);
+        assert_eq!("Wait".parse::<StaleAction>().unwrap(), StaleAction::Wait);
+        assert_eq!("error".parse::<StaleAction>().unwrap(), StaleAction::Error);
+        assert!("retry".parse::<StaleAction>().is_err());
+    }
+
+    #[test]
+    fn table_references_respect_identifier_boundaries() {
+        let sql = "SELECT * FROM db.follows_mv AS f JOIN `db`.`users` AS u ON 1";
+        assert!(references(sql, "db.follows_mv"));
+        assert!(references(sql, "db.users"));
+        assert!(!references(sql, "db.follows"));
+        assert!(!references(sql, "b.follows_mv"));
+    }
+
+    #[test]
+    fn lag_is_read_from_numbers_and_quoted_integers() {
+        assert_eq!(lag_value(&serde_json::json!(12)), Some(12));
+        assert_eq!(lag_value(&serde_json::json!("12")), Some(12));
+        assert_eq!(lag_value(&Value::Null), None);
+    }
+
+    #[test]
+    fn stale_tables_are_described() {
+        let stale = vec![("db.follows_mv".to_string(), 95)];
+        assert_eq!(
+            stale_notification(&stale),
+            "Possibly stale results: db.follows_mv is 95s behind"
+        );
+        let err = FreshnessError::Stale {
+            max_staleness: 30,
+            stale,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Data is staler than max_staleness_secs = 30: db.follows_mv is 95s behind"
+        );
+    }
+}
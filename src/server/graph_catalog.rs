@@ -1,12 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::time::Duration;
 
 use clickhouse::Client;
 use tokio::{sync::RwLock, time::interval};
 
 use crate::graph_catalog::{
-    config::{GraphSchemaConfig, GraphSchemaDefinition},
-    graph_schema::{GraphSchema, GraphSchemaElement},
+    config::{DiscoveredTables, GraphSchemaConfig, GraphSchemaDefinition},
+    graph_schema::{GraphSchema, GraphSchemaElement, QueryCacheConfig},
+    schema_diff::SchemaDiff,
 };
 use crate::utils::id_encoding::IdEncoding;
 
@@ -100,63 +101,99 @@ async fn test_clickhouse_connection(client: Client) -> Result<(), String> {
         .map_err(|e| format!("ClickHouse connection test failed: {}", e))
 }
 
+/// A schema built from YAML: its name, the schema, its config and the table
+/// metadata it was built from (kept for completing lazy elements).
+type LoadedSchema = (String, GraphSchema, GraphSchemaConfig, DiscoveredTables);
+
+/// Build a schema, with auto-discovery and engine detection when a client
+/// is available (lazy elements are left to `lazy_schema`).
+async fn build_schema(
+    config: &GraphSchemaConfig,
+    clickhouse_client: Option<&Client>,
+) -> Result<(GraphSchema, DiscoveredTables), String> {
+    let discovered = match clickhouse_client {
+        Some(client) => {
+            config.validate().map_err(|e| e.to_string())?;
+            config
+                .discover_tables(client)
+                .await
+                .map_err(|e| e.to_string())?
+        }
+        None => DiscoveredTables::default(),
+    };
+    let schema = config
+        .to_graph_schema_discovered(&discovered)
+        .map_err(|e| e.to_string())?;
+    Ok((schema, discovered))
+}
+
+/// The views `config` declares, each built as its own graph named
+/// `<name>.<view>`.
+async fn build_views(
+    name: &str,
+    config: &GraphSchemaConfig,
+    clickhouse_client: Option<&Client>,
+) -> Result<Vec<LoadedSchema>, String> {
+    let mut views = Vec::new();
+    for view in config.graph_schema.views.keys() {
+        let view_name = format!("{}.{}", name, view);
+        let view_config = config
+            .for_view(&view_name, view)
+            .map_err(|e| format!("Failed to create view '{}': {}", view_name, e))?;
+        let (schema, discovered) = build_schema(&view_config, clickhouse_client)
+            .await
+            .map_err(|e| format!("Failed to create view '{}': {}", view_name, e))?;
+        views.push((view_name, schema, view_config, discovered));
+    }
+    Ok(views)
+}
+
 /// Load schema and config from YAML content string
 async fn load_schema_and_config_from_yaml_content(
     yaml_content: &str,
     clickhouse_client: Option<&Client>,
-) -> Result<(GraphSchema, GraphSchemaConfig), String> {
+) -> Result<(GraphSchema, GraphSchemaConfig, DiscoveredTables), String> {
     let config: GraphSchemaConfig = serde_yaml::from_str(yaml_content)
         .map_err(|e| format!("Failed to parse YAML config: {}", e))?;
 
-    let schema = if let Some(client) = clickhouse_client {
-        // Use auto-discovery and engine detection when client is available
-        config
-            .to_graph_schema_with_client(client)
-            .await
-            .map_err(|e| format!("Failed to create schema with auto-discovery: {}", e))?
-    } else {
-        // Fallback to manual mode without client
-        config
-            .to_graph_schema()
-            .map_err(|e| format!("Failed to create schema from config: {}", e))?
-    };
+    let (schema, discovered) = build_schema(&config, clickhouse_client)
+        .await
+        .map_err(|e| format!("Failed to create schema from config: {}", e))?;
 
-    Ok((schema, config))
+    Ok((schema, config, discovered))
 }
 
-/// Load multiple schemas from YAML content (supports both single and multi-schema format)
+/// Load multiple schemas from YAML content (supports both single and multi-schema format),
+/// with the names of its tenant graphs
 async fn load_schemas_from_yaml_content(
     yaml_content: &str,
     clickhouse_client: Option<&Client>,
-) -> Result<Vec<(String, GraphSchema, GraphSchemaConfig)>, String> {
+) -> Result<(Vec<LoadedSchema>, BTreeSet<String>), String> {
     use crate::graph_catalog::config::SchemaConfigFile;
 
     let config_file: SchemaConfigFile = serde_yaml::from_str(yaml_content)
         .map_err(|e| format!("Failed to parse YAML config: {}", e))?;
 
     let mut results = Vec::new();
+    let mut tenant_graphs = BTreeSet::new();
 
     match config_file {
         SchemaConfigFile::Single(config) => {
             // Single schema - use name from config or "default"
             let schema_name = config.name.clone().unwrap_or_else(|| "default".to_string());
 
-            let schema = if let Some(client) = clickhouse_client {
-                config
-                    .to_graph_schema_with_client(client)
-                    .await
-                    .map_err(|e| format!("Failed to create schema: {}", e))?
-            } else {
-                config
-                    .to_graph_schema()
-                    .map_err(|e| format!("Failed to create schema: {}", e))?
-            };
+            let (schema, discovered) = build_schema(&config, clickhouse_client)
+                .await
+                .map_err(|e| format!("Failed to create schema: {}", e))?;
 
-            results.push((schema_name, schema, config));
+            let views = build_views(&schema_name, &config, clickhouse_client).await?;
+            results.push((schema_name, schema, *config, discovered));
+            results.extend(views);
         }
         SchemaConfigFile::Multi {
             default_schema,
             schemas,
+            tenants,
         } => {
             // Multiple schemas
             for config in schemas {
@@ -164,28 +201,55 @@ async fn load_schemas_from_yaml_content(
                     "Each schema in multi-schema file must have a 'name' field".to_string()
                 })?;
 
-                let schema = if let Some(client) = clickhouse_client {
-                    config
-                        .to_graph_schema_with_client(client)
-                        .await
-                        .map_err(|e| format!("Failed to create schema '{}': {}", schema_name, e))?
-                } else {
-                    config
-                        .to_graph_schema()
-                        .map_err(|e| format!("Failed to create schema '{}': {}", schema_name, e))?
-                };
+                let (schema, discovered) = build_schema(&config, clickhouse_client)
+                    .await
+                    .map_err(|e| format!("Failed to create schema '{}': {}", schema_name, e))?;
 
-                results.push((schema_name.clone(), schema, config));
+                let views = build_views(&schema_name, &config, clickhouse_client).await?;
+                results.push((schema_name.clone(), schema, config, discovered));
+                results.extend(views);
+            }
+
+            // Tenant graphs: copies of a schema above, in their own database
+            for tenant in tenants {
+                if results.iter().any(|(name, _, _, _)| name == &tenant.name)
+                    || tenant.name == DEFAULT_GRAPH
+                {
+                    return Err(format!(
+                        "Tenant '{}' has the name of another graph",
+                        tenant.name
+                    ));
+                }
+                let base = results
+                    .iter()
+                    .find(|(name, _, _, _)| name == &tenant.schema)
+                    .map(|(_, _, config, _)| config)
+                    .ok_or_else(|| {
+                        format!(
+                            "Tenant '{}': schema '{}' not found in schemas list",
+                            tenant.name, tenant.schema
+                        )
+                    })?;
+                let config = base.for_tenant(&tenant.name, tenant.database.as_deref());
+                let (schema, discovered) = build_schema(&config, clickhouse_client)
+                    .await
+                    .map_err(|e| format!("Failed to create tenant '{}': {}", tenant.name, e))?;
+                // A tenant's views read its tables, and are isolated like it
+                let views = build_views(&tenant.name, &config, clickhouse_client).await?;
+                tenant_graphs.extend(views.iter().map(|(name, _, _, _)| name.clone()));
+                tenant_graphs.insert(tenant.name.clone());
+                results.push((tenant.name, schema, config, discovered));
+                results.extend(views);
             }
 
             // If default_schema specified, add "default" alias
             if let Some(default_name) = default_schema {
                 if let Some(idx) = results
                     .iter()
-                    .position(|(name, _, _)| name == &default_name)
+                    .position(|(name, _, _, _)| name == &default_name)
                 {
-                    let (_, schema, config) = results[idx].clone();
-                    results.push(("default".to_string(), schema, config));
+                    let (_, schema, config, discovered) = results[idx].clone();
+                    results.push(("default".to_string(), schema, config, discovered));
                 } else {
                     return Err(format!(
                         "default_schema '{}' not found in schemas list",
@@ -196,7 +260,37 @@ async fn load_schemas_from_yaml_content(
         }
     }
 
-    Ok(results)
+    Ok((results, tenant_graphs))
+}
+
+/// `--validate-schema` checks for every schema of a YAML file: tables and
+/// mapped columns must exist in ClickHouse. Skipped (with a warning) when no
+/// client is configured.
+async fn validate_schemas(
+    schemas_list: &[LoadedSchema],
+    clickhouse_client: Option<&Client>,
+) -> Result<(), String> {
+    let Some(client) = clickhouse_client else {
+        log::warn!("  ⚠ Schema validation requested but no ClickHouse client available");
+        log::warn!("    Skipping validation - some queries may fail at runtime");
+        return Ok(());
+    };
+    log::info!("  Validating schemas against ClickHouse...");
+    for (schema_name, _, config, _) in schemas_list {
+        match config
+            .validate_schema(&mut crate::graph_catalog::SchemaValidator::new(
+                client.clone(),
+            ))
+            .await
+        {
+            Ok(_) => log::info!("  ✓ Schema '{}' validation passed", schema_name),
+            Err(e) => {
+                log::warn!("  ✗ Schema '{}' validation failed: {}", schema_name, e);
+                return Err(format!("Schema '{}' validation failed: {}", schema_name, e));
+            }
+        }
+    }
+    Ok(())
 }
 
 pub async fn initialize_global_schema(
@@ -218,7 +312,7 @@ pub async fn initialize_global_schema(
 
         // Load schemas (supports both single and multi-schema formats)
         match load_schemas_from_yaml_content(&yaml_content, clickhouse_client.as_ref()).await {
-            Ok(schemas_list) => {
+            Ok((schemas_list, tenant_graphs)) => {
                 log::info!(
                     "✓ Loaded {} schema(s) from YAML: {}",
                     schemas_list.len(),
@@ -227,37 +321,7 @@ pub async fn initialize_global_schema(
 
                 // Validate schemas against ClickHouse if requested
                 if validate_schema {
-                    if let Some(client) = clickhouse_client.as_ref() {
-                        log::info!("  Validating schemas against ClickHouse...");
-                        for (schema_name, _, config) in &schemas_list {
-                            match config
-                                .validate_schema(&mut crate::graph_catalog::SchemaValidator::new(
-                                    client.clone(),
-                                ))
-                                .await
-                            {
-                                Ok(_) => {
-                                    log::info!("  ✓ Schema '{}' validation passed", schema_name)
-                                }
-                                Err(e) => {
-                                    log::warn!(
-                                        "  ✗ Schema '{}' validation failed: {}",
-                                        schema_name,
-                                        e
-                                    );
-                                    return Err(format!(
-                                        "Schema '{}' validation failed: {}",
-                                        schema_name, e
-                                    ));
-                                }
-                            }
-                        }
-                    } else {
-                        log::warn!(
-                            "  ⚠ Schema validation requested but no ClickHouse client available"
-                        );
-                        log::warn!("    Skipping validation - some queries may fail at runtime");
-                    }
+                    validate_schemas(&schemas_list, clickhouse_client.as_ref()).await?;
                 }
 
                 // Build schema maps
@@ -267,10 +331,21 @@ pub async fn initialize_global_schema(
 
                 // Check if "default" was explicitly set in config (from default_schema field)
                 let has_explicit_default =
-                    schemas_list.iter().any(|(name, _, _)| name == "default");
-                let mut first_schema_info: Option<(GraphSchema, GraphSchemaConfig)> = None;
+                    schemas_list.iter().any(|(name, _, _, _)| name == "default");
+                let mut first_schema_info: Option<(
+                    GraphSchema,
+                    GraphSchemaConfig,
+                    DiscoveredTables,
+                )> = None;
+                super::catalog_export::publish_in_background(
+                    schemas_list
+                        .iter()
+                        .map(|(name, _, config, _)| (name.clone(), config.clone()))
+                        .collect(),
+                );
 
-                for (schema_name, schema, config) in schemas_list {
+                for (schema_name, schema, config, discovered) in schemas_list {
+                    super::lazy_schema::track(&schema_name, &config, discovered.clone());
                     // Skip "default" here if it was explicitly set - we'll add it after the loop
                     if schema_name == "default" {
                         // Store to add after processing all schemas
@@ -293,13 +368,14 @@ pub async fn initialize_global_schema(
                         GLOBAL_SCHEMA_CONFIG
                             .set(RwLock::new(config.clone()))
                             .map_err(|_| "Failed to initialize global view config")?;
-                        first_schema_info = Some((schema, config));
+                        first_schema_info = Some((schema, config, discovered));
                     }
                 }
 
                 // If no explicit default was set, use the first schema as default
                 if !has_explicit_default {
-                    if let Some((schema, config)) = first_schema_info {
+                    if let Some((schema, config, discovered)) = first_schema_info {
+                        super::lazy_schema::track("default", &config, discovered);
                         schemas.insert("default".to_string(), schema);
                         view_configs.insert("default".to_string(), config);
                         log::info!("  ✓ First schema also registered as 'default'");
@@ -312,6 +388,7 @@ pub async fn initialize_global_schema(
                 GLOBAL_SCHEMA_CONFIGS
                     .set(RwLock::new(view_configs))
                     .map_err(|_| "Failed to initialize global view configs")?;
+                set_tenant_graphs(tenant_graphs);
 
                 println!(
                     "✓ Schema initialization complete (YAML mode, {} schema(s) registered)",
@@ -370,6 +447,12 @@ pub async fn initialize_global_schema(
                         edges: Vec::new(),
                         vector_indexes: Vec::new(),
                         fulltext_indexes: Vec::new(),
+                        constraints: Default::default(),
+                        unknown_labels: Default::default(),
+                        query_cache: Default::default(),
+                        snapshot_column: None,
+                        cluster: None,
+                        views: BTreeMap::new(),
                     },
                 };
                 view_configs.insert("default".to_string(), empty_config);
@@ -414,6 +497,12 @@ pub async fn initialize_global_schema(
                                 edges: Vec::new(),
                                 vector_indexes: Vec::new(),
                                 fulltext_indexes: Vec::new(),
+                                constraints: Default::default(),
+                                unknown_labels: Default::default(),
+                                query_cache: Default::default(),
+                                snapshot_column: None,
+                                cluster: None,
+                                views: BTreeMap::new(),
                             },
                         };
                         view_configs.insert("default".to_string(), empty_config);
@@ -466,6 +555,12 @@ pub async fn initialize_global_schema(
                 edges: Vec::new(),
                 vector_indexes: Vec::new(),
                 fulltext_indexes: Vec::new(),
+                constraints: Default::default(),
+                unknown_labels: Default::default(),
+                query_cache: Default::default(),
+                snapshot_column: None,
+                cluster: None,
+                views: BTreeMap::new(),
             },
         };
         view_configs.insert("default".to_string(), empty_config);
@@ -511,6 +606,51 @@ pub async fn get_view_config() -> Option<GraphSchemaConfig> {
     }
 }
 
+/// Name under which the default graph is registered (the schema named by
+/// `default_schema`, or the first one loaded).
+pub const DEFAULT_GRAPH: &str = "default";
+
+/// Pick the graph a query runs against: its `USE` clause, then the graph the
+/// request or connection selected, then [`DEFAULT_GRAPH`].
+pub fn resolve_graph_name(use_graph: Option<&str>, requested: Option<&str>) -> String {
+    use_graph.or(requested).unwrap_or(DEFAULT_GRAPH).to_string()
+}
+
+/// Graphs declared under `tenants:` in the schema file.
+static TENANT_GRAPHS: std::sync::RwLock<BTreeSet<String>> = std::sync::RwLock::new(BTreeSet::new());
+
+fn set_tenant_graphs(graphs: BTreeSet<String>) {
+    *TENANT_GRAPHS.write().unwrap_or_else(|e| e.into_inner()) = graphs;
+}
+
+/// Whether `graph` is a tenant graph, isolated from every other graph.
+pub fn is_tenant_graph(graph: &str) -> bool {
+    TENANT_GRAPHS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .contains(graph)
+}
+
+/// The graph a request or connection is confined to: the one named by the
+/// `X-Graph-Name` header, else the selected graph when it is a tenant graph.
+pub fn pinned_graph(header: Option<&str>, requested: Option<&str>) -> Option<String> {
+    header
+        .or(requested.filter(|graph| is_tenant_graph(graph)))
+        .map(str::to_string)
+}
+
+/// Reject a query whose target graph (after `USE`) is not the graph its
+/// request or connection is confined to.
+pub fn check_graph_isolation(pinned: Option<&str>, target: &str) -> Result<(), String> {
+    match pinned {
+        Some(pinned) if pinned != target => Err(format!(
+            "Graph '{}' is not accessible from a session on graph '{}'",
+            target, pinned
+        )),
+        _ => Ok(()),
+    }
+}
+
 // Multi-schema support functions - NEW
 pub async fn get_graph_schema_by_name(schema_name: &str) -> Result<GraphSchema, String> {
     let schemas_guard = GLOBAL_SCHEMAS
@@ -519,10 +659,42 @@ pub async fn get_graph_schema_by_name(schema_name: &str) -> Result<GraphSchema,
         .read()
         .await;
 
-    schemas_guard
-        .get(schema_name)
-        .cloned()
-        .ok_or(format!("Schema '{}' not found", schema_name))
+    schemas_guard.get(schema_name).cloned().ok_or_else(|| {
+        let mut available: Vec<&str> = schemas_guard.keys().map(String::as_str).collect();
+        available.sort_unstable();
+        format!(
+            "Schema '{}' not found. Available schemas: {:?}",
+            schema_name, available
+        )
+    })
+}
+
+/// `schema_name` with the request's inline `overrides` fragment merged in,
+/// for that request only. Nothing is registered; the shared schema and its
+/// cached plans are unaffected.
+pub async fn get_graph_schema_with_overrides(
+    schema_name: &str,
+    overrides: &str,
+) -> Result<GraphSchema, String> {
+    let config = get_view_config_by_name(schema_name)
+        .await?
+        .with_overrides(overrides)
+        .map_err(|e| e.to_string())?;
+    config.validate().map_err(|e| e.to_string())?;
+    config.to_graph_schema().map_err(|e| e.to_string())
+}
+
+/// The query cache defaults of `schema_name`, or none for an unknown schema.
+pub async fn get_query_cache_config(schema_name: &str) -> QueryCacheConfig {
+    match GLOBAL_SCHEMAS.get() {
+        Some(schemas) => schemas
+            .read()
+            .await
+            .get(schema_name)
+            .map(GraphSchema::query_cache)
+            .unwrap_or_default(),
+        None => QueryCacheConfig::default(),
+    }
 }
 
 pub async fn get_view_config_by_name(schema_name: &str) -> Result<GraphSchemaConfig, String> {
@@ -556,7 +728,7 @@ pub async fn load_schema_from_content(
     println!("Loading schema '{}' from YAML content", schema_name);
 
     match load_schema_and_config_from_yaml_content(yaml_content, None).await {
-        Ok((schema, config)) => {
+        Ok((schema, config, discovered)) => {
             println!(
                 "✓ Successfully loaded schema '{}' from YAML content",
                 schema_name
@@ -591,19 +763,42 @@ pub async fn load_schema_from_content(
 
             // Warn (not block) if this name was already registered with different content.
             warn_on_schema_content_collision(schema_name, yaml_content).await;
+            let views = build_views(schema_name, &config, None).await?;
 
             // Add to multi-schema storage
             let schemas_lock = GLOBAL_SCHEMAS
                 .get()
                 .ok_or("Global schemas not initialized")?;
             let mut schemas_guard = schemas_lock.write().await;
+            super::lazy_schema::track(schema_name, &config, discovered.clone());
             schemas_guard.insert(schema_name.to_string(), schema.clone());
+            // A server started without a schema only has an empty placeholder
+            // default graph; the first loaded schema takes its place so queries
+            // without USE / schema_name have something to run against.
+            let becomes_default = schema_name != DEFAULT_GRAPH
+                && schemas_guard.get(DEFAULT_GRAPH).is_none_or(|d| {
+                    d.all_node_schemas().is_empty() && d.get_relationships_schemas().is_empty()
+                });
+            if becomes_default {
+                super::lazy_schema::track(DEFAULT_GRAPH, &config, discovered);
+                schemas_guard.insert(DEFAULT_GRAPH.to_string(), schema.clone());
+                log::info!("Schema '{}' is now the default graph", schema_name);
+            }
 
             let configs_lock = GLOBAL_SCHEMA_CONFIGS
                 .get()
                 .ok_or("Global view configs not initialized")?;
             let mut configs_guard = configs_lock.write().await;
-            configs_guard.insert(schema_name.to_string(), config);
+            if becomes_default {
+                configs_guard.insert(DEFAULT_GRAPH.to_string(), config.clone());
+            }
+            configs_guard.insert(schema_name.to_string(), config.clone());
+            for (view_name, view_schema, view_config, view_discovered) in views {
+                super::lazy_schema::track(&view_name, &view_config, view_discovered);
+                schemas_guard.insert(view_name.clone(), view_schema);
+                configs_guard.insert(view_name, view_config);
+            }
+            super::catalog_export::publish_in_background(vec![(schema_name.to_string(), config)]);
 
             println!(
                 "✓ Schema '{}' loaded successfully and registered in GLOBAL_SCHEMAS",
@@ -615,6 +810,111 @@ pub async fn load_schema_from_content(
     }
 }
 
+/// Re-read the YAML at `GRAPH_CONFIG_PATH` and swap its schemas in place.
+///
+/// The file is parsed (and validated when requested) before anything is
+/// touched, so a broken file leaves the running schemas as they were. The
+/// swap happens under both catalog write locks, so queries see either the
+/// old or the new version of a schema, never a mix. Schemas loaded at
+/// runtime via `/schemas/load` under other names are kept.
+///
+/// Returns the diff of every schema name registered from the file.
+pub async fn reload_global_schema(
+    clickhouse_client: Option<Client>,
+    validate_schema: bool,
+) -> Result<Vec<(String, SchemaDiff)>, String> {
+    let yaml_config_path = std::env::var("GRAPH_CONFIG_PATH")
+        .map_err(|_| "GRAPH_CONFIG_PATH is not set; nothing to reload".to_string())?;
+    let yaml_content = std::fs::read_to_string(&yaml_config_path)
+        .map_err(|e| format!("Failed to read YAML file {}: {}", yaml_config_path, e))?;
+
+    let (mut schemas_list, tenant_graphs) =
+        load_schemas_from_yaml_content(&yaml_content, clickhouse_client.as_ref()).await?;
+    if validate_schema {
+        validate_schemas(&schemas_list, clickhouse_client.as_ref()).await?;
+    }
+    // Same default as at startup: an explicit default_schema, else the first.
+    if !schemas_list
+        .iter()
+        .any(|(name, _, _, _)| name == DEFAULT_GRAPH)
+    {
+        if let Some((_, schema, config, discovered)) = schemas_list.first().cloned() {
+            schemas_list.push((DEFAULT_GRAPH.to_string(), schema, config, discovered));
+        }
+    }
+
+    let schemas_lock = GLOBAL_SCHEMAS
+        .get()
+        .ok_or("Global schemas not initialized")?;
+    let configs_lock = GLOBAL_SCHEMA_CONFIGS
+        .get()
+        .ok_or("Global view configs not initialized")?;
+    let mut schemas_guard = schemas_lock.write().await;
+    let mut configs_guard = configs_lock.write().await;
+
+    super::catalog_export::publish_in_background(
+        schemas_list
+            .iter()
+            .map(|(name, _, config, _)| (name.clone(), config.clone()))
+            .collect(),
+    );
+    let mut diffs = Vec::with_capacity(schemas_list.len());
+    for (schema_name, schema, config, discovered) in schemas_list {
+        diffs.push((
+            schema_name.clone(),
+            SchemaDiff::between(schemas_guard.get(&schema_name), &schema),
+        ));
+        preregister_schema_labels(&schema);
+        if let Some(cache) = super::GLOBAL_QUERY_CACHE.get() {
+            cache.invalidate_schema(&schema_name);
+        }
+        super::lazy_schema::track(&schema_name, &config, discovered);
+        schemas_guard.insert(schema_name.clone(), schema);
+        configs_guard.insert(schema_name, config);
+    }
+    set_tenant_graphs(tenant_graphs);
+
+    log::info!(
+        "✓ Reloaded {} schema(s) from {}",
+        diffs.len(),
+        yaml_config_path
+    );
+    Ok(diffs)
+}
+
+/// Poll the mtime of `GRAPH_CONFIG_PATH` and hot-reload it when it changes.
+///
+/// Reloads validate against ClickHouse when a client is available; a failed
+/// reload is logged and the previous schemas stay active.
+pub async fn watch_schema_file(clickhouse_client: Option<Client>, every: Duration) {
+    let Ok(path) = std::env::var("GRAPH_CONFIG_PATH") else {
+        return;
+    };
+    let modified = |path: &str| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    let mut last_modified = modified(&path);
+    let mut ticker = interval(every);
+    log::info!("Watching {} for schema changes every {:?}", path, every);
+
+    loop {
+        ticker.tick().await;
+        let current = modified(&path);
+        if current.is_none() || current == last_modified {
+            continue;
+        }
+        last_modified = current;
+
+        let validate = clickhouse_client.is_some();
+        match reload_global_schema(clickhouse_client.clone(), validate).await {
+            Ok(diffs) => {
+                for (schema_name, diff) in diffs.iter().filter(|(_, d)| !d.is_empty()) {
+                    log::info!("Schema '{}' reloaded: {:?}", schema_name, diff);
+                }
+            }
+            Err(e) => log::warn!("Schema reload from {} failed: {}", path, e),
+        }
+    }
+}
+
 pub async fn get_graph_catalog(clickhouse_client: Client) -> Result<GraphSchema, String> {
     let graph_catalog_query = "SELECT id, schema_json FROM graph_catalog FINAL";
     let graph_catalog_result: Result<GraphCatalog, clickhouse::error::Error> = clickhouse_client
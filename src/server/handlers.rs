@@ -1,31 +1,48 @@
-use std::{collections::HashMap, sync::Arc, time::Instant};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use axum::{
     extract::State,
-    http::{header, HeaderValue, StatusCode},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
 use clickhouse::Client;
+use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::{
     clickhouse_query_generator,
-    graph_catalog::graph_schema::{GraphSchema, GraphSchemaElement},
+    graph_catalog::graph_schema::{GraphSchema, GraphSchemaElement, QueryCacheConfig},
+    graph_catalog::{
+        denormalization_advisor, graph_constraints, llm_context, schema_lint, schema_templates,
+    },
     graph_catalog::{DraftOptions, DraftRequest, EdgeHint, FkEdgeHint, NodeHint, SchemaDiscovery},
-    open_cypher_parser::{self, ast::CypherStatement},
-    query_planner::{self, types::QueryType},
-    render_plan::plan_builder::RenderPlanBuilder,
+    open_cypher_parser::{
+        self,
+        ast::{CypherStatement, ExplainMode},
+    },
+    query_planner::{self, parameter_types, types::QueryType},
+    render_plan::{plan_builder::RenderPlanBuilder, CteContent, RenderPlan},
+    utils::redaction::{self, redact, redact_sql},
 };
 
 use super::{
-    graph_catalog,
+    attribution, autocomplete, cancellation, continuation, endpoint_routing, error_hints, explain,
+    freshness, graph_catalog, lazy_schema,
     metrics::{self, ErrorClass, Outcome, QuerySample},
     models::{GraphQueryResponse, OutputFormat, QueryRequest, QueryStats, SqlOnlyResponse},
     parameter_substitution, query_cache,
-    query_context::{with_query_context, QueryContext},
-    AppState, GLOBAL_QUERY_CACHE, GLOBAL_SERVER_METRICS,
+    query_context::{self, with_query_context, QueryContext},
+    query_settings,
+    recursion_depth::{self, RecursionDepthPolicy},
+    script, session_params, stored_queries, AppState, GLOBAL_QUERY_CACHE, GLOBAL_SERVER_METRICS,
+    GLOBAL_WORKLOAD_LOG,
 };
 
 /// Record a completed query into the global registry (no-op if metrics are off
@@ -42,6 +59,16 @@ fn record_query(metrics: &QueryPerformanceMetrics, query_text: &str, outcome: Ou
     }
 }
 
+/// Log the traversals of a successful read for the denormalization advisor
+/// (no-op unless the workload log is on).
+async fn record_workload(schema_name: &str, query: &str, metrics: &QueryPerformanceMetrics) {
+    if let Some(log) = GLOBAL_WORKLOAD_LOG.get() {
+        if let Ok(schema) = graph_catalog::get_graph_schema_by_name(schema_name).await {
+            log.record(schema_name, &schema, query, metrics.total_time * 1000.0);
+        }
+    }
+}
+
 /// Merge view_parameters and query parameters into a single HashMap
 ///
 /// Both view_parameters and parameters can contain values that need to be substituted
@@ -319,10 +346,153 @@ pub async fn stats_queries_handler(
     .into_response()
 }
 
+/// Header naming the graph a request runs against and is confined to.
+pub const GRAPH_NAME_HEADER: &str = "X-Graph-Name";
+
+/// The graph named by a request's `X-Graph-Name` header, if any.
+pub(super) fn graph_name_header(
+    headers: &HeaderMap,
+) -> Result<Option<String>, (StatusCode, String)> {
+    let Some(value) = headers.get(GRAPH_NAME_HEADER) else {
+        return Ok(None);
+    };
+    let graph = value.to_str().map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            format!("{} must be a graph name", GRAPH_NAME_HEADER),
+        )
+    })?;
+    Ok(Some(graph.to_string()))
+}
+
+/// Confine `payload` to the graph of its `X-Graph-Name` header, which also
+/// becomes its `schema_name` when none is given.
+pub(super) fn pin_graph_header(
+    headers: &HeaderMap,
+    payload: &mut QueryRequest,
+) -> Result<(), (StatusCode, String)> {
+    let Some(graph) = graph_name_header(headers)? else {
+        return Ok(());
+    };
+    payload.schema_name.get_or_insert_with(|| graph.clone());
+    payload.pinned_graph = Some(graph);
+    Ok(())
+}
+
 pub async fn query_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(mut payload): Json<QueryRequest>,
+) -> Result<Response, Response> {
+    pin_graph_header(&headers, &mut payload).map_err(IntoResponse::into_response)?;
+    // `:param name => value` sets a session parameter; other statements of
+    // the session see its parameters under their own
+    if let Some(response) = session_params::command_response(&headers, &payload.query) {
+        return Ok(response);
+    }
+    if let Some(id) = session_params::session_id(&headers) {
+        let session = session_params::store().parameters(id);
+        payload.parameters = session_params::merge(&session, payload.parameters.take());
+    }
+    // `a; b; c` runs each statement in turn and answers with all results.
+    if let Some(statements) = script::multiple_statements(&payload.query) {
+        return script::run_statements(app_state, payload, statements).await;
+    }
+    run_statement(State(app_state), Json(payload)).await
+}
+
+/// Run one statement through the `/query` pipeline.
+pub(crate) async fn run_statement(
+    State(app_state): State<Arc<AppState>>,
+    Json(mut payload): Json<QueryRequest>,
+) -> Result<Response, Response> {
+    // A tenant graph confines the request like `X-Graph-Name` does, stored
+    // queries included
+    if payload.pinned_graph.is_none() {
+        payload.pinned_graph = graph_catalog::pinned_graph(None, payload.schema_name.as_deref());
+    }
+
+    // `CALL query.run('name', {...})` runs a stored query: swap in its
+    // statement and validated parameters, then go through the normal path.
+    let empty = HashMap::new();
+    let stored = match stored_queries::expand_run_call(
+        &payload.query,
+        payload.parameters.as_ref().unwrap_or(&empty),
+        payload.role.as_deref(),
+    ) {
+        Ok(stored) => stored,
+        Err(e) => return Err((e.status(), e.to_string()).into_response()),
+    };
+    let Some(stored) = stored else {
+        return run_with_timeout(app_state, payload).await;
+    };
+
+    log::info!("Running stored query '{}'", stored.name);
+    payload.query = stored.query;
+    payload.parameters = Some(stored.parameters);
+    if stored.schema_name.is_some() {
+        payload.schema_name = stored.schema_name;
+    }
+    let start = Instant::now();
+    let response = match run_with_timeout(app_state, payload).await {
+        Ok(resp) => resp,
+        Err(resp) => resp,
+    };
+    let ok = response.status().is_success();
+    stored_queries::registry().record(&stored.name, start.elapsed(), ok);
+    if ok {
+        Ok(response)
+    } else {
+        Err(response)
+    }
+}
+
+/// Run one statement within its timeout (`timeout_ms`, capped by
+/// `query_timeout_secs`). Its ClickHouse queries are killed when it times
+/// out or the client disconnects.
+async fn run_with_timeout(
+    app_state: Arc<AppState>,
+    payload: QueryRequest,
+) -> Result<Response, Response> {
+    let timeout =
+        cancellation::effective_timeout(payload.timeout_ms, app_state.config.query_timeout_secs);
+    let executor = app_state.executor.clone();
+    let max_cte_depth = app_state.config.max_cte_depth;
+    let billed_to = attribution::Attribution {
+        schema: payload.schema_name.clone(),
+        tenant: payload.tenant_id.clone(),
+        role: payload.role.clone(),
+    };
+    // Boxed: the statement's future is large, and the timeout wrapper would
+    // otherwise add another copy of it to the stack
+    let statement = Box::pin(attribution::scope(
+        billed_to,
+        run_query_request(State(app_state), Json(payload)),
+    ));
+    match cancellation::run(executor, timeout, statement).await {
+        Ok(result) => result
+            .map(IntoResponse::into_response)
+            .map_err(|(status, message)| {
+                // Known ClickHouse failures get a structured body with a remedy
+                match error_hints::error_body(&message, max_cte_depth) {
+                    Some(body) => (status, Json(body)).into_response(),
+                    None => (status, message).into_response(),
+                }
+            }),
+        Err(timed_out) => {
+            log::warn!("{}", timed_out);
+            if let Some(reg) = GLOBAL_SERVER_METRICS.get() {
+                reg.record_error(ErrorClass::Exec);
+            }
+            Err((StatusCode::REQUEST_TIMEOUT, timed_out.to_string()).into_response())
+        }
+    }
+}
+
+async fn run_query_request(
     State(app_state): State<Arc<AppState>>,
     Json(payload): Json<QueryRequest>,
-) -> Result<impl IntoResponse, impl IntoResponse> {
+) -> Result<impl IntoResponse, (StatusCode, String)> {
     // Acquire concurrency permit if semaphore is configured
     let _permit = if let Some(sem) = app_state.query_semaphore.clone() {
         match sem.try_acquire_owned() {
@@ -357,24 +527,69 @@ pub async fn query_handler(
     let start_time = Instant::now();
     let metrics = QueryPerformanceMetrics::new();
 
-    log::debug!("Query handler called with query: {}", payload.query);
+    log::debug!(
+        "Query handler called with query: {}",
+        redact(&payload.query)
+    );
 
     // Extract all needed fields from payload BEFORE any partial moves
     // Use clone() or take() to avoid partial move issues
     let output_format = payload.format.clone().unwrap_or(OutputFormat::JSONEachRow);
     let sql_only = payload.sql_only.unwrap_or(false);
+    if payload.stream.unwrap_or(false) && output_format != OutputFormat::JSONEachRow {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "stream is only supported with format JSONEachRow".to_string(),
+        ));
+    }
+    let paging = continuation::Paging::from_request(&payload)?;
+    if paging.is_some()
+        && (payload.stream.unwrap_or(false) || output_format != OutputFormat::JSONEachRow)
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "max_response_bytes and continuation_token are only supported with non-streamed format JSONEachRow"
+                .to_string(),
+        ));
+    }
+    if payload.schema_overrides.is_some() && !app_state.config.allow_schema_overrides {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "schema_overrides are disabled on this server (CLICKGRAPH_ALLOW_SCHEMA_OVERRIDES)"
+                .to_string(),
+        ));
+    }
     let query_string = payload.query.clone();
     let schema_name_param = payload.schema_name.clone();
 
-    // Query cache integration - Strip CYPHER prefix FIRST
+    // Strip comments (// and /* */) first, so one before the CYPHER prefix
+    // doesn't hide it (the Bolt path does the same)
+    let uncommented_query = open_cypher_parser::strip_comments(&query_string);
+
+    // Query cache integration - Strip CYPHER prefix
     // Extract replan option and clean query
-    let replan_option = query_cache::ReplanOption::from_query_prefix(&query_string)
+    let replan_option = query_cache::ReplanOption::from_query_prefix(&uncommented_query)
         .unwrap_or(query_cache::ReplanOption::Default);
-    let clean_query_with_comments = query_cache::ReplanOption::strip_prefix(&query_string);
+    let clean_query = query_cache::ReplanOption::strip_prefix(&uncommented_query).to_string();
+
+    // The first query against a schema checks its pending lazy elements
+    let target_schema = graph_catalog::resolve_graph_name(
+        extract_schema_from_use_clause(&clean_query).as_deref(),
+        schema_name_param.as_deref(),
+    );
+    graph_catalog::check_graph_isolation(payload.pinned_graph.as_deref(), &target_schema)
+        .map_err(|e| (StatusCode::FORBIDDEN, e))?;
+    lazy_schema::ensure_ready(
+        app_state.executor.as_ref(),
+        &target_schema,
+        Duration::from_secs(app_state.config.lazy_schema_retry_secs),
+    )
+    .await;
 
-    // Strip SQL-style comments (-- and /* */) before parsing
-    let clean_query_string = open_cypher_parser::strip_comments(clean_query_with_comments);
-    let clean_query = clean_query_string.clone();
+    // EXPLAIN / PROFILE: plan (and for PROFILE, run) the statement that follows
+    if let (Some(mode), explained) = open_cypher_parser::split_explain_prefix(&clean_query) {
+        return explain_query(&app_state, &payload, mode, explained, schema_name_param).await;
+    }
 
     // Handle SHOW DATABASES early (special case for Neo4j browser compatibility)
     let clean_upper = clean_query.trim().to_uppercase();
@@ -406,11 +621,10 @@ pub async fn query_handler(
     if clean_upper.contains("APOC.META.SCHEMA") && clean_upper.contains("UNWIND") {
         log::info!("Detected apoc.meta.schema MCP query — short-circuiting with unwound results");
 
-        // Determine schema: payload param > USE clause > "default"
-        let schema_name = schema_name_param
-            .clone()
-            .or_else(|| extract_schema_from_use_clause(&clean_query))
-            .unwrap_or_else(|| "default".to_string());
+        let schema_name = graph_catalog::resolve_graph_name(
+            extract_schema_from_use_clause(&clean_query).as_deref(),
+            schema_name_param.as_deref(),
+        );
 
         let schema_guard = crate::server::GLOBAL_SCHEMAS.get().ok_or_else(|| {
             (
@@ -478,10 +692,16 @@ pub async fn query_handler(
         log::info!("Detected COPY TO: destination={}", destination);
         let export_start = Instant::now();
 
-        // Resolve schema
-        let schema_name_for_export = schema_name_param
-            .clone()
-            .unwrap_or_else(|| "default".to_string());
+        // Resolve schema (a USE clause inside the COPY subquery wins)
+        let schema_name_for_export = graph_catalog::resolve_graph_name(
+            extract_schema_from_use_clause(&inner_query).as_deref(),
+            schema_name_param.as_deref(),
+        );
+        graph_catalog::check_graph_isolation(
+            payload.pinned_graph.as_deref(),
+            &schema_name_for_export,
+        )
+        .map_err(|e| (StatusCode::FORBIDDEN, e))?;
         let graph_schema =
             match graph_catalog::get_graph_schema_by_name(&schema_name_for_export).await {
                 Ok(s) => s,
@@ -511,7 +731,7 @@ pub async fn query_handler(
         })
         .await?;
 
-        log::debug!("COPY TO SQL: {}", export_sql);
+        log::debug!("COPY TO SQL: {}", redact_sql(&export_sql));
 
         // If sql_only, return the SQL
         if sql_only {
@@ -555,7 +775,7 @@ pub async fn query_handler(
     // Parse to check if it's a procedure call or procedure-only query
     let (_is_procedure, is_union, proc_name_opt) =
         if let Ok((_, parsed_stmt)) = open_cypher_parser::parse_cypher_statement(&clean_query) {
-            log::debug!("Parse succeeded for query: {}", &clean_query);
+            log::debug!("Parse succeeded for query: {}", redact(&clean_query));
 
             // Check if it's a procedure-only statement
             let proc_check = crate::procedures::is_procedure_only_statement(&parsed_stmt);
@@ -586,7 +806,7 @@ pub async fn query_handler(
 
             (proc_check, union_check, proc_name)
         } else {
-            log::debug!("Parse FAILED for query: {}", &clean_query);
+            log::debug!("Parse FAILED for query: {}", redact(&clean_query));
             (false, false, None)
         };
 
@@ -605,7 +825,10 @@ pub async fn query_handler(
         };
 
         let registry = crate::procedures::ProcedureRegistry::new();
-        let schema_name = schema_name_param.unwrap_or_else(|| "default".to_string());
+        let schema_name = graph_catalog::resolve_graph_name(
+            extract_schema_from_use_clause(&clean_query).as_deref(),
+            schema_name_param.as_deref(),
+        );
 
         // Now execute (no lifetimes involved)
         let results =
@@ -674,10 +897,10 @@ pub async fn query_handler(
             };
 
             // Resolve schema
-            // Resolve schema
-            let schema_name_for_export = schema_name_param
-                .clone()
-                .unwrap_or_else(|| "default".to_string());
+            let schema_name_for_export = graph_catalog::resolve_graph_name(
+                extract_schema_from_use_clause(&clean_query).as_deref(),
+                schema_name_param.as_deref(),
+            );
             let graph_schema =
                 match graph_catalog::get_graph_schema_by_name(&schema_name_for_export).await {
                     Ok(s) => s,
@@ -703,7 +926,7 @@ pub async fn query_handler(
             )
             .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
 
-            log::info!("Export SQL: {}", export_sql);
+            log::info!("Export SQL: {}", redact_sql(&export_sql));
 
             // If sql_only, return the SQL
             if sql_only {
@@ -717,10 +940,12 @@ pub async fn query_handler(
 
             // Execute the export SQL (INSERT produces no result rows)
             let role = payload.role.as_deref();
-            match app_state
-                .executor
-                .execute_text(&export_sql, "TabSeparated", role)
-                .await
+            match endpoint_routing::batch(app_state.executor.execute_text(
+                &export_sql,
+                "TabSeparated",
+                role,
+            ))
+            .await
             {
                 Ok(_) => {
                     log::info!(
@@ -743,228 +968,852 @@ pub async fn query_handler(
             }
         }
 
-        // ── Vector search procedures: db.index.vector.queryNodes ──
-        // These bypass ProcedureRegistry because they need ClickHouse execution.
-        if crate::procedures::vector_search::is_vector_search_procedure(&proc_name) {
-            log::info!("Detected vector search procedure");
-
-            let search_start = Instant::now();
+        // ── Graph construction: graph.construct(cypher, mapping) ──
+        // Writes the inner query's rows into node/relationship tables.
+        if crate::procedures::graph_construct::is_construct_procedure(&proc_name) {
+            let construct_start = Instant::now();
 
-            // Re-parse to extract arguments and USE clause
-            let search_args;
-            let use_schema_name;
-            {
+            let spec = {
                 let (_, stmt) =
                     open_cypher_parser::parse_cypher_statement(&clean_query).map_err(|e| {
                         (
                             StatusCode::BAD_REQUEST,
-                            format!("Failed to parse vector search call: {}", e),
+                            format!("Failed to parse graph.construct call: {}", e),
                         )
                     })?;
                 let expressions: Vec<_> = match &stmt {
-                    CypherStatement::ProcedureCall(pc) => {
-                        use_schema_name = None;
-                        pc.arguments.iter().collect()
-                    }
-                    CypherStatement::Query { query, .. } => {
-                        use_schema_name = query
-                            .use_clause
-                            .as_ref()
-                            .map(|uc| uc.database_name.to_string());
-                        let cc = query.call_clause.as_ref().ok_or_else(|| {
-                            (
-                                StatusCode::BAD_REQUEST,
-                                "No CALL clause found in vector search query".to_string(),
-                            )
-                        })?;
-                        cc.arguments.iter().map(|a| &a.value).collect()
-                    }
-                    CypherStatement::CopyTo(_) => {
-                        return Err((
-                            StatusCode::BAD_REQUEST,
-                            "Unexpected COPY TO in vector search context".to_string(),
-                        ));
-                    }
+                    CypherStatement::ProcedureCall(pc) => pc.arguments.iter().collect(),
+                    CypherStatement::Query { query, .. } => query
+                        .call_clause
+                        .as_ref()
+                        .map(|cc| cc.arguments.iter().map(|a| &a.value).collect())
+                        .unwrap_or_default(),
+                    CypherStatement::CopyTo(_) => Vec::new(),
                 };
-                search_args =
-                    crate::procedures::vector_search::parse_vector_search_args(&expressions)
-                        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
-            }
+                crate::procedures::graph_construct::parse_construct_call(&expressions)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e))?
+            };
 
-            // Resolve schema: USE clause > schema_name param > "default"
-            let schema_name_for_search = use_schema_name
-                .or_else(|| schema_name_param.clone())
-                .unwrap_or_else(|| "default".to_string());
-            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_search)
+            let schema_name_for_construct = graph_catalog::resolve_graph_name(
+                extract_schema_from_use_clause(&clean_query).as_deref(),
+                schema_name_param.as_deref(),
+            );
+            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_construct)
                 .await
                 .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            crate::server::query_context::set_current_schema(Arc::new(graph_schema.clone()));
 
-            let index_config = crate::procedures::vector_search::resolve_vector_index(
+            let inner_query = open_cypher_parser::strip_comments(&spec.cypher_query);
+            let fields = crate::procedures::graph_construct::result_fields(&inner_query)
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            let targets = spec
+                .resolve(&graph_schema, &fields)
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            let inner_sql = translate_cypher_to_sql(
+                &inner_query,
                 &graph_schema,
-                &search_args.index_name,
-            )
-            .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
-
-            // Generate SQL
-            let search_sql = crate::procedures::vector_search::build_vector_search_sql(
-                &search_args,
-                index_config,
+                &schema_name_for_construct,
+                app_state.config.max_cte_depth,
             )
             .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            let statements: Vec<String> = targets
+                .iter()
+                .map(|target| {
+                    crate::procedures::graph_construct::build_construct_sql(
+                        &inner_sql,
+                        target,
+                        spec.batch_size,
+                    )
+                })
+                .collect();
 
-            log::debug!(
-                "Vector search: index='{}', top_k={}, sql_length={}",
-                search_args.index_name,
-                search_args.top_k,
-                search_sql.len()
-            );
-
-            // If sql_only, return the SQL
             if sql_only {
                 let response = SqlOnlyResponse {
                     cypher_query: payload.query.clone(),
-                    generated_sql: search_sql,
+                    generated_sql: statements.join(";\n"),
                     execution_mode: "sql_only".to_string(),
                 };
                 return Ok(Json(response).into_response());
             }
+            if !app_state.config.allow_graph_construct {
+                return Err((
+                    StatusCode::FORBIDDEN,
+                    "graph.construct writes are disabled on this server \
+                     (CLICKGRAPH_ALLOW_GRAPH_CONSTRUCT); use sql_only to preview them"
+                        .to_string(),
+                ));
+            }
 
-            // Execute and return results as JSON
+            // Run target by target, nodes first; stop at the first failure.
             let role = payload.role.as_deref();
-            match app_state
-                .executor
-                .execute_text(&search_sql, "JSONEachRow", role)
+            let mut written = Vec::with_capacity(targets.len());
+            for (target, sql) in targets.iter().zip(&statements) {
+                let target_start = Instant::now();
+                log::info!("graph.construct → {}: {}", target.name, redact_sql(sql));
+                if let Err(e) = endpoint_routing::batch(app_state.executor.execute_text(
+                    sql,
+                    "TabSeparated",
+                    role,
+                ))
                 .await
-            {
-                Ok(result_text) => {
-                    log::info!(
-                        "Vector search completed in {:.3} seconds",
-                        search_start.elapsed().as_secs_f64()
-                    );
-                    // Parse JSONEachRow response, failing if any row is malformed
-                    let rows: Vec<serde_json::Value> = result_text
-                        .lines()
-                        .filter(|line| !line.is_empty())
-                        .map(serde_json::from_str::<serde_json::Value>)
-                        .collect::<Result<Vec<_>, _>>()
-                        .map_err(|e| {
-                            (
-                                StatusCode::INTERNAL_SERVER_ERROR,
-                                format!("Failed to parse ClickHouse JSONEachRow response: {}", e),
-                            )
-                        })?;
-                    return Ok(Json(serde_json::json!(rows)).into_response());
-                }
-                Err(e) => {
+                {
                     return Err((
                         StatusCode::INTERNAL_SERVER_ERROR,
-                        format!("Vector search execution failed: {}", e),
+                        format!(
+                            "graph.construct failed writing {}: {} (already written: [{}])",
+                            target.name,
+                            e,
+                            targets[..written.len()]
+                                .iter()
+                                .map(|t| t.name.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
                     ));
                 }
+                written.push(serde_json::json!({
+                    "target": target.name,
+                    "table": target.table_name(),
+                    "columns": target.columns.iter().map(|(c, _)| c).collect::<Vec<_>>(),
+                    "elapsed_ms": target_start.elapsed().as_millis() as u64,
+                }));
             }
+            log::info!(
+                "graph.construct wrote {} target(s) in {:.3} seconds",
+                written.len(),
+                construct_start.elapsed().as_secs_f64()
+            );
+            return Ok(Json(serde_json::json!({
+                "source": spec.cypher_query,
+                "batch_size": spec.batch_size,
+                "targets": written,
+            }))
+            .into_response());
         }
 
-        // ── Fulltext search procedures: db.index.fulltext.queryNodes ──
-        // Same intercept pattern as vector search.
-        if crate::procedures::fulltext_search::is_fulltext_search_procedure(&proc_name) {
-            log::info!("Detected fulltext search procedure");
-
-            let search_start = Instant::now();
+        // ── Subgraph sampling: graph.sampleSubgraph(config) ──
+        // Copies a sample of every mapped table into a new database.
+        if crate::procedures::graph_sample::is_sample_procedure(&proc_name) {
+            let sample_start = Instant::now();
 
-            let search_args;
-            let use_schema_name;
-            {
+            let call = {
                 let (_, stmt) =
                     open_cypher_parser::parse_cypher_statement(&clean_query).map_err(|e| {
                         (
                             StatusCode::BAD_REQUEST,
-                            format!("Failed to parse fulltext search call: {}", e),
+                            format!("Failed to parse graph.sampleSubgraph call: {}", e),
                         )
                     })?;
                 let expressions: Vec<_> = match &stmt {
-                    CypherStatement::ProcedureCall(pc) => {
-                        use_schema_name = None;
-                        pc.arguments.iter().collect()
-                    }
-                    CypherStatement::Query { query, .. } => {
-                        use_schema_name = query
-                            .use_clause
-                            .as_ref()
-                            .map(|uc| uc.database_name.to_string());
-                        let cc = query.call_clause.as_ref().ok_or_else(|| {
-                            (
-                                StatusCode::BAD_REQUEST,
-                                "No CALL clause found in fulltext search query".to_string(),
-                            )
-                        })?;
-                        cc.arguments.iter().map(|a| &a.value).collect()
-                    }
-                    CypherStatement::CopyTo(_) => {
-                        return Err((
-                            StatusCode::BAD_REQUEST,
-                            "Unexpected COPY TO in fulltext search context".to_string(),
-                        ));
-                    }
+                    CypherStatement::ProcedureCall(pc) => pc.arguments.iter().collect(),
+                    CypherStatement::Query { query, .. } => query
+                        .call_clause
+                        .as_ref()
+                        .map(|cc| cc.arguments.iter().map(|a| &a.value).collect())
+                        .unwrap_or_default(),
+                    CypherStatement::CopyTo(_) => Vec::new(),
                 };
-                search_args =
-                    crate::procedures::fulltext_search::parse_fulltext_search_args(&expressions)
-                        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
-            }
+                crate::procedures::graph_sample::parse_sample_call(&expressions)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e))?
+            };
 
-            // Resolve schema: USE clause > schema_name param > "default"
-            let schema_name_for_search = use_schema_name
-                .or_else(|| schema_name_param.clone())
-                .unwrap_or_else(|| "default".to_string());
-            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_search)
+            let schema_name_for_sample = graph_catalog::resolve_graph_name(
+                extract_schema_from_use_clause(&clean_query).as_deref(),
+                schema_name_param.as_deref(),
+            );
+            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_sample)
                 .await
                 .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
-
-            let index_config = crate::procedures::fulltext_search::resolve_fulltext_index(
+            let plan = crate::procedures::graph_sample::build_sample_plan(
                 &graph_schema,
-                &search_args.index_name,
+                &schema_name_for_sample,
+                &call,
             )
             .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
-
-            let search_sql = crate::procedures::fulltext_search::build_fulltext_search_sql(
-                &search_args,
-                index_config,
-            );
-
-            log::debug!(
-                "Fulltext search: index='{}', query='{}', sql_length={}",
-                search_args.index_name,
-                search_args.query_text.chars().take(50).collect::<String>(),
-                search_sql.len()
-            );
+            let statements = plan.statements();
 
             if sql_only {
                 let response = SqlOnlyResponse {
                     cypher_query: payload.query.clone(),
-                    generated_sql: search_sql,
+                    generated_sql: statements.join(";\n"),
                     execution_mode: "sql_only".to_string(),
                 };
                 return Ok(Json(response).into_response());
             }
 
+            // Node tables are created before the relationship tables that
+            // read them; stop at the first failure.
             let role = payload.role.as_deref();
-            match app_state
-                .executor
-                .execute_text(&search_sql, "JSONEachRow", role)
+            for sql in &statements {
+                log::info!("graph.sampleSubgraph: {}", redact_sql(sql));
+                if let Err(e) = endpoint_routing::batch(app_state.executor.execute_text(
+                    sql,
+                    "TabSeparated",
+                    role,
+                ))
                 .await
-            {
-                Ok(result_text) => {
-                    log::info!(
-                        "Fulltext search completed in {:.3} seconds",
-                        search_start.elapsed().as_secs_f64()
-                    );
-                    let rows: Vec<serde_json::Value> = result_text
-                        .lines()
-                        .filter(|line| !line.is_empty())
-                        .map(serde_json::from_str::<serde_json::Value>)
-                        .collect::<Result<Vec<_>, _>>()
-                        .map_err(|e| {
-                            (
-                                StatusCode::INTERNAL_SERVER_ERROR,
+                {
+                    return Err((
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format!("graph.sampleSubgraph failed: {}", e),
+                    ));
+                }
+            }
+            let rows = endpoint_routing::batch(
+                app_state
+                    .executor
+                    .execute_json(&crate::procedures::graph_sample::count_sql(&plan), role),
+            )
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("graph.sampleSubgraph row count failed: {}", e),
+                )
+            })?;
+            let schema_yaml = graph_catalog::get_view_config_by_name(&schema_name_for_sample)
+                .await
+                .and_then(|config| {
+                    crate::procedures::graph_sample::derive_schema_yaml(
+                        &config,
+                        &schema_name_for_sample,
+                        &plan,
+                    )
+                })
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
+            log::info!(
+                "graph.sampleSubgraph copied {} table(s) into {} in {:.3} seconds",
+                plan.tables.len(),
+                plan.database,
+                sample_start.elapsed().as_secs_f64()
+            );
+            let record = crate::procedures::graph_sample::sample_record(&plan, &rows, schema_yaml);
+            return Ok(
+                Json(crate::procedures::executor::format_as_json(vec![record])).into_response(),
+            );
+        }
+
+        // ── Graph export: graph.exportGraph(config) ──
+        // Writes every label and type to partitioned Parquet files.
+        if crate::procedures::graph_export::is_export_graph_procedure(&proc_name) {
+            let export_start = Instant::now();
+
+            let call = {
+                let (_, stmt) =
+                    open_cypher_parser::parse_cypher_statement(&clean_query).map_err(|e| {
+                        (
+                            StatusCode::BAD_REQUEST,
+                            format!("Failed to parse graph.exportGraph call: {}", e),
+                        )
+                    })?;
+                let expressions: Vec<_> = match &stmt {
+                    CypherStatement::ProcedureCall(pc) => pc.arguments.iter().collect(),
+                    CypherStatement::Query { query, .. } => query
+                        .call_clause
+                        .as_ref()
+                        .map(|cc| cc.arguments.iter().map(|a| &a.value).collect())
+                        .unwrap_or_default(),
+                    CypherStatement::CopyTo(_) => Vec::new(),
+                };
+                crate::procedures::graph_export::parse_export_graph_call(&expressions)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e))?
+            };
+
+            let schema_name_for_export = graph_catalog::resolve_graph_name(
+                extract_schema_from_use_clause(&clean_query).as_deref(),
+                schema_name_param.as_deref(),
+            );
+            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_export)
+                .await
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            let plan = crate::procedures::graph_export::build_export_plan(&graph_schema, &call)
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            let count_sql = crate::procedures::graph_export::count_sql(&plan);
+
+            if sql_only {
+                let statements: Vec<&str> = plan
+                    .files
+                    .iter()
+                    .map(|f| f.sql.as_str())
+                    .chain(std::iter::once(count_sql.as_str()))
+                    .collect();
+                let response = SqlOnlyResponse {
+                    cypher_query: payload.query.clone(),
+                    generated_sql: statements.join(";\n"),
+                    execution_mode: "sql_only".to_string(),
+                };
+                return Ok(Json(response).into_response());
+            }
+
+            // Files are independent: run `parallelism` of them at once and
+            // report every failure before writing the manifest.
+            let role = payload.role.as_deref();
+            let writes: Vec<_> = plan
+                .files
+                .iter()
+                .map(|file| {
+                    let executor = app_state.executor.clone();
+                    let (sql, path) = (file.sql.clone(), file.path.clone());
+                    let role = role.map(str::to_string);
+                    async move {
+                        log::info!("graph.exportGraph: {}", redact_sql(&sql));
+                        endpoint_routing::batch(executor.execute_text(
+                            &sql,
+                            "TabSeparated",
+                            role.as_deref(),
+                        ))
+                        .await
+                        .err()
+                        .map(|e| format!("{}: {}", path, e))
+                    }
+                })
+                .collect();
+            let failures: Vec<String> = futures_util::stream::iter(writes)
+                .buffer_unordered(call.parallelism)
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .flatten()
+                .collect();
+            if !failures.is_empty() {
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!(
+                        "graph.exportGraph failed writing {} of {} file(s): {}",
+                        failures.len(),
+                        plan.files.len(),
+                        failures.join("; ")
+                    ),
+                ));
+            }
+            let rows = endpoint_routing::batch(app_state.executor.execute_json(&count_sql, role))
+                .await
+                .map_err(|e| {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format!("graph.exportGraph row count failed: {}", e),
+                    )
+                })?;
+            let manifest =
+                crate::procedures::graph_export::manifest(&plan, &schema_name_for_export, &rows);
+            let manifest_sql =
+                crate::procedures::graph_export::manifest_sql(&plan, &manifest, call.overwrite)
+                    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
+            if let Err(e) = endpoint_routing::batch(app_state.executor.execute_text(
+                &manifest_sql,
+                "TabSeparated",
+                role,
+            ))
+            .await
+            {
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("graph.exportGraph failed writing the manifest: {}", e),
+                ));
+            }
+            log::info!(
+                "graph.exportGraph wrote {} file(s) to {} in {:.3} seconds",
+                plan.files.len(),
+                plan.destination,
+                export_start.elapsed().as_secs_f64()
+            );
+            let record = crate::procedures::graph_export::export_record(&plan, manifest);
+            return Ok(
+                Json(crate::procedures::executor::format_as_json(vec![record])).into_response(),
+            );
+        }
+
+        // ── Data quality checks: graph.quality() ──
+        // One aggregate query over every mapped table of the graph.
+        if crate::procedures::graph_quality::is_quality_procedure(&proc_name) {
+            let quality_start = Instant::now();
+
+            let schema_name_for_quality = graph_catalog::resolve_graph_name(
+                extract_schema_from_use_clause(&clean_query).as_deref(),
+                schema_name_param.as_deref(),
+            );
+            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_quality)
+                .await
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            let quality_sql = crate::procedures::graph_quality::build_quality_sql(&graph_schema)
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+            if sql_only {
+                let response = SqlOnlyResponse {
+                    cypher_query: payload.query.clone(),
+                    generated_sql: quality_sql.unwrap_or_default(),
+                    execution_mode: "sql_only".to_string(),
+                };
+                return Ok(Json(response).into_response());
+            }
+
+            let rows = match quality_sql {
+                Some(sql) => endpoint_routing::batch(
+                    app_state
+                        .executor
+                        .execute_json(&sql, payload.role.as_deref()),
+                )
+                .await
+                .map_err(|e| {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format!("graph.quality execution failed: {}", e),
+                    )
+                })?,
+                None => Vec::new(),
+            };
+            log::info!(
+                "graph.quality checked {} label(s)/type(s) in {:.3} seconds",
+                rows.len(),
+                quality_start.elapsed().as_secs_f64()
+            );
+            let records = rows
+                .iter()
+                .map(crate::procedures::graph_quality::quality_record)
+                .collect();
+            return Ok(Json(crate::procedures::executor::format_as_json(records)).into_response());
+        }
+
+        // ── PageRank: graph.pageRank(label, relTypes, config) ──
+        // One query; the iterations are unrolled into a CTE chain.
+        if crate::procedures::graph_page_rank::is_page_rank_procedure(&proc_name) {
+            let page_rank_start = Instant::now();
+
+            let call = {
+                let (_, stmt) =
+                    open_cypher_parser::parse_cypher_statement(&clean_query).map_err(|e| {
+                        (
+                            StatusCode::BAD_REQUEST,
+                            format!("Failed to parse graph.pageRank call: {}", e),
+                        )
+                    })?;
+                let expressions: Vec<_> = match &stmt {
+                    CypherStatement::ProcedureCall(pc) => pc.arguments.iter().collect(),
+                    CypherStatement::Query { query, .. } => query
+                        .call_clause
+                        .as_ref()
+                        .map(|cc| cc.arguments.iter().map(|a| &a.value).collect())
+                        .unwrap_or_default(),
+                    CypherStatement::CopyTo(_) => Vec::new(),
+                };
+                crate::procedures::graph_page_rank::parse_page_rank_call(&expressions)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e))?
+            };
+
+            let schema_name_for_page_rank = graph_catalog::resolve_graph_name(
+                extract_schema_from_use_clause(&clean_query).as_deref(),
+                schema_name_param.as_deref(),
+            );
+            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_page_rank)
+                .await
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            let page_rank_sql =
+                crate::procedures::graph_page_rank::build_page_rank_sql(&graph_schema, &call)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+            if sql_only {
+                let response = SqlOnlyResponse {
+                    cypher_query: payload.query.clone(),
+                    generated_sql: page_rank_sql,
+                    execution_mode: "sql_only".to_string(),
+                };
+                return Ok(Json(response).into_response());
+            }
+
+            let rows = endpoint_routing::batch(
+                app_state
+                    .executor
+                    .execute_json(&page_rank_sql, payload.role.as_deref()),
+            )
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("graph.pageRank execution failed: {}", e),
+                )
+            })?;
+            log::info!(
+                "graph.pageRank scored {} {} node(s) in {} iteration(s), {:.3} seconds",
+                rows.len(),
+                call.label,
+                call.iterations,
+                page_rank_start.elapsed().as_secs_f64()
+            );
+            let records = rows
+                .iter()
+                .map(crate::procedures::graph_page_rank::page_rank_record)
+                .collect();
+            return Ok(Json(crate::procedures::executor::format_as_json(records)).into_response());
+        }
+
+        // ── Connected components: graph.wcc / graph.scc(label, relTypes, config) ──
+        // One query; the label propagation rounds are unrolled into a CTE chain.
+        if let Some(kind) = crate::procedures::graph_components::component_kind(&proc_name) {
+            let components_start = Instant::now();
+            let procedure = kind.procedure_name();
+
+            let call = {
+                let (_, stmt) =
+                    open_cypher_parser::parse_cypher_statement(&clean_query).map_err(|e| {
+                        (
+                            StatusCode::BAD_REQUEST,
+                            format!("Failed to parse {} call: {}", procedure, e),
+                        )
+                    })?;
+                let expressions: Vec<_> = match &stmt {
+                    CypherStatement::ProcedureCall(pc) => pc.arguments.iter().collect(),
+                    CypherStatement::Query { query, .. } => query
+                        .call_clause
+                        .as_ref()
+                        .map(|cc| cc.arguments.iter().map(|a| &a.value).collect())
+                        .unwrap_or_default(),
+                    CypherStatement::CopyTo(_) => Vec::new(),
+                };
+                crate::procedures::graph_components::parse_components_call(kind, &expressions)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e))?
+            };
+
+            let schema_name_for_components = graph_catalog::resolve_graph_name(
+                extract_schema_from_use_clause(&clean_query).as_deref(),
+                schema_name_param.as_deref(),
+            );
+            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_components)
+                .await
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            let components_sql =
+                crate::procedures::graph_components::build_components_sql(&graph_schema, &call)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+            if sql_only {
+                let response = SqlOnlyResponse {
+                    cypher_query: payload.query.clone(),
+                    generated_sql: components_sql,
+                    execution_mode: "sql_only".to_string(),
+                };
+                return Ok(Json(response).into_response());
+            }
+
+            let rows = endpoint_routing::batch(
+                app_state
+                    .executor
+                    .execute_json(&components_sql, payload.role.as_deref()),
+            )
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("{} execution failed: {}", procedure, e),
+                )
+            })?;
+            log::info!(
+                "{} labelled {} {} node(s) in {} iteration(s), {:.3} seconds",
+                procedure,
+                rows.len(),
+                call.label,
+                call.iterations,
+                components_start.elapsed().as_secs_f64()
+            );
+            let records = rows
+                .iter()
+                .map(crate::procedures::graph_components::component_record)
+                .collect();
+            return Ok(Json(crate::procedures::executor::format_as_json(records)).into_response());
+        }
+
+        // ── Weighted shortest path: graph.shortestPath.dijkstra(source, target, config) ──
+        // One recursive-CTE query carrying accumulated weight per path.
+        if crate::procedures::graph_shortest_path::is_dijkstra_procedure(&proc_name) {
+            let dijkstra_start = Instant::now();
+
+            let call = {
+                let (_, stmt) =
+                    open_cypher_parser::parse_cypher_statement(&clean_query).map_err(|e| {
+                        (
+                            StatusCode::BAD_REQUEST,
+                            format!("Failed to parse graph.shortestPath.dijkstra call: {}", e),
+                        )
+                    })?;
+                let expressions: Vec<_> = match &stmt {
+                    CypherStatement::ProcedureCall(pc) => pc.arguments.iter().collect(),
+                    CypherStatement::Query { query, .. } => query
+                        .call_clause
+                        .as_ref()
+                        .map(|cc| cc.arguments.iter().map(|a| &a.value).collect())
+                        .unwrap_or_default(),
+                    CypherStatement::CopyTo(_) => Vec::new(),
+                };
+                crate::procedures::graph_shortest_path::parse_dijkstra_call(&expressions)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e))?
+            };
+
+            let schema_name_for_dijkstra = graph_catalog::resolve_graph_name(
+                extract_schema_from_use_clause(&clean_query).as_deref(),
+                schema_name_param.as_deref(),
+            );
+            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_dijkstra)
+                .await
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            let dijkstra_sql =
+                crate::procedures::graph_shortest_path::build_dijkstra_sql(&graph_schema, &call)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+            if sql_only {
+                let response = SqlOnlyResponse {
+                    cypher_query: payload.query.clone(),
+                    generated_sql: dijkstra_sql,
+                    execution_mode: "sql_only".to_string(),
+                };
+                return Ok(Json(response).into_response());
+            }
+
+            let rows = endpoint_routing::batch(
+                app_state
+                    .executor
+                    .execute_json(&dijkstra_sql, payload.role.as_deref()),
+            )
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("graph.shortestPath.dijkstra execution failed: {}", e),
+                )
+            })?;
+            log::info!(
+                "graph.shortestPath.dijkstra from {} to {} found {} path(s) in {:.3} seconds",
+                call.source,
+                call.target,
+                rows.len(),
+                dijkstra_start.elapsed().as_secs_f64()
+            );
+            let records = rows
+                .iter()
+                .map(crate::procedures::graph_shortest_path::dijkstra_record)
+                .collect();
+            return Ok(Json(crate::procedures::executor::format_as_json(records)).into_response());
+        }
+
+        // ── Vector search procedures: db.index.vector.queryNodes ──
+        // These bypass ProcedureRegistry because they need ClickHouse execution.
+        if crate::procedures::vector_search::is_vector_search_procedure(&proc_name) {
+            log::info!("Detected vector search procedure");
+
+            let search_start = Instant::now();
+
+            // Re-parse to extract arguments and USE clause
+            let search_args;
+            let use_schema_name;
+            {
+                let (_, stmt) =
+                    open_cypher_parser::parse_cypher_statement(&clean_query).map_err(|e| {
+                        (
+                            StatusCode::BAD_REQUEST,
+                            format!("Failed to parse vector search call: {}", e),
+                        )
+                    })?;
+                let expressions: Vec<_> = match &stmt {
+                    CypherStatement::ProcedureCall(pc) => {
+                        use_schema_name = None;
+                        pc.arguments.iter().collect()
+                    }
+                    CypherStatement::Query { query, .. } => {
+                        use_schema_name = query
+                            .use_clause
+                            .as_ref()
+                            .map(|uc| uc.database_name.to_string());
+                        let cc = query.call_clause.as_ref().ok_or_else(|| {
+                            (
+                                StatusCode::BAD_REQUEST,
+                                "No CALL clause found in vector search query".to_string(),
+                            )
+                        })?;
+                        cc.arguments.iter().map(|a| &a.value).collect()
+                    }
+                    CypherStatement::CopyTo(_) => {
+                        return Err((
+                            StatusCode::BAD_REQUEST,
+                            "Unexpected COPY TO in vector search context".to_string(),
+                        ));
+                    }
+                };
+                search_args =
+                    crate::procedures::vector_search::parse_vector_search_args(&expressions)
+                        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            }
+
+            let schema_name_for_search = graph_catalog::resolve_graph_name(
+                use_schema_name.as_deref(),
+                schema_name_param.as_deref(),
+            );
+            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_search)
+                .await
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+            let index_config = crate::procedures::vector_search::resolve_vector_index(
+                &graph_schema,
+                &search_args.index_name,
+            )
+            .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+            // Generate SQL
+            let search_sql = crate::procedures::vector_search::build_vector_search_sql(
+                &search_args,
+                index_config,
+            )
+            .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+            log::debug!(
+                "Vector search: index='{}', top_k={}, sql_length={}",
+                search_args.index_name,
+                search_args.top_k,
+                search_sql.len()
+            );
+
+            // If sql_only, return the SQL
+            if sql_only {
+                let response = SqlOnlyResponse {
+                    cypher_query: payload.query.clone(),
+                    generated_sql: search_sql,
+                    execution_mode: "sql_only".to_string(),
+                };
+                return Ok(Json(response).into_response());
+            }
+
+            // Execute and return results as JSON
+            let role = payload.role.as_deref();
+            match app_state
+                .executor
+                .execute_text(&search_sql, "JSONEachRow", role)
+                .await
+            {
+                Ok(result_text) => {
+                    log::info!(
+                        "Vector search completed in {:.3} seconds",
+                        search_start.elapsed().as_secs_f64()
+                    );
+                    // Parse JSONEachRow response, failing if any row is malformed
+                    let rows: Vec<serde_json::Value> = result_text
+                        .lines()
+                        .filter(|line| !line.is_empty())
+                        .map(serde_json::from_str::<serde_json::Value>)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| {
+                            (
+                                StatusCode::INTERNAL_SERVER_ERROR,
+                                format!("Failed to parse ClickHouse JSONEachRow response: {}", e),
+                            )
+                        })?;
+                    return Ok(Json(serde_json::json!(rows)).into_response());
+                }
+                Err(e) => {
+                    return Err((
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format!("Vector search execution failed: {}", e),
+                    ));
+                }
+            }
+        }
+
+        // ── Fulltext search procedures: db.index.fulltext.queryNodes ──
+        // Same intercept pattern as vector search.
+        if crate::procedures::fulltext_search::is_fulltext_search_procedure(&proc_name) {
+            log::info!("Detected fulltext search procedure");
+
+            let search_start = Instant::now();
+
+            let search_args;
+            let use_schema_name;
+            {
+                let (_, stmt) =
+                    open_cypher_parser::parse_cypher_statement(&clean_query).map_err(|e| {
+                        (
+                            StatusCode::BAD_REQUEST,
+                            format!("Failed to parse fulltext search call: {}", e),
+                        )
+                    })?;
+                let expressions: Vec<_> = match &stmt {
+                    CypherStatement::ProcedureCall(pc) => {
+                        use_schema_name = None;
+                        pc.arguments.iter().collect()
+                    }
+                    CypherStatement::Query { query, .. } => {
+                        use_schema_name = query
+                            .use_clause
+                            .as_ref()
+                            .map(|uc| uc.database_name.to_string());
+                        let cc = query.call_clause.as_ref().ok_or_else(|| {
+                            (
+                                StatusCode::BAD_REQUEST,
+                                "No CALL clause found in fulltext search query".to_string(),
+                            )
+                        })?;
+                        cc.arguments.iter().map(|a| &a.value).collect()
+                    }
+                    CypherStatement::CopyTo(_) => {
+                        return Err((
+                            StatusCode::BAD_REQUEST,
+                            "Unexpected COPY TO in fulltext search context".to_string(),
+                        ));
+                    }
+                };
+                search_args =
+                    crate::procedures::fulltext_search::parse_fulltext_search_args(&expressions)
+                        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+            }
+
+            let schema_name_for_search = graph_catalog::resolve_graph_name(
+                use_schema_name.as_deref(),
+                schema_name_param.as_deref(),
+            );
+            let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name_for_search)
+                .await
+                .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+            let index_config = crate::procedures::fulltext_search::resolve_fulltext_index(
+                &graph_schema,
+                &search_args.index_name,
+            )
+            .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+            let search_sql = crate::procedures::fulltext_search::build_fulltext_search_sql(
+                &search_args,
+                index_config,
+            );
+
+            log::debug!(
+                "Fulltext search: index='{}', query='{}', sql_length={}",
+                search_args.index_name,
+                search_args.query_text.chars().take(50).collect::<String>(),
+                search_sql.len()
+            );
+
+            if sql_only {
+                let response = SqlOnlyResponse {
+                    cypher_query: payload.query.clone(),
+                    generated_sql: search_sql,
+                    execution_mode: "sql_only".to_string(),
+                };
+                return Ok(Json(response).into_response());
+            }
+
+            let role = payload.role.as_deref();
+            match app_state
+                .executor
+                .execute_text(&search_sql, "JSONEachRow", role)
+                .await
+            {
+                Ok(result_text) => {
+                    log::info!(
+                        "Fulltext search completed in {:.3} seconds",
+                        search_start.elapsed().as_secs_f64()
+                    );
+                    let rows: Vec<serde_json::Value> = result_text
+                        .lines()
+                        .filter(|line| !line.is_empty())
+                        .map(serde_json::from_str::<serde_json::Value>)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| {
+                            (
+                                StatusCode::INTERNAL_SERVER_ERROR,
                                 format!("Failed to parse ClickHouse JSONEachRow response: {}", e),
                             )
                         })?;
@@ -980,7 +1829,10 @@ pub async fn query_handler(
         }
 
         let registry = crate::procedures::ProcedureRegistry::new();
-        let schema_name = schema_name_param.unwrap_or_else(|| "default".to_string());
+        let schema_name = graph_catalog::resolve_graph_name(
+            extract_schema_from_use_clause(&clean_query).as_deref(),
+            schema_name_param.as_deref(),
+        );
 
         // Check if procedure exists
         if !registry.contains(&proc_name) {
@@ -1026,31 +1878,20 @@ pub async fn query_handler(
     // Quick syntax validation (doesn't need full planning)
     // Note: Use parse_cypher_statement to support UNION ALL queries
     let schema_name = match open_cypher_parser::parse_cypher_statement(&clean_query) {
-        Ok((_, statement)) => {
-            // Parse succeeded - extract schema name from USE clause
-            match statement {
-                open_cypher_parser::ast::CypherStatement::Query { query, .. } => {
-                    if let Some(ref use_clause) = query.use_clause {
-                        use_clause.database_name.to_string()
-                    } else {
-                        // No USE clause - use request parameter or "default"
-                        schema_name_param.unwrap_or_else(|| "default".to_string())
-                    }
-                }
-                open_cypher_parser::ast::CypherStatement::ProcedureCall(_) => {
-                    // Procedure calls don't have USE clauses
-                    schema_name_param.unwrap_or_else(|| "default".to_string())
-                }
-                open_cypher_parser::ast::CypherStatement::CopyTo(_) => {
-                    // COPY TO uses request parameter or "default"
-                    schema_name_param.unwrap_or_else(|| "default".to_string())
-                }
+        // Parse succeeded - USE clause > request parameter > default graph
+        Ok((_, statement)) => match query_planner::target_graph(&statement) {
+            Ok(use_graph) => {
+                graph_catalog::resolve_graph_name(use_graph, schema_name_param.as_deref())
             }
-        }
+            Err(e) => return Err((StatusCode::BAD_REQUEST, e.to_string())),
+        },
         Err(e) => {
             // ❌ PARSE ERROR: Return immediately with clear error message
             // Don't proceed to schema lookup (which would give misleading "Schema not found")
-            log::error!("Query parse failed during schema extraction: {}", e);
+            log::error!(
+                "Query parse failed during schema extraction: {}",
+                redact(&e.to_string())
+            );
             return Err((
                 StatusCode::BAD_REQUEST,
                 format!(
@@ -1061,6 +1902,10 @@ pub async fn query_handler(
         }
     };
 
+    // The parsed target is what runs, whatever the text-level check saw
+    graph_catalog::check_graph_isolation(payload.pinned_graph.as_deref(), &schema_name)
+        .map_err(|e| (StatusCode::FORBIDDEN, e))?;
+
     log::debug!(
         "Using schema: {} ({})",
         schema_name,
@@ -1080,21 +1925,25 @@ pub async fn query_handler(
 
     // Scope a ClickHouse-stats slot around the whole inner run so the executor
     // can record per-query CH stats that the finalization sites read back.
-    let result = metrics::with_ch_stats_scope(with_query_context(context, async move {
-        query_handler_inner(
-            app_state,
-            payload,
-            schema_name,
-            clean_query,
-            output_format,
-            sql_only,
-            replan_option,
-            start_time,
-            metrics,
-        )
-        .await
-    }))
-    .await;
+    // The stats are read before the scope ends, for the error outcomes below.
+    let (result, ch_stats) =
+        metrics::with_ch_stats_scope(with_query_context(context, async move {
+            let result = query_handler_inner(
+                app_state,
+                payload,
+                schema_name,
+                clean_query,
+                output_format,
+                sql_only,
+                replan_option,
+                start_time,
+                metrics,
+                paging,
+            )
+            .await;
+            (result, metrics::current_ch_stats())
+        }))
+        .await;
 
     // Successful queries are recorded with full phase breakdown at the inner
     // finalization sites; here we capture the error outcomes. Every `Err` from
@@ -1113,7 +1962,7 @@ pub async fn query_handler(
                 outcome: Outcome::Err(ErrorClass::from_status(status.as_u16())),
                 has_phase_breakdown: false,
                 query_text: Some(&query_string),
-                ch: metrics::current_ch_stats(),
+                ch: ch_stats,
             });
         }
     }
@@ -1132,7 +1981,21 @@ async fn query_handler_inner(
     replan_option: query_cache::ReplanOption,
     start_time: Instant,
     mut metrics: QueryPerformanceMetrics,
+    paging: Option<continuation::Paging>,
 ) -> Result<Response, (StatusCode, String)> {
+    // Check parameters against the declared types of the properties they are
+    // compared with, before the cache lookup so cached plans are covered too
+    let mut payload = payload;
+    if payload.parameters.as_ref().is_some_and(|p| !p.is_empty()) {
+        if let Ok(schema) = request_schema(&payload, &schema_name).await {
+            let expected = parameter_types::infer_parameter_types(&clean_query, &schema);
+            if let Some(parameters) = payload.parameters.as_mut() {
+                parameter_substitution::check_parameter_types(parameters, &expected)
+                    .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+            }
+        }
+    }
+
     // Convert view_parameters to String values for cache key
     let vp_strings: Option<HashMap<String, String>> =
         payload.view_parameters.as_ref().map(|params| {
@@ -1154,12 +2017,40 @@ async fn query_handler_inner(
         vp_strings.as_ref(),
     );
     let mut cache_status = "MISS";
+    // ClickHouse query cache settings sent with read queries, then the
+    // request's own allowed settings
+    let mut ch_settings = query_cache_settings(
+        &payload,
+        graph_catalog::get_query_cache_config(&schema_name).await,
+    );
+    ch_settings.extend(
+        query_settings::validate(
+            payload.settings.iter().flatten(),
+            &app_state.config.allowed_settings,
+        )
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?,
+    );
+    // Set when the unfiltered scan guard capped the query (X-Query-Notification)
+    let mut scan_notification: Option<String> = None;
+    // `CYPHER include_deleted=true` (soft-deleted rows stay visible) and
+    // `CYPHER collation=...`. The cache key ignores the CYPHER prefix, so
+    // these queries never share the cache. A comment may precede the prefix.
+    let prefixed_query = open_cypher_parser::strip_comments(&payload.query);
+    let query_options = query_planner::QueryOptions::from_query(&prefixed_query);
 
     // Try cache lookup (unless replan=force or Graph format which needs plan context)
     let cached_sql = if output_format == OutputFormat::Graph {
         log::debug!("Cache BYPASS for Graph format (needs plan context)");
         cache_status = "BYPASS";
         None
+    } else if query_options.bypasses_cache() {
+        log::debug!("Cache BYPASS for include_deleted/collation query");
+        cache_status = "BYPASS";
+        None
+    } else if payload.schema_overrides.is_some() {
+        log::debug!("Cache BYPASS for inline schema overrides");
+        cache_status = "BYPASS";
+        None
     } else if replan_option != query_cache::ReplanOption::Force {
         if let Some(cache) = GLOBAL_QUERY_CACHE.get() {
             if let Some(sql) = cache.get(&cache_key) {
@@ -1200,7 +2091,7 @@ async fn query_handler_inner(
                 }
             }
         } else {
-            sql_template
+            sql_template.clone()
         };
 
         // Check for unsubstituted $param placeholders before executing
@@ -1230,15 +2121,33 @@ async fn query_handler_inner(
             return Ok(response);
         }
 
-        // Execute query and return
-        let ch_sql_queries = vec![final_sql];
+        // Execute the template so query parameters can be bound natively
+        let ch_sql_queries = vec![sql_template];
+        let stale_notification =
+            check_freshness(&app_state, &payload, &schema_name, &ch_sql_queries).await?;
         let execution_start = Instant::now();
         let response = execute_cte_queries(
-            app_state,
+            app_state.clone(),
             ch_sql_queries,
-            output_format,
-            all_params, // Use merged parameters
+            output_format.clone(),
+            payload.stream.unwrap_or(false),
+            &payload.parameters,
+            &payload.view_parameters,
+            &ch_settings,
             payload.role.clone(),
+            paging.as_ref(),
+        )
+        .await;
+        let response = recover_from_recursion_depth(
+            response,
+            app_state,
+            &payload,
+            &schema_name,
+            &clean_query,
+            vp_strings,
+            output_format,
+            &ch_settings,
+            paging.as_ref(),
         )
         .await;
         metrics.execution_time = execution_start.elapsed().as_secs_f64();
@@ -1253,6 +2162,8 @@ async fn query_handler_inner(
                 // Add cache status header to response
                 let headers = resp.headers_mut();
                 headers.insert("X-Query-Cache-Status", HeaderValue::from_static("HIT"));
+                insert_notification_header(&mut resp, stale_notification.as_deref());
+                insert_clickhouse_cache_header(&mut resp);
 
                 return Ok(resp);
             }
@@ -1263,19 +2174,11 @@ async fn query_handler_inner(
     // graph_ctx holds (LogicalPlan, PlanCtx, GraphSchema) when format=Graph
     let (ch_sql_queries, maybe_schema_elem, is_read, query_type_str, graph_ctx) = {
         // ✅ FAIL LOUDLY: If schema not found, return clear error (no silent fallback)
-        let graph_schema = match graph_catalog::get_graph_schema_by_name(&schema_name).await {
+        let graph_schema = match request_schema(&payload, &schema_name).await {
             Ok(schema) => schema,
             Err(e) => {
-                let available = graph_catalog::list_available_schemas().await;
-                log::error!(
-                    "Schema '{}' not found. Available schemas: {:?}",
-                    schema_name,
-                    available
-                );
-                return Err((
-                    StatusCode::BAD_REQUEST,
-                    format!("{} Available schemas: {:?}", e, available),
-                ));
+                log::error!("{}", e);
+                return Err((StatusCode::BAD_REQUEST, e));
             }
         };
 
@@ -1295,12 +2198,24 @@ async fn query_handler_inner(
             Ok((_remaining, stmt)) => stmt,
             Err(e) => {
                 metrics.parse_time = parse_start.elapsed().as_secs_f64();
-                log::error!("Query parse failed: {:?}", e);
+                log::error!("Query parse failed: {}", redact(&format!("{:?}", e)));
                 // Return 400 for parse errors (both sql_only and normal mode)
                 return Err((StatusCode::BAD_REQUEST, format!("Parse error: {}", e)));
             }
         };
 
+        // USE ... FOR TIMESTAMP: plan (and render) against that snapshot of
+        // every table
+        let graph_schema =
+            match query_planner::snapshot_schema(&parsed_stmt, &graph_schema, &query_options) {
+                Ok(Cow::Owned(snapshot)) => {
+                    crate::server::query_context::set_current_schema(Arc::new(snapshot.clone()));
+                    snapshot
+                }
+                Ok(Cow::Borrowed(_)) => graph_schema,
+                Err(e) => return Err((StatusCode::BAD_REQUEST, e.to_string())),
+            };
+
         // Phase 1.5: Transform id() functions (same as Bolt protocol does)
         // This converts id(alias) = N to proper property comparisons
         // NOTE: HTTP is stateless, so we create a temporary IdMapper per request
@@ -1470,6 +2385,39 @@ async fn query_handler_inner(
                     return Err((StatusCode::BAD_REQUEST, format!("Planning error: {}", e)));
                 }
             };
+
+            // Per-label max_unfiltered_rows guard for whole-label exploration scans
+            let trusted = app_state.config.is_trusted_role(payload.role.as_deref());
+            let (logical_plan, scan_guard) =
+                match query_planner::scan_guard::enforce_unfiltered_scan_limit(
+                    logical_plan,
+                    &graph_schema,
+                    trusted,
+                ) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        metrics.planning_time = planning_start.elapsed().as_secs_f64();
+                        return Err((StatusCode::BAD_REQUEST, format!("Planning error: {}", e)));
+                    }
+                };
+            // Disconnected patterns need CLICKGRAPH_ALLOW_CARTESIAN_PRODUCT or the
+            // `CYPHER cartesian=allow` prefix
+            let allow_cartesian = app_state.config.allow_cartesian_product
+                || query_planner::cartesian_guard::has_allow_hint(&prefixed_query);
+            let cartesian_by_hint = match query_planner::cartesian_guard::enforce_cartesian_policy(
+                &logical_plan,
+                allow_cartesian,
+            ) {
+                Ok(has_cartesian) => has_cartesian && !app_state.config.allow_cartesian_product,
+                Err(e) => {
+                    metrics.planning_time = planning_start.elapsed().as_secs_f64();
+                    return Err((StatusCode::BAD_REQUEST, format!("Planning error: {}", e)));
+                }
+            };
+            scan_notification = scan_guard
+                .notification()
+                .map(str::to_string)
+                .or_else(|| plan_ctx.unknown_labels().map(|u| u.description()));
             metrics.planning_time = planning_start.elapsed().as_secs_f64();
 
             // Phase 3: Render plan generation
@@ -1491,6 +2439,9 @@ async fn query_handler_inner(
                     }
                 };
             metrics.render_time = render_start.elapsed().as_secs_f64();
+            if let Some(reg) = GLOBAL_SERVER_METRICS.get() {
+                reg.record_cte_depth(cte_depth(&render_plan));
+            }
 
             // Phase 4: SQL generation
             let sql_generation_start = Instant::now();
@@ -1501,8 +2452,22 @@ async fn query_handler_inner(
             metrics.sql_generation_time = sql_generation_start.elapsed().as_secs_f64();
             crate::debug_println!("\n ch_query \n {} \n", ch_query);
 
-            // Store in cache (even in sql_only mode for future use)
-            if let Some(cache) = GLOBAL_QUERY_CACHE.get() {
+            // Store in cache (even in sql_only mode for future use). Guarded
+            // scans are never cached: their SQL depends on the caller's role.
+            // Neither are unknown-label rewrites, so every hit gets its notification.
+            if scan_guard.is_guarded() {
+                log::debug!("Cache SKIP for guarded unfiltered scan");
+            } else if plan_ctx.unknown_labels().is_some() {
+                log::debug!("Cache SKIP for query with unknown labels");
+            } else if cartesian_by_hint {
+                // The cache key ignores the CYPHER prefix, so an unhinted
+                // repeat would otherwise skip the cartesian check.
+                log::debug!("Cache SKIP for cartesian product allowed by query hint");
+            } else if query_options.bypasses_cache() {
+                log::debug!("Cache SKIP for include_deleted/collation query");
+            } else if payload.schema_overrides.is_some() {
+                log::debug!("Cache SKIP for inline schema overrides");
+            } else if let Some(cache) = GLOBAL_QUERY_CACHE.get() {
                 cache.insert(cache_key.clone(), ch_query.clone());
                 log::debug!("Stored SQL template in cache");
             }
@@ -1522,6 +2487,7 @@ async fn query_handler_inner(
                         .headers_mut()
                         .insert("X-Query-Cache-Status", cache_header);
                 }
+                insert_notification_header(&mut response, scan_notification.as_deref());
                 return Ok(response);
             }
 
@@ -1542,19 +2508,25 @@ async fn query_handler_inner(
         }
     };
 
+    // Reads of watermarked tables honour the request's max_staleness_secs
+    let stale_notification = if is_read {
+        check_freshness(&app_state, &payload, &schema_name, &ch_sql_queries).await?
+    } else {
+        None
+    };
+
     // Phase 5: Execute query
     let execution_start = Instant::now();
     let sql_queries_count = ch_sql_queries.len();
 
-    // Merge view_parameters and query parameters for substitution
-    let all_params = merge_parameters(&payload.parameters, &payload.view_parameters);
-
     // Graph format: execute SQL, get rows, transform to nodes/edges
     if let Some((logical_plan, plan_ctx, graph_schema)) = graph_ctx {
         let rows = execute_json_rows(
             &app_state,
             &ch_sql_queries,
-            all_params,
+            &payload.parameters,
+            &payload.view_parameters,
+            &ch_settings,
             payload.role.clone(),
         )
         .await?;
@@ -1577,22 +2549,42 @@ async fn query_handler_inner(
         };
 
         record_query(&metrics, &payload.query, Outcome::Ok);
+        record_workload(&schema_name, &clean_query, &metrics).await;
 
         let mut resp = Json(response).into_response();
         if let Ok(cache_header) = axum::http::HeaderValue::try_from(cache_status) {
             resp.headers_mut()
                 .insert("X-Query-Cache-Status", cache_header);
         }
+        insert_notification_header(&mut resp, scan_notification.as_deref());
+        insert_notification_header(&mut resp, stale_notification.as_deref());
+        insert_clickhouse_cache_header(&mut resp);
         return Ok(resp);
     }
 
     let response = if is_read {
-        execute_cte_queries(
-            app_state,
+        let response = execute_cte_queries(
+            app_state.clone(),
             ch_sql_queries,
-            output_format,
-            all_params,
+            output_format.clone(),
+            payload.stream.unwrap_or(false),
+            &payload.parameters,
+            &payload.view_parameters,
+            &ch_settings,
             payload.role.clone(),
+            paging.as_ref(),
+        )
+        .await;
+        recover_from_recursion_depth(
+            response,
+            app_state,
+            &payload,
+            &schema_name,
+            &clean_query,
+            vp_strings,
+            output_format,
+            &ch_settings,
+            paging.as_ref(),
         )
         .await
     } else {
@@ -1640,8 +2632,14 @@ async fn query_handler_inner(
                 resp.headers_mut()
                     .insert("X-Query-Cache-Status", cache_header);
             }
+            insert_notification_header(&mut resp, scan_notification.as_deref());
+            insert_notification_header(&mut resp, stale_notification.as_deref());
+            insert_clickhouse_cache_header(&mut resp);
 
             record_query(&metrics, &payload.query, Outcome::Ok);
+            if is_read {
+                record_workload(&schema_name, &clean_query, &metrics).await;
+            }
             Ok(resp)
         }
         Err(e) => Err(e),
@@ -1702,26 +2700,240 @@ async fn query_handler_inner(
 //     }
 // }
 
+/// Number of CTEs in the SQL rendered from `plan`, counting those nested in
+/// other CTEs.
+fn cte_depth(plan: &RenderPlan) -> usize {
+    plan.ctes
+        .0
+        .iter()
+        .map(|cte| match &cte.content {
+            CteContent::Structured(inner) => 1 + cte_depth(inner),
+            CteContent::RawSql(_) => 1,
+        })
+        .sum()
+}
+
+/// The schema `payload` is planned against: `schema_name`, with the
+/// request's `schema_overrides` merged in when it has any.
+async fn request_schema(payload: &QueryRequest, schema_name: &str) -> Result<GraphSchema, String> {
+    match payload.schema_overrides.as_deref() {
+        Some(overrides) => graph_catalog::get_graph_schema_with_overrides(schema_name, overrides)
+            .await
+            .map_err(|e| format!("Invalid schema_overrides: {}", e)),
+        None => graph_catalog::get_graph_schema_by_name(schema_name).await,
+    }
+}
+
+/// Check the watermarked edge tables `sqls` read against the request's
+/// `max_staleness_secs`, per its `on_stale`. Returns the possibly-stale
+/// notification when the result should carry one.
+async fn check_freshness(
+    app_state: &Arc<AppState>,
+    payload: &QueryRequest,
+    schema_name: &str,
+    sqls: &[String],
+) -> Result<Option<String>, (StatusCode, String)> {
+    let Some(max_staleness) = payload.max_staleness_secs else {
+        return Ok(None);
+    };
+    let graph_schema = request_schema(payload, schema_name)
+        .await
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    freshness::check(
+        app_state.executor.as_ref(),
+        &graph_schema,
+        sqls,
+        max_staleness,
+        payload.on_stale.unwrap_or_default(),
+        payload.role.as_deref(),
+    )
+    .await
+    .map_err(|e| {
+        let status = match e {
+            freshness::FreshnessError::Stale { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            freshness::FreshnessError::Watermark { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, e.to_string())
+    })
+}
+
+/// Attach the unfiltered scan guard's notification (if any) as `X-Query-Notification`,
+/// after any notification the response already carries.
+fn insert_notification_header(resp: &mut Response, notification: Option<&str>) {
+    let Some(notification) = notification else {
+        return;
+    };
+    let combined = match resp
+        .headers()
+        .get("X-Query-Notification")
+        .and_then(|v| v.to_str().ok())
+    {
+        Some(existing) => format!("{existing}; {notification}"),
+        None => notification.to_string(),
+    };
+    if let Ok(value) = HeaderValue::try_from(combined) {
+        resp.headers_mut().insert("X-Query-Notification", value);
+    }
+}
+
+/// Recursion-depth safety valve for a read whose execution returned `result`.
+///
+/// When ClickHouse aborted a recursive variable-length CTE at
+/// `max_recursive_cte_evaluation_depth` and the policy is
+/// [`RecursionDepthPolicy::Unroll`], re-plan `clean_query` with every path
+/// unrolled to `vlp_fallback_hops` hops, run it and flag the partial rows in
+/// `X-Query-Notification`. Otherwise, or when the fallback cannot run either,
+/// the ClickHouse exception is replaced by an actionable error. Any other
+/// result passes through.
+#[allow(clippy::too_many_arguments)] // the fallback re-runs the whole read: query, schema, view scope, output shape and settings
+async fn recover_from_recursion_depth(
+    result: Result<Response, (StatusCode, String)>,
+    app_state: Arc<AppState>,
+    payload: &QueryRequest,
+    schema_name: &str,
+    clean_query: &str,
+    view_parameter_values: Option<HashMap<String, String>>,
+    output_format: OutputFormat,
+    settings: &[(String, String)],
+    paging: Option<&continuation::Paging>,
+) -> Result<Response, (StatusCode, String)> {
+    let error = match result {
+        Err((_, message)) if recursion_depth::is_depth_exceeded(&message) => message,
+        other => return other,
+    };
+    log::warn!("Query hit the recursion depth limit: {}", redact(&error));
+    let max_cte_depth = app_state.config.max_cte_depth;
+    let depth_error = || {
+        (
+            StatusCode::BAD_REQUEST,
+            recursion_depth::depth_exceeded_message(max_cte_depth),
+        )
+    };
+    if app_state.config.recursion_depth_policy != RecursionDepthPolicy::Unroll {
+        return Err(depth_error());
+    }
+
+    let hops = app_state.config.vlp_fallback_hops;
+    let graph_schema = request_schema(payload, schema_name)
+        .await
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    query_context::set_vlp_fallback_hops(Some(hops));
+    let planned = plan_cypher_to_sql(
+        clean_query,
+        &graph_schema,
+        payload.tenant_id.clone(),
+        view_parameter_values,
+        payload.max_inferred_types,
+        max_cte_depth,
+    );
+    query_context::set_vlp_fallback_hops(None);
+    let sql = match planned {
+        Ok(planned) => planned.sql,
+        Err(e) => {
+            log::warn!(
+                "Recursion-depth fallback could not be planned: {}",
+                redact(&e)
+            );
+            return Err(depth_error());
+        }
+    };
+
+    log::info!("Retrying with variable-length paths unrolled to {hops} hops");
+    let mut response = match execute_cte_queries(
+        app_state,
+        vec![sql],
+        output_format,
+        payload.stream.unwrap_or(false),
+        &payload.parameters,
+        &payload.view_parameters,
+        settings,
+        payload.role.clone(),
+        paging,
+    )
+    .await
+    {
+        Err((_, message)) if recursion_depth::is_depth_exceeded(&message) => {
+            return Err(depth_error())
+        }
+        other => other?,
+    };
+    insert_notification_header(
+        &mut response,
+        Some(&recursion_depth::fallback_notification(hops, max_cte_depth)),
+    );
+    Ok(response)
+}
+
+/// Report whether ClickHouse served the query from its query cache as
+/// `X-ClickHouse-Query-Cache: HIT|MISS`. Absent when the query cache was not
+/// used or its status is unknown (text formats, non-ClickHouse backends).
+fn insert_clickhouse_cache_header(resp: &mut Response) {
+    if let Some(hit) = metrics::current_ch_stats().and_then(|stats| stats.query_cache_hit) {
+        let value = HeaderValue::from_static(if hit { "HIT" } else { "MISS" });
+        resp.headers_mut().insert("X-ClickHouse-Query-Cache", value);
+    }
+}
+
+/// ClickHouse settings enabling the query cache for a read query: the
+/// request's `use_query_cache` / `query_cache_ttl`, falling back to the
+/// schema's `query_cache` defaults. Empty when the cache is off.
+fn query_cache_settings(
+    payload: &QueryRequest,
+    defaults: QueryCacheConfig,
+) -> Vec<(String, String)> {
+    if !payload.use_query_cache.unwrap_or(defaults.enabled) {
+        return Vec::new();
+    }
+    let mut settings = vec![("use_query_cache".to_string(), "1".to_string())];
+    if let Some(ttl) = payload.query_cache_ttl.or(defaults.ttl_seconds) {
+        settings.push(("query_cache_ttl".to_string(), ttl.to_string()));
+    }
+    settings
+}
+
 /// Substitute parameters and validate that no unsubstituted placeholders remain.
 /// Shared by `execute_json_rows` and `execute_cte_queries`.
+///
+/// When `native` is set, query parameters are bound as ClickHouse query
+/// parameters (`{name:Type}`) instead of being inlined; view parameters are
+/// always inlined.
 fn prepare_final_sql(
     ch_sql_queries: &[String],
-    parameters: Option<&std::collections::HashMap<String, Value>>,
-) -> Result<String, (StatusCode, String)> {
+    query_params: &Option<std::collections::HashMap<String, Value>>,
+    view_params: &Option<std::collections::HashMap<String, Value>>,
+    native: bool,
+) -> Result<parameter_substitution::BoundSql, (StatusCode, String)> {
     let ch_query_string = ch_sql_queries.join(" ");
+    let substitution_error = |e: parameter_substitution::ParameterSubstitutionError| {
+        (
+            StatusCode::BAD_REQUEST,
+            format!("Parameter substitution error: {}", e),
+        )
+    };
 
-    let final_sql = if let Some(params) = parameters {
-        parameter_substitution::substitute_parameters(&ch_query_string, params).map_err(|e| {
-            (
-                StatusCode::BAD_REQUEST,
-                format!("Parameter substitution error: {}", e),
-            )
-        })?
+    let bound = if query_params.is_none() && view_params.is_none() {
+        parameter_substitution::BoundSql {
+            sql: ch_query_string,
+            params: Vec::new(),
+        }
+    } else if native {
+        let none = std::collections::HashMap::new();
+        parameter_substitution::bind_parameters(
+            &ch_query_string,
+            query_params.as_ref().unwrap_or(&none),
+            view_params.as_ref().unwrap_or(&none),
+        )
+        .map_err(substitution_error)?
     } else {
-        ch_query_string
+        let params = merge_parameters(query_params, view_params).unwrap_or_default();
+        parameter_substitution::BoundSql {
+            sql: parameter_substitution::substitute_parameters(&ch_query_string, &params)
+                .map_err(substitution_error)?,
+            params: Vec::new(),
+        }
     };
 
-    if let Some(missing_param) = parameter_substitution::find_unsubstituted_parameter(&final_sql) {
+    if let Some(missing_param) = parameter_substitution::find_unsubstituted_parameter(&bound.sql) {
         return Err((
             StatusCode::BAD_REQUEST,
             format!(
@@ -1731,43 +2943,66 @@ fn prepare_final_sql(
         ));
     }
 
-    Ok(final_sql)
+    Ok(bound)
 }
 
 /// Execute SQL and return parsed JSON rows (used by Graph format).
 async fn execute_json_rows(
     app_state: &Arc<AppState>,
     ch_sql_queries: &[String],
-    parameters: Option<std::collections::HashMap<String, Value>>,
+    query_params: &Option<std::collections::HashMap<String, Value>>,
+    view_params: &Option<std::collections::HashMap<String, Value>>,
+    settings: &[(String, String)],
     role: Option<String>,
 ) -> Result<Vec<Value>, (StatusCode, String)> {
-    let final_sql = prepare_final_sql(ch_sql_queries, parameters.as_ref())?;
+    let native = app_state.executor.supports_query_params();
+    let bound = prepare_final_sql(ch_sql_queries, query_params, view_params, native)?;
 
-    log::debug!("Executing SQL (graph format):\n{}", final_sql);
+    log::debug!("Executing SQL (graph format):\n{}", redact_sql(&bound.sql));
 
     app_state
         .executor
-        .execute_json(&final_sql, role.as_deref())
+        .execute_json_with_settings(&bound.sql, &bound.params, settings, role.as_deref())
         .await
         .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Executor error: {}", e),
-            )
+            let message = format!("Executor error: {}", e);
+            if recursion_depth::is_depth_exceeded(&message) {
+                // No unrolled retry here: graph output needs the original plan
+                (
+                    StatusCode::BAD_REQUEST,
+                    recursion_depth::depth_exceeded_message(app_state.config.max_cte_depth),
+                )
+            } else {
+                (StatusCode::INTERNAL_SERVER_ERROR, message)
+            }
         })
 }
 
+#[allow(clippy::too_many_arguments)] // read execution: statement, output shape (format, stream, paging), parameters, settings and role
 async fn execute_cte_queries(
     app_state: Arc<AppState>,
     ch_sql_queries: Vec<String>,
     output_format: OutputFormat,
-    parameters: Option<std::collections::HashMap<String, Value>>,
+    stream: bool,
+    query_params: &Option<std::collections::HashMap<String, Value>>,
+    view_params: &Option<std::collections::HashMap<String, Value>>,
+    settings: &[(String, String)],
     role: Option<String>,
+    paging: Option<&continuation::Paging>,
 ) -> Result<Response, (StatusCode, String)> {
-    let final_sql = prepare_final_sql(&ch_sql_queries, parameters.as_ref())?;
+    let native = app_state.executor.supports_query_params();
+    let bound = prepare_final_sql(&ch_sql_queries, query_params, view_params, native)?;
 
     // Log full SQL for debugging (especially helpful when ClickHouse truncates errors)
-    log::debug!("Executing SQL:\n{}", final_sql);
+    log::debug!("Executing SQL:\n{}", redact_sql(&bound.sql));
+    if !bound.params.is_empty() {
+        if redaction::mode() == redaction::LiteralRedaction::Off {
+            log::debug!("Query parameters: {:?}", bound.params);
+        } else {
+            let names: Vec<&str> = bound.params.iter().map(|(name, _)| name.as_str()).collect();
+            log::debug!("Query parameters (values redacted): {:?}", names);
+        }
+    }
 
     if output_format == OutputFormat::Pretty
         || output_format == OutputFormat::PrettyCompact
@@ -1777,7 +3012,13 @@ async fn execute_cte_queries(
         let format_str: String = output_format.into();
         let text = app_state
             .executor
-            .execute_text(&final_sql, &format_str, role.as_deref())
+            .execute_text_with_settings(
+                &bound.sql,
+                &format_str,
+                &bound.params,
+                settings,
+                role.as_deref(),
+            )
             .await
             .map_err(|e| {
                 (
@@ -1791,10 +3032,37 @@ async fn execute_cte_queries(
             .headers_mut()
             .insert(header::CONTENT_TYPE, HeaderValue::from_static("text/plain"));
         Ok(response)
+    } else if stream {
+        let mut chunks = app_state
+            .executor
+            .execute_json_stream(&bound.sql, &bound.params, settings, role.as_deref())
+            .await
+            .map_err(|e| {
+                (
+                    axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Executor error: {}", e),
+                )
+            })?;
+        // Wait for the first chunk so a query that fails up front still gets
+        // an error status; a failure after that can only cut the body short.
+        let first = chunks.next().await.transpose().map_err(|e| {
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Executor error: {}", e),
+            )
+        })?;
+        let body = futures_util::stream::iter(first.map(Ok)).chain(chunks);
+
+        let mut response = Response::new(axum::body::Body::from_stream(body));
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/x-ndjson"),
+        );
+        Ok(response)
     } else {
         let rows = app_state
             .executor
-            .execute_json(&final_sql, role.as_deref())
+            .execute_json_with_settings(&bound.sql, &bound.params, settings, role.as_deref())
             .await
             .map_err(|e| {
                 (
@@ -1804,9 +3072,19 @@ async fn execute_cte_queries(
             })?;
 
         // Wrap results in an object with "results" key for consistency with Neo4j format
-        let response_obj = serde_json::json!({
-            "results": rows
-        });
+        let response_obj = match paging {
+            Some(paging) => {
+                let page = paging.page(rows)?;
+                let mut obj = serde_json::json!({ "results": page.rows });
+                if let Some(token) = page.continuation_token {
+                    obj["continuation_token"] = Value::String(token);
+                }
+                obj
+            }
+            None => serde_json::json!({
+                "results": rows
+            }),
+        };
 
         Ok(Json(response_obj).into_response())
     }
@@ -2035,14 +3313,49 @@ pub async fn load_schema_handler(
             }
 
             Ok(Json(serde_json::json!({
-                "message": format!("Schema '{}' loaded successfully", payload.schema_name),
-                "schema_name": payload.schema_name
+                "message": format!("Schema '{}' loaded successfully", payload.schema_name),
+                "schema_name": payload.schema_name
+            })))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({
+                "error": format!("Failed to load schema: {}", e)
+            })),
+        )),
+    }
+}
+
+/// `POST /schemas/reload`: re-read `GRAPH_CONFIG_PATH` and swap its schemas
+/// without a restart. Validated against ClickHouse when a client is
+/// configured; on any failure the running schemas are left untouched.
+pub async fn reload_schemas_handler(
+    State(app_state): State<Arc<AppState>>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let validate_schema = app_state.clickhouse_client.is_some();
+
+    match graph_catalog::reload_global_schema(app_state.clickhouse_client.clone(), validate_schema)
+        .await
+    {
+        Ok(diffs) => {
+            let schemas: Vec<serde_json::Value> = diffs
+                .into_iter()
+                .map(|(schema_name, diff)| {
+                    let mut entry = serde_json::to_value(diff).unwrap_or_default();
+                    entry["schema_name"] = serde_json::Value::String(schema_name);
+                    entry
+                })
+                .collect();
+            Ok(Json(serde_json::json!({
+                "message": format!("Reloaded {} schema(s)", schemas.len()),
+                "validated": validate_schema,
+                "schemas": schemas
             })))
         }
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({
-                "error": format!("Failed to load schema: {}", e)
+                "error": format!("Failed to reload schemas: {}", e)
             })),
         )),
     }
@@ -2073,6 +3386,361 @@ pub async fn get_schema_handler(
     }
 }
 
+/// Number of recent queries scanned for autocomplete history boosting.
+const COMPLETE_HISTORY_QUERIES: usize = 200;
+
+/// Ranked name completions for editors.
+///
+/// `GET /schemas/{name}/complete?prefix=Us&context=label|property|reltype`
+/// with optional `owner=<label or type>` (property context only), `limit`,
+/// and `history=false` to skip boosting by recent query history.
+pub async fn complete_handler(
+    axum::extract::Path(schema_name): axum::extract::Path<String>,
+    axum::extract::Query(params): axum::extract::Query<HashMap<String, String>>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let context_param = params.get("context").map(String::as_str).unwrap_or("label");
+    let Some(context) = autocomplete::CompletionContext::parse(context_param) else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": format!(
+                    "Invalid context '{}': expected label, property or reltype",
+                    context_param
+                )
+            })),
+        ));
+    };
+    let schema = graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({ "error": e })),
+            )
+        })?;
+
+    let prefix = params.get("prefix").map(String::as_str).unwrap_or("");
+    let owner = params.get("owner").map(String::as_str);
+    let limit = params
+        .get("limit")
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(autocomplete::DEFAULT_LIMIT);
+    let use_history = params.get("history").map(String::as_str) != Some("false");
+
+    // History only carries query text when `metrics_query_preview` is enabled.
+    let history: Vec<String> = match GLOBAL_SERVER_METRICS.get() {
+        Some(reg) if use_history && reg.enabled() => reg
+            .recent_queries(COMPLETE_HISTORY_QUERIES)
+            .into_iter()
+            .filter_map(|r| r.query_preview)
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let suggestions = autocomplete::complete(&schema, prefix, context, owner, &history, limit);
+    Ok(Json(serde_json::json!({
+        "schema_name": schema_name,
+        "suggestions": suggestions,
+    })))
+}
+
+/// Compact schema description for LLM agents writing Cypher.
+///
+/// `GET /schemas/{name}/llm-context` with optional `samples=<n>` example
+/// values per property (default 3, at most 10, `0` to skip sampling).
+/// Sampling failures are logged and leave the examples out.
+pub async fn llm_context_handler(
+    State(app_state): State<Arc<AppState>>,
+    axum::extract::Path(schema_name): axum::extract::Path<String>,
+    axum::extract::Query(params): axum::extract::Query<HashMap<String, String>>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let schema = graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({ "error": e })),
+            )
+        })?;
+    let descriptions = graph_catalog::get_view_config_by_name(&schema_name)
+        .await
+        .map(|config| llm_context::descriptions(&config))
+        .unwrap_or_default();
+
+    let samples = params
+        .get("samples")
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(llm_context::DEFAULT_SAMPLES);
+
+    let (mut context, queries) = llm_context::build(&schema_name, &schema, &descriptions, samples);
+    for query in queries {
+        match app_state.executor.execute_json(&query.sql, None).await {
+            Ok(rows) => {
+                if let Some(row) = rows.first() {
+                    context.add_examples(&query, row);
+                }
+            }
+            Err(e) => log::warn!("llm-context sampling failed for {}: {}", schema_name, e),
+        }
+    }
+    Ok(Json(serde_json::json!(context)))
+}
+
+/// Denormalized edge tables suggested by the logged workload.
+///
+/// `GET /schemas/{name}/denormalization-advice` runs the advisor
+/// (`graph_catalog::denormalization_advisor`) over the workload log's
+/// queries for the schema. `min_queries` (default 5) sets how many queries
+/// must traverse an edge, `max_properties` (default 8) caps the node
+/// properties copied per side, and `ddl=true` adds `CREATE TABLE` statements.
+/// Answers 404 when the workload log is off.
+pub async fn denormalization_advice_handler(
+    axum::extract::Path(schema_name): axum::extract::Path<String>,
+    axum::extract::Query(params): axum::extract::Query<HashMap<String, String>>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let Some(log) = GLOBAL_WORKLOAD_LOG.get() else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "The workload log is off; set CLICKGRAPH_WORKLOAD_LOG_CAPACITY to record queries for the advisor"
+            })),
+        ));
+    };
+    let schema = graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({ "error": e })),
+            )
+        })?;
+
+    let defaults = denormalization_advisor::AdvisorOptions::default();
+    let options = denormalization_advisor::AdvisorOptions {
+        min_queries: params
+            .get("min_queries")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(defaults.min_queries),
+        max_properties_per_side: params
+            .get("max_properties")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(defaults.max_properties_per_side),
+        include_ddl: params.get("ddl").map(String::as_str) == Some("true"),
+    };
+    let workload = log.queries(&schema_name);
+    let advice = denormalization_advisor::advise(&schema, &workload, options);
+    Ok(Json(serde_json::json!({
+        "schema_name": schema_name,
+        "queries_analyzed": workload.len(),
+        "advice": advice,
+    })))
+}
+
+/// Check the schema's declared constraints against the data.
+///
+/// `GET /schemas/{name}/constraints/validate` runs one query per declared
+/// fact (see `graph_catalog::graph_constraints`) and reports its violation
+/// count. `max_depth` bounds the acyclicity walk; `sql_only=true` returns the
+/// queries without running them.
+pub async fn validate_constraints_handler(
+    State(app_state): State<Arc<AppState>>,
+    axum::extract::Path(schema_name): axum::extract::Path<String>,
+    axum::extract::Query(params): axum::extract::Query<HashMap<String, String>>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let schema = graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({ "error": e })),
+            )
+        })?;
+
+    let max_depth = params
+        .get("max_depth")
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(graph_constraints::DEFAULT_CYCLE_CHECK_DEPTH);
+    let sql_only = params.get("sql_only").map(String::as_str) == Some("true");
+
+    let mut all_valid = true;
+    let mut results = Vec::new();
+    for check in graph_constraints::validation_checks(&schema, max_depth) {
+        if sql_only {
+            results.push(serde_json::json!({
+                "constraint": check.constraint,
+                "sql": check.sql,
+            }));
+            continue;
+        }
+        let outcome = app_state.executor.execute_json(&check.sql, None).await;
+        let entry = match outcome {
+            Ok(rows) => {
+                let violations = rows
+                    .first()
+                    .and_then(|row| row.get("violations"))
+                    .and_then(|v| v.as_u64().or_else(|| v.as_str()?.parse().ok()))
+                    .unwrap_or(0);
+                all_valid &= violations == 0;
+                serde_json::json!({
+                    "constraint": check.constraint,
+                    "sql": check.sql,
+                    "violations": violations,
+                    "valid": violations == 0,
+                })
+            }
+            Err(e) => {
+                all_valid = false;
+                serde_json::json!({
+                    "constraint": check.constraint,
+                    "sql": check.sql,
+                    "error": e.to_string(),
+                })
+            }
+        };
+        results.push(entry);
+    }
+
+    let mut body = serde_json::json!({
+        "schema_name": schema_name,
+        "checks": results,
+    });
+    if !sql_only {
+        body["valid"] = serde_json::Value::Bool(all_valid);
+    }
+    Ok(Json(body))
+}
+
+/// Lint a loaded schema.
+///
+/// `GET /schemas/{name}/lint` reports naming, mapping, table, sort-key and
+/// unused-column problems (see `graph_catalog::schema_lint`). `live=false`
+/// skips the checks that read table metadata from ClickHouse;
+/// `fail_on=info|warning|error` answers `422` when a finding is at least that
+/// severe, so CI can gate on it.
+pub async fn lint_schema_handler(
+    State(app_state): State<Arc<AppState>>,
+    axum::extract::Path(schema_name): axum::extract::Path<String>,
+    axum::extract::Query(params): axum::extract::Query<HashMap<String, String>>,
+) -> Result<(StatusCode, Json<serde_json::Value>), (StatusCode, Json<serde_json::Value>)> {
+    let schema = graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({ "error": e })),
+            )
+        })?;
+    let live = params.get("live").map(String::as_str) != Some("false");
+    lint_report(
+        &app_state,
+        &schema_name,
+        &schema,
+        live,
+        params.get("fail_on").map(String::as_str),
+    )
+    .await
+}
+
+/// Body of `POST /schemas/lint`.
+#[derive(Debug, Deserialize)]
+pub struct LintSchemaRequest {
+    /// Schema YAML, as for `/schemas/load`
+    pub config_content: String,
+    /// Check the tables in ClickHouse as well (default true)
+    pub live: Option<bool>,
+    pub fail_on: Option<String>,
+}
+
+/// Lint schema YAML without loading it.
+///
+/// `POST /schemas/lint` runs the checks of [`lint_schema_handler`] on
+/// `config_content`, so CI can lint a schema file before deploying it.
+pub async fn lint_yaml_handler(
+    State(app_state): State<Arc<AppState>>,
+    Json(request): Json<LintSchemaRequest>,
+) -> Result<(StatusCode, Json<serde_json::Value>), (StatusCode, Json<serde_json::Value>)> {
+    let bad_request = |e: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": e })),
+        )
+    };
+    let config =
+        crate::graph_catalog::config::GraphSchemaConfig::from_yaml_str(&request.config_content)
+            .map_err(|e| bad_request(format!("Invalid schema YAML: {}", e)))?;
+    let schema = config
+        .to_graph_schema()
+        .map_err(|e| bad_request(format!("Invalid schema: {}", e)))?;
+    let schema_name = config.name.clone().unwrap_or_default();
+    lint_report(
+        &app_state,
+        &schema_name,
+        &schema,
+        request.live.unwrap_or(true),
+        request.fail_on.as_deref(),
+    )
+    .await
+}
+
+async fn lint_report(
+    app_state: &AppState,
+    schema_name: &str,
+    schema: &GraphSchema,
+    live: bool,
+    fail_on: Option<&str>,
+) -> Result<(StatusCode, Json<serde_json::Value>), (StatusCode, Json<serde_json::Value>)> {
+    let error = |status: StatusCode, e: String| (status, Json(serde_json::json!({ "error": e })));
+    let fail_on = fail_on
+        .map(str::parse::<schema_lint::Severity>)
+        .transpose()
+        .map_err(|e| error(StatusCode::BAD_REQUEST, e))?;
+
+    let tables = if live {
+        let (tables_sql, columns_sql) =
+            schema_lint::metadata_queries(schema).map_err(|e| error(StatusCode::BAD_REQUEST, e))?;
+        let fetch = |sql: String| async move {
+            app_state
+                .executor
+                .execute_json(&sql, None)
+                .await
+                .map_err(|e| {
+                    error(
+                        StatusCode::BAD_GATEWAY,
+                        format!("Reading table metadata failed: {}", e),
+                    )
+                })
+        };
+        let table_rows = fetch(tables_sql).await?;
+        let column_rows = fetch(columns_sql).await?;
+        Some(schema_lint::parse_metadata(&table_rows, &column_rows))
+    } else {
+        None
+    };
+    let warnings = schema_lint::lint(schema, tables.as_ref());
+
+    let count = |severity| warnings.iter().filter(|w| w.severity == severity).count();
+    let summary = serde_json::json!({
+        "error": count(schema_lint::Severity::Error),
+        "warning": count(schema_lint::Severity::Warning),
+        "info": count(schema_lint::Severity::Info),
+    });
+    let failed = fail_on.is_some_and(|level| warnings.iter().any(|w| w.severity >= level));
+    let status = if failed {
+        StatusCode::UNPROCESSABLE_ENTITY
+    } else {
+        StatusCode::OK
+    };
+    Ok((
+        status,
+        Json(serde_json::json!({
+            "schema_name": schema_name,
+            "live": live,
+            "summary": summary,
+            "warnings": warnings,
+        })),
+    ))
+}
+
 /// Translate a Cypher query string into ClickHouse SQL.
 ///
 /// Used by export procedures to compile the inner Cypher query.
@@ -2087,11 +3755,37 @@ fn translate_cypher_to_sql(
         "Translating inner Cypher query for schema '{}'",
         schema_name
     );
+    plan_cypher_to_sql(cypher, graph_schema, None, None, None, max_cte_depth)
+        .map(|planned| planned.sql)
+        .map_err(|e| format!("Inner {}", e))
+}
+
+/// A planned query: its SQL, with the logical plan's rendering and summary
+/// for EXPLAIN / PROFILE.
+struct PlannedCypher {
+    logical_plan: String,
+    summary: explain::PlanSummary,
+    sql: String,
+}
+
+/// Plan a Cypher query and generate its SQL.
+fn plan_cypher_to_sql(
+    cypher: &str,
+    graph_schema: &GraphSchema,
+    tenant_id: Option<String>,
+    view_parameter_values: Option<HashMap<String, String>>,
+    max_inferred_types: Option<usize>,
+    max_cte_depth: u32,
+) -> Result<PlannedCypher, String> {
     // Parse
     let (_, parsed_stmt) = open_cypher_parser::parse_cypher_statement(cypher)
-        .map_err(|e| format!("Inner Cypher parse error: {}", e))?;
+        .map_err(|e| format!("Cypher parse error: {}", e))?;
+    let query_options = query_planner::QueryOptions::from_query(cypher);
+    let graph_schema = query_planner::snapshot_schema(&parsed_stmt, graph_schema, &query_options)
+        .map_err(|e| format!("Cypher planning error: {}", e))?;
+    let graph_schema = &*graph_schema;
 
-    // id() transform (stateless for export — no IdMapper scope needed)
+    // id() transform (stateless — no IdMapper scope needed)
     use crate::query_planner::ast_transform;
     use crate::server::bolt_protocol::id_mapper::IdMapper;
     let id_mapper = IdMapper::new();
@@ -2105,33 +3799,192 @@ fn translate_cypher_to_sql(
 
     // Plan
     crate::query_planner::logical_plan::reset_all_counters();
-    let (logical_plan, plan_ctx) =
-        query_planner::evaluate_read_statement(cypher_statement, graph_schema, None, None, None)
-            .map_err(|e| format!("Inner Cypher planning error: {}", e))?;
+    let (logical_plan, plan_ctx) = query_planner::evaluate_read_statement(
+        cypher_statement,
+        graph_schema,
+        tenant_id,
+        view_parameter_values,
+        max_inferred_types,
+    )
+    .map_err(|e| format!("Cypher planning error: {}", e))?;
+    let plan_text = logical_plan.to_string();
 
     // Render
     let render_plan = logical_plan
         .to_render_plan_with_ctx(graph_schema, Some(&plan_ctx), None)
-        .map_err(|e| format!("Inner Cypher render error: {}", e))?;
+        .map_err(|e| format!("Cypher render error: {}", e))?;
+
+    let summary = explain::PlanSummary::new(&logical_plan, &render_plan.ctes.0);
 
     // Generate SQL
-    Ok(clickhouse_query_generator::generate_sql(
-        render_plan,
-        max_cte_depth,
-    ))
+    let sql = clickhouse_query_generator::generate_sql(render_plan, max_cte_depth);
+    Ok(PlannedCypher {
+        logical_plan: plan_text,
+        summary,
+        sql,
+    })
+}
+
+/// `EXPLAIN` / `PROFILE` a read query: return its logical plan and SQL, and
+/// for PROFILE the rows plus ClickHouse's stats from `system.query_log`.
+async fn explain_query(
+    app_state: &AppState,
+    payload: &QueryRequest,
+    mode: ExplainMode,
+    cypher: &str,
+    schema_name_param: Option<String>,
+) -> Result<Response, (StatusCode, String)> {
+    let mode_name = explain::mode_name(mode);
+    let use_graph = match open_cypher_parser::parse_cypher_statement(cypher) {
+        Ok((_, statement)) => {
+            if query_planner::get_statement_query_type(&statement) != QueryType::Read
+                || matches!(statement, CypherStatement::CopyTo(_))
+            {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    format!("{} supports read queries only", mode_name),
+                ));
+            }
+            query_planner::target_graph(&statement)
+                .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?
+                .map(str::to_string)
+        }
+        Err(e) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Query syntax error: {}", e),
+            ))
+        }
+    };
+    let schema_name =
+        graph_catalog::resolve_graph_name(use_graph.as_deref(), schema_name_param.as_deref());
+    let graph_schema = graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let view_parameter_values: Option<HashMap<String, String>> =
+        payload.view_parameters.as_ref().map(|params| {
+            params
+                .iter()
+                .map(|(k, v)| {
+                    let s = match v {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    (k.clone(), s)
+                })
+                .collect()
+        });
+
+    let context = QueryContext::new(Some(schema_name.clone()));
+    let PlannedCypher {
+        logical_plan,
+        summary,
+        sql,
+    } = with_query_context(context, async {
+        crate::server::query_context::set_current_schema(Arc::new(graph_schema.clone()));
+        plan_cypher_to_sql(
+            cypher,
+            &graph_schema,
+            payload.tenant_id.clone(),
+            view_parameter_values,
+            payload.max_inferred_types,
+            app_state.config.max_cte_depth,
+        )
+    })
+    .await
+    .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let mut response = explain::ExplainResponse {
+        mode: mode_name,
+        cypher_query: cypher.trim().to_string(),
+        schema_name,
+        logical_plan,
+        plan: summary,
+        generated_sql: String::new(),
+        results: None,
+        stats: None,
+        stats_error: None,
+    };
+
+    if mode == ExplainMode::Explain {
+        // Show values inlined when given; otherwise the `$param` template
+        response.generated_sql = prepare_final_sql(
+            std::slice::from_ref(&sql),
+            &payload.parameters,
+            &payload.view_parameters,
+            false,
+        )
+        .map(|bound| bound.sql)
+        .unwrap_or(sql);
+        return Ok(Json(response).into_response());
+    }
+
+    let bound = prepare_final_sql(
+        std::slice::from_ref(&sql),
+        &payload.parameters,
+        &payload.view_parameters,
+        app_state.executor.supports_query_params(),
+    )?;
+    let role = payload.role.as_deref();
+    let tag = explain::profile_tag();
+    let rows = app_state
+        .executor
+        .execute_json_with_params(&explain::tag_sql(&bound.sql, &tag), &bound.params, role)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("PROFILE execution failed: {}", e),
+            )
+        })?;
+    response.generated_sql = bound.sql;
+    response.results = Some(rows);
+
+    if let Err(e) = app_state
+        .executor
+        .execute_text("SYSTEM FLUSH LOGS", "TabSeparated", role)
+        .await
+    {
+        log::warn!("PROFILE: SYSTEM FLUSH LOGS failed: {}", e);
+    }
+    match app_state
+        .executor
+        .execute_json(&explain::query_log_sql(&tag), role)
+        .await
+    {
+        Ok(rows) => match rows.first().and_then(explain::ProfileStats::from_row) {
+            Some(stats) => response.stats = Some(stats),
+            None => {
+                response.stats_error = Some(
+                    "no system.query_log entry for the profiled query (query logging disabled or not flushed yet)"
+                        .to_string(),
+                )
+            }
+        },
+        Err(e) => {
+            response.stats_error = Some(format!("reading system.query_log failed: {}", e));
+        }
+    }
+    Ok(Json(response).into_response())
 }
 
-/// Extract a schema name from a leading `USE <schema>` clause in a Cypher query.
+/// Extract the graph a Cypher query names with `USE <schema>`.
 ///
-/// This lightweight text-based extraction mirrors the normal path's USE handling
-/// (which relies on the parser AST) for interceptions that run before parsing.
+/// A statement that parses reports the graph of any UNION branch, as the
+/// normal path's [`query_planner::target_graph`] does; interceptions whose
+/// statements don't parse fall back to a leading `USE` in the text.
 fn extract_schema_from_use_clause(query: &str) -> Option<String> {
+    if let Ok((_, statement)) = open_cypher_parser::parse_cypher_statement(query) {
+        if let Ok(Some(graph)) = query_planner::target_graph(&statement) {
+            return Some(graph.to_string());
+        }
+    }
     let trimmed = query.trim_start();
-    if !trimmed
-        .get(..4)
-        .map(|s| s.eq_ignore_ascii_case("USE "))
-        .unwrap_or(false)
-    {
+    let keyword = trimmed
+        .get(..3)
+        .is_some_and(|s| s.eq_ignore_ascii_case("USE"));
+    if !keyword || !trimmed[3..].starts_with(char::is_whitespace) {
         return None;
     }
 
@@ -2341,3 +4194,56 @@ pub async fn draft_handler(
         "message": "Review and edit the YAML before loading with /schemas/load"
     })))
 }
+
+/// `GET /schemas/templates` — the built-in raw-log schema templates.
+pub async fn list_templates_handler() -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "templates": schema_templates::templates() }))
+}
+
+/// A built-in schema template rendered for one database.
+///
+/// `GET /schemas/templates/{name}` with optional `database` (default: the
+/// template's) and `schema_name` (default: the template name). The response
+/// carries the YAML for `/schemas/load` and the `CREATE VIEW` statements the
+/// mapping needs; nothing is loaded or created here.
+pub async fn render_template_handler(
+    axum::extract::Path(template_name): axum::extract::Path<String>,
+    axum::extract::Query(params): axum::extract::Query<HashMap<String, String>>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let template = schema_templates::find(&template_name).ok_or_else(|| {
+        let names: Vec<_> = schema_templates::templates()
+            .iter()
+            .map(|t| t.name)
+            .collect();
+        (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": format!(
+                    "Unknown schema template '{}'; available: {}",
+                    template_name,
+                    names.join(", ")
+                )
+            })),
+        )
+    })?;
+    let rendered = template
+        .render(
+            params.get("database").map(String::as_str),
+            params.get("schema_name").map(String::as_str),
+        )
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "error": e })),
+            )
+        })?;
+    Ok(Json(serde_json::json!({
+        "template": rendered.template,
+        "schema_name": rendered.schema_name,
+        "database": rendered.database,
+        "tables": template.tables,
+        "setup_sql": rendered.setup_sql,
+        "yaml": rendered.yaml,
+        "message": "Run setup_sql, then load the YAML with /schemas/load"
+    })))
+}
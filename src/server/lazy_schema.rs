@@ -0,0 +1,561 @@
+//! Lazily loaded schema elements.
+//!
+//! A node or edge marked `lazy: true` is registered without touching its
+//! table: no engine detection, column discovery or validation at load, so a
+//! very large schema starts quickly and may reference tables that don't
+//! exist yet. The first query against the schema ([`ensure_ready`]) and a
+//! background task ([`complete_loop`]) check each pending table in
+//! `system.columns` — it must exist and have every mapped column — then
+//! rebuild the schema with what they discovered. A failed check is retried
+//! after `lazy_schema_retry_secs`. `GET /schemas/{name}/status` reports
+//! where every lazy element stands.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{graph_catalog, AppState, GLOBAL_SCHEMAS};
+use crate::executor::QueryExecutor;
+use crate::graph_catalog::config::{DiscoveredTables, EdgeDefinition, GraphSchemaConfig};
+use crate::graph_catalog::engine_detection::{parse_engine, TableEngine};
+use crate::graph_catalog::expression_parser::{parse_property_value, PropertyValue};
+use crate::procedures::graph_quality::sql_string;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ElementKind {
+    Node,
+    Edge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ElementState {
+    /// Not checked yet
+    Pending,
+    /// Table and mapped columns exist; discovered metadata is in use
+    Ready,
+    /// Last check failed (see `error`); retried later
+    Failed,
+}
+
+/// Where one lazy node or edge stands.
+#[derive(Debug, Clone, Serialize)]
+pub struct ElementStatus {
+    pub kind: ElementKind,
+    /// Label or edge type
+    pub name: String,
+    pub database: String,
+    pub table: String,
+    pub state: ElementState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// RFC 3339 time of the last check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked_at: Option<String>,
+    /// Columns the mappings reference, which the table must have
+    #[serde(skip)]
+    required_columns: Vec<String>,
+    #[serde(skip)]
+    checked: Option<Instant>,
+}
+
+impl ElementStatus {
+    fn new(
+        kind: ElementKind,
+        name: &str,
+        database: &str,
+        table: &str,
+        columns: Vec<String>,
+    ) -> Self {
+        let mut required_columns: Vec<String> = Vec::new();
+        for column in columns {
+            if !required_columns.contains(&column) {
+                required_columns.push(column);
+            }
+        }
+        ElementStatus {
+            kind,
+            name: name.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+            state: ElementState::Pending,
+            error: None,
+            checked_at: None,
+            required_columns,
+            checked: None,
+        }
+    }
+
+    /// Whether the element should be checked now.
+    fn is_due(&self, retry: Duration) -> bool {
+        match self.state {
+            ElementState::Pending => true,
+            ElementState::Ready => false,
+            ElementState::Failed => self.checked.is_none_or(|at| at.elapsed() >= retry),
+        }
+    }
+
+    fn record(&mut self, outcome: Result<(), String>) {
+        self.checked = Some(Instant::now());
+        self.checked_at = Some(chrono::Utc::now().to_rfc3339());
+        match outcome {
+            Ok(()) => {
+                self.state = ElementState::Ready;
+                self.error = None;
+            }
+            Err(e) => {
+                self.state = ElementState::Failed;
+                self.error = Some(e);
+            }
+        }
+    }
+}
+
+/// Plain columns referenced by property mappings (expressions are skipped).
+fn mapped_columns<'a>(mappings: impl Iterator<Item = &'a String>) -> Vec<String> {
+    mappings
+        .filter_map(|value| match parse_property_value(value) {
+            Ok(PropertyValue::Column(column)) => Some(column),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The lazy elements of a schema, all pending.
+fn lazy_elements(config: &GraphSchemaConfig) -> Vec<ElementStatus> {
+    let mut elements = Vec::new();
+    for node in config.graph_schema.nodes.iter().filter(|n| n.lazy) {
+        // node_id names properties; unmapped ones are columns of the same name
+        let mut columns: Vec<String> = node
+            .node_id
+            .columns()
+            .into_iter()
+            .filter(|id| !node.properties.contains_key(*id))
+            .map(str::to_string)
+            .collect();
+        columns.extend(mapped_columns(node.properties.values()));
+        columns.extend(node.label_column.clone());
+        elements.push(ElementStatus::new(
+            ElementKind::Node,
+            &node.label,
+            &node.database,
+            &node.table,
+            columns,
+        ));
+    }
+    for edge in &config.graph_schema.edges {
+        let EdgeDefinition::Standard(edge) = edge else {
+            continue;
+        };
+        if !edge.lazy {
+            continue;
+        }
+        let mut columns: Vec<String> = edge
+            .from_id
+            .columns()
+            .into_iter()
+            .chain(edge.to_id.columns())
+            .map(str::to_string)
+            .collect();
+        columns.extend(mapped_columns(edge.properties.values()));
+        elements.push(ElementStatus::new(
+            ElementKind::Edge,
+            &edge.type_name,
+            &edge.database,
+            &edge.table,
+            columns,
+        ));
+    }
+    elements
+}
+
+struct TrackedSchema {
+    /// Tells a reload of the schema, which replaces its tracking, apart
+    generation: u64,
+    /// Metadata the schema is built from: load-time discovery plus the
+    /// tables of elements that became ready
+    discovered: DiscoveredTables,
+    elements: Vec<ElementStatus>,
+}
+
+#[derive(Default)]
+struct LazySchemas {
+    schemas: Mutex<HashMap<String, TrackedSchema>>,
+    generations: AtomicU64,
+    /// One completion at a time, so concurrent first queries check each
+    /// table once
+    completing: tokio::sync::Mutex<()>,
+}
+
+fn registry() -> &'static LazySchemas {
+    static REGISTRY: OnceLock<LazySchemas> = OnceLock::new();
+    REGISTRY.get_or_init(LazySchemas::default)
+}
+
+impl LazySchemas {
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, TrackedSchema>> {
+        self.schemas.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn due(&self, schema_name: &str, retry: Duration) -> Vec<ElementStatus> {
+        self.lock()
+            .get(schema_name)
+            .map(|tracked| {
+                tracked
+                    .elements
+                    .iter()
+                    .filter(|e| e.is_due(retry))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Start tracking the lazy elements of a schema just registered under
+/// `schema_name`, replacing what was tracked under that name. `discovered`
+/// is the metadata the schema was built from.
+pub fn track(schema_name: &str, config: &GraphSchemaConfig, discovered: DiscoveredTables) {
+    let elements = lazy_elements(config);
+    let registry = registry();
+    let generation = registry.generations.fetch_add(1, Ordering::Relaxed);
+    let mut schemas = registry.lock();
+    if elements.is_empty() {
+        schemas.remove(schema_name);
+        return;
+    }
+    log::info!(
+        "Schema '{}': {} lazy element(s) pending first use",
+        schema_name,
+        elements.len()
+    );
+    schemas.insert(
+        schema_name.to_string(),
+        TrackedSchema {
+            generation,
+            discovered,
+            elements,
+        },
+    );
+}
+
+/// Lazy elements of a schema and where they stand (empty when it has none).
+pub fn status(schema_name: &str) -> Vec<ElementStatus> {
+    registry()
+        .lock()
+        .get(schema_name)
+        .map(|tracked| tracked.elements.clone())
+        .unwrap_or_default()
+}
+
+/// Check the table of a lazy element: its columns (name, type) and engine.
+async fn check_table(
+    executor: &dyn QueryExecutor,
+    element: &ElementStatus,
+) -> Result<(Vec<(String, String)>, Option<TableEngine>), String> {
+    let database = sql_string(&element.database);
+    let table = sql_string(&element.table);
+    let sql = format!(
+        "SELECT name, type FROM system.columns WHERE database = {} AND table = {} ORDER BY position",
+        database, table
+    );
+    let rows = executor
+        .execute_json(&sql, None)
+        .await
+        .map_err(|e| format!("failed to query columns: {}", e))?;
+    let text = |row: &Value, key: &str| row.get(key).and_then(Value::as_str).map(str::to_string);
+    let columns: Vec<(String, String)> = rows
+        .iter()
+        .filter_map(|row| Some((text(row, "name")?, text(row, "type")?)))
+        .collect();
+    if columns.is_empty() {
+        return Err(format!(
+            "table {}.{} does not exist",
+            element.database, element.table
+        ));
+    }
+    let missing: Vec<&str> = element
+        .required_columns
+        .iter()
+        .filter(|c| !columns.iter().any(|(name, _)| name == *c))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "table {}.{} has no column(s) {}",
+            element.database,
+            element.table,
+            missing.join(", ")
+        ));
+    }
+
+    // Engine detection is best effort, as at load
+    let sql = format!(
+        "SELECT engine, engine_full FROM system.tables WHERE database = {} AND name = {}",
+        database, table
+    );
+    let engine = match executor.execute_json(&sql, None).await {
+        Ok(rows) => rows
+            .first()
+            .and_then(|row| parse_engine(&text(row, "engine")?, &text(row, "engine_full")?).ok()),
+        Err(e) => {
+            log::debug!(
+                "Engine detection failed for {}.{}: {}",
+                element.database,
+                element.table,
+                e
+            );
+            None
+        }
+    };
+    Ok((columns, engine))
+}
+
+/// Check the due lazy elements of a schema and, when any became ready,
+/// rebuild it with their tables' metadata. Failures are recorded in the
+/// status, not returned: a query against a still-missing table fails in
+/// ClickHouse as it would have without `lazy`.
+pub async fn ensure_ready(executor: &dyn QueryExecutor, schema_name: &str, retry: Duration) {
+    let registry = registry();
+    if registry.due(schema_name, retry).is_empty() {
+        return;
+    }
+    let _completing = registry.completing.lock().await;
+    // Re-read: a completion that held the lock may have done the work
+    let due = registry.due(schema_name, retry);
+    if due.is_empty() {
+        return;
+    }
+
+    let mut outcomes = Vec::with_capacity(due.len());
+    for element in &due {
+        outcomes.push(check_table(executor, element).await);
+    }
+
+    let (generation, discovered, became_ready) = {
+        let mut schemas = registry.lock();
+        let Some(tracked) = schemas.get_mut(schema_name) else {
+            return;
+        };
+        let mut became_ready = Vec::new();
+        for (element, outcome) in due.iter().zip(outcomes) {
+            let Some(entry) = tracked.elements.iter_mut().find(|e| {
+                e.kind == element.kind && e.name == element.name && e.table == element.table
+            }) else {
+                continue;
+            };
+            match outcome {
+                Ok((columns, engine)) => {
+                    tracked
+                        .discovered
+                        .insert(&entry.database, &entry.table, Some(columns), engine);
+                    entry.record(Ok(()));
+                    became_ready.push(entry.name.clone());
+                }
+                Err(e) => {
+                    log::warn!(
+                        "Schema '{}': lazy {:?} '{}' not ready: {}",
+                        schema_name,
+                        entry.kind,
+                        entry.name,
+                        e
+                    );
+                    entry.record(Err(e));
+                }
+            }
+        }
+        (tracked.generation, tracked.discovered.clone(), became_ready)
+    };
+    if became_ready.is_empty() {
+        return;
+    }
+
+    if let Err(e) = rebuild(schema_name, generation, &discovered).await {
+        log::warn!("Schema '{}': rebuild failed: {}", schema_name, e);
+        let mut schemas = registry.lock();
+        if let Some(tracked) = schemas.get_mut(schema_name) {
+            for entry in tracked
+                .elements
+                .iter_mut()
+                .filter(|e| became_ready.contains(&e.name))
+            {
+                entry.record(Err(format!("schema rebuild failed: {}", e)));
+            }
+        }
+        return;
+    }
+    log::info!(
+        "Schema '{}': lazy element(s) ready: {}",
+        schema_name,
+        became_ready.join(", ")
+    );
+}
+
+/// Rebuild a registered schema from its config and `discovered`, and swap
+/// it in unless it was reloaded since `generation` was tracked.
+async fn rebuild(
+    schema_name: &str,
+    generation: u64,
+    discovered: &DiscoveredTables,
+) -> Result<(), String> {
+    let config = graph_catalog::get_view_config_by_name(schema_name).await?;
+    let schema = config
+        .to_graph_schema_discovered(discovered)
+        .map_err(|e| e.to_string())?;
+
+    let schemas_lock = GLOBAL_SCHEMAS
+        .get()
+        .ok_or("Global schemas not initialized")?;
+    let mut schemas = schemas_lock.write().await;
+    // Reloads track the new version under the same write lock
+    let current = registry().lock().get(schema_name).map(|t| t.generation);
+    if current != Some(generation) {
+        return Ok(());
+    }
+    schemas.insert(schema_name.to_string(), schema);
+    if let Some(cache) = super::GLOBAL_QUERY_CACHE.get() {
+        cache.invalidate_schema(schema_name);
+    }
+    Ok(())
+}
+
+/// Background completion: every `every`, check the due lazy elements of
+/// every tracked schema.
+pub async fn complete_loop(executor: Arc<dyn QueryExecutor>, every: Duration) {
+    let mut ticker = tokio::time::interval(every);
+    loop {
+        ticker.tick().await;
+        let names: Vec<String> = registry()
+            .lock()
+            .iter()
+            .filter(|(_, tracked)| tracked.elements.iter().any(|e| e.is_due(every)))
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in names {
+            ensure_ready(executor.as_ref(), &name, every).await;
+        }
+    }
+}
+
+/// `GET /schemas/{name}/status`: the schema's lazy elements and whether all
+/// of them are ready.
+pub async fn status_handler(
+    State(_app_state): State<Arc<AppState>>,
+    Path(schema_name): Path<String>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({ "error": e })),
+            )
+        })?;
+    let elements = status(&schema_name);
+    let count = |state: ElementState| elements.iter().filter(|e| e.state == state).count();
+    Ok(Json(serde_json::json!({
+        "schema_name": schema_name,
+        "complete": count(ElementState::Ready) == elements.len(),
+        "pending": count(ElementState::Pending),
+        "failed": count(ElementState::Failed),
+        "elements": elements,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: id
+      property_mappings:
+        name: full_name
+        upper_name: "upper(full_name)"
+    - label: Event
+      database: db
+      table: events
+      node_id: event_id
+      lazy: true
+      label_column: kind
+      label_value: click
+      property_mappings:
+        at: ts
+        day: "toDate(ts)"
+  edges:
+    - type: DID
+      database: db
+      table: user_events
+      from_id: user_id
+      to_id: event_id
+      from_node: User
+      to_node: Event
+      lazy: true
+      property_mappings:
+        weight: w
+"#;
+
+    fn config() -> GraphSchemaConfig {
+        serde_yaml::from_str(YAML).unwrap()
+    }
+
+    #[test]
+    fn lists_lazy_elements_with_their_mapped_columns() {
+        let elements = lazy_elements(&config());
+        assert_eq!(elements.len(), 2);
+
+        let event = &elements[0];
+        assert_eq!(event.kind, ElementKind::Node);
+        assert_eq!(event.name, "Event");
+        assert_eq!(event.state, ElementState::Pending);
+        let mut columns = event.required_columns.clone();
+        columns.sort();
+        assert_eq!(columns, vec!["event_id", "kind", "ts"]);
+
+        let did = &elements[1];
+        assert_eq!(did.kind, ElementKind::Edge);
+        assert_eq!(did.required_columns, vec!["user_id", "event_id", "w"]);
+    }
+
+    #[test]
+    fn failed_elements_wait_for_the_retry_interval() {
+        let mut element = lazy_elements(&config()).remove(0);
+        assert!(element.is_due(Duration::from_secs(60)));
+
+        element.record(Err("table db.events does not exist".to_string()));
+        assert_eq!(element.state, ElementState::Failed);
+        assert!(!element.is_due(Duration::from_secs(60)));
+        assert!(element.is_due(Duration::ZERO));
+
+        element.record(Ok(()));
+        assert_eq!(element.state, ElementState::Ready);
+        assert!(element.error.is_none());
+        assert!(!element.is_due(Duration::ZERO));
+    }
+
+    #[test]
+    fn schemas_without_lazy_elements_are_not_tracked() {
+        let mut config = config();
+        track("lazy_unit_tracked", &config, DiscoveredTables::default());
+        assert_eq!(status("lazy_unit_tracked").len(), 2);
+
+        config.graph_schema.nodes[1].lazy = false;
+        config.graph_schema.edges.clear();
+        track("lazy_unit_tracked", &config, DiscoveredTables::default());
+        assert!(status("lazy_unit_tracked").is_empty());
+    }
+}
@@ -0,0 +1,269 @@
+//! LLM client for `POST /query/natural`
+//!
+//! Supports two API formats:
+//! - **Anthropic** (default): Claude API with `x-api-key` auth
+//! - **OpenAI-compatible**: Works with OpenAI, Ollama, vLLM, LiteLLM, Together, Groq, etc.
+//!
+//! Provider, model and URL come from the server config
+//! (`CLICKGRAPH_LLM_PROVIDER`, `CLICKGRAPH_LLM_MODEL`,
+//! `CLICKGRAPH_LLM_API_URL`); the API key only from the environment.
+
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::config::ServerConfig;
+
+/// Request timeout for LLM API calls
+const LLM_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A single Cypher statement and a confidence line fit comfortably
+const LLM_MAX_TOKENS: u32 = 1024;
+
+/// Supported API providers
+#[derive(Debug, Clone, PartialEq)]
+pub enum LlmProvider {
+    Anthropic,
+    OpenAI,
+}
+
+/// LLM endpoint the server calls
+#[derive(Debug, Clone)]
+pub struct LlmConfig {
+    pub api_key: Option<String>,
+    pub model: String,
+    pub api_url: String,
+    pub provider: LlmProvider,
+}
+
+impl LlmConfig {
+    /// The configured LLM, or None without an API key. A custom
+    /// `llm_api_url` (a local model server) needs no key.
+    ///
+    /// - `openai` → OpenAI-compatible mode (checks `OPENAI_API_KEY` then `ANTHROPIC_API_KEY`)
+    /// - `anthropic` or unset → Anthropic mode (checks `ANTHROPIC_API_KEY`)
+    pub fn from_server_config(config: &ServerConfig) -> Option<Self> {
+        let provider_str = config
+            .llm_provider
+            .as_deref()
+            .unwrap_or_default()
+            .to_lowercase();
+        let key = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| std::env::var(name).ok().filter(|k| !k.is_empty()))
+        };
+
+        let (provider, api_key, default_model, default_url) = match provider_str.as_str() {
+            "openai" => (
+                LlmProvider::OpenAI,
+                key(&["OPENAI_API_KEY", "ANTHROPIC_API_KEY"]),
+                "gpt-4o",
+                "https://api.openai.com/v1/chat/completions",
+            ),
+            _ => (
+                LlmProvider::Anthropic,
+                key(&["ANTHROPIC_API_KEY"]),
+                "claude-sonnet-4-20250514",
+                "https://api.anthropic.com/v1/messages",
+            ),
+        };
+
+        if api_key.is_none() && config.llm_api_url.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            api_key,
+            model: config
+                .llm_model
+                .clone()
+                .unwrap_or_else(|| default_model.to_string()),
+            api_url: config
+                .llm_api_url
+                .clone()
+                .unwrap_or_else(|| default_url.to_string()),
+            provider,
+        })
+    }
+}
+
+// ── Anthropic API types ──
+
+#[derive(Debug, Serialize)]
+struct AnthropicRequest {
+    model: String,
+    max_tokens: u32,
+    system: String,
+    messages: Vec<ChatMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicResponse {
+    content: Vec<AnthropicContentBlock>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicContentBlock {
+    text: Option<String>,
+}
+
+// ── OpenAI-compatible API types ──
+
+#[derive(Debug, Serialize)]
+struct OpenAIRequest {
+    model: String,
+    max_tokens: u32,
+    messages: Vec<ChatMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIResponse {
+    choices: Vec<OpenAIChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIChoice {
+    message: OpenAIMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIMessage {
+    content: Option<String>,
+}
+
+// ── Shared types ──
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ChatMessage {
+    role: String,
+    content: String,
+}
+
+/// Call the LLM API with the given system and user prompts.
+/// Dispatches to Anthropic or OpenAI-compatible format based on config.
+pub async fn call_llm(
+    client: &Client,
+    config: &LlmConfig,
+    system_prompt: &str,
+    user_prompt: &str,
+) -> Result<String, String> {
+    match config.provider {
+        LlmProvider::Anthropic => call_anthropic(client, config, system_prompt, user_prompt).await,
+        LlmProvider::OpenAI => call_openai(client, config, system_prompt, user_prompt).await,
+    }
+}
+
+async fn call_anthropic(
+    client: &Client,
+    config: &LlmConfig,
+    system_prompt: &str,
+    user_prompt: &str,
+) -> Result<String, String> {
+    let request = AnthropicRequest {
+        model: config.model.clone(),
+        max_tokens: LLM_MAX_TOKENS,
+        system: system_prompt.to_string(),
+        messages: vec![ChatMessage {
+            role: "user".to_string(),
+            content: user_prompt.to_string(),
+        }],
+    };
+
+    let mut builder = client
+        .post(&config.api_url)
+        .header("anthropic-version", "2023-06-01")
+        .header("content-type", "application/json")
+        .timeout(LLM_REQUEST_TIMEOUT);
+    if let Some(key) = &config.api_key {
+        builder = builder.header("x-api-key", key);
+    }
+    let response = builder
+        .json(&request)
+        .send()
+        .await
+        .map_err(|e| format!("Anthropic API request failed: {}", e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Anthropic API error ({}): {}", status, body));
+    }
+
+    let msg: AnthropicResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Anthropic response: {}", e))?;
+
+    let text = msg
+        .content
+        .into_iter()
+        .filter_map(|b| b.text)
+        .collect::<Vec<_>>()
+        .join("");
+
+    if text.is_empty() {
+        return Err("Anthropic returned empty response".to_string());
+    }
+
+    Ok(text)
+}
+
+async fn call_openai(
+    client: &Client,
+    config: &LlmConfig,
+    system_prompt: &str,
+    user_prompt: &str,
+) -> Result<String, String> {
+    let request = OpenAIRequest {
+        model: config.model.clone(),
+        max_tokens: LLM_MAX_TOKENS,
+        messages: vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: system_prompt.to_string(),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: user_prompt.to_string(),
+            },
+        ],
+    };
+
+    let mut builder = client
+        .post(&config.api_url)
+        .header("content-type", "application/json")
+        .timeout(LLM_REQUEST_TIMEOUT);
+    if let Some(key) = &config.api_key {
+        builder = builder.header("authorization", format!("Bearer {}", key));
+    }
+    let response = builder
+        .json(&request)
+        .send()
+        .await
+        .map_err(|e| format!("OpenAI API request failed: {}", e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("OpenAI API error ({}): {}", status, body));
+    }
+
+    let msg: OpenAIResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse OpenAI response: {}", e))?;
+
+    let text = msg
+        .choices
+        .into_iter()
+        .filter_map(|c| c.message.content)
+        .collect::<Vec<_>>()
+        .join("");
+
+    if text.is_empty() {
+        return Err("OpenAI returned empty response".to_string());
+    }
+
+    Ok(text)
+}
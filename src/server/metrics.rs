@@ -1,7 +1,8 @@
 //! Server observability registry.
 //!
-//! Aggregate counters, per-phase latency histograms, captured ClickHouse
-//! execution stats, and a bounded slow-query ring buffer — rendered as both
+//! Aggregate counters, per-phase and per-planner-stage latency histograms, a
+//! CTE depth histogram, Bolt session gauges, captured ClickHouse execution
+//! stats, and a bounded slow-query ring buffer — rendered as both
 //! Prometheus exposition text (`/metrics`) and a JSON snapshot (`/stats`).
 //!
 //! Deliberately **zero new dependencies**: hand-rolled `AtomicU64` counters and
@@ -24,6 +25,7 @@ use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use serde::Serialize;
 
 use super::handlers::QueryPerformanceMetrics;
+use crate::utils::redaction::redact;
 
 /// Runtime configuration for the registry, built from `ServerConfig` at startup.
 #[derive(Clone, Debug)]
@@ -122,6 +124,12 @@ pub struct ChExecStats {
     pub read_rows: Option<u64>,
     pub read_bytes: Option<u64>,
     pub elapsed_ns: Option<u64>,
+    /// Whether ClickHouse served the result from its query cache; only known
+    /// for queries run with `use_query_cache`.
+    pub query_cache_hit: Option<bool>,
+    /// Requests sent to ClickHouse for this query (the statement itself plus
+    /// any retries, fallbacks or watermark checks).
+    pub round_trips: u64,
 }
 
 tokio::task_local! {
@@ -168,6 +176,18 @@ pub fn record_ch_summary(read_rows: u64, read_bytes: u64, elapsed_ns: u64) {
     });
 }
 
+/// Record whether the current query's result came from ClickHouse's query
+/// cache. No-op outside a [`with_ch_stats_scope`] scope.
+pub fn record_ch_query_cache_hit(hit: bool) {
+    let _ = CH_STATS_SLOT.try_with(|s| s.borrow_mut().query_cache_hit = Some(hit));
+}
+
+/// Record one request sent to ClickHouse for the current query. No-op outside
+/// a [`with_ch_stats_scope`] scope.
+pub fn record_ch_round_trip() {
+    let _ = CH_STATS_SLOT.try_with(|s| s.borrow_mut().round_trips += 1);
+}
+
 // ── latency histogram ────────────────────────────────────────────────────────
 
 /// Fixed upper bounds in seconds; an implicit `+Inf` bucket follows.
@@ -260,6 +280,71 @@ impl HistogramSnapshot {
 /// timers).
 const PHASES: [&str; 6] = ["total", "parse", "plan", "render", "sqlgen", "exec"];
 
+/// Stages of the read planner (`query_planner::evaluate_read_statement`),
+/// which together make up most of the `plan` phase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlannerStage {
+    /// Cypher AST to the initial logical plan
+    Build,
+    /// The three analyzer passes
+    Analyze,
+    /// The two optimizer passes
+    Optimize,
+}
+
+const PLANNER_STAGES: [&str; 3] = ["build", "analyze", "optimize"];
+
+impl PlannerStage {
+    fn index(self) -> usize {
+        match self {
+            PlannerStage::Build => 0,
+            PlannerStage::Analyze => 1,
+            PlannerStage::Optimize => 2,
+        }
+    }
+}
+
+/// Record how long a planner stage took, into the global registry (no-op if
+/// metrics are off or uninitialized).
+pub fn record_planner_stage(stage: PlannerStage, secs: f64) {
+    if let Some(reg) = super::GLOBAL_SERVER_METRICS.get() {
+        reg.observe_planner_stage(stage, secs);
+    }
+}
+
+// ── CTE depth histogram ──────────────────────────────────────────────────────
+
+/// Upper bounds for the number of CTEs in a query's SQL; `+Inf` follows.
+const CTE_DEPTH_BOUNDS: [u64; 8] = [0, 1, 2, 3, 4, 6, 8, 16];
+
+/// Distribution of CTE counts over [`CTE_DEPTH_BOUNDS`], same bucket layout
+/// as [`LatencyHistogram`].
+struct DepthHistogram {
+    buckets: [AtomicU64; CTE_DEPTH_BOUNDS.len() + 1],
+    sum: AtomicU64,
+    count: AtomicU64,
+}
+
+impl DepthHistogram {
+    fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            sum: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    fn observe(&self, depth: u64) {
+        let idx = CTE_DEPTH_BOUNDS
+            .iter()
+            .position(|&b| depth <= b)
+            .unwrap_or(CTE_DEPTH_BOUNDS.len());
+        self.buckets[idx].fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(depth, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
 // ── slow-query ring buffer ───────────────────────────────────────────────────
 
 /// One entry in the slow-query ring. `query_preview` is JSON-only (never a
@@ -341,6 +426,16 @@ impl Drop for InFlightGuard<'_> {
     }
 }
 
+/// RAII guard for one open Bolt connection: counts it on creation and drops
+/// it from the open-sessions gauge when the connection ends.
+pub struct BoltSessionGuard<'a>(&'a AtomicI64);
+
+impl Drop for BoltSessionGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
 /// Aggregate server metrics registry. Held behind a global `OnceCell`.
 pub struct ServerMetrics {
     start_time: Instant,
@@ -354,7 +449,13 @@ pub struct ServerMetrics {
     errors_by_class: [AtomicU64; ERROR_CLASSES.len()],
 
     histograms: [LatencyHistogram; PHASES.len()],
+    planner_stages: [LatencyHistogram; PLANNER_STAGES.len()],
+    cte_depth: DepthHistogram,
 
+    bolt_sessions_total: AtomicU64,
+    bolt_sessions_open: AtomicI64,
+
+    ch_round_trips: AtomicU64,
     ch_network_bytes: AtomicU64,
     ch_read_rows: AtomicU64,
     ch_read_bytes: AtomicU64,
@@ -375,6 +476,11 @@ impl ServerMetrics {
             by_type: std::array::from_fn(|_| AtomicU64::new(0)),
             errors_by_class: std::array::from_fn(|_| AtomicU64::new(0)),
             histograms: std::array::from_fn(|_| LatencyHistogram::new()),
+            planner_stages: std::array::from_fn(|_| LatencyHistogram::new()),
+            cte_depth: DepthHistogram::new(),
+            bolt_sessions_total: AtomicU64::new(0),
+            bolt_sessions_open: AtomicI64::new(0),
+            ch_round_trips: AtomicU64::new(0),
             ch_network_bytes: AtomicU64::new(0),
             ch_read_rows: AtomicU64::new(0),
             ch_read_bytes: AtomicU64::new(0),
@@ -392,6 +498,32 @@ impl ServerMetrics {
         InFlightGuard(&self.in_flight)
     }
 
+    /// Count a Bolt connection; the returned guard closes it on drop.
+    pub fn bolt_session_guard(&self) -> BoltSessionGuard<'_> {
+        if self.cfg.enabled {
+            self.bolt_sessions_total.fetch_add(1, Ordering::Relaxed);
+        }
+        self.bolt_sessions_open.fetch_add(1, Ordering::Relaxed);
+        BoltSessionGuard(&self.bolt_sessions_open)
+    }
+
+    pub fn bolt_sessions_open(&self) -> i64 {
+        self.bolt_sessions_open.load(Ordering::Relaxed)
+    }
+
+    fn observe_planner_stage(&self, stage: PlannerStage, secs: f64) {
+        if self.cfg.enabled {
+            self.planner_stages[stage.index()].observe(secs);
+        }
+    }
+
+    /// Record the number of CTEs in a query's generated SQL.
+    pub fn record_cte_depth(&self, depth: usize) {
+        if self.cfg.enabled {
+            self.cte_depth.observe(depth as u64);
+        }
+    }
+
     /// Record a single error by class without phase timings — for early-return
     /// paths (e.g. capacity reject, parse failure) that never built a full
     /// `QueryPerformanceMetrics`.
@@ -432,6 +564,8 @@ impl ServerMetrics {
         }
 
         if let Some(ch) = &sample.ch {
+            self.ch_round_trips
+                .fetch_add(ch.round_trips, Ordering::Relaxed);
             self.ch_network_bytes
                 .fetch_add(ch.network_bytes, Ordering::Relaxed);
             if let Some(r) = ch.read_rows {
@@ -451,7 +585,7 @@ impl ServerMetrics {
                 query_preview: if self.cfg.query_preview {
                     sample
                         .query_text
-                        .map(|q| q.chars().take(120).collect::<String>())
+                        .map(|q| redact(q).chars().take(120).collect::<String>())
                 } else {
                     None
                 },
@@ -506,6 +640,17 @@ impl ServerMetrics {
                 p99_ms: s.percentile_ms(0.99),
             }
         });
+        let planner = std::array::from_fn::<PhaseLatency, { PLANNER_STAGES.len() }, _>(|i| {
+            let s = self.planner_stages[i].snapshot();
+            PhaseLatency {
+                phase: PLANNER_STAGES[i],
+                count: s.count,
+                mean_ms: s.mean_ms(),
+                p50_ms: s.percentile_ms(0.50),
+                p95_ms: s.percentile_ms(0.95),
+                p99_ms: s.percentile_ms(0.99),
+            }
+        });
         let by_type = QUERY_TYPES
             .iter()
             .enumerate()
@@ -530,7 +675,11 @@ impl ServerMetrics {
             by_type,
             errors_by_class,
             latency: phases.to_vec(),
+            planner_latency: planner.to_vec(),
+            bolt_sessions_open: self.bolt_sessions_open(),
+            bolt_sessions_total: self.bolt_sessions_total.load(Ordering::Relaxed),
             clickhouse: ChStatsSnapshot {
+                round_trips: self.ch_round_trips.load(Ordering::Relaxed),
                 network_bytes: self.ch_network_bytes.load(Ordering::Relaxed),
                 read_rows: self.ch_read_rows.load(Ordering::Relaxed),
                 read_bytes: self.ch_read_bytes.load(Ordering::Relaxed),
@@ -588,6 +737,27 @@ impl ServerMetrics {
             );
         }
 
+        let _ = writeln!(
+            out,
+            "# HELP clickgraph_queries_by_status_total Queries by outcome: ok or the error class."
+        );
+        let _ = writeln!(out, "# TYPE clickgraph_queries_by_status_total counter");
+        let failed = self.queries_failed.load(Ordering::Relaxed);
+        let _ = writeln!(
+            out,
+            "clickgraph_queries_by_status_total{{status=\"ok\"}} {}",
+            self.queries_total
+                .load(Ordering::Relaxed)
+                .saturating_sub(failed)
+        );
+        for (i, &c) in ERROR_CLASSES.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "clickgraph_queries_by_status_total{{status=\"{c}\"}} {}",
+                self.errors_by_class[i].load(Ordering::Relaxed)
+            );
+        }
+
         let _ = writeln!(
             out,
             "# HELP clickgraph_in_flight_queries Queries currently executing."
@@ -639,7 +809,108 @@ impl ServerMetrics {
             );
         }
 
+        // Planner stage latency histograms.
+        let _ = writeln!(
+            out,
+            "# HELP clickgraph_planner_stage_duration_seconds Read planner stage latency."
+        );
+        let _ = writeln!(
+            out,
+            "# TYPE clickgraph_planner_stage_duration_seconds histogram"
+        );
+        for (i, &stage) in PLANNER_STAGES.iter().enumerate() {
+            let s = self.planner_stages[i].snapshot();
+            let mut cumulative = 0u64;
+            for (b, &bound) in BUCKET_BOUNDS.iter().enumerate() {
+                cumulative += s.counts[b];
+                let _ = writeln!(
+                    out,
+                    "clickgraph_planner_stage_duration_seconds_bucket{{stage=\"{stage}\",le=\"{bound}\"}} {cumulative}"
+                );
+            }
+            cumulative += s.counts[N_BUCKETS - 1];
+            let _ = writeln!(
+                out,
+                "clickgraph_planner_stage_duration_seconds_bucket{{stage=\"{stage}\",le=\"+Inf\"}} {cumulative}"
+            );
+            let _ = writeln!(
+                out,
+                "clickgraph_planner_stage_duration_seconds_sum{{stage=\"{stage}\"}} {}",
+                s.sum_micros as f64 / 1_000_000.0
+            );
+            let _ = writeln!(
+                out,
+                "clickgraph_planner_stage_duration_seconds_count{{stage=\"{stage}\"}} {}",
+                s.count
+            );
+        }
+
+        // CTE depth distribution.
+        let _ = writeln!(
+            out,
+            "# HELP clickgraph_query_cte_depth CTEs in the generated SQL of a read."
+        );
+        let _ = writeln!(out, "# TYPE clickgraph_query_cte_depth histogram");
+        let mut cumulative = 0u64;
+        for (b, &bound) in CTE_DEPTH_BOUNDS.iter().enumerate() {
+            cumulative += self.cte_depth.buckets[b].load(Ordering::Relaxed);
+            let _ = writeln!(
+                out,
+                "clickgraph_query_cte_depth_bucket{{le=\"{bound}\"}} {cumulative}"
+            );
+        }
+        cumulative += self.cte_depth.buckets[CTE_DEPTH_BOUNDS.len()].load(Ordering::Relaxed);
+        let _ = writeln!(
+            out,
+            "clickgraph_query_cte_depth_bucket{{le=\"+Inf\"}} {cumulative}"
+        );
+        let _ = writeln!(
+            out,
+            "clickgraph_query_cte_depth_sum {}",
+            self.cte_depth.sum.load(Ordering::Relaxed)
+        );
+        let _ = writeln!(
+            out,
+            "clickgraph_query_cte_depth_count {}",
+            self.cte_depth.count.load(Ordering::Relaxed)
+        );
+
+        // Bolt connections.
+        let _ = writeln!(
+            out,
+            "# HELP clickgraph_bolt_sessions_total Bolt connections accepted."
+        );
+        let _ = writeln!(out, "# TYPE clickgraph_bolt_sessions_total counter");
+        let _ = writeln!(
+            out,
+            "clickgraph_bolt_sessions_total {}",
+            self.bolt_sessions_total.load(Ordering::Relaxed)
+        );
+        let _ = writeln!(
+            out,
+            "# HELP clickgraph_bolt_sessions_open Bolt connections currently open."
+        );
+        let _ = writeln!(out, "# TYPE clickgraph_bolt_sessions_open gauge");
+        let _ = writeln!(
+            out,
+            "clickgraph_bolt_sessions_open {}",
+            self.bolt_sessions_open()
+        );
+
         // ClickHouse-side counters.
+        let _ = writeln!(
+            out,
+            "# HELP clickgraph_clickhouse_round_trips_total Requests sent to ClickHouse."
+        );
+        let _ = writeln!(
+            out,
+            "# TYPE clickgraph_clickhouse_round_trips_total counter"
+        );
+        let _ = writeln!(
+            out,
+            "clickgraph_clickhouse_round_trips_total {}",
+            self.ch_round_trips.load(Ordering::Relaxed)
+        );
         let _ = writeln!(
             out,
             "# HELP clickgraph_clickhouse_network_bytes_total Bytes transferred from ClickHouse."
@@ -697,6 +968,7 @@ pub struct PhaseLatency {
 
 #[derive(Serialize)]
 pub struct ChStatsSnapshot {
+    pub round_trips: u64,
     pub network_bytes: u64,
     pub read_rows: u64,
     pub read_bytes: u64,
@@ -712,6 +984,9 @@ pub struct StatsSnapshot {
     pub by_type: std::collections::BTreeMap<String, u64>,
     pub errors_by_class: std::collections::BTreeMap<String, u64>,
     pub latency: Vec<PhaseLatency>,
+    pub planner_latency: Vec<PhaseLatency>,
+    pub bolt_sessions_open: i64,
+    pub bolt_sessions_total: u64,
     pub clickhouse: ChStatsSnapshot,
 }
 
@@ -851,6 +1126,51 @@ mod tests {
         assert_eq!(snap.latency[1].count, 0); // parse NOT observed
     }
 
+    #[test]
+    fn bolt_session_guard_tracks_open_and_total() {
+        let sm = ServerMetrics::new(MetricsConfig::default());
+        {
+            let _s1 = sm.bolt_session_guard();
+            let _s2 = sm.bolt_session_guard();
+            assert_eq!(sm.bolt_sessions_open(), 2);
+        }
+        let snap = sm.snapshot();
+        assert_eq!(snap.bolt_sessions_open, 0);
+        assert_eq!(snap.bolt_sessions_total, 2);
+    }
+
+    #[test]
+    fn planner_stages_cte_depth_and_round_trips() {
+        let sm = ServerMetrics::new(MetricsConfig::default());
+        sm.observe_planner_stage(PlannerStage::Analyze, 0.002);
+        sm.record_cte_depth(0);
+        sm.record_cte_depth(5);
+        let m = http_metrics("read", 0.01, 1);
+        sm.record_query(&QuerySample {
+            ch: Some(ChExecStats {
+                round_trips: 2,
+                ..ChExecStats::default()
+            }),
+            ..sample(&m, Outcome::Ok)
+        });
+
+        let snap = sm.snapshot();
+        assert_eq!(snap.planner_latency[1].count, 1);
+        assert_eq!(snap.planner_latency[0].count, 0);
+        assert_eq!(snap.clickhouse.round_trips, 2);
+
+        let mut out = String::new();
+        sm.render_prometheus(&mut out);
+        assert!(
+            out.contains("clickgraph_planner_stage_duration_seconds_count{stage=\"analyze\"} 1")
+        );
+        assert!(out.contains("clickgraph_query_cte_depth_bucket{le=\"0\"} 1"));
+        assert!(out.contains("clickgraph_query_cte_depth_bucket{le=\"6\"} 2"));
+        assert!(out.contains("clickgraph_query_cte_depth_sum 5"));
+        assert!(out.contains("clickgraph_clickhouse_round_trips_total 2"));
+        assert!(out.contains("clickgraph_queries_by_status_total{status=\"ok\"} 1"));
+    }
+
     #[test]
     fn ring_buffer_evicts_and_sorts() {
         let cfg = MetricsConfig {
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -24,23 +26,46 @@ use tokio::signal;
 use tokio::sync::{OnceCell, RwLock, Semaphore};
 
 use crate::config::ServerConfig;
-use crate::executor::{remote::RemoteClickHouseExecutor, QueryExecutor};
+use crate::executor::{remote::RemoteClickHouseExecutor, retry::RetryPolicy, QueryExecutor};
 use crate::graph_catalog::graph_schema::GraphSchema;
 use bolt_protocol::{BoltConfig, BoltServer};
 use connection_pool::RoleConnectionPool;
 
+pub mod attribution;
+mod autocomplete;
 pub mod bolt_protocol;
+pub mod bulk_import;
+pub mod cancellation;
+pub mod catalog_export;
 mod clickhouse_client;
 pub mod connection_pool;
+mod continuation;
+pub mod endpoint_routing;
+mod error_hints;
+mod estimate;
+mod explain;
+pub mod freshness;
 pub mod graph_catalog;
 pub mod graph_output;
 pub mod handlers;
+pub mod lazy_schema;
+mod llm;
 pub mod metrics;
 pub mod models;
+mod natural_query;
+mod neo4j_http;
 mod parameter_substitution;
 mod query_cache;
 pub mod query_context;
+pub mod query_settings;
+pub mod recursion_depth;
+mod script;
+pub mod session_params;
 mod sql_generation_handler;
+mod stored_queries;
+pub mod subgraph_export;
+pub mod tls;
+pub mod workload_log;
 
 #[derive(Clone)]
 pub struct AppState {
@@ -95,6 +120,10 @@ pub static GLOBAL_QUERY_CACHE: OnceCell<query_cache::QueryCache> = OnceCell::con
 // ring). Initialized once in `run_server` before the listener binds.
 pub static GLOBAL_SERVER_METRICS: OnceCell<Arc<metrics::ServerMetrics>> = OnceCell::const_new();
 
+// Traversals of recent reads for the denormalization advisor. Set in
+// `run_server` only when `CLICKGRAPH_WORKLOAD_LOG_CAPACITY` is above 0.
+pub static GLOBAL_WORKLOAD_LOG: OnceCell<Arc<workload_log::WorkloadLog>> = OnceCell::const_new();
+
 // S1 stats-informed planning (`docs/design/STATS_PLANNING.md`): process-wide
 // TTL cache of per-table row counts. Set ONLY in remote ClickHouse mode when
 // `CLICKGRAPH_STATS_ENABLED=true`; absent everywhere else (embedded, other
@@ -129,6 +158,23 @@ pub async fn run_with_config(config: ServerConfig) {
     // identity-mapping default.
     query_context::set_server_neo4j_compat(config.neo4j_compat_mode);
 
+    // Test mode: seeded rand(), frozen clock and per-query alias numbering,
+    // so golden SQL outputs and bug reports reproduce byte for byte.
+    if config.test_mode {
+        log::warn!(
+            "Test mode: rand() seeded with {}, current time frozen at {}",
+            config.test_seed,
+            config.test_frozen_time.to_rfc3339()
+        );
+        query_context::set_server_deterministic(Some(query_context::DeterministicMode {
+            seed: config.test_seed,
+            frozen_time: config.test_frozen_time,
+        }));
+    }
+
+    // Literal redaction applies to everything logged or returned from here on.
+    crate::utils::redaction::set_mode(config.redact_literals);
+
     // Test that logging is working
     log::debug!("=== SERVER STARTING (debug log test) ===");
     log::info!(
@@ -294,8 +340,18 @@ pub async fn run_with_config(config: ServerConfig) {
     // Try to create ClickHouse client (optional for YAML-only mode)
     let client_opt = clickhouse_client::try_get_client();
 
-    if client_opt.is_some() {
+    if let Some(client) = client_opt.as_ref() {
         log::info!("✓ ClickHouse client created successfully");
+        // Older or analyzer-less servers cannot run `WITH RECURSIVE`; plan
+        // bounded variable-length paths as unrolled per-hop CTEs instead.
+        let unavailable = clickhouse_client::detect_recursive_cte_support(client).await;
+        if let Some(reason) = &unavailable {
+            log::warn!(
+                "⚠ Recursive CTEs unavailable ({reason}); variable-length paths will be \
+                 unrolled to their upper bound and unbounded patterns rejected"
+            );
+        }
+        query_context::set_server_recursive_cte_unavailable(unavailable);
     } else {
         log::warn!("⚠ ClickHouse client could not be created (missing env vars?)");
     }
@@ -314,12 +370,33 @@ pub async fn run_with_config(config: ServerConfig) {
         }
     };
 
-    let query_semaphore = make_query_semaphore(&config);
-    let app_state = if client_opt.is_some() {
-        let executor: Arc<dyn QueryExecutor> = Arc::new(RemoteClickHouseExecutor::with_ch_summary(
+    // Heavy procedures run on CLICKHOUSE_BATCH_URL when it is set
+    let batch_pool =
+        match connection_pool::RoleConnectionPool::batch_from_env(config.max_cte_depth).await {
+            Ok(pool) => pool.map(Arc::new),
+            Err(e) => {
+                log::error!("✗ FATAL: Failed to create batch connection pool: {}", e);
+                std::process::exit(1);
+            }
+        };
+    let remote_executor = || -> Arc<dyn QueryExecutor> {
+        let executor = RemoteClickHouseExecutor::with_ch_summary(
             connection_pool.clone(),
             config.metrics_ch_summary,
+        )
+        .with_retry_policy(RetryPolicy::new(
+            config.read_retries,
+            Duration::from_millis(config.read_retry_base_ms),
         ));
+        match &batch_pool {
+            Some(batch_pool) => Arc::new(executor.with_batch_pool(batch_pool.clone())),
+            None => Arc::new(executor),
+        }
+    };
+
+    let query_semaphore = make_query_semaphore(&config);
+    let app_state = if client_opt.is_some() {
+        let executor = remote_executor();
         AppState {
             executor,
             clickhouse_client: client_opt.clone(),
@@ -335,10 +412,7 @@ pub async fn run_with_config(config: ServerConfig) {
             "  Note: Some query functionality may be limited without ClickHouse connection."
         );
 
-        let executor: Arc<dyn QueryExecutor> = Arc::new(RemoteClickHouseExecutor::with_ch_summary(
-            connection_pool.clone(),
-            config.metrics_ch_summary,
-        ));
+        let executor = remote_executor();
         AppState {
             executor,
             clickhouse_client: None,
@@ -424,21 +498,64 @@ pub fn build_router(app_state: AppState, config: &ServerConfig) -> Router {
         .route("/health", get(health_check))
         .route("/query", post(query_handler))
         .route("/query/sql", post(sql_generation_handler))
+        .route("/query/script", post(script::script_handler))
+        .route("/query/estimate", post(estimate::estimate_handler))
+        .route("/query/natural", post(natural_query::natural_query_handler))
+        .route(
+            "/queries",
+            get(stored_queries::list_handler).post(stored_queries::register_handler),
+        )
+        .route(
+            "/queries/{name}",
+            get(stored_queries::get_handler).delete(stored_queries::delete_handler),
+        )
+        .route("/import", post(bulk_import::import_handler))
+        .route(
+            "/export",
+            get(subgraph_export::export_get_handler).post(subgraph_export::export_post_handler),
+        )
         .route("/schemas", get(list_schemas_handler))
         .route("/schemas/load", post(load_schema_handler))
+        .route("/schemas/reload", post(handlers::reload_schemas_handler))
         .route("/schemas/{name}", get(get_schema_handler))
+        .route("/schemas/{name}/complete", get(handlers::complete_handler))
+        .route("/schemas/{name}/status", get(lazy_schema::status_handler))
+        .route(
+            "/schemas/{name}/llm-context",
+            get(handlers::llm_context_handler),
+        )
+        .route(
+            "/schemas/{name}/denormalization-advice",
+            get(handlers::denormalization_advice_handler),
+        )
+        .route(
+            "/schemas/{name}/constraints/validate",
+            get(handlers::validate_constraints_handler),
+        )
+        .route("/schemas/{name}/lint", get(handlers::lint_schema_handler))
         .route("/schemas/introspect", post(introspect_handler))
+        .route("/schemas/lint", post(handlers::lint_yaml_handler))
         .route("/schemas/discover-prompt", post(discover_prompt_handler))
         .route("/schemas/draft", post(draft_handler))
+        .route("/schemas/templates", get(handlers::list_templates_handler))
+        .route(
+            "/schemas/templates/{name}",
+            get(handlers::render_template_handler),
+        )
+        // Neo4j HTTP transactional API compatibility
+        .route("/db/{name}/tx/commit", post(neo4j_http::tx_commit_handler))
         // Observability / stats / performance monitoring
         .route("/metrics", get(handlers::metrics_handler))
         .route("/stats", get(handlers::stats_handler))
         .route("/stats/queries", get(handlers::stats_queries_handler))
+        .route("/stats/resources", get(attribution::resources_handler))
         .with_state(Arc::new(app_state))
         // Body size limit (default 1 MB, configurable via CLICKGRAPH_MAX_REQUEST_BODY_BYTES)
         .layer(DefaultBodyLimit::max(config.max_request_body_bytes))
         // Catch panics in handlers — return 500 instead of dropping the connection
-        .layer(CatchPanicLayer::new());
+        .layer(CatchPanicLayer::new())
+        // Literals echoed in error bodies (CLICKGRAPH_REDACT_LITERALS)
+        .layer(axum::middleware::map_response(redact_error_body));
 
     // Per-request timeout (covers parsing + planning + execution)
     if config.query_timeout_secs > 0 {
@@ -450,6 +567,41 @@ pub fn build_router(app_state: AppState, config: &ServerConfig) -> Router {
     app
 }
 
+/// Redact literals in the body of a failed response. Error messages often
+/// quote the offending Cypher or SQL, values included.
+async fn redact_error_body(resp: axum::response::Response) -> axum::response::Response {
+    if resp.status().is_success()
+        || crate::utils::redaction::mode() == crate::utils::redaction::LiteralRedaction::Off
+    {
+        return resp;
+    }
+    fn redact_strings(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::String(s) => *s = crate::utils::redaction::redact(s).into_owned(),
+            serde_json::Value::Array(items) => items.iter_mut().for_each(redact_strings),
+            serde_json::Value::Object(map) => map.values_mut().for_each(redact_strings),
+            _ => {}
+        }
+    }
+
+    let (mut parts, body) = resp.into_parts();
+    let body = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => {
+            if let Ok(mut json) = serde_json::from_slice::<serde_json::Value>(&bytes) {
+                redact_strings(&mut json);
+                json.to_string().into()
+            } else if let Ok(text) = std::str::from_utf8(&bytes) {
+                crate::utils::redaction::redact(text).into_owned().into()
+            } else {
+                bytes.into()
+            }
+        }
+        Err(e) => format!("Failed to read error response: {}", e).into(),
+    };
+    parts.headers.remove(axum::http::header::CONTENT_LENGTH);
+    axum::response::Response::from_parts(parts, body)
+}
+
 /// Bind and serve HTTP (and optionally Bolt) using the given `app_state`.
 async fn run_server(app_state: AppState, config: ServerConfig) {
     let http_bind_address = format!("{}:{}", config.http_host, config.http_port);
@@ -464,6 +616,15 @@ async fn run_server(app_state: AppState, config: ServerConfig) {
         query_preview: config.metrics_query_preview,
     };
     let _ = GLOBAL_SERVER_METRICS.set(Arc::new(metrics::ServerMetrics::new(metrics_cfg)));
+    if config.workload_log_capacity > 0 {
+        log::info!(
+            "Workload log enabled ({} queries)",
+            config.workload_log_capacity
+        );
+        let _ = GLOBAL_WORKLOAD_LOG.set(Arc::new(workload_log::WorkloadLog::new(
+            config.workload_log_capacity,
+        )));
+    }
 
     let app = build_router(app_state.clone(), &config);
 
@@ -479,6 +640,25 @@ async fn run_server(app_state: AppState, config: ServerConfig) {
         log::info!("Max concurrent queries: {}", config.max_concurrent_queries);
     }
 
+    // TLS for both listeners (CLICKGRAPH_TLS_CERT / CLICKGRAPH_TLS_KEY)
+    let tls_acceptor = match tls::acceptor(&config) {
+        Ok(acceptor) => acceptor,
+        Err(e) => {
+            log::error!("✗ FATAL: TLS configuration error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if tls_acceptor.is_some() {
+        log::info!(
+            "TLS enabled for HTTP and Bolt listeners{}",
+            if config.tls_client_ca_path.is_some() {
+                " (client certificates required)"
+            } else {
+                ""
+            }
+        );
+    }
+
     let http_listener = match TcpListener::bind(&http_bind_address).await {
         Ok(listener) => {
             log::info!("Successfully bound HTTP listener to {}", http_bind_address);
@@ -504,7 +684,49 @@ async fn run_server(app_state: AppState, config: ServerConfig) {
         }
     };
 
-    let http_server = axum::serve(http_listener, app);
+    let http_server: Pin<Box<dyn Future<Output = std::io::Result<()>> + Send>> = match &tls_acceptor
+    {
+        Some(acceptor) => {
+            let listener = match tls::TlsListener::new(http_listener, acceptor.clone()) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    log::error!("✗ FATAL: Failed to start HTTPS listener: {}", e);
+                    std::process::exit(1);
+                }
+            };
+            Box::pin(axum::serve(listener, app).into_future())
+        }
+        None => Box::pin(axum::serve(http_listener, app).into_future()),
+    };
+
+    // Optional per-tenant resource export (CLICKGRAPH_ATTRIBUTION_TABLE)
+    if let (Some(table), Some(client)) = (
+        config.attribution_table.clone(),
+        app_state.clickhouse_client.clone(),
+    ) {
+        tokio::spawn(attribution::export_loop(
+            client,
+            table,
+            Duration::from_secs(config.attribution_export_secs),
+            config.query_log_cluster.clone(),
+        ));
+    }
+
+    // Background completion of lazy schema elements (CLICKGRAPH_LAZY_SCHEMA_RETRY_SECS)
+    if config.lazy_schema_retry_secs > 0 {
+        tokio::spawn(lazy_schema::complete_loop(
+            app_state.executor.clone(),
+            Duration::from_secs(config.lazy_schema_retry_secs),
+        ));
+    }
+
+    // Optional schema file watcher (CLICKGRAPH_SCHEMA_WATCH_SECS)
+    if config.schema_watch_secs > 0 && std::env::var("GRAPH_CONFIG_PATH").is_ok() {
+        tokio::spawn(graph_catalog::watch_schema_file(
+            app_state.clickhouse_client.clone(),
+            Duration::from_secs(config.schema_watch_secs),
+        ));
+    }
 
     // Start Bolt server if enabled
     if config.bolt_enabled {
@@ -523,6 +745,20 @@ async fn run_server(app_state: AppState, config: ServerConfig) {
             },
             host: config.bolt_host.clone(),
             port: config.bolt_port,
+            trusted_roles: config.trusted_roles.clone(),
+            allowed_settings: config.allowed_settings.clone(),
+            stable_order: config.bolt_stable_order,
+            advertised_address: config.bolt_advertised_address.clone(),
+            allow_cartesian_product: config.allow_cartesian_product,
+            result_spill: config.bolt_spill_dir.as_ref().map(|dir| {
+                bolt_protocol::result_spill::SpillConfig {
+                    dir: dir.into(),
+                    memory_rows: config.bolt_spill_threshold_rows,
+                    max_bytes: config.bolt_spill_max_bytes,
+                }
+            }),
+            query_timeout_secs: config.query_timeout_secs,
+            lazy_schema_retry_secs: config.lazy_schema_retry_secs,
         };
 
         // Clone the executor from app_state for Bolt server
@@ -544,6 +780,7 @@ async fn run_server(app_state: AppState, config: ServerConfig) {
         };
 
         // Spawn Bolt server task
+        let tls_acceptor = tls_acceptor.clone();
         tokio::spawn(async move {
             println!("Bolt server loop starting, listening for connections...");
             loop {
@@ -554,103 +791,17 @@ async fn run_server(app_state: AppState, config: ServerConfig) {
                         let server = bolt_server.clone();
 
                         // Spawn individual connection handler
+                        let tls_acceptor = tls_acceptor.clone();
                         tokio::spawn(async move {
-                            // Peek at first 4 bytes to detect protocol type
-                            let mut peek_buf = [0u8; 4];
-                            match stream.peek(&mut peek_buf).await {
-                                Ok(n) if n >= 4 => {
-                                    // Check if this is an HTTP request (WebSocket upgrade or probe)
-                                    let is_http = peek_buf.starts_with(b"GET ")
-                                        || peek_buf.starts_with(b"POST")
-                                        || peek_buf.starts_with(b"OPTI") // OPTIONS preflight
-                                        || peek_buf.starts_with(b"HEAD")
-                                        || peek_buf.starts_with(b"PUT ")
-                                        || peek_buf.starts_with(b"DELE"); // DELETE
-                                    if is_http {
-                                        // Only GET may carry a WebSocket upgrade; other HTTP
-                                        // methods are browser probes — drop them silently.
-                                        if peek_buf.starts_with(b"GET ")
-                                            || peek_buf.starts_with(b"POST")
-                                        {
-                                            log::debug!(
-                                                "Detected HTTP/WebSocket probe from {}",
-                                                addr_str
-                                            );
-
-                                            // Attempt WebSocket upgrade
-                                            match bolt_protocol::websocket::WebSocketBoltAdapter::new(
-                                                stream,
-                                            )
-                                            .await
-                                            {
-                                                Ok(ws_adapter) => {
-                                                    match server
-                                                        .handle_connection(ws_adapter, addr_str.clone())
-                                                        .await
-                                                    {
-                                                        Ok(_) => {
-                                                            log::debug!("WebSocket Bolt connection closed successfully");
-                                                        }
-                                                        Err(e) => {
-                                                            log::debug!("WebSocket Bolt connection closed from {}: {:?}", addr_str, e);
-                                                        }
-                                                    }
-                                                }
-                                                Err(_) => {
-                                                    // Browser probe without WS upgrade — expected, ignore
-                                                    log::debug!(
-                                                        "HTTP probe (no WS upgrade) from {} — ignored",
-                                                        addr_str
-                                                    );
-                                                }
-                                            }
-                                        } else {
-                                            // OPTIONS/HEAD/etc: browser CORS preflight — drop silently
-                                            log::debug!(
-                                                "HTTP {} probe on Bolt port from {} — ignored",
-                                                std::str::from_utf8(&peek_buf).unwrap_or("?"),
-                                                addr_str
-                                            );
-                                        }
-                                    } else {
-                                        log::info!(
-                                            "Detected TCP Bolt connection from {}",
-                                            addr_str
-                                        );
-
-                                        // Handle raw TCP Bolt connection (existing behavior)
-                                        match server
-                                            .handle_connection(stream, addr_str.clone())
-                                            .await
-                                        {
-                                            Ok(_) => {
-                                                log::debug!(
-                                                    "TCP Bolt connection closed successfully"
-                                                );
-                                            }
-                                            Err(e) => {
-                                                log::error!(
-                                                    "TCP Bolt connection error from {}: {:?}",
-                                                    addr_str,
-                                                    e
-                                                );
-                                            }
-                                        }
+                            match tls_acceptor {
+                                Some(acceptor) => {
+                                    if let Some(stream) =
+                                        tls::handshake(&acceptor, stream, addr).await
+                                    {
+                                        server.serve(stream, addr_str).await;
                                     }
                                 }
-                                Ok(_) => {
-                                    log::warn!(
-                                        "Connection from {} closed before protocol detection",
-                                        addr_str
-                                    );
-                                }
-                                Err(e) => {
-                                    log::error!(
-                                        "Failed to peek connection from {}: {}",
-                                        addr_str,
-                                        e
-                                    );
-                                }
+                                None => server.serve(stream, addr_str).await,
                             }
                         });
                     }
@@ -663,12 +814,17 @@ async fn run_server(app_state: AppState, config: ServerConfig) {
         });
     }
 
+    let (http_scheme, bolt_scheme) = if tls_acceptor.is_some() {
+        ("https", "bolt+s")
+    } else {
+        ("http", "bolt")
+    };
     println!("ClickGraph server is running");
-    println!("  HTTP API: http://{}", http_bind_address);
+    println!("  HTTP API: {}://{}", http_scheme, http_bind_address);
     if config.bolt_enabled {
         println!(
-            "  Bolt Protocol: bolt://{}:{}",
-            config.bolt_host, config.bolt_port
+            "  Bolt Protocol: {}://{}:{}",
+            bolt_scheme, config.bolt_host, config.bolt_port
         );
     }
 
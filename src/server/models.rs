@@ -1,9 +1,9 @@
 use clickhouse::Row;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct QueryRequest {
     pub query: String,
     pub format: Option<OutputFormat>,
@@ -22,6 +22,42 @@ pub struct QueryRequest {
     /// Maximum number of inferred edge types for generic patterns like `[*1]` (default: 4)
     /// Set higher for GraphRAG use cases with many edge types. Reasonable values: 4-20.
     pub max_inferred_types: Option<usize>,
+    /// Run read queries with ClickHouse's query cache (`use_query_cache`);
+    /// overrides the schema's `query_cache.enabled`
+    pub use_query_cache: Option<bool>,
+    /// Query cache TTL in seconds (`query_cache_ttl`); overrides the schema's
+    /// `query_cache.ttl_seconds`
+    pub query_cache_ttl: Option<u64>,
+    /// Stream `JSONEachRow` results as newline-delimited JSON (chunked
+    /// transfer encoding) instead of buffering them into one `results` array
+    pub stream: Option<bool>,
+    /// Cap on the `JSONEachRow` response body in bytes; rows past the budget
+    /// are left out and a `continuation_token` for them is returned
+    pub max_response_bytes: Option<usize>,
+    /// Token from a previous truncated response; resumes the same query
+    /// after the rows already returned
+    pub continuation_token: Option<String>,
+    /// Maximum lag in seconds of the watermarked (`freshness`) edge tables
+    /// the query reads; checked before it runs
+    pub max_staleness_secs: Option<u64>,
+    /// What to do with tables further behind than `max_staleness_secs`
+    /// (annotate, wait or error; default annotate)
+    pub on_stale: Option<super::freshness::StaleAction>,
+    /// Timeout of the statement in milliseconds, capped by the server's
+    /// `query_timeout_secs`; its ClickHouse queries are killed when it
+    /// expires or the client disconnects
+    pub timeout_ms: Option<u64>,
+    /// Inline YAML fragment (`nodes:` / `edges:` lists) merged into the
+    /// schema for this request only, to try a mapping before adding it to the
+    /// schema file
+    pub schema_overrides: Option<String>,
+    /// ClickHouse settings for this request's queries (e.g.
+    /// `join_algorithm`), limited to the server's `allowed_settings`
+    pub settings: Option<BTreeMap<String, Value>>,
+    /// Graph the request is confined to (`X-Graph-Name` header or a tenant
+    /// graph); a `USE` or `schema_name` naming another graph is rejected
+    #[serde(skip)]
+    pub pinned_graph: Option<String>,
 }
 
 // #[derive(Debug, Serialize)]
@@ -79,7 +115,7 @@ pub struct QueryStats {
 }
 
 /// A graph node in the structured graph response
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GraphNode {
     pub element_id: String,
     pub labels: Vec<String>,
@@ -87,7 +123,7 @@ pub struct GraphNode {
 }
 
 /// A graph edge in the structured graph response
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GraphEdge {
     pub element_id: String,
     pub rel_type: String,
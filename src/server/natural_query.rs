@@ -0,0 +1,641 @@
+//! Natural-language questions (`POST /query/natural`).
+//!
+//! The question goes to the configured LLM (see [`super::llm`]) along with
+//! the schema's LLM context (`graph_catalog::llm_context`), asking for one
+//! read-only Cypher statement and a confidence line. Nothing the model writes
+//! runs unchecked: the Cypher must parse, read only, use nothing but the
+//! schema's labels, relationship types and properties, and plan to SQL.
+//! Grounded Cypher then runs through the normal `/query` pipeline and the
+//! answer carries both:
+//!
+//! ```json
+//! {"question": "...", "schema": "social", "cypher": "MATCH ...",
+//!  "confidence": "high", "note": "direct lookup by name", "results": [...]}
+//! ```
+//!
+//! Off unless `CLICKGRAPH_NL_QUERY=true`; the endpoint answers 404 otherwise.
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::Arc,
+};
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    clickhouse_query_generator,
+    graph_catalog::{graph_schema::GraphSchema, llm_context},
+    open_cypher_parser::{
+        self,
+        ast::{
+            CypherStatement, Expression, MatchClause, NodePattern, OpenCypherQueryAst,
+            OptionalMatchClause, OrderByClause, PathPattern, Property, ReadingClause,
+            RelationshipPattern, WithClause,
+        },
+    },
+    query_planner::analyzer::where_property_extractor::WherePropertyExtractor,
+};
+
+use super::{
+    graph_catalog,
+    handlers::{graph_name_header, run_statement},
+    llm::{self, LlmConfig},
+    models::QueryRequest,
+    neo4j_http::error_message,
+    AppState,
+};
+
+const SYSTEM_PROMPT: &str = "You translate questions about a graph into one read-only \
+Cypher query for ClickGraph, a Cypher engine over ClickHouse tables.
+
+The graph, as JSON:
+{context}
+
+Write a single MATCH ... RETURN statement; never CREATE, MERGE, SET, REMOVE or DELETE. \
+Add LIMIT 100 unless the question asks for a count or another aggregate.
+
+Reply with the query in a ```cypher fenced block, then one line:
+Confidence: high|medium|low - <why>";
+
+#[derive(Debug, Deserialize)]
+pub struct NaturalQueryRequest {
+    pub question: String,
+    /// Schema to ground the question in (defaults to "default")
+    pub schema_name: Option<String>,
+    pub role: Option<String>,
+    /// Return the validated Cypher without running it
+    pub cypher_only: Option<bool>,
+    /// Timeout of the generated query, as for `/query`
+    pub timeout_ms: Option<u64>,
+}
+
+/// How sure the model says it is of its translation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    High,
+    Medium,
+    Low,
+    /// The reply had no confidence line
+    Unstated,
+}
+
+/// The Cypher and confidence line of an LLM reply
+#[derive(Debug, Clone, PartialEq)]
+pub struct Translation {
+    pub cypher: String,
+    pub confidence: Confidence,
+    pub note: Option<String>,
+}
+
+/// Split an LLM reply into its Cypher (the first fenced block, or the whole
+/// reply without one) and its `Confidence:` line.
+pub fn parse_reply(reply: &str) -> Result<Translation, String> {
+    let fenced = reply.contains("```");
+    let (mut opened, mut closed) = (false, false);
+    let mut lines = Vec::new();
+    let mut confidence = Confidence::Unstated;
+    let mut note = None;
+
+    for line in reply.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            if opened {
+                closed = true;
+            } else {
+                opened = true;
+            }
+            continue;
+        }
+        if opened && !closed {
+            lines.push(line);
+        } else if trimmed.to_ascii_lowercase().starts_with("confidence:") {
+            let rest = trimmed["confidence:".len()..].trim();
+            let (level, why) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            confidence = match level
+                .trim_matches(|c: char| !c.is_alphabetic())
+                .to_ascii_lowercase()
+                .as_str()
+            {
+                "high" => Confidence::High,
+                "medium" => Confidence::Medium,
+                "low" => Confidence::Low,
+                _ => Confidence::Unstated,
+            };
+            let why = why.trim_start_matches(|c: char| c.is_whitespace() || "-—:,".contains(c));
+            note = (!why.is_empty()).then(|| why.to_string());
+        } else if !fenced {
+            lines.push(line);
+        }
+    }
+
+    let cypher = lines
+        .join("\n")
+        .trim()
+        .trim_end_matches(';')
+        .trim()
+        .to_string();
+    if cypher.is_empty() {
+        return Err("The LLM reply holds no Cypher query".to_string());
+    }
+    Ok(Translation {
+        cypher,
+        confidence,
+        note,
+    })
+}
+
+/// What in `statement` the schema does not have, and any write: one
+/// message per problem, sorted. Empty when the statement is grounded.
+pub fn ground(statement: &CypherStatement<'_>, schema: &GraphSchema) -> Vec<String> {
+    let mut grounding = Grounding {
+        schema,
+        bound: HashMap::new(),
+        properties: HashMap::new(),
+        problems: BTreeSet::new(),
+    };
+    match statement {
+        CypherStatement::Query {
+            query,
+            union_clauses,
+        } => {
+            grounding.query(query);
+            for union in union_clauses {
+                grounding.query(&union.query);
+            }
+        }
+        CypherStatement::ProcedureCall(_) => {}
+        CypherStatement::CopyTo(_) => {
+            grounding
+                .problems
+                .insert("COPY ... TO writes files; only read queries are allowed".to_string());
+        }
+    }
+    grounding.finish()
+}
+
+struct Grounding<'s> {
+    schema: &'s GraphSchema,
+    /// Properties each labelled alias may use
+    bound: HashMap<String, HashSet<String>>,
+    /// Properties used, by alias
+    properties: HashMap<String, HashSet<String>>,
+    problems: BTreeSet<String>,
+}
+
+impl Grounding<'_> {
+    fn query(&mut self, query: &OpenCypherQueryAst<'_>) {
+        if query.create_clause.is_some()
+            || query.foreach_clause.is_some()
+            || query.merge_clause.is_some()
+            || query.set_clause.is_some()
+            || query.remove_clause.is_some()
+            || query.delete_clause.is_some()
+        {
+            self.problems.insert(
+                "only read queries are allowed, not CREATE, MERGE, SET, REMOVE or DELETE"
+                    .to_string(),
+            );
+        }
+        if query.reading_clauses.is_empty() {
+            query
+                .match_clauses
+                .iter()
+                .for_each(|m| self.match_clause(m));
+            query
+                .optional_match_clauses
+                .iter()
+                .for_each(|m| self.optional_match(m));
+        } else {
+            for clause in &query.reading_clauses {
+                match clause {
+                    ReadingClause::Match(m) => self.match_clause(m),
+                    ReadingClause::OptionalMatch(m) => self.optional_match(m),
+                }
+            }
+        }
+        for unwind in &query.unwind_clauses {
+            self.expression(&unwind.expression);
+        }
+        if let Some(with) = &query.with_clause {
+            self.with(with);
+        }
+        if let Some(where_clause) = &query.where_clause {
+            self.expression(&where_clause.conditions);
+        }
+        if let Some(return_clause) = &query.return_clause {
+            for item in &return_clause.return_items {
+                self.expression(&item.expression);
+            }
+        }
+        if let Some(order_by) = &query.order_by_clause {
+            self.order_by(order_by);
+        }
+        if let Some(subquery) = &query.call_subquery {
+            self.query(&subquery.query);
+            for union in &subquery.union_clauses {
+                self.query(&union.query);
+            }
+        }
+    }
+
+    fn match_clause(&mut self, clause: &MatchClause<'_>) {
+        for (_, pattern) in &clause.path_patterns {
+            self.path(pattern);
+        }
+        if let Some(where_clause) = &clause.where_clause {
+            self.expression(&where_clause.conditions);
+        }
+    }
+
+    fn optional_match(&mut self, clause: &OptionalMatchClause<'_>) {
+        for pattern in &clause.path_patterns {
+            self.path(pattern);
+        }
+        if let Some(where_clause) = &clause.where_clause {
+            self.expression(&where_clause.conditions);
+        }
+    }
+
+    fn with(&mut self, with: &WithClause<'_>) {
+        for item in &with.with_items {
+            self.expression(&item.expression);
+        }
+        if let Some(where_clause) = &with.where_clause {
+            self.expression(&where_clause.conditions);
+        }
+        if let Some(order_by) = &with.order_by {
+            self.order_by(order_by);
+        }
+        if let Some(unwind) = &with.subsequent_unwind {
+            self.expression(&unwind.expression);
+        }
+        if let Some(m) = &with.subsequent_match {
+            self.match_clause(m);
+        }
+        for m in &with.subsequent_optional_matches {
+            self.optional_match(m);
+        }
+        if let Some(next) = &with.subsequent_with {
+            self.with(next);
+        }
+    }
+
+    fn order_by(&mut self, order_by: &OrderByClause<'_>) {
+        for item in &order_by.order_by_items {
+            self.expression(&item.expression);
+        }
+    }
+
+    fn path(&mut self, pattern: &PathPattern<'_>) {
+        match pattern {
+            PathPattern::Node(node) => self.node(node),
+            PathPattern::ConnectedPattern(parts) => {
+                for part in parts {
+                    self.node(&part.start_node.borrow());
+                    self.relationship(&part.relationship);
+                    self.node(&part.end_node.borrow());
+                }
+            }
+            PathPattern::ShortestPath(inner) | PathPattern::AllShortestPaths(inner) => {
+                self.path(inner)
+            }
+        }
+    }
+
+    fn node(&mut self, node: &NodePattern<'_>) {
+        let Some(labels) = &node.labels else {
+            return self.bind(node.name, "", None, &node.properties);
+        };
+        let mut allowed = HashSet::new();
+        let mut known = true;
+        for label in labels {
+            match self.schema.node_schema_opt(label) {
+                Some(schema) => {
+                    allowed.extend(schema.property_mappings.keys().cloned());
+                    allowed.extend(schema.node_id.columns().into_iter().map(str::to_string));
+                }
+                None => {
+                    known = false;
+                    self.problems.insert(format!("unknown label `{}`", label));
+                }
+            }
+        }
+        let what = format!(":{}", labels.join("|"));
+        self.bind(node.name, &what, known.then_some(allowed), &node.properties);
+    }
+
+    fn relationship(&mut self, rel: &RelationshipPattern<'_>) {
+        let Some(types) = &rel.labels else {
+            return self.bind(rel.name, "", None, &rel.properties);
+        };
+        let mut allowed = HashSet::new();
+        let mut known = true;
+        for rel_type in types {
+            let schemas = self.schema.get_all_rel_schemas_for_type(rel_type);
+            if schemas.is_empty() {
+                known = false;
+                self.problems
+                    .insert(format!("unknown relationship type `{}`", rel_type));
+            }
+            for schema in schemas {
+                allowed.extend(schema.property_mappings.keys().cloned());
+            }
+        }
+        let what = format!(":{}", types.join("|"));
+        self.bind(rel.name, &what, known.then_some(allowed), &rel.properties);
+    }
+
+    /// Check a pattern's inline properties and remember what its alias may
+    /// use. `allowed` is None when the pattern has no (known) label.
+    fn bind(
+        &mut self,
+        name: Option<&str>,
+        what: &str,
+        allowed: Option<HashSet<String>>,
+        properties: &Option<Vec<Property<'_>>>,
+    ) {
+        for property in properties.iter().flatten() {
+            if let Property::PropertyKV(kv) = property {
+                self.expression(&kv.value);
+                if allowed.as_ref().is_some_and(|a| !a.contains(kv.key)) {
+                    self.problems
+                        .insert(format!("unknown property `{}` of {}", kv.key, what));
+                }
+            }
+        }
+        if let (Some(name), Some(allowed)) = (name, allowed) {
+            self.bound
+                .entry(name.to_string())
+                .or_default()
+                .extend(allowed);
+        }
+    }
+
+    fn expression(&mut self, expr: &Expression<'_>) {
+        WherePropertyExtractor::collect_property_references(expr, &mut self.properties);
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        for (alias, keys) in &self.properties {
+            let Some(allowed) = self.bound.get(alias) else {
+                continue;
+            };
+            for key in keys.iter().filter(|key| !allowed.contains(*key)) {
+                self.problems
+                    .insert(format!("unknown property `{}.{}`", alias, key));
+            }
+        }
+        self.problems.into_iter().collect()
+    }
+}
+
+/// `POST /query/natural` — translate a question into Cypher with the
+/// configured LLM, validate it against the schema and run it.
+pub async fn natural_query_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(request): Json<NaturalQueryRequest>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    let error = |status: StatusCode, message: String| (status, Json(json!({ "error": message })));
+
+    if !app_state.config.nl_query_enabled {
+        return Err(error(
+            StatusCode::NOT_FOUND,
+            "Natural-language queries are disabled; set CLICKGRAPH_NL_QUERY=true".to_string(),
+        ));
+    }
+    let Some(llm_config) = LlmConfig::from_server_config(&app_state.config) else {
+        return Err(error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "No LLM configured: set ANTHROPIC_API_KEY, or CLICKGRAPH_LLM_PROVIDER=openai \
+             with OPENAI_API_KEY or CLICKGRAPH_LLM_API_URL"
+                .to_string(),
+        ));
+    };
+    let question = request.question.trim();
+    if question.is_empty() {
+        return Err(error(
+            StatusCode::BAD_REQUEST,
+            "question must not be empty".to_string(),
+        ));
+    }
+
+    // `X-Graph-Name` selects and confines the graph as it does for /query;
+    // the generated statement runs under the same pin
+    let header = graph_name_header(&headers).map_err(|(status, e)| error(status, e))?;
+    let requested = request.schema_name.clone().or_else(|| header.clone());
+    let schema_name = graph_catalog::resolve_graph_name(None, requested.as_deref());
+    let pinned_graph = graph_catalog::pinned_graph(header.as_deref(), requested.as_deref());
+    graph_catalog::check_graph_isolation(pinned_graph.as_deref(), &schema_name)
+        .map_err(|e| error(StatusCode::FORBIDDEN, e))?;
+    let schema = graph_catalog::get_graph_schema_by_name(&schema_name)
+        .await
+        .map_err(|e| error(StatusCode::NOT_FOUND, e))?;
+    let descriptions = graph_catalog::get_view_config_by_name(&schema_name)
+        .await
+        .map(|config| llm_context::descriptions(&config))
+        .unwrap_or_default();
+    let (context, _) = llm_context::build(&schema_name, &schema, &descriptions, 0);
+    let context = serde_json::to_string(&context).unwrap_or_default();
+    let system_prompt = SYSTEM_PROMPT.replace("{context}", &context);
+
+    log::info!("Translating a question against schema '{}'", schema_name);
+    let reply = llm::call_llm(
+        &reqwest::Client::new(),
+        &llm_config,
+        &system_prompt,
+        question,
+    )
+    .await
+    .map_err(|e| error(StatusCode::BAD_GATEWAY, e))?;
+    let translation = parse_reply(&reply).map_err(|e| error(StatusCode::BAD_GATEWAY, e))?;
+
+    let rejected = |message: String, problems: Vec<String>| {
+        log::info!("Rejected generated Cypher: {}", message);
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(json!({
+                "error": message,
+                "cypher": translation.cypher,
+                "confidence": translation.confidence,
+                "problems": problems,
+            })),
+        )
+    };
+
+    // The AST holds `Rc`s, so keep it out of the awaits below.
+    let (problems, is_query) = {
+        let cleaned = open_cypher_parser::strip_comments(&translation.cypher);
+        match open_cypher_parser::parse_cypher_statement(&cleaned) {
+            Ok((_, statement)) => (
+                ground(&statement, &schema),
+                matches!(statement, CypherStatement::Query { .. }),
+            ),
+            Err(e) => {
+                return Err(rejected(
+                    format!("Generated Cypher does not parse: {}", e),
+                    Vec::new(),
+                ))
+            }
+        }
+    };
+    if !problems.is_empty() {
+        return Err(rejected(
+            format!(
+                "Generated Cypher does not fit schema '{}': {}",
+                schema_name,
+                problems.join("; ")
+            ),
+            problems,
+        ));
+    }
+    if is_query {
+        clickhouse_query_generator::cypher_to_sql(
+            &translation.cypher,
+            &schema,
+            app_state.config.max_cte_depth,
+        )
+        .map_err(|e| rejected(format!("Generated Cypher does not plan: {}", e), Vec::new()))?;
+    }
+
+    let mut body = json!({
+        "question": question,
+        "schema": schema_name,
+        "cypher": translation.cypher,
+        "confidence": translation.confidence,
+        "note": translation.note,
+    });
+    if request.cypher_only.unwrap_or(false) {
+        return Ok(Json(body));
+    }
+
+    let payload = QueryRequest {
+        query: translation.cypher.clone(),
+        format: None,
+        sql_only: None,
+        schema_name: Some(schema_name),
+        parameters: None,
+        tenant_id: None,
+        view_parameters: None,
+        role: request.role,
+        max_inferred_types: None,
+        use_query_cache: None,
+        query_cache_ttl: None,
+        stream: None,
+        max_response_bytes: None,
+        continuation_token: None,
+        max_staleness_secs: None,
+        on_stale: None,
+        timeout_ms: request.timeout_ms,
+        schema_overrides: None,
+        settings: None,
+        pinned_graph,
+    };
+    let response = match Box::pin(run_statement(State(app_state), Json(payload))).await {
+        Ok(resp) => resp,
+        Err(resp) => resp,
+    };
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap_or_default();
+    if !status.is_success() {
+        body["error"] = json!(error_message(&bytes));
+        return Err((status, Json(body)));
+    }
+    let result: Value = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    body["results"] = result.get("results").cloned().unwrap_or(result);
+    Ok(Json(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    const SCHEMA_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: Person
+      database: test
+      table: people
+      node_id: person_id
+      property_mappings:
+        person_id: person_id
+        name: full_name
+  edges:
+    - type: KNOWS
+      database: test
+      table: knows
+      from_id: from_person
+      to_id: to_person
+      from_node: Person
+      to_node: Person
+      property_mappings:
+        since: since_date
+"#;
+
+    fn problems(cypher: &str) -> Vec<String> {
+        let schema = GraphSchemaConfig::from_yaml_str(SCHEMA_YAML)
+            .expect("parse schema")
+            .to_graph_schema()
+            .expect("convert schema");
+        let (_, statement) = open_cypher_parser::parse_cypher_statement(cypher).expect("parse");
+        ground(&statement, &schema)
+    }
+
+    #[test]
+    fn reply_splits_into_cypher_and_confidence() {
+        let reply = "Here you go:\n```cypher\nMATCH (p:Person)\nRETURN p.name LIMIT 10;\n```\n\
+                     Confidence: high - names are a direct lookup";
+        let translation = parse_reply(reply).expect("translation");
+        assert_eq!(
+            translation.cypher,
+            "MATCH (p:Person)\nRETURN p.name LIMIT 10"
+        );
+        assert_eq!(translation.confidence, Confidence::High);
+        assert_eq!(
+            translation.note.as_deref(),
+            Some("names are a direct lookup")
+        );
+
+        let bare = parse_reply("MATCH (p:Person) RETURN count(p)").expect("translation");
+        assert_eq!(bare.cypher, "MATCH (p:Person) RETURN count(p)");
+        assert_eq!(bare.confidence, Confidence::Unstated);
+        assert!(parse_reply("```cypher\n```\nConfidence: low").is_err());
+    }
+
+    #[test]
+    fn grounded_query_has_no_problems() {
+        let cypher = "MATCH (a:Person {name: 'Ann'})-[k:KNOWS]->(b:Person) \
+                      WHERE k.since > '2020' RETURN b.name, b.person_id ORDER BY b.name";
+        assert!(problems(cypher).is_empty(), "{:?}", problems(cypher));
+    }
+
+    #[test]
+    fn unknown_names_and_writes_are_reported() {
+        assert_eq!(
+            problems("MATCH (a:Person)-[:LIKES]->(b:Company) RETURN a.salary, b.name"),
+            vec![
+                "unknown label `Company`",
+                "unknown property `a.salary`",
+                "unknown relationship type `LIKES`",
+            ]
+        );
+        assert_eq!(
+            problems("MATCH (a:Person {age: 3}) RETURN a"),
+            vec!["unknown property `age` of :Person"]
+        );
+        assert_eq!(
+            problems("MATCH (a:Person) DETACH DELETE a"),
+            vec!["only read queries are allowed, not CREATE, MERGE, SET, REMOVE or DELETE"]
+        );
+    }
+}
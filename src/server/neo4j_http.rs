@@ -0,0 +1,261 @@
+//! Neo4j HTTP transactional API compatibility (`POST /db/{name}/tx/commit`).
+//!
+//! Integrations written against Neo4j's HTTP API post a batch of statements
+//! and expect the Neo4j result envelope back:
+//!
+//! ```json
+//! {"results": [{"columns": ["n.name"], "data": [{"row": ["Alice"], "meta": [null]}]}],
+//!  "errors": []}
+//! ```
+//!
+//! Each statement is run through the regular [`query_handler`] pipeline
+//! (parsing, caching, scan guard, metrics) with JSON output, and the JSON rows
+//! are reshaped into `columns` / `data`. ClickGraph is read-only over HTTP, so
+//! "commit" is simply sequential execution; as in Neo4j, the first failing
+//! statement stops the batch and is reported in `errors` with a
+//! `Neo.*` status code, and the HTTP status stays 200.
+
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{
+    graph_catalog,
+    handlers::query_handler,
+    models::{OutputFormat, QueryRequest},
+    AppState,
+};
+
+/// Neo4j's default database name, mapped to the `default` schema when no
+/// schema of that name is loaded.
+const NEO4J_DEFAULT_DATABASE: &str = "neo4j";
+
+#[derive(Debug, Deserialize)]
+pub struct TxCommitRequest {
+    #[serde(default)]
+    pub statements: Vec<TxStatement>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TxStatement {
+    pub statement: String,
+    #[serde(default)]
+    pub parameters: Option<HashMap<String, Value>>,
+}
+
+/// `POST /db/{name}/tx/commit` — run statements and answer in Neo4j's shape.
+pub async fn tx_commit_handler(
+    State(app_state): State<Arc<AppState>>,
+    Path(database): Path<String>,
+    headers: HeaderMap,
+    Json(request): Json<TxCommitRequest>,
+) -> Json<Value> {
+    let schema_name = resolve_schema_name(&database).await;
+
+    let mut results = Vec::new();
+    let mut errors = Vec::new();
+    for statement in request.statements {
+        let payload = QueryRequest {
+            query: statement.statement,
+            format: Some(OutputFormat::JSONEachRow),
+            sql_only: None,
+            schema_name: Some(schema_name.clone()),
+            parameters: statement.parameters,
+            tenant_id: None,
+            view_parameters: None,
+            role: None,
+            max_inferred_types: None,
+            use_query_cache: None,
+            query_cache_ttl: None,
+            stream: None,
+            max_response_bytes: None,
+            continuation_token: None,
+            max_staleness_secs: None,
+            on_stale: None,
+            timeout_ms: None,
+            schema_overrides: None,
+            settings: None,
+            pinned_graph: None,
+        };
+        let response =
+            match query_handler(State(app_state.clone()), headers.clone(), Json(payload)).await {
+                Ok(resp) => resp.into_response(),
+                Err(resp) => resp.into_response(),
+            };
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap_or_default();
+
+        if status.is_success() {
+            match serde_json::from_slice::<Value>(&body) {
+                Ok(value) => results.push(statement_result(&value)),
+                Err(e) => {
+                    errors.push(neo4j_error(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        &format!("Unexpected query response: {}", e),
+                    ));
+                    break;
+                }
+            }
+        } else {
+            errors.push(neo4j_error(status, &error_message(&body)));
+            break;
+        }
+    }
+
+    Json(serde_json::json!({
+        "results": results,
+        "errors": errors,
+    }))
+}
+
+/// Use the schema named by the URL, falling back to `default` for Neo4j's
+/// implicit `neo4j` database.
+async fn resolve_schema_name(database: &str) -> String {
+    if database == NEO4J_DEFAULT_DATABASE
+        && graph_catalog::get_graph_schema_by_name(database)
+            .await
+            .is_err()
+    {
+        "default".to_string()
+    } else {
+        database.to_string()
+    }
+}
+
+/// Reshape a `/query` JSON response into one Neo4j statement result.
+///
+/// Queries answer `{"results": [...]}`, procedures `{"records": [...]}` and
+/// `SHOW DATABASES` `{"databases": [...]}`; each row is an object whose key
+/// order is the RETURN order.
+fn statement_result(response: &Value) -> Value {
+    let rows = ["results", "records", "databases"]
+        .iter()
+        .find_map(|key| response.get(key).and_then(Value::as_array))
+        .cloned()
+        .unwrap_or_default();
+
+    let columns: Vec<String> = rows
+        .first()
+        .and_then(Value::as_object)
+        .map(|row| row.keys().cloned().collect())
+        .unwrap_or_default();
+    let data: Vec<Value> = rows
+        .iter()
+        .map(|row| {
+            let values: Vec<Value> = columns
+                .iter()
+                .map(|c| row.get(c).cloned().unwrap_or(Value::Null))
+                .collect();
+            serde_json::json!({
+                "row": values,
+                "meta": vec![Value::Null; columns.len()],
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "columns": columns,
+        "data": data,
+    })
+}
+
+/// Error bodies are either plain text or `{"error": "..."}`.
+pub(super) fn error_message(body: &[u8]) -> String {
+    serde_json::from_slice::<Value>(body)
+        .ok()
+        .and_then(|v| v.get("error").and_then(Value::as_str).map(str::to_string))
+        .unwrap_or_else(|| String::from_utf8_lossy(body).into_owned())
+}
+
+fn neo4j_error(status: StatusCode, message: &str) -> Value {
+    serde_json::json!({
+        "code": neo4j_status_code(status, message),
+        // Returned with 200, so the error-body redaction layer skips it
+        "message": crate::utils::redaction::redact(message),
+    })
+}
+
+/// Map an HTTP status from the query pipeline to a Neo4j status code.
+fn neo4j_status_code(status: StatusCode, message: &str) -> &'static str {
+    match status {
+        StatusCode::NOT_FOUND => "Neo.ClientError.Database.DatabaseNotFound",
+        StatusCode::SERVICE_UNAVAILABLE => "Neo.TransientError.General.DatabaseUnavailable",
+        s if s.is_client_error() => {
+            let lower = message.to_lowercase();
+            if lower.contains("parse") || lower.contains("syntax") {
+                "Neo.ClientError.Statement.SyntaxError"
+            } else {
+                "Neo.ClientError.Statement.SemanticError"
+            }
+        }
+        _ => "Neo.DatabaseError.General.UnknownError",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_become_columns_and_data_in_return_order() {
+        let response = serde_json::json!({
+            "results": [
+                {"u.name": "Alice", "u.age": 30},
+                {"u.name": "Bob", "u.age": null},
+            ]
+        });
+        let result = statement_result(&response);
+        assert_eq!(result["columns"], serde_json::json!(["u.name", "u.age"]));
+        assert_eq!(
+            result["data"][1],
+            serde_json::json!({"row": ["Bob", null], "meta": [null, null]})
+        );
+    }
+
+    #[test]
+    fn procedure_records_and_empty_results() {
+        let result = statement_result(&serde_json::json!({
+            "records": [{"label": "User"}],
+            "count": 1
+        }));
+        assert_eq!(result["columns"], serde_json::json!(["label"]));
+
+        let result = statement_result(&serde_json::json!({"results": []}));
+        assert_eq!(result, serde_json::json!({"columns": [], "data": []}));
+    }
+
+    #[test]
+    fn error_bodies_and_status_codes() {
+        assert_eq!(error_message(br#"{"error": "busy"}"#), "busy");
+        assert_eq!(
+            error_message(b"Query parsing failed"),
+            "Query parsing failed"
+        );
+
+        assert_eq!(
+            neo4j_status_code(StatusCode::BAD_REQUEST, "Query syntax error: at line 1"),
+            "Neo.ClientError.Statement.SyntaxError"
+        );
+        assert_eq!(
+            neo4j_status_code(StatusCode::BAD_REQUEST, "Unknown label"),
+            "Neo.ClientError.Statement.SemanticError"
+        );
+        assert_eq!(
+            neo4j_status_code(StatusCode::NOT_FOUND, "Schema not found"),
+            "Neo.ClientError.Database.DatabaseNotFound"
+        );
+        assert_eq!(
+            neo4j_status_code(StatusCode::INTERNAL_SERVER_ERROR, "boom"),
+            "Neo.DatabaseError.General.UnknownError"
+        );
+    }
+}
@@ -5,6 +5,9 @@
 use serde_json::Value;
 use std::collections::HashMap;
 
+use crate::graph_catalog::schema_types::SchemaType;
+use crate::query_planner::parameter_types::ParameterType;
+
 #[derive(Debug, thiserror::Error)]
 pub enum ParameterSubstitutionError {
     #[error("Missing required parameter: {0}")]
@@ -15,6 +18,14 @@ pub enum ParameterSubstitutionError {
 
     #[error("Unsupported parameter type for value: {0}")]
     UnsupportedType(String),
+
+    #[error("Parameter '{name}' must be {expected} (compared with {site}), got {found}")]
+    TypeMismatch {
+        name: String,
+        expected: String,
+        site: String,
+        found: &'static str,
+    },
 }
 
 /// Escape a string value for use in ClickHouse SQL
@@ -169,6 +180,264 @@ pub fn find_unsubstituted_parameter(sql: &str) -> Option<String> {
 pub fn substitute_parameters(
     sql: &str,
     parameters: &HashMap<String, Value>,
+) -> Result<String, ParameterSubstitutionError> {
+    replace_placeholders(sql, |name| match parameters.get(name) {
+        Some(value) => format_parameter(value),
+        None => Err(ParameterSubstitutionError::MissingParameter(
+            name.to_string(),
+        )),
+    })
+}
+
+/// SQL with ClickHouse query parameter placeholders plus their values.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoundSql {
+    pub sql: String,
+    /// `(name, value)` pairs, sent to ClickHouse as `param_<name>=<value>`.
+    /// Values are in ClickHouse's escaped text format for the placeholder type.
+    pub params: Vec<(String, String)>,
+}
+
+/// Bind `$paramName` placeholders as ClickHouse query parameters.
+///
+/// Each placeholder backed by a `query_params` value is rewritten to
+/// `{paramName:Type}` and its value returned separately, so ClickHouse parses
+/// it as data and the SQL text stays the same for every call. Values with no
+/// single ClickHouse type (null, maps, empty or mixed lists) and all
+/// `inline_params` (parameterized-view arguments) are inlined as escaped
+/// literals, exactly like [`substitute_parameters`]. Query parameters take
+/// precedence over inline parameters with the same name.
+///
+/// # Example
+/// ```ignore
+/// let bound = bind_parameters(
+///     "SELECT * FROM users WHERE email = $email",
+///     &HashMap::from([("email".to_string(), json!("alice@example.com"))]),
+///     &HashMap::new(),
+/// )
+/// .unwrap();
+/// // bound.sql:    "SELECT * FROM users WHERE email = {email:String}"
+/// // bound.params: [("email", "alice@example.com")]
+/// ```
+pub fn bind_parameters(
+    sql: &str,
+    query_params: &HashMap<String, Value>,
+    inline_params: &HashMap<String, Value>,
+) -> Result<BoundSql, ParameterSubstitutionError> {
+    let mut params: Vec<(String, String)> = Vec::new();
+    let sql = replace_placeholders(sql, |name| {
+        if let Some(value) = query_params.get(name) {
+            if let Some((ch_type, text)) = native_parameter(value) {
+                if !params.iter().any(|(n, _)| n == name) {
+                    params.push((name.to_string(), text));
+                }
+                return Ok(format!("{{{}:{}}}", name, ch_type));
+            }
+            return format_parameter(value);
+        }
+        match inline_params.get(name) {
+            Some(value) => format_parameter(value),
+            None => Err(ParameterSubstitutionError::MissingParameter(
+                name.to_string(),
+            )),
+        }
+    })?;
+    Ok(BoundSql { sql, params })
+}
+
+/// ClickHouse type and escaped text value of a natively bindable parameter.
+fn native_parameter(value: &Value) -> Option<(String, String)> {
+    match value {
+        Value::String(s) => Some(("String".to_string(), escape_text_value(s))),
+        Value::Array(items) => {
+            let element_type = items
+                .iter()
+                .map(|item| match item {
+                    Value::String(_) => Some("String"),
+                    other => scalar_type(other),
+                })
+                .reduce(|a, b| match (a?, b?) {
+                    (a, b) if a == b => Some(a),
+                    ("Int64" | "UInt64" | "Float64", "Int64" | "UInt64" | "Float64") => {
+                        Some("Float64")
+                    }
+                    _ => None,
+                })??;
+            let literals: Result<Vec<String>, _> = items
+                .iter()
+                .map(|item| match item {
+                    Value::Bool(b) => Ok(b.to_string()),
+                    other => format_parameter(other),
+                })
+                .collect();
+            Some((
+                format!("Array({})", element_type),
+                format!("[{}]", literals.ok()?.join(",")),
+            ))
+        }
+        other => scalar_type(other).map(|t| {
+            let text = match other {
+                Value::Bool(b) => b.to_string(),
+                Value::Number(n) => n.to_string(),
+                _ => unreachable!("scalar_type only accepts numbers and booleans here"),
+            };
+            (t.to_string(), text)
+        }),
+    }
+}
+
+/// ClickHouse type of a JSON number or boolean.
+fn scalar_type(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::Bool(_) => Some("Bool"),
+        Value::Number(n) if n.is_i64() => Some("Int64"),
+        Value::Number(n) if n.is_u64() => Some("UInt64"),
+        Value::Number(n) if n.as_f64().is_some_and(f64::is_finite) => Some("Float64"),
+        _ => None,
+    }
+}
+
+/// Check supplied parameters against the types the query compares them
+/// with (see `query_planner::parameter_types`), converting values that
+/// convert losslessly: numeric strings to numbers, whole floats to integers,
+/// numbers to strings, `0`/`1` and `"true"`/`"false"` to booleans, and
+/// RFC 3339 timestamps to ClickHouse's `YYYY-MM-DD hh:mm:ss`. `null` always
+/// passes; parameters without an expected type are left alone.
+pub fn check_parameter_types(
+    parameters: &mut HashMap<String, Value>,
+    expected: &HashMap<String, ParameterType>,
+) -> Result<(), ParameterSubstitutionError> {
+    for (name, ty) in expected {
+        let Some(value) = parameters.get_mut(name) else {
+            continue;
+        };
+        let converted = if ty.list {
+            match value {
+                Value::Array(items) => items
+                    .iter()
+                    .map(|item| coerce(item, &ty.expected))
+                    .collect::<Option<Vec<_>>>()
+                    .map(Value::Array),
+                Value::Null => Some(Value::Null),
+                _ => None,
+            }
+        } else {
+            coerce(value, &ty.expected)
+        };
+        match converted {
+            Some(converted) => *value = converted,
+            None => {
+                return Err(ParameterSubstitutionError::TypeMismatch {
+                    name: name.clone(),
+                    expected: if ty.list {
+                        format!("a list of {}", ty.expected)
+                    } else {
+                        ty.expected.to_string()
+                    },
+                    site: ty.site.clone(),
+                    found: json_type_name(value),
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `value` as a value of type `ty`, or `None` when it has no such reading.
+fn coerce(value: &Value, ty: &SchemaType) -> Option<Value> {
+    if value.is_null() {
+        return Some(Value::Null);
+    }
+    match (ty, value) {
+        (SchemaType::Integer, Value::Number(n)) if n.is_i64() || n.is_u64() => Some(value.clone()),
+        (SchemaType::Integer, Value::Number(n)) => n
+            .as_f64()
+            .filter(|f| f.fract() == 0.0 && f.abs() < 9.2e18)
+            .map(|f| Value::from(f as i64)),
+        (SchemaType::Integer, Value::String(s)) => {
+            let s = s.trim();
+            s.parse::<i64>()
+                .map(Value::from)
+                .or_else(|_| s.parse::<u64>().map(Value::from))
+                .ok()
+        }
+        (SchemaType::Float, Value::Number(_)) => Some(value.clone()),
+        (SchemaType::Float, Value::String(s)) => s
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number),
+        (SchemaType::String, Value::String(_)) => Some(value.clone()),
+        (SchemaType::String, Value::Number(n)) => Some(Value::String(n.to_string())),
+        (SchemaType::Boolean, Value::Bool(_)) => Some(value.clone()),
+        (SchemaType::Boolean, Value::Number(n)) => match n.as_u64() {
+            Some(0) => Some(Value::Bool(false)),
+            Some(1) => Some(Value::Bool(true)),
+            _ => None,
+        },
+        (SchemaType::Boolean, Value::String(s)) => match s.trim().to_lowercase().as_str() {
+            "true" | "1" => Some(Value::Bool(true)),
+            "false" | "0" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        (SchemaType::Uuid, Value::String(s)) => uuid::Uuid::parse_str(s.trim())
+            .ok()
+            .map(|u| Value::String(u.to_string())),
+        (SchemaType::Date, Value::String(s)) => {
+            chrono::NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")
+                .ok()
+                .map(|_| value.clone())
+        }
+        // Unix seconds compare with DateTime columns as they are
+        (SchemaType::DateTime, Value::Number(n)) if n.is_i64() || n.is_u64() => Some(value.clone()),
+        (SchemaType::DateTime, Value::String(s)) => parse_datetime(s.trim())
+            .map(|dt| Value::String(dt.format("%Y-%m-%d %H:%M:%S%.f").to_string())),
+        _ => None,
+    }
+}
+
+/// A timestamp as ClickHouse reads it (`YYYY-MM-DD[ hh:mm:ss[.fff]]`), or
+/// RFC 3339 (`T` separator, offset), which is converted to UTC.
+fn parse_datetime(s: &str) -> Option<chrono::NaiveDateTime> {
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Some(dt.naive_utc());
+    }
+    ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| chrono::NaiveDateTime::parse_from_str(s, format).ok())
+        .or_else(|| {
+            chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        })
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "a list",
+        Value::Object(_) => "a map",
+    }
+}
+
+/// Escape a string for a query parameter value (TSV escaped format: no
+/// surrounding quotes, backslash escapes for control characters).
+fn escape_text_value(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\0', "\\0")
+}
+
+/// Replace every `$name` placeholder with `replace(name)`; a lone `$` is kept.
+fn replace_placeholders(
+    sql: &str,
+    mut replace: impl FnMut(&str) -> Result<String, ParameterSubstitutionError>,
 ) -> Result<String, ParameterSubstitutionError> {
     let mut result = String::with_capacity(sql.len() * 2); // Pre-allocate
     let mut chars = sql.chars().peekable();
@@ -196,17 +465,7 @@ pub fn substitute_parameters(
                 if !is_valid_parameter_name(&param_name) {
                     return Err(ParameterSubstitutionError::InvalidParameterName(param_name));
                 }
-
-                // Look up parameter value
-                match parameters.get(&param_name) {
-                    Some(value) => {
-                        let formatted = format_parameter(value)?;
-                        result.push_str(&formatted);
-                    }
-                    None => {
-                        return Err(ParameterSubstitutionError::MissingParameter(param_name));
-                    }
-                }
+                result.push_str(&replace(&param_name)?);
             }
         } else {
             result.push(ch);
@@ -365,4 +624,99 @@ mod tests {
             "SELECT price * 1.1 AS price_with_tax WHERE currency = '$'"
         );
     }
+
+    #[test]
+    fn test_bind_parameters_native_types() {
+        let params = HashMap::from([
+            ("name".to_string(), json!("O'Brien\tjr")),
+            ("age".to_string(), json!(30)),
+            ("score".to_string(), json!(1.5)),
+            ("active".to_string(), json!(true)),
+            ("ids".to_string(), json!([1, 2, 3])),
+            ("tags".to_string(), json!(["a", "b"])),
+        ]);
+        let sql = "WHERE name = $name AND age > $age AND score < $score AND active = $active \
+                   AND id IN $ids AND hasAny(tags, $tags) OR name = $name";
+        let bound = bind_parameters(sql, &params, &HashMap::new()).unwrap();
+        assert_eq!(
+            bound.sql,
+            "WHERE name = {name:String} AND age > {age:Int64} AND score < {score:Float64} \
+             AND active = {active:Bool} AND id IN {ids:Array(Int64)} \
+             AND hasAny(tags, {tags:Array(String)}) OR name = {name:String}"
+        );
+        assert_eq!(
+            bound.params,
+            vec![
+                ("name".to_string(), "O'Brien\\tjr".to_string()),
+                ("age".to_string(), "30".to_string()),
+                ("score".to_string(), "1.5".to_string()),
+                ("active".to_string(), "true".to_string()),
+                ("ids".to_string(), "[1,2,3]".to_string()),
+                ("tags".to_string(), "['a','b']".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bind_parameters_inlines_what_cannot_be_bound() {
+        let query = HashMap::from([
+            ("missing".to_string(), json!(null)),
+            ("mixed".to_string(), json!([1, "a"])),
+        ]);
+        let view = HashMap::from([("tenant_id".to_string(), json!("acme"))]);
+        let sql = "FROM t(tenant_id = $tenant_id) WHERE x = $missing OR y IN $mixed";
+        let bound = bind_parameters(sql, &query, &view).unwrap();
+        assert_eq!(
+            bound.sql,
+            "FROM t(tenant_id = 'acme') WHERE x = NULL OR y IN [1, 'a']"
+        );
+        assert!(bound.params.is_empty());
+
+        assert!(matches!(
+            bind_parameters("WHERE x = $other", &query, &view),
+            Err(ParameterSubstitutionError::MissingParameter(name)) if name == "other"
+        ));
+    }
+
+    #[test]
+    fn test_check_parameter_types_converts_or_rejects() {
+        let expect = |expected: SchemaType, list: bool| ParameterType {
+            expected,
+            list,
+            site: "u.x".to_string(),
+        };
+        let expected = HashMap::from([
+            ("age".to_string(), expect(SchemaType::Integer, false)),
+            ("name".to_string(), expect(SchemaType::String, false)),
+            ("ids".to_string(), expect(SchemaType::Integer, true)),
+            ("active".to_string(), expect(SchemaType::Boolean, false)),
+            ("at".to_string(), expect(SchemaType::DateTime, false)),
+        ]);
+        let mut params = HashMap::from([
+            ("age".to_string(), json!("25")),
+            ("name".to_string(), json!(42)),
+            ("ids".to_string(), json!([1, "2", 3.0])),
+            ("active".to_string(), json!(1)),
+            ("at".to_string(), json!("2024-03-01T10:00:00+02:00")),
+            ("other".to_string(), json!({"a": 1})),
+        ]);
+        check_parameter_types(&mut params, &expected).unwrap();
+        assert_eq!(params["age"], json!(25));
+        assert_eq!(params["name"], json!("42"));
+        assert_eq!(params["ids"], json!([1, 2, 3]));
+        assert_eq!(params["active"], json!(true));
+        assert_eq!(params["at"], json!("2024-03-01 08:00:00"));
+
+        let mut params = HashMap::from([("age".to_string(), json!("twenty"))]);
+        let err = check_parameter_types(&mut params, &expected).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parameter 'age' must be integer (compared with u.x), got a string"
+        );
+        let mut params = HashMap::from([("ids".to_string(), json!(7))]);
+        assert!(check_parameter_types(&mut params, &expected)
+            .unwrap_err()
+            .to_string()
+            .contains("must be a list of integer"));
+    }
 }
@@ -107,25 +107,30 @@ impl ReplanOption {
     /// - "MATCH ..." -> "MATCH ..."
     pub fn strip_prefix(query: &str) -> &str {
         let trimmed = query.trim();
-        if !trimmed.to_uppercase().starts_with("CYPHER") {
+        let mut tokens = trimmed.split_whitespace();
+        if !tokens
+            .next()
+            .is_some_and(|token| token.eq_ignore_ascii_case("CYPHER"))
+        {
             return query;
         }
 
-        // Find first occurrence of MATCH, RETURN, WITH, UNWIND, CREATE, etc.
-        // These are the actual Cypher clause keywords
-        let cypher_keywords = [
-            "MATCH", "RETURN", "WITH", "UNWIND", "CREATE", "MERGE", "DELETE", "SET", "REMOVE",
-            "CALL", "EXPLAIN", "PROFILE", "USE",
-        ];
-
-        for keyword in cypher_keywords {
-            if let Some(pos) = trimmed.to_uppercase().find(keyword) {
-                return trimmed[pos..].trim();
+        // Skip `<option>=<value>` options (and a `CYPHER 5` version); the
+        // statement starts at the first other token. Option names can
+        // contain clause keywords (`include_deleted`), so tokens are matched
+        // whole rather than searched for keywords.
+        let mut rest = &trimmed["CYPHER".len()..];
+        loop {
+            rest = rest.trim_start();
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let token = &rest[..end];
+            let is_option = token.contains('=')
+                || (!token.is_empty() && token.chars().all(|c| c.is_ascii_digit() || c == '.'));
+            if !is_option {
+                return rest.trim_end();
             }
+            rest = &rest[end..];
         }
-
-        // If no keyword found, return original
-        query
     }
 }
 
@@ -505,6 +510,14 @@ mod tests {
             ReplanOption::strip_prefix("  CYPHER replan=skip  MATCH (n) RETURN n  "),
             "MATCH (n) RETURN n"
         );
+        assert_eq!(
+            ReplanOption::strip_prefix("CYPHER include_deleted=true USE social MATCH (n) RETURN n"),
+            "USE social MATCH (n) RETURN n"
+        );
+        assert_eq!(
+            ReplanOption::strip_prefix("CYPHER 5 replan=force EXPLAIN MATCH (n) RETURN n"),
+            "EXPLAIN MATCH (n) RETURN n"
+        );
     }
 
     #[test]
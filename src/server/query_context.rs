@@ -31,7 +31,7 @@
 //! The `.scope()` wrapper is REQUIRED for task_local to work. Without it, `try_with()` returns None.
 
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::future::Future;
 use std::sync::Arc;
 
@@ -226,6 +226,50 @@ pub struct QueryContext {
     /// Union/merge semantics: an alias bound in ANY enclosing scope is "outer"
     /// to a more-deeply-nested EXISTS, so entries are only ever added.
     pub exists_outer_aliases: HashSet<String>,
+
+    /// Edge properties folded by `reduce(.. r IN relationships(p) | .. r.prop ..)`,
+    /// keyed by path variable. Merged from the full plan at the top of
+    /// `to_render_plan` so a VLP CTE built deep inside a WITH chain still
+    /// accumulates the `path_edge_<prop>` arrays a later clause folds over.
+    pub vlp_path_edge_properties: HashMap<String, BTreeSet<String>>,
+
+    /// Why the target database cannot run `WITH RECURSIVE`, or `None` when it
+    /// can. When set, bounded variable-length paths are unrolled into one
+    /// non-recursive CTE per hop and unbounded ones are rejected with this
+    /// reason. Seeded from [`set_server_recursive_cte_unavailable`].
+    pub recursive_cte_unavailable: Option<String>,
+
+    /// Upper bound for every variable-length path, which is then unrolled
+    /// into non-recursive per-hop CTEs. Set only while re-planning a query
+    /// that ClickHouse aborted at `max_recursive_cte_evaluation_depth`
+    /// (`RecursionDepthPolicy::Unroll`).
+    pub vlp_fallback_hops: Option<u32>,
+
+    /// Frozen clock and `rand()` seed when the server runs in test mode, so
+    /// the same query renders byte-identical SQL on every run. Seeded from
+    /// [`set_server_deterministic`].
+    pub deterministic: Option<DeterministicMode>,
+
+    /// Per-query alias / CTE / `rand()` counters used instead of the
+    /// process-wide ones while `deterministic` is set, so concurrent queries
+    /// can't shift each other's `t1`, `cte1`, ... names.
+    pub deterministic_counters: DeterministicCounters,
+}
+
+/// Test mode settings: every `rand()` in generated SQL draws from `seed` and
+/// every current-time function evaluates to `frozen_time`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeterministicMode {
+    pub seed: u64,
+    pub frozen_time: chrono::DateTime<chrono::Utc>,
+}
+
+/// Last value handed out by each per-query counter (0 = none yet)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeterministicCounters {
+    pub aliases: u32,
+    pub ctes: u32,
+    pub rand_calls: u32,
 }
 
 /// Process-wide default SQL dialect for server-handled queries. Set once at
@@ -264,6 +308,39 @@ pub fn server_neo4j_compat() -> bool {
     SERVER_NEO4J_COMPAT.get().copied().unwrap_or(false)
 }
 
+/// Process-wide reason the server's ClickHouse cannot run recursive CTEs. Set
+/// once at startup from the version probe
+/// (`clickhouse_client::detect_recursive_cte_support`).
+/// Unset (embedded, `cg`, non-ClickHouse backends) means recursive CTEs are
+/// available.
+static SERVER_RECURSIVE_CTE_UNAVAILABLE: std::sync::OnceLock<Option<String>> =
+    std::sync::OnceLock::new();
+
+/// Record whether server-handled queries may use recursive CTEs. Idempotent
+/// (first write wins); call once during server init before serving requests.
+pub fn set_server_recursive_cte_unavailable(reason: Option<String>) {
+    let _ = SERVER_RECURSIVE_CTE_UNAVAILABLE.set(reason);
+}
+
+fn server_recursive_cte_unavailable() -> Option<String> {
+    SERVER_RECURSIVE_CTE_UNAVAILABLE.get().cloned().flatten()
+}
+
+/// Process-wide test mode (`--test-mode` / `CLICKGRAPH_TEST_MODE`). Unset
+/// (the default, embedded, `cg`) means real clocks and random numbers.
+static SERVER_DETERMINISTIC: std::sync::OnceLock<Option<DeterministicMode>> =
+    std::sync::OnceLock::new();
+
+/// Make server-handled queries deterministic. Idempotent (first write wins);
+/// call once during server init before serving requests.
+pub fn set_server_deterministic(mode: Option<DeterministicMode>) {
+    let _ = SERVER_DETERMINISTIC.set(mode);
+}
+
+fn server_deterministic() -> Option<DeterministicMode> {
+    SERVER_DETERMINISTIC.get().copied().flatten()
+}
+
 impl QueryContext {
     /// Create a new query context with schema name.
     ///
@@ -277,6 +354,8 @@ impl QueryContext {
         Self {
             schema_name,
             dialect: server_dialect(),
+            recursive_cte_unavailable: server_recursive_cte_unavailable(),
+            deterministic: server_deterministic(),
             ..Default::default()
         }
     }
@@ -316,6 +395,67 @@ where
 // DIALECT ACCESSORS
 // ============================================================================
 
+/// Why recursive CTEs cannot be used for the current query, if they cannot.
+/// `None` outside a task-local scope.
+pub fn get_recursive_cte_unavailable() -> Option<String> {
+    QUERY_CONTEXT
+        .try_with(|ctx| ctx.borrow().recursive_cte_unavailable.clone())
+        .ok()
+        .flatten()
+}
+
+/// The hop bound of a recursion-depth fallback plan, if this query is one.
+/// `None` outside a task-local scope.
+pub fn get_vlp_fallback_hops() -> Option<u32> {
+    QUERY_CONTEXT
+        .try_with(|ctx| ctx.borrow().vlp_fallback_hops)
+        .ok()
+        .flatten()
+}
+
+/// Plan the rest of the current query with every variable-length path
+/// unrolled to at most `hops` hops (`None` restores recursive CTEs).
+pub fn set_vlp_fallback_hops(hops: Option<u32>) {
+    let _ = QUERY_CONTEXT.try_with(|ctx| {
+        ctx.borrow_mut().vlp_fallback_hops = hops;
+    });
+}
+
+/// The test mode settings of the current query, if it runs in test mode.
+/// `None` outside a task-local scope.
+pub fn get_deterministic_mode() -> Option<DeterministicMode> {
+    QUERY_CONTEXT
+        .try_with(|ctx| ctx.borrow().deterministic)
+        .ok()
+        .flatten()
+}
+
+/// Advance one of the current query's deterministic counters and return its
+/// new value, or `None` when the query isn't in test mode (callers then use
+/// their process-wide counter).
+pub fn next_deterministic_counter(
+    counter: fn(&mut DeterministicCounters) -> &mut u32,
+) -> Option<u32> {
+    QUERY_CONTEXT
+        .try_with(|ctx| {
+            let mut ctx = ctx.borrow_mut();
+            ctx.deterministic?;
+            let value = counter(&mut ctx.deterministic_counters);
+            *value += 1;
+            Some(*value)
+        })
+        .ok()
+        .flatten()
+}
+
+/// Restart the current query's deterministic counters (alongside
+/// `reset_all_counters`), so a re-planned query reuses the same names.
+pub fn reset_deterministic_counters() {
+    let _ = QUERY_CONTEXT.try_with(|ctx| {
+        ctx.borrow_mut().deterministic_counters = DeterministicCounters::default();
+    });
+}
+
 /// Get the SQL dialect for the current query.
 /// Returns [`SqlDialect::ClickHouse`] when called outside a task-local
 /// scope (e.g. unit tests), matching the historical hard-coded behavior.
@@ -970,6 +1110,33 @@ pub fn merge_exists_outer_aliases<I: IntoIterator<Item = String>>(aliases: I) {
     });
 }
 
+/// Merge (union) per-path-variable edge properties read by `reduce()` over
+/// `relationships(p)` into `vlp_path_edge_properties`.
+pub fn merge_vlp_path_edge_properties(props: HashMap<String, BTreeSet<String>>) {
+    let _ = QUERY_CONTEXT.try_with(|ctx| {
+        let mut ctx = ctx.borrow_mut();
+        for (path_var, names) in props {
+            ctx.vlp_path_edge_properties
+                .entry(path_var)
+                .or_default()
+                .extend(names);
+        }
+    });
+}
+
+/// Edge properties registered for `path_var` via [`merge_vlp_path_edge_properties`].
+pub fn get_vlp_path_edge_properties(path_var: &str) -> BTreeSet<String> {
+    QUERY_CONTEXT
+        .try_with(|ctx| {
+            ctx.borrow()
+                .vlp_path_edge_properties
+                .get(path_var)
+                .cloned()
+                .unwrap_or_default()
+        })
+        .unwrap_or_default()
+}
+
 /// #596: Is `alias` bound in the outer (enclosing) query scope? Used by
 /// `generate_exists_sql` to classify each EXISTS relationship-pattern endpoint
 /// as an outer anchor vs. a fresh inner variable.
@@ -0,0 +1,93 @@
+//! Per-request ClickHouse settings: the `settings` object of `/query`, and
+//! the `settings` map of a Bolt transaction's `tx_metadata`.
+//!
+//! ```json
+//! {"query": "MATCH ...", "settings": {"join_algorithm": "partial_merge", "max_memory_usage": 20000000000}}
+//! ```
+//!
+//! Only settings named in the server's `allowed_settings`
+//! (`CLICKGRAPH_ALLOWED_SETTINGS`) are accepted; any other name fails the
+//! request. Accepted settings are sent with every ClickHouse query of the
+//! statement, the way the query cache settings are, rather than spliced into
+//! the SQL text. Backends other than ClickHouse ignore them.
+
+use serde_json::Value;
+
+/// The requested settings as `(name, value)` options, or why they are
+/// refused. Values are strings, numbers or booleans (sent as `1` / `0`).
+pub fn validate<'a>(
+    requested: impl IntoIterator<Item = (&'a String, &'a Value)>,
+    allowed: &[String],
+) -> Result<Vec<(String, String)>, String> {
+    requested
+        .into_iter()
+        .map(|(name, value)| {
+            if !allowed.iter().any(|a| a == name) {
+                return Err(if allowed.is_empty() {
+                    format!(
+                        "ClickHouse setting '{}' is not allowed: this server accepts no \
+                         per-request settings (CLICKGRAPH_ALLOWED_SETTINGS)",
+                        name
+                    )
+                } else {
+                    format!(
+                        "ClickHouse setting '{}' is not allowed; allowed settings: {}",
+                        name,
+                        allowed.join(", ")
+                    )
+                });
+            }
+            let value = match value {
+                Value::String(s) if !s.chars().any(char::is_control) => s.clone(),
+                Value::Number(n) => n.to_string(),
+                Value::Bool(b) => u8::from(*b).to_string(),
+                _ => {
+                    return Err(format!(
+                        "ClickHouse setting '{}' must be a string, number or boolean",
+                        name
+                    ))
+                }
+            };
+            Ok((name.clone(), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    fn settings(value: Value) -> BTreeMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn accepts_allowed_settings() {
+        let allowed = ["join_algorithm".to_string(), "max_memory_usage".to_string()];
+        let requested = settings(json!({"join_algorithm": "hash", "max_memory_usage": 1000}));
+        assert_eq!(
+            validate(&requested, &allowed).unwrap(),
+            [
+                ("join_algorithm".to_string(), "hash".to_string()),
+                ("max_memory_usage".to_string(), "1000".to_string()),
+            ]
+        );
+        let flag = settings(json!({"join_algorithm": true}));
+        assert_eq!(validate(&flag, &allowed).unwrap()[0].1, "1");
+    }
+
+    #[test]
+    fn rejects_other_settings_and_values() {
+        let allowed = ["join_algorithm".to_string()];
+        let err = validate(&settings(json!({"readonly": 0})), &allowed).unwrap_err();
+        assert!(err.contains("allowed settings: join_algorithm"), "{err}");
+        let err = validate(&settings(json!({"join_algorithm": 1})), &[]).unwrap_err();
+        assert!(err.contains("CLICKGRAPH_ALLOWED_SETTINGS"), "{err}");
+        for value in [json!(["hash"]), json!(null), json!("hash\nx")] {
+            let requested = settings(json!({ "join_algorithm": value }));
+            assert!(validate(&requested, &allowed).is_err(), "{value}");
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Safety valve for variable-length paths that outgrow ClickHouse's
+//! `max_recursive_cte_evaluation_depth`.
+//!
+//! ClickHouse aborts a recursive CTE that is still producing rows at that
+//! depth with a `TOO_DEEP_RECURSION` exception. Instead of passing the raw
+//! exception on, the server either explains what to change
+//! ([`RecursionDepthPolicy::Error`]) or re-plans the query with every
+//! variable-length path unrolled to at most `vlp_fallback_hops` hops and
+//! returns those rows with a warning ([`RecursionDepthPolicy::Unroll`]).
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// What to do when a query hits the recursion depth limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecursionDepthPolicy {
+    /// Fail with an error that says how to bound the pattern
+    #[default]
+    Error,
+    /// Retry with paths unrolled to the fallback bound; rows from longer
+    /// paths are missing, which the response's notification says
+    Unroll,
+}
+
+#[derive(Debug, Error)]
+#[error("expected error or unroll, got '{0}'")]
+pub struct ParseRecursionDepthPolicyError(String);
+
+impl FromStr for RecursionDepthPolicy {
+    type Err = ParseRecursionDepthPolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "" | "error" => Ok(RecursionDepthPolicy::Error),
+            "unroll" | "retry" => Ok(RecursionDepthPolicy::Unroll),
+            _ => Err(ParseRecursionDepthPolicyError(s.to_string())),
+        }
+    }
+}
+
+/// Whether an executor error is ClickHouse aborting a recursive CTE at
+/// `max_recursive_cte_evaluation_depth`.
+pub fn is_depth_exceeded(error: &str) -> bool {
+    let error = error.to_ascii_lowercase();
+    error.contains("too_deep_recursion")
+        || error.contains("recursive cte evaluation depth")
+        || error.contains("max_recursive_cte_evaluation_depth")
+}
+
+/// The error returned in place of ClickHouse's exception.
+pub fn depth_exceeded_message(max_cte_depth: u32) -> String {
+    format!(
+        "Variable-length path exceeded the recursion limit \
+         (max_recursive_cte_evaluation_depth = {max_cte_depth}). Give the pattern a \
+         smaller upper bound (e.g. *1..5), raise CLICKGRAPH_MAX_CTE_DEPTH, or set \
+         CLICKGRAPH_RECURSION_DEPTH_POLICY=unroll to return paths of up to \
+         CLICKGRAPH_VLP_FALLBACK_HOPS hops instead."
+    )
+}
+
+/// The `X-Query-Notification` of a response served by the fallback plan.
+pub fn fallback_notification(hops: u32, max_cte_depth: u32) -> String {
+    format!(
+        "Partial results: the recursive plan exceeded max_recursive_cte_evaluation_depth \
+         ({max_cte_depth}), so variable-length paths were cut at {hops} hops"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_parses_from_env_values() {
+        assert_eq!(
+            "".parse::<RecursionDepthPolicy>().unwrap(),
+            RecursionDepthPolicy::Error
+        );
+        assert_eq!(
+            "Unroll".parse::<RecursionDepthPolicy>().unwrap(),
+            RecursionDepthPolicy::Unroll
+        );
+        assert_eq!(
+            "retry".parse::<RecursionDepthPolicy>().unwrap(),
+            RecursionDepthPolicy::Unroll
+        );
+        assert!("partial".parse::<RecursionDepthPolicy>().is_err());
+    }
+
+    #[test]
+    fn recognises_clickhouse_depth_errors() {
+        assert!(is_depth_exceeded(
+            "Executor error: Code: 306. DB::Exception: Maximum recursive CTE evaluation \
+             depth (100) exceeded, during evaluation of vlp_a_b AS (...). Consider raising \
+             max_recursive_cte_evaluation_depth setting. (TOO_DEEP_RECURSION)"
+        ));
+        assert!(!is_depth_exceeded(
+            "Executor error: Code: 47. DB::Exception: Unknown expression identifier `x`"
+        ));
+    }
+}
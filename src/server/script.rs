@@ -0,0 +1,217 @@
+//! Multi-statement requests.
+//!
+//! A `/query` whose text holds several `;`-separated statements runs them in
+//! order and answers with one entry per statement:
+//!
+//! ```json
+//! {"statements": [
+//!   {"statement": "CALL db.labels()", "status": 200, "result": {"records": [...]}},
+//!   {"statement": "MATCH (x:Nope) RETURN x", "status": 400, "error": "..."}
+//! ]}
+//! ```
+//!
+//! `POST /query/script` takes the same request but streams progress as
+//! newline-delimited JSON: one line per statement as it finishes (the entry
+//! above plus `index` and `elapsed_ms`), then a `{"summary": {...}}` line.
+//!
+//! Each statement goes through the single-statement `/query` pipeline with
+//! the request's other fields (schema, parameters, role, ...), so a `USE`
+//! clause only applies to its own statement. The first failing statement
+//! stops the run.
+
+use std::{convert::Infallible, sync::Arc, time::Instant};
+
+use axum::{
+    body::{Body, Bytes},
+    extract::State,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+
+use super::{
+    handlers::{pin_graph_header, run_statement},
+    models::QueryRequest,
+    neo4j_http::error_message,
+    AppState,
+};
+use crate::open_cypher_parser;
+use crate::utils::redaction::redact;
+
+/// Progress lines buffered ahead of a slow script client.
+const SCRIPT_CHANNEL_CAPACITY: usize = 16;
+
+/// The statements of `query` when it holds more than one, else `None`.
+///
+/// A query that does not parse as a statement list is left to the
+/// single-statement path, which reports the parse error.
+pub(super) fn multiple_statements(query: &str) -> Option<Vec<String>> {
+    let clean = open_cypher_parser::strip_comments(query);
+    // Only a `;` followed by more text can separate statements.
+    if !clean.trim().trim_end_matches(';').contains(';') {
+        return None;
+    }
+    let statements = script_statements(&clean).ok()?;
+    (statements.len() > 1).then_some(statements)
+}
+
+/// Statement texts of a comment-stripped script.
+fn script_statements(clean: &str) -> Result<Vec<String>, String> {
+    open_cypher_parser::parse_cypher_statements(clean)
+        .map(|parsed| {
+            parsed
+                .into_iter()
+                .map(|(text, _)| text.to_string())
+                .collect()
+        })
+        .map_err(|e| format!("Script parse error: {}", e))
+}
+
+/// Run `statements` in order for `/query`, stopping at the first failure,
+/// whose status becomes the response status.
+pub(super) async fn run_statements(
+    app_state: Arc<AppState>,
+    payload: QueryRequest,
+    statements: Vec<String>,
+) -> Result<Response, Response> {
+    if payload.stream.unwrap_or(false) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "stream is only supported for a single statement".to_string(),
+        )
+            .into_response());
+    }
+    if payload.max_response_bytes.is_some() || payload.continuation_token.is_some() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "max_response_bytes and continuation_token are only supported for a single statement"
+                .to_string(),
+        )
+            .into_response());
+    }
+
+    let mut entries = Vec::with_capacity(statements.len());
+    let mut status = StatusCode::OK;
+    for statement in statements {
+        let (statement_status, entry) = run_one(&app_state, &payload, statement).await;
+        entries.push(entry);
+        if !statement_status.is_success() {
+            status = statement_status;
+            break;
+        }
+    }
+
+    let response = (status, Json(json!({ "statements": entries }))).into_response();
+    if status.is_success() {
+        Ok(response)
+    } else {
+        Err(response)
+    }
+}
+
+/// `POST /query/script` — run a script, streaming one NDJSON line per
+/// statement and a final summary.
+pub async fn script_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(mut payload): Json<QueryRequest>,
+) -> Result<Response, (StatusCode, String)> {
+    pin_graph_header(&headers, &mut payload)?;
+    let statements = script_statements(&open_cypher_parser::strip_comments(&payload.query))
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    if statements.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Script contains no statements".to_string(),
+        ));
+    }
+    log::info!("Running script of {} statements", statements.len());
+
+    let (tx, rx) = mpsc::channel::<Bytes>(SCRIPT_CHANNEL_CAPACITY);
+    tokio::spawn(async move {
+        let start = Instant::now();
+        let total = statements.len();
+        let mut executed = 0;
+        let mut failed = false;
+        for (index, statement) in statements.into_iter().enumerate() {
+            let started = Instant::now();
+            let (status, mut entry) = run_one(&app_state, &payload, statement).await;
+            entry["index"] = json!(index);
+            entry["elapsed_ms"] = json!(started.elapsed().as_millis() as u64);
+            executed += 1;
+            if tx.send(ndjson_line(&entry)).await.is_err() {
+                log::info!("Script client disconnected after {} statements", executed);
+                return;
+            }
+            if !status.is_success() {
+                failed = true;
+                break;
+            }
+        }
+        let summary = json!({
+            "summary": {
+                "total": total,
+                "executed": executed,
+                "failed": failed,
+                "elapsed_ms": start.elapsed().as_millis() as u64,
+            }
+        });
+        let _ = tx.send(ndjson_line(&summary)).await;
+    });
+
+    let lines = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|line| (Ok::<_, Infallible>(line), rx))
+    });
+    let mut response = Response::new(Body::from_stream(lines));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/x-ndjson"),
+    );
+    Ok(response)
+}
+
+/// Run one statement of a multi-statement request and describe its outcome.
+async fn run_one(
+    app_state: &Arc<AppState>,
+    payload: &QueryRequest,
+    statement: String,
+) -> (StatusCode, Value) {
+    let mut request = payload.clone();
+    request.query = statement.clone();
+    request.stream = None;
+    request.max_response_bytes = None;
+    request.continuation_token = None;
+    let response = match run_statement(State(app_state.clone()), Json(request)).await {
+        Ok(resp) | Err(resp) => resp,
+    };
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap_or_default();
+
+    let mut entry = json!({ "statement": statement, "status": status.as_u16() });
+    if status.is_success() {
+        // Text formats (Pretty, CSV) are kept as a string.
+        entry["result"] = serde_json::from_slice(&body)
+            .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&body).into_owned()));
+    } else {
+        entry["error"] = Value::String(redact(&error_message(&body)).into_owned());
+        // Known ClickHouse failures carry their code and a suggestion
+        if let Ok(Value::Object(details)) = serde_json::from_slice::<Value>(&body) {
+            for key in ["error_code", "suggestion"] {
+                if let Some(value) = details.get(key) {
+                    entry[key] = value.clone();
+                }
+            }
+        }
+    }
+    (status, entry)
+}
+
+fn ndjson_line(value: &Value) -> Bytes {
+    let mut line = value.to_string().into_bytes();
+    line.push(b'\n');
+    Bytes::from(line)
+}
@@ -0,0 +1,361 @@
+//! Session parameters set with Neo4j-Browser-style `:param` commands.
+//!
+//! ```text
+//! :param country => 'US'      set one parameter
+//! :param {k: 10, min: -1.5}   set several
+//! :params {k: 10}             replace all of them
+//! :params                     list them
+//! :params clear               remove all of them
+//! ```
+//!
+//! Later queries of the same session reference them as `$country` without
+//! sending them again; parameters sent with a query take precedence. Over
+//! HTTP the session is named by the `X-Session-Id` header, whose value the
+//! server issues in its answer to the first `:param` command. Sessions idle
+//! for [`SESSION_IDLE_TIMEOUT`] are dropped. A Bolt connection is its own
+//! session and keeps its parameters in its `BoltContext`.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    http::{HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::{Map, Value};
+
+use crate::open_cypher_parser::{self, ast::CypherStatement};
+
+use super::stored_queries::literal_json;
+
+/// Header naming the HTTP session whose parameters a query uses.
+pub const SESSION_HEADER: &str = "X-Session-Id";
+
+/// How long a session's parameters outlive its last request.
+pub const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Parameters of one session, by name.
+pub type SessionParameters = BTreeMap<String, Value>;
+
+/// A parsed `:param` / `:params` command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamCommand {
+    /// `:param name => value` and `:param {map}`
+    Set(Map<String, Value>),
+    /// `:params {map}`
+    Replace(Map<String, Value>),
+    /// `:params clear`
+    Clear,
+    /// `:params`
+    List,
+}
+
+impl ParamCommand {
+    /// Apply the command to a session's parameters.
+    pub fn apply(self, parameters: &mut SessionParameters) {
+        match self {
+            ParamCommand::Set(values) => parameters.extend(values),
+            ParamCommand::Replace(values) => *parameters = values.into_iter().collect(),
+            ParamCommand::Clear => parameters.clear(),
+            ParamCommand::List => {}
+        }
+    }
+}
+
+/// Recognise a `:param` / `:params` command; `None` for anything else.
+///
+/// Values are Cypher literals, and may reference parameters the session
+/// already has (`:param b => $a`).
+pub fn parse_command(
+    text: &str,
+    parameters: &SessionParameters,
+) -> Option<Result<ParamCommand, String>> {
+    let text = text.trim().trim_end_matches(';').trim_end();
+    let (command, rest) = text.split_at(text.find(char::is_whitespace).unwrap_or(text.len()));
+    let replace = match command.to_ascii_lowercase().as_str() {
+        ":param" => false,
+        ":params" => true,
+        _ => return None,
+    };
+    let rest = rest.trim();
+    Some(if rest.is_empty() {
+        Ok(ParamCommand::List)
+    } else if rest.eq_ignore_ascii_case("clear") {
+        Ok(ParamCommand::Clear)
+    } else if rest.starts_with('{') {
+        map_value(rest, parameters).map(|values| {
+            if replace {
+                ParamCommand::Replace(values)
+            } else {
+                ParamCommand::Set(values)
+            }
+        })
+    } else if let Some((name, value)) = rest.split_once("=>") {
+        let name = name.trim().trim_matches('`');
+        if name.is_empty() || name.chars().any(char::is_whitespace) {
+            Err(format!("invalid parameter name '{}'", name))
+        } else {
+            literal(value, parameters)
+                .map(|value| ParamCommand::Set(Map::from_iter([(name.to_string(), value)])))
+        }
+    } else {
+        Err(format!(
+            "usage: {} name => value, {} {{name: value}} or {} clear",
+            command, command, command
+        ))
+    })
+}
+
+fn map_value(text: &str, parameters: &SessionParameters) -> Result<Map<String, Value>, String> {
+    match literal(text, parameters)? {
+        Value::Object(values) => Ok(values),
+        _ => Err("expected a map of parameters".to_string()),
+    }
+}
+
+/// JSON value of one Cypher literal expression.
+fn literal(text: &str, parameters: &SessionParameters) -> Result<Value, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("missing parameter value".to_string());
+    }
+    let query = format!("RETURN {}", text);
+    let invalid = || format!("invalid parameter value: {}", text);
+    let (rest, statement) =
+        open_cypher_parser::parse_cypher_statement(&query).map_err(|_| invalid())?;
+    let item = match &statement {
+        CypherStatement::Query { query, .. } if rest.trim().is_empty() => query
+            .return_clause
+            .as_ref()
+            .filter(|r| r.return_items.len() == 1)
+            .map(|r| &r.return_items[0].expression),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+    let known: HashMap<String, Value> = parameters
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    literal_json(item, &known)
+}
+
+/// Query parameters with the session's filled in under them: a parameter
+/// sent with the query wins over the session's value of the same name.
+pub fn merge(
+    session: &SessionParameters,
+    request: Option<HashMap<String, Value>>,
+) -> Option<HashMap<String, Value>> {
+    if session.is_empty() {
+        return request;
+    }
+    let mut merged: HashMap<String, Value> = session
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    merged.extend(request.unwrap_or_default());
+    Some(merged)
+}
+
+struct Session {
+    parameters: SessionParameters,
+    last_used: Instant,
+}
+
+/// Parameters of the live HTTP sessions.
+#[derive(Default)]
+pub struct SessionStore {
+    sessions: Mutex<HashMap<String, Session>>,
+}
+
+impl SessionStore {
+    /// Parameters of session `id`; empty for an unknown or expired session.
+    pub fn parameters(&self, id: &str) -> SessionParameters {
+        let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        expire(&mut sessions);
+        match sessions.get_mut(id) {
+            Some(session) => {
+                session.last_used = Instant::now();
+                session.parameters.clone()
+            }
+            None => SessionParameters::new(),
+        }
+    }
+
+    /// Run a `:param` command in session `id`, starting a new session when
+    /// `id` is `None` or no longer known. Returns the session's id and
+    /// parameters afterwards.
+    pub fn run_command(
+        &self,
+        id: Option<&str>,
+        text: &str,
+    ) -> Option<Result<(String, SessionParameters), String>> {
+        let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        expire(&mut sessions);
+        let id = id
+            .filter(|id| sessions.contains_key(*id))
+            .map(str::to_string);
+        let empty = SessionParameters::new();
+        let current = id
+            .as_ref()
+            .and_then(|id| sessions.get(id))
+            .map_or(&empty, |s| &s.parameters);
+        let command = match parse_command(text, current)? {
+            Ok(command) => command,
+            Err(e) => return Some(Err(e)),
+        };
+        let id = id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        let session = sessions.entry(id.clone()).or_insert_with(|| Session {
+            parameters: SessionParameters::new(),
+            last_used: Instant::now(),
+        });
+        command.apply(&mut session.parameters);
+        session.last_used = Instant::now();
+        Some(Ok((id, session.parameters.clone())))
+    }
+}
+
+fn expire(sessions: &mut HashMap<String, Session>) {
+    sessions.retain(|_, s| s.last_used.elapsed() < SESSION_IDLE_TIMEOUT);
+}
+
+/// Process-wide store of HTTP sessions.
+pub fn store() -> &'static SessionStore {
+    static STORE: OnceLock<SessionStore> = OnceLock::new();
+    STORE.get_or_init(SessionStore::default)
+}
+
+/// Session id sent with a request, if any.
+pub fn session_id(headers: &HeaderMap) -> Option<&str> {
+    headers.get(SESSION_HEADER).and_then(|v| v.to_str().ok())
+}
+
+/// Answer a `/query` request whose text is a `:param` command; `None` when
+/// it is not one.
+pub fn command_response(headers: &HeaderMap, query: &str) -> Option<Response> {
+    Some(match store().run_command(session_id(headers), query)? {
+        Ok((id, parameters)) => {
+            let mut response = Json(serde_json::json!({
+                "session_id": id,
+                "parameters": parameters,
+            }))
+            .into_response();
+            if let Ok(value) = HeaderValue::from_str(&id) {
+                response.headers_mut().insert(SESSION_HEADER, value);
+            }
+            response
+        }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(text: &str, parameters: &SessionParameters) -> ParamCommand {
+        parse_command(text, parameters).unwrap().unwrap()
+    }
+
+    #[test]
+    fn parses_browser_param_commands() {
+        let none = SessionParameters::new();
+        assert_eq!(
+            parse(":param country => 'US'", &none),
+            ParamCommand::Set(Map::from_iter([("country".to_string(), json!("US"))]))
+        );
+        assert_eq!(
+            parse(":param {k: 10, min: -1.5, tags: ['a', 'b']};", &none),
+            ParamCommand::Set(Map::from_iter([
+                ("k".to_string(), json!(10)),
+                ("min".to_string(), json!(-1.5)),
+                ("tags".to_string(), json!(["a", "b"])),
+            ]))
+        );
+        assert_eq!(
+            parse(":params {k: 1}", &none),
+            ParamCommand::Replace(Map::from_iter([("k".to_string(), json!(1))]))
+        );
+        assert_eq!(parse(":params", &none), ParamCommand::List);
+        assert_eq!(parse(":PARAMS clear", &none), ParamCommand::Clear);
+        assert!(parse_command("MATCH (n) RETURN n", &none).is_none());
+        assert!(parse_command(":parameters", &none).is_none());
+    }
+
+    #[test]
+    fn values_can_reference_session_parameters() {
+        let session = SessionParameters::from_iter([("a".to_string(), json!(3))]);
+        assert_eq!(
+            parse(":param b => [$a, -7]", &session),
+            ParamCommand::Set(Map::from_iter([("b".to_string(), json!([3, -7]))]))
+        );
+        let err = parse_command(":param b => $missing", &session)
+            .unwrap()
+            .unwrap_err();
+        assert!(err.contains("missing parameter $missing"), "{err}");
+    }
+
+    #[test]
+    fn rejects_malformed_commands() {
+        let none = SessionParameters::new();
+        for text in [
+            ":param k",
+            ":param k =>",
+            ":param => 1",
+            ":param k => n.name",
+            ":param k => 1 RETURN 2",
+            ":params [1]",
+        ] {
+            assert!(parse_command(text, &none).unwrap().is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn request_parameters_win_over_session_ones() {
+        let session = SessionParameters::from_iter([
+            ("k".to_string(), json!(10)),
+            ("country".to_string(), json!("US")),
+        ]);
+        let merged = merge(&session, Some(HashMap::from([("k".to_string(), json!(3))]))).unwrap();
+        assert_eq!(merged["k"], json!(3));
+        assert_eq!(merged["country"], json!("US"));
+        assert_eq!(merge(&SessionParameters::new(), None), None);
+    }
+
+    #[test]
+    fn store_issues_and_keeps_sessions() {
+        let store = SessionStore::default();
+        let (id, parameters) = store.run_command(None, ":param k => 1").unwrap().unwrap();
+        assert_eq!(parameters["k"], json!(1));
+        let (same, parameters) = store
+            .run_command(Some(&id), ":param {c: 'US'}")
+            .unwrap()
+            .unwrap();
+        assert_eq!(same, id);
+        assert_eq!(parameters.len(), 2);
+        assert_eq!(store.parameters(&id)["c"], json!("US"));
+
+        // An unknown id starts a fresh session instead of adopting the name
+        let (other, parameters) = store
+            .run_command(Some("guessed"), ":params")
+            .unwrap()
+            .unwrap();
+        assert_ne!(other, "guessed");
+        assert!(parameters.is_empty());
+        assert!(store.parameters("guessed").is_empty());
+
+        store
+            .run_command(Some(&id), ":params clear")
+            .unwrap()
+            .unwrap();
+        assert!(store.parameters(&id).is_empty());
+    }
+}
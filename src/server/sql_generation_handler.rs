@@ -1,6 +1,10 @@
-use std::{collections::HashMap, sync::Arc, time::Instant};
+use std::{borrow::Cow, collections::HashMap, sync::Arc, time::Instant};
 
-use axum::{extract::State, http::StatusCode, response::Json};
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::Json,
+};
 
 use crate::{
     clickhouse_query_generator, open_cypher_parser,
@@ -10,6 +14,7 @@ use crate::{
 
 use super::{
     graph_catalog,
+    handlers::graph_name_header,
     models::{
         ErrorDetails, SqlGenerationError, SqlGenerationMetadata, SqlGenerationRequest,
         SqlGenerationResponse,
@@ -21,6 +26,7 @@ use super::{
 /// Handler for POST /query/sql - Generate SQL without execution (production API)
 pub async fn sql_generation_handler(
     State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Json(payload): Json<SqlGenerationRequest>,
 ) -> Result<Json<SqlGenerationResponse>, (StatusCode, Json<SqlGenerationError>)> {
     let start_time = Instant::now();
@@ -56,30 +62,61 @@ pub async fn sql_generation_handler(
     // for garbage input).
     let stripped_for_use_check = open_cypher_parser::strip_comments(&payload.query);
     let clean_query = stripped_for_use_check.trim();
-    let schema_name = if clean_query.to_uppercase().starts_with("USE ") {
-        // Quick extraction of schema name from USE clause
-        match open_cypher_parser::parse_cypher_statement(clean_query) {
-            Ok((_, statement)) => match statement {
-                open_cypher_parser::ast::CypherStatement::Query { query, .. } => {
-                    if let Some(ref use_clause) = query.use_clause {
-                        use_clause.database_name
-                    } else {
-                        payload.schema_name.as_deref().unwrap_or("default")
-                    }
-                }
-                _ => payload.schema_name.as_deref().unwrap_or("default"),
-            },
-            Err(_) => payload.schema_name.as_deref().unwrap_or("default"),
-        }
-    } else {
-        payload.schema_name.as_deref().unwrap_or("default")
+    // USE clause > request `schema_name` > default graph
+    let use_graph = match open_cypher_parser::parse_cypher_statement(clean_query) {
+        Ok((_, statement)) => match query_planner::target_graph(&statement) {
+            Ok(use_graph) => use_graph,
+            Err(e) => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(SqlGenerationError {
+                        cypher_query: payload.query.clone(),
+                        error: e.to_string(),
+                        error_type: "PlanningError".to_string(),
+                        error_details: None,
+                    }),
+                ));
+            }
+        },
+        Err(_) => None,
     };
+    // `X-Graph-Name` selects and confines the graph as it does for /query
+    let header = graph_name_header(&headers).map_err(|(status, error)| {
+        (
+            status,
+            Json(SqlGenerationError {
+                cypher_query: payload.query.clone(),
+                error,
+                error_type: "RequestError".to_string(),
+                error_details: None,
+            }),
+        )
+    })?;
+    let requested = payload.schema_name.clone().or_else(|| header.clone());
+    let schema_name = &graph_catalog::resolve_graph_name(use_graph, requested.as_deref());
+    let pinned = graph_catalog::pinned_graph(header.as_deref(), requested.as_deref());
+    if let Err(error) = graph_catalog::check_graph_isolation(pinned.as_deref(), schema_name) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(SqlGenerationError {
+                cypher_query: payload.query.clone(),
+                error,
+                error_type: "GraphIsolationError".to_string(),
+                error_details: None,
+            }),
+        ));
+    }
 
-    // Check query cache first
+    // Check query cache first (the key ignores the CYPHER prefix, so
+    // `include_deleted=true` and `collation=...` queries bypass it)
     let cache_key = QueryCacheKey::new(&payload.query, schema_name);
+    let query_options = query_planner::QueryOptions::from_query(&payload.query);
 
     let mut cache_status = "MISS";
-    let cached_sql = if let Some(cache) = GLOBAL_QUERY_CACHE.get() {
+    let cached_sql = if query_options.bypasses_cache() {
+        cache_status = "BYPASS";
+        None
+    } else if let Some(cache) = GLOBAL_QUERY_CACHE.get() {
         if let Some(sql) = cache.get(&cache_key) {
             cache_status = "HIT";
             Some(sql)
@@ -186,6 +223,24 @@ pub async fn sql_generation_handler(
     };
     let parse_time = parse_start.elapsed().as_secs_f64() * 1000.0;
 
+    // USE ... FOR TIMESTAMP: generate against that snapshot of every table
+    let graph_schema =
+        match query_planner::snapshot_schema(&cypher_statement, &graph_schema, &query_options) {
+            Ok(Cow::Owned(snapshot)) => snapshot,
+            Ok(Cow::Borrowed(_)) => graph_schema,
+            Err(e) => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(SqlGenerationError {
+                        cypher_query: payload.query.clone(),
+                        error: e.to_string(),
+                        error_type: "PlanningError".to_string(),
+                        error_details: None,
+                    }),
+                ));
+            }
+        };
+
     // Extract the first query for query_type detection
     // For UNION queries, all branches should have the same type
     let first_query = match &cypher_statement {
@@ -407,7 +462,10 @@ pub async fn sql_generation_handler(
     };
 
     // Store in cache
-    if let Some(cache) = GLOBAL_QUERY_CACHE.get() {
+    if let Some(cache) = GLOBAL_QUERY_CACHE
+        .get()
+        .filter(|_| !query_options.bypasses_cache())
+    {
         cache.insert(cache_key, ch_query.clone());
     }
 
@@ -0,0 +1,755 @@
+//! Named, parameterized stored queries.
+//!
+//! A stored query is a Cypher statement registered under a name with a typed
+//! parameter list (`POST /queries`). Clients then run it by name, over HTTP
+//! or Bolt, without writing Cypher:
+//!
+//! ```cypher
+//! CALL query.run('top_followers', {k: 10})
+//! ```
+//!
+//! The call is expanded into the stored statement plus validated parameters
+//! before the regular query pipeline sees it, so stored queries share the
+//! query cache (the statement text never changes between calls) and the
+//! usual parameter escaping. A definition may restrict which roles can run
+//! it, and every run is counted in per-query usage stats.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{OnceLock, RwLock},
+    time::Duration,
+};
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::open_cypher_parser::{
+    self,
+    ast::{CypherStatement, Expression, Literal, Operator},
+};
+
+use super::{graph_catalog, AppState};
+
+/// Procedure name that runs a stored query.
+pub const RUN_PROCEDURE: &str = "query.run";
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum StoredQueryError {
+    #[error("Stored query not found: {0}")]
+    NotFound(String),
+
+    #[error("Role {role:?} is not allowed to run stored query '{name}'")]
+    Forbidden { name: String, role: Option<String> },
+
+    #[error("Missing required parameter '{param}' for stored query '{name}'")]
+    MissingParameter { name: String, param: String },
+
+    #[error("Unknown parameter '{param}' for stored query '{name}'")]
+    UnknownParameter { name: String, param: String },
+
+    #[error("Parameter '{param}' of stored query '{name}' must be {expected}, got {actual}")]
+    TypeMismatch {
+        name: String,
+        param: String,
+        expected: ParamType,
+        actual: String,
+    },
+
+    #[error("Invalid stored query: {0}")]
+    InvalidDefinition(String),
+
+    #[error("Invalid {RUN_PROCEDURE}() call: {0}")]
+    InvalidCall(String),
+}
+
+impl StoredQueryError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            StoredQueryError::NotFound(_) => StatusCode::NOT_FOUND,
+            StoredQueryError::Forbidden { .. } => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    /// Neo4j status code reported in a Bolt FAILURE.
+    pub fn neo4j_code(&self) -> &'static str {
+        match self {
+            StoredQueryError::NotFound(_) => "Neo.ClientError.Procedure.ProcedureNotFound",
+            StoredQueryError::Forbidden { .. } => "Neo.ClientError.Security.Forbidden",
+            StoredQueryError::MissingParameter { .. } => {
+                "Neo.ClientError.Statement.ParameterMissing"
+            }
+            StoredQueryError::TypeMismatch { .. } => "Neo.ClientError.Statement.TypeError",
+            _ => "Neo.ClientError.Procedure.ProcedureCallFailed",
+        }
+    }
+}
+
+/// Declared type of a stored query parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamType {
+    String,
+    Integer,
+    Float,
+    Boolean,
+    List,
+    Any,
+}
+
+impl ParamType {
+    fn accepts(self, value: &Value) -> bool {
+        match self {
+            ParamType::String => value.is_string(),
+            ParamType::Integer => value.is_i64() || value.is_u64(),
+            ParamType::Float => value.is_number(),
+            ParamType::Boolean => value.is_boolean(),
+            ParamType::List => value.is_array(),
+            ParamType::Any => true,
+        }
+    }
+}
+
+impl std::fmt::Display for ParamType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ParamType::String => "a string",
+            ParamType::Integer => "an integer",
+            ParamType::Float => "a number",
+            ParamType::Boolean => "a boolean",
+            ParamType::List => "a list",
+            ParamType::Any => "any value",
+        };
+        f.write_str(name)
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "float",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "list",
+        Value::Object(_) => "map",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParamSpec {
+    #[serde(rename = "type")]
+    pub param_type: ParamType,
+    /// Value used when the caller omits the parameter; without a default the
+    /// parameter is required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+}
+
+/// Body of `POST /queries`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredQuery {
+    pub name: String,
+    pub query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Schema the query runs against (defaults to the caller's schema)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    #[serde(default)]
+    pub parameters: BTreeMap<String, ParamSpec>,
+    /// Roles allowed to run the query; `None` lets every caller run it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_roles: Option<Vec<String>>,
+}
+
+impl StoredQuery {
+    /// Check the name, statement and parameter declarations.
+    pub fn validate(&self) -> Result<(), StoredQueryError> {
+        let invalid = |msg: String| Err(StoredQueryError::InvalidDefinition(msg));
+
+        if !is_identifier(&self.name) {
+            return invalid(format!(
+                "name '{}' must start with a letter or underscore and contain only letters, digits and underscores",
+                self.name
+            ));
+        }
+        for (param, spec) in &self.parameters {
+            if !is_identifier(param) {
+                return invalid(format!("invalid parameter name '{}'", param));
+            }
+            if let Some(default) = &spec.default {
+                if !spec.param_type.accepts(default) {
+                    return invalid(format!(
+                        "default for '{}' must be {}, got {}",
+                        param,
+                        spec.param_type,
+                        json_type_name(default)
+                    ));
+                }
+            }
+        }
+
+        let cleaned = open_cypher_parser::strip_comments(&self.query);
+        match open_cypher_parser::parse_cypher_statement(&cleaned) {
+            Ok((_, CypherStatement::ProcedureCall(call)))
+                if call.procedure_name.eq_ignore_ascii_case(RUN_PROCEDURE) =>
+            {
+                return invalid(format!("a stored query cannot call {}()", RUN_PROCEDURE));
+            }
+            Ok(_) => {}
+            Err(e) => return invalid(format!("query does not parse: {}", e)),
+        }
+
+        let undeclared: Vec<String> = referenced_parameters(&cleaned)
+            .into_iter()
+            .filter(|p| !self.parameters.contains_key(p))
+            .collect();
+        if !undeclared.is_empty() {
+            return invalid(format!(
+                "query uses undeclared parameter(s): {}",
+                undeclared.join(", ")
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate caller arguments against the declaration and fill defaults.
+    pub fn bind(
+        &self,
+        mut args: Map<String, Value>,
+    ) -> Result<HashMap<String, Value>, StoredQueryError> {
+        if let Some(param) = args.keys().find(|k| !self.parameters.contains_key(*k)) {
+            return Err(StoredQueryError::UnknownParameter {
+                name: self.name.clone(),
+                param: param.clone(),
+            });
+        }
+
+        let mut bound = HashMap::with_capacity(self.parameters.len());
+        for (param, spec) in &self.parameters {
+            let value = match args.remove(param).or_else(|| spec.default.clone()) {
+                Some(value) => value,
+                None => {
+                    return Err(StoredQueryError::MissingParameter {
+                        name: self.name.clone(),
+                        param: param.clone(),
+                    })
+                }
+            };
+            if !spec.param_type.accepts(&value) {
+                return Err(StoredQueryError::TypeMismatch {
+                    name: self.name.clone(),
+                    param: param.clone(),
+                    expected: spec.param_type,
+                    actual: json_type_name(&value).to_string(),
+                });
+            }
+            bound.insert(param.clone(), value);
+        }
+        Ok(bound)
+    }
+
+    fn allows(&self, role: Option<&str>) -> bool {
+        match &self.allowed_roles {
+            None => true,
+            Some(roles) => role.is_some_and(|r| roles.iter().any(|allowed| allowed == r)),
+        }
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `$name` placeholders in a Cypher statement, skipping string literals.
+fn referenced_parameters(query: &str) -> Vec<String> {
+    let mut params = Vec::new();
+    let mut chars = query.chars().peekable();
+    let mut quote: Option<char> = None;
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) => {
+                if c == '\\' {
+                    chars.next();
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None if c == '\'' || c == '"' || c == '`' => quote = Some(c),
+            None if c == '$' => {
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next.is_ascii_alphanumeric() || next == '_' {
+                        name.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                if !name.is_empty() && !params.contains(&name) {
+                    params.push(name);
+                }
+            }
+            None => {}
+        }
+    }
+    params
+}
+
+/// Usage counters of one stored query.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StoredQueryStats {
+    pub calls: u64,
+    pub errors: u64,
+    pub total_time_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_called_at: Option<String>,
+}
+
+/// A stored query expanded for execution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundQuery {
+    pub name: String,
+    pub query: String,
+    pub schema_name: Option<String>,
+    pub parameters: HashMap<String, Value>,
+}
+
+#[derive(Default)]
+pub struct StoredQueryRegistry {
+    entries: RwLock<BTreeMap<String, (StoredQuery, StoredQueryStats)>>,
+}
+
+impl StoredQueryRegistry {
+    /// Add or replace a definition; returns true when it replaced one.
+    /// Replacing a query resets its stats.
+    pub fn register(&self, query: StoredQuery) -> Result<bool, StoredQueryError> {
+        query.validate()?;
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        Ok(entries
+            .insert(query.name.clone(), (query, StoredQueryStats::default()))
+            .is_some())
+    }
+
+    pub fn remove(&self, name: &str) -> bool {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        entries.remove(name).is_some()
+    }
+
+    pub fn get(&self, name: &str) -> Option<(StoredQuery, StoredQueryStats)> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries.get(name).cloned()
+    }
+
+    pub fn list(&self) -> Vec<(StoredQuery, StoredQueryStats)> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries.values().cloned().collect()
+    }
+
+    /// Resolve a call by name: access check, then argument binding.
+    pub fn bind(
+        &self,
+        name: &str,
+        args: Map<String, Value>,
+        role: Option<&str>,
+    ) -> Result<BoundQuery, StoredQueryError> {
+        let (query, _) = self
+            .get(name)
+            .ok_or_else(|| StoredQueryError::NotFound(name.to_string()))?;
+        if !query.allows(role) {
+            return Err(StoredQueryError::Forbidden {
+                name: name.to_string(),
+                role: role.map(str::to_string),
+            });
+        }
+        let parameters = query.bind(args)?;
+        Ok(BoundQuery {
+            name: query.name,
+            query: query.query,
+            schema_name: query.schema_name,
+            parameters,
+        })
+    }
+
+    /// Count one run of `name`.
+    pub fn record(&self, name: &str, elapsed: Duration, ok: bool) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        if let Some((_, stats)) = entries.get_mut(name) {
+            stats.calls += 1;
+            if !ok {
+                stats.errors += 1;
+            }
+            stats.total_time_ms += elapsed.as_secs_f64() * 1000.0;
+            stats.last_called_at = Some(chrono::Utc::now().to_rfc3339());
+        }
+    }
+}
+
+/// Process-wide registry shared by the HTTP and Bolt front ends.
+pub fn registry() -> &'static StoredQueryRegistry {
+    static REGISTRY: OnceLock<StoredQueryRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(StoredQueryRegistry::default)
+}
+
+/// Name and arguments of a `query.run` call.
+type RunCall = (String, Map<String, Value>);
+
+/// Recognise `CALL query.run('name', {args})` and return the name and
+/// arguments; `None` for any other statement.
+///
+/// `$param` arguments are looked up in `parameters`, so drivers can pass
+/// `CALL query.run('name', $args)` with the map as a Bolt/HTTP parameter.
+pub fn parse_run_call(
+    query: &str,
+    parameters: &HashMap<String, Value>,
+) -> Option<Result<RunCall, StoredQueryError>> {
+    let cleaned = open_cypher_parser::strip_comments(query);
+    if !cleaned.to_ascii_lowercase().contains(RUN_PROCEDURE) {
+        return None;
+    }
+    let (_, statement) = open_cypher_parser::parse_cypher_statement(&cleaned).ok()?;
+    let arguments: Vec<&Expression<'_>> = match &statement {
+        CypherStatement::ProcedureCall(call)
+            if call.procedure_name.eq_ignore_ascii_case(RUN_PROCEDURE) =>
+        {
+            call.arguments.iter().collect()
+        }
+        CypherStatement::Query { query, .. }
+            if query
+                .call_clause
+                .as_ref()
+                .is_some_and(|c| c.procedure_name.eq_ignore_ascii_case(RUN_PROCEDURE)) =>
+        {
+            return Some(Err(StoredQueryError::InvalidCall(
+                "stored queries must be called standalone".to_string(),
+            )));
+        }
+        _ => return None,
+    };
+    Some(run_call_arguments(&arguments, parameters))
+}
+
+fn run_call_arguments(
+    arguments: &[&Expression<'_>],
+    parameters: &HashMap<String, Value>,
+) -> Result<RunCall, StoredQueryError> {
+    let invalid = |msg: &str| StoredQueryError::InvalidCall(msg.to_string());
+    let (name, args) = match arguments {
+        [name] => (name, None),
+        [name, args] => (name, Some(args)),
+        _ => return Err(invalid("expected (name) or (name, {parameters})")),
+    };
+    let Value::String(name) = literal_value(name, parameters)? else {
+        return Err(invalid("the query name must be a string"));
+    };
+    let args = match args.map(|a| literal_value(a, parameters)).transpose()? {
+        None | Some(Value::Null) => Map::new(),
+        Some(Value::Object(map)) => map,
+        Some(_) => return Err(invalid("parameters must be a map")),
+    };
+    Ok((name, args))
+}
+
+fn literal_value(
+    expr: &Expression<'_>,
+    parameters: &HashMap<String, Value>,
+) -> Result<Value, StoredQueryError> {
+    literal_json(expr, parameters).map_err(StoredQueryError::InvalidCall)
+}
+
+/// JSON value of a literal expression (numbers, strings, lists, maps),
+/// with `$param` looked up in `parameters`. Also used for `:param` values.
+pub(crate) fn literal_json(
+    expr: &Expression<'_>,
+    parameters: &HashMap<String, Value>,
+) -> Result<Value, String> {
+    Ok(match expr {
+        Expression::Literal(Literal::Integer(n)) => Value::from(*n),
+        Expression::Literal(Literal::Float(f)) => Value::from(*f),
+        Expression::Literal(Literal::Boolean(b)) => Value::from(*b),
+        Expression::Literal(Literal::String(s)) => Value::from(*s),
+        Expression::Literal(Literal::Null) => Value::Null,
+        // `-5` parses as `0 - 5`
+        Expression::OperatorApplicationExp(op)
+            if op.operator == Operator::Subtraction
+                && matches!(
+                    op.operands[..],
+                    [Expression::Literal(Literal::Integer(0)), _]
+                ) =>
+        {
+            match &op.operands[1] {
+                Expression::Literal(Literal::Integer(n)) => Value::from(-n),
+                Expression::Literal(Literal::Float(f)) => Value::from(-f),
+                other => return Err(format!("cannot negate {:?}", other)),
+            }
+        }
+        Expression::Parameter(p) => parameters
+            .get(*p)
+            .cloned()
+            .ok_or_else(|| format!("missing parameter ${}", p))?,
+        Expression::List(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| literal_json(item, parameters))
+                .collect::<Result<_, _>>()?,
+        ),
+        Expression::MapLiteral(entries) => Value::Object(
+            entries
+                .iter()
+                .map(|(k, v)| Ok((k.to_string(), literal_json(v, parameters)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        other => {
+            return Err(format!(
+                "values must be literals or parameters, got {:?}",
+                other
+            ))
+        }
+    })
+}
+
+/// Expand a `query.run` call into the stored statement; `Ok(None)` when
+/// `query` is not such a call.
+pub fn expand_run_call(
+    query: &str,
+    parameters: &HashMap<String, Value>,
+    role: Option<&str>,
+) -> Result<Option<BoundQuery>, StoredQueryError> {
+    match parse_run_call(query, parameters) {
+        None => Ok(None),
+        Some(call) => {
+            let (name, args) = call?;
+            registry().bind(&name, args, role).map(Some)
+        }
+    }
+}
+
+fn entry_json(query: &StoredQuery, stats: &StoredQueryStats) -> Value {
+    let mut value = serde_json::to_value(query).unwrap_or(Value::Null);
+    if let Value::Object(map) = &mut value {
+        map.insert(
+            "stats".to_string(),
+            serde_json::to_value(stats).unwrap_or(Value::Null),
+        );
+    }
+    value
+}
+
+fn error_response(status: StatusCode, message: String) -> Response {
+    (status, Json(serde_json::json!({ "error": message }))).into_response()
+}
+
+/// `POST /queries` — register (or replace) a stored query.
+pub async fn register_handler(
+    State(app_state): State<std::sync::Arc<AppState>>,
+    Json(query): Json<StoredQuery>,
+) -> Response {
+    if let Err(e) = query.validate() {
+        return error_response(e.status(), e.to_string());
+    }
+    // With a fixed schema the statement must also plan against it.
+    if let Some(schema_name) = &query.schema_name {
+        let schema = match graph_catalog::get_graph_schema_by_name(schema_name).await {
+            Ok(schema) => schema,
+            Err(e) => return error_response(StatusCode::NOT_FOUND, e),
+        };
+        if let Err(e) = crate::clickhouse_query_generator::cypher_to_sql(
+            &query.query,
+            &schema,
+            app_state.config.max_cte_depth,
+        ) {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Invalid stored query: {}", e),
+            );
+        }
+    }
+
+    let name = query.name.clone();
+    match registry().register(query) {
+        Ok(replaced) => {
+            let status = if replaced {
+                StatusCode::OK
+            } else {
+                StatusCode::CREATED
+            };
+            (
+                status,
+                Json(serde_json::json!({ "name": name, "replaced": replaced })),
+            )
+                .into_response()
+        }
+        Err(e) => error_response(e.status(), e.to_string()),
+    }
+}
+
+/// `GET /queries` — all stored queries with their usage stats.
+pub async fn list_handler() -> Json<Value> {
+    let queries: Vec<Value> = registry()
+        .list()
+        .iter()
+        .map(|(query, stats)| entry_json(query, stats))
+        .collect();
+    Json(serde_json::json!({ "queries": queries }))
+}
+
+/// `GET /queries/{name}`
+pub async fn get_handler(Path(name): Path<String>) -> Response {
+    match registry().get(&name) {
+        Some((query, stats)) => Json(entry_json(&query, &stats)).into_response(),
+        None => {
+            let e = StoredQueryError::NotFound(name);
+            error_response(e.status(), e.to_string())
+        }
+    }
+}
+
+/// `DELETE /queries/{name}`
+pub async fn delete_handler(Path(name): Path<String>) -> Response {
+    if registry().remove(&name) {
+        StatusCode::NO_CONTENT.into_response()
+    } else {
+        let e = StoredQueryError::NotFound(name);
+        error_response(e.status(), e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn top_followers() -> StoredQuery {
+        serde_json::from_value(serde_json::json!({
+            "name": "top_followers",
+            "query": "MATCH (u:User)<-[:FOLLOWS]-(f) WHERE u.country = $country \
+                      WITH u, count(f) AS followers WHERE followers >= $k \
+                      RETURN u.name, followers ORDER BY followers DESC LIMIT 10",
+            "parameters": {
+                "k": {"type": "integer", "default": 10},
+                "country": {"type": "string"}
+            },
+            "allowed_roles": ["analyst"]
+        }))
+        .expect("valid definition")
+    }
+
+    #[test]
+    fn definitions_are_validated() {
+        assert_eq!(top_followers().validate(), Ok(()));
+
+        let mut q = top_followers();
+        q.parameters.remove("country");
+        assert!(matches!(
+            q.validate(),
+            Err(StoredQueryError::InvalidDefinition(msg)) if msg.contains("country")
+        ));
+
+        let mut q = top_followers();
+        q.name = "top-followers".to_string();
+        assert!(q.validate().is_err());
+
+        let mut q = top_followers();
+        q.query = "CALL query.run('top_followers')".to_string();
+        assert!(q.validate().is_err());
+
+        // `$` inside a string literal is not a parameter.
+        assert_eq!(
+            referenced_parameters("MATCH (n) WHERE n.price = '$5' AND n.id = $id RETURN n"),
+            vec!["id".to_string()]
+        );
+    }
+
+    #[test]
+    fn arguments_are_checked_and_defaulted() {
+        let q = top_followers();
+        let args = |v: Value| v.as_object().cloned().unwrap();
+
+        let bound = q.bind(args(serde_json::json!({"country": "NZ"}))).unwrap();
+        assert_eq!(bound["k"], serde_json::json!(10));
+
+        assert_eq!(
+            q.bind(Map::new()),
+            Err(StoredQueryError::MissingParameter {
+                name: "top_followers".to_string(),
+                param: "country".to_string()
+            })
+        );
+        assert!(matches!(
+            q.bind(args(serde_json::json!({"country": "NZ", "k": "ten"}))),
+            Err(StoredQueryError::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            q.bind(args(serde_json::json!({"country": "NZ", "limit": 3}))),
+            Err(StoredQueryError::UnknownParameter { .. })
+        ));
+    }
+
+    #[test]
+    fn roles_and_stats() {
+        let registry = StoredQueryRegistry::default();
+        assert_eq!(registry.register(top_followers()), Ok(false));
+        assert_eq!(registry.register(top_followers()), Ok(true));
+
+        let args = serde_json::json!({"country": "NZ"})
+            .as_object()
+            .cloned()
+            .unwrap();
+        assert!(matches!(
+            registry.bind("top_followers", args.clone(), None),
+            Err(StoredQueryError::Forbidden { .. })
+        ));
+        let bound = registry
+            .bind("top_followers", args, Some("analyst"))
+            .unwrap();
+        assert!(bound.query.starts_with("MATCH"));
+
+        registry.record("top_followers", Duration::from_millis(5), true);
+        registry.record("top_followers", Duration::from_millis(5), false);
+        let (_, stats) = registry.get("top_followers").unwrap();
+        assert_eq!((stats.calls, stats.errors), (2, 1));
+        assert!(stats.last_called_at.is_some());
+        assert_eq!(
+            registry.bind("missing", Map::new(), None),
+            Err(StoredQueryError::NotFound("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn run_call_parsing() {
+        let params = HashMap::from([("args".to_string(), serde_json::json!({"k": 3}))]);
+
+        let (name, args) = parse_run_call("CALL query.run('top_followers', {k: 10})", &params)
+            .unwrap()
+            .unwrap();
+        assert_eq!(name, "top_followers");
+        assert_eq!(Value::Object(args), serde_json::json!({"k": 10}));
+
+        let (_, args) = parse_run_call("CALL query.run('top_followers', $args)", &params)
+            .unwrap()
+            .unwrap();
+        assert_eq!(Value::Object(args), serde_json::json!({"k": 3}));
+
+        let (_, args) = parse_run_call("CALL query.run('top_followers')", &params)
+            .unwrap()
+            .unwrap();
+        assert!(args.is_empty());
+
+        assert!(parse_run_call("CALL db.labels()", &params).is_none());
+        assert!(parse_run_call("MATCH (n) RETURN n", &params).is_none());
+        assert!(matches!(
+            parse_run_call("CALL query.run(42)", &params),
+            Some(Err(StoredQueryError::InvalidCall(_)))
+        ));
+    }
+}
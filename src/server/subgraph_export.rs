@@ -0,0 +1,657 @@
+//! Subgraph export as GraphML or GEXF (`GET` / `POST /export`).
+//!
+//! Hands a subgraph to Gephi, yEd or Cytoscape without custom scripts. The
+//! subgraph is either the result of a Cypher query or every node of some
+//! labels and every relationship of some types:
+//!
+//! ```text
+//! GET  /export?query=MATCH (a:User)-[r:FOLLOWS]->(b:User) RETURN a, r, b&format=gexf
+//! GET  /export?labels=User,Post&rel_types=AUTHORED&format=graphml
+//! POST /export  {"query": "...", "parameters": {...}, "format": "graphml"}
+//! ```
+//!
+//! Each statement runs through the regular [`query_handler`] pipeline with
+//! the `Graph` output format, which maps the returned node and relationship
+//! columns to nodes and edges (scalars are ignored). Nodes and edges are
+//! deduplicated across statements. Edges whose endpoints are not in the
+//! result are left out, since the formats require every edge endpoint to be
+//! a node of the document. Property types are inferred from the values.
+//! Like the other whole-table scans, exports run on the batch endpoint.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    convert::Infallible,
+    sync::Arc,
+};
+
+use axum::{
+    body::Body,
+    extract::{Query, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{
+    endpoint_routing,
+    handlers::query_handler,
+    models::{GraphEdge, GraphNode, OutputFormat, QueryRequest},
+    AppState,
+};
+
+/// Document format of an export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    GraphMl,
+    Gexf,
+}
+
+impl ExportFormat {
+    fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::GraphMl => "application/graphml+xml",
+            ExportFormat::Gexf => "application/gexf+xml",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::GraphMl => "graphml",
+            ExportFormat::Gexf => "gexf",
+        }
+    }
+}
+
+/// Body of `POST /export`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExportRequest {
+    /// Cypher query whose returned nodes and relationships are exported
+    pub query: Option<String>,
+    /// Export every node of these labels (instead of `query`)
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Export every relationship of these types, with their endpoints
+    /// (instead of `query`)
+    #[serde(default)]
+    pub rel_types: Vec<String>,
+    /// Row limit per label or type
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub format: ExportFormat,
+    pub schema_name: Option<String>,
+    pub parameters: Option<HashMap<String, Value>>,
+    pub tenant_id: Option<String>,
+    pub view_parameters: Option<HashMap<String, Value>>,
+    pub role: Option<String>,
+    pub timeout_ms: Option<u64>,
+}
+
+/// Query string of `GET /export`: lists are comma-separated.
+#[derive(Debug, Deserialize)]
+pub struct ExportParams {
+    query: Option<String>,
+    labels: Option<String>,
+    rel_types: Option<String>,
+    limit: Option<u64>,
+    #[serde(default)]
+    format: ExportFormat,
+    schema_name: Option<String>,
+    role: Option<String>,
+}
+
+impl From<ExportParams> for ExportRequest {
+    fn from(params: ExportParams) -> Self {
+        let list = |value: Option<String>| -> Vec<String> {
+            value
+                .map(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        ExportRequest {
+            query: params.query,
+            labels: list(params.labels),
+            rel_types: list(params.rel_types),
+            limit: params.limit,
+            format: params.format,
+            schema_name: params.schema_name,
+            role: params.role,
+            ..ExportRequest::default()
+        }
+    }
+}
+
+/// `GET /export?query=...` or `?labels=...&rel_types=...`.
+pub async fn export_get_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(params): Query<ExportParams>,
+) -> Response {
+    export(app_state, headers, params.into()).await
+}
+
+/// `POST /export` with an [`ExportRequest`].
+pub async fn export_post_handler(
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(request): Json<ExportRequest>,
+) -> Response {
+    export(app_state, headers, request).await
+}
+
+async fn export(app_state: Arc<AppState>, headers: HeaderMap, request: ExportRequest) -> Response {
+    let statements = match export_statements(&request) {
+        Ok(statements) => statements,
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+
+    let mut graph = Subgraph::default();
+    for statement in statements {
+        let payload = QueryRequest {
+            query: statement,
+            format: Some(OutputFormat::Graph),
+            sql_only: None,
+            schema_name: request.schema_name.clone(),
+            parameters: request.parameters.clone(),
+            tenant_id: request.tenant_id.clone(),
+            view_parameters: request.view_parameters.clone(),
+            role: request.role.clone(),
+            max_inferred_types: None,
+            use_query_cache: None,
+            query_cache_ttl: None,
+            stream: None,
+            max_response_bytes: None,
+            continuation_token: None,
+            max_staleness_secs: None,
+            on_stale: None,
+            timeout_ms: request.timeout_ms,
+            schema_overrides: None,
+            settings: None,
+            pinned_graph: None,
+        };
+        let response = match endpoint_routing::batch(query_handler(
+            State(app_state.clone()),
+            headers.clone(),
+            Json(payload),
+        ))
+        .await
+        {
+            Ok(resp) => resp,
+            Err(resp) => return resp,
+        };
+        if !response.status().is_success() {
+            return response;
+        }
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap_or_default();
+        let result: GraphResult = match serde_json::from_slice(&body) {
+            Ok(result) => result,
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    format!(
+                        "Export needs a query returning nodes or relationships: {}",
+                        e
+                    ),
+                )
+                    .into_response()
+            }
+        };
+        graph.extend(result);
+    }
+
+    let chunks = match request.format {
+        ExportFormat::GraphMl => graph.to_graphml(),
+        ExportFormat::Gexf => graph.to_gexf(),
+    };
+    let body = futures_util::stream::iter(chunks.into_iter().map(Ok::<_, Infallible>));
+    let mut response = Response::new(Body::from_stream(body));
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(request.format.content_type()),
+    );
+    if let Ok(disposition) = HeaderValue::try_from(format!(
+        "attachment; filename=\"subgraph.{}\"",
+        request.format.extension()
+    )) {
+        headers.insert(header::CONTENT_DISPOSITION, disposition);
+    }
+    response
+}
+
+/// The Cypher statements an export runs: its query, or one per label and
+/// relationship type.
+fn export_statements(request: &ExportRequest) -> Result<Vec<String>, String> {
+    let limit = request
+        .limit
+        .map(|n| format!(" LIMIT {}", n))
+        .unwrap_or_default();
+    match &request.query {
+        Some(query) if request.labels.is_empty() && request.rel_types.is_empty() => {
+            Ok(vec![query.clone()])
+        }
+        Some(_) => Err("Give either a query or labels / rel_types, not both".to_string()),
+        None if request.labels.is_empty() && request.rel_types.is_empty() => {
+            Err("Export needs a query, or labels / rel_types to export".to_string())
+        }
+        None => {
+            let mut statements = Vec::new();
+            for label in &request.labels {
+                statements.push(format!("MATCH (n:{}) RETURN n{}", name(label)?, limit));
+            }
+            for rel_type in &request.rel_types {
+                statements.push(format!(
+                    "MATCH (a)-[r:{}]->(b) RETURN a, r, b{}",
+                    name(rel_type)?,
+                    limit
+                ));
+            }
+            Ok(statements)
+        }
+    }
+}
+
+/// A label or type, checked to be a plain identifier so it can be spliced
+/// into a pattern.
+fn name(name: &str) -> Result<&str, String> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(name)
+    } else {
+        Err(format!("Invalid label or relationship type '{}'", name))
+    }
+}
+
+/// The `nodes` and `edges` of a `/query` response in the `Graph` format.
+#[derive(Debug, Deserialize)]
+struct GraphResult {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+}
+
+/// Nodes and edges collected across statements, in first-seen order.
+#[derive(Debug, Default)]
+struct Subgraph {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    seen_nodes: HashSet<String>,
+    seen_edges: HashSet<String>,
+}
+
+impl Subgraph {
+    fn extend(&mut self, result: GraphResult) {
+        for node in result.nodes {
+            if self.seen_nodes.insert(node.element_id.clone()) {
+                self.nodes.push(node);
+            }
+        }
+        for edge in result.edges {
+            if self.seen_edges.insert(edge.element_id.clone()) {
+                self.edges.push(edge);
+            }
+        }
+    }
+
+    /// Edges whose endpoints are both exported nodes.
+    fn connected_edges(&self) -> impl Iterator<Item = &GraphEdge> {
+        self.edges.iter().filter(|e| {
+            self.seen_nodes.contains(&e.start_node_element_id)
+                && self.seen_nodes.contains(&e.end_node_element_id)
+        })
+    }
+
+    /// GraphML document, one chunk per element.
+    fn to_graphml(&self) -> Vec<String> {
+        let node_keys = property_types(self.nodes.iter().map(|n| &n.properties));
+        let edge_keys = property_types(self.connected_edges().map(|e| &e.properties));
+
+        let mut header = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+             <key id=\"labels\" for=\"node\" attr.name=\"labels\" attr.type=\"string\"/>\n\
+             <key id=\"type\" for=\"edge\" attr.name=\"type\" attr.type=\"string\"/>\n",
+        );
+        for (prefix, target, keys) in [("n", "node", &node_keys), ("e", "edge", &edge_keys)] {
+            for (index, (name, kind)) in keys.iter().enumerate() {
+                header.push_str(&format!(
+                    "<key id=\"{prefix}{index}\" for=\"{target}\" attr.name=\"{}\" attr.type=\"{}\"/>\n",
+                    xml_escape(name),
+                    kind.type_name()
+                ));
+            }
+        }
+        header.push_str("<graph id=\"G\" edgedefault=\"directed\">\n");
+
+        let data =
+            |prefix: &str, keys: &BTreeMap<String, ValueKind>, props: &HashMap<String, Value>| {
+                keys.keys()
+                    .enumerate()
+                    .filter_map(|(index, name)| {
+                        let value = props.get(name).filter(|v| !v.is_null())?;
+                        Some(format!(
+                            "<data key=\"{prefix}{index}\">{}</data>",
+                            xml_escape(&value_text(value))
+                        ))
+                    })
+                    .collect::<String>()
+            };
+
+        let mut chunks = vec![header];
+        for node in &self.nodes {
+            chunks.push(format!(
+                "<node id=\"{}\"><data key=\"labels\">{}</data>{}</node>\n",
+                xml_escape(&node.element_id),
+                xml_escape(&node.labels.join(":")),
+                data("n", &node_keys, &node.properties)
+            ));
+        }
+        for edge in self.connected_edges() {
+            chunks.push(format!(
+                "<edge id=\"{}\" source=\"{}\" target=\"{}\"><data key=\"type\">{}</data>{}</edge>\n",
+                xml_escape(&edge.element_id),
+                xml_escape(&edge.start_node_element_id),
+                xml_escape(&edge.end_node_element_id),
+                xml_escape(&edge.rel_type),
+                data("e", &edge_keys, &edge.properties)
+            ));
+        }
+        chunks.push("</graph>\n</graphml>\n".to_string());
+        chunks
+    }
+
+    /// GEXF 1.3 document, one chunk per element.
+    fn to_gexf(&self) -> Vec<String> {
+        let node_keys = property_types(self.nodes.iter().map(|n| &n.properties));
+        let edge_keys = property_types(self.connected_edges().map(|e| &e.properties));
+
+        let mut header = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n\
+             <graph defaultedgetype=\"directed\" mode=\"static\">\n",
+        );
+        for (class, keys) in [("node", &node_keys), ("edge", &edge_keys)] {
+            header.push_str(&format!("<attributes class=\"{class}\">\n"));
+            for (index, (name, kind)) in keys.iter().enumerate() {
+                header.push_str(&format!(
+                    "<attribute id=\"{index}\" title=\"{}\" type=\"{}\"/>\n",
+                    xml_escape(name),
+                    kind.type_name()
+                ));
+            }
+            header.push_str("</attributes>\n");
+        }
+
+        let attvalues = |keys: &BTreeMap<String, ValueKind>, props: &HashMap<String, Value>| {
+            let values: String = keys
+                .keys()
+                .enumerate()
+                .filter_map(|(index, name)| {
+                    let value = props.get(name).filter(|v| !v.is_null())?;
+                    Some(format!(
+                        "<attvalue for=\"{index}\" value=\"{}\"/>",
+                        xml_escape(&value_text(value))
+                    ))
+                })
+                .collect();
+            if values.is_empty() {
+                values
+            } else {
+                format!("<attvalues>{values}</attvalues>")
+            }
+        };
+
+        let mut chunks = vec![header, "<nodes>\n".to_string()];
+        for node in &self.nodes {
+            chunks.push(format!(
+                "<node id=\"{}\" label=\"{}\">{}</node>\n",
+                xml_escape(&node.element_id),
+                xml_escape(&node.labels.join(":")),
+                attvalues(&node_keys, &node.properties)
+            ));
+        }
+        chunks.push("</nodes>\n<edges>\n".to_string());
+        for edge in self.connected_edges() {
+            chunks.push(format!(
+                "<edge id=\"{}\" source=\"{}\" target=\"{}\" label=\"{}\">{}</edge>\n",
+                xml_escape(&edge.element_id),
+                xml_escape(&edge.start_node_element_id),
+                xml_escape(&edge.end_node_element_id),
+                xml_escape(&edge.rel_type),
+                attvalues(&edge_keys, &edge.properties)
+            ));
+        }
+        chunks.push("</edges>\n</graph>\n</gexf>\n".to_string());
+        chunks
+    }
+}
+
+/// Declared type of a property column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    Boolean,
+    Long,
+    Double,
+    String,
+}
+
+impl ValueKind {
+    fn of(value: &Value) -> Option<Self> {
+        match value {
+            Value::Null => None,
+            Value::Bool(_) => Some(ValueKind::Boolean),
+            Value::Number(n) if n.is_i64() || n.is_u64() => Some(ValueKind::Long),
+            Value::Number(_) => Some(ValueKind::Double),
+            _ => Some(ValueKind::String),
+        }
+    }
+
+    /// The narrowest kind holding values of both kinds.
+    fn widen(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (ValueKind::Long, ValueKind::Double) | (ValueKind::Double, ValueKind::Long) => {
+                ValueKind::Double
+            }
+            _ => ValueKind::String,
+        }
+    }
+
+    /// Type name; GraphML and GEXF share these.
+    fn type_name(self) -> &'static str {
+        match self {
+            ValueKind::Boolean => "boolean",
+            ValueKind::Long => "long",
+            ValueKind::Double => "double",
+            ValueKind::String => "string",
+        }
+    }
+}
+
+/// Every property name with the kind of its values, in name order.
+fn property_types<'a>(
+    elements: impl Iterator<Item = &'a HashMap<String, Value>>,
+) -> BTreeMap<String, ValueKind> {
+    let mut keys: BTreeMap<String, ValueKind> = BTreeMap::new();
+    for properties in elements {
+        for (name, value) in properties {
+            let Some(kind) = ValueKind::of(value) else {
+                continue;
+            };
+            keys.entry(name.clone())
+                .and_modify(|k| *k = k.widen(kind))
+                .or_insert(kind);
+        }
+    }
+    keys
+}
+
+/// A property value as attribute text; lists and maps as JSON.
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn node(id: &str, label: &str, properties: Value) -> GraphNode {
+        GraphNode {
+            element_id: id.to_string(),
+            labels: vec![label.to_string()],
+            properties: serde_json::from_value(properties).unwrap(),
+        }
+    }
+
+    fn edge(id: &str, from: &str, to: &str) -> GraphEdge {
+        GraphEdge {
+            element_id: id.to_string(),
+            rel_type: "FOLLOWS".to_string(),
+            start_node_element_id: from.to_string(),
+            end_node_element_id: to.to_string(),
+            properties: serde_json::from_value(json!({"since": 2021})).unwrap(),
+        }
+    }
+
+    fn subgraph() -> Subgraph {
+        let mut graph = Subgraph::default();
+        graph.extend(GraphResult {
+            nodes: vec![
+                node("User:1", "User", json!({"name": "Alice & Bob", "score": 1})),
+                node("User:2", "User", json!({"name": "Carol", "score": 2.5})),
+            ],
+            edges: vec![edge("FOLLOWS:1->2", "User:1", "User:2")],
+        });
+        // Repeats are dropped; an edge to an unexported node is left out
+        graph.extend(GraphResult {
+            nodes: vec![node("User:1", "User", json!({"name": "Alice & Bob"}))],
+            edges: vec![
+                edge("FOLLOWS:1->2", "User:1", "User:2"),
+                edge("FOLLOWS:1->3", "User:1", "User:3"),
+            ],
+        });
+        graph
+    }
+
+    #[test]
+    fn statements_from_query_or_labels() {
+        let request = ExportRequest {
+            labels: vec!["User".to_string()],
+            rel_types: vec!["FOLLOWS".to_string()],
+            limit: Some(100),
+            ..ExportRequest::default()
+        };
+        assert_eq!(
+            export_statements(&request).unwrap(),
+            vec![
+                "MATCH (n:User) RETURN n LIMIT 100",
+                "MATCH (a)-[r:FOLLOWS]->(b) RETURN a, r, b LIMIT 100",
+            ]
+        );
+
+        let request = ExportRequest {
+            query: Some("MATCH (n) RETURN n".to_string()),
+            ..ExportRequest::default()
+        };
+        assert_eq!(
+            export_statements(&request).unwrap(),
+            vec!["MATCH (n) RETURN n"]
+        );
+
+        assert!(export_statements(&ExportRequest::default()).is_err());
+        let both = ExportRequest {
+            query: Some("MATCH (n) RETURN n".to_string()),
+            labels: vec!["User".to_string()],
+            ..ExportRequest::default()
+        };
+        assert!(export_statements(&both).is_err());
+        let injected = ExportRequest {
+            labels: vec!["User) DETACH DELETE (n".to_string()],
+            ..ExportRequest::default()
+        };
+        assert!(export_statements(&injected).is_err());
+
+        let params: ExportParams =
+            serde_json::from_value(json!({"labels": "User, Post", "format": "gexf"})).unwrap();
+        let request = ExportRequest::from(params);
+        assert_eq!(request.labels, vec!["User", "Post"]);
+        assert_eq!(request.format, ExportFormat::Gexf);
+    }
+
+    #[test]
+    fn graphml_document() {
+        let doc = subgraph().to_graphml().concat();
+        assert!(
+            doc.contains("<key id=\"n0\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>")
+        );
+        // Integer and float scores widen to double
+        assert!(
+            doc.contains("<key id=\"n1\" for=\"node\" attr.name=\"score\" attr.type=\"double\"/>")
+        );
+        assert!(
+            doc.contains("<key id=\"e0\" for=\"edge\" attr.name=\"since\" attr.type=\"long\"/>")
+        );
+        assert!(doc.contains(
+            "<node id=\"User:1\"><data key=\"labels\">User</data>\
+             <data key=\"n0\">Alice &amp; Bob</data><data key=\"n1\">1</data></node>"
+        ));
+        assert!(doc.contains(
+            "<edge id=\"FOLLOWS:1-&gt;2\" source=\"User:1\" target=\"User:2\">\
+             <data key=\"type\">FOLLOWS</data><data key=\"e0\">2021</data></edge>"
+        ));
+        assert_eq!(doc.matches("<node ").count(), 2);
+        assert_eq!(doc.matches("<edge ").count(), 1);
+        assert!(doc.ends_with("</graph>\n</graphml>\n"));
+    }
+
+    #[test]
+    fn gexf_document() {
+        let doc = subgraph().to_gexf().concat();
+        assert!(doc.contains("<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">"));
+        assert!(doc.contains(
+            "<attributes class=\"node\">\n<attribute id=\"0\" title=\"name\" type=\"string\"/>"
+        ));
+        assert!(doc.contains(
+            "<node id=\"User:2\" label=\"User\"><attvalues>\
+             <attvalue for=\"0\" value=\"Carol\"/><attvalue for=\"1\" value=\"2.5\"/>\
+             </attvalues></node>"
+        ));
+        assert!(doc.contains(
+            "<edge id=\"FOLLOWS:1-&gt;2\" source=\"User:1\" target=\"User:2\" label=\"FOLLOWS\">"
+        ));
+        assert_eq!(doc.matches("<edge ").count(), 1);
+        assert!(doc.ends_with("</edges>\n</graph>\n</gexf>\n"));
+    }
+}
@@ -0,0 +1,340 @@
+//! TLS termination for the HTTP and Bolt listeners.
+//!
+//! With `CLICKGRAPH_TLS_CERT` and `CLICKGRAPH_TLS_KEY` set, both listeners
+//! accept TLS connections only: HTTPS on the HTTP port, and `bolt+s://` /
+//! `neo4j+s://` (or `wss://` from Neo4j Browser) on the Bolt port.
+//! `CLICKGRAPH_TLS_CLIENT_CA` additionally requires every client to present
+//! a certificate issued by that CA (mutual TLS).
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use thiserror::Error;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_rustls::rustls::{
+    self,
+    pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
+    server::WebPkiClientVerifier,
+    RootCertStore,
+};
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+
+use crate::config::ServerConfig;
+
+/// How long a client may take to complete the TLS handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Error)]
+pub enum TlsError {
+    #[error("CLICKGRAPH_TLS_CERT and CLICKGRAPH_TLS_KEY must be set together")]
+    Incomplete,
+
+    #[error("failed to read {path}: {source}")]
+    Pem {
+        path: String,
+        source: rustls::pki_types::pem::Error,
+    },
+
+    #[error("no certificates in {0}")]
+    NoCertificates(String),
+
+    #[error("invalid client CA certificate: {0}")]
+    ClientCa(String),
+
+    #[error("invalid TLS configuration: {0}")]
+    Rustls(#[from] rustls::Error),
+}
+
+/// The acceptor both listeners wrap their connections in, or `None` when TLS
+/// isn't configured.
+pub fn acceptor(config: &ServerConfig) -> Result<Option<TlsAcceptor>, TlsError> {
+    match (&config.tls_cert_path, &config.tls_key_path) {
+        (None, None) => Ok(None),
+        (Some(cert), Some(key)) => {
+            build_acceptor(cert, key, config.tls_client_ca_path.as_deref()).map(Some)
+        }
+        _ => Err(TlsError::Incomplete),
+    }
+}
+
+/// Acceptor serving the PEM certificate chain at `cert_path` with the key at
+/// `key_path`; with `client_ca_path`, clients must present a certificate
+/// issued by one of the CAs in that PEM file.
+pub fn build_acceptor(
+    cert_path: &str,
+    key_path: &str,
+    client_ca_path: Option<&str>,
+) -> Result<TlsAcceptor, TlsError> {
+    let certs = read_certs(cert_path)?;
+    let key = PrivateKeyDer::from_pem_file(key_path).map_err(|source| TlsError::Pem {
+        path: key_path.to_string(),
+        source,
+    })?;
+
+    // Explicit provider: the default one is ambiguous when more than one
+    // crypto backend is compiled into the binary
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let builder = rustls::ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()?;
+    let builder = match client_ca_path {
+        Some(ca_path) => {
+            let mut roots = RootCertStore::empty();
+            for cert in read_certs(ca_path)? {
+                roots
+                    .add(cert)
+                    .map_err(|e| TlsError::ClientCa(e.to_string()))?;
+            }
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .map_err(|e| TlsError::ClientCa(e.to_string()))?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+    let server_config = builder.with_single_cert(certs, key)?;
+    Ok(TlsAcceptor::from(Arc::new(server_config)))
+}
+
+fn read_certs(path: &str) -> Result<Vec<CertificateDer<'static>>, TlsError> {
+    let pem_error = |source| TlsError::Pem {
+        path: path.to_string(),
+        source,
+    };
+    let certs = CertificateDer::pem_file_iter(path)
+        .map_err(pem_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(pem_error)?;
+    if certs.is_empty() {
+        return Err(TlsError::NoCertificates(path.to_string()));
+    }
+    Ok(certs)
+}
+
+/// Complete the TLS handshake of an accepted connection. Clients that don't
+/// speak TLS, present no valid certificate under mTLS, or stall are dropped.
+pub async fn handshake(
+    acceptor: &TlsAcceptor,
+    stream: TcpStream,
+    addr: SocketAddr,
+) -> Option<TlsStream<TcpStream>> {
+    match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+        Ok(Ok(stream)) => Some(stream),
+        Ok(Err(e)) => {
+            log::warn!("TLS handshake with {} failed: {}", addr, e);
+            None
+        }
+        Err(_) => {
+            log::warn!("TLS handshake with {} timed out", addr);
+            None
+        }
+    }
+}
+
+/// HTTPS listener for `axum::serve`. Handshakes run in their own tasks, so a
+/// slow client doesn't hold up the connections accepted after it.
+pub struct TlsListener {
+    incoming: mpsc::Receiver<(TlsStream<TcpStream>, SocketAddr)>,
+    local_addr: SocketAddr,
+}
+
+impl TlsListener {
+    pub fn new(listener: TcpListener, acceptor: TlsAcceptor) -> std::io::Result<Self> {
+        let local_addr = listener.local_addr()?;
+        let (tx, incoming) = mpsc::channel(64);
+        tokio::spawn(async move {
+            loop {
+                let (stream, addr) = match listener.accept().await {
+                    Ok(accepted) => accepted,
+                    Err(e) => {
+                        // Usually out of file descriptors; retrying at once
+                        // would spin
+                        log::error!("Failed to accept HTTPS connection: {}", e);
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        continue;
+                    }
+                };
+                if tx.is_closed() {
+                    break;
+                }
+                let acceptor = acceptor.clone();
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    if let Some(stream) = handshake(&acceptor, stream, addr).await {
+                        let _ = tx.send((stream, addr)).await;
+                    }
+                });
+            }
+        });
+        Ok(Self {
+            incoming,
+            local_addr,
+        })
+    }
+}
+
+impl axum::serve::Listener for TlsListener {
+    type Io = TlsStream<TcpStream>;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        match self.incoming.recv().await {
+            Some(accepted) => accepted,
+            // The accept loop only stops once this listener is gone
+            None => std::future::pending().await,
+        }
+    }
+
+    fn local_addr(&self) -> std::io::Result<Self::Addr> {
+        Ok(self.local_addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio_rustls::rustls::pki_types::ServerName;
+    use tokio_rustls::TlsConnector;
+
+    struct Pki {
+        dir: tempfile::TempDir,
+        server_cert: rcgen::Certificate,
+        ca: rcgen::Certificate,
+        ca_key: rcgen::KeyPair,
+    }
+
+    impl Pki {
+        fn new() -> Self {
+            let dir = tempfile::tempdir().unwrap();
+            let mut ca_params = rcgen::CertificateParams::new(Vec::new()).unwrap();
+            ca_params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+            let ca_key = rcgen::KeyPair::generate().unwrap();
+            let ca = ca_params.self_signed(&ca_key).unwrap();
+
+            let server_key = rcgen::KeyPair::generate().unwrap();
+            let server_cert = rcgen::CertificateParams::new(vec!["localhost".to_string()])
+                .unwrap()
+                .signed_by(&server_key, &ca, &ca_key)
+                .unwrap();
+
+            let pki = Pki {
+                dir,
+                server_cert,
+                ca,
+                ca_key,
+            };
+            pki.write("server.pem", &pki.server_cert.pem());
+            pki.write("server.key", &server_key.serialize_pem());
+            pki.write("ca.pem", &pki.ca.pem());
+            pki
+        }
+
+        fn path(&self, name: &str) -> String {
+            self.dir.path().join(name).to_string_lossy().into_owned()
+        }
+
+        fn write(&self, name: &str, contents: &str) {
+            std::fs::File::create(self.path(name))
+                .unwrap()
+                .write_all(contents.as_bytes())
+                .unwrap();
+        }
+
+        fn connector(&self, with_client_cert: bool) -> TlsConnector {
+            let mut roots = RootCertStore::empty();
+            roots.add(self.ca.der().clone()).unwrap();
+            let provider = Arc::new(rustls::crypto::ring::default_provider());
+            let builder = rustls::ClientConfig::builder_with_provider(provider)
+                .with_safe_default_protocol_versions()
+                .unwrap()
+                .with_root_certificates(roots);
+            let config = if with_client_cert {
+                let key = rcgen::KeyPair::generate().unwrap();
+                let cert = rcgen::CertificateParams::new(vec!["client".to_string()])
+                    .unwrap()
+                    .signed_by(&key, &self.ca, &self.ca_key)
+                    .unwrap();
+                builder
+                    .with_client_auth_cert(
+                        vec![cert.der().clone()],
+                        PrivateKeyDer::try_from(key.serialize_der()).unwrap(),
+                    )
+                    .unwrap()
+            } else {
+                builder.with_no_client_auth()
+            };
+            TlsConnector::from(Arc::new(config))
+        }
+    }
+
+    /// Echo the first message of one TLS connection through `acceptor`;
+    /// returns what the client read back, or `None` if TLS failed.
+    async fn echo_once(acceptor: TlsAcceptor, connector: TlsConnector) -> Option<Vec<u8>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, addr) = listener.accept().await.unwrap();
+            if let Some(mut stream) = handshake(&acceptor, stream, addr).await {
+                let mut buf = [0u8; 4];
+                if stream.read_exact(&mut buf).await.is_ok() {
+                    let _ = stream.write_all(&buf).await;
+                    let _ = stream.flush().await;
+                }
+            }
+        });
+
+        let tcp = TcpStream::connect(addr).await.unwrap();
+        let name = ServerName::try_from("localhost").unwrap();
+        let mut stream = connector.connect(name, tcp).await.ok()?;
+        stream.write_all(&[0x60, 0x60, 0xB0, 0x17]).await.ok()?;
+        let mut buf = [0u8; 4];
+        stream.read_exact(&mut buf).await.ok()?;
+        Some(buf.to_vec())
+    }
+
+    #[test]
+    fn cert_and_key_go_together() {
+        let config = ServerConfig {
+            tls_cert_path: Some("server.pem".to_string()),
+            ..ServerConfig::default()
+        };
+        assert!(matches!(acceptor(&config), Err(TlsError::Incomplete)));
+        assert!(acceptor(&ServerConfig::default()).unwrap().is_none());
+    }
+
+    #[test]
+    fn unreadable_files_are_reported_by_path() {
+        let err = build_acceptor("/nonexistent/server.pem", "/nonexistent/server.key", None)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("/nonexistent/server.pem"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn serves_tls() {
+        let pki = Pki::new();
+        let acceptor =
+            build_acceptor(&pki.path("server.pem"), &pki.path("server.key"), None).unwrap();
+        let echoed = echo_once(acceptor, pki.connector(false)).await;
+        assert_eq!(echoed, Some(vec![0x60, 0x60, 0xB0, 0x17]));
+    }
+
+    #[tokio::test]
+    async fn mutual_tls_requires_a_client_certificate() {
+        let pki = Pki::new();
+        let acceptor = || {
+            build_acceptor(
+                &pki.path("server.pem"),
+                &pki.path("server.key"),
+                Some(&pki.path("ca.pem")),
+            )
+            .unwrap()
+        };
+        assert!(echo_once(acceptor(), pki.connector(true)).await.is_some());
+        assert!(echo_once(acceptor(), pki.connector(false)).await.is_none());
+    }
+}
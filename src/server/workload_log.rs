@@ -0,0 +1,114 @@
+//! Workload log — the traversals of recent successful read queries.
+//!
+//! Opt-in (`CLICKGRAPH_WORKLOAD_LOG_CAPACITY`, default 0 = off). Each entry
+//! is the [`traversal profile`](crate::query_planner::traversal_profile) of
+//! one query, tagged with its schema and wall time; no query text or
+//! parameter values are kept. `/schemas/{name}/denormalization-advice` feeds
+//! the entries of one schema to the denormalization advisor.
+//!
+//! Like the slow-query ring in `metrics.rs`, it is a bounded `VecDeque`
+//! behind a `Mutex`, oldest entries dropped first. Profiling re-parses and
+//! re-plans the query, so it only runs when the log is on.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::graph_catalog::denormalization_advisor::WorkloadQuery;
+use crate::graph_catalog::graph_schema::GraphSchema;
+use crate::query_planner::traversal_profile::profile_query;
+
+pub struct WorkloadLog {
+    entries: Mutex<VecDeque<(String, WorkloadQuery)>>,
+    capacity: usize,
+}
+
+impl WorkloadLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(VecDeque::with_capacity(capacity.min(1024))),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Profile `cypher` against `schema` and log its traversals. Queries
+    /// without a traversal are not logged.
+    pub fn record(&self, schema_name: &str, schema: &GraphSchema, cypher: &str, total_ms: f64) {
+        let traversals = profile_query(cypher, schema);
+        if traversals.is_empty() {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            if entries.len() >= self.capacity {
+                entries.pop_front();
+            }
+            entries.push_back((
+                schema_name.to_string(),
+                WorkloadQuery {
+                    traversals,
+                    total_ms,
+                },
+            ));
+        }
+    }
+
+    /// The logged queries of one schema, oldest first.
+    pub fn queries(&self, schema_name: &str) -> Vec<WorkloadQuery> {
+        self.entries
+            .lock()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|(name, _)| name == schema_name)
+                    .map(|(_, query)| query.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_catalog::config::GraphSchemaConfig;
+
+    #[test]
+    fn keeps_the_most_recent_traversing_queries_per_schema() {
+        let schema = GraphSchemaConfig::from_yaml_str(
+            r#"
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: id
+      property_mappings:
+        id: id
+        name: name
+  edges:
+    - type: FOLLOWS
+      database: test
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings: {}
+"#,
+        )
+        .expect("valid yaml")
+        .to_graph_schema()
+        .expect("valid schema");
+
+        let log = WorkloadLog::new(2);
+        let hop = "MATCH (a:User)-[:FOLLOWS]->(b:User) RETURN b.name";
+        log.record("social", &schema, hop, 1.0);
+        log.record("social", &schema, "MATCH (a:User) RETURN a.name", 2.0);
+        log.record("other", &schema, hop, 3.0);
+        log.record("social", &schema, hop, 4.0);
+
+        let queries = log.queries("social");
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries[0].total_ms, 4.0);
+        assert_eq!(log.queries("other").len(), 1);
+    }
+}
@@ -160,6 +160,21 @@ pub fn try_render_percentile(fn_name: &str, args_sql: &[String]) -> Option<Strin
     Some(mapper.percentile_aggregate(&args_sql[0], &args_sql[1], continuous))
 }
 
+/// `collect(DISTINCT x)` as the dialect's de-duplicating list aggregate
+/// (CH `groupUniqArray(x)`, Spark `collect_set(x)`), given the SQL of `x`.
+/// Other aggregates keep the SQL `DISTINCT` modifier (`count(DISTINCT x)`,
+/// which ClickHouse runs as `countDistinct`).
+pub fn try_render_collect_distinct(
+    fn_name: &str,
+    distinct_arg_sql: Option<String>,
+) -> Option<String> {
+    if !fn_name.eq_ignore_ascii_case("collect") {
+        return None;
+    }
+    let mapper = crate::sql_generator::function_mapper::current_function_mapper();
+    distinct_arg_sql.map(|arg| format!("{}({})", mapper.collect_set(), arg))
+}
+
 #[cfg(test)]
 mod dialect_function_name_tests {
     use super::dialect_function_name;
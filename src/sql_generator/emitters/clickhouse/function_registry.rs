@@ -109,7 +109,9 @@ lazy_static::lazy_static! {
             arg_transform: Some(|args| {
                 if args.is_empty() {
                     // datetime() with no args returns current timestamp
-                    vec!["now64(3)".to_string()]
+                    vec![super::function_translator::current_datetime_sql(
+                        crate::server::query_context::get_current_dialect(),
+                    )]
                 } else {
                     // datetime(string) parses ISO8601/various formats to DateTime64
                     vec![args[0].clone(), "3".to_string()] // 3 = millisecond precision
@@ -129,8 +131,9 @@ lazy_static::lazy_static! {
                 use crate::sql_generator::SqlDialect;
                 let databricks = matches!(get_current_dialect(), SqlDialect::Databricks);
                 if args.is_empty() {
-                    let now = if databricks { "current_timestamp()" } else { "now64(3)" };
-                    return vec![now.to_string()];
+                    return vec![super::function_translator::current_datetime_sql(
+                        get_current_dialect(),
+                    )];
                 }
                 let wrapped = if databricks {
                     format!("to_timestamp({})", args[0])
@@ -149,14 +152,9 @@ lazy_static::lazy_static! {
             databricks_name: Some("to_date"),
             arg_transform: Some(|args| {
                 if args.is_empty() {
-                    use crate::server::query_context::get_current_dialect;
-                    use crate::sql_generator::SqlDialect;
-                    let now = if matches!(get_current_dialect(), SqlDialect::Databricks) {
-                        "current_date()"
-                    } else {
-                        "today()"
-                    };
-                    vec![now.to_string()]
+                    vec![super::function_translator::current_date_sql(
+                        crate::server::query_context::get_current_dialect(),
+                    )]
                 } else {
                     vec![args[0].clone()]
                 }
@@ -170,7 +168,9 @@ lazy_static::lazy_static! {
             databricks_name: None,
             arg_transform: Some(|args| {
                 if args.is_empty() {
-                    vec!["now()".to_string()]
+                    vec![super::function_translator::current_timestamp_sql(
+                        crate::server::query_context::get_current_dialect(),
+                    )]
                 } else {
                     vec![args[0].clone()]
                 }
@@ -346,16 +346,14 @@ lazy_static::lazy_static! {
             arg_transform: None,
         });
 
-        // rand() -> rand() / 4294967295.0 (normalize to 0.0-1.0)
+        // rand() -> rand() / 4294967296.0 (normalize to 0.0-1.0). Rendered
+        // whole by function_translator::random_sql, which also seeds it in
+        // test mode; the entry only marks rand() as supported.
         m.insert("rand", FunctionMapping {
             neo4j_name: "rand",
             clickhouse_name: "rand",
             databricks_name: None,
-            arg_transform: Some(|_args| {
-                // Neo4j rand() returns 0.0-1.0
-                // ClickHouse rand() returns UInt32
-                vec!["rand() / 4294967295.0".to_string()]
-            }),
+            arg_transform: None,
         });
 
         // sign() -> sign() [1:1 mapping]
@@ -805,17 +803,20 @@ lazy_static::lazy_static! {
         // duration({days: 5}) -> toIntervalDay(5)
         // This requires special handling for map arguments
 
-        // localdatetime() -> now() or parseDateTime64BestEffort()
+        // localdatetime() -> toDateTime64(now64(3) or parseDateTime64BestEffort(), 3)
         m.insert("localdatetime", FunctionMapping {
             neo4j_name: "localdatetime",
-            clickhouse_name: "now64",
+            clickhouse_name: "toDateTime64",
             databricks_name: None,
             arg_transform: Some(|args| {
-                if args.is_empty() {
-                    vec!["3".to_string()] // millisecond precision
+                let value = if args.is_empty() {
+                    super::function_translator::current_datetime_sql(
+                        crate::server::query_context::get_current_dialect(),
+                    )
                 } else {
-                    vec![format!("parseDateTime64BestEffort({}, 3)", args[0])]
-                }
+                    format!("parseDateTime64BestEffort({}, 3)", args[0])
+                };
+                vec![value, "3".to_string()] // millisecond precision
             }),
         });
 
@@ -826,7 +827,9 @@ lazy_static::lazy_static! {
             databricks_name: None,
             arg_transform: Some(|args| {
                 if args.is_empty() {
-                    vec!["now()".to_string()]
+                    vec![super::function_translator::current_timestamp_sql(
+                        crate::server::query_context::get_current_dialect(),
+                    )]
                 } else {
                     args.to_vec()
                 }
@@ -4,7 +4,7 @@ use super::to_sql::ToSql;
 /// Neo4j Function Translator
 ///
 /// Translates Neo4j function calls to ClickHouse SQL equivalents
-use crate::query_planner::logical_expr::{LogicalExpr, ScalarFnCall};
+use crate::query_planner::logical_expr::{Literal, LogicalExpr, ScalarFnCall};
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
@@ -305,6 +305,19 @@ pub fn translate_scalar_function(
         // Fall through to normal function_registry handling
     }
 
+    // datetime() with no args is the current timestamp, not a parse of one
+    if fn_name_lower == "datetime" && fn_call.args.is_empty() {
+        return Ok(current_datetime_sql(
+            crate::server::query_context::get_current_dialect(),
+        ));
+    }
+
+    if fn_name_lower == "rand" && fn_call.args.is_empty() {
+        return Ok(random_sql(
+            crate::server::query_context::get_current_dialect(),
+        ));
+    }
+
     // Special handling for duration() with map argument
     // Neo4j: duration({days: 5, hours: 2}) -> ClickHouse: (toIntervalDay(5) + toIntervalHour(2))
     if fn_name_lower == "duration" {
@@ -397,16 +410,11 @@ pub fn translate_scalar_function(
 /// months)` — both accept fractional/expression args, so sub-second precision
 /// maps onto the fractional `secs` field.
 ///
-/// Limitations (both shared with the consuming `render_interval_arithmetic`):
-/// - Spark rejects adding a year-month interval to a day-time interval, so a
-///   `duration({months: m, days: d})` that mixes the two families produces SQL
-///   that errors at execution on Databricks. Single-family and single-unit
-///   durations are the validated, supported cases.
-/// - Only single-level interval arithmetic is supported: `x + duration(..)`.
-///   Nested forms like `x + duration(..) + duration(..)` are mis-handled on
-///   both dialects because the consumer detects the interval operand by
-///   substring (`toInterval` / `make_*_interval`) and the inner result string
-///   still contains that marker. Pre-existing for ClickHouse; not addressed here.
+/// Limitation (shared with the consuming `render_interval_arithmetic`): Spark
+/// rejects adding a year-month interval to a day-time interval, so a
+/// `duration({months: m, days: d})` that mixes the two families produces SQL
+/// that errors at execution on Databricks. Single-family and single-unit
+/// durations are the validated, supported cases.
 pub(crate) fn interval_expr_for_unit(
     unit_lower: &str,
     value_sql: &str,
@@ -453,6 +461,142 @@ pub(crate) fn interval_expr_for_unit(
     })
 }
 
+/// The current timestamp for a zero-argument `datetime()`, in the dialect's
+/// native millisecond-precision type. Test mode renders its frozen instant.
+pub(crate) fn current_datetime_sql(dialect: crate::sql_generator::SqlDialect) -> String {
+    let frozen = crate::server::query_context::get_deterministic_mode().map(|m| m.frozen_time);
+    match (dialect, frozen) {
+        (crate::sql_generator::SqlDialect::Databricks, Some(t)) => {
+            format!("to_timestamp('{}')", t.format("%Y-%m-%d %H:%M:%S%.3f"))
+        }
+        (crate::sql_generator::SqlDialect::Databricks, None) => "current_timestamp()".to_string(),
+        (_, Some(t)) => format!(
+            "toDateTime64('{}', 3, 'UTC')",
+            t.format("%Y-%m-%d %H:%M:%S%.3f")
+        ),
+        (_, None) => "now64(3)".to_string(),
+    }
+}
+
+/// The current timestamp at second precision (`timestamp()`, `localtime()`).
+pub(crate) fn current_timestamp_sql(dialect: crate::sql_generator::SqlDialect) -> String {
+    let frozen = crate::server::query_context::get_deterministic_mode().map(|m| m.frozen_time);
+    match (dialect, frozen) {
+        (crate::sql_generator::SqlDialect::Databricks, _) => current_datetime_sql(dialect),
+        (_, Some(t)) => format!("toDateTime('{}', 'UTC')", t.format("%Y-%m-%d %H:%M:%S")),
+        (_, None) => "now()".to_string(),
+    }
+}
+
+/// The current date for a zero-argument `date()`.
+pub(crate) fn current_date_sql(dialect: crate::sql_generator::SqlDialect) -> String {
+    let frozen = crate::server::query_context::get_deterministic_mode().map(|m| m.frozen_time);
+    match (dialect, frozen) {
+        (crate::sql_generator::SqlDialect::Databricks, Some(t)) => {
+            format!("to_date('{}')", t.format("%Y-%m-%d"))
+        }
+        (crate::sql_generator::SqlDialect::Databricks, None) => "current_date()".to_string(),
+        (_, Some(t)) => format!("toDate('{}')", t.format("%Y-%m-%d")),
+        (_, None) => "today()".to_string(),
+    }
+}
+
+/// `rand()`: a float in [0, 1). ClickHouse's own `rand()` is a UInt32, so it
+/// is scaled. In test mode each call site hashes the seed, its position in
+/// the query and the row number, which repeats as long as the rows arrive in
+/// the same order (e.g. `max_threads = 1`).
+pub(crate) fn random_sql(dialect: crate::sql_generator::SqlDialect) -> String {
+    use crate::server::query_context::{get_deterministic_mode, next_deterministic_counter};
+    let seeded = get_deterministic_mode()
+        .zip(next_deterministic_counter(|c| &mut c.rand_calls))
+        .map(|(m, call)| (m.seed, call));
+    match (dialect, seeded) {
+        (crate::sql_generator::SqlDialect::Databricks, Some((seed, call))) => {
+            format!("rand({})", seed.wrapping_add(call as u64))
+        }
+        (crate::sql_generator::SqlDialect::Databricks, None) => "rand()".to_string(),
+        (_, Some((seed, call))) => format!(
+            "(cityHash64({}, {}, rowNumberInAllBlocks()) % 4294967296) / 4294967296.0",
+            seed, call
+        ),
+        (_, None) => "(rand() / 4294967296.0)".to_string(),
+    }
+}
+
+/// Parse an ISO 8601 duration string (`P30D`, `PT1H30M`, `P2W`,
+/// `P1Y2M3DT4H5M6.5S`) into `(unit, value)` pairs spelled the way
+/// [`interval_expr_for_unit`] expects. `M` means months before the `T` and
+/// minutes after it. Returns `None` for anything that is not a well-formed
+/// duration, including one without components (`P`, `PT`).
+pub(crate) fn parse_iso8601_duration(text: &str) -> Option<Vec<(&'static str, String)>> {
+    let upper = text.trim().to_ascii_uppercase();
+    let body = upper.strip_prefix('P')?;
+    let (date_part, time_part) = match body.split_once('T') {
+        Some((date, time)) if !time.is_empty() => (date, Some(time)),
+        Some(_) => return None,
+        None => (body, None),
+    };
+
+    fn components(
+        part: &str,
+        units: &[(char, &'static str)],
+        out: &mut Vec<(&'static str, String)>,
+    ) -> Option<()> {
+        let mut remaining = units;
+        let mut value = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() || c == '.' || (c == '-' && value.is_empty()) {
+                value.push(c);
+                continue;
+            }
+            // Designators must appear at most once and in canonical order.
+            let idx = remaining.iter().position(|(d, _)| *d == c)?;
+            value.parse::<f64>().ok()?;
+            out.push((remaining[idx].1, std::mem::take(&mut value)));
+            remaining = &remaining[idx + 1..];
+        }
+        value.is_empty().then_some(())
+    }
+
+    let mut parts = Vec::new();
+    components(
+        date_part,
+        &[
+            ('Y', "years"),
+            ('M', "months"),
+            ('W', "weeks"),
+            ('D', "days"),
+        ],
+        &mut parts,
+    )?;
+    if let Some(time) = time_part {
+        components(
+            time,
+            &[('H', "hours"), ('M', "minutes"), ('S', "seconds")],
+            &mut parts,
+        )?;
+    }
+    (!parts.is_empty()).then_some(parts)
+}
+
+/// Render an ISO 8601 duration string (see [`parse_iso8601_duration`]) as the
+/// active dialect's interval expression, combined the same way as the map
+/// form: a single unit renders bare, several are summed in parentheses.
+pub(crate) fn iso8601_duration_interval(
+    text: &str,
+    dialect: crate::sql_generator::SqlDialect,
+) -> Option<String> {
+    let parts: Vec<String> = parse_iso8601_duration(text)?
+        .iter()
+        .map(|(unit, value)| interval_expr_for_unit(unit, value, dialect))
+        .collect::<Option<_>>()?;
+    Some(if parts.len() == 1 {
+        parts[0].clone()
+    } else {
+        format!("({})", parts.join(" + "))
+    })
+}
+
 /// Translate a Neo4j `duration({...})` map into the active dialect's combined
 /// interval expression, delegating the per-unit spelling to
 /// [`interval_expr_for_unit`].
@@ -464,13 +608,15 @@ pub(crate) fn interval_expr_for_unit(
 ///   duration({days: 5}) -> toIntervalDay(5)
 ///   duration({days: 5, hours: 2}) -> (toIntervalDay(5) + toIntervalHour(2))
 ///   duration({months: 1, days: 15}) -> (toIntervalMonth(1) + toIntervalDay(15))
+///   duration('P30D') -> toIntervalDay(30)
 fn translate_duration_function(
     fn_call: &ScalarFnCall,
 ) -> Result<String, ClickhouseQueryGeneratorError> {
-    // duration() expects exactly one argument which should be a map literal
+    // duration() expects exactly one argument: a map or an ISO 8601 string
     if fn_call.args.len() != 1 {
         return Err(ClickhouseQueryGeneratorError::SchemaError(
-            "duration() requires exactly one map argument, e.g., duration({days: 5})".to_string(),
+            "duration() requires exactly one argument, e.g., duration({days: 5}) or duration('P5D')"
+                .to_string(),
         ));
     }
 
@@ -510,19 +656,22 @@ fn translate_duration_function(
                 Ok(format!("({})", parts.join(" + ")))
             }
         }
+        LogicalExpr::Literal(Literal::String(text)) => {
+            let dialect = crate::server::query_context::get_current_dialect();
+            iso8601_duration_interval(text, dialect).ok_or_else(|| {
+                ClickhouseQueryGeneratorError::SchemaError(format!(
+                    "Invalid ISO 8601 duration '{}', expected e.g. 'P30D', 'PT1H30M' or 'P1Y2M3DT4H'",
+                    text
+                ))
+            })
+        }
         _ => {
-            // If not a map literal, try to use it as a duration string (e.g., "P1D")
-            // This is an ISO 8601 duration format that Neo4j also supports
+            // Only literal strings can be parsed at translation time; a computed
+            // string would need per-row parsing that ClickHouse doesn't offer.
             let arg_sql = fn_call.args[0].to_sql()?;
-            log::warn!(
-                "duration() called with non-map argument: {}. This may not work correctly in ClickHouse.",
-                arg_sql
-            );
-            // Attempt to parse as ISO 8601 duration - ClickHouse doesn't natively support this,
-            // but we could potentially support it via string parsing
             Err(ClickhouseQueryGeneratorError::SchemaError(format!(
-                "duration() requires a map argument like duration({{days: 5}}), got: {}. \
-                 ISO 8601 duration strings are not yet supported.",
+                "duration() requires a map like duration({{days: 5}}) or a literal ISO 8601 \
+                 string like duration('P5D'), got: {}",
                 arg_sql
             )))
         }
@@ -580,7 +729,6 @@ pub fn get_supported_functions() -> Vec<&'static str> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::query_planner::logical_expr::{Literal, LogicalExpr};
 
     #[test]
     fn interval_expr_for_unit_clickhouse_spellings() {
@@ -955,6 +1103,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_iso8601_duration_components() {
+        assert_eq!(
+            parse_iso8601_duration("P30D").unwrap(),
+            vec![("days", "30".to_string())]
+        );
+        assert_eq!(
+            parse_iso8601_duration("pt1h30m").unwrap(),
+            vec![("hours", "1".to_string()), ("minutes", "30".to_string())]
+        );
+        // M is months before T and minutes after it
+        assert_eq!(
+            parse_iso8601_duration("P1Y2M3DT4H5M6.5S").unwrap(),
+            vec![
+                ("years", "1".to_string()),
+                ("months", "2".to_string()),
+                ("days", "3".to_string()),
+                ("hours", "4".to_string()),
+                ("minutes", "5".to_string()),
+                ("seconds", "6.5".to_string()),
+            ]
+        );
+        for bad in [
+            "", "30D", "P", "PT", "P1DT", "P1D2Y", "P1H", "PT1D", "P1.2.3D", "P5",
+        ] {
+            assert!(parse_iso8601_duration(bad).is_none(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_translate_duration_iso8601_string() {
+        let fn_call = ScalarFnCall {
+            name: "duration".to_string(),
+            args: vec![LogicalExpr::Literal(Literal::String("P2W".to_string()))],
+        };
+        assert_eq!(
+            translate_scalar_function(&fn_call).unwrap(),
+            "toIntervalWeek(2)"
+        );
+
+        let fn_call = ScalarFnCall {
+            name: "duration".to_string(),
+            args: vec![LogicalExpr::Literal(Literal::String("PT1H30M".to_string()))],
+        };
+        assert_eq!(
+            translate_scalar_function(&fn_call).unwrap(),
+            "(toIntervalHour(1) + toIntervalMinute(30))"
+        );
+
+        let fn_call = ScalarFnCall {
+            name: "duration".to_string(),
+            args: vec![LogicalExpr::Literal(Literal::String(
+                "thirty days".to_string(),
+            ))],
+        };
+        assert!(translate_scalar_function(&fn_call).is_err());
+    }
+
+    #[test]
+    fn test_translate_datetime_without_args_is_now() {
+        let fn_call = ScalarFnCall {
+            name: "datetime".to_string(),
+            args: vec![],
+        };
+        assert_eq!(translate_scalar_function(&fn_call).unwrap(), "now64(3)");
+    }
+
     #[test]
     fn test_translate_duration_invalid_args() {
         use crate::query_planner::logical_expr::Literal;
@@ -6,13 +6,10 @@
 //! the INSERT and let chdb fill it.
 //!
 //! This module formalises the contract so other strategies (e.g.,
-//! Snowflake) can plug in without changing the SQL generator. Phase 3 will
-//! add an `id_generation: Option<IdStrategy>` field to `NodeSchema` and
-//! wire `column_for_insert` into the INSERT builder.
-//!
-//! For Phase 2, this module is the contract; the only strategy actually
-//! exercised by the renderer today is `Uuid` (the default), which produces
-//! "omit the column from INSERT".
+//! Snowflake) can plug in without changing the SQL generator. The node
+//! INSERT builder (`write_plan_builder`) consults `auto_id_decision` with
+//! the schema's `id_generation` (default `Uuid`, "omit the column from
+//! INSERT") whenever a CREATE pattern leaves the ID out.
 
 use serde::{Deserialize, Serialize};
 
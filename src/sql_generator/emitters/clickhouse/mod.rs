@@ -51,7 +51,12 @@ pub use variable_length_cte::{NodeProperty, VariableLengthCteGenerator, WeightCt
 // }
 
 pub fn generate_sql(plan: RenderPlan, max_cte_depth: u32) -> String {
-    to_sql_query::render_plan_to_sql(plan, max_cte_depth)
+    let sql = to_sql_query::render_plan_to_sql(plan, max_cte_depth);
+    // Aggregated edge tables exist only in the schema: read their subqueries
+    match crate::server::query_context::get_current_schema() {
+        Some(schema) => schema.expand_edge_aggregates(sql),
+        None => sql,
+    }
 }
 
 /// Convert a Cypher query string directly to ClickHouse SQL.
@@ -85,7 +90,7 @@ pub fn cypher_to_sql(
         .to_render_plan_with_ctx(schema, Some(&plan_ctx), None)
         .map_err(|e| format!("Render error: {}", e))?;
 
-    Ok(generate_sql(render_plan, max_cte_depth))
+    Ok(schema.expand_edge_aggregates(generate_sql(render_plan, max_cte_depth)))
 }
 
 /// Convert a Cypher query to ClickHouse SQL after clearing all write
@@ -115,11 +120,15 @@ pub fn cypher_to_sql_read_only(
     } = statement
     {
         query.create_clause = None;
+        query.foreach_clause = None;
+        query.merge_clause = None;
         query.set_clause = None;
         query.remove_clause = None;
         query.delete_clause = None;
         for u in union_clauses.iter_mut() {
             u.query.create_clause = None;
+            u.query.foreach_clause = None;
+            u.query.merge_clause = None;
             u.query.set_clause = None;
             u.query.remove_clause = None;
             u.query.delete_clause = None;
@@ -134,7 +143,7 @@ pub fn cypher_to_sql_read_only(
         .to_render_plan_with_ctx(schema, Some(&plan_ctx), None)
         .map_err(|e| format!("Render error: {}", e))?;
 
-    Ok(generate_sql(render_plan, max_cte_depth))
+    Ok(schema.expand_edge_aggregates(generate_sql(render_plan, max_cte_depth)))
 }
 
 /// Convert a Cypher query string to ClickHouse SQL, also returning the
@@ -168,6 +177,6 @@ pub fn cypher_to_sql_with_metadata(
         .to_render_plan_with_ctx(schema, Some(&plan_ctx), None)
         .map_err(|e| format!("Render error: {}", e))?;
 
-    let sql = generate_sql(render_plan, max_cte_depth);
+    let sql = schema.expand_edge_aggregates(generate_sql(render_plan, max_cte_depth));
     Ok((sql, logical_plan, plan_ctx))
 }
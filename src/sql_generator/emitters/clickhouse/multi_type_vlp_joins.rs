@@ -31,7 +31,7 @@ use crate::graph_catalog::config::Identifier;
 use crate::graph_catalog::expression_parser::PropertyValue;
 use crate::graph_catalog::graph_schema::GraphSchema;
 use crate::query_planner::analyzer::multi_type_vlp_expansion::{
-    enumerate_vlp_paths, enumerate_vlp_paths_undirected, PathEnumeration,
+    enumerate_vlp_paths, enumerate_vlp_paths_undirected, PathEnumeration, PathHop,
 };
 use crate::query_planner::join_context::{VLP_END_ID_COLUMN, VLP_START_ID_COLUMN};
 use crate::query_planner::logical_plan::VariableLengthSpec;
@@ -52,6 +52,25 @@ fn prop_alias_collides_with_reserved_id(prefix: &str, safe_alias: &str) -> bool
     aliased == VLP_END_ID_COLUMN || aliased == VLP_START_ID_COLUMN
 }
 
+/// Prefix of the per-branch relationship property columns projected for a
+/// fixed single hop over several relationship types (`rel_prop_since`).
+pub const REL_PROPERTY_COLUMN_PREFIX: &str = "rel_prop_";
+
+/// CTE column carrying relationship property `property` of a multi-type
+/// single hop.
+pub fn rel_property_column(property: &str) -> String {
+    format!("{}{}", REL_PROPERTY_COLUMN_PREFIX, property)
+}
+
+/// One UNION branch: a path, plus every relationship type each hop covers.
+/// Types of a polymorphic edge table that connect the same node types share
+/// one branch, filtered with `type_column IN (...)`.
+#[derive(Debug, Clone)]
+struct PathBranch {
+    path: PathEnumeration,
+    hop_types: Vec<Vec<String>>,
+}
+
 /// Property projection for heterogeneous node types
 #[derive(Debug, Clone)]
 pub struct PropertyProjection {
@@ -97,6 +116,8 @@ pub struct MultiTypeVlpJoinGenerator<'a> {
     // Node information
     start_alias: String, // e.g., "u"
     end_alias: String,   // e.g., "x"
+    // Cypher alias of the relationship (e.g., "r"), when the pattern names it
+    rel_alias: Option<String>,
 
     // Whether the pattern is undirected (includes both incoming and outgoing edges)
     undirected: bool,
@@ -165,6 +186,7 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
             max_hops,
             start_alias,
             end_alias,
+            rel_alias: None,
             undirected,
             start_filters,
             end_filters,
@@ -311,15 +333,122 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
         };
 
         let empty_str_arr = mapper.empty_string_array_cast();
+        let rel_property_columns: String = self
+            .required_rel_properties()
+            .iter()
+            .map(|p| format!(", NULL AS {}", rel_property_column(p)))
+            .collect();
         format!(
             "SELECT '' AS end_type, {end_id_sql} AS end_id, {start_id_sql} AS start_id, '' AS start_type, \
              '{{}}' AS end_properties, '{{}}' AS start_properties, \
              0 AS hop_count, {empty_str_arr} AS path_relationships, \
              {empty_str_arr} AS rel_properties, \
-             {empty_str_arr} AS path_nodes WHERE 0 = 1"
+             {empty_str_arr} AS path_nodes{rel_property_columns} WHERE 0 = 1"
         )
     }
 
+    /// Set the Cypher alias of the relationship, so a fixed single hop can
+    /// project the relationship properties the query reads (`r.since`).
+    pub fn with_rel_alias(mut self, rel_alias: String) -> Self {
+        self.rel_alias = Some(rel_alias);
+        self
+    }
+
+    /// Relationship properties to project as `rel_prop_*` columns, sorted.
+    ///
+    /// Only a fixed single hop binds the relationship variable to one edge;
+    /// for longer paths it is a list and has no properties of its own.
+    fn required_rel_properties(&self) -> Vec<String> {
+        if self.min_hops != 1 || self.max_hops != 1 {
+            return Vec::new();
+        }
+        let (Some(alias), Some(plan_ctx)) = (&self.rel_alias, &self.plan_ctx) else {
+            return Vec::new();
+        };
+        let Some(reqs) = plan_ctx.get_property_requirements() else {
+            return Vec::new();
+        };
+
+        let mut properties: Vec<String> = if reqs.requires_all(alias) {
+            self.rel_types
+                .iter()
+                .filter_map(|t| self.schema.get_rel_schema(t).ok())
+                .flat_map(|rel| rel.property_mappings.keys().cloned())
+                .collect()
+        } else {
+            reqs.get_requirements(alias)
+                .map(|props| props.iter().cloned().collect())
+                .unwrap_or_default()
+        };
+        properties.sort();
+        properties.dedup();
+        properties
+    }
+
+    /// Schema identity of a polymorphic hop: the shared table and the columns
+    /// that tell its types apart. `None` for tables holding a single type.
+    fn polymorphic_hop_key(&self, hop: &PathHop) -> Option<String> {
+        let (schema_from, schema_to) = if hop.reversed {
+            (&hop.to_node_type, &hop.from_node_type)
+        } else {
+            (&hop.from_node_type, &hop.to_node_type)
+        };
+        let rel = self
+            .schema
+            .get_rel_schema_with_nodes(&hop.rel_type, Some(schema_from), Some(schema_to))
+            .ok()?;
+        rel.type_discriminator_column()?;
+        let discriminator_columns: Vec<&str> = rel
+            .row_discriminators(&hop.rel_type)
+            .into_iter()
+            .map(|(column, _)| column)
+            .collect();
+        Some(format!(
+            "{}.{}|{:?}|{:?}|{:?}|{}|{}|{}",
+            rel.database,
+            rel.table_name,
+            rel.from_id,
+            rel.to_id,
+            discriminator_columns,
+            hop.from_node_type,
+            hop.to_node_type,
+            hop.reversed
+        ))
+    }
+
+    /// Group paths that differ only in the type of polymorphic hops, so each
+    /// group scans the shared edge table once instead of once per type.
+    fn merge_polymorphic_paths(&self, paths: Vec<PathEnumeration>) -> Vec<PathBranch> {
+        let mut branches: Vec<PathBranch> = Vec::new();
+        let mut index: HashMap<Vec<String>, usize> = HashMap::new();
+        for path in paths {
+            let key: Option<Vec<String>> = if path.hops.is_empty() {
+                None
+            } else {
+                path.hops
+                    .iter()
+                    .map(|hop| self.polymorphic_hop_key(hop))
+                    .collect()
+            };
+            if let Some(&i) = key.as_ref().and_then(|k| index.get(k)) {
+                for (types, hop) in branches[i].hop_types.iter_mut().zip(&path.hops) {
+                    if !types.contains(&hop.rel_type) {
+                        types.push(hop.rel_type.clone());
+                    }
+                }
+                continue;
+            }
+            if let Some(key) = key {
+                index.insert(key, branches.len());
+            }
+            branches.push(PathBranch {
+                hop_types: path.hops.iter().map(|h| vec![h.rel_type.clone()]).collect(),
+                path,
+            });
+        }
+        branches
+    }
+
     /// Add a property projection for heterogeneous types
     pub fn add_property(
         &mut self,
@@ -487,8 +616,9 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
         );
 
         // Step 2: Generate SQL for each path branch
+        let branches = self.merge_polymorphic_paths(paths);
         let mut branch_sqls = Vec::new();
-        for (idx, path) in paths.iter().enumerate() {
+        for (idx, PathBranch { path, hop_types }) in branches.iter().enumerate() {
             if path.hops.is_empty() {
                 // Zero-hop path: start_node = end_node (same node, no traversal)
                 match self.generate_zero_hop_branch_sql() {
@@ -500,7 +630,7 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
                 }
                 continue;
             }
-            match self.generate_path_branch_sql(path, idx) {
+            match self.generate_path_branch_sql(path, hop_types, idx) {
                 Ok(sql) => branch_sqls.push(sql),
                 Err(e) => {
                     log::debug!("Failed to generate SQL for path {:?}: {}", path, e);
@@ -694,6 +824,7 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
     fn generate_path_branch_sql(
         &self,
         path: &PathEnumeration,
+        hop_types: &[Vec<String>],
         _branch_idx: usize,
     ) -> Result<String, String> {
         let hops = &path.hops;
@@ -743,6 +874,10 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
             };
             let rel_table = self.get_rel_table_with_db(&hop.rel_type, schema_from, schema_to)?;
             let (from_col, to_col) = self.get_rel_columns(&hop.rel_type, schema_from, schema_to)?;
+            let rel_schema = self
+                .schema
+                .get_rel_schema_with_nodes(&hop.rel_type, Some(schema_from), Some(schema_to))
+                .ok();
 
             // For reversed hops, swap the join columns
             let (join_from_col, join_to_col) = if hop.reversed {
@@ -788,8 +923,11 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
 
                 // Add relationship filters (apply to the combined rel/node table)
                 if let Some(ref rel_filters) = self.rel_filters {
-                    let rel_filter = rel_filters.replace("rel.", &format!("{}.", end_node_alias));
-                    where_clauses.push(rel_filter);
+                    where_clauses.push(resolve_rel_filter(
+                        rel_filters,
+                        &end_node_alias,
+                        rel_schema,
+                    ));
                 }
 
                 // FK-edge: rel "alias" is the end node table itself.
@@ -822,14 +960,16 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
                 from_clauses.push(rel_join_sql);
 
                 // Add polymorphic type filters (type_column, from_label_column, to_label_column)
-                if let Ok(rel_schema) = self.schema.get_rel_schema_with_nodes(
-                    &hop.rel_type,
-                    Some(schema_from),
-                    Some(schema_to),
-                ) {
-                    if let Some(ref type_col) = rel_schema.type_column {
-                        where_clauses
-                            .push(format!("{}.{} = '{}'", rel_alias, type_col, hop.rel_type));
+                if let Some(rel_schema) = rel_schema {
+                    if let Some(type_col) = rel_schema.type_discriminator_column() {
+                        let types = &hop_types[hop_idx];
+                        where_clauses.push(if types.len() > 1 {
+                            let quoted: Vec<String> =
+                                types.iter().map(|t| format!("'{}'", t)).collect();
+                            format!("{}.{} IN ({})", rel_alias, type_col, quoted.join(", "))
+                        } else {
+                            format!("{}.{} = '{}'", rel_alias, type_col, hop.rel_type)
+                        });
                     }
                     if let Some(ref from_label_col) = rel_schema.from_label_column {
                         // Use schema_from (original direction) not hop.from_node_type (may be reversed)
@@ -846,8 +986,7 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
 
                 // Add relationship filters
                 if let Some(ref rel_filters) = self.rel_filters {
-                    let rel_filter = rel_filters.replace("rel.", &format!("{}.", rel_alias));
-                    where_clauses.push(rel_filter);
+                    where_clauses.push(resolve_rel_filter(rel_filters, &rel_alias, rel_schema));
                 }
 
                 // Target node table JOIN
@@ -939,6 +1078,7 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
             &start_alias_sql,
             hop_count,
             &path.hops,
+            hop_types,
             &path_node_aliases,
             &hop_rel_fk_info,
         );
@@ -975,6 +1115,7 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
         start_alias_sql: &str,
         hop_count: usize,
         hops: &[crate::query_planner::analyzer::multi_type_vlp_expansion::PathHop],
+        hop_types: &[Vec<String>],
         path_node_aliases: &[(String, String)],
         hop_rel_fk_info: &[(String, String, String)],
     ) -> Vec<String> {
@@ -1428,9 +1569,22 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
 
         // Add path_relationships for relationships(p) function support
         // Generate array of relationship types: ['FOLLOWS', 'AUTHORED', ...]
+        // A hop merged over several polymorphic types reads its type column.
         let rel_types: Vec<String> = hops
             .iter()
-            .map(|hop| format!("'{}'", hop.rel_type))
+            .zip(hop_types)
+            .enumerate()
+            .map(|(hop_idx, (hop, types))| {
+                let type_col = self
+                    .schema
+                    .get_rel_schema(&hop.rel_type)
+                    .ok()
+                    .and_then(|rel| rel.type_discriminator_column().map(str::to_string));
+                match type_col {
+                    Some(col) if types.len() > 1 => format!("r{}.{}", hop_idx + 1, col),
+                    _ => format!("'{}'", hop.rel_type),
+                }
+            })
             .collect();
         items.push(format!(
             "{} AS path_relationships",
@@ -1512,6 +1666,17 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
                 .array_literal(&rel_props.join(", "))
         ));
 
+        // Relationship properties read as `r.prop` on a fixed single hop:
+        // one column per property, NULL for types that do not map it.
+        if let [hop] = hops {
+            for property in self.required_rel_properties() {
+                let value = self
+                    .rel_property_sql(hop, &property)
+                    .unwrap_or_else(|| "NULL".to_string());
+                items.push(format!("{} AS {}", value, rel_property_column(&property)));
+            }
+        }
+
         // Add path_nodes for nodes(p) function support
         // Build array of all node IDs in the path: [start_id, intermediate..., end_id]
         let path_node_exprs: Vec<String> = path_node_aliases
@@ -1539,6 +1704,37 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
 
         items
     }
+    /// SQL for relationship property `property` of a single-hop branch, read
+    /// from the alias `generate_path_branch_sql` gave the relationship table.
+    fn rel_property_sql(&self, hop: &PathHop, property: &str) -> Option<String> {
+        let (schema_from, schema_to) = if hop.reversed {
+            (&hop.to_node_type, &hop.from_node_type)
+        } else {
+            (&hop.from_node_type, &hop.to_node_type)
+        };
+        let rel = self
+            .schema
+            .get_rel_schema_with_nodes(&hop.rel_type, Some(schema_from), Some(schema_to))
+            .ok()?;
+        let value = rel.property_mappings.get(property)?;
+        let rel_table = self
+            .get_rel_table_with_db(&hop.rel_type, schema_from, schema_to)
+            .ok()?;
+        let end_table = self.get_node_table_with_db(&hop.to_node_type).ok()?;
+        let alias = if rel_table == end_table {
+            // FK-edge: the end node table is the relationship table
+            let prefix = match hop.to_node_type.as_str() {
+                "User" => "u",
+                "Post" => "p",
+                _ => "n",
+            };
+            format!("{}2", prefix)
+        } else {
+            "r1".to_string()
+        };
+        Some(value.to_sql(&alias))
+    }
+
     /// Get table name with database prefix for a node type
     /// 🔧 PARAMETERIZED VIEW FIX: Applies view parameters if the node schema has view_parameters defined
     fn get_node_table_with_db(&self, node_type: &str) -> Result<String, String> {
@@ -1815,6 +2011,37 @@ impl<'a> MultiTypeVlpJoinGenerator<'a> {
 }
 
 /// Split a SQL expression on top-level " AND " (respecting parentheses depth).
+/// Point a relationship filter (`rel.since > 1`) at `alias`, mapping each
+/// Cypher property to the hop's own column. Names the schema does not map
+/// are kept as written.
+fn resolve_rel_filter(
+    filter: &str,
+    alias: &str,
+    rel_schema: Option<&crate::graph_catalog::graph_schema::RelationshipSchema>,
+) -> String {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut out = String::with_capacity(filter.len());
+    let mut rest = filter;
+    while let Some(pos) = rest.find("rel.") {
+        let preceded_by_ident = rest[..pos].chars().next_back().is_some_and(is_ident);
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos + "rel.".len()..];
+        if preceded_by_ident {
+            out.push_str("rel.");
+            continue;
+        }
+        let end = rest.find(|c: char| !is_ident(c)).unwrap_or(rest.len());
+        let (name, tail) = rest.split_at(end);
+        match rel_schema.and_then(|rel| rel.property_mappings.get(name)) {
+            Some(value) => out.push_str(&value.to_sql(alias)),
+            None => out.push_str(&format!("{}.{}", alias, name)),
+        }
+        rest = tail;
+    }
+    out.push_str(rest);
+    out
+}
+
 pub fn split_top_level_and(sql: &str) -> Vec<&str> {
     let mut parts = Vec::new();
     let mut depth = 0;
@@ -161,6 +161,21 @@ impl ToSql for LogicalExpr {
                 // conflicts with Cypher functions (like "any" for array predicates)
                 let fn_name_lower = fn_call.name.to_lowercase();
 
+                // collect(DISTINCT x) → groupUniqArray / collect_set.
+                let distinct_arg = match fn_call.args.as_slice() {
+                    [LogicalExpr::OperatorApplicationExp(op)]
+                        if op.operator == Operator::Distinct && op.operands.len() == 1 =>
+                    {
+                        Some(op.operands[0].to_sql()?)
+                    }
+                    _ => None,
+                };
+                if let Some(sql) =
+                    super::common::try_render_collect_distinct(&fn_name_lower, distinct_arg)
+                {
+                    return Ok(sql);
+                }
+
                 // percentileCont/Disc are parametric quantiles — render through the
                 // dialect FunctionMapper, honoring the percentile arg (#639).
                 if let Some(sql) = super::common::try_render_percentile(&fn_name_lower, &args_sql) {
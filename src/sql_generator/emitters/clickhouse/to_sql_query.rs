@@ -11,8 +11,8 @@ use crate::{
         ViewTableRef,
         {
             ArrayJoinItem, Cte, CteContent, CteItems, FilterItems, FromTableItem,
-            GroupByExpressions, Join, JoinItems, JoinType, OrderByItems, OrderByOrder, RenderPlan,
-            SelectItem, SelectItems, ToSql, UnionItems, UnionType,
+            GroupByExpressions, Join, JoinItems, JoinType, OrderByItem, OrderByItems, OrderByOrder,
+            RenderPlan, SelectItem, SelectItems, ToSql, Union, UnionItems, UnionType,
         },
     },
     server::query_context::{
@@ -384,11 +384,12 @@ fn render_string_addition(op: &OperatorApplication) -> Option<String> {
     None
 }
 
-/// Interval arithmetic on epoch-millis: wrap non-interval operands as a
-/// timestamp, do the `+`/`-`, and convert the result back to epoch-millis.
-/// Dialect-aware via the function mapper — ClickHouse:
+/// Interval arithmetic. An epoch-millis operand is wrapped as a timestamp, the
+/// `+`/`-` applied, and the result converted back to epoch-millis — ClickHouse:
 /// `toUnixTimestamp64Milli(fromUnixTimestamp64Milli(x) + toIntervalDay(n))`;
 /// Databricks: `unix_millis(timestamp_millis(x) + make_dt_interval(n,0,0,0))`.
+/// An operand that is already a native date/timestamp (`datetime()`, `date(..)`,
+/// an earlier interval result) keeps its type: `(now64(3) - toIntervalDay(30))`.
 /// `rendered` is the path's pre-rendered operands; one of them is the interval
 /// (produced by the `duration()` translation).
 fn render_interval_arithmetic(op: &OperatorApplication, rendered: &[String]) -> Option<String> {
@@ -396,56 +397,68 @@ fn render_interval_arithmetic(op: &OperatorApplication, rendered: &[String]) ->
     use crate::sql_generator::SqlDialect;
 
     let dialect = get_current_dialect();
-    // An operand is the interval term when it is a dialect interval constructor.
-    // Databricks markers are anchored on the call `(` so a column whose name
-    // merely contains the token isn't mistaken for an interval. (The CH `(`-less
-    // `toInterval` check is kept verbatim to preserve byte-identical CH output.)
-    let is_interval = |r: &str| match dialect {
-        SqlDialect::Databricks => {
-            r.contains("make_dt_interval(") || r.contains("make_ym_interval(")
-        }
-        _ => r.contains("toInterval"),
+    // Operands are classified by their outermost call, so the result of an
+    // inner `x + duration(..)` is not itself mistaken for the interval term.
+    let head = |r: &str| r.trim_start_matches('(').to_string();
+    let is_interval = |r: &str| {
+        let h = head(r);
+        match dialect {
+            SqlDialect::Databricks => {
+                h.starts_with("make_dt_interval(") || h.starts_with("make_ym_interval(")
+            }
+            _ => h.starts_with("toInterval"),
+        }
     };
 
     if (op.operator == Operator::Addition || op.operator == Operator::Subtraction)
         && rendered.len() == 2
         && rendered.iter().any(|r| is_interval(r))
     {
-        // An operand that is already a timestamp expression must not be re-wrapped.
-        // Databricks function markers are anchored on the call `(`; `current_timestamp`
-        // is intentionally bare (Spark allows it as a keyword without parens). The CH
-        // arm is kept verbatim to preserve byte-identical CH output.
-        let already_timestamp = |r: &str| match dialect {
-            SqlDialect::Databricks => {
-                r.contains("timestamp_millis(")
-                    || r.contains("to_timestamp(")
-                    || r.contains("from_unixtime(")
-                    || r.contains("current_timestamp")
-            }
-            _ => {
-                r.contains("fromUnixTimestamp64Milli")
-                    || r.contains("parseDateTime64BestEffort")
-                    || r.contains("toDateTime")
-                    || r.contains("now64")
-                    || r.contains("now()")
+        // An operand that is already a date/timestamp expression must not be
+        // re-wrapped. `current_timestamp`/`current_date` are intentionally bare
+        // (Spark allows them as keywords without parens).
+        let native_temporal = |r: &str| {
+            let h = head(r);
+            match dialect {
+                SqlDialect::Databricks => {
+                    h.starts_with("timestamp_millis(")
+                        || h.starts_with("to_timestamp(")
+                        || h.starts_with("to_date(")
+                        || h.starts_with("from_unixtime(")
+                        || h.starts_with("current_timestamp")
+                        || h.starts_with("current_date")
+                }
+                _ => {
+                    h.starts_with("fromUnixTimestamp64Milli")
+                        || h.starts_with("parseDateTime64BestEffort")
+                        || h.starts_with("toDateTime")
+                        || h.starts_with("toDate(")
+                        || h.starts_with("now64")
+                        || h.starts_with("now()")
+                        || h.starts_with("today()")
+                }
             }
         };
         let mapper = crate::sql_generator::function_mapper::current_function_mapper();
+        let sql_op = if op.operator == Operator::Addition {
+            "+"
+        } else {
+            "-"
+        };
+        let base = rendered.iter().find(|r| !is_interval(r));
+        if base.is_some_and(|r| native_temporal(r)) {
+            return Some(format!("({} {} {})", &rendered[0], sql_op, &rendered[1]));
+        }
         let wrapped: Vec<String> = rendered
             .iter()
             .map(|r| {
-                if is_interval(r) || already_timestamp(r) {
+                if is_interval(r) {
                     r.clone()
                 } else {
                     mapper.epoch_millis_to_timestamp(r)
                 }
             })
             .collect();
-        let sql_op = if op.operator == Operator::Addition {
-            "+"
-        } else {
-            "-"
-        };
         return Some(
             mapper
                 .timestamp_to_epoch_millis(&format!("{} {} {}", &wrapped[0], sql_op, &wrapped[1])),
@@ -1382,6 +1395,7 @@ fn rewrite_vlp_select_aliases(mut plan: RenderPlan) -> RenderPlan {
                     to_id_column: None,
                     graph_rel: None,
                     is_cartesian: false,
+                    use_final: false,
                 };
                 plan.joins.0.push(join);
             }
@@ -1666,6 +1680,18 @@ fn rewrite_vlp_select_aliases(mut plan: RenderPlan) -> RenderPlan {
             }
         }
 
+        // UNWIND (ARRAY JOIN) runs over the VLP CTE rows too: `UNWIND nodes(p) AS n`
+        // must unroll t.path_nodes, not a `nodes(p)` call ClickHouse can't resolve.
+        for array_join in plan.array_join.0.iter_mut() {
+            array_join.expression = rewrite_expr_for_vlp(
+                &array_join.expression,
+                &start_alias,
+                &end_alias,
+                &path_variable,
+                is_optional_vlp,
+            );
+        }
+
         // Also rewrite WHERE clause for VLP queries
         // The WHERE may reference Cypher node aliases (e.g., o.user_id) that need
         // to be rewritten to VLP CTE column references (e.g., t.end_user_id)
@@ -1993,6 +2019,15 @@ fn rewrite_vlp_branch_select(branch: &mut RenderPlan, parent_ctes: &[crate::rend
             false,
         );
     }
+    for array_join in branch.array_join.0.iter_mut() {
+        array_join.expression = rewrite_expr_for_vlp(
+            &array_join.expression,
+            &start_alias,
+            &end_alias,
+            &path_variable,
+            false,
+        );
+    }
     // 🔧 FIX: Also rewrite WHERE clause (filters) for VLP UNION branches
     // Without this, branches with LIMIT get wrapped in subqueries with unrewritten WHERE clauses
     if let Some(ref filter_expr) = branch.filters.0 {
@@ -2490,11 +2525,139 @@ pub(crate) fn rewrite_expr_for_vlp(
             })
         }
 
+        // reduce(acc = init, r IN relationships(p) | ... r.prop ...) folds over the
+        // per-hop `path_edge_<prop>` arrays the VLP CTE accumulated for it.
+        RenderExpr::ReduceExpr(reduce) => {
+            let rw = |e: &RenderExpr| {
+                Box::new(rewrite_expr_for_vlp(
+                    e,
+                    start_alias,
+                    end_alias,
+                    path_variable,
+                    skip_start_alias,
+                ))
+            };
+            let (list, expression) = match fold_over_vlp_edge_properties(reduce, path_variable) {
+                Some((list, expression)) => (Box::new(list), rw(&expression)),
+                None => (rw(&reduce.list), rw(&reduce.expression)),
+            };
+            RenderExpr::ReduceExpr(ReduceExpr {
+                accumulator: reduce.accumulator.clone(),
+                initial_value: rw(&reduce.initial_value),
+                variable: reduce.variable.clone(),
+                list,
+                expression,
+            })
+        }
+
         // Leave other expressions unchanged
         other => other.clone(),
     }
 }
 
+/// For `reduce(acc = init, r IN relationships(p) | expr)` where `expr` reads
+/// edge properties through `r`, build the `(list, expr)` pair that folds over
+/// the VLP CTE's `path_edge_<prop>` arrays (populated from
+/// `plan_path_edge_properties`).
+///
+/// - one property: fold directly over `t.path_edge_<prop>`, with `r.prop` → `r`
+/// - several: fold over `arrayZip(...)` of the arrays (sorted by property name),
+///   with `r.prop` → `tupleElement(r, i)`
+///
+/// Returns `None` when the list is not `relationships(path_variable)` or no edge
+/// property is read, leaving the generic rewrite (`relationships(p)` →
+/// `t.path_relationships`) in charge.
+fn fold_over_vlp_edge_properties(
+    reduce: &ReduceExpr,
+    path_variable: &Option<String>,
+) -> Option<(RenderExpr, RenderExpr)> {
+    use crate::clickhouse_query_generator::variable_length_cte::path_edge_property_column;
+    use crate::graph_catalog::expression_parser::PropertyValue;
+    use crate::render_plan::expression_utils::ExprVisitor;
+
+    let path_var = path_variable.as_ref()?;
+    match reduce.list.as_ref() {
+        RenderExpr::ScalarFnCall(func)
+            if func.name.eq_ignore_ascii_case("relationships")
+                && matches!(func.args.as_slice(), [RenderExpr::TableAlias(a)] if &a.0 == path_var) =>
+            {}
+        _ => return None,
+    }
+
+    struct EdgePropCollector<'a> {
+        variable: &'a str,
+        props: std::collections::BTreeSet<String>,
+    }
+    impl ExprVisitor for EdgePropCollector<'_> {
+        fn transform_property_access(&mut self, prop: &PropertyAccess) -> RenderExpr {
+            if prop.table_alias.0 == self.variable {
+                self.props.insert(prop.column.raw().to_string());
+            }
+            RenderExpr::PropertyAccessExp(prop.clone())
+        }
+    }
+    let mut collector = EdgePropCollector {
+        variable: &reduce.variable,
+        props: Default::default(),
+    };
+    collector.transform_expr(&reduce.expression);
+    let props: Vec<String> = collector.props.into_iter().collect();
+    if props.is_empty() {
+        return None;
+    }
+
+    let edge_array = |prop: &str| {
+        RenderExpr::Column(Column(PropertyValue::Column(format!(
+            "{}.{}",
+            VLP_CTE_FROM_ALIAS,
+            path_edge_property_column(prop)
+        ))))
+    };
+    let list = if props.len() == 1 {
+        edge_array(&props[0])
+    } else {
+        RenderExpr::ScalarFnCall(ScalarFnCall {
+            name: "arrayZip".to_string(),
+            args: props.iter().map(|p| edge_array(p)).collect(),
+        })
+    };
+
+    struct EdgePropSubstituter<'a> {
+        variable: &'a str,
+        props: &'a [String],
+    }
+    impl ExprVisitor for EdgePropSubstituter<'_> {
+        fn transform_property_access(&mut self, prop: &PropertyAccess) -> RenderExpr {
+            if prop.table_alias.0 != self.variable {
+                return RenderExpr::PropertyAccessExp(prop.clone());
+            }
+            let element = RenderExpr::TableAlias(TableAlias(self.variable.to_string()));
+            if self.props.len() == 1 {
+                return element;
+            }
+            let position = self
+                .props
+                .iter()
+                .position(|p| p == prop.column.raw())
+                .unwrap_or_default();
+            RenderExpr::ScalarFnCall(ScalarFnCall {
+                name: "tupleElement".to_string(),
+                args: vec![
+                    element,
+                    RenderExpr::Literal(Literal::Integer(position as i64 + 1)),
+                ],
+            })
+        }
+    }
+    let expression = EdgePropSubstituter {
+        variable: &reduce.variable,
+        props: &props,
+    }
+    .transform_expr(&reduce.expression);
+
+    Some((list, expression))
+}
+
 /// Check if an expression is `path IS NULL` where path is the VLP path variable
 fn is_vlp_path_is_null(expr: &RenderExpr, path_variable: &Option<String>) -> bool {
     if let Some(path_var) = path_variable {
@@ -2727,6 +2890,11 @@ fn rewrite_fixed_path_functions(mut plan: RenderPlan) -> RenderPlan {
         for order_item in &mut plan.order_by.0 {
             order_item.expression = rewrite_with_info(&order_item.expression, &path_info);
         }
+
+        // And UNWIND expressions: `UNWIND nodes(p) AS n` unrolls the id array
+        for array_join in &mut plan.array_join.0 {
+            array_join.expression = rewrite_with_info(&array_join.expression, &path_info);
+        }
     }
 
     plan
@@ -4311,6 +4479,48 @@ fn build_branch_inner_select_with_own_items(
     branch_inner
 }
 
+/// Every arm of a Cypher `UNION`, joined by the union keyword. When the base
+/// plan still holds the first arm's fields it is rendered as an arm too.
+fn render_cypher_union_arms(plan: &RenderPlan, union: &Union) -> String {
+    let union_type_str = match union.union_type {
+        UnionType::Distinct => "UNION DISTINCT \n",
+        UnionType::All => "UNION ALL \n",
+    };
+    let mut out = String::new();
+    let mut first = true;
+    // When the base plan still holds the first arm's fields (it was
+    // not consolidated into union.input), render it as an arm too.
+    if plan.from.0.is_some() {
+        let base_arm = RenderPlan {
+            ctes: CteItems(vec![]),
+            select: plan.select.clone(),
+            from: plan.from.clone(),
+            joins: plan.joins.clone(),
+            array_join: plan.array_join.clone(),
+            filters: plan.filters.clone(),
+            group_by: plan.group_by.clone(),
+            having_clause: plan.having_clause.clone(),
+            order_by: plan.order_by.clone(),
+            skip: plan.skip.clone(),
+            limit: plan.limit.clone(),
+            union: UnionItems(None),
+            fixed_path_info: None,
+            is_multi_label_scan: false,
+            variable_registry: None,
+        };
+        out.push_str(&render_cypher_union_arm(&base_arm));
+        first = false;
+    }
+    for arm in &union.input {
+        if !first {
+            out.push_str(union_type_str);
+        }
+        first = false;
+        out.push_str(&render_cypher_union_arm(arm));
+    }
+    out
+}
+
 fn render_cypher_union_arm(arm: &RenderPlan) -> String {
     // Isolate this arm's alias context, exactly like render_union_branch_sql.
     let snapshot = snapshot_branch_context();
@@ -4449,6 +4659,20 @@ fn render_cypher_union_arm(arm: &RenderPlan) -> String {
 /// CTE references (names starting with `with_`, `vlp_`, `pattern_`, `rel_`, `__`)
 /// are returned as-is. Base table names that are missing the `db.` prefix get it
 /// by looking up the table in the current schema's node/relationship definitions.
+/// `"GLOBAL "` when the graph is sharded over a cluster (`cluster` in the
+/// schema): its joins and `IN` subqueries read Distributed tables, which
+/// must be sent whole to every shard for complete results.
+fn distributed_join_prefix() -> &'static str {
+    let sharded = crate::server::query_context::get_current_dialect().supports_global_joins()
+        && crate::server::query_context::get_current_schema()
+            .is_some_and(|schema| schema.cluster().is_some());
+    if sharded {
+        "GLOBAL "
+    } else {
+        ""
+    }
+}
+
 fn ensure_database_prefix(table_name: &str) -> String {
     // Already has database prefix
     if table_name.contains('.') {
@@ -4980,10 +5204,6 @@ pub fn render_plan_to_sql(mut plan: RenderPlan, _max_cte_depth: u32) -> String {
             let cypher_union_per_arm = plan.union.0.as_ref().is_some_and(|u| u.is_cypher_union);
             if cypher_union_per_arm {
                 let union = plan.union.0.as_ref().expect("checked above");
-                let union_type_str = match union.union_type {
-                    UnionType::Distinct => "UNION DISTINCT \n",
-                    UnionType::All => "UNION ALL \n",
-                };
 
                 // #609: ClickHouse's analyzer (verified on 25.8) cannot
                 // resolve a recursive CTE referenced from the SECOND (or
@@ -5000,37 +5220,7 @@ pub fn render_plan_to_sql(mut plan: RenderPlan, _max_cte_depth: u32) -> String {
                     sql.push_str("SELECT * FROM (\n");
                 }
 
-                let mut first = true;
-                // When the base plan still holds the first arm's fields (it was
-                // not consolidated into union.input), render it as an arm too.
-                if plan.from.0.is_some() {
-                    let base_arm = RenderPlan {
-                        ctes: CteItems(vec![]),
-                        select: plan.select.clone(),
-                        from: plan.from.clone(),
-                        joins: plan.joins.clone(),
-                        array_join: plan.array_join.clone(),
-                        filters: plan.filters.clone(),
-                        group_by: plan.group_by.clone(),
-                        having_clause: plan.having_clause.clone(),
-                        order_by: plan.order_by.clone(),
-                        skip: plan.skip.clone(),
-                        limit: plan.limit.clone(),
-                        union: UnionItems(None),
-                        fixed_path_info: None,
-                        is_multi_label_scan: false,
-                        variable_registry: None,
-                    };
-                    sql.push_str(&render_cypher_union_arm(&base_arm));
-                    first = false;
-                }
-                for arm in &union.input {
-                    if !first {
-                        sql.push_str(union_type_str);
-                    }
-                    first = false;
-                    sql.push_str(&render_cypher_union_arm(arm));
-                }
+                sql.push_str(&render_cypher_union_arms(&plan, union));
                 if wrap_for_recursive_ctes {
                     sql.push_str("\n) AS __cypher_union");
                 }
@@ -5505,12 +5695,13 @@ pub fn render_plan_to_sql(mut plan: RenderPlan, _max_cte_depth: u32) -> String {
                                 unambiguous_column_match(&non_order_items, &item.expression)
                             })
                             .and_then(|sel| sel.col_alias.as_ref());
+                        let collate = collate_clause(item);
                         if let Some(alias) = matched_alias {
-                            format!("`{}` {}", alias.0, order_str)
+                            format!("`{}` {}{}", alias.0, order_str, collate)
                         } else {
                             // No surviving column to reference — unchanged
                             // prior (pre-#503) behavior for this corner case.
-                            format!("{} {}", rendered, order_str)
+                            format!("{} {}{}", rendered, order_str, collate)
                         }
                     })
                     .collect();
@@ -5554,8 +5745,11 @@ pub fn render_plan_to_sql(mut plan: RenderPlan, _max_cte_depth: u32) -> String {
                             ""
                         };
                         Some(format!(
-                            "__union.`{}` {}{}",
-                            col_alias, order_str, nulls_clause
+                            "__union.`{}` {}{}{}",
+                            col_alias,
+                            order_str,
+                            nulls_clause,
+                            collate_clause(item)
                         ))
                     })
                     .collect();
@@ -5653,7 +5847,14 @@ pub fn render_plan_to_sql(mut plan: RenderPlan, _max_cte_depth: u32) -> String {
         .map(|aj| aj.alias.clone())
         .collect();
 
+    // `RETURN DISTINCT u.name ORDER BY u.age`-style keys are computed inside
+    // the DISTINCT and sorted on by a wrapper
+    let distinct_order = distinct_order_wrapper(&mut plan);
+
     sql.push_str(&plan.ctes.to_sql());
+    if let Some((_, hidden)) = &distinct_order {
+        sql.push_str(&format!("SELECT * EXCEPT ({}) FROM (\n", hidden.join(", ")));
+    }
     sql.push_str(&plan.select.to_sql_with_unwind_aliases(&unwind_aliases));
 
     // Add FROM clause - UNWIND-only queries (no actual table) need a one-row
@@ -5696,7 +5897,10 @@ pub fn render_plan_to_sql(mut plan: RenderPlan, _max_cte_depth: u32) -> String {
             crate::server::query_context::get_current_dialect(),
             crate::sql_generator::SqlDialect::Databricks
         );
-    if distinct_spark_order {
+    if let Some((order_by, _)) = &distinct_order {
+        sql.push_str(") AS __distinct\n");
+        sql.push_str(&order_by.to_sql());
+    } else if distinct_spark_order {
         sql.push_str(&render_order_by_with_select_aliases(
             &plan.order_by,
             &plan.select,
@@ -5958,6 +6162,94 @@ impl ToSql for GroupByExpressions {
 /// projection is rendered as that projection's alias (backtick-quoted) rather
 /// than the underlying `table.col`, which is no longer in scope after DISTINCT.
 /// Terms with no matching projection fall back to the raw expression.
+/// Sorting of a `SELECT DISTINCT` by keys that read columns it does not
+/// select (`RETURN DISTINCT u ORDER BY u.age`). Such a key is added to the
+/// DISTINCT as a hidden `__order_col_N` column, one value per distinct row
+/// since it only reads returned variables, and the rows are sorted by a
+/// wrapper `SELECT * EXCEPT (<hidden>) FROM (...) AS __distinct`.
+///
+/// Returns the wrapper's ORDER BY and the hidden columns, or `None` when
+/// every key is a selected expression or reads selected aliases only.
+fn distinct_order_wrapper(plan: &mut RenderPlan) -> Option<(OrderByItems, Vec<String>)> {
+    if !plan.select.distinct || plan.union.0.is_some() || plan.order_by.0.is_empty() {
+        return None;
+    }
+    // Compared as rendered: VLP rewriting maps `g.name` and `t.end_name` to
+    // the same column
+    let selected_alias = |expr: &RenderExpr| {
+        let sql = expr.to_sql();
+        plan.select
+            .items
+            .iter()
+            .find(|s| s.expression == *expr || s.expression.to_sql() == sql)
+            .and_then(|s| s.col_alias.clone())
+    };
+    let reads_columns = reads_table_columns;
+    let selected: Vec<Option<ColumnAlias>> = plan
+        .order_by
+        .0
+        .iter()
+        .map(|item| selected_alias(&item.expression))
+        .collect();
+    if !plan
+        .order_by
+        .0
+        .iter()
+        .zip(&selected)
+        .any(|(item, alias)| alias.is_none() && reads_columns(&item.expression))
+    {
+        return None;
+    }
+
+    let mapper = crate::sql_generator::function_mapper::current_function_mapper();
+    let mut hidden = Vec::new();
+    let mut outer = Vec::with_capacity(plan.order_by.0.len());
+    for (idx, (item, alias)) in plan.order_by.0.iter().zip(selected).enumerate() {
+        let term = match alias {
+            Some(alias) => format!("__distinct.{}", mapper.quote_alias(&alias.0)),
+            None if reads_columns(&item.expression) => {
+                let name = format!("__order_col_{}", idx);
+                plan.select.items.push(SelectItem {
+                    expression: item.expression.clone(),
+                    col_alias: Some(ColumnAlias(name.clone())),
+                });
+                let term = format!("__distinct.{}", mapper.quote_alias(&name));
+                hidden.push(name);
+                term
+            }
+            // Reads selected aliases only, which the wrapper sees as columns
+            None => item.expression.to_sql(),
+        };
+        outer.push(OrderByItem {
+            expression: RenderExpr::Raw(term),
+            order: item.order.clone(),
+            collation: item.collation.clone(),
+        });
+    }
+    Some((OrderByItems(outer), hidden))
+}
+
+/// Whether `expr` reads a column of a FROM/JOIN table (`u.email`), rather
+/// than only literals and selected aliases.
+fn reads_table_columns(expr: &RenderExpr) -> bool {
+    match expr {
+        RenderExpr::PropertyAccessExp(_) => true,
+        RenderExpr::Column(col) => col.raw().contains('.'),
+        RenderExpr::OperatorApplicationExp(op) => op.operands.iter().any(reads_table_columns),
+        RenderExpr::ScalarFnCall(f) => f.args.iter().any(reads_table_columns),
+        RenderExpr::AggregateFnCall(agg) => agg.args.iter().any(reads_table_columns),
+        RenderExpr::List(items) => items.iter().any(reads_table_columns),
+        RenderExpr::Case(c) => {
+            c.expr.as_deref().is_some_and(reads_table_columns)
+                || c.when_then
+                    .iter()
+                    .any(|(when, then)| reads_table_columns(when) || reads_table_columns(then))
+                || c.else_expr.as_deref().is_some_and(reads_table_columns)
+        }
+        _ => false,
+    }
+}
+
 fn render_order_by_with_select_aliases(order_by: &OrderByItems, select: &SelectItems) -> String {
     if order_by.0.is_empty() {
         return String::new();
@@ -5975,6 +6267,7 @@ fn render_order_by_with_select_aliases(order_by: &OrderByItems, select: &SelectI
         sql.push_str(&term);
         sql.push(' ');
         sql.push_str(&item.order.to_sql());
+        sql.push_str(&collate_clause(item));
         if i + 1 < order_by.0.len() {
             sql.push_str(", ");
         }
@@ -5994,6 +6287,7 @@ impl ToSql for OrderByItems {
             sql.push_str(&item.expression.to_sql());
             sql.push(' ');
             sql.push_str(&item.order.to_sql());
+            sql.push_str(&collate_clause(item));
             if i + 1 < self.0.len() {
                 sql.push_str(", ");
             }
@@ -6078,7 +6372,12 @@ impl ToSql for Cte {
                 let mut cte_body = String::new();
 
                 // Handle UNION plans - the union branches contain their own SELECTs
-                if plan.union.0.is_some() {
+                if let Some(union) = plan.union.0.as_ref().filter(|u| u.is_cypher_union) {
+                    // A Cypher UNION feeding a WITH (e.g. a `CALL { ... UNION ... }`
+                    // body): every arm keeps its own projection, so the plan-level
+                    // SELECT must not be pushed into the later arms.
+                    cte_body.push_str(&render_cypher_union_arms(plan, union));
+                } else if plan.union.0.is_some() {
                     // Check if we have custom SELECT items (WITH projection), modifiers, or GROUP BY
                     let has_custom_select = !plan.select.items.is_empty();
                     let has_order_by_skip_limit = !plan.order_by.0.is_empty()
@@ -6417,9 +6716,11 @@ impl ToSql for Join {
         // Base tables that are missing the prefix get it from the task-local schema.
         let qualified_table_name = ensure_database_prefix(&self.table_name);
 
+        let is_cross_join = self.is_cross_join();
+
         let join_type_str = match self.join_type {
             JoinType::Join => {
-                if self.joining_on.is_empty() {
+                if is_cross_join {
                     "CROSS JOIN"
                 } else {
                     "JOIN"
@@ -6430,15 +6731,35 @@ impl ToSql for Join {
             JoinType::Right => "RIGHT JOIN",
         };
 
+        // FINAL is ClickHouse-only, like on the FROM table.
+        let use_final = self.use_final
+            && crate::server::query_context::get_current_dialect().supports_final_keyword();
+
         // For LEFT JOIN with pre_filter, use subquery form:
         // LEFT JOIN (SELECT * FROM table WHERE pre_filter) AS alias ON ...
         // This ensures the filter is applied BEFORE the join (correct LEFT JOIN semantics)
         //
-        // For INNER JOIN with pre_filter, add filter to ON clause:
+        // A FINAL table takes the same form for every join type, so the filter
+        // runs on the merged rows inside the scan rather than after the join:
+        // INNER JOIN (SELECT * FROM table FINAL WHERE pre_filter) AS alias ON ...
+        //
+        // For other INNER JOINs with pre_filter, add filter to ON clause:
         // INNER JOIN table AS alias ON <join_cond> AND <pre_filter>
         // This is semantically equivalent and more efficient than subquery
-        let table_expr = if let Some(ref pre_filter) = self.pre_filter {
-            if matches!(self.join_type, JoinType::Left) {
+        let subquery_filter = self
+            .pre_filter
+            .as_ref()
+            .filter(|_| use_final || matches!(self.join_type, JoinType::Left));
+        let table_expr = if let Some(pre_filter) = subquery_filter {
+            if use_final {
+                // The scan is aliased like the join, so the filter renders as is
+                format!(
+                    "(SELECT * FROM {} AS {} FINAL WHERE {})",
+                    qualified_table_name,
+                    self.table_alias,
+                    pre_filter.to_sql()
+                )
+            } else {
                 // Use to_sql_without_table_alias to render column names without table prefix
                 // since inside the subquery, the table is not yet aliased
                 let filter_sql = pre_filter.to_sql_without_table_alias();
@@ -6450,21 +6771,24 @@ impl ToSql for Join {
                     "(SELECT * FROM {} WHERE {})",
                     qualified_table_name, filter_sql
                 )
-            } else {
-                // For non-LEFT joins, pre_filter will be added to ON clause below
-                qualified_table_name.clone()
             }
         } else {
             qualified_table_name.clone()
         };
 
-        let mut sql = format!("{} {} AS {}", join_type_str, table_expr, self.table_alias);
-
-        // Note: FINAL keyword for joins would need to be added here if Join struct
-        // is enhanced to track use_final. For now, joins don't support FINAL.
+        let mut sql = format!(
+            "{}{} {} AS {}",
+            distributed_join_prefix(),
+            join_type_str,
+            table_expr,
+            self.table_alias
+        );
+        if use_final && subquery_filter.is_none() {
+            sql.push_str(" FINAL");
+        }
 
         // Only add ON clause if there are joining conditions
-        if !self.joining_on.is_empty() {
+        if !self.joining_on.is_empty() && !is_cross_join {
             // Conditions are AND-joined below. When there is more than one, a
             // condition that is itself a top-level `AND`/`OR` (e.g. a cross-alias
             // `OR` predicate moved into the ON by the #462 post-WITH OPTIONAL fix)
@@ -6492,7 +6816,7 @@ impl ToSql for Join {
             // For INNER JOINs (not LEFT), add pre_filter to ON clause
             // This applies polymorphic edge filters, schema filters, etc.
             if let Some(ref pre_filter) = self.pre_filter {
-                if !matches!(self.join_type, JoinType::Left) {
+                if subquery_filter.is_none() {
                     let filter_sql = pre_filter.to_sql();
                     crate::debug_print!(
                         "  Adding pre_filter to INNER JOIN ON clause: {}",
@@ -6701,6 +7025,31 @@ impl RenderExpr {
                             }
                         }
                     }
+                    // duration('P30D') — ISO 8601 string literal
+                    if let RenderExpr::Literal(Literal::String(text)) = &fn_call.args[0] {
+                        if let Some(interval) =
+                            super::function_translator::iso8601_duration_interval(
+                                text,
+                                crate::server::query_context::get_current_dialect(),
+                            )
+                        {
+                            return interval;
+                        }
+                        log::debug!("Invalid ISO 8601 duration '{}', using as-is", text);
+                    }
+                }
+
+                // datetime() with no args -> current timestamp
+                if fn_name_lower == "datetime" && fn_call.args.is_empty() {
+                    return super::function_translator::current_datetime_sql(
+                        crate::server::query_context::get_current_dialect(),
+                    );
+                }
+
+                if fn_name_lower == "rand" && fn_call.args.is_empty() {
+                    return super::function_translator::random_sql(
+                        crate::server::query_context::get_current_dialect(),
+                    );
                 }
 
                 // Special handling for datetime({epochMillis: x}) -> identity pass-through
@@ -6835,6 +7184,21 @@ impl RenderExpr {
                 // `mapping.name_for(dialect)`.
                 let fn_name_lower = agg.name.to_lowercase();
 
+                // collect(DISTINCT x) → groupUniqArray / collect_set.
+                let distinct_arg = match agg.args.as_slice() {
+                    [RenderExpr::OperatorApplicationExp(op)]
+                        if op.operator == Operator::Distinct && op.operands.len() == 1 =>
+                    {
+                        Some(op.operands[0].to_sql())
+                    }
+                    _ => None,
+                };
+                if let Some(sql) =
+                    super::common::try_render_collect_distinct(&fn_name_lower, distinct_arg)
+                {
+                    return sql;
+                }
+
                 // percentileCont/Disc are parametric quantiles — render through the
                 // dialect FunctionMapper, honoring the percentile arg (#639).
                 {
@@ -7402,57 +7766,26 @@ impl RenderExpr {
                     }
                 };
 
-                // For ClickHouse, use caseWithExpression for simple CASE expressions
-                if let Some(case_expr) = &case.expr {
-                    // caseWithExpression(expr, val1, res1, val2, res2, ..., default)
-                    let mut args = vec![case_expr.to_sql()];
-
-                    for (when_expr, then_expr) in &case.when_then {
-                        args.push(when_expr.to_sql());
-                        args.push(render_result(then_expr));
-                    }
-
-                    let else_expr = case
-                        .else_expr
-                        .as_ref()
-                        .map(|e| render_result(e))
-                        .unwrap_or_else(|| {
-                            if has_list_branch {
-                                crate::sql_generator::function_mapper::current_function_mapper()
-                                    .array_literal("")
-                            } else {
-                                "NULL".to_string()
-                            }
-                        });
-                    args.push(else_expr);
-
-                    format!("caseWithExpression({})", args.join(", "))
-                } else {
-                    // Searched CASE - use standard CASE syntax
-                    let mut sql = String::from("CASE");
-
-                    for (when_expr, then_expr) in &case.when_then {
-                        sql.push_str(&format!(
-                            " WHEN {} THEN {}",
-                            when_expr.to_sql(),
-                            render_result(then_expr)
-                        ));
-                    }
-
-                    if let Some(else_expr) = &case.else_expr {
-                        sql.push_str(&format!(" ELSE {}", render_result(else_expr)));
-                    }
-
-                    sql.push_str(" END");
-                    sql
-                }
+                let fmap = crate::sql_generator::function_mapper::current_function_mapper();
+                let operand = case.expr.as_ref().map(|e| e.to_sql());
+                let branches: Vec<(String, String)> = case
+                    .when_then
+                    .iter()
+                    .map(|(when_expr, then_expr)| (when_expr.to_sql(), render_result(then_expr)))
+                    .collect();
+                let else_expr = match &case.else_expr {
+                    Some(e) => Some(render_result(e)),
+                    None if has_list_branch => Some(fmap.array_literal("")),
+                    None => None,
+                };
+                fmap.case_expression(operand.as_deref(), &branches, else_expr.as_deref())
             }
             RenderExpr::InSubquery(InSubquery { expr, subplan }) => {
                 let left = expr.to_sql();
                 let body = subplan.to_sql();
                 let body = body.split_whitespace().collect::<Vec<&str>>().join(" ");
 
-                format!("{} IN ({})", left, body)
+                format!("{} {}IN ({})", left, distributed_join_prefix(), body)
             }
             RenderExpr::ExistsSubquery(exists) => {
                 // Use the pre-generated SQL from the ExistsSubquery
@@ -8160,6 +8493,20 @@ impl ToSql for OperatorApplication {
     }
 }
 
+/// ` COLLATE '<locale>'` after the direction of an item sorted by a locale
+/// (ClickHouse's `ORDER BY x ASC COLLATE 'de'`), else empty. Spark has no
+/// per-query ICU collation, so Databricks sorts by the plain value.
+fn collate_clause(item: &OrderByItem) -> String {
+    use crate::server::query_context::get_current_dialect;
+    use crate::sql_generator::SqlDialect;
+    match &item.collation {
+        Some(locale) if !matches!(get_current_dialect(), SqlDialect::Databricks) => {
+            format!(" COLLATE '{}'", locale)
+        }
+        _ => String::new(),
+    }
+}
+
 impl ToSql for OrderByOrder {
     fn to_sql(&self) -> String {
         match self {
@@ -8568,6 +8915,7 @@ mod tests {
             to_id_column: None,
             graph_rel: None,
             is_cartesian: false,
+            use_final: false,
         }]);
         b.filters = FilterItems(Some(filter));
         b
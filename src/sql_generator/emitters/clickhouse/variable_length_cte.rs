@@ -1,4 +1,5 @@
 use crate::graph_catalog::config::Identifier;
+use crate::graph_catalog::expression_parser::PropertyValue;
 use crate::graph_catalog::graph_schema::GraphSchema;
 use crate::query_planner::join_context::VLP_END_ID_COLUMN;
 use crate::query_planner::logical_plan::VariableLengthSpec;
@@ -32,7 +33,7 @@ fn get_default_max_hops() -> u32 {
 /// `alias.col` (quoted); composite IDs collapse into a pipe-joined string via
 /// `concat(toString(alias.c1), '|', toString(alias.c2), ...)` so they can be
 /// stored in `path_nodes` and used for `has()` cycle checks.
-fn emit_id_expr(table_alias: &str, id: &Identifier) -> String {
+pub(crate) fn emit_id_expr(table_alias: &str, id: &Identifier) -> String {
     match id {
         Identifier::Single(col) => format!(
             "{}.{}",
@@ -56,8 +57,34 @@ fn emit_id_expr(table_alias: &str, id: &Identifier) -> String {
     }
 }
 
+/// Emit a SQL expression for a node's entry in `path_nodes`, composite-aware.
+///
+/// Single-column IDs render exactly like [`emit_id_expr`]. Composite IDs render
+/// as a native tuple (`tuple(alias.c1, alias.c2, ...)`) rather than the
+/// pipe-joined string, so cycle detection compares keys column-by-column and
+/// can't be fooled by ID values that themselves contain `|`.
+pub(crate) fn emit_node_key_expr(table_alias: &str, id: &Identifier) -> String {
+    match id {
+        Identifier::Single(_) => emit_id_expr(table_alias, id),
+        Identifier::Composite(cols) => {
+            let tuple = current_function_mapper().tuple_constructor();
+            let parts: Vec<String> = cols
+                .iter()
+                .map(|c| {
+                    format!(
+                        "{}.{}",
+                        table_alias,
+                        crate::clickhouse_query_generator::quote_identifier(c)
+                    )
+                })
+                .collect();
+            format!("{tuple}({})", parts.join(", "))
+        }
+    }
+}
+
 /// Emit the recursive-CTE cycle-check predicate: `NOT array_contains(vp.path_nodes, id)`.
-/// `id_expr` should be the bare ID expression (typically from [`emit_id_expr`]).
+/// `id_expr` should be the bare node-key expression (typically from [`emit_node_key_expr`]).
 fn emit_cycle_check(id_expr: &str) -> String {
     let array_contains = current_function_mapper().array_contains();
     format!("NOT {array_contains}(vp.path_nodes, {id_expr})")
@@ -104,7 +131,15 @@ pub fn undirected_doubled_edges_cte_name(
         .chars()
         .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
         .collect();
-    format!("undir_edges_{start_alias}_{end_alias}_{table_key}")
+    format!("{UNDIRECTED_DOUBLED_EDGES_PREFIX}{start_alias}_{end_alias}_{table_key}")
+}
+
+const UNDIRECTED_DOUBLED_EDGES_PREFIX: &str = "undir_edges_";
+
+/// Whether a join/FROM table name is a doubled-edge CTE (see
+/// [`undirected_doubled_edges_cte_name`]) rather than a schema table.
+pub fn is_undirected_doubled_edges_cte(table_name: &str) -> bool {
+    table_name.starts_with(UNDIRECTED_DOUBLED_EDGES_PREFIX)
 }
 
 /// #617: enumerate the edge-table columns a doubled-edge CTE must project
@@ -179,6 +214,18 @@ fn arr(elems: &str) -> String {
     current_function_mapper().array_literal(elems)
 }
 
+/// CTE column that accumulates one edge property per hop, e.g. `weight` →
+/// `path_edge_weight`. Shared between the VLP generator (which emits the array)
+/// and the outer-SELECT rewrite of `reduce(.. r IN relationships(p) | .. r.weight ..)`
+/// (which folds over it), so the two always agree on the name.
+pub fn path_edge_property_column(cypher_prop: &str) -> String {
+    let sanitized: String = cypher_prop
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("path_edge_{}", sanitized)
+}
+
 /// Whether `s` looks like a bare integer literal (digits, optional leading
 /// `-`). Used to scope the Spark BFS anchor cast to numeric IDs — column
 /// references inherit their column's type and string-keyed IDs would break
@@ -200,6 +247,18 @@ pub struct NodeProperty {
     pub alias: String,        // Output alias (e.g., "name" or "u1_name")
 }
 
+impl NodeProperty {
+    /// The property read from `table_alias`: `alias.column`, or for a computed
+    /// property (`column_name` holding e.g. `concat(first_name, ' ', last_name)`)
+    /// the expression with its columns qualified by `table_alias`.
+    pub fn sql(&self, table_alias: &str) -> String {
+        match PropertyValue::from_raw(self.column_name.clone()) {
+            expr @ PropertyValue::Expression(_) => expr.to_sql(table_alias),
+            PropertyValue::Column(column) => format!("{}.{}", table_alias, column),
+        }
+    }
+}
+
 /// Drop exact-duplicate `NodeProperty` entries (same alias, column, and output
 /// name), preserving first-seen order. A CLOSED variable-length pattern —
 /// `(a)-[:R*2..2]->(a)` / `*2..3` — resolves the SAME endpoint property once for
@@ -277,6 +336,14 @@ pub struct VariableLengthCteGenerator<'a> {
     /// `is_undirected` here would turn each monotone arm into a complete
     /// undirected walk and double-count every path.
     pub undirected_single_walk: bool,
+    /// Edge properties read by `reduce()` over `relationships(path)`. Each one is
+    /// accumulated hop-by-hop into a `path_edge_<prop>` array (see
+    /// `path_edge_property_column`) so the fold can run over the CTE row.
+    pub path_edge_properties: Vec<String>,
+    /// Build the path as one non-recursive CTE per hop, `UNION ALL`-ed up to
+    /// `max_hops`, for servers without `WITH RECURSIVE`. Only valid for a
+    /// bounded pattern that is not [`Self::requires_recursive_cte`].
+    pub unroll_recursion: bool,
 }
 
 /// Configuration for weighted shortest path using a pre-computed edge weight CTE
@@ -497,8 +564,10 @@ impl<'a> VariableLengthCteGenerator<'a> {
             weight_cte: None,
             needs_path_relationships: true,
             use_bfs_mode: false,
+            unroll_recursion: false,
             is_undirected: false,
             undirected_single_walk: false,
+            path_edge_properties: Vec::new(),
         }
     }
 
@@ -573,8 +642,10 @@ impl<'a> VariableLengthCteGenerator<'a> {
             weight_cte: None,
             needs_path_relationships: true,
             use_bfs_mode: false,
+            unroll_recursion: false,
             is_undirected: false,
             undirected_single_walk: false,
+            path_edge_properties: Vec::new(),
         }
     }
 
@@ -936,12 +1007,12 @@ impl<'a> VariableLengthCteGenerator<'a> {
         self.path_variable.is_some()
     }
 
-    /// Build the SQL expression for the end node ID.
-    /// Returns expression like `end_node.PersonId` or `concat(toString(end_node.col1), ...)`.
+    /// Build the `path_nodes` key expression for the end node.
+    /// Returns expression like `end_node.PersonId` or `tuple(end_node.col1, end_node.col2)`.
     /// Used for node-uniqueness cycle detection in path_nodes arrays.
-    fn build_end_node_id_expr(&self) -> String {
+    fn build_end_node_key_expr(&self) -> String {
         let end_id_identifier = Identifier::from_comma_separated(&self.end_node_id_column);
-        emit_id_expr(&self.end_node_alias, &end_id_identifier)
+        emit_node_key_expr(&self.end_node_alias, &end_id_identifier)
     }
 
     /// Whether the standard directed recursive VLP should enforce EDGE-uniqueness
@@ -977,6 +1048,26 @@ impl<'a> VariableLengthCteGenerator<'a> {
         self.shortest_path_mode.is_none()
             && self.spec.effective_min_hops() >= 1
             && !self.is_heterogeneous_polymorphic_path()
+            && !self.walks_acyclic_edges()
+    }
+
+    /// Whether this is a directed walk over a single relationship type the
+    /// schema declares acyclic (`constraints.acyclic`). Such a walk can never
+    /// revisit a node or an edge, so the standard arms emit no cycle check and
+    /// carry no `path_edges` array. Undirected walks are excluded: following
+    /// an edge backwards closes a cycle even in an acyclic graph.
+    fn walks_acyclic_edges(&self) -> bool {
+        if self.is_undirected || self.undirected_single_walk {
+            return false;
+        }
+        match self.relationship_types.as_deref() {
+            // Resolved types are composite keys (`TYPE::From::To`).
+            Some([rel_type]) => {
+                let base = rel_type.split("::").next().unwrap_or(rel_type);
+                self.schema.constraints().is_acyclic(base)
+            }
+            _ => false,
+        }
     }
 
     /// #617: whether this VLP walks a DOUBLED-EDGE set instead of the raw edge
@@ -1209,6 +1300,48 @@ impl<'a> VariableLengthCteGenerator<'a> {
         }
     }
 
+    /// SQL for edge property `cypher_prop` on the relationship row aliased
+    /// `rel_alias`, resolved through the first relationship schema of the
+    /// traversed type(s) that maps it. Unmapped names read a same-named column.
+    fn edge_property_sql(&self, rel_alias: &str, cypher_prop: &str) -> String {
+        self.relationship_types
+            .iter()
+            .flatten()
+            .flat_map(
+                |rel_type| match self.schema.get_relationships_schema_opt(rel_type) {
+                    Some(rel_schema) => vec![rel_schema],
+                    None => self
+                        .schema
+                        .rel_schemas_for_type(rel_type.split("::").next().unwrap_or(rel_type)),
+                },
+            )
+            .find_map(|rel_schema| rel_schema.property_mappings.get(cypher_prop).cloned())
+            .unwrap_or_else(|| PropertyValue::Column(cypher_prop.to_string()))
+            .to_sql(rel_alias)
+    }
+
+    /// `path_edge_<prop>` selections for `path_edge_properties`. The base arm
+    /// (`prev_alias = None`) seeds each array with this hop's value; the
+    /// recursive arm appends to the array carried on `prev_alias`.
+    fn path_edge_property_selections(
+        &self,
+        rel_alias: &str,
+        prev_alias: Option<&str>,
+    ) -> Vec<String> {
+        let ac = current_function_mapper().array_concat();
+        self.path_edge_properties
+            .iter()
+            .map(|prop| {
+                let column = path_edge_property_column(prop);
+                let value = arr(&self.edge_property_sql(rel_alias, prop));
+                match prev_alias {
+                    Some(prev) => format!("{ac}({prev}.{column}, {value}) as {column}"),
+                    None => format!("{value} as {column}"),
+                }
+            })
+            .collect()
+    }
+
     /// Get the ClickHouse array type for path_edges
     /// Returns type like: `Array(Tuple(UInt32, UInt32))` or `Array(Tuple(String, String, ...))`
     #[allow(dead_code)]
@@ -1287,7 +1420,7 @@ impl<'a> VariableLengthCteGenerator<'a> {
         Cte::new_vlp(
             self.cte_name.clone(),
             crate::render_plan::CteContent::RawSql(cte_sql),
-            true, // is_recursive
+            !self.unroll_recursion, // is_recursive
             self.start_node_alias.clone(),
             self.end_node_alias.clone(),
             self.start_node_table.clone(),
@@ -1367,7 +1500,7 @@ impl<'a> VariableLengthCteGenerator<'a> {
         for prop in &self.properties {
             if prop.cypher_alias == self.end_cypher_alias {
                 // Try ClickHouse column name (e.g., end_node.full_name → end_name)
-                let pattern_col = format!("{}.{}", self.end_node_alias, prop.column_name);
+                let pattern_col = prop.sql(&self.end_node_alias);
                 let replacement = format!("end_{}", prop.alias);
                 rewritten = rewritten.replace(&pattern_col, &replacement);
 
@@ -1723,9 +1856,21 @@ impl<'a> VariableLengthCteGenerator<'a> {
     }
 
     /// Generate the actual recursive SQL string
+    /// Path shapes whose SQL has no unrolled form: weighted shortestPath to a
+    /// known target (BFS + backward reconstruction) and heterogeneous
+    /// polymorphic paths (recursive intermediate-node walk).
+    pub fn requires_recursive_cte(&self) -> bool {
+        (self.weight_cte.is_some()
+            && self.shortest_path_mode.is_some()
+            && self.end_node_filters.is_some())
+            || self.is_heterogeneous_polymorphic_path()
+    }
+
     fn generate_recursive_sql(&self) -> String {
-        // Lightweight BFS mode for shortestPath + length(path)-only queries
-        if self.use_bfs_mode {
+        // Lightweight BFS mode for shortestPath + length(path)-only queries.
+        // Its frontier walk is recursive; unrolled, shortestPath uses the
+        // per-path CTE below, whose columns are a superset.
+        if self.use_bfs_mode && !self.unroll_recursion {
             return self.generate_bfs_shortest_path_sql();
         }
 
@@ -1768,6 +1913,9 @@ impl<'a> VariableLengthCteGenerator<'a> {
 
         // Generate the core recursive query body (without CTE name wrapper)
         let mut query_body = String::new();
+        // Unrolled mode: the per-hop CTEs `query_body` unions, emitted ahead of
+        // the VLP CTE.
+        let mut unrolled_hops = String::new();
 
         // Special case: For shortest path self-loops (a to a), only zero-hop is needed
         let is_shortest_self_loop = self.shortest_path_mode.is_some()
@@ -1829,8 +1977,6 @@ impl<'a> VariableLengthCteGenerator<'a> {
             //
             // Mitigation: Ensure edge tables have unique (from_id, to_id) pairs,
             // or the application should enforce this constraint before loading data.
-            query_body.push_str("\n    UNION ALL\n");
-
             let default_depth = max_hops.unwrap_or_else(|| {
                 if min_hops == 0 {
                     3 // Lower limit for zero-hop base queries
@@ -1839,9 +1985,34 @@ impl<'a> VariableLengthCteGenerator<'a> {
                 }
             });
 
-            query_body.push_str(
-                &self.generate_recursive_case_with_cte_name(default_depth, &recursive_cte_name),
-            );
+            if self.unroll_recursion {
+                // Hop k reads hop k-1 exactly as the recursive case reads the
+                // CTE itself; `hop_count < default_depth` stays as the bound.
+                let base_hop = if min_hops == 0 { 0 } else { 1 };
+                let base_body = std::mem::take(&mut query_body);
+                let hop_name = |hop: u32| format!("{}_hop{}", recursive_cte_name, hop);
+                let mut hops = vec![format!("{} AS (\n{}\n)", hop_name(base_hop), base_body)];
+                for hop in base_hop + 1..=default_depth {
+                    hops.push(format!(
+                        "{} AS (\n{}\n)",
+                        hop_name(hop),
+                        self.generate_recursive_case_with_cte_name(
+                            default_depth,
+                            &hop_name(hop - 1)
+                        )
+                    ));
+                }
+                unrolled_hops = hops.join(",\n");
+                query_body = (base_hop..=default_depth)
+                    .map(|hop| format!("    SELECT * FROM {}", hop_name(hop)))
+                    .collect::<Vec<_>>()
+                    .join("\n    UNION ALL\n");
+            } else {
+                query_body.push_str("\n    UNION ALL\n");
+                query_body.push_str(
+                    &self.generate_recursive_case_with_cte_name(default_depth, &recursive_cte_name),
+                );
+            }
         }
 
         // Build CTE structure based on shortest path mode and filters
@@ -2009,13 +2180,19 @@ impl<'a> VariableLengthCteGenerator<'a> {
             }
         };
 
+        let sql = if unrolled_hops.is_empty() {
+            sql
+        } else {
+            format!("{},\n{}", unrolled_hops, sql)
+        };
+
         // #617: the doubled-edge walk needs its sibling edge CTE defined before
         // the recursive CTE. Emit only when the body actually references it —
         // a *0..0 pattern (zero-hop seed only, no recursive arm) never joins
         // the edge relation.
         if self.uses_doubled_edges() {
             let edges_name = self.doubled_edges_cte_name();
-            if query_body.contains(&edges_name) {
+            if query_body.contains(&edges_name) || unrolled_hops.contains(&edges_name) {
                 return format!("{},\n{}", self.generate_doubled_edges_cte(), sql);
             }
         }
@@ -2159,8 +2336,9 @@ impl<'a> VariableLengthCteGenerator<'a> {
         for prop in &self.properties {
             if prop.cypher_alias == self.end_cypher_alias {
                 prop_selects.push(format!(
-                    "{}.{} as end_{}",
-                    self.end_node_alias, prop.column_name, prop.alias
+                    "{} as end_{}",
+                    prop.sql(&self.end_node_alias),
+                    prop.alias
                 ));
             }
         }
@@ -2245,27 +2423,30 @@ impl<'a> VariableLengthCteGenerator<'a> {
             std::collections::HashSet::new() // Not needed when tables are same
         };
 
+        let start_id_identifier = Identifier::from_comma_separated(&self.start_node_id_column);
+        let start_id_expr = emit_id_expr(&self.start_node_alias, &start_id_identifier);
         let mut select_items = vec![
-            format!(
-                "{}.{} as start_id",
-                self.start_node_alias, self.start_node_id_column
-            ),
-            format!(
-                "{}.{} as end_id",
-                self.start_node_alias,
-                self.start_node_id_column // Same node for self-loop
-            ),
-            "0 as hop_count".to_string(), // Zero hops
+            format!("{start_id_expr} as start_id"),
+            format!("{start_id_expr} as end_id"), // Same node for self-loop
+            "0 as hop_count".to_string(),         // Zero hops
             format!("{empty_str_arr} as path_relationships"), // Minimal placeholder when path data not needed
             // Add path_nodes for UNWIND nodes(p) support - for zero hop, just the start node
             format!(
                 "{} as path_nodes",
-                arr(&format!(
-                    "{}.{}",
-                    self.start_node_alias, self.start_node_id_column
+                arr(&emit_node_key_expr(
+                    &self.start_node_alias,
+                    &start_id_identifier
                 ))
             ),
         ];
+        // Zero hops traverse no edges, so every edge-property array starts empty.
+        for prop in &self.path_edge_properties {
+            select_items.push(format!(
+                "{} as {}",
+                arr(""),
+                path_edge_property_column(prop)
+            ));
+        }
 
         // Add properties for start node (which is also the end node)
         for prop in &self.properties {
@@ -2278,8 +2459,9 @@ impl<'a> VariableLengthCteGenerator<'a> {
                 }
 
                 select_items.push(format!(
-                    "{}.{} as start_{}",
-                    self.start_node_alias, prop.column_name, prop.alias
+                    "{} as start_{}",
+                    prop.sql(&self.start_node_alias),
+                    prop.alias
                 ));
             }
             // For zero-hop, end properties are same as start properties
@@ -2299,8 +2481,9 @@ impl<'a> VariableLengthCteGenerator<'a> {
                     select_items.push(format!("'' as end_{}", prop.alias));
                 } else {
                     select_items.push(format!(
-                        "{}.{} as end_{}",
-                        self.start_node_alias, prop.column_name, prop.alias
+                        "{} as end_{}",
+                        prop.sql(&self.start_node_alias),
+                        prop.alias
                     ));
                 }
             }
@@ -2409,8 +2592,8 @@ impl<'a> VariableLengthCteGenerator<'a> {
                         "{} as path_nodes",
                         arr(&format!(
                             "{}, {}",
-                            emit_id_expr(&self.start_node_alias, &start_id_identifier),
-                            emit_id_expr(&self.end_node_alias, &end_id_identifier),
+                            emit_node_key_expr(&self.start_node_alias, &start_id_identifier),
+                            emit_node_key_expr(&self.end_node_alias, &end_id_identifier),
                         ))
                     )
                 }
@@ -2442,6 +2625,7 @@ impl<'a> VariableLengthCteGenerator<'a> {
                     arr(&self.build_edge_tuple_base())
                 ));
             }
+            select_items.extend(self.path_edge_property_selections(&self.relationship_alias, None));
 
             // For composite IDs, add individual ID component columns
             // This allows queries like RETURN dest.bank_id, dest.account_number
@@ -2488,8 +2672,9 @@ impl<'a> VariableLengthCteGenerator<'a> {
                 {
                     // Property belongs to start node (and is not the ID column)
                     select_items.push(format!(
-                        "{}.{} as start_{}",
-                        self.start_node_alias, prop.column_name, prop.alias
+                        "{} as start_{}",
+                        prop.sql(&self.start_node_alias),
+                        prop.alias
                     ));
                 }
                 if prop.cypher_alias == self.end_cypher_alias
@@ -2497,8 +2682,9 @@ impl<'a> VariableLengthCteGenerator<'a> {
                 {
                     // Property belongs to end node (and is not the ID column)
                     select_items.push(format!(
-                        "{}.{} as end_{}",
-                        self.end_node_alias, prop.column_name, prop.alias
+                        "{} as end_{}",
+                        prop.sql(&self.end_node_alias),
+                        prop.alias
                     ));
                 }
             }
@@ -2707,7 +2893,10 @@ impl<'a> VariableLengthCteGenerator<'a> {
         let end_id_selection = format!("{end_id_expr_str} as end_id");
         let path_nodes_selection = format!(
             "{ac}(vp.path_nodes, {}) as path_nodes",
-            arr(&end_id_expr_str)
+            arr(&emit_node_key_expr(
+                &self.end_node_alias,
+                &end_id_identifier
+            ))
         );
 
         // Build property selections for recursive case
@@ -2736,6 +2925,8 @@ impl<'a> VariableLengthCteGenerator<'a> {
                 arr(&self.build_edge_tuple_recursive(&self.relationship_alias))
             ));
         }
+        select_items
+            .extend(self.path_edge_property_selections(&self.relationship_alias, Some("vp")));
 
         // For composite IDs, add individual ID component columns
         // Pass through start ID components from vp, add end ID components from joined node
@@ -2782,8 +2973,9 @@ impl<'a> VariableLengthCteGenerator<'a> {
             {
                 // End node properties come from the newly joined node
                 select_items.push(format!(
-                    "{}.{} as end_{}",
-                    self.end_node_alias, prop.column_name, prop.alias
+                    "{} as end_{}",
+                    prop.sql(&self.end_node_alias),
+                    prop.alias
                 ));
             }
         }
@@ -2800,13 +2992,16 @@ impl<'a> VariableLengthCteGenerator<'a> {
         // Node-uniqueness (`NOT has(path_nodes, end_id)`) is retained for shortestPath
         // (revisiting a node can never yield a shorter path) and for the non-standard
         // strategies, gated via uses_edge_uniqueness().
-        let cycle_pred = if self.uses_edge_uniqueness() {
-            emit_edge_cycle_check(&self.build_edge_tuple_recursive(&self.relationship_alias))
-        } else {
-            emit_cycle_check(&self.build_end_node_id_expr())
-        };
-
-        let mut where_conditions = vec![format!("vp.hop_count < {}", max_hops), cycle_pred];
+        //
+        // Walks over a declared-acyclic type need neither (walks_acyclic_edges()).
+        let mut where_conditions = vec![format!("vp.hop_count < {}", max_hops)];
+        if self.uses_edge_uniqueness() {
+            where_conditions.push(emit_edge_cycle_check(
+                &self.build_edge_tuple_recursive(&self.relationship_alias),
+            ));
+        } else if !self.walks_acyclic_edges() {
+            where_conditions.push(emit_cycle_check(&self.build_end_node_key_expr()));
+        }
 
         // Add polymorphic edge filter if this is a polymorphic edge table
         if let Some(poly_filter) = self.generate_polymorphic_edge_filter() {
@@ -3064,16 +3259,15 @@ impl<'a> VariableLengthCteGenerator<'a> {
         }
 
         let empty_str_arr = current_function_mapper().empty_string_array_cast();
+        let start_id = Identifier::from_comma_separated(&self.start_node_id_column);
+        let end_id = Identifier::from_comma_separated(&self.end_node_id_column);
         // Build property selections
         let mut select_items = vec![
             format!(
-                "{}.{} as start_id",
-                self.start_node_alias, self.start_node_id_column
-            ),
-            format!(
-                "{}.{} as end_id",
-                self.end_node_alias, self.end_node_id_column
+                "{} as start_id",
+                emit_id_expr(&self.start_node_alias, &start_id)
             ),
+            format!("{} as end_id", emit_id_expr(&self.end_node_alias, &end_id)),
             "1 as hop_count".to_string(),
         ];
         if self.needs_path_data() {
@@ -3084,11 +3278,9 @@ impl<'a> VariableLengthCteGenerator<'a> {
         select_items.push(format!(
             "{} as path_nodes",
             arr(&format!(
-                "{}.{}, {}.{}",
-                self.start_node_alias,
-                self.start_node_id_column,
-                self.end_node_alias,
-                self.end_node_id_column
+                "{}, {}",
+                emit_node_key_expr(&self.start_node_alias, &start_id),
+                emit_node_key_expr(&self.end_node_alias, &end_id)
             ))
         ));
 
@@ -3096,14 +3288,16 @@ impl<'a> VariableLengthCteGenerator<'a> {
         for prop in &self.properties {
             if prop.cypher_alias == self.start_cypher_alias {
                 select_items.push(format!(
-                    "{}.{} as start_{}",
-                    self.start_node_alias, prop.column_name, prop.alias
+                    "{} as start_{}",
+                    prop.sql(&self.start_node_alias),
+                    prop.alias
                 ));
             }
             if prop.cypher_alias == self.end_cypher_alias {
                 select_items.push(format!(
-                    "{}.{} as end_{}",
-                    self.end_node_alias, prop.column_name, prop.alias
+                    "{} as end_{}",
+                    prop.sql(&self.end_node_alias),
+                    prop.alias
                 ));
             }
         }
@@ -3111,15 +3305,16 @@ impl<'a> VariableLengthCteGenerator<'a> {
         let select_clause = select_items.join(",\n        ");
 
         // FK-edge pattern: direct 2-way join between start and end nodes
-        // start_node.fk_col = end_node.id_col (e.g., child.parent_id = parent.object_id)
+        // start_node.fk_col = end_node.id_col (e.g., child.parent_id = parent.object_id).
+        // Composite FKs pair up column-by-column with the end node's composite ID.
+        let fk_cols = Identifier::from_comma_separated(&self.relationship_from_column);
         let mut query = format!(
-            "    SELECT \n        {select}\n    FROM {start_table} {start}\n    JOIN {end_table} {end} ON {start}.{fk_col} = {end}.{end_id_col}",
+            "    SELECT \n        {select}\n    FROM {start_table} {start}\n    JOIN {end_table} {end} ON {on}",
             select = select_clause,
             start = self.start_node_alias,
             start_table = self.format_table_name(&self.start_node_table),
             end = self.end_node_alias,
-            fk_col = self.relationship_from_column,  // FK column on start node
-            end_id_col = self.end_node_id_column,     // ID column on end node
+            on = fk_cols.to_sql_equality(&self.start_node_alias, &end_id, &self.end_node_alias),
             end_table = self.format_table_name(&self.end_node_table)
         );
 
@@ -3196,11 +3391,13 @@ impl<'a> VariableLengthCteGenerator<'a> {
         let fmap = current_function_mapper();
         let ac = fmap.array_concat();
         let empty_str_arr = fmap.empty_string_array_cast();
+        let end_id = Identifier::from_comma_separated(&self.end_node_id_column);
+        let fk_cols = Identifier::from_comma_separated(&self.relationship_from_column);
         // Build property selections
         // start_id stays the same (notes.txt), end_id becomes new_end
         let mut select_items = vec![
             "vp.start_id".to_string(), // start stays the same
-            format!("{}.{} as end_id", "new_end", self.end_node_id_column), // new parent
+            format!("{} as end_id", emit_id_expr("new_end", &end_id)), // new parent
             "vp.hop_count + 1 as hop_count".to_string(),
         ];
         if self.needs_path_data() {
@@ -3214,7 +3411,7 @@ impl<'a> VariableLengthCteGenerator<'a> {
         // APPEND the new node to path_nodes
         select_items.push(format!(
             "{ac}(vp.path_nodes, {}) as path_nodes",
-            arr(&format!("new_end.{}", self.end_node_id_column))
+            arr(&emit_node_key_expr("new_end", &end_id))
         ));
 
         // Add properties: start properties from CTE, end properties from new joined node
@@ -3223,10 +3420,7 @@ impl<'a> VariableLengthCteGenerator<'a> {
                 select_items.push(format!("vp.start_{} as start_{}", prop.alias, prop.alias));
             }
             if prop.cypher_alias == self.end_cypher_alias {
-                select_items.push(format!(
-                    "{}.{} as end_{}",
-                    "new_end", prop.column_name, prop.alias
-                ));
+                select_items.push(format!("{} as end_{}", prop.sql("new_end"), prop.alias));
             }
         }
 
@@ -3234,7 +3428,7 @@ impl<'a> VariableLengthCteGenerator<'a> {
 
         let mut where_conditions = vec![
             format!("vp.hop_count < {}", max_hops),
-            emit_cycle_check(&format!("new_end.{}", self.end_node_id_column)),
+            emit_cycle_check(&emit_node_key_expr("new_end", &end_id)),
         ];
 
         // Add edge constraints if defined in schema
@@ -3264,14 +3458,13 @@ impl<'a> VariableLengthCteGenerator<'a> {
         // current_node = previous end (e.g., Work)
         // new_end = current_node's parent (e.g., Documents)
         format!(
-            "    SELECT\n        {select}\n    FROM {cte_name} vp\n    JOIN {current_table} current_node ON vp.end_id = current_node.{current_id_col}\n    JOIN {end_table} new_end ON current_node.{fk_col} = new_end.{end_id_col}\n    WHERE {where_clause}",
+            "    SELECT\n        {select}\n    FROM {cte_name} vp\n    JOIN {current_table} current_node ON vp.end_id = {current_id}\n    JOIN {end_table} new_end ON {fk_join}\n    WHERE {where_clause}",
             select = select_clause,
             cte_name = cte_name,
             current_table = self.format_table_name(&self.end_node_table),
-            current_id_col = self.end_node_id_column,
+            current_id = emit_id_expr("current_node", &end_id),
             end_table = self.format_table_name(&self.end_node_table),
-            fk_col = self.relationship_from_column,
-            end_id_col = self.end_node_id_column,
+            fk_join = fk_cols.to_sql_equality("current_node", &end_id, "new_end"),
             where_clause = where_clause
         )
     }
@@ -3282,10 +3475,12 @@ impl<'a> VariableLengthCteGenerator<'a> {
         let fmap = current_function_mapper();
         let ac = fmap.array_concat();
         let empty_str_arr = fmap.empty_string_array_cast();
+        let start_id = Identifier::from_comma_separated(&self.start_node_id_column);
+        let fk_cols = Identifier::from_comma_separated(&self.relationship_from_column);
         // Build property selections
         // The NEW start_id is new_start, end_id stays the same (root)
         let mut select_items = vec![
-            format!("{}.{} as start_id", "new_start", self.start_node_id_column),
+            format!("{} as start_id", emit_id_expr("new_start", &start_id)),
             "vp.end_id".to_string(), // end_id stays the same (root)
             "vp.hop_count + 1 as hop_count".to_string(),
         ];
@@ -3300,16 +3495,13 @@ impl<'a> VariableLengthCteGenerator<'a> {
         // PREPEND the new node to path_nodes
         select_items.push(format!(
             "{ac}({}, vp.path_nodes) as path_nodes",
-            arr(&format!("new_start.{}", self.start_node_id_column))
+            arr(&emit_node_key_expr("new_start", &start_id))
         ));
 
         // Add properties: end properties from CTE, start properties from new joined node
         for prop in &self.properties {
             if prop.cypher_alias == self.start_cypher_alias {
-                select_items.push(format!(
-                    "{}.{} as start_{}",
-                    "new_start", prop.column_name, prop.alias
-                ));
+                select_items.push(format!("{} as start_{}", prop.sql("new_start"), prop.alias));
             }
             if prop.cypher_alias == self.end_cypher_alias {
                 select_items.push(format!("vp.end_{} as end_{}", prop.alias, prop.alias));
@@ -3320,7 +3512,7 @@ impl<'a> VariableLengthCteGenerator<'a> {
 
         let mut where_conditions = vec![
             format!("vp.hop_count < {}", max_hops),
-            emit_cycle_check(&format!("new_start.{}", self.start_node_id_column)),
+            emit_cycle_check(&emit_node_key_expr("new_start", &start_id)),
         ];
 
         // Add edge constraints if defined in schema
@@ -3350,13 +3542,13 @@ impl<'a> VariableLengthCteGenerator<'a> {
         // current_node = previous start (e.g., Documents)
         // new_start = a child of current (e.g., Work where Work.parent_id = Documents.object_id)
         format!(
-            "    SELECT\n        {select}\n    FROM {cte_name} vp\n    JOIN {current_table} current_node ON vp.start_id = current_node.{current_id_col}\n    JOIN {start_table} new_start ON new_start.{fk_col} = current_node.{current_id_col}\n    WHERE {where_clause}",
+            "    SELECT\n        {select}\n    FROM {cte_name} vp\n    JOIN {current_table} current_node ON vp.start_id = {current_id}\n    JOIN {start_table} new_start ON {fk_join}\n    WHERE {where_clause}",
             select = select_clause,
             cte_name = cte_name,
             current_table = self.format_table_name(&self.start_node_table),
-            current_id_col = self.start_node_id_column,
+            current_id = emit_id_expr("current_node", &start_id),
             start_table = self.format_table_name(&self.start_node_table),
-            fk_col = self.relationship_from_column,
+            fk_join = fk_cols.to_sql_equality("new_start", &start_id, "current_node"),
             where_clause = where_clause
         )
     }
@@ -3869,14 +4061,16 @@ impl<'a> VariableLengthCteGenerator<'a> {
         for prop in &self.properties {
             if prop.cypher_alias == self.start_cypher_alias && !self.start_is_denormalized {
                 select_items.push(format!(
-                    "{}.{} as start_{}",
-                    self.start_node_alias, prop.column_name, prop.alias
+                    "{} as start_{}",
+                    prop.sql(&self.start_node_alias),
+                    prop.alias
                 ));
             }
             if prop.cypher_alias == self.end_cypher_alias && !self.end_is_denormalized {
                 select_items.push(format!(
-                    "{}.{} as end_{}",
-                    self.end_node_alias, prop.column_name, prop.alias
+                    "{} as end_{}",
+                    prop.sql(&self.end_node_alias),
+                    prop.alias
                 ));
             }
         }
@@ -4002,8 +4196,9 @@ impl<'a> VariableLengthCteGenerator<'a> {
             }
             if prop.cypher_alias == self.end_cypher_alias && !self.end_is_denormalized {
                 select_items.push(format!(
-                    "{}.{} as end_{}",
-                    self.end_node_alias, prop.column_name, prop.alias
+                    "{} as end_{}",
+                    prop.sql(&self.end_node_alias),
+                    prop.alias
                 ));
             }
         }
@@ -4439,6 +4634,94 @@ mod tests {
             sql
         );
     }
+
+    #[test]
+    fn test_fk_edge_composite_ids_use_tuple_joins_and_cycle_keys() {
+        let schema = create_test_schema();
+        let spec = VariableLengthSpec::range(1, 4);
+        let generator = VariableLengthCteGenerator::new_with_fk_edge(
+            &schema,
+            spec,
+            "fs_objects",
+            "tenant_id, object_id",
+            "fs_objects",
+            "tenant_id, parent_id",
+            "tenant_id, object_id",
+            "fs_objects",
+            "tenant_id, object_id",
+            "child",
+            "parent",
+            "r",
+            vec![],
+            None,
+            Some("start_node.name = 'notes.txt'".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            true,
+        );
+
+        let base = generator.generate_fk_edge_base_case(1);
+        assert!(
+            base.contains(
+                "ON start_node.tenant_id = end_node.tenant_id AND start_node.parent_id = end_node.object_id"
+            ),
+            "composite FK should join column-by-column. SQL: {}",
+            base
+        );
+        assert!(
+            base.contains("concat(toString(start_node.tenant_id), '|', toString(start_node.object_id)) as start_id"),
+            "start_id should stay a pipe-joined string. SQL: {}",
+            base
+        );
+        assert!(
+            base.contains(
+                "[tuple(start_node.tenant_id, start_node.object_id), tuple(end_node.tenant_id, end_node.object_id)] as path_nodes"
+            ),
+            "path_nodes should hold composite keys as tuples. SQL: {}",
+            base
+        );
+
+        // start filter → APPEND expansion toward parents
+        let recursive = generator.generate_fk_edge_recursive_case(4, "vlp_child_parent");
+        assert!(
+            recursive.contains(
+                "vp.end_id = concat(toString(current_node.tenant_id), '|', toString(current_node.object_id))"
+            ),
+            "recursive anchor should match the composite end_id. SQL: {}",
+            recursive
+        );
+        assert!(
+            recursive.contains(
+                "current_node.tenant_id = new_end.tenant_id AND current_node.parent_id = new_end.object_id"
+            ),
+            "recursive FK hop should join column-by-column. SQL: {}",
+            recursive
+        );
+        assert!(
+            recursive
+                .contains("NOT has(vp.path_nodes, tuple(new_end.tenant_id, new_end.object_id))"),
+            "cycle check should compare tuple keys. SQL: {}",
+            recursive
+        );
+    }
+
+    #[test]
+    fn test_single_id_path_nodes_unchanged() {
+        let id = Identifier::Single("user_id".to_string());
+        assert_eq!(emit_node_key_expr("end_node", &id), "end_node.user_id");
+        assert_eq!(
+            emit_node_key_expr("end_node", &id),
+            emit_id_expr("end_node", &id)
+        );
+    }
 }
 
 /// Generates optimized chained JOIN SQL for exact hop count queries
@@ -4541,14 +4824,14 @@ impl ChainedJoinGenerator {
         // Add start node properties
         for prop in &self.properties {
             if prop.cypher_alias == self.start_cypher_alias {
-                select_items.push(format!("s.{} as start_{}", prop.column_name, prop.alias));
+                select_items.push(format!("{} as start_{}", prop.sql("s"), prop.alias));
             }
         }
 
         // Add end node properties
         for prop in &self.properties {
             if prop.cypher_alias == self.end_cypher_alias {
-                select_items.push(format!("e.{} as end_{}", prop.column_name, prop.alias));
+                select_items.push(format!("{} as end_{}", prop.sql("e"), prop.alias));
             }
         }
 
@@ -4668,10 +4951,10 @@ impl ChainedJoinGenerator {
         // Add properties (both start and end reference same node)
         for prop in &self.properties {
             if prop.cypher_alias == self.start_cypher_alias {
-                select_items.push(format!("s.{} as start_{}", prop.column_name, prop.alias));
+                select_items.push(format!("{} as start_{}", prop.sql("s"), prop.alias));
             }
             if prop.cypher_alias == self.end_cypher_alias {
-                select_items.push(format!("s.{} as end_{}", prop.column_name, prop.alias));
+                select_items.push(format!("{} as end_{}", prop.sql("s"), prop.alias));
             }
         }
 
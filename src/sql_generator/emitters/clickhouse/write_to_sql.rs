@@ -4,19 +4,30 @@
 //! `clickgraph-embedded::write_helpers`:
 //!
 //! - `INSERT INTO `db`.`table` (cols) VALUES (row1), (row2), ...`
+//! - MERGE: `INSERT INTO `db`.`table` (cols) SELECT row WHERE
+//!   (SELECT count() FROM `db`.`table` WHERE key = value ...) = 0`
+//! - Edges between MATCH-bound nodes: `INSERT INTO `db`.`table` (cols)
+//!   SELECT ...` over the rendered read pipeline.
 //! - `UPDATE `db`.`table` SET col = expr WHERE id_col IN (subquery)`
 //!   (lightweight; no `SETTINGS` clause at query time — the table must have
 //!   been created with `enable_block_number_column=1, enable_block_offset_column=1`,
 //!   which Phase 3 wires into `data_loader.rs`).
 //! - `DELETE FROM `db`.`table` WHERE id_col IN (subquery)` (lightweight).
+//!   Tables whose engine has no lightweight delete fall back to
+//!   `ALTER TABLE `db`.`table` DELETE WHERE ... SETTINGS mutations_sync = 1`.
 //! - `Sequence` flattens to a `Vec<String>` with one statement per element,
 //!   in execution order; the executor (Phase 3) runs each in turn.
 //!
-//! No `SETTINGS mutations_sync = …` is emitted — Decision 0.7 explicitly
-//! ruled out the mutation path.
+//! Outside that fallback no `SETTINGS mutations_sync = …` is emitted —
+//! Decision 0.7 ruled out the mutation path for MergeTree tables. The only
+//! other query-time setting is the opt-in
+//! read-your-writes variant ([`write_render_to_sql_sync_inserts`]), which
+//! makes each INSERT wait for the async-insert buffer to flush.
 
 use crate::render_plan::plan_builder_helpers::render_expr_to_sql_string;
-use crate::render_plan::write_render::{DeleteOp, InsertOp, RowSource, UpdateOp, WriteRenderPlan};
+use crate::render_plan::write_render::{
+    DeleteKind, DeleteOp, InsertOp, RowSource, UpdateOp, WriteRenderPlan,
+};
 use crate::render_plan::RenderPlan;
 
 /// Render a `WriteRenderPlan` to one or more SQL statements, in execution
@@ -24,24 +35,57 @@ use crate::render_plan::RenderPlan;
 /// runs each in sequence.
 pub fn write_render_to_sql(plan: &WriteRenderPlan) -> Vec<String> {
     let mut out = Vec::new();
-    push_sql(plan, &mut out);
+    push_sql(plan, false, &mut out);
     out
 }
 
-fn push_sql(plan: &WriteRenderPlan, out: &mut Vec<String>) {
+/// Like [`write_render_to_sql`], but every INSERT carries
+/// `SETTINGS wait_for_async_insert = 1` so it only returns once the rows are
+/// visible to the next SELECT, even when the server buffers inserts
+/// (`async_insert = 1`). Lightweight UPDATE / DELETE are already synchronous.
+pub fn write_render_to_sql_sync_inserts(plan: &WriteRenderPlan) -> Vec<String> {
+    let mut out = Vec::new();
+    push_sql(plan, true, &mut out);
+    out
+}
+
+/// `SYSTEM FLUSH ASYNC INSERT QUEUE` restricted to the given `(database, table)`
+/// pairs; flushes every pending async insert when `tables` is empty.
+pub fn flush_async_insert_queue_sql(tables: &[(String, String)]) -> String {
+    if tables.is_empty() {
+        return "SYSTEM FLUSH ASYNC INSERT QUEUE".to_string();
+    }
+    let targets = tables
+        .iter()
+        .map(|(db, table)| format!("`{}`.`{}`", db, table))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("SYSTEM FLUSH ASYNC INSERT QUEUE {}", targets)
+}
+
+fn push_sql(plan: &WriteRenderPlan, sync_inserts: bool, out: &mut Vec<String>) {
     match plan {
+        WriteRenderPlan::Insert(op) if sync_inserts => {
+            out.push(format_insert(op, " SETTINGS wait_for_async_insert = 1"))
+        }
         WriteRenderPlan::Insert(op) => out.push(insert_sql(op)),
         WriteRenderPlan::Update(op) => out.push(update_sql(op)),
         WriteRenderPlan::Delete(op) => out.push(delete_sql(op)),
         WriteRenderPlan::Sequence(seq) => {
             for inner in seq {
-                push_sql(inner, out);
+                push_sql(inner, sync_inserts, out);
             }
         }
     }
 }
 
 fn insert_sql(op: &InsertOp) -> String {
+    format_insert(op, "")
+}
+
+/// `settings` is spliced in before `VALUES` (or the MERGE `SELECT`), where
+/// ClickHouse expects an INSERT's `SETTINGS` clause.
+fn format_insert(op: &InsertOp, settings: &str) -> String {
     let cols = op
         .columns
         .iter()
@@ -49,28 +93,74 @@ fn insert_sql(op: &InsertOp) -> String {
         .collect::<Vec<_>>()
         .join(", ");
 
+    if let Some(source) = &op.source {
+        return format!(
+            "INSERT INTO `{}`.`{}` ({}){} {}",
+            op.database,
+            op.table,
+            cols,
+            settings,
+            render_subquery(source)
+        );
+    }
+
     let rows: Vec<String> = op
         .rows
         .iter()
         .map(|row| {
-            let values = row
-                .iter()
+            row.iter()
                 .map(render_expr_inline)
                 .collect::<Vec<_>>()
-                .join(", ");
-            format!("({})", values)
+                .join(", ")
         })
         .collect();
 
+    if let Some(key) = &op.merge_key {
+        // MERGE writes one row, guarded by a scalar count of matching rows.
+        return format!(
+            "INSERT INTO `{}`.`{}` ({}){} SELECT {} WHERE ({}) = 0",
+            op.database,
+            op.table,
+            cols,
+            settings,
+            rows.first().map(String::as_str).unwrap_or_default(),
+            merge_match_count_sql(op, key, "count()"),
+        );
+    }
+
+    let rows: Vec<String> = rows.iter().map(|values| format!("({})", values)).collect();
     format!(
-        "INSERT INTO `{}`.`{}` ({}) VALUES {}",
+        "INSERT INTO `{}`.`{}` ({}){} VALUES {}",
         op.database,
         op.table,
         cols,
+        settings,
         rows.join(", ")
     )
 }
 
+/// `SELECT <count> FROM table WHERE key_col = value AND ...` over the MERGE
+/// key columns of `op`'s first row.
+fn merge_match_count_sql(op: &InsertOp, key: &[String], count: &str) -> String {
+    let conditions: Vec<String> = key
+        .iter()
+        .filter_map(|k| {
+            let idx = op.columns.iter().position(|c| c == k)?;
+            let value = op.rows.first()?.get(idx)?;
+            Some(format!("`{}` = {}", k, render_expr_inline(value)))
+        })
+        .collect();
+    let filter = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
+    };
+    format!(
+        "SELECT {} FROM `{}`.`{}`{}",
+        count, op.database, op.table, filter
+    )
+}
+
 fn update_sql(op: &UpdateOp) -> String {
     let assignments = op
         .assignments
@@ -90,13 +180,24 @@ fn update_sql(op: &UpdateOp) -> String {
 }
 
 fn delete_sql(op: &DeleteOp) -> String {
-    format!(
-        "DELETE FROM `{}`.`{}` WHERE `{}` IN {}",
-        op.database,
-        op.table,
-        op.id_column,
-        render_id_source(&op.source),
-    )
+    match op.kind {
+        DeleteKind::Lightweight => format!(
+            "DELETE FROM `{}`.`{}` WHERE `{}` IN {}",
+            op.database,
+            op.table,
+            op.id_column,
+            render_id_source(&op.source),
+        ),
+        // Mutations are asynchronous by default; wait so the next statement
+        // (and the caller's next read) sees the rows gone.
+        DeleteKind::Mutation => format!(
+            "ALTER TABLE `{}`.`{}` DELETE WHERE `{}` IN {} SETTINGS mutations_sync = 1",
+            op.database,
+            op.table,
+            op.id_column,
+            render_id_source(&op.source),
+        ),
+    }
 }
 
 /// Render a probe SQL that counts the rows a `DeleteOp` would affect,
@@ -115,6 +216,23 @@ pub fn probe_delete_count_sql(op: &DeleteOp) -> String {
     )
 }
 
+/// Probe SQL counting the existing rows a MERGE `InsertOp` would match, so
+/// `nodes_created` reports 0 when the row already exists. `None` for a plain
+/// INSERT, whose row count is exact.
+pub fn probe_merge_match_count_sql(op: &InsertOp) -> Option<String> {
+    op.merge_key
+        .as_ref()
+        .map(|key| merge_match_count_sql(op, key, "count() AS n"))
+}
+
+/// Probe SQL counting the rows an `INSERT ... SELECT` will write. `None`
+/// for a `VALUES` INSERT, whose row count is exact.
+pub fn probe_insert_select_count_sql(op: &InsertOp) -> Option<String> {
+    op.source
+        .as_ref()
+        .map(|source| format!("SELECT count() AS n FROM ({})", render_subquery(source)))
+}
+
 /// Probe SQL for an `UpdateOp` — same pattern as `probe_delete_count_sql`.
 pub fn probe_update_count_sql(op: &UpdateOp) -> String {
     format!(
@@ -199,6 +317,9 @@ mod tests {
             table: "person".into(),
             columns: vec!["id".into(), "name".into(), "age".into()],
             rows: vec![vec![lit_string("u1"), lit_string("Alice"), lit_int(30)]],
+            merge_key: None,
+            source: None,
+            relationship: false,
         };
         assert_eq!(
             insert_sql(&op),
@@ -213,6 +334,9 @@ mod tests {
             table: "person".into(),
             columns: vec!["id".into()],
             rows: vec![vec![lit_string("u1")], vec![lit_string("u2")]],
+            merge_key: None,
+            source: None,
+            relationship: false,
         };
         assert_eq!(
             insert_sql(&op),
@@ -220,6 +344,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merge_insert_is_guarded_by_match_count() {
+        let mut op = InsertOp {
+            database: "test".into(),
+            table: "person".into(),
+            columns: vec!["id".into(), "name".into()],
+            rows: vec![vec![lit_string("u1"), lit_string("Alice")]],
+            merge_key: Some(vec!["id".into()]),
+            source: None,
+            relationship: false,
+        };
+        assert_eq!(
+            insert_sql(&op),
+            "INSERT INTO `test`.`person` (`id`, `name`) SELECT 'u1', 'Alice' \
+             WHERE (SELECT count() FROM `test`.`person` WHERE `id` = 'u1') = 0"
+        );
+        assert_eq!(
+            probe_merge_match_count_sql(&op).as_deref(),
+            Some("SELECT count() AS n FROM `test`.`person` WHERE `id` = 'u1'")
+        );
+        assert_eq!(
+            write_render_to_sql_sync_inserts(&WriteRenderPlan::Insert(op.clone()))[0],
+            "INSERT INTO `test`.`person` (`id`, `name`) SETTINGS wait_for_async_insert = 1 \
+             SELECT 'u1', 'Alice' WHERE (SELECT count() FROM `test`.`person` WHERE `id` = 'u1') = 0"
+        );
+
+        // An empty key only inserts into an empty table.
+        op.merge_key = Some(vec![]);
+        assert!(insert_sql(&op).ends_with("WHERE (SELECT count() FROM `test`.`person`) = 0"));
+
+        op.merge_key = None;
+        assert_eq!(probe_merge_match_count_sql(&op), None);
+    }
+
     #[test]
     fn delete_with_literal_ids() {
         let op = DeleteOp {
@@ -227,6 +385,7 @@ mod tests {
             table: "person".into(),
             id_column: "id".into(),
             source: RowSource::Ids(vec![lit_string("u1"), lit_string("u2")]),
+            kind: DeleteKind::Lightweight,
         };
         assert_eq!(
             delete_sql(&op),
@@ -234,6 +393,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn delete_falls_back_to_synchronous_mutation() {
+        let op = DeleteOp {
+            database: "test".into(),
+            table: "person".into(),
+            id_column: "id".into(),
+            source: RowSource::Ids(vec![lit_string("u1")]),
+            kind: DeleteKind::Mutation,
+        };
+        assert_eq!(
+            delete_sql(&op),
+            "ALTER TABLE `test`.`person` DELETE WHERE `id` IN ('u1') \
+             SETTINGS mutations_sync = 1"
+        );
+        assert_eq!(
+            probe_delete_count_sql(&op),
+            "SELECT count() AS n FROM `test`.`person` WHERE `id` IN ('u1')"
+        );
+    }
+
     #[test]
     fn update_with_literal_ids() {
         let op = UpdateOp {
@@ -257,12 +436,14 @@ mod tests {
                 table: "knows".into(),
                 id_column: "from_id".into(),
                 source: RowSource::Ids(vec![lit_string("u1")]),
+                kind: DeleteKind::Lightweight,
             }),
             WriteRenderPlan::Delete(DeleteOp {
                 database: "t".into(),
                 table: "person".into(),
                 id_column: "id".into(),
                 source: RowSource::Ids(vec![lit_string("u1")]),
+                kind: DeleteKind::Lightweight,
             }),
         ]);
         let sql = write_render_to_sql(&op);
@@ -271,6 +452,49 @@ mod tests {
         assert!(sql[1].contains("person"));
     }
 
+    #[test]
+    fn sync_inserts_adds_settings_to_inserts_only() {
+        let op = WriteRenderPlan::Sequence(vec![
+            WriteRenderPlan::Insert(InsertOp {
+                database: "t".into(),
+                table: "person".into(),
+                columns: vec!["id".into()],
+                rows: vec![vec![lit_string("u1")]],
+                merge_key: None,
+                source: None,
+                relationship: false,
+            }),
+            WriteRenderPlan::Delete(DeleteOp {
+                database: "t".into(),
+                table: "person".into(),
+                id_column: "id".into(),
+                source: RowSource::Ids(vec![lit_string("u2")]),
+                kind: DeleteKind::Lightweight,
+            }),
+        ]);
+        let sql = write_render_to_sql_sync_inserts(&op);
+        assert_eq!(
+            sql[0],
+            "INSERT INTO `t`.`person` (`id`) SETTINGS wait_for_async_insert = 1 VALUES ('u1')"
+        );
+        assert_eq!(sql[1], "DELETE FROM `t`.`person` WHERE `id` IN ('u2')");
+    }
+
+    #[test]
+    fn flush_queue_lists_target_tables() {
+        assert_eq!(
+            flush_async_insert_queue_sql(&[
+                ("t".to_string(), "person".to_string()),
+                ("t".to_string(), "knows".to_string()),
+            ]),
+            "SYSTEM FLUSH ASYNC INSERT QUEUE `t`.`person`, `t`.`knows`"
+        );
+        assert_eq!(
+            flush_async_insert_queue_sql(&[]),
+            "SYSTEM FLUSH ASYNC INSERT QUEUE"
+        );
+    }
+
     #[test]
     fn string_escape_in_insert() {
         let op = InsertOp {
@@ -278,6 +502,9 @@ mod tests {
             table: "p".into(),
             columns: vec!["name".into()],
             rows: vec![vec![lit_string("O'Brien")]],
+            merge_key: None,
+            source: None,
+            relationship: false,
         };
         let sql = insert_sql(&op);
         assert!(
@@ -10,6 +10,10 @@ impl FunctionMapper for ClickhouseFunctionMapper {
         "groupArray"
     }
 
+    fn collect_set(&self) -> &'static str {
+        "groupUniqArray"
+    }
+
     fn array_element(&self) -> &'static str {
         "arrayElement"
     }
@@ -78,6 +82,26 @@ impl FunctionMapper for ClickhouseFunctionMapper {
         format!("[{elems}]")
     }
 
+    fn case_expression(
+        &self,
+        operand: Option<&str>,
+        branches: &[(String, String)],
+        else_expr: Option<&str>,
+    ) -> String {
+        let mut args: Vec<&str> = operand.into_iter().collect();
+        for (when, then) in branches {
+            args.push(when);
+            args.push(then);
+        }
+        args.push(else_expr.unwrap_or("NULL"));
+        let function = if operand.is_some() {
+            "caseWithExpression"
+        } else {
+            "multiIf"
+        };
+        format!("{function}({})", args.join(", "))
+    }
+
     fn tuple_constructor(&self) -> &'static str {
         "tuple"
     }
@@ -124,6 +148,14 @@ impl FunctionMapper for ClickhouseFunctionMapper {
         " NULLS LAST"
     }
 
+    fn lower_case(&self) -> &'static str {
+        "lowerUTF8"
+    }
+
+    fn strips_accents(&self) -> bool {
+        true
+    }
+
     fn percentile_aggregate(&self, expr: &str, percentile: &str, continuous: bool) -> String {
         if continuous {
             // percentileCont = linear interpolation. ClickHouse quantiles are
@@ -155,6 +187,10 @@ impl FunctionMapper for ClickhouseFunctionMapper {
             )
         }
     }
+
+    fn hash_bucket(&self, columns: &str, buckets: u64) -> String {
+        format!("modulo(cityHash64({columns}), {buckets})")
+    }
 }
 
 #[cfg(test)]
@@ -186,6 +222,24 @@ mod tests {
         assert_eq!(m.array_slice("a", "2", None), "arraySlice(a, 2)");
     }
 
+    #[test]
+    fn case_expression_uses_case_with_expression_and_multi_if() {
+        let m = ClickhouseFunctionMapper;
+        let branches = [("'a'".to_string(), "1".to_string())];
+        assert_eq!(
+            m.case_expression(Some("x"), &branches, Some("0")),
+            "caseWithExpression(x, 'a', 1, 0)"
+        );
+        let branches = [
+            ("x > 1".to_string(), "'big'".to_string()),
+            ("x > 0".to_string(), "'small'".to_string()),
+        ];
+        assert_eq!(
+            m.case_expression(None, &branches, None),
+            "multiIf(x > 1, 'big', x > 0, 'small', NULL)"
+        );
+    }
+
     #[test]
     fn epoch_millis_timestamp_roundtrip_uses_clickhouse_functions() {
         let m = ClickhouseFunctionMapper;
@@ -239,5 +293,9 @@ mod tests {
             m.percentile_aggregate("t.x", "0.9", false),
             "arrayElementOrNull(arraySort(groupArray(t.x)), greatest(1, toUInt32(ceil(0.9 * count(t.x)))))"
         );
+        assert_eq!(
+            m.hash_bucket("e.a, e.b", 1_000_000),
+            "modulo(cityHash64(e.a, e.b), 1000000)"
+        );
     }
 }
@@ -44,6 +44,10 @@ impl FunctionMapper for DatabricksFunctionMapper {
         "collect_list"
     }
 
+    fn collect_set(&self) -> &'static str {
+        "collect_set"
+    }
+
     fn array_element(&self) -> &'static str {
         // Spark/Databricks: element_at(array, index) is 1-based, matching CH's arrayElement.
         "element_at"
@@ -150,6 +154,28 @@ impl FunctionMapper for DatabricksFunctionMapper {
         format!("array({elems})")
     }
 
+    fn case_expression(
+        &self,
+        operand: Option<&str>,
+        branches: &[(String, String)],
+        else_expr: Option<&str>,
+    ) -> String {
+        // Spark has neither `caseWithExpression` nor `multiIf`; ANSI CASE
+        // covers both forms.
+        let mut sql = String::from("CASE");
+        if let Some(operand) = operand {
+            sql.push_str(&format!(" {operand}"));
+        }
+        for (when, then) in branches {
+            sql.push_str(&format!(" WHEN {when} THEN {then}"));
+        }
+        if let Some(else_expr) = else_expr {
+            sql.push_str(&format!(" ELSE {else_expr}"));
+        }
+        sql.push_str(" END");
+        sql
+    }
+
     fn tuple_constructor(&self) -> &'static str {
         // Spark's `struct(a, b, c)` is the analogue of CH's `tuple()`:
         // element-wise ordering and equality match.
@@ -220,6 +246,14 @@ impl FunctionMapper for DatabricksFunctionMapper {
         " NULLS LAST"
     }
 
+    fn lower_case(&self) -> &'static str {
+        "lower"
+    }
+
+    fn strips_accents(&self) -> bool {
+        false
+    }
+
     fn percentile_aggregate(&self, expr: &str, percentile: &str, continuous: bool) -> String {
         if continuous {
             // Spark `percentile(expr, p)` does linear interpolation, matching
@@ -242,6 +276,10 @@ impl FunctionMapper for DatabricksFunctionMapper {
             )
         }
     }
+
+    fn hash_bucket(&self, columns: &str, buckets: u64) -> String {
+        format!("pmod(xxhash64({columns}), {buckets})")
+    }
 }
 
 #[cfg(test)]
@@ -255,6 +293,7 @@ mod tests {
     fn databricks_spellings() {
         let m = for_dialect(SqlDialect::Databricks);
         assert_eq!(m.collect_list(), "collect_list");
+        assert_eq!(m.collect_set(), "collect_set");
         assert_eq!(m.array_element(), "element_at");
         assert_eq!(m.count_if(), "count_if");
         assert_eq!(
@@ -310,6 +349,25 @@ mod tests {
             m.percentile_aggregate("t.x", "0.9", false),
             "try_element_at(array_sort(collect_list(t.x)), greatest(1, cast(ceil(0.9 * count(t.x)) as int)))"
         );
+        assert_eq!(
+            m.hash_bucket("e.a, e.b", 1_000_000),
+            "pmod(xxhash64(e.a, e.b), 1000000)"
+        );
+    }
+
+    #[test]
+    fn case_expression_is_ansi_case() {
+        let m = for_dialect(SqlDialect::Databricks);
+        let branches = [("'a'".to_string(), "1".to_string())];
+        assert_eq!(
+            m.case_expression(Some("x"), &branches, Some("0")),
+            "CASE x WHEN 'a' THEN 1 ELSE 0 END"
+        );
+        let branches = [("x > 1".to_string(), "'big'".to_string())];
+        assert_eq!(
+            m.case_expression(None, &branches, None),
+            "CASE WHEN x > 1 THEN 'big' END"
+        );
     }
 
     /// Documented structural gap: `array_count` has no clean Spark mapping.
@@ -33,6 +33,11 @@ pub(crate) trait FunctionMapper: Send + Sync {
     /// Collect into a list aggregate. CH: `groupArray`. Spark: `collect_list`.
     fn collect_list(&self) -> &'static str;
 
+    /// Collect the distinct values into a list (`collect(DISTINCT x)`).
+    /// CH: `groupUniqArray`. Spark: `collect_set`. Both skip NULLs, like
+    /// Cypher's `collect`.
+    fn collect_set(&self) -> &'static str;
+
     /// 1-based array indexing. CH: `arrayElement`. Spark: `element_at`.
     fn array_element(&self) -> &'static str;
 
@@ -122,6 +127,18 @@ pub(crate) trait FunctionMapper: Send + Sync {
     /// rendered expressions and pass them here.
     fn array_literal(&self, elems: &str) -> String;
 
+    /// `CASE` from pre-rendered parts: `operand` for a simple CASE
+    /// (`CASE x WHEN v THEN r`), `None` for a searched one (`CASE WHEN c
+    /// THEN r`). CH: `caseWithExpression(x, v1, r1, ..., else)` /
+    /// `multiIf(c1, r1, ..., else)`, with `NULL` for a missing ELSE.
+    /// Spark: ANSI `CASE ... END`.
+    fn case_expression(
+        &self,
+        operand: Option<&str>,
+        branches: &[(String, String)],
+        else_expr: Option<&str>,
+    ) -> String;
+
     /// Tuple / struct constructor for composite-key comparisons (e.g.,
     /// `tuple(a, b) = tuple(c, d)`). CH: `tuple`. Spark: `struct`.
     /// Both spellings preserve element-wise ordering and equality.
@@ -198,6 +215,16 @@ pub(crate) trait FunctionMapper: Send + Sync {
     /// normalization pass — see #556.
     fn id_order_key_nulls_clause(&self) -> &'static str;
 
+    /// Lowercasing function for case-insensitive collation keys. Must be
+    /// Unicode-aware: CH's plain `lower` only folds ASCII, so CH uses
+    /// `lowerUTF8`; Spark `lower` already handles Unicode.
+    fn lower_case(&self) -> &'static str;
+
+    /// Whether accent-insensitive collation keys can be built. CH strips
+    /// combining marks after `normalizeUTF8NFD`; Spark has no Unicode
+    /// normalization function, so `ai` collations are ignored there.
+    fn strips_accents(&self) -> bool;
+
     /// Render an openCypher percentile aggregate — `percentileCont(expr, p)` or
     /// `percentileDisc(expr, p)` — honoring the percentile argument `p` (#639).
     ///
@@ -223,6 +250,14 @@ pub(crate) trait FunctionMapper: Send + Sync {
     /// `expr` and `percentile` are pre-rendered SQL fragments; `continuous`
     /// selects Cont vs Disc.
     fn percentile_aggregate(&self, expr: &str, percentile: &str, continuous: bool) -> String;
+
+    /// Deterministic bucket in `[0, buckets)` for a row, hashed from the
+    /// comma-separated `columns`. Used by the `{sample: f}` edge hint, which
+    /// keeps a row when its bucket falls below `f * buckets`: the same edge is
+    /// kept (or dropped) on every scan, so a sampled traversal sees one
+    /// consistent subgraph. CH `cityHash64` is unsigned; Spark `xxhash64` is a
+    /// signed long, hence `pmod`.
+    fn hash_bucket(&self, columns: &str, buckets: u64) -> String;
 }
 
 /// Returns the function mapper for the active SQL dialect, read from the
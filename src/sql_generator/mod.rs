@@ -85,6 +85,13 @@ impl SqlDialect {
     pub fn supports_final_keyword(&self) -> bool {
         matches!(self, SqlDialect::ClickHouse)
     }
+
+    /// Whether this dialect has `GLOBAL JOIN` / `GLOBAL IN`, which run the
+    /// right-hand side once on the initiator and send it to every shard of a
+    /// ClickHouse cluster instead of joining each shard with its own rows.
+    pub fn supports_global_joins(&self) -> bool {
+        matches!(self, SqlDialect::ClickHouse)
+    }
 }
 
 /// Renders a `RenderPlan` into SQL text for a target dialect.
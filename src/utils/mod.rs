@@ -1,5 +1,6 @@
 pub mod cte_column_naming;
 pub mod cte_naming;
 pub mod id_encoding;
+pub mod redaction;
 pub mod serde_arc;
 pub mod serde_arc_vec;
@@ -0,0 +1,282 @@
+//! Redaction of literals in Cypher text, generated SQL and error messages.
+//!
+//! Values embedded in a query — `WHERE u.email = 'alice@example.com'` — end
+//! up in logs, the slow-query ring and error responses. With redaction on
+//! (`CLICKGRAPH_REDACT_LITERALS`), string and number literals are replaced
+//! before the text leaves the process:
+//!
+//! - `elide`: `'alice@example.com'` → `'?'`, `42` → `?`
+//! - `hash`: `'alice@example.com'` → `'#<8 hex digits>'`, `42` → `#<8 hex
+//!   digits>` — the first 4 bytes of the literal's SHA-256, so equal values
+//!   can still be correlated across log lines without being readable
+//!
+//! Identifiers are kept: backquoted names, `$param` placeholders and digits
+//! inside names (`r1`, `u_2`). In SQL, double quotes delimit identifiers
+//! (`AS "u.name"`) and are kept too; in Cypher and free-form messages they
+//! delimit strings and are redacted.
+
+use std::borrow::Cow;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// How literals are rewritten before text is logged or returned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LiteralRedaction {
+    /// Text is passed through unchanged
+    #[default]
+    Off,
+    /// Literals become `?`
+    Elide,
+    /// Literals become a short hash of their value
+    Hash,
+}
+
+#[derive(Debug, Error)]
+#[error("expected off, elide or hash, got '{0}'")]
+pub struct ParseLiteralRedactionError(String);
+
+impl FromStr for LiteralRedaction {
+    type Err = ParseLiteralRedactionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "" | "off" | "false" | "none" => Ok(LiteralRedaction::Off),
+            "elide" => Ok(LiteralRedaction::Elide),
+            "hash" => Ok(LiteralRedaction::Hash),
+            _ => Err(ParseLiteralRedactionError(s.to_string())),
+        }
+    }
+}
+
+/// Process-wide mode, set once at server startup.
+static MODE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_mode(mode: LiteralRedaction) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+pub fn mode() -> LiteralRedaction {
+    match MODE.load(Ordering::Relaxed) {
+        1 => LiteralRedaction::Elide,
+        2 => LiteralRedaction::Hash,
+        _ => LiteralRedaction::Off,
+    }
+}
+
+/// Redact Cypher text or an error message under the current mode.
+pub fn redact(text: &str) -> Cow<'_, str> {
+    redact_with(text, mode(), true)
+}
+
+/// Redact generated SQL under the current mode.
+pub fn redact_sql(text: &str) -> Cow<'_, str> {
+    redact_with(text, mode(), false)
+}
+
+/// Replace the literals of `text`; `double_quoted_strings` says whether
+/// `"..."` is a string (Cypher) rather than an identifier (SQL).
+pub fn redact_with(
+    text: &str,
+    mode: LiteralRedaction,
+    double_quoted_strings: bool,
+) -> Cow<'_, str> {
+    if mode == LiteralRedaction::Off {
+        return Cow::Borrowed(text);
+    }
+
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    let mut copied = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        let is_string = c == b'\'' || (c == b'"' && double_quoted_strings);
+        if is_string {
+            let (end, closed) = quoted_end(bytes, i);
+            let content_end = if closed { end - 1 } else { end };
+            out.push_str(&text[copied..i]);
+            out.push(c as char);
+            out.push_str(&replacement(&text[i + 1..content_end], mode));
+            out.push(c as char);
+            i = end;
+            copied = i;
+        } else if c == b'`' || c == b'"' {
+            // Quoted identifier: keep as written
+            i = quoted_end(bytes, i).0;
+        } else if c.is_ascii_digit() && !follows_identifier(bytes, i) {
+            let end = number_end(bytes, i);
+            out.push_str(&text[copied..i]);
+            out.push_str(&replacement(&text[i..end], mode));
+            i = end;
+            copied = i;
+        } else if is_ident_byte(c) {
+            // Skip the whole word so digits inside names stay
+            while i < bytes.len() && is_ident_byte(bytes[i]) {
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    out.push_str(&text[copied..]);
+    Cow::Owned(out)
+}
+
+fn replacement(literal: &str, mode: LiteralRedaction) -> String {
+    match mode {
+        LiteralRedaction::Off => literal.to_string(),
+        LiteralRedaction::Elide => "?".to_string(),
+        LiteralRedaction::Hash => {
+            let digest = Sha256::digest(literal.as_bytes());
+            format!("#{}", hex::encode(&digest[..4]))
+        }
+    }
+}
+
+/// Index just past the closing quote of the literal opening at `start`, and
+/// whether it was closed (unterminated literals run to the end of the text).
+/// Handles `\` escapes and doubled quotes.
+fn quoted_end(bytes: &[u8], start: usize) -> (usize, bool) {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if quote != b'`' => i += 2,
+            b if b == quote => {
+                if bytes.get(i + 1) == Some(&quote) {
+                    i += 2;
+                } else {
+                    return (i + 1, true);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    (bytes.len(), false)
+}
+
+/// Index just past the number starting at `start`: digits, an optional
+/// fraction and exponent, or a `0x` hex literal.
+fn number_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    if bytes[i] == b'0' && matches!(bytes.get(i + 1), Some(b'x' | b'X')) {
+        i += 2;
+        while i < bytes.len() && bytes[i].is_ascii_hexdigit() {
+            i += 1;
+        }
+        return i;
+    }
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    if bytes.get(i) == Some(&b'.') && bytes.get(i + 1).is_some_and(u8::is_ascii_digit) {
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        let digits_at = if matches!(bytes.get(i + 1), Some(b'+' | b'-')) {
+            i + 2
+        } else {
+            i + 1
+        };
+        if bytes.get(digits_at).is_some_and(u8::is_ascii_digit) {
+            i = digits_at;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+    }
+    i
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || b >= 0x80
+}
+
+fn follows_identifier(bytes: &[u8], i: usize) -> bool {
+    i > 0 && is_ident_byte(bytes[i - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elide(text: &str) -> String {
+        redact_with(text, LiteralRedaction::Elide, true).into_owned()
+    }
+
+    #[test]
+    fn off_borrows_the_input() {
+        let text = "MATCH (u) WHERE u.email = 'a@b.c' RETURN u";
+        assert!(matches!(
+            redact_with(text, LiteralRedaction::Off, true),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn elides_string_and_number_literals() {
+        assert_eq!(
+            elide(
+                r#"MATCH (u:User) WHERE u.email = "alice@x.io" AND u.age > 42.5 RETURN u.name LIMIT 10"#
+            ),
+            r#"MATCH (u:User) WHERE u.email = "?" AND u.age > ? RETURN u.name LIMIT ?"#
+        );
+        assert_eq!(
+            elide("WHERE u.name = 'O''Brien' OR u.name = 'it\\'s' OR u.id = 0x1F"),
+            "WHERE u.name = '?' OR u.name = '?' OR u.id = ?"
+        );
+    }
+
+    #[test]
+    fn keeps_identifiers_and_parameters() {
+        assert_eq!(
+            elide("MATCH (u1)-[r2*1..3]->(`weird 7`) WHERE u1.x = $p1 RETURN r2"),
+            "MATCH (u1)-[r2*?..?]->(`weird 7`) WHERE u1.x = $p1 RETURN r2"
+        );
+    }
+
+    #[test]
+    fn sql_keeps_double_quoted_aliases() {
+        let sql =
+            r#"SELECT u_1.full_name AS "u.name" FROM db.users AS u_1 WHERE u_1.email = 'a@b.c'"#;
+        assert_eq!(
+            redact_with(sql, LiteralRedaction::Elide, false),
+            r#"SELECT u_1.full_name AS "u.name" FROM db.users AS u_1 WHERE u_1.email = '?'"#
+        );
+    }
+
+    #[test]
+    fn hash_is_stable_and_hides_the_value() {
+        let a = redact_with("u.email = 'alice@x.io'", LiteralRedaction::Hash, true);
+        let b = redact_with("v.email = 'alice@x.io'", LiteralRedaction::Hash, true);
+        assert!(!a.contains("alice"));
+        assert_eq!(a.trim_start_matches("u."), b.trim_start_matches("v."));
+        assert!(a.starts_with("u.email = '#"), "{a}");
+    }
+
+    #[test]
+    fn unterminated_string_is_redacted_to_the_end() {
+        assert_eq!(elide("WHERE u.name = 'secret"), "WHERE u.name = '?'");
+    }
+
+    #[test]
+    fn parses_modes() {
+        assert_eq!(
+            "HASH".parse::<LiteralRedaction>().unwrap(),
+            LiteralRedaction::Hash
+        );
+        assert_eq!(
+            "".parse::<LiteralRedaction>().unwrap(),
+            LiteralRedaction::Off
+        );
+        assert!("mask".parse::<LiteralRedaction>().is_err());
+    }
+}
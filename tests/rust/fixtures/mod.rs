@@ -2,6 +2,13 @@
 //!
 //! This module provides shared test data and utilities for all test types.
 
+use async_trait::async_trait;
+use serde_json::Value;
+
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::GLOBAL_SCHEMAS;
+
 /// Sample graph schema for testing
 pub const TEST_GRAPH_SCHEMA: &str = r#"
 nodes:
@@ -40,4 +47,45 @@ pub fn create_test_data() -> Vec<(String, String)> {
         ("full_name".to_string(), "Alice Smith".to_string()),
         ("age".to_string(), "28".to_string()),
     ]
-}
\ No newline at end of file
+}
+
+/// Executor that runs nothing: every query returns no rows. For tests that
+/// only look at the generated SQL or at errors raised before execution.
+pub struct NoopExecutor;
+
+#[async_trait]
+impl QueryExecutor for NoopExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        Ok(Vec::new())
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+/// Register the social benchmark schema as the `default` graph, once per
+/// test binary.
+pub async fn ensure_default_schema_registered() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let schema = GraphSchemaConfig::from_yaml_file(
+        "benchmarks/social_network/schemas/social_benchmark.yaml",
+    )
+    .expect("load benchmark schema")
+    .to_graph_schema()
+    .expect("convert benchmark schema");
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    map.entry("default".to_string()).or_insert(schema);
+}
@@ -0,0 +1,321 @@
+//! Keeps `clickgraph-api-client` in step with the server: every route it
+//! knows is mounted, its request types parse as the handlers' payloads, and
+//! the typed client works end-to-end against the real router.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::graph_catalog::schema_discovery as server_discovery;
+use clickgraph::server::{
+    build_router, bulk_import, handlers, models, subgraph_export, AppState, GLOBAL_SCHEMAS,
+};
+use clickgraph_api_client::{self as api, routes, Client};
+
+struct StubExecutor;
+
+#[async_trait]
+impl QueryExecutor for StubExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        Ok(vec![json!({"name": "Alice"})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_default_registered() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    if !map.contains_key("default") {
+        let schema = GraphSchemaConfig::from_yaml_file(
+            "benchmarks/social_network/schemas/social_benchmark.yaml",
+        )
+        .expect("load benchmark schema")
+        .to_graph_schema()
+        .expect("convert benchmark schema");
+        map.insert("default".to_string(), schema);
+    }
+}
+
+fn router() -> axum::Router {
+    let state = AppState {
+        executor: Arc::new(StubExecutor),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    build_router(state, &ServerConfig::default())
+}
+
+/// Serialize a client type and parse it back as the server's type.
+fn as_server<C: Serialize, S: DeserializeOwned>(value: &C) -> S {
+    let json = serde_json::to_value(value).expect("serialize client type");
+    serde_json::from_value(json.clone())
+        .unwrap_or_else(|e| panic!("server rejected client JSON {json}: {e}"))
+}
+
+#[tokio::test]
+async fn every_client_route_is_mounted() {
+    ensure_default_registered().await;
+    for route in routes::ALL {
+        let uri = routes::path(*route, "default");
+        let resp = router()
+            .oneshot(
+                Request::builder()
+                    .method(route.method)
+                    .uri(&uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from("{}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = resp.status();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        // Handlers may 404 an unknown name, but always with a message; the
+        // router's own 404 for an unmounted path has an empty body.
+        assert!(
+            status != StatusCode::METHOD_NOT_ALLOWED
+                && !(status == StatusCode::NOT_FOUND && body.is_empty()),
+            "{} {uri} is not mounted (status {status})",
+            route.method
+        );
+    }
+}
+
+#[test]
+fn client_requests_parse_as_handler_payloads() {
+    let query: models::QueryRequest = as_server(
+        &api::QueryRequest::new("MATCH (u:User) RETURN u.name")
+            .schema("social")
+            .parameter("id", 1)
+            .role("analyst"),
+    );
+    assert_eq!(query.schema_name.as_deref(), Some("social"));
+    assert_eq!(query.parameters.unwrap()["id"], json!(1));
+    assert_eq!(query.role.as_deref(), Some("analyst"));
+
+    let formatted: models::QueryRequest = as_server(&api::QueryRequest {
+        format: Some(api::OutputFormat::CSVWithNames),
+        stream: Some(true),
+        ..api::QueryRequest::new("RETURN 1")
+    });
+    assert!(formatted.format.is_some());
+    assert_eq!(formatted.stream, Some(true));
+
+    let paged: models::QueryRequest = as_server(&api::QueryRequest {
+        max_response_bytes: Some(4096),
+        continuation_token: Some("djE6".into()),
+        ..api::QueryRequest::new("RETURN 1")
+    });
+    assert_eq!(paged.max_response_bytes, Some(4096));
+    assert_eq!(paged.continuation_token.as_deref(), Some("djE6"));
+
+    let fresh: models::QueryRequest = as_server(&api::QueryRequest {
+        max_staleness_secs: Some(30),
+        on_stale: Some(api::StaleAction::Wait),
+        timeout_ms: Some(2_000),
+        ..api::QueryRequest::new("RETURN 1")
+    });
+    assert_eq!(fresh.max_staleness_secs, Some(30));
+    assert_eq!(fresh.timeout_ms, Some(2_000));
+    assert_eq!(
+        fresh.on_stale,
+        Some(clickgraph::server::freshness::StaleAction::Wait)
+    );
+
+    let overridden: models::QueryRequest = as_server(&api::QueryRequest {
+        schema_overrides: Some("nodes: []".into()),
+        ..api::QueryRequest::new("RETURN 1")
+    });
+    assert_eq!(overridden.schema_overrides.as_deref(), Some("nodes: []"));
+
+    let tuned: models::QueryRequest = as_server(
+        &api::QueryRequest::new("RETURN 1")
+            .setting("join_algorithm", "partial_merge")
+            .setting("max_memory_usage", 1_000_000),
+    );
+    let settings = tuned.settings.unwrap();
+    assert_eq!(settings["join_algorithm"], json!("partial_merge"));
+    assert_eq!(settings["max_memory_usage"], json!(1_000_000));
+
+    let export: subgraph_export::ExportRequest = as_server(&api::ExportRequest {
+        labels: vec!["User".into()],
+        format: api::ExportFormat::Gexf,
+        ..api::ExportRequest::default()
+    });
+    assert_eq!(export.labels, vec!["User"]);
+    assert_eq!(export.format, subgraph_export::ExportFormat::Gexf);
+
+    let import: bulk_import::ImportRequest = as_server(&api::ImportRequest {
+        mappings: vec![api::ImportMapping {
+            source: "s3://bucket/users.parquet".into(),
+            label: Some("User".into()),
+            columns: HashMap::from([("name".into(), "full_name".into())]),
+            ..api::ImportMapping::default()
+        }],
+        ..api::ImportRequest::default()
+    });
+    assert_eq!(import.mappings[0].label.as_deref(), Some("User"));
+    assert_eq!(import.mappings[0].columns["name"], "full_name");
+
+    let sql: models::SqlGenerationRequest = as_server(&api::SqlGenerationRequest {
+        target_database: Some("clickhouse".into()),
+        include_plan: Some(true),
+        ..api::SqlGenerationRequest::new("MATCH (n) RETURN n")
+    });
+    assert_eq!(sql.include_plan, Some(true));
+
+    let load: handlers::LoadSchemaRequest = as_server(&api::SchemaLoadRequest {
+        schema_name: "social".into(),
+        config_content: "graph_schema: {}".into(),
+        validate_schema: Some(false),
+    });
+    assert_eq!(load.schema_name, "social");
+    assert_eq!(load.validate_schema, Some(false));
+
+    let lint: handlers::LintSchemaRequest = as_server(&api::SchemaLintRequest {
+        config_content: "graph_schema: {}".into(),
+        fail_on: Some("warning".into()),
+        ..api::SchemaLintRequest::default()
+    });
+    assert_eq!(lint.live, None);
+    assert_eq!(lint.fail_on.as_deref(), Some("warning"));
+
+    let introspect: handlers::IntrospectRequest = as_server(&api::IntrospectRequest {
+        database: "social".into(),
+    });
+    assert_eq!(introspect.database, "social");
+
+    let draft: handlers::DraftRequestPayload = as_server(&api::DraftRequest {
+        database: "social".into(),
+        schema_name: "social".into(),
+        nodes: vec![api::NodeHint {
+            table: "users".into(),
+            label: "User".into(),
+            node_id: "user_id".into(),
+        }],
+        edges: Some(vec![api::EdgeHint {
+            table: "follows".into(),
+            edge_type: "FOLLOWS".into(),
+            from_node: "User".into(),
+            to_node: "User".into(),
+            from_id: "follower_id".into(),
+            to_id: "followed_id".into(),
+        }]),
+        fk_edges: None,
+        options: Some(api::DraftOptions {
+            auto_discover_columns: Some(true),
+        }),
+    });
+    assert_eq!(draft.nodes.len(), 1);
+    assert_eq!(draft.edges.unwrap()[0].edge_type, "FOLLOWS");
+}
+
+#[test]
+fn server_introspect_response_decodes_as_client_type() {
+    let server = server_discovery::IntrospectResponse {
+        database: "social".into(),
+        tables: vec![server_discovery::TableMetadata {
+            name: "users".into(),
+            kind: server_discovery::SourceKind::View,
+            graph_source: true,
+            note: Some("view".into()),
+            view_parameters: vec!["tenant_id".into()],
+            columns: vec![server_discovery::ColumnMetadata {
+                name: "user_id".into(),
+                data_type: "UInt64".into(),
+                is_primary_key: true,
+                is_in_order_by: true,
+            }],
+            row_count: Some(3),
+            sample: vec![json!({"user_id": 1})],
+        }],
+        next_step: "POST /schemas/draft".into(),
+        suggestions: vec![],
+        inferred_edges: vec![clickgraph::graph_catalog::InferredEdge {
+            from_table: "posts".into(),
+            from_column: "author_id".into(),
+            to_table: "users".into(),
+            to_column: "user_id".into(),
+            match_percent: 99.5,
+            sampled_values: 1000,
+            distinct_values: 200,
+            cardinality: "many_to_one".into(),
+            confidence: "high".into(),
+        }],
+    };
+    let client: api::IntrospectResponse = as_server(&server);
+    assert_eq!(client.inferred_edges[0].to_column, "user_id");
+    assert_eq!(client.inferred_edges[0].match_percent, 99.5);
+    assert_eq!(client.tables[0].columns[0].data_type, "UInt64");
+    assert_eq!(client.tables[0].row_count, Some(3));
+    assert_eq!(client.tables[0].kind, api::SourceKind::View);
+    assert_eq!(client.tables[0].view_parameters, vec!["tenant_id"]);
+}
+
+#[tokio::test]
+async fn typed_client_round_trips_through_the_router() {
+    ensure_default_registered().await;
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router()).await });
+    let client = Client::new(&format!("http://{addr}")).unwrap();
+
+    let health = client.health().await.unwrap();
+    assert_eq!(health.status, "healthy");
+
+    let rows = client
+        .query(&api::QueryRequest::new(
+            "MATCH (u:User) RETURN u.name AS name",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(rows.results, vec![json!({"name": "Alice"})]);
+
+    let sql = client
+        .query_sql(&api::SqlGenerationRequest::new(
+            "MATCH (u:User) RETURN u.name AS name",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(sql.target_database, "clickhouse");
+    assert!(
+        sql.sql.iter().any(|s| s.contains("SELECT")),
+        "{:?}",
+        sql.sql
+    );
+
+    let schemas = client.list_schemas().await.unwrap();
+    assert!(schemas.schemas.iter().any(|s| s.name == "default"));
+
+    let err = client.get_schema("no_such_schema").await.unwrap_err();
+    assert_eq!(err.status(), Some(404));
+}
@@ -0,0 +1,190 @@
+//! `USE graph AS OF SYSTEM TIME '...' VALID AT '...'` on bitemporal edges:
+//! every scan of an edge table declaring a time dimension keeps the rows
+//! whose period in that dimension contains the instant.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_cypher_statement,
+    query_planner::{evaluate_read_statement, snapshot_schema, QueryOptions},
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+    server::query_context::{set_current_schema, with_query_context, QueryContext},
+};
+
+const SCHEMA: &str = r#"
+name: ledger
+graph_schema:
+  nodes:
+    - label: Account
+      database: test
+      table: accounts
+      node_id: account_id
+      property_mappings:
+        account_id: account_id
+        name: name
+    - label: Company
+      database: test
+      table: companies
+      node_id: company_id
+      property_mappings:
+        company_id: company_id
+        name: name
+  edges:
+    - type: OWNS
+      database: test
+      table: ownership_cdc
+      from_id: owner_id
+      to_id: company_id
+      from_node: Account
+      to_node: Company
+      filter: "share > 0"
+      temporal:
+        valid_time: {from: valid_from, to: valid_to}
+        system_time: {from: recorded_at, to: superseded_at}
+      property_mappings:
+        share: share
+    - type: CONTROLS
+      database: test
+      table: control_cdc
+      from_id: parent_id
+      to_id: child_id
+      from_node: Company
+      to_node: Company
+      temporal:
+        valid_time: {from: valid_from}
+      property_mappings: {}
+    - type: KNOWS
+      database: test
+      table: knows
+      from_id: a_id
+      to_id: b_id
+      from_node: Account
+      to_node: Account
+      property_mappings: {}
+"#;
+
+fn schema(yaml: &str) -> Result<GraphSchema, String> {
+    GraphSchemaConfig::from_yaml_str(yaml)
+        .map_err(|e| e.to_string())?
+        .to_graph_schema()
+        .map_err(|e| e.to_string())
+}
+
+/// Mirrors the HTTP handler: time view of the schema, installed as the
+/// task's current schema, then plan and render.
+async fn cypher_to_sql(yaml: &str, cypher: &str) -> Result<String, String> {
+    let base = schema(yaml)?;
+    let cypher = cypher.to_string();
+    with_query_context(QueryContext::new(Some("ledger".to_string())), async move {
+        let (_, statement) = parse_cypher_statement(&cypher).expect("parse");
+        let schema = snapshot_schema(&statement, &base, &QueryOptions::default())
+            .map_err(|e| e.to_string())?
+            .into_owned();
+        set_current_schema(std::sync::Arc::new(schema.clone()));
+        clickgraph::query_planner::logical_plan::reset_all_counters();
+        let (logical_plan, plan_ctx) =
+            evaluate_read_statement(statement, &schema, None, None, None)
+                .map_err(|e| e.to_string())?;
+        let render_plan =
+            logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+                .map_err(|e| e.to_string())?;
+        Ok(render_plan.to_sql())
+    })
+    .await
+}
+
+#[tokio::test]
+async fn both_time_dimensions_filter_bitemporal_edges() {
+    let sql = cypher_to_sql(
+        SCHEMA,
+        "USE ledger AS OF SYSTEM TIME '2024-06-01 12:00:00' VALID AT '2024-01-01' \
+         MATCH (a:Account)-[o:OWNS]->(c:Company) RETURN a.name, c.name, o.share",
+    )
+    .await
+    .unwrap();
+    for predicate in [
+        "o.recorded_at <= toDateTime64('2024-06-01 12:00:00.000', 3)",
+        "o.superseded_at IS NULL OR (o.superseded_at > toDateTime64('2024-06-01 12:00:00.000', 3))",
+        "o.valid_from <= toDateTime64('2024-01-01 00:00:00.000', 3)",
+        "o.valid_to IS NULL OR (o.valid_to > toDateTime64('2024-01-01 00:00:00.000', 3))",
+    ] {
+        assert!(sql.contains(predicate), "missing {predicate}; SQL:\n{sql}");
+    }
+    assert!(sql.contains("o.share > 0"), "SQL:\n{sql}");
+    // Node tables declare no time dimension and are read whole
+    assert!(!sql.contains("a.valid_from"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn edges_without_a_dimension_are_read_whole() {
+    let sql = cypher_to_sql(
+        SCHEMA,
+        "USE ledger AS OF SYSTEM TIME '2024-06-01' \
+         MATCH (a:Account)-[:KNOWS]->(b:Account)-[o:OWNS]->(c:Company)-[:CONTROLS]->(d:Company) \
+         RETURN d.name",
+    )
+    .await
+    .unwrap();
+    // OWNS declares system time; CONTROLS only valid time; KNOWS neither
+    assert_eq!(sql.matches("recorded_at <=").count(), 1, "SQL:\n{sql}");
+    assert!(!sql.contains("valid_from"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn variable_length_paths_read_the_valid_time() {
+    let sql = cypher_to_sql(
+        SCHEMA,
+        "USE ledger VALID AT '2023-12-31T23:59:59.5' \
+         MATCH (a:Company)-[:CONTROLS*1..4]->(b:Company) WHERE a.company_id = 1 RETURN b.name",
+    )
+    .await
+    .unwrap();
+    let recursive = sql.split("UNION ALL").nth(1).expect("recursive CTE branch");
+    assert!(
+        recursive.contains("valid_from <= toDateTime64('2023-12-31 23:59:59.500', 3)"),
+        "recursive step not filtered; SQL:\n{sql}"
+    );
+    // No `to` column: the period is open-ended
+    assert!(!sql.contains("valid_to"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn invalid_time_travel_is_rejected() {
+    let err = cypher_to_sql(
+        SCHEMA,
+        "USE ledger VALID AT 'last week' MATCH (c:Company) RETURN c.name",
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("Invalid VALID AT timestamp"), "{err}");
+
+    let err = cypher_to_sql(
+        SCHEMA,
+        "USE ledger AS OF SYSTEM TIME '2024-06-01' MATCH (c:Company) RETURN c.name \
+         UNION ALL USE ledger AS OF SYSTEM TIME '2024-05-01' MATCH (c:Company) RETURN c.name",
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("different system times"), "{err}");
+
+    let static_graph = SCHEMA.replace(
+        "        system_time: {from: recorded_at, to: superseded_at}\n",
+        "",
+    );
+    let err = cypher_to_sql(
+        &static_graph,
+        "USE ledger AS OF SYSTEM TIME '2024-06-01' MATCH (c:Company) RETURN c.name",
+    )
+    .await
+    .unwrap_err();
+    assert!(
+        err.contains("declares no edge with temporal.system_time"),
+        "{err}"
+    );
+
+    let empty = SCHEMA.replace(
+        "      temporal:\n        valid_time: {from: valid_from}\n",
+        "      temporal: {}\n",
+    );
+    let err = schema(&empty).unwrap_err();
+    assert!(err.contains("neither valid_time nor system_time"), "{err}");
+}
@@ -1021,8 +1021,8 @@ async fn test_startnode_and_endnode_in_case_expression() {
         "node comparison should reference the node id column (user_id): got SQL:\n{sql}"
     );
     assert!(
-        sql_lower.contains("case when"),
-        "CASE WHEN should appear in SQL"
+        sql_lower.contains("multiif("),
+        "CASE WHEN should render as multiIf"
     );
 
     // Test endNode(r) in CASE WHEN
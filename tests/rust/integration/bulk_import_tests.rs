@@ -0,0 +1,209 @@
+//! `POST /import` — bulk loads of files into a graph's tables.
+//!
+//! Drives the real router with a stub executor that records the statements
+//! and fails the ones touching a poisoned source.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+const SCHEMA: &str = r#"
+name: import_graph
+graph_schema:
+  nodes:
+    - label: User
+      database: app
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+  edges:
+    - type: FOLLOWS
+      database: app
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+"#;
+
+#[derive(Default)]
+struct RecordingExecutor {
+    statements: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for RecordingExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        Ok(Vec::new())
+    }
+    async fn execute_text(
+        &self,
+        sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        self.statements.lock().unwrap().push(sql.to_string());
+        if sql.contains("broken") {
+            return Err(ExecutorError::QueryFailed(
+                "Code: 636. DB::Exception: Cannot extract table structure".to_string(),
+            ));
+        }
+        Ok(String::new())
+    }
+}
+
+async fn ensure_schema_registered() {
+    let config = GraphSchemaConfig::from_yaml_str(SCHEMA).expect("parse schema");
+    let schema = config.to_graph_schema().expect("convert schema");
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await
+        .insert("import_graph".to_string(), schema);
+}
+
+async fn import(executor: Arc<RecordingExecutor>, body: Value) -> (StatusCode, String) {
+    ensure_schema_registered().await;
+    let state = AppState {
+        executor,
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/import")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    (status, String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn lines(body: &str) -> Vec<Value> {
+    body.lines()
+        .map(|line| serde_json::from_str(line).expect("NDJSON line"))
+        .collect()
+}
+
+#[tokio::test]
+async fn mappings_run_in_order_with_progress() {
+    let executor = Arc::new(RecordingExecutor::default());
+    let (status, body) = import(
+        executor.clone(),
+        json!({
+            "schema_name": "import_graph",
+            "mappings": [
+                {"source": "s3://bucket/users.parquet", "label": "User"},
+                {"source": "s3://bucket/follows.csv", "rel_type": "FOLLOWS",
+                 "columns": {"follower_id": "src", "followed_id": "dst"}},
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let lines = lines(&body);
+    assert_eq!(lines.len(), 3, "{body}");
+    assert_eq!(lines[0]["target"], "node:User");
+    assert_eq!(lines[0]["status"], 200);
+    assert_eq!(lines[1]["table"], "app.follows");
+    assert_eq!(lines[2]["summary"]["imported"], 2);
+    assert_eq!(lines[2]["summary"]["failed"], false);
+
+    let statements = executor.statements.lock().unwrap();
+    assert!(
+        statements[0].starts_with("INSERT INTO app.users (user_id, full_name) SELECT"),
+        "{}",
+        statements[0]
+    );
+    assert!(
+        statements[1]
+            .contains("SELECT `src`, `dst` FROM s3('s3://bucket/follows.csv', 'CSVWithNames')"),
+        "{}",
+        statements[1]
+    );
+}
+
+#[tokio::test]
+async fn first_failure_stops_the_import() {
+    let executor = Arc::new(RecordingExecutor::default());
+    let (status, body) = import(
+        executor.clone(),
+        json!({
+            "schema_name": "import_graph",
+            "mappings": [
+                {"source": "/data/broken.csv", "label": "User"},
+                {"source": "/data/follows.csv", "rel_type": "FOLLOWS"},
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let lines = lines(&body);
+    assert_eq!(lines.len(), 2, "{body}");
+    assert_eq!(lines[0]["status"], 500);
+    assert!(lines[0]["error"].as_str().unwrap().contains("Code: 636"));
+    assert_eq!(lines[1]["summary"]["failed"], true);
+    assert_eq!(executor.statements.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn invalid_imports_run_nothing() {
+    let executor = Arc::new(RecordingExecutor::default());
+    let (status, body) = import(
+        executor.clone(),
+        json!({
+            "schema_name": "import_graph",
+            "mappings": [
+                {"source": "s3://bucket/users.parquet", "label": "User"},
+                {"source": "s3://bucket/likes.parquet", "rel_type": "LIKES"},
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert!(body.contains("relationship:LIKES"), "{body}");
+    assert!(executor.statements.lock().unwrap().is_empty());
+
+    let (status, body) = import(
+        executor.clone(),
+        json!({
+            "schema_name": "import_graph",
+            "sql_only": true,
+            "mappings": [{"source": "s3://bucket/users.parquet", "label": "User"}],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let preview: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(preview["statements"][0]["table"], "app.users");
+    assert!(executor.statements.lock().unwrap().is_empty());
+}
@@ -0,0 +1,109 @@
+//! Disconnected patterns on `/query` — rejected with guidance unless the
+//! query opts in with `CYPHER cartesian=allow`, then rendered as CROSS JOIN.
+//!
+//! Drives the real router in `sql_only` mode against the social benchmark.
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+use crate::fixtures::NoopExecutor;
+
+async fn ensure_default_graph() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    if !map.contains_key("default") {
+        let schema = GraphSchemaConfig::from_yaml_file(
+            "benchmarks/social_network/schemas/social_benchmark.yaml",
+        )
+        .expect("load benchmark schema")
+        .to_graph_schema()
+        .expect("convert benchmark schema");
+        map.insert("default".to_string(), schema);
+    }
+}
+
+async fn sql_for(query: &str) -> (StatusCode, String) {
+    ensure_default_graph().await;
+    let state = AppState {
+        executor: Arc::new(NoopExecutor),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let payload = json!({"query": query, "sql_only": true});
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body: Value = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let text = body["generated_sql"]
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| body.to_string());
+    (status, text)
+}
+
+#[tokio::test]
+async fn disconnected_patterns_need_the_allow_hint() {
+    let (status, body) = sql_for("MATCH (a:User), (b:Post) RETURN a.name, b.title").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert!(
+        body.contains("cartesian product") && body.contains("CYPHER cartesian=allow"),
+        "explains how to opt in; body: {body}"
+    );
+
+    let (status, sql) =
+        sql_for("CYPHER cartesian=allow MATCH (a:User), (b:Post) RETURN a.name, b.title").await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+    assert!(sql.contains("CROSS JOIN"), "SQL:\n{sql}");
+    assert!(!sql.contains("1 = 1"), "SQL:\n{sql}");
+
+    // A comment ahead of the prefix doesn't hide it, as on Bolt
+    for query in [
+        "// both sides\nCYPHER cartesian=allow MATCH (a:User), (b:Post) RETURN a.name, b.title",
+        "/* both sides */ CYPHER cartesian=allow MATCH (a:User), (b:Post) RETURN a.name, b.title",
+    ] {
+        let (status, sql) = sql_for(query).await;
+        assert_eq!(status, StatusCode::OK, "{query}: {sql}");
+        assert!(sql.contains("CROSS JOIN"), "SQL:\n{sql}");
+    }
+}
+
+#[tokio::test]
+async fn connected_patterns_need_no_hint() {
+    let (status, sql) = sql_for("MATCH (a:User)-[:FOLLOWS]->(b:User) RETURN a.name, b.name").await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+
+    // A predicate relating both parts correlates them; ClickHouse turns the
+    // filtered cross join into an inner join.
+    let (status, sql) =
+        sql_for("MATCH (a:User), (b:User) WHERE a.user_id = b.user_id RETURN a.name").await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+    assert!(sql.contains("WHERE a.user_id = b.user_id"), "SQL:\n{sql}");
+}
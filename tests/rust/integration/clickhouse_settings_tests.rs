@@ -0,0 +1,156 @@
+//! Per-request ClickHouse settings on `/query` — the request's `settings`
+//! object, checked against the server's `allowed_settings`.
+//!
+//! Drives the real router with a stub executor that records the settings each
+//! statement was sent with.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+#[derive(Default)]
+struct SettingsExecutor {
+    settings: Mutex<Vec<Vec<(String, String)>>>,
+}
+
+#[async_trait]
+impl QueryExecutor for SettingsExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.execute_json_with_settings(sql, &[], &[], role).await
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+    async fn execute_json_with_settings(
+        &self,
+        _sql: &str,
+        _params: &[(String, String)],
+        settings: &[(String, String)],
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.settings.lock().unwrap().push(settings.to_vec());
+        Ok(vec![json!({"name": "Alice"})])
+    }
+}
+
+async fn ensure_default_registered() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    if !map.contains_key("default") {
+        let schema = GraphSchemaConfig::from_yaml_file(
+            "benchmarks/social_network/schemas/social_benchmark.yaml",
+        )
+        .expect("load benchmark schema")
+        .to_graph_schema()
+        .expect("convert benchmark schema");
+        map.insert("default".to_string(), schema);
+    }
+}
+
+/// Run `payload` on a server allowing `allowed`; returns the status, the
+/// response body and the settings of every executed statement.
+async fn run(allowed: &[&str], payload: Value) -> (StatusCode, String, Vec<Vec<(String, String)>>) {
+    ensure_default_registered().await;
+    let config = ServerConfig {
+        allowed_settings: allowed.iter().map(|s| s.to_string()).collect(),
+        ..ServerConfig::default()
+    };
+    let executor = Arc::new(SettingsExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: config.clone(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &config);
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let executed = executor.settings.lock().unwrap().clone();
+    (
+        status,
+        String::from_utf8_lossy(&body).into_owned(),
+        executed,
+    )
+}
+
+#[tokio::test]
+async fn allowed_settings_reach_the_executor() {
+    let (status, _, executed) = run(
+        &["join_algorithm", "max_memory_usage"],
+        json!({
+            "query": "MATCH (u:User) WHERE u.user_id = 11 RETURN u.name AS name",
+            "settings": {"max_memory_usage": 20000000000u64, "join_algorithm": "partial_merge"}
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        executed,
+        vec![vec![
+            ("join_algorithm".to_string(), "partial_merge".to_string()),
+            ("max_memory_usage".to_string(), "20000000000".to_string()),
+        ]]
+    );
+}
+
+#[tokio::test]
+async fn other_settings_are_rejected() {
+    let (status, body, executed) = run(
+        &["join_algorithm"],
+        json!({
+            "query": "MATCH (u:User) WHERE u.user_id = 12 RETURN u.name AS name",
+            "settings": {"readonly": 0}
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.contains("'readonly' is not allowed"), "{body}");
+    assert!(executed.is_empty(), "{executed:?}");
+
+    let (status, body, _) = run(
+        &[],
+        json!({
+            "query": "MATCH (u:User) WHERE u.user_id = 13 RETURN u.name AS name",
+            "settings": {"join_algorithm": "hash"}
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.contains("CLICKGRAPH_ALLOWED_SETTINGS"), "{body}");
+}
@@ -0,0 +1,103 @@
+//! Cluster mode: a schema with a `cluster` section reads its mapped local
+//! tables through their Distributed tables, and on ClickHouse joins and IN
+//! subqueries go `GLOBAL` so each shard sees the other shards' rows.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_cypher_statement,
+    query_planner::evaluate_read_statement,
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+    server::query_context::{set_current_schema, with_query_context, QueryContext},
+    sql_generator::SqlDialect,
+};
+
+const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  cluster:
+    name: main
+    distributed_suffix: _all
+    distributed_tables:
+      follows_local: follows
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: name
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows_local
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings: {}
+"#;
+
+const QUERY: &str = "MATCH (u:User)-[:FOLLOWS]->(v:User) WHERE u.name = 'Alice' RETURN v.name";
+
+async fn cypher_to_sql(yaml: &str, cypher: &str, dialect: SqlDialect) -> String {
+    let schema: GraphSchema = GraphSchemaConfig::from_yaml_str(yaml)
+        .unwrap()
+        .to_graph_schema()
+        .unwrap();
+    let cypher = cypher.to_string();
+    let ctx = QueryContext {
+        dialect,
+        ..QueryContext::default()
+    };
+    with_query_context(ctx, async move {
+        set_current_schema(std::sync::Arc::new(schema.clone()));
+        clickgraph::query_planner::logical_plan::reset_all_counters();
+        let (_, statement) = parse_cypher_statement(&cypher).expect("parse");
+        let (logical_plan, plan_ctx) =
+            evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+        logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+            .expect("render")
+            .to_sql()
+    })
+    .await
+}
+
+#[tokio::test]
+async fn reads_distributed_tables_with_global_joins() {
+    let sql = cypher_to_sql(SCHEMA, QUERY, SqlDialect::ClickHouse).await;
+    assert!(sql.contains("db.users_all AS u"), "SQL:\n{sql}");
+    assert!(sql.contains("db.follows AS"), "SQL:\n{sql}");
+    assert!(!sql.contains("follows_local"), "SQL:\n{sql}");
+    assert!(!sql.contains("db.users AS"), "SQL:\n{sql}");
+    assert_eq!(sql.matches("GLOBAL INNER JOIN").count(), 2, "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn global_joins_are_clickhouse_only() {
+    let sql = cypher_to_sql(SCHEMA, QUERY, SqlDialect::Databricks).await;
+    assert!(sql.contains("db.users_all AS u"), "SQL:\n{sql}");
+    assert!(!sql.contains("GLOBAL"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn schemas_without_a_cluster_are_unchanged() {
+    let yaml = SCHEMA.replace(
+        "  cluster:\n    name: main\n    distributed_suffix: _all\n    distributed_tables:\n      follows_local: follows\n",
+        "",
+    );
+    let sql = cypher_to_sql(&yaml, QUERY, SqlDialect::ClickHouse).await;
+    assert!(sql.contains("db.users AS u"), "SQL:\n{sql}");
+    assert!(sql.contains("db.follows_local AS"), "SQL:\n{sql}");
+    assert!(!sql.contains("GLOBAL"), "SQL:\n{sql}");
+}
+
+#[test]
+fn cluster_needs_a_name() {
+    let yaml = SCHEMA.replace("name: main", "name: ''");
+    let err = GraphSchemaConfig::from_yaml_str(&yaml)
+        .unwrap()
+        .to_graph_schema()
+        .unwrap_err();
+    assert!(err.to_string().contains("cluster.name"), "{err}");
+}
@@ -0,0 +1,165 @@
+//! `collations` on node and edge properties and the `CYPHER collation=...`
+//! query option: string comparisons compare collation keys, ORDER BY sorts by
+//! them under the locale.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_cypher_statement,
+    query_planner::{evaluate_read_statement, snapshot_schema, QueryOptions},
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+    server::query_context::{set_current_schema, with_query_context, QueryContext},
+};
+
+const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        city: city
+        age: age
+      property_types:
+        name: string
+        city: string
+        age: integer
+      collations:
+        name: de+ci
+        city: ai
+  edges:
+    - type: FOLLOWS
+      database: test
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings:
+        note: note
+      collations:
+        note: ci
+"#;
+
+fn schema(yaml: &str) -> Result<GraphSchema, String> {
+    GraphSchemaConfig::from_yaml_str(yaml)
+        .map_err(|e| e.to_string())?
+        .to_graph_schema()
+        .map_err(|e| e.to_string())
+}
+
+/// Mirrors the HTTP handler: the query's view of the schema, installed as the
+/// task's current schema, then plan and render.
+async fn cypher_to_sql(cypher: &str) -> Result<String, String> {
+    let base = schema(SCHEMA).expect("convert schema");
+    let options = QueryOptions::from_query(cypher);
+    let cypher = match cypher.strip_prefix("CYPHER ") {
+        Some(rest) => rest.split_once(' ').map_or(rest, |(_, query)| query),
+        None => cypher,
+    }
+    .to_string();
+    with_query_context(QueryContext::new(Some("social".to_string())), async move {
+        let (_, statement) = parse_cypher_statement(&cypher).expect("parse");
+        let schema = snapshot_schema(&statement, &base, &options)
+            .map_err(|e| e.to_string())?
+            .into_owned();
+        set_current_schema(std::sync::Arc::new(schema.clone()));
+        clickgraph::query_planner::logical_plan::reset_all_counters();
+        let (logical_plan, plan_ctx) =
+            evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+        let render_plan =
+            logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+                .expect("render");
+        Ok(render_plan.to_sql())
+    })
+    .await
+}
+
+#[tokio::test]
+async fn collated_columns_compare_collation_keys() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User) WHERE u.name = 'Müller' AND u.city STARTS WITH 'Sao' RETURN u.user_id",
+    )
+    .await
+    .unwrap();
+    assert!(
+        sql.contains("lowerUTF8(u.full_name) = lowerUTF8('Müller')"),
+        "SQL:\n{sql}"
+    );
+    assert!(
+        sql.contains("replaceRegexpAll(normalizeUTF8NFD(u.city)"),
+        "SQL:\n{sql}"
+    );
+    // Uncollated columns compare as before.
+    let sql = cypher_to_sql("MATCH (u:User) WHERE u.age = 30 RETURN u.name")
+        .await
+        .unwrap();
+    assert!(!sql.contains("lowerUTF8"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn order_by_sorts_by_the_key_under_the_locale() {
+    let sql = cypher_to_sql("MATCH (u:User) RETURN u.name ORDER BY u.name, u.city DESC")
+        .await
+        .unwrap();
+    assert!(
+        sql.contains("ORDER BY lowerUTF8(u.full_name) ASC COLLATE 'de'"),
+        "SQL:\n{sql}"
+    );
+    assert!(!sql.contains("DESC COLLATE"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn edge_property_collations_apply_to_in_lists() {
+    let sql = cypher_to_sql(
+        "MATCH (a:User)-[f:FOLLOWS]->(b:User) WHERE f.note IN ['Hi', 'Hey'] RETURN b.user_id",
+    )
+    .await
+    .unwrap();
+    assert!(sql.contains("lowerUTF8(f.note)"), "SQL:\n{sql}");
+    assert!(sql.contains("lowerUTF8('Hey')"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn collation_survives_with() {
+    let sql = cypher_to_sql("MATCH (u:User) WITH u.name AS nm RETURN nm ORDER BY nm")
+        .await
+        .unwrap();
+    assert!(sql.contains("ASC COLLATE 'de'"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn query_collation_overrides_the_schema() {
+    let sql = cypher_to_sql(
+        "CYPHER collation=ci MATCH (u:User) WHERE u.city = 'Paris' AND u.age > 30 \
+         RETURN u.user_id ORDER BY u.name",
+    )
+    .await
+    .unwrap();
+    assert!(
+        sql.contains("lowerUTF8(u.city) = lowerUTF8('Paris')"),
+        "SQL:\n{sql}"
+    );
+    // Only string properties are keyed, and the schema's locale is replaced.
+    assert!(!sql.contains("lowerUTF8(u.age)"), "SQL:\n{sql}");
+    assert!(!sql.contains("normalizeUTF8NFD"), "SQL:\n{sql}");
+    assert!(!sql.contains("COLLATE"), "SQL:\n{sql}");
+    assert!(sql.contains("lowerUTF8(u.full_name) ASC"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn invalid_query_collation_is_rejected() {
+    let err = cypher_to_sql("CYPHER collation=de+fr MATCH (u:User) RETURN u.name")
+        .await
+        .unwrap_err();
+    assert!(err.contains("Invalid query collation 'de+fr'"), "{err}");
+}
+
+#[test]
+fn collation_of_unmapped_property_is_rejected() {
+    let err = schema(&SCHEMA.replace("        note: ci\n", "        weight: ci\n")).unwrap_err();
+    assert!(err.contains("collation of 'weight'"), "{err}");
+}
@@ -945,27 +945,19 @@ async fn test_case_expressions_in_return() {
     let sql = render_plan.to_sql();
     println!("Generated SQL:\n{}", sql);
 
-    // Verify the query contains expected elements
-    assert!(
-        sql.to_lowercase().contains("case"),
-        "Should contain CASE expression"
-    );
-    assert!(
-        sql.to_lowercase().contains("when"),
-        "Should contain WHEN clauses"
-    );
-    assert!(
-        sql.to_lowercase().contains("then"),
-        "Should contain THEN clauses"
-    );
-    assert!(
-        sql.to_lowercase().contains("else"),
-        "Should contain ELSE clause"
-    );
-    assert!(
-        sql.to_lowercase().contains("end"),
-        "Should contain END keyword"
-    );
+    // Searched CASE renders as multiIf(cond1, res1, ..., else)
+    assert!(
+        sql.contains("multiIf("),
+        "Should contain the CASE expression as multiIf"
+    );
+    for branch in [
+        "'No followers'",
+        "'Few followers'",
+        "'Some followers'",
+        "'Popular'",
+    ] {
+        assert!(sql.contains(branch), "Should contain the {branch} branch");
+    }
 }
 
 /// Test complex property access patterns with nested expressions
@@ -1096,8 +1088,8 @@ async fn test_deeply_nested_expressions() {
 
     // Verify the query contains expected elements
     assert!(
-        sql.to_lowercase().contains("case"),
-        "Should contain CASE expression"
+        sql.contains("multiIf("),
+        "Should contain the CASE expression as multiIf"
     );
     assert!(
         sql.to_lowercase().contains("*"),
@@ -57,7 +57,7 @@ RETURN u.name, p.content";
 /// P06: Variable-length path (Recursive CTE)
 const P06_VLP: &str = "MATCH (a:User)-[:FOLLOWS*1..3]->(b:User) RETURN a.name, b.name LIMIT 10";
 
-/// P07: collect() aggregation (groupArray + DISTINCT)
+/// P07: collect(DISTINCT) aggregation (groupUniqArray)
 const P07_COLLECT: &str = "\
 MATCH (u:User)-[:AUTHORED]->(p:Post)-[:HAS_TAG]->(t:Tag) \
 RETURN u.name, collect(DISTINCT t.name) AS tags";
@@ -241,8 +241,7 @@ async fn cs_standard_p07_collect() {
     let schema = load_schema(SCHEMA_STANDARD);
     let sql = generate_sql(&schema, P07_COLLECT).await;
     assert_valid_sql(&sql, "standard", "P07");
-    assert_contains(&sql, "standard/P07", "groupArray");
-    assert_contains(&sql, "standard/P07", "DISTINCT");
+    assert_contains(&sql, "standard/P07", "groupUniqArray");
     assert_contains(&sql, "standard/P07", "tag_name");
     assert_contains(&sql, "standard/P07", "cs_test.post_tags");
 }
@@ -284,7 +283,7 @@ async fn cs_standard_p11_case() {
     let schema = load_schema(SCHEMA_STANDARD);
     let sql = generate_sql(&schema, P11_CASE).await;
     assert_valid_sql(&sql, "standard", "P11");
-    assert_contains(&sql, "standard/P11", "CASE");
+    assert_contains(&sql, "standard/P11", "multiIf(");
     assert_contains(&sql, "standard/P11", "created_at");
     assert_contains(&sql, "standard/P11", "'recent'");
     assert_contains(&sql, "standard/P11", "'old'");
@@ -375,7 +374,7 @@ async fn cs_fk_edge_p07_collect() {
     let sql = generate_sql(&schema, P07_COLLECT).await;
     assert_valid_sql(&sql, "fk_edge", "P07");
     assert_not_contains(&sql, "fk_edge/P07", "cs_test.authored");
-    assert_contains(&sql, "fk_edge/P07", "groupArray");
+    assert_contains(&sql, "fk_edge/P07", "groupUniqArray");
 }
 
 #[tokio::test]
@@ -411,7 +410,7 @@ async fn cs_fk_edge_p11_case() {
     let sql = generate_sql(&schema, P11_CASE).await;
     assert_valid_sql(&sql, "fk_edge", "P11");
     assert_not_contains(&sql, "fk_edge/P11", "cs_test.authored");
-    assert_contains(&sql, "fk_edge/P11", "CASE");
+    assert_contains(&sql, "fk_edge/P11", "multiIf(");
 }
 
 #[tokio::test]
@@ -488,7 +487,7 @@ async fn cs_denormalized_p07_collect() {
     let schema = load_schema(SCHEMA_DENORMALIZED);
     let sql = generate_sql(&schema, P07_COLLECT).await;
     assert_valid_sql(&sql, "denormalized", "P07");
-    assert_contains(&sql, "denormalized/P07", "groupArray");
+    assert_contains(&sql, "denormalized/P07", "groupUniqArray");
 }
 
 #[tokio::test]
@@ -525,7 +524,7 @@ async fn cs_denormalized_p11_case() {
     let schema = load_schema(SCHEMA_DENORMALIZED);
     let sql = generate_sql(&schema, P11_CASE).await;
     assert_valid_sql(&sql, "denormalized", "P11");
-    assert_contains(&sql, "denormalized/P11", "CASE");
+    assert_contains(&sql, "denormalized/P11", "multiIf(");
 }
 
 #[tokio::test]
@@ -619,7 +618,7 @@ async fn cs_polymorphic_p07_collect() {
     let sql = generate_sql(&schema, P07_COLLECT).await;
     assert_valid_sql(&sql, "polymorphic", "P07");
     assert_contains(&sql, "polymorphic/P07", "cs_test.interactions");
-    assert_contains(&sql, "polymorphic/P07", "groupArray");
+    assert_contains(&sql, "polymorphic/P07", "groupUniqArray");
     assert_not_contains(&sql, "polymorphic/P07", "cs_test.post_tags");
 }
 
@@ -656,7 +655,7 @@ async fn cs_polymorphic_p11_case() {
     let sql = generate_sql(&schema, P11_CASE).await;
     assert_valid_sql(&sql, "polymorphic", "P11");
     assert_contains(&sql, "polymorphic/P11", "cs_test.interactions");
-    assert_contains(&sql, "polymorphic/P11", "CASE");
+    assert_contains(&sql, "polymorphic/P11", "multiIf(");
 }
 
 #[tokio::test]
@@ -740,7 +739,7 @@ async fn cs_composite_id_p07_collect() {
     let schema = load_schema(SCHEMA_COMPOSITE_ID);
     let sql = generate_sql(&schema, P07_COLLECT).await;
     assert_valid_sql(&sql, "composite_id", "P07");
-    assert_contains(&sql, "composite_id/P07", "groupArray");
+    assert_contains(&sql, "composite_id/P07", "groupUniqArray");
     assert_contains(&sql, "composite_id/P07", "org_id");
 }
 
@@ -774,7 +773,7 @@ async fn cs_composite_id_p11_case() {
     let schema = load_schema(SCHEMA_COMPOSITE_ID);
     let sql = generate_sql(&schema, P11_CASE).await;
     assert_valid_sql(&sql, "composite_id", "P11");
-    assert_contains(&sql, "composite_id/P11", "CASE");
+    assert_contains(&sql, "composite_id/P11", "multiIf(");
 }
 
 #[tokio::test]
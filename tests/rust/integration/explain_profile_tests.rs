@@ -0,0 +1,208 @@
+//! `EXPLAIN` / `PROFILE` prefixes over `/query`.
+//!
+//! Drives the real router with a stub executor, like
+//! `stored_query_tests.rs`, that answers the `system.query_log` lookup.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::server::{build_router, AppState};
+
+use crate::fixtures::ensure_default_schema_registered;
+
+/// Remembers each statement; `query_log` lookups return `log_rows`.
+#[derive(Default)]
+struct RecordingExecutor {
+    log_rows: Vec<Value>,
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for RecordingExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        if sql.contains("system.query_log") {
+            return Ok(self.log_rows.clone());
+        }
+        Ok(vec![json!({"name": "Alice"})])
+    }
+    async fn execute_text(
+        &self,
+        sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(String::new())
+    }
+}
+
+async fn run(executor: RecordingExecutor, payload: Value) -> (StatusCode, Value, Vec<String>) {
+    ensure_default_schema_registered().await;
+    let executor = Arc::new(executor);
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, body, executed)
+}
+
+const QUERY: &str =
+    "MATCH (a:User)-[:FOLLOWS]->(b:User) WHERE a.country = $country RETURN b.name AS name";
+
+#[tokio::test]
+async fn explain_returns_plan_and_sql_without_running() {
+    let (status, body, executed) = run(
+        RecordingExecutor::default(),
+        json!({"query": format!("EXPLAIN {QUERY}"), "parameters": {"country": "NZ"}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    assert_eq!(body["mode"], "EXPLAIN");
+    assert_eq!(body["schema_name"], "default");
+    assert!(
+        !body["logical_plan"].as_str().unwrap().is_empty(),
+        "body: {body}"
+    );
+    let sql = body["generated_sql"].as_str().unwrap();
+    assert!(
+        sql.contains("user_follows_bench") && sql.contains("'NZ'"),
+        "SQL:\n{sql}"
+    );
+    assert!(body.get("stats").is_none());
+
+    let (status, _, _) = run(
+        RecordingExecutor::default(),
+        json!({"query": "EXPLAIN CREATE (u:User {user_id: 1})"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn explain_summarizes_patterns_strategies_and_ctes() {
+    let (status, body, _) = run(
+        RecordingExecutor::default(),
+        json!({"query": "EXPLAIN MATCH (a:User)-[r:FOLLOWS*1..3]->(b:User) WHERE a.user_id = 1 RETURN b.name"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let patterns = body["plan"]["patterns"].as_array().unwrap();
+    assert_eq!(patterns.len(), 1, "plan: {}", body["plan"]);
+    assert_eq!(
+        patterns[0]["pattern"],
+        "(a:User)-[r:FOLLOWS*1..3]->(b:User)"
+    );
+    assert_eq!(patterns[0]["strategy"], "variable_length");
+    let cte = patterns[0]["cte"].as_str().expect("VLP pattern has a CTE");
+    let ctes = body["plan"]["ctes"].as_array().unwrap();
+    assert!(
+        ctes.iter()
+            .any(|c| c["name"] == cte && c["recursive"] == true),
+        "plan: {}",
+        body["plan"]
+    );
+
+    let (status, body, _) = run(
+        RecordingExecutor::default(),
+        json!({"query": "EXPLAIN MATCH (a:User) OPTIONAL MATCH (a)-[f:FOLLOWS]->(b:User) RETURN a.name, b.name"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let strategies: Vec<(&str, &str)> = body["plan"]["patterns"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| {
+            (
+                p["alias"].as_str().unwrap(),
+                p["strategy"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        strategies,
+        vec![("f", "optional_join")],
+        "plan: {}",
+        body["plan"]
+    );
+}
+
+#[tokio::test]
+async fn profile_runs_query_and_reads_query_log() {
+    let executor = RecordingExecutor {
+        log_rows: vec![json!({
+            "query_duration_ms": "7", "read_rows": "1200", "read_bytes": "96000",
+            "result_rows": "1", "result_bytes": "64", "memory_usage": "1048576"
+        })],
+        ..Default::default()
+    };
+    let (status, body, executed) = run(
+        executor,
+        json!({"query": format!("profile {QUERY}"), "parameters": {"country": "NZ"}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body["mode"], "PROFILE");
+    assert_eq!(body["results"], json!([{"name": "Alice"}]));
+    assert_eq!(body["stats"]["read_rows"], 1200);
+    assert_eq!(body["stats"]["query_duration_ms"], 7);
+
+    let [query, flush, lookup] = executed.as_slice() else {
+        panic!("expected query, flush, lookup; got {executed:?}");
+    };
+    let tag = query
+        .strip_prefix("/* ")
+        .and_then(|q| q.split(" */").next())
+        .expect("profiled SQL is tagged");
+    assert!(query.contains("user_follows_bench"), "{query}");
+    assert_eq!(flush, "SYSTEM FLUSH LOGS");
+    assert!(lookup.contains(tag), "{lookup}");
+
+    // No log entry: the rows still come back, with an explanation.
+    let (status, body, _) = run(
+        RecordingExecutor::default(),
+        json!({"query": format!("PROFILE {QUERY}"), "parameters": {"country": "NZ"}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.get("stats").is_none());
+    assert!(
+        body["stats_error"].as_str().unwrap().contains("query_log"),
+        "body: {body}"
+    );
+}
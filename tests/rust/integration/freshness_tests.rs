@@ -0,0 +1,190 @@
+//! `freshness` on edge tables and the `max_staleness_secs` / `on_stale`
+//! consistency window: which tables a query's SQL reads, and what happens
+//! when their watermark lags.
+//!
+//! A stub executor answers every watermark query with a fixed lag.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema};
+use clickgraph::server::freshness::{self, FreshnessError, StaleAction};
+
+const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+  edges:
+    - type: FOLLOWS
+      database: test
+      table: follows_mv
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      freshness:
+        watermark: inserted_at
+        typical_lag_seconds: 2
+      property_mappings: {}
+    - type: BLOCKS
+      database: test
+      table: blocks
+      from_id: blocker_id
+      to_id: blocked_id
+      from_node: User
+      to_node: User
+      property_mappings: {}
+"#;
+
+struct LagExecutor {
+    lag: i64,
+    queries: AtomicUsize,
+}
+
+impl LagExecutor {
+    fn new(lag: i64) -> Self {
+        Self {
+            lag,
+            queries: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait]
+impl QueryExecutor for LagExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        assert!(sql.contains("max(`inserted_at`)"), "{sql}");
+        assert!(sql.ends_with("FROM test.follows_mv"), "{sql}");
+        self.queries.fetch_add(1, Ordering::SeqCst);
+        // ClickHouse quotes Int64 in JSON output
+        Ok(vec![json!({"lag": self.lag.to_string()})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+    async fn execute_json_with_settings(
+        &self,
+        sql: &str,
+        _params: &[(String, String)],
+        _settings: &[(String, String)],
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.execute_json(sql, role).await
+    }
+}
+
+fn schema(yaml: &str) -> Result<GraphSchema, String> {
+    GraphSchemaConfig::from_yaml_str(yaml)
+        .expect("parse schema")
+        .to_graph_schema()
+        .map_err(|e| e.to_string())
+}
+
+fn follows_sql() -> Vec<String> {
+    vec!["SELECT f.follower_id FROM test.follows_mv AS f".to_string()]
+}
+
+#[test]
+fn only_watermarked_tables_are_checked() {
+    let schema = schema(SCHEMA).expect("convert schema");
+    let tables = freshness::referenced_tables(&schema, &follows_sql());
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].0, "test.follows_mv");
+    assert_eq!(tables[0].1.typical_lag_seconds, 2);
+
+    let blocks = vec!["SELECT * FROM test.blocks AS b".to_string()];
+    assert!(freshness::referenced_tables(&schema, &blocks).is_empty());
+}
+
+#[test]
+fn freshness_needs_a_watermark_column() {
+    let err = schema(&SCHEMA.replace("watermark: inserted_at", "watermark: ''")).unwrap_err();
+    assert!(err.contains("freshness.watermark is empty"), "{err}");
+}
+
+#[tokio::test]
+async fn fresh_tables_pass_without_notification() {
+    let schema = schema(SCHEMA).expect("convert schema");
+    let executor = LagExecutor::new(5);
+    let note = freshness::check(
+        &executor,
+        &schema,
+        &follows_sql(),
+        10,
+        StaleAction::Error,
+        None,
+    )
+    .await
+    .expect("fresh enough");
+    assert_eq!(note, None);
+}
+
+#[tokio::test]
+async fn stale_tables_are_annotated_or_refused() {
+    let schema = schema(SCHEMA).expect("convert schema");
+    let executor = LagExecutor::new(95);
+
+    let note = freshness::check(
+        &executor,
+        &schema,
+        &follows_sql(),
+        30,
+        StaleAction::Annotate,
+        None,
+    )
+    .await
+    .expect("annotated");
+    assert_eq!(
+        note.as_deref(),
+        Some("Possibly stale results: test.follows_mv is 95s behind")
+    );
+
+    let err = freshness::check(
+        &executor,
+        &schema,
+        &follows_sql(),
+        30,
+        StaleAction::Error,
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, FreshnessError::Stale { .. }), "{err}");
+}
+
+#[tokio::test]
+async fn wait_gives_up_after_the_typical_lag() {
+    let schema = schema(SCHEMA).expect("convert schema");
+    let executor = LagExecutor::new(95);
+    let err = freshness::check(
+        &executor,
+        &schema,
+        &follows_sql(),
+        30,
+        StaleAction::Wait,
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, FreshnessError::Stale { .. }), "{err}");
+    // Initial check plus at least one re-check within the 2s typical lag
+    assert!(executor.queries.load(Ordering::SeqCst) >= 2);
+}
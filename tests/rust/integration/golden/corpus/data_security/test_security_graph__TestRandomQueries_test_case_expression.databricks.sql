@@ -1,5 +1,5 @@
 SELECT 
       u.name AS `u.name`, 
-      caseWithExpression(u.exposure, 'external', 'RISK', 'OK') AS `risk_level`
+      CASE u.exposure WHEN 'external' THEN 'RISK' ELSE 'OK' END AS `risk_level`
 FROM data_security.ds_users AS u
 ORDER BY u.name ASC
@@ -3,6 +3,6 @@ SELECT
       p.personId AS `p.personId`, 
       t0.person2Id AS `friend.personId`
 FROM ldbc.comment AS c
-JOIN ldbc.person AS p ON 1 = 1
+CROSS JOIN ldbc.person AS p
 LEFT JOIN ldbc.person_knows_person AS t0 ON t0.person1Id = p.personId
 WHERE (p.personId = 1 AND c.commentId = 100)
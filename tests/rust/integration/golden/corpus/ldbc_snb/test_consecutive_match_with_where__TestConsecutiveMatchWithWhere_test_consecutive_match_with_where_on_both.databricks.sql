@@ -2,5 +2,5 @@ SELECT
       c.commentId AS `c.commentId`, 
       p.personId AS `p.personId`
 FROM ldbc.comment AS c
-JOIN ldbc.person AS p ON 1 = 1
+CROSS JOIN ldbc.person AS p
 WHERE (c.commentId = 1 AND p.personId = 2)
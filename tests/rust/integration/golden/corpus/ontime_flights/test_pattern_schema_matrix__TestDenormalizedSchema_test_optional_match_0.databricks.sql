@@ -19,10 +19,16 @@ FROM default.flights AS a
 )
 GROUP BY "code"
 
+), 
+__optional_agg_0 AS (SELECT 
+      r.Origin AS `__key0`, 
+      count(r.flight_id) AS `rel_count`
+FROM default.flights AS r
+GROUP BY r.Origin
 )
 SELECT 
       a.code AS `a.code`, 
-      count(r.flight_id) AS `rel_count`
+      coalesce(any_value(__optional_agg_0.rel_count), 0) AS `rel_count`
 FROM __denorm_scan_a AS a
-LEFT JOIN default.flights AS r ON a.code = r.Origin
+LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = a.code
 GROUP BY a.code
@@ -1,7 +1,13 @@
+WITH __optional_agg_0 AS (SELECT 
+      r.follower_id AS `__key0`, 
+      count(r.follow_id) AS `follow_count`
+FROM test_integration.user_follows_test AS r
+GROUP BY r.follower_id
+)
 SELECT 
       u.user_id AS `u.user_id`, 
-      count(r.follow_id) AS `follow_count`
+      coalesce(any_value(__optional_agg_0.follow_count), 0) AS `follow_count`
 FROM test_integration.users_test AS u
-LEFT JOIN test_integration.user_follows_test AS r ON r.follower_id = u.user_id
+LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = u.user_id
 GROUP BY u.user_id
 LIMIT 5
\ No newline at end of file
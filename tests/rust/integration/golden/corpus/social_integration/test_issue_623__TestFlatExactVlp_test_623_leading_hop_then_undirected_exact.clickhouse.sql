@@ -37,6 +37,6 @@ SELECT
       a.full_name AS "a.name", 
       t.end_name AS "c.name"
 FROM vlp_b_c AS t
-JOIN test_integration.users_test AS a ON 1 = 1
+CROSS JOIN test_integration.users_test AS a
 INNER JOIN test_integration.user_follows_test AS t0 ON t0.follower_id = a.user_id AND t0.followed_id = t.start_id
 LIMIT 10
\ No newline at end of file
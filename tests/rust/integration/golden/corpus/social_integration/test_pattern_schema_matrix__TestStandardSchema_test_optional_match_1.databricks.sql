@@ -1,6 +1,12 @@
+WITH __optional_agg_0 AS (SELECT 
+      r.follower_id AS `__key0`, 
+      count(r.follow_id) AS `rel_count`
+FROM test_integration.user_follows_test AS r
+GROUP BY r.follower_id
+)
 SELECT 
       a.user_id AS `a.user_id`, 
-      count(r.follow_id) AS `rel_count`
+      coalesce(any_value(__optional_agg_0.rel_count), 0) AS `rel_count`
 FROM test_integration.users_test AS a
-LEFT JOIN test_integration.user_follows_test AS r ON r.follower_id = a.user_id
+LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = a.user_id
 GROUP BY a.user_id
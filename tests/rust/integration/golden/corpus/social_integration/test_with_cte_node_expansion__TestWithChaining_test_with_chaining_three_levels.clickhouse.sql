@@ -1,4 +1,4 @@
-WITH with_a_cte_1 AS (SELECT 
+WITH with_a_cte_0 AS (SELECT 
       a.age AS "p1_a_age", 
       a.city AS "p1_a_city", 
       a.country AS "p1_a_country", 
@@ -26,10 +26,10 @@ with_a_b_cte_1 AS (SELECT
       b.full_name AS "p1_b_name", 
       b.registration_date AS "p1_b_registration_date", 
       b.user_id AS "p1_b_user_id"
-FROM with_a_cte_1 AS a
+FROM with_a_cte_0 AS a
 CROSS JOIN test_integration.users_test AS b
 ), 
-with_a_b_c_cte_1 AS (SELECT 
+with_a_b_c_cte_2 AS (SELECT 
       a_b.p1_a_age AS "p1_a_age", 
       a_b.p1_a_city AS "p1_a_city", 
       a_b.p1_a_country AS "p1_a_country", 
@@ -100,5 +100,5 @@ SELECT
       a_b_c.p1_c_name AS "c.name", 
       a_b_c.p1_c_registration_date AS "c.registration_date", 
       a_b_c.p1_c_user_id AS "c.user_id"
-FROM with_a_b_c_cte_1 AS a_b_c
+FROM with_a_b_c_cte_2 AS a_b_c
 LIMIT 1
\ No newline at end of file
@@ -1,4 +1,4 @@
-WITH with_a_cte_1 AS (SELECT 
+WITH with_a_cte_0 AS (SELECT 
       a.age AS `p1_a_age`, 
       a.city AS `p1_a_city`, 
       a.country AS `p1_a_country`, 
@@ -26,7 +26,7 @@ with_a_b_cte_1 AS (SELECT
       b.full_name AS `p1_b_name`, 
       b.registration_date AS `p1_b_registration_date`, 
       b.user_id AS `p1_b_user_id`
-FROM with_a_cte_1 AS a
+FROM with_a_cte_0 AS a
 CROSS JOIN test_integration.users_test AS b
 )
 SELECT 
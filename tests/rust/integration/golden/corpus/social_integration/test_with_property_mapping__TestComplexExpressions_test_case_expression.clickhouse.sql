@@ -1,5 +1,5 @@
 WITH with_status_cte_0 AS (SELECT 
-      CASE WHEN u.is_active THEN u.full_name ELSE 'inactive' END AS "status"
+      multiIf(u.is_active, u.full_name, 'inactive') AS "status"
 FROM test_integration.users_test AS u
 )
 SELECT 
@@ -1,5 +1,5 @@
 WITH with_status_cte_0 AS (SELECT 
-      CASE WHEN u.is_active THEN CASE WHEN u.country = 'USA' THEN u.full_name ELSE 'non-US' END ELSE 'inactive' END AS "status"
+      multiIf(u.is_active, multiIf(u.country = 'USA', u.full_name, 'non-US'), 'inactive') AS "status"
 FROM test_integration.users_test AS u
 )
 SELECT 
@@ -1,19 +1,10 @@
 WITH with_e_n_u_cte_0 AS (SELECT 
-      u.email_address AS "p1_u_email", 
-      u.full_name AS "p1_u_name", 
-      u.user_id AS "p1_u_user_id"
+      u.full_name AS "n", 
+      u.email_address AS "e"
 FROM test_integration.users_test AS u
 )
 SELECT 
-      e_n_u.p1_u_email AS "n.email", 
-      e_n_u.p1_u_email AS "n.email_address", 
-      e_n_u.p1_u_name AS "n.full_name", 
-      e_n_u.p1_u_name AS "n.name", 
-      e_n_u.p1_u_user_id AS "n.user_id", 
-      e_n_u.p1_u_email AS "e.email", 
-      e_n_u.p1_u_email AS "e.email_address", 
-      e_n_u.p1_u_name AS "e.full_name", 
-      e_n_u.p1_u_name AS "e.name", 
-      e_n_u.p1_u_user_id AS "e.user_id"
+      e_n_u.n AS "n", 
+      e_n_u.e AS "e"
 FROM with_e_n_u_cte_0 AS e_n_u
 LIMIT 1
\ No newline at end of file
@@ -4,7 +4,7 @@ WITH RECURSIVE vlp_a_b AS (
         concat(toString(end_node.bank_id), '|', toString(end_node.account_number)) as end_id,
         1 as hop_count,
         CAST([] AS Array(String)) as path_relationships,
-        [concat(toString(start_node.bank_id), '|', toString(start_node.account_number)), concat(toString(end_node.bank_id), '|', toString(end_node.account_number))] as path_nodes,
+        [tuple(start_node.bank_id, start_node.account_number), tuple(end_node.bank_id, end_node.account_number)] as path_nodes,
         [rel.transfer_id] as path_edges,
         start_node.account_number as start_account_number,
         end_node.account_number as end_account_number
@@ -17,7 +17,7 @@ WITH RECURSIVE vlp_a_b AS (
         concat(toString(end_node.bank_id), '|', toString(end_node.account_number)) as end_id,
         vp.hop_count + 1 as hop_count,
         CAST([] AS Array(String)) as path_relationships,
-        arrayConcat(vp.path_nodes, [concat(toString(end_node.bank_id), '|', toString(end_node.account_number))]) as path_nodes,
+        arrayConcat(vp.path_nodes, [tuple(end_node.bank_id, end_node.account_number)]) as path_nodes,
         arrayConcat(vp.path_edges, [rel.transfer_id]) as path_edges,
         vp.start_account_number as start_account_number,
         end_node.account_number as end_account_number
@@ -4,7 +4,7 @@ WITH RECURSIVE vlp_a_b AS (
         concat(string(end_node.bank_id), '|', string(end_node.account_number)) as end_id,
         1 as hop_count,
         CAST(array() AS ARRAY<STRING>) as path_relationships,
-        array(concat(string(start_node.bank_id), '|', string(start_node.account_number)), concat(string(end_node.bank_id), '|', string(end_node.account_number))) as path_nodes,
+        array(struct(start_node.bank_id, start_node.account_number), struct(end_node.bank_id, end_node.account_number)) as path_nodes,
         array(rel.transfer_id) as path_edges,
         start_node.account_number as start_account_number,
         end_node.account_number as end_account_number
@@ -17,7 +17,7 @@ WITH RECURSIVE vlp_a_b AS (
         concat(string(end_node.bank_id), '|', string(end_node.account_number)) as end_id,
         vp.hop_count + 1 as hop_count,
         CAST(array() AS ARRAY<STRING>) as path_relationships,
-        concat(vp.path_nodes, array(concat(string(end_node.bank_id), '|', string(end_node.account_number)))) as path_nodes,
+        concat(vp.path_nodes, array(struct(end_node.bank_id, end_node.account_number))) as path_nodes,
         concat(vp.path_edges, array(rel.transfer_id)) as path_edges,
         vp.start_account_number as start_account_number,
         end_node.account_number as end_account_number
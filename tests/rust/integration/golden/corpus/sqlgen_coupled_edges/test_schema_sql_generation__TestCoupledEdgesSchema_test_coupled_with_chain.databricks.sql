@@ -1,5 +1,5 @@
 WITH with_d_reqs_cte_0 AS (SELECT 
-      any_value(t0.query) AS `p1_d_name`, 
+      t0.query AS `p1_d_name`, 
       count(*) AS `reqs`
 FROM zeek.dns_log AS t0
 GROUP BY t0.query
@@ -19,10 +19,16 @@ FROM db_denormalized.flights_denorm AS a
 )
 GROUP BY "code"
 
+), 
+__optional_agg_0 AS (SELECT 
+      t0.origin_code AS "__key0", 
+      count(t0.dest_code) AS "cnt"
+FROM db_denormalized.flights_denorm AS t0
+GROUP BY t0.origin_code
 )
 SELECT 
       a.code AS "a.code", 
-      count(t0.dest_code) AS "cnt"
+      coalesce(anyLast(__optional_agg_0.cnt), 0) AS "cnt"
 FROM __denorm_scan_a AS a
-LEFT JOIN db_denormalized.flights_denorm AS t0 ON a.code = t0.origin_code
+LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = a.code
 GROUP BY a.code
@@ -19,10 +19,16 @@ FROM db_denormalized.flights_denorm AS a
 )
 GROUP BY "code"
 
+), 
+__optional_agg_0 AS (SELECT 
+      f.origin_code AS "__key0", 
+      count(f.flight_id) AS "outgoing"
+FROM db_denormalized.flights_denorm AS f
+GROUP BY f.origin_code
 )
 SELECT 
       a.code AS "a.code", 
-      count(f.flight_id) AS "outgoing"
+      coalesce(anyLast(__optional_agg_0.outgoing), 0) AS "outgoing"
 FROM __denorm_scan_a AS a
-LEFT JOIN db_denormalized.flights_denorm AS f ON a.code = f.origin_code
+LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = a.code
 GROUP BY a.code
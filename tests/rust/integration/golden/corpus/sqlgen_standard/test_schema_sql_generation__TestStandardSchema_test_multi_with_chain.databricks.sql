@@ -8,7 +8,7 @@ GROUP BY u.user_id
 )
 SELECT 
       u.p1_u_name AS `u.name`, 
-      any_value(u.posts) AS `posts`, 
+      u.posts AS `posts`, 
       count(t0.followed_id) AS `following`
 FROM with_posts_u_cte_0 AS u
 INNER JOIN db_standard.user_follows AS t0 ON t0.follower_id = u.p1_u_user_id
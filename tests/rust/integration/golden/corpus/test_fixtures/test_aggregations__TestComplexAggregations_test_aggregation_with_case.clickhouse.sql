@@ -1,4 +1,4 @@
 SELECT 
-      count(CASE WHEN n.age < 30 THEN 1 END) AS "young", 
-      count(CASE WHEN n.age >= 30 THEN 1 END) AS "mature"
+      count(multiIf(n.age < 30, 1, NULL)) AS "young", 
+      count(multiIf(n.age >= 30, 1, NULL)) AS "mature"
 FROM test_integration.users AS n
@@ -1,6 +1,6 @@
 SELECT 
-      CASE WHEN n.age < 30 THEN 'Young' ELSE 'Mature' END AS "age_group", 
+      multiIf(n.age < 30, 'Young', 'Mature') AS "age_group", 
       count(n.user_id) AS "count"
 FROM test_integration.users AS n
-GROUP BY CASE WHEN n.age < 30 THEN 'Young' ELSE 'Mature' END
+GROUP BY multiIf(n.age < 30, 'Young', 'Mature')
 ORDER BY age_group ASC
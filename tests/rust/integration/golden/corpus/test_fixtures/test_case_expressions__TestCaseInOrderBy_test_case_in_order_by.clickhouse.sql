@@ -2,4 +2,4 @@ SELECT
       n.name AS "n.name", 
       n.age AS "n.age"
 FROM test_integration.users AS n
-ORDER BY CASE WHEN n.name = 'Alice' THEN 1 WHEN n.name = 'Bob' THEN 2 ELSE 3 END ASC, n.name ASC
+ORDER BY multiIf(n.name = 'Alice', 1, n.name = 'Bob', 2, 3) ASC, n.name ASC
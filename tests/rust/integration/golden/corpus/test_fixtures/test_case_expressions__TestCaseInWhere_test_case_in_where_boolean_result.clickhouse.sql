@@ -2,5 +2,5 @@ SELECT
       n.name AS "n.name", 
       n.age AS "n.age"
 FROM test_integration.users AS n
-WHERE CASE WHEN n.age >= 30 THEN true ELSE false END
+WHERE multiIf(n.age >= 30, true, false)
 ORDER BY n.name ASC
@@ -1,5 +1,5 @@
 SELECT 
       n.name AS `n.name`
 FROM test_integration.users AS n
-WHERE caseWithExpression(n.name, 'Alice', 1, 'Bob', 1, 0) = 1
+WHERE CASE n.name WHEN 'Alice' THEN 1 WHEN 'Bob' THEN 1 ELSE 0 END = 1
 ORDER BY n.name ASC
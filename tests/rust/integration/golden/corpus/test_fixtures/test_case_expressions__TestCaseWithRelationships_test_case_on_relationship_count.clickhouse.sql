@@ -1,12 +1,18 @@
-WITH with_a_follows_cte_0 AS (SELECT 
-      anyLast(a.name) AS "p1_a_name", 
+WITH __optional_agg_0 AS (SELECT 
+      t0.follower_id AS "__key0", 
       count(t0.followed_id) AS "follows"
+FROM test_integration.follows AS t0
+GROUP BY t0.follower_id
+), 
+with_a_follows_cte_0 AS (SELECT 
+      anyLast(a.name) AS "p1_a_name", 
+      coalesce(anyLast(__optional_agg_0.follows), 0) AS "follows"
 FROM test_integration.users AS a
-LEFT JOIN test_integration.follows AS t0 ON t0.follower_id = a.user_id
+LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = a.user_id
 GROUP BY a.user_id
 )
 SELECT 
       a_follows.p1_a_name AS "a.name", 
-      CASE WHEN a_follows.follows = 0 THEN 'No follows' WHEN a_follows.follows = 1 THEN 'One follow' ELSE 'Multiple follows' END AS "follow_status"
+      multiIf(a_follows.follows = 0, 'No follows', a_follows.follows = 1, 'One follow', 'Multiple follows') AS "follow_status"
 FROM with_a_follows_cte_0 AS a_follows
 ORDER BY a_follows.p1_a_name ASC
@@ -1,6 +1,6 @@
 SELECT 
       n.name AS "n.name", 
       n.age AS "n.age", 
-      CASE WHEN n.age >= 30 THEN caseWithExpression(n.name, 'Alice', 'Senior Admin', 'Senior User') ELSE CASE WHEN n.age >= 25 THEN 'Regular User' ELSE 'Junior User' END END AS "role"
+      multiIf(n.age >= 30, caseWithExpression(n.name, 'Alice', 'Senior Admin', 'Senior User'), multiIf(n.age >= 25, 'Regular User', 'Junior User')) AS "role"
 FROM test_integration.users AS n
 ORDER BY n.name ASC
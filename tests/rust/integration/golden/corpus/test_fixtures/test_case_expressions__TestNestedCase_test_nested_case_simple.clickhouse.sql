@@ -1,6 +1,6 @@
 SELECT 
       n.name AS "n.name", 
       n.age AS "n.age", 
-      CASE WHEN n.age < 25 THEN 'Young' ELSE CASE WHEN n.age < 35 THEN 'Adult' ELSE 'Senior' END END AS "category"
+      multiIf(n.age < 25, 'Young', multiIf(n.age < 35, 'Adult', 'Senior')) AS "category"
 FROM test_integration.users AS n
 ORDER BY n.name ASC
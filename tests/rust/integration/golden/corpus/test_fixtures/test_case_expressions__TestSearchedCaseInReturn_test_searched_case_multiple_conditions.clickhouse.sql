@@ -1,6 +1,6 @@
 SELECT 
       n.name AS "n.name", 
       n.age AS "n.age", 
-      CASE WHEN (n.age > 30 AND n.name = 'Alice') THEN 'Senior Admin' WHEN n.age > 30 THEN 'Senior User' WHEN n.age > 25 THEN 'Regular User' ELSE 'Junior User' END AS "category"
+      multiIf((n.age > 30 AND n.name = 'Alice'), 'Senior Admin', n.age > 30, 'Senior User', n.age > 25, 'Regular User', 'Junior User') AS "category"
 FROM test_integration.users AS n
 ORDER BY n.name ASC
@@ -1,6 +1,6 @@
 SELECT 
       n.name AS "n.name", 
       n.age AS "n.age", 
-      CASE WHEN n.age < 25 THEN 'Young' WHEN n.age < 35 THEN 'Adult' ELSE 'Senior' END AS "age_group"
+      multiIf(n.age < 25, 'Young', n.age < 35, 'Adult', 'Senior') AS "age_group"
 FROM test_integration.users AS n
 ORDER BY n.name ASC
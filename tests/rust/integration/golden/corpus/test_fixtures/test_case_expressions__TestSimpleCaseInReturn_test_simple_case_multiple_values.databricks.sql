@@ -1,5 +1,5 @@
 SELECT 
       n.name AS `n.name`, 
-      caseWithExpression(n.name, 'Alice', 'Level 3', 'Bob', 'Level 2', 'Charlie', 'Level 2', 'Level 1') AS `level`
+      CASE n.name WHEN 'Alice' THEN 'Level 3' WHEN 'Bob' THEN 'Level 2' WHEN 'Charlie' THEN 'Level 2' ELSE 'Level 1' END AS `level`
 FROM test_integration.users AS n
 ORDER BY n.name ASC
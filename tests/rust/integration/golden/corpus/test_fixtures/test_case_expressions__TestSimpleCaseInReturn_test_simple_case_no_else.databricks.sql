@@ -1,5 +1,5 @@
 SELECT 
       n.name AS `n.name`, 
-      caseWithExpression(n.name, 'Alice', 'VIP', NULL) AS `status`
+      CASE n.name WHEN 'Alice' THEN 'VIP' END AS `status`
 FROM test_integration.users AS n
 ORDER BY n.name ASC
@@ -1,5 +1,5 @@
 SELECT 
       n.name AS `n.name`, 
-      caseWithExpression(n.name, 'Alice', 'Admin', 'User') AS `role`
+      CASE n.name WHEN 'Alice' THEN 'Admin' ELSE 'User' END AS `role`
 FROM test_integration.users AS n
 ORDER BY n.name ASC
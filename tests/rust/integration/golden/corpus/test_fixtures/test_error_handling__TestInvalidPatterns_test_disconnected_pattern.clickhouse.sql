@@ -2,5 +2,5 @@ SELECT
       a.name AS "a.name", 
       b.name AS "b.name"
 FROM test_integration.users AS a
-JOIN test_integration.users AS b ON 1 = 1
+CROSS JOIN test_integration.users AS b
 WHERE (a.name = 'Alice' AND b.name = 'Bob')
@@ -2,6 +2,6 @@ SELECT
       array('TestUser') AS `user_labels`, 
       array('TestUser') AS `n_labels`
 FROM test_integration.users AS n
-JOIN test_integration.users AS u ON 1 = 1
+CROSS JOIN test_integration.users AS u
 WHERE u.user_id = n.user_id
 LIMIT 1
\ No newline at end of file
@@ -5,6 +5,6 @@ SELECT
 FROM test_integration.users AS a
 LEFT JOIN test_integration.follows AS t0 ON t0.follower_id = a.user_id
 LEFT JOIN test_integration.users AS b ON b.user_id = t0.followed_id
-JOIN test_integration.users AS x ON 1 = 1
+CROSS JOIN test_integration.users AS x
 WHERE (x.name = 'Bob' AND a.name = 'Alice')
 ORDER BY b.name ASC
@@ -7,10 +7,16 @@ SELECT
       a.query AS `domain_name`
 FROM zeek.dns_log AS a
 
+), 
+__optional_agg_0 AS (SELECT 
+      r.query AS `__key0`, 
+      count(r.uid) AS `rel_count`
+FROM zeek.dns_log AS r
+GROUP BY r.query
 )
 SELECT 
       a.domain_name AS `a.domain_name`, 
-      count(r.uid) AS `rel_count`
+      coalesce(any_value(__optional_agg_0.rel_count), 0) AS `rel_count`
 FROM __denorm_scan_a AS a
-LEFT JOIN zeek.dns_log AS r ON a.domain_name = r.query
+LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = a.domain_name
 GROUP BY a.domain_name
@@ -7,10 +7,16 @@ SELECT
       a."id.resp_h" AS "ip_address"
 FROM zeek.dns_log AS a
 
+), 
+__optional_agg_0 AS (SELECT 
+      r."id.orig_h" AS "__key0", 
+      count(r.uid) AS "rel_count"
+FROM zeek.dns_log AS r
+GROUP BY r."id.orig_h"
 )
 SELECT 
       a.ip_address AS "a.ip_address", 
-      count(r.uid) AS "rel_count"
+      coalesce(anyLast(__optional_agg_0.rel_count), 0) AS "rel_count"
 FROM __denorm_scan_a AS a
-LEFT JOIN zeek.dns_log AS r ON a.ip_address = r."id.orig_h"
+LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = a.ip_address
 GROUP BY a.ip_address
@@ -1,4 +1,4 @@
 SELECT 
       count(*) AS "total"
 FROM zeek.dns_log AS r
-WHERE type(r) = 'REQUESTED'
+WHERE false
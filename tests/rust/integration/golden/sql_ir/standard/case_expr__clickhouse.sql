@@ -1,3 +1,3 @@
 SELECT 
-      CASE WHEN u.is_active = true THEN 'active' ELSE 'inactive' END AS "status"
+      multiIf(u.is_active = true, 'active', 'inactive') AS "status"
 FROM social.users_bench AS u
@@ -0,0 +1,7 @@
+SELECT 
+      a.full_name AS "a.name", 
+      b.post_title AS "b.title", 
+      c.post_title AS "c.title"
+FROM social.posts_bench AS b
+CROSS JOIN social.posts_bench AS c
+CROSS JOIN social.users_bench AS a
@@ -0,0 +1,131 @@
+//! `CALL graph.wcc(...)` / `CALL graph.scc(...)` over `/query`.
+//!
+//! Drives the real router with a stub executor, like
+//! `graph_page_rank_tests.rs`, that answers the components query with canned
+//! component ids.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::server::{build_router, AppState};
+
+use crate::fixtures::ensure_default_schema_registered;
+
+#[derive(Default)]
+struct ComponentsExecutor {
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for ComponentsExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(vec![
+            json!({"nodeId": 1, "componentId": 1}),
+            json!({"nodeId": 4, "componentId": 1}),
+            json!({"nodeId": 3, "componentId": 3}),
+        ])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn run(payload: Value) -> (StatusCode, Value, Vec<String>) {
+    ensure_default_schema_registered().await;
+    let executor = Arc::new(ComponentsExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, body, executed)
+}
+
+#[tokio::test]
+async fn wcc_returns_component_ids_per_node() {
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.wcc('User', 'FOLLOWS', {iterations: 10})"
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let [sql] = executed.as_slice() else {
+        panic!("expected one components query; got {executed:?}");
+    };
+    assert!(sql.contains("FROM social.user_follows_bench AS t"), "{sql}");
+    assert!(sql.contains("SELECT dst, src FROM wcc_edges"), "{sql}");
+    assert!(
+        sql.contains("FROM wcc_10 ORDER BY componentId, nodeId"),
+        "{sql}"
+    );
+
+    assert_eq!(body["count"], 3);
+    assert_eq!(body["records"][1]["nodeId"], 4);
+    assert_eq!(body["records"][1]["componentId"], 1);
+    assert_eq!(body["records"][2]["componentId"], 3);
+}
+
+#[tokio::test]
+async fn scc_sql_only_and_bad_arguments() {
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.scc('User', 'FOLLOWS', {iterations: 3})",
+        "sql_only": true
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    let sql = body["generated_sql"].as_str().unwrap();
+    assert!(sql.contains("scc_3 AS ("), "{sql}");
+    assert!(!sql.contains("scc_4"), "{sql}");
+    assert!(sql.contains("WHERE has(b.ancestors, a.node_id)"), "{sql}");
+
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.scc('User', 'FOLLOWS', {iterations: 100})"
+    }))
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "body: {body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    assert!(
+        body.as_str()
+            .unwrap_or_default()
+            .contains("graph.scc: iterations must be an integer from 1 to 40"),
+        "{body}"
+    );
+}
@@ -0,0 +1,262 @@
+//! `CALL graph.construct(cypher, mapping)` over `/query`.
+//!
+//! Drives the real router with a stub executor, like
+//! `stored_query_tests.rs`, and checks the INSERT statements it receives.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+use crate::fixtures;
+
+/// Remembers each statement; fails any that writes to `fail_table`.
+#[derive(Default)]
+struct RecordingExecutor {
+    fail_table: Option<&'static str>,
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for RecordingExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        Ok(Vec::new())
+    }
+    async fn execute_text(
+        &self,
+        sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        if self.fail_table.is_some_and(|t| sql.contains(t)) {
+            return Err(ExecutorError::QueryFailed("no such table".to_string()));
+        }
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(String::new())
+    }
+}
+
+/// The benchmark schema as `default`, and as the tenant graph
+/// `construct_tenant` reading database `acme`
+async fn ensure_default_schema_registered() {
+    fixtures::ensure_default_schema_registered().await;
+    let tenant = GraphSchemaConfig::from_yaml_file(
+        "benchmarks/social_network/schemas/social_benchmark.yaml",
+    )
+    .expect("load benchmark schema")
+    .for_tenant("construct_tenant", Some("acme"))
+    .to_graph_schema()
+    .expect("convert tenant schema");
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set by the fixture")
+        .write()
+        .await
+        .entry("construct_tenant".to_string())
+        .or_insert(tenant);
+}
+
+async fn run(executor: RecordingExecutor, payload: Value) -> (StatusCode, Value, Vec<String>) {
+    send(executor, payload, None, true).await
+}
+
+/// POST `payload` to a server that runs graph.construct writes when
+/// `allow_writes`, pinned to `pinned` by the `X-Graph-Name` header.
+async fn send(
+    executor: RecordingExecutor,
+    payload: Value,
+    pinned: Option<&str>,
+    allow_writes: bool,
+) -> (StatusCode, Value, Vec<String>) {
+    ensure_default_schema_registered().await;
+    let executor = Arc::new(executor);
+    let config = ServerConfig {
+        allow_graph_construct: allow_writes,
+        ..ServerConfig::default()
+    };
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: config.clone(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &config);
+    let mut request = Request::builder()
+        .method("POST")
+        .uri("/query")
+        .header("content-type", "application/json");
+    if let Some(pinned) = pinned {
+        request = request.header("X-Graph-Name", pinned);
+    }
+    let resp = app
+        .oneshot(request.body(Body::from(payload.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, body, executed)
+}
+
+const CO_FOLLOW: &str = "CALL graph.construct(
+    'MATCH (a:User)-[:FOLLOWS]->(x:User)<-[:FOLLOWS]-(b:User)
+     WHERE a.user_id < b.user_id
+     RETURN a.user_id AS src, b.user_id AS dst, count(*) AS shared',
+    {relationships: [{table: 'social.co_follows', columns: {a_id: 'src', b_id: 'dst', weight: 'shared'}}],
+     nodes: [{label: 'User', properties: {user_id: 'src'}}],
+     batch_size: 5000})";
+
+#[tokio::test]
+async fn construct_writes_nodes_then_relationships() {
+    let (status, body, executed) =
+        run(RecordingExecutor::default(), json!({"query": CO_FOLLOW})).await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(executed.len(), 2, "{executed:?}");
+    assert!(
+        executed[0].starts_with("INSERT INTO `social`.`users_bench` (`user_id`)")
+            && executed[0].contains("SELECT DISTINCT __construct.`src`"),
+        "{}",
+        executed[0]
+    );
+    assert!(
+        executed[1].starts_with("INSERT INTO `social`.`co_follows` (`a_id`, `b_id`, `weight`)")
+            && executed[1].contains("max_block_size = 5000"),
+        "{}",
+        executed[1]
+    );
+    let targets: Vec<_> = body["targets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["target"].as_str().unwrap())
+        .collect();
+    assert_eq!(targets, ["node:User", "table:social.co_follows"]);
+}
+
+#[tokio::test]
+async fn construct_reports_partial_writes_and_validates_mapping() {
+    let (status, body, executed) = run(
+        RecordingExecutor {
+            fail_table: Some("co_follows"),
+            ..Default::default()
+        },
+        json!({"query": CO_FOLLOW}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(executed.len(), 1);
+    assert!(
+        body.to_string().contains("already written: [node:User]"),
+        "body: {body}"
+    );
+
+    let (status, body, executed) = run(
+        RecordingExecutor::default(),
+        json!({"query": CO_FOLLOW.replace("weight: 'shared'", "weight: 'total'")}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.to_string().contains("`total`"), "body: {body}");
+    assert!(executed.is_empty());
+
+    let (status, body, executed) = run(
+        RecordingExecutor::default(),
+        json!({"query": CO_FOLLOW, "sql_only": true}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(executed.is_empty());
+    assert_eq!(
+        body["generated_sql"]
+            .as_str()
+            .unwrap()
+            .matches("INSERT INTO")
+            .count(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn construct_writes_only_when_the_server_allows_them() {
+    let (status, body, executed) = send(
+        RecordingExecutor::default(),
+        json!({"query": CO_FOLLOW}),
+        None,
+        false,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "body: {body}");
+    assert!(
+        body.to_string()
+            .contains("CLICKGRAPH_ALLOW_GRAPH_CONSTRUCT"),
+        "body: {body}"
+    );
+    assert!(executed.is_empty());
+
+    // Previews write nothing, so they are still served
+    let (status, body, executed) = send(
+        RecordingExecutor::default(),
+        json!({"query": CO_FOLLOW, "sql_only": true}),
+        None,
+        false,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(executed.is_empty());
+}
+
+#[tokio::test]
+async fn pinned_graph_cannot_construct_into_a_foreign_database() {
+    let pinned = Some("construct_tenant");
+    // `social` is the base schema's database, not the tenant's
+    for database in ["social", "derived"] {
+        let query = CO_FOLLOW.replace("social.co_follows", &format!("{database}.co_follows"));
+        let (status, body, executed) = send(
+            RecordingExecutor::default(),
+            json!({"query": query, "schema_name": "construct_tenant"}),
+            pinned,
+            true,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "body: {body}");
+        assert!(
+            body.to_string()
+                .contains("outside the graph's databases (acme)"),
+            "body: {body}"
+        );
+        assert!(executed.is_empty());
+    }
+
+    let query = CO_FOLLOW.replace("social.co_follows", "acme.co_follows");
+    let (status, body, executed) = send(
+        RecordingExecutor::default(),
+        json!({"query": query, "schema_name": "construct_tenant"}),
+        pinned,
+        true,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(
+        executed
+            .iter()
+            .all(|sql| sql.starts_with("INSERT INTO `acme`.")),
+        "{executed:?}"
+    );
+}
@@ -0,0 +1,124 @@
+//! `CALL graph.pageRank(...)` over `/query`.
+//!
+//! Drives the real router with a stub executor, like
+//! `graph_quality_tests.rs`, that answers the PageRank query with canned
+//! scores.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::server::{build_router, AppState};
+
+use crate::fixtures::ensure_default_schema_registered;
+
+#[derive(Default)]
+struct PageRankExecutor {
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for PageRankExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(vec![
+            json!({"nodeId": 2, "score": 0.5}),
+            json!({"nodeId": 1, "score": "0.25"}),
+        ])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn run(payload: Value) -> (StatusCode, Value, Vec<String>) {
+    ensure_default_schema_registered().await;
+    let executor = Arc::new(PageRankExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, body, executed)
+}
+
+#[tokio::test]
+async fn page_rank_returns_node_ids_and_scores() {
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.pageRank('User', 'FOLLOWS', {iterations: 20})"
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let [sql] = executed.as_slice() else {
+        panic!("expected one PageRank query; got {executed:?}");
+    };
+    assert!(sql.contains("FROM social.user_follows_bench AS t"), "{sql}");
+    assert!(sql.contains("FROM pr_20 ORDER BY score DESC"), "{sql}");
+
+    assert_eq!(body["count"], 2);
+    assert_eq!(body["records"][0]["nodeId"], 2);
+    assert_eq!(body["records"][0]["score"], 0.5);
+    assert_eq!(body["records"][1]["score"], 0.25);
+}
+
+#[tokio::test]
+async fn page_rank_sql_only_and_bad_arguments() {
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.pageRank('User', 'FOLLOWS', {iterations: 2, dampingFactor: 0.9})",
+        "sql_only": true
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    let sql = body["generated_sql"].as_str().unwrap();
+    assert!(sql.contains("pr_2 AS ("), "{sql}");
+    assert!(!sql.contains("pr_3"), "{sql}");
+    assert!(sql.contains("0.9 * coalesce(c.contribution, 0)"), "{sql}");
+
+    let (status, body, executed) =
+        run(json!({"query": "CALL graph.pageRank('User', 'AUTHORED')"})).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "body: {body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    assert!(
+        body.as_str()
+            .unwrap_or_default()
+            .contains("does not connect"),
+        "{body}"
+    );
+}
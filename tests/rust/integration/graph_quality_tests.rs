@@ -0,0 +1,122 @@
+//! `CALL graph.quality()` over `/query`.
+//!
+//! Drives the real router with a stub executor, like
+//! `graph_construct_tests.rs`, that answers the quality query with canned
+//! counters.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::server::{build_router, AppState};
+
+use crate::fixtures::ensure_default_schema_registered;
+
+#[derive(Default)]
+struct QualityExecutor {
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for QualityExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(vec![
+            json!({
+                "kind": "node", "name": "User", "table_name": "social.users_bench",
+                "row_count": "1000", "table_row_count": "1000", "null_ids": "0",
+                "duplicate_ids": "3", "orphan_from": null, "orphan_to": null
+            }),
+            json!({
+                "kind": "relationship", "name": "FOLLOWS",
+                "table_name": "social.user_follows_bench",
+                "row_count": "5000", "table_row_count": "5000", "null_ids": "50",
+                "duplicate_ids": null, "orphan_from": "12", "orphan_to": "0"
+            }),
+        ])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn run(payload: Value) -> (StatusCode, Value, Vec<String>) {
+    ensure_default_schema_registered().await;
+    let executor = Arc::new(QualityExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, body, executed)
+}
+
+#[tokio::test]
+async fn quality_reports_counters_and_ratios() {
+    let (status, body, executed) = run(json!({"query": "CALL graph.quality()"})).await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let [sql] = executed.as_slice() else {
+        panic!("expected one quality query; got {executed:?}");
+    };
+    assert!(
+        sql.contains("social.users_bench") && sql.contains("social.user_follows_bench"),
+        "{sql}"
+    );
+
+    assert_eq!(body["count"], 2);
+    let user = &body["records"][0];
+    assert_eq!(user["name"], "User");
+    assert_eq!(user["duplicate_ids"], 3);
+    assert_eq!(user["label_coverage"], 1.0);
+    let follows = &body["records"][1];
+    assert_eq!(follows["orphan_from"], 12);
+    assert_eq!(follows["null_id_ratio"], 0.01);
+    assert_eq!(follows["duplicate_ids"], Value::Null);
+}
+
+#[tokio::test]
+async fn quality_sql_only_returns_the_query() {
+    let (status, body, executed) =
+        run(json!({"query": "CALL graph.quality()", "sql_only": true})).await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    let sql = body["generated_sql"].as_str().unwrap();
+    assert!(sql.contains("UNION ALL"), "{sql}");
+    assert!(sql.contains("AS orphan_from"), "{sql}");
+}
@@ -0,0 +1,156 @@
+//! `CALL graph.sampleSubgraph(...)` over `/query`.
+//!
+//! Drives the real router with a stub executor, like
+//! `graph_quality_tests.rs`, that records the sampling statements and
+//! answers the row-count query.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS, GLOBAL_SCHEMA_CONFIGS};
+
+const SCHEMA_NAME: &str = "sample_bench";
+
+#[derive(Default)]
+struct SampleExecutor {
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for SampleExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(vec![json!({"table_name": "users_bench", "rows": "950"})])
+    }
+    async fn execute_text(
+        &self,
+        sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(String::new())
+    }
+}
+
+async fn ensure_schema_registered() {
+    let config = GraphSchemaConfig::from_yaml_file(
+        "benchmarks/social_network/schemas/social_benchmark.yaml",
+    )
+    .expect("load benchmark schema");
+    let schema = config.to_graph_schema().expect("convert benchmark schema");
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    let _ = GLOBAL_SCHEMA_CONFIGS.set(tokio::sync::RwLock::new(HashMap::new()));
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await
+        .insert(SCHEMA_NAME.to_string(), schema);
+    GLOBAL_SCHEMA_CONFIGS
+        .get()
+        .expect("GLOBAL_SCHEMA_CONFIGS set above")
+        .write()
+        .await
+        .insert(SCHEMA_NAME.to_string(), config);
+}
+
+async fn run(payload: Value) -> (StatusCode, Value, Vec<String>) {
+    ensure_schema_registered().await;
+    let executor = Arc::new(SampleExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, body, executed)
+}
+
+#[tokio::test]
+async fn sample_copies_tables_and_returns_derived_schema() {
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.sampleSubgraph({nodesPerLabel: 1000, database: 'dev_sample'})",
+        "schema_name": SCHEMA_NAME,
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+
+    assert_eq!(executed[0], "CREATE DATABASE IF NOT EXISTS `dev_sample`");
+    let users = executed
+        .iter()
+        .position(|sql| sql.starts_with("CREATE TABLE `dev_sample`.`users_bench`"))
+        .expect("users table copied");
+    let follows = executed
+        .iter()
+        .position(|sql| sql.starts_with("CREATE TABLE `dev_sample`.`user_follows_bench`"))
+        .expect("follows table copied");
+    assert!(
+        users < follows,
+        "node tables are copied first: {executed:?}"
+    );
+    assert!(executed.last().unwrap().contains("count() AS rows"));
+
+    let record = &body["records"][0];
+    assert_eq!(record["database"], "dev_sample");
+    let yaml = record["schema_yaml"].as_str().expect("schema yaml");
+    let derived = GraphSchemaConfig::from_yaml_str(yaml).expect("derived schema loads");
+    assert_eq!(derived.name.as_deref(), Some("sample_bench_sample"));
+    let tables = record["tables"].as_array().unwrap();
+    let users = tables
+        .iter()
+        .find(|t| t["table"] == "dev_sample.users_bench")
+        .expect("users table reported");
+    assert_eq!(users["rows"], 950);
+}
+
+#[tokio::test]
+async fn sample_sql_only_returns_statements_without_running_them() {
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.sampleSubgraph({preserveDegreeDistribution: false})",
+        "schema_name": SCHEMA_NAME,
+        "sql_only": true,
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    let sql = body["generated_sql"].as_str().unwrap();
+    assert!(sql.starts_with("CREATE DATABASE IF NOT EXISTS `sample_bench_sample`"));
+    assert!(
+        sql.contains("ORDER BY cityHash64(key, 0) LIMIT 10000"),
+        "{sql}"
+    );
+}
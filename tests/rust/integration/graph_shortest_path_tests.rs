@@ -0,0 +1,169 @@
+//! `CALL graph.shortestPath.dijkstra(...)` over `/query`.
+//!
+//! Drives the real router with a stub executor, like
+//! `graph_components_tests.rs`, that answers the path query with a canned
+//! cheapest path.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+const ROADS_YAML: &str = r#"
+graph_schema:
+  nodes:
+    - label: Junction
+      database: logistics
+      table: junctions
+      node_id: junction_id
+      property_mappings:
+        junction_id: junction_id
+  edges:
+    - type: ROAD
+      database: logistics
+      table: road_segments
+      from_id: from_junction
+      to_id: to_junction
+      from_node: Junction
+      to_node: Junction
+      property_mappings:
+        cost: travel_seconds
+"#;
+
+#[derive(Default)]
+struct PathExecutor {
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for PathExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(vec![json!({
+            "sourceNodeId": 1,
+            "targetNodeId": 9,
+            "totalCost": "42.5",
+            "nodeIds": [1, 5, 9],
+            "costs": [0, "12", "42.5"],
+        })])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_roads_schema_registered() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let schema = GraphSchemaConfig::from_yaml_str(ROADS_YAML)
+        .expect("parse roads schema")
+        .to_graph_schema()
+        .expect("convert roads schema");
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    map.entry("roads".to_string()).or_insert(schema);
+}
+
+async fn run(payload: Value) -> (StatusCode, Value, Vec<String>) {
+    ensure_roads_schema_registered().await;
+    let executor = Arc::new(PathExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, body, executed)
+}
+
+#[tokio::test]
+async fn dijkstra_returns_cheapest_path() {
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'cost'})",
+        "schema_name": "roads"
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let [sql] = executed.as_slice() else {
+        panic!("expected one path query; got {executed:?}");
+    };
+    assert!(sql.starts_with("WITH RECURSIVE sp_nodes AS"), "{sql}");
+    assert!(
+        sql.contains("toFloat64(t.travel_seconds) AS weight FROM logistics.road_segments AS t"),
+        "{sql}"
+    );
+    assert!(sql.contains("p.total_weight + e.weight AS w"), "{sql}");
+
+    assert_eq!(body["count"], 1);
+    let record = &body["records"][0];
+    assert_eq!(record["totalCost"], 42.5);
+    assert_eq!(record["nodeIds"], json!([1, 5, 9]));
+    assert_eq!(record["costs"], json!([0, 12.0, 42.5]));
+}
+
+#[tokio::test]
+async fn dijkstra_sql_only_and_bad_arguments() {
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'cost', maxHops: 7})",
+        "schema_name": "roads",
+        "sql_only": true
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    let sql = body["generated_sql"].as_str().unwrap();
+    assert!(sql.contains("length(p.path_nodes) <= 7"), "{sql}");
+
+    let (status, body, executed) = run(json!({
+        "query": "CALL graph.shortestPath.dijkstra(1, 9, {weightProperty: 'toll'})",
+        "schema_name": "roads"
+    }))
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "body: {body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    assert!(
+        body.as_str()
+            .unwrap_or_default()
+            .contains("no relationship type with property `toll`"),
+        "{body}"
+    );
+}
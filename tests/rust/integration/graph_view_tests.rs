@@ -0,0 +1,122 @@
+//! Graph views: `USE social.prod_only` queries a subset of the schema's
+//! labels and types whose scans all carry the view's filters.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_cypher_statement,
+    query_planner::evaluate_read_statement,
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+    server::query_context::{set_current_schema, with_query_context, QueryContext},
+};
+
+const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: app
+      table: users
+      node_id: user_id
+      filter: "is_deleted = 0"
+      property_mappings:
+        user_id: user_id
+        name: name
+    - label: Bot
+      database: app
+      table: bots
+      node_id: bot_id
+      property_mappings:
+        bot_id: bot_id
+  edges:
+    - type: FOLLOWS
+      database: app
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings: {}
+    - type: RUNS
+      database: app
+      table: runs
+      from_id: user_id
+      to_id: bot_id
+      from_node: User
+      to_node: Bot
+      property_mappings: {}
+  views:
+    prod_only:
+      labels: [User]
+      filters:
+        User: "env = 'prod'"
+        FOLLOWS: "created_at >= now() - toIntervalDay(30)"
+"#;
+
+/// The graph `USE social.prod_only` selects, as the catalog registers it
+fn view_schema() -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(SCHEMA)
+        .unwrap()
+        .for_view("social.prod_only", "prod_only")
+        .unwrap()
+        .to_graph_schema()
+        .unwrap()
+}
+
+async fn cypher_to_sql(schema: GraphSchema, cypher: &str) -> Result<String, String> {
+    let cypher = cypher.to_string();
+    with_query_context(
+        QueryContext::new(Some("social.prod_only".to_string())),
+        async move {
+            set_current_schema(std::sync::Arc::new(schema.clone()));
+            clickgraph::query_planner::logical_plan::reset_all_counters();
+            let (_, statement) = parse_cypher_statement(&cypher).expect("parse");
+            let (logical_plan, plan_ctx) =
+                evaluate_read_statement(statement, &schema, None, None, None)
+                    .map_err(|e| e.to_string())?;
+            let render_plan =
+                logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+                    .map_err(|e| e.to_string())?;
+            Ok(render_plan.to_sql())
+        },
+    )
+    .await
+}
+
+#[tokio::test]
+async fn view_filters_every_scan() {
+    let sql = cypher_to_sql(
+        view_schema(),
+        "USE social.prod_only MATCH (a:User)-[f:FOLLOWS]->(b:User) RETURN a.name, b.name",
+    )
+    .await
+    .unwrap();
+    for predicate in [
+        "a.is_deleted = 0",
+        "a.env = 'prod'",
+        "b.env = 'prod'",
+        "f.created_at >= (now() - toIntervalDay(30))",
+    ] {
+        assert!(sql.contains(predicate), "missing {predicate}; SQL:\n{sql}");
+    }
+}
+
+#[tokio::test]
+async fn view_hides_dropped_labels_and_types() {
+    let err = cypher_to_sql(
+        view_schema(),
+        "USE social.prod_only MATCH (u:User)-[:RUNS]->(b:Bot) RETURN b.bot_id",
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("RUNS") || err.contains("Bot"), "{err}");
+}
+
+#[test]
+fn use_clause_names_the_view_graph() {
+    let (_, statement) =
+        parse_cypher_statement("USE social.prod_only MATCH (u:User) RETURN u").unwrap();
+    assert_eq!(
+        clickgraph::query_planner::target_graph(&statement).unwrap(),
+        Some("social.prod_only")
+    );
+}
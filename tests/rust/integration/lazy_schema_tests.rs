@@ -0,0 +1,188 @@
+//! Lazy schema elements against the real router.
+//!
+//! A stub executor plays ClickHouse's `system.columns` / `system.tables`:
+//! the `events` table exists (with a column the mappings don't mention),
+//! `user_events` doesn't yet.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::{DiscoveredTables, GraphSchemaConfig};
+use clickgraph::server::{
+    build_router, lazy_schema, AppState, GLOBAL_SCHEMAS, GLOBAL_SCHEMA_CONFIGS,
+};
+
+const SCHEMA: &str = r#"
+name: lazy_graph
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: name
+    - label: Event
+      database: test
+      table: events
+      node_id: event_id
+      lazy: true
+      auto_discover_columns: true
+      property_mappings:
+        event_id: event_id
+  edges:
+    - type: DID
+      database: test
+      table: user_events
+      from_id: user_id
+      to_id: event_id
+      from_node: User
+      to_node: Event
+      lazy: true
+"#;
+
+#[derive(Default)]
+struct CatalogExecutor {
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for CatalogExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        if sql.contains("system.columns") {
+            if sql.contains("'events'") {
+                return Ok(vec![
+                    json!({"name": "event_id", "type": "UInt64"}),
+                    json!({"name": "payload", "type": "String"}),
+                ]);
+            }
+            return Ok(Vec::new());
+        }
+        if sql.contains("system.tables") {
+            return Ok(vec![
+                json!({"engine": "MergeTree", "engine_full": "MergeTree ORDER BY event_id"}),
+            ]);
+        }
+        Ok(vec![json!({"e.payload": "clicked"})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn register_schema() {
+    let config = GraphSchemaConfig::from_yaml_str(SCHEMA).expect("parse schema");
+    let schema = config.to_graph_schema().expect("convert schema");
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    let _ = GLOBAL_SCHEMA_CONFIGS.set(tokio::sync::RwLock::new(HashMap::new()));
+    lazy_schema::track("lazy_graph", &config, DiscoveredTables::default());
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await
+        .insert("lazy_graph".to_string(), schema);
+    GLOBAL_SCHEMA_CONFIGS
+        .get()
+        .expect("GLOBAL_SCHEMA_CONFIGS set above")
+        .write()
+        .await
+        .insert("lazy_graph".to_string(), config);
+}
+
+async fn send(executor: Arc<CatalogExecutor>, request: Request<Body>) -> (StatusCode, Value) {
+    let config = ServerConfig::default();
+    let state = AppState {
+        executor,
+        clickhouse_client: None,
+        config: config.clone(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let resp = build_router(state, &config).oneshot(request).await.unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    (status, body)
+}
+
+fn status_request(schema: &str) -> Request<Body> {
+    Request::builder()
+        .uri(format!("/schemas/{schema}/status"))
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn first_query_completes_lazy_elements() {
+    register_schema().await;
+    let executor = Arc::new(CatalogExecutor::default());
+
+    let (status, body) = send(executor.clone(), status_request("lazy_graph")).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["complete"], false);
+    assert_eq!(body["pending"], 2);
+    assert!(executor.executed.lock().unwrap().is_empty());
+
+    // `payload` only exists once the Event table's columns are discovered
+    let payload = json!({
+        "query": "MATCH (e:Event) RETURN e.payload",
+        "schema_name": "lazy_graph",
+    });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/query")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap();
+    let (status, body) = send(executor.clone(), request).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let executed = executor.executed.lock().unwrap().clone();
+    let query_sql = executed.last().unwrap();
+    assert!(query_sql.contains("payload"), "{query_sql}");
+
+    let (_, body) = send(executor, status_request("lazy_graph")).await;
+    assert_eq!(body["complete"], false);
+    assert_eq!(body["pending"], 0);
+    assert_eq!(body["failed"], 1);
+    let elements = body["elements"].as_array().unwrap();
+    assert_eq!(elements[0]["name"], "Event");
+    assert_eq!(elements[0]["state"], "ready");
+    assert!(elements[0]["checked_at"].is_string());
+    assert_eq!(elements[1]["name"], "DID");
+    assert_eq!(elements[1]["state"], "failed");
+    assert_eq!(
+        elements[1]["error"],
+        "table test.user_events does not exist"
+    );
+}
+
+#[tokio::test]
+async fn status_of_unknown_schema_is_not_found() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    let executor = Arc::new(CatalogExecutor::default());
+    let (status, _) = send(executor, status_request("no_such_graph")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,154 @@
+//! `GET /schemas/{name}/llm-context` against the real router.
+//!
+//! A stub executor answers every sampling query with the same example
+//! arrays and records what it was asked.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS, GLOBAL_SCHEMA_CONFIGS};
+
+const SCHEMA: &str = r#"
+name: llm_social
+graph_schema:
+  nodes:
+    - label: Person
+      database: test
+      table: people
+      node_id: person_id
+      description: People on the platform
+      property_mappings:
+        person_id: person_id
+        name: full_name
+      property_types:
+        person_id: integer
+  edges:
+    - type: KNOWS
+      database: test
+      table: knows
+      from_id: from_person
+      to_id: to_person
+      from_node: Person
+      to_node: Person
+      description: Mutual acquaintance
+      property_mappings:
+        since: since_date
+"#;
+
+#[derive(Default)]
+struct SampleExecutor {
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for SampleExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(vec![json!({"p0": ["Alice", "Bob"], "p1": ["1", "2"]})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_schema_registered() {
+    let config = GraphSchemaConfig::from_yaml_str(SCHEMA).expect("parse schema");
+    let schema = config.to_graph_schema().expect("convert schema");
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    let _ = GLOBAL_SCHEMA_CONFIGS.set(tokio::sync::RwLock::new(HashMap::new()));
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await
+        .insert("llm_social".to_string(), schema);
+    GLOBAL_SCHEMA_CONFIGS
+        .get()
+        .expect("GLOBAL_SCHEMA_CONFIGS set above")
+        .write()
+        .await
+        .insert("llm_social".to_string(), config);
+}
+
+async fn get(uri: &str) -> (StatusCode, Value, Vec<String>) {
+    ensure_schema_registered().await;
+    let executor = Arc::new(SampleExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let resp = build_router(state, &ServerConfig::default())
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes).expect("JSON body");
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, body, executed)
+}
+
+#[tokio::test]
+async fn context_lists_labels_patterns_and_examples() {
+    let (status, body, executed) = get("/schemas/llm_social/llm-context?samples=2").await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(executed.len(), 2);
+    assert!(
+        executed
+            .iter()
+            .all(|sql| sql.contains("groupUniqArrayIf(2)")),
+        "{executed:?}"
+    );
+
+    let person = &body["nodes"][0];
+    assert_eq!(person["label"], "Person");
+    assert_eq!(person["description"], "People on the platform");
+    assert_eq!(person["id"], json!(["person_id"]));
+    assert_eq!(person["properties"], json!(["name", "person_id: integer"]));
+    assert_eq!(person["examples"]["name"], json!(["Alice", "Bob"]));
+    assert_eq!(person["examples"]["person_id"], json!(["1", "2"]));
+
+    let knows = &body["edges"][0];
+    assert_eq!(knows["pattern"], "(:Person)-[:KNOWS]->(:Person)");
+    assert_eq!(knows["description"], "Mutual acquaintance");
+    assert_eq!(knows["examples"]["since"], json!(["Alice", "Bob"]));
+    assert!(body["estimated_tokens"].as_u64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn zero_samples_skips_clickhouse() {
+    let (status, body, executed) = get("/schemas/llm_social/llm-context?samples=0").await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert!(executed.is_empty(), "{executed:?}");
+    assert!(body["nodes"][0].get("examples").is_none());
+}
+
+#[tokio::test]
+async fn unknown_schema_is_not_found() {
+    let (status, body, _) = get("/schemas/no_such_schema/llm-context").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(body["error"].as_str().is_some(), "{body}");
+}
@@ -4,42 +4,20 @@
 
 use std::sync::Arc;
 
-use async_trait::async_trait;
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
 use serde_json::Value;
 use tower::ServiceExt; // for `oneshot`
 
 use clickgraph::config::ServerConfig;
-use clickgraph::executor::{ExecutorError, QueryExecutor};
 use clickgraph::server::metrics::{ErrorClass, MetricsConfig, Outcome, QuerySample, ServerMetrics};
 use clickgraph::server::{build_router, AppState, GLOBAL_SERVER_METRICS};
 
-/// Minimal executor — the observability endpoints never invoke it.
-struct StubExecutor;
-
-#[async_trait]
-impl QueryExecutor for StubExecutor {
-    async fn execute_json(
-        &self,
-        _sql: &str,
-        _role: Option<&str>,
-    ) -> Result<Vec<Value>, ExecutorError> {
-        Ok(vec![])
-    }
-    async fn execute_text(
-        &self,
-        _sql: &str,
-        _format: &str,
-        _role: Option<&str>,
-    ) -> Result<String, ExecutorError> {
-        Ok(String::new())
-    }
-}
+use crate::fixtures::NoopExecutor;
 
 fn test_state() -> AppState {
     AppState {
-        executor: Arc::new(StubExecutor),
+        executor: Arc::new(NoopExecutor),
         clickhouse_client: None,
         config: ServerConfig::default(),
         query_semaphore: None,
@@ -103,6 +81,11 @@ async fn metrics_endpoint_serves_prometheus() {
     assert!(body.contains("clickgraph_queries_total"));
     assert!(body.contains("clickgraph_query_duration_seconds_bucket{phase=\"total\""));
     assert!(body.contains("clickgraph_queries_by_type_total{type=\"read\"}"));
+    assert!(body.contains("clickgraph_queries_by_status_total{status=\"ok\"}"));
+    assert!(body.contains("# TYPE clickgraph_planner_stage_duration_seconds histogram"));
+    assert!(body.contains("# TYPE clickgraph_query_cte_depth histogram"));
+    assert!(body.contains("clickgraph_bolt_sessions_open"));
+    assert!(body.contains("clickgraph_clickhouse_round_trips_total"));
 
     // Every non-comment, non-blank line is a valid `name <value>` exposition.
     for line in body.lines() {
@@ -114,6 +97,35 @@ async fn metrics_endpoint_serves_prometheus() {
     }
 }
 
+#[tokio::test]
+async fn read_planning_records_stage_latency() {
+    use clickgraph::graph_catalog::config::GraphSchemaConfig;
+    use clickgraph::open_cypher_parser::parse_cypher_statement;
+    use clickgraph::query_planner::evaluate_read_statement;
+    use clickgraph::server::query_context::{with_query_context, QueryContext};
+
+    let reg = ensure_metrics();
+    let before = reg.snapshot().planner_latency;
+    let schema = GraphSchemaConfig::from_yaml_file(
+        "benchmarks/social_network/schemas/social_benchmark.yaml",
+    )
+    .expect("load benchmark schema")
+    .to_graph_schema()
+    .expect("convert benchmark schema");
+    with_query_context(QueryContext::new(None), async {
+        let (_, statement) = parse_cypher_statement("MATCH (u:User) RETURN u.name").expect("parse");
+        evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+    })
+    .await;
+
+    let after = reg.snapshot().planner_latency;
+    let stages: Vec<_> = after.iter().map(|s| s.phase).collect();
+    assert_eq!(stages, ["build", "analyze", "optimize"]);
+    for (b, a) in before.iter().zip(&after) {
+        assert!(a.count > b.count, "{} not recorded", a.phase);
+    }
+}
+
 #[tokio::test]
 async fn stats_endpoint_serves_json() {
     ensure_metrics();
@@ -136,6 +148,9 @@ async fn stats_endpoint_serves_json() {
     assert!(json["metrics"]["uptime_secs"].is_u64());
     assert!(json["metrics"]["queries_total"].is_u64());
     assert!(json["metrics"]["latency"].is_array());
+    assert!(json["metrics"]["planner_latency"].is_array());
+    assert!(json["metrics"]["bolt_sessions_open"].is_i64());
+    assert!(json["metrics"]["clickhouse"]["round_trips"].is_u64());
 }
 
 #[tokio::test]
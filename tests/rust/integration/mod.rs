@@ -2,21 +2,68 @@
 //!
 //! These tests verify that components work together correctly with real dependencies.
 
+// Shared stubs; each test binary uses a subset
+#[allow(dead_code)]
+#[path = "../fixtures/mod.rs"]
+mod fixtures;
+
+mod api_client_contract_tests;
+mod bitemporal_tests;
 mod browser_expand_tests;
 mod browser_interaction_tests;
 pub(crate) mod browser_test_schemas;
+mod bulk_import_tests;
+mod cartesian_guard_tests;
+mod clickhouse_settings_tests;
+mod cluster_tests;
+mod collation_tests;
 mod complex_feature_tests;
 mod corpus_sweep;
 mod cross_schema_pattern_tests;
 mod cte_column_aliasing_tests;
 #[cfg(feature = "databricks")]
 mod databricks_introspect_tests;
+mod explain_profile_tests;
+mod freshness_tests;
+mod graph_components_tests;
+mod graph_construct_tests;
+mod graph_page_rank_tests;
+mod graph_quality_tests;
+mod graph_sample_tests;
+mod graph_shortest_path_tests;
+mod graph_view_tests;
+mod lazy_schema_tests;
 mod ldbc_regression_tests;
+mod llm_context_tests;
 mod metrics_endpoint_tests;
+mod multi_rel_type_tests;
+mod natural_query_tests;
+mod neo4j_http_tx_tests;
+mod optional_after_with_tests;
 mod parameter_function_test;
 mod path_variable_tests;
+mod query_cache_settings_tests;
+mod query_parameter_tests;
+mod query_timeout_tests;
+mod resource_report_tests;
+mod response_budget_tests;
+mod return_projection_tests;
+mod scan_pushdown_tests;
+mod schema_lint_tests;
+mod schema_overrides_tests;
+mod schema_reload_tests;
+mod schema_template_tests;
+mod script_tests;
+mod session_param_tests;
 mod skip_offset_tests;
+mod snapshot_tests;
+mod soft_delete_tests;
 mod sql_generation_handler_comment_tests;
 mod sql_golden_tests;
 mod stats_anchor_golden_tests;
+mod stored_query_tests;
+mod streaming_results_tests;
+mod subgraph_export_tests;
+mod use_clause_routing_tests;
+mod with_chaining_tests;
 mod with_where_having_tests;
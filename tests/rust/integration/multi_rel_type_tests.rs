@@ -0,0 +1,134 @@
+//! Relationship type alternation `[r:A|B]`: one UNION branch per edge table,
+//! collapsed to `type_column IN (...)` when the types share a polymorphic
+//! table, with the relationship's properties readable as `r.prop`.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_query,
+    query_planner::evaluate_read_query,
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+};
+
+const SCHEMA: &str = r#"
+name: multi_rel_type_test
+graph_schema:
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+    - label: Post
+      database: test
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+        title: post_title
+  edges:
+    - polymorphic: true
+      database: test
+      table: interactions
+      from_id: from_id
+      to_id: to_id
+      type_column: interaction_type
+      from_label_column: from_type
+      to_label_column: to_type
+      type_values:
+        - FOLLOWS
+        - BLOCKS
+      property_mappings:
+        weight: interaction_weight
+    - type: AUTHORED
+      database: test
+      table: authored
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings:
+        authored_at: created_at
+    - type: LIKED
+      database: test
+      table: likes
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings:
+        liked_at: liked_date
+"#;
+
+fn schema() -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(SCHEMA)
+        .expect("parse schema")
+        .to_graph_schema()
+        .expect("convert schema")
+}
+
+fn cypher_to_sql(cypher: &str) -> String {
+    let schema = schema();
+    let ast = parse_query(cypher).expect("Failed to parse Cypher");
+    let (logical_plan, plan_ctx) =
+        evaluate_read_query(ast, &schema, None, None).expect("Failed to build logical plan");
+    let render_plan = logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+        .expect("Failed to render plan");
+    render_plan.to_sql()
+}
+
+#[test]
+fn polymorphic_types_share_one_branch_with_in_filter() {
+    let sql =
+        cypher_to_sql("MATCH (a:User)-[r:FOLLOWS|BLOCKS]->(b:User) RETURN a.name, b.name, type(r)");
+    assert!(
+        sql.contains("interaction_type IN ('BLOCKS', 'FOLLOWS')"),
+        "SQL:\n{sql}"
+    );
+    assert!(!sql.contains("UNION ALL"), "SQL:\n{sql}");
+    assert!(
+        sql.contains("[r1.interaction_type] AS path_relationships"),
+        "SQL:\n{sql}"
+    );
+}
+
+#[test]
+fn separate_tables_get_one_union_branch_each() {
+    let sql = cypher_to_sql("MATCH (a:User)-[r:AUTHORED|LIKED]->(p:Post) RETURN a.name, p.title");
+    assert!(sql.contains("test.authored"), "SQL:\n{sql}");
+    assert!(sql.contains("test.likes"), "SQL:\n{sql}");
+    assert_eq!(sql.matches("UNION ALL").count(), 1, "SQL:\n{sql}");
+}
+
+#[test]
+fn relationship_property_filter_and_return_use_mapped_columns() {
+    let sql = cypher_to_sql(
+        "MATCH (a:User)-[r:FOLLOWS|BLOCKS]->(b:User) WHERE r.weight > 1 RETURN a.name, r.weight",
+    );
+    assert!(sql.contains("r1.interaction_weight > 1"), "SQL:\n{sql}");
+    assert!(
+        sql.contains("r1.interaction_weight AS rel_prop_weight"),
+        "SQL:\n{sql}"
+    );
+    assert!(
+        sql.contains(r#"t.rel_prop_weight AS "r.weight""#),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("WHERE t.rel_prop_weight > 1"), "SQL:\n{sql}");
+}
+
+#[test]
+fn relationship_property_missing_on_a_type_is_null_in_that_branch() {
+    let sql =
+        cypher_to_sql("MATCH (a:User)-[r:AUTHORED|LIKED]->(p:Post) RETURN a.name, r.authored_at");
+    assert!(
+        sql.contains("r1.created_at AS rel_prop_authored_at"),
+        "SQL:\n{sql}"
+    );
+    assert!(sql.contains("NULL AS rel_prop_authored_at"), "SQL:\n{sql}");
+    assert!(
+        sql.contains(r#"t.rel_prop_authored_at AS "r.authored_at""#),
+        "SQL:\n{sql}"
+    );
+}
@@ -0,0 +1,180 @@
+//! `POST /query/natural` against the real router.
+//!
+//! A wiremock server stands in for an OpenAI-compatible LLM and a stub
+//! executor answers the generated query, recording what it ran.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS, GLOBAL_SCHEMA_CONFIGS};
+
+const SCHEMA: &str = r#"
+name: nl_social
+graph_schema:
+  nodes:
+    - label: Person
+      database: test
+      table: people
+      node_id: person_id
+      description: People on the platform
+      property_mappings:
+        person_id: person_id
+        name: full_name
+  edges:
+    - type: KNOWS
+      database: test
+      table: knows
+      from_id: from_person
+      to_id: to_person
+      from_node: Person
+      to_node: Person
+"#;
+
+#[derive(Default)]
+struct RowsExecutor {
+    executed: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for RowsExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed.lock().unwrap().push(sql.to_string());
+        Ok(vec![json!({"p.name": "Alice"}), json!({"p.name": "Bob"})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_schema_registered() {
+    let config = GraphSchemaConfig::from_yaml_str(SCHEMA).expect("parse schema");
+    let schema = config.to_graph_schema().expect("convert schema");
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    let _ = GLOBAL_SCHEMA_CONFIGS.set(tokio::sync::RwLock::new(HashMap::new()));
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await
+        .insert("nl_social".to_string(), schema);
+    GLOBAL_SCHEMA_CONFIGS
+        .get()
+        .expect("GLOBAL_SCHEMA_CONFIGS set above")
+        .write()
+        .await
+        .insert("nl_social".to_string(), config);
+}
+
+/// An OpenAI-compatible LLM that always replies with `reply`.
+async fn llm_replying(reply: &str) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(body_string_contains("(:Person)-[:KNOWS]->(:Person)"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "choices": [{"message": {"content": reply}}]
+        })))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn ask(llm: Option<&MockServer>, question: &str) -> (StatusCode, Value, Vec<String>) {
+    ensure_schema_registered().await;
+    let config = match llm {
+        Some(server) => ServerConfig {
+            nl_query_enabled: true,
+            llm_provider: Some("openai".to_string()),
+            llm_api_url: Some(format!("{}/v1/chat/completions", server.uri())),
+            ..ServerConfig::default()
+        },
+        None => ServerConfig::default(),
+    };
+    let executor = Arc::new(RowsExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: config.clone(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let payload = json!({"question": question, "schema_name": "nl_social"});
+    let resp = build_router(state, &config)
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query/natural")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes).expect("JSON body");
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, body, executed)
+}
+
+#[tokio::test]
+async fn question_runs_as_grounded_cypher() {
+    let llm = llm_replying(
+        "```cypher\nMATCH (p:Person)-[:KNOWS]->(:Person) RETURN p.name LIMIT 100\n```\n\
+         Confidence: high - a plain traversal",
+    )
+    .await;
+    let (status, body, executed) = ask(Some(&llm), "Who knows someone?").await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(
+        body["cypher"],
+        "MATCH (p:Person)-[:KNOWS]->(:Person) RETURN p.name LIMIT 100"
+    );
+    assert_eq!(body["confidence"], "high");
+    assert_eq!(body["note"], "a plain traversal");
+    assert_eq!(body["results"][1]["p.name"], "Bob");
+    assert_eq!(executed.len(), 1);
+    assert!(executed[0].contains("full_name"), "{}", executed[0]);
+}
+
+#[tokio::test]
+async fn unknown_property_is_rejected_before_running() {
+    let llm = llm_replying("MATCH (p:Person) RETURN p.salary\nConfidence: low").await;
+    let (status, body, executed) = ask(Some(&llm), "What do people earn?").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+    assert_eq!(body["problems"], json!(["unknown property `p.salary`"]));
+    assert_eq!(body["cypher"], "MATCH (p:Person) RETURN p.salary");
+    assert!(executed.is_empty(), "{executed:?}");
+}
+
+#[tokio::test]
+async fn disabled_endpoint_is_not_found() {
+    let (status, body, _) = ask(None, "Who knows someone?").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .contains("CLICKGRAPH_NL_QUERY"));
+}
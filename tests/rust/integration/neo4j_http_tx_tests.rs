@@ -0,0 +1,141 @@
+//! `POST /db/{name}/tx/commit` — Neo4j HTTP transactional API compatibility.
+//!
+//! Drives the real router with a stub executor that returns fixed JSON rows,
+//! so the Neo4j `results` / `errors` envelope can be checked end to end
+//! without ClickHouse. Mirrors `sql_generation_handler_comment_tests.rs`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::server::{build_router, AppState};
+
+use crate::fixtures::ensure_default_schema_registered;
+
+/// Returns the same two rows for every query.
+struct FixedRowsExecutor;
+
+#[async_trait]
+impl QueryExecutor for FixedRowsExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        Ok(vec![
+            json!({"name": "Alice", "id": 1}),
+            json!({"name": "Bob", "id": 2}),
+        ])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+fn test_state() -> AppState {
+    AppState {
+        executor: Arc::new(FixedRowsExecutor),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    }
+}
+
+async fn post_tx_commit(database: &str, payload: Value) -> (StatusCode, Value) {
+    ensure_default_schema_registered().await;
+    let app = build_router(test_state(), &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/db/{}/tx/commit", database))
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    (
+        status,
+        serde_json::from_slice(&bytes).expect("valid JSON body"),
+    )
+}
+
+#[tokio::test]
+async fn tx_commit_returns_neo4j_result_envelope() {
+    let (status, body) = post_tx_commit(
+        "neo4j",
+        json!({
+            "statements": [{
+                "statement": "MATCH (u:User) WHERE u.user_id = $id RETURN u.name AS name, u.user_id AS id",
+                "parameters": {"id": 1}
+            }]
+        }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body["errors"], json!([]));
+    assert_eq!(body["results"][0]["columns"], json!(["name", "id"]));
+    assert_eq!(
+        body["results"][0]["data"][0],
+        json!({"row": ["Alice", 1], "meta": [null, null]})
+    );
+}
+
+#[tokio::test]
+async fn tx_commit_stops_at_first_failing_statement() {
+    let (status, body) = post_tx_commit(
+        "default",
+        json!({
+            "statements": [
+                {"statement": "MATCH (u:User) RETURN u.name AS name"},
+                {"statement": "MATCH (u:User RETURN u"},
+                {"statement": "MATCH (u:User) RETURN u.name AS name"}
+            ]
+        }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK, "errors are reported in the body");
+    assert_eq!(body["results"].as_array().unwrap().len(), 1, "body: {body}");
+    assert_eq!(
+        body["errors"][0]["code"],
+        json!("Neo.ClientError.Statement.SyntaxError"),
+        "body: {body}"
+    );
+}
+
+#[tokio::test]
+async fn tx_commit_unknown_database_is_reported() {
+    let (_, body) = post_tx_commit(
+        "no_such_schema",
+        json!({"statements": [{"statement": "MATCH (u:User) RETURN u.name"}]}),
+    )
+    .await;
+
+    assert_eq!(body["results"], json!([]));
+    assert!(
+        body["errors"][0]["code"]
+            .as_str()
+            .unwrap()
+            .starts_with("Neo.ClientError."),
+        "body: {body}"
+    );
+}
@@ -0,0 +1,136 @@
+//! OPTIONAL MATCH correlated to a WITH stage: the stage CTE is the FROM of
+//! the final SELECT, the optional pattern hangs off it as LEFT JOINs, and
+//! WHERE conjuncts on the stage's columns gate those joins.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_cypher_statement,
+    query_planner::evaluate_read_statement,
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+    server::query_context::{set_current_schema, with_query_context, QueryContext},
+};
+
+const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        country: country
+    - label: Post
+      database: db
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings: {}
+    - type: AUTHORED
+      database: db
+      table: authored
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings: {}
+"#;
+
+async fn cypher_to_sql(cypher: &str) -> String {
+    let schema: GraphSchema = GraphSchemaConfig::from_yaml_str(SCHEMA)
+        .unwrap()
+        .to_graph_schema()
+        .unwrap();
+    let cypher = cypher.to_string();
+    with_query_context(QueryContext::default(), async move {
+        set_current_schema(std::sync::Arc::new(schema.clone()));
+        clickgraph::query_planner::logical_plan::reset_all_counters();
+        let (_, statement) = parse_cypher_statement(&cypher).expect("parse");
+        let (logical_plan, plan_ctx) =
+            evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+        logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+            .expect("render")
+            .to_sql()
+    })
+    .await
+}
+
+#[tokio::test]
+async fn optional_match_hangs_off_the_aggregated_stage() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User)-[:AUTHORED]->(p:Post) WITH u, count(p) AS c \
+         OPTIONAL MATCH (u)-[:FOLLOWS]->(m:User) \
+         RETURN u.name, c, count(m) AS following",
+    )
+    .await;
+
+    assert!(sql.contains("FROM with_c_u_cte_0 AS u"), "{sql}");
+    assert!(
+        sql.contains("LEFT JOIN db.follows AS t2 ON t2.follower_id = u.p1_u_user_id"),
+        "{sql}"
+    );
+    assert!(
+        sql.contains("count(t2.followed_id) AS \"following\""),
+        "{sql}"
+    );
+}
+
+#[tokio::test]
+async fn stage_column_predicate_gates_the_left_join() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User)-[:AUTHORED]->(p:Post) WITH u, count(p) AS c \
+         OPTIONAL MATCH (u)-[:FOLLOWS]->(m:User) WHERE c > 1 \
+         RETURN u.name, c, m.name",
+    )
+    .await;
+
+    assert!(
+        sql.contains("LEFT JOIN db.follows AS t2 ON t2.follower_id = u.p1_u_user_id AND u.c > 1"),
+        "{sql}"
+    );
+    assert!(!sql.contains("WHERE"), "gate left in the WHERE:\n{sql}");
+}
+
+#[tokio::test]
+async fn node_predicate_and_stage_gate_share_the_combined_join() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User)-[:AUTHORED]->(p:Post) WITH u, count(p) AS c \
+         OPTIONAL MATCH (u)-[:FOLLOWS]->(m:User) WHERE m.country = 'US' AND c > 1 \
+         RETURN u.name, c, m.name",
+    )
+    .await;
+
+    assert!(sql.contains("WHERE m.country = 'US') AS m"), "{sql}");
+    assert!(
+        sql.contains("AS m ON m.__cg_combined_anchor_key = u.p1_u_user_id AND u.c > 1"),
+        "{sql}"
+    );
+}
+
+#[tokio::test]
+async fn function_call_gate_on_the_stage_stays_out_of_the_where() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User)-[:AUTHORED]->(p:Post) WITH u, count(p) AS c \
+         OPTIONAL MATCH (u)-[:FOLLOWS]->(m:User) WHERE isEmpty(u.name) \
+         RETURN u.name, c, m.name",
+    )
+    .await;
+
+    let main = &sql[sql.find("FROM with_c_u_cte_0 AS u").expect("stage FROM")..];
+    let follows = &main[main.find("LEFT JOIN db.follows AS t2").expect("edge join")..];
+    let on = &follows[..follows.find("\nLEFT JOIN").unwrap_or(follows.len())];
+    assert!(on.contains("empty(u.p1_u_name) = true"), "{sql}");
+    assert!(!main.contains("WHERE"), "gate left in the WHERE:\n{sql}");
+}
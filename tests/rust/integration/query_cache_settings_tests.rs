@@ -0,0 +1,185 @@
+//! ClickHouse query cache settings on `/query` — the request's
+//! `use_query_cache` / `query_cache_ttl` over the schema's `query_cache`
+//! defaults, and the `X-ClickHouse-Query-Cache` response header.
+//!
+//! Drives the real router with a stub executor that records the settings each
+//! statement was sent with and reports a cache hit whenever the cache is on.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::graph_catalog::graph_schema::QueryCacheConfig;
+use clickgraph::server::{build_router, metrics, AppState, GLOBAL_SCHEMAS};
+
+#[derive(Default)]
+struct SettingsExecutor {
+    settings: Mutex<Vec<Vec<(String, String)>>>,
+}
+
+#[async_trait]
+impl QueryExecutor for SettingsExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.execute_json_with_settings(sql, &[], &[], role).await
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+    async fn execute_json_with_settings(
+        &self,
+        _sql: &str,
+        _params: &[(String, String)],
+        settings: &[(String, String)],
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.settings.lock().unwrap().push(settings.to_vec());
+        if !settings.is_empty() {
+            metrics::record_ch_query_cache_hit(true);
+        }
+        Ok(vec![json!({"name": "Alice"})])
+    }
+}
+
+/// `default` is the social benchmark; `cached` is the same graph with the
+/// query cache on by default.
+async fn ensure_graphs_registered() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    for name in ["default", "cached"] {
+        if map.contains_key(name) {
+            continue;
+        }
+        let mut schema = GraphSchemaConfig::from_yaml_file(
+            "benchmarks/social_network/schemas/social_benchmark.yaml",
+        )
+        .expect("load benchmark schema")
+        .to_graph_schema()
+        .expect("convert benchmark schema");
+        if name == "cached" {
+            schema.set_query_cache(QueryCacheConfig {
+                enabled: true,
+                ttl_seconds: Some(300),
+            });
+        }
+        map.insert(name.to_string(), schema);
+    }
+}
+
+/// Run `payload`; returns the status, the `X-ClickHouse-Query-Cache` header
+/// and the settings the query was executed with.
+async fn run(payload: Value) -> (StatusCode, Option<String>, Vec<(String, String)>) {
+    ensure_graphs_registered().await;
+    let executor = Arc::new(SettingsExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let header = resp
+        .headers()
+        .get("x-clickhouse-query-cache")
+        .map(|v| v.to_str().unwrap().to_string());
+    let mut executed = executor.settings.lock().unwrap().clone();
+    assert_eq!(executed.len(), 1, "one statement per query: {executed:?}");
+    (status, header, executed.pop().unwrap())
+}
+
+fn pairs(settings: &[(&str, &str)]) -> Vec<(String, String)> {
+    settings
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn request_flag_enables_query_cache() {
+    let (status, header, settings) = run(json!({
+        "query": "MATCH (u:User) WHERE u.user_id = 1 RETURN u.name AS name"
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(settings.is_empty(), "{settings:?}");
+    assert_eq!(header, None);
+
+    let (status, header, settings) = run(json!({
+        "query": "MATCH (u:User) WHERE u.user_id = 2 RETURN u.name AS name",
+        "use_query_cache": true,
+        "query_cache_ttl": 30
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        settings,
+        pairs(&[("use_query_cache", "1"), ("query_cache_ttl", "30")])
+    );
+    assert_eq!(header.as_deref(), Some("HIT"));
+}
+
+#[tokio::test]
+async fn schema_default_applies_unless_overridden() {
+    let (_, header, settings) = run(json!({
+        "query": "MATCH (u:User) WHERE u.user_id = 3 RETURN u.name AS name",
+        "schema_name": "cached"
+    }))
+    .await;
+    assert_eq!(
+        settings,
+        pairs(&[("use_query_cache", "1"), ("query_cache_ttl", "300")])
+    );
+    assert_eq!(header.as_deref(), Some("HIT"));
+
+    let (_, _, settings) = run(json!({
+        "query": "MATCH (u:User) WHERE u.user_id = 4 RETURN u.name AS name",
+        "schema_name": "cached",
+        "query_cache_ttl": 5
+    }))
+    .await;
+    assert_eq!(
+        settings,
+        pairs(&[("use_query_cache", "1"), ("query_cache_ttl", "5")])
+    );
+
+    let (_, header, settings) = run(json!({
+        "query": "MATCH (u:User) WHERE u.user_id = 5 RETURN u.name AS name",
+        "schema_name": "cached",
+        "use_query_cache": false
+    }))
+    .await;
+    assert!(settings.is_empty(), "{settings:?}");
+    assert_eq!(header, None);
+}
@@ -0,0 +1,208 @@
+//! `/query` parameters sent to ClickHouse as native query parameters.
+//!
+//! Drives the real router with a stub executor, like `stored_query_tests.rs`,
+//! and checks the SQL text and `(name, value)` pairs the executor receives.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+use crate::fixtures;
+
+type Executed = Vec<(String, Vec<(String, String)>)>;
+
+/// Records each statement with its bound parameters.
+struct RecordingExecutor {
+    native: bool,
+    executed: Mutex<Executed>,
+}
+
+#[async_trait]
+impl QueryExecutor for RecordingExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.execute_json_with_params(sql, &[], role).await
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+    fn supports_query_params(&self) -> bool {
+        self.native
+    }
+    async fn execute_json_with_params(
+        &self,
+        sql: &str,
+        params: &[(String, String)],
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed
+            .lock()
+            .unwrap()
+            .push((sql.to_string(), params.to_vec()));
+        Ok(vec![json!({"name": "Alice"})])
+    }
+}
+
+async fn ensure_default_schema_registered() {
+    fixtures::ensure_default_schema_registered().await;
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set by the fixture")
+        .write()
+        .await;
+    map.entry("typed_params".to_string()).or_insert_with(|| {
+        GraphSchemaConfig::from_yaml_str(TYPED_SCHEMA)
+            .expect("typed schema yaml")
+            .to_graph_schema()
+            .expect("convert typed schema")
+    });
+}
+
+const TYPED_SCHEMA: &str = r#"
+graph_schema:
+  nodes:
+    - label: Person
+      database: test
+      table: people
+      node_id: id
+      property_mappings:
+        id: id
+        name: name
+        age: age
+      property_types:
+        age: integer
+  edges: []
+"#;
+
+async fn run(native: bool, payload: Value) -> (StatusCode, Executed) {
+    ensure_default_schema_registered().await;
+    let executor = Arc::new(RecordingExecutor {
+        native,
+        executed: Mutex::default(),
+    });
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let executed = executor.executed.lock().unwrap().clone();
+    (status, executed)
+}
+
+const QUERY: &str =
+    "MATCH (u:User) WHERE u.country = $country AND u.user_id IN $ids RETURN u.name AS name";
+
+#[tokio::test]
+async fn query_parameters_are_bound_natively() {
+    let (status, executed) = run(
+        true,
+        json!({"query": QUERY, "parameters": {"country": "N'Z\\", "ids": [1, 2]}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let [(sql, params)] = executed.as_slice() else {
+        panic!("expected one statement, got {executed:?}");
+    };
+    assert!(
+        sql.contains("{country:String}") && sql.contains("{ids:Array(Int64)}"),
+        "placeholders typed; SQL:\n{sql}"
+    );
+    assert!(!sql.contains("N'Z"), "value kept out of the SQL:\n{sql}");
+    assert!(params.contains(&("country".to_string(), "N'Z\\\\".to_string())));
+    assert!(params.contains(&("ids".to_string(), "[1,2]".to_string())));
+
+    // Same SQL text for different values, only the parameters change.
+    let (_, again) = run(
+        true,
+        json!({"query": QUERY, "parameters": {"country": "AU", "ids": [3]}}),
+    )
+    .await;
+    assert_eq!(again[0].0, *sql);
+    assert!(again[0]
+        .1
+        .contains(&("country".to_string(), "AU".to_string())));
+}
+
+#[tokio::test]
+async fn query_parameters_are_inlined_without_executor_support() {
+    let (status, executed) = run(
+        false,
+        json!({"query": QUERY, "parameters": {"country": "NZ", "ids": [1, 2]}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (sql, params) = &executed[0];
+    assert!(params.is_empty());
+    assert!(
+        sql.contains("'NZ'") && !sql.contains("{country:"),
+        "SQL:\n{sql}"
+    );
+}
+
+#[tokio::test]
+async fn missing_query_parameter_is_rejected() {
+    let (status, executed) = run(
+        true,
+        json!({"query": QUERY, "parameters": {"country": "NZ"}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(executed.is_empty());
+}
+
+#[tokio::test]
+async fn query_parameters_are_checked_against_declared_property_types() {
+    let query = "MATCH (p:Person) WHERE p.age > $minAge RETURN p.name AS name";
+    let (status, executed) = run(
+        true,
+        json!({"query": query, "schema_name": "typed_params", "parameters": {"minAge": "30"}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (sql, params) = &executed[0];
+    assert!(
+        sql.contains("{minAge:Int64}"),
+        "string converted; SQL:\n{sql}"
+    );
+    assert!(params.contains(&("minAge".to_string(), "30".to_string())));
+
+    let (status, executed) = run(
+        true,
+        json!({"query": query, "schema_name": "typed_params", "parameters": {"minAge": "thirty"}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(executed.is_empty(), "nothing reaches ClickHouse");
+}
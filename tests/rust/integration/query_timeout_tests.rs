@@ -0,0 +1,128 @@
+//! `timeout_ms` on `/query`: a statement past its timeout answers 408 and
+//! the ClickHouse queries it started are killed by `query_id`.
+//!
+//! The stub executor stands in for the remote one: it tags each query with
+//! `cancellation::query_options()` the same way, then hangs until killed.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::server::{build_router, cancellation, AppState};
+
+use crate::fixtures::ensure_default_schema_registered;
+
+#[derive(Default)]
+struct HangingExecutor {
+    started: Mutex<Vec<String>>,
+    cancelled: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for HangingExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        let options = cancellation::query_options();
+        let (_, query_id) = options
+            .first()
+            .cloned()
+            .expect("query_id inside a statement");
+        self.started.lock().unwrap().push(query_id);
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        Ok(vec![])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+    async fn cancel_queries(&self, query_ids: &[String]) {
+        self.cancelled.lock().unwrap().extend_from_slice(query_ids);
+    }
+}
+
+async fn post_query(executor: Arc<HangingExecutor>, payload: Value) -> (StatusCode, String) {
+    ensure_default_schema_registered().await;
+    let state = AppState {
+        executor,
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let resp = build_router(state, &ServerConfig::default())
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    (status, String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Wait for the kill, which runs on a spawned task.
+async fn cancelled(executor: &HangingExecutor) -> Vec<String> {
+    for _ in 0..50 {
+        let cancelled = executor.cancelled.lock().unwrap().clone();
+        if !cancelled.is_empty() {
+            return cancelled;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    Vec::new()
+}
+
+#[tokio::test]
+async fn timed_out_query_is_killed_by_query_id() {
+    let executor = Arc::new(HangingExecutor::default());
+    let (status, body) = post_query(
+        executor.clone(),
+        json!({"query": "MATCH (u:User) RETURN u.name LIMIT 5", "timeout_ms": 50}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::REQUEST_TIMEOUT, "{body}");
+    assert!(body.contains("timeout of 50 ms"), "{body}");
+
+    let started = executor.started.lock().unwrap().clone();
+    assert_eq!(started.len(), 1);
+    assert!(started[0].starts_with("clickgraph-"), "{started:?}");
+    assert_eq!(cancelled(&executor).await, started);
+}
+
+#[tokio::test]
+async fn dropped_request_kills_its_queries() {
+    ensure_default_schema_registered().await;
+    let executor = Arc::new(HangingExecutor::default());
+    let request = post_query(
+        executor.clone(),
+        json!({"query": "MATCH (u:User) RETURN u.name LIMIT 5"}),
+    );
+    // A client that goes away drops the request's future
+    assert!(tokio::time::timeout(Duration::from_millis(100), request)
+        .await
+        .is_err());
+    let started = executor.started.lock().unwrap().clone();
+    assert_eq!(started.len(), 1);
+    assert_eq!(cancelled(&executor).await, started);
+}
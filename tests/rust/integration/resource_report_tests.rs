@@ -0,0 +1,191 @@
+//! Per-tenant resource attribution against the real router.
+//!
+//! A stub executor records the `log_comment` each statement's queries would
+//! carry to ClickHouse, and answers `/stats/resources` with canned
+//! `system.query_log` rows.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{
+    attribution, build_router, AppState, GLOBAL_SCHEMAS, GLOBAL_SCHEMA_CONFIGS,
+};
+
+const SCHEMA: &str = r#"
+name: billing_graph
+graph_schema:
+  nodes:
+    - label: Account
+      database: test
+      table: accounts
+      node_id: account_id
+      property_mappings:
+        account_id: account_id
+        name: name
+  edges: []
+"#;
+
+/// SQL run, with the ClickHouse settings attribution added to it.
+type Executed = (String, Vec<(String, String)>);
+
+#[derive(Default)]
+struct RecordingExecutor {
+    executed: Mutex<Vec<Executed>>,
+}
+
+#[async_trait]
+impl QueryExecutor for RecordingExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.executed
+            .lock()
+            .unwrap()
+            .push((sql.to_string(), attribution::query_settings()));
+        if sql.contains("system.query_log") {
+            return Ok(vec![json!({
+                "bucket": "2026-10-17 10:00:00",
+                "tenant": "acme",
+                "queries": 12,
+                "read_bytes": 4096,
+                "cpu_seconds": 0.5,
+            })]);
+        }
+        Ok(vec![json!({"a.name": "Acme"})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_schema_registered() {
+    let config = GraphSchemaConfig::from_yaml_str(SCHEMA).expect("parse schema");
+    let schema = config.to_graph_schema().expect("convert schema");
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    let _ = GLOBAL_SCHEMA_CONFIGS.set(tokio::sync::RwLock::new(HashMap::new()));
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await
+        .insert("billing_graph".to_string(), schema);
+    GLOBAL_SCHEMA_CONFIGS
+        .get()
+        .expect("GLOBAL_SCHEMA_CONFIGS set above")
+        .write()
+        .await
+        .insert("billing_graph".to_string(), config);
+}
+
+async fn send(
+    config: ServerConfig,
+    request: Request<Body>,
+) -> (StatusCode, String, Arc<RecordingExecutor>) {
+    let executor = Arc::new(RecordingExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: config.clone(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let resp = build_router(state, &config).oneshot(request).await.unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    (
+        status,
+        String::from_utf8(bytes.to_vec()).expect("UTF-8 body"),
+        executor,
+    )
+}
+
+fn report(query: &str) -> Request<Body> {
+    Request::builder()
+        .uri(format!("/stats/resources{query}"))
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn statement_queries_carry_their_tenant() {
+    ensure_schema_registered().await;
+    let payload = json!({
+        "query": "MATCH (a:Account) RETURN a.name",
+        "schema_name": "billing_graph",
+        "tenant_id": "acme",
+    });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/query")
+        .header("content-type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap();
+    let (status, body, executor) = send(ServerConfig::default(), request).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+
+    let executed = executor.executed.lock().unwrap();
+    assert_eq!(executed.len(), 1);
+    let (name, comment) = &executed[0].1[0];
+    assert_eq!(name, "log_comment");
+    let comment: Value = serde_json::from_str(comment).unwrap();
+    assert_eq!(
+        comment,
+        json!({"app": "clickgraph", "schema": "billing_graph", "tenant": "acme", "role": ""})
+    );
+}
+
+#[tokio::test]
+async fn report_sums_query_log_per_group() {
+    let (status, body, executor) = send(
+        ServerConfig::default(),
+        report("?since=2026-10-17&until=2026-10-18&bucket=hour&by=tenant"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let body: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["since"], "2026-10-17T00:00:00+00:00");
+    assert_eq!(body["bucket"], "hour");
+    assert_eq!(body["group_by"], json!(["tenant"]));
+    assert_eq!(body["rows"][0]["tenant"], "acme");
+    assert_eq!(body["rows"][0]["read_bytes"], 4096);
+
+    let executed = executor.executed.lock().unwrap();
+    let (sql, settings) = &executed[0];
+    assert!(sql.contains("toStartOfHour(event_time) AS bucket"), "{sql}");
+    assert!(sql.contains("GROUP BY bucket, tenant"), "{sql}");
+    // The report itself is not billed to anyone
+    assert!(settings.is_empty(), "{settings:?}");
+}
+
+#[tokio::test]
+async fn bad_report_parameters_are_rejected() {
+    let (status, body, executor) = send(ServerConfig::default(), report("?bucket=week")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.contains("unknown bucket `week`"), "{body}");
+    assert!(executor.executed.lock().unwrap().is_empty());
+
+    let embedded = ServerConfig {
+        embedded: true,
+        ..ServerConfig::default()
+    };
+    let (status, _, _) = send(embedded, report("")).await;
+    assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+}
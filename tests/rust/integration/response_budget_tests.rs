@@ -0,0 +1,176 @@
+//! `max_response_bytes` on `/query`: results are cut at a row boundary to fit
+//! the budget, and `continuation_token` fetches the remaining pages.
+//!
+//! Drives the real router with a stub executor that returns fixed rows.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+/// Returns `count` rows `{"name": "user-<i>"}` for every query.
+struct RowsExecutor {
+    count: usize,
+}
+
+#[async_trait]
+impl QueryExecutor for RowsExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        Ok((0..self.count)
+            .map(|i| json!({ "name": format!("user-{i}") }))
+            .collect())
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_default_registered() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    if !map.contains_key("default") {
+        let schema = GraphSchemaConfig::from_yaml_file(
+            "benchmarks/social_network/schemas/social_benchmark.yaml",
+        )
+        .expect("load benchmark schema")
+        .to_graph_schema()
+        .expect("convert benchmark schema");
+        map.insert("default".to_string(), schema);
+    }
+}
+
+/// Run `payload`; returns the status and the raw body.
+async fn run(count: usize, payload: Value) -> (StatusCode, Vec<u8>) {
+    ensure_default_registered().await;
+    let state = AppState {
+        executor: Arc::new(RowsExecutor { count }),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, body.to_vec())
+}
+
+const QUERY: &str = "MATCH (u:User) RETURN u.name AS name ORDER BY name";
+
+#[tokio::test]
+async fn pages_fit_budget_and_resume_with_token() {
+    let budget = 300;
+    let mut payload = json!({ "query": QUERY, "max_response_bytes": budget });
+    let mut names = Vec::new();
+    let mut pages = 0;
+    loop {
+        let (status, body) = run(50, payload.clone()).await;
+        assert_eq!(status, StatusCode::OK, "{}", String::from_utf8_lossy(&body));
+        assert!(body.len() <= budget, "page of {} bytes", body.len());
+        pages += 1;
+
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        for row in body["results"].as_array().unwrap() {
+            names.push(row["name"].as_str().unwrap().to_string());
+        }
+        match body.get("continuation_token") {
+            Some(token) => payload["continuation_token"] = token.clone(),
+            None => break,
+        }
+    }
+    assert!(pages > 1);
+    let expected: Vec<String> = (0..50).map(|i| format!("user-{i}")).collect();
+    assert_eq!(names, expected);
+}
+
+#[tokio::test]
+async fn small_results_have_no_token() {
+    let (status, body) = run(2, json!({ "query": QUERY, "max_response_bytes": 1024 })).await;
+    assert_eq!(status, StatusCode::OK);
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["results"].as_array().unwrap().len(), 2);
+    assert!(body.get("continuation_token").is_none());
+}
+
+#[tokio::test]
+async fn budget_below_one_row_is_rejected() {
+    let (status, body) = run(5, json!({ "query": QUERY, "max_response_bytes": 20 })).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert!(
+        String::from_utf8_lossy(&body).contains("max_response_bytes"),
+        "{}",
+        String::from_utf8_lossy(&body)
+    );
+}
+
+#[tokio::test]
+async fn token_from_another_query_is_rejected() {
+    let (_, body) = run(50, json!({ "query": QUERY, "max_response_bytes": 300 })).await;
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    let token = body["continuation_token"].clone();
+    assert!(token.is_string());
+
+    let (status, body) = run(
+        50,
+        json!({
+            "query": "MATCH (u:User) RETURN u.user_id AS id",
+            "max_response_bytes": 300,
+            "continuation_token": token,
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(String::from_utf8_lossy(&body).contains("different query"));
+}
+
+#[tokio::test]
+async fn budget_requires_buffered_json_each_row() {
+    for extra in [json!({ "stream": true }), json!({ "format": "Pretty" })] {
+        let mut payload = json!({ "query": QUERY, "max_response_bytes": 300 });
+        payload
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        let (status, body) = run(5, payload).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(
+            String::from_utf8_lossy(&body).contains("only supported with non-streamed"),
+            "{}",
+            String::from_utf8_lossy(&body)
+        );
+    }
+}
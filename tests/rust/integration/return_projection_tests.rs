@@ -0,0 +1,111 @@
+//! Whole-node RETURN on wide labels: `return_properties` / `max_return_columns`
+//! pick the projected columns, `RETURN n { .* }` projects them all.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_query,
+    query_planner::evaluate_read_query,
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+};
+
+const SCHEMA: &str = r#"
+name: return_projection_test
+graph_schema:
+  nodes:
+    - label: Event
+      database: test
+      table: events
+      node_id: id
+      return_properties: [title]
+      max_return_columns: 3
+      property_mappings:
+        id: event_id
+        title: title
+        alpha: col_alpha
+        beta: col_beta
+        zeta: col_zeta
+    - label: Host
+      database: test
+      table: hosts
+      node_id: id
+      return_properties: [name]
+      property_mappings:
+        id: host_id
+        name: hostname
+        os: os_name
+        ip: ip_addr
+  edges:
+    - type: SEEN_ON
+      database: test
+      table: event_hosts
+      from_id: event_id
+      to_id: host_id
+      from_node: Event
+      to_node: Host
+      property_mappings: {}
+"#;
+
+fn schema() -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(SCHEMA)
+        .expect("parse schema")
+        .to_graph_schema()
+        .expect("convert schema")
+}
+
+fn cypher_to_sql(cypher: &str) -> String {
+    let schema = schema();
+    let ast = parse_query(cypher).expect("Failed to parse Cypher");
+    let (logical_plan, plan_ctx) =
+        evaluate_read_query(ast, &schema, None, None).expect("Failed to build logical plan");
+    let render_plan = logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+        .expect("Failed to render plan");
+    render_plan.to_sql()
+}
+
+#[test]
+fn whole_node_return_fills_the_column_budget_in_priority_order() {
+    let sql = cypher_to_sql("MATCH (e:Event) RETURN e");
+    assert!(sql.contains(r#"e.event_id AS "e.id""#), "SQL:\n{sql}");
+    assert!(sql.contains(r#"e.title AS "e.title""#), "SQL:\n{sql}");
+    assert!(sql.contains(r#"e.col_alpha AS "e.alpha""#), "SQL:\n{sql}");
+    assert!(!sql.contains("col_beta"), "SQL:\n{sql}");
+    assert!(!sql.contains("col_zeta"), "SQL:\n{sql}");
+
+    // Named properties are not subject to the budget.
+    let sql = cypher_to_sql("MATCH (e:Event) RETURN e.zeta");
+    assert!(sql.contains("col_zeta"), "SQL:\n{sql}");
+}
+
+#[test]
+fn property_list_without_budget_projects_only_the_list() {
+    let sql = cypher_to_sql("MATCH (e:Event)-[:SEEN_ON]->(h:Host) RETURN h");
+    assert!(sql.contains(r#"h.host_id AS "h.id""#), "SQL:\n{sql}");
+    assert!(sql.contains(r#"h.hostname AS "h.name""#), "SQL:\n{sql}");
+    assert!(!sql.contains("os_name"), "SQL:\n{sql}");
+    assert!(!sql.contains("ip_addr"), "SQL:\n{sql}");
+}
+
+#[test]
+fn all_properties_projection_overrides_the_subset() {
+    let sql = cypher_to_sql("MATCH (e:Event)-[:SEEN_ON]->(h:Host) RETURN e { .* }, h");
+    for column in ["event_id", "title", "col_alpha", "col_beta", "col_zeta"] {
+        assert!(sql.contains(column), "{column} missing; SQL:\n{sql}");
+    }
+    assert!(!sql.contains("os_name"), "SQL:\n{sql}");
+}
+
+#[test]
+fn subset_applies_after_with() {
+    let sql = cypher_to_sql("MATCH (e:Event) WITH e RETURN e");
+    assert!(sql.contains(r#"AS "e.alpha""#), "SQL:\n{sql}");
+    assert!(!sql.contains(r#"AS "e.beta""#), "SQL:\n{sql}");
+
+    let sql = cypher_to_sql("MATCH (e:Event) WITH e RETURN e {.*}");
+    assert!(sql.contains(r#"AS "e.beta""#), "SQL:\n{sql}");
+}
+
+#[test]
+fn chained_with_keeps_every_column_for_later_clauses() {
+    let sql = cypher_to_sql("MATCH (e:Event) WITH e WITH e RETURN e.zeta");
+    assert!(sql.contains("col_zeta"), "SQL:\n{sql}");
+}
@@ -0,0 +1,133 @@
+//! Scan predicate pushdown: `use_final` tables are read with `FINAL`, and
+//! WHERE conjuncts that only read one joined FINAL or `filter:` table move
+//! into that table's scan so ClickHouse can prune on its primary key.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_cypher_statement,
+    query_planner::evaluate_read_statement,
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+    server::query_context::{set_current_schema, with_query_context, QueryContext},
+    sql_generator::SqlDialect,
+};
+
+const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      use_final: true
+      filter: "is_deleted = 0"
+      property_mappings:
+        user_id: user_id
+        name: name
+        country: country
+    - label: Post
+      database: db
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      use_final: true
+      filter: "active = 1"
+      property_mappings:
+        since: since
+    - type: AUTHORED
+      database: db
+      table: authored
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings: {}
+"#;
+
+async fn cypher_to_sql(cypher: &str, dialect: SqlDialect) -> String {
+    let schema: GraphSchema = GraphSchemaConfig::from_yaml_str(SCHEMA)
+        .unwrap()
+        .to_graph_schema()
+        .unwrap();
+    let cypher = cypher.to_string();
+    let ctx = QueryContext {
+        dialect,
+        ..QueryContext::default()
+    };
+    with_query_context(ctx, async move {
+        set_current_schema(std::sync::Arc::new(schema.clone()));
+        clickgraph::query_planner::logical_plan::reset_all_counters();
+        let (_, statement) = parse_cypher_statement(&cypher).expect("parse");
+        let (logical_plan, plan_ctx) =
+            evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+        logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+            .expect("render")
+            .to_sql()
+    })
+    .await
+}
+
+const FOLLOWS_QUERY: &str = "MATCH (u:User)-[f:FOLLOWS]->(v:User)-[:AUTHORED]->(p:Post) \
+     WHERE u.country = 'US' AND f.since > 3 AND v.name <> u.name AND p.title = 'x' \
+     RETURN u.name, v.name, p.title";
+
+#[tokio::test]
+async fn final_tables_scan_with_their_own_where_conjuncts() {
+    let sql = cypher_to_sql(FOLLOWS_QUERY, SqlDialect::ClickHouse).await;
+    for fragment in [
+        "FROM db.users AS u FINAL",
+        "INNER JOIN (SELECT * FROM db.follows AS f FINAL WHERE (f.since > 3 AND (f.active = 1))) AS f ON f.follower_id = u.user_id",
+        "INNER JOIN (SELECT * FROM db.users AS v FINAL WHERE (v.is_deleted = 0)) AS v ON v.user_id = f.followed_id",
+        // Tables with neither setting are untouched.
+        "INNER JOIN db.authored AS t1 ON t1.user_id = v.user_id",
+        "INNER JOIN db.posts AS p ON p.post_id = t1.post_id",
+    ] {
+        assert!(sql.contains(fragment), "missing {fragment}; SQL:\n{sql}");
+    }
+    // Cross-alias and anchor predicates stay in the outer WHERE.
+    assert!(
+        sql.contains("WHERE (((p.title = 'x' AND v.name <> u.name) AND u.country = 'US') AND (u.is_deleted = 0))"),
+        "{sql}"
+    );
+}
+
+#[tokio::test]
+async fn filtered_tables_take_their_conjuncts_in_the_join_without_final() {
+    let sql = cypher_to_sql(FOLLOWS_QUERY, SqlDialect::Databricks).await;
+    assert!(!sql.contains("FINAL"), "{sql}");
+    for fragment in [
+        "INNER JOIN db.follows AS f ON f.follower_id = u.user_id AND (f.since > 3 AND (f.active = 1))",
+        "INNER JOIN db.users AS v ON v.user_id = f.followed_id AND (v.is_deleted = 0)",
+    ] {
+        assert!(sql.contains(fragment), "missing {fragment}; SQL:\n{sql}");
+    }
+}
+
+#[tokio::test]
+async fn optional_joins_are_read_with_final_but_keep_their_filters_outside() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User) OPTIONAL MATCH (u)-[f:FOLLOWS]->(v:User) WHERE f.since > 3 \
+         RETURN u.name, v.name",
+        SqlDialect::ClickHouse,
+    )
+    .await;
+    assert!(
+        sql.contains("LEFT JOIN db.follows AS f FINAL ON f.follower_id = u.user_id\n"),
+        "{sql}"
+    );
+    assert!(
+        sql.contains("LEFT JOIN db.users AS v FINAL ON v.user_id = f.followed_id\n"),
+        "{sql}"
+    );
+    assert!(!sql.contains("SELECT * FROM"), "{sql}");
+}
@@ -0,0 +1,185 @@
+//! `GET /schemas/{name}/lint` and `POST /schemas/lint`.
+//!
+//! Drives the real router with a stub executor answering the
+//! `system.tables` / `system.columns` metadata queries.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+const SCHEMA: &str = r#"
+name: lint_graph
+graph_schema:
+  nodes:
+    - label: User
+      database: lint
+      table: users
+      node_id: user_id
+      property_mappings:
+        name: full_name
+  edges:
+    - type: FOLLOWS
+      database: lint
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+"#;
+
+/// Answers the metadata queries: `follows` is sorted by neither endpoint
+/// and `users` has a column no property maps.
+struct MetadataExecutor {
+    fail: bool,
+}
+
+#[async_trait]
+impl QueryExecutor for MetadataExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        if self.fail {
+            return Err(ExecutorError::QueryFailed(
+                "network error: connection refused".to_string(),
+            ));
+        }
+        if sql.contains("system.tables") {
+            return Ok(vec![
+                json!({"database": "lint", "name": "users", "sorting_key": "user_id"}),
+                json!({"database": "lint", "name": "follows", "sorting_key": "created_at"}),
+            ]);
+        }
+        let columns = [
+            ("users", "user_id"),
+            ("users", "full_name"),
+            ("users", "email"),
+            ("follows", "follower_id"),
+            ("follows", "followed_id"),
+            ("follows", "created_at"),
+        ];
+        Ok(columns
+            .iter()
+            .map(|(table, name)| json!({"database": "lint", "table": table, "name": name}))
+            .collect())
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_schema_registered() {
+    let config = GraphSchemaConfig::from_yaml_str(SCHEMA).expect("parse schema");
+    let schema = config.to_graph_schema().expect("convert schema");
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await
+        .insert("lint_graph".to_string(), schema);
+}
+
+async fn send(fail: bool, request: Request<Body>) -> (StatusCode, Value) {
+    ensure_schema_registered().await;
+    let state = AppState {
+        executor: Arc::new(MetadataExecutor { fail }),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app.oneshot(request).await.unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    (status, serde_json::from_slice(&bytes).expect("JSON body"))
+}
+
+fn get(uri: &str) -> Request<Body> {
+    Request::builder().uri(uri).body(Body::empty()).unwrap()
+}
+
+fn has(report: &Value, category: &str, element: &str) -> bool {
+    report["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|w| w["category"] == category && w["element"] == element)
+}
+
+#[tokio::test]
+async fn live_lint_reports_sort_keys_and_unused_columns() {
+    let (status, report) = send(false, get("/schemas/lint_graph/lint")).await;
+    assert_eq!(status, StatusCode::OK, "{report}");
+    assert_eq!(report["live"], true);
+    assert!(
+        has(&report, "join_keys", "relationship:FOLLOWS"),
+        "{report}"
+    );
+    assert!(
+        has(&report, "unused_columns", "table:lint.users"),
+        "{report}"
+    );
+    assert!(!has(&report, "join_keys", "node:User"), "{report}");
+    assert_eq!(report["summary"]["error"], 0);
+    assert_eq!(report["summary"]["warning"], 1);
+
+    // CI gate: a warning fails `fail_on=warning` but not `fail_on=error`
+    let (status, _) = send(false, get("/schemas/lint_graph/lint?fail_on=warning")).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = send(false, get("/schemas/lint_graph/lint?fail_on=error")).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn offline_lint_skips_clickhouse() {
+    let (status, report) = send(true, get("/schemas/lint_graph/lint?live=false")).await;
+    assert_eq!(status, StatusCode::OK, "{report}");
+    assert_eq!(report["warnings"], json!([]));
+
+    let (status, report) = send(true, get("/schemas/lint_graph/lint")).await;
+    assert_eq!(status, StatusCode::BAD_GATEWAY, "{report}");
+
+    let (status, _) = send(false, get("/schemas/missing_graph/lint")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn yaml_is_linted_without_loading() {
+    let yaml = SCHEMA
+        .replace("name: lint_graph", "name: draft_graph")
+        .replace("type: FOLLOWS", "type: follows");
+    let request = Request::builder()
+        .method("POST")
+        .uri("/schemas/lint")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({"config_content": yaml, "live": false}).to_string(),
+        ))
+        .unwrap();
+    let (status, report) = send(false, request).await;
+    assert_eq!(status, StatusCode::OK, "{report}");
+    assert_eq!(report["schema_name"], "draft_graph");
+    assert!(has(&report, "naming", "relationship:follows"), "{report}");
+    let schemas = GLOBAL_SCHEMAS.get().unwrap().read().await;
+    assert!(!schemas.contains_key("draft_graph"));
+}
@@ -0,0 +1,222 @@
+//! `schema_overrides` on `/query` — an inline YAML fragment of node and edge
+//! definitions merged into the schema for that request only.
+//!
+//! Drives the real router in `sql_only` mode.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS, GLOBAL_SCHEMA_CONFIGS};
+
+use crate::fixtures::NoopExecutor;
+
+const SCHEMA: &str = r#"
+name: overrides_graph
+graph_schema:
+  nodes:
+    - label: User
+      database: app
+      table: users
+      node_id: user_id
+      property_mappings:
+        name: full_name
+"#;
+
+const OVERRIDES: &str = r#"
+nodes:
+  - label: Order
+    database: app
+    table: orders
+    node_id: order_id
+    property_mappings:
+      total: amount
+edges:
+  - type: PLACED
+    database: app
+    table: orders
+    from_id: customer_id
+    to_id: order_id
+    from_node: User
+    to_node: Order
+"#;
+
+/// `overrides_graph`, and the tenant graph `overrides_tenant` that reads it
+/// from `tenant_db`
+async fn ensure_schema_registered() {
+    let config = GraphSchemaConfig::from_yaml_str(SCHEMA).expect("parse schema");
+    let tenant = config.for_tenant("overrides_tenant", Some("tenant_db"));
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    let _ = GLOBAL_SCHEMA_CONFIGS.set(tokio::sync::RwLock::new(HashMap::new()));
+    for (name, config) in [("overrides_graph", config), ("overrides_tenant", tenant)] {
+        let schema = config.to_graph_schema().expect("convert schema");
+        GLOBAL_SCHEMAS
+            .get()
+            .expect("GLOBAL_SCHEMAS set above")
+            .write()
+            .await
+            .insert(name.to_string(), schema);
+        GLOBAL_SCHEMA_CONFIGS
+            .get()
+            .expect("GLOBAL_SCHEMA_CONFIGS set above")
+            .write()
+            .await
+            .insert(name.to_string(), config);
+    }
+}
+
+async fn sql_for(query: &str, overrides: Option<&str>) -> (StatusCode, String, String) {
+    post_query(query, "overrides_graph", overrides, None, true).await
+}
+
+/// POST `query` against `graph` to a server that accepts overrides when
+/// `allow_overrides`, pinned to `pinned` by the `X-Graph-Name` header.
+async fn post_query(
+    query: &str,
+    graph: &str,
+    overrides: Option<&str>,
+    pinned: Option<&str>,
+    allow_overrides: bool,
+) -> (StatusCode, String, String) {
+    ensure_schema_registered().await;
+    let config = ServerConfig {
+        allow_schema_overrides: allow_overrides,
+        ..ServerConfig::default()
+    };
+    let state = AppState {
+        executor: Arc::new(NoopExecutor),
+        clickhouse_client: None,
+        config: config.clone(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &config);
+    let payload = json!({
+        "query": query,
+        "schema_name": graph,
+        "sql_only": true,
+        "schema_overrides": overrides,
+    });
+    let mut request = Request::builder()
+        .method("POST")
+        .uri("/query")
+        .header("content-type", "application/json");
+    if let Some(pinned) = pinned {
+        request = request.header("X-Graph-Name", pinned);
+    }
+    let resp = app
+        .oneshot(request.body(Body::from(payload.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = resp.status();
+    let cache_status = resp
+        .headers()
+        .get("X-Query-Cache-Status")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body: Value = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let text = body["generated_sql"]
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| body.to_string());
+    (status, text, cache_status)
+}
+
+#[tokio::test]
+async fn overrides_apply_to_their_request_only() {
+    let query = "MATCH (u:User)-[:PLACED]->(o:Order) RETURN u.name, o.total";
+
+    let (status, sql, cache_status) = sql_for(query, Some(OVERRIDES)).await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+    assert!(sql.contains("app.orders"), "SQL:\n{sql}");
+    assert!(sql.contains(".amount"), "SQL:\n{sql}");
+    assert_eq!(cache_status, "BYPASS");
+
+    // Neither the shared schema nor the query cache kept the fragment
+    let (status, body, _) = sql_for(query, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    let configs = GLOBAL_SCHEMA_CONFIGS.get().unwrap().read().await;
+    assert_eq!(configs["overrides_graph"].graph_schema.nodes.len(), 1);
+}
+
+#[tokio::test]
+async fn invalid_overrides_are_rejected() {
+    let (status, body, _) = sql_for("MATCH (u:User) RETURN u.name", Some("vertices: []")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert!(body.contains("Invalid schema_overrides"), "{body}");
+
+    // An edge to a label that neither the schema nor the fragment defines
+    let dangling = r#"
+edges:
+  - type: PLACED
+    database: app
+    table: orders
+    from_id: customer_id
+    to_id: order_id
+    from_node: User
+    to_node: Order
+"#;
+    let (status, body, _) = sql_for("MATCH (u:User) RETURN u.name", Some(dangling)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert!(body.contains("Invalid schema_overrides"), "{body}");
+}
+
+#[tokio::test]
+async fn overrides_are_off_unless_the_server_allows_them() {
+    let (status, body, _) = post_query(
+        "MATCH (u:User) RETURN u.name",
+        "overrides_graph",
+        Some(OVERRIDES),
+        None,
+        false,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{body}");
+    assert!(body.contains("CLICKGRAPH_ALLOW_SCHEMA_OVERRIDES"), "{body}");
+
+    // Requests without overrides are unaffected
+    let (status, body, _) = post_query(
+        "MATCH (u:User) RETURN u.name",
+        "overrides_graph",
+        None,
+        None,
+        false,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+}
+
+#[tokio::test]
+async fn pinned_tenant_overrides_stay_in_the_tenant_database() {
+    let query = "MATCH (u:User)-[:PLACED]->(o:Order) RETURN u.name, o.total";
+    let pinned = Some("overrides_tenant");
+
+    // `OVERRIDES` maps Order to `app`, the base schema's database
+    let (status, body, _) =
+        post_query(query, "overrides_tenant", Some(OVERRIDES), pinned, true).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert!(body.contains("database 'app'"), "{body}");
+
+    let foreign = OVERRIDES.replace("database: app", "database: billing");
+    let (status, body, _) =
+        post_query(query, "overrides_tenant", Some(&foreign), pinned, true).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert!(body.contains("database 'billing'"), "{body}");
+
+    let own = OVERRIDES.replace("database: app", "database: tenant_db");
+    let (status, sql, _) = post_query(query, "overrides_tenant", Some(&own), pinned, true).await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+    assert!(sql.contains("tenant_db.orders"), "SQL:\n{sql}");
+    assert!(!sql.contains("app."), "SQL:\n{sql}");
+}
@@ -0,0 +1,98 @@
+//! `POST /schemas/reload` over the real router.
+//!
+//! The reloaded file is a copy of the social benchmark schema, so the
+//! `default` alias it re-registers stays compatible with the other router
+//! tests in this binary that query it concurrently.
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::Value;
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS, GLOBAL_SCHEMA_CONFIGS};
+
+use crate::fixtures::NoopExecutor;
+
+const BENCHMARK_SCHEMA: &str = "benchmarks/social_network/schemas/social_benchmark.yaml";
+
+async fn request(method: &str, uri: &str) -> (StatusCode, Value) {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let _ = GLOBAL_SCHEMA_CONFIGS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let state = AppState {
+        executor: Arc::new(NoopExecutor),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    (status, serde_json::from_slice(&bytes).expect("json body"))
+}
+
+fn schema_entry<'a>(body: &'a Value, name: &str) -> &'a Value {
+    body["schemas"]
+        .as_array()
+        .and_then(|schemas| schemas.iter().find(|s| s["schema_name"] == name))
+        .unwrap_or_else(|| panic!("no entry for {name}: {body}"))
+}
+
+// A single test: GRAPH_CONFIG_PATH is process-wide.
+#[tokio::test]
+async fn reload_swaps_schemas_and_reports_diff() {
+    let original = std::fs::read_to_string(BENCHMARK_SCHEMA).expect("read benchmark schema");
+    let file = tempfile::NamedTempFile::new().expect("temp file");
+    std::fs::write(file.path(), &original).unwrap();
+    std::env::set_var("GRAPH_CONFIG_PATH", file.path());
+
+    let (status, body) = request("POST", "/schemas/reload").await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body["validated"], false);
+    schema_entry(&body, "default");
+    schema_entry(&body, "social_benchmark");
+
+    // Add a label: only that label shows up in the diff.
+    let with_tag = original.replacen(
+        "  nodes:\n",
+        "  nodes:\n    - label: Tag\n      database: social\n      table: tags_bench\n      node_id: tag_id\n      property_mappings: {}\n\n",
+        1,
+    );
+    std::fs::write(file.path(), with_tag).unwrap();
+    let (status, body) = request("POST", "/schemas/reload").await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let entry = schema_entry(&body, "social_benchmark");
+    assert_eq!(entry["added_nodes"], serde_json::json!(["Tag"]));
+    assert_eq!(entry["removed_nodes"], serde_json::json!([]));
+    assert_eq!(entry["changed_edges"], serde_json::json!([]));
+
+    // A broken file is rejected and the running schema is kept.
+    std::fs::write(file.path(), "graph_schema: [not, a, schema").unwrap();
+    let (status, body) = request("POST", "/schemas/reload").await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR, "body: {body}");
+    let (status, body) = request("GET", "/schemas/social_benchmark").await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(
+        body["nodes"]
+            .as_array()
+            .is_some_and(|nodes| nodes.iter().any(|n| n == "Tag")),
+        "{body}"
+    );
+
+    std::env::remove_var("GRAPH_CONFIG_PATH");
+}
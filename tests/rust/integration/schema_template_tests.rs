@@ -0,0 +1,134 @@
+//! `GET /schemas/templates[/{name}]` and the rendered mappings in use.
+//!
+//! Drives the real router (no ClickHouse): lists the templates, renders the
+//! Zeek one for a custom database, registers the YAML as a schema and checks
+//! that `/query/sql` plans typical pivots over it.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS, GLOBAL_SCHEMA_CONFIGS};
+
+use crate::fixtures::NoopExecutor;
+
+async fn request(method: &str, uri: &str, payload: Option<Value>) -> (StatusCode, Value) {
+    let state = AppState {
+        executor: Arc::new(NoopExecutor),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let body = payload.map_or_else(Body::empty, |p| Body::from(p.to_string()));
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(body)
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    (status, body)
+}
+
+async fn register(schema_name: &str, yaml: &str) {
+    let config = GraphSchemaConfig::from_yaml_str(yaml).expect("template yaml parses");
+    let schema = config.to_graph_schema().expect("template yaml converts");
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    let _ = GLOBAL_SCHEMA_CONFIGS.set(tokio::sync::RwLock::new(HashMap::new()));
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await
+        .insert(schema_name.to_string(), schema);
+    GLOBAL_SCHEMA_CONFIGS
+        .get()
+        .expect("GLOBAL_SCHEMA_CONFIGS set above")
+        .write()
+        .await
+        .insert(schema_name.to_string(), config);
+}
+
+#[tokio::test]
+async fn lists_templates_and_rejects_unknown_ones() {
+    let (status, body) = request("GET", "/schemas/templates", None).await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let names: Vec<_> = body["templates"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["zeek", "netflow", "otel-traces"]);
+
+    let (status, body) = request("GET", "/schemas/templates/syslog", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(body["error"].as_str().unwrap().contains("zeek"), "{body}");
+
+    let (status, _) = request("GET", "/schemas/templates/zeek?database=a%20b", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn rendered_zeek_template_serves_security_pivots() {
+    let (status, body) = request(
+        "GET",
+        "/schemas/templates/zeek?database=corp_logs&schema_name=zeek_template_test",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body["schema_name"], "zeek_template_test");
+    assert!(body["setup_sql"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("CREATE VIEW IF NOT EXISTS corp_logs.dns_answers"));
+    register("zeek_template_test", body["yaml"].as_str().unwrap()).await;
+
+    for (cypher, table) in [
+        (
+            "MATCH (src:IP)-[c:ACCESSED]->(dst:IP) RETURN src.ip, dst.ip, c.service",
+            "corp_logs.conn_log",
+        ),
+        (
+            "MATCH (src:IP)-[:ACCESSED]->(h:Host) RETURN src.ip, h.name",
+            "corp_logs.http_log",
+        ),
+        (
+            "MATCH (src:IP)-[:QUERIED]->(d:Domain) RETURN d.name, count(*) AS lookups",
+            "corp_logs.dns_log",
+        ),
+        (
+            "MATCH (d:Domain)-[:RESOLVED_TO]->(ip:IP) WHERE d.name = 'example.com' RETURN ip.ip",
+            "corp_logs.dns_answers",
+        ),
+    ] {
+        let (status, body) = request(
+            "POST",
+            "/query/sql",
+            Some(json!({ "query": cypher, "schema_name": "zeek_template_test" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{cypher}: {body}");
+        let sql = body["sql"].to_string();
+        assert!(sql.contains(table), "{cypher} should read {table}: {sql}");
+    }
+}
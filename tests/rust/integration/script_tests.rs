@@ -0,0 +1,180 @@
+//! Multi-statement `/query` requests and the streaming `/query/script`
+//! endpoint, driven through the real router with a stub executor.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+/// Answers every statement with one row and records the SQL it ran.
+#[derive(Default)]
+struct RecordingExecutor {
+    sql: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl QueryExecutor for RecordingExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.sql.lock().unwrap().push(sql.to_string());
+        Ok(vec![json!({"name": "Alice"})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_default_registered() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    if !map.contains_key("default") {
+        let schema = GraphSchemaConfig::from_yaml_file(
+            "benchmarks/social_network/schemas/social_benchmark.yaml",
+        )
+        .expect("load benchmark schema")
+        .to_graph_schema()
+        .expect("convert benchmark schema");
+        map.insert("default".to_string(), schema);
+    }
+}
+
+/// POST `query` to `uri`; returns the status, the body text and the SQL the
+/// executor ran.
+async fn post(uri: &str, query: &str) -> (StatusCode, String, Vec<String>) {
+    ensure_default_registered().await;
+    let executor = Arc::new(RecordingExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(json!({ "query": query }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let sql = executor.sql.lock().unwrap().clone();
+    (status, String::from_utf8(bytes.to_vec()).unwrap(), sql)
+}
+
+const SCRIPT: &str = "
+    // users, then their follows
+    MATCH (u:User) RETURN u.name AS name;
+    MATCH (u:User)-[:FOLLOWS]->(f:User) RETURN f.name AS name;
+";
+
+#[tokio::test]
+async fn query_runs_each_statement_in_order() {
+    let (status, body, sql) = post("/query", SCRIPT).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let body: Value = serde_json::from_str(&body).unwrap();
+    let statements = body["statements"].as_array().unwrap();
+    assert_eq!(statements.len(), 2, "{body}");
+    assert_eq!(
+        statements[0]["statement"],
+        "MATCH (u:User) RETURN u.name AS name"
+    );
+    assert_eq!(statements[1]["status"], 200);
+    assert_eq!(statements[1]["result"]["results"][0]["name"], "Alice");
+
+    assert_eq!(sql.len(), 2, "{sql:?}");
+    assert!(!sql[0].contains("follow"), "{}", sql[0]);
+    assert!(sql[1].contains("follow"), "{}", sql[1]);
+}
+
+#[tokio::test]
+async fn query_stops_at_the_first_failing_statement() {
+    let (status, body, sql) = post(
+        "/query",
+        "MATCH (u:User) RETURN u.name AS name; \
+         MATCH (x:Nope) RETURN x.name AS name; \
+         MATCH (u:User) RETURN u.name AS name",
+    )
+    .await;
+    assert!(!status.is_success(), "{body}");
+    let body: Value = serde_json::from_str(&body).unwrap();
+    let statements = body["statements"].as_array().unwrap();
+    assert_eq!(statements.len(), 2, "{body}");
+    assert_eq!(statements[1]["status"], status.as_u16());
+    assert!(statements[1]["error"].is_string(), "{body}");
+    assert_eq!(sql.len(), 1, "{sql:?}");
+}
+
+#[tokio::test]
+async fn semicolon_in_a_string_is_one_statement() {
+    let (status, body, _) = post(
+        "/query",
+        "MATCH (u:User) WHERE u.name = 'a;b' RETURN u.name AS name;",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let body: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["results"][0]["name"], "Alice", "{body}");
+}
+
+#[tokio::test]
+async fn script_streams_one_line_per_statement_and_a_summary() {
+    let (status, body, sql) = post("/query/script", SCRIPT).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let lines: Vec<Value> = body
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 3, "{body}");
+    assert_eq!(lines[0]["index"], 0);
+    assert_eq!(lines[1]["index"], 1);
+    assert_eq!(lines[1]["status"], 200);
+    assert!(lines[1]["elapsed_ms"].is_u64(), "{body}");
+    assert_eq!(
+        lines[2]["summary"],
+        json!({
+            "total": 2,
+            "executed": 2,
+            "failed": false,
+            "elapsed_ms": lines[2]["summary"]["elapsed_ms"]
+        })
+    );
+    assert_eq!(sql.len(), 2, "{sql:?}");
+
+    let (status, body, sql) = post(
+        "/query/script",
+        "MATCH (u:User) RETURN u.name GARBAGE; RETURN 1",
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    assert!(body.contains("Script parse error"), "{body}");
+    assert!(sql.is_empty());
+}
@@ -0,0 +1,124 @@
+//! Session parameters — `:param` commands over `/query`, then queries of
+//! the same `X-Session-Id` session referencing them.
+//!
+//! Drives the real router with a stub executor, like `stored_query_tests.rs`.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::server::{build_router, session_params::SESSION_HEADER, AppState};
+
+use crate::fixtures::ensure_default_schema_registered;
+
+/// Returns one fixed row and remembers the SQL it was asked to run.
+#[derive(Default)]
+struct RecordingExecutor(Mutex<Vec<String>>);
+
+#[async_trait]
+impl QueryExecutor for RecordingExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.0.lock().unwrap().push(sql.to_string());
+        Ok(vec![json!({"name": "Alice"})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+/// POST `payload` to `/query` in `session`; returns the status, body and
+/// the SQL sent to the executor.
+async fn query(session: Option<&str>, payload: Value) -> (StatusCode, Value, Vec<String>) {
+    ensure_default_schema_registered().await;
+    let executor = Arc::new(RecordingExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let mut request = Request::builder()
+        .method("POST")
+        .uri("/query")
+        .header("content-type", "application/json");
+    if let Some(id) = session {
+        request = request.header(SESSION_HEADER, id);
+    }
+    let resp = app
+        .oneshot(request.body(Body::from(payload.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let value = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let sql = executor.0.lock().unwrap().clone();
+    (status, value, sql)
+}
+
+#[tokio::test]
+async fn queries_of_a_session_see_its_parameters() {
+    let (status, body, _) = query(None, json!({"query": ":param country => 'NZ'"})).await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body["parameters"], json!({"country": "NZ"}));
+    let session = body["session_id"].as_str().expect("session id").to_string();
+
+    let (status, body, _) = query(
+        Some(&session),
+        json!({"query": ":param {active: true, limit: 5}"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body["session_id"], json!(session));
+    assert_eq!(body["parameters"].as_object().unwrap().len(), 3);
+
+    let cypher = "MATCH (u:User) WHERE u.country = $country AND u.is_active = $active \
+                  RETURN u.name AS name";
+    let (status, body, sql) = query(Some(&session), json!({"query": cypher})).await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let sql = sql.join("\n");
+    assert!(
+        sql.contains("u.country = 'NZ'") && sql.contains("u.is_active = 1"),
+        "session parameters bound; SQL:\n{sql}"
+    );
+
+    // A parameter sent with the query wins over the session's
+    let (status, body, sql) = query(
+        Some(&session),
+        json!({"query": cypher, "parameters": {"country": "AU"}}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(sql.join("\n").contains("u.country = 'AU'"), "SQL: {sql:?}");
+
+    let (status, body, _) = query(Some(&session), json!({"query": ":params clear"})).await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body["parameters"], json!({}));
+}
+
+#[tokio::test]
+async fn malformed_param_command_is_a_bad_request() {
+    let (status, body, sql) = query(None, json!({"query": ":param country"})).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "body: {body}");
+    assert!(body["error"].as_str().unwrap().contains("usage"), "{body}");
+    assert!(sql.is_empty());
+}
@@ -0,0 +1,170 @@
+//! `USE graph FOR TIMESTAMP '...'` on a schema backed by daily snapshot
+//! tables: every table scan of the query reads that one snapshot.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_cypher_statement,
+    query_planner::{evaluate_read_statement, snapshot_schema, QueryOptions},
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+    server::query_context::{set_current_schema, with_query_context, QueryContext},
+};
+
+const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  snapshot_column: snapshot_date
+  nodes:
+    - label: User
+      database: test
+      table: users_snapshot
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+    - label: Post
+      database: test
+      table: posts_snapshot
+      node_id: post_id
+      filter: "deleted = 0"
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: test
+      table: follows_snapshot
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings: {}
+    - type: AUTHORED
+      database: test
+      table: authored_snapshot
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings: {}
+"#;
+
+fn schema(yaml: &str) -> GraphSchema {
+    GraphSchemaConfig::from_yaml_str(yaml)
+        .expect("parse schema")
+        .to_graph_schema()
+        .expect("convert schema")
+}
+
+/// Mirrors the HTTP handler: snapshot view of the schema, installed as the
+/// task's current schema, then plan and render.
+async fn cypher_to_sql(yaml: &str, cypher: &str) -> Result<String, String> {
+    let base = schema(yaml);
+    let cypher = cypher.to_string();
+    with_query_context(QueryContext::new(Some("social".to_string())), async move {
+        let (_, statement) = parse_cypher_statement(&cypher).expect("parse");
+        let schema = snapshot_schema(&statement, &base, &QueryOptions::default())
+            .map_err(|e| e.to_string())?
+            .into_owned();
+        set_current_schema(std::sync::Arc::new(schema.clone()));
+        clickgraph::query_planner::logical_plan::reset_all_counters();
+        let (logical_plan, plan_ctx) =
+            evaluate_read_statement(statement, &schema, None, None, None)
+                .map_err(|e| e.to_string())?;
+        let render_plan =
+            logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+                .map_err(|e| e.to_string())?;
+        Ok(render_plan.to_sql())
+    })
+    .await
+}
+
+#[tokio::test]
+async fn every_table_scan_reads_the_snapshot() {
+    let sql = cypher_to_sql(
+        SCHEMA,
+        "USE social FOR TIMESTAMP '2024-06-01' \
+         MATCH (a:User)-[:FOLLOWS]->(b:User) RETURN a.name, b.name",
+    )
+    .await
+    .unwrap();
+    for alias in ["a", "b"] {
+        assert!(
+            sql.contains(&format!("{alias}.snapshot_date = toDate('2024-06-01')")),
+            "{alias} not filtered; SQL:\n{sql}"
+        );
+    }
+    assert_eq!(
+        sql.matches("snapshot_date = toDate('2024-06-01')").count(),
+        3,
+        "SQL:\n{sql}"
+    );
+}
+
+#[tokio::test]
+async fn snapshot_predicate_combines_with_the_schema_filter() {
+    let sql = cypher_to_sql(
+        SCHEMA,
+        "USE social FOR TIMESTAMP '2024-06-01T08:30:00' \
+         MATCH (u:User)-[:AUTHORED]->(p:Post) RETURN p.title",
+    )
+    .await
+    .unwrap();
+    assert!(sql.contains("p.deleted = 0"), "SQL:\n{sql}");
+    assert!(
+        sql.contains("p.snapshot_date = toDate('2024-06-01')"),
+        "SQL:\n{sql}"
+    );
+}
+
+#[tokio::test]
+async fn variable_length_paths_read_the_snapshot() {
+    let sql = cypher_to_sql(
+        SCHEMA,
+        "USE social FOR TIMESTAMP '2024-06-01' \
+         MATCH (a:User)-[:FOLLOWS*1..3]->(b:User) WHERE a.user_id = 1 RETURN b.name",
+    )
+    .await
+    .unwrap();
+    let recursive = sql.split("UNION ALL").nth(1).expect("recursive CTE branch");
+    assert!(
+        recursive.contains("snapshot_date = toDate('2024-06-01')"),
+        "recursive step not filtered; SQL:\n{sql}"
+    );
+}
+
+#[tokio::test]
+async fn queries_without_for_timestamp_read_every_snapshot() {
+    let sql = cypher_to_sql(SCHEMA, "USE social MATCH (u:User) RETURN u.name")
+        .await
+        .unwrap();
+    assert!(!sql.contains("snapshot_date"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn invalid_snapshots_are_rejected() {
+    let err = cypher_to_sql(
+        SCHEMA,
+        "USE social FOR TIMESTAMP 'yesterday' MATCH (u:User) RETURN u.name",
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("Invalid snapshot timestamp"), "{err}");
+
+    let err = cypher_to_sql(
+        SCHEMA,
+        "USE social FOR TIMESTAMP '2024-06-01' MATCH (u:User) RETURN u.name \
+         UNION ALL USE social FOR TIMESTAMP '2024-05-01' MATCH (u:User) RETURN u.name",
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("different snapshots"), "{err}");
+
+    let no_snapshots = SCHEMA.replace("  snapshot_column: snapshot_date\n", "");
+    let err = cypher_to_sql(
+        &no_snapshots,
+        "USE social FOR TIMESTAMP '2024-06-01' MATCH (u:User) RETURN u.name",
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("declares no snapshot_column"), "{err}");
+}
@@ -0,0 +1,144 @@
+//! `soft_delete` columns on node and edge tables: every table scan skips
+//! deleted rows unless the query opts in with `CYPHER include_deleted=true`.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_cypher_statement,
+    query_planner::{evaluate_read_statement, snapshot_schema, QueryOptions},
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+    server::query_context::{set_current_schema, with_query_context, QueryContext},
+};
+
+const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  snapshot_column: snapshot_date
+  nodes:
+    - label: User
+      database: test
+      table: users
+      node_id: user_id
+      soft_delete:
+        column: deleted_at
+      property_mappings:
+        user_id: user_id
+        name: full_name
+    - label: Post
+      database: test
+      table: posts
+      node_id: post_id
+      filter: "visibility = 'public'"
+      soft_delete:
+        column: is_deleted
+        marker: flag
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: test
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      soft_delete:
+        column: deleted_at
+      property_mappings: {}
+    - type: AUTHORED
+      database: test
+      table: authored
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings: {}
+"#;
+
+fn schema(yaml: &str) -> Result<GraphSchema, String> {
+    GraphSchemaConfig::from_yaml_str(yaml)
+        .expect("parse schema")
+        .to_graph_schema()
+        .map_err(|e| e.to_string())
+}
+
+/// Mirrors the HTTP handler: the query's view of the schema, installed as the
+/// task's current schema, then plan and render.
+async fn cypher_to_sql(cypher: &str) -> String {
+    let base = schema(SCHEMA).expect("convert schema");
+    let options = QueryOptions::from_query(cypher);
+    let cypher = cypher
+        .strip_prefix("CYPHER include_deleted=true ")
+        .unwrap_or(cypher)
+        .to_string();
+    with_query_context(QueryContext::new(Some("social".to_string())), async move {
+        let (_, statement) = parse_cypher_statement(&cypher).expect("parse");
+        let schema = snapshot_schema(&statement, &base, &options)
+            .expect("query schema")
+            .into_owned();
+        set_current_schema(std::sync::Arc::new(schema.clone()));
+        clickgraph::query_planner::logical_plan::reset_all_counters();
+        let (logical_plan, plan_ctx) =
+            evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+        let render_plan =
+            logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+                .expect("render");
+        render_plan.to_sql()
+    })
+    .await
+}
+
+#[tokio::test]
+async fn reads_skip_soft_deleted_rows() {
+    let sql = cypher_to_sql("MATCH (a:User)-[:FOLLOWS]->(b:User) RETURN a.name, b.name").await;
+    assert!(sql.contains("a.deleted_at IS NULL"), "SQL:\n{sql}");
+    assert!(sql.contains("b.deleted_at IS NULL"), "SQL:\n{sql}");
+    assert_eq!(
+        sql.matches("deleted_at IS NULL").count(),
+        3,
+        "edge not filtered; SQL:\n{sql}"
+    );
+}
+
+#[tokio::test]
+async fn flag_marker_combines_with_the_schema_filter() {
+    let sql = cypher_to_sql("MATCH (u:User)-[:AUTHORED]->(p:Post) RETURN p.title").await;
+    assert!(sql.contains("p.visibility = 'public'"), "SQL:\n{sql}");
+    assert!(sql.contains("p.is_deleted = 0"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn include_deleted_bypasses_the_filter() {
+    let sql = cypher_to_sql(
+        "CYPHER include_deleted=true MATCH (u:User)-[:AUTHORED]->(p:Post) RETURN u.name, p.title",
+    )
+    .await;
+    assert!(!sql.contains("deleted_at"), "SQL:\n{sql}");
+    assert!(!sql.contains("is_deleted"), "SQL:\n{sql}");
+    // The table's own filter still applies.
+    assert!(sql.contains("p.visibility = 'public'"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn include_deleted_keeps_the_snapshot_filter() {
+    let sql = cypher_to_sql(
+        "CYPHER include_deleted=true USE social FOR TIMESTAMP '2024-06-01' \
+         MATCH (u:User) RETURN u.name",
+    )
+    .await;
+    assert!(!sql.contains("deleted_at"), "SQL:\n{sql}");
+    assert!(
+        sql.contains("u.snapshot_date = toDate('2024-06-01')"),
+        "SQL:\n{sql}"
+    );
+}
+
+#[test]
+fn conflicting_soft_delete_settings_are_rejected() {
+    let conflicting = SCHEMA.replace(
+        "      table: authored\n",
+        "      table: posts\n      soft_delete:\n        column: deleted_at\n",
+    );
+    let err = schema(&conflicting).unwrap_err();
+    assert!(err.contains("conflicting soft_delete"), "{err}");
+}
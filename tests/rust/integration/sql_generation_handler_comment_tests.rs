@@ -15,43 +15,19 @@
 
 use std::sync::Arc;
 
-use async_trait::async_trait;
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
 use serde_json::{json, Value};
 use tower::ServiceExt; // for `oneshot`
 
 use clickgraph::config::ServerConfig;
-use clickgraph::executor::{ExecutorError, QueryExecutor};
-use clickgraph::graph_catalog::config::GraphSchemaConfig;
-use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+use clickgraph::server::{build_router, AppState};
 
-/// The `/query/sql` endpoint only translates Cypher -> SQL; it never calls
-/// the executor, so this stub is never actually invoked.
-struct StubExecutor;
-
-#[async_trait]
-impl QueryExecutor for StubExecutor {
-    async fn execute_json(
-        &self,
-        _sql: &str,
-        _role: Option<&str>,
-    ) -> Result<Vec<Value>, ExecutorError> {
-        Ok(vec![])
-    }
-    async fn execute_text(
-        &self,
-        _sql: &str,
-        _format: &str,
-        _role: Option<&str>,
-    ) -> Result<String, ExecutorError> {
-        Ok(String::new())
-    }
-}
+use crate::fixtures::{ensure_default_schema_registered, NoopExecutor};
 
 fn test_state() -> AppState {
     AppState {
-        executor: Arc::new(StubExecutor),
+        executor: Arc::new(NoopExecutor),
         clickhouse_client: None,
         config: ServerConfig::default(),
         query_semaphore: None,
@@ -59,27 +35,6 @@ fn test_state() -> AppState {
     }
 }
 
-/// Register the benchmark schema as "default" in the process-global schema
-/// registry the handler resolves `schema_name` against. `GLOBAL_SCHEMAS` is
-/// a `OnceCell` shared by the whole `integration` test binary, so this is
-/// idempotent: only the first caller across all test files actually sets
-/// it, everyone else just inserts/overwrites the "default" entry.
-async fn ensure_default_schema_registered() {
-    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
-    let schema = GraphSchemaConfig::from_yaml_file(
-        "benchmarks/social_network/schemas/social_benchmark.yaml",
-    )
-    .expect("load benchmark schema")
-    .to_graph_schema()
-    .expect("convert benchmark schema");
-    let mut map = GLOBAL_SCHEMAS
-        .get()
-        .expect("GLOBAL_SCHEMAS set above")
-        .write()
-        .await;
-    map.entry("default".to_string()).or_insert(schema);
-}
-
 async fn body_json(resp: axum::response::Response) -> Value {
     let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
         .await
@@ -2902,6 +2902,352 @@ async fn denorm_553_zero_match_anchor_inline_map_preserves_null_extended_row() {
     );
 }
 
+/// A node derived purely from a log column (no node table) whose `node_id`
+/// maps to an expression: a standalone scan returns each value once — the
+/// distinct values of a single-position node, as the both-positions case
+/// already gets from its UNION DISTINCT — and every property access renders
+/// the expression, not a column quoted from its text.
+#[tokio::test]
+async fn log_derived_node_with_expression_id() {
+    let schema = GraphSchemaConfig::from_yaml_str(
+        r#"
+name: zeek_derived
+graph_schema:
+  nodes:
+    - label: IP
+      database: zeek
+      table: dns_log
+      node_id: ip
+      property_mappings: {}
+      from_node_properties:
+        ip: "id.orig_h"
+    - label: Domain
+      database: zeek
+      table: dns_log
+      node_id: name
+      property_mappings: {}
+      to_node_properties:
+        name: "lower(trimRight(query, '.'))"
+  edges:
+    - type: QUERIED
+      database: zeek
+      table: dns_log
+      from_id: "id.orig_h"
+      to_id: "lower(trimRight(query, '.'))"
+      from_node: IP
+      to_node: Domain
+      property_mappings: {}
+"#,
+    )
+    .and_then(|config| config.to_graph_schema())
+    .expect("valid schema");
+
+    let scan = render(
+        &schema,
+        "MATCH (d:Domain) WHERE d.name ENDS WITH '.com' RETURN d.name, count(*)",
+        SqlDialect::ClickHouse,
+    )
+    .await;
+    assert!(
+        scan.contains(
+            "FROM (SELECT DISTINCT lower(trimRight(query, '.')) AS name FROM zeek.dns_log) AS d"
+        ),
+        "standalone scan should read the distinct node values:\n{scan}"
+    );
+    assert!(scan.contains("endsWith(d.name, '.com')"), "{scan}");
+    assert!(
+        !scan.contains("\"lower("),
+        "expression quoted as a column:\n{scan}"
+    );
+
+    let whole = render(&schema, "MATCH (d:Domain) RETURN d", SqlDialect::ClickHouse).await;
+    assert!(whole.contains("d.name AS \"d.name\""), "{whole}");
+
+    let hop = normalize(
+        &render(
+            &schema,
+            "MATCH (i:IP)-[:QUERIED]->(d:Domain) WHERE d.name = 'example.com' \
+             RETURN d.name, count(DISTINCT i) AS clients",
+            SqlDialect::ClickHouse,
+        )
+        .await,
+    );
+    // In a traversal the expression is evaluated on the edge row
+    assert!(
+        hop.contains("WHERE lower(trimRight(t0.query, '.')) = 'example.com'")
+            && hop.contains("GROUP BY lower(trimRight(t0.query, '.'))"),
+        "{hop}"
+    );
+    assert!(!hop.contains("SELECT DISTINCT"), "{hop}");
+}
+
+/// `RETURN DISTINCT ... ORDER BY <key>` where the key is not a returned
+/// column: it is computed inside the DISTINCT and sorted on by a wrapper, so
+/// each distinct row sorts by its own key. Keys over variables that are not
+/// returned are rejected, as in Neo4j.
+#[tokio::test]
+async fn distinct_order_by_key_not_returned() {
+    let schema = load_schema("benchmarks/social_network/schemas/social_benchmark.yaml");
+    let sql = normalize(
+        &render(
+            &schema,
+            "MATCH (u:User)-[:FOLLOWS]->(v:User) RETURN DISTINCT v.name AS n, v \
+             ORDER BY toLower(v.email) DESC, n SKIP 2 LIMIT 5",
+            SqlDialect::ClickHouse,
+        )
+        .await,
+    );
+    assert!(
+        sql.contains("SELECT * EXCEPT (__order_col_0) FROM (\nSELECT DISTINCT"),
+        "{sql}"
+    );
+    assert!(
+        sql.contains("lower(v.email_address) AS \"__order_col_0\"\nFROM"),
+        "{sql}"
+    );
+    assert!(
+        sql.contains(
+            ") AS __distinct\nORDER BY __distinct.\"__order_col_0\" DESC, n ASC\nLIMIT 2, 5"
+        ),
+        "{sql}"
+    );
+
+    // Returned expressions and aliases sort without a wrapper
+    for cypher in [
+        "MATCH (u:User) RETURN DISTINCT u.name ORDER BY u.name",
+        "MATCH (u:User) RETURN DISTINCT u ORDER BY u.email",
+        "MATCH (u:User) RETURN DISTINCT u.name AS name ORDER BY toLower(name)",
+    ] {
+        let sql = render(&schema, cypher, SqlDialect::ClickHouse).await;
+        assert!(!sql.contains("__distinct"), "{cypher}:\n{sql}");
+    }
+
+    let err = try_render(
+        &schema,
+        "MATCH (u:User) RETURN DISTINCT u.name ORDER BY u.email",
+        SqlDialect::ClickHouse,
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("'u' is not returned"), "{err}");
+}
+
+/// Grouping keys are every RETURN/WITH item without an aggregate anywhere
+/// inside it, whatever its shape: subscripted and map-wrapped aggregates,
+/// functions over WITH scalars, and scalar aliases chained through WITHs.
+#[tokio::test]
+async fn group_by_keys_derived_from_return_expressions() {
+    let schema = load_schema("benchmarks/social_network/schemas/social_benchmark.yaml");
+    let cases: [(&str, &[&str]); 5] = [
+        (
+            "MATCH (n:User)-[:FOLLOWS]->(m:User) RETURN n.name, collect(m.name)[0] AS f",
+            &[
+                "groupArray(m.full_name)[1] AS \"f\"",
+                "INNER JOIN social.users_bench AS m ON m.user_id = t0.followed_id",
+                "GROUP BY n.full_name",
+            ],
+        ),
+        (
+            "MATCH (n:User) RETURN {name: n.name} AS mp, count(*) AS k",
+            &["GROUP BY map('name', toString(n.full_name))"],
+        ),
+        (
+            "MATCH (n:User) WITH n.country AS c, count(*) AS k \
+             RETURN toUpper(c) AS u, sum(k) AS s",
+            &["upper(c_k.c) AS \"u\"", "GROUP BY upper(c_k.c)"],
+        ),
+        (
+            "MATCH (n:User) WITH n.country AS country, n.city AS city \
+             RETURN country AS u, count(city) AS c",
+            &[
+                "city_country.country AS \"u\"",
+                "count(city_country.city) AS \"c\"",
+                "GROUP BY city_country.country",
+            ],
+        ),
+        (
+            "MATCH (n:User) WITH n.country AS k, n.city AS city \
+             WITH k, count(city) AS c RETURN k, c",
+            &[
+                "n.city AS \"city\"",
+                "count(city_k.city) AS \"c\"",
+                "GROUP BY city_k.k",
+            ],
+        ),
+    ];
+    for (cypher, expected) in cases {
+        let sql = normalize(&render(&schema, cypher, SqlDialect::ClickHouse).await);
+        for fragment in expected {
+            assert!(
+                sql.contains(fragment),
+                "{cypher}: missing {fragment}\n{sql}"
+            );
+        }
+        // Grouping keys are selected as-is, not wrapped in anyLast()
+        assert!(!sql.contains("anyLast"), "{cypher}:\n{sql}");
+    }
+}
+
+/// `DISTINCT` inside an aggregate call applies to its whole argument, and
+/// `collect(DISTINCT x)` renders as the dialect's de-duplicating list
+/// aggregate. A variable merely starting with `distinct` is not the keyword.
+#[tokio::test]
+async fn aggregate_distinct_modifier() {
+    let schema = load_schema("benchmarks/social_network/schemas/social_benchmark.yaml");
+    let cases: [(&str, &[&str], &[&str]); 4] = [
+        (
+            "MATCH (n:User) RETURN count(DISTINCT n.city + 'x') AS c",
+            &["count(DISTINCT concat(n.city, 'x')) AS \"c\""],
+            &["count(DISTINCT concat(n.city, 'x')) AS `c`"],
+        ),
+        (
+            "MATCH (n:User)-[:FOLLOWS]->(m:User) RETURN n.name, collect(DISTINCT m.name) AS f",
+            &["groupUniqArray(m.full_name) AS \"f\""],
+            &["collect_set(m.full_name) AS `f`"],
+        ),
+        (
+            "MATCH (n:User)-[:FOLLOWS]->(m:User) WITH n, collect(DISTINCT m) AS ms \
+             RETURN n.name, size(ms) AS k",
+            &["groupUniqArray(tuple(m.city, "],
+            &["collect_set(struct(m.city, "],
+        ),
+        (
+            "MATCH (n:User) WITH n.user_id AS distinct_users \
+             RETURN count(distinct_users) AS c",
+            &["count(distinct_users.distinct_users) AS \"c\""],
+            &["count(distinct_users.distinct_users) AS `c`"],
+        ),
+    ];
+    for (cypher, clickhouse, databricks) in cases {
+        for (dialect, expected) in [
+            (SqlDialect::ClickHouse, clickhouse),
+            (SqlDialect::Databricks, databricks),
+        ] {
+            let sql = normalize(&render(&schema, cypher, dialect).await);
+            for fragment in expected {
+                assert!(
+                    sql.contains(fragment),
+                    "{dialect:?} {cypher}: missing {fragment}\n{sql}"
+                );
+            }
+        }
+    }
+}
+
+/// Map projections render as map literals: `.prop` reads the property, a
+/// bare variable its current value, and `.*` every mapped property in name
+/// order. An aggregating projection groups by its other entries.
+#[tokio::test]
+async fn map_projection_in_return_and_with() {
+    let schema = load_schema("benchmarks/social_network/schemas/social_benchmark.yaml");
+    let cases: [(&str, &[&str], &[&str]); 5] = [
+        (
+            "MATCH (n:User) RETURN n {.name, .city} AS m",
+            &["map('name', toString(n.full_name), 'city', toString(n.city)) AS \"m\""],
+            &["map('name', string(n.full_name), 'city', string(n.city)) AS `m`"],
+        ),
+        (
+            "MATCH (n:User)-[:FOLLOWS]->(f:User) RETURN n {.name, total: count(f)} AS m",
+            &["'total', toString(count(t", "GROUP BY n.full_name"],
+            &["'total', string(count(t", "GROUP BY n.full_name"],
+        ),
+        (
+            "MATCH (n:User) RETURN n {.*, city: toUpper(n.city)} AS m",
+            &[
+                "map('country', toString(n.country), 'email', toString(n.email_address), \
+               'is_active', toString(n.is_active), 'name', toString(n.full_name), \
+               'registration_date', toString(n.registration_date), \
+               'user_id', toString(n.user_id), 'city', toString(upper(n.city)))",
+            ],
+            &["map('country', string(n.country), 'email', string(n.email_address), "],
+        ),
+        (
+            "MATCH (n:User) WITH n, n.city AS c RETURN n {.name, c} AS m",
+            &["map('name', toString(c_n.p1_n_name), 'c', toString(c_n.c)) AS \"m\""],
+            &["map('name', string(c_n.p1_n_name), 'c', string(c_n.c)) AS `m`"],
+        ),
+        (
+            "MATCH (n:User)-[:FOLLOWS]->(f:User) WITH n {.name, total: count(f)} AS m RETURN m",
+            &["'total', toString(count(t", "GROUP BY n.full_name"],
+            &["'total', string(count(t", "GROUP BY n.full_name"],
+        ),
+    ];
+    for (cypher, clickhouse, databricks) in cases {
+        for (dialect, expected) in [
+            (SqlDialect::ClickHouse, clickhouse),
+            (SqlDialect::Databricks, databricks),
+        ] {
+            let sql = normalize(&render(&schema, cypher, dialect).await);
+            for fragment in expected {
+                assert!(
+                    sql.contains(fragment),
+                    "{dialect:?} {cypher}: missing {fragment}\n{sql}"
+                );
+            }
+        }
+    }
+
+    // `.*` needs a node or relationship
+    let err = try_render(
+        &schema,
+        "MATCH (n:User) WITH n.city AS c RETURN c {.*, k: 1} AS m",
+        SqlDialect::ClickHouse,
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("is not a node or relationship"), "{err}");
+}
+
+/// CASE renders as `multiIf` (searched) and `caseWithExpression` (simple)
+/// on ClickHouse and as ANSI CASE on Databricks, wherever an expression
+/// goes: projections, WHERE, ORDER BY and aggregate arguments.
+#[tokio::test]
+async fn case_expressions_in_projections_filters_and_aggregates() {
+    let schema = load_schema("benchmarks/social_network/schemas/social_benchmark.yaml");
+    let cases: [(&str, &str, &str); 5] = [
+        (
+            "MATCH (n:User) RETURN Case When n.city = 'a' Then 1 When n.city = 'b' Then 2 End AS c",
+            "multiIf(n.city = 'a', 1, n.city = 'b', 2, NULL) AS \"c\"",
+            "CASE WHEN n.city = 'a' THEN 1 WHEN n.city = 'b' THEN 2 END AS `c`",
+        ),
+        (
+            "MATCH (n:User) WHERE CASE WHEN n.is_active THEN n.city = 'x' ELSE false END \
+             RETURN n.name",
+            "WHERE multiIf(n.is_active, n.city = 'x', false)",
+            "WHERE CASE WHEN n.is_active THEN n.city = 'x' ELSE false END",
+        ),
+        (
+            "MATCH (n:User) RETURN n.name ORDER BY CASE n.country WHEN 'US' THEN 0 ELSE 1 END",
+            "ORDER BY caseWithExpression(n.country, 'US', 0, 1) ASC",
+            "ORDER BY CASE n.country WHEN 'US' THEN 0 ELSE 1 END ASC",
+        ),
+        (
+            "MATCH (n:User) RETURN n.country, sum(CASE WHEN n.is_active THEN 1 ELSE 0 END) AS a",
+            "sum(multiIf(n.is_active, 1, 0)) AS \"a\"",
+            "sum(CASE WHEN n.is_active THEN 1 ELSE 0 END) AS `a`",
+        ),
+        (
+            "MATCH (n:User)-[:FOLLOWS]->(m:User) \
+             WITH n, CASE WHEN count(m) > 10 THEN 'big' ELSE 'small' END AS size \
+             RETURN size, count(n) AS k",
+            "multiIf(count(t",
+            "CASE WHEN count(t",
+        ),
+    ];
+    for (cypher, clickhouse, databricks) in cases {
+        for (dialect, expected) in [
+            (SqlDialect::ClickHouse, clickhouse),
+            (SqlDialect::Databricks, databricks),
+        ] {
+            let sql = normalize(&render(&schema, cypher, dialect).await);
+            assert!(
+                sql.contains(expected),
+                "{dialect:?} {cypher}: missing {expected}\n{sql}"
+            );
+        }
+    }
+}
+
 /// FIXED (#533, was the third #479 gap): composite-key OPTIONAL MATCH
 /// WHERE-on-optional-node. `composite_node_ids.yaml` (Account identified by
 /// the TWO-column key `[bank_id, account_number]`) renders the classic
@@ -3475,15 +3821,20 @@ async fn denorm_with_match_chain_filters_per_branch_column_456() {
 
     for dialect in [SqlDialect::ClickHouse, SqlDialect::Databricks] {
         let sql = render(&schema, case_cypher, dialect).await;
+        let case_when = match dialect {
+            SqlDialect::ClickHouse => "multiIf(",
+            _ => "CASE WHEN ",
+        };
         // The dest branch's CASE must test its OWN column…
         assert!(
-            sql.contains("CASE WHEN a.dest_state = 'CA'"),
+            sql.contains(&format!("{case_when}a.dest_state = 'CA'")),
             "#456 follow-up ({dialect:?}): dest branch must remap the \
              column INSIDE the CASE wrapper to dest_state:\n{sql}"
         );
         // …and the origin column may appear in exactly one branch's CASE.
         assert_eq!(
-            sql.matches("CASE WHEN a.origin_state = 'CA'").count(),
+            sql.matches(&format!("{case_when}a.origin_state = 'CA'"))
+                .count(),
             1,
             "#456 follow-up ({dialect:?}): the dest branch leaked \
              origin_state inside the CASE (wrapper not descended):\n{sql}"
@@ -4407,6 +4758,56 @@ async fn undirected_optional_anchor_where_not_dropped_575() {
     );
 }
 
+/// OPTIONAL undirected single hop. Split into per-direction LEFT JOIN arms,
+/// each arm null-extended independently: an anchor with edges in only one
+/// direction got its match from one arm plus a spurious `(a, NULL)` row from
+/// the other, and the reverse arm was driven FROM the optional node. Same-label
+/// standard edges now take one LEFT JOIN over the doubled-edge CTE; shapes that
+/// keep the split (FK-edge, composite ids) drive every arm from the anchor.
+#[tokio::test]
+async fn optional_undirected_hop_anchored_in_both_directions() {
+    let std_schema = load_schema(SchemaId::Standard.yaml_path());
+    let sql = render(
+        &std_schema,
+        "MATCH (a:User) OPTIONAL MATCH (a)-[:FOLLOWS]-(b:User) RETURN a.name, b.name",
+        SqlDialect::ClickHouse,
+    )
+    .await;
+    assert!(
+        !sql.contains("UNION ALL\nSELECT \n      a.full_name"),
+        "same-label optional hop must not split into direction arms:\n{sql}"
+    );
+    assert!(
+        sql.contains(
+            "FROM social.users_bench AS a\nLEFT JOIN undir_edges_a_b_social_user_follows_bench"
+        ),
+        "optional hop must LEFT JOIN the doubled-edge CTE from the anchor:\n{sql}"
+    );
+
+    for (schema_id, cypher, anchor_from) in [
+        (
+            SchemaId::FkEdge,
+            "MATCH (c:Customer) OPTIONAL MATCH (c)-[:PLACED_BY]-(o:Order) RETURN c.name, o.order_id",
+            "FROM db_fk_edge.customers_fk AS c",
+        ),
+        (
+            SchemaId::Standard,
+            "MATCH (p:Post) OPTIONAL MATCH (p)-[:AUTHORED]-(a:User) RETURN a.name, p.title",
+            "FROM social.posts_bench AS p",
+        ),
+    ] {
+        let schema = load_schema(schema_id.yaml_path());
+        let sql = render(&schema, cypher, SqlDialect::ClickHouse).await;
+        let arms = sql.matches("UNION ALL").count() + 1;
+        assert_eq!(
+            sql.matches(anchor_from).count(),
+            arms,
+            "every direction arm must be driven from the required anchor \
+             (`{anchor_from}`):\n{sql}"
+        );
+    }
+}
+
 /// FIXED (#582): an OR-mixed WHERE predicate spanning the anchor AND the
 /// OPTIONAL side (`MATCH (a:Airport) OPTIONAL MATCH (a)-[:FLIGHT]-(b:Airport)
 /// WHERE a.city = 'Phoenix' OR b.state = 'ZZ'`) silently dropped the
@@ -8590,7 +8991,7 @@ mod walker_drift_family_484_490_495_476_483 {
         // auto-generated counter value (t1/t2 vs t5/t6 depending on test
         // ordering within the process).
         assert!(
-            sql.contains("CASE WHEN ")
+            sql.contains("multiIf(")
                 && sql.contains(".ip = ")
                 && !sql.contains("srcip1.ip = srcip2.ip"),
             "#495: CASE predicate must bind to edge aliases, not the raw \
@@ -9461,6 +9862,52 @@ mod vlp_fixed_path_family_496_497_498_499_501 {
         );
     }
 
+    /// `WITH collect(..) AS xs UNWIND xs AS x MATCH ...` materializes the
+    /// collected array in the WITH CTE and ARRAY JOINs it for the downstream
+    /// MATCH; scalars exported next to the array must survive the UNWIND.
+    #[tokio::test]
+    async fn with_collect_unwind_then_match() {
+        let schema = load_schema(SchemaId::Standard.yaml_path());
+        let sql = normalize(
+            &render(
+                &schema,
+                "MATCH (u:User) WHERE u.user_id < 5 WITH collect(u.user_id) AS ids \
+                 UNWIND ids AS id MATCH (v:User) WHERE v.user_id = id RETURN v.name",
+                SqlDialect::ClickHouse,
+            )
+            .await,
+        );
+        assert!(sql.contains("groupArray(u.user_id) AS \"ids\""), "{sql}");
+        assert!(sql.contains("ARRAY JOIN ids AS id"), "{sql}");
+        assert!(sql.contains("v.user_id = id"), "{sql}");
+
+        let sql = normalize(
+            &render(
+                &schema,
+                "MATCH (u:User) WITH u, count(*) AS c, collect(u.user_id) AS ids \
+                 UNWIND ids AS id MATCH (u)-[:FOLLOWS]->(f:User) RETURN c, id, f.name",
+                SqlDialect::ClickHouse,
+            )
+            .await,
+        );
+        assert!(sql.contains("ARRAY JOIN ids AS id"), "{sql}");
+        assert!(sql.contains("u.c AS \"c\""), "scalar `c` dropped:\n{sql}");
+
+        let sql = normalize(
+            &render(
+                &schema,
+                "MATCH (u:User) WITH collect(u.user_id) AS ids, count(*) AS c \
+                 UNWIND ids AS id RETURN id, c",
+                SqlDialect::ClickHouse,
+            )
+            .await,
+        );
+        assert!(
+            sql.contains("c_ids.c AS \"c\""),
+            "scalar `c` dropped:\n{sql}"
+        );
+    }
+
     /// #510: a WITH-clause aggregate over a denorm/coupled anchor
     /// (`WITH a, count(r) AS c`) must GROUP BY the anchor CTE's
     /// Cypher-property-named column, never the raw physical db column — and
@@ -11977,7 +12424,8 @@ mod with_aggregate_denorm_mixed_sources_family_549_550 {
                       WITH b, count(*) AS n RETURN b.city, b.code, n";
         let sql = normalize(&render(&schema, cypher, SqlDialect::ClickHouse).await);
 
-        let code_re = regex::Regex::new(r#"anyLast\(t\d+\.origin_code\) AS "p1_b_code""#).unwrap();
+        // `code` is the grouping key, so it is selected without anyLast().
+        let code_re = regex::Regex::new(r#"\bt\d+\.origin_code AS "p1_b_code""#).unwrap();
         assert!(
             code_re.is_match(&sql),
             "#549 guard: Airport's node_id property `code` must still resolve \
@@ -13856,15 +14304,15 @@ async fn disconnected_comma_cartesian_materializes_both_nodes_601() {
             sql.contains("FROM social.users_bench AS a"),
             "#601: left anchor `a` must be the FROM table for {dialect:?}, got:\n{sql}"
         );
-        // `c` must be materialized as a cross join (empty ON → `ON 1 = 1`), NOT
-        // dropped. Without it `c.user_id` is unbound.
+        // `c` must be materialized as a cross join, NOT dropped. Without it
+        // `c.user_id` is unbound.
         assert!(
             sql.contains("social.users_bench AS c"),
             "#601: right anchor `c` must be materialized (cross join), not dropped, for {dialect:?}, got:\n{sql}"
         );
         assert!(
-            sql.contains("ON 1 = 1"),
-            "#601: the disconnected cartesian must render as a cross join (`ON 1 = 1`) for {dialect:?}, got:\n{sql}"
+            sql.contains("CROSS JOIN social.users_bench AS c"),
+            "#601: the disconnected cartesian must render as a CROSS JOIN for {dialect:?}, got:\n{sql}"
         );
     }
 }
@@ -13892,7 +14340,7 @@ async fn disconnected_separate_match_with_where_materializes_both_601() {
             "#601 facet2: `a` must be the FROM table for {dialect:?}, got:\n{sql}"
         );
         assert!(
-            sql.contains("social.users_bench AS c") && sql.contains("ON 1 = 1"),
+            sql.contains("CROSS JOIN social.users_bench AS c"),
             "#601 facet2: `c` must be materialized as a cross join for {dialect:?}, got:\n{sql}"
         );
         // Both side predicates survive into the outer WHERE.
@@ -13927,8 +14375,122 @@ async fn disconnected_cartesian_count_star_keeps_cross_join_601() {
             "#601 facet3: `a` must be the FROM table for {dialect:?}, got:\n{sql}"
         );
         assert!(
-            sql.contains("social.users_bench AS c") && sql.contains("ON 1 = 1"),
+            sql.contains("CROSS JOIN social.users_bench AS c"),
             "#601 facet3: the cardinality-significant cross join must NOT be pruned for {dialect:?}, got:\n{sql}"
         );
     }
 }
+
+/// An OPTIONAL MATCH aggregated per anchor (`WITH u, count(p)`) is computed
+/// once per anchor id in its own CTE and LEFT JOINed, with `count` defaulting
+/// to 0. The chain is INNER joined inside the CTE: a partial match of a
+/// multi-hop OPTIONAL MATCH is no match. A required hop next to the optional
+/// one multiplies the rows being counted, so that shape is left as it was.
+#[tokio::test]
+async fn optional_match_aggregate_is_pre_aggregated_per_anchor() {
+    let schema = load_schema(SchemaId::Standard.yaml_path());
+    let cypher = "MATCH (u:User) OPTIONAL MATCH (u)-[:FOLLOWS]->(f:User)-[:AUTHORED]->(p:Post) \
+                  WITH u, count(p) AS posts RETURN u.name, posts";
+
+    for dialect in [SqlDialect::ClickHouse, SqlDialect::Databricks] {
+        let sql = normalize(&render(&schema, cypher, dialect).await);
+        let (agg, rest) = sql
+            .split_once("with_posts_u_cte_0 AS")
+            .unwrap_or_else(|| panic!("expected the WITH CTE for {dialect:?}, got:\n{sql}"));
+        assert!(
+            agg.contains("__optional_agg_0 AS (SELECT")
+                && agg.contains("count(t1.post_id)")
+                && agg.contains(
+                    "INNER JOIN social.authored_bench AS t1 ON t1.user_id = t0.followed_id"
+                )
+                && agg.contains("GROUP BY t0.follower_id"),
+            "expected the pattern aggregated per follower_id for {dialect:?}, got:\n{sql}"
+        );
+        assert!(
+            rest.contains("coalesce(")
+                && rest.contains("__optional_agg_0.posts), 0)")
+                && rest.contains(
+                    "LEFT JOIN __optional_agg_0 AS __optional_agg_0 ON __optional_agg_0.__key0 = u.user_id"
+                )
+                && !rest.contains("user_follows_bench"),
+            "expected the anchor LEFT JOINed to the aggregate for {dialect:?}, got:\n{sql}"
+        );
+    }
+
+    let fanned_out =
+        "MATCH (u:User)-[:FOLLOWS]->(g:User) OPTIONAL MATCH (u)-[:AUTHORED]->(p:Post) \
+                      WITH u, count(p) AS posts RETURN u.name, posts";
+    let sql = render(&schema, fanned_out, SqlDialect::ClickHouse).await;
+    assert!(!sql.contains("__optional_agg"), "got:\n{sql}");
+}
+
+/// `-[:FOLLOWS {sample: 0.1}]->` keeps the follows whose hashed endpoint ids
+/// fall in the lowest 10% of the buckets, on that edge scan only: a second,
+/// unhinted FOLLOWS hop is left alone. On a variable-length edge every step
+/// samples, so the walk stays on one sampled graph.
+#[tokio::test]
+async fn edge_sample_hint_filters_that_edge_scan_only() {
+    let schema = load_schema(SchemaId::Standard.yaml_path());
+    for (dialect, bucket) in [
+        (
+            SqlDialect::ClickHouse,
+            "modulo(cityHash64(s.follower_id, s.followed_id), 1000000) < 100000",
+        ),
+        (
+            SqlDialect::Databricks,
+            "pmod(xxhash64(s.follower_id, s.followed_id), 1000000) < 100000",
+        ),
+    ] {
+        let sql = render(
+            &schema,
+            "MATCH (a:User)-[s:FOLLOWS {sample: 0.1}]->(b:User)-[:FOLLOWS]->(c:User) \
+             RETURN count(DISTINCT c)",
+            dialect,
+        )
+        .await;
+        assert_eq!(sql.matches(bucket).count(), 1, "{dialect:?}:\n{sql}");
+        assert!(!sql.contains("sample"), "{dialect:?}:\n{sql}");
+    }
+
+    let vlp = render(
+        &schema,
+        "MATCH (a:User {user_id: 1})-[:FOLLOWS*1..3 {sample: 0.5}]->(b:User) \
+         RETURN count(DISTINCT b)",
+        SqlDialect::ClickHouse,
+    )
+    .await;
+    let step = "modulo(cityHash64(rel.follower_id, rel.followed_id), 1000000) < 500000";
+    assert_eq!(
+        vlp.matches(step).count(),
+        2,
+        "base and recursive step:\n{vlp}"
+    );
+
+    let whole = render(
+        &schema,
+        "MATCH (a:User)-[:FOLLOWS {sample: 1}]->(b:User) RETURN count(*)",
+        SqlDialect::ClickHouse,
+    )
+    .await;
+    assert!(!whole.contains("cityHash64"), "got:\n{whole}");
+
+    for (cypher, expected) in [
+        (
+            "MATCH (a:User)-[s:FOLLOWS {sample: 1.5}]->(b:User) RETURN count(*)",
+            "must be a number in (0, 1]",
+        ),
+        (
+            "MATCH (a:User)-[s:FOLLOWS {sample: 'x'}]->(b:User) RETURN count(*)",
+            "must be a number in (0, 1]",
+        ),
+        (
+            "MATCH (a:User)-[s:FOLLOWS|LIKED {sample: 0.1}]->(b) RETURN count(*)",
+            "needs a single relationship type",
+        ),
+    ] {
+        let err = try_render(&schema, cypher, SqlDialect::ClickHouse)
+            .await
+            .expect_err(cypher);
+        assert!(err.contains(expected), "{cypher}: {err}");
+    }
+}
@@ -53,6 +53,9 @@ fn fixture_snapshot() -> Arc<TableStatsSnapshot> {
 /// `stats_cartesian_smaller_table_anchor`: `(a:User), (b:Post)` — two FROM
 /// markers; alphabetical picks `a` (users, 1M in the fixture); stats must flip
 /// the anchor to `b` (posts, 100).
+///
+/// `stats_cartesian_smaller_sides_first`: the cross-joined markers after the
+/// anchor follow row count too — `c` (posts) before `a` (users).
 const STATS_CORPUS: &[(&str, &str)] = &[
     (
         "stats_cartesian_smaller_table_anchor",
@@ -62,6 +65,10 @@ const STATS_CORPUS: &[(&str, &str)] = &[
         "stats_cartesian_three_way",
         "MATCH (a:User), (b:Post), (c:User) RETURN a.name, b.title, c.name",
     ),
+    (
+        "stats_cartesian_smaller_sides_first",
+        "MATCH (a:User), (b:Post), (c:Post) RETURN a.name, b.title, c.title",
+    ),
 ];
 
 fn load_schema() -> GraphSchema {
@@ -0,0 +1,214 @@
+//! Stored queries — `POST /queries` then `CALL query.run(...)` over `/query`.
+//!
+//! Drives the real router with a stub executor, like
+//! `neo4j_http_tx_tests.rs`. The stored-query registry is process-wide, so
+//! each test registers its own query name.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::server::{build_router, AppState};
+
+use crate::fixtures::ensure_default_schema_registered;
+
+/// Returns one fixed row and remembers the SQL it was asked to run.
+#[derive(Default)]
+struct RecordingExecutor(Mutex<Vec<String>>);
+
+#[async_trait]
+impl QueryExecutor for RecordingExecutor {
+    async fn execute_json(
+        &self,
+        sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        self.0.lock().unwrap().push(sql.to_string());
+        Ok(vec![json!({"name": "Alice"})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn request(method: &str, uri: &str, payload: Option<Value>) -> (StatusCode, Value) {
+    let (status, body, _) = request_with_sql(method, uri, payload).await;
+    (status, body)
+}
+
+/// Like [`request`], also returning the SQL sent to the executor.
+async fn request_with_sql(
+    method: &str,
+    uri: &str,
+    payload: Option<Value>,
+) -> (StatusCode, Value, Vec<String>) {
+    ensure_default_schema_registered().await;
+    let executor = Arc::new(RecordingExecutor::default());
+    let state = AppState {
+        executor: executor.clone(),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let body = payload.map_or_else(Body::empty, |p| Body::from(p.to_string()));
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(body)
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let value = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    let sql = executor.0.lock().unwrap().clone();
+    (status, value, sql)
+}
+
+fn users_in_country(name: &str, allowed_roles: Option<Vec<&str>>) -> Value {
+    json!({
+        "name": name,
+        "query": "MATCH (u:User) WHERE u.country = $country AND u.is_active = $active RETURN u.name AS name",
+        "parameters": {
+            "country": {"type": "string"},
+            "active": {"type": "boolean", "default": true}
+        },
+        "allowed_roles": allowed_roles,
+    })
+}
+
+#[tokio::test]
+async fn stored_query_runs_by_name_with_bound_parameters() {
+    let (status, body) = request(
+        "POST",
+        "/queries",
+        Some(users_in_country("users_in_country_sql", None)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "body: {body}");
+
+    let (status, body, sql) = request_with_sql(
+        "POST",
+        "/query",
+        Some(json!({"query": "CALL query.run('users_in_country_sql', {country: 'NZ'})"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    let sql = sql.join("\n");
+    assert!(
+        sql.contains("u.country = 'NZ'") && sql.contains("u.is_active = 1"),
+        "argument and default bound; SQL:\n{sql}"
+    );
+
+    // Arguments can come from a request parameter.
+    let (status, body, sql) = request_with_sql(
+        "POST",
+        "/query",
+        Some(json!({
+            "query": "CALL query.run('users_in_country_sql', $args)",
+            "parameters": {"args": {"country": "AU", "active": false}}
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(sql.join("\n").contains("u.country = 'AU'"), "SQL: {sql:?}");
+
+    let (_, body) = request("GET", "/queries/users_in_country_sql", None).await;
+    assert_eq!(body["stats"]["calls"], json!(2), "body: {body}");
+    assert_eq!(body["stats"]["errors"], json!(0), "body: {body}");
+}
+
+#[tokio::test]
+async fn stored_query_checks_roles_and_parameters() {
+    let (status, _) = request(
+        "POST",
+        "/queries",
+        Some(users_in_country(
+            "users_in_country_rbac",
+            Some(vec!["analyst"]),
+        )),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let run = |role: Option<&str>, args: Value| {
+        json!({
+            "query": format!("CALL query.run('users_in_country_rbac', {})", args),
+            "role": role,
+            "sql_only": true
+        })
+    };
+    let (status, _) = request("POST", "/query", Some(run(None, json!({})))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // `{}` → missing required `country`
+    let (status, body) = request("POST", "/query", Some(run(Some("analyst"), json!({})))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(
+        body.to_string().contains("country"),
+        "missing parameter named; body: {body}"
+    );
+
+    let (status, _) = request(
+        "POST",
+        "/query",
+        Some(json!({
+            "query": "CALL query.run('no_such_query')"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn stored_query_definitions_are_validated_and_removable() {
+    let mut bad = users_in_country("users_in_country_bad", None);
+    bad["parameters"].as_object_mut().unwrap().remove("country");
+    let (status, body) = request("POST", "/queries", Some(bad)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "body: {body}");
+
+    let mut unknown_label = users_in_country("users_in_country_label", None);
+    unknown_label["query"] = json!("MATCH (u:Nope) WHERE u.country = $country RETURN u");
+    unknown_label["schema_name"] = json!("default");
+    let (status, _) = request("POST", "/queries", Some(unknown_label)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = request(
+        "POST",
+        "/queries",
+        Some(users_in_country("users_in_country_tmp", None)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (_, body) = request("GET", "/queries", None).await;
+    assert!(body["queries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|q| q["name"] == "users_in_country_tmp"));
+
+    let (status, _) = request("DELETE", "/queries/users_in_country_tmp", None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = request("GET", "/queries/users_in_country_tmp", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
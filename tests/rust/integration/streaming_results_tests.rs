@@ -0,0 +1,242 @@
+//! `"stream": true` on `/query`: `JSONEachRow` results are sent as
+//! newline-delimited JSON, chunk by chunk as the executor yields them.
+//!
+//! Drives the real router with stub executors: one that streams fixed chunks
+//! (or fails), and one that only buffers, to cover the trait's default.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use bytes::Bytes;
+use futures_util::StreamExt;
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ByteStream, ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+/// Streams `chunks` in order; an `Err` entry fails the stream at that point.
+struct ChunkExecutor {
+    chunks: Vec<Result<&'static str, &'static str>>,
+}
+
+#[async_trait]
+impl QueryExecutor for ChunkExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        panic!("streaming requests must not buffer the result")
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+    async fn execute_json_stream(
+        &self,
+        _sql: &str,
+        _params: &[(String, String)],
+        _settings: &[(String, String)],
+        _role: Option<&str>,
+    ) -> Result<ByteStream, ExecutorError> {
+        let chunks: Vec<_> = self
+            .chunks
+            .iter()
+            .map(|chunk| {
+                chunk
+                    .map(|chunk| Bytes::from_static(chunk.as_bytes()))
+                    .map_err(|e| ExecutorError::Io(e.to_string()))
+            })
+            .collect();
+        Ok(futures_util::stream::iter(chunks).boxed())
+    }
+}
+
+/// Buffers rows only, so streaming falls back to the trait default.
+struct BufferedExecutor;
+
+#[async_trait]
+impl QueryExecutor for BufferedExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        Ok(vec![json!({"name": "Alice"}), json!({"name": "Bob"})])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_default_registered() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    if !map.contains_key("default") {
+        let schema = GraphSchemaConfig::from_yaml_file(
+            "benchmarks/social_network/schemas/social_benchmark.yaml",
+        )
+        .expect("load benchmark schema")
+        .to_graph_schema()
+        .expect("convert benchmark schema");
+        map.insert("default".to_string(), schema);
+    }
+}
+
+/// Run `payload`; returns the status, content type and the body chunks (or
+/// the error that cut the body short).
+async fn run(
+    executor: Arc<dyn QueryExecutor>,
+    payload: Value,
+) -> (StatusCode, Option<String>, Vec<Bytes>, Option<String>) {
+    ensure_default_registered().await;
+    let state = AppState {
+        executor,
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/query")
+                .header("content-type", "application/json")
+                .body(Body::from(payload.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .map(|v| v.to_str().unwrap().to_string());
+    let mut body = resp.into_body().into_data_stream();
+    let mut chunks = Vec::new();
+    let mut error = None;
+    while let Some(chunk) = body.next().await {
+        match chunk {
+            Ok(chunk) => chunks.push(chunk),
+            Err(e) => {
+                error = Some(e.to_string());
+                break;
+            }
+        }
+    }
+    (status, content_type, chunks, error)
+}
+
+const QUERY: &str = "MATCH (u:User) RETURN u.name AS name";
+
+#[tokio::test]
+async fn stream_forwards_executor_chunks_as_ndjson() {
+    // Chunk boundaries fall mid-row; the client reassembles lines.
+    let executor = Arc::new(ChunkExecutor {
+        chunks: vec![
+            Ok("{\"name\":\"Alice\"}\n{\"na"),
+            Ok("me\":\"Bob\"}\n"),
+            Ok("{\"name\":\"Carol\"}\n"),
+        ],
+    });
+    let (status, content_type, chunks, error) =
+        run(executor, json!({ "query": QUERY, "stream": true })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type.as_deref(), Some("application/x-ndjson"));
+    assert_eq!(error, None);
+    assert_eq!(chunks.len(), 3, "{chunks:?}");
+
+    let body = chunks.concat();
+    let rows: Vec<Value> = std::str::from_utf8(&body)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            json!({"name": "Alice"}),
+            json!({"name": "Bob"}),
+            json!({"name": "Carol"})
+        ]
+    );
+}
+
+#[tokio::test]
+async fn stream_reports_failures() {
+    // Failing before the first chunk is still an error response.
+    let executor = Arc::new(ChunkExecutor {
+        chunks: vec![Err("Code: 60. Unknown table")],
+    });
+    let (status, _, chunks, _) = run(executor, json!({ "query": QUERY, "stream": true })).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(
+        String::from_utf8_lossy(&chunks.concat()).contains("Unknown table"),
+        "{chunks:?}"
+    );
+
+    // Once rows have been sent, a failure can only cut the body short.
+    let executor = Arc::new(ChunkExecutor {
+        chunks: vec![Ok("{\"name\":\"Alice\"}\n"), Err("connection reset")],
+    });
+    let (status, _, chunks, error) = run(executor, json!({ "query": QUERY, "stream": true })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(chunks, vec![Bytes::from_static(b"{\"name\":\"Alice\"}\n")]);
+    assert!(error.is_some());
+}
+
+#[tokio::test]
+async fn stream_falls_back_to_buffered_rows() {
+    let (status, content_type, chunks, _) = run(
+        Arc::new(BufferedExecutor),
+        json!({ "query": QUERY, "stream": true }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type.as_deref(), Some("application/x-ndjson"));
+    assert_eq!(
+        chunks.concat(),
+        b"{\"name\":\"Alice\"}\n{\"name\":\"Bob\"}\n".to_vec()
+    );
+
+    // Without the flag the response is the usual `results` object.
+    let (_, content_type, chunks, _) =
+        run(Arc::new(BufferedExecutor), json!({ "query": QUERY })).await;
+    assert_eq!(content_type.as_deref(), Some("application/json"));
+    let body: Value = serde_json::from_slice(&chunks.concat()).unwrap();
+    assert_eq!(body["results"][1]["name"], "Bob");
+}
+
+#[tokio::test]
+async fn stream_requires_json_each_row() {
+    let (status, _, chunks, _) = run(
+        Arc::new(BufferedExecutor),
+        json!({ "query": QUERY, "stream": true, "format": "Pretty" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(
+        String::from_utf8_lossy(&chunks.concat()).contains("stream is only supported"),
+        "{chunks:?}"
+    );
+}
@@ -0,0 +1,146 @@
+//! `GET` / `POST /export` — a subgraph as a GraphML or GEXF download.
+//!
+//! Drives the real router with a stub executor returning node rows.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::executor::{ExecutorError, QueryExecutor};
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+const SCHEMA: &str = r#"
+name: export_graph
+graph_schema:
+  nodes:
+    - label: User
+      database: app
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+"#;
+
+struct UsersExecutor;
+
+#[async_trait]
+impl QueryExecutor for UsersExecutor {
+    async fn execute_json(
+        &self,
+        _sql: &str,
+        _role: Option<&str>,
+    ) -> Result<Vec<Value>, ExecutorError> {
+        Ok(vec![
+            json!({"n.user_id": 1, "n.name": "Alice & Co"}),
+            json!({"n.user_id": 2, "n.name": "Bob"}),
+        ])
+    }
+    async fn execute_text(
+        &self,
+        _sql: &str,
+        _format: &str,
+        _role: Option<&str>,
+    ) -> Result<String, ExecutorError> {
+        Ok(String::new())
+    }
+}
+
+async fn ensure_schema_registered() {
+    let config = GraphSchemaConfig::from_yaml_str(SCHEMA).expect("parse schema");
+    let schema = config.to_graph_schema().expect("convert schema");
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(HashMap::new()));
+    GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await
+        .insert("export_graph".to_string(), schema);
+}
+
+async fn export(request: Request<Body>) -> (StatusCode, String, String) {
+    ensure_schema_registered().await;
+    let state = AppState {
+        executor: Arc::new(UsersExecutor),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let resp = app.oneshot(request).await.unwrap();
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    (
+        status,
+        content_type,
+        String::from_utf8_lossy(&bytes).into_owned(),
+    )
+}
+
+fn post(body: Value) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/export")
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn query_result_exports_as_graphml() {
+    let (status, content_type, doc) = export(post(json!({
+        "query": "MATCH (n:User) RETURN n",
+        "schema_name": "export_graph",
+    })))
+    .await;
+    assert_eq!(status, StatusCode::OK, "{doc}");
+    assert_eq!(content_type, "application/graphml+xml");
+    assert!(doc.contains("<graphml"), "{doc}");
+    assert_eq!(doc.matches("<node ").count(), 2, "{doc}");
+    assert!(doc.contains("Alice &amp; Co"), "{doc}");
+}
+
+#[tokio::test]
+async fn label_set_exports_as_gexf() {
+    let (status, content_type, doc) = export(
+        Request::builder()
+            .uri("/export?labels=User&format=gexf&schema_name=export_graph&limit=10")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{doc}");
+    assert_eq!(content_type, "application/gexf+xml");
+    assert!(doc.contains("<gexf xmlns=\"http://gexf.net/1.3\""), "{doc}");
+    assert!(doc.contains("label=\"User\""), "{doc}");
+    assert_eq!(doc.matches("<node ").count(), 2, "{doc}");
+}
+
+#[tokio::test]
+async fn export_needs_exactly_one_source() {
+    let (status, _, body) = export(post(json!({"schema_name": "export_graph"}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+
+    let (status, _, body) = export(post(json!({
+        "query": "MATCH (n:User) RETURN n",
+        "labels": ["User"],
+    })))
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+}
@@ -0,0 +1,235 @@
+//! Multi-graph routing — `USE <graph>` picks the schema a `/query` is planned
+//! against, ahead of the request's `schema_name`, then the default graph.
+//!
+//! Drives the real router in `sql_only` mode and checks which graph's tables
+//! the generated SQL reads.
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{json, Value};
+use tower::ServiceExt; // for `oneshot`
+
+use clickgraph::config::ServerConfig;
+use clickgraph::graph_catalog::config::GraphSchemaConfig;
+use clickgraph::server::{build_router, AppState, GLOBAL_SCHEMAS};
+
+use crate::fixtures::NoopExecutor;
+
+/// `default` is the social benchmark (`social.users_bench`); `cs_standard`
+/// maps the same labels onto `cs_test.users`.
+async fn ensure_graphs_registered() {
+    let _ = GLOBAL_SCHEMAS.set(tokio::sync::RwLock::new(std::collections::HashMap::new()));
+    let mut map = GLOBAL_SCHEMAS
+        .get()
+        .expect("GLOBAL_SCHEMAS set above")
+        .write()
+        .await;
+    for (name, path) in [
+        (
+            "default",
+            "benchmarks/social_network/schemas/social_benchmark.yaml",
+        ),
+        ("cs_standard", "schemas/test/cross_schema/cs_standard.yaml"),
+    ] {
+        if !map.contains_key(name) {
+            let schema = GraphSchemaConfig::from_yaml_file(path)
+                .expect("load schema")
+                .to_graph_schema()
+                .expect("convert schema");
+            map.insert(name.to_string(), schema);
+        }
+    }
+}
+
+async fn sql_for(payload: Value) -> (StatusCode, String) {
+    sql_for_graph(None, payload).await
+}
+
+/// `sql_for` with the request's `X-Graph-Name` header set to `graph`.
+async fn sql_for_graph(graph: Option<&str>, payload: Value) -> (StatusCode, String) {
+    let mut payload = payload;
+    payload["sql_only"] = json!(true);
+    let (status, body) = post_for_graph("/query", graph, payload).await;
+    let text = body["generated_sql"]
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| body.to_string());
+    (status, text)
+}
+
+/// POST `payload` to `uri`, with the `X-Graph-Name` header set to `graph`.
+async fn post_for_graph(uri: &str, graph: Option<&str>, payload: Value) -> (StatusCode, Value) {
+    ensure_graphs_registered().await;
+    let state = AppState {
+        executor: Arc::new(NoopExecutor),
+        clickhouse_client: None,
+        config: ServerConfig::default(),
+        query_semaphore: None,
+        pool: None,
+    };
+    let app = build_router(state, &ServerConfig::default());
+    let mut request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json");
+    if let Some(graph) = graph {
+        request = request.header("X-Graph-Name", graph);
+    }
+    let resp = app
+        .oneshot(request.body(Body::from(payload.to_string())).unwrap())
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .expect("read body");
+    let body: Value = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    (status, body)
+}
+
+#[tokio::test]
+async fn use_clause_selects_the_graph() {
+    let (status, sql) = sql_for(json!({"query": "MATCH (u:User) RETURN u.name"})).await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+    assert!(
+        sql.contains("social.users_bench"),
+        "default graph; SQL:\n{sql}"
+    );
+
+    let (status, sql) =
+        sql_for(json!({"query": "USE cs_standard MATCH (u:User) RETURN u.name"})).await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+    assert!(sql.contains("cs_test.users"), "SQL:\n{sql}");
+
+    // USE wins over the request's schema_name, which wins over the default.
+    let (_, sql) = sql_for(json!({
+        "query": "USE cs_standard MATCH (u:User) RETURN u.user_id",
+        "schema_name": "default"
+    }))
+    .await;
+    assert!(sql.contains("cs_test.users"), "SQL:\n{sql}");
+    let (_, sql) = sql_for(json!({
+        "query": "MATCH (u:User) RETURN u.user_id",
+        "schema_name": "cs_standard"
+    }))
+    .await;
+    assert!(sql.contains("cs_test.users"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn use_clause_rejects_unknown_and_conflicting_graphs() {
+    let (status, body) = sql_for(json!({"query": "USE nope MATCH (u:User) RETURN u"})).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(
+        body.contains("'nope' not found") && body.contains("cs_standard"),
+        "lists the loaded graphs; body: {body}"
+    );
+
+    let (status, body) = sql_for(json!({
+        "query": "USE cs_standard MATCH (u:User) RETURN u.name AS name \
+                  UNION ALL USE default MATCH (u:User) RETURN u.name AS name"
+    }))
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.contains("different graphs"), "body: {body}");
+
+    // Repeating the same graph in every branch is fine.
+    let (status, sql) = sql_for(json!({
+        "query": "USE cs_standard MATCH (u:User) RETURN u.name AS name \
+                  UNION ALL USE cs_standard MATCH (u:User) RETURN u.name AS name"
+    }))
+    .await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+    assert!(!sql.contains("users_bench"), "SQL:\n{sql}");
+}
+
+#[tokio::test]
+async fn graph_name_header_confines_the_request() {
+    let query = json!({"query": "MATCH (u:User) RETURN u.name"});
+    let (status, sql) = sql_for_graph(Some("cs_standard"), query.clone()).await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+    assert!(sql.contains("cs_test.users"), "SQL:\n{sql}");
+
+    // Naming the same graph again is fine, reaching another one is not.
+    let (status, sql) = sql_for_graph(
+        Some("cs_standard"),
+        json!({"query": "USE cs_standard MATCH (u:User) RETURN u.name"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{sql}");
+    let (status, body) = sql_for_graph(
+        Some("cs_standard"),
+        json!({"query": "USE default MATCH (u:User) RETURN u.name"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(body.contains("not accessible"), "body: {body}");
+    let (status, _) = sql_for_graph(
+        Some("cs_standard"),
+        json!({"query": "MATCH (u:User) RETURN u.name", "schema_name": "default"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // Every statement of a multi-statement request is confined too.
+    let (status, body) = sql_for_graph(
+        Some("cs_standard"),
+        json!({"query": "MATCH (u:User) RETURN u.name; USE default MATCH (u:User) RETURN u.name"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "body: {body}");
+}
+
+#[tokio::test]
+async fn graph_name_header_confines_every_use_clause() {
+    // A USE in a later UNION branch, or after a newline, still counts.
+    for query in [
+        "MATCH (u:User) RETURN u.name AS name \
+         UNION ALL USE default MATCH (u:User) RETURN u.name AS name",
+        "USE\ndefault MATCH (u:User) RETURN u.name",
+        "COPY (USE default MATCH (u:User) RETURN u.name) TO '/tmp/users.csv'",
+    ] {
+        let (status, body) = sql_for_graph(Some("cs_standard"), json!({ "query": query })).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{query}: {body}");
+        assert!(body.contains("not accessible"), "{query}: {body}");
+    }
+}
+
+#[tokio::test]
+async fn sql_and_estimate_endpoints_honour_the_graph_header() {
+    let (status, body) = post_for_graph(
+        "/query/sql",
+        Some("cs_standard"),
+        json!({"query": "MATCH (u:User) RETURN u.name"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert!(body.to_string().contains("cs_test.users"), "{body}");
+    let (status, body) = post_for_graph(
+        "/query/sql",
+        Some("cs_standard"),
+        json!({"query": "USE default MATCH (u:User) RETURN u.name"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{body}");
+
+    // /query/estimate follows USE, and the header confines it.
+    let (status, body) = post_for_graph(
+        "/query/estimate",
+        None,
+        json!({"query": "USE cs_standard MATCH (u:User) RETURN u"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["schema_name"], "cs_standard", "{body}");
+    let (status, body) = post_for_graph(
+        "/query/estimate",
+        Some("cs_standard"),
+        json!({"query": "USE default MATCH (u:User) RETURN u"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "{body}");
+}
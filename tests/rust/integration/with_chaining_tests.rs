@@ -0,0 +1,153 @@
+//! Chained WITH pipelines: every WITH stage becomes its own CTE reading the
+//! previous one, keeping its ORDER BY / SKIP / LIMIT, and the variables it
+//! passes through stay readable by later stages and the final RETURN.
+
+use clickgraph::{
+    graph_catalog::{config::GraphSchemaConfig, graph_schema::GraphSchema},
+    open_cypher_parser::parse_cypher_statement,
+    query_planner::evaluate_read_statement,
+    render_plan::{logical_plan_to_render_plan_with_ctx, ToSql},
+    server::query_context::{set_current_schema, with_query_context, QueryContext},
+};
+
+const SCHEMA: &str = r#"
+name: social
+graph_schema:
+  nodes:
+    - label: User
+      database: db
+      table: users
+      node_id: user_id
+      property_mappings:
+        user_id: user_id
+        name: full_name
+        country: country
+    - label: Post
+      database: db
+      table: posts
+      node_id: post_id
+      property_mappings:
+        post_id: post_id
+        title: title
+  edges:
+    - type: FOLLOWS
+      database: db
+      table: follows
+      from_id: follower_id
+      to_id: followed_id
+      from_node: User
+      to_node: User
+      property_mappings: {}
+    - type: AUTHORED
+      database: db
+      table: authored
+      from_id: user_id
+      to_id: post_id
+      from_node: User
+      to_node: Post
+      property_mappings: {}
+"#;
+
+async fn cypher_to_sql(cypher: &str) -> String {
+    let schema: GraphSchema = GraphSchemaConfig::from_yaml_str(SCHEMA)
+        .unwrap()
+        .to_graph_schema()
+        .unwrap();
+    let cypher = cypher.to_string();
+    with_query_context(QueryContext::default(), async move {
+        set_current_schema(std::sync::Arc::new(schema.clone()));
+        clickgraph::query_planner::logical_plan::reset_all_counters();
+        let (_, statement) = parse_cypher_statement(&cypher).expect("parse");
+        let (logical_plan, plan_ctx) =
+            evaluate_read_statement(statement, &schema, None, None, None).expect("plan");
+        logical_plan_to_render_plan_with_ctx(logical_plan, &schema, Some(&plan_ctx))
+            .expect("render")
+            .to_sql()
+    })
+    .await
+}
+
+/// The body of CTE `name`, up to the next CTE or the final SELECT.
+fn cte_body<'a>(sql: &'a str, name: &str) -> &'a str {
+    let start = sql
+        .find(&format!("{name} AS (SELECT"))
+        .unwrap_or_else(|| panic!("no CTE {name}:\n{sql}"));
+    let body = &sql[start..];
+    let end = body.find("\n)").expect("CTE end");
+    &body[..end]
+}
+
+#[tokio::test]
+async fn four_stage_pipeline_reads_each_previous_stage() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User) WITH u.country AS c, count(u) AS cnt WHERE cnt > 1 \
+         WITH c, cnt ORDER BY cnt DESC LIMIT 5 \
+         MATCH (v:User) WHERE v.country = c \
+         WITH c, cnt, count(v) AS total \
+         RETURN c, cnt, total ORDER BY total DESC",
+    )
+    .await;
+
+    let aggregate = cte_body(&sql, "with_c_cnt_cte_0");
+    assert!(aggregate.contains("count(u.user_id) AS \"cnt\""), "{sql}");
+    assert!(aggregate.contains("HAVING cnt > 1"), "{sql}");
+
+    let top = cte_body(&sql, "with_c_cnt_cte_1");
+    assert!(top.contains("FROM with_c_cnt_cte_0 AS c_cnt"), "{sql}");
+    assert!(top.contains("ORDER BY c_cnt.cnt DESC"), "{sql}");
+    assert!(top.contains("LIMIT 5"), "{sql}");
+
+    let rematch = cte_body(&sql, "with_c_cnt_total_cte_2");
+    assert!(rematch.contains("FROM with_c_cnt_cte_1 AS c_cnt"), "{sql}");
+    assert!(rematch.contains("ON v.country = c_cnt.c"), "{sql}");
+    assert!(rematch.contains("count(v.user_id) AS \"total\""), "{sql}");
+    assert!(!sql.contains(".id\""), "scalars rendered as nodes:\n{sql}");
+}
+
+#[tokio::test]
+async fn scalar_pass_through_keeps_its_column() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User)-[:FOLLOWS]->(f:User) WITH u, count(f) AS fc \
+         WITH u, fc ORDER BY fc DESC LIMIT 10 \
+         RETURN u.name, fc",
+    )
+    .await;
+
+    let second = cte_body(&sql, "with_fc_u_cte_1");
+    assert!(second.contains("AS \"fc\""), "{sql}");
+    assert!(second.contains("LIMIT 10"), "{sql}");
+    assert!(sql.contains("fc_u.fc AS \"fc\""), "{sql}");
+}
+
+#[tokio::test]
+async fn grouping_on_a_passed_through_scalar() {
+    let sql = cypher_to_sql("MATCH (u:User) WITH u, u.country AS c RETURN c, count(u) AS n").await;
+
+    assert!(sql.contains("c_u.c AS \"c\""), "{sql}");
+    assert!(sql.contains("GROUP BY c_u.c"), "{sql}");
+    assert!(!sql.contains("anyLast"), "{sql}");
+}
+
+#[tokio::test]
+async fn sliced_collect_groups_after_an_ordered_stage() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User)-[:AUTHORED]->(p:Post) WITH u, p ORDER BY p.title \
+         WITH u, collect(p.title)[0..3] AS titles \
+         RETURN u.name, titles",
+    )
+    .await;
+
+    assert!(sql.contains("groupArray("), "{sql}");
+    assert!(sql.contains("GROUP BY"), "{sql}");
+}
+
+#[tokio::test]
+async fn collect_of_a_property_reads_the_stage_column() {
+    let sql = cypher_to_sql(
+        "MATCH (u:User) WITH u ORDER BY u.name LIMIT 3 \
+         RETURN collect(u.name) AS names",
+    )
+    .await;
+
+    assert!(sql.contains("groupArray(u.p1_u_name)"), "{sql}");
+}
@@ -160,9 +160,12 @@ property_mappings:
             view_parameters: Some(vec!["tenant_id".to_string()]),
             use_final: None,
             filter: None,
+            soft_delete: None,
+            collations: HashMap::new(),
             auto_discover_columns: false,
             exclude_columns: vec![],
             naming_convention: "snake_case".to_string(),
+            lazy: false,
             from_node_properties: None,
             to_node_properties: None,
             r#type: Some("string".to_string()),
@@ -170,6 +173,12 @@ property_mappings:
             source: None,
             property_types: HashMap::new(),
             id_generation: None,
+            max_unfiltered_rows: None,
+            on_unfiltered_scan: None,
+            return_properties: None,
+            max_return_columns: None,
+            description: None,
+            owners: Vec::new(),
         };
 
         // Serialize to YAML